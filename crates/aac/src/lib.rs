@@ -178,8 +178,13 @@ impl PartialAudioSpecificConfig {
     /// - Sampling Frequency
     /// - Channel Configuration
     pub fn parse(data: &[u8]) -> io::Result<Self> {
-        let mut bitreader = BitReader::new_from_slice(data);
+        Self::parse_bits(&mut BitReader::new_from_slice(data))
+    }
 
+    /// Parse the top fields of an Audio Specific Config that starts at the
+    /// current position of `bitreader`, which need not be byte aligned (as in
+    /// the LATM `StreamMuxConfig`).
+    pub fn parse_bits<R: io::Read>(bitreader: &mut BitReader<R>) -> io::Result<Self> {
         // GetAudioObjectType() # ISO/IEC 14496-3:2019(E) - 1.6.2.1 (Table 1.20)
         let mut audio_object_type = bitreader.read_bits(5)? as u16;
         if audio_object_type == 31 {
@@ -216,6 +221,20 @@ impl PartialAudioSpecificConfig {
             channel_configuration,
         })
     }
+
+    /// Number of output channels of the channel configuration, or `None` when
+    /// it is 0 (defined by a program config element) or reserved.
+    ///
+    /// ISO/IEC 14496-3:2019(E) - 1.6.3.4 (Table 1.19)
+    pub const fn channel_count(&self) -> Option<u8> {
+        match self.channel_configuration {
+            1..=6 => Some(self.channel_configuration),
+            7 | 12 | 14 => Some(8),
+            11 => Some(7),
+            13 => Some(24),
+            _ => None,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -234,6 +253,7 @@ mod tests {
         assert_eq!(config.audio_object_type, AudioObjectType::AacLowComplexity);
         assert_eq!(config.sampling_frequency, 44100);
         assert_eq!(config.channel_configuration, 2);
+        assert_eq!(config.channel_count(), Some(2));
     }
 
     #[test]
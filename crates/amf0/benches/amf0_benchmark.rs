@@ -0,0 +1,88 @@
+use std::borrow::Cow;
+use std::hint::black_box;
+
+use amf0::{Amf0Decoder, Amf0Encoder, Amf0Value};
+use criterion::{Criterion, Throughput, criterion_group, criterion_main};
+
+/// An `onMetaData` script tag body as written after a long 4K recording, dominated by the
+/// keyframe index.
+fn metadata_values(keyframes: usize) -> Vec<Amf0Value<'static>> {
+    let times = (0..keyframes)
+        .map(|i| Amf0Value::Number(i as f64 * 2.0))
+        .collect::<Vec<_>>();
+    let positions = (0..keyframes)
+        .map(|i| Amf0Value::Number(13.0 + i as f64 * 5_000_000.0))
+        .collect::<Vec<_>>();
+    let keyframes = Amf0Value::Object(
+        vec![
+            (Cow::Borrowed("times"), Amf0Value::StrictArray(times.into())),
+            (
+                Cow::Borrowed("filepositions"),
+                Amf0Value::StrictArray(positions.into()),
+            ),
+        ]
+        .into(),
+    );
+
+    let mut properties = vec![
+        (Cow::Borrowed("duration"), Amf0Value::Number(7_200.0)),
+        (Cow::Borrowed("width"), Amf0Value::Number(3_840.0)),
+        (Cow::Borrowed("height"), Amf0Value::Number(2_160.0)),
+        (Cow::Borrowed("videodatarate"), Amf0Value::Number(20_000.0)),
+        (Cow::Borrowed("framerate"), Amf0Value::Number(60.0)),
+        (Cow::Borrowed("videocodecid"), Amf0Value::Number(12.0)),
+        (Cow::Borrowed("audiodatarate"), Amf0Value::Number(128.0)),
+        (
+            Cow::Borrowed("audiosamplerate"),
+            Amf0Value::Number(48_000.0),
+        ),
+        (Cow::Borrowed("stereo"), Amf0Value::Boolean(true)),
+        (Cow::Borrowed("audiocodecid"), Amf0Value::Number(10.0)),
+        (
+            Cow::Borrowed("metadatacreator"),
+            Amf0Value::String(Cow::Borrowed("rust-srec")),
+        ),
+        (Cow::Borrowed("hasKeyframes"), Amf0Value::Boolean(true)),
+    ];
+    properties.push((Cow::Borrowed("keyframes"), keyframes));
+
+    vec![
+        Amf0Value::String(Cow::Borrowed("onMetaData")),
+        Amf0Value::EcmaArray(properties.into()),
+    ]
+}
+
+fn amf0_benchmark(c: &mut Criterion) {
+    let values = metadata_values(3_600);
+    let mut encoded = Vec::new();
+    for value in &values {
+        Amf0Encoder::encode(&mut encoded, value).unwrap();
+    }
+
+    let mut group = c.benchmark_group("Amf0");
+    group.throughput(Throughput::Bytes(encoded.len() as u64));
+
+    group.bench_function("decode_metadata", |b| {
+        b.iter(|| {
+            let (values, error) = Amf0Decoder::new(black_box(&encoded)).decode_all();
+            assert!(error.is_none());
+            black_box(values);
+        })
+    });
+
+    group.bench_function("encode_metadata", |b| {
+        let mut buffer = Vec::with_capacity(encoded.len());
+        b.iter(|| {
+            buffer.clear();
+            for value in black_box(&values) {
+                Amf0Encoder::encode(&mut buffer, value).unwrap();
+            }
+            black_box(&buffer);
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, amf0_benchmark);
+criterion_main!(benches);
@@ -38,56 +38,71 @@ impl<'a> Amf0Decoder<'a> {
         self.pos >= self.data.len()
     }
 
+    /// The bytes not consumed yet.
+    fn remaining(&self) -> &'a [u8] {
+        self.data.get(self.pos..).unwrap_or_default()
+    }
+
+    fn eof() -> Amf0ReadError {
+        Amf0ReadError::Io(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            "not enough data",
+        ))
+    }
+
     /// Read `len` bytes from the buffer, advancing the position.
     fn read_bytes(&mut self, len: usize) -> Result<&'a [u8], Amf0ReadError> {
-        let end = self.pos + len;
-        if end > self.data.len() {
-            return Err(Amf0ReadError::Io(io::Error::new(
-                io::ErrorKind::UnexpectedEof,
-                "not enough data",
-            )));
-        }
-        let bytes = &self.data[self.pos..end];
-        self.pos = end;
+        let (bytes, _) = self
+            .remaining()
+            .split_at_checked(len)
+            .ok_or_else(Self::eof)?;
+        self.pos += len;
         Ok(bytes)
     }
 
+    /// Read `N` bytes as an array, advancing the position.
+    ///
+    /// The length is known at compile time, so the fixed-size reads below compile down to a
+    /// single bounds check and an unaligned load.
+    #[inline]
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N], Amf0ReadError> {
+        let bytes = self.remaining().first_chunk::<N>().ok_or_else(Self::eof)?;
+        self.pos += N;
+        Ok(*bytes)
+    }
+
     /// Read a single byte, advancing the position.
     fn read_u8(&mut self) -> Result<u8, Amf0ReadError> {
-        let bytes = self.read_bytes(1)?;
-        Ok(bytes[0])
+        let [byte] = self.read_array()?;
+        Ok(byte)
     }
 
     /// Read a big-endian u16, advancing the position.
     fn read_u16_be(&mut self) -> Result<u16, Amf0ReadError> {
-        let bytes = self.read_bytes(2)?;
-        Ok(u16::from_be_bytes([bytes[0], bytes[1]]))
+        Ok(u16::from_be_bytes(self.read_array()?))
     }
 
     /// Read a big-endian u32, advancing the position.
     fn read_u32_be(&mut self) -> Result<u32, Amf0ReadError> {
-        let bytes = self.read_bytes(4)?;
-        Ok(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+        Ok(u32::from_be_bytes(self.read_array()?))
     }
 
     /// Read a big-endian f64, advancing the position.
     fn read_f64_be(&mut self) -> Result<f64, Amf0ReadError> {
-        let bytes = self.read_bytes(8)?;
-        Ok(f64::from_be_bytes([
-            bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7],
-        ]))
+        Ok(f64::from_be_bytes(self.read_array()?))
     }
 
     /// Read a big-endian i16, advancing the position.
     fn read_i16_be(&mut self) -> Result<i16, Amf0ReadError> {
-        let bytes = self.read_bytes(2)?;
-        Ok(i16::from_be_bytes([bytes[0], bytes[1]]))
+        Ok(i16::from_be_bytes(self.read_array()?))
     }
 
-    /// Read a big-endian u24 (3 bytes), advancing the position.
-    fn read_u24_be(&mut self) -> Result<u32, Amf0ReadError> {
-        let bytes = self.read_bytes(3)?;
-        Ok(u32::from_be_bytes([0, bytes[0], bytes[1], bytes[2]]))
+    /// Capacity to reserve for `len` declared entries of at least `min_size` bytes each.
+    ///
+    /// The declared count comes from the wire, so it is capped by what the remaining data
+    /// could possibly hold instead of being trusted for the allocation.
+    fn capacity_for(&self, len: u32, min_size: usize) -> usize {
+        (len as usize).min(self.remaining().len() / min_size)
     }
 
     /// Read all the encoded values from the decoder.
@@ -220,31 +235,22 @@ impl<'a> Amf0Decoder<'a> {
         Ok(Cow::Borrowed(std::str::from_utf8(bytes)?))
     }
 
-    fn is_read_object_eof(&mut self) -> Result<bool, Amf0ReadError> {
-        // Need at least 3 bytes to check
-        if self.pos + 3 > self.data.len() {
-            return Ok(false);
-        }
-
-        let saved_pos = self.pos;
-        let value = self.read_u24_be()?;
-
-        if Amf0Marker::is_object_end_u24(value) {
-            Ok(true)
-        } else {
-            self.pos = saved_pos;
-            Ok(false)
+    /// Consume the object end marker if it is next.
+    fn is_read_object_eof(&mut self) -> bool {
+        let Some(&[a, b, c]) = self.remaining().first_chunk::<3>() else {
+            return false;
+        };
+        let is_end = Amf0Marker::is_object_end_u24(u32::from_be_bytes([0, a, b, c]));
+        if is_end {
+            self.pos += 3;
         }
+        is_end
     }
 
     fn read_object(&mut self) -> Result<Vec<(Cow<'a, str>, Amf0Value<'a>)>, Amf0ReadError> {
         let mut properties = Vec::new();
 
-        loop {
-            if self.is_read_object_eof()? {
-                break;
-            }
-
+        while !self.is_read_object_eof() {
             let key = self.read_string()?;
             let val = self.decode()?;
 
@@ -257,7 +263,8 @@ impl<'a> Amf0Decoder<'a> {
     fn read_ecma_array(&mut self) -> Result<Vec<(Cow<'a, str>, Amf0Value<'a>)>, Amf0ReadError> {
         let len = self.read_u32_be()?;
 
-        let mut properties = Vec::new();
+        // Each property is at least a key length and a marker
+        let mut properties = Vec::with_capacity(self.capacity_for(len, 3));
 
         for _ in 0..len {
             let key = self.read_string()?;
@@ -267,7 +274,7 @@ impl<'a> Amf0Decoder<'a> {
 
         // Sometimes the object end marker is present and sometimes it is not.
         // If it is there just consume it, if not then we are done.
-        let _ = self.is_read_object_eof()?;
+        self.is_read_object_eof();
 
         Ok(properties)
     }
@@ -282,10 +289,18 @@ impl<'a> Amf0Decoder<'a> {
     fn read_strict_array(&mut self) -> Result<Vec<Amf0Value<'a>>, Amf0ReadError> {
         let len = self.read_u32_be()?;
 
-        let mut values = Vec::with_capacity(len as usize);
+        // Each value is at least a marker
+        let mut values = Vec::with_capacity(self.capacity_for(len, 1));
 
         for _ in 0..len {
-            let val = self.decode()?;
+            // Keyframe indexes are long runs of numbers: skip the generic dispatch for them
+            let val = match self.remaining().first_chunk::<9>() {
+                Some(&[0x00, ref number @ ..]) => {
+                    self.pos += 9;
+                    Amf0Value::Number(f64::from_be_bytes(*number))
+                }
+                _ => self.decode()?,
+            };
             values.push(val);
         }
 
@@ -407,6 +422,20 @@ mod tests {
         assert!(amf_reader.is_empty());
     }
 
+    #[test]
+    fn test_reader_strict_array_with_bogus_length() {
+        // Declares u32::MAX elements but carries a single null
+        let amf0_array = vec![0x0a, 0xFF, 0xFF, 0xFF, 0xFF, 0x05];
+
+        let mut amf_reader = Amf0Decoder::new(&amf0_array);
+        let result = amf_reader.decode_with_type(Amf0Marker::StrictArray);
+
+        assert!(matches!(
+            result,
+            Err(Amf0ReadError::Io(err)) if err.kind() == io::ErrorKind::UnexpectedEof
+        ));
+    }
+
     #[test]
     fn test_reader_strict_array() {
         let mut amf0_array = vec![0x0a, 0x00, 0x00, 0x00, 0x03]; // StrictArray marker with 3 elements
@@ -51,8 +51,10 @@ impl Amf0Encoder {
 
     /// Encode an AMF0 number
     pub fn encode_number(writer: &mut impl io::Write, value: f64) -> Result<(), Amf0WriteError> {
-        writer.write_u8(Amf0Marker::Number as u8)?;
-        writer.write_f64::<BigEndian>(value)?;
+        // A single write: keyframe indexes are thousands of numbers in a row
+        let mut buf = [Amf0Marker::Number as u8; 9];
+        buf[1..].copy_from_slice(&value.to_be_bytes());
+        writer.write_all(&buf)?;
         Ok(())
     }
 
@@ -4,6 +4,8 @@ use byteorder::ReadBytesExt;
 use bytes::Bytes;
 use bytes_util::{BitReader, BitWriter, BytesCursorExt};
 
+use crate::seq::SequenceHeaderObu;
+
 /// AV1 Video Descriptor
 ///
 /// <https://aomediacodec.github.io/av1-mpeg2-ts/#av1-video-descriptor>
@@ -125,6 +127,31 @@ pub struct AV1CodecConfigurationRecord {
 }
 
 impl AV1CodecConfigurationRecord {
+    /// Builds the ISOBMFF record describing a sequence header.
+    ///
+    /// `sequence_header_obu` is the complete sequence header OBU, with its
+    /// `obu_size` field, and becomes the `configOBUs`.
+    pub fn from_sequence_header(seq: &SequenceHeaderObu, sequence_header_obu: Bytes) -> Self {
+        let operating_point = seq.operating_points.first();
+        let color_config = &seq.color_config;
+        AV1CodecConfigurationRecord {
+            seq_profile: seq.seq_profile,
+            seq_level_idx_0: operating_point.map_or(0, |point| point.seq_level_idx),
+            seq_tier_0: operating_point.is_some_and(|point| point.seq_tier),
+            high_bitdepth: color_config.bit_depth > 8,
+            twelve_bit: color_config.bit_depth == 12,
+            monochrome: color_config.mono_chrome,
+            chroma_subsampling_x: color_config.subsampling_x,
+            chroma_subsampling_y: color_config.subsampling_y,
+            chroma_sample_position: color_config.chroma_sample_position,
+            hdr_wcg_idc: 0,
+            initial_presentation_delay_minus_one: operating_point
+                .and_then(|point| point.initial_display_delay)
+                .map(|delay| delay - 1),
+            config_obu: sequence_header_obu,
+        }
+    }
+
     /// Returns the `config_obu` payload as a zero-copy `Bytes` slice.
     ///
     /// The AV1 codec configuration record (`av1C` payload) has a fixed-size
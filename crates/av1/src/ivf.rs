@@ -12,7 +12,9 @@ use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 use bytes::Bytes;
 use bytes_util::BytesCursorExt;
 
+use crate::config::AV1CodecConfigurationRecord;
 use crate::error::{Av1Error, Result};
+use crate::sample::{IsobmffSample, IsobmffSampleConverter};
 
 /// IVF file signature: `"DKIF"`.
 const IVF_SIGNATURE: [u8; 4] = *b"DKIF";
@@ -184,6 +186,62 @@ impl IvfFrame {
     }
 }
 
+/// A sample of an IVF stream converted for ISOBMFF.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IvfIsobmffSample {
+    /// Presentation timestamp in timebase units.
+    pub pts: u64,
+    /// Time to the next frame in timebase units. The last frame repeats the
+    /// duration of the one before it.
+    pub duration: u64,
+    /// The sample payload.
+    pub sample: IsobmffSample,
+}
+
+/// An IVF stream converted for ISOBMFF: its `av1C` record and samples.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IvfIsobmffTrack {
+    /// The IVF file header, with the dimensions and timebase.
+    pub header: IvfHeader,
+    /// The `av1C` record built from the last sequence header of the stream.
+    pub config: AV1CodecConfigurationRecord,
+    /// The samples, in file order.
+    pub samples: Vec<IvfIsobmffSample>,
+}
+
+/// Converts a complete IVF file into ISOBMFF samples and an `av1C` record.
+///
+/// See [`IsobmffSampleConverter`] for how temporal units are rewritten.
+pub fn ivf_to_isobmff(data: Bytes) -> Result<IvfIsobmffTrack> {
+    let mut reader = io::Cursor::new(data);
+    let header = IvfHeader::demux(&mut reader)?;
+    let mut converter = IsobmffSampleConverter::new();
+    let mut samples: Vec<IvfIsobmffSample> = Vec::new();
+
+    while reader.position() < reader.get_ref().len() as u64 {
+        let frame = IvfFrame::demux(&mut reader)?;
+        let sample = converter.convert(&frame.data)?;
+        if let Some(previous) = samples.last_mut() {
+            previous.duration = frame.header.pts.saturating_sub(previous.pts);
+        }
+        let duration = samples.last().map_or(1, |previous| previous.duration);
+        samples.push(IvfIsobmffSample {
+            pts: frame.header.pts,
+            duration,
+            sample,
+        });
+    }
+
+    let config = converter
+        .config_record()
+        .ok_or_else(|| Av1Error::InvalidObu("IVF stream has no sequence header".to_string()))?;
+    Ok(IvfIsobmffTrack {
+        header,
+        config,
+        samples,
+    })
+}
+
 /// IVF file writer.
 ///
 /// Writes an IVF file header followed by frames. Call [`finalize`](IvfWriter::finalize)
@@ -253,6 +311,66 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_ivf_to_isobmff() {
+        use crate::obu_stream::write_obu;
+        use crate::{ObuHeader, ObuType};
+
+        let seq = b"\0\0\0j\xef\xbf\xe1\xbc\x02\x19\x90\x10\x10\x10@";
+        let mut key_frame = Vec::new();
+        write_obu(&mut key_frame, ObuType::TemporalDelimiter, None, &[]).unwrap();
+        write_obu(&mut key_frame, ObuType::SequenceHeader, None, seq).unwrap();
+        write_obu(&mut key_frame, ObuType::Frame, None, &[0b0001_0000, 0xAA]).unwrap();
+
+        let mut inter_frame = Vec::new();
+        write_obu(&mut inter_frame, ObuType::TemporalDelimiter, None, &[]).unwrap();
+        write_obu(&mut inter_frame, ObuType::Padding, None, &[0x00; 4]).unwrap();
+        // The last OBU may omit its size field
+        ObuHeader {
+            obu_type: ObuType::Frame,
+            size: None,
+            extension_header: None,
+        }
+        .mux(&mut inter_frame)
+        .unwrap();
+        inter_frame.extend_from_slice(&[0b0011_0000, 0xBB]);
+
+        let mut writer = IvfWriter::new(Vec::new(), &test_header()).unwrap();
+        writer.write_frame(0, &key_frame).unwrap();
+        writer.write_frame(1, &inter_frame).unwrap();
+        writer.write_frame(3, &inter_frame).unwrap();
+        let track = ivf_to_isobmff(Bytes::from(writer.into_inner())).unwrap();
+
+        let mut seq_obu = Vec::new();
+        write_obu(&mut seq_obu, ObuType::SequenceHeader, None, seq).unwrap();
+        assert_eq!(track.config.config_obu.as_ref(), seq_obu.as_slice());
+        assert_eq!(track.config.seq_profile, 0);
+        let mut av1c = Vec::new();
+        track.config.mux(&mut av1c).unwrap();
+        let parsed = AV1CodecConfigurationRecord::demux(&mut io::Cursor::new(av1c.into())).unwrap();
+        assert_eq!(parsed, track.config);
+
+        let timing: Vec<_> = track
+            .samples
+            .iter()
+            .map(|sample| (sample.pts, sample.duration, sample.sample.is_sync))
+            .collect();
+        assert_eq!(timing, [(0, 1, true), (1, 2, false), (3, 2, false)]);
+
+        let mut expected = seq_obu;
+        write_obu(&mut expected, ObuType::Frame, None, &[0b0001_0000, 0xAA]).unwrap();
+        assert_eq!(track.samples[0].sample.data.as_ref(), expected.as_slice());
+        assert_eq!(
+            track.samples[1].sample.data.as_ref(),
+            &[0x32, 0x02, 0b0011_0000, 0xBB]
+        );
+        crate::sample::validate_isobmff_sample(&track.samples[1].sample.data).unwrap();
+
+        let mut writer = IvfWriter::new(Vec::new(), &test_header()).unwrap();
+        writer.write_frame(0, &inter_frame).unwrap();
+        assert!(ivf_to_isobmff(Bytes::from(writer.into_inner())).is_err());
+    }
+
     #[test]
     fn test_ivf_header_round_trip() {
         let header = test_header();
@@ -4,10 +4,12 @@
 //! - OBU (Open Bitstream Unit) header parsing and writing
 //! - AV1 Codec Configuration Record (ISO BMFF / MPEG-2 TS)
 //! - Sequence header OBU parsing
+//! - Frame header OBU parsing for key frame detection
+//! - Metadata OBU parsing (HDR content light level, mastering display, ITU-T T.35)
 //! - IVF container format parsing and writing
 //! - Low-overhead OBU bitstream parsing and writing
 //! - Annex B length-delimited bitstream parsing and writing
-//! - ISOBMFF sample payload parsing helpers
+//! - ISOBMFF sample payload parsing helpers and IVF to ISOBMFF sample conversion
 //!
 //! ## License
 //!
@@ -31,4 +33,4 @@ pub mod sample;
 pub use config::{AV1CodecConfigurationRecord, AV1VideoDescriptor};
 pub use error::{Av1Error, Result};
 pub use obu::utils::{leb128_size, write_leb128};
-pub use obu::{ObuExtensionHeader, ObuHeader, ObuType, seq};
+pub use obu::{ObuExtensionHeader, ObuHeader, ObuType, frame, metadata, seq};
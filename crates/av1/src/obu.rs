@@ -3,6 +3,8 @@ use std::io;
 use bytes_util::{BitReader, BitWriter};
 use utils::read_leb128;
 
+pub mod frame;
+pub mod metadata;
 pub mod seq;
 pub mod utils;
 
@@ -0,0 +1,248 @@
+//! Frame Header
+//!
+//! Only the leading fields of `uncompressed_header()` are parsed: enough to
+//! tell key frames from the rest without the reference frame state a decoder
+//! keeps.
+
+use std::io;
+
+use bytes::Bytes;
+use bytes_util::BitReader;
+
+use super::seq::SequenceHeaderObu;
+use super::{ObuHeader, ObuType};
+use crate::error::Result;
+use crate::obu_stream::ContainerObuIterator;
+
+/// `frame_type`
+///
+/// AV1-Spec-2 - 6.8.2
+#[derive(Debug, Clone, PartialEq, Eq, Copy)]
+pub enum FrameType {
+    /// `KEY_FRAME`
+    Key,
+    /// `INTER_FRAME`
+    Inter,
+    /// `INTRA_ONLY_FRAME`
+    IntraOnly,
+    /// `SWITCH_FRAME`
+    Switch,
+}
+
+impl From<u8> for FrameType {
+    fn from(value: u8) -> Self {
+        match value & 0x03 {
+            0 => FrameType::Key,
+            1 => FrameType::Inter,
+            2 => FrameType::IntraOnly,
+            _ => FrameType::Switch,
+        }
+    }
+}
+
+/// Frame Header OBU, also the start of a Frame OBU
+///
+/// AV1-Spec-2 - 5.9
+#[derive(Debug, Clone, PartialEq, Eq, Copy)]
+pub struct FrameHeaderObu {
+    /// The OBU header that precedes the frame header
+    pub header: ObuHeader,
+    /// `show_existing_frame`
+    ///
+    /// 1 bit
+    pub show_existing_frame: bool,
+    /// `frame_to_show_map_idx` if `show_existing_frame` is 1
+    ///
+    /// 3 bits
+    pub frame_to_show_map_idx: Option<u8>,
+    /// `frame_type`
+    ///
+    /// 2 bits. Not coded when `show_existing_frame` is 1, in which case it is
+    /// the type of the shown reference frame and reported as
+    /// [`FrameType::Inter`] here.
+    pub frame_type: FrameType,
+    /// `show_frame`
+    ///
+    /// 1 bit
+    pub show_frame: bool,
+    /// `showable_frame`
+    ///
+    /// 1 bit
+    pub showable_frame: bool,
+    /// `error_resilient_mode`
+    ///
+    /// 1 bit
+    pub error_resilient_mode: bool,
+}
+
+impl FrameHeaderObu {
+    /// Returns a reference to the header of the OBU.
+    pub const fn header(&self) -> &ObuHeader {
+        &self.header
+    }
+
+    /// Parses the leading fields of a frame header from the given reader.
+    ///
+    /// `seq` is the sequence header in effect, which decides which fields
+    /// are present.
+    pub fn parse(
+        header: ObuHeader,
+        seq: &SequenceHeaderObu,
+        reader: &mut impl io::Read,
+    ) -> io::Result<Self> {
+        if seq.reduced_still_picture_header {
+            return Ok(Self {
+                header,
+                show_existing_frame: false,
+                frame_to_show_map_idx: None,
+                frame_type: FrameType::Key,
+                show_frame: true,
+                showable_frame: false,
+                error_resilient_mode: true,
+            });
+        }
+
+        let mut bit_reader = BitReader::new(reader);
+        // temporal_point_info() is present when the decoder model gives
+        // presentation times per frame
+        let frame_presentation_time_length = seq
+            .decoder_model_info
+            .filter(|_| {
+                seq.timing_info
+                    .is_some_and(|timing_info| timing_info.num_ticks_per_picture.is_none())
+            })
+            .map(|decoder_model_info| decoder_model_info.frame_presentation_time_length);
+
+        let show_existing_frame = bit_reader.read_bit()?;
+        if show_existing_frame {
+            let frame_to_show_map_idx = bit_reader.read_bits(3)? as u8;
+            return Ok(Self {
+                header,
+                show_existing_frame,
+                frame_to_show_map_idx: Some(frame_to_show_map_idx),
+                frame_type: FrameType::Inter,
+                show_frame: true,
+                showable_frame: false,
+                error_resilient_mode: false,
+            });
+        }
+
+        let frame_type = FrameType::from(bit_reader.read_bits(2)? as u8);
+        let show_frame = bit_reader.read_bit()?;
+        if show_frame && let Some(length) = frame_presentation_time_length {
+            // frame_presentation_time
+            bit_reader.read_bits(length)?;
+        }
+        let showable_frame = if show_frame {
+            frame_type != FrameType::Key
+        } else {
+            bit_reader.read_bit()?
+        };
+        let error_resilient_mode =
+            if frame_type == FrameType::Switch || (frame_type == FrameType::Key && show_frame) {
+                true
+            } else {
+                bit_reader.read_bit()?
+            };
+
+        Ok(Self {
+            header,
+            show_existing_frame,
+            frame_to_show_map_idx: None,
+            frame_type,
+            show_frame,
+            showable_frame,
+            error_resilient_mode,
+        })
+    }
+
+    /// Parses the first frame header of a temporal unit, such as an
+    /// ISOBMFF sample or an FLV coded frame.
+    ///
+    /// Returns `None` if the temporal unit has no frame header.
+    pub fn first_in_temporal_unit(data: &Bytes, seq: &SequenceHeaderObu) -> Result<Option<Self>> {
+        let mut cursor = io::Cursor::new(data.clone());
+        for obu in ContainerObuIterator::new(&mut cursor) {
+            let obu = obu?;
+            if matches!(
+                obu.header.obu_type,
+                ObuType::FrameHeader | ObuType::Frame | ObuType::RedundantFrameHeader
+            ) {
+                let frame_header = Self::parse(obu.header, seq, &mut io::Cursor::new(obu.data))?;
+                return Ok(Some(frame_header));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Whether this is a shown key frame, which resets all references and
+    /// lets decoding start here.
+    pub fn is_key_frame(&self) -> bool {
+        !self.show_existing_frame && self.frame_type == FrameType::Key && self.show_frame
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(all(coverage_nightly, test), coverage(off))]
+mod tests {
+    use super::*;
+
+    fn seq() -> SequenceHeaderObu {
+        let obu = b"\0\0\0j\xef\xbf\xe1\xbc\x02\x19\x90\x10\x10\x10@";
+        let header = ObuHeader {
+            obu_type: ObuType::SequenceHeader,
+            size: None,
+            extension_header: None,
+        };
+        SequenceHeaderObu::parse(header, &mut io::Cursor::new(obu)).unwrap()
+    }
+
+    #[test]
+    fn test_frame_header_parse() {
+        let seq = seq();
+        // Temporal delimiter, then a frame OBU: show_existing_frame=0,
+        // frame_type=KEY_FRAME, show_frame=1
+        let data = Bytes::from_static(&[0x12, 0x00, 0x32, 0x02, 0b0001_0000, 0x00]);
+        let frame_header = FrameHeaderObu::first_in_temporal_unit(&data, &seq)
+            .unwrap()
+            .unwrap();
+        assert_eq!(frame_header.header.obu_type, ObuType::Frame);
+        assert_eq!(frame_header.frame_type, FrameType::Key);
+        assert!(frame_header.show_frame);
+        assert!(frame_header.error_resilient_mode);
+        assert!(frame_header.is_key_frame());
+
+        // Inter frame, not shown, showable, error resilient
+        let header = ObuHeader {
+            obu_type: ObuType::FrameHeader,
+            size: None,
+            extension_header: None,
+        };
+        let frame_header =
+            FrameHeaderObu::parse(header, &seq, &mut io::Cursor::new([0b0010_1100])).unwrap();
+        assert_eq!(frame_header.frame_type, FrameType::Inter);
+        assert!(!frame_header.show_frame);
+        assert!(frame_header.showable_frame);
+        assert!(frame_header.error_resilient_mode);
+        assert!(!frame_header.is_key_frame());
+
+        // Shown intra-only frames are not key frames
+        let frame_header =
+            FrameHeaderObu::parse(header, &seq, &mut io::Cursor::new([0b0101_0000])).unwrap();
+        assert_eq!(frame_header.frame_type, FrameType::IntraOnly);
+        assert!(!frame_header.is_key_frame());
+
+        // show_existing_frame of slot 5
+        let frame_header =
+            FrameHeaderObu::parse(header, &seq, &mut io::Cursor::new([0b1101_0000])).unwrap();
+        assert!(frame_header.show_existing_frame);
+        assert_eq!(frame_header.frame_to_show_map_idx, Some(5));
+        assert!(!frame_header.is_key_frame());
+
+        let data = Bytes::from_static(&[0x12, 0x00]);
+        assert_eq!(
+            FrameHeaderObu::first_in_temporal_unit(&data, &seq).unwrap(),
+            None
+        );
+    }
+}
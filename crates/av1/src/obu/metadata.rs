@@ -0,0 +1,346 @@
+//! Metadata OBU
+
+use std::io::{self, Read};
+
+use bytes::Bytes;
+use bytes_util::BitReader;
+
+use super::ObuHeader;
+use super::utils::read_leb128;
+
+/// ITU-T T.35 country code of the United States
+pub const ITUT_T35_COUNTRY_CODE_US: u8 = 0xB5;
+
+/// ITU-T T.35 provider code of Samsung, which registered HDR10+
+pub const ITUT_T35_PROVIDER_CODE_SAMSUNG: u16 = 0x003C;
+
+/// `metadata_type`
+///
+/// AV1-Spec-2 - 6.7.1
+#[derive(Debug, Clone, PartialEq, Eq, Copy)]
+pub enum MetadataType {
+    /// `METADATA_TYPE_HDR_CLL`
+    HdrCll,
+    /// `METADATA_TYPE_HDR_MDCV`
+    HdrMdcv,
+    /// `METADATA_TYPE_SCALABILITY`
+    Scalability,
+    /// `METADATA_TYPE_ITUT_T35`
+    ItutT35,
+    /// `METADATA_TYPE_TIMECODE`
+    Timecode,
+    /// Reserved or unregistered user private types
+    Reserved(u64),
+}
+
+impl From<u64> for MetadataType {
+    fn from(value: u64) -> Self {
+        match value {
+            1 => MetadataType::HdrCll,
+            2 => MetadataType::HdrMdcv,
+            3 => MetadataType::Scalability,
+            4 => MetadataType::ItutT35,
+            5 => MetadataType::Timecode,
+            _ => MetadataType::Reserved(value),
+        }
+    }
+}
+
+impl From<MetadataType> for u64 {
+    fn from(value: MetadataType) -> Self {
+        match value {
+            MetadataType::HdrCll => 1,
+            MetadataType::HdrMdcv => 2,
+            MetadataType::Scalability => 3,
+            MetadataType::ItutT35 => 4,
+            MetadataType::Timecode => 5,
+            MetadataType::Reserved(value) => value,
+        }
+    }
+}
+
+/// Content light level metadata
+///
+/// AV1-Spec-2 - 5.8.3
+#[derive(Debug, Clone, PartialEq, Eq, Copy)]
+pub struct HdrCll {
+    /// `max_cll`, in cd/m²
+    ///
+    /// 16 bits
+    pub max_cll: u16,
+    /// `max_fall`, in cd/m²
+    ///
+    /// 16 bits
+    pub max_fall: u16,
+}
+
+/// Mastering display colour volume metadata
+///
+/// AV1-Spec-2 - 5.8.4
+#[derive(Debug, Clone, PartialEq, Eq, Copy)]
+pub struct HdrMdcv {
+    /// `primary_chromaticity_x` and `primary_chromaticity_y` of the red,
+    /// green and blue primaries, as 0.16 fixed-point values
+    pub primary_chromaticity: [(u16, u16); 3],
+    /// `white_point_chromaticity_x` and `white_point_chromaticity_y`, as
+    /// 0.16 fixed-point values
+    pub white_point_chromaticity: (u16, u16),
+    /// `luminance_max`, as a 24.8 fixed-point value in cd/m²
+    pub luminance_max: u32,
+    /// `luminance_min`, as an 18.14 fixed-point value in cd/m²
+    pub luminance_min: u32,
+}
+
+impl HdrMdcv {
+    /// Maximum luminance in cd/m².
+    pub fn max_luminance_nits(&self) -> f64 {
+        f64::from(self.luminance_max) / f64::from(1 << 8)
+    }
+
+    /// Minimum luminance in cd/m².
+    pub fn min_luminance_nits(&self) -> f64 {
+        f64::from(self.luminance_min) / f64::from(1 << 14)
+    }
+}
+
+/// ITU-T T.35 registered user data
+///
+/// AV1-Spec-2 - 5.8.2
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ItutT35 {
+    /// `itu_t_t35_country_code`
+    ///
+    /// 8 bits
+    pub country_code: u8,
+    /// `itu_t_t35_country_code_extension_byte` if `itu_t_t35_country_code` is 0xFF
+    pub country_code_extension: Option<u8>,
+    /// `itu_t_t35_payload_bytes`, starting with the terminal provider code
+    pub payload: Bytes,
+}
+
+impl ItutT35 {
+    /// `itu_t_t35_terminal_provider_code`, the first two payload bytes.
+    pub fn provider_code(&self) -> Option<u16> {
+        let code = self.payload.get(..2)?;
+        Some(u16::from_be_bytes([code[0], code[1]]))
+    }
+
+    /// Whether the payload carries HDR10+ dynamic metadata (SMPTE ST 2094-40).
+    pub fn is_hdr10_plus(&self) -> bool {
+        // terminal_provider_oriented_code 0x0001, application_identifier 4
+        self.country_code == ITUT_T35_COUNTRY_CODE_US
+            && self.provider_code() == Some(ITUT_T35_PROVIDER_CODE_SAMSUNG)
+            && self.payload.get(2..5) == Some(&[0x00, 0x01, 0x04])
+    }
+}
+
+/// The payload of a metadata OBU
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Metadata {
+    /// `metadata_hdr_cll()`
+    HdrCll(HdrCll),
+    /// `metadata_hdr_mdcv()`
+    HdrMdcv(HdrMdcv),
+    /// `metadata_itut_t35()`
+    ItutT35(ItutT35),
+    /// Metadata types that are not parsed, with their payload after
+    /// `metadata_type`
+    Other {
+        /// `metadata_type`
+        metadata_type: MetadataType,
+        /// The payload, trailing bits included
+        payload: Bytes,
+    },
+}
+
+/// Metadata OBU
+///
+/// AV1-Spec-2 - 5.8
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MetadataObu {
+    /// The OBU header that precedes the metadata
+    pub header: ObuHeader,
+    /// The metadata
+    pub metadata: Metadata,
+}
+
+impl MetadataObu {
+    /// Returns a reference to the header of the OBU.
+    pub const fn header(&self) -> &ObuHeader {
+        &self.header
+    }
+
+    /// `metadata_type` of the OBU.
+    pub fn metadata_type(&self) -> MetadataType {
+        match &self.metadata {
+            Metadata::HdrCll(_) => MetadataType::HdrCll,
+            Metadata::HdrMdcv(_) => MetadataType::HdrMdcv,
+            Metadata::ItutT35(_) => MetadataType::ItutT35,
+            Metadata::Other { metadata_type, .. } => *metadata_type,
+        }
+    }
+
+    /// Parses the metadata OBU payload from the given reader.
+    ///
+    /// Reads `obu_size` bytes when the header has a size field, and the rest
+    /// of the reader otherwise.
+    pub fn parse(header: ObuHeader, reader: &mut impl io::Read) -> io::Result<Self> {
+        let mut payload = Vec::new();
+        match header.size {
+            Some(size) => {
+                reader.take(size).read_to_end(&mut payload)?;
+                if payload.len() as u64 != size {
+                    return Err(io::Error::new(
+                        io::ErrorKind::UnexpectedEof,
+                        "metadata OBU is shorter than obu_size",
+                    ));
+                }
+            }
+            None => {
+                reader.read_to_end(&mut payload)?;
+            }
+        }
+        let payload = Bytes::from(payload);
+
+        let mut bit_reader = BitReader::new(io::Cursor::new(&payload[..]));
+        let metadata_type = MetadataType::from(read_leb128(&mut bit_reader)?);
+        let metadata = match metadata_type {
+            MetadataType::HdrCll => Metadata::HdrCll(HdrCll {
+                max_cll: bit_reader.read_bits(16)? as u16,
+                max_fall: bit_reader.read_bits(16)? as u16,
+            }),
+            MetadataType::HdrMdcv => {
+                let mut primary_chromaticity = [(0, 0); 3];
+                for primary in &mut primary_chromaticity {
+                    *primary = (
+                        bit_reader.read_bits(16)? as u16,
+                        bit_reader.read_bits(16)? as u16,
+                    );
+                }
+                Metadata::HdrMdcv(HdrMdcv {
+                    primary_chromaticity,
+                    white_point_chromaticity: (
+                        bit_reader.read_bits(16)? as u16,
+                        bit_reader.read_bits(16)? as u16,
+                    ),
+                    luminance_max: bit_reader.read_bits(32)? as u32,
+                    luminance_min: bit_reader.read_bits(32)? as u32,
+                })
+            }
+            MetadataType::ItutT35 => {
+                let country_code = bit_reader.read_bits(8)? as u8;
+                let country_code_extension = if country_code == 0xFF {
+                    Some(bit_reader.read_bits(8)? as u8)
+                } else {
+                    None
+                };
+                let start = bit_reader.into_inner().position() as usize;
+                Metadata::ItutT35(ItutT35 {
+                    country_code,
+                    country_code_extension,
+                    payload: payload.slice(start..trailing_bits_start(&payload).max(start)),
+                })
+            }
+            _ => {
+                let start = bit_reader.into_inner().position() as usize;
+                Metadata::Other {
+                    metadata_type,
+                    payload: payload.slice(start..),
+                }
+            }
+        };
+
+        Ok(Self { header, metadata })
+    }
+}
+
+/// Offset of the `trailing_bits()` of an OBU payload: the last non-zero
+/// byte, which holds the trailing one bit.
+fn trailing_bits_start(payload: &[u8]) -> usize {
+    payload
+        .iter()
+        .rposition(|byte| *byte != 0)
+        .unwrap_or(payload.len())
+}
+
+#[cfg(test)]
+#[cfg_attr(all(coverage_nightly, test), coverage(off))]
+mod tests {
+    use super::*;
+    use crate::ObuType;
+
+    fn header(size: Option<u64>) -> ObuHeader {
+        ObuHeader {
+            obu_type: ObuType::Metadata,
+            size,
+            extension_header: None,
+        }
+    }
+
+    #[test]
+    fn test_hdr_metadata_parse() {
+        let cll = [0x01, 0x03, 0xE8, 0x01, 0x90, 0x80];
+        let obu = MetadataObu::parse(header(None), &mut io::Cursor::new(cll)).unwrap();
+        assert_eq!(obu.metadata_type(), MetadataType::HdrCll);
+        assert_eq!(
+            obu.metadata,
+            Metadata::HdrCll(HdrCll {
+                max_cll: 1000,
+                max_fall: 400,
+            })
+        );
+
+        let mut mdcv = vec![0x02];
+        for value in [34000u16, 16000, 13250, 34500, 7500, 3000, 15635, 16450] {
+            mdcv.extend_from_slice(&value.to_be_bytes());
+        }
+        mdcv.extend_from_slice(&(1000u32 << 8).to_be_bytes());
+        mdcv.extend_from_slice(&(1u32 << 12).to_be_bytes());
+        mdcv.push(0x80);
+        // Followed by another OBU that must not be consumed
+        let size = mdcv.len() as u64;
+        mdcv.extend_from_slice(&[0x12, 0x00]);
+        let mut cursor = io::Cursor::new(mdcv);
+        let obu = MetadataObu::parse(header(Some(size)), &mut cursor).unwrap();
+        assert_eq!(cursor.position(), size);
+        let Metadata::HdrMdcv(mdcv) = obu.metadata else {
+            panic!("expected HdrMdcv, got {:?}", obu.metadata);
+        };
+        assert_eq!(mdcv.primary_chromaticity[0], (34000, 16000));
+        assert_eq!(mdcv.white_point_chromaticity, (15635, 16450));
+        assert_eq!(mdcv.max_luminance_nits(), 1000.0);
+        assert_eq!(mdcv.min_luminance_nits(), 0.25);
+    }
+
+    #[test]
+    fn test_itut_t35_parse() {
+        // HDR10+ header, then application_version and a payload byte
+        let data = [
+            0x04, 0xB5, 0x00, 0x3C, 0x00, 0x01, 0x04, 0x01, 0x40, 0x80, 0x00,
+        ];
+        let obu = MetadataObu::parse(header(None), &mut io::Cursor::new(data)).unwrap();
+        let Metadata::ItutT35(t35) = &obu.metadata else {
+            panic!("expected ItutT35, got {:?}", obu.metadata);
+        };
+        assert_eq!(t35.country_code_extension, None);
+        assert_eq!(t35.provider_code(), Some(ITUT_T35_PROVIDER_CODE_SAMSUNG));
+        assert!(t35.is_hdr10_plus());
+        assert_eq!(
+            t35.payload.as_ref(),
+            &[0x00, 0x3C, 0x00, 0x01, 0x04, 0x01, 0x40]
+        );
+
+        let data = [0x04, 0xFF, 0x01, 0xAA, 0x80];
+        let obu = MetadataObu::parse(header(None), &mut io::Cursor::new(data)).unwrap();
+        let Metadata::ItutT35(t35) = obu.metadata else {
+            panic!("expected ItutT35");
+        };
+        assert_eq!(t35.country_code_extension, Some(0x01));
+        assert!(!t35.is_hdr10_plus());
+        assert_eq!(t35.payload.as_ref(), &[0xAA]);
+
+        let data = [0x05, 0x1F, 0x80];
+        let obu = MetadataObu::parse(header(None), &mut io::Cursor::new(data)).unwrap();
+        assert_eq!(obu.metadata_type(), MetadataType::Timecode);
+    }
+}
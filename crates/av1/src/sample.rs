@@ -12,9 +12,12 @@ use std::io;
 
 use bytes::Bytes;
 
+use crate::config::AV1CodecConfigurationRecord;
 use crate::error::{Av1Error, Result};
 use crate::obu::ObuType;
-use crate::obu_stream::{ContainerObuIterator, Obu};
+use crate::obu::frame::FrameHeaderObu;
+use crate::obu::seq::SequenceHeaderObu;
+use crate::obu_stream::{ContainerObuIterator, Obu, write_obu};
 
 /// Parsing options for AV1 ISOBMFF sample payloads.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -117,6 +120,85 @@ pub fn validate_isobmff_sample_bytes_with_options(
     Ok(())
 }
 
+/// An AV1 temporal unit rewritten as an ISOBMFF sample payload.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IsobmffSample {
+    /// OBUs of the temporal unit, each with `obu_has_size_field=1`
+    pub data: Bytes,
+    /// Whether the sample starts with a shown key frame
+    pub is_sync: bool,
+}
+
+/// Rewrites temporal units of low-overhead bitstreams (such as IVF frames)
+/// as ISOBMFF sample payloads.
+///
+/// Temporal delimiters, padding and redundant frame headers are dropped and
+/// every remaining OBU gets a size field. The latest sequence header is kept
+/// to detect sync samples and to build the `av1C` record.
+#[derive(Debug, Clone, Default)]
+pub struct IsobmffSampleConverter {
+    sequence_header: Option<(SequenceHeaderObu, Bytes)>,
+}
+
+impl IsobmffSampleConverter {
+    /// Creates a converter that has not seen a sequence header yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The latest sequence header seen.
+    pub fn sequence_header(&self) -> Option<&SequenceHeaderObu> {
+        self.sequence_header.as_ref().map(|(seq, _)| seq)
+    }
+
+    /// The `av1C` record of the latest sequence header seen.
+    pub fn config_record(&self) -> Option<AV1CodecConfigurationRecord> {
+        self.sequence_header
+            .as_ref()
+            .map(|(seq, obu)| AV1CodecConfigurationRecord::from_sequence_header(seq, obu.clone()))
+    }
+
+    /// Rewrites one temporal unit. The last OBU may omit its size field.
+    pub fn convert(&mut self, temporal_unit: &Bytes) -> Result<IsobmffSample> {
+        let mut cursor = io::Cursor::new(temporal_unit.clone());
+        let mut out = Vec::with_capacity(temporal_unit.len() + 8);
+        let mut is_sync = None;
+
+        for obu in ContainerObuIterator::new(&mut cursor) {
+            let Obu { header, data } = obu?;
+            match header.obu_type {
+                ObuType::TemporalDelimiter | ObuType::Padding | ObuType::RedundantFrameHeader => {
+                    continue;
+                }
+                ObuType::TileList => {
+                    return Err(Av1Error::InvalidObu(
+                        "OBU_TILE_LIST is not allowed in ISOBMFF samples".to_string(),
+                    ));
+                }
+                ObuType::SequenceHeader => {
+                    let seq = SequenceHeaderObu::parse(header, &mut io::Cursor::new(&data))?;
+                    let mut obu = Vec::with_capacity(data.len() + 3);
+                    write_obu(&mut obu, header.obu_type, header.extension_header, &data)?;
+                    self.sequence_header = Some((seq, Bytes::from(obu)));
+                }
+                ObuType::FrameHeader | ObuType::Frame if is_sync.is_none() => {
+                    is_sync = Some(self.sequence_header().is_some_and(|seq| {
+                        FrameHeaderObu::parse(header, seq, &mut io::Cursor::new(&data))
+                            .is_ok_and(|frame_header| frame_header.is_key_frame())
+                    }));
+                }
+                _ => {}
+            }
+            write_obu(&mut out, header.obu_type, header.extension_header, &data)?;
+        }
+
+        Ok(IsobmffSample {
+            data: Bytes::from(out),
+            is_sync: is_sync.unwrap_or(false),
+        })
+    }
+}
+
 fn validate_isobmff_obu_type(obu_type: ObuType, options: IsobmffSampleParseOptions) -> Result<()> {
     match obu_type {
         ObuType::TileList => Err(Av1Error::InvalidObu(
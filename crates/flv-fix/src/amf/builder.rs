@@ -1,8 +1,13 @@
 use crate::amf::model::{AmfScriptData, KeyframeData};
 use crate::analyzer::FlvStats;
+use crate::chapters::{CUE_POINTS_KEY, ChapterMarker};
 use amf0::{Amf0Encoder, Amf0Marker, Amf0Value, Amf0WriteError};
 use byteorder::{BigEndian, WriteBytesExt};
-use flv::{audio::SoundFormat, video::VideoCodecId};
+use flv::{
+    audio::SoundFormat,
+    hevc::HdrInfo,
+    video::{VideoCodecId, VideoFourCC},
+};
 use std::borrow::Cow;
 use tracing::debug;
 
@@ -35,6 +40,11 @@ const NATURAL_METADATA_KEY_ORDER: &[&str] = &[
     "keyframes",
 ];
 
+/// Whether `key` is an `onMetaData` property managed by the builder itself.
+pub(crate) fn is_managed_metadata_key(key: &str) -> bool {
+    key == FIXED_PADDING_KEY || NATURAL_METADATA_KEY_ORDER.contains(&key)
+}
+
 /// A fluent builder for creating `onMetaData` script data.
 const FIXED_PADDING_KEY: &str = "__srec_padding";
 const FIXED_PADDING_OVERHEAD: usize = 2 + FIXED_PADDING_KEY.len() + 1 + 4;
@@ -88,6 +98,7 @@ impl OnMetaDataBuilder {
             }
             self.data.framerate = Some(video_stats.video_frame_rate as f64);
             self.data.videocodecid = video_stats.video_codec;
+            self.data.video_fourcc = video_stats.video_fourcc;
             self.data.videosize = Some(video_stats.video_data_size);
             self.data.lastkeyframetimestamp = Some(video_stats.last_keyframe_timestamp);
             self.data.lastkeyframelocation = Some(video_stats.last_keyframe_position);
@@ -105,6 +116,75 @@ impl OnMetaDataBuilder {
         self
     }
 
+    /// Records timestamp gaps from an `FlvStats` object as `gapcount` and `gapduration`
+    /// (seconds) custom properties.
+    pub fn with_gap_stats(mut self, stats: &FlvStats) -> Self {
+        if stats.gap_count > 0 {
+            self.data.custom_properties.insert(
+                "gapcount".to_string(),
+                Amf0Value::Number(stats.gap_count as f64),
+            );
+            self.data.custom_properties.insert(
+                "gapduration".to_string(),
+                Amf0Value::Number(stats.gap_duration as f64 / 1000.0),
+            );
+        }
+        self
+    }
+
+    /// Records the colour description of HDR video as `hdr`, `colorprimaries`,
+    /// `transfercharacteristics` and `matrixcoefficients` custom properties, plus
+    /// `masterdisplay`, `maxcll` and `maxfall` when the stream carries them.
+    pub fn with_hdr_info(mut self, stats: &FlvStats) -> Self {
+        let Some(hdr) = stats
+            .video_stats
+            .as_ref()
+            .and_then(|video_stats| video_stats.hdr)
+            .filter(HdrInfo::is_hdr)
+        else {
+            return self;
+        };
+        let properties = &mut self.data.custom_properties;
+        properties.insert(
+            "hdr".to_string(),
+            Amf0Value::String(Cow::Borrowed(hdr.transfer_name())),
+        );
+        for (key, value) in [
+            ("colorprimaries", hdr.colour_primaries),
+            ("transfercharacteristics", hdr.transfer_characteristics),
+            ("matrixcoefficients", hdr.matrix_coeffs),
+        ] {
+            properties.insert(key.to_string(), Amf0Value::Number(f64::from(value)));
+        }
+        if let Some(display) = hdr.mastering_display {
+            properties.insert(
+                "masterdisplay".to_string(),
+                Amf0Value::String(Cow::Owned(display.to_string())),
+            );
+        }
+        if let Some(light_level) = hdr.content_light_level {
+            properties.insert(
+                "maxcll".to_string(),
+                Amf0Value::Number(f64::from(light_level.max_cll)),
+            );
+            properties.insert(
+                "maxfall".to_string(),
+                Amf0Value::Number(f64::from(light_level.max_fall)),
+            );
+        }
+        self
+    }
+
+    /// Records chapters as a `cuePoints` array of navigation cue points.
+    pub fn with_cue_points(mut self, chapters: &[ChapterMarker]) -> Self {
+        let cue_points = chapters.iter().map(ChapterMarker::to_cue_point).collect();
+        self.data.custom_properties.insert(
+            CUE_POINTS_KEY.to_string(),
+            Amf0Value::StrictArray(Cow::Owned(cue_points)),
+        );
+        self
+    }
+
     /// Sets the duration in seconds.
     pub fn with_duration(mut self, duration: f64) -> Self {
         self.data.duration = Some(duration);
@@ -135,6 +215,12 @@ impl OnMetaDataBuilder {
         self
     }
 
+    /// Sets the Enhanced RTMP video codec fourCC.
+    pub fn with_video_fourcc(mut self, fourcc: VideoFourCC) -> Self {
+        self.data.video_fourcc = Some(fourcc);
+        self
+    }
+
     /// Sets the audio codec ID.
     pub fn with_audio_codec(mut self, codec: SoundFormat) -> Self {
         self.data.audiocodecid = Some(codec);
@@ -448,6 +534,11 @@ impl OnMetaDataBuilder {
                 .data
                 .videocodecid
                 .map(|v| Amf0Value::Number(v as u8 as f64))
+                .or_else(|| {
+                    self.data
+                        .video_fourcc
+                        .map(|fourcc| Amf0Value::Number(fourcc.as_u32() as f64))
+                })
                 .or(Some(Amf0Value::Number(0.0))),
             "videodatarate" => self
                 .data
@@ -772,6 +863,24 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_on_meta_data_builder_writes_fourcc_videocodecid() {
+        let builder = OnMetaDataBuilder::new().with_video_fourcc(VideoFourCC::Av01);
+        let (bytes, _) = builder.build_bytes(0, false).unwrap();
+
+        let mut decoder = Amf0Decoder::new(&bytes);
+        let _name = decoder.decode().unwrap();
+        let Amf0Value::Object(props) = decoder.decode().unwrap() else {
+            panic!("Expected object for metadata");
+        };
+        let (_, codec) = props.iter().find(|(k, _)| k == "videocodecid").unwrap();
+        assert_eq!(codec, &Amf0Value::Number(0x61763031 as f64));
+
+        let parsed = AmfScriptData::from_amf_object_ref(&props).unwrap();
+        assert_eq!(parsed.videocodecid, None);
+        assert_eq!(parsed.video_fourcc, Some(VideoFourCC::Av01));
+    }
+
     #[test]
     fn test_on_meta_data_builder_placeholder_keyframes() {
         let builder = OnMetaDataBuilder::new()
@@ -1,5 +1,8 @@
 use amf0::{Amf0Value, Amf0WriteError};
-use flv::{audio::SoundFormat, video::VideoCodecId};
+use flv::{
+    audio::SoundFormat,
+    video::{VideoCodecId, VideoFourCC},
+};
 use std::collections::HashMap;
 use time::OffsetDateTime;
 
@@ -12,6 +15,9 @@ pub struct AmfScriptData {
     pub height: Option<f64>,
     pub framerate: Option<f64>,
     pub videocodecid: Option<VideoCodecId>,
+    /// Enhanced RTMP codec, written as the numeric fourCC in `videocodecid` when there is no
+    /// legacy codec id.
+    pub video_fourcc: Option<VideoFourCC>,
     pub videodatarate: Option<f64>,
 
     // Audio Properties
@@ -96,11 +102,12 @@ impl AmfScriptData {
                 "width" => data.width = value.as_number(),
                 "height" => data.height = value.as_number(),
                 "framerate" => data.framerate = value.as_number(),
-                "videocodecid" => {
-                    data.videocodecid = value
-                        .as_number()
-                        .and_then(|v| VideoCodecId::try_from(v as u8).ok())
-                }
+                "videocodecid" => match value.as_number() {
+                    Some(v) if v > u8::MAX as f64 => {
+                        data.video_fourcc = VideoFourCC::try_from(v as u32).ok()
+                    }
+                    v => data.videocodecid = v.and_then(|v| VideoCodecId::try_from(v as u8).ok()),
+                },
                 "videodatarate" => data.videodatarate = value.as_number(),
                 "audiocodecid" => {
                     data.audiocodecid = value
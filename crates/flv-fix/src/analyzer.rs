@@ -1,17 +1,24 @@
 use flv::{
     audio::{AudioTagUtils, SoundFormat, SoundRate, SoundSize, SoundType},
+    avc::{AvcSeiContext, SeiMessage},
     header::FlvHeader,
+    hevc::HdrInfo,
     resolution::Resolution,
-    tag::FlvTag,
-    video::VideoCodecId,
+    tag::{CodecKind, FlvTag},
+    video::{VideoCodecId, VideoFourCC},
 };
 
+use media_types::StreamDescription;
 use std::fmt;
 use tracing::{debug, trace};
 
-use crate::operators::MIN_INTERVAL_BETWEEN_KEYFRAMES_MS;
+use crate::operators::{DEFAULT_MIN_GAP_MS, MIN_INTERVAL_BETWEEN_KEYFRAMES_MS};
 use crate::utils::{FLV_HEADER_SIZE, FLV_PREVIOUS_TAG_SIZE};
 
+/// NAL unit length prefix size of HEVC frames. The FLV muxers in use
+/// (FFmpeg, OBS, SRS and the platforms' own) all write 4-byte lengths.
+const HEVC_NALU_LENGTH_SIZE: usize = 4;
+
 /// Error type for FLV analysis operations
 #[derive(Debug, thiserror::Error)]
 pub enum AnalyzerError {
@@ -39,6 +46,8 @@ pub struct Keyframe {
 #[derive(Debug, Clone, Default)]
 pub struct VideoStats {
     pub video_codec: Option<VideoCodecId>,
+    /// FourCC of Enhanced RTMP streams; the only codec identity for codecs without a legacy id (AV1).
+    pub video_fourcc: Option<VideoFourCC>,
     pub video_tag_count: u32,
     pub video_tags_size: u64,
     pub video_data_size: u64,
@@ -48,6 +57,12 @@ pub struct VideoStats {
     pub first_video_timestamp: Option<u32>,
     pub first_keyframe_timestamp: Option<u32>,
     pub resolution: Option<Resolution>,
+    /// Colour description and HDR metadata of HEVC streams
+    pub hdr: Option<HdrInfo>,
+    /// Encoder information from H.264 user data SEI (e.g. the x264 version and options)
+    pub encoder_info: Option<String>,
+    /// Codec parameters of the first parsable sequence header
+    pub description: Option<StreamDescription>,
     pub last_keyframe_timestamp: u32,
     pub last_keyframe_position: u64,
     pub keyframes: Vec<Keyframe>,
@@ -110,6 +125,14 @@ pub struct FlvStats {
     pub last_audio_timestamp: u32,
 
     pub first_audio_timestamp: Option<u32>,
+
+    /// Number of timestamp gaps of at least `DEFAULT_MIN_GAP_MS` between media tags
+    pub gap_count: u32,
+    /// Total duration of those gaps in milliseconds
+    pub gap_duration: u32,
+    /// Number of audio/video tags whose timestamp went backwards compared to the previous tag
+    /// of the same type
+    pub timestamp_regressions: u32,
 }
 
 impl Default for FlvStats {
@@ -134,6 +157,9 @@ impl Default for FlvStats {
             audio_sample_size: 0,
             first_audio_timestamp: None,
             audio_data_rate: 0.0,
+            gap_count: 0,
+            gap_duration: 0,
+            timestamp_regressions: 0,
         }
     }
 }
@@ -143,6 +169,35 @@ impl FlvStats {
         *self = Self::default();
     }
 
+    /// What the analyzed stream carries: the sequence header's codec
+    /// parameters completed with the measured frame rate and audio format.
+    pub fn stream_description(&self) -> StreamDescription {
+        let mut description = StreamDescription::default();
+        if let Some(video_stats) = &self.video_stats {
+            if let Some(header) = &video_stats.description {
+                description = header.clone();
+            }
+            description.merge(&StreamDescription {
+                video_codec: video_stats
+                    .video_fourcc
+                    .map(Into::into)
+                    .or_else(|| video_stats.video_codec.and_then(|id| id.video_codec())),
+                resolution: video_stats.resolution,
+                frame_rate: (video_stats.video_frame_rate > 0.0)
+                    .then_some(f64::from(video_stats.video_frame_rate)),
+                hdr: video_stats.hdr.is_some_and(|hdr| hdr.is_hdr()),
+                ..Default::default()
+            });
+        }
+        if self.has_audio {
+            description.audio_codec = self.audio_codec.and_then(|format| format.audio_codec());
+            description.audio_channels = Some(if self.audio_stereo { 2 } else { 1 });
+            description.audio_sample_rate =
+                (self.audio_sample_rate > 0.0).then_some(self.audio_sample_rate as u32);
+        }
+        description
+    }
+
     pub fn calculate_audio_bitrate(&self) -> f32 {
         self.calculate_rate(
             (self.audio_data_size * 8) as f32,
@@ -258,11 +313,14 @@ impl FlvStats {
         writeln!(f, "  Media:")?;
         writeln!(f, "    Has video: {}", self.has_video)?;
         if let Some(video_stats) = &self.video_stats {
-            writeln!(
-                f,
-                "    Video codec: {:?}",
-                video_stats.video_codec.unwrap_or(VideoCodecId::Avc)
-            )?;
+            match (video_stats.video_codec, video_stats.video_fourcc) {
+                (None, Some(fourcc)) => writeln!(f, "    Video codec: {fourcc}")?,
+                (codec, _) => writeln!(
+                    f,
+                    "    Video codec: {:?}",
+                    codec.unwrap_or(VideoCodecId::Avc)
+                )?,
+            }
             if let Some(resolution) = &video_stats.resolution {
                 writeln!(
                     f,
@@ -270,6 +328,34 @@ impl FlvStats {
                     resolution.width, resolution.height
                 )?;
             }
+            if let Some(hdr) = video_stats.hdr.filter(HdrInfo::is_hdr) {
+                writeln!(
+                    f,
+                    "    HDR: {} ({}-bit, primaries {}, matrix {})",
+                    hdr.transfer_name(),
+                    hdr.bit_depth,
+                    hdr.colour_primaries,
+                    hdr.matrix_coeffs
+                )?;
+                if let Some(display) = hdr.mastering_display {
+                    writeln!(
+                        f,
+                        "    Mastering display: {:.4}-{:.0} nits",
+                        display.min_luminance_nits(),
+                        display.max_luminance_nits()
+                    )?;
+                }
+                if let Some(light_level) = hdr.content_light_level {
+                    writeln!(
+                        f,
+                        "    MaxCLL/MaxFALL: {}/{} nits",
+                        light_level.max_cll, light_level.max_fall
+                    )?;
+                }
+            }
+            if let Some(encoder_info) = &video_stats.encoder_info {
+                writeln!(f, "    Encoder: {encoder_info}")?;
+            }
             writeln!(f, "    Frame rate: {:.2} fps", video_stats.video_frame_rate)?;
             writeln!(
                 f,
@@ -341,6 +427,20 @@ impl FlvStats {
                 video_stats.last_video_timestamp
             )?;
         }
+        if self.gap_count > 0 {
+            writeln!(
+                f,
+                "    Gaps: {} ({}ms total)",
+                self.gap_count, self.gap_duration
+            )?;
+        }
+        if self.timestamp_regressions > 0 {
+            writeln!(
+                f,
+                "    Timestamp regressions: {}",
+                self.timestamp_regressions
+            )?;
+        }
         Ok(())
     }
 
@@ -427,6 +527,9 @@ pub struct FlvAnalyzer {
     pub header_analyzed: bool,
     pub has_video_sequence_header: bool,
     pub has_audio_sequence_header: bool,
+
+    /// SEI context of the last AVC sequence header
+    avc_sei: Option<AvcSeiContext>,
 }
 
 impl FlvAnalyzer {
@@ -435,6 +538,7 @@ impl FlvAnalyzer {
         self.header_analyzed = false;
         self.has_video_sequence_header = false;
         self.has_audio_sequence_header = false;
+        self.avc_sei = None;
     }
 
     pub fn analyze_header(&mut self, header: &FlvHeader) -> Result<(), AnalyzerError> {
@@ -473,7 +577,7 @@ impl FlvAnalyzer {
                     audio_tag_utils.sound_type()
                 );
                 let sound_format = audio_tag_utils.sound_format().unwrap_or(SoundFormat::Aac);
-                let sample_rate = audio_tag_utils
+                let mut sample_rate = audio_tag_utils
                     .sound_rate()
                     .map(|s| match s {
                         SoundRate::Hz5512 => 5512.0,
@@ -494,7 +598,21 @@ impl FlvAnalyzer {
 
                 let sound_type = audio_tag_utils.sound_type().unwrap_or(SoundType::Stereo);
 
-                let stereo = sound_type == SoundType::Stereo;
+                let mut stereo = sound_type == SoundType::Stereo;
+
+                // The FLV header always claims 44.1 kHz stereo for AAC; the
+                // AudioSpecificConfig (or in-band ADTS/LATM config) is authoritative
+                if sound_format == SoundFormat::Aac
+                    && let Some(config) = tag
+                        .decode_audio()
+                        .ok()
+                        .and_then(|audio| audio.body.audio_config())
+                {
+                    sample_rate = config.sampling_frequency as f32;
+                    if let Some(channels) = config.channel_count() {
+                        stereo = channels >= 2;
+                    }
+                }
 
                 self.stats.audio_sample_rate = sample_rate;
                 self.stats.audio_sample_size = sample_size;
@@ -543,11 +661,23 @@ impl FlvAnalyzer {
                 }
             }
 
-            if video_stats.video_codec.is_none() {
+            if video_stats.hdr.is_none() {
+                video_stats.hdr = tag.get_hdr_info();
+            }
+
+            if video_stats.description.is_none() {
+                video_stats.description = tag.get_stream_description();
+            }
+
+            if let Some(context) = tag.get_avc_sei_context() {
+                self.avc_sei = Some(context);
+            }
+
+            if video_stats.video_codec.is_none() && video_stats.video_fourcc.is_none() {
                 // parse the codec id
-                if let Some(codec_id) = tag.get_video_codec_id() {
-                    video_stats.video_codec = Some(codec_id);
-                } else {
+                video_stats.video_codec = metadata_video_codec_id(tag);
+                video_stats.video_fourcc = tag.get_video_fourcc();
+                if video_stats.video_codec.is_none() && video_stats.video_fourcc.is_none() {
                     debug!(
                         ts_ms = tag.timestamp_ms,
                         len = tag.data().len(),
@@ -557,9 +687,34 @@ impl FlvAnalyzer {
             }
 
             self.has_video_sequence_header = true;
-        } else if tag.is_key_frame() {
+        } else if tag.is_key_frame_nalu() {
             let position = self.stats.file_size;
 
+            // HDR encoders repeat the mastering display and light level SEI in
+            // front of keyframes when the sequence header does not carry them
+            if let Some(hdr) = video_stats.hdr.as_mut()
+                && hdr.is_hdr()
+                && !hdr.has_sei_metadata()
+            {
+                tag.update_hdr_info(hdr, HEVC_NALU_LENGTH_SIZE);
+            }
+
+            // x264 and similar encoders identify themselves in a user data
+            // SEI, usually only in the first keyframe
+            if video_stats.encoder_info.is_none()
+                && let Some(context) = &self.avc_sei
+            {
+                video_stats.encoder_info =
+                    tag.avc_sei_messages(context)
+                        .iter()
+                        .find_map(|message| match message {
+                            SeiMessage::UserDataUnregistered(user_data) => {
+                                user_data.text().map(str::to_owned)
+                            }
+                            _ => None,
+                        });
+            }
+
             // Respect the minimum interval between keyframes
             let add_keyframe = video_stats.last_keyframe_timestamp == 0
                 || (timestamp.saturating_sub(video_stats.last_keyframe_timestamp)
@@ -600,6 +755,29 @@ impl FlvAnalyzer {
     }
 
     pub fn analyze_tag(&mut self, tag: &FlvTag) -> Result<(), AnalyzerError> {
+        if self.stats.tag_count > 0 && !tag.is_script_tag() {
+            let gap = tag.timestamp_ms.saturating_sub(self.stats.last_timestamp);
+            if gap >= DEFAULT_MIN_GAP_MS {
+                self.stats.gap_count += 1;
+                self.stats.gap_duration = self.stats.gap_duration.saturating_add(gap);
+            }
+        }
+
+        let previous_timestamp = if tag.is_audio_tag() && self.stats.audio_tag_count > 0 {
+            Some(self.stats.last_audio_timestamp)
+        } else if tag.is_video_tag() {
+            self.stats
+                .video_stats
+                .as_ref()
+                .filter(|video_stats| video_stats.video_tag_count > 0)
+                .map(|video_stats| video_stats.last_video_timestamp)
+        } else {
+            None
+        };
+        if previous_timestamp.is_some_and(|previous| tag.timestamp_ms < previous) {
+            self.stats.timestamp_regressions += 1;
+        }
+
         if tag.is_audio_tag() {
             self.analyze_audio_tag(tag);
         } else if tag.is_video_tag() {
@@ -644,10 +822,38 @@ impl FlvAnalyzer {
     }
 }
 
+/// Codec id advertised in `onMetaData` for the stream of `tag`.
+///
+/// Enhanced RTMP tags carry a fourCC instead of a legacy codec id. AVC and HEVC map onto the
+/// legacy ids (7 and 12) that players already read from `videocodecid`.
+fn metadata_video_codec_id(tag: &FlvTag) -> Option<VideoCodecId> {
+    tag.get_video_codec_id()
+        .or_else(|| match tag.get_video_codec()? {
+            CodecKind::Avc => Some(VideoCodecId::Avc),
+            CodecKind::Hevc => Some(VideoCodecId::LegacyHevc),
+            _ => None,
+        })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::amf::builder::OnMetaDataBuilder;
+    use crate::test_utils::{
+        create_audio_tag, create_enhanced_av1_sequence_header, create_enhanced_av1_video_tag,
+        create_enhanced_hevc_sequence_header, create_enhanced_hevc_video_tag,
+    };
+    use amf0::Amf0Value;
+    use flv::data::FlvData;
     use flv::header::FlvHeader;
+    use media_types::VideoCodec;
+
+    fn tag(data: FlvData) -> FlvTag {
+        match data {
+            FlvData::Tag(tag) => tag,
+            other => panic!("expected tag, got {other:?}"),
+        }
+    }
 
     #[test]
     fn test_analyze_header() {
@@ -656,4 +862,90 @@ mod tests {
         assert!(analyzer.analyze_header(&header).is_ok());
         assert_eq!(analyzer.stats.file_size, 13); // 9 bytes for header + 4 bytes for previous tag size
     }
+
+    #[test]
+    fn test_records_timestamp_gaps() {
+        let mut analyzer = FlvAnalyzer::default();
+        analyzer
+            .analyze_header(&FlvHeader::new(true, false))
+            .unwrap();
+        for timestamp in [0, 23, 46, 1546, 1569, 4000] {
+            analyzer
+                .analyze_tag(&tag(create_audio_tag(timestamp)))
+                .unwrap();
+        }
+
+        let stats = analyzer.build_stats().unwrap();
+        assert_eq!(stats.gap_count, 2);
+        assert_eq!(stats.gap_duration, 1500 + 2431);
+
+        let model = OnMetaDataBuilder::new().with_gap_stats(stats).build_model();
+        assert_eq!(
+            model.custom_properties.get("gapcount"),
+            Some(&Amf0Value::Number(2.0))
+        );
+    }
+
+    #[test]
+    fn test_analyze_enhanced_hevc_stream() {
+        let mut analyzer = FlvAnalyzer::default();
+        analyzer
+            .analyze_header(&FlvHeader::new(false, true))
+            .unwrap();
+
+        analyzer
+            .analyze_tag(&tag(create_enhanced_hevc_sequence_header(0, 1)))
+            .unwrap();
+        analyzer
+            .analyze_tag(&tag(create_enhanced_hevc_video_tag(0, true)))
+            .unwrap();
+        analyzer
+            .analyze_tag(&tag(create_enhanced_hevc_video_tag(40, false)))
+            .unwrap();
+        analyzer
+            .analyze_tag(&tag(create_enhanced_hevc_video_tag(2000, true)))
+            .unwrap();
+
+        assert!(analyzer.has_video_sequence_header);
+        let stats = analyzer.build_stats().unwrap();
+        let video_stats = stats.video_stats.as_ref().unwrap();
+        assert_eq!(video_stats.video_codec, Some(VideoCodecId::LegacyHevc));
+        let keyframe_times: Vec<_> = video_stats
+            .keyframes
+            .iter()
+            .map(|k| k.timestamp_s)
+            .collect();
+        assert_eq!(keyframe_times, vec![0.0, 2.0]);
+    }
+
+    #[test]
+    fn test_analyze_enhanced_av1_stream() {
+        let mut analyzer = FlvAnalyzer::default();
+        analyzer
+            .analyze_header(&FlvHeader::new(false, true))
+            .unwrap();
+
+        analyzer
+            .analyze_tag(&tag(create_enhanced_av1_sequence_header(0, 13)))
+            .unwrap();
+        analyzer
+            .analyze_tag(&tag(create_enhanced_av1_video_tag(0, true)))
+            .unwrap();
+        analyzer
+            .analyze_tag(&tag(create_enhanced_av1_video_tag(40, false)))
+            .unwrap();
+
+        let stats = analyzer.build_stats().unwrap();
+        let video_stats = stats.video_stats.as_ref().unwrap();
+        assert_eq!(video_stats.video_codec, None);
+        assert_eq!(video_stats.video_fourcc, Some(VideoFourCC::Av01));
+        assert!(video_stats.resolution.is_some());
+        assert_eq!(video_stats.keyframes.len(), 1);
+
+        let description = stats.stream_description();
+        assert_eq!(description.video_codec, Some(VideoCodec::Av1));
+        assert_eq!(description.level, Some(13));
+        assert_eq!(description.resolution, video_stats.resolution);
+        assert!(!description.has_audio());
+    }
 }
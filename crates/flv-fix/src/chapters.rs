@@ -0,0 +1,76 @@
+//! # Chapter markers
+//!
+//! Events from outside the pipeline (a manual marker from the API, an ad cue from the source,
+//! a burst of danmu) recorded as chapters of the file being written.
+//!
+//! Clones of [`ChapterMarkers`] share the same queue: keep one to call
+//! [`mark`](ChapterMarkers::mark) and hand another to the writer. Each marker is placed at the
+//! media time of the next tag written, stored as a Flash-style navigation cue point in the
+//! `cuePoints` array of `onMetaData`, and listed in the segment's analysis report.
+//!
+//! ## License
+//!
+//! MIT License
+//!
+//! ## Authors
+//!
+//! - hua0512
+//!
+
+use amf0::Amf0Value;
+use serde::Serialize;
+use std::borrow::Cow;
+use std::sync::{Arc, Mutex, PoisonError};
+
+/// `onMetaData` property holding the chapters of a file.
+pub const CUE_POINTS_KEY: &str = "cuePoints";
+
+/// A chapter of a written file.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ChapterMarker {
+    /// Media time of the chapter start in seconds
+    pub time_s: f64,
+    pub title: String,
+}
+
+impl ChapterMarker {
+    /// The marker as a navigation cue point (`{ name, time, type }`).
+    pub fn to_cue_point(&self) -> Amf0Value<'static> {
+        Amf0Value::Object(Cow::Owned(vec![
+            (
+                Cow::Borrowed("name"),
+                Amf0Value::String(Cow::Owned(self.title.clone())),
+            ),
+            (Cow::Borrowed("time"), Amf0Value::Number(self.time_s)),
+            (
+                Cow::Borrowed("type"),
+                Amf0Value::String(Cow::Borrowed("navigation")),
+            ),
+        ]))
+    }
+}
+
+/// Queue of chapter markers waiting to be placed in the output.
+#[derive(Debug, Clone, Default)]
+pub struct ChapterMarkers {
+    pending: Arc<Mutex<Vec<String>>>,
+}
+
+impl ChapterMarkers {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start a chapter titled `title` at the next tag written.
+    pub fn mark(&self, title: impl Into<String>) {
+        self.pending
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(title.into());
+    }
+
+    /// Take the titles of all markers not yet placed.
+    pub(crate) fn take_pending(&self) -> Vec<String> {
+        std::mem::take(&mut *self.pending.lock().unwrap_or_else(PoisonError::into_inner))
+    }
+}
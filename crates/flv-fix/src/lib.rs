@@ -16,19 +16,23 @@
 //! ## Component Overview
 //!
 //! - `analyzer`: Tools for analyzing FLV stream structure and content
+//! - `chapters`: Chapter markers injected from outside the pipeline
 //! - `constants`: String constants to avoid repeated allocations
 //! - `operators`: Modular pipeline operators for stream transformations
 //! - `pipeline`: Stream processing pipeline implementation
+//! - `report`: Structured, serializable analysis reports of written segments
 //! - `script_modifier`: Utilities for manipulating FLV script tags
 //! - `utils`: Helper functions and utilities
 //! - `writer`: Asynchronous FLV writing functionality
 
 pub mod amf;
 mod analyzer;
+mod chapters;
 mod constants;
 mod crc32;
 mod operators;
 mod pipeline;
+pub mod report;
 mod script_modifier;
 mod utils;
 pub mod writer;
@@ -38,9 +42,11 @@ mod writer_task;
 pub mod test_utils;
 
 pub use analyzer::{AnalyzerError, FlvAnalyzer};
+pub use chapters::{CUE_POINTS_KEY, ChapterMarker, ChapterMarkers};
 pub use constants::*;
 pub use operators::*;
 pub use pipeline::*;
+pub use report::{AnalysisReport, RepairCounters};
 pub use script_modifier::*;
 pub use utils::*;
 
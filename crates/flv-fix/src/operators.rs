@@ -4,21 +4,31 @@
 //! These operators can be combined into a pipeline to perform various transformations and
 //! validations on FLV data.
 
+mod audio_only;
+mod corrupt_tag;
 mod defragment;
 mod duplicate_filter;
+mod gap_fill;
+mod gop_integrity;
 mod gop_sort;
 mod header_check;
 mod limit;
 mod script_filler;
 mod script_filter;
 mod split;
+mod split_predicate;
 mod time_consistency;
 mod timing_repair;
+mod track_select;
 
 // Re-export common operators
+pub use audio_only::AudioOnlyOperator;
+pub use corrupt_tag::{CorruptTagConfig, CorruptTagOperator};
 pub use defragment::DefragmentOperator;
-pub use duplicate_filter::DuplicateTagFilterConfig;
 pub use duplicate_filter::DuplicateTagFilterOperator;
+pub use duplicate_filter::{DuplicateHashAlgorithm, DuplicateMatchMode, DuplicateTagFilterConfig};
+pub use gap_fill::{DEFAULT_MIN_GAP_MS, GapFillConfig, GapFillMode, GapFillOperator};
+pub use gop_integrity::{GopIntegrityAction, GopIntegrityConfig, GopIntegrityOperator};
 pub use gop_sort::GopSortOperator;
 pub use header_check::HeaderCheckOperator;
 pub use limit::LimitConfig;
@@ -28,5 +38,12 @@ pub use script_filler::{ScriptFillerConfig, ScriptKeyframesFillerOperator};
 pub use script_filter::ScriptFilterOperator;
 pub use split::SequenceHeaderChangeMode;
 pub use split::SplitOperator;
+pub use split_predicate::{
+    ResolutionChangePredicate, SplitPredicate, SplitPredicateFactory, SplitTrigger,
+};
 pub use time_consistency::{ContinuityMode, TimeConsistencyOperator};
-pub use timing_repair::{RepairStrategy, TimingRepairConfig, TimingRepairOperator};
+pub use timing_repair::{
+    RelaxedRepair, RepairStrategy, StrictRepair, TimingCheck, TimingRepairConfig,
+    TimingRepairOperator,
+};
+pub use track_select::{TrackSelectOperator, TrackSelection};
@@ -0,0 +1,248 @@
+//! # AudioOnlyOperator
+//!
+//! The `AudioOnlyOperator` strips video from an FLV stream, producing an audio-only file.
+//!
+//! ## Purpose
+//!
+//! Some streams are only worth keeping for their audio (radio-style or podcast streams).
+//! Dropping video early saves disk space and avoids all the video-specific repair work
+//! further down the pipeline.
+//!
+//! ## Operation
+//!
+//! 1. FLV headers are rewritten with the video flag cleared
+//! 2. Video tags are dropped
+//! 3. `onMetaData` script tags lose their video properties (resolution, frame rate, video
+//!    codec, keyframe index, ...) and get `hasVideo` set to `false`
+//!
+//! Script tags that cannot be parsed are forwarded unchanged.
+//!
+//! ## License
+//!
+//! MIT License
+//!
+//! ## Authors
+//!
+//! - hua0512
+//!
+
+use amf0::{Amf0Encoder, Amf0Value};
+use bytes::Bytes;
+use flv::data::FlvData;
+use flv::script::ScriptData;
+use flv::tag::{FlvTag, FlvTagType};
+use pipeline_common::{PipelineError, Processor, StreamerContext};
+use std::borrow::Cow;
+use std::sync::Arc;
+use tracing::{debug, info, warn};
+
+/// `onMetaData` properties that only describe the video track.
+const VIDEO_METADATA_KEYS: &[&str] = &[
+    "width",
+    "height",
+    "displayWidth",
+    "displayHeight",
+    "framerate",
+    "fps",
+    "videocodecid",
+    "videodatarate",
+    "videosize",
+    "videokeyframe_frequency",
+    "lastkeyframetimestamp",
+    "lastkeyframelocation",
+    "hasKeyframes",
+    "keyframes",
+];
+
+/// Operator that drops video and rewrites headers and metadata for audio-only output
+pub struct AudioOnlyOperator {
+    context: Arc<StreamerContext>,
+    dropped_video_tags: u64,
+}
+
+impl AudioOnlyOperator {
+    /// Create a new AudioOnlyOperator
+    pub fn new(context: Arc<StreamerContext>) -> Self {
+        Self {
+            context,
+            dropped_video_tags: 0,
+        }
+    }
+
+    /// Remove video properties from an `onMetaData` tag.
+    ///
+    /// Returns `None` if the tag is not a parseable `onMetaData` tag.
+    fn strip_video_metadata(&self, tag: &FlvTag) -> Option<FlvTag> {
+        let mut cursor = std::io::Cursor::new(tag.data().clone());
+        let script = match ScriptData::demux(&mut cursor) {
+            Ok(script) => script,
+            Err(e) => {
+                warn!(
+                    "{} Failed to parse script tag at {}ms, forwarding unchanged: {}",
+                    self.context.name, tag.timestamp_ms, e
+                );
+                return None;
+            }
+        };
+        if script.name != crate::AMF0_ON_METADATA {
+            return None;
+        }
+
+        let first = script.data.first()?;
+        let props = first.as_object_properties()?;
+        let mut stripped: Vec<(Cow<'static, str>, Amf0Value<'static>)> = props
+            .iter()
+            .filter(|(key, _)| !VIDEO_METADATA_KEYS.contains(&key.as_ref()))
+            .map(|(key, value)| {
+                let value = if key == "hasVideo" {
+                    Amf0Value::Boolean(false)
+                } else {
+                    value.clone()
+                };
+                (key.clone(), value)
+            })
+            .collect();
+        if !stripped.iter().any(|(key, _)| key == "hasVideo") {
+            stripped.push((Cow::Borrowed("hasVideo"), Amf0Value::Boolean(false)));
+        }
+
+        let stripped = match first {
+            Amf0Value::EcmaArray(_) => Amf0Value::EcmaArray(Cow::Owned(stripped)),
+            _ => Amf0Value::Object(Cow::Owned(stripped)),
+        };
+
+        let mut buffer = Vec::with_capacity(tag.data().len());
+        let encoded = Amf0Encoder::encode_string(&mut buffer, &script.name)
+            .and_then(|_| Amf0Encoder::encode(&mut buffer, &stripped))
+            .and_then(|_| {
+                script.data[1..]
+                    .iter()
+                    .try_for_each(|value| Amf0Encoder::encode(&mut buffer, value))
+            });
+        if let Err(e) = encoded {
+            warn!(
+                "{} Failed to encode audio-only metadata, forwarding unchanged: {}",
+                self.context.name, e
+            );
+            return None;
+        }
+
+        debug!(
+            "{} Stripped video properties from onMetaData ({} -> {} bytes)",
+            self.context.name,
+            tag.data().len(),
+            buffer.len()
+        );
+
+        Some(FlvTag::new(
+            tag.timestamp_ms,
+            tag.stream_id,
+            tag.tag_type(),
+            tag.is_filtered(),
+            Bytes::from(buffer),
+        ))
+    }
+}
+
+impl Processor<FlvData> for AudioOnlyOperator {
+    fn process(
+        &mut self,
+        context: &Arc<StreamerContext>,
+        input: FlvData,
+        output: &mut dyn FnMut(FlvData) -> Result<(), PipelineError>,
+    ) -> Result<(), PipelineError> {
+        if context.token.is_cancelled() {
+            return Err(PipelineError::Cancelled);
+        }
+        match input {
+            FlvData::Header(mut header) => {
+                header.has_video = false;
+                output(FlvData::Header(header))
+            }
+            FlvData::Tag(tag) if tag.is_video_tag() => {
+                self.dropped_video_tags += 1;
+                Ok(())
+            }
+            FlvData::Tag(tag) if tag.tag_type() == FlvTagType::ScriptData => {
+                match self.strip_video_metadata(&tag) {
+                    Some(stripped) => output(FlvData::Tag(stripped)),
+                    None => output(FlvData::Tag(tag)),
+                }
+            }
+            _ => output(input),
+        }
+    }
+
+    fn finish(
+        &mut self,
+        _context: &Arc<StreamerContext>,
+        _output: &mut dyn FnMut(FlvData) -> Result<(), PipelineError>,
+    ) -> Result<(), PipelineError> {
+        info!(
+            "{} Audio-only mode dropped {} video tags",
+            self.context.name, self.dropped_video_tags
+        );
+        Ok(())
+    }
+
+    fn name(&self) -> &'static str {
+        "AudioOnlyOperator"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{
+        create_audio_tag, create_script_tag, create_test_header, create_video_tag,
+    };
+    use pipeline_common::CancellationToken;
+
+    #[test]
+    fn drops_video_and_rewrites_header_and_metadata() {
+        let context = StreamerContext::arc_new(CancellationToken::new());
+        let mut operator = AudioOnlyOperator::new(context.clone());
+        let mut output_items = Vec::new();
+        let mut output_fn = |item: FlvData| -> Result<(), PipelineError> {
+            output_items.push(item);
+            Ok(())
+        };
+
+        for item in [
+            create_test_header(),
+            create_script_tag(0, true),
+            create_video_tag(0, true),
+            create_audio_tag(0),
+            create_video_tag(40, false),
+            create_audio_tag(23),
+        ] {
+            operator.process(&context, item, &mut output_fn).unwrap();
+        }
+        operator.finish(&context, &mut output_fn).unwrap();
+
+        assert_eq!(output_items.len(), 4);
+        match &output_items[0] {
+            FlvData::Header(header) => {
+                assert!(header.has_audio);
+                assert!(!header.has_video);
+            }
+            other => panic!("expected header, got {other:?}"),
+        }
+
+        let FlvData::Tag(script) = &output_items[1] else {
+            panic!("expected script tag");
+        };
+        let mut cursor = std::io::Cursor::new(script.data().clone());
+        let script = ScriptData::demux(&mut cursor).unwrap();
+        let props = script.data[0].as_object_properties().unwrap();
+        let keys: Vec<&str> = props.iter().map(|(key, _)| key.as_ref()).collect();
+        assert_eq!(keys, vec!["duration", "audiocodecid", "hasVideo"]);
+        assert_eq!(props[2].1, Amf0Value::Boolean(false));
+
+        assert!(
+            output_items[2..]
+                .iter()
+                .all(|item| matches!(item, FlvData::Tag(tag) if tag.is_audio_tag()))
+        );
+    }
+}
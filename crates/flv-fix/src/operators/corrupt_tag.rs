@@ -0,0 +1,306 @@
+//! # CorruptTagOperator
+//!
+//! The `CorruptTagOperator` drops tags whose content cannot be real.
+//!
+//! ## Purpose
+//!
+//! Bit errors and truncated CDN responses produce tags with plausible headers but garbage
+//! inside. The decoder resynchronizes at the next plausible tag boundary when the framing is
+//! broken (see `FlvDecoder::skipped_bytes`), but a corrupt tag that still frames correctly
+//! reaches the pipeline, where a random codec id or a timestamp hours away derails timing
+//! repair, splitting and the written metadata.
+//!
+//! ## Operation
+//!
+//! Audio and video tags are dropped when:
+//!
+//! 1. The payload is empty
+//! 2. The video frame type or codec id / enhanced packet type is unknown
+//! 3. The audio sound format is reserved
+//! 4. The timestamp is more than `max_timestamp_jump_ms` away from the previous media tag
+//!
+//! A timestamp jump is only treated as corruption while it stays isolated: once
+//! `timeline_confirm_tags` consecutive tags agree on the new timeline, it is accepted and left
+//! to the timing operators. Encrypted tags skip the payload checks. Dropped tags and their
+//! bytes are recorded in the pipeline's [`RepairCounters`](crate::RepairCounters).
+//!
+//! ## License
+//!
+//! MIT License
+//!
+//! ## Authors
+//!
+//! - hua0512
+//!
+
+use flv::audio::SoundFormat;
+use flv::data::FlvData;
+use flv::tag::FlvTag;
+use pipeline_common::{PipelineError, Processor, StreamerContext};
+use std::sync::Arc;
+use tracing::{debug, info, warn};
+
+use crate::report::RepairCounters;
+
+/// Largest enhanced video packet type (`ModEx`).
+const MAX_ENHANCED_PACKET_TYPE: u8 = 7;
+
+/// Configuration for the CorruptTagOperator
+#[derive(Debug, Clone)]
+pub struct CorruptTagConfig {
+    /// Largest timestamp distance between consecutive media tags that is plausible (ms)
+    pub max_timestamp_jump_ms: u32,
+
+    /// Consecutive tags on a new timeline needed to accept a timestamp jump
+    pub timeline_confirm_tags: u32,
+}
+
+impl Default for CorruptTagConfig {
+    fn default() -> Self {
+        Self {
+            max_timestamp_jump_ms: 3_600_000,
+            timeline_confirm_tags: 3,
+        }
+    }
+}
+
+/// Why a tag was considered corrupt
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Corruption {
+    EmptyPayload,
+    UnknownVideoFormat,
+    ReservedSoundFormat,
+    TimestampJump,
+}
+
+/// Operator that drops corrupt audio and video tags
+pub struct CorruptTagOperator {
+    context: Arc<StreamerContext>,
+    config: CorruptTagConfig,
+    repair_counters: RepairCounters,
+    /// Timestamp of the last media tag accepted
+    last_timestamp: Option<u32>,
+    /// Timestamp of the last rejected jump and how many tags agreed with it
+    jump_candidate: Option<(u32, u32)>,
+    corrupted_tags: u64,
+}
+
+impl CorruptTagOperator {
+    /// Create a new CorruptTagOperator
+    pub fn new(context: Arc<StreamerContext>, config: CorruptTagConfig) -> Self {
+        Self {
+            context,
+            config,
+            repair_counters: RepairCounters::default(),
+            last_timestamp: None,
+            jump_candidate: None,
+            corrupted_tags: 0,
+        }
+    }
+
+    /// Records dropped tags into `repair_counters`.
+    pub fn with_repair_counters(mut self, repair_counters: RepairCounters) -> Self {
+        self.repair_counters = repair_counters;
+        self
+    }
+
+    fn reset(&mut self) {
+        self.last_timestamp = None;
+        self.jump_candidate = None;
+    }
+
+    fn check_payload(tag: &FlvTag) -> Option<Corruption> {
+        let Some(&first_byte) = tag.data().first() else {
+            return Some(Corruption::EmptyPayload);
+        };
+        if tag.is_filtered() {
+            return None;
+        }
+
+        if tag.is_video_tag() {
+            let class = tag.classification();
+            let frame_type = (first_byte >> 4) & 0x07;
+            let known_format = if class.enhanced {
+                first_byte & 0x0F <= MAX_ENHANCED_PACKET_TYPE
+            } else {
+                class.codec.is_some()
+            };
+            return (!(1..=5).contains(&frame_type) || !known_format)
+                .then_some(Corruption::UnknownVideoFormat);
+        }
+
+        SoundFormat::try_from(first_byte >> 4)
+            .is_err()
+            .then_some(Corruption::ReservedSoundFormat)
+    }
+
+    fn check_timestamp(&mut self, timestamp: u32) -> Option<Corruption> {
+        let max_jump = self.config.max_timestamp_jump_ms;
+        let Some(last) = self.last_timestamp else {
+            self.last_timestamp = Some(timestamp);
+            return None;
+        };
+        if timestamp.abs_diff(last) <= max_jump {
+            self.jump_candidate = None;
+            self.last_timestamp = Some(timestamp);
+            return None;
+        }
+
+        let agreeing = match self.jump_candidate {
+            Some((candidate, count)) if timestamp.abs_diff(candidate) <= max_jump => count + 1,
+            _ => 1,
+        };
+        if agreeing >= self.config.timeline_confirm_tags {
+            info!(
+                "{} Accepting timestamp jump from {}ms to {}ms",
+                self.context.name, last, timestamp
+            );
+            self.jump_candidate = None;
+            self.last_timestamp = Some(timestamp);
+            return None;
+        }
+        self.jump_candidate = Some((timestamp, agreeing));
+        Some(Corruption::TimestampJump)
+    }
+
+    fn check(&mut self, tag: &FlvTag) -> Option<Corruption> {
+        // Corrupt payloads must not move the timeline
+        Self::check_payload(tag).or_else(|| self.check_timestamp(tag.timestamp_ms))
+    }
+}
+
+impl Processor<FlvData> for CorruptTagOperator {
+    fn process(
+        &mut self,
+        context: &Arc<StreamerContext>,
+        input: FlvData,
+        output: &mut dyn FnMut(FlvData) -> Result<(), PipelineError>,
+    ) -> Result<(), PipelineError> {
+        if context.token.is_cancelled() {
+            return Err(PipelineError::Cancelled);
+        }
+        match input {
+            FlvData::Header(_) => {
+                self.reset();
+                output(input)
+            }
+            FlvData::Tag(tag) if tag.is_audio_tag() || tag.is_video_tag() => {
+                let Some(corruption) = self.check(&tag) else {
+                    return output(FlvData::Tag(tag));
+                };
+                debug!(
+                    "{} Dropping corrupt {:?} tag at {}ms ({} bytes): {:?}",
+                    self.context.name,
+                    tag.tag_type(),
+                    tag.timestamp_ms,
+                    tag.size(),
+                    corruption
+                );
+                self.corrupted_tags += 1;
+                self.repair_counters.record_dropped(1);
+                self.repair_counters.record_corrupted_tag(&tag);
+                Ok(())
+            }
+            _ => output(input),
+        }
+    }
+
+    fn finish(
+        &mut self,
+        _context: &Arc<StreamerContext>,
+        _output: &mut dyn FnMut(FlvData) -> Result<(), PipelineError>,
+    ) -> Result<(), PipelineError> {
+        if self.corrupted_tags > 0 {
+            warn!(
+                "{} Dropped {} corrupt tags",
+                self.context.name, self.corrupted_tags
+            );
+        }
+        Ok(())
+    }
+
+    fn name(&self) -> &'static str {
+        "CorruptTagOperator"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{
+        create_audio_tag, create_test_header, create_test_tag, create_video_tag,
+    };
+    use flv::tag::FlvTagType;
+    use pipeline_common::CancellationToken;
+
+    fn run(input: Vec<FlvData>) -> (Vec<u32>, RepairCounters) {
+        let context = StreamerContext::arc_new(CancellationToken::new());
+        let counters = RepairCounters::new();
+        let mut operator = CorruptTagOperator::new(context.clone(), CorruptTagConfig::default())
+            .with_repair_counters(counters.clone());
+        let mut output_items = Vec::new();
+        let mut output_fn = |item: FlvData| -> Result<(), PipelineError> {
+            output_items.push(item);
+            Ok(())
+        };
+
+        operator
+            .process(&context, create_test_header(), &mut output_fn)
+            .unwrap();
+        for item in input {
+            operator.process(&context, item, &mut output_fn).unwrap();
+        }
+        operator.finish(&context, &mut output_fn).unwrap();
+
+        let timestamps = output_items
+            .into_iter()
+            .filter_map(|item| match item {
+                FlvData::Tag(tag) => Some(tag.timestamp_ms),
+                _ => None,
+            })
+            .collect();
+        (timestamps, counters)
+    }
+
+    #[test]
+    fn drops_tags_with_impossible_payloads() {
+        let (timestamps, counters) = run(vec![
+            create_video_tag(0, true),
+            // Empty payload
+            create_test_tag(FlvTagType::Video, 33, vec![]),
+            // Frame type 0, codec id 15
+            create_test_tag(FlvTagType::Video, 66, vec![0x0F, 0x01, 0x00]),
+            // Reserved sound format 13
+            create_test_tag(FlvTagType::Audio, 70, vec![0xDF, 0x01]),
+            create_audio_tag(80),
+            create_video_tag(100, false),
+        ]);
+
+        assert_eq!(timestamps, vec![0, 80, 100]);
+        let repairs = counters.snapshot();
+        assert_eq!(repairs.corrupted_tags, 3);
+        assert_eq!(repairs.corrupted_bytes, 11 + 14 + 13);
+        assert_eq!(repairs.dropped_tags, 3);
+    }
+
+    #[test]
+    fn drops_isolated_timestamp_jumps_but_accepts_new_timelines() {
+        let (timestamps, counters) = run(vec![
+            create_video_tag(1_000, true),
+            create_video_tag(0x7F00_0000, false),
+            create_audio_tag(1_020),
+            create_video_tag(1_033, false),
+            // The source restarted on a far-away timeline
+            create_video_tag(50_000_000, true),
+            create_audio_tag(50_000_010),
+            create_video_tag(50_000_033, false),
+            create_video_tag(50_000_066, false),
+        ]);
+
+        assert_eq!(
+            timestamps,
+            vec![1_000, 1_020, 1_033, 50_000_033, 50_000_066]
+        );
+        assert_eq!(counters.snapshot().corrupted_tags, 3);
+    }
+}
@@ -38,6 +38,8 @@ use pipeline_common::{PipelineError, Processor, StreamerContext};
 use std::sync::Arc;
 use tracing::{debug, warn};
 
+use crate::report::RepairCounters;
+
 /// An operator that buffers and validates FLV stream fragments to ensure continuity and validity.
 ///
 /// The DefragmentOperator helps manage fragmented streams by:
@@ -49,6 +51,7 @@ pub struct DefragmentOperator {
     context: Arc<StreamerContext>,
     is_gathering: bool,
     buffer: Vec<FlvData>,
+    repair_counters: RepairCounters,
 }
 
 impl DefragmentOperator {
@@ -62,12 +65,29 @@ impl DefragmentOperator {
             context,
             is_gathering: false,
             buffer: Vec::with_capacity(Self::MIN_TAGS_NUM),
+            repair_counters: RepairCounters::default(),
         }
     }
 
+    /// Records discarded tags into `repair_counters`.
+    pub fn with_repair_counters(mut self, repair_counters: RepairCounters) -> Self {
+        self.repair_counters = repair_counters;
+        self
+    }
+
     // The minimum number of tags required to consider a segment valid.
     const MIN_TAGS_NUM: usize = 10;
 
+    // Counts the buffered tags as dropped before they are discarded.
+    fn record_discarded(&self) {
+        let tags = self
+            .buffer
+            .iter()
+            .filter(|item| matches!(item, FlvData::Tag(_)))
+            .count();
+        self.repair_counters.record_dropped(tags as u64);
+    }
+
     // Resets the operator state, clearing the buffer and stopping gathering mode.
     fn reset(&mut self) {
         self.is_gathering = false;
@@ -83,6 +103,7 @@ impl DefragmentOperator {
                 self.buffer.len(),
                 self.buffer.iter().map(|d| d.size()).sum::<usize>()
             );
+            self.record_discarded();
             self.reset();
         }
         self.is_gathering = true;
@@ -164,6 +185,7 @@ impl Processor<FlvData> for DefragmentOperator {
                     self.context.name,
                     self.buffer.len()
                 );
+                self.record_discarded();
                 self.reset();
             }
         }
@@ -9,8 +9,12 @@
 //! This operator performs a conservative deduplication:
 //! - Only applies to audio/video *media* tags (script tags and sequence headers
 //!   are passed through).
-//! - Considers a tag duplicate if `(tag_type, timestamp_ms, crc32(data), len)`
-//!   matches one seen recently.
+//! - Considers a tag duplicate if `(tag_type, timestamp_ms, hash(data), len)`
+//!   matches one seen recently ([`DuplicateMatchMode::Payload`]), or only
+//!   `(tag_type, timestamp_ms, len)` ([`DuplicateMatchMode::TimestampAndSize`]).
+//! - "Recently" is a rolling window bounded by a tag count and, optionally, by
+//!   media time, so CDNs that resend several seconds after a reconnect can be
+//!   covered without keeping an unbounded history.
 //! - Additionally, if a large timestamp back-jump is detected, it will try to
 //!   detect "replay loops" where the same content is re-sent with a constant
 //!   timestamp offset and drop those tags as well.
@@ -22,10 +26,12 @@ use flv::data::FlvData;
 use flv::tag::FlvTag;
 use pipeline_common::{PipelineError, Processor, StreamerContext};
 use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::Hasher;
 use std::sync::Arc;
 use tracing::{debug, trace};
 
 use crate::crc32;
+use crate::report::RepairCounters;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 struct TagKey(u64);
@@ -37,7 +43,14 @@ struct FingerprintKey(u64);
 struct PayloadIdentity {
     tag_type: u8,
     len: u64,
-    crc: u64,
+    hash: u64,
+}
+
+/// How a duplicate was recognized.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum DuplicateMatch {
+    Exact,
+    Replayed,
 }
 
 #[inline]
@@ -54,7 +67,7 @@ impl TagKey {
         let x = ((identity.tag_type as u64) << 56)
             ^ identity.len.rotate_left(17)
             ^ timestamp_ms as u64
-            ^ identity.crc.rotate_left(1);
+            ^ identity.hash.rotate_left(1);
         TagKey(mix64(x))
     }
 }
@@ -63,17 +76,29 @@ impl FingerprintKey {
     fn from_identity(identity: PayloadIdentity) -> Self {
         let x = ((identity.tag_type as u64) << 56)
             ^ identity.len.rotate_left(17)
-            ^ identity.crc.rotate_left(1);
+            ^ identity.hash.rotate_left(1);
         FingerprintKey(mix64(x))
     }
 }
 
 impl PayloadIdentity {
-    fn new(tag: &FlvTag) -> Self {
+    fn new(tag: &FlvTag, config: &DuplicateTagFilterConfig) -> Self {
+        let data = tag.data().as_ref();
+        let hash = match (config.match_mode, config.hash_algorithm) {
+            (DuplicateMatchMode::TimestampAndSize, _) => 0,
+            (DuplicateMatchMode::Payload, DuplicateHashAlgorithm::Crc32) => {
+                crc32::crc32(data) as u64
+            }
+            (DuplicateMatchMode::Payload, DuplicateHashAlgorithm::Fx64) => {
+                let mut hasher = rustc_hash::FxHasher::default();
+                hasher.write(data);
+                hasher.finish()
+            }
+        };
         Self {
             tag_type: tag.tag_type().into(),
-            len: tag.data().len() as u64,
-            crc: crc32::crc32(tag.data().as_ref()) as u64,
+            len: data.len() as u64,
+            hash,
         }
     }
 
@@ -86,11 +111,40 @@ impl PayloadIdentity {
     }
 }
 
+/// Hash used to compare media tag payloads.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DuplicateHashAlgorithm {
+    /// CRC-32 of the payload.
+    #[default]
+    Crc32,
+    /// 64-bit FxHash of the payload, with fewer collisions in large windows.
+    Fx64,
+}
+
+/// Which properties make two media tags duplicates.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DuplicateMatchMode {
+    /// Same tag type, timestamp, size and payload hash.
+    #[default]
+    Payload,
+    /// Same tag type, timestamp and size, without hashing the payload.
+    ///
+    /// Cheaper, but replay offset matching is disabled: without the payload, tags of the same
+    /// size are too ambiguous to infer a timestamp offset from.
+    TimestampAndSize,
+}
+
 #[derive(Debug, Clone)]
 pub struct DuplicateTagFilterConfig {
     /// Maximum number of recently-seen tags to remember for exact duplicate
     /// suppression.
     pub window_capacity_tags: usize,
+    /// Also forget tags more than this many milliseconds of media time behind
+    /// the newest tag. `None` bounds the window by `window_capacity_tags` only.
+    pub window_duration_ms: Option<u32>,
+    /// Hash used to compare payloads in [`DuplicateMatchMode::Payload`].
+    pub hash_algorithm: DuplicateHashAlgorithm,
+    pub match_mode: DuplicateMatchMode,
     /// Minimum timestamp back-jump (ms) to consider the stream as "replaying"
     /// recent content (e.g. streamer went offline and service loops tail).
     pub replay_backjump_threshold_ms: u32,
@@ -106,6 +160,9 @@ impl Default for DuplicateTagFilterConfig {
     fn default() -> Self {
         Self {
             window_capacity_tags: 8 * 1024,
+            window_duration_ms: None,
+            hash_algorithm: DuplicateHashAlgorithm::default(),
+            match_mode: DuplicateMatchMode::default(),
             replay_backjump_threshold_ms: 2_000,
             enable_replay_offset_matching: true,
         }
@@ -124,6 +181,7 @@ pub struct DuplicateTagFilterOperator {
     replay_offset_ms: Option<i64>,
     dropped_duplicates: u64,
     next_drop_log_at: u64,
+    repair_counters: RepairCounters,
 }
 
 impl DuplicateTagFilterOperator {
@@ -145,9 +203,16 @@ impl DuplicateTagFilterOperator {
             replay_offset_ms: None,
             dropped_duplicates: 0,
             next_drop_log_at: 1_000,
+            repair_counters: RepairCounters::default(),
         }
     }
 
+    /// Records dropped duplicates into `repair_counters`.
+    pub fn with_repair_counters(mut self, repair_counters: RepairCounters) -> Self {
+        self.repair_counters = repair_counters;
+        self
+    }
+
     pub fn with_capacity(context: Arc<StreamerContext>, capacity: usize) -> Self {
         Self::with_config(
             context,
@@ -181,18 +246,32 @@ impl DuplicateTagFilterOperator {
             key,
             fingerprint,
             seq,
+            timestamp_ms: tag.timestamp_ms,
         });
 
-        while self.order.len() > self.config.window_capacity_tags {
-            if let Some(old) = self.order.pop_front() {
-                self.seen.remove(&old.key);
-                if self
-                    .fingerprint_last
-                    .get(&old.fingerprint)
-                    .is_some_and(|&(_, last_seq)| last_seq == old.seq)
-                {
-                    self.fingerprint_last.remove(&old.fingerprint);
-                }
+        self.evict();
+    }
+
+    /// Forget the oldest tags until the window is within its count and duration bounds.
+    fn evict(&mut self) {
+        let oldest_timestamp = self
+            .config
+            .window_duration_ms
+            .map(|duration| self.max_timestamp_seen.saturating_sub(duration));
+        while let Some(old) = self.order.front().copied() {
+            let over_capacity = self.order.len() > self.config.window_capacity_tags;
+            let expired = oldest_timestamp.is_some_and(|oldest| old.timestamp_ms < oldest);
+            if !over_capacity && !expired {
+                break;
+            }
+            self.order.pop_front();
+            self.seen.remove(&old.key);
+            if self
+                .fingerprint_last
+                .get(&old.fingerprint)
+                .is_some_and(|&(_, last_seq)| last_seq == old.seq)
+            {
+                self.fingerprint_last.remove(&old.fingerprint);
             }
         }
     }
@@ -207,7 +286,10 @@ impl DuplicateTagFilterOperator {
         identity: PayloadIdentity,
         fingerprint: FingerprintKey,
     ) -> Option<TagKey> {
-        if !self.replay_active || !self.config.enable_replay_offset_matching {
+        if !self.replay_active
+            || !self.config.enable_replay_offset_matching
+            || self.config.match_mode == DuplicateMatchMode::TimestampAndSize
+        {
             return None;
         }
 
@@ -245,20 +327,20 @@ impl DuplicateTagFilterOperator {
         identity: PayloadIdentity,
         key: TagKey,
         fingerprint: FingerprintKey,
-    ) -> bool {
+    ) -> Option<DuplicateMatch> {
         // 1) Exact match (type + timestamp + payload).
         if self.seen.contains(&key) {
-            return true;
+            return Some(DuplicateMatch::Exact);
         }
 
         // 2) Replay-mode match: same payload, but timestamp shifted by a constant offset.
         if let Some(mapped_key) = self.replay_mapped_key(tag, identity, fingerprint)
             && self.is_exact_duplicate(mapped_key)
         {
-            return true;
+            return Some(DuplicateMatch::Replayed);
         }
 
-        false
+        None
     }
 }
 
@@ -267,6 +349,7 @@ struct SeenEntry {
     key: TagKey,
     fingerprint: FingerprintKey,
     seq: u64,
+    timestamp_ms: u32,
 }
 
 impl Processor<FlvData> for DuplicateTagFilterOperator {
@@ -311,12 +394,15 @@ impl Processor<FlvData> for DuplicateTagFilterOperator {
                     self.replay_active = true;
                 }
 
-                let identity = PayloadIdentity::new(&tag);
+                let identity = PayloadIdentity::new(&tag, &self.config);
                 let key = identity.tag_key(tag.timestamp_ms);
                 let fingerprint = identity.fingerprint();
 
-                if self.track_and_check(&tag, identity, key, fingerprint) {
+                if let Some(duplicate) = self.track_and_check(&tag, identity, key, fingerprint) {
                     self.dropped_duplicates = self.dropped_duplicates.saturating_add(1);
+                    self.repair_counters.record_duplicated(1);
+                    self.repair_counters
+                        .record_duplicate_media_tag(&tag, duplicate == DuplicateMatch::Replayed);
                     trace!(
                         "{} Dropping duplicate media tag: type={:?} ts={} len={}",
                         self.context.name,
@@ -578,4 +664,97 @@ mod tests {
         // Only the first tail should remain.
         assert_eq!(media_tag_count, 4);
     }
+
+    #[test]
+    fn test_window_duration_forgets_old_tags() {
+        let context = StreamerContext::arc_new(CancellationToken::new());
+        let cfg = DuplicateTagFilterConfig {
+            window_duration_ms: Some(5_000),
+            enable_replay_offset_matching: false,
+            ..Default::default()
+        };
+        let mut operator = DuplicateTagFilterOperator::with_config(context.clone(), cfg);
+        let mut output_items = Vec::new();
+        let mut output_fn = |item: FlvData| -> Result<(), PipelineError> {
+            output_items.push(item);
+            Ok(())
+        };
+
+        operator
+            .process(&context, create_test_header(), &mut output_fn)
+            .unwrap();
+        for ts in [0, 3_000, 8_000] {
+            operator
+                .process(&context, create_video_tag(ts, true), &mut output_fn)
+                .unwrap();
+        }
+        // 3s is still within 5s of the newest tag, 0s is not
+        for ts in [3_000, 0] {
+            operator
+                .process(&context, create_video_tag(ts, true), &mut output_fn)
+                .unwrap();
+        }
+
+        let timestamps: Vec<_> = output_items
+            .iter()
+            .filter_map(|i| match i {
+                FlvData::Tag(t) => Some(t.timestamp_ms),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(timestamps, vec![0, 3_000, 8_000, 0]);
+    }
+
+    #[test]
+    fn test_match_modes_and_counters() {
+        let context = StreamerContext::arc_new(CancellationToken::new());
+        let counters = RepairCounters::new();
+        let mut operator = DuplicateTagFilterOperator::with_config(
+            context.clone(),
+            DuplicateTagFilterConfig {
+                hash_algorithm: DuplicateHashAlgorithm::Fx64,
+                ..Default::default()
+            },
+        )
+        .with_repair_counters(counters.clone());
+        let mut output_fn = |_: FlvData| -> Result<(), PipelineError> { Ok(()) };
+
+        operator
+            .process(&context, create_test_header(), &mut output_fn)
+            .unwrap();
+        for item in [
+            create_video_tag(100, true),
+            create_video_tag(100, true),
+            // Same size and timestamp, different payload
+            create_video_tag(100, false),
+            create_audio_tag(120),
+            create_audio_tag(120),
+        ] {
+            operator.process(&context, item, &mut output_fn).unwrap();
+        }
+        let counts = counters.snapshot();
+        assert_eq!(counts.duplicated_tags, 2);
+        assert_eq!(counts.duplicated_video_tags, 1);
+        assert_eq!(counts.duplicated_audio_tags, 1);
+        assert!(counts.duplicated_bytes > 0);
+        assert_eq!(counts.replayed_tags, 0);
+
+        // Without comparing payloads, the non-keyframe at the same timestamp is a duplicate
+        let counters = RepairCounters::new();
+        let mut operator = DuplicateTagFilterOperator::with_config(
+            context.clone(),
+            DuplicateTagFilterConfig {
+                match_mode: DuplicateMatchMode::TimestampAndSize,
+                ..Default::default()
+            },
+        )
+        .with_repair_counters(counters.clone());
+        operator
+            .process(&context, create_test_header(), &mut output_fn)
+            .unwrap();
+        for item in [create_video_tag(100, true), create_video_tag(100, false)] {
+            operator.process(&context, item, &mut output_fn).unwrap();
+        }
+        assert_eq!(counters.snapshot().duplicated_video_tags, 1);
+    }
 }
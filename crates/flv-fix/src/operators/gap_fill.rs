@@ -0,0 +1,397 @@
+//! # GapFillOperator
+//!
+//! The `GapFillOperator` handles large timestamp gaps caused by CDN stalls.
+//!
+//! ## Purpose
+//!
+//! When a CDN stalls, the stream resumes with timestamps that jump ahead by several seconds.
+//! `TimingRepairOperator` treats such jumps as discontinuities and collapses them, which
+//! shortens the recording and shifts everything after the stall relative to wall-clock time.
+//! This operator keeps the gap instead, in one of two ways:
+//!
+//! 1. `Record`: the gap is left in the timeline and reported in the final `onMetaData`
+//!    (`gapcount` / `gapduration`)
+//! 2. `Fill`: silent AAC frames and repeated video keyframes are inserted across the gap,
+//!    so players keep a continuous audio clock and show a frozen picture
+//!
+//! ## Operation
+//!
+//! A gap is the distance between two consecutive audio/video tags. Gaps between
+//! `min_gap_ms` and `max_gap_ms` are handled; longer gaps are left to timing repair.
+//!
+//! Silent audio is only generated for AAC-LC mono or stereo streams, once an AAC sequence
+//! header has been seen. Video is filled by repeating the last keyframe every
+//! `video_fill_interval_ms`.
+//!
+//! ## License
+//!
+//! MIT License
+//!
+//! ## Authors
+//!
+//! - hua0512
+//!
+
+use bytes::{BufMut, BytesMut};
+use flv::audio::SoundFormat;
+use flv::data::FlvData;
+use flv::tag::{FlvTag, FlvTagType};
+use pipeline_common::{PipelineError, Processor, StreamerContext};
+use std::sync::Arc;
+use tracing::{debug, info};
+
+/// Default smallest gap (ms) that is considered a stall.
+pub const DEFAULT_MIN_GAP_MS: u32 = 1000;
+
+/// Raw AAC-LC frames that decode to silence, by channel count.
+const AAC_LC_SILENT_MONO: &[u8] = &[0x00, 0xC8, 0x00, 0x80, 0x23, 0x80];
+const AAC_LC_SILENT_STEREO: &[u8] = &[0x21, 0x00, 0x49, 0x90, 0x02, 0x19, 0x00, 0x23, 0x80];
+
+const AAC_SAMPLE_RATES: [u32; 13] = [
+    96000, 88200, 64000, 48000, 44100, 32000, 24000, 22050, 16000, 12000, 11025, 8000, 7350,
+];
+
+/// What to do with a detected timestamp gap
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GapFillMode {
+    /// Keep the gap in the timeline and report it in the final metadata
+    #[default]
+    Record,
+    /// Insert silent audio and repeated video keyframes across the gap
+    Fill,
+}
+
+/// Configuration for the GapFillOperator
+#[derive(Debug, Clone)]
+pub struct GapFillConfig {
+    /// How detected gaps are handled
+    pub mode: GapFillMode,
+
+    /// Smallest gap between consecutive media tags that is handled (ms)
+    pub min_gap_ms: u32,
+
+    /// Largest gap that is kept or filled (ms); longer gaps are left to timing repair
+    pub max_gap_ms: u32,
+
+    /// Interval between repeated video keyframes while filling (ms)
+    pub video_fill_interval_ms: u32,
+}
+
+impl Default for GapFillConfig {
+    fn default() -> Self {
+        Self {
+            mode: GapFillMode::default(),
+            min_gap_ms: DEFAULT_MIN_GAP_MS,
+            max_gap_ms: 30_000,
+            video_fill_interval_ms: 500,
+        }
+    }
+}
+
+/// Silent AAC frame template derived from the stream's sequence header
+struct SilentAac {
+    /// First byte of the audio tag (sound format, rate, size, type)
+    tag_header: u8,
+    frame: &'static [u8],
+    frame_duration_ms: f64,
+}
+
+impl SilentAac {
+    /// Build a template from an AAC sequence header tag.
+    ///
+    /// Returns `None` for codecs or layouts without a known silent frame.
+    fn from_sequence_header(tag: &FlvTag) -> Option<Self> {
+        let data = tag.data().as_ref();
+        let tag_header = *data.first()?;
+        if tag_header >> 4 != SoundFormat::Aac as u8 {
+            return None;
+        }
+
+        // AudioSpecificConfig: objectType(5) samplingFrequencyIndex(4) channelConfiguration(4)
+        let asc = data.get(2..4)?;
+        let object_type = asc[0] >> 3;
+        let freq_index = ((asc[0] & 0x07) << 1) | (asc[1] >> 7);
+        let channels = (asc[1] >> 3) & 0x0F;
+
+        // AAC-LC only: other profiles need different silent frames
+        if object_type != 2 {
+            return None;
+        }
+        let frame = match channels {
+            1 => AAC_LC_SILENT_MONO,
+            2 => AAC_LC_SILENT_STEREO,
+            _ => return None,
+        };
+        let sample_rate = *AAC_SAMPLE_RATES.get(freq_index as usize)?;
+
+        Some(Self {
+            tag_header,
+            frame,
+            frame_duration_ms: 1024.0 * 1000.0 / sample_rate as f64,
+        })
+    }
+
+    fn tag(&self, timestamp_ms: u32, stream_id: u32) -> FlvTag {
+        let mut data = BytesMut::with_capacity(2 + self.frame.len());
+        data.put_u8(self.tag_header);
+        data.put_u8(1); // AAC raw
+        data.put_slice(self.frame);
+        FlvTag::new(
+            timestamp_ms,
+            stream_id,
+            FlvTagType::Audio,
+            false,
+            data.freeze(),
+        )
+    }
+}
+
+/// Operator that keeps or fills large timestamp gaps
+pub struct GapFillOperator {
+    context: Arc<StreamerContext>,
+    config: GapFillConfig,
+    silent_aac: Option<SilentAac>,
+    last_keyframe: Option<FlvTag>,
+    last_timestamp: Option<u32>,
+    last_audio_timestamp: Option<u32>,
+    last_video_timestamp: Option<u32>,
+    gap_count: u32,
+    gap_duration_ms: u64,
+    inserted_tags: u64,
+}
+
+impl GapFillOperator {
+    /// Create a new GapFillOperator
+    pub fn new(context: Arc<StreamerContext>, config: GapFillConfig) -> Self {
+        Self {
+            context,
+            config,
+            silent_aac: None,
+            last_keyframe: None,
+            last_timestamp: None,
+            last_audio_timestamp: None,
+            last_video_timestamp: None,
+            gap_count: 0,
+            gap_duration_ms: 0,
+            inserted_tags: 0,
+        }
+    }
+
+    /// Reset the operator state
+    pub fn reset(&mut self) {
+        self.silent_aac = None;
+        self.last_keyframe = None;
+        self.last_timestamp = None;
+        self.last_audio_timestamp = None;
+        self.last_video_timestamp = None;
+    }
+
+    /// Filler tags strictly between the last tags and `until`, in timestamp order.
+    fn fillers(&self, until: u32) -> Vec<FlvTag> {
+        let mut fillers = Vec::new();
+
+        if let (Some(silent), Some(start)) = (&self.silent_aac, self.last_audio_timestamp) {
+            let mut n = 1u32;
+            loop {
+                let timestamp = start as f64 + n as f64 * silent.frame_duration_ms;
+                if timestamp >= until as f64 {
+                    break;
+                }
+                fillers.push(silent.tag(timestamp as u32, 0));
+                n += 1;
+            }
+        }
+
+        if let (Some(keyframe), Some(start)) = (&self.last_keyframe, self.last_video_timestamp) {
+            let interval = self.config.video_fill_interval_ms.max(1);
+            let mut timestamp = start.saturating_add(interval);
+            while timestamp < until {
+                let mut tag = keyframe.clone();
+                tag.timestamp_ms = timestamp;
+                fillers.push(tag);
+                timestamp = timestamp.saturating_add(interval);
+            }
+        }
+
+        fillers.sort_by_key(|tag| tag.timestamp_ms);
+        fillers
+    }
+
+    fn track(&mut self, tag: &FlvTag) {
+        self.last_timestamp = Some(tag.timestamp_ms);
+        if tag.is_audio_tag() {
+            if tag.is_audio_sequence_header() {
+                self.silent_aac = SilentAac::from_sequence_header(tag);
+            } else {
+                self.last_audio_timestamp = Some(tag.timestamp_ms);
+            }
+        } else if tag.is_video_tag() && !tag.is_video_sequence_header() {
+            self.last_video_timestamp = Some(tag.timestamp_ms);
+            if tag.is_key_frame_nalu() {
+                self.last_keyframe = Some(tag.clone());
+            }
+        }
+    }
+}
+
+impl Processor<FlvData> for GapFillOperator {
+    fn process(
+        &mut self,
+        context: &Arc<StreamerContext>,
+        input: FlvData,
+        output: &mut dyn FnMut(FlvData) -> Result<(), PipelineError>,
+    ) -> Result<(), PipelineError> {
+        if context.token.is_cancelled() {
+            return Err(PipelineError::Cancelled);
+        }
+        match input {
+            FlvData::Header(_) => {
+                self.reset();
+                output(input)
+            }
+            FlvData::Tag(tag) if tag.is_audio_tag() || tag.is_video_tag() => {
+                if let Some(last) = self.last_timestamp
+                    && tag.timestamp_ms.saturating_sub(last) >= self.config.min_gap_ms
+                {
+                    let gap = tag.timestamp_ms - last;
+                    if gap > self.config.max_gap_ms {
+                        debug!(
+                            "{} Gap of {}ms at {}ms exceeds {}ms, leaving it to timing repair",
+                            self.context.name, gap, last, self.config.max_gap_ms
+                        );
+                    } else {
+                        self.gap_count += 1;
+                        self.gap_duration_ms += gap as u64;
+                        info!(
+                            "{} Timestamp gap of {}ms detected at {}ms ({:?})",
+                            self.context.name, gap, last, self.config.mode
+                        );
+
+                        if self.config.mode == GapFillMode::Fill {
+                            for filler in self.fillers(tag.timestamp_ms) {
+                                self.inserted_tags += 1;
+                                output(FlvData::Tag(filler))?;
+                            }
+                        }
+                    }
+                }
+
+                self.track(&tag);
+                output(FlvData::Tag(tag))
+            }
+            _ => output(input),
+        }
+    }
+
+    fn finish(
+        &mut self,
+        _context: &Arc<StreamerContext>,
+        _output: &mut dyn FnMut(FlvData) -> Result<(), PipelineError>,
+    ) -> Result<(), PipelineError> {
+        if self.gap_count > 0 {
+            info!(
+                "{} Handled {} timestamp gaps totalling {}ms, inserted {} filler tags",
+                self.context.name, self.gap_count, self.gap_duration_ms, self.inserted_tags
+            );
+        }
+        Ok(())
+    }
+
+    fn name(&self) -> &'static str {
+        "GapFillOperator"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{
+        create_audio_tag, create_test_header, create_test_tag, create_video_tag,
+    };
+    use pipeline_common::CancellationToken;
+
+    fn run(config: GapFillConfig, input: Vec<FlvData>) -> Vec<FlvTag> {
+        let context = StreamerContext::arc_new(CancellationToken::new());
+        let mut operator = GapFillOperator::new(context.clone(), config);
+        let mut output_items = Vec::new();
+        let mut output_fn = |item: FlvData| -> Result<(), PipelineError> {
+            output_items.push(item);
+            Ok(())
+        };
+
+        operator
+            .process(&context, create_test_header(), &mut output_fn)
+            .unwrap();
+        for item in input {
+            operator.process(&context, item, &mut output_fn).unwrap();
+        }
+        operator.finish(&context, &mut output_fn).unwrap();
+
+        output_items
+            .into_iter()
+            .filter_map(|item| match item {
+                FlvData::Tag(tag) => Some(tag),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Stall of 1s between the last tags at 100ms and the resumed tags at 1100ms.
+    fn stalled_stream() -> Vec<FlvData> {
+        vec![
+            // AAC-LC, 44.1kHz, stereo
+            create_test_tag(FlvTagType::Audio, 0, vec![0xAF, 0x00, 0x12, 0x10]),
+            create_video_tag(0, true),
+            create_audio_tag(100),
+            create_video_tag(100, false),
+            create_audio_tag(1100),
+            create_video_tag(1100, true),
+        ]
+    }
+
+    #[test]
+    fn record_mode_keeps_gap_without_inserting() {
+        let tags = run(GapFillConfig::default(), stalled_stream());
+        let timestamps: Vec<_> = tags.iter().map(|tag| tag.timestamp_ms).collect();
+        assert_eq!(timestamps, vec![0, 0, 100, 100, 1100, 1100]);
+    }
+
+    #[test]
+    fn fill_mode_inserts_silence_and_repeated_keyframes() {
+        let config = GapFillConfig {
+            mode: GapFillMode::Fill,
+            ..GapFillConfig::default()
+        };
+        let tags = run(config, stalled_stream());
+        let fillers = &tags[4..tags.len() - 2];
+
+        let audio: Vec<_> = fillers.iter().filter(|tag| tag.is_audio_tag()).collect();
+        let video: Vec<_> = fillers.iter().filter(|tag| tag.is_video_tag()).collect();
+
+        // 1000ms / 23.2ms per AAC frame at 44.1kHz
+        assert_eq!(audio.len(), 43);
+        assert_eq!(
+            audio[0].data().as_ref(),
+            &[&[0xAF, 0x01][..], AAC_LC_SILENT_STEREO].concat()[..]
+        );
+        assert_eq!(audio[0].timestamp_ms, 123);
+
+        // Keyframe from 0ms repeated every 500ms after the last video tag at 100ms
+        let video_timestamps: Vec<_> = video.iter().map(|tag| tag.timestamp_ms).collect();
+        assert_eq!(video_timestamps, vec![600]);
+        assert!(video.iter().all(|tag| tag.is_key_frame_nalu()));
+
+        assert!(fillers.is_sorted_by_key(|tag| tag.timestamp_ms));
+    }
+
+    #[test]
+    fn ignores_gaps_outside_configured_range() {
+        let config = GapFillConfig {
+            mode: GapFillMode::Fill,
+            max_gap_ms: 500,
+            ..GapFillConfig::default()
+        };
+        let tags = run(config, stalled_stream());
+        assert_eq!(tags.len(), 6);
+    }
+}
@@ -0,0 +1,428 @@
+//! # GopIntegrityOperator
+//!
+//! The `GopIntegrityOperator` makes sure every GOP written starts with a video keyframe.
+//!
+//! ## Purpose
+//!
+//! After a reconnect or a CDN failover the stream often resumes in the middle of a GOP. The
+//! inter frames before the next keyframe reference pictures the decoder never received, so
+//! players show grey blocks or smeared pictures for the first seconds of the file. The same
+//! happens after a video sequence header change, since frames of the old configuration cannot
+//! be decoded with the new one.
+//!
+//! ## Operation
+//!
+//! After each FLV header, and after each video sequence header that differs from the previous
+//! one, the operator waits for a keyframe. Video inter frames seen before it form an incomplete
+//! leading GOP, handled according to [`GopIntegrityAction`]:
+//!
+//! 1. `Drop`: the inter frames are dropped as they arrive; audio, sequence headers and script
+//!    tags pass through
+//! 2. `Quarantine`: everything from the first inter frame on is held back. When a keyframe
+//!    arrives within `max_quarantine_ms`, the held media tags are dropped so audio and video
+//!    both start on the keyframe. Otherwise (or at the end of the stream) the held tags are
+//!    released unchanged, since there is nothing better to write
+//!
+//! Sequence headers and script tags are never dropped. Dropped tags are recorded in the
+//! pipeline's [`RepairCounters`](crate::RepairCounters) and show up in analysis reports.
+//!
+//! ## License
+//!
+//! MIT License
+//!
+//! ## Authors
+//!
+//! - hua0512
+//!
+
+use bytes::Bytes;
+use flv::data::FlvData;
+use flv::tag::FlvTag;
+use pipeline_common::{PipelineError, Processor, StreamerContext};
+use std::sync::Arc;
+use tracing::{debug, info, warn};
+
+use crate::report::RepairCounters;
+
+/// Most tags held in quarantine before they are released regardless of media time.
+const MAX_QUARANTINE_TAGS: usize = 4096;
+
+/// What to do with an incomplete leading GOP
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GopIntegrityAction {
+    /// Drop video inter frames until the next keyframe
+    #[default]
+    Drop,
+    /// Hold tags back until the next keyframe, then drop the held media tags
+    Quarantine,
+}
+
+/// Configuration for the GopIntegrityOperator
+#[derive(Debug, Clone)]
+pub struct GopIntegrityConfig {
+    /// How incomplete leading GOPs are handled
+    pub action: GopIntegrityAction,
+
+    /// Longest span of media time held in quarantine before it is released (ms)
+    pub max_quarantine_ms: u32,
+}
+
+impl Default for GopIntegrityConfig {
+    fn default() -> Self {
+        Self {
+            action: GopIntegrityAction::default(),
+            max_quarantine_ms: 10_000,
+        }
+    }
+}
+
+/// Operator that removes incomplete leading GOPs
+pub struct GopIntegrityOperator {
+    context: Arc<StreamerContext>,
+    config: GopIntegrityConfig,
+    repair_counters: RepairCounters,
+    /// Whether the next video frame must be a keyframe
+    awaiting_keyframe: bool,
+    last_video_sequence_header: Option<Bytes>,
+    /// Tags held back in `Quarantine` mode, in arrival order
+    quarantine: Vec<FlvData>,
+    quarantine_start_ms: Option<u32>,
+    /// Inter frames dropped from the current incomplete GOP in `Drop` mode
+    dropped_in_gop: u64,
+    incomplete_gops: u64,
+}
+
+impl GopIntegrityOperator {
+    /// Create a new GopIntegrityOperator
+    pub fn new(context: Arc<StreamerContext>, config: GopIntegrityConfig) -> Self {
+        Self {
+            context,
+            config,
+            repair_counters: RepairCounters::default(),
+            awaiting_keyframe: true,
+            last_video_sequence_header: None,
+            quarantine: Vec::new(),
+            quarantine_start_ms: None,
+            dropped_in_gop: 0,
+            incomplete_gops: 0,
+        }
+    }
+
+    /// Records dropped tags into `repair_counters`.
+    pub fn with_repair_counters(mut self, repair_counters: RepairCounters) -> Self {
+        self.repair_counters = repair_counters;
+        self
+    }
+
+    fn record_incomplete_gop(&mut self, dropped_tags: u64) {
+        self.incomplete_gops += 1;
+        self.repair_counters.record_dropped(dropped_tags);
+        self.repair_counters.record_incomplete_gop(dropped_tags);
+    }
+
+    /// Close the incomplete GOP of `Drop` mode, if any.
+    fn end_dropped_gop(&mut self) {
+        if self.dropped_in_gop > 0 {
+            debug!(
+                "{} Dropped {} inter frames of an incomplete GOP",
+                self.context.name, self.dropped_in_gop
+            );
+            let dropped = std::mem::take(&mut self.dropped_in_gop);
+            self.record_incomplete_gop(dropped);
+        }
+    }
+
+    /// Release the quarantined tags unchanged.
+    fn release_quarantine(
+        &mut self,
+        output: &mut dyn FnMut(FlvData) -> Result<(), PipelineError>,
+    ) -> Result<(), PipelineError> {
+        self.quarantine_start_ms = None;
+        for item in self.quarantine.drain(..) {
+            output(item)?;
+        }
+        Ok(())
+    }
+
+    /// The keyframe arrived: drop the quarantined media tags and forward the rest.
+    fn resolve_quarantine(
+        &mut self,
+        output: &mut dyn FnMut(FlvData) -> Result<(), PipelineError>,
+    ) -> Result<(), PipelineError> {
+        if self.quarantine.is_empty() {
+            return Ok(());
+        }
+        self.quarantine_start_ms = None;
+        let mut dropped = 0;
+        for item in std::mem::take(&mut self.quarantine) {
+            match &item {
+                FlvData::Tag(tag) if Self::is_media_frame(tag) => dropped += 1,
+                _ => output(item)?,
+            }
+        }
+        debug!(
+            "{} Dropped {} quarantined tags of an incomplete GOP",
+            self.context.name, dropped
+        );
+        self.record_incomplete_gop(dropped);
+        Ok(())
+    }
+
+    /// Audio or video data that is not a sequence header.
+    fn is_media_frame(tag: &FlvTag) -> bool {
+        (tag.is_audio_tag() && !tag.is_audio_sequence_header())
+            || (tag.is_video_tag() && !tag.is_video_sequence_header())
+    }
+
+    fn handle_video_sequence_header(&mut self, tag: &FlvTag) {
+        let data = tag.data();
+        if let Some(previous) = &self.last_video_sequence_header
+            && previous != data
+        {
+            debug!(
+                "{} Video sequence header changed, waiting for a keyframe",
+                self.context.name
+            );
+            self.awaiting_keyframe = true;
+        }
+        self.last_video_sequence_header = Some(data.clone());
+    }
+
+    fn handle_tag(
+        &mut self,
+        tag: FlvTag,
+        output: &mut dyn FnMut(FlvData) -> Result<(), PipelineError>,
+    ) -> Result<(), PipelineError> {
+        if tag.is_video_sequence_header() {
+            self.handle_video_sequence_header(&tag);
+        } else if tag.is_video_tag() && tag.is_key_frame() {
+            self.awaiting_keyframe = false;
+            self.end_dropped_gop();
+            self.resolve_quarantine(output)?;
+        } else if tag.is_video_tag() && self.awaiting_keyframe {
+            match self.config.action {
+                GopIntegrityAction::Drop => {
+                    self.dropped_in_gop += 1;
+                    return Ok(());
+                }
+                GopIntegrityAction::Quarantine => {
+                    self.quarantine_start_ms.get_or_insert(tag.timestamp_ms);
+                }
+            }
+        }
+
+        let Some(start_ms) = self.quarantine_start_ms else {
+            return output(FlvData::Tag(tag));
+        };
+        let held_ms = tag.timestamp_ms.saturating_sub(start_ms);
+        self.quarantine.push(FlvData::Tag(tag));
+        if held_ms > self.config.max_quarantine_ms || self.quarantine.len() >= MAX_QUARANTINE_TAGS {
+            warn!(
+                "{} No keyframe within {}ms of an incomplete GOP, releasing {} quarantined tags",
+                self.context.name,
+                held_ms,
+                self.quarantine.len()
+            );
+            self.awaiting_keyframe = false;
+            self.release_quarantine(output)?;
+        }
+        Ok(())
+    }
+}
+
+impl Processor<FlvData> for GopIntegrityOperator {
+    fn process(
+        &mut self,
+        context: &Arc<StreamerContext>,
+        input: FlvData,
+        output: &mut dyn FnMut(FlvData) -> Result<(), PipelineError>,
+    ) -> Result<(), PipelineError> {
+        if context.token.is_cancelled() {
+            return Err(PipelineError::Cancelled);
+        }
+        match input {
+            FlvData::Header(_) => {
+                // The previous stream ended without a keyframe
+                self.end_dropped_gop();
+                self.release_quarantine(output)?;
+                self.awaiting_keyframe = true;
+                self.last_video_sequence_header = None;
+                output(input)
+            }
+            FlvData::Tag(tag) => self.handle_tag(tag, output),
+            _ => output(input),
+        }
+    }
+
+    fn finish(
+        &mut self,
+        _context: &Arc<StreamerContext>,
+        output: &mut dyn FnMut(FlvData) -> Result<(), PipelineError>,
+    ) -> Result<(), PipelineError> {
+        self.end_dropped_gop();
+        self.release_quarantine(output)?;
+        if self.incomplete_gops > 0 {
+            info!(
+                "{} Removed {} incomplete GOPs",
+                self.context.name, self.incomplete_gops
+            );
+        }
+        Ok(())
+    }
+
+    fn name(&self) -> &'static str {
+        "GopIntegrityOperator"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{
+        create_audio_tag, create_test_header, create_video_sequence_header, create_video_tag,
+    };
+    use pipeline_common::CancellationToken;
+
+    fn run(config: GopIntegrityConfig, input: Vec<FlvData>) -> (Vec<FlvData>, RepairCounters) {
+        let context = StreamerContext::arc_new(CancellationToken::new());
+        let counters = RepairCounters::new();
+        let mut operator = GopIntegrityOperator::new(context.clone(), config)
+            .with_repair_counters(counters.clone());
+        let mut output_items = Vec::new();
+        let mut output_fn = |item: FlvData| -> Result<(), PipelineError> {
+            output_items.push(item);
+            Ok(())
+        };
+
+        for item in input {
+            operator.process(&context, item, &mut output_fn).unwrap();
+        }
+        operator.finish(&context, &mut output_fn).unwrap();
+        (output_items, counters)
+    }
+
+    fn timestamps(items: &[FlvData]) -> Vec<(bool, u32)> {
+        items
+            .iter()
+            .filter_map(|item| match item {
+                FlvData::Tag(tag) => Some((tag.is_video_tag(), tag.timestamp_ms)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn quarantine() -> GopIntegrityConfig {
+        GopIntegrityConfig {
+            action: GopIntegrityAction::Quarantine,
+            max_quarantine_ms: 1_000,
+        }
+    }
+
+    #[test]
+    fn drops_inter_frames_before_the_first_keyframe() {
+        let (items, counters) = run(
+            GopIntegrityConfig::default(),
+            vec![
+                create_test_header(),
+                create_video_sequence_header(0, 1),
+                create_video_tag(0, false),
+                create_audio_tag(10),
+                create_video_tag(33, false),
+                create_video_tag(66, true),
+                create_video_tag(100, false),
+            ],
+        );
+
+        assert_eq!(
+            timestamps(&items),
+            vec![(true, 0), (false, 10), (true, 66), (true, 100)]
+        );
+        let repairs = counters.snapshot();
+        assert_eq!(repairs.incomplete_gops, 1);
+        assert_eq!(repairs.incomplete_gop_tags, 2);
+        assert_eq!(repairs.dropped_tags, 2);
+    }
+
+    #[test]
+    fn waits_for_a_keyframe_after_a_reconnect_or_sequence_header_change() {
+        let (items, counters) = run(
+            GopIntegrityConfig::default(),
+            vec![
+                create_test_header(),
+                create_video_sequence_header(0, 1),
+                create_video_tag(0, true),
+                create_video_tag(33, false),
+                // Same configuration: the GOP continues
+                create_video_sequence_header(40, 1),
+                create_video_tag(66, false),
+                create_video_sequence_header(80, 2),
+                create_video_tag(100, false),
+                create_video_tag(133, true),
+                create_test_header(),
+                create_video_tag(200, false),
+            ],
+        );
+
+        assert_eq!(
+            timestamps(&items),
+            vec![
+                (true, 0),
+                (true, 0),
+                (true, 33),
+                (true, 40),
+                (true, 66),
+                (true, 80),
+                (true, 133),
+            ]
+        );
+        assert_eq!(counters.snapshot().incomplete_gops, 2);
+    }
+
+    #[test]
+    fn quarantine_drops_held_media_when_a_keyframe_arrives() {
+        let (items, counters) = run(
+            quarantine(),
+            vec![
+                create_test_header(),
+                create_audio_tag(0),
+                create_video_tag(10, false),
+                create_audio_tag(20),
+                create_video_sequence_header(30, 1),
+                create_video_tag(40, true),
+                create_audio_tag(50),
+            ],
+        );
+
+        assert_eq!(
+            timestamps(&items),
+            vec![(false, 0), (true, 30), (true, 40), (false, 50)]
+        );
+        assert_eq!(counters.snapshot().incomplete_gop_tags, 2);
+    }
+
+    #[test]
+    fn quarantine_releases_tags_without_a_keyframe() {
+        let (items, counters) = run(
+            quarantine(),
+            vec![
+                create_test_header(),
+                create_video_tag(0, false),
+                create_audio_tag(500),
+                create_video_tag(1_500, false),
+                create_video_tag(1_533, false),
+            ],
+        );
+
+        assert_eq!(
+            timestamps(&items),
+            vec![(true, 0), (false, 500), (true, 1_500), (true, 1_533)]
+        );
+        assert_eq!(counters.snapshot().incomplete_gops, 0);
+
+        let (items, _) = run(
+            quarantine(),
+            vec![create_test_header(), create_video_tag(0, false)],
+        );
+        assert_eq!(timestamps(&items), vec![(true, 0)]);
+    }
+}
@@ -73,23 +73,25 @@ impl GopSortOperator {
 
         // Special handling for small buffers with sequence headers
         if self.gop_tags.len() < Self::TAGS_BUFFER_SIZE {
-            let avc_header_pos = self
+            // Sequence headers are codec-agnostic here: legacy AVC/HEVC packet type 0 and
+            // Enhanced RTMP `PacketTypeSequenceStart` are both classified as such.
+            let video_header_pos = self
                 .gop_tags
                 .iter()
                 .position(|tag| tag.is_video_sequence_header());
-            let aac_header_pos = self
+            let audio_header_pos = self
                 .gop_tags
                 .iter()
                 .position(|tag| tag.is_audio_sequence_header());
 
             // If we have both sequence headers, emit them directly
-            if let (Some(avc_pos), Some(aac_pos)) = (avc_header_pos, aac_header_pos) {
+            if let (Some(avc_pos), Some(aac_pos)) = (video_header_pos, audio_header_pos) {
                 // Find first script tag
                 let script_pos = self.gop_tags.iter().position(|tag| tag.is_script_tag());
 
                 debug!(
-                    "{} AVC header position: {:?}, AAC header position: {:?}",
-                    self.context.name, avc_header_pos, aac_header_pos
+                    "{} video header position: {:?}, audio header position: {:?}",
+                    self.context.name, video_header_pos, audio_header_pos
                 );
 
                 // Emit script tag first if present
@@ -254,8 +256,9 @@ impl Processor<FlvData> for GopSortOperator {
 mod tests {
     use super::*;
     use crate::test_utils::{
-        self, create_audio_sequence_header, create_audio_tag, create_script_tag,
-        create_test_header, create_video_sequence_header, create_video_tag,
+        self, create_audio_sequence_header, create_audio_tag, create_enhanced_hevc_sequence_header,
+        create_enhanced_hevc_video_tag, create_script_tag, create_test_header,
+        create_video_sequence_header, create_video_tag,
     };
     use flv::tag::FlvTagType;
     use pipeline_common::{CancellationToken, StreamerContext};
@@ -350,6 +353,60 @@ mod tests {
         }
     }
 
+    #[test]
+    fn enhanced_hevc_sequence_start_is_emitted_first() {
+        let context = StreamerContext::arc_new(CancellationToken::new());
+        let mut operator = GopSortOperator::new(context.clone());
+        let mut output_items = Vec::new();
+        let mut output_fn = |item: FlvData| -> Result<(), PipelineError> {
+            output_items.push(item);
+            Ok(())
+        };
+
+        operator
+            .process(&context, create_test_header(), &mut output_fn)
+            .unwrap();
+        operator
+            .process(&context, create_audio_tag(5), &mut output_fn)
+            .unwrap();
+        operator
+            .process(&context, create_audio_sequence_header(0, 1), &mut output_fn)
+            .unwrap();
+        operator
+            .process(
+                &context,
+                create_enhanced_hevc_sequence_header(0, 1),
+                &mut output_fn,
+            )
+            .unwrap();
+        operator
+            .process(
+                &context,
+                create_enhanced_hevc_video_tag(20, false),
+                &mut output_fn,
+            )
+            .unwrap();
+        operator
+            .process(
+                &context,
+                create_enhanced_hevc_video_tag(40, true),
+                &mut output_fn,
+            )
+            .unwrap();
+        operator.finish(&context, &mut output_fn).unwrap();
+
+        let tags: Vec<_> = output_items
+            .iter()
+            .filter_map(|item| match item {
+                FlvData::Tag(tag) => Some(tag),
+                _ => None,
+            })
+            .collect();
+        assert!(tags[0].is_video_sequence_header());
+        assert!(tags[1].is_audio_sequence_header());
+        assert_eq!(tags.len(), 5);
+    }
+
     #[test]
     fn test_interleaving() {
         let context = StreamerContext::arc_new(CancellationToken::new());
@@ -1,7 +1,7 @@
 //! # LimitOperator
 //!
-//! The `LimitOperator` implements size and duration limits for FLV streams, automatically
-//! splitting the stream when configured thresholds are reached.
+//! The `LimitOperator` implements size, duration and wall-clock limits for FLV streams,
+//! automatically splitting the stream when configured thresholds are reached.
 //!
 //! ## Purpose
 //!
@@ -20,6 +20,7 @@
 //! - Tracks accumulated byte size of all emitted tags
 //! - Monitors the maximum timestamp seen in the stream
 //! - Triggers splits when size or duration thresholds are exceeded
+//! - Triggers splits when the local time crosses a configured wall-clock boundary
 //! - Re-injects stream headers after each split
 //! - Supports optional callbacks when splits occur
 //!
@@ -36,9 +37,9 @@ use flv::data::FlvData;
 use flv::header::FlvHeader;
 use flv::tag::FlvTag;
 use pipeline_common::split_reason::SplitReason;
-use pipeline_common::{PipelineError, Processor, StreamerContext};
+use pipeline_common::{PipelineError, Processor, StreamerContext, WallClockBoundary};
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 use tracing::{debug, info};
 
 /// Optional callback for when a stream split occurs
@@ -52,6 +53,9 @@ pub struct LimitConfig {
     /// Maximum duration in milliseconds before splitting (None = no limit)
     pub max_duration_ms: Option<u32>,
 
+    /// Split at every multiple of this interval since local midnight (None = no limit)
+    pub wall_clock_split: Option<Duration>,
+
     /// Whether to split at keyframes only (may exceed limits slightly)
     pub split_at_keyframes_only: bool,
 
@@ -67,6 +71,7 @@ impl Default for LimitConfig {
         Self {
             max_size_bytes: None,
             max_duration_ms: None,
+            wall_clock_split: None,
             split_at_keyframes_only: true,
             on_split: None,
         }
@@ -120,6 +125,7 @@ pub struct LimitOperator {
     context: Arc<StreamerContext>,
     config: LimitConfig,
     state: StreamState,
+    wall_clock: Option<WallClockBoundary>,
     last_split_time: Instant,
 }
 
@@ -129,10 +135,12 @@ impl LimitOperator {
     }
 
     pub fn with_config(context: Arc<StreamerContext>, config: LimitConfig) -> Self {
+        let wall_clock = config.wall_clock_split.map(WallClockBoundary::new);
         Self {
             context,
             config,
             state: StreamState::new(),
+            wall_clock,
             last_split_time: Instant::now(),
         }
     }
@@ -146,6 +154,8 @@ impl LimitOperator {
 
         if duration_exceeded {
             SplitReason::DurationLimit
+        } else if self.wall_clock.as_ref().is_some_and(|w| w.is_due()) {
+            SplitReason::WallClock
         } else {
             SplitReason::SizeLimit
         }
@@ -177,6 +187,19 @@ impl LimitOperator {
             }
         }
 
+        // Check wall-clock boundary if configured - armed by the first content tag
+        if self.state.first_content_tag_seen
+            && let Some(wall_clock) = &self.wall_clock
+            && wall_clock.is_due()
+        {
+            debug!(
+                "{} Wall-clock boundary crossed (every {}s)",
+                self.context.name,
+                wall_clock.interval().as_secs()
+            );
+            return true;
+        }
+
         false
     }
 
@@ -222,6 +245,9 @@ impl LimitOperator {
 
         // Reset accumulated counters for the new segment
         self.state.reset_counters();
+        if let Some(wall_clock) = &mut self.wall_clock {
+            wall_clock.restart();
+        }
         self.last_split_time = Instant::now();
         Ok(())
     }
@@ -242,6 +268,9 @@ impl Processor<FlvData> for LimitOperator {
                 // Reset state for a new stream
                 self.state = StreamState::new();
                 self.state.header = Some(header.clone());
+                if let Some(wall_clock) = &mut self.wall_clock {
+                    wall_clock.restart();
+                }
                 self.last_split_time = Instant::now();
 
                 // Forward the header
@@ -362,6 +391,7 @@ mod tests {
         let config = LimitConfig {
             max_size_bytes: Some(100 * 1024),
             max_duration_ms: None,
+            wall_clock_split: None,
             split_at_keyframes_only: true,
             on_split: Some(Box::new(move |_, _, _| {
                 split_counter.fetch_add(1, Ordering::SeqCst);
@@ -466,6 +496,7 @@ mod tests {
         let config = LimitConfig {
             max_size_bytes: None,
             max_duration_ms: Some(500),
+            wall_clock_split: None,
             split_at_keyframes_only: true,
             on_split: Some(Box::new(move |_, _, _| {
                 split_counter.fetch_add(1, Ordering::SeqCst);
@@ -584,6 +615,7 @@ mod tests {
         let config = LimitConfig {
             max_size_bytes: None,
             max_duration_ms: None,
+            wall_clock_split: None,
             split_at_keyframes_only: true,
             on_split: Some(Box::new(move |_, _, _| {
                 split_counter.fetch_add(1, Ordering::SeqCst);
@@ -655,6 +687,7 @@ mod tests {
         let config = LimitConfig {
             max_size_bytes: Some(500),
             max_duration_ms: Some(300),
+            wall_clock_split: None,
             split_at_keyframes_only: false,
             on_split: Some(Box::new(move |_, _, _| {
                 split_count.fetch_add(1, Ordering::SeqCst);
@@ -748,6 +781,7 @@ mod tests {
         let config = LimitConfig {
             max_size_bytes: None,
             max_duration_ms: Some(400),
+            wall_clock_split: None,
             split_at_keyframes_only: true,
             on_split: Some(Box::new({
                 let st_clone = Arc::clone(&split_timestamps);
@@ -889,6 +923,7 @@ mod tests {
         let config = LimitConfig {
             max_size_bytes: Some(1000),
             max_duration_ms: None,
+            wall_clock_split: None,
             split_at_keyframes_only: false,
             on_split: Some(Box::new(move |_, _, _| {
                 split_count.fetch_add(1, Ordering::SeqCst);
@@ -937,6 +972,7 @@ mod tests {
         let config = LimitConfig {
             max_size_bytes: Some(1024), // 1KB limit
             max_duration_ms: None,
+            wall_clock_split: None,
             split_at_keyframes_only: true, // This should be ignored for audio-only
             on_split: Some(Box::new(move |_, _, _| {
                 split_counter.fetch_add(1, Ordering::SeqCst);
@@ -1037,6 +1073,7 @@ mod tests {
         let config = LimitConfig {
             max_size_bytes: None,
             max_duration_ms: Some(1000), // 1 second limit
+            wall_clock_split: None,
             split_at_keyframes_only: true,
             on_split: Some(Box::new(move |_, _, _| {
                 split_counter.fetch_add(1, Ordering::SeqCst);
@@ -1153,6 +1190,7 @@ mod tests {
         let config = LimitConfig {
             max_size_bytes: Some(1024),
             max_duration_ms: None,
+            wall_clock_split: None,
             split_at_keyframes_only: false,
             on_split: None,
         };
@@ -1211,6 +1249,7 @@ mod tests {
         let config = LimitConfig {
             max_size_bytes: None,
             max_duration_ms: Some(500),
+            wall_clock_split: None,
             split_at_keyframes_only: true,
             on_split: None,
         };
@@ -1267,4 +1306,75 @@ mod tests {
             "Should emit exactly one Split(DurationLimit) marker"
         );
     }
+
+    #[test]
+    fn test_wall_clock_split_waits_for_keyframe() {
+        let context = StreamerContext::arc_new(CancellationToken::new());
+        let now = Arc::new(Mutex::new(time::macros::datetime!(2024-05-01 12:59:58 +0)));
+        let clock = Arc::clone(&now);
+
+        let config = LimitConfig {
+            wall_clock_split: Some(Duration::from_secs(3_600)),
+            ..LimitConfig::default()
+        };
+        let mut operator = LimitOperator::with_config(context.clone(), config);
+        operator.wall_clock = Some(WallClockBoundary::with_clock(
+            Duration::from_secs(3_600),
+            move || *clock.lock().unwrap(),
+        ));
+
+        let mut output_items = Vec::new();
+        let mut output_fn = |item: FlvData| -> Result<(), PipelineError> {
+            output_items.push(item);
+            Ok(())
+        };
+
+        operator
+            .process(&context, test_utils::create_test_header(), &mut output_fn)
+            .unwrap();
+        operator
+            .process(
+                &context,
+                test_utils::create_video_tag(0, true),
+                &mut output_fn,
+            )
+            .unwrap();
+
+        // The boundary passes on a P-frame; the split waits for the next keyframe.
+        *now.lock().unwrap() = time::macros::datetime!(2024-05-01 13:00:01 +0);
+        operator
+            .process(
+                &context,
+                test_utils::create_video_tag(100, false),
+                &mut output_fn,
+            )
+            .unwrap();
+        operator
+            .process(
+                &context,
+                test_utils::create_video_tag(200, true),
+                &mut output_fn,
+            )
+            .unwrap();
+        operator
+            .process(
+                &context,
+                test_utils::create_video_tag(300, true),
+                &mut output_fn,
+            )
+            .unwrap();
+
+        let splits: Vec<_> = output_items
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| matches!(item, FlvData::Split(_)))
+            .collect();
+        assert_eq!(splits.len(), 1, "Should split once per boundary");
+        let (idx, split) = splits[0];
+        assert!(matches!(split, FlvData::Split(SplitReason::WallClock)));
+        assert!(
+            matches!(&output_items[idx - 1], FlvData::Tag(tag) if tag.timestamp_ms == 100),
+            "Split should come right before the keyframe following the boundary"
+        );
+    }
 }
@@ -22,6 +22,9 @@
 //! - When changes are detected, marks the stream for splitting
 //! - At the next regular media tag, re-injects headers and sequence information
 //!
+//! Split decisions can be customized with [`SplitPredicate`]s, which can veto sequence-header
+//! splits or request splits at keyframes (see the `split_predicate` module).
+//!
 //!
 //! ## License
 //!
@@ -34,12 +37,15 @@
 use flv::data::FlvData;
 use flv::header::FlvHeader;
 use flv::tag::FlvTag;
+use flv::video::EnhancedPacketType;
 use pipeline_common::split_reason::{AudioCodecInfo, SplitReason, VideoCodecInfo};
 use pipeline_common::{PipelineError, Processor, StreamerContext};
 use std::sync::Arc;
 use tracing::{debug, info};
 
+use super::split_predicate::SplitPredicate;
 use crate::crc32;
+use crate::report::RepairCounters;
 
 /// Controls how `SplitOperator` decides whether a sequence header "changed".
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -131,6 +137,8 @@ pub struct SplitOperator {
     state: StreamState,
     drop_duplicate_sequence_headers: bool,
     sequence_header_change_mode: SequenceHeaderChangeMode,
+    predicates: Vec<Box<dyn SplitPredicate>>,
+    repair_counters: RepairCounters,
 }
 
 impl SplitOperator {
@@ -148,9 +156,59 @@ impl SplitOperator {
             state: StreamState::new(),
             drop_duplicate_sequence_headers,
             sequence_header_change_mode,
+            predicates: Vec::new(),
+            repair_counters: RepairCounters::default(),
         }
     }
 
+    /// Records dropped duplicate sequence headers into `repair_counters`.
+    pub fn with_repair_counters(mut self, repair_counters: RepairCounters) -> Self {
+        self.repair_counters = repair_counters;
+        self
+    }
+
+    /// Add user-provided split conditions.
+    pub fn with_predicates(
+        mut self,
+        predicates: impl IntoIterator<Item = Box<dyn SplitPredicate>>,
+    ) -> Self {
+        self.predicates.extend(predicates);
+        self
+    }
+
+    /// Whether every predicate allows splitting on a change from `previous` to `current`.
+    fn allows_sequence_header_split(
+        &mut self,
+        previous: Option<&FlvTag>,
+        current: &FlvTag,
+    ) -> bool {
+        let Some(previous) = previous else {
+            return true;
+        };
+        self.predicates
+            .iter_mut()
+            .all(|predicate| predicate.on_sequence_header_change(previous, current))
+    }
+
+    /// Asks the predicates whether to split before `tag`, at safe split points only.
+    fn requested_split(&mut self, tag: &FlvTag) -> Option<SplitReason> {
+        if self.predicates.is_empty() || !self.state.has_emitted_media_tag {
+            return None;
+        }
+        let has_video = self.state.header.as_ref().is_none_or(|h| h.has_video);
+        let is_split_point = if has_video {
+            tag.is_key_frame_nalu()
+        } else {
+            tag.is_audio_tag()
+        };
+        if !is_split_point {
+            return None;
+        }
+        self.predicates
+            .iter_mut()
+            .find_map(|predicate| predicate.split_before(tag))
+    }
+
     /// Calculate CRC32 for a byte slice.
     fn calculate_crc32(data: &[u8]) -> u32 {
         crc32::crc32(data)
@@ -185,6 +243,7 @@ impl SplitOperator {
     ///   - skips `[packet_type][composition_time(3)]`
     /// - enhanced: `fourcc || payload[5..]`
     ///   - skips the first byte (flags/packet type)
+    ///   - AV1 also skips `initial_presentation_delay` (record byte 3), a buffering hint
     fn calculate_video_sequence_signature(tag: &FlvTag) -> u32 {
         let data = tag.data().as_ref();
         if data.is_empty() {
@@ -196,7 +255,13 @@ impl SplitOperator {
 
         if enhanced {
             // Layout: [flags+packet_type][fourcc(4)][codec_config...]
-            if data.len() >= 5 {
+            let av1_sequence_start = data.get(1..5) == Some(b"av01".as_slice())
+                && EnhancedPacketType::from(data[0] & 0x0F) == EnhancedPacketType::SEQUENCE_START;
+            if av1_sequence_start && data.len() >= 9 {
+                // AV1CodecConfigurationRecord: [marker+version][profile+level][flags][delay][obus...]
+                state = crc32::crc32_update(state, &data[1..8]);
+                state = crc32::crc32_update(state, &data[9..]);
+            } else if data.len() >= 5 {
                 state = crc32::crc32_update(state, &data[1..5]);
                 state = crc32::crc32_update(state, &data[5..]);
             } else {
@@ -251,6 +316,20 @@ impl SplitOperator {
         use flv::hevc::HevcPacket;
         use flv::video::{EnhancedPacket, VideoData, VideoTagBody};
 
+        // Fallback when the config record cannot be parsed: use the codec from the tag
+        // header (legacy codec id or Enhanced RTMP fourCC).
+        let fallback = VideoCodecInfo {
+            codec: tag
+                .get_video_codec()
+                .map(|codec| format!("{codec:?}").to_uppercase())
+                .unwrap_or_else(|| "unknown".to_string()),
+            profile: None,
+            level: None,
+            width: None,
+            height: None,
+            signature,
+        };
+
         let data = tag.data().clone();
         let mut cursor = std::io::Cursor::new(data);
 
@@ -263,8 +342,8 @@ impl SplitOperator {
                         codec: "AVC".to_string(),
                         profile: Some(config.profile_indication),
                         level: Some(config.level_indication),
-                        width: resolution.as_ref().map(|r| r.width as u32),
-                        height: resolution.as_ref().map(|r| r.height as u32),
+                        width: resolution.as_ref().map(|r| r.width),
+                        height: resolution.as_ref().map(|r| r.height),
                         signature,
                     }
                 }
@@ -275,8 +354,8 @@ impl SplitOperator {
                         codec: "HEVC".to_string(),
                         profile: Some(config.general_profile_idc),
                         level: Some(config.general_level_idc),
-                        width: resolution.as_ref().map(|r| r.width as u32),
-                        height: resolution.as_ref().map(|r| r.height as u32),
+                        width: resolution.as_ref().map(|r| r.width),
+                        height: resolution.as_ref().map(|r| r.height),
                         signature,
                     }
                 }
@@ -287,8 +366,8 @@ impl SplitOperator {
                         codec: "AV1".to_string(),
                         profile: Some(config.seq_profile),
                         level: Some(config.seq_level_idx_0),
-                        width: resolution.as_ref().map(|r| r.width as u32),
-                        height: resolution.as_ref().map(|r| r.height as u32),
+                        width: resolution.as_ref().map(|r| r.width),
+                        height: resolution.as_ref().map(|r| r.height),
                         signature,
                     }
                 }
@@ -299,8 +378,8 @@ impl SplitOperator {
                         codec: "AVC".to_string(),
                         profile: Some(config.profile_indication),
                         level: Some(config.level_indication),
-                        width: resolution.as_ref().map(|r| r.width as u32),
-                        height: resolution.as_ref().map(|r| r.height as u32),
+                        width: resolution.as_ref().map(|r| r.width),
+                        height: resolution.as_ref().map(|r| r.height),
                         signature,
                     }
                 }
@@ -311,35 +390,14 @@ impl SplitOperator {
                         codec: "HEVC".to_string(),
                         profile: Some(config.general_profile_idc),
                         level: Some(config.general_level_idc),
-                        width: resolution.as_ref().map(|r| r.width as u32),
-                        height: resolution.as_ref().map(|r| r.height as u32),
+                        width: resolution.as_ref().map(|r| r.width),
+                        height: resolution.as_ref().map(|r| r.height),
                         signature,
                     }
                 }
-                _ => {
-                    // Fallback: use codec ID from tag
-                    let codec = tag
-                        .get_video_codec_id()
-                        .map(|id| format!("{id:?}"))
-                        .unwrap_or_else(|| "unknown".to_string());
-                    VideoCodecInfo {
-                        codec,
-                        profile: None,
-                        level: None,
-                        width: None,
-                        height: None,
-                        signature,
-                    }
-                }
-            },
-            Err(_) => VideoCodecInfo {
-                codec: "unknown".to_string(),
-                profile: None,
-                level: None,
-                width: None,
-                height: None,
-                signature,
+                _ => fallback,
             },
+            Err(_) => fallback,
         }
     }
 
@@ -613,6 +671,7 @@ impl Processor<FlvData> for SplitOperator {
                             "{} Dropping duplicate video sequence header (sig: {:x})",
                             self.context.name, sig
                         );
+                        self.repair_counters.record_duplicated(1);
                         self.state.video_sequence_tag = Some(tag);
                         self.state.video_sig = Some(sig);
                         return Ok(());
@@ -625,7 +684,17 @@ impl Processor<FlvData> for SplitOperator {
                         // negotiating/settling the initial codec configuration (common right at
                         // stream start). Splitting here creates an "empty" first segment consisting
                         // only of headers/sequence tags.
-                        if self.state.has_emitted_media_tag {
+                        if self.state.has_emitted_media_tag
+                            && !self.allows_sequence_header_split(
+                                self.state.video_sequence_tag.clone().as_ref(),
+                                &tag,
+                            )
+                        {
+                            info!(
+                                "{} Video sequence header changed (sig: {:x} -> {:x}), split vetoed by predicate",
+                                self.context.name, prev_sig, sig
+                            );
+                        } else if self.state.has_emitted_media_tag {
                             info!(
                                 "{} Video sequence header changed (sig: {:x} -> {:x}), marking for split",
                                 self.context.name, prev_sig, sig
@@ -667,6 +736,7 @@ impl Processor<FlvData> for SplitOperator {
                             "{} Dropping duplicate audio sequence header (sig: {:x})",
                             self.context.name, sig
                         );
+                        self.repair_counters.record_duplicated(1);
                         self.state.audio_sequence_tag = Some(tag);
                         self.state.audio_sig = Some(sig);
                         return Ok(());
@@ -675,7 +745,17 @@ impl Processor<FlvData> for SplitOperator {
                     if let Some(prev_sig) = self.state.audio_sig
                         && prev_sig != sig
                     {
-                        if self.state.has_emitted_media_tag {
+                        if self.state.has_emitted_media_tag
+                            && !self.allows_sequence_header_split(
+                                self.state.audio_sequence_tag.clone().as_ref(),
+                                &tag,
+                            )
+                        {
+                            info!(
+                                "{} Audio parameters changed (sig: {:x} -> {:x}), split vetoed by predicate",
+                                self.context.name, prev_sig, sig
+                            );
+                        } else if self.state.has_emitted_media_tag {
                             info!(
                                 "{} Audio parameters changed (sig: {:x} -> {:x})",
                                 self.context.name, prev_sig, sig
@@ -707,6 +787,10 @@ impl Processor<FlvData> for SplitOperator {
                 // Regular media tag: if a change was detected earlier, split before emitting.
                 if self.state.changed {
                     self.split_stream(output)?;
+                } else if let Some(reason) = self.requested_split(&tag) {
+                    info!("{} Split requested: {}", self.context.name, reason);
+                    output(FlvData::Split(reason))?;
+                    self.split_stream(output)?;
                 }
                 self.state.has_emitted_media_tag = true;
                 output(FlvData::Tag(tag))
@@ -737,8 +821,11 @@ mod tests {
     use pipeline_common::{CancellationToken, StreamerContext};
 
     use super::*;
+    use crate::operators::{ResolutionChangePredicate, SplitTrigger};
     use crate::test_utils::{
-        create_audio_sequence_header, create_audio_tag, create_test_header,
+        create_audio_sequence_header, create_audio_tag, create_enhanced_av1_sequence_header,
+        create_enhanced_av1_sequence_header_with_delay, create_enhanced_av1_video_tag,
+        create_enhanced_hevc_sequence_header, create_enhanced_hevc_video_tag, create_test_header,
         create_video_sequence_header, create_video_tag,
     };
 
@@ -802,6 +889,237 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_predicate_vetoes_sequence_header_split() {
+        let context = StreamerContext::arc_new(CancellationToken::new());
+        let mut operator = SplitOperator::new(context.clone())
+            .with_predicates([Box::new(|_: &FlvTag| None) as Box<dyn SplitPredicate>])
+            .with_predicates([Box::new(ResolutionChangePredicate) as Box<dyn SplitPredicate>]);
+        let mut output_items = Vec::new();
+        let mut output_fn = |item: FlvData| -> Result<(), PipelineError> {
+            output_items.push(item);
+            Ok(())
+        };
+
+        for item in [
+            create_test_header(),
+            create_audio_sequence_header(0, 1),
+            create_audio_tag(0),
+            create_audio_sequence_header(20, 2),
+            create_audio_tag(40),
+        ] {
+            operator.process(&context, item, &mut output_fn).unwrap();
+        }
+
+        let header_count = output_items
+            .iter()
+            .filter(|item| matches!(item, FlvData::Header(_)))
+            .count();
+        assert_eq!(header_count, 1, "audio change should not split");
+        // The new sequence header is written inline
+        assert_eq!(output_items.len(), 5);
+    }
+
+    #[test]
+    fn test_split_trigger_splits_at_next_keyframe() {
+        let context = StreamerContext::arc_new(CancellationToken::new());
+        let trigger = SplitTrigger::new();
+        let mut operator = SplitOperator::new(context.clone())
+            .with_predicates([Box::new(trigger.clone()) as Box<dyn SplitPredicate>]);
+        let mut output_items = Vec::new();
+        let mut output_fn = |item: FlvData| -> Result<(), PipelineError> {
+            output_items.push(item);
+            Ok(())
+        };
+
+        for item in [
+            create_test_header(),
+            create_video_sequence_header(0, 1),
+            create_video_tag(0, true),
+        ] {
+            operator.process(&context, item, &mut output_fn).unwrap();
+        }
+        trigger.trigger();
+        for item in [create_video_tag(40, false), create_video_tag(80, true)] {
+            operator.process(&context, item, &mut output_fn).unwrap();
+        }
+
+        let split_at = output_items
+            .iter()
+            .position(|item| matches!(item, FlvData::Split(SplitReason::Requested { .. })))
+            .expect("requested split marker");
+        // Split waits for the keyframe at 80ms, then re-injects header and sequence header
+        assert!(matches!(&output_items[split_at - 1], FlvData::Tag(tag) if tag.timestamp_ms == 40));
+        assert!(matches!(output_items[split_at + 1], FlvData::Header(_)));
+        assert!(
+            matches!(&output_items[split_at + 2], FlvData::Tag(tag) if tag.is_video_sequence_header())
+        );
+        assert!(matches!(output_items.last(), Some(FlvData::Tag(tag)) if tag.timestamp_ms == 80));
+    }
+
+    #[test]
+    fn test_enhanced_hevc_sequence_start_change_detection() {
+        let context = StreamerContext::arc_new(CancellationToken::new());
+        let mut operator = SplitOperator::new(context.clone());
+        let mut output_items = Vec::new();
+
+        let mut output_fn = |item: FlvData| -> Result<(), PipelineError> {
+            output_items.push(item);
+            Ok(())
+        };
+
+        operator
+            .process(&context, create_test_header(), &mut output_fn)
+            .unwrap();
+        operator
+            .process(
+                &context,
+                create_enhanced_hevc_sequence_header(0, 1),
+                &mut output_fn,
+            )
+            .unwrap();
+        operator
+            .process(
+                &context,
+                create_enhanced_hevc_video_tag(100, true),
+                &mut output_fn,
+            )
+            .unwrap();
+
+        // Identical SequenceStart must not split
+        operator
+            .process(
+                &context,
+                create_enhanced_hevc_sequence_header(150, 1),
+                &mut output_fn,
+            )
+            .unwrap();
+        operator
+            .process(
+                &context,
+                create_enhanced_hevc_video_tag(200, true),
+                &mut output_fn,
+            )
+            .unwrap();
+
+        // Changed hvcC must split
+        operator
+            .process(
+                &context,
+                create_enhanced_hevc_sequence_header(250, 2),
+                &mut output_fn,
+            )
+            .unwrap();
+        operator
+            .process(
+                &context,
+                create_enhanced_hevc_video_tag(300, true),
+                &mut output_fn,
+            )
+            .unwrap();
+
+        let splits: Vec<_> = output_items
+            .iter()
+            .filter_map(|item| match item {
+                FlvData::Split(reason) => Some(reason),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(splits.len(), 1, "Should split once on hvcC change");
+        match splits[0] {
+            SplitReason::VideoCodecChange { from, to } => {
+                assert_eq!(from.codec, "HEVC");
+                assert_eq!(to.codec, "HEVC");
+                assert_ne!(from.signature, to.signature);
+            }
+            other => panic!("Expected VideoCodecChange, got: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_enhanced_av1_sequence_start_change_detection() {
+        let context = StreamerContext::arc_new(CancellationToken::new());
+        let mut operator = SplitOperator::with_config(
+            context.clone(),
+            SequenceHeaderChangeMode::SemanticSignature,
+            false,
+        );
+        let mut output_items = Vec::new();
+
+        let mut output_fn = |item: FlvData| -> Result<(), PipelineError> {
+            output_items.push(item);
+            Ok(())
+        };
+
+        operator
+            .process(&context, create_test_header(), &mut output_fn)
+            .unwrap();
+        operator
+            .process(
+                &context,
+                create_enhanced_av1_sequence_header(0, 8),
+                &mut output_fn,
+            )
+            .unwrap();
+        operator
+            .process(
+                &context,
+                create_enhanced_av1_video_tag(100, true),
+                &mut output_fn,
+            )
+            .unwrap();
+
+        // A different presentation delay does not change decoder configuration
+        operator
+            .process(
+                &context,
+                create_enhanced_av1_sequence_header_with_delay(150, 8, 0x1A),
+                &mut output_fn,
+            )
+            .unwrap();
+        operator
+            .process(
+                &context,
+                create_enhanced_av1_video_tag(200, true),
+                &mut output_fn,
+            )
+            .unwrap();
+
+        // A level change does
+        operator
+            .process(
+                &context,
+                create_enhanced_av1_sequence_header(250, 13),
+                &mut output_fn,
+            )
+            .unwrap();
+        operator
+            .process(
+                &context,
+                create_enhanced_av1_video_tag(300, true),
+                &mut output_fn,
+            )
+            .unwrap();
+
+        let splits: Vec<_> = output_items
+            .iter()
+            .filter_map(|item| match item {
+                FlvData::Split(reason) => Some(reason),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(splits.len(), 1, "Should split once on AV1 level change");
+        match splits[0] {
+            SplitReason::VideoCodecChange { from, to } => {
+                assert_eq!(from.codec, "AV1");
+                assert_eq!(from.level, Some(8));
+                assert_eq!(to.level, Some(13));
+                assert!(to.width.is_some());
+            }
+            other => panic!("Expected VideoCodecChange, got: {other:?}"),
+        }
+    }
+
     #[test]
     fn test_audio_codec_change_detection() {
         let context = StreamerContext::arc_new(CancellationToken::new());
@@ -0,0 +1,169 @@
+//! # Split predicates
+//!
+//! User-provided conditions that decide when [`SplitOperator`](super::SplitOperator) starts a
+//! new segment, on top of (or instead of) its built-in sequence-header change detection.
+//!
+//! ## Hooks
+//!
+//! A [`SplitPredicate`] has two hooks:
+//!
+//! 1. [`on_sequence_header_change`](SplitPredicate::on_sequence_header_change) is called when
+//!    the configured `SequenceHeaderChangeMode` detects a changed sequence header. Any predicate
+//!    returning `false` vetoes the split and the new header is written inline.
+//! 2. [`split_before`](SplitPredicate::split_before) is called for regular media tags at safe
+//!    split points (video keyframes, or audio tags in audio-only streams). The first predicate
+//!    returning a reason starts a new segment before that tag.
+//!
+//! Closures of type `FnMut(&FlvTag) -> Option<SplitReason>` implement the trait through
+//! `split_before`.
+//!
+//! ## Built-in predicates
+//!
+//! - [`ResolutionChangePredicate`]: only split on sequence-header changes that change resolution
+//! - [`SplitTrigger`]: split on request from another task
+//!
+//! ## License
+//!
+//! MIT License
+//!
+//! ## Authors
+//!
+//! - hua0512
+//!
+
+use flv::tag::FlvTag;
+use pipeline_common::split_reason::SplitReason;
+use std::fmt;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// A condition that decides when `SplitOperator` starts a new segment.
+pub trait SplitPredicate: Send {
+    /// Whether a detected change between two sequence headers of the same media type should
+    /// split the stream.
+    fn on_sequence_header_change(&mut self, _previous: &FlvTag, _current: &FlvTag) -> bool {
+        true
+    }
+
+    /// Whether to split right before `tag`, and why.
+    fn split_before(&mut self, _tag: &FlvTag) -> Option<SplitReason> {
+        None
+    }
+}
+
+impl<F> SplitPredicate for F
+where
+    F: FnMut(&FlvTag) -> Option<SplitReason> + Send,
+{
+    fn split_before(&mut self, tag: &FlvTag) -> Option<SplitReason> {
+        self(tag)
+    }
+}
+
+/// Creates a fresh predicate for every pipeline built from a config.
+#[derive(Clone)]
+pub struct SplitPredicateFactory(Arc<dyn Fn() -> Box<dyn SplitPredicate> + Send + Sync>);
+
+impl SplitPredicateFactory {
+    pub fn new(factory: impl Fn() -> Box<dyn SplitPredicate> + Send + Sync + 'static) -> Self {
+        Self(Arc::new(factory))
+    }
+
+    pub fn create(&self) -> Box<dyn SplitPredicate> {
+        (self.0)()
+    }
+}
+
+impl fmt::Debug for SplitPredicateFactory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SplitPredicateFactory")
+    }
+}
+
+/// Only split on video sequence-header changes that change the resolution.
+///
+/// Audio sequence-header changes never split. When either resolution cannot be parsed the
+/// change is allowed, since the codec itself may have changed.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ResolutionChangePredicate;
+
+impl SplitPredicate for ResolutionChangePredicate {
+    fn on_sequence_header_change(&mut self, previous: &FlvTag, current: &FlvTag) -> bool {
+        if !current.is_video_tag() {
+            return false;
+        }
+        match (
+            previous.get_video_resolution(),
+            current.get_video_resolution(),
+        ) {
+            (Some(previous), Some(current)) => previous != current,
+            _ => true,
+        }
+    }
+}
+
+/// Splits the stream on request, e.g. from an API handler.
+///
+/// Clones share the same trigger: keep one to call [`trigger`](Self::trigger) and hand another
+/// to the pipeline. The split happens at the next safe split point.
+#[derive(Debug, Default, Clone)]
+pub struct SplitTrigger {
+    requested: Arc<AtomicBool>,
+}
+
+impl SplitTrigger {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Request a split at the next safe split point.
+    pub fn trigger(&self) {
+        self.requested.store(true, Ordering::Release);
+    }
+}
+
+impl SplitPredicate for SplitTrigger {
+    fn split_before(&mut self, _tag: &FlvTag) -> Option<SplitReason> {
+        self.requested
+            .swap(false, Ordering::AcqRel)
+            .then(|| SplitReason::Requested {
+                description: "manual split".to_string(),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{create_video_sequence_header, create_video_tag};
+    use flv::data::FlvData;
+
+    fn tag(data: FlvData) -> FlvTag {
+        match data {
+            FlvData::Tag(tag) => tag,
+            other => panic!("expected tag, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn trigger_fires_once() {
+        let trigger = SplitTrigger::new();
+        let mut predicate = trigger.clone();
+        let keyframe = tag(create_video_tag(0, true));
+
+        assert!(predicate.split_before(&keyframe).is_none());
+        trigger.trigger();
+        assert!(predicate.split_before(&keyframe).is_some());
+        assert!(predicate.split_before(&keyframe).is_none());
+    }
+
+    #[test]
+    fn resolution_predicate_ignores_audio_changes() {
+        let mut predicate = ResolutionChangePredicate;
+        let video = tag(create_video_sequence_header(0, 1));
+        let audio = tag(crate::test_utils::create_audio_sequence_header(0, 1));
+        assert!(!predicate.on_sequence_header_change(&audio, &audio));
+        // Unparseable resolutions fall back to splitting
+        assert!(predicate.on_sequence_header_change(&video, &video));
+    }
+}
@@ -21,7 +21,8 @@
 //!
 //! The operator:
 //! - Tracks audio and video streams separately
-//! - Extracts frame rate and audio sample rate from metadata
+//! - Extracts frame rate and audio sample rate from metadata, preferring the
+//!   per-frame durations of H.264 picture timing SEI when the SPS enables them
 //! - Calculates expected intervals between frames and audio samples
 //! - Detects timestamps that violate expected patterns
 //! - Applies carefully calculated corrections to maintain proper timing
@@ -30,7 +31,8 @@
 //! ## Configuration
 //!
 //! The operator supports various configuration options:
-//! - Repair strategies (strict vs. relaxed)
+//! - Repair strategies: the built-in [`StrictRepair`] and [`RelaxedRepair`], or any
+//!   [`RepairStrategy`] implementation for stream-specific policies
 //! - Default frame rates when metadata is missing
 //! - Tolerance thresholds for discontinuity detection
 //! - Debug modes for timing issue diagnosis
@@ -45,12 +47,14 @@
 //!
 
 use amf0::Amf0Value;
+use flv::avc::{AvcSeiContext, SeiMessage};
 use flv::data::FlvData;
 use flv::script::ScriptData;
 use flv::tag::{FlvTag, FlvTagType};
 use pipeline_common::{PipelineError, Processor, StreamerContext};
 use std::cmp::max;
 use std::f64;
+use std::fmt;
 use std::sync::Arc;
 use tracing::{debug, error, info, trace, warn};
 
@@ -59,22 +63,61 @@ use tracing::{debug, error, info, trace, warn};
 /// (e.g., 30fps = 33.33ms), we allow +/- 1ms tolerance to account for rounding
 const TOLERANCE: u32 = 1;
 
-/// Defines the strategy for timestamp repair
-#[derive(Debug, Clone, Copy, PartialEq, Default)]
-pub enum RepairStrategy {
-    /// Strict mode enforces exact frame intervals and corrects any deviation
-    Strict,
+/// The timing of a media tag relative to the previous tag, as seen by a [`RepairStrategy`].
+#[derive(Debug, Clone, Copy)]
+pub struct TimingCheck<'a> {
+    /// The tag being checked, with its original timestamp
+    pub tag: &'a FlvTag,
+    /// Timestamp of the tag with the current correction applied, minus the timestamp of the
+    /// previous tag (ms)
+    pub diff_ms: i64,
+    /// Expected interval between tags of this type, including rounding tolerance (ms)
+    pub expected_interval_ms: u32,
+    /// Whether a tag of the same media type was seen before in this segment
+    pub has_previous_of_type: bool,
+    /// The configured `max_discontinuity` (ms)
+    pub max_discontinuity_ms: u32,
+}
+
+/// Decides when the timestamps of a stream are discontinuous and need a new correction.
+///
+/// Timestamp rebounds are always corrected; strategies only decide which forward jumps are
+/// discontinuities. Once a tag is found discontinuous, the operator computes a new correction
+/// delta so the tag follows the previous one at the expected interval.
+pub trait RepairStrategy: fmt::Debug + Send + Sync {
+    fn is_discontinuous(&self, check: &TimingCheck<'_>) -> bool;
+}
+
+/// Enforces exact frame intervals and corrects any deviation.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StrictRepair;
 
-    /// Relaxed mode only fixes severe timing issues, allowing minor variations
-    #[default]
-    Relaxed,
+impl RepairStrategy for StrictRepair {
+    fn is_discontinuous(&self, check: &TimingCheck<'_>) -> bool {
+        if check.tag.is_video_tag() && check.has_previous_of_type {
+            check.diff_ms < 0 || check.diff_ms > check.expected_interval_ms as i64
+        } else {
+            // More lenient for non-video tags or when no video history exists
+            check.diff_ms > check.expected_interval_ms as i64 * 2
+        }
+    }
+}
+
+/// Only fixes jumps longer than `max_discontinuity`, allowing minor variations.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RelaxedRepair;
+
+impl RepairStrategy for RelaxedRepair {
+    fn is_discontinuous(&self, check: &TimingCheck<'_>) -> bool {
+        check.diff_ms > check.max_discontinuity_ms as i64
+    }
 }
 
 /// Configuration options for the TimingRepairOperator
 #[derive(Debug, Clone)]
 pub struct TimingRepairConfig {
     /// Strategy to use for repairing timestamps
-    pub strategy: RepairStrategy,
+    pub strategy: Arc<dyn RepairStrategy>,
 
     /// Default video frame rate if not specified in metadata (fps)
     pub default_frame_rate: f64,
@@ -89,7 +132,7 @@ pub struct TimingRepairConfig {
 impl Default for TimingRepairConfig {
     fn default() -> Self {
         Self {
-            strategy: RepairStrategy::default(),
+            strategy: Arc::new(RelaxedRepair),
             default_frame_rate: 30.0,
             default_audio_rate: 44100.0,
             max_discontinuity: 1000, // 1 second
@@ -136,6 +179,13 @@ struct TimingState {
     tag_count: u32,
     /// Whether the stream has video
     has_video: bool,
+
+    /// SEI context of an AVC stream whose SPS enables picture timing SEI
+    avc_sei: Option<AvcSeiContext>,
+
+    /// Whether the video frame interval comes from picture timing SEI, which
+    /// is trusted over the frame rate in metadata
+    sei_frame_timing: bool,
 }
 
 impl TimingState {
@@ -158,6 +208,8 @@ impl TimingState {
             discontinuity_count: 0,
             tag_count: 0,
             has_video: false,
+            avc_sei: None,
+            sei_frame_timing: false,
         }
     }
 
@@ -184,6 +236,8 @@ impl TimingState {
         self.audio_sample_interval =
             Self::calculate_audio_sample_interval(config.default_audio_rate);
         self.has_video = false;
+        self.avc_sei = None;
+        self.sei_frame_timing = false;
     }
 
     /// Calculate the video frame interval in milliseconds based on frame rate
@@ -204,12 +258,44 @@ impl TimingState {
     }
 
     fn update_frame_rate(&mut self, fps: f64) {
-        if fps > 0.0 {
+        if fps > 0.0 && !self.sei_frame_timing {
             self.frame_rate = fps;
             self.video_frame_interval = Self::calculate_video_frame_interval(fps);
         }
     }
 
+    /// Pick up the SEI context of an AVC sequence header. Only streams whose
+    /// SPS has timing info and enables picture timing SEI keep one.
+    fn update_avc_sei_context(&mut self, tag: &FlvTag) {
+        self.avc_sei = tag
+            .get_avc_sei_context()
+            .filter(|context| context.timing_info.is_some() && context.has_pic_timing());
+    }
+
+    /// Use the display duration in the picture timing SEI of a frame as the
+    /// expected interval to the next frame. This follows the encoder's own
+    /// timing (including pulldown) regardless of the container timestamps.
+    fn update_frame_interval_from_sei(&mut self, tag: &FlvTag) {
+        let Some(context) = &self.avc_sei else {
+            return;
+        };
+        let Some(timing_info) = &context.timing_info else {
+            return;
+        };
+        let duration_secs =
+            tag.avc_sei_messages(context)
+                .iter()
+                .find_map(|message| match message {
+                    SeiMessage::PicTiming(pic_timing) => pic_timing.duration_secs(timing_info),
+                    _ => None,
+                });
+        if let Some(duration_secs) = duration_secs.filter(|secs| *secs > 0.0) {
+            self.frame_rate = 1.0 / duration_secs;
+            self.video_frame_interval = f64::ceil(duration_secs * 1000.0) as u32;
+            self.sei_frame_timing = true;
+        }
+    }
+
     fn update_audio_rate(&mut self, rate_hz: f64) {
         if rate_hz > 0.0 {
             self.audio_rate = rate_hz;
@@ -314,22 +400,20 @@ impl TimingState {
             }
         };
 
-        // Add tolerance to account for rounding errors
-        let threshold = base_threshold + TOLERANCE;
+        let has_previous_of_type = match tag.tag_type() {
+            FlvTagType::Video => self.last_video_tag.is_some(),
+            FlvTagType::Audio => self.last_audio_tag.is_some(),
+            _ => true,
+        };
 
-        // For strict mode, check if difference is significantly different from expected interval
-        match config.strategy {
-            RepairStrategy::Strict => {
-                // In strict mode, we check if the timestamp differs from what we'd expect
-                if tag.is_video_tag() && self.last_video_tag.is_some() {
-                    diff < 0 || diff > threshold.into()
-                } else {
-                    // For non-video tags or when no video history exists
-                    diff > threshold as i64 * 2 // More lenient for non-video
-                }
-            }
-            RepairStrategy::Relaxed => diff > config.max_discontinuity as i64,
-        }
+        config.strategy.is_discontinuous(&TimingCheck {
+            tag,
+            diff_ms: diff,
+            // Add tolerance to account for rounding errors
+            expected_interval_ms: base_threshold + TOLERANCE,
+            has_previous_of_type,
+            max_discontinuity_ms: config.max_discontinuity,
+        })
     }
 
     /// Calculate a new delta correction when a problem is detected
@@ -408,7 +492,7 @@ impl TimingRepairOperator {
     }
 
     /// Create a new TimingRepairOperator with default configuration
-    pub fn with_strategy(context: Arc<StreamerContext>, strategy: RepairStrategy) -> Self {
+    pub fn with_strategy(context: Arc<StreamerContext>, strategy: Arc<dyn RepairStrategy>) -> Self {
         let config = TimingRepairConfig {
             strategy,
             ..Default::default()
@@ -617,6 +701,12 @@ impl Processor<FlvData> for TimingRepairOperator {
                     );
                 }
 
+                if tag.is_video_sequence_header() {
+                    self.state.update_avc_sei_context(&tag);
+                } else if tag.is_video_tag() {
+                    self.state.update_frame_interval_from_sei(&tag);
+                }
+
                 // Update state with this tag
                 self.state.update_last_tags(&tag);
 
@@ -715,7 +805,7 @@ mod tests {
         }
 
         let config = TimingRepairConfig {
-            strategy: RepairStrategy::Strict,
+            strategy: Arc::new(StrictRepair),
             ..Default::default()
         };
 
@@ -792,7 +882,7 @@ mod tests {
         }
 
         let config = TimingRepairConfig {
-            strategy: RepairStrategy::Strict,
+            strategy: Arc::new(StrictRepair),
             ..Default::default()
         };
 
@@ -835,7 +925,7 @@ mod tests {
         }
 
         let config = TimingRepairConfig {
-            strategy: RepairStrategy::Relaxed,
+            strategy: Arc::new(RelaxedRepair),
             ..Default::default()
         };
 
@@ -879,4 +969,93 @@ mod tests {
             "Audio and video should maintain reasonable sync"
         );
     }
+
+    #[test]
+    fn test_custom_strategy_decides_discontinuities() {
+        /// Keeps every forward jump, e.g. for streams with intentional pauses.
+        #[derive(Debug)]
+        struct KeepGaps;
+
+        impl RepairStrategy for KeepGaps {
+            fn is_discontinuous(&self, _check: &TimingCheck<'_>) -> bool {
+                false
+            }
+        }
+
+        let input_tags = || {
+            vec![
+                create_test_header(),
+                create_video_tag(0, true),
+                create_video_tag(33, false),
+                create_video_tag(5_033, true),
+                create_video_tag(5_066, false),
+            ]
+        };
+        let video_timestamps = |strategy: Arc<dyn RepairStrategy>| {
+            let config = TimingRepairConfig {
+                strategy,
+                ..Default::default()
+            };
+            process_tags_through_operator(config, input_tags())
+                .into_iter()
+                .filter_map(|item| match item {
+                    FlvData::Tag(tag) if tag.is_video_tag() => Some(tag.timestamp_ms),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            video_timestamps(Arc::new(KeepGaps)),
+            vec![0, 33, 5_033, 5_066]
+        );
+        let relaxed = video_timestamps(Arc::new(RelaxedRepair));
+        assert!(
+            relaxed[2] < 1_000,
+            "relaxed repair collapses the gap: {relaxed:?}"
+        );
+    }
+
+    #[test]
+    fn pic_timing_sei_sets_frame_interval() {
+        use std::num::NonZeroU32;
+
+        use h264::{PicTimingInfo, TimingInfo};
+
+        use crate::test_utils::create_test_tag;
+
+        let context = StreamerContext::arc_new(CancellationToken::new());
+        let mut operator = TimingRepairOperator::new(context, TimingRepairConfig::default());
+        operator.state.avc_sei = Some(AvcSeiContext {
+            nalu_length_size: 4,
+            timing_info: Some(TimingInfo {
+                num_units_in_tick: NonZeroU32::new(1001).unwrap(),
+                time_scale: NonZeroU32::new(60000).unwrap(),
+            }),
+            pic_timing_info: Some(PicTimingInfo {
+                nal_hrd: None,
+                vcl_hrd: None,
+                low_delay_hrd_flag: false,
+                pic_struct_present_flag: true,
+            }),
+        });
+
+        // SEI NAL with a pic_timing of pic_struct 7 (frame doubling, 4 ticks)
+        // and no clock timestamp
+        let nal = [0x06, 0x01, 0x01, 0b0111_0000, 0x80];
+        let mut data = vec![0x27, 0x01, 0x00, 0x00, 0x00];
+        data.extend_from_slice(&(nal.len() as u32).to_be_bytes());
+        data.extend_from_slice(&nal);
+        let FlvData::Tag(tag) = create_test_tag(FlvTagType::Video, 0, data) else {
+            unreachable!()
+        };
+
+        operator.state.update_frame_interval_from_sei(&tag);
+        assert_eq!(operator.state.video_frame_interval, 67);
+        assert!(operator.state.sei_frame_timing);
+
+        // Metadata no longer overrides the encoder's timing
+        operator.state.update_frame_rate(25.0);
+        assert_eq!(operator.state.video_frame_interval, 67);
+    }
 }
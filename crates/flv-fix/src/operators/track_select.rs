@@ -0,0 +1,345 @@
+//! # TrackSelectOperator
+//!
+//! The `TrackSelectOperator` handles E-RTMP v2 multitrack audio and video tags.
+//!
+//! ## Purpose
+//!
+//! Multitrack streams carry several renditions (e.g. camera angles or commentary languages)
+//! in the same FLV stream, either packed into one tag or interleaved as one tag per track.
+//! Most players and the downstream operators expect a single track per media type, so this
+//! operator can either:
+//!
+//! 1. Keep all tracks, passing multitrack tags through unchanged
+//! 2. Select a single track, rewriting its multitrack tags into plain enhanced tags and
+//!    dropping every other track
+//!
+//! ## Operation
+//!
+//! Tags without a multitrack header belong to the default track (0). When a non-default
+//! track is selected, those tags are only dropped once a multitrack tag of the same media
+//! type has been seen, so single-track streams are never emptied by a stale selection.
+//!
+//! Timestamps are kept monotonic per `(media type, track)`: a tag that goes back in time
+//! relative to the last tag of the same track is clamped to that track's last timestamp.
+//! Interleaved tracks are not compared with each other.
+//!
+//! ## License
+//!
+//! MIT License
+//!
+//! ## Authors
+//!
+//! - hua0512
+//!
+
+use flv::data::FlvData;
+use flv::multitrack::DEFAULT_TRACK_ID;
+use flv::tag::{FlvTag, FlvTagType};
+use pipeline_common::{PipelineError, Processor, StreamerContext};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tracing::{debug, info, warn};
+
+/// Which multitrack tracks to keep.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TrackSelection {
+    /// Keep every track.
+    #[default]
+    All,
+    /// Keep only the given track id.
+    Single(u8),
+}
+
+/// Operator that selects tracks from E-RTMP multitrack streams
+pub struct TrackSelectOperator {
+    context: Arc<StreamerContext>,
+    selection: TrackSelection,
+    /// Last timestamp per `(media type, track id)`.
+    last_timestamps: HashMap<(FlvTagType, u8), u32>,
+    seen_multitrack_audio: bool,
+    seen_multitrack_video: bool,
+    dropped_tags: u64,
+    clamped_tags: u64,
+}
+
+impl TrackSelectOperator {
+    /// Create a new TrackSelectOperator
+    pub fn new(context: Arc<StreamerContext>, selection: TrackSelection) -> Self {
+        Self {
+            context,
+            selection,
+            last_timestamps: HashMap::new(),
+            seen_multitrack_audio: false,
+            seen_multitrack_video: false,
+            dropped_tags: 0,
+            clamped_tags: 0,
+        }
+    }
+
+    /// Reset the operator state
+    pub fn reset(&mut self) {
+        self.last_timestamps.clear();
+        self.seen_multitrack_audio = false;
+        self.seen_multitrack_video = false;
+    }
+
+    fn seen_multitrack(&self, tag_type: FlvTagType) -> bool {
+        match tag_type {
+            FlvTagType::Audio => self.seen_multitrack_audio,
+            FlvTagType::Video => self.seen_multitrack_video,
+            _ => false,
+        }
+    }
+
+    fn mark_multitrack(&mut self, tag_type: FlvTagType) {
+        let seen = match tag_type {
+            FlvTagType::Audio => &mut self.seen_multitrack_audio,
+            FlvTagType::Video => &mut self.seen_multitrack_video,
+            _ => return,
+        };
+        if !*seen {
+            *seen = true;
+            info!(
+                "{} Multitrack {:?} detected, track selection: {:?}",
+                self.context.name, tag_type, self.selection
+            );
+        }
+    }
+
+    /// Clamp `tag` so it does not go back in time on any of `tracks`, then record it.
+    fn enforce_continuity(&mut self, tag: &mut FlvTag, tracks: &[u8]) {
+        let tag_type = tag.tag_type();
+        let floor = tracks
+            .iter()
+            .filter_map(|track| self.last_timestamps.get(&(tag_type, *track)))
+            .max()
+            .copied();
+
+        if let Some(floor) = floor
+            && tag.timestamp_ms < floor
+        {
+            debug!(
+                "{} Clamping {:?} track(s) {:?} timestamp {}ms to {}ms",
+                self.context.name, tag_type, tracks, tag.timestamp_ms, floor
+            );
+            tag.timestamp_ms = floor;
+            self.clamped_tags += 1;
+        }
+
+        for track in tracks {
+            self.last_timestamps
+                .insert((tag_type, *track), tag.timestamp_ms);
+        }
+    }
+
+    /// Returns the tag to forward, or `None` if it should be dropped.
+    fn select(&mut self, mut tag: FlvTag) -> Option<FlvTag> {
+        let tag_type = tag.tag_type();
+
+        let packet = match tag.multitrack() {
+            Ok(packet) => packet,
+            Err(e) => {
+                warn!(
+                    "{} Failed to parse multitrack {:?} tag at {}ms, passing through: {}",
+                    self.context.name, tag_type, tag.timestamp_ms, e
+                );
+                return Some(tag);
+            }
+        };
+
+        let Some(packet) = packet else {
+            // Default track
+            if let TrackSelection::Single(id) = self.selection
+                && id != DEFAULT_TRACK_ID
+                && self.seen_multitrack(tag_type)
+            {
+                return None;
+            }
+            if self.seen_multitrack(tag_type) {
+                self.enforce_continuity(&mut tag, &[DEFAULT_TRACK_ID]);
+            }
+            return Some(tag);
+        };
+
+        self.mark_multitrack(tag_type);
+
+        match self.selection {
+            TrackSelection::All => {
+                let tracks: Vec<u8> = packet.track_ids().collect();
+                self.enforce_continuity(&mut tag, &tracks);
+                Some(tag)
+            }
+            TrackSelection::Single(id) => {
+                let track = packet.track(id)?;
+                tag.set_data(packet.single_track_payload(track));
+                self.enforce_continuity(&mut tag, &[id]);
+                Some(tag)
+            }
+        }
+    }
+}
+
+impl Processor<FlvData> for TrackSelectOperator {
+    fn process(
+        &mut self,
+        context: &Arc<StreamerContext>,
+        input: FlvData,
+        output: &mut dyn FnMut(FlvData) -> Result<(), PipelineError>,
+    ) -> Result<(), PipelineError> {
+        if context.token.is_cancelled() {
+            return Err(PipelineError::Cancelled);
+        }
+        match input {
+            FlvData::Header(_) => {
+                self.reset();
+                output(input)
+            }
+            FlvData::Tag(tag) if tag.is_audio_tag() || tag.is_video_tag() => {
+                match self.select(tag) {
+                    Some(tag) => output(FlvData::Tag(tag)),
+                    None => {
+                        self.dropped_tags += 1;
+                        Ok(())
+                    }
+                }
+            }
+            _ => output(input),
+        }
+    }
+
+    fn finish(
+        &mut self,
+        _context: &Arc<StreamerContext>,
+        _output: &mut dyn FnMut(FlvData) -> Result<(), PipelineError>,
+    ) -> Result<(), PipelineError> {
+        if self.dropped_tags > 0 || self.clamped_tags > 0 {
+            info!(
+                "{} Track selection dropped {} tags and clamped {} timestamps",
+                self.context.name, self.dropped_tags, self.clamped_tags
+            );
+        }
+        Ok(())
+    }
+
+    fn name(&self) -> &'static str {
+        "TrackSelectOperator"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{create_test_header, create_test_tag, create_video_tag};
+    use pipeline_common::CancellationToken;
+
+    /// ManyTracks hvc1 coded frames carrying tracks 0 and 1.
+    fn two_track_video(timestamp: u32) -> FlvData {
+        create_test_tag(
+            FlvTagType::Video,
+            timestamp,
+            vec![
+                0x96, 0x11, b'h', b'v', b'c', b'1', //
+                0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0xA0, //
+                0x01, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0xB1,
+            ],
+        )
+    }
+
+    /// OneTrack hvc1 coded frames for `track`.
+    fn one_track_video(timestamp: u32, track: u8) -> FlvData {
+        create_test_tag(
+            FlvTagType::Video,
+            timestamp,
+            vec![
+                0xA6, 0x01, b'h', b'v', b'c', b'1', track, 0x00, 0x00, 0x00, track,
+            ],
+        )
+    }
+
+    fn run(selection: TrackSelection, input: Vec<FlvData>) -> Vec<FlvTag> {
+        let context = StreamerContext::arc_new(CancellationToken::new());
+        let mut operator = TrackSelectOperator::new(context.clone(), selection);
+        let mut output_items = Vec::new();
+        let mut output_fn = |item: FlvData| -> Result<(), PipelineError> {
+            output_items.push(item);
+            Ok(())
+        };
+
+        operator
+            .process(&context, create_test_header(), &mut output_fn)
+            .unwrap();
+        for item in input {
+            operator.process(&context, item, &mut output_fn).unwrap();
+        }
+        operator.finish(&context, &mut output_fn).unwrap();
+
+        output_items
+            .into_iter()
+            .filter_map(|item| match item {
+                FlvData::Tag(tag) => Some(tag),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn keeps_all_tracks_unchanged() {
+        let tags = run(
+            TrackSelection::All,
+            vec![two_track_video(0), create_video_tag(40, false)],
+        );
+        assert_eq!(tags.len(), 2);
+        assert!(tags[0].is_multitrack());
+    }
+
+    #[test]
+    fn selects_single_track_and_rewrites_payload() {
+        let tags = run(
+            TrackSelection::Single(1),
+            vec![
+                two_track_video(0),
+                one_track_video(40, 0),
+                one_track_video(40, 1),
+                // Default-track tag after multitrack was seen belongs to track 0
+                create_video_tag(80, false),
+            ],
+        );
+
+        assert_eq!(tags.len(), 2);
+        assert!(!tags[0].is_multitrack());
+        assert_eq!(
+            tags[0].data().as_ref(),
+            &[0x91, b'h', b'v', b'c', b'1', 0x00, 0x00, 0x00, 0xB1]
+        );
+        assert_eq!(
+            tags[1].data().as_ref(),
+            &[0xA1, b'h', b'v', b'c', b'1', 0, 0, 0, 1]
+        );
+    }
+
+    #[test]
+    fn non_default_selection_keeps_single_track_streams() {
+        let tags = run(
+            TrackSelection::Single(2),
+            vec![create_video_tag(0, true), create_video_tag(40, false)],
+        );
+        assert_eq!(tags.len(), 2);
+    }
+
+    #[test]
+    fn clamps_timestamps_per_track() {
+        let tags = run(
+            TrackSelection::All,
+            vec![
+                one_track_video(100, 0),
+                one_track_video(50, 1),
+                one_track_video(90, 0),
+                one_track_video(60, 1),
+            ],
+        );
+
+        let timestamps: Vec<_> = tags.iter().map(|tag| tag.timestamp_ms).collect();
+        // Track 1 starting earlier than track 0 is not a regression; track 0 going back is.
+        assert_eq!(timestamps, vec![100, 50, 100, 60]);
+    }
+}
@@ -6,28 +6,37 @@
 //!
 //! ## Pipeline Architecture
 //!
-//! Input → Defragment → HeaderCheck → Split → GopSort → TimeConsistency →
-//!        TimingRepair → Limit → TimeConsistency2 → ScriptKeyframesFiller → ScriptFilter → Output
+//! Input → Defragment → HeaderCheck → (CorruptTag) → (AudioOnly) → TrackSelect → (GopIntegrity) → Split → GopSort → TimeConsistency →
+//!        (GapFill) → TimingRepair → Limit → TimeConsistency2 → ScriptKeyframesFiller → ScriptFilter → Output
 //!
 //! Each operator addresses specific issues that can occur in FLV streams:
 //!
 //! - **Defragment**: Handles fragmented streams by buffering and validating segments
 //! - **HeaderCheck**: Ensures streams begin with a valid FLV header
+//! - **CorruptTag**: Drops tags with impossible payloads or isolated timestamp jumps
+//! - **AudioOnly**: Optionally drops video and rewrites the header and metadata for audio-only output
+//! - **TrackSelect**: Keeps all E-RTMP multitrack tracks or selects a single one
+//! - **GopIntegrity**: Optionally removes GOPs without a leading keyframe after reconnects
 //! - **Split**: Divides content at appropriate points for better playability
 //! - **GopSort**: Ensures video tags are properly ordered by GOP (Group of Pictures)
 //! - **TimeConsistency**: Maintains consistent timestamps throughout the stream
+//! - **GapFill**: Optionally keeps or fills large timestamp gaps caused by CDN stalls
 //! - **TimingRepair**: Fixes timestamp anomalies like negative values or jumps
 //! - **Limit**: Enforces file size and duration limits
 //! - **ScriptKeyframesFiller**: Prepares metadata for proper seeking by adding keyframe placeholders
 //! - **ScriptFilter**: Removes or modifies problematic script tags
 
 use crate::operators::{
-    ContinuityMode, DefragmentOperator, DuplicateTagFilterConfig, DuplicateTagFilterOperator,
-    GopSortOperator, HeaderCheckOperator, LimitConfig, LimitOperator,
-    MIN_INTERVAL_BETWEEN_KEYFRAMES_MS, RepairStrategy, ScriptFillerConfig, ScriptFilterOperator,
-    ScriptKeyframesFillerOperator, SequenceHeaderChangeMode, SplitOperator,
-    TimeConsistencyOperator, TimingRepairConfig, TimingRepairOperator,
+    AudioOnlyOperator, ContinuityMode, CorruptTagConfig, CorruptTagOperator, DefragmentOperator,
+    DuplicateTagFilterConfig, DuplicateTagFilterOperator, GapFillConfig, GapFillOperator,
+    GopIntegrityConfig, GopIntegrityOperator, GopSortOperator, HeaderCheckOperator, LimitConfig,
+    LimitOperator, MIN_INTERVAL_BETWEEN_KEYFRAMES_MS, RelaxedRepair, RepairStrategy,
+    ScriptFillerConfig, ScriptFilterOperator, ScriptKeyframesFillerOperator,
+    SequenceHeaderChangeMode, SplitOperator, SplitPredicateFactory, TimeConsistencyOperator,
+    TimingRepairConfig, TimingRepairOperator, TrackSelectOperator, TrackSelection,
 };
+use crate::report::RepairCounters;
+use crate::script_modifier::CustomMetadata;
 use flv::data::FlvData;
 use flv::error::FlvError;
 use futures::stream::Stream;
@@ -52,6 +61,9 @@ pub struct FlvPipelineConfig {
     /// How to detect audio/video sequence-header changes that trigger a split.
     pub sequence_header_change_mode: SequenceHeaderChangeMode,
 
+    /// Extra split conditions; each pipeline gets fresh predicates from these factories.
+    pub split_predicates: Vec<SplitPredicateFactory>,
+
     /// Whether to drop semantically duplicate audio/video sequence headers.
     ///
     /// When enabled, the pipeline will suppress repeated AAC/AVC/HEVC sequence
@@ -60,8 +72,17 @@ pub struct FlvPipelineConfig {
     /// reduce "mid-stream join" friendliness for live pipelines.
     pub drop_duplicate_sequence_headers: bool,
 
+    /// Gap handling for CDN stalls; gaps are collapsed by timing repair when unset
+    pub gap_fill_config: Option<GapFillConfig>,
+
+    /// Detection of corrupt tags; corrupt tags are passed through when unset
+    pub corrupt_tag_config: Option<CorruptTagConfig>,
+
+    /// Handling of incomplete leading GOPs after reconnects; they are kept when unset
+    pub gop_integrity_config: Option<GopIntegrityConfig>,
+
     /// Strategy for timestamp repair
-    pub repair_strategy: RepairStrategy,
+    pub repair_strategy: Arc<dyn RepairStrategy>,
 
     /// Mode for timeline continuity
     pub continuity_mode: ContinuityMode,
@@ -69,6 +90,29 @@ pub struct FlvPipelineConfig {
     /// Configuration for keyframe index injection
     pub keyframe_index_config: Option<ScriptFillerConfig>,
 
+    /// Which tracks of an E-RTMP multitrack stream to write.
+    pub track_selection: TrackSelection,
+
+    /// Whether to drop video tags and write an audio-only FLV.
+    pub audio_only: bool,
+
+    /// Counters of tags dropped or deduplicated by the pipeline.
+    ///
+    /// Clones of the config share these counters; pass them to `FlvWriter::set_repair_counters`
+    /// to include per-segment repair counts in analysis reports.
+    pub repair_counters: RepairCounters,
+
+    /// Custom `onMetaData` properties written when segments are finalized.
+    ///
+    /// Pass them to `FlvWriter::set_custom_metadata`; they are not applied in pipe mode.
+    pub custom_metadata: CustomMetadata,
+
+    /// Run the pipeline as three stages on separate tasks when spawned: parsing and splitting,
+    /// GOP sorting and duplicate filtering, then timing repair and metadata.
+    ///
+    /// Helps high-bitrate streams that saturate a single core, at the cost of extra hand-offs.
+    pub parallel_stages: bool,
+
     /// Retained for configuration compatibility; metadata patching is always layout-stable.
     pub enable_low_latency: bool,
 
@@ -81,10 +125,19 @@ impl Default for FlvPipelineConfig {
             duplicate_tag_filtering: true,
             duplicate_tag_filter_config: DuplicateTagFilterConfig::default(),
             sequence_header_change_mode: SequenceHeaderChangeMode::Crc32,
+            split_predicates: Vec::new(),
             drop_duplicate_sequence_headers: false,
-            repair_strategy: RepairStrategy::Relaxed,
+            gap_fill_config: None,
+            corrupt_tag_config: Some(CorruptTagConfig::default()),
+            gop_integrity_config: None,
+            repair_strategy: Arc::new(RelaxedRepair),
             continuity_mode: ContinuityMode::Reset,
             keyframe_index_config: Some(ScriptFillerConfig::default()),
+            track_selection: TrackSelection::All,
+            audio_only: false,
+            repair_counters: RepairCounters::default(),
+            custom_metadata: CustomMetadata::default(),
+            parallel_stages: false,
             enable_low_latency: true,
             pipe_mode: false,
         }
@@ -98,10 +151,15 @@ impl FlvPipelineConfig {
     }
 
     fn timing_repair_config(&self) -> TimingRepairConfig {
-        TimingRepairConfig {
-            strategy: self.repair_strategy,
+        let mut config = TimingRepairConfig {
+            strategy: Arc::clone(&self.repair_strategy),
             ..TimingRepairConfig::default()
+        };
+        // Gaps kept by the gap filler must not be collapsed again
+        if let Some(gap_fill) = &self.gap_fill_config {
+            config.max_discontinuity = config.max_discontinuity.max(gap_fill.max_gap_ms);
         }
+        config
     }
 }
 
@@ -137,6 +195,11 @@ impl FlvPipelineConfigBuilder {
         self
     }
 
+    pub fn split_predicate(mut self, split_predicate: SplitPredicateFactory) -> Self {
+        self.config.split_predicates.push(split_predicate);
+        self
+    }
+
     pub fn drop_duplicate_sequence_headers(
         mut self,
         drop_duplicate_sequence_headers: bool,
@@ -145,7 +208,25 @@ impl FlvPipelineConfigBuilder {
         self
     }
 
-    pub fn repair_strategy(mut self, repair_strategy: RepairStrategy) -> Self {
+    pub fn gap_fill_config(mut self, gap_fill_config: Option<GapFillConfig>) -> Self {
+        self.config.gap_fill_config = gap_fill_config;
+        self
+    }
+
+    pub fn corrupt_tag_config(mut self, corrupt_tag_config: Option<CorruptTagConfig>) -> Self {
+        self.config.corrupt_tag_config = corrupt_tag_config;
+        self
+    }
+
+    pub fn gop_integrity_config(
+        mut self,
+        gop_integrity_config: Option<GopIntegrityConfig>,
+    ) -> Self {
+        self.config.gop_integrity_config = gop_integrity_config;
+        self
+    }
+
+    pub fn repair_strategy(mut self, repair_strategy: Arc<dyn RepairStrategy>) -> Self {
         self.config.repair_strategy = repair_strategy;
         self
     }
@@ -163,6 +244,31 @@ impl FlvPipelineConfigBuilder {
         self
     }
 
+    pub fn track_selection(mut self, track_selection: TrackSelection) -> Self {
+        self.config.track_selection = track_selection;
+        self
+    }
+
+    pub fn audio_only(mut self, audio_only: bool) -> Self {
+        self.config.audio_only = audio_only;
+        self
+    }
+
+    pub fn repair_counters(mut self, repair_counters: RepairCounters) -> Self {
+        self.config.repair_counters = repair_counters;
+        self
+    }
+
+    pub fn custom_metadata(mut self, custom_metadata: CustomMetadata) -> Self {
+        self.config.custom_metadata = custom_metadata;
+        self
+    }
+
+    pub fn parallel_stages(mut self, parallel_stages: bool) -> Self {
+        self.config.parallel_stages = parallel_stages;
+        self
+    }
+
     pub fn enable_low_latency(mut self, enable_low_latency: bool) -> Self {
         self.config.enable_low_latency = enable_low_latency;
         self
@@ -215,8 +321,26 @@ impl PipelineProvider for FlvPipeline {
         let config = self.config.clone();
 
         // Create all operators with adapters
-        let defrag_operator = DefragmentOperator::new(context.clone());
+        let defrag_operator = DefragmentOperator::new(context.clone())
+            .with_repair_counters(config.repair_counters.clone());
         let header_check_operator = HeaderCheckOperator::new(context.clone(), true, true);
+        let corrupt_tag_operator = config.corrupt_tag_config.clone().map(|corrupt_tag_config| {
+            CorruptTagOperator::new(context.clone(), corrupt_tag_config)
+                .with_repair_counters(config.repair_counters.clone())
+        });
+        let audio_only_operator = config
+            .audio_only
+            .then(|| AudioOnlyOperator::new(context.clone()));
+        let track_select_operator =
+            TrackSelectOperator::new(context.clone(), config.track_selection);
+        let gop_integrity_operator =
+            config
+                .gop_integrity_config
+                .clone()
+                .map(|gop_integrity_config| {
+                    GopIntegrityOperator::new(context.clone(), gop_integrity_config)
+                        .with_repair_counters(config.repair_counters.clone())
+                });
 
         // Configure the limit operator
         let max_duration_ms = self
@@ -230,6 +354,7 @@ impl PipelineProvider for FlvPipeline {
                 None
             },
             max_duration_ms,
+            wall_clock_split: self.common_config.wall_clock_split,
             split_at_keyframes_only: true,
             on_split: None,
         };
@@ -243,18 +368,32 @@ impl PipelineProvider for FlvPipeline {
             context.clone(),
             config.sequence_header_change_mode,
             config.drop_duplicate_sequence_headers,
+        )
+        .with_repair_counters(config.repair_counters.clone())
+        .with_predicates(
+            config
+                .split_predicates
+                .iter()
+                .map(SplitPredicateFactory::create),
         );
 
         let duplicate_tag_filter_operator = if config.duplicate_tag_filtering {
-            Some(DuplicateTagFilterOperator::with_config(
-                context.clone(),
-                config.duplicate_tag_filter_config.clone(),
-            ))
+            Some(
+                DuplicateTagFilterOperator::with_config(
+                    context.clone(),
+                    config.duplicate_tag_filter_config.clone(),
+                )
+                .with_repair_counters(config.repair_counters.clone()),
+            )
         } else {
             None
         };
         let time_consistency_operator =
             TimeConsistencyOperator::new(context.clone(), config.continuity_mode);
+        let gap_fill_operator = config
+            .gap_fill_config
+            .clone()
+            .map(|gap_fill_config| GapFillOperator::new(context.clone(), gap_fill_config));
         let time_consistency_operator_2 =
             TimeConsistencyOperator::new(context.clone(), config.continuity_mode);
 
@@ -285,16 +424,45 @@ impl PipelineProvider for FlvPipeline {
         // Build the synchronous pipeline
         let mut sync_pipeline = pipeline_common::Pipeline::new(context.clone())
             .add_processor(defrag_operator)
-            .add_processor(header_check_operator)
-            .add_processor(split_operator)
-            .add_processor(gop_sort_operator);
+            .add_processor(header_check_operator);
+
+        if let Some(op) = corrupt_tag_operator {
+            sync_pipeline = sync_pipeline.add_processor(op);
+        }
+
+        if let Some(op) = audio_only_operator {
+            sync_pipeline = sync_pipeline.add_processor(op);
+        }
+
+        sync_pipeline = sync_pipeline.add_processor(track_select_operator);
+
+        if let Some(op) = gop_integrity_operator {
+            sync_pipeline = sync_pipeline.add_processor(op);
+        }
+
+        sync_pipeline = sync_pipeline.add_processor(split_operator);
+
+        if config.parallel_stages {
+            sync_pipeline = sync_pipeline.add_stage_boundary();
+        }
+
+        sync_pipeline = sync_pipeline.add_processor(gop_sort_operator);
 
         if let Some(op) = duplicate_tag_filter_operator {
             sync_pipeline = sync_pipeline.add_processor(op);
         }
 
+        if config.parallel_stages {
+            sync_pipeline = sync_pipeline.add_stage_boundary();
+        }
+
+        sync_pipeline = sync_pipeline.add_processor(time_consistency_operator);
+
+        if let Some(op) = gap_fill_operator {
+            sync_pipeline = sync_pipeline.add_processor(op);
+        }
+
         sync_pipeline = sync_pipeline
-            .add_processor(time_consistency_operator)
             .add_processor(timing_repair_operator)
             .add_processor(limit_operator)
             .add_processor(time_consistency_operator_2);
@@ -317,6 +485,7 @@ impl PipelineProvider for FlvPipeline {
 /// Tests for the FLV processing pipeline
 mod test {
     use super::*;
+    use crate::operators::StrictRepair;
     use crate::writer::FlvWriter;
     use crate::writer_task::FlvWriterConfig;
 
@@ -334,19 +503,46 @@ mod test {
     #[test]
     fn repair_strategy_defaults_to_relaxed_and_forwards_overrides() {
         let default_config = FlvPipelineConfig::default();
-        assert_eq!(default_config.repair_strategy, RepairStrategy::Relaxed);
         assert_eq!(
-            default_config.timing_repair_config().strategy,
-            RepairStrategy::Relaxed
+            format!("{:?}", default_config.repair_strategy),
+            "RelaxedRepair"
+        );
+        assert_eq!(
+            format!("{:?}", default_config.timing_repair_config().strategy),
+            "RelaxedRepair"
         );
 
         let strict_config = FlvPipelineConfig::builder()
-            .repair_strategy(RepairStrategy::Strict)
+            .repair_strategy(Arc::new(StrictRepair))
             .build();
         assert_eq!(
-            strict_config.timing_repair_config().strategy,
-            RepairStrategy::Strict
+            format!("{:?}", strict_config.timing_repair_config().strategy),
+            "StrictRepair"
         );
+
+        let gap_config = FlvPipelineConfig::builder()
+            .gap_fill_config(Some(GapFillConfig::default()))
+            .build();
+        assert_eq!(
+            gap_config.timing_repair_config().max_discontinuity,
+            GapFillConfig::default().max_gap_ms
+        );
+    }
+
+    #[test]
+    fn parallel_stages_split_the_pipeline_in_three() {
+        let context = StreamerContext::arc_new(CancellationToken::new());
+        let build = |parallel_stages| {
+            let config = FlvPipelineConfig::builder()
+                .parallel_stages(parallel_stages)
+                .build();
+            FlvPipeline::with_config(context.clone(), &PipelineConfig::default(), config)
+                .build_pipeline()
+                .stage_count()
+        };
+
+        assert_eq!(build(false), 1);
+        assert_eq!(build(true), 3);
     }
 
     #[tokio::test]
@@ -0,0 +1,385 @@
+//! # Analysis reports
+//!
+//! A structured, serializable summary of a written FLV segment, built from the analyzer's
+//! [`FlvStats`] and the pipeline's [`RepairCounters`].
+//!
+//! Reports are meant for users who want to see what happened to a recording: how many tags
+//! were written, the bitrate of each track, how regular the keyframes were, which timestamp
+//! anomalies remained and how many tags the pipeline dropped or deduplicated.
+//!
+//! ## License
+//!
+//! MIT License
+//!
+//! ## Authors
+//!
+//! - hua0512
+//!
+
+use flv::hevc::HdrInfo;
+use flv::tag::FlvTag;
+use media_types::StreamDescription;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::analyzer::FlvStats;
+use crate::chapters::ChapterMarker;
+
+/// Tag counts of a segment, by tag type.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct TagCounts {
+    pub total: u32,
+    pub video: u32,
+    pub audio: u32,
+    pub script: u32,
+}
+
+/// Average bitrates of a segment in kbit/s.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct Bitrates {
+    pub video_kbps: f32,
+    pub audio_kbps: f32,
+}
+
+/// Number of keyframe intervals that fall in `[interval_secs, interval_secs + 1)`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct KeyframeIntervalBucket {
+    pub interval_secs: u32,
+    pub count: u32,
+}
+
+/// HDR signalling of the video track.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct HdrMetadata {
+    /// `PQ` or `HLG`
+    pub transfer: &'static str,
+    pub colour_primaries: u8,
+    pub transfer_characteristics: u8,
+    pub matrix_coeffs: u8,
+    pub bit_depth: u8,
+    /// Mastering display in x265 `master-display` syntax
+    pub mastering_display: Option<String>,
+    pub max_cll: Option<u16>,
+    pub max_fall: Option<u16>,
+}
+
+impl From<HdrInfo> for HdrMetadata {
+    fn from(hdr: HdrInfo) -> Self {
+        Self {
+            transfer: hdr.transfer_name(),
+            colour_primaries: hdr.colour_primaries,
+            transfer_characteristics: hdr.transfer_characteristics,
+            matrix_coeffs: hdr.matrix_coeffs,
+            bit_depth: hdr.bit_depth,
+            mastering_display: hdr.mastering_display.map(|display| display.to_string()),
+            max_cll: hdr
+                .content_light_level
+                .map(|light_level| light_level.max_cll),
+            max_fall: hdr
+                .content_light_level
+                .map(|light_level| light_level.max_fall),
+        }
+    }
+}
+
+/// Timestamp irregularities still present in the written segment.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct TimestampAnomalies {
+    pub gap_count: u32,
+    pub gap_duration_ms: u32,
+    pub regressions: u32,
+}
+
+/// Tags removed by the pipeline while producing a segment.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct RepairCounts {
+    /// Tags discarded as unusable (e.g. incomplete fragments)
+    pub dropped_tags: u64,
+    /// Tags discarded as duplicates (e.g. replayed content, repeated sequence headers)
+    pub duplicated_tags: u64,
+    /// Duplicate media tags dropped by the duplicate-tag filter, by track
+    pub duplicated_video_tags: u64,
+    pub duplicated_audio_tags: u64,
+    /// Payload bytes of the dropped duplicate media tags
+    pub duplicated_bytes: u64,
+    /// Duplicate media tags recognized as a replay with shifted timestamps
+    pub replayed_tags: u64,
+    /// Leading GOPs without a keyframe removed after reconnects or codec changes
+    pub incomplete_gops: u64,
+    /// Tags dropped from those GOPs
+    pub incomplete_gop_tags: u64,
+    /// Tags dropped because their content was corrupt
+    pub corrupted_tags: u64,
+    /// Bytes of those tags, headers included
+    pub corrupted_bytes: u64,
+}
+
+impl RepairCounts {
+    fn saturating_sub(self, other: Self) -> Self {
+        Self {
+            dropped_tags: self.dropped_tags.saturating_sub(other.dropped_tags),
+            duplicated_tags: self.duplicated_tags.saturating_sub(other.duplicated_tags),
+            duplicated_video_tags: self
+                .duplicated_video_tags
+                .saturating_sub(other.duplicated_video_tags),
+            duplicated_audio_tags: self
+                .duplicated_audio_tags
+                .saturating_sub(other.duplicated_audio_tags),
+            duplicated_bytes: self.duplicated_bytes.saturating_sub(other.duplicated_bytes),
+            replayed_tags: self.replayed_tags.saturating_sub(other.replayed_tags),
+            incomplete_gops: self.incomplete_gops.saturating_sub(other.incomplete_gops),
+            incomplete_gop_tags: self
+                .incomplete_gop_tags
+                .saturating_sub(other.incomplete_gop_tags),
+            corrupted_tags: self.corrupted_tags.saturating_sub(other.corrupted_tags),
+            corrupted_bytes: self.corrupted_bytes.saturating_sub(other.corrupted_bytes),
+        }
+    }
+}
+
+/// Pipeline-wide counters of removed tags.
+///
+/// Clones share the same counters, so operators can record into one clone while the writer
+/// reads another.
+#[derive(Debug, Clone, Default)]
+pub struct RepairCounters {
+    inner: Arc<RepairCountersInner>,
+}
+
+#[derive(Debug, Default)]
+struct RepairCountersInner {
+    dropped_tags: AtomicU64,
+    duplicated_tags: AtomicU64,
+    duplicated_video_tags: AtomicU64,
+    duplicated_audio_tags: AtomicU64,
+    duplicated_bytes: AtomicU64,
+    replayed_tags: AtomicU64,
+    incomplete_gops: AtomicU64,
+    incomplete_gop_tags: AtomicU64,
+    corrupted_tags: AtomicU64,
+    corrupted_bytes: AtomicU64,
+}
+
+impl RepairCounters {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record_dropped(&self, count: u64) {
+        self.inner.dropped_tags.fetch_add(count, Ordering::Relaxed);
+    }
+
+    pub fn record_duplicated(&self, count: u64) {
+        self.inner
+            .duplicated_tags
+            .fetch_add(count, Ordering::Relaxed);
+    }
+
+    /// Record the track and size of a dropped duplicate media tag.
+    ///
+    /// This only adds detail: the tag itself is counted through
+    /// [`record_duplicated`](Self::record_duplicated).
+    pub fn record_duplicate_media_tag(&self, tag: &FlvTag, replayed: bool) {
+        let inner = &self.inner;
+        if tag.is_video_tag() {
+            inner.duplicated_video_tags.fetch_add(1, Ordering::Relaxed);
+        } else if tag.is_audio_tag() {
+            inner.duplicated_audio_tags.fetch_add(1, Ordering::Relaxed);
+        }
+        inner
+            .duplicated_bytes
+            .fetch_add(tag.data().len() as u64, Ordering::Relaxed);
+        if replayed {
+            inner.replayed_tags.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Record an incomplete leading GOP and the number of its tags that were dropped.
+    ///
+    /// This only adds detail: the tags themselves are counted through
+    /// [`record_dropped`](Self::record_dropped).
+    pub fn record_incomplete_gop(&self, dropped_tags: u64) {
+        self.inner.incomplete_gops.fetch_add(1, Ordering::Relaxed);
+        self.inner
+            .incomplete_gop_tags
+            .fetch_add(dropped_tags, Ordering::Relaxed);
+    }
+
+    /// Record a tag dropped because its content was corrupt.
+    ///
+    /// This only adds detail: the tag itself is counted through
+    /// [`record_dropped`](Self::record_dropped).
+    pub fn record_corrupted_tag(&self, tag: &FlvTag) {
+        self.inner.corrupted_tags.fetch_add(1, Ordering::Relaxed);
+        self.inner
+            .corrupted_bytes
+            .fetch_add(tag.size() as u64, Ordering::Relaxed);
+    }
+
+    /// Current totals since the counters were created.
+    pub fn snapshot(&self) -> RepairCounts {
+        RepairCounts {
+            dropped_tags: self.inner.dropped_tags.load(Ordering::Relaxed),
+            duplicated_tags: self.inner.duplicated_tags.load(Ordering::Relaxed),
+            duplicated_video_tags: self.inner.duplicated_video_tags.load(Ordering::Relaxed),
+            duplicated_audio_tags: self.inner.duplicated_audio_tags.load(Ordering::Relaxed),
+            duplicated_bytes: self.inner.duplicated_bytes.load(Ordering::Relaxed),
+            replayed_tags: self.inner.replayed_tags.load(Ordering::Relaxed),
+            incomplete_gops: self.inner.incomplete_gops.load(Ordering::Relaxed),
+            incomplete_gop_tags: self.inner.incomplete_gop_tags.load(Ordering::Relaxed),
+            corrupted_tags: self.inner.corrupted_tags.load(Ordering::Relaxed),
+            corrupted_bytes: self.inner.corrupted_bytes.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Counts recorded since `start` was taken.
+    pub fn since(&self, start: RepairCounts) -> RepairCounts {
+        self.snapshot().saturating_sub(start)
+    }
+}
+
+/// Structured analysis of a written FLV segment.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct AnalysisReport {
+    pub duration_secs: u32,
+    pub file_size: u64,
+    pub has_video: bool,
+    pub has_audio: bool,
+    /// Codecs and their parameters
+    pub stream: StreamDescription,
+    pub tags: TagCounts,
+    pub bitrates: Bitrates,
+    /// Set when the video uses an HDR transfer function
+    pub hdr: Option<HdrMetadata>,
+    /// Encoder information from H.264 user data SEI
+    pub encoder_info: Option<String>,
+    /// Keyframe intervals rounded down to whole seconds, in ascending order
+    pub keyframe_intervals: Vec<KeyframeIntervalBucket>,
+    pub timestamp_anomalies: TimestampAnomalies,
+    pub repairs: RepairCounts,
+    /// Chapter markers placed in the segment, in media-time order
+    pub chapters: Vec<ChapterMarker>,
+}
+
+impl AnalysisReport {
+    /// Build a report from finalized analyzer stats (see `FlvAnalyzer::build_stats`).
+    pub fn from_stats(stats: &FlvStats, repairs: RepairCounts) -> Self {
+        let video_stats = stats.video_stats.as_ref();
+
+        let mut histogram = BTreeMap::new();
+        if let Some(video_stats) = video_stats {
+            for pair in video_stats.keyframes.windows(2) {
+                let interval_secs = (pair[1].timestamp_s - pair[0].timestamp_s).max(0.0) as u32;
+                *histogram.entry(interval_secs).or_insert(0) += 1;
+            }
+        }
+
+        Self {
+            duration_secs: stats.duration,
+            file_size: stats.file_size,
+            has_video: stats.has_video,
+            has_audio: stats.has_audio,
+            stream: stats.stream_description(),
+            tags: TagCounts {
+                total: stats.tag_count,
+                video: video_stats.map_or(0, |video_stats| video_stats.video_tag_count),
+                audio: stats.audio_tag_count,
+                script: stats.script_tag_count,
+            },
+            bitrates: Bitrates {
+                video_kbps: video_stats.map_or(0.0, |video_stats| video_stats.video_data_rate),
+                audio_kbps: stats.audio_data_rate,
+            },
+            hdr: video_stats
+                .and_then(|video_stats| video_stats.hdr)
+                .filter(HdrInfo::is_hdr)
+                .map(HdrMetadata::from),
+            encoder_info: video_stats.and_then(|video_stats| video_stats.encoder_info.clone()),
+            keyframe_intervals: histogram
+                .into_iter()
+                .map(|(interval_secs, count)| KeyframeIntervalBucket {
+                    interval_secs,
+                    count,
+                })
+                .collect(),
+            timestamp_anomalies: TimestampAnomalies {
+                gap_count: stats.gap_count,
+                gap_duration_ms: stats.gap_duration,
+                regressions: stats.timestamp_regressions,
+            },
+            repairs,
+            chapters: Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FlvAnalyzer;
+    use crate::test_utils::{create_audio_tag, create_video_tag};
+    use flv::data::FlvData;
+    use flv::header::FlvHeader;
+
+    #[test]
+    fn builds_report_from_analyzer_stats() {
+        let mut analyzer = FlvAnalyzer::default();
+        analyzer
+            .analyze_header(&FlvHeader::new(true, true))
+            .unwrap();
+        for item in [
+            create_video_tag(0, true),
+            create_audio_tag(0),
+            create_video_tag(2_000, true),
+            create_audio_tag(1_990),
+            create_video_tag(4_500, true),
+            create_audio_tag(1_900),
+            create_video_tag(9_000, true),
+        ] {
+            let FlvData::Tag(tag) = item else {
+                unreachable!()
+            };
+            analyzer.analyze_tag(&tag).unwrap();
+        }
+
+        let counters = RepairCounters::new();
+        let start = counters.snapshot();
+        counters.record_dropped(2);
+        counters.record_duplicated(3);
+
+        let stats = analyzer.build_stats().unwrap();
+        let report = AnalysisReport::from_stats(stats, counters.since(start));
+
+        assert_eq!(
+            report.tags,
+            TagCounts {
+                total: 7,
+                video: 4,
+                audio: 3,
+                script: 0,
+            }
+        );
+        assert_eq!(
+            report.keyframe_intervals,
+            vec![
+                KeyframeIntervalBucket {
+                    interval_secs: 2,
+                    count: 2,
+                },
+                KeyframeIntervalBucket {
+                    interval_secs: 4,
+                    count: 1,
+                },
+            ]
+        );
+        assert_eq!(report.timestamp_anomalies.regressions, 1);
+        assert_eq!(report.repairs.dropped_tags, 2);
+        assert_eq!(report.repairs.duplicated_tags, 3);
+
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["tags"]["video"], 4);
+        assert_eq!(json["keyframe_intervals"][1]["interval_secs"], 4);
+    }
+}
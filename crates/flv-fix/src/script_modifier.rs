@@ -19,22 +19,28 @@
 //!
 
 use std::{
+    borrow::Cow,
     fs,
     io::{self, BufReader, Read, Seek, Write},
     path::Path,
 };
 
+use amf0::Amf0Value;
 use flv::tag::FlvTagType;
 use tracing::{debug, trace, warn};
 
 use crate::{
+    METADATA_METADATACREATOR,
     amf::{
-        builder::{FixedSizeMetadataError, OnMetaDataBuilder},
+        builder::{FixedSizeMetadataError, OnMetaDataBuilder, is_managed_metadata_key},
         model::AmfScriptData,
     },
     analyzer::FlvStats,
 };
 
+/// Placeholder in [`CustomMetadata`] values, replaced by the file stem of the segment.
+pub const SEGMENT_PLACEHOLDER: &str = "{segment}";
+
 /// Error type for script modification operations
 #[derive(Debug, thiserror::Error)]
 pub enum ScriptModifierError {
@@ -48,6 +54,73 @@ pub enum ScriptModifierError {
     ScriptData(&'static str),
 }
 
+/// Custom string properties to add to `onMetaData`, such as the streamer name, the platform or
+/// a reference to the danmu file of the segment.
+///
+/// Properties keep their insertion order; inserting an existing key replaces its value. Values
+/// may contain [`SEGMENT_PLACEHOLDER`], e.g. `{segment}.xml`.
+///
+/// `metadatacreator` replaces the default creator string. Other properties managed by the
+/// metadata builder (`duration`, `keyframes`, ...) are ignored.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CustomMetadata {
+    properties: Vec<(String, String)>,
+}
+
+impl CustomMetadata {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a property, replacing any previous value for `key`.
+    pub fn insert(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        let key = key.into();
+        let value = value.into();
+        match self.properties.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, existing)) => *existing = value,
+            None => self.properties.push((key, value)),
+        }
+    }
+
+    /// Builder-style [`insert`](Self::insert).
+    pub fn with(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.insert(key, value);
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.properties.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.properties
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+    }
+
+    /// Write the properties into `data`, expanding placeholders for the segment at `file_path`.
+    pub fn apply_to(&self, data: &mut AmfScriptData, file_path: &Path) {
+        let segment = file_path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy())
+            .unwrap_or_default();
+        for (key, value) in &self.properties {
+            let value = value.replace(SEGMENT_PLACEHOLDER, &segment);
+            if key == METADATA_METADATACREATOR {
+                data.metadatacreator = Some(value);
+            } else if is_managed_metadata_key(key) {
+                warn!(
+                    key,
+                    "Ignoring custom metadata property managed by the writer"
+                );
+            } else {
+                data.custom_properties
+                    .insert(key.clone(), Amf0Value::String(Cow::Owned(value)));
+            }
+        }
+    }
+}
+
 /// Injects stats into the script data section of an FLV file.
 /// * `file_path` - The path to the FLV file.
 /// * `stats` - The statistics to inject into the script data section.
@@ -56,6 +129,24 @@ pub enum ScriptModifierError {
 pub fn inject_stats_into_script_data(
     file_path: &Path,
     stats: &FlvStats,
+    low_latency_metadata: bool,
+) -> Result<(), ScriptModifierError> {
+    inject_metadata_into_script_data(
+        file_path,
+        stats,
+        &CustomMetadata::default(),
+        low_latency_metadata,
+    )
+}
+
+/// Injects stats and custom properties into the script data section of an FLV file.
+///
+/// The metadata payload keeps its size, so custom properties are only written if they fit in
+/// the space reserved when the file was written; otherwise the script tag is left unchanged.
+pub fn inject_metadata_into_script_data(
+    file_path: &Path,
+    stats: &FlvStats,
+    custom_metadata: &CustomMetadata,
     _low_latency_metadata: bool,
 ) -> Result<(), ScriptModifierError> {
     debug!("Injecting stats into script data section.");
@@ -113,7 +204,8 @@ pub fn inject_stats_into_script_data(
     // Generate new script data buffer
     if let Some(props) = amf_data[0].as_object_properties() {
         // current script data model
-        let script_data_model = AmfScriptData::from_amf_object_ref(props)?;
+        let mut script_data_model = AmfScriptData::from_amf_object_ref(props)?;
+        custom_metadata.apply_to(&mut script_data_model, file_path);
 
         debug!("script data model: {script_data_model:?}");
 
@@ -122,6 +122,86 @@ pub fn create_video_sequence_header(timestamp: u32, version: u8) -> FlvData {
     create_test_tag(FlvTagType::Video, timestamp, data)
 }
 
+/// Create an Enhanced RTMP HEVC `SequenceStart` tag with specified version
+#[cfg(test)]
+pub fn create_enhanced_hevc_sequence_header(timestamp: u32, version: u8) -> FlvData {
+    let data = vec![
+        0x90, // ExHeader + Keyframe (1) + PacketTypeSequenceStart (0)
+        b'h', b'v', b'c', b'1',    // fourCC
+        0x01,    // HEVC configurationVersion
+        version, // hvcC data
+        0x60, 0x00,
+    ];
+    create_test_tag(FlvTagType::Video, timestamp, data)
+}
+
+/// Create an Enhanced RTMP HEVC coded-frames tag
+#[cfg(test)]
+pub fn create_enhanced_hevc_video_tag(timestamp: u32, is_keyframe: bool) -> FlvData {
+    let frame_type: u8 = if is_keyframe { 1 } else { 2 };
+    let data = vec![
+        0x80 | (frame_type << 4) | 0x01, // ExHeader + frame type + PacketTypeCodedFrames (1)
+        b'h',
+        b'v',
+        b'c',
+        b'1',
+        0x00,
+        0x00,
+        0x00, // composition time
+        0x00,
+        0x00,
+        0x00,
+        0x01, // NALU length
+        0x26,
+    ];
+    create_test_tag(FlvTagType::Video, timestamp, data)
+}
+
+/// Create an Enhanced RTMP AV1 `SequenceStart` tag with specified `seq_level_idx_0`
+#[cfg(test)]
+pub fn create_enhanced_av1_sequence_header(timestamp: u32, level: u8) -> FlvData {
+    create_enhanced_av1_sequence_header_with_delay(timestamp, level, 0)
+}
+
+/// Create an Enhanced RTMP AV1 `SequenceStart` tag with an `initial_presentation_delay` byte
+#[cfg(test)]
+pub fn create_enhanced_av1_sequence_header_with_delay(
+    timestamp: u32,
+    level: u8,
+    presentation_delay: u8,
+) -> FlvData {
+    let mut data = vec![
+        0x90, // ExHeader + Keyframe (1) + PacketTypeSequenceStart (0)
+        b'a',
+        b'v',
+        b'0',
+        b'1',               // fourCC
+        0x81,               // marker + version
+        level & 0x1F,       // seq_profile (0) + seq_level_idx_0
+        0x0C,               // 4:2:0 chroma subsampling
+        presentation_delay, // initial_presentation_delay
+    ];
+    // Sequence header OBU
+    data.extend_from_slice(b"\n\x0f\0\0\0j\xef\xbf\xe1\xbc\x02\x19\x90\x10\x10\x10@");
+    create_test_tag(FlvTagType::Video, timestamp, data)
+}
+
+/// Create an Enhanced RTMP AV1 coded-frames tag
+#[cfg(test)]
+pub fn create_enhanced_av1_video_tag(timestamp: u32, is_keyframe: bool) -> FlvData {
+    let frame_type: u8 = if is_keyframe { 1 } else { 2 };
+    let data = vec![
+        0x80 | (frame_type << 4) | 0x03, // ExHeader + frame type + PacketTypeCodedFramesX (3)
+        b'a',
+        b'v',
+        b'0',
+        b'1',
+        0x12, // temporal delimiter OBU
+        0x00,
+    ];
+    create_test_tag(FlvTagType::Video, timestamp, data)
+}
+
 /// Create an audio sequence header with specified version
 #[cfg(test)]
 pub fn create_audio_sequence_header(timestamp: u32, version: u8) -> FlvData {
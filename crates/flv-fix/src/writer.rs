@@ -2,9 +2,12 @@ use pipeline_common::{
     ProgressConfig, ProtocolWriter, SplitReason, WriterError, WriterProgress, WriterStats,
 };
 
+use crate::chapters::ChapterMarkers;
+use crate::report::{AnalysisReport, RepairCounters};
+use crate::script_modifier::CustomMetadata;
 use crate::writer_task::{FlvFormatStrategy, FlvWriterConfig};
 use flv::data::FlvData;
-use pipeline_common::{WriterConfig, WriterState, WriterTask};
+use pipeline_common::{SinkFactory, WriterConfig, WriterState, WriterTask};
 
 /// A specialized writer task for FLV data.
 pub struct FlvWriter {
@@ -41,6 +44,63 @@ impl FlvWriter {
         self.writer_task.set_on_file_close_callback(callback);
     }
 
+    /// Set a callback to be invoked with an analysis report when a segment is completed.
+    ///
+    /// The callback receives the file path, sequence number (0-based), and the report. It runs
+    /// before the segment-complete callback of the same segment.
+    pub fn set_on_segment_report_callback<F>(&mut self, callback: F)
+    where
+        F: Fn(&std::path::Path, u32, &AnalysisReport) + Send + Sync + 'static,
+    {
+        self.writer_task
+            .strategy_mut()
+            .set_on_report_callback(callback);
+    }
+
+    /// Include the pipeline's repair counters (see `FlvPipelineConfig::repair_counters`) in
+    /// segment analysis reports.
+    ///
+    /// Repairs are attributed to the segment that is open when they are recorded. Since the
+    /// pipeline runs slightly ahead of the writer, counts near a split may land in the
+    /// neighbouring segment.
+    pub fn set_repair_counters(&mut self, repair_counters: RepairCounters) {
+        self.writer_task
+            .strategy_mut()
+            .set_repair_counters(repair_counters);
+    }
+
+    /// Add custom properties (see `FlvPipelineConfig::custom_metadata`) to the `onMetaData`
+    /// tag of every segment.
+    pub fn set_custom_metadata(&mut self, custom_metadata: CustomMetadata) {
+        self.writer_task
+            .strategy_mut()
+            .set_custom_metadata(custom_metadata);
+    }
+
+    /// Write the keyframe index into the file every `interval` of media time while recording
+    /// (see `FlvFormatStrategy::set_metadata_checkpoint_interval`).
+    pub fn set_metadata_checkpoint_interval(&mut self, interval: Option<std::time::Duration>) {
+        self.writer_task
+            .strategy_mut()
+            .set_metadata_checkpoint_interval(interval);
+    }
+
+    /// Place markers from `chapter_markers` as chapters of the file being written
+    /// (see `FlvFormatStrategy::set_chapter_markers`).
+    pub fn set_chapter_markers(&mut self, chapter_markers: ChapterMarkers) {
+        self.writer_task
+            .strategy_mut()
+            .set_chapter_markers(chapter_markers);
+    }
+
+    /// Write each file to a sink from `sink_factory` instead of disk
+    /// (see `FlvFormatStrategy::set_output_sink`).
+    pub fn set_output_sink(&mut self, sink_factory: Option<SinkFactory>) {
+        self.writer_task
+            .strategy_mut()
+            .set_output_sink(sink_factory);
+    }
+
     /// Set a progress callback with default intervals (1MB bytes, 1000ms time).
     pub fn set_progress_callback<F>(&mut self, callback: F)
     where
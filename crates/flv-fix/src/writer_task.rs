@@ -4,18 +4,21 @@ use crate::{
         model::AmfScriptData,
     },
     analyzer::{AnalyzerError, FlvAnalyzer, FlvStats},
+    chapters::{ChapterMarker, ChapterMarkers},
+    report::{AnalysisReport, RepairCounters, RepairCounts},
+    script_modifier::CustomMetadata,
 };
 use bytes::Bytes;
 use flv::{FlvData, FlvHeader, FlvWriter, script::ScriptData};
 use pipeline_common::split_reason::SplitReason;
 use pipeline_common::{
-    FormatStrategy, PostWriteAction, WriterConfig, WriterState, expand_filename_template,
+    FormatStrategy, OutputSink, PostWriteAction, SinkFactory, WriterConfig, WriterState,
+    expand_filename_template,
 };
 use std::{
-    fs::OpenOptions,
     io::{BufWriter, Seek, Write},
     path::{Path, PathBuf},
-    time::Instant,
+    time::{Duration, Instant},
 };
 
 use tracing::{Span, info};
@@ -23,6 +26,13 @@ use tracing_indicatif::span_ext::IndicatifSpanExt;
 
 const METADATA_PATCH_RESERVATION_BYTES: usize = 256;
 
+/// Output buffer size. Tags at least this large (most video frames of high-bitrate streams)
+/// are written straight from their payload instead of being copied into the buffer first.
+const WRITE_BUFFER_SIZE: usize = 64 * 1024;
+
+/// Callback type for segment analysis reports (path, sequence_number, report).
+pub type SegmentReportCallback = Box<dyn Fn(&Path, u32, &AnalysisReport) + Send + Sync>;
+
 /// Error type for FLV strategy
 #[derive(Debug, thiserror::Error)]
 pub enum FlvStrategyError {
@@ -58,6 +68,21 @@ pub struct FlvFormatStrategy {
     /// The most recent split reason received, if any.
     last_split_reason: Option<SplitReason>,
     metadata_patch: Option<MetadataPatch>,
+    /// Pipeline repair counters and their values when the current file was opened.
+    repair_counters: Option<RepairCounters>,
+    repairs_at_open: RepairCounts,
+    on_report: Option<SegmentReportCallback>,
+    /// Custom `onMetaData` properties and the path they are expanded for.
+    custom_metadata: CustomMetadata,
+    current_path: PathBuf,
+    /// Media time between in-place metadata checkpoints, and the time of the last one.
+    metadata_checkpoint_interval_ms: Option<u32>,
+    last_checkpoint_ms: Option<u32>,
+    /// Opens the output of each file instead of creating it on disk.
+    sink_factory: Option<SinkFactory>,
+    /// Source of chapter markers, and the chapters placed in the current file.
+    chapter_markers: Option<ChapterMarkers>,
+    chapters: Vec<ChapterMarker>,
 }
 
 struct MetadataPatch {
@@ -79,9 +104,63 @@ impl FlvFormatStrategy {
             last_status_bytes: 0,
             last_split_reason: None,
             metadata_patch: None,
+            repair_counters: None,
+            repairs_at_open: RepairCounts::default(),
+            on_report: None,
+            custom_metadata: CustomMetadata::default(),
+            current_path: PathBuf::new(),
+            metadata_checkpoint_interval_ms: None,
+            last_checkpoint_ms: None,
+            sink_factory: None,
+            chapter_markers: None,
+            chapters: Vec::new(),
         }
     }
 
+    /// Place markers from `chapter_markers` as chapters of the file being written.
+    pub fn set_chapter_markers(&mut self, chapter_markers: ChapterMarkers) {
+        self.chapter_markers = Some(chapter_markers);
+    }
+
+    /// Write each file to a sink opened by `sink_factory` instead of creating it on disk.
+    ///
+    /// The factory is called with the path of every new file, so rotation and limits behave as
+    /// usual. Sinks cannot seek, so the final metadata patch and checkpoints are skipped and
+    /// the `onMetaData` tag keeps the values known when the file was opened.
+    pub fn set_output_sink(&mut self, sink_factory: Option<SinkFactory>) {
+        self.sink_factory = sink_factory;
+    }
+
+    /// Periodically write the keyframe index collected so far into the reserved `onMetaData`
+    /// space while a file is still being recorded.
+    ///
+    /// Each checkpoint is a single positioned write of the metadata payload, so files cut short
+    /// by a crash or power loss remain seekable. `None` disables checkpoints.
+    pub fn set_metadata_checkpoint_interval(&mut self, interval: Option<Duration>) {
+        self.metadata_checkpoint_interval_ms = interval
+            .filter(|interval| !interval.is_zero())
+            .map(|interval| u32::try_from(interval.as_millis()).unwrap_or(u32::MAX));
+    }
+
+    /// Add `custom_metadata` to the `onMetaData` tag of every file.
+    pub fn set_custom_metadata(&mut self, custom_metadata: CustomMetadata) {
+        self.custom_metadata = custom_metadata;
+    }
+
+    /// Use `repair_counters` for the repair counts of analysis reports.
+    pub fn set_repair_counters(&mut self, repair_counters: RepairCounters) {
+        self.repairs_at_open = repair_counters.snapshot();
+        self.repair_counters = Some(repair_counters);
+    }
+
+    /// Set a callback to receive an analysis report whenever a file is closed.
+    pub fn set_on_report_callback<F>(&mut self, callback: F)
+    where
+        F: Fn(&Path, u32, &AnalysisReport) + Send + Sync + 'static,
+    {
+        self.on_report = Some(Box::new(callback));
+    }
+
     fn calculate_duration(&self) -> u32 {
         self.analyzer.stats.calculate_duration()
     }
@@ -148,12 +227,18 @@ impl FlvFormatStrategy {
         else {
             return Ok(None);
         };
-        let Ok(model) = AmfScriptData::from_amf_object_ref(properties) else {
+        let Ok(mut model) = AmfScriptData::from_amf_object_ref(properties) else {
             return Ok(None);
         };
+        // Custom properties go into the written tag too, so the final patch has room for them
+        let has_custom_metadata = !self.custom_metadata.is_empty();
+        if has_custom_metadata {
+            self.custom_metadata
+                .apply_to(&mut model, &self.current_path);
+        }
 
         let include_keyframes = model.spacer_size.is_some() || model.keyframes.is_some();
-        let prepared_tag = if model.spacer_size.is_none() {
+        let prepared_tag = if model.spacer_size.is_none() || has_custom_metadata {
             let builder = OnMetaDataBuilder::from_script_data(model.clone());
             let (canonical, _) = builder
                 .clone()
@@ -184,12 +269,43 @@ impl FlvFormatStrategy {
         Ok(prepared_tag)
     }
 
+    /// Patch the metadata of the open file with the current stats, then return to its end.
+    fn checkpoint_metadata(
+        &mut self,
+        writer: &mut FlvWriter<BufWriter<OutputSink>>,
+    ) -> Result<(), FlvStrategyError> {
+        let Some(patch) = &self.metadata_patch else {
+            return Ok(());
+        };
+        if !writer.writer.get_ref().is_seekable() {
+            return Ok(());
+        }
+        let stats = self.analyzer.build_stats()?;
+        let metadata = match Self::build_final_metadata(patch, stats, &self.chapters) {
+            Ok(metadata) => metadata,
+            Err(FixedSizeMetadataError::TooLarge { .. }) => return Ok(()),
+            Err(error) => return Err(error.into()),
+        };
+        writer
+            .writer
+            .seek(std::io::SeekFrom::Start(patch.payload_offset))?;
+        writer.writer.write_all(&metadata.bytes)?;
+        writer.writer.seek(std::io::SeekFrom::End(0))?;
+        tracing::debug!(
+            keyframes = metadata.keyframes_written,
+            "Checkpointed FLV metadata"
+        );
+        Ok(())
+    }
+
     fn build_final_metadata(
-        patch: MetadataPatch,
+        patch: &MetadataPatch,
         stats: &FlvStats,
+        chapters: &[ChapterMarker],
     ) -> Result<crate::amf::builder::FixedSizeMetadata, FixedSizeMetadataError> {
         let include_keyframes = patch.include_keyframes;
-        let mut builder = OnMetaDataBuilder::from_script_data(patch.model).with_stats(stats);
+        let mut builder =
+            OnMetaDataBuilder::from_script_data(patch.model.clone()).with_stats(stats);
         if include_keyframes && let Some(video_stats) = &stats.video_stats {
             let (times, filepositions) = video_stats
                 .keyframes
@@ -198,21 +314,46 @@ impl FlvFormatStrategy {
                 .unzip();
             builder = builder.with_final_keyframes(times, filepositions);
         }
+        // Chapters, HDR and gap info are best-effort: only keep them if they fit without
+        // dropping keyframes, in that order of priority
+        let with_chapters =
+            (!chapters.is_empty()).then(|| builder.clone().with_cue_points(chapters));
+        let has_hdr = stats
+            .video_stats
+            .as_ref()
+            .and_then(|video_stats| video_stats.hdr)
+            .is_some_and(|hdr| hdr.is_hdr());
+        let with_hdr = has_hdr.then(|| {
+            with_chapters
+                .clone()
+                .unwrap_or_else(|| builder.clone())
+                .with_hdr_info(stats)
+        });
+        let with_gaps = (stats.gap_count > 0).then(|| {
+            with_hdr
+                .clone()
+                .or_else(|| with_chapters.clone())
+                .unwrap_or_else(|| builder.clone())
+                .with_gap_stats(stats)
+        });
+        for candidate in [with_gaps, with_hdr, with_chapters].into_iter().flatten() {
+            if let Ok(metadata) = candidate.build_fixed_size(patch.payload_size)
+                && !metadata.truncated
+            {
+                return Ok(metadata);
+            }
+        }
         builder.build_fixed_size(patch.payload_size)
     }
 }
 
 impl FormatStrategy<FlvData> for FlvFormatStrategy {
-    type Writer = FlvWriter<BufWriter<std::fs::File>>;
+    type Writer = FlvWriter<BufWriter<OutputSink>>;
     type StrategyError = FlvStrategyError;
 
     fn create_writer(&self, path: &Path) -> Result<Self::Writer, Self::StrategyError> {
-        let file = OpenOptions::new()
-            .write(true)
-            .create(true)
-            .truncate(true)
-            .open(path)?;
-        let buf_writer = BufWriter::with_capacity(1024 * 1024, file);
+        let sink = OutputSink::create(path, self.sink_factory.as_ref())?;
+        let buf_writer = BufWriter::with_capacity(WRITE_BUFFER_SIZE, sink);
         Ok(FlvWriter::new(buf_writer)?)
     }
 
@@ -255,6 +396,28 @@ impl FormatStrategy<FlvData> for FlvFormatStrategy {
 
                 writer.write_tag_f(tag)?;
                 bytes_written += (11 + 4 + tag.data().len()) as u64;
+
+                if !tag.is_script_tag()
+                    && let Some(chapter_markers) = &self.chapter_markers
+                {
+                    let time_s = tag.timestamp_ms as f64 / 1000.0;
+                    self.chapters.extend(
+                        chapter_markers
+                            .take_pending()
+                            .into_iter()
+                            .map(|title| ChapterMarker { time_s, title }),
+                    );
+                }
+
+                if let Some(interval) = self.metadata_checkpoint_interval_ms
+                    && !tag.is_script_tag()
+                {
+                    let last = *self.last_checkpoint_ms.get_or_insert(tag.timestamp_ms);
+                    if tag.timestamp_ms.saturating_sub(last) >= interval {
+                        self.last_checkpoint_ms = Some(tag.timestamp_ms);
+                        self.checkpoint_metadata(writer)?;
+                    }
+                }
                 Ok(bytes_written)
             }
             FlvData::Split(reason) => {
@@ -295,6 +458,12 @@ impl FormatStrategy<FlvData> for FlvFormatStrategy {
         self.last_status_bytes = 0;
         self.last_split_reason = None;
         self.metadata_patch = None;
+        self.current_path = path.to_path_buf();
+        self.last_checkpoint_ms = None;
+        self.chapters.clear();
+        if let Some(repair_counters) = &self.repair_counters {
+            self.repairs_at_open = repair_counters.snapshot();
+        }
 
         info!(path = %path.display(), "Opening segment");
 
@@ -311,7 +480,7 @@ impl FormatStrategy<FlvData> for FlvFormatStrategy {
         writer: &mut Self::Writer,
         path: &Path,
         _config: &WriterConfig,
-        _state: &WriterState,
+        state: &WriterState,
     ) -> Result<u64, Self::StrategyError> {
         writer.flush()?;
 
@@ -321,9 +490,11 @@ impl FormatStrategy<FlvData> for FlvFormatStrategy {
 
         if let Ok(stats) = analyzer.build_stats().cloned() {
             info!("Path : {}: {}", path.display(), &stats);
-            if let Some(patch) = self.metadata_patch.take() {
+            if let Some(patch) = self.metadata_patch.take()
+                && writer.writer.get_ref().is_seekable()
+            {
                 let payload_offset = patch.payload_offset;
-                match Self::build_final_metadata(patch, &stats) {
+                match Self::build_final_metadata(&patch, &stats, &self.chapters) {
                     Ok(metadata) => {
                         if metadata.truncated {
                             tracing::warn!(
@@ -336,7 +507,6 @@ impl FormatStrategy<FlvData> for FlvFormatStrategy {
                             .writer
                             .seek(std::io::SeekFrom::Start(payload_offset))?;
                         writer.writer.write_all(&metadata.bytes)?;
-                        writer.writer.flush()?;
                     }
                     Err(FixedSizeMetadataError::TooLarge { target, minimum }) => {
                         tracing::warn!(
@@ -350,6 +520,17 @@ impl FormatStrategy<FlvData> for FlvFormatStrategy {
                 }
             }
 
+            if let Some(on_report) = &self.on_report {
+                let repairs = self
+                    .repair_counters
+                    .as_ref()
+                    .map(|repair_counters| repair_counters.since(self.repairs_at_open))
+                    .unwrap_or_default();
+                let mut report = AnalysisReport::from_stats(&stats, repairs);
+                report.chapters = std::mem::take(&mut self.chapters);
+                on_report(path, state.file_sequence_number, &report);
+            }
+
             info!(
                 path = %path.display(),
                 tags = tag_count,
@@ -365,6 +546,9 @@ impl FormatStrategy<FlvData> for FlvFormatStrategy {
             );
         }
 
+        writer.writer.flush()?;
+        writer.writer.get_mut().finish()?;
+
         // Reset the analyzer and place it back into the strategy object for the next file segment.
         analyzer.reset();
         self.analyzer = analyzer;
@@ -520,6 +704,284 @@ mod tests {
         assert_eq!(has_audio, &Amf0Value::Boolean(true));
     }
 
+    #[test]
+    fn writer_reports_segment_analysis_with_repair_counts() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let mut writer = RecordingWriter::new(FlvWriterConfig {
+            output_dir: tempdir.path().to_path_buf(),
+            base_name: "segment-%i".to_string(),
+            enable_low_latency: true,
+        });
+        let repair_counters = RepairCounters::new();
+        repair_counters.record_duplicated(5);
+        writer.set_repair_counters(repair_counters.clone());
+        // Repairs made while the segment is open are attributed to it
+        writer.set_on_segment_start_callback(move |_, _| repair_counters.record_dropped(2));
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let callback_reports = Arc::clone(&reports);
+        writer.set_on_segment_report_callback(move |_, sequence, report| {
+            callback_reports
+                .lock()
+                .unwrap()
+                .push((sequence, report.clone()));
+        });
+        let (tx, rx) = tokio::sync::mpsc::channel::<Result<FlvData, PipelineError>>(8);
+
+        tx.blocking_send(Ok(FlvData::Header(FlvHeader::new(true, false))))
+            .unwrap();
+        tx.blocking_send(Ok(crate::test_utils::create_audio_tag(0)))
+            .unwrap();
+        tx.blocking_send(Ok(crate::test_utils::create_audio_tag(2_000)))
+            .unwrap();
+        drop(tx);
+
+        writer.run(rx.into()).unwrap();
+
+        let reports = reports.lock().unwrap();
+        assert_eq!(reports.len(), 1);
+        let (sequence, report) = &reports[0];
+        assert_eq!(*sequence, 0);
+        assert_eq!(report.tags.audio, 2);
+        assert_eq!(report.timestamp_anomalies.gap_count, 1);
+        assert_eq!(report.repairs.dropped_tags, 2);
+        assert_eq!(report.repairs.duplicated_tags, 0);
+    }
+
+    #[test]
+    fn checkpoints_keyframe_index_in_place_while_recording() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let path = tempdir.path().join("checkpoint.flv");
+        let config = WriterConfig::new(
+            tempdir.path().to_path_buf(),
+            "checkpoint".to_string(),
+            "flv".to_string(),
+        );
+        let state = WriterState::default();
+        let mut strategy = FlvFormatStrategy::new(true);
+        strategy.set_metadata_checkpoint_interval(Some(Duration::from_secs(3)));
+        let mut writer = strategy.create_writer(&path).unwrap();
+        strategy
+            .on_file_open(&mut writer, &path, &config, &state)
+            .unwrap();
+
+        let read_keyframe_times = || {
+            let mut reader = std::io::BufReader::new(std::fs::File::open(&path).unwrap());
+            reader.seek(std::io::SeekFrom::Start(13)).unwrap();
+            let (tag, _) = FlvParser::parse_tag(&mut reader).unwrap().unwrap();
+            let mut cursor = std::io::Cursor::new(tag.data().clone());
+            let script = ScriptData::demux(&mut cursor).unwrap();
+            let model =
+                AmfScriptData::from_amf_object_ref(script.data[0].as_object_properties().unwrap())
+                    .unwrap();
+            match model.keyframes {
+                Some(crate::amf::model::KeyframeData::Final { times, .. }) => times,
+                _ => Vec::new(),
+            }
+        };
+
+        let mut items = vec![
+            FlvData::Header(FlvHeader::new(true, false)),
+            crate::test_utils::create_script_tag(0, true),
+        ];
+        items.extend((0..=3).map(|i| crate::test_utils::create_video_tag(i * 2_000, true)));
+        let mut bytes_written = 0;
+        for item in &items {
+            bytes_written += strategy.write_item(&mut writer, item).unwrap();
+        }
+        writer.writer.flush().unwrap();
+
+        // The checkpoint at 4s indexes the keyframes up to then without moving the file tail
+        assert_eq!(read_keyframe_times(), vec![0.0, 2.0, 4.0]);
+        assert_eq!(std::fs::metadata(&path).unwrap().len(), bytes_written);
+
+        strategy
+            .on_file_close(&mut writer, &path, &config, &state)
+            .unwrap();
+        assert_eq!(read_keyframe_times(), vec![0.0, 2.0, 4.0, 6.0]);
+    }
+
+    #[test]
+    fn chapter_markers_are_written_as_cue_points() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let path = tempdir.path().join("chapters.flv");
+        let config = WriterConfig::new(
+            tempdir.path().to_path_buf(),
+            "chapters".to_string(),
+            "flv".to_string(),
+        );
+        let state = WriterState::default();
+        let markers = ChapterMarkers::new();
+        let reported = Arc::new(Mutex::new(None));
+        let report_slot = Arc::clone(&reported);
+        let mut strategy = FlvFormatStrategy::new(true);
+        strategy.set_chapter_markers(markers.clone());
+        strategy.set_on_report_callback(move |_, _, report| {
+            *report_slot.lock().unwrap() = Some(report.chapters.clone());
+        });
+        let mut writer = strategy.create_writer(&path).unwrap();
+        strategy
+            .on_file_open(&mut writer, &path, &config, &state)
+            .unwrap();
+
+        let mut items = vec![
+            FlvData::Header(FlvHeader::new(true, false)),
+            crate::test_utils::create_script_tag(0, true),
+        ];
+        items.extend((0..4).map(|i| crate::test_utils::create_video_tag(i * 1_000, true)));
+        for (i, item) in items.iter().enumerate() {
+            if i == 4 {
+                markers.mark("highlight");
+            }
+            strategy.write_item(&mut writer, item).unwrap();
+        }
+        strategy
+            .on_file_close(&mut writer, &path, &config, &state)
+            .unwrap();
+
+        let expected = vec![ChapterMarker {
+            time_s: 2.0,
+            title: "highlight".to_string(),
+        }];
+        assert_eq!(reported.lock().unwrap().as_ref(), Some(&expected));
+
+        let mut reader = std::io::BufReader::new(std::fs::File::open(&path).unwrap());
+        reader.seek(std::io::SeekFrom::Start(13)).unwrap();
+        let (tag, _) = FlvParser::parse_tag(&mut reader).unwrap().unwrap();
+        let mut cursor = std::io::Cursor::new(tag.data().clone());
+        let script = ScriptData::demux(&mut cursor).unwrap();
+        let properties = script.data[0].as_object_properties().unwrap();
+        let (_, cue_points) = properties
+            .iter()
+            .find(|(key, _)| key == crate::CUE_POINTS_KEY)
+            .unwrap();
+        assert_eq!(
+            cue_points,
+            &Amf0Value::StrictArray(vec![expected[0].to_cue_point()].into())
+        );
+    }
+
+    #[test]
+    fn writer_finalizes_custom_metadata() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let mut writer = RecordingWriter::new(FlvWriterConfig {
+            output_dir: tempdir.path().to_path_buf(),
+            base_name: "segment-%i".to_string(),
+            enable_low_latency: true,
+        });
+        writer.set_custom_metadata(
+            CustomMetadata::new()
+                .with("streamer", "someone")
+                .with("danmu", "{segment}.xml")
+                .with("metadatacreator", "rust-srec")
+                .with("duration", "ignored"),
+        );
+        let opened_path = Arc::new(Mutex::new(None));
+        let callback_path = Arc::clone(&opened_path);
+        writer.set_on_segment_start_callback(move |path, _| {
+            *callback_path.lock().unwrap() = Some(path.to_path_buf());
+        });
+        let (tx, rx) = tokio::sync::mpsc::channel::<Result<FlvData, PipelineError>>(8);
+
+        tx.blocking_send(Ok(FlvData::Header(FlvHeader::new(true, true))))
+            .unwrap();
+        tx.blocking_send(Ok(crate::test_utils::create_script_tag(0, true)))
+            .unwrap();
+        tx.blocking_send(Ok(crate::test_utils::create_video_tag(0, true)))
+            .unwrap();
+        tx.blocking_send(Ok(crate::test_utils::create_video_tag(1_000, true)))
+            .unwrap();
+        drop(tx);
+
+        writer.run(rx.into()).unwrap();
+
+        let path = opened_path.lock().unwrap().clone().unwrap();
+        let stem = path.file_stem().unwrap().to_string_lossy().into_owned();
+        let mut reader = std::io::BufReader::new(std::fs::File::open(path).unwrap());
+        reader.seek(std::io::SeekFrom::Start(13)).unwrap();
+        let (tag, _) = FlvParser::parse_tag(&mut reader).unwrap().unwrap();
+        let mut cursor = std::io::Cursor::new(tag.data().clone());
+        let script = ScriptData::demux(&mut cursor).unwrap();
+        let properties = script.data[0].as_object_properties().unwrap();
+        let value = |key: &str| {
+            properties
+                .iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, value)| value.clone())
+        };
+
+        assert_eq!(
+            value("streamer").and_then(|v| v.as_str().map(str::to_string)),
+            Some("someone".to_string())
+        );
+        assert_eq!(
+            value("danmu").and_then(|v| v.as_str().map(str::to_string)),
+            Some(format!("{stem}.xml"))
+        );
+        assert_eq!(
+            value("metadatacreator").and_then(|v| v.as_str().map(|s| s.trim_end().to_string())),
+            Some("rust-srec".to_string())
+        );
+        assert_eq!(value("duration"), Some(Amf0Value::Number(1.0)));
+        assert!(value("keyframes").is_some());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn writer_rotates_async_output_sinks() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let mut writer = RecordingWriter::new(FlvWriterConfig {
+            output_dir: tempdir.path().to_path_buf(),
+            base_name: "segment-%i".to_string(),
+            enable_low_latency: true,
+        });
+        let outputs = Arc::new(Mutex::new(Vec::new()));
+        let factory_outputs = Arc::clone(&outputs);
+        writer.set_output_sink(Some(SinkFactory::new(move |path| {
+            let (client, mut server) = tokio::io::duplex(4096);
+            let received = tokio::spawn(async move {
+                let mut received = Vec::new();
+                tokio::io::AsyncReadExt::read_to_end(&mut server, &mut received)
+                    .await
+                    .map(|_| received)
+            });
+            factory_outputs
+                .lock()
+                .unwrap()
+                .push((path.to_path_buf(), received));
+            Ok(Box::new(client) as pipeline_common::AsyncSink)
+        })));
+        let (tx, rx) = tokio::sync::mpsc::channel::<Result<FlvData, PipelineError>>(16);
+        for _ in 0..2 {
+            tx.send(Ok(FlvData::Header(FlvHeader::new(true, false))))
+                .await
+                .unwrap();
+            tx.send(Ok(crate::test_utils::create_script_tag(0, true)))
+                .await
+                .unwrap();
+            tx.send(Ok(crate::test_utils::create_video_tag(0, true)))
+                .await
+                .unwrap();
+        }
+        drop(tx);
+
+        tokio::task::spawn_blocking(move || writer.run(rx.into()))
+            .await
+            .unwrap()
+            .unwrap();
+
+        let outputs = std::mem::take(&mut *outputs.lock().unwrap());
+        assert_eq!(outputs.len(), 2);
+        for (path, received) in outputs {
+            // Nothing is written to disk, and every sink holds a complete FLV file
+            assert!(!path.exists());
+            let received = received.await.unwrap().unwrap();
+            assert!(received.starts_with(b"FLV"));
+            let mut reader = std::io::Cursor::new(received);
+            FlvParser::parse_header(&mut reader).unwrap();
+            let tags = FlvParser::parse_tags(&mut reader, |_, _, _| {}, 9).unwrap();
+            assert_eq!(tags, 2);
+        }
+    }
+
     #[test]
     fn writer_preserves_filtered_script_payload_even_when_it_is_parseable() {
         let tempdir = tempfile::tempdir().unwrap();
@@ -0,0 +1,108 @@
+use bytes::{Bytes, BytesMut};
+use criterion::{BatchSize, Criterion, Throughput, criterion_group, criterion_main};
+use flv::data::FlvData;
+use flv::header::FlvHeader;
+use flv::parser_async::FlvDecoder;
+use flv::tag::{FlvTag, FlvTagType};
+use flv::writer::FlvWriter;
+use std::hint::black_box;
+use std::io::{self, BufWriter, Seek, SeekFrom, Write};
+use tokio_util::codec::Decoder;
+
+/// About ten seconds of a 20 Mbps stream: 30 fps video and AAC audio.
+fn synthetic_stream() -> Bytes {
+    let mut writer = FlvWriter::new(io::Cursor::new(Vec::new())).unwrap();
+    writer.write_header(&FlvHeader::new(true, true)).unwrap();
+    for frame in 0..300u32 {
+        let keyframe = frame % 60 == 0;
+        let size = if keyframe { 400_000 } else { 80_000 };
+        let mut video = vec![0u8; size];
+        video[0] = if keyframe { 0x17 } else { 0x27 };
+        video[1] = 0x01;
+        writer
+            .write_tag(FlvTagType::Video, Bytes::from(video), frame * 33)
+            .unwrap();
+
+        let mut audio = vec![0u8; 400];
+        audio[0] = 0xAF;
+        audio[1] = 0x01;
+        writer
+            .write_tag(FlvTagType::Audio, Bytes::from(audio), frame * 33 + 10)
+            .unwrap();
+    }
+    Bytes::from(writer.writer.into_inner())
+}
+
+fn decode(stream: &Bytes) -> Vec<FlvTag> {
+    let mut decoder = FlvDecoder::default();
+    let mut buffer = BytesMut::from(&stream[..]);
+    let mut tags = Vec::new();
+    while let Some(item) = decoder.decode_eof(&mut buffer).unwrap() {
+        if let FlvData::Tag(tag) = item {
+            tags.push(tag);
+        }
+    }
+    tags
+}
+
+/// Discards everything written to it.
+struct NullSink;
+
+impl Write for NullSink {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Seek for NullSink {
+    fn seek(&mut self, _pos: SeekFrom) -> io::Result<u64> {
+        Ok(0)
+    }
+}
+
+fn zero_copy_benchmark(c: &mut Criterion) {
+    let stream = synthetic_stream();
+    let mut group = c.benchmark_group("ZeroCopy");
+    group.throughput(Throughput::Bytes(stream.len() as u64));
+
+    group.bench_function("decode", |b| {
+        b.iter_batched(
+            || BytesMut::from(&stream[..]),
+            |mut buffer| {
+                let mut decoder = FlvDecoder::default();
+                while let Some(item) = decoder.decode_eof(&mut buffer).unwrap() {
+                    black_box(item);
+                }
+            },
+            BatchSize::LargeInput,
+        )
+    });
+
+    let tags = decode(&stream);
+    // A buffer smaller than most video tags lets them bypass the copy into the buffer.
+    for (name, capacity) in [
+        ("write_64k_buffer", 64 * 1024),
+        ("write_1m_buffer", 1024 * 1024),
+    ] {
+        group.bench_function(name, |b| {
+            b.iter(|| {
+                let mut writer =
+                    FlvWriter::new(BufWriter::with_capacity(capacity, NullSink)).unwrap();
+                for tag in &tags {
+                    writer.write_tag_f(tag).unwrap();
+                }
+                writer.writer.flush().unwrap();
+                black_box(writer);
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, zero_copy_benchmark);
+criterion_main!(benches);
@@ -1,10 +1,18 @@
-// This
+//! AAC audio packets, including the ADTS and LOAS/LATM framings some
+//! encoders put inside FLV tags instead of raw access units.
 
 use std::io;
 
+use ::aac::{PartialAudioSpecificConfig, SampleFrequencyIndex};
 use bytes::Bytes;
+use bytes_util::BitReader;
 use std::fmt;
 
+/// ADTS sync word, the first 12 bits of every ADTS frame
+const ADTS_SYNC_WORD: u16 = 0xFFF;
+/// LOAS sync word, the first 11 bits of every `AudioSyncStream()` frame
+const LOAS_SYNC_WORD: u16 = 0x2B7;
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum AacPacketType {
     /// AAC Sequence Header
@@ -69,46 +77,272 @@ impl AacPacket {
         matches!(self, AacPacket::SequenceHeader(_))
     }
 
-    pub(crate) fn is_stereo(&self) -> bool {
+    /// The audio configuration of the packet.
+    ///
+    /// Sequence headers normally hold an AudioSpecificConfig, but some encoders
+    /// send ADTS or LOAS/LATM frames instead, which carry the configuration in
+    /// band. Raw packets only have one in those framings.
+    pub fn audio_config(&self) -> Option<PartialAudioSpecificConfig> {
+        let (data, is_sequence_header) = match self {
+            AacPacket::SequenceHeader(data) => (data, true),
+            AacPacket::Raw(data) => (data, false),
+            AacPacket::Unknown { .. } => return None,
+        };
+        if is_adts(data) {
+            parse_adts_config(data)
+        } else if LoasFrame::is_loas(data) {
+            LoasFrame::parse_all(data)
+                .into_iter()
+                .find_map(|frame| frame.config)
+        } else if is_sequence_header {
+            PartialAudioSpecificConfig::parse(data).ok()
+        } else {
+            None
+        }
+    }
+
+    /// Whether the packet uses LOAS/LATM framing.
+    pub fn is_loas(&self) -> bool {
         match self {
-            AacPacket::SequenceHeader(data)
-                if data.len() >= 2 && data[0] == 0xFF && data[1] == 0xF1 =>
-            {
-                let channel_config = (data[3] >> 3) & 0x0F;
-                channel_config == 2 // Stereo
+            AacPacket::SequenceHeader(data) | AacPacket::Raw(data) => LoasFrame::is_loas(data),
+            AacPacket::Unknown { .. } => false,
+        }
+    }
+
+    /// Number of output channels, if the configuration defines it.
+    pub fn channels(&self) -> Option<u8> {
+        self.audio_config()?.channel_count()
+    }
+
+    pub fn is_stereo(&self) -> bool {
+        self.channels().is_some_and(|channels| channels >= 2)
+    }
+
+    /// Sampling frequency in Hz, 44100 if unknown.
+    pub fn sample_rate(&self) -> f32 {
+        self.audio_config()
+            .map_or(44100.0, |config| config.sampling_frequency as f32)
+    }
+
+    /// AAC always decodes to 16-bit samples as far as FLV is concerned.
+    pub fn sample_size(&self) -> u32 {
+        16
+    }
+}
+
+fn is_adts(data: &[u8]) -> bool {
+    data.len() >= 7 && u16::from_be_bytes([data[0], data[1]]) >> 4 == ADTS_SYNC_WORD
+}
+
+/// The configuration in an ADTS fixed header. ISO/IEC 14496-3:2019(E) - 1.A.2.2.1
+fn parse_adts_config(data: &[u8]) -> Option<PartialAudioSpecificConfig> {
+    let profile = u16::from(data[2] >> 6);
+    let sampling_frequency = SampleFrequencyIndex::from_u8((data[2] >> 2) & 0x0F)?.to_freq()?;
+    Some(PartialAudioSpecificConfig {
+        audio_object_type: (profile + 1).into(),
+        sampling_frequency,
+        channel_configuration: ((data[2] & 0x01) << 2) | (data[3] >> 6),
+    })
+}
+
+/// `LatmGetValue()`. ISO/IEC 14496-3:2019(E) - 1.7.3 (Table 1.45)
+fn latm_get_value<R: io::Read>(reader: &mut BitReader<R>) -> io::Result<u64> {
+    let bytes_for_value = reader.read_bits(2)?;
+    let mut value = 0;
+    for _ in 0..=bytes_for_value {
+        value = (value << 8) | reader.read_bits(8)?;
+    }
+    Ok(value)
+}
+
+/// One `AudioMuxElement(1)` of a LOAS `AudioSyncStream()`.
+///
+/// Only the common layout of one program, one layer and one subframe per
+/// frame with byte-counted payloads (`frameLengthType` 0) yields payloads.
+///
+/// ISO/IEC 14496-3:2019(E) - 1.7.2 and 1.7.3
+#[derive(Debug, Clone, PartialEq)]
+pub struct LoasFrame {
+    /// The configuration from the frame's `StreamMuxConfig`, `None` when the
+    /// frame reuses the previous one (`useSameStreamMux`)
+    pub config: Option<PartialAudioSpecificConfig>,
+    /// The raw AAC access unit, empty when the mux layout is not supported
+    pub payload: Bytes,
+}
+
+impl LoasFrame {
+    /// Whether `data` starts with a LOAS sync word.
+    pub fn is_loas(data: &[u8]) -> bool {
+        data.len() >= 3 && u16::from_be_bytes([data[0], data[1]]) >> 5 == LOAS_SYNC_WORD
+    }
+
+    /// Split `data` into LOAS frames, stopping at the first byte that does
+    /// not start a complete frame. Frames that fail to parse are skipped.
+    pub fn parse_all(data: &[u8]) -> Vec<Self> {
+        let mut frames = Vec::new();
+        // Whether the last StreamMuxConfig has a layout we can take payloads from
+        let mut layout_supported = false;
+        let mut rest = data;
+        while Self::is_loas(rest) {
+            let length = usize::from(u16::from_be_bytes([rest[1], rest[2]]) & 0x1FFF);
+            let Some(element) = rest.get(3..3 + length) else {
+                break;
+            };
+            if let Ok(frame) = Self::parse_element(element, &mut layout_supported) {
+                frames.push(frame);
             }
-            _ => false,
+            rest = &rest[3 + length..];
         }
+        frames
     }
 
-    pub(crate) fn sample_rate(&self) -> f32 {
-        match self {
-            AacPacket::SequenceHeader(data)
-                if data.len() >= 2 && data[0] == 0xFF && data[1] == 0xF1 =>
-            {
-                let sample_rate_index = (data[2] >> 2) & 0x03;
-                match sample_rate_index {
-                    0 => 96000.0,
-                    1 => 88200.0,
-                    2 => 64000.0,
-                    3 => 48000.0,
-                    _ => 44100.0,
+    fn parse_element(element: &[u8], layout_supported: &mut bool) -> io::Result<Self> {
+        let mut reader = BitReader::new_from_slice(element);
+        let use_same_stream_mux = reader.read_bit()?;
+        let config = if use_same_stream_mux {
+            None
+        } else {
+            let (config, supported) = Self::parse_stream_mux_config(&mut reader)?;
+            *layout_supported = supported;
+            Some(config)
+        };
+
+        let mut payload = Vec::new();
+        if *layout_supported {
+            // PayloadLengthInfo()
+            let mut length = 0;
+            loop {
+                let tmp = reader.read_bits(8)? as usize;
+                length += tmp;
+                if tmp != 255 {
+                    break;
                 }
             }
-            _ => 44100.0,
+            payload.reserve(length);
+            for _ in 0..length {
+                payload.push(reader.read_bits(8)? as u8);
+            }
         }
+
+        Ok(Self {
+            config,
+            payload: Bytes::from(payload),
+        })
     }
 
-    pub(crate) fn sample_size(&self) -> u32 {
-        match self {
-            AacPacket::SequenceHeader(data)
-                if data.len() >= 2 && data[0] == 0xFF && data[1] == 0xF1 =>
-            {
-                let sample_size = (data[2] >> 4) & 0x0F;
-                sample_size as u32
+    /// `StreamMuxConfig()`, returning the configuration of the first stream
+    /// and whether the layout is the supported one.
+    ///
+    /// ISO/IEC 14496-3:2019(E) - 1.7.3 (Table 1.42)
+    fn parse_stream_mux_config<R: io::Read + io::Seek>(
+        reader: &mut BitReader<R>,
+    ) -> io::Result<(PartialAudioSpecificConfig, bool)> {
+        let audio_mux_version = reader.read_bit()?;
+        if audio_mux_version && reader.read_bit()? {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "Unsupported audioMuxVersionA",
+            ));
+        }
+        if audio_mux_version {
+            // taraBufferFullness
+            latm_get_value(reader)?;
+        }
+        let all_streams_same_time_framing = reader.read_bit()?;
+        let num_sub_frames = reader.read_bits(6)?;
+        let num_program = reader.read_bits(4)?;
+        let num_layer = reader.read_bits(3)?;
+        let mut supported = all_streams_same_time_framing
+            && num_sub_frames == 0
+            && num_program == 0
+            && num_layer == 0;
+
+        let config = if audio_mux_version {
+            let asc_len = latm_get_value(reader)?;
+            let start = reader.bit_stream_position()?;
+            let config = PartialAudioSpecificConfig::parse_bits(reader)?;
+            let consumed = reader.bit_stream_position()? - start;
+            reader.seek_bits(asc_len as i64 - consumed as i64)?;
+            config
+        } else {
+            let config = PartialAudioSpecificConfig::parse_bits(reader)?;
+            // Without a length, the rest of the AudioSpecificConfig has to be
+            // walked to find what follows it
+            supported &= Self::skip_audio_specific_config_rest(reader, &config)?;
+            config
+        };
+        if !supported {
+            return Ok((config, false));
+        }
+
+        let frame_length_type = reader.read_bits(3)?;
+        if frame_length_type != 0 {
+            return Ok((config, false));
+        }
+        // latmBufferFullness
+        reader.read_bits(8)?;
+        let other_data_present = reader.read_bit()?;
+        if other_data_present {
+            if audio_mux_version {
+                // otherDataLenBits
+                latm_get_value(reader)?;
+            } else {
+                loop {
+                    let other_data_len_esc = reader.read_bit()?;
+                    reader.read_bits(8)?;
+                    if !other_data_len_esc {
+                        break;
+                    }
+                }
             }
-            _ => 16,
         }
+        let crc_check_present = reader.read_bit()?;
+        if crc_check_present {
+            // crcCheckSum
+            reader.read_bits(8)?;
+        }
+        Ok((config, true))
+    }
+
+    /// Skip the fields of an AudioSpecificConfig after the channel
+    /// configuration. Returns `false` for object types whose specific config
+    /// is not understood, in which case the reader position is undefined.
+    ///
+    /// ISO/IEC 14496-3:2019(E) - 1.6.2.1 (Table 1.19) and 4.4.1 (Table 4.1)
+    fn skip_audio_specific_config_rest<R: io::Read>(
+        reader: &mut BitReader<R>,
+        config: &PartialAudioSpecificConfig,
+    ) -> io::Result<bool> {
+        let mut audio_object_type = config.audio_object_type.as_u16();
+        // Explicit SBR/PS signalling
+        if audio_object_type == 5 || audio_object_type == 29 {
+            // extensionSamplingFrequencyIndex
+            if reader.read_bits(4)? == 0xF {
+                reader.read_bits(24)?;
+            }
+            audio_object_type = reader.read_bits(5)? as u16;
+            if audio_object_type == 31 {
+                audio_object_type = 32 + reader.read_bits(6)? as u16;
+            }
+        }
+        // GASpecificConfig() of the non error resilient AAC object types,
+        // without a program_config_element()
+        if !(1..=4).contains(&audio_object_type) || config.channel_configuration == 0 {
+            return Ok(false);
+        }
+        // frameLengthFlag
+        reader.read_bit()?;
+        let depends_on_core_coder = reader.read_bit()?;
+        if depends_on_core_coder {
+            // coreCoderDelay
+            reader.read_bits(14)?;
+        }
+        let extension_flag = reader.read_bit()?;
+        if extension_flag {
+            // extensionFlag3
+            reader.read_bit()?;
+        }
+        Ok(true)
     }
 }
 
@@ -148,8 +382,88 @@ impl fmt::Display for AacPacketType {
 #[cfg(test)]
 #[cfg_attr(all(test, coverage_nightly), coverage(off))]
 mod tests {
+    use ::aac::AudioObjectType;
+    use bytes_util::BitWriter;
+
     use super::*;
 
+    fn loas(element: &[u8]) -> Vec<u8> {
+        let length = element.len() as u16;
+        let mut frame = vec![0x56, 0xE0 | (length >> 8) as u8, length as u8];
+        frame.extend_from_slice(element);
+        frame
+    }
+
+    #[test]
+    fn test_audio_config() {
+        // AudioSpecificConfig: AAC LC, 48 kHz, mono
+        let packet = AacPacket::SequenceHeader(Bytes::from_static(&[0x11, 0x88]));
+        let config = packet.audio_config().unwrap();
+        assert_eq!(config.audio_object_type, AudioObjectType::AacLowComplexity);
+        assert_eq!(packet.sample_rate(), 48000.0);
+        assert_eq!(packet.channels(), Some(1));
+        assert!(!packet.is_stereo());
+        assert!(!packet.is_loas());
+
+        // ADTS header: AAC LC, 44.1 kHz, stereo
+        let packet = AacPacket::Raw(Bytes::from_static(&[
+            0xFF, 0xF1, 0x50, 0x80, 0x02, 0x1F, 0xFC,
+        ]));
+        assert_eq!(packet.sample_rate(), 44100.0);
+        assert!(packet.is_stereo());
+
+        // Raw access units carry no configuration
+        let packet = AacPacket::Raw(Bytes::from_static(&[0x21, 0x10, 0x04]));
+        assert_eq!(packet.audio_config(), None);
+        assert_eq!(packet.sample_rate(), 44100.0);
+    }
+
+    #[test]
+    fn test_parse_loas() {
+        let mut element = Vec::new();
+        let mut writer = BitWriter::new(&mut element);
+        // useSameStreamMux, audioMuxVersion, allStreamsSameTimeFraming
+        writer.write_bit(false).unwrap();
+        writer.write_bit(false).unwrap();
+        writer.write_bit(true).unwrap();
+        // numSubFrames, numProgram, numLayer
+        writer.write_bits(0, 6 + 4 + 3).unwrap();
+        // AudioSpecificConfig: AAC LC, 48 kHz, stereo, GASpecificConfig
+        writer.write_bits(2, 5).unwrap();
+        writer.write_bits(3, 4).unwrap();
+        writer.write_bits(2, 4).unwrap();
+        writer.write_bits(0, 3).unwrap();
+        // frameLengthType, latmBufferFullness
+        writer.write_bits(0, 3).unwrap();
+        writer.write_bits(0xFF, 8).unwrap();
+        // otherDataPresent, crcCheckPresent
+        writer.write_bits(0, 2).unwrap();
+        // PayloadLengthInfo and payload
+        writer.write_bits(3, 8).unwrap();
+        for byte in [0xDE, 0xAD, 0xBE] {
+            writer.write_bits(byte, 8).unwrap();
+        }
+        writer.finish().unwrap();
+
+        let mut data = loas(&element);
+        // useSameStreamMux, then a 2 byte payload
+        data.extend(loas(&[0x81, 0x00, 0x81, 0x00]));
+
+        let frames = LoasFrame::parse_all(&data);
+        assert_eq!(frames.len(), 2);
+        let config = frames[0].config.unwrap();
+        assert_eq!(config.sampling_frequency, 48000);
+        assert_eq!(config.channel_count(), Some(2));
+        assert_eq!(frames[0].payload, Bytes::from_static(&[0xDE, 0xAD, 0xBE]));
+        assert_eq!(frames[1].config, None);
+        assert_eq!(frames[1].payload, Bytes::from_static(&[0x01, 0x02]));
+
+        let packet = AacPacket::Raw(Bytes::from(data));
+        assert!(packet.is_loas());
+        assert_eq!(packet.sample_rate(), 48000.0);
+        assert!(packet.is_stereo());
+    }
+
     #[test]
     fn test_new() {
         // Test AAC Sequence Header packet
@@ -24,9 +24,11 @@
 
 use std::{fmt, io};
 
+use ::aac::PartialAudioSpecificConfig;
 use byteorder::{BigEndian, ReadBytesExt};
 use bytes::Bytes;
 use bytes_util::BytesCursorExt;
+use media_types::AudioCodec;
 
 use super::aac::{AacPacket, AacPacketType};
 
@@ -81,6 +83,19 @@ impl TryFrom<u8> for SoundFormat {
     }
 }
 
+impl SoundFormat {
+    /// The shared codec of this sound format, if it has one.
+    ///
+    /// `ExHeader` carries its codec as an [`AudioFourCC`] instead.
+    pub fn audio_codec(&self) -> Option<AudioCodec> {
+        match self {
+            SoundFormat::Aac => Some(AudioCodec::Aac),
+            SoundFormat::Mp3 | SoundFormat::Mp38k => Some(AudioCodec::Mp3),
+            _ => None,
+        }
+    }
+}
+
 #[repr(u8)]
 #[derive(Debug, Clone, PartialEq, Copy)]
 pub enum SoundRate {
@@ -253,6 +268,23 @@ impl AudioDataBody {
         }
     }
 
+    /// AAC audio configuration, from the AudioSpecificConfig of a sequence
+    /// header or the in-band configuration of ADTS and LOAS/LATM framing.
+    pub fn audio_config(&self) -> Option<PartialAudioSpecificConfig> {
+        match self {
+            AudioDataBody::Aac(packet) => packet.audio_config(),
+            AudioDataBody::Unknown { .. } => None,
+        }
+    }
+
+    /// Number of output channels of AAC audio, if known.
+    pub fn channels(&self) -> Option<u8> {
+        match self {
+            AudioDataBody::Aac(packet) => packet.channels(),
+            AudioDataBody::Unknown { .. } => None,
+        }
+    }
+
     pub fn is_stereo(&self) -> bool {
         match self {
             AudioDataBody::Aac(packet) => packet.is_stereo(),
@@ -394,6 +426,19 @@ impl AudioFourCC {
     }
 }
 
+impl From<AudioFourCC> for AudioCodec {
+    fn from(fourcc: AudioFourCC) -> Self {
+        match fourcc {
+            AudioFourCC::Ac3 => AudioCodec::Ac3,
+            AudioFourCC::Eac3 => AudioCodec::Eac3,
+            AudioFourCC::Opus => AudioCodec::Opus,
+            AudioFourCC::Mp3 => AudioCodec::Mp3,
+            AudioFourCC::Flac => AudioCodec::Flac,
+            AudioFourCC::Aac => AudioCodec::Aac,
+        }
+    }
+}
+
 #[repr(u8)]
 #[derive(Debug, Clone, PartialEq, Copy)]
 pub enum AvMultitrackType {
@@ -516,15 +561,13 @@ impl AudioData {
                         if _final_audio_packet_type == AudioPacketType::Multitrack {
                             _is_audio_multitrack = true;
 
-                            // Read the multitrack type
-                            let multitrack_type_byte = reader.read_u8()?;
+                            // [audioMultitrackType:4][audioPacketType:4], the packet type
+                            // applies to all audio tracks in the audio message
+                            let multitrack_byte = reader.read_u8()?;
                             _audio_multitrack_type =
-                                AvMultitrackType::try_from(multitrack_type_byte & 0x0F)?;
-
-                            // Fetch AudioPacketType for all audio tracks in the audio message
-                            let packet_type_byte = reader.read_u8()?;
+                                AvMultitrackType::try_from(multitrack_byte >> 4)?;
                             let new_packet_type =
-                                AudioPacketType::try_from(packet_type_byte & 0x0F)?;
+                                AudioPacketType::try_from(multitrack_byte & 0x0F)?;
 
                             // Make sure it's not multitrack again
                             if new_packet_type == AudioPacketType::Multitrack {
@@ -1,6 +1,9 @@
 use av1::{AV1CodecConfigurationRecord, ObuHeader, seq::SequenceHeaderObu};
 use bytes::Bytes;
 
+use media_types::{StreamDescription, VideoCodec};
+
+use crate::hevc::{TRANSFER_HLG, TRANSFER_PQ};
 use crate::resolution::Resolution;
 
 /// AV1 Packet
@@ -24,6 +27,45 @@ impl Av1Packet {
     ///
     /// Returns `None` for non-`SequenceStart` variants or if parsing fails.
     pub fn get_video_resolution(&self) -> Option<Resolution> {
+        let seq = self.sequence_header()?;
+
+        Some(Resolution::new(
+            seq.max_frame_width as u32,
+            seq.max_frame_height as u32,
+        ))
+    }
+
+    /// Codec, profile, level, bit depth, resolution and HDR signalling of a
+    /// [`SequenceStart`](Av1Packet::SequenceStart) packet.
+    pub fn get_stream_description(&self) -> Option<StreamDescription> {
+        let Av1Packet::SequenceStart(config) = self else {
+            return None;
+        };
+        let seq = self.sequence_header();
+        let bit_depth = match (config.high_bitdepth, config.twelve_bit) {
+            (true, true) => 12,
+            (true, false) => 10,
+            _ => 8,
+        };
+
+        Some(StreamDescription {
+            video_codec: Some(VideoCodec::Av1),
+            profile: Some(config.seq_profile),
+            level: Some(config.seq_level_idx_0),
+            resolution: self.get_video_resolution(),
+            bit_depth: Some(bit_depth),
+            hdr: seq.is_some_and(|seq| {
+                matches!(
+                    seq.color_config.transfer_characteristics,
+                    TRANSFER_PQ | TRANSFER_HLG
+                )
+            }),
+            ..Default::default()
+        })
+    }
+
+    /// The sequence header OBU of the codec configuration record.
+    fn sequence_header(&self) -> Option<SequenceHeaderObu> {
         let config = match self {
             Av1Packet::SequenceStart(config) => config,
             _ => return None,
@@ -35,12 +77,7 @@ impl Av1Packet {
 
         let mut cursor = std::io::Cursor::new(config.config_obu.clone());
         let header = ObuHeader::parse(&mut cursor).ok()?;
-        let seq = SequenceHeaderObu::parse(header, &mut cursor).ok()?;
-
-        Some(Resolution {
-            width: seq.max_frame_width as f32,
-            height: seq.max_frame_height as f32,
-        })
+        SequenceHeaderObu::parse(header, &mut cursor).ok()
     }
 }
 
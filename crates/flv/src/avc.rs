@@ -1,9 +1,14 @@
-use std::io;
+use std::io::{self, Read};
 
 use byteorder::{BigEndian, ReadBytesExt};
 use bytes::Bytes;
-use bytes_util::BytesCursorExt;
-use h264::AVCDecoderConfigurationRecord;
+use bytes_util::{BitReader, BytesCursorExt};
+use expgolomb::BitReaderExpGolombExt;
+use h264::{
+    AVCDecoderConfigurationRecord, EmulationPreventionIo, NALUnitType, PicTimingInfo, TimingInfo,
+};
+
+use media_types::{StreamDescription, VideoCodec};
 
 use crate::resolution::Resolution;
 
@@ -60,10 +65,7 @@ impl AvcPacket {
                     Ok(sps) => {
                         let width = sps.width();
                         let height = sps.height();
-                        Some(Resolution {
-                            width: width as f32,
-                            height: height as f32,
-                        })
+                        Some(Resolution::new(width as u32, height as u32))
                     }
                     Err(_) => None,
                 }
@@ -72,6 +74,415 @@ impl AvcPacket {
             _ => None,
         }
     }
+
+    /// Codec, profile, level, bit depth and resolution of a sequence header.
+    pub fn get_stream_description(&self) -> Option<StreamDescription> {
+        let AvcPacket::SequenceHeader(config) = self else {
+            return None;
+        };
+        Some(StreamDescription {
+            video_codec: Some(VideoCodec::Avc),
+            profile: Some(config.profile_indication),
+            level: Some(config.level_indication),
+            resolution: self.get_video_resolution(),
+            // The extension is only present in the High profiles; others are 8-bit
+            bit_depth: Some(
+                config
+                    .extended_config
+                    .as_ref()
+                    .map_or(8, |ext| ext.bit_depth_luma_minus8 + 8),
+            ),
+            ..Default::default()
+        })
+    }
+
+    /// The SPS parameters needed to read the SEI messages of later frames.
+    pub fn get_sei_context(&self) -> Option<AvcSeiContext> {
+        match self {
+            AvcPacket::SequenceHeader(config) => Some(AvcSeiContext::from_config(config)),
+            _ => None,
+        }
+    }
+
+    /// The SEI messages of a NALU packet, in stream order.
+    pub fn sei_messages(&self, context: &AvcSeiContext) -> Vec<SeiMessage> {
+        match self {
+            AvcPacket::Nalu { data, .. } => context.parse_nalus(data),
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// SEI payload types. ISO/IEC-14496-10-2022 - D.1.1
+const SEI_BUFFERING_PERIOD: usize = 0;
+const SEI_PIC_TIMING: usize = 1;
+const SEI_USER_DATA_REGISTERED_ITU_T_T35: usize = 4;
+const SEI_USER_DATA_UNREGISTERED: usize = 5;
+
+/// The parts of an AVC sequence header that decide how SEI messages are read.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct AvcSeiContext {
+    /// Size of the NAL unit length prefixes of the frames
+    pub nalu_length_size: usize,
+    /// VUI `timing_info` of the first SPS
+    pub timing_info: Option<TimingInfo>,
+    /// VUI HRD parameters and `pic_struct_present_flag` of the first SPS
+    pub pic_timing_info: Option<PicTimingInfo>,
+}
+
+impl AvcSeiContext {
+    /// Read the context from the first SPS of a decoder configuration record.
+    pub fn from_config(config: &AVCDecoderConfigurationRecord) -> Self {
+        let mut context = Self {
+            nalu_length_size: usize::from(config.length_size_minus_one) + 1,
+            ..Default::default()
+        };
+        if let Some(sps) = config.sps.first()
+            && let Ok((sps, pic_timing_info)) =
+                h264::Sps::parse_pic_timing_info_with_emulation_prevention(io::Cursor::new(sps))
+        {
+            context.timing_info = sps.timing_info;
+            context.pic_timing_info = pic_timing_info;
+        }
+        context
+    }
+
+    /// Whether frames may carry picture timing SEI messages worth reading.
+    pub fn has_pic_timing(&self) -> bool {
+        self.pic_timing_info
+            .as_ref()
+            .is_some_and(|info| info.pic_struct_present_flag || info.cpb_dpb_delays().is_some())
+    }
+
+    /// Parse the SEI messages of a frame of length-prefixed NAL units.
+    pub fn parse_nalus(&self, data: &[u8]) -> Vec<SeiMessage> {
+        let length_size = self.nalu_length_size;
+        let mut messages = Vec::new();
+        let mut rest = data;
+        while rest.len() > length_size {
+            let length = rest[..length_size]
+                .iter()
+                .fold(0usize, |length, byte| (length << 8) | usize::from(*byte));
+            let Some(nal) = rest.get(length_size..length_size + length) else {
+                break;
+            };
+            messages.extend(self.parse_sei(nal));
+            rest = &rest[length_size + length..];
+        }
+        messages
+    }
+
+    /// Parse the messages of a SEI NAL unit. Returns nothing for other NAL
+    /// unit types; messages that fail to parse are skipped.
+    pub fn parse_sei(&self, nal: &[u8]) -> Vec<SeiMessage> {
+        let mut messages = Vec::new();
+        if nal.len() < 2 || NALUnitType::try_from(nal[0] & 0x1F).ok() != Some(NALUnitType::SEI) {
+            return messages;
+        }
+        let mut rbsp = Vec::with_capacity(nal.len());
+        if EmulationPreventionIo::new(&nal[1..])
+            .read_to_end(&mut rbsp)
+            .is_err()
+        {
+            return messages;
+        }
+
+        let mut i = 0;
+        // Stop at the rbsp_trailing_bits
+        while i + 2 <= rbsp.len() && rbsp[i] != 0x80 {
+            let mut read_value = || {
+                let mut value = 0;
+                while rbsp.get(i) == Some(&0xFF) {
+                    value += 255;
+                    i += 1;
+                }
+                let last = rbsp.get(i).copied()?;
+                i += 1;
+                Some(value + usize::from(last))
+            };
+            let (Some(payload_type), Some(payload_size)) = (read_value(), read_value()) else {
+                break;
+            };
+            let Some(payload) = rbsp.get(i..i + payload_size) else {
+                break;
+            };
+            let message = match payload_type {
+                SEI_BUFFERING_PERIOD => {
+                    BufferingPeriod::parse(payload, self).map(SeiMessage::BufferingPeriod)
+                }
+                SEI_PIC_TIMING => PicTiming::parse(payload, self).map(SeiMessage::PicTiming),
+                SEI_USER_DATA_REGISTERED_ITU_T_T35 => Ok(SeiMessage::UserDataRegistered(
+                    Bytes::copy_from_slice(payload),
+                )),
+                SEI_USER_DATA_UNREGISTERED => {
+                    UserDataUnregistered::parse(payload).map(SeiMessage::UserDataUnregistered)
+                }
+                _ => Ok(SeiMessage::Other {
+                    payload_type,
+                    payload: Bytes::copy_from_slice(payload),
+                }),
+            };
+            if let Ok(message) = message {
+                messages.push(message);
+            }
+            i += payload_size;
+        }
+        messages
+    }
+}
+
+/// An H.264 SEI message. ISO/IEC-14496-10-2022 - D.1
+#[derive(Debug, Clone, PartialEq)]
+pub enum SeiMessage {
+    BufferingPeriod(BufferingPeriod),
+    PicTiming(PicTiming),
+    /// `user_data_registered_itu_t_t35`, starting with the country code
+    /// (e.g. CEA-608/708 captions)
+    UserDataRegistered(Bytes),
+    UserDataUnregistered(UserDataUnregistered),
+    Other {
+        payload_type: usize,
+        payload: Bytes,
+    },
+}
+
+/// `initial_cpb_removal_delay` and `initial_cpb_removal_delay_offset` of one
+/// CPB specification, in 90 kHz units.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InitialCpbRemoval {
+    pub delay: u32,
+    pub offset: u32,
+}
+
+/// Buffering period SEI message. ISO/IEC-14496-10-2022 - D.1.2
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BufferingPeriod {
+    pub seq_parameter_set_id: u32,
+    /// One entry per CPB specification of the NAL HRD
+    pub nal_initial_cpb_removal: Vec<InitialCpbRemoval>,
+    /// One entry per CPB specification of the VCL HRD
+    pub vcl_initial_cpb_removal: Vec<InitialCpbRemoval>,
+}
+
+impl BufferingPeriod {
+    fn parse(payload: &[u8], context: &AvcSeiContext) -> io::Result<Self> {
+        let mut reader = BitReader::new_from_slice(payload);
+        let seq_parameter_set_id = reader.read_exp_golomb()? as u32;
+        let info = context.pic_timing_info.as_ref();
+        let mut read_hrd = |hrd: Option<&h264::HrdParameters>| -> io::Result<_> {
+            let mut entries = Vec::new();
+            if let Some(hrd) = hrd {
+                let length = hrd.initial_cpb_removal_delay_length_minus1 + 1;
+                for _ in 0..=hrd.cpb_cnt_minus1 {
+                    entries.push(InitialCpbRemoval {
+                        delay: reader.read_bits(length)? as u32,
+                        offset: reader.read_bits(length)? as u32,
+                    });
+                }
+            }
+            Ok(entries)
+        };
+        let nal_initial_cpb_removal = read_hrd(info.and_then(|info| info.nal_hrd.as_ref()))?;
+        let vcl_initial_cpb_removal = read_hrd(info.and_then(|info| info.vcl_hrd.as_ref()))?;
+        Ok(Self {
+            seq_parameter_set_id,
+            nal_initial_cpb_removal,
+            vcl_initial_cpb_removal,
+        })
+    }
+}
+
+/// One `clock_timestamp` of a picture timing SEI message.
+///
+/// The optional fields are omitted by encoders when unchanged from the
+/// previous timestamp.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClockTimestamp {
+    pub ct_type: u8,
+    pub nuit_field_based: bool,
+    pub counting_type: u8,
+    pub discontinuity: bool,
+    pub cnt_dropped: bool,
+    pub n_frames: u8,
+    pub seconds: Option<u8>,
+    pub minutes: Option<u8>,
+    pub hours: Option<u8>,
+    pub time_offset: i32,
+}
+
+impl ClockTimestamp {
+    fn parse<R: io::Read>(reader: &mut BitReader<R>, time_offset_length: u8) -> io::Result<Self> {
+        let ct_type = reader.read_bits(2)? as u8;
+        let nuit_field_based = reader.read_bit()?;
+        let counting_type = reader.read_bits(5)? as u8;
+        let full_timestamp = reader.read_bit()?;
+        let discontinuity = reader.read_bit()?;
+        let cnt_dropped = reader.read_bit()?;
+        let n_frames = reader.read_bits(8)? as u8;
+        let (mut seconds, mut minutes, mut hours) = (None, None, None);
+        if full_timestamp {
+            seconds = Some(reader.read_bits(6)? as u8);
+            minutes = Some(reader.read_bits(6)? as u8);
+            hours = Some(reader.read_bits(5)? as u8);
+        } else if reader.read_bit()? {
+            seconds = Some(reader.read_bits(6)? as u8);
+            if reader.read_bit()? {
+                minutes = Some(reader.read_bits(6)? as u8);
+                if reader.read_bit()? {
+                    hours = Some(reader.read_bits(5)? as u8);
+                }
+            }
+        }
+        let time_offset = if time_offset_length > 0 {
+            let shift = 32 - u32::from(time_offset_length.min(32));
+            ((reader.read_bits(time_offset_length)? as u32) << shift) as i32 >> shift
+        } else {
+            0
+        };
+        Ok(Self {
+            ct_type,
+            nuit_field_based,
+            counting_type,
+            discontinuity,
+            cnt_dropped,
+            n_frames,
+            seconds,
+            minutes,
+            hours,
+            time_offset,
+        })
+    }
+
+    /// The timestamp in seconds, counting omitted fields as zero.
+    /// ISO/IEC-14496-10-2022 - D.2.3, equation D-2
+    pub fn as_secs_f64(&self, timing_info: &TimingInfo) -> f64 {
+        let whole_secs =
+            (u32::from(self.hours.unwrap_or(0)) * 60 + u32::from(self.minutes.unwrap_or(0))) * 60
+                + u32::from(self.seconds.unwrap_or(0));
+        let ticks = f64::from(self.n_frames)
+            * f64::from(timing_info.num_units_in_tick.get())
+            * (1.0 + f64::from(u8::from(self.nuit_field_based)))
+            + f64::from(self.time_offset);
+        f64::from(whole_secs) + ticks / f64::from(timing_info.time_scale.get())
+    }
+}
+
+/// Picture timing SEI message. ISO/IEC-14496-10-2022 - D.1.3
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PicTiming {
+    /// Set when the SPS has HRD parameters, in clock ticks
+    pub cpb_removal_delay: Option<u32>,
+    /// Set when the SPS has HRD parameters, in clock ticks
+    pub dpb_output_delay: Option<u32>,
+    /// Set when the SPS has `pic_struct_present_flag`
+    pub pic_struct: Option<u8>,
+    pub clock_timestamps: Vec<ClockTimestamp>,
+}
+
+impl PicTiming {
+    fn parse(payload: &[u8], context: &AvcSeiContext) -> io::Result<Self> {
+        let Some(info) = context.pic_timing_info.as_ref() else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "picture timing SEI without VUI parameters",
+            ));
+        };
+        let mut reader = BitReader::new_from_slice(payload);
+        let hrd = info.cpb_dpb_delays();
+
+        let (mut cpb_removal_delay, mut dpb_output_delay) = (None, None);
+        if let Some(hrd) = hrd {
+            cpb_removal_delay =
+                Some(reader.read_bits(hrd.cpb_removal_delay_length_minus1 + 1)? as u32);
+            dpb_output_delay =
+                Some(reader.read_bits(hrd.dpb_output_delay_length_minus1 + 1)? as u32);
+        }
+
+        let mut pic_struct = None;
+        let mut clock_timestamps = Vec::new();
+        if info.pic_struct_present_flag {
+            let value = reader.read_bits(4)? as u8;
+            // time_offset_length is inferred to be 24 without HRD parameters
+            let time_offset_length = hrd.map_or(24, |hrd| hrd.time_offset_length);
+            for _ in 0..Self::num_clock_ts(value) {
+                // clock_timestamp_flag
+                if reader.read_bit()? {
+                    clock_timestamps.push(ClockTimestamp::parse(&mut reader, time_offset_length)?);
+                }
+            }
+            pic_struct = Some(value);
+        }
+
+        Ok(Self {
+            cpb_removal_delay,
+            dpb_output_delay,
+            pic_struct,
+            clock_timestamps,
+        })
+    }
+
+    /// `NumClockTS` of a `pic_struct`. ISO/IEC-14496-10-2022 - Table D-1
+    fn num_clock_ts(pic_struct: u8) -> usize {
+        match pic_struct {
+            0..=2 => 1,
+            3 | 4 | 7 => 2,
+            5 | 6 | 8 => 3,
+            _ => 0,
+        }
+    }
+
+    /// Display duration of the picture in clock ticks (`DeltaTfiDivisor`), if
+    /// `pic_struct` is known. A frame lasts two ticks.
+    /// ISO/IEC-14496-10-2022 - Table E-6
+    pub fn duration_ticks(&self) -> Option<u32> {
+        match self.pic_struct? {
+            1 | 2 => Some(1),
+            0 | 3 | 4 => Some(2),
+            5 | 6 => Some(3),
+            7 => Some(4),
+            8 => Some(6),
+            _ => None,
+        }
+    }
+
+    /// Display duration of the picture in seconds.
+    pub fn duration_secs(&self, timing_info: &TimingInfo) -> Option<f64> {
+        let ticks = self.duration_ticks()?;
+        Some(
+            f64::from(ticks) * f64::from(timing_info.num_units_in_tick.get())
+                / f64::from(timing_info.time_scale.get()),
+        )
+    }
+}
+
+/// `user_data_unregistered` SEI message. ISO/IEC-14496-10-2022 - D.1.7
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserDataUnregistered {
+    pub uuid: [u8; 16],
+    pub payload: Bytes,
+}
+
+impl UserDataUnregistered {
+    fn parse(payload: &[u8]) -> io::Result<Self> {
+        let Some((uuid, payload)) = payload.split_first_chunk::<16>() else {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "user data unregistered SEI shorter than its UUID",
+            ));
+        };
+        Ok(Self {
+            uuid: *uuid,
+            payload: Bytes::copy_from_slice(payload),
+        })
+    }
+
+    /// The payload as text, for the encoder information strings written by
+    /// x264 and similar encoders. `None` for binary payloads.
+    pub fn text(&self) -> Option<&str> {
+        let text = std::str::from_utf8(&self.payload).ok()?;
+        let text = text.trim_end_matches('\0').trim();
+        (!text.is_empty() && !text.chars().any(|c| c.is_control())).then_some(text)
+    }
 }
 
 impl std::fmt::Display for AvcPacket {
@@ -134,3 +545,125 @@ impl TryFrom<u8> for AvcPacketType {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroU32;
+
+    use bytes_util::BitWriter;
+    use h264::PicTimingInfo;
+
+    use super::*;
+
+    fn context() -> AvcSeiContext {
+        AvcSeiContext {
+            nalu_length_size: 4,
+            timing_info: Some(TimingInfo {
+                num_units_in_tick: NonZeroU32::new(1001).unwrap(),
+                time_scale: NonZeroU32::new(60000).unwrap(),
+            }),
+            pic_timing_info: Some(PicTimingInfo {
+                nal_hrd: None,
+                vcl_hrd: None,
+                low_delay_hrd_flag: false,
+                pic_struct_present_flag: true,
+            }),
+        }
+    }
+
+    fn sei_nal(messages: &[(u8, &[u8])]) -> Vec<u8> {
+        let mut nal = vec![0x06];
+        for (payload_type, payload) in messages {
+            nal.push(*payload_type);
+            nal.push(payload.len() as u8);
+            nal.extend_from_slice(payload);
+        }
+        nal.push(0x80);
+        nal
+    }
+
+    #[test]
+    fn test_parse_sei_messages() {
+        let context = context();
+
+        // pic_struct = 0 (frame) with one full clock timestamp of 00:01:05 + 30 frames
+        let mut pic_timing = Vec::new();
+        let mut writer = BitWriter::new(&mut pic_timing);
+        writer.write_bits(0, 4).unwrap();
+        writer.write_bit(true).unwrap();
+        writer.write_bits(0, 2 + 1 + 5).unwrap();
+        writer.write_bit(true).unwrap();
+        writer.write_bits(0, 2).unwrap();
+        writer.write_bits(30, 8).unwrap();
+        writer.write_bits(5, 6).unwrap();
+        writer.write_bits(1, 6).unwrap();
+        writer.write_bits(0, 5).unwrap();
+        writer.write_bits(0, 24).unwrap();
+        writer.finish().unwrap();
+
+        let mut user_data = vec![0xAB; 16];
+        user_data.extend_from_slice(b"x264 - core 164 r3095\0");
+
+        let nal = sei_nal(&[(0, &[0x80]), (1, &pic_timing), (5, &user_data)]);
+        let mut frame = (nal.len() as u32).to_be_bytes().to_vec();
+        frame.extend_from_slice(&nal);
+        let packet = AvcPacket::Nalu {
+            composition_time: 0,
+            data: Bytes::from(frame),
+        };
+
+        let messages = packet.sei_messages(&context);
+        assert_eq!(messages.len(), 3);
+        assert_eq!(
+            messages[0],
+            SeiMessage::BufferingPeriod(BufferingPeriod {
+                seq_parameter_set_id: 0,
+                nal_initial_cpb_removal: Vec::new(),
+                vcl_initial_cpb_removal: Vec::new(),
+            })
+        );
+
+        let SeiMessage::PicTiming(pic_timing) = &messages[1] else {
+            panic!("expected pic_timing, got {:?}", messages[1]);
+        };
+        let timing_info = context.timing_info.as_ref().unwrap();
+        assert_eq!(pic_timing.pic_struct, Some(0));
+        assert_eq!(pic_timing.cpb_removal_delay, None);
+        assert_eq!(pic_timing.duration_ticks(), Some(2));
+        let duration = pic_timing.duration_secs(timing_info).unwrap();
+        assert!((duration - 1001.0 / 30000.0).abs() < 1e-9);
+        assert_eq!(pic_timing.clock_timestamps.len(), 1);
+        let clock_timestamp = pic_timing.clock_timestamps[0];
+        assert_eq!(clock_timestamp.minutes, Some(1));
+        let secs = clock_timestamp.as_secs_f64(timing_info);
+        assert!((secs - (65.0 + 30.0 * 1001.0 / 60000.0)).abs() < 1e-9);
+
+        let SeiMessage::UserDataUnregistered(user_data) = &messages[2] else {
+            panic!("expected user data, got {:?}", messages[2]);
+        };
+        assert_eq!(user_data.uuid, [0xAB; 16]);
+        assert_eq!(user_data.text(), Some("x264 - core 164 r3095"));
+    }
+
+    #[test]
+    fn test_parse_sei_pic_timing_needs_vui() {
+        let context = AvcSeiContext {
+            nalu_length_size: 4,
+            ..Default::default()
+        };
+        assert!(!context.has_pic_timing());
+
+        // Unknown payloads are kept, picture timing cannot be read without the VUI
+        let nal = sei_nal(&[(1, &[0x00]), (6, &[0x01, 0x02])]);
+        assert_eq!(
+            context.parse_sei(&nal),
+            vec![SeiMessage::Other {
+                payload_type: 6,
+                payload: Bytes::from_static(&[0x01, 0x02]),
+            }]
+        );
+
+        // Not a SEI NAL unit
+        assert!(context.parse_sei(&[0x65, 0x88, 0x84]).is_empty());
+    }
+}
@@ -1,12 +1,231 @@
-use std::io;
+use std::fmt;
+use std::io::{self, Read};
 
 use byteorder::{BigEndian, ReadBytesExt};
 use bytes::Bytes;
 use bytes_util::BytesCursorExt;
-use h265::{HEVCDecoderConfigurationRecord, NALUnitType};
+use h264::EmulationPreventionIo;
+use h265::{HEVCDecoderConfigurationRecord, NALUnitType, SpsRbsp};
+
+use media_types::{StreamDescription, VideoCodec};
 
 use crate::resolution::Resolution;
 
+/// SEI payload type of the mastering display colour volume (SMPTE ST 2086)
+const SEI_MASTERING_DISPLAY_COLOUR_VOLUME: usize = 137;
+
+/// SEI payload type of the content light level information
+const SEI_CONTENT_LIGHT_LEVEL_INFO: usize = 144;
+
+/// Transfer characteristics of SMPTE ST 2084 (PQ), used by HDR10
+pub const TRANSFER_PQ: u8 = 16;
+
+/// Transfer characteristics of ARIB STD-B67 (hybrid log-gamma)
+pub const TRANSFER_HLG: u8 = 18;
+
+/// Colour volume of the display the content was mastered on, as carried by
+/// the mastering display colour volume SEI.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MasteringDisplay {
+    /// Chromaticity of the green, blue and red primaries, in increments of
+    /// 0.00002
+    pub primaries: [(u16, u16); 3],
+    pub white_point: (u16, u16),
+    /// Maximum luminance in increments of 0.0001 cd/m²
+    pub max_luminance: u32,
+    /// Minimum luminance in increments of 0.0001 cd/m²
+    pub min_luminance: u32,
+}
+
+impl MasteringDisplay {
+    fn parse(payload: &[u8]) -> Option<Self> {
+        let payload: &[u8; 24] = payload.get(..24)?.try_into().ok()?;
+        let u16_at = |i: usize| u16::from_be_bytes([payload[i], payload[i + 1]]);
+        let u32_at = |i: usize| {
+            u32::from_be_bytes([payload[i], payload[i + 1], payload[i + 2], payload[i + 3]])
+        };
+        Some(Self {
+            primaries: [
+                (u16_at(0), u16_at(2)),
+                (u16_at(4), u16_at(6)),
+                (u16_at(8), u16_at(10)),
+            ],
+            white_point: (u16_at(12), u16_at(14)),
+            max_luminance: u32_at(16),
+            min_luminance: u32_at(20),
+        })
+    }
+
+    /// Maximum luminance in cd/m².
+    pub fn max_luminance_nits(&self) -> f64 {
+        f64::from(self.max_luminance) / 10_000.0
+    }
+
+    /// Minimum luminance in cd/m².
+    pub fn min_luminance_nits(&self) -> f64 {
+        f64::from(self.min_luminance) / 10_000.0
+    }
+}
+
+/// Formats as the `master-display` string understood by x265 and FFmpeg.
+impl fmt::Display for MasteringDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [green, blue, red] = self.primaries;
+        write!(
+            f,
+            "G({},{})B({},{})R({},{})WP({},{})L({},{})",
+            green.0,
+            green.1,
+            blue.0,
+            blue.1,
+            red.0,
+            red.1,
+            self.white_point.0,
+            self.white_point.1,
+            self.max_luminance,
+            self.min_luminance
+        )
+    }
+}
+
+/// Content light level information SEI, in cd/m².
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContentLightLevel {
+    /// Maximum content light level of any pixel
+    pub max_cll: u16,
+    /// Maximum frame-average light level
+    pub max_fall: u16,
+}
+
+/// Colour description and HDR metadata of an HEVC stream.
+///
+/// The colour description comes from the SPS VUI; the mastering display and
+/// content light level from prefix SEI messages, which encoders either put in
+/// the decoder configuration record or repeat in front of every keyframe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HdrInfo {
+    pub colour_primaries: u8,
+    pub transfer_characteristics: u8,
+    pub matrix_coeffs: u8,
+    pub full_range: bool,
+    pub bit_depth: u8,
+    pub mastering_display: Option<MasteringDisplay>,
+    pub content_light_level: Option<ContentLightLevel>,
+}
+
+impl HdrInfo {
+    /// Colour description of an SPS, with the unspecified defaults when it
+    /// has no VUI.
+    pub fn from_sps(sps: &SpsRbsp) -> Self {
+        let signal = sps
+            .vui_parameters
+            .as_ref()
+            .map(|vui| vui.video_signal_type.clone())
+            .unwrap_or_default();
+        Self {
+            colour_primaries: signal.colour_primaries,
+            transfer_characteristics: signal.transfer_characteristics,
+            matrix_coeffs: signal.matrix_coeffs,
+            full_range: signal.video_full_range_flag,
+            bit_depth: sps.bit_depth_y(),
+            mastering_display: None,
+            content_light_level: None,
+        }
+    }
+
+    /// Whether the stream uses an HDR transfer function.
+    pub fn is_hdr(&self) -> bool {
+        matches!(self.transfer_characteristics, TRANSFER_PQ | TRANSFER_HLG)
+    }
+
+    /// Short name of the transfer function: `PQ`, `HLG` or `SDR`.
+    pub fn transfer_name(&self) -> &'static str {
+        match self.transfer_characteristics {
+            TRANSFER_PQ => "PQ",
+            TRANSFER_HLG => "HLG",
+            _ => "SDR",
+        }
+    }
+
+    /// Whether the SEI metadata has been seen.
+    pub fn has_sei_metadata(&self) -> bool {
+        self.mastering_display.is_some() || self.content_light_level.is_some()
+    }
+
+    /// Pick up the HDR messages of a prefix SEI NAL unit, header included.
+    /// Returns whether anything was found.
+    pub fn update_from_sei(&mut self, nal: &[u8]) -> bool {
+        if nal.len() < 3 || NALUnitType::from((nal[0] >> 1) & 0x3F) != NALUnitType::PrefixSeiNut {
+            return false;
+        }
+        let mut rbsp = Vec::with_capacity(nal.len());
+        if EmulationPreventionIo::new(&nal[2..])
+            .read_to_end(&mut rbsp)
+            .is_err()
+        {
+            return false;
+        }
+
+        let mut found = false;
+        let mut i = 0;
+        // Stop at the rbsp_trailing_bits
+        while i + 2 <= rbsp.len() && rbsp[i] != 0x80 {
+            let mut read_value = || {
+                let mut value = 0;
+                while rbsp.get(i) == Some(&0xFF) {
+                    value += 255;
+                    i += 1;
+                }
+                let last = rbsp.get(i).copied()?;
+                i += 1;
+                Some(value + usize::from(last))
+            };
+            let (Some(payload_type), Some(payload_size)) = (read_value(), read_value()) else {
+                break;
+            };
+            let Some(payload) = rbsp.get(i..i + payload_size) else {
+                break;
+            };
+            match payload_type {
+                SEI_MASTERING_DISPLAY_COLOUR_VOLUME => {
+                    if let Some(display) = MasteringDisplay::parse(payload) {
+                        self.mastering_display = Some(display);
+                        found = true;
+                    }
+                }
+                SEI_CONTENT_LIGHT_LEVEL_INFO if payload.len() >= 4 => {
+                    self.content_light_level = Some(ContentLightLevel {
+                        max_cll: u16::from_be_bytes([payload[0], payload[1]]),
+                        max_fall: u16::from_be_bytes([payload[2], payload[3]]),
+                    });
+                    found = true;
+                }
+                _ => {}
+            }
+            i += payload_size;
+        }
+        found
+    }
+
+    /// Pick up the HDR messages of the prefix SEI NAL units in a frame of
+    /// length-prefixed NAL units. Returns whether anything was found.
+    pub fn update_from_nalus(&mut self, data: &[u8], length_size: usize) -> bool {
+        let mut found = false;
+        let mut rest = data;
+        while rest.len() > length_size {
+            let length = rest[..length_size]
+                .iter()
+                .fold(0usize, |length, byte| (length << 8) | usize::from(*byte));
+            let Some(nal) = rest.get(length_size..length_size + length) else {
+                break;
+            };
+            found |= self.update_from_sei(nal);
+            rest = &rest[length_size + length..];
+        }
+        found
+    }
+}
+
 #[repr(u8)]
 #[derive(Debug, Clone, PartialEq)]
 pub enum HevcPacketType {
@@ -94,9 +313,11 @@ impl HevcPacket {
                         }
                         h265::SpsNALUnit::parse(std::io::Cursor::new(sps.clone()))
                             .ok()
-                            .map(|sps_nalu| Resolution {
-                                width: sps_nalu.rbsp.pic_width_in_luma_samples.get() as f32,
-                                height: sps_nalu.rbsp.pic_height_in_luma_samples.get() as f32,
+                            .map(|sps_nalu| {
+                                Resolution::new(
+                                    sps_nalu.rbsp.pic_width_in_luma_samples.get() as u32,
+                                    sps_nalu.rbsp.pic_height_in_luma_samples.get() as u32,
+                                )
                             })
                     })
             }
@@ -105,6 +326,51 @@ impl HevcPacket {
     }
 }
 
+impl HevcPacket {
+    /// Codec, profile, level, bit depth, resolution and HDR signalling of a
+    /// sequence start.
+    pub fn get_stream_description(&self) -> Option<StreamDescription> {
+        let HevcPacket::SequenceStart(config) = self else {
+            return None;
+        };
+        Some(StreamDescription {
+            video_codec: Some(VideoCodec::Hevc),
+            profile: Some(config.general_profile_idc),
+            level: Some(config.general_level_idc),
+            resolution: self.get_video_resolution(),
+            bit_depth: Some(config.bit_depth_luma_minus8 + 8),
+            hdr: self.get_hdr_info().is_some_and(|hdr| hdr.is_hdr()),
+            ..Default::default()
+        })
+    }
+
+    /// Colour description and HDR metadata of a sequence start, from its
+    /// first SPS and any prefix SEI arrays.
+    pub fn get_hdr_info(&self) -> Option<HdrInfo> {
+        let HevcPacket::SequenceStart(config) = self else {
+            return None;
+        };
+        let sps = config
+            .arrays
+            .iter()
+            .find(|array| array.nal_unit_type == NALUnitType::SpsNut)
+            .and_then(|sps_array| sps_array.nalus.first())
+            .filter(|sps| sps.len() >= 4)?;
+        let sps = h265::SpsNALUnit::parse(std::io::Cursor::new(sps.clone())).ok()?;
+
+        let mut info = HdrInfo::from_sps(&sps.rbsp);
+        for sei in config
+            .arrays
+            .iter()
+            .filter(|array| array.nal_unit_type == NALUnitType::PrefixSeiNut)
+            .flat_map(|array| &array.nalus)
+        {
+            info.update_from_sei(sei);
+        }
+        Some(info)
+    }
+}
+
 impl std::fmt::Display for HevcPacket {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -149,3 +415,56 @@ impl std::fmt::Display for HevcPacket {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hdr_sei_parsing() {
+        let mut info = HdrInfo {
+            colour_primaries: 9,
+            transfer_characteristics: TRANSFER_PQ,
+            matrix_coeffs: 9,
+            full_range: false,
+            bit_depth: 10,
+            mastering_display: None,
+            content_light_level: None,
+        };
+        assert!(info.is_hdr());
+        assert!(!info.has_sei_metadata());
+
+        #[rustfmt::skip]
+        let sei: &[u8] = &[
+            0x4E, 0x01, // prefix SEI NAL unit header
+            0x89, 0x18, // mastering display colour volume, 24 bytes
+            0x33, 0xC2, 0x86, 0xC4, 0x1D, 0x4C, 0x0B, 0xB8, 0x84, 0xD0, 0x3E, 0x80,
+            0x3D, 0x13, 0x40, 0x42,
+            0x00, 0x98, 0x96, 0x80,
+            0x00, 0x00, 0x03, 0x00, 0x01, // emulation prevention byte
+            0x90, 0x04, // content light level, 4 bytes
+            0x03, 0xE8, 0x01, 0x90,
+            0x80,
+        ];
+        let mut frame = (sei.len() as u32).to_be_bytes().to_vec();
+        frame.extend_from_slice(sei);
+        // An IDR slice that is not SEI
+        frame.extend_from_slice(&[0x00, 0x00, 0x00, 0x03, 0x26, 0x01, 0xAF]);
+        assert!(info.update_from_nalus(&frame, 4));
+
+        let display = info.mastering_display.unwrap();
+        assert_eq!(
+            display.to_string(),
+            "G(13250,34500)B(7500,3000)R(34000,16000)WP(15635,16450)L(10000000,1)"
+        );
+        assert_eq!(display.max_luminance_nits(), 1000.0);
+        assert_eq!(
+            info.content_light_level,
+            Some(ContentLightLevel {
+                max_cll: 1000,
+                max_fall: 400,
+            })
+        );
+        assert_eq!(info.transfer_name(), "PQ");
+    }
+}
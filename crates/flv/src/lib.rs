@@ -1,4 +1,4 @@
-mod aac;
+pub mod aac;
 pub mod audio;
 pub mod av1;
 pub mod avc;
@@ -10,6 +10,7 @@ pub mod error;
 pub mod framing;
 pub mod header;
 pub mod hevc;
+pub mod multitrack;
 pub mod parser;
 pub mod parser_async;
 pub mod resolution;
@@ -0,0 +1,267 @@
+//! # E-RTMP v2 Multitrack
+//!
+//! Parsing of multitrack audio and video tag payloads (`AudioPacketType::Multitrack` and
+//! the enhanced video `Multitrack` packet type).
+//!
+//! A multitrack payload carries one or more tracks, each identified by a `trackId`. Track 0
+//! is the default track: payloads without a multitrack header implicitly belong to it.
+//!
+//! ## Layout
+//!
+//! ```text
+//! [header:4][packetType=Multitrack:4]
+//! [multitrackType:4][packetType:4]
+//! [fourCC]                         -- only when all tracks share a codec
+//! {
+//!     [fourCC]                     -- only for ManyTracksManyCodecs
+//!     [trackId:u8]
+//!     [size:u24]                   -- absent for OneTrack (body spans the rest)
+//!     [body]
+//! }*
+//! ```
+//!
+//! ## Specifications
+//!
+//! - [E-RTMP v2 specification](https://github.com/veovera/enhanced-rtmp/blob/main/docs/enhanced/enhanced-rtmp-v2.md#enhanced-multitrack)
+
+use std::io;
+
+use bytes::{BufMut, Bytes, BytesMut};
+
+use crate::audio::{AvMultitrackType, SoundFormat};
+use crate::tag::FlvTagType;
+use crate::video::EnhancedPacketType;
+
+/// Track that non-multitrack payloads belong to.
+pub const DEFAULT_TRACK_ID: u8 = 0;
+
+/// `AudioPacketType::Multitrack`
+const AUDIO_PACKET_TYPE_MULTITRACK: u8 = 5;
+
+/// A single track inside a multitrack payload.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrackPayload {
+    /// Track identifier.
+    pub track_id: u8,
+    /// Codec FourCC of this track.
+    pub fourcc: [u8; 4],
+    /// Track body, starting right after the (Ex)TagHeader fields.
+    ///
+    /// For video coded frames this still begins with the composition time offset.
+    pub data: Bytes,
+}
+
+/// A parsed multitrack audio or video payload.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MultitrackPacket {
+    /// Audio or video.
+    pub tag_type: FlvTagType,
+    /// Upper nibble of the first payload byte (ExHeader flag + frame type, or sound format).
+    header: u8,
+    /// How tracks are laid out in the payload.
+    pub multitrack_type: AvMultitrackType,
+    /// Packet type shared by every track (sequence start, coded frames, ...).
+    pub packet_type: u8,
+    /// Tracks in payload order.
+    pub tracks: Vec<TrackPayload>,
+}
+
+impl MultitrackPacket {
+    /// Whether `data` is a multitrack payload for `tag_type`.
+    pub fn is_multitrack(tag_type: FlvTagType, data: &[u8]) -> bool {
+        let Some(&first) = data.first() else {
+            return false;
+        };
+        match tag_type {
+            FlvTagType::Video => {
+                first & 0x80 != 0
+                    && EnhancedPacketType::from(first & 0x0F) == EnhancedPacketType::MULTITRACK
+            }
+            FlvTagType::Audio => {
+                first >> 4 == SoundFormat::ExHeader as u8
+                    && first & 0x0F == AUDIO_PACKET_TYPE_MULTITRACK
+            }
+            _ => false,
+        }
+    }
+
+    /// Parse a multitrack payload.
+    ///
+    /// Returns `Ok(None)` for payloads that are not multitrack, which belong to
+    /// [`DEFAULT_TRACK_ID`].
+    pub fn parse(tag_type: FlvTagType, data: &Bytes) -> io::Result<Option<Self>> {
+        if !Self::is_multitrack(tag_type, data) {
+            return Ok(None);
+        }
+
+        let second = *data.get(1).ok_or_else(|| eof("multitrack header"))?;
+        let multitrack_type = AvMultitrackType::try_from(second >> 4)?;
+        let packet_type = second & 0x0F;
+        if Self::is_multitrack(tag_type, &[(data[0] & 0xF0) | packet_type]) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "Nested multitrack is not allowed",
+            ));
+        }
+
+        let mut pos = 2;
+        let shared_fourcc = if multitrack_type == AvMultitrackType::ManyTracksManyCodecs {
+            None
+        } else {
+            Some(read_fourcc(data, &mut pos)?)
+        };
+
+        let mut tracks = Vec::new();
+        while pos < data.len() {
+            let fourcc = match shared_fourcc {
+                Some(fourcc) => fourcc,
+                None => read_fourcc(data, &mut pos)?,
+            };
+            let track_id = *data.get(pos).ok_or_else(|| eof("track id"))?;
+            pos += 1;
+
+            let size = if multitrack_type == AvMultitrackType::OneTrack {
+                data.len() - pos
+            } else {
+                let bytes = data.get(pos..pos + 3).ok_or_else(|| eof("track size"))?;
+                pos += 3;
+                u32::from_be_bytes([0, bytes[0], bytes[1], bytes[2]]) as usize
+            };
+            let end = pos
+                .checked_add(size)
+                .filter(|&end| end <= data.len())
+                .ok_or_else(|| eof("track body"))?;
+
+            tracks.push(TrackPayload {
+                track_id,
+                fourcc,
+                data: data.slice(pos..end),
+            });
+            pos = end;
+
+            if multitrack_type == AvMultitrackType::OneTrack {
+                break;
+            }
+        }
+
+        Ok(Some(Self {
+            tag_type,
+            header: data[0] & 0xF0,
+            multitrack_type,
+            packet_type,
+            tracks,
+        }))
+    }
+
+    /// Returns the track with `track_id`, if present.
+    pub fn track(&self, track_id: u8) -> Option<&TrackPayload> {
+        self.tracks.iter().find(|track| track.track_id == track_id)
+    }
+
+    /// Track ids in payload order.
+    pub fn track_ids(&self) -> impl Iterator<Item = u8> + '_ {
+        self.tracks.iter().map(|track| track.track_id)
+    }
+
+    /// Rebuild `track` as a plain (non-multitrack) enhanced payload.
+    ///
+    /// The result is what a single-track E-RTMP sender would emit for the same packet, so it
+    /// can replace the tag payload when only one track is kept.
+    pub fn single_track_payload(&self, track: &TrackPayload) -> Bytes {
+        let mut out = BytesMut::with_capacity(5 + track.data.len());
+        out.put_u8(self.header | self.packet_type);
+        out.put_slice(&track.fourcc);
+        out.put_slice(&track.data);
+        out.freeze()
+    }
+}
+
+fn read_fourcc(data: &[u8], pos: &mut usize) -> io::Result<[u8; 4]> {
+    let bytes = data
+        .get(*pos..*pos + 4)
+        .and_then(|bytes| <[u8; 4]>::try_from(bytes).ok())
+        .ok_or_else(|| eof("FourCC"))?;
+    *pos += 4;
+    Ok(bytes)
+}
+
+fn eof(field: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::UnexpectedEof,
+        format!("Multitrack payload truncated while reading {field}"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_many_tracks_with_shared_codec() {
+        let data = Bytes::from_static(&[
+            0x96, // ExHeader + KeyFrame + Multitrack
+            0x11, // ManyTracks + CodedFrames
+            b'h', b'v', b'c', b'1', // shared fourCC
+            0x00, 0x00, 0x00, 0x04, // track 0, 4 bytes
+            0x00, 0x00, 0x00, 0xAA, //
+            0x02, 0x00, 0x00, 0x05, // track 2, 5 bytes
+            0x00, 0x00, 0x00, 0xBB, 0xCC,
+        ]);
+
+        let packet = MultitrackPacket::parse(FlvTagType::Video, &data)
+            .unwrap()
+            .unwrap();
+        assert_eq!(packet.multitrack_type, AvMultitrackType::ManyTracks);
+        assert_eq!(packet.packet_type, 1);
+        assert_eq!(packet.track_ids().collect::<Vec<_>>(), vec![0, 2]);
+
+        let track = packet.track(2).unwrap();
+        assert_eq!(&track.fourcc, b"hvc1");
+        assert_eq!(
+            packet.single_track_payload(track).as_ref(),
+            &[0x91, b'h', b'v', b'c', b'1', 0x00, 0x00, 0x00, 0xBB, 0xCC]
+        );
+    }
+
+    #[test]
+    fn parses_many_codecs_and_one_track_audio() {
+        let data = Bytes::from_static(&[
+            0x95, // ExHeader + Multitrack
+            0x21, // ManyTracksManyCodecs + CodedFrames
+            b'O', b'p', b'u', b's', 0x01, 0x00, 0x00, 0x01, 0x10, //
+            b'm', b'p', b'4', b'a', 0x03, 0x00, 0x00, 0x02, 0x20, 0x21,
+        ]);
+        let packet = MultitrackPacket::parse(FlvTagType::Audio, &data)
+            .unwrap()
+            .unwrap();
+        assert_eq!(packet.tracks.len(), 2);
+        assert_eq!(&packet.tracks[0].fourcc, b"Opus");
+        assert_eq!(packet.tracks[1].track_id, 3);
+        assert_eq!(packet.tracks[1].data.as_ref(), &[0x20, 0x21]);
+
+        let data = Bytes::from_static(&[0x95, 0x01, b'm', b'p', b'4', b'a', 0x01, 0xAF, 0xFE]);
+        let packet = MultitrackPacket::parse(FlvTagType::Audio, &data)
+            .unwrap()
+            .unwrap();
+        assert_eq!(packet.multitrack_type, AvMultitrackType::OneTrack);
+        assert_eq!(packet.tracks[0].track_id, 1);
+        assert_eq!(packet.tracks[0].data.as_ref(), &[0xAF, 0xFE]);
+    }
+
+    #[test]
+    fn rejects_truncated_and_ignores_single_track_payloads() {
+        let truncated = Bytes::from_static(&[0x96, 0x11, b'h', b'v', b'c', b'1', 0x00, 0x00]);
+        assert!(MultitrackPacket::parse(FlvTagType::Video, &truncated).is_err());
+
+        let single = Bytes::from_static(&[0x91, b'h', b'v', b'c', b'1', 0, 0, 0]);
+        assert_eq!(
+            MultitrackPacket::parse(FlvTagType::Video, &single).unwrap(),
+            None
+        );
+        let legacy = Bytes::from_static(&[0x17, 0x01, 0, 0, 0]);
+        assert_eq!(
+            MultitrackPacket::parse(FlvTagType::Video, &legacy).unwrap(),
+            None
+        );
+    }
+}
@@ -39,6 +39,11 @@ pub struct FlvDecoder {
     last_tag_size: u32,
     // Tracks the current byte position in the stream
     position: u64,
+    // Set when the position of the next tag header is in doubt (after a PreviousTagSize
+    // mismatch or an unfinished resync); the header must then look like a real tag boundary.
+    suspect_boundary: bool,
+    // Bytes discarded while resynchronizing
+    skipped_bytes: u64,
 }
 
 impl FlvDecoder {
@@ -47,43 +52,72 @@ impl FlvDecoder {
         self.position
     }
 
-    // Helper function to attempt resynchronization by finding the next potential tag start
-    // Returns true if resync advanced the buffer, false otherwise.
+    /// Total number of bytes discarded while resynchronizing after corrupt data.
+    pub fn skipped_bytes(&self) -> u64 {
+        self.skipped_bytes
+    }
+
+    // Whether `bytes` starts with something that looks like a real tag: a known tag type with
+    // the reserved bits clear, a sane data size, a zero stream id and, once the body is
+    // buffered, a matching PreviousTagSize after it.
+    fn is_plausible_boundary(bytes: &[u8]) -> bool {
+        let Some(header) = bytes.get(..TAG_HEADER_SIZE) else {
+            return false;
+        };
+        if header[0] & 0xC0 != 0 || !matches!(header[0] & 0x1F, 8 | 9 | 18) {
+            return false;
+        }
+        let data_size = u32::from_be_bytes([0, header[1], header[2], header[3]]);
+        if data_size > MAX_TAG_DATA_SIZE || header[8..] != [0u8; 3] {
+            return false;
+        }
+        let end = TAG_HEADER_SIZE + data_size as usize;
+        match bytes.get(end..end + PREV_TAG_SIZE_FIELD_SIZE) {
+            Some(prev) => u32::from_be_bytes([prev[0], prev[1], prev[2], prev[3]]) == end as u32,
+            None => true,
+        }
+    }
+
+    // Skip to the next plausible tag boundary after the first byte of `src`.
+    // Returns true if a boundary was found; otherwise everything but a tail too short to
+    // judge is discarded and the next header is checked once more data arrives.
     fn try_resync(&mut self, src: &mut BytesMut) -> bool {
-        // Look for the next potential tag start (TagType lives in low 5 bits; filter bit may be set).
-        if let Some(pos) = src.iter().position(|&b| matches!(b & 0x1F, 8 | 9 | 18)) {
-            // Discard bytes before the potential tag start
-            src.advance(pos);
-            self.position += pos as u64;
+        let mut pos = 1;
+        let found = loop {
+            if pos + TAG_HEADER_SIZE > src.len() {
+                break false;
+            }
+            if Self::is_plausible_boundary(&src[pos..]) {
+                break true;
+            }
+            pos += 1;
+        };
+        let skipped = pos.min(src.len());
+        src.advance(skipped);
+        self.position += skipped as u64;
+        self.skipped_bytes += skipped as u64;
+        // We implicitly skipped whatever was before the boundary, including any
+        // PreviousTagSize field, so we now expect a tag header directly.
+        self.expecting_tag_header = true;
+        // We lost context, so reset last tag size knowledge
+        self.last_tag_size = 0;
+        self.suspect_boundary = !found;
+
+        if found {
             debug!(
-                "Resync: Found potential tag start after skipping {} bytes. Remaining buffer: {}",
-                pos,
+                "Resync: Found tag boundary after skipping {} bytes. Remaining buffer: {}",
+                skipped,
                 src.len()
             );
-            // After skipping, we are positioned at a potential tag type byte.
-            // We implicitly skipped whatever was before it, including any PreviousTagSize field.
-            // We now expect a tag header directly.
-            self.expecting_tag_header = true;
-            // We lost context, so reset last tag size knowledge
-            self.last_tag_size = 0;
-            true
         } else {
-            // No potential tag start found in the current buffer, discard it all.
-            let discarded_len = src.len();
-            src.clear();
-            self.position += discarded_len as u64;
             // Request more data by reserving a minimal amount
             src.reserve(BUFFER_SIZE);
             warn!(
-                "Resync: No potential tag start found. Discarded {} bytes.",
-                discarded_len
+                "Resync: No tag boundary found yet. Discarded {} bytes.",
+                skipped
             );
-            // Still expecting a tag header when new data arrives
-            self.expecting_tag_header = true;
-            // We lost context
-            self.last_tag_size = 0;
-            false
         }
+        found
     }
 }
 
@@ -237,9 +271,9 @@ impl Decoder for FlvDecoder {
                     "PreviousTagSize mismatch: Expected {}, found {}. Stream might be corrupted.",
                     self.last_tag_size, prev_tag_size
                 );
-                // Decide whether to bail out or try to continue. Let's try to continue for now.
-                // If we wanted strict checking, we could return Err here or trigger resync.
-                // Investigation shows the some streams have this mismatch (script tags with incorrect size).
+                // Investigation shows the some streams have this mismatch (script tags with incorrect size),
+                // so continue, but only trust the next header if it looks like a real tag boundary.
+                self.suspect_boundary = true;
             } else {
                 trace!("Read PreviousTagSize: {}", prev_tag_size);
             }
@@ -273,34 +307,28 @@ impl Decoder for FlvDecoder {
                 "Invalid tag type encountered: {}. Attempting resync.",
                 src[0]
             );
-            // Discard the single invalid byte and try resyncing
-            // src.advance(1);
-            // Now attempt resync on the rest
-            if !self.try_resync(src) {
-                // Resync advanced the buffer. Return None to signal progress
-                // but no complete frame yet from *this* specific call point.
-                // The next call to decode will attempt parsing from the new position.
-                return Ok(None);
-            } else {
-                // Resync cleared the buffer or couldn't find anything.
-                // Need more data. try_resync already reserved space.
-                trace!("Resync failed or cleared buffer, returning None for more data.");
-                return Ok(None);
-            }
+            // Skip the invalid byte and everything up to the next plausible tag boundary.
+            // Return None either way: the next call to decode parses from the new position,
+            // or waits for more data if no boundary was found yet.
+            self.try_resync(src);
+            return Ok(None);
         }
 
         if data_size > MAX_TAG_DATA_SIZE {
             warn!(
-                "Unusually large tag data size: {} (max allowed: {}). Skipping tag header and attempting resync.",
+                "Unusually large tag data size: {} (max allowed: {}). Attempting resync.",
                 data_size, MAX_TAG_DATA_SIZE
             );
-            // Discard the invalid tag header
-            src.advance(TAG_HEADER_SIZE);
-            self.position += TAG_HEADER_SIZE as u64;
-            self.last_tag_size = 0; // Lost context
-            // Return None here as well to indicate progress (skipping header)
-            // without producing a full item. Let the next call handle PreviousTagSize.
-            trace!("Skipped large tag header, returning None to yield.");
+            self.try_resync(src);
+            return Ok(None);
+        }
+
+        if self.suspect_boundary && !Self::is_plausible_boundary(src) {
+            warn!(
+                "Implausible tag header after corrupt data (type {}, size {}). Attempting resync.",
+                tag_type, data_size
+            );
+            self.try_resync(src);
             return Ok(None);
         }
 
@@ -334,6 +362,7 @@ impl Decoder for FlvDecoder {
         );
         self.last_tag_size = total_tag_size as u32;
         self.expecting_tag_header = false;
+        self.suspect_boundary = false;
         Ok(Some(FlvData::Tag(tag)))
     }
 
@@ -389,6 +418,11 @@ impl<R: AsyncRead + Unpin> FlvDecoderStream<R> {
     pub fn position(&self) -> u64 {
         self.framed.decoder().position()
     }
+
+    /// Total number of bytes discarded while resynchronizing after corrupt data.
+    pub fn skipped_bytes(&self) -> u64 {
+        self.framed.decoder().skipped_bytes()
+    }
 }
 
 impl<R: AsyncRead + Unpin> Stream for FlvDecoderStream<R> {
@@ -758,6 +792,62 @@ mod tests {
         assert_eq!(&buffer[..], &[0x00, 0x00, 0x00, 0x10]);
     }
 
+    #[test]
+    fn test_decode_tag_payload_is_not_copied() {
+        let mut decoder = FlvDecoder {
+            header_parsed: true,
+            expecting_tag_header: true,
+            ..Default::default()
+        };
+        let mut buffer = BytesMut::new();
+        buffer.extend_from_slice(&[
+            0x09, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+            0x17, 0x01, 0x00, 0x00, 0x00, //
+            0x00, 0x00, 0x00, 0x10,
+        ]);
+        let payload_start = buffer.as_ptr() as usize + TAG_HEADER_SIZE;
+
+        let Some(FlvData::Tag(tag)) = decoder.decode(&mut buffer).unwrap() else {
+            panic!("Expected Tag");
+        };
+        // The payload is a view into the decode buffer
+        assert_eq!(tag.data().as_ptr() as usize, payload_start);
+    }
+
+    #[test]
+    fn test_decode_resyncs_after_corrupt_tag_size() {
+        init_tracing();
+        let mut decoder = FlvDecoder::default();
+        let mut buffer = BytesMut::new();
+        decoder.header_parsed = true;
+        decoder.expecting_tag_header = true;
+
+        buffer.extend_from_slice(&[
+            // Audio tag claiming 3 data bytes but carrying 5
+            0x08, 0x00, 0x00, 0x03, 0x00, 0x00, 0x64, 0x00, 0x00, 0x00, 0x00, //
+            0xAF, 0x01, 0x11, 0x22, 0x33, //
+            0x00, 0x00, 0x00, 0x10, // PreviousTagSize of the real tag
+            // Video tag, 5 data bytes
+            0x09, 0x00, 0x00, 0x05, 0x00, 0x00, 0xC8, 0x00, 0x00, 0x00, 0x00, //
+            0x17, 0x01, 0x00, 0x00, 0x00, //
+            0x00, 0x00, 0x00, 0x10,
+        ]);
+
+        let mut tags = Vec::new();
+        while let Some(item) = decoder.decode_eof(&mut buffer).unwrap() {
+            if let FlvData::Tag(tag) = item {
+                tags.push(tag);
+            }
+        }
+
+        // The truncated audio tag is still emitted, then the decoder finds the video tag again
+        assert_eq!(tags.len(), 2);
+        assert_eq!(tags[1].tag_type(), FlvTagType::Video);
+        assert_eq!(tags[1].timestamp_ms, 200);
+        // The stray payload bytes were read as part of a PreviousTagSize; the rest of it is skipped
+        assert_eq!(decoder.skipped_bytes(), 2);
+    }
+
     #[test]
     fn test_decode_incomplete_tag_data_arrival() {
         init_tracing();
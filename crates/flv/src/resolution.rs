@@ -1,5 +1 @@
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub struct Resolution {
-    pub width: f32,
-    pub height: f32,
-}
+pub use media_types::Resolution;
@@ -6,9 +6,13 @@ use bytes_util::BytesCursorExt;
 use tracing::{debug, trace};
 
 use crate::audio::{AudioFourCC, SoundFormat};
+use crate::avc::{AvcSeiContext, SeiMessage};
+use crate::hevc::{HdrInfo, HevcPacket};
+use crate::multitrack::MultitrackPacket;
 use crate::resolution::Resolution;
 use crate::video::{EnhancedPacketType, VideoCodecId, VideoFourCC, VideoFrameType};
 use crate::{framing, framing::ParsedTagHeader};
+use media_types::StreamDescription;
 
 use super::audio::AudioData;
 use super::script::ScriptData;
@@ -94,9 +98,17 @@ impl TagClass {
         let keyframe = ((first_byte >> 4) & 0x07) == VideoFrameType::KeyFrame as u8;
 
         if enhanced {
-            let packet_type = EnhancedPacketType::from(first_byte & 0x0F);
+            // Multitrack payloads move the packet type to the second byte and the (first
+            // track's) fourCC one byte further.
+            let multitrack = MultitrackPacket::is_multitrack(FlvTagType::Video, data);
+            let (packet_type, fourcc_at) = if multitrack {
+                (data.get(1).map_or(0xFF, |b| b & 0x0F), 2)
+            } else {
+                (first_byte & 0x0F, 1)
+            };
+            let packet_type = EnhancedPacketType::from(packet_type);
             let codec = data
-                .get(1..5)
+                .get(fourcc_at..fourcc_at + 4)
                 .and_then(|bytes| <[u8; 4]>::try_from(bytes).ok())
                 .and_then(|bytes| VideoFourCC::try_from(bytes).ok())
                 .map(|codec| match codec {
@@ -108,11 +120,15 @@ impl TagClass {
                 });
             let coded_frames = packet_type == EnhancedPacketType::CODED_FRAMES
                 || packet_type == EnhancedPacketType::CODED_FRAMES_X;
+            // AV1 may carry its configuration record inside an MPEG-2 TS video descriptor.
+            let sequence_start = packet_type == EnhancedPacketType::SEQUENCE_START
+                || (packet_type == EnhancedPacketType::MPEG2_SEQUENCE_START
+                    && codec == Some(CodecKind::Av1));
 
             return Self {
                 keyframe,
-                keyframe_media: keyframe && coded_frames && data.len() >= 5,
-                sequence_header: packet_type == EnhancedPacketType::SEQUENCE_START,
+                keyframe_media: keyframe && coded_frames && data.len() >= fourcc_at + 4,
+                sequence_header: sequence_start,
                 end_of_sequence: packet_type == EnhancedPacketType::SEQUENCE_END,
                 enhanced: true,
                 codec,
@@ -156,9 +172,14 @@ impl TagClass {
         };
 
         if sound_format == SoundFormat::ExHeader {
-            let packet_type = first_byte & 0x0F;
+            let multitrack = MultitrackPacket::is_multitrack(FlvTagType::Audio, data);
+            let (packet_type, fourcc_at) = if multitrack {
+                (data.get(1).map_or(0xFF, |b| b & 0x0F), 2)
+            } else {
+                (first_byte & 0x0F, 1)
+            };
             let codec = data
-                .get(1..5)
+                .get(fourcc_at..fourcc_at + 4)
                 .and_then(|bytes| <[u8; 4]>::try_from(bytes).ok())
                 .and_then(|bytes| AudioFourCC::from_u32(u32::from_be_bytes(bytes)).ok())
                 .map(|codec| match codec {
@@ -414,7 +435,7 @@ impl FlvTag {
             Ok(video_data) => {
                 let body = video_data.body;
                 body.get_video_resolution()
-                    .filter(|res| res.width > 0.0 && res.height > 0.0)
+                    .filter(|res| res.width > 0 && res.height > 0)
             }
             Err(e) => {
                 debug!(
@@ -427,6 +448,121 @@ impl FlvTag {
         }
     }
 
+    /// Codec, profile, level, bit depth, resolution and HDR signalling of a
+    /// video sequence header.
+    pub fn get_stream_description(&self) -> Option<StreamDescription> {
+        if self.is_filtered || self.tag_type != FlvTagType::Video || self.data.len() < 5 {
+            return None;
+        }
+        let mut reader = std::io::Cursor::new(self.data.clone());
+        VideoData::demux(&mut reader)
+            .ok()?
+            .body
+            .get_stream_description()
+    }
+
+    /// Colour description and HDR metadata of an HEVC sequence header.
+    pub fn get_hdr_info(&self) -> Option<HdrInfo> {
+        if !self.is_hevc_video() || self.data.len() < 5 {
+            return None;
+        }
+        let mut reader = std::io::Cursor::new(self.data.clone());
+        VideoData::demux(&mut reader).ok()?.body.get_hdr_info()
+    }
+
+    /// Update `info` with the HDR SEI messages of an HEVC frame whose NAL units
+    /// carry `length_size`-byte length prefixes. Returns whether anything was
+    /// found.
+    pub fn update_hdr_info(&self, info: &mut HdrInfo, length_size: usize) -> bool {
+        if !self.is_hevc_video() || self.data.len() < 5 {
+            return false;
+        }
+        let mut reader = std::io::Cursor::new(self.data.clone());
+        match VideoData::demux(&mut reader).map(|video| video.body) {
+            Ok(body) => match body.hevc_packet() {
+                Some(HevcPacket::Nalu { data, .. }) => info.update_from_nalus(data, length_size),
+                _ => false,
+            },
+            Err(_) => false,
+        }
+    }
+
+    /// SEI parsing context of an AVC sequence header.
+    pub fn get_avc_sei_context(&self) -> Option<AvcSeiContext> {
+        if !self.is_avc_video() || self.data.len() < 5 {
+            return None;
+        }
+        let mut reader = std::io::Cursor::new(self.data.clone());
+        VideoData::demux(&mut reader)
+            .ok()?
+            .body
+            .avc_packet()?
+            .get_sei_context()
+    }
+
+    /// SEI messages of an AVC frame, read with the context of the sequence
+    /// header in effect.
+    pub fn avc_sei_messages(&self, context: &AvcSeiContext) -> Vec<SeiMessage> {
+        if !self.is_avc_video() || self.data.len() < 5 {
+            return Vec::new();
+        }
+        let mut reader = std::io::Cursor::new(self.data.clone());
+        match VideoData::demux(&mut reader) {
+            Ok(video) => video
+                .body
+                .avc_packet()
+                .map_or_else(Vec::new, |packet| packet.sei_messages(context)),
+            Err(_) => Vec::new(),
+        }
+    }
+
+    fn is_avc_video(&self) -> bool {
+        !self.is_filtered
+            && self.tag_type == FlvTagType::Video
+            && self.class.codec == Some(CodecKind::Avc)
+    }
+
+    fn is_hevc_video(&self) -> bool {
+        !self.is_filtered
+            && self.tag_type == FlvTagType::Video
+            && self.class.codec == Some(CodecKind::Hevc)
+    }
+
+    /// Video codec of the tag, for both legacy and enhanced (fourCC) payloads.
+    pub fn get_video_codec(&self) -> Option<CodecKind> {
+        if self.tag_type != FlvTagType::Video {
+            return None;
+        }
+        self.class.codec
+    }
+
+    /// FourCC of an Enhanced RTMP video tag, or `None` for legacy and non-video tags.
+    ///
+    /// For multitrack tags this is the codec of the first track.
+    pub fn get_video_fourcc(&self) -> Option<VideoFourCC> {
+        if self.tag_type != FlvTagType::Video || !self.class.enhanced {
+            return None;
+        }
+        let at = if self.is_multitrack() { 2 } else { 1 };
+        let bytes = <[u8; 4]>::try_from(self.data.get(at..at + 4)?).ok()?;
+        VideoFourCC::try_from(bytes).ok()
+    }
+
+    /// Whether this is an E-RTMP v2 multitrack audio or video tag.
+    pub fn is_multitrack(&self) -> bool {
+        !self.is_filtered && MultitrackPacket::is_multitrack(self.tag_type, &self.data)
+    }
+
+    /// Parse the tracks of a multitrack tag.
+    ///
+    /// Returns `Ok(None)` for tags that carry only the default track.
+    pub fn multitrack(&self) -> std::io::Result<Option<MultitrackPacket>> {
+        if self.is_filtered {
+            return Ok(None);
+        }
+        MultitrackPacket::parse(self.tag_type, &self.data)
+    }
+
     pub fn get_video_codec_id(&self) -> Option<VideoCodecId> {
         if self.tag_type != FlvTagType::Video || self.class.enhanced {
             return None;
@@ -490,7 +626,7 @@ impl FlvTag {
 /// - ScriptData(18)
 ///
 #[repr(u8)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum FlvTagType {
     Audio = 8,
     Video = 9,
@@ -693,6 +829,60 @@ mod tests {
         assert!(!tag.is_video_sequence_header());
     }
 
+    #[test]
+    fn video_codec_covers_enhanced_and_legacy_payloads() {
+        let enhanced = video_tag(&[0x90, b'h', b'v', b'c', b'1', 0, 0, 0]);
+        assert_eq!(enhanced.get_video_codec(), Some(CodecKind::Hevc));
+        assert_eq!(enhanced.get_video_codec_id(), None);
+
+        let legacy = video_tag(&[0x1C, 0x00, 0, 0, 0]);
+        assert_eq!(legacy.get_video_codec(), Some(CodecKind::Hevc));
+        assert_eq!(legacy.get_video_codec_id(), Some(VideoCodecId::LegacyHevc));
+
+        assert_eq!(audio_tag(&[0xAF, 0x00]).get_video_codec(), None);
+    }
+
+    #[test]
+    fn enhanced_av1_sequence_starts_are_sequence_headers() {
+        // KeyFrame + SequenceStart (av01)
+        let tag = video_tag(&[0x90, b'a', b'v', b'0', b'1', 0x81, 0x0D, 0x0C, 0x00]);
+        assert!(tag.is_video_sequence_header());
+        assert_eq!(tag.get_video_fourcc(), Some(VideoFourCC::Av01));
+        assert_eq!(tag.get_video_codec(), Some(CodecKind::Av1));
+
+        // KeyFrame + MPEG2TSSequenceStart (av01)
+        let tag = video_tag(&[0x95, b'a', b'v', b'0', b'1', 0x80, 0x04, 0x81, 0x0D]);
+        assert!(tag.is_video_sequence_header());
+
+        // MPEG2TSSequenceStart is only defined for AV1
+        let tag = video_tag(&[0x95, b'h', b'v', b'c', b'1', 0x01]);
+        assert!(!tag.is_video_sequence_header());
+
+        assert_eq!(video_tag(&[0x17, 0x00, 0, 0, 0]).get_video_fourcc(), None);
+    }
+
+    #[test]
+    fn classifies_multitrack_payloads_by_inner_packet_type() {
+        // KeyFrame + Multitrack, ManyTracks + CodedFrames (hvc1)
+        let tag = video_tag(&[
+            0x96, 0x11, b'h', b'v', b'c', b'1', 0x00, 0x00, 0x00, 0x01, 0xAA,
+        ]);
+        assert!(tag.is_multitrack());
+        assert!(tag.is_key_frame_nalu());
+        assert_eq!(tag.get_video_codec(), Some(CodecKind::Hevc));
+        assert_eq!(tag.get_video_fourcc(), Some(VideoFourCC::Hvc1));
+
+        // KeyFrame + Multitrack, OneTrack + SequenceStart (av01)
+        let tag = video_tag(&[0x96, 0x00, b'a', b'v', b'0', b'1', 0x01, 0x81]);
+        assert!(tag.is_video_sequence_header());
+
+        // ExHeader audio, Multitrack, OneTrack + SequenceStart (mp4a)
+        let tag = audio_tag(&[0x95, 0x00, b'm', b'p', b'4', b'a', 0x01, 0x12, 0x10]);
+        assert!(tag.is_multitrack());
+        assert!(tag.is_audio_sequence_header());
+        assert_eq!(tag.classification().codec, Some(CodecKind::Aac));
+    }
+
     #[test]
     fn empty_payload_predicates_do_not_panic() {
         let video = video_tag(&[]);
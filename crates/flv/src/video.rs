@@ -79,9 +79,10 @@ use h265::HEVCDecoderConfigurationRecord;
 use tracing::debug;
 
 use super::av1::Av1Packet;
-use super::hevc::HevcPacket;
+use super::hevc::{HdrInfo, HevcPacket};
 use crate::avc::AvcPacket;
 use crate::resolution::Resolution;
+use media_types::{StreamDescription, VideoCodec};
 
 /// Represents the type of video frame in an FLV video tag
 #[repr(u8)]
@@ -214,6 +215,19 @@ impl TryFrom<u8> for VideoCodecId {
     }
 }
 
+impl VideoCodecId {
+    /// The shared codec of this codec id, if it has one.
+    ///
+    /// `ExHeader` carries its codec as a [`VideoFourCC`] instead.
+    pub fn video_codec(&self) -> Option<VideoCodec> {
+        match self {
+            Self::Avc => Some(VideoCodec::Avc),
+            Self::LegacyHevc => Some(VideoCodec::Hevc),
+            _ => None,
+        }
+    }
+}
+
 /// Represents a video codec using the FourCC (four character code) identification
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum VideoFourCC {
@@ -264,6 +278,18 @@ impl VideoFourCC {
     }
 }
 
+impl From<VideoFourCC> for VideoCodec {
+    fn from(fourcc: VideoFourCC) -> Self {
+        match fourcc {
+            VideoFourCC::Avc1 => VideoCodec::Avc,
+            VideoFourCC::Hvc1 => VideoCodec::Hevc,
+            VideoFourCC::Vp08 => VideoCodec::Vp8,
+            VideoFourCC::Vp09 => VideoCodec::Vp9,
+            VideoFourCC::Av01 => VideoCodec::Av1,
+        }
+    }
+}
+
 impl TryFrom<u32> for VideoFourCC {
     type Error = std::io::Error;
 
@@ -409,6 +435,8 @@ impl EnhancedPacketType {
     pub const METADATA: Self = Self(4);
     /// MPEG-2 Sequence Start
     pub const MPEG2_SEQUENCE_START: Self = Self(5);
+    /// Multitrack (E-RTMP v2), see [`crate::multitrack`]
+    pub const MULTITRACK: Self = Self(6);
 }
 
 impl From<u8> for EnhancedPacketType {
@@ -426,6 +454,7 @@ impl std::fmt::Display for EnhancedPacketType {
             3 => write!(f, "CodedFramesX"),
             4 => write!(f, "Metadata"),
             5 => write!(f, "Mpeg2SequenceStart"),
+            6 => write!(f, "Multitrack"),
             _ => write!(f, "Unknown({})", self.0),
         }
     }
@@ -623,6 +652,47 @@ impl VideoTagBody {
             _ => None,
         }
     }
+
+    /// Codec, profile, level, bit depth, resolution and HDR signalling of a
+    /// sequence header.
+    pub fn get_stream_description(&self) -> Option<StreamDescription> {
+        match self {
+            VideoTagBody::Avc(avc_data) | VideoTagBody::Enhanced(EnhancedPacket::Avc(avc_data)) => {
+                avc_data.get_stream_description()
+            }
+            VideoTagBody::Hevc(hevc_data)
+            | VideoTagBody::Enhanced(EnhancedPacket::Hevc(hevc_data)) => {
+                hevc_data.get_stream_description()
+            }
+            VideoTagBody::Enhanced(EnhancedPacket::Av1(av1_data)) => {
+                av1_data.get_stream_description()
+            }
+            _ => None,
+        }
+    }
+
+    /// The HEVC packet of a legacy or enhanced HEVC tag.
+    pub fn hevc_packet(&self) -> Option<&HevcPacket> {
+        match self {
+            VideoTagBody::Hevc(hevc_data)
+            | VideoTagBody::Enhanced(EnhancedPacket::Hevc(hevc_data)) => Some(hevc_data),
+            _ => None,
+        }
+    }
+
+    pub fn get_hdr_info(&self) -> Option<HdrInfo> {
+        self.hevc_packet()?.get_hdr_info()
+    }
+
+    /// The AVC packet of a legacy or enhanced AVC tag.
+    pub fn avc_packet(&self) -> Option<&AvcPacket> {
+        match self {
+            VideoTagBody::Avc(avc_data) | VideoTagBody::Enhanced(EnhancedPacket::Avc(avc_data)) => {
+                Some(avc_data)
+            }
+            _ => None,
+        }
+    }
 }
 
 impl std::fmt::Display for VideoData {
@@ -724,7 +794,7 @@ mod tests {
                 5,
                 "Mpeg2SequenceStart",
             ),
-            (EnhancedPacketType(6), 6, "Unknown(6)"),
+            (EnhancedPacketType::MULTITRACK, 6, "Multitrack"),
             (EnhancedPacketType(7), 7, "Unknown(7)"),
         ];
 
@@ -1104,8 +1174,14 @@ mod tests {
             },
         )));
         let resolution = body.get_video_resolution().unwrap();
-        assert!(resolution.width > 0.0);
-        assert!(resolution.height > 0.0);
+        assert!(resolution.width > 0);
+        assert!(resolution.height > 0);
+        let description = body.get_stream_description().unwrap();
+        assert_eq!(description.video_codec, Some(media_types::VideoCodec::Av1));
+        assert_eq!(description.level, Some(13));
+        assert_eq!(description.bit_depth, Some(8));
+        assert_eq!(description.resolution, Some(resolution));
+        assert!(!description.hdr);
 
         // Non-sequence-start returns None
         let body = VideoTagBody::Enhanced(EnhancedPacket::Av1(Av1Packet::Raw(Bytes::new())));
@@ -47,7 +47,27 @@ use crate::{encode, encode::TAG_HEADER_SIZE};
 use amf0::{Amf0Encoder, Amf0Value, Amf0WriteError};
 use bytes::Bytes;
 use std::borrow::Cow;
-use std::io::{self, Seek, Write};
+use std::io::{self, IoSlice, Seek, Write};
+
+/// Write all of `bufs`, letting `writer` take several slices per call.
+fn write_all_vectored<W: Write>(writer: &mut W, mut bufs: &mut [IoSlice<'_>]) -> io::Result<()> {
+    // Skip leading empty slices
+    IoSlice::advance_slices(&mut bufs, 0);
+    while !bufs.is_empty() {
+        match writer.write_vectored(bufs) {
+            Ok(0) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::WriteZero,
+                    "failed to write whole tag",
+                ));
+            }
+            Ok(written) => IoSlice::advance_slices(&mut bufs, written),
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(())
+}
 
 /// FLV Writer for creating FLV files
 pub struct FlvWriter<W: Write + Seek> {
@@ -104,21 +124,23 @@ impl<W: Write + Seek> FlvWriter<W> {
         timestamp_ms: u32,
     ) -> io::Result<()> {
         let data_size = data.len() as u32;
-
-        // Write tag header
         let header_bytes =
             encode::encode_tag_header_bytes(tag_type, is_filtered, data_size, timestamp_ms, 0)?;
-        self.writer.write_all(&header_bytes)?;
-
-        // Write tag data
-        self.writer.write_all(&data)?;
-
-        // Update previous tag size
-        self.previous_tag_size = data_size + TAG_HEADER_SIZE as u32; // data size + tag header size
-
-        // Write previous tag size
-        let prev_bytes = encode::encode_prev_tag_size_bytes(self.previous_tag_size);
-        self.writer.write_all(&prev_bytes)?;
+        // data size + tag header size
+        let previous_tag_size = data_size + TAG_HEADER_SIZE as u32;
+        let prev_bytes = encode::encode_prev_tag_size_bytes(previous_tag_size);
+
+        // Header, payload and previous tag size go out together, straight from the shared
+        // payload: buffered writers only copy the payload when it is smaller than their buffer.
+        write_all_vectored(
+            &mut self.writer,
+            &mut [
+                IoSlice::new(&header_bytes),
+                IoSlice::new(&data),
+                IoSlice::new(&prev_bytes),
+            ],
+        )?;
+        self.previous_tag_size = previous_tag_size;
 
         // Update timestamp for sequential writing
         self.timestamp = timestamp_ms;
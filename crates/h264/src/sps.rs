@@ -7,6 +7,9 @@ use self::color_config::ColorConfig;
 mod frame_crop_info;
 use self::frame_crop_info::FrameCropInfo;
 
+mod hrd_parameters;
+pub use self::hrd_parameters::{HrdParameters, PicTimingInfo};
+
 mod pic_order_count_type1;
 use self::pic_order_count_type1::PicOrderCountType1;
 
@@ -359,8 +362,30 @@ impl Sps {
     ///
     /// Returns an `Sps` struct.
     pub fn parse(reader: impl io::Read) -> io::Result<Self> {
+        Self::parse_fields(&mut BitReader::new(reader)).map(|(sps, _)| sps)
+    }
+
+    /// Parses an Sps from the input bytes, along with the VUI fields after
+    /// `timing_info` that the buffering period and picture timing SEI messages
+    /// depend on.
+    ///
+    /// The `PicTimingInfo` is `None` if the Sps has no VUI parameters.
+    pub fn parse_with_pic_timing_info(
+        reader: impl io::Read,
+    ) -> io::Result<(Self, Option<PicTimingInfo>)> {
         let mut bit_reader = BitReader::new(reader);
+        let (sps, vui_parameters_present_flag) = Self::parse_fields(&mut bit_reader)?;
+        let pic_timing_info = if vui_parameters_present_flag {
+            Some(PicTimingInfo::parse(&mut bit_reader)?)
+        } else {
+            None
+        };
+        Ok((sps, pic_timing_info))
+    }
 
+    /// Parses the Sps up to and including the VUI `timing_info`, leaving the
+    /// reader right after it. Also returns the `vui_parameters_present_flag`.
+    fn parse_fields<T: io::Read>(bit_reader: &mut BitReader<T>) -> io::Result<(Self, bool)> {
         let forbidden_zero_bit = bit_reader.read_bit()?;
         if forbidden_zero_bit {
             return Err(io::Error::new(
@@ -432,7 +457,7 @@ impl Sps {
 
         let sps_ext = match profile_idc {
             100 | 110 | 122 | 244 | 44 | 83 | 86 | 118 | 128 | 138 | 139 | 134 | 135 => {
-                Some(SpsExtended::parse(&mut bit_reader)?)
+                Some(SpsExtended::parse(bit_reader)?)
             }
             _ => None,
         };
@@ -446,7 +471,7 @@ impl Sps {
         if pic_order_cnt_type == 0 {
             log2_max_pic_order_cnt_lsb_minus4 = Some(bit_reader.read_exp_golomb()? as u8);
         } else if pic_order_cnt_type == 1 {
-            pic_order_cnt_type1 = Some(PicOrderCountType1::parse(&mut bit_reader)?)
+            pic_order_cnt_type1 = Some(PicOrderCountType1::parse(bit_reader)?)
         }
 
         let max_num_ref_frames = bit_reader.read_exp_golomb()? as u8;
@@ -466,7 +491,7 @@ impl Sps {
 
         let frame_cropping_flag = bit_reader.read_bit()?;
         if frame_cropping_flag {
-            frame_crop_info = Some(FrameCropInfo::parse(&mut bit_reader)?)
+            frame_crop_info = Some(FrameCropInfo::parse(bit_reader)?)
         }
 
         // setting default values for vui section
@@ -482,7 +507,7 @@ impl Sps {
 
             let aspect_ratio_info_present_flag = bit_reader.read_bit()?;
             if aspect_ratio_info_present_flag {
-                sample_aspect_ratio = Some(SarDimensions::parse(&mut bit_reader)?)
+                sample_aspect_ratio = Some(SarDimensions::parse(bit_reader)?)
             }
 
             let overscan_info_present_flag = bit_reader.read_bit()?;
@@ -492,7 +517,7 @@ impl Sps {
 
             let video_signal_type_present_flag = bit_reader.read_bit()?;
             if video_signal_type_present_flag {
-                color_config = Some(ColorConfig::parse(&mut bit_reader)?)
+                color_config = Some(ColorConfig::parse(bit_reader)?)
             }
 
             let chroma_loc_info_present_flag = bit_reader.read_bit()?;
@@ -510,16 +535,16 @@ impl Sps {
             }
 
             if chroma_loc_info_present_flag {
-                chroma_sample_loc = Some(ChromaSampleLoc::parse(&mut bit_reader)?)
+                chroma_sample_loc = Some(ChromaSampleLoc::parse(bit_reader)?)
             }
 
             let timing_info_present_flag = bit_reader.read_bit()?;
             if timing_info_present_flag {
-                timing_info = Some(TimingInfo::parse(&mut bit_reader)?)
+                timing_info = Some(TimingInfo::parse(bit_reader)?)
             }
         }
 
-        Ok(Sps {
+        let sps = Sps {
             nal_ref_idc,
             nal_unit_type: NALUnitType::try_from(nal_unit_type)?,
             profile_idc,
@@ -548,7 +573,18 @@ impl Sps {
             color_config,
             chroma_sample_loc,
             timing_info,
-        })
+        };
+        Ok((sps, vui_parameters_present_flag))
+    }
+
+    /// Parses the Sps struct and its `PicTimingInfo` from a reader that may contain
+    /// emulation prevention bytes.
+    /// Is the same as calling [`Self::parse_with_pic_timing_info`] with an
+    /// [`EmulationPreventionIo`] wrapper.
+    pub fn parse_pic_timing_info_with_emulation_prevention(
+        reader: impl io::Read,
+    ) -> io::Result<(Self, Option<PicTimingInfo>)> {
+        Self::parse_with_pic_timing_info(EmulationPreventionIo::new(reader))
     }
 
     /// Builds the Sps struct into a byte stream.
@@ -0,0 +1,199 @@
+use std::io;
+
+use bytes_util::BitReader;
+use expgolomb::BitReaderExpGolombExt;
+
+/// `HrdParameters` contains the fields of `hrd_parameters()` that decide the
+/// layout of the buffering period and picture timing SEI messages.
+///
+/// The per-`SchedSelIdx` bit rate and CPB size values are read but not kept.
+///
+/// ISO/IEC-14496-10-2022 - E.1.2
+#[derive(Debug, Clone, PartialEq)]
+pub struct HrdParameters {
+    /// The `cpb_cnt_minus1` plus 1 is the number of alternative CPB specifications.
+    ///
+    /// The value of this ranges from \[0, 31\].
+    ///
+    /// ISO/IEC-14496-10-2022 - E.2.2
+    pub cpb_cnt_minus1: u8,
+
+    /// The `initial_cpb_removal_delay_length_minus1` plus 1 is the bit length of
+    /// the `initial_cpb_removal_delay` and `initial_cpb_removal_delay_offset` fields
+    /// of the buffering period SEI message.
+    ///
+    /// It is comprised of 5 bits. ISO/IEC-14496-10-2022 - E.2.2
+    pub initial_cpb_removal_delay_length_minus1: u8,
+
+    /// The `cpb_removal_delay_length_minus1` plus 1 is the bit length of the
+    /// `cpb_removal_delay` field of the picture timing SEI message.
+    ///
+    /// It is comprised of 5 bits. ISO/IEC-14496-10-2022 - E.2.2
+    pub cpb_removal_delay_length_minus1: u8,
+
+    /// The `dpb_output_delay_length_minus1` plus 1 is the bit length of the
+    /// `dpb_output_delay` field of the picture timing SEI message.
+    ///
+    /// It is comprised of 5 bits. ISO/IEC-14496-10-2022 - E.2.2
+    pub dpb_output_delay_length_minus1: u8,
+
+    /// The `time_offset_length` is the bit length of the `time_offset` field of
+    /// the picture timing SEI message. 0 means `time_offset` is not coded.
+    ///
+    /// It is comprised of 5 bits. ISO/IEC-14496-10-2022 - E.2.2
+    pub time_offset_length: u8,
+}
+
+impl HrdParameters {
+    /// Parses the fields of `hrd_parameters()` from a bitstream.
+    /// Returns a `HrdParameters` struct.
+    pub fn parse<T: io::Read>(reader: &mut BitReader<T>) -> io::Result<Self> {
+        let cpb_cnt_minus1 = reader.read_exp_golomb()?;
+        if cpb_cnt_minus1 > 31 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "cpb_cnt_minus1 must be in the range [0, 31]",
+            ));
+        }
+        // bit_rate_scale and cpb_size_scale
+        reader.read_bits(8)?;
+        for _ in 0..=cpb_cnt_minus1 {
+            // bit_rate_value_minus1, cpb_size_value_minus1 and cbr_flag
+            reader.read_exp_golomb()?;
+            reader.read_exp_golomb()?;
+            reader.read_bit()?;
+        }
+
+        Ok(HrdParameters {
+            cpb_cnt_minus1: cpb_cnt_minus1 as u8,
+            initial_cpb_removal_delay_length_minus1: reader.read_bits(5)? as u8,
+            cpb_removal_delay_length_minus1: reader.read_bits(5)? as u8,
+            dpb_output_delay_length_minus1: reader.read_bits(5)? as u8,
+            time_offset_length: reader.read_bits(5)? as u8,
+        })
+    }
+}
+
+/// `PicTimingInfo` contains the VUI fields that follow `timing_info`, which
+/// are needed to read the buffering period and picture timing SEI messages.
+///
+/// This contains the following fields: the NAL and VCL `hrd_parameters()`,
+/// `low_delay_hrd_flag` and `pic_struct_present_flag`.
+///
+/// ISO/IEC-14496-10-2022 - E.1.1
+#[derive(Debug, Clone, PartialEq)]
+pub struct PicTimingInfo {
+    /// The NAL HRD parameters, set when `nal_hrd_parameters_present_flag == 1`.
+    pub nal_hrd: Option<HrdParameters>,
+
+    /// The VCL HRD parameters, set when `vcl_hrd_parameters_present_flag == 1`.
+    pub vcl_hrd: Option<HrdParameters>,
+
+    /// The `low_delay_hrd_flag` is a single bit, only coded when either HRD is present.
+    ///
+    /// ISO/IEC-14496-10-2022 - E.2.1
+    pub low_delay_hrd_flag: bool,
+
+    /// The `pic_struct_present_flag` is a single bit.
+    ///
+    /// 1 means picture timing SEI messages carry `pic_struct`.
+    ///
+    /// ISO/IEC-14496-10-2022 - E.2.1
+    pub pic_struct_present_flag: bool,
+}
+
+impl PicTimingInfo {
+    /// Parses the VUI fields from `nal_hrd_parameters_present_flag` up to
+    /// `pic_struct_present_flag` from a bitstream.
+    /// Returns a `PicTimingInfo` struct.
+    pub fn parse<T: io::Read>(reader: &mut BitReader<T>) -> io::Result<Self> {
+        let nal_hrd = if reader.read_bit()? {
+            Some(HrdParameters::parse(reader)?)
+        } else {
+            None
+        };
+        let vcl_hrd = if reader.read_bit()? {
+            Some(HrdParameters::parse(reader)?)
+        } else {
+            None
+        };
+        let low_delay_hrd_flag = if nal_hrd.is_some() || vcl_hrd.is_some() {
+            reader.read_bit()?
+        } else {
+            false
+        };
+        let pic_struct_present_flag = reader.read_bit()?;
+
+        Ok(PicTimingInfo {
+            nal_hrd,
+            vcl_hrd,
+            low_delay_hrd_flag,
+            pic_struct_present_flag,
+        })
+    }
+
+    /// The HRD parameters that give the delay field lengths of the SEI
+    /// messages, if any. This is `CpbDpbDelaysPresentFlag` in the spec.
+    ///
+    /// Both HRDs must use the same lengths when present, so the NAL HRD is preferred.
+    pub fn cpb_dpb_delays(&self) -> Option<&HrdParameters> {
+        self.nal_hrd.as_ref().or(self.vcl_hrd.as_ref())
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(all(test, coverage_nightly), coverage(off))]
+mod tests {
+    use bytes_util::{BitReader, BitWriter};
+    use expgolomb::BitWriterExpGolombExt;
+
+    use crate::sps::PicTimingInfo;
+
+    #[test]
+    fn test_parse_pic_timing_info() {
+        let mut data = Vec::new();
+        let mut writer = BitWriter::new(&mut data);
+
+        // nal_hrd_parameters_present_flag
+        writer.write_bit(true).unwrap();
+        // cpb_cnt_minus1
+        writer.write_exp_golomb(0).unwrap();
+        // bit_rate_scale and cpb_size_scale
+        writer.write_bits(0x34, 8).unwrap();
+        // bit_rate_value_minus1, cpb_size_value_minus1 and cbr_flag
+        writer.write_exp_golomb(6249).unwrap();
+        writer.write_exp_golomb(12499).unwrap();
+        writer.write_bit(false).unwrap();
+        // the four length fields
+        writer.write_bits(23, 5).unwrap();
+        writer.write_bits(23, 5).unwrap();
+        writer.write_bits(5, 5).unwrap();
+        writer.write_bits(24, 5).unwrap();
+        // vcl_hrd_parameters_present_flag
+        writer.write_bit(false).unwrap();
+        // low_delay_hrd_flag
+        writer.write_bit(false).unwrap();
+        // pic_struct_present_flag
+        writer.write_bit(true).unwrap();
+        writer.finish().unwrap();
+
+        let mut reader = BitReader::new_from_slice(&data);
+        let info = PicTimingInfo::parse(&mut reader).unwrap();
+
+        assert!(info.vcl_hrd.is_none());
+        assert!(!info.low_delay_hrd_flag);
+        assert!(info.pic_struct_present_flag);
+        let hrd = info.cpb_dpb_delays().unwrap();
+        assert_eq!(hrd.cpb_cnt_minus1, 0);
+        assert_eq!(hrd.initial_cpb_removal_delay_length_minus1, 23);
+        assert_eq!(hrd.cpb_removal_delay_length_minus1, 23);
+        assert_eq!(hrd.dpb_output_delay_length_minus1, 5);
+        assert_eq!(hrd.time_offset_length, 24);
+
+        // Neither HRD: no low_delay_hrd_flag
+        let mut reader = BitReader::new_from_slice([0b0010_0000]);
+        let info = PicTimingInfo::parse(&mut reader).unwrap();
+        assert!(info.cpb_dpb_delays().is_none());
+        assert!(info.pic_struct_present_flag);
+    }
+}
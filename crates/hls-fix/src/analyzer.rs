@@ -8,6 +8,9 @@
 //! - Analyzes different segment types (TS, fMP4 init, fMP4 media)
 //! - Tracks content metadata (codecs, bitrates, resolutions)
 //! - Collects statistics on segments (counts, durations, sizes)
+//! - Records delivery timing from the downloader's delivery markers (duration drift
+//!   against the target duration, media sequence gaps, discontinuities, download times)
+//! - Picks up the service name and now/next events of DVB-sourced TS streams
 //!
 //! ## License
 //!
@@ -23,8 +26,10 @@ use mp4::fragment::{
     Av1ValidationOptions, extract_av1_track_ids_from_init,
     validate_av1_media_segment_with_track_ids_and_options,
 };
+use serde::Serialize;
 use std::fmt;
 use tracing::{debug, info};
+use ts::{EitEvent, OwnedTsParser};
 
 /// AV1 fMP4 sample validation policy.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -38,6 +43,74 @@ pub enum Av1SampleValidationMode {
     StrictAll,
 }
 
+/// Media sequence numbers missing between two consecutive segments
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct SequenceGap {
+    pub first_missing: u64,
+    pub missing: u64,
+}
+
+/// A DVB event, from the EIT present/following table
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct EventInfo {
+    pub name: String,
+    pub text: String,
+    /// ISO 639-2 language code of the name and text
+    pub language: String,
+    /// Start time in seconds since the Unix epoch (UTC)
+    pub start_time: Option<u64>,
+    pub duration_secs: Option<u32>,
+}
+
+impl EventInfo {
+    fn from_event(event: &EitEvent) -> Option<Self> {
+        let short_event = event.short_event.as_ref()?;
+        Some(Self {
+            name: short_event.event_name.clone(),
+            text: short_event.text.clone(),
+            language: String::from_utf8_lossy(&short_event.language_code).into_owned(),
+            start_time: event.start_time,
+            duration_secs: event.duration_secs,
+        })
+    }
+}
+
+/// The DVB service a TS stream belongs to, from its SDT and EIT tables
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ServiceInfo {
+    pub service_name: Option<String>,
+    pub provider_name: Option<String>,
+    pub present_event: Option<EventInfo>,
+    pub following_event: Option<EventInfo>,
+}
+
+impl ServiceInfo {
+    /// Service of the stream's program, `None` if the parser has seen no SDT.
+    ///
+    /// DVB service IDs equal PAT program numbers; without a PAT the first
+    /// service of the SDT is used.
+    pub fn from_parser(parser: &OwnedTsParser) -> Option<Self> {
+        let sdt = parser.sdt()?;
+        let program = parser
+            .pat()
+            .and_then(|pat| pat.program_numbers().first().copied());
+        let service = program
+            .and_then(|program| sdt.service(program))
+            .or_else(|| sdt.services.first())?;
+        let now_next = parser.now_next(service.service_id);
+        Some(Self {
+            service_name: service.service_name.clone(),
+            provider_name: service.provider_name.clone(),
+            present_event: now_next
+                .and_then(|now_next| now_next.present.as_ref())
+                .and_then(EventInfo::from_event),
+            following_event: now_next
+                .and_then(|now_next| now_next.following.as_ref())
+                .and_then(EventInfo::from_event),
+        })
+    }
+}
+
 // Stats structure to hold all the metrics
 #[derive(Debug, Clone)]
 pub struct HlsStats {
@@ -66,6 +139,19 @@ pub struct HlsStats {
     pub last_segment_type: Option<SegmentType>,
     pub last_segment_size: u64,
     pub last_segment_duration: f32,
+
+    // Delivery timing, from segments carrying a delivery marker
+    /// Largest `EXT-X-TARGETDURATION` of the playlists the segments came from
+    pub target_duration: Option<f64>,
+    /// Duration minus target duration of each whole media segment, in seconds
+    pub duration_drifts: Vec<f32>,
+    pub sequence_gaps: Vec<SequenceGap>,
+    /// Discontinuities after the first media segment
+    pub discontinuity_count: u32,
+    pub download_latencies_ms: Vec<u64>,
+
+    /// DVB service of TS segments carrying service information
+    pub service: Option<ServiceInfo>,
 }
 
 impl Default for HlsStats {
@@ -87,6 +173,12 @@ impl Default for HlsStats {
             last_segment_type: None,
             last_segment_size: 0,
             last_segment_duration: 0.0,
+            target_duration: None,
+            duration_drifts: Vec::new(),
+            sequence_gaps: Vec::new(),
+            discontinuity_count: 0,
+            download_latencies_ms: Vec::new(),
+            service: None,
         }
     }
 }
@@ -189,6 +281,23 @@ impl fmt::Display for HlsStats {
             )?;
         }
 
+        if let Some(service) = &self.service {
+            if let Some(name) = &service.service_name {
+                match &service.provider_name {
+                    Some(provider) if !provider.is_empty() => {
+                        writeln!(f, "  Service: {name} ({provider})")?
+                    }
+                    _ => writeln!(f, "  Service: {name}")?,
+                }
+            }
+            if let Some(event) = &service.present_event {
+                writeln!(f, "  Now: {}", event.name)?;
+            }
+            if let Some(event) = &service.following_event {
+                writeln!(f, "  Next: {}", event.name)?;
+            }
+        }
+
         // Last segment info
         if let Some(segment_type) = &self.last_segment_type {
             writeln!(f, "  Last segment:")?;
@@ -209,6 +318,10 @@ pub struct HlsAnalyzer {
     pub stats: HlsStats,
     last_mp4_av1_track_ids: Option<Vec<u32>>,
     av1_validation_mode: Av1SampleValidationMode,
+    last_media_sequence: Option<u64>,
+    seen_media: bool,
+    /// Collects DVB service information across TS segments
+    ts_parser: OwnedTsParser,
 }
 
 impl HlsAnalyzer {
@@ -226,6 +339,55 @@ impl HlsAnalyzer {
     pub fn reset(&mut self) {
         self.stats.reset();
         self.last_mp4_av1_track_ids = None;
+        self.last_media_sequence = None;
+        self.seen_media = false;
+        self.ts_parser.reset();
+    }
+
+    /// Record the delivery timing of a media segment
+    fn record_delivery(&mut self, segment: &HlsData, duration: f32) {
+        if segment.is_discontinuity() && self.seen_media {
+            self.stats.discontinuity_count += 1;
+        }
+        self.seen_media = true;
+
+        let Some(delivery) = segment.delivery() else {
+            return;
+        };
+        if let Some(download_ms) = delivery.download_ms {
+            self.stats.download_latencies_ms.push(download_ms);
+        }
+        if let Some(target_duration) = delivery.target_duration {
+            self.stats.target_duration = Some(
+                self.stats
+                    .target_duration
+                    .map_or(target_duration, |current| current.max(target_duration)),
+            );
+            // Partial segments are shorter than the target by design.
+            if segment.part().is_none() {
+                self.stats
+                    .duration_drifts
+                    .push(duration - target_duration as f32);
+            }
+        }
+
+        // Parts of one segment share its number; a lower number is a playlist
+        // restart, which only re-anchors the sequence.
+        let media_sequence = delivery.media_sequence;
+        if let Some(last) = self.last_media_sequence
+            && media_sequence > last.saturating_add(1)
+        {
+            debug!(
+                after = last,
+                next = media_sequence,
+                "Media sequence gap in output"
+            );
+            self.stats.sequence_gaps.push(SequenceGap {
+                first_missing: last + 1,
+                missing: media_sequence - last - 1,
+            });
+        }
+        self.last_media_sequence = Some(media_sequence);
     }
 
     /// Analyze a segment and update statistics
@@ -242,6 +404,14 @@ impl HlsAnalyzer {
                 let duration = ts_data.segment.duration;
                 self.stats.ts_segments_duration += duration;
                 self.stats.total_duration += duration;
+                self.record_delivery(segment, duration);
+
+                if let Err(error) = self.ts_parser.parse_packets(ts_data.data().clone()) {
+                    debug!(%error, "Failed to parse TS tables");
+                }
+                if let Some(service) = ServiceInfo::from_parser(&self.ts_parser) {
+                    self.stats.service = Some(service);
+                }
 
                 // Update last segment info
                 self.stats.last_segment_type = Some(SegmentType::Ts);
@@ -311,6 +481,7 @@ impl HlsAnalyzer {
                 let duration = media_segment.segment.duration;
                 self.stats.mp4_segments_duration += duration;
                 self.stats.total_duration += duration;
+                self.record_delivery(segment, duration);
 
                 // Update last segment info
                 self.stats.last_segment_type = Some(SegmentType::M4sMedia);
@@ -471,6 +642,53 @@ mod tests {
         assert!(!stats.has_mp4_segments);
     }
 
+    #[test]
+    fn test_analyze_ts_segment_with_service_information() {
+        let mut descriptor = vec![ts::descriptor::TAG_SERVICE, 0, 0x01, 3];
+        descriptor.extend_from_slice(b"ARD");
+        descriptor.push(8);
+        descriptor.extend_from_slice(b"Das Erst");
+        descriptor[1] = (descriptor.len() - 2) as u8;
+        let mut sdt = vec![0x42, 0, 0, 0x00, 0x01, 0xC1, 0x00, 0x00, 0x00, 0x01, 0xFF];
+        sdt.extend_from_slice(&[0x00, 0x01, 0xFC, 0x80, descriptor.len() as u8]);
+        sdt.extend_from_slice(&descriptor);
+        let section_length = sdt.len() - 3 + 4;
+        sdt[1] = 0xF0 | (section_length >> 8) as u8;
+        sdt[2] = section_length as u8;
+        let crc = ts::mpeg2_crc32(&sdt);
+        sdt.extend_from_slice(&crc.to_be_bytes());
+
+        let mut muxer = ts::TsMuxer::new(1, 0x1000);
+        muxer
+            .add_stream(0x100, ts::StreamType::H264, vec![])
+            .unwrap();
+        let mut data = Vec::new();
+        muxer.write_tables(&mut data).unwrap();
+        muxer.write_section(&mut data, ts::PID_SDT, &sdt).unwrap();
+
+        let mut analyzer = HlsAnalyzer::new();
+        analyzer
+            .analyze_segment(&HlsData::ts(
+                MediaSegment {
+                    duration: 2.0,
+                    ..MediaSegment::empty()
+                },
+                Bytes::from(data),
+            ))
+            .unwrap();
+
+        let service = analyzer.stats.service.clone().unwrap();
+        assert_eq!(service.service_name.as_deref(), Some("Das Erst"));
+        assert_eq!(service.provider_name.as_deref(), Some("ARD"));
+        assert_eq!(service.present_event, None);
+        assert!(
+            analyzer
+                .stats
+                .to_string()
+                .contains("Service: Das Erst (ARD)")
+        );
+    }
+
     #[test]
     fn test_analyze_mp4_segments() {
         let mut analyzer = HlsAnalyzer::new();
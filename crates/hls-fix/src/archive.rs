@@ -0,0 +1,471 @@
+//! Playlist archive output for the writer.
+//!
+//! Instead of concatenating segments, each output keeps the segments as
+//! individual files in a directory next to a local playlist that references
+//! them. The playlist is rewritten after every segment as an `EVENT` playlist,
+//! so a recording in progress is already playable and seekable, and becomes a
+//! `VOD` playlist with `EXT-X-ENDLIST` when the output is closed. Segments are
+//! stored exactly as downloaded: discontinuities and init changes are carried
+//! by the playlist instead of being repaired in the media.
+
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{Seek, Write},
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use bytes::Bytes;
+use hls::{HlsData, M4sData};
+use m3u8_rs::{Map, MediaPlaylist, MediaPlaylistType, MediaSegment};
+use mp4::timing::{TrackTiming, parse_fragment_timings, parse_track_timings};
+use pipeline_common::{
+    FormatStrategy, PostWriteAction, SplitReason, WallClockBoundary, WriterConfig, WriterState,
+    expand_filename_template,
+};
+use tracing::{debug, info};
+
+use crate::writer_task::HlsStrategyError;
+
+/// Lowest playlist version allowing floating point `EXTINF` durations.
+const PLAYLIST_VERSION: usize = 3;
+/// `EXT-X-MAP` in a playlist without `EXT-X-I-FRAMES-ONLY` requires version 6.
+const PLAYLIST_VERSION_WITH_MAP: usize = 6;
+
+pub(crate) struct HlsArchiveStrategy {
+    max_file_size: Option<u64>,
+    wall_clock: Option<WallClockBoundary>,
+    /// Directory of the current playlist's segments
+    segment_dir: PathBuf,
+    /// `segment_dir` as referenced from the playlist
+    segment_prefix: String,
+    entries: Vec<MediaSegment>,
+    next_segment: u32,
+    next_init: u32,
+    /// Init segment the current playlist's media refers to
+    current_init: Option<Bytes>,
+    /// The map of `current_init`, attached to the next media entry
+    pending_map: Option<Map>,
+    /// Latest init segment, carried over to the next playlist
+    last_init: Option<Bytes>,
+    tracks: Vec<TrackTiming>,
+    media_duration: f64,
+    last_split_reason: Option<SplitReason>,
+}
+
+impl HlsArchiveStrategy {
+    pub(crate) fn new(max_file_size: Option<u64>) -> Self {
+        Self {
+            max_file_size,
+            wall_clock: None,
+            segment_dir: PathBuf::new(),
+            segment_prefix: String::new(),
+            entries: Vec::new(),
+            next_segment: 0,
+            next_init: 0,
+            current_init: None,
+            pending_map: None,
+            last_init: None,
+            tracks: Vec::new(),
+            media_duration: 0.0,
+            last_split_reason: None,
+        }
+    }
+
+    pub(crate) fn with_wall_clock_split(mut self, interval: Option<Duration>) -> Self {
+        self.wall_clock = interval.map(WallClockBoundary::new);
+        self
+    }
+
+    /// Store a segment file, returning its URI relative to the playlist.
+    fn store(&self, name: &str, data: &[u8]) -> Result<String, HlsStrategyError> {
+        fs::write(self.segment_dir.join(name), data)?;
+        Ok(format!("{}/{}", self.segment_prefix, name))
+    }
+
+    fn store_init(&mut self, data: &Bytes) -> Result<u64, HlsStrategyError> {
+        let uri = self.store(&format!("init_{:03}.mp4", self.next_init), data)?;
+        info!(uri, "Archived init segment");
+        self.next_init += 1;
+        self.tracks = parse_track_timings(data);
+        self.current_init = Some(data.clone());
+        self.last_init = Some(data.clone());
+        self.pending_map = Some(Map {
+            uri,
+            ..Map::default()
+        });
+        Ok(data.len() as u64)
+    }
+
+    /// Duration measured from the fragments, falling back to `EXTINF`.
+    fn media_segment_duration(&self, data: &Bytes, segment: &MediaSegment) -> f64 {
+        let measured = parse_fragment_timings(data, &self.tracks)
+            .ok()
+            .and_then(|timings| {
+                timings.into_iter().find_map(|timing| {
+                    self.tracks
+                        .iter()
+                        .find(|track| track.track_id == timing.track_id && track.timescale > 0)
+                        .map(|track| timing.duration as f64 / f64::from(track.timescale))
+                })
+            })
+            .filter(|duration| *duration > 0.0);
+        measured.unwrap_or_else(|| f64::from(segment.duration))
+    }
+
+    fn store_media(
+        &mut self,
+        segment: &MediaSegment,
+        data: &Bytes,
+        extension: &str,
+        duration: f64,
+    ) -> Result<(), HlsStrategyError> {
+        let uri = self.store(&format!("{:06}.{extension}", self.next_segment), data)?;
+        self.next_segment += 1;
+
+        let map = self.pending_map.take();
+        // The first entry needs no discontinuity; a new init is one by itself.
+        let discontinuity = !self.entries.is_empty() && (segment.discontinuity || map.is_some());
+        self.entries.push(MediaSegment {
+            uri,
+            duration: duration as f32,
+            discontinuity,
+            map,
+            program_date_time: segment.program_date_time,
+            ..MediaSegment::empty()
+        });
+        self.media_duration += duration;
+        Ok(())
+    }
+
+    fn write_playlist(&self, writer: &mut File, ended: bool) -> Result<(), HlsStrategyError> {
+        let has_map = self.entries.iter().any(|entry| entry.map.is_some());
+        let target_duration = self
+            .entries
+            .iter()
+            .map(|entry| f64::from(entry.duration).ceil() as u64)
+            .max()
+            .unwrap_or(0)
+            .max(1);
+        let playlist = MediaPlaylist {
+            version: Some(if has_map {
+                PLAYLIST_VERSION_WITH_MAP
+            } else {
+                PLAYLIST_VERSION
+            }),
+            target_duration,
+            segments: self.entries.clone(),
+            end_list: ended,
+            playlist_type: Some(if ended {
+                MediaPlaylistType::Vod
+            } else {
+                MediaPlaylistType::Event
+            }),
+            ..MediaPlaylist::default()
+        };
+
+        let mut content = Vec::new();
+        playlist.write_to(&mut content)?;
+        writer.set_len(0)?;
+        writer.rewind()?;
+        writer.write_all(&content)?;
+        Ok(())
+    }
+}
+
+/// Directory holding the segments of the playlist at `path`: the playlist's
+/// file name without its extension.
+fn segment_dir_for(path: &Path) -> (PathBuf, String) {
+    let name = path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "segments".to_string());
+    (path.with_file_name(&name), escape_uri_path(&name))
+}
+
+/// Escape the characters of a file name that cannot appear as-is in a relative URI.
+fn escape_uri_path(name: &str) -> String {
+    let mut escaped = String::with_capacity(name.len());
+    for c in name.chars() {
+        match c {
+            '%' | ' ' | '#' | '?' | '"' => escaped.push_str(&format!("%{:02X}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+impl FormatStrategy<HlsData> for HlsArchiveStrategy {
+    type Writer = File;
+    type StrategyError = HlsStrategyError;
+
+    fn create_writer(&self, path: &Path) -> Result<Self::Writer, Self::StrategyError> {
+        debug!("Creating playlist archive at: {}", path.display());
+        fs::create_dir_all(segment_dir_for(path).0)?;
+        let file = OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(path)?;
+        Ok(file)
+    }
+
+    fn write_item(
+        &mut self,
+        writer: &mut Self::Writer,
+        item: &HlsData,
+    ) -> Result<u64, Self::StrategyError> {
+        let bytes_written = match item {
+            HlsData::TsData(ts) => {
+                self.store_media(&ts.segment, ts.data(), "ts", f64::from(ts.segment.duration))?;
+                ts.data().len() as u64
+            }
+            HlsData::M4sData(M4sData::InitSegment(init)) => {
+                if self.current_init.as_ref() == Some(&init.data) {
+                    debug!("Skipping repeated init segment");
+                    return Ok(0);
+                }
+                self.store_init(&init.data)?
+            }
+            HlsData::M4sData(M4sData::Segment(segment)) => {
+                // A playlist opened by a split starts with the init it still needs.
+                let mut bytes_written = 0;
+                if self.current_init.is_none()
+                    && let Some(init) = self.last_init.clone()
+                {
+                    bytes_written += self.store_init(&init)?;
+                }
+                let duration = self.media_segment_duration(&segment.data, &segment.segment);
+                self.store_media(&segment.segment, &segment.data, "m4s", duration)?;
+                bytes_written + segment.data.len() as u64
+            }
+            HlsData::EndMarker(reason) => {
+                self.last_split_reason = reason.clone();
+                return Ok(0);
+            }
+        };
+
+        self.write_playlist(writer, false)?;
+        Ok(bytes_written)
+    }
+
+    fn should_rotate_file(&self, _config: &WriterConfig, state: &WriterState) -> bool {
+        if state.items_written_current_file == 0 {
+            return false;
+        }
+        let size_reached = self
+            .max_file_size
+            .is_some_and(|max_size| max_size > 0 && state.bytes_written_current_file >= max_size);
+        size_reached || self.wall_clock.as_ref().is_some_and(|w| w.is_due())
+    }
+
+    fn next_file_path(&self, config: &WriterConfig, state: &WriterState) -> PathBuf {
+        let file_name =
+            expand_filename_template(&config.file_name_template, Some(state.file_sequence_number));
+        config.base_path.join(format!("{file_name}.m3u8"))
+    }
+
+    fn on_file_open(
+        &mut self,
+        _writer: &mut Self::Writer,
+        path: &Path,
+        _config: &WriterConfig,
+        _state: &WriterState,
+    ) -> Result<u64, Self::StrategyError> {
+        (self.segment_dir, self.segment_prefix) = segment_dir_for(path);
+        self.entries.clear();
+        self.next_segment = 0;
+        self.next_init = 0;
+        self.current_init = None;
+        self.pending_map = None;
+        self.media_duration = 0.0;
+        self.last_split_reason = None;
+        if let Some(wall_clock) = &mut self.wall_clock {
+            wall_clock.restart();
+        }
+
+        info!(path = %path.display(), "Opening playlist archive");
+        Ok(0)
+    }
+
+    fn on_file_close(
+        &mut self,
+        writer: &mut Self::Writer,
+        path: &Path,
+        _config: &WriterConfig,
+        state: &WriterState,
+    ) -> Result<u64, Self::StrategyError> {
+        if self.last_split_reason.is_none()
+            && let Some(max_size) = self.max_file_size
+            && max_size > 0
+            && state.items_written_current_file > 0
+            && state.bytes_written_current_file >= max_size
+        {
+            self.last_split_reason = Some(SplitReason::SizeLimit);
+        }
+        if self.last_split_reason.is_none()
+            && state.items_written_current_file > 0
+            && self.wall_clock.as_ref().is_some_and(|w| w.is_due())
+        {
+            self.last_split_reason = Some(SplitReason::WallClock);
+        }
+
+        self.write_playlist(writer, true)?;
+        info!(
+            path = %path.display(),
+            segments = self.entries.len(),
+            duration_secs = self.media_duration,
+            "Closed playlist archive"
+        );
+        Ok(0)
+    }
+
+    fn after_item_written(
+        &mut self,
+        item: &HlsData,
+        _bytes_written: u64,
+        state: &WriterState,
+    ) -> Result<PostWriteAction, Self::StrategyError> {
+        if matches!(item, HlsData::EndMarker(_)) && state.items_written_current_file > 1 {
+            Ok(PostWriteAction::Rotate)
+        } else {
+            Ok(PostWriteAction::None)
+        }
+    }
+
+    fn current_media_duration_secs(&self) -> f64 {
+        self.media_duration
+    }
+
+    fn close_context(&self) -> Option<SplitReason> {
+        self.last_split_reason.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{HlsWriter, HlsWriterConfig};
+    use pipeline_common::{PipelineError, ProtocolWriter};
+
+    /// Archive `items`, returning the temp dir and the playlists written, by name.
+    fn archive(items: Vec<HlsData>) -> (tempfile::TempDir, Vec<(String, MediaPlaylist)>) {
+        let tempdir = tempfile::tempdir().expect("create temp dir");
+        let mut writer = HlsWriter::new(HlsWriterConfig {
+            output_dir: tempdir.path().to_path_buf(),
+            base_name: "live %i".to_string(),
+            extension: "ts".to_string(),
+            max_file_size: None,
+            wall_clock_split: None,
+            playlist_archive: true,
+            continuous_ts: false,
+            captions: None,
+        });
+
+        let (tx, rx) = tokio::sync::mpsc::channel::<Result<HlsData, PipelineError>>(16);
+        let handle = std::thread::spawn(move || writer.run(rx.into()));
+        for item in items {
+            tx.blocking_send(Ok(item)).unwrap();
+        }
+        drop(tx);
+        handle
+            .join()
+            .expect("writer thread join")
+            .expect("writer ok");
+
+        let mut playlists: Vec<_> = fs::read_dir(tempdir.path())
+            .expect("read_dir")
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|e| e == "m3u8"))
+            .map(|path| {
+                let name = path.file_stem().unwrap().to_string_lossy().into_owned();
+                let content = fs::read(&path).unwrap();
+                (name, m3u8_rs::parse_media_playlist_res(&content).unwrap())
+            })
+            .collect();
+        playlists.sort_by(|a, b| a.0.cmp(&b.0));
+        (tempdir, playlists)
+    }
+
+    fn ts(duration: f32, discontinuity: bool) -> HlsData {
+        HlsData::ts(
+            MediaSegment {
+                duration,
+                discontinuity,
+                ..MediaSegment::empty()
+            },
+            Bytes::from_static(&[0x47; 188]),
+        )
+    }
+
+    #[test]
+    fn writes_vod_playlist_with_discontinuities() {
+        let (dir, playlists) = archive(vec![ts(2.0, false), ts(2.5, false), ts(4.2, true)]);
+        assert_eq!(playlists.len(), 1);
+        let (name, playlist) = &playlists[0];
+
+        assert_eq!(playlist.playlist_type, Some(MediaPlaylistType::Vod));
+        assert!(playlist.end_list);
+        assert_eq!(playlist.target_duration, 5);
+        assert_eq!(playlist.version, Some(PLAYLIST_VERSION));
+        let prefix = name.replace(' ', "%20");
+        let uris: Vec<_> = playlist.segments.iter().map(|s| s.uri.clone()).collect();
+        assert_eq!(
+            uris,
+            [
+                format!("{prefix}/000000.ts"),
+                format!("{prefix}/000001.ts"),
+                format!("{prefix}/000002.ts"),
+            ]
+        );
+        let discontinuities: Vec<_> = playlist.segments.iter().map(|s| s.discontinuity).collect();
+        assert_eq!(discontinuities, [false, false, true]);
+        assert!(dir.path().join(name).join("000002.ts").is_file());
+    }
+
+    #[test]
+    fn new_playlist_after_split_starts_with_the_init() {
+        let init = HlsData::mp4_init(MediaSegment::empty(), Bytes::from_static(b"init"));
+        let media = || {
+            HlsData::mp4_segment(
+                MediaSegment {
+                    duration: 2.0,
+                    ..MediaSegment::empty()
+                },
+                Bytes::from_static(b"media"),
+            )
+        };
+        let (dir, playlists) = archive(vec![
+            init.clone(),
+            media(),
+            init,
+            media(),
+            HlsData::end_marker_with_reason(SplitReason::Discontinuity),
+            media(),
+        ]);
+        assert_eq!(playlists.len(), 2);
+
+        let (first_name, first) = &playlists[0];
+        let maps: Vec<_> = first
+            .segments
+            .iter()
+            .map(|s| s.map.as_ref().map(|map| map.uri.clone()))
+            .collect();
+        // The repeated init is not archived again.
+        let first_prefix = first_name.replace(' ', "%20");
+        assert_eq!(maps, [Some(format!("{first_prefix}/init_000.mp4")), None]);
+        assert_eq!(first.version, Some(PLAYLIST_VERSION_WITH_MAP));
+
+        let (second_name, second) = &playlists[1];
+        let second_prefix = second_name.replace(' ', "%20");
+        assert_eq!(
+            second.segments[0].map.as_ref().map(|map| map.uri.clone()),
+            Some(format!("{second_prefix}/init_000.mp4"))
+        );
+        assert_eq!(
+            fs::read(dir.path().join(second_name).join("init_000.mp4")).unwrap(),
+            b"init"
+        );
+    }
+}
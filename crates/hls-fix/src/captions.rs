@@ -0,0 +1,1176 @@
+//! # Closed caption extraction
+//!
+//! [`CaptionExtractor`] pulls CEA-608 and CEA-708 closed captions out of the video of
+//! MPEG-TS segments and turns them into timed cues, which [`CaptionFormat`] renders as SRT or
+//! WebVTT sidecars.
+//!
+//! Captions travel as ATSC A/53 `cc_data` in SEI messages (ITU-T T.35 registered user data,
+//! `GA94`) of H.264 and H.265 access units. Each `cc_data` triplet carries either a byte pair
+//! of one of the two CEA-608 fields or a piece of a CEA-708 DTVCC packet.
+//!
+//! - CEA-608: channels CC1 to CC4 in pop-on, roll-up and paint-on modes.
+//! - CEA-708: the text of the visible windows of every caption service. Positioning, pen
+//!   styles and colors are not kept.
+//!
+//! Cue times are milliseconds from the start of the file. Segments are laid end to end by
+//! their playlist durations, so cues line up with the concatenated output even across
+//! discontinuities. fMP4 segments advance the timeline but are not searched for captions.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write as _;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use bytes::{Bytes, BytesMut};
+use h264::EmulationPreventionIo;
+use hls::{HlsData, M4sData};
+use tracing::debug;
+use ts::{OwnedTsParser, PesHeader, StreamType, TS_PACKET_SIZE, TsPacket, timestamp_diff};
+
+use crate::transmux::annex_b_nal_units;
+
+/// SEI payload type of ITU-T T.35 registered user data.
+const SEI_USER_DATA_REGISTERED: usize = 4;
+
+/// T.35 header of ATSC A/53 caption data: country (United States), provider (ATSC), user
+/// identifier `GA94` and user data type `cc_data`.
+const A53_CC_DATA_HEADER: [u8; 8] = [0xB5, 0x00, 0x31, b'G', b'A', b'9', b'4', 0x03];
+
+/// Rows and columns of the CEA-608 caption grid.
+const CEA608_ROWS: usize = 15;
+const CEA608_COLUMNS: usize = 32;
+
+/// Row (1-based) addressed by a preamble address code, by the low bits of its first byte.
+const PAC_ROWS: [usize; 8] = [11, 1, 3, 12, 14, 5, 7, 9];
+
+/// CEA-608 special characters, second byte 0x30 to 0x3F. 0x39 is a transparent space.
+const SPECIAL_CHARS: &str = "®°½¿™¢£♪à èâêîôû";
+
+/// CEA-608 extended characters, second byte 0x20 to 0x3F after a first byte of 0x12 or 0x13.
+const EXTENDED_CHARS: [&str; 2] = [
+    "ÁÉÓÚÜü‘¡*'—©℠•“”ÀÂÇÈÊËëÎÏïÔÙùÛ«»",
+    "ÃãÍÌìÒòÕõ{}\\^_|~ÄäÖöß¥¤│ÅåØø┌┐└┘",
+];
+
+/// Where a cue was carried.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum CaptionChannel {
+    /// CEA-608 channel CC1 to CC4
+    Cea608(u8),
+    /// CEA-708 caption service 1 to 63
+    Cea708(u8),
+}
+
+impl CaptionChannel {
+    /// Short name used in sidecar file names, e.g. `cc1` or `service1`.
+    pub fn label(&self) -> String {
+        match self {
+            CaptionChannel::Cea608(channel) => format!("cc{channel}"),
+            CaptionChannel::Cea708(service) => format!("service{service}"),
+        }
+    }
+}
+
+/// A caption shown from `start_ms` to `end_ms`, in milliseconds from the start of the file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CaptionCue {
+    pub channel: CaptionChannel,
+    pub start_ms: u64,
+    pub end_ms: u64,
+    /// Caption rows, separated by newlines
+    pub text: String,
+}
+
+/// Subtitle format of caption sidecars.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CaptionFormat {
+    Srt,
+    WebVtt,
+}
+
+impl CaptionFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            CaptionFormat::Srt => "srt",
+            CaptionFormat::WebVtt => "vtt",
+        }
+    }
+
+    /// Sidecar of `path` holding the captions of `channel`, e.g. `name.cc1.srt` for `name.ts`.
+    pub fn sidecar_path(&self, path: &Path, channel: CaptionChannel) -> PathBuf {
+        path.with_extension(format!("{}.{}", channel.label(), self.extension()))
+    }
+
+    /// Render cues as a subtitle document.
+    pub fn render(&self, cues: &[CaptionCue]) -> String {
+        let mut out = String::new();
+        match self {
+            CaptionFormat::Srt => {
+                for (index, cue) in cues.iter().enumerate() {
+                    let _ = write!(
+                        out,
+                        "{}\n{} --> {}\n{}\n\n",
+                        index + 1,
+                        format_time(cue.start_ms, ','),
+                        format_time(cue.end_ms, ','),
+                        cue.text
+                    );
+                }
+            }
+            CaptionFormat::WebVtt => {
+                out.push_str("WEBVTT\n\n");
+                for cue in cues {
+                    let text = cue
+                        .text
+                        .replace('&', "&amp;")
+                        .replace('<', "&lt;")
+                        .replace('>', "&gt;");
+                    let _ = write!(
+                        out,
+                        "{} --> {}\n{}\n\n",
+                        format_time(cue.start_ms, '.'),
+                        format_time(cue.end_ms, '.'),
+                        text
+                    );
+                }
+            }
+        }
+        out
+    }
+}
+
+impl FromStr for CaptionFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "srt" => Ok(CaptionFormat::Srt),
+            "vtt" | "webvtt" => Ok(CaptionFormat::WebVtt),
+            other => Err(format!("unknown caption format: {other}")),
+        }
+    }
+}
+
+/// `HH:MM:SS<separator>mmm`
+fn format_time(ms: u64, separator: char) -> String {
+    format!(
+        "{:02}:{:02}:{:02}{separator}{:03}",
+        ms / 3_600_000,
+        ms / 60_000 % 60,
+        ms / 1_000 % 60,
+        ms % 1_000
+    )
+}
+
+/// Turns the screen updates of one channel into cues.
+#[derive(Debug, Default)]
+struct CueTrack {
+    /// Start and text of the caption on screen
+    current: Option<(u64, String)>,
+}
+
+impl CueTrack {
+    /// Show `text` from `time_ms` on, returning the cue it replaces.
+    fn update(&mut self, time_ms: u64, text: String) -> Option<(u64, u64, String)> {
+        if self.current.as_ref().map(|(_, current)| current.as_str()) == Some(text.as_str()) {
+            return None;
+        }
+        let previous = self.current.take();
+        if !text.is_empty() {
+            self.current = Some((time_ms, text));
+        }
+        previous
+            .filter(|(start, _)| *start < time_ms)
+            .map(|(start, text)| (start, time_ms, text))
+    }
+}
+
+/// Extracts closed captions from HLS segments.
+#[derive(Debug, Default)]
+pub struct CaptionExtractor {
+    /// Elementary stream types of the video PIDs, from the last PMT seen
+    video_streams: HashMap<u16, StreamType>,
+    /// Start of the next segment on the file timeline, in milliseconds
+    next_segment_ms: u64,
+    /// CEA-608 decoders of field 1 and field 2
+    cea608: [Cea608Decoder; 2],
+    dtvcc: DtvccPacket,
+    services: BTreeMap<u8, Cea708Service>,
+    tracks: BTreeMap<CaptionChannel, CueTrack>,
+}
+
+impl CaptionExtractor {
+    pub fn new() -> Self {
+        Self {
+            cea608: [Cea608Decoder::new(0), Cea608Decoder::new(1)],
+            ..Self::default()
+        }
+    }
+
+    /// Extract the captions of one segment, returning the cues it completed.
+    pub fn push(&mut self, item: &HlsData) -> Vec<CaptionCue> {
+        let mut cues = Vec::new();
+        match item {
+            HlsData::TsData(ts) => {
+                let start_ms = self.next_segment_ms;
+                for (offset_ms, triplets) in self.demux_ts(ts.data()) {
+                    self.decode(start_ms + offset_ms, &triplets, &mut cues);
+                }
+                self.next_segment_ms += seconds_to_ms(ts.segment.duration);
+            }
+            HlsData::M4sData(M4sData::Segment(segment)) => {
+                self.next_segment_ms += seconds_to_ms(segment.segment.duration);
+            }
+            HlsData::M4sData(M4sData::InitSegment(_)) | HlsData::EndMarker(_) => {}
+        }
+        cues
+    }
+
+    /// Close the captions still on screen at the end of the file and start over.
+    pub fn finish(&mut self) -> Vec<CaptionCue> {
+        let end_ms = self.next_segment_ms;
+        let cues = std::mem::take(&mut self.tracks)
+            .into_iter()
+            .filter_map(|(channel, mut track)| {
+                track
+                    .update(end_ms, String::new())
+                    .map(|(start_ms, end_ms, text)| CaptionCue {
+                        channel,
+                        start_ms,
+                        end_ms,
+                        text,
+                    })
+            })
+            .collect();
+        self.reset();
+        cues
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self {
+            video_streams: std::mem::take(&mut self.video_streams),
+            ..Self::new()
+        };
+    }
+
+    /// Caption data of the video access units of a TS segment, in presentation order, with
+    /// their offset from the first one in milliseconds.
+    fn demux_ts(&mut self, data: &Bytes) -> Vec<(u64, Vec<[u8; 3]>)> {
+        let mut parser = OwnedTsParser::new();
+        if let Err(error) = parser.parse_packets(data.clone()) {
+            debug!(%error, "Failed to parse TS program tables");
+        }
+        let streams: HashMap<u16, StreamType> = parser
+            .pmts()
+            .values()
+            .flat_map(|pmt| pmt.streams.iter())
+            .filter(|stream| matches!(stream.stream_type, StreamType::H264 | StreamType::H265))
+            .map(|stream| (stream.elementary_pid, stream.stream_type))
+            .collect();
+        if !streams.is_empty() {
+            self.video_streams = streams;
+        }
+
+        let mut pes: HashMap<u16, BytesMut> = HashMap::new();
+        let mut units = Vec::new();
+        for offset in (0..data.len() / TS_PACKET_SIZE).map(|i| i * TS_PACKET_SIZE) {
+            let Ok(packet) = TsPacket::parse(data.slice(offset..offset + TS_PACKET_SIZE)) else {
+                continue;
+            };
+            if !self.video_streams.contains_key(&packet.pid) {
+                continue;
+            }
+            let Some(payload) = packet.payload else {
+                continue;
+            };
+            if packet.payload_unit_start_indicator
+                && let Some(previous) = pes.insert(packet.pid, BytesMut::new())
+            {
+                self.caption_unit(packet.pid, &previous, &mut units);
+            }
+            if let Some(buffer) = pes.get_mut(&packet.pid) {
+                buffer.extend_from_slice(&payload);
+            }
+        }
+        for (pid, buffer) in pes {
+            self.caption_unit(pid, &buffer, &mut units);
+        }
+
+        // Captions are sent in decode order but belong to the presentation order of frames.
+        let Some(&(first, _)) = units.first() else {
+            return Vec::new();
+        };
+        let start = units
+            .iter()
+            .map(|(pts, _)| timestamp_diff(*pts, first))
+            .min()
+            .unwrap_or(0);
+        let mut units: Vec<_> = units
+            .into_iter()
+            .map(|(pts, triplets)| ((timestamp_diff(pts, first) - start) as u64 / 90, triplets))
+            .collect();
+        units.sort_by_key(|(offset_ms, _)| *offset_ms);
+        units.retain(|(_, triplets)| !triplets.is_empty());
+        units
+    }
+
+    /// Collect the `cc_data` of one video PES packet.
+    fn caption_unit(&self, pid: u16, data: &[u8], units: &mut Vec<(u64, Vec<[u8; 3]>)>) {
+        let Ok(header) = PesHeader::parse(data) else {
+            return;
+        };
+        let (Some(pts), Some(payload)) = (header.pts, data.get(header.payload_offset..)) else {
+            return;
+        };
+        let hevc = self.video_streams.get(&pid) == Some(&StreamType::H265);
+        let mut triplets = Vec::new();
+        for nal in annex_b_nal_units(payload) {
+            let sei = if hevc {
+                // Prefix SEI, after a two-byte NAL unit header
+                nal.len() > 2 && (nal[0] >> 1) & 0x3F == 39
+            } else {
+                nal[0] & 0x1F == 6
+            };
+            if sei {
+                let mut rbsp = Vec::with_capacity(nal.len());
+                let header_len = if hevc { 2 } else { 1 };
+                if EmulationPreventionIo::new(&nal[header_len..])
+                    .read_to_end(&mut rbsp)
+                    .is_ok()
+                {
+                    sei_cc_data(&rbsp, &mut triplets);
+                }
+            }
+        }
+        units.push((pts, triplets));
+    }
+
+    /// Decode the `cc_data` triplets of one access unit shown at `time_ms`.
+    fn decode(&mut self, time_ms: u64, triplets: &[[u8; 3]], cues: &mut Vec<CaptionCue>) {
+        let mut updates = Vec::new();
+        for &[header, first, second] in triplets {
+            let valid = header & 0x04 != 0;
+            match header & 0x03 {
+                field @ (0 | 1) if valid => {
+                    self.cea608[usize::from(field)].push(first, second, &mut updates)
+                }
+                3 if valid => {
+                    self.decode_dtvcc(&mut updates);
+                    self.dtvcc.start(first, second);
+                }
+                2 if valid => self.dtvcc.data.extend_from_slice(&[first, second]),
+                // An invalid DTVCC triplet ends the packet in progress
+                2 | 3 => self.decode_dtvcc(&mut updates),
+                _ => {}
+            }
+            if self.dtvcc.is_complete() {
+                self.decode_dtvcc(&mut updates);
+            }
+        }
+
+        for (channel, text) in updates {
+            let track = self.tracks.entry(channel).or_default();
+            if let Some((start_ms, end_ms, text)) = track.update(time_ms, text) {
+                cues.push(CaptionCue {
+                    channel,
+                    start_ms,
+                    end_ms,
+                    text,
+                });
+            }
+        }
+    }
+
+    fn decode_dtvcc(&mut self, updates: &mut Vec<(CaptionChannel, String)>) {
+        let packet = std::mem::take(&mut self.dtvcc);
+        let Some(data) = packet.data.get(1..packet.size.min(packet.data.len())) else {
+            return;
+        };
+        let mut i = 0;
+        while i < data.len() {
+            let header = data[i];
+            i += 1;
+            let mut service = header >> 5;
+            let block_size = usize::from(header & 0x1F);
+            if service == 0 {
+                // Null service block: the rest of the packet is padding
+                break;
+            }
+            if service == 7 {
+                let Some(&extended) = data.get(i) else {
+                    break;
+                };
+                service = extended & 0x3F;
+                i += 1;
+            }
+            let end = (i + block_size).min(data.len());
+            let decoder = self.services.entry(service).or_default();
+            if decoder.decode(&data[i..end]) {
+                updates.push((CaptionChannel::Cea708(service), decoder.text()));
+            }
+            i = end;
+        }
+    }
+}
+
+fn seconds_to_ms(seconds: f32) -> u64 {
+    (f64::from(seconds.max(0.0)) * 1000.0).round() as u64
+}
+
+/// Append the ATSC A/53 `cc_data` triplets of an SEI RBSP (without NAL unit header).
+fn sei_cc_data(rbsp: &[u8], out: &mut Vec<[u8; 3]>) {
+    let mut i = 0;
+    // Stop at the rbsp_trailing_bits
+    while i + 2 <= rbsp.len() && rbsp[i] != 0x80 {
+        let mut read_value = || {
+            let mut value = 0;
+            while rbsp.get(i) == Some(&0xFF) {
+                value += 255;
+                i += 1;
+            }
+            let last = rbsp.get(i).copied()?;
+            i += 1;
+            Some(value + usize::from(last))
+        };
+        let (Some(payload_type), Some(payload_size)) = (read_value(), read_value()) else {
+            return;
+        };
+        let Some(payload) = rbsp.get(i..i + payload_size) else {
+            return;
+        };
+        if payload_type == SEI_USER_DATA_REGISTERED
+            && let Some(cc_data) = payload.strip_prefix(&A53_CC_DATA_HEADER)
+            && let [flags, _em_data, triplets @ ..] = cc_data
+            && flags & 0x40 != 0
+        {
+            let count = usize::from(flags & 0x1F);
+            out.extend(
+                triplets
+                    .chunks_exact(3)
+                    .take(count)
+                    .map(|triplet| [triplet[0], triplet[1], triplet[2]]),
+            );
+        }
+        i += payload_size;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cea608Mode {
+    PopOn,
+    RollUp(usize),
+    PaintOn,
+    /// Text service data, not captions
+    Text,
+}
+
+type Cea608Memory = [[char; CEA608_COLUMNS]; CEA608_ROWS];
+
+/// Caption state of one CEA-608 data channel.
+#[derive(Debug)]
+struct Cea608Channel {
+    mode: Cea608Mode,
+    displayed: Cea608Memory,
+    non_displayed: Cea608Memory,
+    row: usize,
+    column: usize,
+}
+
+impl Default for Cea608Channel {
+    fn default() -> Self {
+        Self {
+            mode: Cea608Mode::PopOn,
+            displayed: [[' '; CEA608_COLUMNS]; CEA608_ROWS],
+            non_displayed: [[' '; CEA608_COLUMNS]; CEA608_ROWS],
+            row: CEA608_ROWS - 1,
+            column: 0,
+        }
+    }
+}
+
+impl Cea608Channel {
+    /// Memory that characters go to in the current mode.
+    fn target(&mut self) -> Option<&mut Cea608Memory> {
+        match self.mode {
+            Cea608Mode::PopOn => Some(&mut self.non_displayed),
+            Cea608Mode::RollUp(_) | Cea608Mode::PaintOn => Some(&mut self.displayed),
+            Cea608Mode::Text => None,
+        }
+    }
+
+    fn put_char(&mut self, c: char) {
+        let (row, column) = (self.row, self.column.min(CEA608_COLUMNS - 1));
+        if let Some(memory) = self.target() {
+            memory[row][column] = c;
+        }
+        self.column = (column + 1).min(CEA608_COLUMNS);
+    }
+
+    fn backspace(&mut self) {
+        self.column = self.column.saturating_sub(1);
+        let (row, column) = (self.row, self.column);
+        if let Some(memory) = self.target() {
+            memory[row][column] = ' ';
+        }
+    }
+
+    /// Apply a control code (channel bit cleared). Returns the text to show when the screen
+    /// may have changed.
+    fn control(&mut self, first: u8, second: u8) -> Option<String> {
+        let changed = match (first, second) {
+            // Carriage return shows the completed row before rolling it up
+            (0x14 | 0x15, 0x2D) => {
+                let text = self.text();
+                self.carriage_return();
+                return Some(text);
+            }
+            (0x14 | 0x15, 0x20..=0x2F) => self.command(second),
+            // Tab offsets
+            (0x17, 0x21..=0x23) => {
+                self.column = (self.column + usize::from(second - 0x20)).min(CEA608_COLUMNS - 1);
+                false
+            }
+            // Mid-row style codes take up a space
+            (0x11, 0x20..=0x2F) => {
+                self.put_char(' ');
+                false
+            }
+            (0x11, 0x30..=0x3F) => {
+                self.put_char(nth_char(SPECIAL_CHARS, second - 0x30));
+                false
+            }
+            // Extended characters replace the standard fallback sent before them
+            (0x12 | 0x13, 0x20..=0x3F) => {
+                self.backspace();
+                self.put_char(nth_char(
+                    EXTENDED_CHARS[usize::from(first - 0x12)],
+                    second - 0x20,
+                ));
+                false
+            }
+            (0x10..=0x17, 0x40..=0x7F) => {
+                self.preamble(first, second);
+                false
+            }
+            _ => false,
+        };
+        changed.then(|| self.text())
+    }
+
+    fn carriage_return(&mut self) {
+        if let Cea608Mode::RollUp(rows) = self.mode {
+            let top = (self.row + 1).saturating_sub(rows);
+            self.displayed[..top].fill([' '; CEA608_COLUMNS]);
+            self.displayed[top..=self.row].rotate_left(1);
+            self.displayed[self.row] = [' '; CEA608_COLUMNS];
+            self.column = 0;
+        }
+    }
+
+    fn command(&mut self, command: u8) -> bool {
+        match command {
+            // Resume caption loading
+            0x20 => {
+                self.mode = Cea608Mode::PopOn;
+                false
+            }
+            0x21 => {
+                self.backspace();
+                true
+            }
+            // Delete to end of row
+            0x24 => {
+                let (row, column) = (self.row, self.column);
+                if let Some(memory) = self.target() {
+                    memory[row][column..].fill(' ');
+                }
+                true
+            }
+            // Roll-up with 2, 3 or 4 rows
+            0x25..=0x27 => {
+                if !matches!(self.mode, Cea608Mode::RollUp(_)) {
+                    self.displayed = [[' '; CEA608_COLUMNS]; CEA608_ROWS];
+                    self.non_displayed = [[' '; CEA608_COLUMNS]; CEA608_ROWS];
+                    self.row = CEA608_ROWS - 1;
+                }
+                self.mode = Cea608Mode::RollUp(usize::from(command - 0x23));
+                self.column = 0;
+                true
+            }
+            // Resume direct captioning
+            0x29 => {
+                self.mode = Cea608Mode::PaintOn;
+                false
+            }
+            // Text restart, resume text display
+            0x2A | 0x2B => {
+                self.mode = Cea608Mode::Text;
+                false
+            }
+            // Erase displayed memory
+            0x2C => {
+                self.displayed = [[' '; CEA608_COLUMNS]; CEA608_ROWS];
+                true
+            }
+            // Erase non-displayed memory
+            0x2E => {
+                self.non_displayed = [[' '; CEA608_COLUMNS]; CEA608_ROWS];
+                false
+            }
+            // End of caption: flip memories
+            0x2F => {
+                std::mem::swap(&mut self.displayed, &mut self.non_displayed);
+                self.mode = Cea608Mode::PopOn;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Preamble address code: move the cursor to a row and indent.
+    fn preamble(&mut self, first: u8, second: u8) {
+        let mut row = PAC_ROWS[usize::from(first & 0x07)];
+        if first & 0x07 != 0 && second & 0x20 != 0 {
+            row += 1;
+        }
+        let row = row - 1;
+        if let Cea608Mode::RollUp(rows) = self.mode
+            && row != self.row
+        {
+            // The roll-up window moves along with its base row
+            let mut moved = [[' '; CEA608_COLUMNS]; CEA608_ROWS];
+            for offset in 0..rows.min(row + 1).min(self.row + 1) {
+                moved[row - offset] = self.displayed[self.row - offset];
+            }
+            self.displayed = moved;
+        }
+        self.row = row;
+        self.column = if second & 0x10 != 0 {
+            usize::from((second & 0x0E) >> 1) * 4
+        } else {
+            0
+        };
+    }
+
+    fn text(&self) -> String {
+        memory_text(self.displayed.iter().map(|row| row.iter().collect()))
+    }
+}
+
+/// Decoder of one CEA-608 field, carrying two data channels.
+#[derive(Debug, Default)]
+struct Cea608Decoder {
+    /// 0 for field 1 (CC1, CC2), 1 for field 2 (CC3, CC4)
+    field: u8,
+    /// Data channel the following characters belong to
+    data_channel: usize,
+    channels: [Cea608Channel; 2],
+    /// Last control code, whose transmitted repeat is ignored
+    last_control: Option<(u8, u8)>,
+    /// Inside extended data services packets of field 2
+    in_xds: bool,
+}
+
+impl Cea608Decoder {
+    fn new(field: u8) -> Self {
+        Self {
+            field,
+            ..Self::default()
+        }
+    }
+
+    fn push(&mut self, first: u8, second: u8, updates: &mut Vec<(CaptionChannel, String)>) {
+        // Strip odd parity
+        let (first, second) = (first & 0x7F, second & 0x7F);
+        if first == 0 && second == 0 {
+            return;
+        }
+        if first < 0x10 {
+            // XDS packets start with 0x01 to 0x0E and end with 0x0F
+            self.in_xds = first != 0x0F;
+            self.last_control = None;
+            return;
+        }
+        if first < 0x20 {
+            if self.last_control.take() == Some((first, second)) {
+                return;
+            }
+            self.last_control = Some((first, second));
+            self.in_xds = false;
+            self.data_channel = usize::from(first & 0x08 != 0);
+            if let Some(text) = self.channels[self.data_channel].control(first & !0x08, second) {
+                let label = self.field * 2 + self.data_channel as u8 + 1;
+                updates.push((CaptionChannel::Cea608(label), text));
+            }
+            return;
+        }
+        self.last_control = None;
+        if self.in_xds {
+            return;
+        }
+        let channel = &mut self.channels[self.data_channel];
+        channel.put_char(basic_char(first));
+        if second >= 0x20 {
+            channel.put_char(basic_char(second));
+        }
+    }
+}
+
+/// CEA-608 standard character set: ASCII with a few substitutions.
+fn basic_char(byte: u8) -> char {
+    match byte {
+        0x2A => 'á',
+        0x5C => 'é',
+        0x5E => 'í',
+        0x5F => 'ó',
+        0x60 => 'ú',
+        0x7B => 'ç',
+        0x7C => '÷',
+        0x7D => 'Ñ',
+        0x7E => 'ñ',
+        0x7F => '█',
+        _ => char::from(byte),
+    }
+}
+
+fn nth_char(table: &str, index: u8) -> char {
+    table.chars().nth(usize::from(index)).unwrap_or(' ')
+}
+
+/// Join the non-blank rows of a caption screen.
+fn memory_text(rows: impl Iterator<Item = String>) -> String {
+    rows.map(|row| row.trim().to_string())
+        .filter(|row| !row.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// A DTVCC packet being assembled from `cc_data`.
+#[derive(Debug, Default)]
+struct DtvccPacket {
+    /// Packet bytes, starting with the packet header
+    data: Vec<u8>,
+    /// Total size announced by the header
+    size: usize,
+}
+
+impl DtvccPacket {
+    fn start(&mut self, header: u8, first: u8) {
+        let size_code = usize::from(header & 0x3F);
+        self.size = if size_code == 0 { 128 } else { size_code * 2 };
+        self.data = vec![header, first];
+    }
+
+    fn is_complete(&self) -> bool {
+        !self.data.is_empty() && self.data.len() >= self.size
+    }
+}
+
+#[derive(Debug, Default)]
+struct Cea708Window {
+    visible: bool,
+    rows: Vec<Vec<char>>,
+    row: usize,
+    column: usize,
+}
+
+impl Cea708Window {
+    fn resize(&mut self, row_count: usize) {
+        self.rows.resize_with(row_count, Vec::new);
+        self.row = self.row.min(row_count - 1);
+    }
+
+    fn put_char(&mut self, c: char) {
+        let row = &mut self.rows[self.row];
+        if self.column < row.len() {
+            row[self.column] = c;
+        } else {
+            row.resize(self.column, ' ');
+            row.push(c);
+        }
+        self.column += 1;
+    }
+
+    fn clear(&mut self) {
+        self.rows.iter_mut().for_each(Vec::clear);
+        self.row = 0;
+        self.column = 0;
+    }
+}
+
+/// Window state of one CEA-708 caption service.
+#[derive(Debug, Default)]
+struct Cea708Service {
+    windows: [Option<Cea708Window>; 8],
+    current: usize,
+}
+
+impl Cea708Service {
+    fn window(&mut self) -> Option<&mut Cea708Window> {
+        self.windows[self.current].as_mut()
+    }
+
+    fn put_char(&mut self, c: char) {
+        if let Some(window) = self.window() {
+            window.put_char(c);
+        }
+    }
+
+    /// Apply `action` to the windows selected by a bitmap parameter.
+    fn for_windows(&mut self, bitmap: u8, mut action: impl FnMut(&mut Option<Cea708Window>)) {
+        for (id, window) in self.windows.iter_mut().enumerate() {
+            if bitmap & (1 << id) != 0 {
+                action(window);
+            }
+        }
+    }
+
+    /// Decode one service block. Returns whether the screen may have changed.
+    fn decode(&mut self, block: &[u8]) -> bool {
+        let mut changed = false;
+        let mut i = 0;
+        while i < block.len() {
+            let code = block[i];
+            i += 1;
+            // Parameter bytes of commands
+            let params = &block[i..];
+            match code {
+                // End of text
+                0x03 => changed = true,
+                // Backspace
+                0x08 => {
+                    if let Some(window) = self.window()
+                        && window.column > 0
+                    {
+                        window.column -= 1;
+                        if let Some(c) = window.rows[window.row].get_mut(window.column) {
+                            *c = ' ';
+                        }
+                    }
+                }
+                // Form feed
+                0x0C => {
+                    if let Some(window) = self.window() {
+                        window.clear();
+                    }
+                    changed = true;
+                }
+                // Carriage return
+                0x0D => {
+                    if let Some(window) = self.window() {
+                        window.column = 0;
+                        if window.row + 1 < window.rows.len() {
+                            window.row += 1;
+                        } else {
+                            window.rows.remove(0);
+                            window.rows.push(Vec::new());
+                        }
+                    }
+                    changed = true;
+                }
+                // Horizontal carriage return
+                0x0E => {
+                    if let Some(window) = self.window() {
+                        window.rows[window.row].clear();
+                        window.column = 0;
+                    }
+                }
+                // EXT1: extended code sets
+                0x10 => {
+                    let Some(&extended) = params.first() else {
+                        break;
+                    };
+                    i += 1;
+                    match extended {
+                        0x00..=0x07 => {}
+                        0x08..=0x0F => i += 1,
+                        0x10..=0x17 => i += 2,
+                        0x18..=0x1F => i += 3,
+                        0x20..=0x7F => self.put_char(g2_char(extended)),
+                        0x80..=0x87 => i += 4,
+                        0x88..=0x8F => i += 5,
+                        // Variable-length C3 commands carry their length
+                        0x90..=0x9F => {
+                            i += 1 + params.get(1).map_or(0, |len| usize::from(len & 0x1F))
+                        }
+                        // G3 only defines the [CC] icon
+                        0xA0..=0xFF => self.put_char('_'),
+                    }
+                }
+                0x11..=0x17 => i += 1,
+                // P16: 16-bit character
+                0x18 => {
+                    if let [high, low, ..] = params
+                        && let Some(c) = char::from_u32((u32::from(*high) << 8) | u32::from(*low))
+                    {
+                        self.put_char(c);
+                    }
+                    i += 2;
+                }
+                0x19..=0x1F => i += 2,
+                0x00..=0x02 | 0x04..=0x07 | 0x09..=0x0B | 0x0F => {}
+                0x7F => self.put_char('♪'),
+                0x20..=0x7E => self.put_char(char::from(code)),
+                // SetCurrentWindow
+                0x80..=0x87 => self.current = usize::from(code - 0x80),
+                // ClearWindows, DisplayWindows, HideWindows, ToggleWindows, DeleteWindows
+                0x88..=0x8C => {
+                    let Some(&bitmap) = params.first() else {
+                        break;
+                    };
+                    i += 1;
+                    self.for_windows(bitmap, |slot| {
+                        if code == 0x8C {
+                            *slot = None;
+                        } else if let Some(window) = slot {
+                            match code {
+                                0x88 => window.clear(),
+                                0x89 => window.visible = true,
+                                0x8A => window.visible = false,
+                                _ => window.visible = !window.visible,
+                            }
+                        }
+                    });
+                    changed = true;
+                }
+                // Delay
+                0x8D => i += 1,
+                // Reset
+                0x8F => {
+                    self.windows = Default::default();
+                    changed = true;
+                }
+                0x8E | 0x93..=0x96 => {}
+                // SetPenAttributes, SetPenLocation
+                0x90 | 0x92 => {
+                    if code == 0x92
+                        && let [row, column, ..] = params
+                        && let Some(window) = self.window()
+                    {
+                        window.row = usize::from(row & 0x0F).min(window.rows.len() - 1);
+                        window.column = usize::from(column & 0x3F);
+                    }
+                    i += 2;
+                }
+                // SetPenColor
+                0x91 => i += 3,
+                // SetWindowAttributes
+                0x97 => i += 4,
+                // DefineWindow
+                0x98..=0x9F => {
+                    let Some(&[style, _, _, rows, _, _]) = params.get(..6) else {
+                        break;
+                    };
+                    i += 6;
+                    self.current = usize::from(code - 0x98);
+                    let window = self.windows[self.current].get_or_insert_default();
+                    window.visible = style & 0x20 != 0;
+                    window.resize(usize::from(rows & 0x0F) + 1);
+                    changed = true;
+                }
+                // G1: Latin-1
+                0xA0..=0xFF => self.put_char(char::from(code)),
+            }
+        }
+        changed
+    }
+
+    fn text(&self) -> String {
+        memory_text(
+            self.windows
+                .iter()
+                .flatten()
+                .filter(|window| window.visible)
+                .flat_map(|window| window.rows.iter().map(|row| row.iter().collect())),
+        )
+    }
+}
+
+/// CEA-708 G2 character set, falling back to `_` for unsupported symbols.
+fn g2_char(code: u8) -> char {
+    match code {
+        0x20 | 0x21 => ' ',
+        0x25 => '…',
+        0x2A => 'Š',
+        0x2C => 'Œ',
+        0x30 => '█',
+        0x31 => '‘',
+        0x32 => '’',
+        0x33 => '“',
+        0x34 => '”',
+        0x35 => '•',
+        0x39 => '™',
+        0x3A => 'š',
+        0x3C => 'œ',
+        0x3D => '℠',
+        0x3F => 'Ÿ',
+        _ => '_',
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use m3u8_rs::MediaSegment;
+    use ts::{EsFrame, TsMuxer};
+
+    fn cea608(pairs: &[[u8; 2]]) -> Vec<[u8; 3]> {
+        pairs.iter().map(|[a, b]| [0xFC, *a, *b]).collect()
+    }
+
+    fn text_pairs(text: &str) -> Vec<[u8; 2]> {
+        text.as_bytes()
+            .chunks(2)
+            .map(|pair| [pair[0], pair.get(1).copied().unwrap_or(0)])
+            .collect()
+    }
+
+    #[test]
+    fn test_sei_cc_data() {
+        let mut rbsp = vec![0x04, 17];
+        rbsp.extend_from_slice(&A53_CC_DATA_HEADER);
+        rbsp.extend_from_slice(&[0x42, 0xFF, 0xFC, 0x94, 0x20, 0xFC, 0x94, 0x20, 0xFF]);
+        rbsp.push(0x80);
+        let mut triplets = Vec::new();
+        sei_cc_data(&rbsp, &mut triplets);
+        assert_eq!(triplets, vec![[0xFC, 0x94, 0x20], [0xFC, 0x94, 0x20]]);
+    }
+
+    /// Annex B H.264 access unit with an SEI carrying CEA-608 field 1 pairs.
+    fn access_unit(pairs: &[[u8; 2]]) -> Vec<u8> {
+        let mut data = vec![0, 0, 0, 1, 0x06, 0x04, (10 + pairs.len() * 3) as u8];
+        data.extend_from_slice(&A53_CC_DATA_HEADER);
+        data.extend_from_slice(&[0x40 | pairs.len() as u8, 0xFF]);
+        data.extend(cea608(pairs).concat());
+        data.extend_from_slice(&[0x80, 0, 0, 1, 0x41, 0x9A]);
+        data
+    }
+
+    fn ts_segment(frames: &[(u64, u64, Vec<u8>)]) -> HlsData {
+        let mut muxer = TsMuxer::new(1, 0x1000);
+        muxer
+            .add_stream(0x100, StreamType::H264, Vec::new())
+            .unwrap();
+        let mut data = Vec::new();
+        muxer.write_tables(&mut data).unwrap();
+        for (pts, dts, access_unit) in frames {
+            let frame = EsFrame {
+                pid: 0x100,
+                pts: *pts,
+                dts: Some(*dts),
+                random_access: false,
+                data: access_unit,
+            };
+            muxer.write_frame(&mut data, &frame).unwrap();
+        }
+        HlsData::ts(
+            MediaSegment {
+                duration: 2.0,
+                ..MediaSegment::empty()
+            },
+            Bytes::from(data),
+        )
+    }
+
+    #[test]
+    fn test_extracts_captions_from_ts_segments() {
+        let mut extractor = CaptionExtractor::new();
+        // The frame flipping the caption on screen is decoded before, but presented after, the
+        // frame loading it.
+        let mut load = vec![[0x14, 0x20], [0x14, 0x70]];
+        load.extend(text_pairs("HELLO"));
+        let first = ts_segment(&[
+            (96_000, 90_000, access_unit(&[[0x14, 0x2F]])),
+            (93_000, 93_000, access_unit(&load)),
+        ]);
+        assert!(extractor.push(&first).is_empty());
+
+        let second = ts_segment(&[(276_000, 276_000, access_unit(&[[0x14, 0x2C]]))]);
+        assert_eq!(
+            extractor.push(&second),
+            vec![CaptionCue {
+                channel: CaptionChannel::Cea608(1),
+                start_ms: 33,
+                end_ms: 2_000,
+                text: "HELLO".to_string(),
+            }]
+        );
+        assert!(extractor.finish().is_empty());
+    }
+
+    #[test]
+    fn test_cea608_pop_on_and_roll_up() {
+        let mut extractor = CaptionExtractor::new();
+        let mut cues = Vec::new();
+
+        // Pop-on caption on row 15, doubled control codes as broadcast
+        let mut pairs = vec![[0x14, 0x20], [0x14, 0x20], [0x14, 0x70], [0x14, 0x70]];
+        pairs.extend(text_pairs("HELLO"));
+        pairs.extend([[0x14, 0x2F], [0x14, 0x2F]]);
+        extractor.decode(1_000, &cea608(&pairs), &mut cues);
+        extractor.decode(3_000, &cea608(&[[0x14, 0x2C]]), &mut cues);
+        assert_eq!(
+            cues,
+            vec![CaptionCue {
+                channel: CaptionChannel::Cea608(1),
+                start_ms: 1_000,
+                end_ms: 3_000,
+                text: "HELLO".to_string(),
+            }]
+        );
+
+        // Roll-up: each carriage return shows the rows typed so far
+        let mut pairs = vec![[0x14, 0x25]];
+        pairs.extend(text_pairs("ONE"));
+        pairs.push([0x14, 0x2D]);
+        extractor.decode(4_000, &cea608(&pairs), &mut cues);
+        let mut pairs = text_pairs("TWO");
+        pairs.push([0x14, 0x2D]);
+        extractor.decode(5_000, &cea608(&pairs), &mut cues);
+        extractor.next_segment_ms = 6_000;
+        cues.extend(extractor.finish());
+        let texts: Vec<_> = cues[1..].iter().map(|cue| cue.text.as_str()).collect();
+        assert_eq!(texts, vec!["ONE", "ONE\nTWO"]);
+        assert_eq!((cues[2].start_ms, cues[2].end_ms), (5_000, 6_000));
+    }
+
+    #[test]
+    fn test_cea708_window_text() {
+        let mut service = Cea708Service::default();
+        // DefineWindow 0 (visible, 2 rows), text, carriage return, more text
+        let mut block = vec![0x98, 0x20, 0, 0, 0x01, 0x1F, 0];
+        block.extend_from_slice(b"Hi");
+        block.push(0x0D);
+        block.extend_from_slice(b"there");
+        block.push(0x03);
+        assert!(service.decode(&block));
+        assert_eq!(service.text(), "Hi\nthere");
+
+        // HideWindows(0)
+        assert!(service.decode(&[0x8A, 0x01]));
+        assert_eq!(service.text(), "");
+    }
+
+    #[test]
+    fn test_renders_srt_and_webvtt() {
+        let cues = [CaptionCue {
+            channel: CaptionChannel::Cea608(1),
+            start_ms: 3_723_004,
+            end_ms: 3_725_000,
+            text: "A <b> & C".to_string(),
+        }];
+        assert_eq!(
+            CaptionFormat::Srt.render(&cues),
+            "1\n01:02:03,004 --> 01:02:05,000\nA <b> & C\n\n"
+        );
+        assert_eq!(
+            CaptionFormat::WebVtt.render(&cues),
+            "WEBVTT\n\n01:02:03.004 --> 01:02:05.000\nA &lt;b&gt; &amp; C\n\n"
+        );
+        assert_eq!(
+            CaptionFormat::Srt.sidecar_path(Path::new("out/rec.ts"), CaptionChannel::Cea708(1)),
+            PathBuf::from("out/rec.service1.srt")
+        );
+    }
+}
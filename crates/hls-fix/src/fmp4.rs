@@ -0,0 +1,235 @@
+//! Continuous fMP4 timeline for the writer.
+//!
+//! HLS fMP4 segments carry absolute decode times (`tfdt`) from the packager.
+//! Concatenated as-is, each output file starts at an arbitrary time and a
+//! reconnect (or a packager restart behind `EXT-X-DISCONTINUITY`) makes the
+//! timeline jump backwards or forwards mid-file. [`Fmp4Timeline`] rebases each
+//! file to start at zero and closes such jumps, shifting every track by the
+//! same amount of wall time so audio and video stay in sync.
+
+use std::collections::HashMap;
+
+use bytes::Bytes;
+use mp4::timing::{
+    FragmentTiming, TrackTiming, parse_fragment_timings, parse_track_timings,
+    rewrite_base_media_decode_times,
+};
+use tracing::{debug, info, warn};
+
+/// A decode time this far (in seconds) from where the previous segment ended
+/// is treated as a timeline break rather than jitter.
+const MAX_DECODE_TIME_DRIFT_SECS: f64 = 1.0;
+
+#[derive(Debug, Default)]
+pub(crate) struct Fmp4Timeline {
+    tracks: Vec<TrackTiming>,
+    /// Seconds added to every track's decode times; `None` until the first
+    /// media segment of the current file fixes the origin.
+    shift_secs: Option<f64>,
+    /// Per-track decode time (output ticks) where the next segment should start.
+    next_decode_time: HashMap<u32, u64>,
+}
+
+impl Fmp4Timeline {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start a new output file. Track timescales are kept: the init segment
+    /// re-emitted at the start of the file describes the same tracks.
+    pub(crate) fn reset(&mut self) {
+        self.shift_secs = None;
+        self.next_decode_time.clear();
+    }
+
+    pub(crate) fn set_init(&mut self, init: &Bytes) {
+        self.tracks = parse_track_timings(init);
+        debug!(tracks = ?self.tracks, "fMP4 track timescales");
+    }
+
+    fn timescale(&self, track_id: u32) -> Option<f64> {
+        self.tracks
+            .iter()
+            .find(|track| track.track_id == track_id)
+            .map(|track| f64::from(track.timescale))
+    }
+
+    fn ticks(&self, track_id: u32, secs: f64) -> i64 {
+        self.timescale(track_id)
+            .map_or(0, |timescale| (secs * timescale).round() as i64)
+    }
+
+    /// Rewrite a media segment onto the continuous timeline. Returns the
+    /// segment bytes to write and, when the fragments carry timing, the
+    /// segment duration in seconds measured from its samples.
+    pub(crate) fn process(&mut self, data: &Bytes, discontinuity: bool) -> (Bytes, Option<f64>) {
+        let timings: Vec<FragmentTiming> = match parse_fragment_timings(data, &self.tracks) {
+            Ok(timings) => timings
+                .into_iter()
+                .filter(|timing| self.timescale(timing.track_id).is_some())
+                .collect(),
+            Err(e) => {
+                debug!("fMP4 segment timing unavailable: {e}");
+                return (data.clone(), None);
+            }
+        };
+        let Some(reference) = timings.first().copied() else {
+            return (data.clone(), None);
+        };
+        let reference_timescale = self.timescale(reference.track_id).unwrap_or(1.0);
+
+        let shift_secs = match self.shift_secs {
+            None => {
+                let origin = timings
+                    .iter()
+                    .filter_map(|timing| {
+                        self.timescale(timing.track_id)
+                            .map(|timescale| timing.base_media_decode_time as f64 / timescale)
+                    })
+                    .fold(f64::INFINITY, f64::min);
+                -origin
+            }
+            Some(shift_secs) => {
+                let actual = reference.base_media_decode_time as i64
+                    + self.ticks(reference.track_id, shift_secs);
+                match self.next_decode_time.get(&reference.track_id) {
+                    Some(&expected) => {
+                        let drift_secs = (expected as i64 - actual) as f64 / reference_timescale;
+                        if discontinuity || drift_secs.abs() > MAX_DECODE_TIME_DRIFT_SECS {
+                            info!(drift_secs, discontinuity, "Closing fMP4 decode time jump");
+                            shift_secs + drift_secs
+                        } else {
+                            shift_secs
+                        }
+                    }
+                    None => shift_secs,
+                }
+            }
+        };
+        self.shift_secs = Some(shift_secs);
+
+        let shifts: HashMap<u32, i64> = self
+            .tracks
+            .iter()
+            .map(|track| (track.track_id, self.ticks(track.track_id, shift_secs)))
+            .collect();
+        let shifted = |track_id: u32, time: u64| -> u64 {
+            let shift = shifts.get(&track_id).copied().unwrap_or(0);
+            time.saturating_add_signed(shift)
+        };
+
+        for timing in &timings {
+            let start = shifted(timing.track_id, timing.base_media_decode_time);
+            self.next_decode_time
+                .insert(timing.track_id, start.saturating_add(timing.duration));
+        }
+
+        let output = match rewrite_base_media_decode_times(data, shifted) {
+            Ok(output) => output,
+            Err(e) => {
+                warn!("Failed to rewrite fMP4 decode times, writing segment unchanged: {e}");
+                data.clone()
+            }
+        };
+        let duration = reference.duration as f64 / reference_timescale;
+        (output, Some(duration))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mp4::test_support::{make_box, make_full_box};
+
+    fn init() -> Bytes {
+        let trak = |track_id: u32, timescale: u32| {
+            let mut tkhd = vec![0u8; 8];
+            tkhd.extend_from_slice(&track_id.to_be_bytes());
+            tkhd.extend_from_slice(&[0u8; 4]);
+            let mut mdhd = vec![0u8; 8];
+            mdhd.extend_from_slice(&timescale.to_be_bytes());
+            mdhd.extend_from_slice(&[0u8; 8]);
+            make_box(
+                b"trak",
+                &[
+                    make_full_box(b"tkhd", 0, 0, &tkhd),
+                    make_box(b"mdia", &make_full_box(b"mdhd", 0, 0, &mdhd)),
+                ]
+                .concat(),
+            )
+        };
+        Bytes::from(make_box(
+            b"moov",
+            &[trak(1, 90_000), trak(2, 48_000)].concat(),
+        ))
+    }
+
+    /// Two seconds of video (track 1) and audio (track 2) starting at `start_secs`.
+    fn segment(start_secs: u64) -> Bytes {
+        let traf = |track_id: u32, timescale: u64| {
+            let tfhd = make_full_box(b"tfhd", 0, 0x020000, &track_id.to_be_bytes());
+            let tfdt = make_full_box(b"tfdt", 1, 0, &(start_secs * timescale).to_be_bytes());
+            let mut trun = 1u32.to_be_bytes().to_vec();
+            trun.extend_from_slice(&((2 * timescale) as u32).to_be_bytes());
+            make_box(
+                b"traf",
+                &[tfhd, tfdt, make_full_box(b"trun", 0, 0x000100, &trun)].concat(),
+            )
+        };
+        let moof = make_box(b"moof", &[traf(1, 90_000), traf(2, 48_000)].concat());
+        Bytes::from([moof, make_box(b"mdat", &[0u8; 8])].concat())
+    }
+
+    fn decode_times(data: &Bytes) -> Vec<u64> {
+        parse_fragment_timings(data, &[])
+            .unwrap()
+            .iter()
+            .map(|timing| timing.base_media_decode_time)
+            .collect()
+    }
+
+    #[test]
+    fn rebases_file_to_zero_and_keeps_tracks_aligned() {
+        let mut timeline = Fmp4Timeline::new();
+        timeline.set_init(&init());
+
+        let (first, duration) = timeline.process(&segment(1000), false);
+        assert_eq!(decode_times(&first), vec![0, 0]);
+        assert_eq!(duration, Some(2.0));
+
+        let (second, _) = timeline.process(&segment(1002), false);
+        assert_eq!(decode_times(&second), vec![2 * 90_000, 2 * 48_000]);
+    }
+
+    #[test]
+    fn closes_decode_time_jumps_after_reconnect() {
+        let mut timeline = Fmp4Timeline::new();
+        timeline.set_init(&init());
+        timeline.process(&segment(1000), false);
+        timeline.process(&segment(1002), false);
+
+        // The packager restarted its clock.
+        let (restarted, _) = timeline.process(&segment(0), false);
+        assert_eq!(decode_times(&restarted), vec![4 * 90_000, 4 * 48_000]);
+        let (next, _) = timeline.process(&segment(2), false);
+        assert_eq!(decode_times(&next), vec![6 * 90_000, 6 * 48_000]);
+    }
+
+    #[test]
+    fn reset_starts_the_next_file_at_zero() {
+        let mut timeline = Fmp4Timeline::new();
+        timeline.set_init(&init());
+        timeline.process(&segment(1000), false);
+
+        timeline.reset();
+        let (first, _) = timeline.process(&segment(1002), false);
+        assert_eq!(decode_times(&first), vec![0, 0]);
+    }
+
+    #[test]
+    fn segments_without_timing_pass_through() {
+        let mut timeline = Fmp4Timeline::new();
+        let data = Bytes::from_static(b"not an fmp4 segment");
+        assert_eq!(timeline.process(&data, false), (data.clone(), None));
+    }
+}
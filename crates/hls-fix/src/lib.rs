@@ -10,13 +10,26 @@
 //!
 //! ## Component Overview
 //!
+//! - `captions`: CEA-608/708 closed caption extraction into SRT/WebVTT sidecars
 //! - `pipeline`: HLS processing pipeline implementation
+//! - `report`: Structured, serializable analysis reports of written files
+//! - `transmux`: Conversion of HLS segments into an FLV tag stream
 
 pub mod analyzer;
+mod archive;
+pub mod captions;
 mod crc32;
+mod fmp4;
 pub mod operators;
 pub mod pipeline;
+pub mod report;
+pub mod transmux;
+mod ts_timeline;
 mod writer_task;
 
+pub use captions::{CaptionCue, CaptionExtractor, CaptionFormat};
+pub use operators::AdBreakMode;
 pub use pipeline::{HlsPipeline, HlsPipelineConfig};
+pub use report::AnalysisReport;
+pub use transmux::FlvTransmuxer;
 pub use writer_task::{HlsWriter, HlsWriterConfig};
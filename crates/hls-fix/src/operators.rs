@@ -1,7 +1,11 @@
+mod ad_break;
+mod deduplicate;
 mod defragment;
 mod segment_limiter;
 mod segment_split;
 
+pub use ad_break::{AdBreakMode, AdBreakOperator};
+pub use deduplicate::DeduplicateOperator;
 pub use defragment::DefragmentOperator;
 pub use segment_limiter::SegmentLimiterOperator;
 pub use segment_split::SegmentSplitOperator;
@@ -0,0 +1,617 @@
+use std::sync::Arc;
+
+use hls::{HlsData, M4sData, M4sInitSegmentData, SplitReason};
+use m3u8_rs::MediaSegment;
+use pipeline_common::{PipelineError, Processor, StreamerContext};
+use tracing::{debug, info};
+use ts::SpliceCommand;
+
+/// SCTE-35 times and durations are expressed in 90kHz ticks.
+const SCTE35_TIMESCALE: f64 = 90_000.0;
+
+/// Remaining break time (seconds) below which the break is considered over,
+/// absorbing rounding in `EXTINF` durations.
+const BREAK_END_TOLERANCE_SECS: f64 = 0.1;
+
+/// What to do with the segments of a detected ad break.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AdBreakMode {
+    /// Log the break and keep its segments in the recording.
+    Tag,
+    /// Write the break to a file of its own.
+    Split,
+    /// Leave the break out of the recording.
+    Drop,
+}
+
+/// An ad insertion marker read from the playlist or from the segment payload.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Cue {
+    /// A break starts. `duration` is in seconds when announced; `event` is the
+    /// SCTE-35 splice event, used to ignore repeated cues for the same break.
+    Out {
+        duration: Option<f64>,
+        event: Option<u32>,
+    },
+    /// The playlist says we are inside a break (`EXT-X-CUE-OUT-CONT`), e.g. when
+    /// recording started after its `EXT-X-CUE-OUT`.
+    Continue,
+    /// The break ends with this segment.
+    In,
+}
+
+#[derive(Debug)]
+struct AdBreak {
+    event: Option<u32>,
+    /// Announced time left in the break; `None` when only an explicit IN ends it.
+    remaining: Option<f64>,
+    segments: usize,
+    duration: f64,
+}
+
+/// HLS processor: Detects ad breaks and tags, splits or drops them.
+///
+/// # Markers
+///
+/// - `EXT-X-DATERANGE` carrying `SCTE35-OUT` / `SCTE35-IN`, or a Twitch
+///   stitched-ad class
+/// - `EXT-X-CUE-OUT[:duration]`, `EXT-X-CUE-OUT-CONT` and `EXT-X-CUE-IN`
+/// - SCTE-35 `splice_insert` sections muxed into MPEG-TS segments
+///
+/// Playlist markers apply to the segment that carries them. In-band SCTE-35
+/// cues are sent ahead of the splice point, so they take effect from the next
+/// segment (or the next independent LL-HLS part). A break announced with a
+/// duration ends once that much media has passed, unless an IN marker ends it
+/// earlier.
+///
+/// Init segments are held until the next media segment, so in split mode they
+/// open the file they belong to and in drop mode an ad's own init is dropped
+/// together with its media.
+pub struct AdBreakOperator {
+    context: Arc<StreamerContext>,
+    mode: AdBreakMode,
+    current: Option<AdBreak>,
+    /// In-band cue read from the previous segment, applied at the next split point
+    pending: Option<Cue>,
+    /// Splice event of the latest break, whose repeated cues are ignored
+    last_event: Option<u32>,
+    held_init: Option<M4sInitSegmentData>,
+    /// Media was emitted since the last end marker
+    emitted_media: bool,
+}
+
+impl AdBreakOperator {
+    pub fn new(context: Arc<StreamerContext>, mode: AdBreakMode) -> Self {
+        Self {
+            context,
+            mode,
+            current: None,
+            pending: None,
+            last_event: None,
+            held_init: None,
+            emitted_media: false,
+        }
+    }
+
+    /// Apply a cue, returning the break it ended.
+    fn apply(&mut self, cue: Cue) -> Option<AdBreak> {
+        match cue {
+            Cue::Out { duration, event } => {
+                if event.is_some() {
+                    if self.last_event == event {
+                        // Encoders repeat a cue around its splice point.
+                        return None;
+                    }
+                    self.last_event = event;
+                }
+                // A new OUT inside a break is a back-to-back break: restart the countdown.
+                let (segments, elapsed) = self
+                    .current
+                    .take()
+                    .map_or((0, 0.0), |current| (current.segments, current.duration));
+                self.current = Some(AdBreak {
+                    event,
+                    remaining: duration,
+                    segments,
+                    duration: elapsed,
+                });
+                None
+            }
+            Cue::Continue => {
+                if self.current.is_none() {
+                    self.current = Some(AdBreak {
+                        event: None,
+                        remaining: None,
+                        segments: 0,
+                        duration: 0.0,
+                    });
+                }
+                None
+            }
+            Cue::In => self.current.take(),
+        }
+    }
+
+    fn transition(
+        &mut self,
+        entering: bool,
+        ended: Option<AdBreak>,
+        output: &mut dyn FnMut(HlsData) -> Result<(), PipelineError>,
+    ) -> Result<(), PipelineError> {
+        if entering {
+            info!("{} Ad break started ({:?})", self.context.name, self.mode);
+        } else if let Some(ended) = ended {
+            info!(
+                "{} Ad break ended after {} segments ({:.1}s, {:?})",
+                self.context.name, ended.segments, ended.duration, self.mode
+            );
+        }
+
+        if self.mode == AdBreakMode::Split && self.emitted_media {
+            let reason = if entering {
+                SplitReason::AdBreakStart
+            } else {
+                SplitReason::AdBreakEnd
+            };
+            output(HlsData::end_marker_with_reason(reason))?;
+            self.emitted_media = false;
+        }
+        Ok(())
+    }
+
+    fn handle_media(
+        &mut self,
+        input: HlsData,
+        output: &mut dyn FnMut(HlsData) -> Result<(), PipelineError>,
+    ) -> Result<(), PipelineError> {
+        let Some(segment) = input.media_segment() else {
+            return output(input);
+        };
+        let duration = if segment.duration.is_finite() {
+            f64::from(segment.duration.max(0.0))
+        } else {
+            0.0
+        };
+        let split_point = input.part().is_none_or(|part| part.independent);
+
+        let before = self.current.as_ref().map(|current| current.event);
+        let mut ended = None;
+        let pending = split_point.then(|| self.pending.take()).flatten();
+        for cue in pending.into_iter().chain(playlist_cues(segment)) {
+            if let Some(current) = self.apply(cue) {
+                ended = Some(current);
+            }
+        }
+        let after = self.current.as_ref().map(|current| current.event);
+
+        if before.is_some() != after.is_some() {
+            self.transition(after.is_some(), ended, output)?;
+        } else if before.is_some() && before != after {
+            // Back-to-back breaks stay in the same ad file.
+            debug!("{} Next ad break in the same pod", self.context.name);
+        }
+
+        if let Some(current) = &mut self.current {
+            current.segments += 1;
+            current.duration += duration;
+            if let Some(remaining) = &mut current.remaining {
+                *remaining -= duration;
+                if *remaining <= BREAK_END_TOLERANCE_SECS && self.pending.is_none() {
+                    self.pending = Some(Cue::In);
+                }
+            }
+        }
+
+        if let Some(cue) = inband_cue(&input) {
+            debug!("{} In-band SCTE-35 cue: {:?}", self.context.name, cue);
+            self.pending = Some(cue);
+        }
+
+        let init = self.held_init.take();
+        if self.current.is_some() && self.mode == AdBreakMode::Drop {
+            return Ok(());
+        }
+        if let Some(init) = init {
+            output(HlsData::M4sData(M4sData::InitSegment(init)))?;
+        }
+        output(input)?;
+        self.emitted_media = true;
+        Ok(())
+    }
+}
+
+/// Ad markers attached to a segment in the playlist, in the order they apply.
+fn playlist_cues(segment: &MediaSegment) -> Vec<Cue> {
+    let mut cues = Vec::new();
+
+    if let Some(daterange) = &segment.daterange {
+        let has_attribute = |name: &str| {
+            daterange
+                .other_attributes
+                .as_ref()
+                .is_some_and(|attributes| attributes.contains_key(name))
+        };
+        let twitch_ad = daterange.class.as_deref() == Some("twitch-stitched-ad")
+            || daterange.id.starts_with("stitched-ad-");
+
+        if has_attribute("SCTE35-IN") && !has_attribute("SCTE35-OUT") {
+            cues.push(Cue::In);
+        } else if has_attribute("SCTE35-OUT") || twitch_ad {
+            cues.push(Cue::Out {
+                duration: daterange.duration.or(daterange.planned_duration),
+                event: None,
+            });
+        }
+    }
+
+    for tag in &segment.unknown_tags {
+        match tag.tag.as_str() {
+            "X-CUE-OUT" => cues.push(Cue::Out {
+                duration: tag.rest.as_deref().and_then(parse_cue_out_duration),
+                event: None,
+            }),
+            "X-CUE-OUT-CONT" => cues.push(Cue::Continue),
+            "X-CUE-IN" => cues.push(Cue::In),
+            _ => {}
+        }
+    }
+
+    cues
+}
+
+/// Duration of `EXT-X-CUE-OUT`, written either bare (`30`) or as `DURATION=30`.
+fn parse_cue_out_duration(rest: &str) -> Option<f64> {
+    let value = rest
+        .split(',')
+        .find_map(|attribute| match attribute.split_once('=') {
+            Some((name, value)) if name.trim().eq_ignore_ascii_case("DURATION") => Some(value),
+            Some(_) => None,
+            None => Some(attribute),
+        })?;
+    value
+        .trim()
+        .trim_matches('"')
+        .parse::<f64>()
+        .ok()
+        .filter(|duration| duration.is_finite() && *duration > 0.0)
+}
+
+/// The last `splice_insert` cue muxed into a TS segment.
+fn inband_cue(input: &HlsData) -> Option<Cue> {
+    let HlsData::TsData(ts) = input else {
+        return None;
+    };
+    let info = match ts.parse_stream_info_only() {
+        Ok(info) => info,
+        Err(e) => {
+            debug!("Failed to scan TS segment for SCTE-35 cues: {e}");
+            return None;
+        }
+    };
+
+    info.scte35_events
+        .iter()
+        .rev()
+        .find_map(|section| match &section.splice_command {
+            SpliceCommand::SpliceInsert(insert) if !insert.splice_event_cancel_indicator => {
+                Some(if insert.out_of_network_indicator {
+                    Cue::Out {
+                        duration: insert
+                            .duration
+                            .as_ref()
+                            .map(|duration| duration.duration as f64 / SCTE35_TIMESCALE),
+                        event: Some(insert.splice_event_id),
+                    }
+                } else {
+                    Cue::In
+                })
+            }
+            _ => None,
+        })
+}
+
+impl Processor<HlsData> for AdBreakOperator {
+    fn process(
+        &mut self,
+        context: &Arc<StreamerContext>,
+        input: HlsData,
+        output: &mut dyn FnMut(HlsData) -> Result<(), PipelineError>,
+    ) -> Result<(), PipelineError> {
+        if context.token.is_cancelled() {
+            return Err(PipelineError::Cancelled);
+        }
+
+        match input {
+            HlsData::M4sData(M4sData::InitSegment(init)) => {
+                // Decided by the media segment that follows.
+                self.held_init = Some(init);
+                Ok(())
+            }
+            HlsData::EndMarker(_) => {
+                self.emitted_media = false;
+                output(input)
+            }
+            _ => self.handle_media(input, output),
+        }
+    }
+
+    fn finish(
+        &mut self,
+        _context: &Arc<StreamerContext>,
+        _output: &mut dyn FnMut(HlsData) -> Result<(), PipelineError>,
+    ) -> Result<(), PipelineError> {
+        if let Some(current) = self.current.take() {
+            info!(
+                "{} Stream ended inside an ad break ({} segments, {:.1}s)",
+                self.context.name, current.segments, current.duration
+            );
+        }
+        Ok(())
+    }
+
+    fn name(&self) -> &'static str {
+        "AdBreak"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+    use m3u8_rs::{DateRange, ExtTag, QuotedOrUnquoted};
+    use std::collections::HashMap;
+    use tokio_util::sync::CancellationToken;
+
+    fn run(mode: AdBreakMode, inputs: Vec<HlsData>) -> Vec<HlsData> {
+        let context = StreamerContext::arc_new(CancellationToken::new());
+        let mut operator = AdBreakOperator::new(context.clone(), mode);
+        let mut out = Vec::new();
+        for input in inputs {
+            operator
+                .process(&context, input, &mut |item: HlsData| {
+                    out.push(item);
+                    Ok(())
+                })
+                .unwrap();
+        }
+        out
+    }
+
+    fn media(uri: &str, tags: &[(&str, Option<&str>)]) -> HlsData {
+        HlsData::mp4_segment(
+            MediaSegment {
+                uri: uri.to_string(),
+                duration: 2.0,
+                unknown_tags: tags
+                    .iter()
+                    .map(|(tag, rest)| ExtTag {
+                        tag: tag.to_string(),
+                        rest: rest.map(str::to_string),
+                    })
+                    .collect(),
+                ..MediaSegment::empty()
+            },
+            Bytes::from_static(b"moof"),
+        )
+    }
+
+    fn with_daterange(attribute: &str, duration: Option<f64>, uri: &str) -> HlsData {
+        let mut attributes = HashMap::from([
+            (
+                "ID".to_string(),
+                QuotedOrUnquoted::Quoted("break-1".to_string()),
+            ),
+            (
+                "START-DATE".to_string(),
+                QuotedOrUnquoted::Quoted("2024-01-01T00:00:00Z".to_string()),
+            ),
+            (
+                attribute.to_string(),
+                QuotedOrUnquoted::Unquoted("0xFC30".to_string()),
+            ),
+        ]);
+        if let Some(duration) = duration {
+            attributes.insert(
+                "DURATION".to_string(),
+                QuotedOrUnquoted::Unquoted(duration.to_string()),
+            );
+        }
+        let daterange = DateRange::from_hashmap(attributes).unwrap();
+        HlsData::mp4_segment(
+            MediaSegment {
+                uri: uri.to_string(),
+                duration: 2.0,
+                daterange: Some(daterange),
+                ..MediaSegment::empty()
+            },
+            Bytes::from_static(b"moof"),
+        )
+    }
+
+    fn uris(items: &[HlsData]) -> Vec<String> {
+        items
+            .iter()
+            .map(|item| match item {
+                HlsData::EndMarker(Some(reason)) => format!("[{reason}]"),
+                HlsData::EndMarker(None) => "[end]".to_string(),
+                HlsData::M4sData(M4sData::InitSegment(init)) => {
+                    format!("init:{}", init.segment.uri)
+                }
+                other => other
+                    .media_segment()
+                    .map(|segment| segment.uri.clone())
+                    .unwrap_or_default(),
+            })
+            .collect()
+    }
+
+    fn cue_out_break() -> Vec<HlsData> {
+        vec![
+            media("c1", &[]),
+            media("a1", &[("X-CUE-OUT", Some("4"))]),
+            media("a2", &[("X-CUE-OUT-CONT", Some("2/4"))]),
+            media("c2", &[("X-CUE-IN", None)]),
+            media("c3", &[]),
+        ]
+    }
+
+    #[test]
+    fn tag_mode_keeps_ad_segments() {
+        let out = run(AdBreakMode::Tag, cue_out_break());
+        assert_eq!(uris(&out), ["c1", "a1", "a2", "c2", "c3"]);
+    }
+
+    #[test]
+    fn split_mode_writes_the_break_to_its_own_file() {
+        let out = run(AdBreakMode::Split, cue_out_break());
+        assert_eq!(
+            uris(&out),
+            [
+                "c1",
+                "[ad break start]",
+                "a1",
+                "a2",
+                "[ad break end]",
+                "c2",
+                "c3"
+            ]
+        );
+    }
+
+    #[test]
+    fn drop_mode_removes_ad_segments_and_their_init() {
+        let ad_init = HlsData::mp4_init(
+            MediaSegment {
+                uri: "ad".to_string(),
+                ..MediaSegment::empty()
+            },
+            Bytes::from_static(b"moov"),
+        );
+        let mut inputs = cue_out_break();
+        inputs.insert(1, ad_init);
+        let out = run(AdBreakMode::Drop, inputs);
+        assert_eq!(uris(&out), ["c1", "c2", "c3"]);
+    }
+
+    #[test]
+    fn announced_duration_ends_the_break_without_cue_in() {
+        let out = run(
+            AdBreakMode::Drop,
+            vec![
+                with_daterange("SCTE35-OUT", Some(4.0), "a1"),
+                media("a2", &[]),
+                media("c1", &[]),
+            ],
+        );
+        assert_eq!(uris(&out), ["c1"]);
+    }
+
+    #[test]
+    fn daterange_scte35_in_ends_the_break() {
+        let out = run(
+            AdBreakMode::Drop,
+            vec![
+                with_daterange("SCTE35-OUT", None, "a1"),
+                media("a2", &[]),
+                media("a3", &[]),
+                with_daterange("SCTE35-IN", None, "c1"),
+            ],
+        );
+        assert_eq!(uris(&out), ["c1"]);
+    }
+
+    #[test]
+    fn content_init_is_emitted_with_its_media() {
+        let init = HlsData::mp4_init(
+            MediaSegment {
+                uri: "content".to_string(),
+                ..MediaSegment::empty()
+            },
+            Bytes::from_static(b"moov"),
+        );
+        let out = run(AdBreakMode::Split, vec![init, media("c1", &[])]);
+        assert_eq!(uris(&out), ["init:content", "c1"]);
+    }
+
+    fn ts_packet(pid: u16, table_id: u8, body: &[u8]) -> Vec<u8> {
+        let syntax = if table_id == 0xFC { 0x30 } else { 0xB0 };
+        let length = body.len() + 4;
+        let mut section = vec![table_id, syntax | (length >> 8) as u8, length as u8];
+        section.extend_from_slice(body);
+        section.extend_from_slice(&ts::mpeg2_crc32(&section).to_be_bytes());
+
+        let mut packet = vec![0x47, 0x40 | (pid >> 8) as u8, pid as u8, 0x10, 0x00];
+        packet.extend_from_slice(&section);
+        packet.resize(188, 0xFF);
+        packet
+    }
+
+    /// A TS segment whose PMT declares a SCTE-35 PID carrying an immediate
+    /// `splice_insert` out of the network for `duration_secs`.
+    fn ts_with_splice_insert(uri: &str, duration_secs: u64) -> HlsData {
+        let pat = [0x00, 0x01, 0xC1, 0x00, 0x00, 0x00, 0x01, 0xF0, 0x00];
+        let pmt = [
+            0x00, 0x01, 0xC1, 0x00, 0x00, 0xE1, 0x00, 0xF0, 0x00, 0x86, 0xE1, 0xF4, 0xF0, 0x06,
+            0x05, 0x04, b'C', b'U', b'E', b'I',
+        ];
+        let ticks = duration_secs * 90_000;
+        let mut splice_insert = vec![0x00, 0x00, 0x00, 0x07, 0x7F, 0xFF];
+        splice_insert.push(0xFE | (ticks >> 32) as u8);
+        splice_insert.extend_from_slice(&(ticks as u32).to_be_bytes());
+        splice_insert.extend_from_slice(&[0x00, 0x01, 0x00, 0x00]);
+        let mut scte35 = vec![0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0xF0];
+        scte35.push(splice_insert.len() as u8);
+        scte35.push(0x05);
+        scte35.extend_from_slice(&splice_insert);
+        scte35.extend_from_slice(&[0x00, 0x00]);
+
+        let data = [
+            ts_packet(0x0000, 0x00, &pat),
+            ts_packet(0x1000, 0x02, &pmt),
+            ts_packet(0x01F4, 0xFC, &scte35),
+        ]
+        .concat();
+        HlsData::ts(
+            MediaSegment {
+                uri: uri.to_string(),
+                duration: 2.0,
+                ..MediaSegment::empty()
+            },
+            Bytes::from(data),
+        )
+    }
+
+    #[test]
+    fn inband_splice_insert_starts_the_break_at_the_next_segment() {
+        let content = |uri: &str| {
+            HlsData::ts(
+                MediaSegment {
+                    uri: uri.to_string(),
+                    duration: 2.0,
+                    ..MediaSegment::empty()
+                },
+                Bytes::from_static(&[0x47, 0x1F, 0xFF, 0x10]),
+            )
+        };
+        let out = run(
+            AdBreakMode::Drop,
+            vec![
+                ts_with_splice_insert("c1", 4),
+                // The cue is repeated inside the break.
+                ts_with_splice_insert("a1", 4),
+                content("a2"),
+                content("c2"),
+            ],
+        );
+        assert_eq!(uris(&out), ["c1", "c2"]);
+    }
+
+    #[test]
+    fn parses_cue_out_durations() {
+        assert_eq!(parse_cue_out_duration("30"), Some(30.0));
+        assert_eq!(parse_cue_out_duration("DURATION=15.5"), Some(15.5));
+        assert_eq!(parse_cue_out_duration("Duration=\"12\",ID=7"), Some(12.0));
+        assert_eq!(parse_cue_out_duration("ID=7"), None);
+        assert_eq!(parse_cue_out_duration("0"), None);
+    }
+}
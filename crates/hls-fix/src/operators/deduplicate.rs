@@ -0,0 +1,290 @@
+use std::collections::{HashSet, VecDeque};
+use std::hash::Hash;
+use std::sync::Arc;
+
+use hls::{HlsData, M4sData};
+use m3u8_rs::MediaSegment;
+use pipeline_common::{PipelineError, Processor, StreamerContext};
+use tracing::{debug, info};
+
+use crate::crc32::crc32;
+
+/// Number of recent media segments remembered for duplicate detection.
+const DEFAULT_WINDOW: usize = 128;
+
+/// Bounded set of recently seen keys; the oldest key is forgotten first.
+#[derive(Debug)]
+struct RecentKeys<K> {
+    order: VecDeque<K>,
+    keys: HashSet<K>,
+    capacity: usize,
+}
+
+impl<K: Clone + Eq + Hash> RecentKeys<K> {
+    fn new(capacity: usize) -> Self {
+        Self {
+            order: VecDeque::with_capacity(capacity),
+            keys: HashSet::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    fn contains(&self, key: &K) -> bool {
+        self.keys.contains(key)
+    }
+
+    fn insert(&mut self, key: K) {
+        if !self.keys.insert(key.clone()) {
+            return;
+        }
+        self.order.push_back(key);
+        if self.order.len() > self.capacity
+            && let Some(oldest) = self.order.pop_front()
+        {
+            self.keys.remove(&oldest);
+        }
+    }
+
+    fn clear(&mut self) {
+        self.order.clear();
+        self.keys.clear();
+    }
+}
+
+/// HLS processor: Drops media segments that were already written.
+///
+/// Playlist refresh races and failover between sources can deliver the same
+/// segment twice, which shows up as a short repeat in the recording. A media
+/// segment is a duplicate of one of the recently written segments when:
+///
+/// - its content is identical (CRC32 and length), or
+/// - it has the same URI, ignoring scheme and host so mirrors of one
+///   origin match, and the same length.
+///
+/// Media sequence numbers are not used: they differ between sources and
+/// restart with the stream. For the same reason URIs are forgotten at a
+/// discontinuity, where a restarted packager may reuse segment names for new
+/// content. Init segments and end markers always pass through.
+pub struct DeduplicateOperator {
+    context: Arc<StreamerContext>,
+    contents: RecentKeys<(u32, usize)>,
+    uris: RecentKeys<(String, usize)>,
+    dropped: u64,
+}
+
+impl DeduplicateOperator {
+    pub fn new(context: Arc<StreamerContext>) -> Self {
+        Self::with_window(context, DEFAULT_WINDOW)
+    }
+
+    fn with_window(context: Arc<StreamerContext>, window: usize) -> Self {
+        let window = window.max(1);
+        Self {
+            context,
+            contents: RecentKeys::new(window),
+            uris: RecentKeys::new(window),
+            dropped: 0,
+        }
+    }
+
+    /// The URI without scheme and host; empty when the segment has no URI.
+    fn origin_relative_uri(segment: &MediaSegment) -> &str {
+        let uri = segment.uri.as_str();
+        match uri.split_once("://") {
+            Some((_, rest)) => rest.find('/').map_or("", |path| &rest[path..]),
+            None => uri,
+        }
+    }
+
+    /// Record a media segment, returning whether it was already seen.
+    fn is_duplicate(&mut self, segment: &MediaSegment, data: &[u8]) -> bool {
+        if segment.discontinuity {
+            self.uris.clear();
+        }
+
+        let content_key = (crc32(data), data.len());
+        let uri = Self::origin_relative_uri(segment);
+        let uri_key = (!uri.is_empty()).then(|| (uri.to_string(), data.len()));
+
+        let duplicate = self.contents.contains(&content_key)
+            || uri_key.as_ref().is_some_and(|key| self.uris.contains(key));
+        if !duplicate {
+            self.contents.insert(content_key);
+            if let Some(key) = uri_key {
+                self.uris.insert(key);
+            }
+        }
+        duplicate
+    }
+}
+
+impl Processor<HlsData> for DeduplicateOperator {
+    fn process(
+        &mut self,
+        context: &Arc<StreamerContext>,
+        input: HlsData,
+        output: &mut dyn FnMut(HlsData) -> Result<(), PipelineError>,
+    ) -> Result<(), PipelineError> {
+        if context.token.is_cancelled() {
+            return Err(PipelineError::Cancelled);
+        }
+
+        let duplicate = match &input {
+            HlsData::TsData(ts) => self.is_duplicate(&ts.segment, ts.data()),
+            HlsData::M4sData(M4sData::Segment(segment)) => {
+                self.is_duplicate(&segment.segment, &segment.data)
+            }
+            HlsData::M4sData(M4sData::InitSegment(_)) | HlsData::EndMarker(_) => false,
+        };
+
+        if duplicate {
+            self.dropped += 1;
+            debug!(
+                "{} Dropping duplicate segment: {}",
+                self.context.name,
+                input.media_segment().map_or("", |segment| &segment.uri)
+            );
+            return Ok(());
+        }
+        output(input)
+    }
+
+    fn finish(
+        &mut self,
+        _context: &Arc<StreamerContext>,
+        _output: &mut dyn FnMut(HlsData) -> Result<(), PipelineError>,
+    ) -> Result<(), PipelineError> {
+        if self.dropped > 0 {
+            info!(
+                "{} Dropped {} duplicate segments",
+                self.context.name, self.dropped
+            );
+        }
+        Ok(())
+    }
+
+    fn name(&self) -> &'static str {
+        "Deduplicate"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+    use tokio_util::sync::CancellationToken;
+
+    fn run(operator: &mut DeduplicateOperator, inputs: Vec<HlsData>) -> Vec<String> {
+        let context = StreamerContext::arc_new(CancellationToken::new());
+        let mut out = Vec::new();
+        for input in inputs {
+            operator
+                .process(&context, input, &mut |item: HlsData| {
+                    out.push(
+                        item.media_segment()
+                            .map_or_else(|| "end".to_string(), |segment| segment.uri.clone()),
+                    );
+                    Ok(())
+                })
+                .unwrap();
+        }
+        out
+    }
+
+    fn segment(uri: &str, data: &'static [u8], discontinuity: bool) -> HlsData {
+        HlsData::ts(
+            MediaSegment {
+                uri: uri.to_string(),
+                duration: 2.0,
+                discontinuity,
+                ..MediaSegment::empty()
+            },
+            Bytes::from_static(data),
+        )
+    }
+
+    fn operator() -> DeduplicateOperator {
+        DeduplicateOperator::new(StreamerContext::arc_new(CancellationToken::new()))
+    }
+
+    #[test]
+    fn drops_segment_delivered_twice() {
+        let out = run(
+            &mut operator(),
+            vec![
+                segment("https://a.example/live/1.ts", b"one", false),
+                segment("https://a.example/live/2.ts", b"two", false),
+                segment("https://a.example/live/1.ts", b"one", false),
+                segment("https://a.example/live/3.ts", b"three", false),
+            ],
+        );
+        assert_eq!(
+            out,
+            [
+                "https://a.example/live/1.ts",
+                "https://a.example/live/2.ts",
+                "https://a.example/live/3.ts"
+            ]
+        );
+    }
+
+    #[test]
+    fn matches_mirrors_by_path_or_content() {
+        let out = run(
+            &mut operator(),
+            vec![
+                segment("https://a.example/live/1.ts?token=a", b"one", false),
+                // Same path on a mirror.
+                segment("https://b.example/live/1.ts?token=a", b"one", false),
+                // Same bytes under another name.
+                segment("https://b.example/other/1.ts?token=b", b"one", false),
+                segment("https://b.example/live/2.ts?token=b", b"two", false),
+            ],
+        );
+        assert_eq!(
+            out,
+            [
+                "https://a.example/live/1.ts?token=a",
+                "https://b.example/live/2.ts?token=b"
+            ]
+        );
+    }
+
+    #[test]
+    fn reused_names_after_discontinuity_are_kept() {
+        let out = run(
+            &mut operator(),
+            vec![
+                segment("seg_1.ts", b"old", false),
+                segment("seg_1.ts", b"new", true),
+            ],
+        );
+        assert_eq!(out, ["seg_1.ts", "seg_1.ts"]);
+    }
+
+    #[test]
+    fn forgets_segments_outside_the_window() {
+        let context = StreamerContext::arc_new(CancellationToken::new());
+        let mut operator = DeduplicateOperator::with_window(context, 2);
+        let out = run(
+            &mut operator,
+            vec![
+                segment("1.ts", b"one", false),
+                segment("2.ts", b"two", false),
+                segment("3.ts", b"three", false),
+                segment("1.ts", b"one", false),
+            ],
+        );
+        assert_eq!(out, ["1.ts", "2.ts", "3.ts", "1.ts"]);
+    }
+
+    #[test]
+    fn init_segments_and_end_markers_pass_through() {
+        let init = || HlsData::mp4_init(MediaSegment::empty(), Bytes::from_static(b"init"));
+        let out = run(
+            &mut operator(),
+            vec![init(), HlsData::end_marker(), init(), HlsData::end_marker()],
+        );
+        assert_eq!(out, ["", "end", "", "end"]);
+    }
+}
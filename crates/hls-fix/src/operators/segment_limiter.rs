@@ -1,17 +1,18 @@
 use bytes::Bytes;
 use hls::{HlsData, M4sData, M4sInitSegmentData, SegmentType, SplitReason};
-use pipeline_common::{PipelineError, Processor, StreamerContext};
+use pipeline_common::{PipelineError, Processor, StreamerContext, WallClockBoundary};
 use std::sync::Arc;
 use std::time::Duration;
 use tracing::debug;
 
-/// HLS processor: Limits HLS segments based on size or duration
+/// HLS processor: Limits HLS segments based on size, duration or wall-clock boundaries
 pub struct SegmentLimiterOperator {
     max_duration: Option<Duration>,
     max_size: Option<u64>,
+    wall_clock: Option<WallClockBoundary>,
     current_duration: Duration,
     current_size: u64,
-    // Store the first initialization segment we encounter
+    // The init segment governing the current media, re-emitted at the start of each file
     init_segment: Option<M4sInitSegmentData>,
     // Track if we've output an init segment recently
     init_segment_sent: bool,
@@ -22,6 +23,7 @@ impl SegmentLimiterOperator {
         Self {
             max_duration,
             max_size,
+            wall_clock: None,
             current_duration: Duration::from_secs(0),
             current_size: 0,
             init_segment: None,
@@ -29,6 +31,12 @@ impl SegmentLimiterOperator {
         }
     }
 
+    /// Also split whenever the local time crosses a multiple of `interval` since midnight.
+    pub fn with_wall_clock_split(mut self, interval: Option<Duration>) -> Self {
+        self.wall_clock = interval.map(WallClockBoundary::new);
+        self
+    }
+
     fn safe_duration(secs: f32) -> Duration {
         if !secs.is_finite() || secs <= 0.0 {
             Duration::ZERO
@@ -44,7 +52,7 @@ impl SegmentLimiterOperator {
         segment_duration: f32,
     ) -> Option<SplitReason> {
         // If no limits are set, no limit can be reached
-        if self.max_duration.is_none() && self.max_size.is_none() {
+        if self.max_duration.is_none() && self.max_size.is_none() && self.wall_clock.is_none() {
             return None;
         }
 
@@ -78,6 +86,18 @@ impl SegmentLimiterOperator {
             }
         }
 
+        // Check wall-clock boundary once the current file has content
+        if self.current_size > 0
+            && let Some(wall_clock) = &self.wall_clock
+            && wall_clock.is_due()
+        {
+            debug!(
+                "Wall-clock boundary reached (every {}s)",
+                wall_clock.interval().as_secs()
+            );
+            return Some(SplitReason::WallClock);
+        }
+
         None
     }
 
@@ -87,6 +107,9 @@ impl SegmentLimiterOperator {
         self.current_duration = Duration::from_secs(0);
         self.current_size = 0;
         self.init_segment_sent = false;
+        if let Some(wall_clock) = &mut self.wall_clock {
+            wall_clock.restart();
+        }
     }
 
     /// Add segment to current tracking
@@ -106,12 +129,16 @@ impl Processor<HlsData> for SegmentLimiterOperator {
         if context.token.is_cancelled() {
             return Err(PipelineError::Cancelled);
         }
+        // An LL-HLS part can only start a new file when it is independent;
+        // a reached limit carries over to the next one.
+        let split_point = input.part().is_none_or(|part| part.independent);
         match input.segment_type() {
             SegmentType::Ts => {
                 if let HlsData::TsData(ts_data) = input {
                     // Check if the current segment would exceed the limit. If so, start a new sequence.
-                    if let Some(reason) =
-                        self.check_limit_reached(ts_data.data(), ts_data.segment.duration)
+                    if split_point
+                        && let Some(reason) =
+                            self.check_limit_reached(ts_data.data(), ts_data.segment.duration)
                     {
                         output(HlsData::end_marker_with_reason(reason))?;
                         self.reset_counters();
@@ -125,10 +152,9 @@ impl Processor<HlsData> for SegmentLimiterOperator {
             }
             SegmentType::M4sInit => {
                 if let HlsData::M4sData(M4sData::InitSegment(init_segment)) = input {
-                    // Store the init segment for later use if it's the first one we've seen
-                    if self.init_segment.is_none() {
-                        self.init_segment = Some(init_segment.clone());
-                    }
+                    // Keep the latest init: after an encoder restart the first one no
+                    // longer describes the media.
+                    self.init_segment = Some(init_segment.clone());
 
                     // Always output the init segment when we encounter it directly
                     output(HlsData::M4sData(M4sData::InitSegment(init_segment)))?;
@@ -138,8 +164,9 @@ impl Processor<HlsData> for SegmentLimiterOperator {
             SegmentType::M4sMedia => {
                 if let HlsData::M4sData(M4sData::Segment(segment)) = input {
                     // Check if the current segment would exceed the limit. If so, start a new sequence.
-                    if let Some(reason) =
-                        self.check_limit_reached(&segment.data, segment.segment.duration)
+                    if split_point
+                        && let Some(reason) =
+                            self.check_limit_reached(&segment.data, segment.segment.duration)
                     {
                         output(HlsData::end_marker_with_reason(reason))?;
                         self.reset_counters();
@@ -186,6 +213,7 @@ impl Processor<HlsData> for SegmentLimiterOperator {
 mod tests {
     use super::*;
     use bytes::Bytes;
+    use hls::PartialSegment;
     use m3u8_rs::MediaSegment;
     use pipeline_common::StreamerContext;
     use tokio_util::sync::CancellationToken;
@@ -226,6 +254,90 @@ mod tests {
         assert!(matches!(out[2], HlsData::TsData(_)));
     }
 
+    #[test]
+    fn defers_split_to_independent_part() {
+        let token = CancellationToken::new();
+        let context = StreamerContext::arc_new(token);
+        let mut operator = SegmentLimiterOperator::new(Some(Duration::from_secs(1)), None);
+
+        let mut out = Vec::new();
+        let mut output = |item: HlsData| -> Result<(), PipelineError> {
+            out.push(item);
+            Ok(())
+        };
+
+        let part = |index: u32, independent: bool| {
+            let marker = PartialSegment {
+                uri: String::new(),
+                duration: 0.6,
+                independent,
+                byte_range: None,
+                gap: false,
+            }
+            .marker(index);
+            HlsData::ts(
+                MediaSegment {
+                    duration: 0.6,
+                    unknown_tags: vec![marker],
+                    ..MediaSegment::empty()
+                },
+                Bytes::from_static(b"aaaaaaaaaa"),
+            )
+        };
+
+        for (index, independent) in [(0, true), (1, false), (2, false), (3, true)] {
+            operator
+                .process(&context, part(index, independent), &mut output)
+                .unwrap();
+        }
+
+        let kinds: Vec<_> = out
+            .iter()
+            .map(|item| matches!(item, HlsData::EndMarker(_)))
+            .collect();
+        assert_eq!(kinds, vec![false, false, false, true, false]);
+    }
+
+    #[test]
+    fn new_file_starts_with_latest_init() {
+        let token = CancellationToken::new();
+        let context = StreamerContext::arc_new(token);
+        let mut operator = SegmentLimiterOperator::new(Some(Duration::from_secs(1)), None);
+
+        let mut out = Vec::new();
+        let mut output = |item: HlsData| -> Result<(), PipelineError> {
+            out.push(item);
+            Ok(())
+        };
+
+        let media = || {
+            HlsData::mp4_segment(
+                MediaSegment {
+                    duration: 0.6,
+                    ..MediaSegment::empty()
+                },
+                Bytes::from_static(b"moof"),
+            )
+        };
+        let inputs = [
+            HlsData::mp4_init(MediaSegment::empty(), Bytes::from_static(b"init-a")),
+            media(),
+            HlsData::mp4_init(MediaSegment::empty(), Bytes::from_static(b"init-b")),
+            media(),
+            media(),
+        ];
+        for input in inputs {
+            operator.process(&context, input, &mut output).unwrap();
+        }
+
+        let reinjected = out
+            .iter()
+            .skip_while(|item| !item.is_end_marker())
+            .nth(1)
+            .and_then(HlsData::data);
+        assert_eq!(reinjected.map(|data| data.as_ref()), Some(&b"init-b"[..]));
+    }
+
     #[test]
     fn does_not_panic_on_non_finite_durations() {
         let token = CancellationToken::new();
@@ -250,4 +362,49 @@ mod tests {
         assert_eq!(out.len(), 1);
         assert!(matches!(out[0], HlsData::TsData(_)));
     }
+
+    #[test]
+    fn splits_on_wall_clock_boundary() {
+        let token = CancellationToken::new();
+        let context = StreamerContext::arc_new(token);
+        let now = Arc::new(std::sync::Mutex::new(
+            time::macros::datetime!(2024-05-01 23:59:58 +8),
+        ));
+        let clock = Arc::clone(&now);
+        let mut operator = SegmentLimiterOperator::new(None, None);
+        operator.wall_clock = Some(WallClockBoundary::with_clock(
+            Duration::from_secs(86_400),
+            move || *clock.lock().unwrap(),
+        ));
+
+        let mut out = Vec::new();
+        let mut output = |item: HlsData| -> Result<(), PipelineError> {
+            out.push(item);
+            Ok(())
+        };
+
+        let seg = || {
+            HlsData::ts(
+                MediaSegment {
+                    duration: 2.0,
+                    ..MediaSegment::empty()
+                },
+                Bytes::from_static(b"aaaaaaaaaa"),
+            )
+        };
+        operator.process(&context, seg(), &mut output).unwrap();
+        *now.lock().unwrap() = time::macros::datetime!(2024-05-02 00:00:00 +8);
+        operator.process(&context, seg(), &mut output).unwrap();
+        operator.process(&context, seg(), &mut output).unwrap();
+
+        let reasons: Vec<_> = out
+            .iter()
+            .filter_map(|item| match item {
+                HlsData::EndMarker(reason) => Some(reason.clone()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(reasons, vec![Some(SplitReason::WallClock)]);
+        assert!(matches!(out[1], HlsData::EndMarker(_)));
+    }
 }
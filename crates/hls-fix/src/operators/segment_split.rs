@@ -16,6 +16,8 @@ use crate::crc32;
 /// # Split Triggers (will cause a new output file)
 ///
 /// - **MP4 init segment changes**: Different CRC indicates codec/resolution changes
+/// - **`EXT-X-DISCONTINUITY`**: The encoder restarted or the source switched; timestamps
+///   and parameters after the tag are unrelated to what came before
 /// - **Video resolution changes**: Detected via SPS parsing or stream profile
 /// - **Program number changes**: Indicates a different broadcast program
 /// - **Transport Stream ID changes**: Indicates a different stream source
@@ -37,6 +39,11 @@ use crate::crc32;
 /// the PMT and stream profile to temporarily show fewer streams. This is normal
 /// behavior, not a stream discontinuity. Only changes that would cause decoder
 /// errors or visual artifacts should trigger a split.
+///
+/// Every split (including one announced upstream by an end marker) starts a new
+/// comparison baseline, and fMP4 output files always begin with the init segment
+/// that governs them: it is re-emitted before the first media segment of a file
+/// when the stream itself does not repeat it.
 pub struct SegmentSplitOperator {
     context: Arc<StreamerContext>,
     last_init_segment_crc: Option<u32>,
@@ -44,6 +51,10 @@ pub struct SegmentSplitOperator {
     last_ts_stream_info: Option<TsStreamInfo>,
     last_resolution: Option<Resolution>,
     last_init_segment: Option<M4sInitSegmentData>,
+    /// The last init segment must precede the next fMP4 media segment
+    reinject_init: bool,
+    /// A media segment was emitted since the last split, so a discontinuity starts a new file
+    emitted_payload: bool,
     /// Best-effort budget for TS resolution probing until we establish a baseline.
     ///
     /// Some streams only carry SPS intermittently; once we have a baseline
@@ -65,6 +76,8 @@ impl SegmentSplitOperator {
             last_ts_stream_info: None,
             last_resolution: None,
             last_init_segment: None,
+            reinject_init: false,
+            emitted_payload: false,
             resolution_probe_remaining: 50,
         }
     }
@@ -460,14 +473,23 @@ impl SegmentSplitOperator {
         Ok(split_reason)
     }
 
-    // Reset operator state
-    fn reset(&mut self) {
+    // Start a new comparison baseline after a split. The last init segment is kept:
+    // it still governs the media that follows unless a new one arrives.
+    fn reset_baseline(&mut self) {
         self.last_init_segment_crc = None;
         self.last_stream_profile = None;
         self.last_ts_stream_info = None;
         self.last_resolution = None;
-        self.last_init_segment = None;
         self.resolution_probe_remaining = 50;
+        self.emitted_payload = false;
+        self.reinject_init = self.last_init_segment.is_some();
+    }
+
+    // Reset operator state
+    fn reset(&mut self) {
+        self.reset_baseline();
+        self.last_init_segment = None;
+        self.reinject_init = false;
     }
 }
 
@@ -493,12 +515,22 @@ impl Processor<HlsData> for SegmentSplitOperator {
                 split_reason = self.handle_ts_segment(&input)?;
             }
             HlsData::EndMarker(_) => {
-                // Reset state when we see an end marker
-                self.reset();
+                // An upstream split (e.g. a discontinuity announced by the downloader)
+                // starts a new file: compare against a fresh baseline.
+                self.reset_baseline();
+                return output(input);
             }
             _ => {}
         }
 
+        if split_reason.is_none() && input.is_discontinuity() && self.emitted_payload {
+            info!(
+                "{} Discontinuity encountered, splitting the stream",
+                self.context.name
+            );
+            split_reason = Some(SplitReason::Discontinuity);
+        }
+
         // If we need to split, emit an end marker first
         if let Some(reason) = split_reason {
             debug!(
@@ -506,20 +538,34 @@ impl Processor<HlsData> for SegmentSplitOperator {
                 self.context.name
             );
             output(HlsData::end_marker_with_reason(reason))?;
+            self.emitted_payload = false;
+            self.reinject_init = true;
+        }
 
-            // If the split was triggered by a non-init segment, we need to re-emit the last init segment.
-            if !matches!(&input, HlsData::M4sData(M4sData::InitSegment(_)))
-                && let Some(init_segment) = &self.last_init_segment
-            {
-                output(HlsData::mp4_init(
-                    init_segment.segment.clone(),
-                    init_segment.data.clone(),
-                ))?;
+        match &input {
+            HlsData::M4sData(M4sData::Segment(_)) if self.reinject_init => {
+                if let Some(init_segment) = &self.last_init_segment {
+                    output(HlsData::mp4_init(
+                        init_segment.segment.clone(),
+                        init_segment.data.clone(),
+                    ))?;
+                }
+                self.reinject_init = false;
+            }
+            HlsData::M4sData(M4sData::InitSegment(_)) | HlsData::TsData(_) => {
+                self.reinject_init = false;
             }
+            _ => {}
         }
 
+        let is_media = matches!(
+            &input,
+            HlsData::M4sData(M4sData::Segment(_)) | HlsData::TsData(_)
+        );
+
         // Always output the original input
         output(input)?;
+        self.emitted_payload |= is_media;
 
         Ok(())
     }
@@ -546,6 +592,110 @@ mod tests {
     use pipeline_common::init_test_tracing;
     use tokio_util::sync::CancellationToken;
 
+    fn run(inputs: Vec<HlsData>) -> Vec<HlsData> {
+        let context = StreamerContext::arc_new(CancellationToken::new());
+        let mut operator = SegmentSplitOperator::new(context.clone());
+        let mut out = Vec::new();
+        for input in inputs {
+            operator
+                .process(&context, input, &mut |item: HlsData| {
+                    out.push(item);
+                    Ok(())
+                })
+                .unwrap();
+        }
+        out
+    }
+
+    fn mp4_media(discontinuity: bool) -> HlsData {
+        HlsData::mp4_segment(
+            MediaSegment {
+                discontinuity,
+                ..MediaSegment::empty()
+            },
+            Bytes::from_static(b"\0\0\0\x08moof"),
+        )
+    }
+
+    fn mp4_init(data: &'static [u8], discontinuity: bool) -> HlsData {
+        HlsData::mp4_init(
+            MediaSegment {
+                discontinuity,
+                ..MediaSegment::empty()
+            },
+            Bytes::from_static(data),
+        )
+    }
+
+    fn kinds(items: &[HlsData]) -> Vec<&'static str> {
+        items
+            .iter()
+            .map(|item| match item {
+                HlsData::EndMarker(Some(SplitReason::Discontinuity)) => "split:discontinuity",
+                HlsData::EndMarker(_) => "split",
+                HlsData::M4sData(M4sData::InitSegment(_)) => "init",
+                HlsData::M4sData(M4sData::Segment(_)) => "media",
+                HlsData::TsData(_) => "ts",
+            })
+            .collect()
+    }
+
+    #[test]
+    fn discontinuity_splits_and_reinjects_init() {
+        let out = run(vec![
+            mp4_init(b"init-a", false),
+            mp4_media(false),
+            mp4_media(true),
+            mp4_media(false),
+        ]);
+        assert_eq!(
+            kinds(&out),
+            [
+                "init",
+                "media",
+                "split:discontinuity",
+                "init",
+                "media",
+                "media"
+            ]
+        );
+    }
+
+    #[test]
+    fn upstream_split_is_not_duplicated() {
+        let out = run(vec![
+            mp4_init(b"init-a", false),
+            mp4_media(false),
+            HlsData::end_marker_with_reason(SplitReason::Discontinuity),
+            mp4_media(true),
+        ]);
+        assert_eq!(
+            kinds(&out),
+            ["init", "media", "split:discontinuity", "init", "media"]
+        );
+    }
+
+    #[test]
+    fn encoder_restart_with_new_init_splits_once() {
+        let out = run(vec![
+            mp4_init(b"init-a", false),
+            mp4_media(false),
+            mp4_init(b"init-b", true),
+            mp4_media(false),
+        ]);
+        assert_eq!(kinds(&out), ["init", "media", "split", "init", "media"]);
+        assert_eq!(
+            out[3].data().map(|data| data.as_ref()),
+            Some(&b"init-b"[..])
+        );
+    }
+
+    #[test]
+    fn leading_discontinuity_does_not_split() {
+        let out = run(vec![mp4_init(b"init-a", true), mp4_media(true)]);
+        assert_eq!(kinds(&out), ["init", "media"]);
+    }
+
     // Helper function to create a working TS data with specific codec combinations
     fn create_ts_data_with_codecs(video_codec: u8, audio_codec: u8, program_num: u16) -> Vec<u8> {
         let mut ts_data = Vec::new();
@@ -5,23 +5,32 @@ use pipeline_common::{
     ChannelSpec, Pipeline, PipelineProvider, StreamerContext, config::PipelineConfig,
 };
 
-use crate::operators::{DefragmentOperator, SegmentLimiterOperator, SegmentSplitOperator};
+use crate::operators::{
+    AdBreakMode, AdBreakOperator, DeduplicateOperator, DefragmentOperator, SegmentLimiterOperator,
+    SegmentSplitOperator,
+};
 
 pub const DEFAULT_CHANNEL_BUDGET_BYTES: usize = 64 * 1024 * 1024;
 
 #[derive(Debug, Clone)]
 pub struct HlsPipelineConfig {
+    /// Drop segments already written, e.g. after a playlist reload race or failover.
+    pub deduplicate: bool,
     pub defragment: bool,
     pub split_segments: bool,
     pub segment_limiter: bool,
+    /// Handling of detected ad breaks; `None` skips ad detection.
+    pub ad_breaks: Option<AdBreakMode>,
 }
 
 impl Default for HlsPipelineConfig {
     fn default() -> Self {
         Self {
+            deduplicate: true,
             defragment: true,
             split_segments: true,
             segment_limiter: true,
+            ad_breaks: None,
         }
     }
 }
@@ -44,6 +53,12 @@ impl HlsPipelineConfigBuilder {
         }
     }
 
+    /// Detect ad breaks and tag, split or drop them
+    pub fn ad_breaks(mut self, mode: AdBreakMode) -> Self {
+        self.config.ad_breaks = Some(mode);
+        self
+    }
+
     pub fn build(self) -> HlsPipelineConfig {
         self.config
     }
@@ -87,21 +102,35 @@ impl PipelineProvider for HlsPipeline {
     fn build_pipeline(&self) -> Pipeline<Self::Item> {
         let mut sync_pipeline = pipeline_common::Pipeline::new(self.context.clone());
 
+        if self.config.deduplicate {
+            sync_pipeline =
+                sync_pipeline.add_processor(DeduplicateOperator::new(self.context.clone()));
+        }
+
         if self.config.defragment {
             sync_pipeline =
                 sync_pipeline.add_processor(DefragmentOperator::new(self.context.clone()));
         }
 
+        // Before splitting, so dropped ads never reach the split decisions.
+        if let Some(mode) = self.config.ad_breaks {
+            sync_pipeline =
+                sync_pipeline.add_processor(AdBreakOperator::new(self.context.clone(), mode));
+        }
+
         if self.config.split_segments {
             sync_pipeline =
                 sync_pipeline.add_processor(SegmentSplitOperator::new(self.context.clone()));
         }
 
         if self.config.segment_limiter {
-            sync_pipeline = sync_pipeline.add_processor(SegmentLimiterOperator::new(
-                self.common_config.max_duration,
-                Some(self.common_config.max_file_size),
-            ));
+            sync_pipeline = sync_pipeline.add_processor(
+                SegmentLimiterOperator::new(
+                    self.common_config.max_duration,
+                    Some(self.common_config.max_file_size),
+                )
+                .with_wall_clock_split(self.common_config.wall_clock_split),
+            );
         }
 
         sync_pipeline
@@ -0,0 +1,227 @@
+//! # Analysis reports
+//!
+//! A structured, serializable summary of a written HLS output file, built from the
+//! analyzer's [`HlsStats`].
+//!
+//! Besides sizes and segment counts, reports describe how the segments were delivered:
+//! how far segment durations drift from the playlist's target duration, which media
+//! sequence numbers are missing, how many discontinuities the file spans and how long the
+//! segments took to download. Delivery timing requires the downloader's delivery markers
+//! (see `hls::DeliveryMarker`); without them those fields stay empty. TS streams from DVB
+//! sources also report their service name and now/next events.
+//!
+//! ## License
+//!
+//! MIT License
+//!
+//! ## Authors
+//!
+//! - hua0512
+//!
+
+use serde::Serialize;
+
+use crate::analyzer::{HlsStats, SequenceGap, ServiceInfo};
+
+/// A segment drifting this much (in seconds) over the target duration rounds above it.
+const OVER_TARGET_DRIFT_SECS: f32 = 0.5;
+
+/// Segment counts of an output file, by segment type.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct SegmentCounts {
+    pub total: u32,
+    pub media: u32,
+    pub init: u32,
+}
+
+/// Segment duration minus the target duration, over the whole media segments of a file.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct DurationDrift {
+    pub mean_secs: f32,
+    pub min_secs: f32,
+    pub max_secs: f32,
+    /// Segments whose duration, rounded to the nearest second, exceeds the target duration
+    pub over_target: u32,
+}
+
+impl DurationDrift {
+    fn from_drifts(drifts: &[f32]) -> Option<Self> {
+        if drifts.is_empty() {
+            return None;
+        }
+        Some(Self {
+            mean_secs: drifts.iter().sum::<f32>() / drifts.len() as f32,
+            min_secs: drifts.iter().copied().fold(f32::INFINITY, f32::min),
+            max_secs: drifts.iter().copied().fold(f32::NEG_INFINITY, f32::max),
+            over_target: drifts
+                .iter()
+                .filter(|drift| **drift >= OVER_TARGET_DRIFT_SECS)
+                .count() as u32,
+        })
+    }
+}
+
+/// Distribution of segment download times in milliseconds.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct LatencyDistribution {
+    pub samples: u32,
+    pub mean_ms: f64,
+    pub min_ms: u64,
+    pub p50_ms: u64,
+    pub p90_ms: u64,
+    pub p99_ms: u64,
+    pub max_ms: u64,
+}
+
+impl LatencyDistribution {
+    fn from_latencies(latencies: &[u64]) -> Option<Self> {
+        let mut sorted = latencies.to_vec();
+        sorted.sort_unstable();
+        let (&min_ms, &max_ms) = (sorted.first()?, sorted.last()?);
+        // Nearest-rank percentile
+        let percentile = |p: usize| sorted[(sorted.len() * p).div_ceil(100).max(1) - 1];
+        Some(Self {
+            samples: sorted.len() as u32,
+            mean_ms: sorted.iter().sum::<u64>() as f64 / sorted.len() as f64,
+            min_ms,
+            p50_ms: percentile(50),
+            p90_ms: percentile(90),
+            p99_ms: percentile(99),
+            max_ms,
+        })
+    }
+}
+
+/// Structured analysis of a written HLS output file.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct AnalysisReport {
+    pub duration_secs: f64,
+    pub file_size: u64,
+    pub segments: SegmentCounts,
+    /// Largest `EXT-X-TARGETDURATION` of the playlists the segments came from
+    pub target_duration_secs: Option<f64>,
+    pub duration_drift: Option<DurationDrift>,
+    /// Media sequence numbers missing from the file, in order
+    pub sequence_gaps: Vec<SequenceGap>,
+    /// Discontinuities inside the file
+    pub discontinuities: u32,
+    pub download_latency: Option<LatencyDistribution>,
+    /// DVB service information of TS segments
+    pub service: Option<ServiceInfo>,
+}
+
+impl AnalysisReport {
+    /// Build a report from the analyzer stats of one output file.
+    pub fn from_stats(stats: &HlsStats) -> Self {
+        Self {
+            duration_secs: f64::from(stats.total_duration),
+            file_size: stats.total_size,
+            segments: SegmentCounts {
+                total: stats.total_segment_count,
+                media: stats.ts_segment_count + stats.mp4_media_segment_count,
+                init: stats.mp4_init_segment_count,
+            },
+            target_duration_secs: stats.target_duration,
+            duration_drift: DurationDrift::from_drifts(&stats.duration_drifts),
+            sequence_gaps: stats.sequence_gaps.clone(),
+            discontinuities: stats.discontinuity_count,
+            download_latency: LatencyDistribution::from_latencies(&stats.download_latencies_ms),
+            service: stats.service.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::HlsAnalyzer;
+    use bytes::Bytes;
+    use hls::{DeliveryMarker, HlsData};
+    use m3u8_rs::MediaSegment;
+
+    fn segment(
+        media_sequence: u64,
+        duration: f32,
+        download_ms: u64,
+        discontinuity: bool,
+    ) -> HlsData {
+        let mut segment = MediaSegment {
+            duration,
+            discontinuity,
+            ..MediaSegment::empty()
+        };
+        DeliveryMarker::new(media_sequence, Some(6.0))
+            .with_download_ms(download_ms)
+            .attach(&mut segment);
+        HlsData::mp4_segment(segment, Bytes::from_static(b"media"))
+    }
+
+    #[test]
+    fn builds_report_from_delivery_markers() {
+        let mut analyzer = HlsAnalyzer::new();
+        for item in [
+            segment(10, 6.0, 100, true),
+            segment(11, 5.5, 300, false),
+            segment(14, 6.6, 200, true),
+            segment(15, 6.0, 1_000, false),
+        ] {
+            analyzer.analyze_segment(&item).unwrap();
+        }
+
+        let report = AnalysisReport::from_stats(&analyzer.stats);
+
+        assert_eq!(report.target_duration_secs, Some(6.0));
+        assert_eq!(
+            report.sequence_gaps,
+            vec![SequenceGap {
+                first_missing: 12,
+                missing: 2,
+            }]
+        );
+        // The discontinuity opening the file is not inside it.
+        assert_eq!(report.discontinuities, 1);
+
+        let drift = report.duration_drift.clone().unwrap();
+        assert_eq!(drift.over_target, 1);
+        assert!((drift.min_secs + 0.5).abs() < 1e-6);
+        assert!((drift.max_secs - 0.6).abs() < 1e-6);
+
+        assert_eq!(
+            report.download_latency,
+            Some(LatencyDistribution {
+                samples: 4,
+                mean_ms: 400.0,
+                min_ms: 100,
+                p50_ms: 200,
+                p90_ms: 1_000,
+                p99_ms: 1_000,
+                max_ms: 1_000,
+            })
+        );
+
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["sequence_gaps"][0]["first_missing"], 12);
+        assert_eq!(json["segments"]["media"], 4);
+    }
+
+    #[test]
+    fn segments_without_markers_leave_timing_empty() {
+        let mut analyzer = HlsAnalyzer::new();
+        analyzer
+            .analyze_segment(&HlsData::ts(
+                MediaSegment {
+                    duration: 2.0,
+                    ..MediaSegment::empty()
+                },
+                Bytes::from_static(&[0x47; 188]),
+            ))
+            .unwrap();
+
+        let report = AnalysisReport::from_stats(&analyzer.stats);
+
+        assert_eq!(report.segments.media, 1);
+        assert_eq!(report.target_duration_secs, None);
+        assert_eq!(report.duration_drift, None);
+        assert_eq!(report.download_latency, None);
+    }
+}
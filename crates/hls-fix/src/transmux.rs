@@ -0,0 +1,993 @@
+//! # HLS to FLV transmuxing
+//!
+//! [`FlvTransmuxer`] converts HLS segments into an FLV tag stream so HLS sources can run
+//! through the FLV operator chain (splitting, timing repair, keyframe index) and end up in
+//! the same container as FLV sources.
+//!
+//! Supported inputs:
+//!
+//! - MPEG-TS segments carrying H.264 or H.265 (Annex B) and ADTS AAC
+//! - fMP4 segments carrying H.264, H.265 and AAC
+//!
+//! H.265 is written as Enhanced RTMP (`hvc1`) tags.
+//!
+//! Timestamps are rebased to start at zero. Each segment continues where the previous one
+//! ended; across an `EXT-X-DISCONTINUITY` or a timestamp jump the clock is re-anchored so
+//! the FLV timeline stays continuous. An end marker closes the current FLV stream: the next
+//! segment opens a new one with a fresh header, metadata and sequence headers.
+//!
+//! ## License
+//!
+//! MIT License
+//!
+//! ## Authors
+//!
+//! - hua0512
+//!
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::io::Read;
+
+use amf0::{Amf0Encoder, Amf0Value};
+use bytes::{BufMut, Bytes, BytesMut};
+use bytes_util::nal_emulation_prevention::EmulationPreventionIo;
+use flv::{FlvData, FlvHeader, FlvTag, FlvTagType};
+use h264::AVCDecoderConfigurationRecord;
+use h265::{
+    ConstantFrameRate, HEVCDecoderConfigurationRecord, NALUnitType, NaluArray, NumTemporalLayers,
+    ParallelismType, ProfileCompatibilityFlags, SpsNALUnit,
+};
+use hls::{HlsData, M4sData};
+use mp4::samples::{SampleCodec, SampleTrack, parse_fragment_samples, parse_sample_tracks};
+use tracing::{debug, warn};
+use ts::{OwnedTsParser, PesHeader, StreamType, TsPacket};
+
+use crate::analyzer::ServiceInfo;
+
+const TS_PACKET_SIZE: usize = 188;
+
+/// PTS/DTS are 33-bit counters of a 90 kHz clock.
+const TIMESTAMP_MODULO: i64 = 1 << 33;
+
+/// A segment starting this far (in milliseconds) from where the previous segment ended is
+/// treated as a timeline break rather than jitter.
+const MAX_TIMESTAMP_DRIFT_MS: i64 = 1_000;
+
+/// Samples per AAC frame.
+const AAC_FRAME_SAMPLES: i64 = 1024;
+
+/// FLV sound format AAC, 44 kHz, 16-bit, stereo: the only header flags AAC tags use.
+const AAC_SOUND_HEADER: u8 = 0xAF;
+const AVC_CODEC_ID: u8 = 7;
+/// Enhanced RTMP `IsExHeader` bit of the video tag header.
+const EX_HEADER: u8 = 0x80;
+const HEVC_FOURCC: &[u8; 4] = b"hvc1";
+const AMF0_ON_METADATA: &str = "onMetaData";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum VideoConfig {
+    /// `AVCDecoderConfigurationRecord`
+    Avc(Bytes),
+    /// `HEVCDecoderConfigurationRecord`
+    Hevc(Bytes),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SampleKind {
+    Video { keyframe: bool, cts_ms: i32 },
+    Audio,
+}
+
+/// One demuxed access unit, timed in source milliseconds.
+#[derive(Debug)]
+struct Sample {
+    kind: SampleKind,
+    dts_ms: i64,
+    data: Bytes,
+}
+
+/// Maps source timestamps onto the continuous FLV timeline.
+#[derive(Debug, Default)]
+struct Clock {
+    /// Added to source timestamps
+    offset: Option<i64>,
+    /// Where the next segment is expected to start on the FLV timeline
+    next_start: Option<i64>,
+}
+
+impl Clock {
+    /// Anchor a segment starting at `first_ms` (source time) and lasting `duration_ms`.
+    fn anchor(&mut self, first_ms: i64, discontinuity: bool, duration_ms: i64) -> i64 {
+        let offset = match (self.offset, self.next_start) {
+            (Some(offset), Some(next))
+                if !discontinuity && (first_ms + offset - next).abs() <= MAX_TIMESTAMP_DRIFT_MS =>
+            {
+                offset
+            }
+            (_, Some(next)) => {
+                debug!(
+                    from = first_ms,
+                    to = next,
+                    discontinuity,
+                    "Re-anchoring transmuxed timeline"
+                );
+                next - first_ms
+            }
+            _ => -first_ms,
+        };
+        self.offset = Some(offset);
+        self.next_start = Some(first_ms + offset + duration_ms);
+        offset
+    }
+}
+
+/// Converts HLS segments into FLV data.
+#[derive(Debug, Default)]
+pub struct FlvTransmuxer {
+    /// Whether an FLV header was emitted for the current stream
+    header_sent: bool,
+    video_config: Option<VideoConfig>,
+    audio_config: Option<Bytes>,
+    /// Sequence headers last written to the current stream
+    sent_video_config: Option<VideoConfig>,
+    sent_audio_config: Option<Bytes>,
+    clock: Clock,
+    /// Tracks of the current fMP4 init segment
+    tracks: Vec<SampleTrack>,
+    /// Elementary stream types by PID, from the last PMT seen
+    ts_streams: HashMap<u16, StreamType>,
+    /// Last unwrapped TS timestamp, in 90 kHz ticks
+    ts_reference: Option<i64>,
+    /// DVB service of the TS stream, announced in onMetaData
+    service: Option<ServiceInfo>,
+    warned_unsupported: bool,
+}
+
+impl FlvTransmuxer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Transmux one HLS item, returning the FLV data it produces.
+    ///
+    /// Segments that cannot be demuxed are logged and skipped.
+    pub fn push(&mut self, item: HlsData) -> Vec<FlvData> {
+        let mut output = Vec::new();
+        match item {
+            HlsData::TsData(ts) => {
+                let samples = self.demux_ts(ts.data());
+                self.emit(
+                    samples,
+                    ts.segment.discontinuity,
+                    ts.segment.duration,
+                    &mut output,
+                );
+            }
+            HlsData::M4sData(M4sData::InitSegment(init)) => {
+                self.tracks = parse_sample_tracks(&init.data);
+                let codecs: Vec<_> = self.tracks.iter().map(|t| t.codec.clone()).collect();
+                for codec in codecs {
+                    match codec {
+                        Some(SampleCodec::Avc(config)) => {
+                            self.video_config = Some(VideoConfig::Avc(config))
+                        }
+                        Some(SampleCodec::Hevc(config)) => {
+                            self.video_config = Some(VideoConfig::Hevc(config))
+                        }
+                        Some(SampleCodec::Aac(config)) => self.audio_config = Some(config),
+                        None => self.warn_unsupported("fMP4 track without AVC, HEVC or AAC"),
+                    }
+                }
+            }
+            HlsData::M4sData(M4sData::Segment(segment)) => {
+                let samples = self.demux_fmp4(&segment.data);
+                self.emit(
+                    samples,
+                    segment.segment.discontinuity,
+                    segment.segment.duration,
+                    &mut output,
+                );
+            }
+            HlsData::EndMarker(reason) => {
+                if self.header_sent {
+                    if let Some(reason) = reason {
+                        output.push(FlvData::Split(reason));
+                    }
+                    self.header_sent = false;
+                    self.clock = Clock::default();
+                }
+            }
+        }
+        output
+    }
+
+    fn warn_unsupported(&mut self, what: &str) {
+        if !self.warned_unsupported {
+            self.warned_unsupported = true;
+            warn!("Transmuxing to FLV skips unsupported streams: {what}");
+        }
+    }
+
+    /// Unwrap a 33-bit TS timestamp next to the previous one.
+    fn unwrap_ts(&mut self, raw: u64) -> i64 {
+        let unwrapped = match self.ts_reference {
+            Some(reference) => {
+                let diff = (raw as i64 - reference).rem_euclid(TIMESTAMP_MODULO);
+                if diff >= TIMESTAMP_MODULO / 2 {
+                    reference + diff - TIMESTAMP_MODULO
+                } else {
+                    reference + diff
+                }
+            }
+            None => raw as i64,
+        };
+        self.ts_reference = Some(unwrapped);
+        unwrapped
+    }
+
+    fn demux_ts(&mut self, data: &Bytes) -> Vec<Sample> {
+        let mut parser = OwnedTsParser::new();
+        if let Err(error) = parser.parse_packets(data.clone()) {
+            debug!(%error, "Failed to parse TS program tables");
+        }
+        let streams: HashMap<u16, StreamType> = parser
+            .pmts()
+            .values()
+            .flat_map(|pmt| pmt.streams.iter())
+            .map(|stream| (stream.elementary_pid, stream.stream_type))
+            .collect();
+        if !streams.is_empty() {
+            self.ts_streams = streams;
+        }
+        if let Some(service) = ServiceInfo::from_parser(&parser) {
+            self.service = Some(service);
+        }
+
+        let mut pes: HashMap<u16, BytesMut> = HashMap::new();
+        let mut samples = Vec::new();
+        for offset in (0..data.len() / TS_PACKET_SIZE).map(|i| i * TS_PACKET_SIZE) {
+            let Ok(packet) = TsPacket::parse(data.slice(offset..offset + TS_PACKET_SIZE)) else {
+                continue;
+            };
+            if !self.ts_streams.contains_key(&packet.pid) {
+                continue;
+            }
+            let Some(payload) = packet.payload else {
+                continue;
+            };
+            if packet.payload_unit_start_indicator
+                && let Some(previous) = pes.insert(packet.pid, BytesMut::new())
+            {
+                self.flush_pes(packet.pid, previous.freeze(), &mut samples);
+            }
+            if let Some(buffer) = pes.get_mut(&packet.pid) {
+                buffer.extend_from_slice(&payload);
+            }
+        }
+        for (pid, buffer) in pes {
+            self.flush_pes(pid, buffer.freeze(), &mut samples);
+        }
+        samples
+    }
+
+    fn flush_pes(&mut self, pid: u16, data: Bytes, samples: &mut Vec<Sample>) {
+        let Ok(header) = PesHeader::parse(&data) else {
+            return;
+        };
+        let Some(pts) = header.pts else {
+            return;
+        };
+        let Some(payload) = data.get(header.payload_offset..).filter(|p| !p.is_empty()) else {
+            return;
+        };
+        let payload = data.slice_ref(payload);
+        match self.ts_streams.get(&pid).copied() {
+            Some(stream_type @ (StreamType::H264 | StreamType::H265)) => {
+                let dts = self.unwrap_ts(header.dts.unwrap_or(pts));
+                let cts_ms = ((pts as i64 - header.dts.unwrap_or(pts) as i64)
+                    .rem_euclid(TIMESTAMP_MODULO)
+                    / 90) as i32;
+                let access_unit = if stream_type == StreamType::H265 {
+                    self.hevc_access_unit(&payload)
+                } else {
+                    self.avc_access_unit(&payload)
+                };
+                if let Some((keyframe, data)) = access_unit {
+                    samples.push(Sample {
+                        kind: SampleKind::Video { keyframe, cts_ms },
+                        dts_ms: dts / 90,
+                        data,
+                    });
+                }
+            }
+            Some(StreamType::AdtsAac) => {
+                let pts = self.unwrap_ts(pts);
+                self.adts_frames(&payload, pts, samples);
+            }
+            Some(other) => self.warn_unsupported(&format!("TS stream type {other:?}")),
+            None => {}
+        }
+    }
+
+    /// Convert an Annex B access unit to length-prefixed NAL units, taking SPS/PPS out of
+    /// band. Returns whether it is a keyframe, or `None` when no video NAL units remain.
+    fn avc_access_unit(&mut self, payload: &Bytes) -> Option<(bool, Bytes)> {
+        let mut sps = Vec::new();
+        let mut pps = Vec::new();
+        let mut keyframe = false;
+        let mut out = BytesMut::with_capacity(payload.len() + 16);
+        for nal in annex_b_nal_units(payload) {
+            match nal[0] & 0x1F {
+                7 => sps.push(payload.slice_ref(nal)),
+                8 => pps.push(payload.slice_ref(nal)),
+                // Access unit delimiter
+                9 => {}
+                nal_type => {
+                    keyframe |= nal_type == 5;
+                    out.put_u32(nal.len() as u32);
+                    out.extend_from_slice(nal);
+                }
+            }
+        }
+
+        if let (Some(first), false) = (sps.first(), pps.is_empty())
+            && first.len() >= 4
+        {
+            let record = AVCDecoderConfigurationRecord {
+                configuration_version: 1,
+                profile_indication: first[1],
+                profile_compatibility: first[2],
+                level_indication: first[3],
+                length_size_minus_one: 3,
+                sps,
+                pps,
+                extended_config: None,
+            };
+            let mut config = Vec::new();
+            if record.build(&mut config).is_ok() {
+                self.video_config = Some(VideoConfig::Avc(Bytes::from(config)));
+            }
+        }
+
+        (!out.is_empty()).then(|| (keyframe, out.freeze()))
+    }
+
+    /// Convert an Annex B access unit to length-prefixed NAL units, taking VPS/SPS/PPS out
+    /// of band. Returns whether it is a keyframe, or `None` when no video NAL units remain.
+    fn hevc_access_unit(&mut self, payload: &Bytes) -> Option<(bool, Bytes)> {
+        let mut vps = Vec::new();
+        let mut sps = Vec::new();
+        let mut pps = Vec::new();
+        let mut keyframe = false;
+        let mut out = BytesMut::with_capacity(payload.len() + 16);
+        for nal in annex_b_nal_units(payload).filter(|nal| nal.len() >= 2) {
+            match (nal[0] >> 1) & 0x3F {
+                32 => vps.push(payload.slice_ref(nal)),
+                33 => sps.push(payload.slice_ref(nal)),
+                34 => pps.push(payload.slice_ref(nal)),
+                // Access unit delimiter
+                35 => {}
+                nal_type => {
+                    // BLA, IDR and CRA pictures
+                    keyframe |= (16..=21).contains(&nal_type);
+                    out.put_u32(nal.len() as u32);
+                    out.extend_from_slice(nal);
+                }
+            }
+        }
+
+        if !vps.is_empty()
+            && !pps.is_empty()
+            && let Some(config) = hevc_decoder_config(vps, sps, pps)
+        {
+            self.video_config = Some(VideoConfig::Hevc(config));
+        }
+
+        (!out.is_empty()).then(|| (keyframe, out.freeze()))
+    }
+
+    /// Split an ADTS payload into raw AAC frames, timing each from the PES PTS.
+    fn adts_frames(&mut self, payload: &Bytes, pts: i64, samples: &mut Vec<Sample>) {
+        let mut offset = 0;
+        let mut frames = 0;
+        while let Some(header) = payload.get(offset..offset + 7) {
+            if header[0] != 0xFF || header[1] & 0xF0 != 0xF0 {
+                break;
+            }
+            let header_len = if header[1] & 0x01 == 0 { 9 } else { 7 };
+            let profile = header[2] >> 6;
+            let frequency_index = (header[2] >> 2) & 0x0F;
+            let channels = ((header[2] & 0x01) << 2) | (header[3] >> 6);
+            let frame_len = (usize::from(header[3] & 0x03) << 11)
+                | (usize::from(header[4]) << 3)
+                | usize::from(header[5] >> 5);
+            let Some(sample_rate) = aac::SampleFrequencyIndex::from_u8(frequency_index)
+                .and_then(|index| index.to_freq())
+            else {
+                break;
+            };
+            if frame_len <= header_len || offset + frame_len > payload.len() {
+                break;
+            }
+
+            let config = (u16::from(profile + 1) << 11)
+                | (u16::from(frequency_index) << 7)
+                | (u16::from(channels) << 3);
+            self.audio_config = Some(Bytes::copy_from_slice(&config.to_be_bytes()));
+
+            let pts = pts + frames * AAC_FRAME_SAMPLES * 90_000 / i64::from(sample_rate);
+            samples.push(Sample {
+                kind: SampleKind::Audio,
+                dts_ms: pts / 90,
+                data: payload.slice(offset + header_len..offset + frame_len),
+            });
+            offset += frame_len;
+            frames += 1;
+        }
+    }
+
+    fn demux_fmp4(&mut self, data: &Bytes) -> Vec<Sample> {
+        let samples = match parse_fragment_samples(data, &self.tracks) {
+            Ok(samples) => samples,
+            Err(error) => {
+                warn!(%error, "Failed to read fMP4 samples, skipping segment");
+                return Vec::new();
+            }
+        };
+        samples
+            .into_iter()
+            .filter_map(|sample| {
+                let track = self
+                    .tracks
+                    .iter()
+                    .find(|track| track.track_id == sample.track_id)?;
+                let timescale = i64::from(track.timescale);
+                let kind = match track.codec.as_ref()? {
+                    SampleCodec::Avc(_) | SampleCodec::Hevc(_) => SampleKind::Video {
+                        keyframe: sample.is_sync,
+                        cts_ms: (sample.composition_offset * 1000 / timescale) as i32,
+                    },
+                    SampleCodec::Aac(_) => SampleKind::Audio,
+                };
+                Some(Sample {
+                    kind,
+                    dts_ms: (sample.decode_time as i64) * 1000 / timescale,
+                    data: sample.data,
+                })
+            })
+            .collect()
+    }
+
+    fn emit(
+        &mut self,
+        mut samples: Vec<Sample>,
+        discontinuity: bool,
+        duration: f32,
+        output: &mut Vec<FlvData>,
+    ) {
+        let Some(first_ms) = samples.iter().map(|sample| sample.dts_ms).min() else {
+            return;
+        };
+        let offset = self
+            .clock
+            .anchor(first_ms, discontinuity, (duration * 1000.0) as i64);
+        let start = (first_ms + offset).max(0) as u32;
+
+        if !self.header_sent {
+            self.header_sent = true;
+            self.sent_video_config = None;
+            self.sent_audio_config = None;
+            let has_video = self.video_config.is_some();
+            let has_audio = self.audio_config.is_some();
+            output.push(FlvData::Header(FlvHeader::new(has_audio, has_video)));
+            output.push(FlvData::Tag(FlvTag::new(
+                0,
+                0,
+                FlvTagType::ScriptData,
+                false,
+                self.metadata(),
+            )));
+        }
+        if self.video_config != self.sent_video_config
+            && let Some(config) = &self.video_config
+        {
+            output.push(FlvData::Tag(video_sequence_header(start, config)));
+            self.sent_video_config = self.video_config.clone();
+        }
+        if self.audio_config != self.sent_audio_config
+            && let Some(config) = &self.audio_config
+        {
+            output.push(FlvData::Tag(audio_tag(start, 0, config)));
+            self.sent_audio_config = self.audio_config.clone();
+        }
+
+        // Video first at equal decode times
+        samples.sort_by_key(|sample| (sample.dts_ms, sample.kind == SampleKind::Audio));
+        for sample in samples {
+            let timestamp = (sample.dts_ms + offset).max(0) as u32;
+            match sample.kind {
+                SampleKind::Video { keyframe, cts_ms } => {
+                    if let Some(config) = &self.sent_video_config {
+                        output.push(FlvData::Tag(video_tag(
+                            timestamp,
+                            config,
+                            keyframe,
+                            cts_ms,
+                            &sample.data,
+                        )));
+                    }
+                }
+                SampleKind::Audio => {
+                    if self.sent_audio_config.is_some() {
+                        output.push(FlvData::Tag(audio_tag(timestamp, 1, &sample.data)));
+                    }
+                }
+            }
+        }
+    }
+
+    fn metadata(&self) -> Bytes {
+        let mut properties = vec![
+            (Cow::Borrowed("duration"), Amf0Value::Number(0.0)),
+            (
+                Cow::Borrowed("hasVideo"),
+                Amf0Value::Boolean(self.video_config.is_some()),
+            ),
+            (
+                Cow::Borrowed("hasAudio"),
+                Amf0Value::Boolean(self.audio_config.is_some()),
+            ),
+        ];
+        if let Some(VideoConfig::Avc(_)) = self.video_config {
+            properties.push((
+                Cow::Borrowed("videocodecid"),
+                Amf0Value::Number(f64::from(AVC_CODEC_ID)),
+            ));
+        }
+        if self.audio_config.is_some() {
+            properties.push((Cow::Borrowed("audiocodecid"), Amf0Value::Number(10.0)));
+        }
+        if let Some(service) = &self.service {
+            let fields = [
+                ("servicename", service.service_name.as_deref()),
+                ("serviceprovider", service.provider_name.as_deref()),
+                (
+                    "title",
+                    service
+                        .present_event
+                        .as_ref()
+                        .map(|event| event.name.as_str()),
+                ),
+            ];
+            for (name, value) in fields {
+                if let Some(value) = value.filter(|value| !value.is_empty()) {
+                    properties.push((
+                        Cow::Borrowed(name),
+                        Amf0Value::String(Cow::Owned(value.to_string())),
+                    ));
+                }
+            }
+        }
+
+        let mut buffer = Vec::new();
+        Amf0Encoder::encode_string(&mut buffer, AMF0_ON_METADATA).unwrap();
+        Amf0Encoder::encode(&mut buffer, &Amf0Value::Object(Cow::Owned(properties))).unwrap();
+        Bytes::from(buffer)
+    }
+}
+
+/// Iterate the NAL units of an Annex B byte stream.
+pub(crate) fn annex_b_nal_units(data: &[u8]) -> impl Iterator<Item = &[u8]> {
+    let mut starts = Vec::new();
+    let mut i = 0;
+    while i + 3 <= data.len() {
+        if data[i] == 0 && data[i + 1] == 0 && data[i + 2] == 1 {
+            starts.push(i + 3);
+            i += 3;
+        } else {
+            i += 1;
+        }
+    }
+    let ends: Vec<usize> = starts
+        .iter()
+        .skip(1)
+        .map(|start| start - 3)
+        .chain(std::iter::once(data.len()))
+        .collect();
+    starts.into_iter().zip(ends).filter_map(|(start, end)| {
+        // Drop the leading zero of 4-byte start codes and trailing_zero_8bits
+        let mut end = end;
+        while end > start && data[end - 1] == 0 {
+            end -= 1;
+        }
+        (end > start).then(|| &data[start..end])
+    })
+}
+
+/// Build an `HEVCDecoderConfigurationRecord` from in-band parameter sets, taking the
+/// profile, tier, level and sample format from the first SPS.
+fn hevc_decoder_config(vps: Vec<Bytes>, sps: Vec<Bytes>, pps: Vec<Bytes>) -> Option<Bytes> {
+    let first = sps.first()?;
+    let parsed = SpsNALUnit::parse(&first[..]).ok()?.rbsp;
+    // The general profile_tier_level is byte aligned: it follows the two-byte NAL unit
+    // header and one byte of SPS id, sub-layer count and temporal id nesting flag.
+    let mut head = [0u8; 13];
+    EmulationPreventionIo::new(first.get(2..)?)
+        .read_exact(&mut head)
+        .ok()?;
+    let ptl = &head[1..];
+
+    let arrays = [
+        (NALUnitType::VpsNut, vps),
+        (NALUnitType::SpsNut, sps),
+        (NALUnitType::PpsNut, pps),
+    ]
+    .into_iter()
+    .map(|(nal_unit_type, nalus)| NaluArray {
+        array_completeness: true,
+        nal_unit_type,
+        nalus,
+    })
+    .collect();
+    let record = HEVCDecoderConfigurationRecord {
+        general_profile_space: ptl[0] >> 6,
+        general_tier_flag: ptl[0] & 0x20 != 0,
+        general_profile_idc: ptl[0] & 0x1F,
+        general_profile_compatibility_flags: ProfileCompatibilityFlags::from_bits_retain(
+            u32::from_be_bytes([ptl[1], ptl[2], ptl[3], ptl[4]]),
+        ),
+        general_constraint_indicator_flags: ptl[5..11]
+            .iter()
+            .fold(0, |flags, byte| (flags << 8) | u64::from(*byte)),
+        general_level_idc: ptl[11],
+        min_spatial_segmentation_idc: 0,
+        parallelism_type: ParallelismType::MixedOrUnknown,
+        chroma_format_idc: parsed.chroma_format_idc,
+        bit_depth_luma_minus8: parsed.bit_depth_luma_minus8,
+        bit_depth_chroma_minus8: parsed.bit_depth_chroma_minus8,
+        avg_frame_rate: 0,
+        constant_frame_rate: ConstantFrameRate::Unknown,
+        num_temporal_layers: NumTemporalLayers::from(parsed.sps_max_sub_layers_minus1 + 1),
+        temporal_id_nested: parsed.sps_temporal_id_nesting_flag,
+        length_size_minus_one: 3,
+        arrays,
+    };
+    let mut config = Vec::new();
+    record.mux(&mut config).ok()?;
+    Some(Bytes::from(config))
+}
+
+fn video_sequence_header(timestamp: u32, config: &VideoConfig) -> FlvTag {
+    let mut data = BytesMut::new();
+    match config {
+        VideoConfig::Avc(record) => {
+            data.extend_from_slice(&[0x10 | AVC_CODEC_ID, 0, 0, 0, 0]);
+            data.extend_from_slice(record);
+        }
+        VideoConfig::Hevc(record) => {
+            // SequenceStart
+            data.put_u8(EX_HEADER | 0x10);
+            data.extend_from_slice(HEVC_FOURCC);
+            data.extend_from_slice(record);
+        }
+    }
+    FlvTag::new(timestamp, 0, FlvTagType::Video, false, data.freeze())
+}
+
+fn video_tag(
+    timestamp: u32,
+    config: &VideoConfig,
+    keyframe: bool,
+    cts_ms: i32,
+    payload: &[u8],
+) -> FlvTag {
+    let frame_type: u8 = if keyframe { 1 } else { 2 };
+    let cts = cts_ms.to_be_bytes();
+    let mut data = BytesMut::with_capacity(payload.len() + 8);
+    match config {
+        VideoConfig::Avc(_) => {
+            data.extend_from_slice(&[(frame_type << 4) | AVC_CODEC_ID, 1]);
+        }
+        VideoConfig::Hevc(_) => {
+            // CodedFrames, carrying a composition time offset
+            data.put_u8(EX_HEADER | (frame_type << 4) | 1);
+            data.extend_from_slice(HEVC_FOURCC);
+        }
+    }
+    data.extend_from_slice(&cts[1..]);
+    data.extend_from_slice(payload);
+    FlvTag::new(timestamp, 0, FlvTagType::Video, false, data.freeze())
+}
+
+fn audio_tag(timestamp: u32, packet_type: u8, payload: &[u8]) -> FlvTag {
+    let mut data = BytesMut::with_capacity(payload.len() + 2);
+    data.extend_from_slice(&[AAC_SOUND_HEADER, packet_type]);
+    data.extend_from_slice(payload);
+    FlvTag::new(timestamp, 0, FlvTagType::Audio, false, data.freeze())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use m3u8_rs::MediaSegment;
+    use pipeline_common::SplitReason;
+
+    const PMT_PID: u16 = 0x1000;
+    const VIDEO_PID: u16 = 0x100;
+    const AUDIO_PID: u16 = 0x101;
+
+    const SPS: &[u8] = &[0x67, 0x64, 0x00, 0x1F, 0xAC];
+    const PPS: &[u8] = &[0x68, 0xEE, 0x3C, 0x80];
+    const HEVC_VPS: &[u8] = &[0x40, 0x01, 0x0C, 0x01];
+    /// Main profile, level 5.1, with emulation prevention bytes inside profile_tier_level.
+    const HEVC_SPS: &[u8] = b"B\x01\x01\x01@\0\0\x03\0\x90\0\0\x03\0\0\x03\0\x99\xa0\x01@ \x05\xa1e\x95R\x90\x84d_\xf8\xc0Z\x80\x80\x80\x82\0\0\x03\0\x02\0\0\x03\x01 \xc0\x0b\xbc\xa2\0\x02bX\0\x011-\x08";
+    const HEVC_PPS: &[u8] = &[0x44, 0x01, 0xC1, 0x72];
+
+    /// Packetize a PSI section or PES packet, stuffing the last packet.
+    fn packetize(pid: u16, payload: &[u8], psi: bool) -> Vec<u8> {
+        let mut data = if psi { vec![0u8] } else { Vec::new() };
+        data.extend_from_slice(payload);
+        let mut out = Vec::new();
+        for (index, chunk) in data.chunks(184).enumerate() {
+            let mut packet = vec![
+                0x47,
+                (pid >> 8) as u8,
+                pid as u8,
+                0x10 | (index as u8 & 0x0F),
+            ];
+            if index == 0 {
+                packet[1] |= 0x40;
+            }
+            if chunk.len() < 184 {
+                packet[3] |= 0x20;
+                let stuffing = 183 - chunk.len();
+                packet.push(stuffing as u8);
+                if stuffing > 0 {
+                    packet.push(0x00);
+                    packet.resize(packet.len() + stuffing - 1, 0xFF);
+                }
+            }
+            packet.extend_from_slice(chunk);
+            out.extend_from_slice(&packet);
+        }
+        out
+    }
+
+    fn section(table_id: u8, id: u16, body: &[u8]) -> Vec<u8> {
+        let length = (5 + body.len() + 4) as u16;
+        let mut section = vec![
+            table_id,
+            0xB0 | (length >> 8) as u8,
+            length as u8,
+            (id >> 8) as u8,
+            id as u8,
+            0xC1,
+            0,
+            0,
+        ];
+        section.extend_from_slice(body);
+        let crc = ts::mpeg2_crc32(&section);
+        section.extend_from_slice(&crc.to_be_bytes());
+        section
+    }
+
+    fn timestamp_field(marker: u8, timestamp: u64) -> [u8; 5] {
+        [
+            (marker << 4) | (((timestamp >> 30) as u8 & 0x07) << 1) | 1,
+            (timestamp >> 22) as u8,
+            (((timestamp >> 15) as u8) << 1) | 1,
+            (timestamp >> 7) as u8,
+            ((timestamp as u8) << 1) | 1,
+        ]
+    }
+
+    fn pes(stream_id: u8, pts: u64, dts: Option<u64>, payload: &[u8]) -> Vec<u8> {
+        let mut header = vec![0, 0, 1, stream_id, 0, 0, 0x80];
+        match dts {
+            Some(dts) => {
+                header.extend_from_slice(&[0xC0, 10]);
+                header.extend_from_slice(&timestamp_field(3, pts));
+                header.extend_from_slice(&timestamp_field(1, dts));
+            }
+            None => {
+                header.extend_from_slice(&[0x80, 5]);
+                header.extend_from_slice(&timestamp_field(2, pts));
+            }
+        }
+        header.extend_from_slice(payload);
+        header
+    }
+
+    fn adts_frame(payload: &[u8]) -> Vec<u8> {
+        let frame_len = 7 + payload.len();
+        // AAC LC, 48 kHz, stereo, no CRC
+        let mut frame = vec![
+            0xFF,
+            0xF1,
+            (1 << 6) | (3 << 2),
+            (2 << 6) | (frame_len >> 11) as u8,
+            (frame_len >> 3) as u8,
+            ((frame_len as u8) << 5) | 0x1F,
+            0xFC,
+        ];
+        frame.extend_from_slice(payload);
+        frame
+    }
+
+    fn ts_segment(pts: u64, discontinuity: bool) -> HlsData {
+        let mut access_unit = vec![0, 0, 0, 1, 0x09, 0xF0, 0, 0, 0, 1];
+        access_unit.extend_from_slice(SPS);
+        access_unit.extend_from_slice(&[0, 0, 0, 1]);
+        access_unit.extend_from_slice(PPS);
+        access_unit.extend_from_slice(&[0, 0, 1, 0x65, 0x88, 0x84]);
+        video_ts_segment(0x1B, &access_unit, pts, discontinuity)
+    }
+
+    fn video_ts_segment(
+        stream_type: u8,
+        access_unit: &[u8],
+        pts: u64,
+        discontinuity: bool,
+    ) -> HlsData {
+        let mut data = packetize(
+            0,
+            &section(0x00, 1, &[0, 1, 0xE0 | (PMT_PID >> 8) as u8, PMT_PID as u8]),
+            true,
+        );
+        let pmt_body = [
+            0xE0 | (VIDEO_PID >> 8) as u8,
+            VIDEO_PID as u8,
+            0xF0,
+            0,
+            stream_type,
+            0xE0 | (VIDEO_PID >> 8) as u8,
+            VIDEO_PID as u8,
+            0xF0,
+            0,
+            0x0F,
+            0xE0 | (AUDIO_PID >> 8) as u8,
+            AUDIO_PID as u8,
+            0xF0,
+            0,
+        ];
+        data.extend(packetize(PMT_PID, &section(0x02, 1, &pmt_body), true));
+
+        data.extend(packetize(
+            VIDEO_PID,
+            &pes(0xE0, pts + 3_000, Some(pts), access_unit),
+            false,
+        ));
+        let mut audio = adts_frame(&[0x21; 10]);
+        audio.extend(adts_frame(&[0x22; 10]));
+        data.extend(packetize(AUDIO_PID, &pes(0xC0, pts, None, &audio), false));
+
+        HlsData::ts(
+            MediaSegment {
+                duration: 2.0,
+                discontinuity,
+                ..MediaSegment::empty()
+            },
+            Bytes::from(data),
+        )
+    }
+
+    fn tags(output: &[FlvData]) -> Vec<(FlvTagType, u32, Bytes)> {
+        output
+            .iter()
+            .filter_map(|item| match item {
+                FlvData::Tag(tag) => Some((tag.tag_type(), tag.timestamp_ms, tag.data().clone())),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn transmuxes_ts_segments() {
+        let mut transmuxer = FlvTransmuxer::new();
+        let output = transmuxer.push(ts_segment(900_000, false));
+
+        assert!(
+            matches!(output[0], FlvData::Header(ref header) if header.has_audio && header.has_video)
+        );
+        let tags = tags(&output);
+        assert_eq!(tags[0].0, FlvTagType::ScriptData);
+
+        // AVC sequence header built from the in-band SPS/PPS
+        let (tag_type, timestamp, data) = &tags[1];
+        assert_eq!((*tag_type, *timestamp), (FlvTagType::Video, 0));
+        assert_eq!(&data[..5], &[0x17, 0, 0, 0, 0]);
+        assert_eq!(&data[5..9], &[1, 0x64, 0x00, 0x1F]);
+
+        // AudioSpecificConfig for AAC LC, 48 kHz, stereo
+        assert_eq!(tags[2].2, Bytes::from_static(&[0xAF, 0, 0x11, 0x90]));
+
+        // Samples ordered by decode time: the keyframe, then the two AAC frames
+        let (_, timestamp, data) = &tags[3];
+        assert_eq!(*timestamp, 0);
+        assert_eq!(&data[..5], &[0x17, 1, 0, 0, 33]);
+        assert_eq!(&data[5..], &[0, 0, 0, 3, 0x65, 0x88, 0x84]);
+        assert_eq!(tags[4].1, 0);
+        assert_eq!(tags[4].2[..2], [0xAF, 1]);
+        assert_eq!(tags[4].2.len(), 12);
+        assert_eq!(tags[5].1, 21);
+    }
+
+    #[test]
+    fn transmuxes_hevc_ts_segments_to_enhanced_rtmp_tags() {
+        let mut access_unit = vec![0, 0, 0, 1, 0x46, 0x01, 0x50];
+        for nal in [HEVC_VPS, HEVC_SPS, HEVC_PPS] {
+            access_unit.extend_from_slice(&[0, 0, 0, 1]);
+            access_unit.extend_from_slice(nal);
+        }
+        // IDR_W_RADL slice
+        access_unit.extend_from_slice(&[0, 0, 1, 0x26, 0x01, 0xAF]);
+
+        let mut transmuxer = FlvTransmuxer::new();
+        let output = transmuxer.push(video_ts_segment(0x24, &access_unit, 900_000, false));
+
+        assert!(
+            matches!(output[0], FlvData::Header(ref header) if header.has_audio && header.has_video)
+        );
+        let tags = tags(&output);
+
+        // SequenceStart carrying an hvcC built from the in-band VPS/SPS/PPS
+        let (tag_type, timestamp, data) = &tags[1];
+        assert_eq!((*tag_type, *timestamp), (FlvTagType::Video, 0));
+        assert_eq!(&data[..5], b"\x90hvc1");
+        let record = HEVCDecoderConfigurationRecord::demux(&data[5..]).unwrap();
+        assert_eq!(record.general_profile_idc, 1);
+        assert_eq!(
+            record.general_profile_compatibility_flags.bits(),
+            0x4000_0000
+        );
+        assert_eq!(record.general_constraint_indicator_flags, 0x9000_0000_0000);
+        assert_eq!(record.general_level_idc, 0x99);
+        assert_eq!(record.chroma_format_idc, 1);
+        assert_eq!(record.length_size_minus_one, 3);
+        let arrays: Vec<_> = record
+            .arrays
+            .iter()
+            .map(|array| (array.nal_unit_type, array.nalus[0].as_ref()))
+            .collect();
+        assert_eq!(
+            arrays,
+            [
+                (NALUnitType::VpsNut, HEVC_VPS),
+                (NALUnitType::SpsNut, HEVC_SPS),
+                (NALUnitType::PpsNut, HEVC_PPS),
+            ]
+        );
+
+        // CodedFrames keyframe with a composition time offset
+        let (_, timestamp, data) = &tags[3];
+        assert_eq!(*timestamp, 0);
+        assert_eq!(&data[..8], b"\x91hvc1\0\0\x21");
+        assert_eq!(&data[8..], &[0, 0, 0, 3, 0x26, 0x01, 0xAF]);
+    }
+
+    #[test]
+    fn keeps_the_timeline_continuous_and_restarts_after_end_markers() {
+        let mut transmuxer = FlvTransmuxer::new();
+        transmuxer.push(ts_segment(900_000, false));
+
+        // A following segment keeps its timestamps; a discontinuity is re-anchored.
+        let output = transmuxer.push(ts_segment(900_000 + 180_000, false));
+        assert!(!output.iter().any(|item| matches!(item, FlvData::Header(_))));
+        assert_eq!(tags(&output)[0].1, 2_000);
+        let output = transmuxer.push(ts_segment(45_000, true));
+        assert_eq!(tags(&output)[0].1, 4_000);
+
+        let output = transmuxer.push(HlsData::end_marker_with_reason(SplitReason::Discontinuity));
+        assert!(matches!(
+            output[..],
+            [FlvData::Split(SplitReason::Discontinuity)]
+        ));
+
+        let output = transmuxer.push(ts_segment(90_000, false));
+        assert!(matches!(output[0], FlvData::Header(_)));
+        let tags = tags(&output);
+        assert_eq!(tags.len(), 6);
+        assert!(tags.iter().all(|(_, timestamp, _)| *timestamp <= 21));
+    }
+}
@@ -0,0 +1,224 @@
+//! Continuous MPEG-TS timeline for the writer.
+//!
+//! Concatenated TS segments only play back seamlessly when every packet
+//! stream continues where the previous segment left off. Across an
+//! `EXT-X-DISCONTINUITY` (an encoder restart, a failover to another source or
+//! an ad break) the PCR/PTS/DTS clock jumps and each PID's continuity counter
+//! restarts, which players treat as corruption. [`TsTimeline`] renumbers the
+//! continuity counters of every PID and shifts all PCR, PTS and DTS values by
+//! the same amount so a file plays as a single continuous stream.
+
+use std::collections::HashMap;
+
+use bytes::{Bytes, BytesMut};
+use tracing::info;
+use ts::{
+    ContinuityFixer, TIMESTAMP_HZ, TS_PACKET_SIZE, packet_pes_header, restamp_packet,
+    shift_timestamp, timestamp_diff,
+};
+
+/// A segment starting this far (in seconds) from where the previous segment
+/// ended is treated as a timeline break rather than jitter.
+const MAX_TIMESTAMP_DRIFT_SECS: f64 = 1.0;
+
+#[derive(Debug, Default)]
+pub(crate) struct TsTimeline {
+    /// 90 kHz ticks added to every PCR/PTS/DTS; `None` until the first
+    /// timestamped segment of the current file. That segment keeps its
+    /// timestamps, later ones are shifted to follow it.
+    shift: Option<i64>,
+    /// Shifted timestamp where the next segment is expected to start.
+    next_start: Option<u64>,
+    /// Renumbers continuity counters across segments.
+    continuity: ContinuityFixer,
+}
+
+impl TsTimeline {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start a new output file.
+    pub(crate) fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Rewrite a segment onto the continuous timeline. `duration` is the
+    /// segment's `EXTINF` in seconds, used to predict where the next segment
+    /// starts.
+    pub(crate) fn process(&mut self, data: &Bytes, discontinuity: bool, duration: f32) -> Bytes {
+        if let Some(start) = segment_start(data) {
+            let shift = match (self.shift, self.next_start) {
+                (Some(shift), Some(expected)) => {
+                    let drift = timestamp_diff(expected, shift_timestamp(start, shift));
+                    let drift_secs = drift as f64 / TIMESTAMP_HZ as f64;
+                    if discontinuity || drift_secs.abs() > MAX_TIMESTAMP_DRIFT_SECS {
+                        info!(drift_secs, discontinuity, "Closing TS timestamp jump");
+                        shift + drift
+                    } else {
+                        shift
+                    }
+                }
+                (shift, _) => shift.unwrap_or(0),
+            };
+            self.shift = Some(shift);
+            self.next_start = Some(shift_timestamp(
+                start,
+                shift + (f64::from(duration) * TIMESTAMP_HZ as f64).round() as i64,
+            ));
+        }
+
+        // Segments are fetched independently, so a counter equal to the
+        // previous segment's last one is not a duplicate packet.
+        self.continuity.start_source();
+        let shift = self.shift.unwrap_or(0);
+        let mut output = BytesMut::from(&data[..]);
+        for packet in output.chunks_exact_mut(TS_PACKET_SIZE) {
+            if packet[0] != 0x47 {
+                continue;
+            }
+            self.continuity.fix_packet(packet);
+            if shift != 0 {
+                restamp_packet(packet, shift);
+            }
+        }
+        output.freeze()
+    }
+}
+
+/// Earliest PES decode timestamp of a segment, taken from the first
+/// timestamped PES of each PID.
+fn segment_start(data: &[u8]) -> Option<u64> {
+    let mut firsts: HashMap<u16, u64> = HashMap::new();
+    for packet in data.chunks_exact(TS_PACKET_SIZE) {
+        let pid = (u16::from(packet[1] & 0x1F) << 8) | u16::from(packet[2]);
+        if packet[0] != 0x47 || firsts.contains_key(&pid) {
+            continue;
+        }
+        if let Some(header) = packet_pes_header(packet)
+            && let Some(timestamp) = header.dts.or(header.pts)
+        {
+            firsts.insert(pid, timestamp);
+        }
+    }
+    let reference = *firsts.values().next()?;
+    firsts
+        .into_values()
+        .min_by_key(|timestamp| timestamp_diff(*timestamp, reference))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VIDEO_PID: u16 = 0x100;
+
+    /// A video packet opening a PES with a PTS, carrying the PCR.
+    fn pes_packet(continuity: u8, pts: u64) -> Vec<u8> {
+        let mut packet = vec![0xFFu8; TS_PACKET_SIZE];
+        packet[0] = 0x47;
+        packet[1] = 0x40 | (VIDEO_PID >> 8) as u8;
+        packet[2] = VIDEO_PID as u8;
+        packet[3] = 0x30 | continuity;
+        // Adaptation field: flags + PCR
+        packet[4] = 7;
+        packet[5] = 0x10;
+        packet[6..12].copy_from_slice(&[
+            (pts >> 25) as u8,
+            (pts >> 17) as u8,
+            (pts >> 9) as u8,
+            (pts >> 1) as u8,
+            ((pts as u8 & 0x01) << 7) | 0x7E,
+            0x00,
+        ]);
+        // PES header with PTS only
+        packet[12..21].copy_from_slice(&[0x00, 0x00, 0x01, 0xE0, 0x00, 0x00, 0x80, 0x80, 0x05]);
+        packet[21..26].copy_from_slice(&ts::encode_timestamp(0x2, pts));
+        packet
+    }
+
+    fn segment(first_continuity: u8, pts: u64) -> Bytes {
+        let mut data = pes_packet(first_continuity, pts);
+        // An adaptation-field-only stuffing packet and a continuation packet
+        let mut stuffing = vec![0xFFu8; TS_PACKET_SIZE];
+        stuffing[..6].copy_from_slice(&[
+            0x47,
+            (VIDEO_PID >> 8) as u8,
+            VIDEO_PID as u8,
+            0x20,
+            183,
+            0x00,
+        ]);
+        stuffing[3] |= first_continuity;
+        data.extend_from_slice(&stuffing);
+        let mut continuation = vec![0xAAu8; TS_PACKET_SIZE];
+        continuation[..4].copy_from_slice(&[
+            0x47,
+            (VIDEO_PID >> 8) as u8,
+            VIDEO_PID as u8,
+            0x10 | ((first_continuity + 1) & 0x0F),
+        ]);
+        data.extend_from_slice(&continuation);
+        Bytes::from(data)
+    }
+
+    fn continuity(data: &Bytes) -> Vec<u8> {
+        data.chunks_exact(TS_PACKET_SIZE)
+            .map(|packet| packet[3] & 0x0F)
+            .collect()
+    }
+
+    fn pts_and_pcr(data: &Bytes) -> (u64, u64) {
+        let header = packet_pes_header(&data[..TS_PACKET_SIZE]).unwrap();
+        let pcr = ts::Pcr::parse(&data[6..12]).unwrap();
+        (header.pts.unwrap(), pcr.base)
+    }
+
+    #[test]
+    fn keeps_the_first_segment_and_follows_on() {
+        let mut timeline = TsTimeline::new();
+        let first = timeline.process(&segment(3, 900_000), false, 2.0);
+        assert_eq!(first, segment(3, 900_000));
+
+        let second = timeline.process(&segment(5, 1_080_000), false, 2.0);
+        assert_eq!(continuity(&second), vec![5, 5, 6]);
+        assert_eq!(pts_and_pcr(&second), (1_080_000, 1_080_000));
+    }
+
+    #[test]
+    fn closes_discontinuities_and_renumbers_counters() {
+        let mut timeline = TsTimeline::new();
+        timeline.process(&segment(3, 900_000), false, 2.0);
+
+        // The encoder restarted: new clock, counters back to zero.
+        let restarted = timeline.process(&segment(0, 10_000), true, 2.0);
+        assert_eq!(continuity(&restarted), vec![5, 5, 6]);
+        assert_eq!(pts_and_pcr(&restarted), (1_080_000, 1_080_000));
+
+        let next = timeline.process(&segment(2, 190_000), false, 2.0);
+        assert_eq!(pts_and_pcr(&next), (1_260_000, 1_260_000));
+    }
+
+    #[test]
+    fn restamps_across_the_33_bit_wrap() {
+        let mut timeline = TsTimeline::new();
+        let near_wrap = ts::TIMESTAMP_MODULO - 90_000;
+        timeline.process(&segment(0, near_wrap), false, 2.0);
+
+        let restarted = timeline.process(&segment(0, 5_000_000), true, 2.0);
+        assert_eq!(pts_and_pcr(&restarted), (90_000, 90_000));
+    }
+
+    #[test]
+    fn reset_keeps_the_next_file_timestamps() {
+        let mut timeline = TsTimeline::new();
+        timeline.process(&segment(3, 900_000), false, 2.0);
+        timeline.process(&segment(0, 10_000), true, 2.0);
+
+        timeline.reset();
+        assert_eq!(
+            timeline.process(&segment(0, 10_000), false, 2.0),
+            segment(0, 10_000)
+        );
+    }
+}
@@ -1,26 +1,48 @@
 use std::{
     fs::OpenOptions,
     io::{BufWriter, Write},
-    path::PathBuf,
+    path::{Path, PathBuf},
+    time::Duration,
 };
 
+use bytes::Bytes;
 use hls::{HlsData, M4sData};
 use pipeline_common::{
-    FormatStrategy, PostWriteAction, ProgressConfig, ProtocolWriter, SplitReason, WriterConfig,
-    WriterError, WriterProgress, WriterState, WriterStats, WriterTask, expand_filename_template,
+    FormatStrategy, PostWriteAction, ProgressConfig, ProtocolWriter, SplitReason,
+    WallClockBoundary, WriterConfig, WriterError, WriterProgress, WriterState, WriterStats,
+    WriterTask, expand_filename_template,
 };
 
-use tracing::{Span, debug, info};
+use tracing::{Span, debug, info, warn};
 use tracing_indicatif::span_ext::IndicatifSpanExt;
 
 use crate::analyzer::HlsAnalyzer;
+use crate::archive::HlsArchiveStrategy;
+use crate::captions::{CaptionCue, CaptionExtractor, CaptionFormat};
+use crate::fmp4::Fmp4Timeline;
+use crate::report::AnalysisReport;
+use crate::ts_timeline::TsTimeline;
+
+/// Callback type for file analysis reports (path, sequence_number, report).
+pub type SegmentReportCallback = Box<dyn Fn(&Path, u32, &AnalysisReport) + Send + Sync>;
 
 pub struct HlsFormatStrategy {
     analyzer: HlsAnalyzer,
+    timeline: Fmp4Timeline,
+    /// Continuity and timestamp repair of TS segments, when enabled
+    ts_timeline: Option<TsTimeline>,
+    /// Init segment already written to the current file
+    written_init: Option<Bytes>,
     current_offset: u64,
     target_duration: f32,
     max_file_size: Option<u64>,
+    wall_clock: Option<WallClockBoundary>,
     last_split_reason: Option<SplitReason>,
+    on_report: Option<SegmentReportCallback>,
+    /// Closed caption extraction into sidecars, when enabled
+    captions: Option<(CaptionFormat, CaptionExtractor)>,
+    /// Caption cues of the current file
+    caption_cues: Vec<CaptionCue>,
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -32,24 +54,88 @@ pub enum HlsStrategyError {
 }
 
 impl HlsFormatStrategy {
-    pub fn new(max_file_size: Option<u64>) -> Self {
+    pub fn new(
+        max_file_size: Option<u64>,
+        continuous_ts: bool,
+        captions: Option<CaptionFormat>,
+    ) -> Self {
         Self {
             analyzer: HlsAnalyzer::new(),
+            timeline: Fmp4Timeline::new(),
+            ts_timeline: continuous_ts.then(TsTimeline::new),
+            written_init: None,
             current_offset: 0,
             target_duration: 0.0,
             max_file_size,
+            wall_clock: None,
             last_split_reason: None,
+            on_report: None,
+            captions: captions.map(|format| (format, CaptionExtractor::new())),
+            caption_cues: Vec::new(),
         }
     }
 
+    /// Also rotate whenever the local time crosses a multiple of `interval` since midnight.
+    pub fn with_wall_clock_split(mut self, interval: Option<Duration>) -> Self {
+        self.wall_clock = interval.map(WallClockBoundary::new);
+        self
+    }
+
+    /// Set a callback to receive an analysis report whenever a file is closed.
+    pub fn set_on_report_callback<F>(&mut self, callback: F)
+    where
+        F: Fn(&Path, u32, &AnalysisReport) + Send + Sync + 'static,
+    {
+        self.on_report = Some(Box::new(callback));
+    }
+
     fn reset_for_new_file(&mut self) -> Result<(), HlsStrategyError> {
         self.analyzer.reset();
+        self.timeline.reset();
+        if let Some(ts_timeline) = &mut self.ts_timeline {
+            ts_timeline.reset();
+        }
+        if let Some((_, extractor)) = &mut self.captions {
+            extractor.reset();
+        }
+        self.caption_cues.clear();
+        self.written_init = None;
         self.current_offset = 0;
         self.target_duration = 0.0;
         self.last_split_reason = None;
+        if let Some(wall_clock) = &mut self.wall_clock {
+            wall_clock.restart();
+        }
         Ok(())
     }
 
+    /// Write the caption cues of the closed file next to it, one sidecar per channel.
+    fn write_caption_sidecars(&mut self, path: &Path) {
+        let Some((format, extractor)) = &mut self.captions else {
+            return;
+        };
+        self.caption_cues.extend(extractor.finish());
+        let mut channels: Vec<_> = self.caption_cues.iter().map(|cue| cue.channel).collect();
+        channels.sort();
+        channels.dedup();
+        for channel in channels {
+            let mut cues: Vec<_> = self
+                .caption_cues
+                .iter()
+                .filter(|cue| cue.channel == channel)
+                .cloned()
+                .collect();
+            cues.sort_by_key(|cue| cue.start_ms);
+            let sidecar = format.sidecar_path(path, channel);
+            match std::fs::write(&sidecar, format.render(&cues)) {
+                Ok(()) => info!(path = %sidecar.display(), cues = cues.len(), "Wrote captions"),
+                Err(error) => {
+                    warn!(path = %sidecar.display(), %error, "Failed to write captions")
+                }
+            }
+        }
+    }
+
     fn update_status(&self, state: &WriterState) {
         // Update the current span with progress information
         let span = Span::current();
@@ -82,13 +168,29 @@ impl FormatStrategy<HlsData> for HlsFormatStrategy {
         writer: &mut Self::Writer,
         item: &HlsData,
     ) -> Result<u64, Self::StrategyError> {
+        if let Some((_, extractor)) = &mut self.captions {
+            self.caption_cues.extend(extractor.push(item));
+        }
         match item {
             HlsData::TsData(ts) => {
                 self.analyzer
                     .analyze_segment(item)
                     .map_err(HlsStrategyError::Analyzer)?;
-                let bytes_written = ts.data().len() as u64;
-                writer.write_all(ts.data())?;
+                let bytes_written = match &mut self.ts_timeline {
+                    Some(ts_timeline) => {
+                        let data = ts_timeline.process(
+                            ts.data(),
+                            ts.segment.discontinuity,
+                            ts.segment.duration,
+                        );
+                        writer.write_all(&data)?;
+                        data.len() as u64
+                    }
+                    None => {
+                        writer.write_all(ts.data())?;
+                        ts.data().len() as u64
+                    }
+                };
                 // Accumulate TS segment duration
                 self.target_duration += ts.segment.duration;
                 Ok(bytes_written)
@@ -98,16 +200,30 @@ impl FormatStrategy<HlsData> for HlsFormatStrategy {
                     .analyze_segment(item)
                     .map_err(HlsStrategyError::Analyzer)?;
                 let bytes_written = match m4s_data {
+                    // A repeated EXT-X-MAP (or a re-emitted init after a
+                    // reconnect) describes the tracks already in this file.
+                    M4sData::InitSegment(init)
+                        if self.written_init.as_ref() == Some(&init.data) =>
+                    {
+                        debug!("Skipping repeated init segment");
+                        0
+                    }
                     M4sData::InitSegment(init) => {
                         info!("Found init segment, offset: {:?}", self.current_offset);
+                        self.timeline.set_init(&init.data);
+                        self.written_init = Some(init.data.clone());
                         let bytes_written = init.data.len() as u64;
                         writer.write_all(&init.data)?;
                         bytes_written
                     }
                     M4sData::Segment(segment) => {
-                        let bytes_written = segment.data.len() as u64;
-                        writer.write_all(&segment.data)?;
-                        self.target_duration += segment.segment.duration;
+                        let (data, duration) = self
+                            .timeline
+                            .process(&segment.data, segment.segment.discontinuity);
+                        let bytes_written = data.len() as u64;
+                        writer.write_all(&data)?;
+                        self.target_duration +=
+                            duration.map_or(segment.segment.duration, |secs| secs as f32);
                         bytes_written
                     }
                 };
@@ -124,16 +240,16 @@ impl FormatStrategy<HlsData> for HlsFormatStrategy {
     }
 
     fn should_rotate_file(&self, _config: &WriterConfig, state: &WriterState) -> bool {
-        let Some(max_size) = self.max_file_size else {
-            return false;
-        };
-        if max_size == 0 {
+        // Rotate before writing the next item once we have at least one item in the current file.
+        // This avoids creating empty files when a rotation is requested before any payload is written.
+        if state.items_written_current_file == 0 {
             return false;
         }
 
-        // Rotate before writing the next item once we have at least one item in the current file.
-        // This avoids creating empty files when a rotation is requested before any payload is written.
-        state.items_written_current_file > 0 && state.bytes_written_current_file >= max_size
+        let size_reached = self
+            .max_file_size
+            .is_some_and(|max_size| max_size > 0 && state.bytes_written_current_file >= max_size);
+        size_reached || self.wall_clock.as_ref().is_some_and(|w| w.is_due())
     }
 
     fn next_file_path(&self, config: &WriterConfig, state: &WriterState) -> PathBuf {
@@ -185,10 +301,26 @@ impl FormatStrategy<HlsData> for HlsFormatStrategy {
         {
             self.last_split_reason = Some(SplitReason::SizeLimit);
         }
+        if self.last_split_reason.is_none()
+            && state.items_written_current_file > 0
+            && self.wall_clock.as_ref().is_some_and(|w| w.is_due())
+        {
+            self.last_split_reason = Some(SplitReason::WallClock);
+        }
 
         let items_written = state.items_written_current_file;
         let duration_secs = self.target_duration;
 
+        if let Some(on_report) = &self.on_report {
+            let mut report = AnalysisReport::from_stats(&self.analyzer.stats);
+            // What was actually written: repeated inits are skipped and fMP4
+            // durations are measured from the samples.
+            report.file_size = state.bytes_written_current_file;
+            report.duration_secs = f64::from(duration_secs);
+            on_report(path, state.file_sequence_number, &report);
+        }
+        self.write_caption_sidecars(path);
+
         info!(
             path = %path.display(),
             items = items_written,
@@ -239,18 +371,48 @@ pub struct HlsWriterConfig {
     pub base_name: String,
     pub extension: String,
     pub max_file_size: Option<u64>,
+    /// Also start a new file whenever the local time crosses a multiple of this
+    /// interval since midnight, e.g. one hour to split on the hour.
+    pub wall_clock_split: Option<Duration>,
+    /// Keep segments as individual files referenced by a local playlist
+    /// (`<name>.m3u8` plus a `<name>/` directory) instead of concatenating them.
+    /// `extension` is ignored: outputs are always `.m3u8` playlists.
+    pub playlist_archive: bool,
+    /// Renumber continuity counters and restamp PCR/PTS/DTS of TS segments so
+    /// each concatenated file plays as one continuous stream, even across
+    /// discontinuities. Has no effect on fMP4 segments or playlist archives.
+    pub continuous_ts: bool,
+    /// Extract CEA-608/708 closed captions from TS segments and write them next to each
+    /// file as one subtitle sidecar per caption channel (`<name>.cc1.srt`,
+    /// `<name>.service1.srt`, ...). Has no effect on playlist archives.
+    pub captions: Option<CaptionFormat>,
+}
+
+enum HlsWriterTask {
+    Concatenate(Box<WriterTask<HlsData, HlsFormatStrategy>>),
+    PlaylistArchive(Box<WriterTask<HlsData, HlsArchiveStrategy>>),
 }
 
 pub struct HlsWriter {
-    writer_task: WriterTask<HlsData, HlsFormatStrategy>,
+    writer_task: HlsWriterTask,
 }
 
 impl HlsWriter {
     pub fn new(config: HlsWriterConfig) -> Self {
-        let writer_config =
-            WriterConfig::new(config.output_dir, config.base_name, config.extension);
-        let strategy = HlsFormatStrategy::new(config.max_file_size);
-        let writer_task = WriterTask::new(writer_config, strategy);
+        let writer_task = if config.playlist_archive {
+            let writer_config =
+                WriterConfig::new(config.output_dir, config.base_name, "m3u8".to_string());
+            let strategy = HlsArchiveStrategy::new(config.max_file_size)
+                .with_wall_clock_split(config.wall_clock_split);
+            HlsWriterTask::PlaylistArchive(Box::new(WriterTask::new(writer_config, strategy)))
+        } else {
+            let writer_config =
+                WriterConfig::new(config.output_dir, config.base_name, config.extension);
+            let strategy =
+                HlsFormatStrategy::new(config.max_file_size, config.continuous_ts, config.captions)
+                    .with_wall_clock_split(config.wall_clock_split);
+            HlsWriterTask::Concatenate(Box::new(WriterTask::new(writer_config, strategy)))
+        };
         Self { writer_task }
     }
 
@@ -259,7 +421,10 @@ impl HlsWriter {
     where
         F: Fn(&std::path::Path, u32) + Send + Sync + 'static,
     {
-        self.writer_task.set_on_file_open_callback(callback);
+        match &mut self.writer_task {
+            HlsWriterTask::Concatenate(task) => task.set_on_file_open_callback(callback),
+            HlsWriterTask::PlaylistArchive(task) => task.set_on_file_open_callback(callback),
+        }
     }
 
     /// Set a callback to be invoked when a segment is completed.
@@ -267,7 +432,27 @@ impl HlsWriter {
     where
         F: Fn(&std::path::Path, u32, f64, u64, Option<&SplitReason>) + Send + Sync + 'static,
     {
-        self.writer_task.set_on_file_close_callback(callback);
+        match &mut self.writer_task {
+            HlsWriterTask::Concatenate(task) => task.set_on_file_close_callback(callback),
+            HlsWriterTask::PlaylistArchive(task) => task.set_on_file_close_callback(callback),
+        }
+    }
+
+    /// Set a callback to be invoked with an analysis report when a segment is completed.
+    ///
+    /// The callback receives the file path, sequence number (0-based), and the report. It runs
+    /// before the segment-complete callback of the same segment. Playlist archives produce no
+    /// reports.
+    pub fn set_on_segment_report_callback<F>(&mut self, callback: F)
+    where
+        F: Fn(&Path, u32, &AnalysisReport) + Send + Sync + 'static,
+    {
+        match &mut self.writer_task {
+            HlsWriterTask::Concatenate(task) => {
+                task.strategy_mut().set_on_report_callback(callback)
+            }
+            HlsWriterTask::PlaylistArchive(_) => {}
+        }
     }
 
     /// Set a progress callback with default intervals (1MB bytes, 1000ms time).
@@ -275,7 +460,10 @@ impl HlsWriter {
     where
         F: Fn(WriterProgress) + Send + Sync + 'static,
     {
-        self.writer_task.set_progress_callback(callback);
+        match &mut self.writer_task {
+            HlsWriterTask::Concatenate(task) => task.set_progress_callback(callback),
+            HlsWriterTask::PlaylistArchive(task) => task.set_progress_callback(callback),
+        }
     }
 
     /// Set a progress callback with custom intervals.
@@ -283,13 +471,19 @@ impl HlsWriter {
     where
         F: Fn(WriterProgress) + Send + Sync + 'static,
     {
-        self.writer_task
-            .set_progress_callback_with_config(callback, config);
+        match &mut self.writer_task {
+            HlsWriterTask::Concatenate(task) => {
+                task.set_progress_callback_with_config(callback, config)
+            }
+            HlsWriterTask::PlaylistArchive(task) => {
+                task.set_progress_callback_with_config(callback, config)
+            }
+        }
     }
 
     /// Get the total media duration in seconds across all files.
     pub fn media_duration_secs(&self) -> f64 {
-        self.writer_task.get_state().media_duration_secs_total
+        self.get_state().media_duration_secs_total
     }
 }
 
@@ -297,7 +491,10 @@ impl ProtocolWriter for HlsWriter {
     type Item = HlsData;
 
     fn get_state(&self) -> &WriterState {
-        self.writer_task.get_state()
+        match &self.writer_task {
+            HlsWriterTask::Concatenate(task) => task.get_state(),
+            HlsWriterTask::PlaylistArchive(task) => task.get_state(),
+        }
     }
 
     fn run(
@@ -305,13 +502,17 @@ impl ProtocolWriter for HlsWriter {
         input: pipeline_common::PipelineReceiver<HlsData>,
     ) -> Result<WriterStats, WriterError> {
         let mut saw_payload = false;
-        self.writer_task.run_from_channel(input, |item, _state| {
+        let accept = move |item: &HlsData, _state: &WriterState| {
             if !saw_payload && matches!(item, HlsData::EndMarker(_)) {
                 return false;
             }
             saw_payload |= !matches!(item, HlsData::EndMarker(_));
             true
-        })
+        };
+        match &mut self.writer_task {
+            HlsWriterTask::Concatenate(task) => task.run_from_channel(input, accept),
+            HlsWriterTask::PlaylistArchive(task) => task.run_from_channel(input, accept),
+        }
     }
 }
 
@@ -331,6 +532,10 @@ mod tests {
             base_name: "test-%i".to_string(),
             extension: "ts".to_string(),
             max_file_size: Some(15),
+            wall_clock_split: None,
+            playlist_archive: false,
+            continuous_ts: false,
+            captions: None,
         });
 
         let (tx, rx) = tokio::sync::mpsc::channel::<Result<HlsData, PipelineError>>(16);
@@ -367,6 +572,54 @@ mod tests {
         assert_eq!(file_count, 2);
     }
 
+    #[test]
+    fn rotates_on_wall_clock_boundary() {
+        let tempdir = tempfile::tempdir().expect("create temp dir");
+        let now = std::sync::Arc::new(std::sync::Mutex::new(time::macros::datetime!(
+            2024-05-01 12:59:59 +0
+        )));
+        let clock = std::sync::Arc::clone(&now);
+
+        let mut strategy = HlsFormatStrategy::new(None, false, None);
+        strategy.wall_clock = Some(WallClockBoundary::with_clock(
+            Duration::from_secs(3_600),
+            move || *clock.lock().unwrap(),
+        ));
+        let mut task = WriterTask::new(
+            WriterConfig::new(
+                tempdir.path().to_path_buf(),
+                "test-%i".to_string(),
+                "ts".to_string(),
+            ),
+            strategy,
+        );
+        let reasons = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let closed = std::sync::Arc::clone(&reasons);
+        task.set_on_file_close_callback(move |_, _, _, _, reason| {
+            closed.lock().unwrap().push(reason.cloned());
+        });
+
+        let seg = || {
+            HlsData::ts(
+                MediaSegment {
+                    duration: 1.0,
+                    ..MediaSegment::empty()
+                },
+                Bytes::from_static(&[0u8; 10]),
+            )
+        };
+        task.process_item(seg()).unwrap();
+        *now.lock().unwrap() = time::macros::datetime!(2024-05-01 13:00:01 +0);
+        task.process_item(seg()).unwrap();
+        task.process_item(seg()).unwrap();
+        task.close().unwrap();
+
+        assert_eq!(
+            *reasons.lock().unwrap(),
+            vec![Some(SplitReason::WallClock), None]
+        );
+    }
+
     #[test]
     fn ignores_leading_end_markers() {
         let tempdir = tempfile::tempdir().expect("create temp dir");
@@ -376,6 +629,10 @@ mod tests {
             base_name: "test-%i".to_string(),
             extension: "ts".to_string(),
             max_file_size: None,
+            wall_clock_split: None,
+            playlist_archive: false,
+            continuous_ts: false,
+            captions: None,
         });
 
         let (tx, rx) = tokio::sync::mpsc::channel::<Result<HlsData, PipelineError>>(16);
@@ -401,4 +658,68 @@ mod tests {
             .count();
         assert_eq!(file_count, 0);
     }
+
+    #[test]
+    fn reports_each_closed_file() {
+        let tempdir = tempfile::tempdir().expect("create temp dir");
+
+        let mut writer = HlsWriter::new(HlsWriterConfig {
+            output_dir: tempdir.path().to_path_buf(),
+            base_name: "test-%i".to_string(),
+            extension: "ts".to_string(),
+            max_file_size: None,
+            wall_clock_split: None,
+            playlist_archive: false,
+            continuous_ts: false,
+            captions: None,
+        });
+        let reports = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let reports_writer = reports.clone();
+        writer.set_on_segment_report_callback(move |_, sequence, report| {
+            reports_writer
+                .lock()
+                .unwrap()
+                .push((sequence, report.clone()));
+        });
+
+        let (tx, rx) = tokio::sync::mpsc::channel::<Result<HlsData, PipelineError>>(16);
+        let handle = std::thread::spawn(move || writer.run(rx.into()));
+
+        let seg = |media_sequence: u64| {
+            let mut segment = MediaSegment {
+                duration: 2.0,
+                ..MediaSegment::empty()
+            };
+            hls::DeliveryMarker::new(media_sequence, Some(2.0))
+                .with_download_ms(50)
+                .attach(&mut segment);
+            Ok(HlsData::ts(segment, Bytes::from_static(&[0u8; 10])))
+        };
+
+        tx.blocking_send(seg(1)).unwrap();
+        tx.blocking_send(seg(3)).unwrap();
+        tx.blocking_send(Ok(HlsData::end_marker())).unwrap();
+        tx.blocking_send(seg(4)).unwrap();
+        drop(tx);
+        handle
+            .join()
+            .expect("writer thread join")
+            .expect("writer ok");
+
+        let reports = reports.lock().unwrap();
+        assert_eq!(reports.len(), 2);
+        let (sequence, first) = &reports[0];
+        assert_eq!(*sequence, 0);
+        assert_eq!(first.file_size, 20);
+        assert_eq!(first.duration_secs, 4.0);
+        assert_eq!(first.sequence_gaps.len(), 1);
+        assert_eq!(
+            first
+                .download_latency
+                .as_ref()
+                .map(|latency| latency.samples),
+            Some(2)
+        );
+        assert!(reports[1].1.sequence_gaps.is_empty());
+    }
 }
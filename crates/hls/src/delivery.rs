@@ -0,0 +1,102 @@
+//! Delivery metadata of downloaded segments.
+//!
+//! `MediaSegment` only carries what the playlist says about a segment. The downloader
+//! records where the segment sat in the media playlist and how long it took to fetch in
+//! an `EXT-X-SEGMENT-DELIVERY` marker in `MediaSegment::unknown_tags` (see
+//! [`DeliveryMarker::tag`]), so processors and analyzers downstream can report on it.
+
+use m3u8_rs::{ExtTag, MediaSegment};
+
+use crate::low_latency::attributes;
+
+/// Tag name of the delivery marker, without the `#EXT-` prefix.
+pub const DELIVERY_MARKER_TAG: &str = "X-SEGMENT-DELIVERY";
+
+/// Where a segment came from and how it was delivered
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DeliveryMarker {
+    /// Media sequence number of the segment (of the parent segment for partial segments)
+    pub media_sequence: u64,
+    /// `EXT-X-TARGETDURATION` of the playlist listing the segment, in seconds
+    pub target_duration: Option<f64>,
+    /// Time spent downloading the segment; `None` when it was served from a cache
+    pub download_ms: Option<u64>,
+}
+
+impl DeliveryMarker {
+    pub fn new(media_sequence: u64, target_duration: Option<f64>) -> Self {
+        Self {
+            media_sequence,
+            target_duration,
+            download_ms: None,
+        }
+    }
+
+    pub fn with_download_ms(mut self, download_ms: u64) -> Self {
+        self.download_ms = Some(download_ms);
+        self
+    }
+
+    pub fn tag(&self) -> ExtTag {
+        let mut rest = format!("MEDIA-SEQUENCE={}", self.media_sequence);
+        if let Some(target_duration) = self.target_duration {
+            rest.push_str(&format!(",TARGET-DURATION={target_duration}"));
+        }
+        if let Some(download_ms) = self.download_ms {
+            rest.push_str(&format!(",DOWNLOAD-MS={download_ms}"));
+        }
+        ExtTag {
+            tag: DELIVERY_MARKER_TAG.to_string(),
+            rest: Some(rest),
+        }
+    }
+
+    /// Record the marker on a segment, replacing an earlier one.
+    pub fn attach(&self, segment: &mut MediaSegment) {
+        segment
+            .unknown_tags
+            .retain(|tag| tag.tag != DELIVERY_MARKER_TAG);
+        segment.unknown_tags.push(self.tag());
+    }
+
+    /// Reads the marker of a downloaded segment, `None` when it has none.
+    pub fn from_segment(segment: &MediaSegment) -> Option<Self> {
+        let tag = segment
+            .unknown_tags
+            .iter()
+            .find(|tag| tag.tag == DELIVERY_MARKER_TAG)?;
+        let mut media_sequence = None;
+        let mut target_duration = None;
+        let mut download_ms = None;
+        for (name, value) in attributes(tag.rest.as_deref().unwrap_or_default()) {
+            match name {
+                "MEDIA-SEQUENCE" => media_sequence = value.parse().ok(),
+                "TARGET-DURATION" => target_duration = value.parse().ok(),
+                "DOWNLOAD-MS" => download_ms = value.parse().ok(),
+                _ => {}
+            }
+        }
+        Some(Self {
+            media_sequence: media_sequence?,
+            target_duration,
+            download_ms,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn delivery_marker_round_trips() {
+        let marker = DeliveryMarker::new(42, Some(6.0)).with_download_ms(350);
+        let mut segment = MediaSegment::default();
+        DeliveryMarker::new(41, None).attach(&mut segment);
+        marker.attach(&mut segment);
+
+        assert_eq!(segment.unknown_tags.len(), 1);
+        assert_eq!(DeliveryMarker::from_segment(&segment), Some(marker));
+        assert_eq!(DeliveryMarker::from_segment(&MediaSegment::default()), None);
+    }
+}
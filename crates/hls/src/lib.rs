@@ -1,13 +1,24 @@
 // HLS (HTTP Live Streaming) segment data handling
+pub mod delivery;
+pub mod low_latency;
 pub mod mp4;
+pub mod multivariant;
 pub mod profile;
 pub mod resolution;
 pub mod segment;
 pub mod ts;
 
 // Export common types for ease of use
-pub use media_types::Resolution;
+pub use delivery::DeliveryMarker;
+pub use low_latency::{
+    LowLatencyPlaylist, PartMarker, PartialSegment, PreloadHint, PreloadHintType, ServerControl,
+};
+pub use media_types::{AudioCodec, Resolution, StreamDescription, VideoCodec};
 pub use mp4::{M4sData, M4sInitSegmentData, M4sSegmentData};
+pub use multivariant::{
+    MultivariantPlaylist, MultivariantPlaylistError, Rendition, RenditionType, Variant,
+    VariantSelection,
+};
 pub use pipeline_common::split_reason::SplitReason;
 pub use profile::{SegmentType, StreamProfile, StreamProfileOptions};
 pub use resolution::ResolutionDetector;
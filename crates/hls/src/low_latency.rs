@@ -0,0 +1,446 @@
+//! Low-Latency HLS playlist extensions.
+//!
+//! m3u8-rs has no model for partial segments: `EXT-X-PART` tags end up in
+//! `MediaSegment::unknown_tags`, and the tags after the last segment URI (the parts and
+//! preload hints of the segment the server is still producing) are dropped entirely.
+//! [`LowLatencyPlaylist::parse`] scans the raw playlist text for the LL-HLS tags and keeps
+//! the part lists index-aligned with `MediaPlaylist::segments`.
+//!
+//! Partial segments handed downstream carry an `EXT-X-PARTIAL-SEGMENT` marker in their
+//! `MediaSegment::unknown_tags` (see [`PartialSegment::marker`]) so processors can tell
+//! them apart from whole segments.
+
+use m3u8_rs::{ByteRange, ExtTag, MediaSegment};
+
+/// Tag name of the marker recorded on partial segments, without the `#EXT-` prefix.
+/// Distinct from `X-PART`, which m3u8-rs leaves on whole segments listing their parts.
+pub const PART_MARKER_TAG: &str = "X-PARTIAL-SEGMENT";
+
+/// Server capabilities advertised by `EXT-X-SERVER-CONTROL`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ServerControl {
+    /// The server supports blocking playlist reload (`_HLS_msn` / `_HLS_part`)
+    pub can_block_reload: bool,
+    /// The server can produce delta updates skipping segments older than this (seconds)
+    pub can_skip_until: Option<f64>,
+    pub hold_back: Option<f64>,
+    pub part_hold_back: Option<f64>,
+}
+
+/// A partial segment (`EXT-X-PART`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartialSegment {
+    pub uri: String,
+    pub duration: f64,
+    /// The part starts with an independent frame
+    pub independent: bool,
+    /// Byte range within `uri`; a missing offset is inferred from the previous part of
+    /// the same resource and stays `None` when that is impossible
+    pub byte_range: Option<ByteRange>,
+    /// The part is unavailable (`GAP=YES`)
+    pub gap: bool,
+}
+
+impl PartialSegment {
+    /// Marker stored in `MediaSegment::unknown_tags` of the segment built for this part.
+    pub fn marker(&self, index: u32) -> ExtTag {
+        ExtTag {
+            tag: PART_MARKER_TAG.to_string(),
+            rest: Some(format!(
+                "INDEX={index},INDEPENDENT={}",
+                if self.independent { "YES" } else { "NO" }
+            )),
+        }
+    }
+}
+
+/// Kind of resource announced by `EXT-X-PRELOAD-HINT`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PreloadHintType {
+    Part,
+    Map,
+}
+
+/// A resource the server will publish next (`EXT-X-PRELOAD-HINT`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct PreloadHint {
+    pub hint_type: PreloadHintType,
+    pub uri: String,
+    pub byte_range_start: Option<u64>,
+    pub byte_range_length: Option<u64>,
+}
+
+/// Position of a partial segment within its parent segment, read back from its marker
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PartMarker {
+    pub index: u32,
+    pub independent: bool,
+}
+
+impl PartMarker {
+    /// Reads the marker of a segment built from a partial segment, `None` for whole segments.
+    pub fn from_segment(segment: &MediaSegment) -> Option<Self> {
+        let tag = segment
+            .unknown_tags
+            .iter()
+            .find(|tag| tag.tag == PART_MARKER_TAG)?;
+        let mut index = None;
+        let mut independent = false;
+        for (name, value) in attributes(tag.rest.as_deref().unwrap_or_default()) {
+            match name {
+                "INDEX" => index = value.parse().ok(),
+                "INDEPENDENT" => independent = value == "YES",
+                _ => {}
+            }
+        }
+        Some(Self {
+            index: index?,
+            independent,
+        })
+    }
+}
+
+/// The LL-HLS view of a media playlist
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LowLatencyPlaylist {
+    pub server_control: Option<ServerControl>,
+    /// `EXT-X-PART-INF` `PART-TARGET` (seconds); present on every LL-HLS playlist
+    pub part_target: Option<f64>,
+    /// Parts of each published segment, index-aligned with `MediaPlaylist::segments`
+    pub segment_parts: Vec<Vec<PartialSegment>>,
+    /// Parts of the segment still being produced, listed after the last segment URI
+    pub pending_parts: Vec<PartialSegment>,
+    pub preload_hints: Vec<PreloadHint>,
+    /// An `EXT-X-DISCONTINUITY` precedes the pending parts
+    pub pending_discontinuity: bool,
+    /// An `EXT-X-KEY` or `EXT-X-MAP` precedes the pending parts, so they cannot be
+    /// decoded with the scope of the published segments
+    pub pending_scope_change: bool,
+}
+
+impl LowLatencyPlaylist {
+    /// Scans a media playlist for LL-HLS tags. Malformed tags are ignored.
+    pub fn parse(input: &[u8]) -> Self {
+        let text = String::from_utf8_lossy(input);
+        let mut playlist = Self::default();
+        let mut parts = Vec::new();
+        // End of the previous part's byte range, for parts that omit the offset
+        let mut range_end: Option<(String, u64)> = None;
+
+        for line in text.lines().map(str::trim) {
+            if line.is_empty() {
+                continue;
+            }
+            if !line.starts_with('#') {
+                playlist.segment_parts.push(std::mem::take(&mut parts));
+                playlist.pending_discontinuity = false;
+                playlist.pending_scope_change = false;
+                continue;
+            }
+            let (tag, rest) = line.split_once(':').unwrap_or((line, ""));
+            match tag {
+                "#EXT-X-SERVER-CONTROL" => {
+                    playlist.server_control = Some(parse_server_control(rest));
+                }
+                "#EXT-X-PART-INF" => {
+                    playlist.part_target = attributes(rest)
+                        .find(|(name, _)| *name == "PART-TARGET")
+                        .and_then(|(_, value)| value.parse().ok());
+                }
+                "#EXT-X-PART" => {
+                    if let Some(part) = parse_part(rest, &mut range_end) {
+                        parts.push(part);
+                    }
+                }
+                "#EXT-X-PRELOAD-HINT" => {
+                    if let Some(hint) = parse_preload_hint(rest) {
+                        playlist.preload_hints.push(hint);
+                    }
+                }
+                "#EXT-X-DISCONTINUITY" => playlist.pending_discontinuity = true,
+                "#EXT-X-KEY" | "#EXT-X-MAP" => playlist.pending_scope_change = true,
+                _ => {}
+            }
+        }
+
+        playlist.pending_parts = parts;
+        playlist
+    }
+
+    /// Whether the playlist advertises partial segments
+    pub fn is_low_latency(&self) -> bool {
+        self.part_target.is_some()
+    }
+
+    /// Whether the server holds playlist requests until the requested update exists
+    pub fn can_block_reload(&self) -> bool {
+        self.server_control
+            .as_ref()
+            .is_some_and(|control| control.can_block_reload)
+    }
+
+    /// The `_HLS_msn` / `_HLS_part` values requesting the update after this playlist.
+    /// The part is only given when the playlist advertises partial segments.
+    pub fn next_update(&self, media_sequence: u64) -> (u64, Option<u32>) {
+        let msn = media_sequence.saturating_add(self.segment_parts.len() as u64);
+        let part = self
+            .is_low_latency()
+            .then(|| u32::try_from(self.pending_parts.len()).unwrap_or(u32::MAX));
+        (msn, part)
+    }
+
+    /// The preload hint for the part that follows `pending_parts`, if the server gave one
+    pub fn next_part_hint(&self) -> Option<&PreloadHint> {
+        self.preload_hints
+            .iter()
+            .find(|hint| hint.hint_type == PreloadHintType::Part)
+    }
+}
+
+fn parse_server_control(rest: &str) -> ServerControl {
+    let mut control = ServerControl::default();
+    for (name, value) in attributes(rest) {
+        match name {
+            "CAN-BLOCK-RELOAD" => control.can_block_reload = value == "YES",
+            "CAN-SKIP-UNTIL" => control.can_skip_until = value.parse().ok(),
+            "HOLD-BACK" => control.hold_back = value.parse().ok(),
+            "PART-HOLD-BACK" => control.part_hold_back = value.parse().ok(),
+            _ => {}
+        }
+    }
+    control
+}
+
+fn parse_part(rest: &str, range_end: &mut Option<(String, u64)>) -> Option<PartialSegment> {
+    let mut uri = None;
+    let mut duration = None;
+    let mut independent = false;
+    let mut gap = false;
+    let mut byte_range = None;
+    for (name, value) in attributes(rest) {
+        match name {
+            "URI" => uri = Some(value.to_string()),
+            "DURATION" => duration = value.parse().ok(),
+            "INDEPENDENT" => independent = value == "YES",
+            "GAP" => gap = value == "YES",
+            "BYTERANGE" => byte_range = parse_byte_range(value),
+            _ => {}
+        }
+    }
+    let uri = uri.filter(|uri| !uri.is_empty())?;
+
+    if let Some(range) = byte_range.as_mut() {
+        if range.offset.is_none() {
+            range.offset = range_end
+                .as_ref()
+                .filter(|(previous, _)| *previous == uri)
+                .map(|(_, end)| *end);
+        }
+        *range_end = range
+            .offset
+            .map(|offset| (uri.clone(), offset.saturating_add(range.length)));
+    } else {
+        *range_end = None;
+    }
+
+    Some(PartialSegment {
+        uri,
+        duration: duration?,
+        independent,
+        byte_range,
+        gap,
+    })
+}
+
+fn parse_preload_hint(rest: &str) -> Option<PreloadHint> {
+    let mut hint_type = None;
+    let mut uri = None;
+    let mut byte_range_start = None;
+    let mut byte_range_length = None;
+    for (name, value) in attributes(rest) {
+        match name {
+            "TYPE" => {
+                hint_type = match value {
+                    "PART" => Some(PreloadHintType::Part),
+                    "MAP" => Some(PreloadHintType::Map),
+                    _ => None,
+                }
+            }
+            "URI" => uri = Some(value.to_string()),
+            "BYTERANGE-START" => byte_range_start = value.parse().ok(),
+            "BYTERANGE-LENGTH" => byte_range_length = value.parse().ok(),
+            _ => {}
+        }
+    }
+    Some(PreloadHint {
+        hint_type: hint_type?,
+        uri: uri.filter(|uri| !uri.is_empty())?,
+        byte_range_start,
+        byte_range_length,
+    })
+}
+
+/// `<length>[@<offset>]`
+fn parse_byte_range(value: &str) -> Option<ByteRange> {
+    let (length, offset) = match value.split_once('@') {
+        Some((length, offset)) => (length, Some(offset.parse().ok()?)),
+        None => (value, None),
+    };
+    Some(ByteRange {
+        length: length.parse().ok()?,
+        offset,
+    })
+}
+
+/// Splits an attribute list into name/value pairs, unquoting quoted values.
+pub(crate) fn attributes(rest: &str) -> impl Iterator<Item = (&str, &str)> {
+    let mut remaining = rest;
+    std::iter::from_fn(move || {
+        loop {
+            remaining = remaining.trim_start_matches([',', ' ']);
+            if remaining.is_empty() {
+                return None;
+            }
+            let (name, after_name) = remaining.split_once('=')?;
+            let (value, after_value) = match after_name.strip_prefix('"') {
+                Some(quoted) => {
+                    let end = quoted.find('"').unwrap_or(quoted.len());
+                    (&quoted[..end], quoted.get(end + 1..).unwrap_or_default())
+                }
+                None => {
+                    let end = after_name.find(',').unwrap_or(after_name.len());
+                    (&after_name[..end], &after_name[end..])
+                }
+            };
+            remaining = after_value;
+            let name = name.trim();
+            if !name.is_empty() {
+                return Some((name, value.trim()));
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PLAYLIST: &str = r#"#EXTM3U
+#EXT-X-TARGETDURATION:4
+#EXT-X-VERSION:6
+#EXT-X-SERVER-CONTROL:CAN-BLOCK-RELOAD=YES,PART-HOLD-BACK=1.0,CAN-SKIP-UNTIL=12.0
+#EXT-X-PART-INF:PART-TARGET=0.33334
+#EXT-X-MEDIA-SEQUENCE:266
+#EXT-X-MAP:URI="init.mp4"
+#EXTINF:4.00008,
+fileSequence266.mp4
+#EXT-X-PART:DURATION=0.33334,URI="filePart267.0.mp4",INDEPENDENT=YES
+#EXT-X-PART:DURATION=0.33334,URI="filePart267.1.mp4"
+#EXT-X-PART:DURATION=0.33334,URI="filePart267.2.mp4"
+#EXTINF:1.00002,
+fileSequence267.mp4
+#EXT-X-DISCONTINUITY
+#EXT-X-PART:DURATION=0.33334,URI="filePart268.0.mp4",INDEPENDENT=YES
+#EXT-X-PART:DURATION=0.33334,URI="filePart268.1.mp4"
+#EXT-X-PRELOAD-HINT:TYPE=PART,URI="filePart268.2.mp4"
+#EXT-X-RENDITION-REPORT:URI="../1M/waitForMSN.php",LAST-MSN=268,LAST-PART=1
+"#;
+
+    #[test]
+    fn parses_parts_aligned_with_segments() {
+        let playlist = LowLatencyPlaylist::parse(PLAYLIST.as_bytes());
+
+        assert!(playlist.is_low_latency());
+        assert!(playlist.can_block_reload());
+        assert_eq!(playlist.part_target, Some(0.33334));
+        assert_eq!(
+            playlist.server_control.as_ref().unwrap().can_skip_until,
+            Some(12.0)
+        );
+
+        assert_eq!(playlist.segment_parts.len(), 2);
+        assert!(playlist.segment_parts[0].is_empty());
+        let uris: Vec<_> = playlist.segment_parts[1]
+            .iter()
+            .map(|part| part.uri.as_str())
+            .collect();
+        assert_eq!(
+            uris,
+            [
+                "filePart267.0.mp4",
+                "filePart267.1.mp4",
+                "filePart267.2.mp4"
+            ]
+        );
+        assert!(playlist.segment_parts[1][0].independent);
+        assert!(!playlist.segment_parts[1][1].independent);
+
+        assert_eq!(playlist.pending_parts.len(), 2);
+        assert!(playlist.pending_discontinuity);
+        assert!(!playlist.pending_scope_change);
+        assert_eq!(
+            playlist.next_part_hint().map(|hint| hint.uri.as_str()),
+            Some("filePart268.2.mp4")
+        );
+        assert_eq!(playlist.next_update(266), (268, Some(2)));
+    }
+
+    #[test]
+    fn infers_part_byte_range_offsets() {
+        let playlist = LowLatencyPlaylist::parse(
+            br#"#EXTM3U
+#EXT-X-PART-INF:PART-TARGET=1.0
+#EXT-X-PART:DURATION=1.0,URI="seg1.ts",BYTERANGE="1000@0",INDEPENDENT=YES
+#EXT-X-PART:DURATION=1.0,URI="seg1.ts",BYTERANGE="500"
+#EXT-X-PART:DURATION=1.0,URI="other.ts",BYTERANGE="700"
+#EXT-X-PRELOAD-HINT:TYPE=PART,URI="seg1.ts",BYTERANGE-START=1500
+"#,
+        );
+
+        let ranges: Vec<_> = playlist
+            .pending_parts
+            .iter()
+            .map(|part| part.byte_range.clone().unwrap())
+            .collect();
+        assert_eq!(ranges[0].offset, Some(0));
+        assert_eq!((ranges[1].length, ranges[1].offset), (500, Some(1000)));
+        assert_eq!(ranges[2].offset, None);
+        assert_eq!(playlist.preload_hints[0].byte_range_start, Some(1500));
+        assert!(!playlist.can_block_reload());
+    }
+
+    #[test]
+    fn regular_playlist_is_not_low_latency() {
+        let playlist = LowLatencyPlaylist::parse(
+            b"#EXTM3U\n#EXT-X-TARGETDURATION:6\n#EXTINF:6.0,\nseg1.ts\n#EXTINF:6.0,\nseg2.ts\n",
+        );
+
+        assert!(!playlist.is_low_latency());
+        assert_eq!(playlist.segment_parts.len(), 2);
+        assert_eq!(playlist.next_update(10), (12, None));
+    }
+
+    #[test]
+    fn part_marker_round_trips() {
+        let part = PartialSegment {
+            uri: "part.ts".to_string(),
+            duration: 0.5,
+            independent: true,
+            byte_range: None,
+            gap: false,
+        };
+        let segment = MediaSegment {
+            unknown_tags: vec![part.marker(3)],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            PartMarker::from_segment(&segment),
+            Some(PartMarker {
+                index: 3,
+                independent: true
+            })
+        );
+        assert_eq!(PartMarker::from_segment(&MediaSegment::default()), None);
+    }
+}
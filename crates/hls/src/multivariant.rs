@@ -0,0 +1,457 @@
+//! Multivariant (master) playlist model and variant selection.
+//!
+//! A multivariant playlist lists the same content at several bitrates and resolutions
+//! (`EXT-X-STREAM-INF`) together with alternative renditions such as audio tracks and
+//! subtitles (`EXT-X-MEDIA`). [`MultivariantPlaylist::select`] picks the variant to record.
+
+use std::cmp::Reverse;
+
+use m3u8_rs::{AlternativeMediaType, ClosedCaptionGroupId, MasterPlaylist, VariantStream};
+use media_types::{AudioCodec, Resolution, StreamDescription, VideoCodec};
+use thiserror::Error;
+
+/// Error produced when parsing a multivariant playlist
+#[derive(Debug, Error)]
+pub enum MultivariantPlaylistError {
+    /// The playlist could not be parsed
+    #[error("failed to parse playlist: {0}")]
+    Parse(String),
+    /// The playlist is a media playlist
+    #[error("expected a multivariant playlist, got a media playlist")]
+    MediaPlaylist,
+}
+
+/// Kind of an alternative rendition (`EXT-X-MEDIA` `TYPE`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenditionType {
+    Audio,
+    Video,
+    Subtitles,
+    ClosedCaptions,
+}
+
+/// An alternative rendition (`EXT-X-MEDIA`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rendition {
+    pub rendition_type: RenditionType,
+    pub group_id: String,
+    pub name: String,
+    pub language: Option<String>,
+    /// Media playlist of the rendition; `None` when it is muxed into the variant
+    pub uri: Option<String>,
+    pub default: bool,
+    pub autoselect: bool,
+}
+
+/// A variant stream (`EXT-X-STREAM-INF`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Variant {
+    /// Media playlist URI, possibly relative to the multivariant playlist
+    pub uri: String,
+    /// Peak bitrate in bits per second
+    pub bandwidth: u64,
+    pub average_bandwidth: Option<u64>,
+    /// RFC 6381 codec identifiers, e.g. `avc1.640028` and `mp4a.40.2`
+    pub codecs: Vec<String>,
+    pub resolution: Option<Resolution>,
+    pub frame_rate: Option<f64>,
+    pub audio_group: Option<String>,
+    pub video_group: Option<String>,
+    pub subtitles_group: Option<String>,
+    pub closed_captions_group: Option<String>,
+}
+
+impl Variant {
+    /// What the variant carries according to its `CODECS`, `RESOLUTION` and
+    /// `FRAME-RATE` attributes.
+    pub fn description(&self) -> StreamDescription {
+        StreamDescription {
+            resolution: self.resolution,
+            frame_rate: self.frame_rate,
+            ..StreamDescription::from_codecs(&self.codecs)
+        }
+    }
+
+    /// Whether the variant carries video.
+    ///
+    /// Without a `CODECS` attribute, a resolution or video group implies video.
+    pub fn has_video(&self) -> bool {
+        if self.codecs.is_empty() {
+            self.resolution.is_some() || self.video_group.is_some()
+        } else {
+            self.codecs
+                .iter()
+                .any(|codec| VideoCodec::from_name(codec).is_some())
+        }
+    }
+
+    /// Whether the variant carries audio, muxed or through an audio group.
+    pub fn has_audio(&self) -> bool {
+        self.audio_group.is_some()
+            || self
+                .codecs
+                .iter()
+                .any(|codec| AudioCodec::from_name(codec).is_some())
+    }
+
+    /// Whether the variant carries audio and no video.
+    pub fn is_audio_only(&self) -> bool {
+        self.has_audio() && !self.has_video()
+    }
+
+    /// Whether the variant carries video and no audio.
+    pub fn is_video_only(&self) -> bool {
+        self.has_video() && !self.has_audio()
+    }
+
+    /// Ordering key for "better" variants: bandwidth, then resolution.
+    fn quality(&self) -> (u64, u64) {
+        let pixels = self
+            .resolution
+            .map_or(0, |r| u64::from(r.width) * u64::from(r.height));
+        (self.bandwidth, pixels)
+    }
+
+    fn from_m3u8(variant: &VariantStream) -> Self {
+        let codecs = variant
+            .codecs
+            .as_deref()
+            .map(|codecs| {
+                codecs
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|codec| !codec.is_empty())
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default();
+        let resolution = variant.resolution.and_then(|r| {
+            Some(Resolution::new(
+                u32::try_from(r.width).ok()?,
+                u32::try_from(r.height).ok()?,
+            ))
+        });
+        let closed_captions_group = match &variant.closed_captions {
+            Some(ClosedCaptionGroupId::GroupId(group) | ClosedCaptionGroupId::Other(group)) => {
+                Some(group.clone())
+            }
+            Some(ClosedCaptionGroupId::None) | None => None,
+        };
+
+        Self {
+            uri: variant.uri.clone(),
+            bandwidth: variant.bandwidth,
+            average_bandwidth: variant.average_bandwidth,
+            codecs,
+            resolution,
+            frame_rate: variant.frame_rate,
+            audio_group: variant.audio.clone(),
+            video_group: variant.video.clone(),
+            subtitles_group: variant.subtitles.clone(),
+            closed_captions_group,
+        }
+    }
+}
+
+/// How to pick a variant from a multivariant playlist
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum VariantSelection {
+    /// The variant with the highest bandwidth
+    #[default]
+    HighestBandwidth,
+    /// The variant with the lowest bandwidth
+    LowestBandwidth,
+    /// The variant whose bandwidth is closest to the target (bits per second)
+    ClosestToBandwidth(u64),
+    /// The best variant whose bandwidth does not exceed the limit (bits per second)
+    MaxBandwidth(u64),
+    /// The best variant with exactly this resolution
+    Resolution { width: u32, height: u32 },
+    /// The best variant no taller than this many lines, e.g. 1080
+    MaxHeight(u32),
+    /// The best variant carrying audio and no video
+    AudioOnly,
+    /// The best variant carrying video and no audio
+    VideoOnly,
+}
+
+/// A parsed multivariant (master) playlist
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MultivariantPlaylist {
+    /// Playable variants, in playlist order. I-frame only variants are not included.
+    pub variants: Vec<Variant>,
+    pub renditions: Vec<Rendition>,
+    pub independent_segments: bool,
+}
+
+impl MultivariantPlaylist {
+    /// Parse a multivariant playlist.
+    pub fn parse(input: &[u8]) -> Result<Self, MultivariantPlaylistError> {
+        match m3u8_rs::parse_playlist_res(input) {
+            Ok(m3u8_rs::Playlist::MasterPlaylist(playlist)) => Ok(Self::from(&playlist)),
+            Ok(m3u8_rs::Playlist::MediaPlaylist(_)) => {
+                Err(MultivariantPlaylistError::MediaPlaylist)
+            }
+            Err(e) => Err(MultivariantPlaylistError::Parse(e.to_string())),
+        }
+    }
+
+    /// Pick a variant. Returns `None` when no variant satisfies the selection.
+    pub fn select(&self, selection: VariantSelection) -> Option<&Variant> {
+        let variants = self.variants.iter();
+        match selection {
+            VariantSelection::HighestBandwidth => variants.max_by_key(|v| v.quality()),
+            VariantSelection::LowestBandwidth => variants.min_by_key(|v| v.quality()),
+            // On equal distance, prefer the higher quality variant
+            VariantSelection::ClosestToBandwidth(target) => {
+                variants.min_by_key(|v| (v.bandwidth.abs_diff(target), Reverse(v.quality())))
+            }
+            VariantSelection::MaxBandwidth(limit) => variants
+                .filter(|v| v.bandwidth <= limit)
+                .max_by_key(|v| v.quality()),
+            VariantSelection::Resolution { width, height } => variants
+                .filter(|v| v.resolution == Some(Resolution::new(width, height)))
+                .max_by_key(|v| v.quality()),
+            VariantSelection::MaxHeight(max_height) => variants
+                .filter(|v| v.resolution.is_some_and(|r| r.height <= max_height))
+                .max_by_key(|v| v.quality()),
+            VariantSelection::AudioOnly => variants
+                .filter(|v| v.is_audio_only())
+                .max_by_key(|v| v.quality()),
+            VariantSelection::VideoOnly => variants
+                .filter(|v| v.is_video_only())
+                .max_by_key(|v| v.quality()),
+        }
+    }
+
+    /// Renditions of `rendition_type` in the group the variant refers to.
+    pub fn renditions_for<'a>(
+        &'a self,
+        variant: &'a Variant,
+        rendition_type: RenditionType,
+    ) -> impl Iterator<Item = &'a Rendition> {
+        let group = match rendition_type {
+            RenditionType::Audio => &variant.audio_group,
+            RenditionType::Video => &variant.video_group,
+            RenditionType::Subtitles => &variant.subtitles_group,
+            RenditionType::ClosedCaptions => &variant.closed_captions_group,
+        };
+        self.renditions.iter().filter(move |rendition| {
+            rendition.rendition_type == rendition_type
+                && group.as_deref() == Some(rendition.group_id.as_str())
+        })
+    }
+
+    /// The rendition a player would pick by default for the variant: the `DEFAULT=YES` one,
+    /// otherwise the first of the group.
+    pub fn default_rendition<'a>(
+        &'a self,
+        variant: &'a Variant,
+        rendition_type: RenditionType,
+    ) -> Option<&'a Rendition> {
+        self.renditions_for(variant, rendition_type)
+            .find(|rendition| rendition.default)
+            .or_else(|| self.renditions_for(variant, rendition_type).next())
+    }
+
+    /// The rendition of the variant's group whose `LANGUAGE` or `NAME` is `name`, ignoring
+    /// ASCII case.
+    pub fn find_rendition<'a>(
+        &'a self,
+        variant: &'a Variant,
+        rendition_type: RenditionType,
+        name: &str,
+    ) -> Option<&'a Rendition> {
+        self.renditions_for(variant, rendition_type)
+            .find(|rendition| {
+                rendition.name.eq_ignore_ascii_case(name)
+                    || rendition
+                        .language
+                        .as_deref()
+                        .is_some_and(|language| language.eq_ignore_ascii_case(name))
+            })
+    }
+}
+
+impl From<&MasterPlaylist> for MultivariantPlaylist {
+    fn from(playlist: &MasterPlaylist) -> Self {
+        let variants = playlist
+            .variants
+            .iter()
+            .filter(|variant| !variant.is_i_frame)
+            .map(Variant::from_m3u8)
+            .collect();
+        let renditions = playlist
+            .alternatives
+            .iter()
+            .filter_map(|media| {
+                let rendition_type = match media.media_type {
+                    AlternativeMediaType::Audio => RenditionType::Audio,
+                    AlternativeMediaType::Video => RenditionType::Video,
+                    AlternativeMediaType::Subtitles => RenditionType::Subtitles,
+                    AlternativeMediaType::ClosedCaptions => RenditionType::ClosedCaptions,
+                    AlternativeMediaType::Other(_) => return None,
+                };
+                Some(Rendition {
+                    rendition_type,
+                    group_id: media.group_id.clone(),
+                    name: media.name.clone(),
+                    language: media.language.clone(),
+                    uri: media.uri.clone(),
+                    default: media.default,
+                    autoselect: media.autoselect,
+                })
+            })
+            .collect();
+
+        Self {
+            variants,
+            renditions,
+            independent_segments: playlist.independent_segments,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PLAYLIST: &str = r#"#EXTM3U
+#EXT-X-INDEPENDENT-SEGMENTS
+#EXT-X-MEDIA:TYPE=AUDIO,GROUP-ID="aac",NAME="English",LANGUAGE="en",DEFAULT=YES,AUTOSELECT=YES,URI="audio/en.m3u8"
+#EXT-X-MEDIA:TYPE=AUDIO,GROUP-ID="aac",NAME="Deutsch",LANGUAGE="de",URI="audio/de.m3u8"
+#EXT-X-MEDIA:TYPE=SUBTITLES,GROUP-ID="subs",NAME="English",LANGUAGE="en",URI="subs/en.m3u8"
+#EXT-X-STREAM-INF:BANDWIDTH=8000000,AVERAGE-BANDWIDTH=6000000,CODECS="avc1.640033,mp4a.40.2",RESOLUTION=3840x2160,FRAME-RATE=60.000,AUDIO="aac",SUBTITLES="subs"
+2160p.m3u8
+#EXT-X-STREAM-INF:BANDWIDTH=4000000,CODECS="avc1.640028,mp4a.40.2",RESOLUTION=1920x1080,FRAME-RATE=60.000,AUDIO="aac",SUBTITLES="subs"
+1080p60.m3u8
+#EXT-X-STREAM-INF:BANDWIDTH=2500000,CODECS="avc1.640028,mp4a.40.2",RESOLUTION=1920x1080,FRAME-RATE=30.000,AUDIO="aac"
+1080p30.m3u8
+#EXT-X-STREAM-INF:BANDWIDTH=800000,CODECS="avc1.4d401f",RESOLUTION=854x480
+480p_video.m3u8
+#EXT-X-STREAM-INF:BANDWIDTH=160000,CODECS="mp4a.40.2",AUDIO="aac"
+audio_only.m3u8
+#EXT-X-I-FRAME-STREAM-INF:BANDWIDTH=300000,CODECS="avc1.640028",RESOLUTION=1920x1080,URI="iframes.m3u8"
+"#;
+
+    fn playlist() -> MultivariantPlaylist {
+        MultivariantPlaylist::parse(PLAYLIST.as_bytes()).unwrap()
+    }
+
+    fn selected(playlist: &MultivariantPlaylist, selection: VariantSelection) -> Option<&str> {
+        playlist.select(selection).map(|v| v.uri.as_str())
+    }
+
+    #[test]
+    fn parses_stream_inf_attributes() {
+        let playlist = playlist();
+
+        assert!(playlist.independent_segments);
+        assert_eq!(playlist.variants.len(), 5);
+        let top = &playlist.variants[0];
+        assert_eq!(top.uri, "2160p.m3u8");
+        assert_eq!(top.bandwidth, 8_000_000);
+        assert_eq!(top.average_bandwidth, Some(6_000_000));
+        assert_eq!(top.codecs, vec!["avc1.640033", "mp4a.40.2"]);
+        assert_eq!(top.resolution, Some(Resolution::new(3840, 2160)));
+        assert_eq!(top.frame_rate, Some(60.0));
+        assert_eq!(top.audio_group.as_deref(), Some("aac"));
+        assert_eq!(top.subtitles_group.as_deref(), Some("subs"));
+        assert_eq!(top.description().to_string(), "H.264 3840x2160@60, AAC");
+        assert_eq!(top.description().level, Some(51));
+
+        assert!(playlist.variants[3].is_video_only());
+        assert!(playlist.variants[4].is_audio_only());
+        assert!(!top.is_audio_only() && !top.is_video_only());
+    }
+
+    #[test]
+    fn resolves_renditions_of_a_variant() {
+        let playlist = playlist();
+        let top = &playlist.variants[0];
+
+        let audio = playlist
+            .renditions_for(top, RenditionType::Audio)
+            .map(|r| r.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(audio, vec!["English", "Deutsch"]);
+        let default_audio = playlist
+            .default_rendition(top, RenditionType::Audio)
+            .unwrap();
+        assert_eq!(default_audio.uri.as_deref(), Some("audio/en.m3u8"));
+        assert_eq!(default_audio.language.as_deref(), Some("en"));
+        assert_eq!(
+            playlist
+                .renditions_for(&playlist.variants[2], RenditionType::Subtitles)
+                .count(),
+            0
+        );
+
+        let find = |name| {
+            playlist
+                .find_rendition(top, RenditionType::Audio, name)
+                .and_then(|r| r.uri.as_deref())
+        };
+        assert_eq!(find("DE"), Some("audio/de.m3u8"));
+        assert_eq!(find("english"), Some("audio/en.m3u8"));
+        assert_eq!(find("fr"), None);
+    }
+
+    #[test]
+    fn selects_variants() {
+        let playlist = playlist();
+
+        assert_eq!(
+            selected(&playlist, VariantSelection::HighestBandwidth),
+            Some("2160p.m3u8")
+        );
+        assert_eq!(
+            selected(&playlist, VariantSelection::LowestBandwidth),
+            Some("audio_only.m3u8")
+        );
+        assert_eq!(
+            selected(&playlist, VariantSelection::ClosestToBandwidth(3_000_000)),
+            Some("1080p30.m3u8")
+        );
+        assert_eq!(
+            selected(&playlist, VariantSelection::MaxBandwidth(5_000_000)),
+            Some("1080p60.m3u8")
+        );
+        assert_eq!(
+            selected(&playlist, VariantSelection::MaxBandwidth(1_000)),
+            None
+        );
+        assert_eq!(
+            selected(
+                &playlist,
+                VariantSelection::Resolution {
+                    width: 1920,
+                    height: 1080
+                }
+            ),
+            Some("1080p60.m3u8")
+        );
+        assert_eq!(
+            selected(&playlist, VariantSelection::MaxHeight(1080)),
+            Some("1080p60.m3u8")
+        );
+        assert_eq!(
+            selected(&playlist, VariantSelection::AudioOnly),
+            Some("audio_only.m3u8")
+        );
+        assert_eq!(
+            selected(&playlist, VariantSelection::VideoOnly),
+            Some("480p_video.m3u8")
+        );
+    }
+
+    #[test]
+    fn rejects_media_playlists() {
+        let media = b"#EXTM3U\n#EXT-X-TARGETDURATION:2\n#EXTINF:2.0,\nseg0.ts\n";
+
+        assert!(matches!(
+            MultivariantPlaylist::parse(media),
+            Err(MultivariantPlaylistError::MediaPlaylist)
+        ));
+    }
+}
@@ -3,6 +3,8 @@ use m3u8_rs::MediaSegment;
 use pipeline_common::split_reason::SplitReason;
 use ts::StreamType;
 
+use crate::delivery::DeliveryMarker;
+use crate::low_latency::PartMarker;
 use crate::mp4::{M4sData, M4sInitSegmentData, M4sSegmentData};
 use crate::profile::{SegmentType, StreamProfile, StreamProfileOptions};
 use crate::ts::{TsSegmentData, TsStreamInfo};
@@ -160,6 +162,16 @@ impl HlsData {
         }
     }
 
+    /// Position within its parent segment when this is an LL-HLS partial segment
+    pub fn part(&self) -> Option<PartMarker> {
+        self.media_segment().and_then(PartMarker::from_segment)
+    }
+
+    /// Delivery metadata recorded by the downloader, if any
+    pub fn delivery(&self) -> Option<DeliveryMarker> {
+        self.media_segment().and_then(DeliveryMarker::from_segment)
+    }
+
     /// Check if this segment indicates the start of a new segment
     /// For TS: typically a keyframe with PAT/PMT tables following
     /// For MP4: an init segment or a media segment starting with moof box
@@ -0,0 +1,122 @@
+use serde::{Deserialize, Serialize};
+
+/// Video codec of a stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VideoCodec {
+    Avc,
+    Hevc,
+    Av1,
+    Vp8,
+    Vp9,
+}
+
+impl VideoCodec {
+    /// Stable lowercase identifier, also used when serialized.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Avc => "avc",
+            Self::Hevc => "hevc",
+            Self::Av1 => "av1",
+            Self::Vp8 => "vp8",
+            Self::Vp9 => "vp9",
+        }
+    }
+
+    /// Recognises an RFC 6381 codec identifier (`avc1.640028`, `hvc1.1.6.L120.90`)
+    /// or a common codec name (`h264`, `hevc`, `bytevc1`), ignoring case.
+    pub fn from_name(name: &str) -> Option<Self> {
+        let fourcc = name.trim().split('.').next()?.to_ascii_lowercase();
+        match fourcc.as_str() {
+            "avc" | "avc1" | "avc3" | "h264" | "h.264" | "x264" => Some(Self::Avc),
+            "hevc" | "hvc1" | "hev1" | "dvh1" | "dvhe" | "h265" | "h.265" | "x265" | "bytevc1" => {
+                Some(Self::Hevc)
+            }
+            "av1" | "av01" => Some(Self::Av1),
+            "vp8" | "vp08" => Some(Self::Vp8),
+            "vp9" | "vp09" => Some(Self::Vp9),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for VideoCodec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Avc => "H.264",
+            Self::Hevc => "H.265",
+            Self::Av1 => "AV1",
+            Self::Vp8 => "VP8",
+            Self::Vp9 => "VP9",
+        })
+    }
+}
+
+/// Audio codec of a stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AudioCodec {
+    Aac,
+    Mp3,
+    Opus,
+    Flac,
+    Ac3,
+    Eac3,
+    Ac4,
+}
+
+impl AudioCodec {
+    /// Stable lowercase identifier, also used when serialized.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Aac => "aac",
+            Self::Mp3 => "mp3",
+            Self::Opus => "opus",
+            Self::Flac => "flac",
+            Self::Ac3 => "ac3",
+            Self::Eac3 => "eac3",
+            Self::Ac4 => "ac4",
+        }
+    }
+
+    /// Recognises an RFC 6381 codec identifier (`mp4a.40.2`, `ec-3`) or a
+    /// common codec name (`aac`, `mp3`), ignoring case.
+    ///
+    /// `mp4a` is AAC unless its object type says otherwise.
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.trim().to_ascii_lowercase();
+        let mut parts = name.split('.');
+        match parts.next()? {
+            "mp4a" => match (parts.next(), parts.next()) {
+                // MPEG-1/2 audio object types, and MPEG-1 Layer 3 in an AudioSpecificConfig
+                (Some("69" | "6b"), _) | (Some("40"), Some("34")) => Some(Self::Mp3),
+                (Some("a5"), _) => Some(Self::Ac3),
+                (Some("a6"), _) => Some(Self::Eac3),
+                (Some("ad"), _) => Some(Self::Opus),
+                _ => Some(Self::Aac),
+            },
+            "aac" | "he-aac" => Some(Self::Aac),
+            "mp3" => Some(Self::Mp3),
+            "opus" => Some(Self::Opus),
+            "flac" => Some(Self::Flac),
+            "ac-3" | "ac3" => Some(Self::Ac3),
+            "ec-3" | "eac3" | "e-ac-3" => Some(Self::Eac3),
+            "ac-4" | "ac4" => Some(Self::Ac4),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for AudioCodec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Aac => "AAC",
+            Self::Mp3 => "MP3",
+            Self::Opus => "Opus",
+            Self::Flac => "FLAC",
+            Self::Ac3 => "AC-3",
+            Self::Eac3 => "E-AC-3",
+            Self::Ac4 => "AC-4",
+        })
+    }
+}
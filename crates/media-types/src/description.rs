@@ -0,0 +1,261 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{AudioCodec, Resolution, VideoCodec};
+
+/// Transfer characteristics (ISO/IEC 23091-2) of HDR video: PQ and HLG.
+const HDR_TRANSFER_CHARACTERISTICS: &[u8] = &[16, 18];
+
+/// What a stream carries, as far as it is known.
+///
+/// Every field is optional: a platform API may only report a codec name, an
+/// HLS variant its `CODECS` and `RESOLUTION`, while a parsed sequence header
+/// gives the full picture. Descriptions from several sources are combined with
+/// [`StreamDescription::merge`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StreamDescription {
+    pub video_codec: Option<VideoCodec>,
+    /// Codec-specific profile as coded in the bitstream, e.g. `profile_idc`
+    /// 100 for H.264 High or `seq_profile` 0 for AV1 Main.
+    pub profile: Option<u8>,
+    /// Codec-specific level as coded in the bitstream, e.g. `level_idc` 40
+    /// for H.264 level 4.0 or `general_level_idc` 120 for H.265 level 4.
+    pub level: Option<u8>,
+    pub resolution: Option<Resolution>,
+    pub frame_rate: Option<f64>,
+    /// Luma bit depth
+    pub bit_depth: Option<u8>,
+    /// Whether the video uses an HDR transfer function (PQ, HLG) or Dolby Vision
+    pub hdr: bool,
+    pub audio_codec: Option<AudioCodec>,
+    pub audio_channels: Option<u8>,
+    /// Audio sample rate in Hz
+    pub audio_sample_rate: Option<u32>,
+}
+
+impl StreamDescription {
+    /// Builds a description from RFC 6381 codec identifiers, such as the
+    /// `CODECS` attribute of an HLS variant.
+    ///
+    /// Profile, level, bit depth and HDR are read from the identifier when it
+    /// carries them; unknown identifiers are ignored.
+    pub fn from_codecs<I, S>(codecs: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut description = Self::default();
+        for codec in codecs {
+            let codec = codec.as_ref().trim();
+            if description.video_codec.is_none()
+                && let Some(video_codec) = VideoCodec::from_name(codec)
+            {
+                description.video_codec = Some(video_codec);
+                description.parse_video_codec_parameters(codec);
+            } else if description.audio_codec.is_none() {
+                description.audio_codec = AudioCodec::from_name(codec);
+            }
+        }
+        description
+    }
+
+    fn parse_video_codec_parameters(&mut self, codec: &str) {
+        let parts: Vec<&str> = codec.split('.').collect();
+        let fourcc = parts[0].to_ascii_lowercase();
+        let dec = |index: usize| parts.get(index).and_then(|part| part.parse::<u8>().ok());
+        match fourcc.as_str() {
+            // avc1.PPCCLL
+            "avc1" | "avc3" => {
+                if let Some(ppccll) = parts.get(1).filter(|part| part.len() == 6) {
+                    self.profile = u8::from_str_radix(&ppccll[0..2], 16).ok();
+                    self.level = u8::from_str_radix(&ppccll[4..6], 16).ok();
+                }
+            }
+            // hvc1.[A-C]P.compat.[LH]level.constraints
+            "hvc1" | "hev1" => {
+                self.profile = parts
+                    .get(1)
+                    .and_then(|part| part.trim_start_matches(['A', 'B', 'C']).parse().ok());
+                self.level = parts
+                    .get(3)
+                    .and_then(|part| part.get(1..))
+                    .and_then(|level| level.parse().ok());
+            }
+            // Dolby Vision signals its own profile and level scheme
+            "dvh1" | "dvhe" => self.hdr = true,
+            // av01.P.LLT.DD[.M.CCC.cp.tc.mc.F]
+            "av01" => {
+                self.profile = dec(1);
+                self.level = parts
+                    .get(2)
+                    .and_then(|part| part.get(..2))
+                    .and_then(|level| level.parse().ok());
+                self.bit_depth = dec(3);
+                self.hdr = dec(7).is_some_and(|tc| HDR_TRANSFER_CHARACTERISTICS.contains(&tc));
+            }
+            // vp09.PP.LL.DD[.CC.cp.tc.mc.FF]
+            "vp09" => {
+                self.profile = dec(1);
+                self.level = dec(2);
+                self.bit_depth = dec(3);
+                self.hdr = dec(6).is_some_and(|tc| HDR_TRANSFER_CHARACTERISTICS.contains(&tc));
+            }
+            _ => {}
+        }
+    }
+
+    /// Fills the fields that are unset here from `other`.
+    ///
+    /// Fields already known are kept, so the more reliable source should be
+    /// merged into first.
+    pub fn merge(&mut self, other: &StreamDescription) {
+        // Profile and level are only meaningful together with their codec
+        if self.video_codec.is_none() || self.video_codec == other.video_codec {
+            self.video_codec = other.video_codec;
+            self.profile = self.profile.or(other.profile);
+            self.level = self.level.or(other.level);
+        }
+        self.resolution = self.resolution.or(other.resolution);
+        self.frame_rate = self.frame_rate.or(other.frame_rate);
+        self.bit_depth = self.bit_depth.or(other.bit_depth);
+        self.hdr |= other.hdr;
+        self.audio_codec = self.audio_codec.or(other.audio_codec);
+        self.audio_channels = self.audio_channels.or(other.audio_channels);
+        self.audio_sample_rate = self.audio_sample_rate.or(other.audio_sample_rate);
+    }
+
+    /// Whether the stream is known to carry video.
+    pub fn has_video(&self) -> bool {
+        self.video_codec.is_some() || self.resolution.is_some()
+    }
+
+    /// Whether the stream is known to carry audio.
+    pub fn has_audio(&self) -> bool {
+        self.audio_codec.is_some()
+    }
+
+    /// Whether the stream carries audio and no video.
+    pub fn is_audio_only(&self) -> bool {
+        self.has_audio() && !self.has_video()
+    }
+
+    /// Whether nothing is known about the stream.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+impl std::fmt::Display for StreamDescription {
+    /// Formats as e.g. `H.264 1920x1080@60 10-bit HDR, AAC 2ch 48000Hz`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut video = Vec::new();
+        if let Some(codec) = self.video_codec {
+            video.push(codec.to_string());
+        }
+        match (self.resolution, self.frame_rate) {
+            (Some(resolution), Some(fps)) => video.push(format!("{resolution}@{fps}")),
+            (Some(resolution), None) => video.push(resolution.to_string()),
+            (None, Some(fps)) => video.push(format!("{fps}fps")),
+            (None, None) => {}
+        }
+        if let Some(bit_depth) = self.bit_depth {
+            video.push(format!("{bit_depth}-bit"));
+        }
+        if self.hdr {
+            video.push("HDR".to_string());
+        }
+
+        let mut audio = Vec::new();
+        if let Some(codec) = self.audio_codec {
+            audio.push(codec.to_string());
+        }
+        if let Some(channels) = self.audio_channels {
+            audio.push(format!("{channels}ch"));
+        }
+        if let Some(sample_rate) = self.audio_sample_rate {
+            audio.push(format!("{sample_rate}Hz"));
+        }
+
+        let tracks: Vec<String> = [video, audio]
+            .into_iter()
+            .filter(|parts| !parts.is_empty())
+            .map(|parts| parts.join(" "))
+            .collect();
+        if tracks.is_empty() {
+            f.write_str("unknown")
+        } else {
+            f.write_str(&tracks.join(", "))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_codecs() {
+        let description = StreamDescription::from_codecs(["avc1.640028", "mp4a.40.2"]);
+        assert_eq!(description.video_codec, Some(VideoCodec::Avc));
+        assert_eq!(description.profile, Some(100));
+        assert_eq!(description.level, Some(40));
+        assert_eq!(description.audio_codec, Some(AudioCodec::Aac));
+        assert!(!description.hdr);
+
+        let description = StreamDescription::from_codecs(["hvc1.2.4.L153.B0", "ec-3"]);
+        assert_eq!(description.video_codec, Some(VideoCodec::Hevc));
+        assert_eq!(description.profile, Some(2));
+        assert_eq!(description.level, Some(153));
+        assert_eq!(description.audio_codec, Some(AudioCodec::Eac3));
+
+        let description = StreamDescription::from_codecs(["av01.0.13M.10.0.112.09.16.09.0"]);
+        assert_eq!(description.video_codec, Some(VideoCodec::Av1));
+        assert_eq!(description.profile, Some(0));
+        assert_eq!(description.level, Some(13));
+        assert_eq!(description.bit_depth, Some(10));
+        assert!(description.hdr);
+        assert!(!description.has_audio());
+
+        let description = StreamDescription::from_codecs(["mp4a.40.34"]);
+        assert_eq!(description.audio_codec, Some(AudioCodec::Mp3));
+        assert!(description.is_audio_only());
+
+        assert!(StreamDescription::from_codecs(["wvtt"]).is_empty());
+    }
+
+    #[test]
+    fn test_merge_and_display() {
+        let mut description = StreamDescription {
+            video_codec: Some(VideoCodec::Hevc),
+            resolution: Some(Resolution::new(1920, 1080)),
+            ..Default::default()
+        };
+        description.merge(&StreamDescription {
+            video_codec: Some(VideoCodec::Avc),
+            profile: Some(100),
+            resolution: Some(Resolution::new(1280, 720)),
+            frame_rate: Some(60.0),
+            audio_codec: Some(AudioCodec::Aac),
+            audio_channels: Some(2),
+            audio_sample_rate: Some(48000),
+            ..Default::default()
+        });
+
+        assert_eq!(description.video_codec, Some(VideoCodec::Hevc));
+        // The profile belongs to another codec
+        assert_eq!(description.profile, None);
+        assert_eq!(description.resolution, Some(Resolution::new(1920, 1080)));
+        assert_eq!(description.frame_rate, Some(60.0));
+        assert_eq!(
+            description.to_string(),
+            "H.265 1920x1080@60, AAC 2ch 48000Hz"
+        );
+        assert_eq!(StreamDescription::default().to_string(), "unknown");
+
+        let json = serde_json::to_value(&description).unwrap();
+        assert_eq!(json["video_codec"], "hevc");
+        assert_eq!(json["resolution"]["width"], 1920);
+        let parsed: StreamDescription = serde_json::from_str(r#"{"audio_codec":"opus"}"#).unwrap();
+        assert_eq!(parsed.audio_codec, Some(AudioCodec::Opus));
+    }
+}
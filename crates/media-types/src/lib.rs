@@ -1,5 +1,12 @@
+mod codec;
+mod description;
+
+pub use codec::{AudioCodec, VideoCodec};
+pub use description::StreamDescription;
+use serde::{Deserialize, Serialize};
+
 /// Video resolution information.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Resolution {
     pub width: u32,
     pub height: u32,
@@ -37,7 +37,9 @@ use std::time::Duration;
 
 use reqwest::header::{HeaderMap, HeaderValue};
 
-use crate::{CacheConfig, DownloaderConfig, proxy::ProxyConfig};
+use crate::{
+    CacheConfig, DownloaderConfig, cookies::CookieJar, proxy::ProxyConfig, retry::RetryPolicy,
+};
 
 /// Builder for creating DownloaderConfig instances with a fluent API
 #[derive(Debug, Clone)]
@@ -111,6 +113,31 @@ impl DownloaderConfigBuilder {
         self
     }
 
+    /// Add a header rendered for every request from a template such as
+    /// `https://live.example.com/{room_id}`
+    pub fn with_header_template(
+        mut self,
+        name: impl AsRef<str>,
+        template: impl Into<String>,
+    ) -> Self {
+        if let Ok(name) = name.as_ref().parse::<reqwest::header::HeaderName>() {
+            self.config.header_templates.add(name, template);
+        }
+        self
+    }
+
+    /// Set the value of a `{name}` placeholder of the header templates
+    pub fn with_template_var(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.config.header_templates.set_var(name, value);
+        self
+    }
+
+    /// Set the cookie jar
+    pub fn with_cookie_jar(mut self, jar: CookieJar) -> Self {
+        self.config.cookie_jar = Some(jar);
+        self
+    }
+
     /// Add a custom HTTP header
     pub fn with_header(mut self, name: impl AsRef<str>, value: impl AsRef<str>) -> Self {
         if let (Ok(name), Ok(value)) = (
@@ -189,21 +216,69 @@ impl DownloaderConfigBuilder {
         self
     }
 
-    /// Set the TCP keep-alive interval for maintaining long-lived HTTP/2 connections
+    /// Set the interval of HTTP/2 PING frames keeping multiplexed connections alive
     ///
-    /// This helps keep HTTP/2 connections alive for multiplexing benefits.
+    /// Unanswered PINGs close dead connections instead of stalling every stream on them.
     /// Recommended: 15-30 seconds for media streaming workloads.
     pub fn with_http2_keep_alive_interval(mut self, interval: Duration) -> Self {
         self.config.http2_keep_alive_interval = Some(interval);
         self
     }
 
+    /// Set how long to wait for a PING acknowledgement, and whether to also PING idle connections
+    pub fn with_http2_keep_alive_timeout(mut self, timeout: Duration, while_idle: bool) -> Self {
+        self.config.http2_keep_alive_timeout = timeout;
+        self.config.http2_keep_alive_while_idle = while_idle;
+        self
+    }
+
+    /// Size HTTP/2 flow-control windows from the measured bandwidth-delay product
+    pub fn with_http2_adaptive_window(mut self, enabled: bool) -> Self {
+        self.config.http2_adaptive_window = enabled;
+        self
+    }
+
+    /// Set the initial HTTP/2 stream and connection flow-control windows in bytes
+    pub fn with_http2_window_sizes(mut self, stream: Option<u32>, connection: Option<u32>) -> Self {
+        self.config.http2_initial_stream_window_size = stream;
+        self.config.http2_initial_connection_window_size = connection;
+        self
+    }
+
+    /// Set the TCP keep-alive interval, or disable it with `None`
+    pub fn with_tcp_keepalive(mut self, interval: Option<Duration>) -> Self {
+        self.config.tcp_keepalive = interval;
+        self
+    }
+
+    /// Set the connection pool: idle connections kept per host and how long they stay open
+    pub fn with_connection_pool(
+        mut self,
+        max_idle_per_host: usize,
+        idle_timeout: Duration,
+    ) -> Self {
+        self.config.pool_max_idle_per_host = max_idle_per_host;
+        self.config.pool_idle_timeout = idle_timeout;
+        self
+    }
+
     /// Configure settings optimized for HLS media streaming
     ///
     /// This sets:
     /// - Keep-alive interval: 20s (maintains HTTP/2 connections for multiplexing)
+    /// - PINGs on idle connections, so they survive between playlist refreshes
+    /// - Adaptive flow-control windows, so concurrent segments share one connection
+    ///   at full speed on high-latency links
     pub fn with_http2_hls_optimized(mut self) -> Self {
         self.config.http2_keep_alive_interval = Some(Duration::from_secs(20));
+        self.config.http2_keep_alive_while_idle = true;
+        self.config.http2_adaptive_window = true;
+        self
+    }
+
+    /// Set how failed requests are retried
+    pub fn with_retry_policy(mut self, retry: RetryPolicy) -> Self {
+        self.config.retry = retry;
         self
     }
 
@@ -335,5 +410,39 @@ mod tests {
             hls_config.http2_keep_alive_interval,
             Some(Duration::from_secs(20))
         );
+        assert!(hls_config.http2_keep_alive_while_idle);
+        assert!(hls_config.http2_adaptive_window);
+    }
+
+    #[test]
+    fn test_connection_tuning() {
+        let default_config = DownloaderConfigBuilder::new().build();
+        assert_eq!(default_config.tcp_keepalive, Some(Duration::from_secs(20)));
+        assert!(!default_config.http2_adaptive_window);
+        assert_eq!(default_config.http2_initial_stream_window_size, None);
+
+        let config = DownloaderConfigBuilder::new()
+            .with_tcp_keepalive(None)
+            .with_http2_keep_alive_timeout(Duration::from_secs(5), true)
+            .with_http2_window_sizes(Some(1 << 20), Some(4 << 20))
+            .with_connection_pool(32, Duration::from_secs(90))
+            .build();
+
+        assert_eq!(config.tcp_keepalive, None);
+        assert_eq!(config.http2_keep_alive_timeout, Duration::from_secs(5));
+        assert!(config.http2_keep_alive_while_idle);
+        assert_eq!(config.http2_initial_stream_window_size, Some(1 << 20));
+        assert_eq!(config.http2_initial_connection_window_size, Some(4 << 20));
+        assert_eq!(config.pool_max_idle_per_host, 32);
+        assert_eq!(config.pool_idle_timeout, Duration::from_secs(90));
+
+        // Clients build with every setting applied
+        crate::create_client(&config).unwrap();
+        crate::create_client(
+            &DownloaderConfigBuilder::new()
+                .with_http2_hls_optimized()
+                .build(),
+        )
+        .unwrap();
     }
 }
@@ -14,7 +14,7 @@ mod utils;
 pub use manager::CacheManager;
 pub use types::{
     CacheConfig, CacheKey, CacheLookupResult, CacheMetadata, CacheResourceType, CacheResult,
-    CacheStatus,
+    CacheStats, CacheStatus,
 };
 pub use utils::extract_cache_headers;
 
@@ -3,6 +3,7 @@
 //! This module provides the main cache manager that coordinates between memory and file caches.
 
 use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
 
 use bytes::Bytes;
 use tokio::io;
@@ -13,6 +14,7 @@ use crate::cache::providers::memory::MemoryCache;
 use crate::cache::providers::provider::CacheProvider;
 use crate::cache::types::{
     CacheConfig, CacheKey, CacheLookupResult, CacheMetadata, CacheResourceType, CacheResult,
+    CacheStats,
 };
 
 /// Cache manager handling both memory and file caching
@@ -22,6 +24,8 @@ pub struct CacheManager {
     memory_cache: Arc<MemoryCache>,
     file_cache: Arc<FileCache>,
     config: Arc<CacheConfig>,
+    hits: Arc<AtomicU64>,
+    misses: Arc<AtomicU64>,
 }
 
 impl CacheManager {
@@ -47,15 +51,18 @@ impl CacheManager {
             max_disk_size,
         ));
 
-        // Initialize the cache directories in advance
+        // Initialize the cache directories in advance, and measure what earlier runs left
         if config.enabled {
             file_cache.ensure_initialized().await?;
+            file_cache.sweep().await?;
         }
 
         Ok(Self {
             memory_cache,
             file_cache,
             config,
+            hits: Arc::new(AtomicU64::new(0)),
+            misses: Arc::new(AtomicU64::new(0)),
         })
     }
 
@@ -78,6 +85,7 @@ impl CacheManager {
 
         // Check memory cache first
         if let Some((data, metadata, status)) = self.memory_cache.get(key).await? {
+            self.hits.fetch_add(1, Ordering::Relaxed);
             return Ok(Some((data, metadata, status)));
         }
 
@@ -92,9 +100,11 @@ impl CacheManager {
                 warn!(%error, "failed to promote file-cache entry to memory cache");
             }
 
+            self.hits.fetch_add(1, Ordering::Relaxed);
             return Ok(Some((data, metadata, status)));
         }
 
+        self.misses.fetch_add(1, Ordering::Relaxed);
         Ok(None)
     }
 
@@ -214,6 +224,16 @@ impl CacheManager {
         self.put(key, data, metadata).await
     }
 
+    /// Hit, miss and eviction counters since the cache manager was created
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            evictions: self.file_cache.evictions(),
+            disk_usage: self.file_cache.usage(),
+        }
+    }
+
     /// Get configuration reference
     pub fn config(&self) -> &CacheConfig {
         &self.config
@@ -266,6 +286,31 @@ mod tests {
         assert!(manager.is_ok());
     }
 
+    #[tokio::test]
+    async fn stats_count_hits_and_misses() {
+        let dir = std::env::temp_dir().join(format!("mesio-cache-stats-{}", std::process::id()));
+        let manager = CacheManager::new(CacheConfig {
+            disk_cache_path: Some(dir.clone()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+        let url = "https://example.test/segment.ts";
+        assert!(manager.get_segment(url).await.unwrap().is_none());
+        manager
+            .put_segment(url, Bytes::from_static(b"segment"))
+            .await
+            .unwrap();
+        assert!(manager.get_segment(url).await.unwrap().is_some());
+
+        let stats = manager.stats();
+        assert_eq!((stats.hits, stats.misses), (1, 1));
+        assert!(stats.disk_usage > 0);
+
+        let _ = tokio::fs::remove_dir_all(&dir).await;
+    }
+
     #[tokio::test]
     async fn from_config_errors_inside_tokio_runtime() {
         let cfg = CacheConfig {
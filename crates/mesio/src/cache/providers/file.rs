@@ -3,6 +3,9 @@
 //! This module implements a file-based persistent cache provider.
 
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::SystemTime;
 
 use bytes::Bytes;
 use tokio::fs;
@@ -20,6 +23,11 @@ pub struct FileCache {
     enabled: bool,
     /// Maximum disk cache size in bytes (0 = unlimited)
     max_size: u64,
+    /// Bytes on disk as of the last sweep, plus entries written since
+    usage: Arc<AtomicU64>,
+    /// Entries evicted to enforce `max_size`
+    evictions: Arc<AtomicU64>,
+    sweeping: Arc<AtomicBool>,
 }
 
 impl FileCache {
@@ -38,6 +46,34 @@ impl FileCache {
             initialized: std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false)),
             enabled,
             max_size,
+            usage: Arc::new(AtomicU64::new(0)),
+            evictions: Arc::new(AtomicU64::new(0)),
+            sweeping: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// Approximate bytes the cache occupies on disk
+    pub fn usage(&self) -> u64 {
+        self.usage.load(Ordering::Relaxed)
+    }
+
+    /// Number of entries evicted to stay under the size limit
+    pub fn evictions(&self) -> u64 {
+        self.evictions.load(Ordering::Relaxed)
+    }
+
+    /// Mark an entry as recently used. Eviction orders entries by the modification time
+    /// of their data file, so a hit refreshes it.
+    async fn touch(path: PathBuf) {
+        let result = tokio::task::spawn_blocking(move || {
+            std::fs::File::options()
+                .write(true)
+                .open(&path)?
+                .set_modified(SystemTime::now())
+        })
+        .await;
+        if let Ok(Err(error)) = result {
+            debug!(%error, "Failed to refresh cache entry access time");
         }
     }
 
@@ -192,6 +228,8 @@ impl CacheProvider for FileCache {
                 Self::remove_file_best_effort(&data_path_clone, "expired cache data").await;
                 Self::remove_file_best_effort(&meta_path_clone, "expired cache metadata").await;
             });
+        } else if self.max_size > 0 {
+            Self::touch(data_path).await;
         }
         let bytes = Bytes::from(data);
 
@@ -227,8 +265,8 @@ impl CacheProvider for FileCache {
 
         // Write data and metadata atomically if possible
         // First write to temporary files then rename
-        let temp_data_path = data_path.with_extension("tmp");
-        let temp_meta_path = meta_path.with_extension("tmp");
+        let temp_data_path = data_path.with_extension("data.tmp");
+        let temp_meta_path = meta_path.with_extension("meta.tmp");
 
         // Write data file
         match fs::write(&temp_data_path, &data).await {
@@ -280,6 +318,12 @@ impl CacheProvider for FileCache {
         }
 
         debug!(key = ?key, "Successfully cached entry to file");
+
+        let entry_size = (data.len() + metadata_json.len()) as u64;
+        let usage = self.usage.fetch_add(entry_size, Ordering::Relaxed) + entry_size;
+        if self.max_size > 0 && usage > self.max_size {
+            self.sweep().await?;
+        }
         Ok(())
     }
 
@@ -372,8 +416,20 @@ impl CacheProvider for FileCache {
 
         self.ensure_initialized().await?;
 
-        // Collect all cached entries with their metadata
-        let mut entries: Vec<(PathBuf, PathBuf, u64, u64)> = Vec::new(); // (data_path, meta_path, size, cached_at)
+        // Writers that push the cache over its limit all ask for a sweep; one is enough
+        if self.sweeping.swap(true, Ordering::Acquire) {
+            return Ok(());
+        }
+        let result = self.evict_least_recently_used().await;
+        self.sweeping.store(false, Ordering::Release);
+        result
+    }
+}
+
+impl FileCache {
+    async fn evict_least_recently_used(&self) -> CacheResult<()> {
+        // Collect all cached entries with the time they were last used
+        let mut entries: Vec<(PathBuf, PathBuf, u64, SystemTime)> = Vec::new(); // (data_path, meta_path, size, last_used)
         let mut total_size: u64 = 0;
 
         // Scan all subdirectories for cache entries
@@ -411,9 +467,9 @@ impl CacheProvider for FileCache {
 
                 let meta_path = path.with_extension("meta");
 
-                // Get file size
-                let file_size = match fs::metadata(&path).await {
-                    Ok(m) => m.len(),
+                // Get file size and last use
+                let (file_size, last_used) = match fs::metadata(&path).await {
+                    Ok(m) => (m.len(), m.modified().unwrap_or(SystemTime::UNIX_EPOCH)),
                     Err(_) => continue,
                 };
 
@@ -422,24 +478,14 @@ impl CacheProvider for FileCache {
                     Err(_) => continue, // Skip entries without valid metadata
                 };
 
-                // Read metadata to get cached_at timestamp
-                let metadata_bytes = match fs::read(&meta_path).await {
-                    Ok(bytes) => bytes,
-                    Err(_) => continue,
-                };
-
-                let metadata: CacheMetadata = match serde_json::from_slice(&metadata_bytes) {
-                    Ok(m) => m,
-                    Err(_) => continue, // Skip entries with invalid metadata
-                };
-
                 let entry_size = file_size + meta_size;
                 total_size += entry_size;
-                entries.push((path, meta_path, entry_size, metadata.cached_at));
+                entries.push((path, meta_path, entry_size, last_used));
             }
         }
 
         // Check if we're over the limit
+        self.usage.store(total_size, Ordering::Relaxed);
         if total_size <= self.max_size {
             debug!(
                 total_size = total_size,
@@ -449,8 +495,8 @@ impl CacheProvider for FileCache {
             return Ok(());
         }
 
-        // Sort by cached_at (oldest first) for LRU eviction
-        entries.sort_by_key(|(_, _, _, cached_at)| *cached_at);
+        // Least recently used first
+        entries.sort_by_key(|(_, _, _, last_used)| *last_used);
 
         // Target 80% of max_size to avoid constant eviction cycles
         let target_size = (self.max_size as f64 * 0.8) as u64;
@@ -480,6 +526,8 @@ impl CacheProvider for FileCache {
             evicted_count += 1;
         }
 
+        self.usage.store(total_size, Ordering::Relaxed);
+        self.evictions.fetch_add(evicted_count, Ordering::Relaxed);
         if evicted_count > 0 {
             debug!(
                 evicted_count = evicted_count,
@@ -493,3 +541,68 @@ impl CacheProvider for FileCache {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::types::CacheResourceType;
+
+    fn temp_cache_dir(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("mesio-file-cache-{name}-{}", std::process::id()))
+    }
+
+    fn key(url: &str) -> CacheKey {
+        CacheKey::new(CacheResourceType::Segment, url, None)
+    }
+
+    async fn put(cache: &FileCache, url: &str, size: usize) {
+        let data = Bytes::from(vec![0u8; size]);
+        cache
+            .put(key(url), data, CacheMetadata::new(size as u64))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn evicts_least_recently_used_entries_over_the_limit() {
+        let dir = temp_cache_dir("lru");
+        let _ = fs::remove_dir_all(&dir).await;
+        let cache = FileCache::new(dir.clone(), true, 3500);
+
+        put(&cache, "https://example.test/a.ts", 1000).await;
+        put(&cache, "https://example.test/b.ts", 1000).await;
+        put(&cache, "https://example.test/c.ts", 1000).await;
+        // Reading `a` makes `b` the least recently used entry
+        assert!(
+            cache
+                .get(&key("https://example.test/a.ts"))
+                .await
+                .unwrap()
+                .is_some()
+        );
+        put(&cache, "https://example.test/d.ts", 1000).await;
+
+        assert!(
+            !cache
+                .contains(&key("https://example.test/b.ts"))
+                .await
+                .unwrap()
+        );
+        assert!(
+            cache
+                .contains(&key("https://example.test/a.ts"))
+                .await
+                .unwrap()
+        );
+        assert!(
+            cache
+                .contains(&key("https://example.test/d.ts"))
+                .await
+                .unwrap()
+        );
+        assert!(cache.evictions() >= 1);
+        assert!(cache.usage() <= 3500);
+
+        let _ = fs::remove_dir_all(&dir).await;
+    }
+}
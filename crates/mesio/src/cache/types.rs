@@ -223,6 +223,19 @@ impl Default for CacheConfig {
     }
 }
 
+/// Counters of a [`CacheManager`](crate::cache::CacheManager) since it was created
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// Lookups served from memory or disk
+    pub hits: u64,
+    /// Lookups that found nothing
+    pub misses: u64,
+    /// Disk entries evicted to stay under `max_disk_cache_size`
+    pub evictions: u64,
+    /// Approximate bytes the disk cache occupies
+    pub disk_usage: u64,
+}
+
 /// Result of a cache operation
 pub type CacheResult<T> = std::result::Result<T, std::io::Error>;
 
@@ -2,7 +2,10 @@ use std::time::Duration;
 
 use reqwest::header::{HeaderMap, HeaderValue};
 
-use crate::{CacheConfig, proxy::ProxyConfig};
+use crate::{
+    CacheConfig, cookies::CookieJar, header_template::HeaderTemplates, proxy::ProxyConfig,
+    retry::RetryPolicy,
+};
 
 pub const DEFAULT_USER_AGENT: &str = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/142.0.0.0 Safari/537.36";
 
@@ -51,6 +54,12 @@ pub struct DownloaderConfig {
     /// Custom HTTP headers for requests
     pub headers: HeaderMap,
 
+    /// Headers rendered for every request, e.g. a `Referer` naming the room
+    pub header_templates: HeaderTemplates,
+
+    /// Cookies sent to the domains they are scoped to, updated by `Set-Cookie` responses
+    pub cookie_jar: Option<CookieJar>,
+
     /// Custom parameters for requests
     pub params: Vec<(String, String)>,
 
@@ -71,11 +80,36 @@ pub struct DownloaderConfig {
     /// Note: With rustls-tls, HTTP/2 is automatically negotiated via ALPN
     pub http_version: HttpVersionPreference,
 
-    /// TCP keep-alive interval for maintaining long-lived connections
-    /// This helps keep HTTP/2 connections alive for multiplexing benefits
+    /// Interval of HTTP/2 PING frames that keep multiplexed connections alive
+    /// and detect dead ones. `None` disables them
     /// Recommended: 15-30 seconds for media streaming
     pub http2_keep_alive_interval: Option<Duration>,
 
+    /// Time to wait for a PING acknowledgement before closing the connection
+    /// Default: 20 seconds
+    pub http2_keep_alive_timeout: Duration,
+
+    /// Whether PINGs are also sent while no request is in flight, keeping
+    /// connections warm between playlist refreshes
+    pub http2_keep_alive_while_idle: bool,
+
+    /// Size HTTP/2 flow-control windows from the measured bandwidth-delay
+    /// product instead of the fixed 64 KiB defaults. Helps concurrent segment
+    /// downloads sharing one connection on high-latency links
+    pub http2_adaptive_window: bool,
+
+    /// Initial HTTP/2 stream-level flow-control window in bytes
+    /// Ignored with `http2_adaptive_window`. `None` uses the default
+    pub http2_initial_stream_window_size: Option<u32>,
+
+    /// Initial HTTP/2 connection-level flow-control window in bytes
+    /// Ignored with `http2_adaptive_window`. `None` uses the default
+    pub http2_initial_connection_window_size: Option<u32>,
+
+    /// TCP keep-alive interval for long-lived connections. `None` disables it
+    /// Default: 20 seconds
+    pub tcp_keepalive: Option<Duration>,
+
     // --- Connection Pool Configuration ---
     /// Maximum idle connections to keep per host
     /// Higher values improve HTTP/2 multiplexing for HLS segment downloads
@@ -86,6 +120,9 @@ pub struct DownloaderConfig {
     /// Longer timeouts improve connection reuse for streaming
     /// Default: 30 seconds
     pub pool_idle_timeout: Duration,
+
+    /// Retries of failed requests, shared by the protocol downloaders
+    pub retry: RetryPolicy,
 }
 
 impl Default for DownloaderConfig {
@@ -99,6 +136,8 @@ impl Default for DownloaderConfig {
             follow_redirects: true,
             user_agent: DEFAULT_USER_AGENT.to_owned(),
             headers: DownloaderConfig::get_default_headers(),
+            header_templates: HeaderTemplates::default(),
+            cookie_jar: None,
             params: Vec::new(),
             proxy: None,
             use_system_proxy: true,
@@ -108,9 +147,16 @@ impl Default for DownloaderConfig {
             // HTTP/2 defaults - optimized for media streaming
             http_version: HttpVersionPreference::Auto,
             http2_keep_alive_interval: Some(Duration::from_secs(20)),
+            http2_keep_alive_timeout: Duration::from_secs(20),
+            http2_keep_alive_while_idle: false,
+            http2_adaptive_window: false,
+            http2_initial_stream_window_size: None,
+            http2_initial_connection_window_size: None,
+            tcp_keepalive: Some(Duration::from_secs(20)),
             // Connection pool defaults - optimized for HLS segment downloads
             pool_max_idle_per_host: 10,
             pool_idle_timeout: Duration::from_secs(30),
+            retry: RetryPolicy::default(),
         }
     }
 }
@@ -140,6 +186,8 @@ impl DownloaderConfig {
             follow_redirects: config.follow_redirects,
             user_agent: config.user_agent,
             headers,
+            header_templates: config.header_templates,
+            cookie_jar: config.cookie_jar,
             params: config.params,
             proxy: config.proxy,
             use_system_proxy: config.use_system_proxy,
@@ -149,9 +197,16 @@ impl DownloaderConfig {
             // HTTP/2 settings
             http_version: config.http_version,
             http2_keep_alive_interval: config.http2_keep_alive_interval,
+            http2_keep_alive_timeout: config.http2_keep_alive_timeout,
+            http2_keep_alive_while_idle: config.http2_keep_alive_while_idle,
+            http2_adaptive_window: config.http2_adaptive_window,
+            http2_initial_stream_window_size: config.http2_initial_stream_window_size,
+            http2_initial_connection_window_size: config.http2_initial_connection_window_size,
+            tcp_keepalive: config.tcp_keepalive,
             // Connection pool settings
             pool_max_idle_per_host: config.pool_max_idle_per_host,
             pool_idle_timeout: config.pool_idle_timeout,
+            retry: config.retry,
         }
     }
 
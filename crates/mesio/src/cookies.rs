@@ -0,0 +1,91 @@
+//! # Cookie Jar
+//!
+//! Cookies sent with download requests, scoped to the domains they belong to. Cookies set by
+//! responses (`Set-Cookie`) are stored in the same jar, so platforms that rotate session cookies
+//! keep working for the whole recording.
+
+use std::sync::Arc;
+
+use reqwest::cookie::{CookieStore, Jar};
+use reqwest::header::HeaderValue;
+use url::Url;
+
+use crate::DownloadError;
+
+/// A shared cookie jar. Clones share their cookies.
+#[derive(Debug, Clone, Default)]
+pub struct CookieJar {
+    jar: Arc<Jar>,
+}
+
+impl CookieJar {
+    /// Create an empty cookie jar
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a cookie in `Set-Cookie` syntax as if `url` had set it. Without a `Domain`
+    /// attribute it is only sent to the host of `url`.
+    pub fn add(&self, cookie: &str, url: &Url) {
+        self.jar.add_cookie_str(cookie, url);
+    }
+
+    /// Add every `name=value` pair of a `Cookie` header value, sent to `domain` and its
+    /// subdomains.
+    pub fn add_cookie_header(&self, header: &str, domain: &str) -> Result<(), DownloadError> {
+        let domain = domain.trim_start_matches('.');
+        let url = Url::parse(&format!("https://{domain}/"))
+            .map_err(|e| DownloadError::invalid_url(domain, e.to_string()))?;
+        for pair in header
+            .split(';')
+            .map(str::trim)
+            .filter(|pair| pair.contains('='))
+        {
+            self.add(&format!("{pair}; Domain={domain}; Path=/"), &url);
+        }
+        Ok(())
+    }
+
+    /// The `Cookie` header value of a request to `url`, if any cookie applies
+    pub fn cookie_header(&self, url: &Url) -> Option<HeaderValue> {
+        self.jar.cookies(url)
+    }
+
+    pub(crate) fn provider(&self) -> Arc<Jar> {
+        Arc::clone(&self.jar)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn header(jar: &CookieJar, url: &str) -> Option<String> {
+        jar.cookie_header(&Url::parse(url).unwrap())
+            .map(|value| value.to_str().unwrap().to_string())
+    }
+
+    #[test]
+    fn cookie_header_pairs_are_scoped_to_the_domain() {
+        let jar = CookieJar::new();
+        jar.add_cookie_header("SESSDATA=abc; bili_jct=def", ".bilibili.com")
+            .unwrap();
+
+        let sent = header(&jar, "https://api.live.bilibili.com/room").unwrap();
+        assert!(sent.contains("SESSDATA=abc"));
+        assert!(sent.contains("bili_jct=def"));
+        assert_eq!(header(&jar, "https://cdn.example.com/live.flv"), None);
+    }
+
+    #[test]
+    fn host_only_cookies_do_not_reach_subdomains() {
+        let jar = CookieJar::new();
+        jar.add("token=1", &Url::parse("https://example.com/").unwrap());
+
+        assert_eq!(
+            header(&jar, "https://example.com/live.m3u8").as_deref(),
+            Some("token=1")
+        );
+        assert_eq!(header(&jar, "https://cdn.example.com/live.m3u8"), None);
+    }
+}
@@ -0,0 +1,10 @@
+// DASH downloader: MPD manifests with fMP4 segments, emitted as `HlsData`.
+
+pub mod config;
+mod dash_downloader;
+pub mod mpd;
+pub mod track;
+
+pub use config::{DashConfig, DashContentType, DashRepresentationSelection};
+pub use dash_downloader::DashDownloader;
+pub use track::{DashSegment, DashTrack};
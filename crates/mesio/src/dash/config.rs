@@ -0,0 +1,73 @@
+//! # DASH Configuration
+//!
+//! This module defines the configuration options specific to MPEG-DASH downloads.
+
+use std::time::Duration;
+
+use crate::DownloaderConfig;
+
+/// Which representation of the selected adaptation sets is downloaded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DashRepresentationSelection {
+    /// The representation with the highest bandwidth.
+    #[default]
+    Highest,
+    /// The representation with the lowest bandwidth.
+    Lowest,
+    /// The highest bandwidth not above this many bits per second, or the lowest one.
+    MaxBandwidth(u64),
+    /// The highest bandwidth not above this height in pixels, or the lowest one.
+    MaxHeight(u32),
+}
+
+/// Which adaptation sets a representation is selected from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DashContentType {
+    /// Video adaptation sets, or sets whose content type is not declared.
+    #[default]
+    Video,
+    /// Audio adaptation sets, usually recorded next to a video-only representation.
+    Audio,
+}
+
+/// Configuration for DASH downloads
+#[derive(Debug, Clone)]
+pub struct DashConfig {
+    /// Base downloader configuration; its retry policy applies to manifest and segment requests
+    pub base: DownloaderConfig,
+    pub representation_selection: DashRepresentationSelection,
+    /// Segments behind the live edge to start a live download with.
+    pub live_edge_segments: usize,
+    /// Lower bound of the refresh interval of live manifests, whatever their
+    /// `minimumUpdatePeriod`.
+    pub min_refresh_interval: Duration,
+    /// Upper bound of the refresh interval of live manifests.
+    pub max_refresh_interval: Duration,
+    /// Segments downloaded concurrently. They are still emitted in order.
+    pub download_concurrency: usize,
+    /// A live download ends with an error when no new segment appears for this long.
+    pub live_stall_timeout: Duration,
+}
+
+impl Default for DashConfig {
+    fn default() -> Self {
+        Self {
+            base: DownloaderConfig::default(),
+            representation_selection: DashRepresentationSelection::default(),
+            live_edge_segments: 3,
+            min_refresh_interval: Duration::from_millis(500),
+            max_refresh_interval: Duration::from_secs(10),
+            download_concurrency: 3,
+            live_stall_timeout: Duration::from_secs(60),
+        }
+    }
+}
+
+impl From<DownloaderConfig> for DashConfig {
+    fn from(base: DownloaderConfig) -> Self {
+        Self {
+            base,
+            ..Self::default()
+        }
+    }
+}
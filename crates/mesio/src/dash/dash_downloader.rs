@@ -0,0 +1,515 @@
+//! # DASH Downloader
+//!
+//! Downloads the selected representation of an MPEG-DASH manifest as fMP4 segments. Live
+//! manifests are refreshed and followed from the live edge; static manifests are downloaded
+//! period by period. The output is the same [`HlsData`] stream as fMP4 HLS, so DASH recordings
+//! go through the existing HLS processing pipeline.
+
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+
+use bytes::Bytes;
+use futures::{Stream, StreamExt};
+use hls::{HlsData, SplitReason};
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info, warn};
+use url::Url;
+
+use super::config::{DashConfig, DashContentType, DashRepresentationSelection};
+use super::mpd::Mpd;
+use super::track::{self, Clock, DashSegment, DashTrack};
+use crate::cache::{CacheKey, CacheManager, CacheMetadata, CacheResourceType};
+use crate::downloader::{ClientPool, create_client_pool};
+use crate::retry::RetryPolicy;
+use crate::{
+    BoxMediaStream, DownloadError, DownloadEvent, DownloadHandle, DownloadRequest, DownloadSession,
+    DownloadTerminal, EventSink, MediaEngine, ProtocolSelection, ProtocolType, ResourceId,
+};
+
+type Item = Result<HlsData, DownloadError>;
+
+struct CancelOnDropStream {
+    inner: BoxMediaStream<HlsData, DownloadError>,
+    token: CancellationToken,
+}
+
+impl Stream for CancelOnDropStream {
+    type Item = Item;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.as_mut().poll_next(cx)
+    }
+}
+
+impl Drop for CancelOnDropStream {
+    fn drop(&mut self) {
+        self.token.cancel();
+    }
+}
+
+/// DASH Downloader for the segments of one representation of an MPD manifest
+pub struct DashDownloader {
+    clients: Arc<ClientPool>,
+    config: DashConfig,
+}
+
+impl DashDownloader {
+    pub fn new() -> Result<Self, DownloadError> {
+        Self::with_config(DashConfig::default())
+    }
+
+    /// Create a new DashDownloader with custom configuration
+    pub fn with_config(config: DashConfig) -> Result<Self, DownloadError> {
+        let clients = Arc::new(create_client_pool(&config.base)?);
+        Ok(Self { clients, config })
+    }
+
+    pub fn config(&self) -> &DashConfig {
+        &self.config
+    }
+
+    pub async fn start_session(
+        &self,
+        request: DownloadRequest,
+    ) -> Result<DownloadSession<HlsData>, DownloadError> {
+        let token = request.cancel.unwrap_or_default();
+        let stream_token = token.child_token();
+        let (events, event_stream) = EventSink::channel(256);
+        events.emit(DownloadEvent::Started {
+            protocol: ProtocolType::Dash,
+            url: Arc::from(request.url.as_str()),
+        });
+
+        let options = match &request.protocol {
+            ProtocolSelection::Dash(options) => options.clone(),
+            _ => request.options.dash.clone(),
+        };
+        let fetcher = Fetcher {
+            clients: Arc::clone(&self.clients),
+            params: self.config.base.params.clone(),
+            retry: self.config.base.retry.clone(),
+            cache: request.cache.clone(),
+            events: events.clone(),
+            bytes_total: Arc::new(AtomicU64::new(0)),
+        };
+
+        // Fetch the manifest up front so that a dead or invalid URL fails the start
+        let (mpd, manifest_url) = tokio::select! {
+            _ = stream_token.cancelled() => return Err(DownloadError::Cancelled),
+            manifest = fetcher.fetch_manifest(&request.url) => manifest?,
+        };
+        let recorder = Recorder {
+            fetcher,
+            config: self.config.clone(),
+            content_type: options.content_type,
+            selection: options
+                .representation_selection
+                .unwrap_or(self.config.representation_selection),
+            manifest_url,
+            token: stream_token.clone(),
+        };
+        let first_tracks = recorder.tracks(&mpd)?;
+        info!(
+            url = %request.url,
+            live = mpd.is_dynamic(),
+            representation = %first_tracks[0].representation_id,
+            bandwidth = first_tracks[0].bandwidth,
+            "Starting DASH download"
+        );
+
+        let (tx, rx) = mpsc::channel(self.config.download_concurrency.max(1) * 2);
+        let lifecycle = tokio::spawn(async move {
+            let terminal = recorder.run(mpd, first_tracks, &tx).await;
+            debug!(?terminal, "DASH download finished");
+            terminal
+        });
+
+        let stream: BoxMediaStream<HlsData, DownloadError> = Box::pin(CancelOnDropStream {
+            inner: ReceiverStream::new(rx).boxed(),
+            token: stream_token.clone(),
+        });
+        Ok(DownloadSession {
+            items: stream,
+            events: event_stream,
+            handle: DownloadHandle::new(
+                stream_token,
+                None,
+                events.dropped_counter(),
+                Some(lifecycle),
+            ),
+        })
+    }
+}
+
+impl MediaEngine for DashDownloader {
+    type Item = HlsData;
+
+    async fn start(
+        &self,
+        mut request: DownloadRequest,
+    ) -> Result<DownloadSession<Self::Item>, DownloadError> {
+        if matches!(request.protocol, ProtocolSelection::Auto) {
+            request.protocol = ProtocolSelection::Dash(Default::default());
+        }
+        self.start_session(request).await
+    }
+}
+
+/// HTTP requests of a DASH download, with retries.
+struct Fetcher {
+    clients: Arc<ClientPool>,
+    params: Vec<(String, String)>,
+    retry: RetryPolicy,
+    cache: Option<Arc<CacheManager>>,
+    events: EventSink,
+    bytes_total: Arc<AtomicU64>,
+}
+
+impl Fetcher {
+    /// Fetch and parse the manifest. The returned URL is the one after redirects, which
+    /// relative segment URLs are resolved against.
+    async fn fetch_manifest(&self, url: &Url) -> Result<(Mpd, Url), DownloadError> {
+        let resource = ResourceId::DashManifest {
+            url: Arc::from(url.as_str()),
+        };
+        let (bytes, final_url) = self.fetch(url, &resource).await?;
+        let text = std::str::from_utf8(&bytes).map_err(|e| DownloadError::InvalidContent {
+            protocol: "dash",
+            reason: format!("manifest is not UTF-8: {e}"),
+        })?;
+        Ok((Mpd::parse(text)?, final_url))
+    }
+
+    /// Fetch a segment, from the cache when it holds the URL. Segment URLs of a
+    /// representation are unique, so the URL alone keys them.
+    async fn fetch_segment(&self, url: &Url) -> Result<Bytes, DownloadError> {
+        let resource = ResourceId::DashSegment {
+            url: Arc::from(url.as_str()),
+        };
+        let cache_key = CacheKey::new(CacheResourceType::Segment, url.as_str(), None);
+        if let Some(cache) = &self.cache
+            && let Ok(Some((bytes, _, _))) = cache.get(&cache_key).await
+        {
+            debug!(url = %url, "DASH segment served from cache");
+            self.events.emit(DownloadEvent::ResourceFinished {
+                resource,
+                bytes: bytes.len() as u64,
+                from_cache: true,
+            });
+            return Ok(bytes);
+        }
+
+        let (bytes, _) = self.fetch(url, &resource).await?;
+        if let Some(cache) = &self.cache {
+            let metadata = CacheMetadata::new(bytes.len() as u64)
+                .with_expiration_by_type(CacheResourceType::Segment, cache.config());
+            if let Err(e) = cache.put(cache_key, bytes.clone(), metadata).await {
+                warn!(url = %url, "failed to cache DASH segment: {e}");
+            }
+        }
+        self.events.emit(DownloadEvent::Progress {
+            resource,
+            bytes_delta: bytes.len() as u64,
+            bytes_total: self
+                .bytes_total
+                .fetch_add(bytes.len() as u64, Ordering::Relaxed)
+                + bytes.len() as u64,
+        });
+        Ok(bytes)
+    }
+
+    async fn fetch(&self, url: &Url, resource: &ResourceId) -> Result<(Bytes, Url), DownloadError> {
+        let mut attempt = 0;
+        loop {
+            match self.fetch_once(url, resource).await {
+                Ok(fetched) => return Ok(fetched),
+                Err(e) if attempt < self.retry.max_retries && self.retry.should_retry(&e) => {
+                    attempt += 1;
+                    let delay = self.retry.delay(attempt);
+                    debug!(url = %url, attempt, error = %e, "Retrying DASH request");
+                    self.events.emit(DownloadEvent::RetryScheduled {
+                        resource: Some(resource.clone()),
+                        attempt,
+                        delay,
+                        reason: Arc::from(e.to_string()),
+                    });
+                    tokio::time::sleep(delay).await;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    async fn fetch_once(
+        &self,
+        url: &Url,
+        resource: &ResourceId,
+    ) -> Result<(Bytes, Url), DownloadError> {
+        let response = self.clients.get(url).query(&self.params).send().await?;
+        if !response.status().is_success() {
+            return Err(DownloadError::http_status(
+                response.status(),
+                url.to_string(),
+                "dash_request",
+            ));
+        }
+        self.events.emit(DownloadEvent::ResourceStarted {
+            resource: resource.clone(),
+            display_url: Arc::from(url.as_str()),
+            content_length: response.content_length(),
+        });
+        let final_url = response.url().clone();
+        let bytes = response.bytes().await?;
+        self.events.emit(DownloadEvent::ResourceFinished {
+            resource: resource.clone(),
+            bytes: bytes.len() as u64,
+            from_cache: false,
+        });
+        Ok((bytes, final_url))
+    }
+}
+
+/// Why the download loop stopped early.
+enum Stop {
+    Terminal(DownloadTerminal),
+    Error(DownloadError),
+}
+
+/// Where the download is in the manifest, across refreshes.
+#[derive(Default)]
+struct Progress {
+    period: Option<String>,
+    init_url: Option<Url>,
+    /// Position of the last segment emitted in the current period.
+    position: Option<u64>,
+    last_new_segment: Option<Instant>,
+}
+
+struct Recorder {
+    fetcher: Fetcher,
+    config: DashConfig,
+    content_type: DashContentType,
+    selection: DashRepresentationSelection,
+    manifest_url: Url,
+    token: CancellationToken,
+}
+
+impl Recorder {
+    fn tracks(&self, mpd: &Mpd) -> Result<Vec<DashTrack>, DownloadError> {
+        track::select_tracks(
+            mpd,
+            &self.manifest_url,
+            self.content_type,
+            self.selection,
+            Clock::system(),
+        )
+    }
+
+    async fn run(
+        mut self,
+        mut mpd: Mpd,
+        mut tracks: Vec<DashTrack>,
+        tx: &mpsc::Sender<Item>,
+    ) -> DownloadTerminal {
+        let mut progress = Progress::default();
+        let mut first = true;
+        loop {
+            for track in &tracks {
+                let live = mpd.is_dynamic();
+                match self
+                    .download_track(track, live, first, &mut progress, tx)
+                    .await
+                {
+                    Ok(()) => {}
+                    Err(stop) => return self.stop(stop, tx).await,
+                }
+            }
+            first = false;
+
+            if !mpd.is_dynamic() {
+                let marker = HlsData::end_marker_with_reason(SplitReason::EndOfStream);
+                if tx.send(Ok(marker)).await.is_err() {
+                    return DownloadTerminal::DownstreamClosed;
+                }
+                return DownloadTerminal::AuthoritativeEnd;
+            }
+
+            let stalled = progress
+                .last_new_segment
+                .is_some_and(|last| last.elapsed() >= self.config.live_stall_timeout);
+            if stalled {
+                let error = DownloadError::Timeout {
+                    reason: format!(
+                        "no new DASH segment for {:?}",
+                        self.config.live_stall_timeout
+                    ),
+                };
+                return self.stop(Stop::Error(error), tx).await;
+            }
+
+            let interval = self.refresh_interval(&mpd, &tracks);
+            tokio::select! {
+                _ = self.token.cancelled() => return DownloadTerminal::Cancelled,
+                _ = tokio::time::sleep(interval) => {}
+            }
+
+            let refreshed = tokio::select! {
+                _ = self.token.cancelled() => return DownloadTerminal::Cancelled,
+                manifest = self.fetcher.fetch_manifest(&self.manifest_url) => manifest,
+            };
+            let refreshed = refreshed.and_then(|(refreshed, manifest_url)| {
+                self.manifest_url = manifest_url;
+                let refreshed_tracks = self.tracks(&refreshed)?;
+                Ok((refreshed, refreshed_tracks))
+            });
+            match refreshed {
+                Ok((refreshed, refreshed_tracks)) => {
+                    mpd = refreshed;
+                    tracks = refreshed_tracks;
+                }
+                Err(e) => return self.stop(Stop::Error(e), tx).await,
+            }
+        }
+    }
+
+    async fn stop(&self, stop: Stop, tx: &mpsc::Sender<Item>) -> DownloadTerminal {
+        match stop {
+            Stop::Terminal(terminal) => terminal,
+            Stop::Error(DownloadError::Cancelled) => DownloadTerminal::Cancelled,
+            Stop::Error(e) => {
+                warn!(error = %e, "DASH download failed");
+                let reason = Arc::from(e.to_string());
+                let _ = tx.send(Err(e)).await;
+                DownloadTerminal::PipelineError(reason)
+            }
+        }
+    }
+
+    /// The refresh interval of a live manifest: its `minimumUpdatePeriod`, or the duration of
+    /// its last segment.
+    fn refresh_interval(&self, mpd: &Mpd, tracks: &[DashTrack]) -> Duration {
+        let interval = mpd.minimum_update_period().unwrap_or_else(|| {
+            tracks
+                .last()
+                .and_then(|track| track.segments.last())
+                .and_then(|segment| Duration::try_from_secs_f64(segment.duration).ok())
+                .unwrap_or(self.config.max_refresh_interval)
+        });
+        interval.clamp(
+            self.config.min_refresh_interval,
+            self.config
+                .max_refresh_interval
+                .max(self.config.min_refresh_interval),
+        )
+    }
+
+    async fn download_track(
+        &self,
+        track: &DashTrack,
+        live: bool,
+        first: bool,
+        progress: &mut Progress,
+        tx: &mpsc::Sender<Item>,
+    ) -> Result<(), Stop> {
+        let period_changed = progress.period.as_deref() != Some(track.period.as_str());
+        if period_changed {
+            if progress.period.is_some() {
+                debug!(period = %track.period, "DASH period changed");
+                send(
+                    tx,
+                    HlsData::end_marker_with_reason(SplitReason::Discontinuity),
+                )
+                .await?;
+            }
+            progress.period = Some(track.period.clone());
+            progress.init_url = None;
+            progress.position = None;
+            if live && first {
+                // Start a few segments behind the live edge rather than at the oldest one
+                let skip = track
+                    .segments
+                    .len()
+                    .saturating_sub(self.config.live_edge_segments.max(1));
+                if skip > 0 {
+                    progress.position = Some(track.segments[skip - 1].position);
+                }
+            }
+        }
+
+        if track.init_url != progress.init_url {
+            if progress.init_url.is_some() {
+                debug!(representation = %track.representation_id, "DASH initialization changed");
+                send(
+                    tx,
+                    HlsData::end_marker_with_reason(SplitReason::Discontinuity),
+                )
+                .await?;
+            }
+            if let Some(url) = &track.init_url {
+                let data = self.fetch(url).await.map_err(Stop::Error)?;
+                send(tx, HlsData::mp4_init(media_segment(url, 0.0), data)).await?;
+            }
+            progress.init_url = track.init_url.clone();
+        }
+
+        let new_segments: Vec<DashSegment> = track
+            .segments
+            .iter()
+            .filter(|segment| progress.position.is_none_or(|last| segment.position > last))
+            .cloned()
+            .collect();
+        if new_segments.is_empty() {
+            return Ok(());
+        }
+        progress.last_new_segment = Some(Instant::now());
+
+        let fetches = new_segments.into_iter().map(|segment| async move {
+            let fetched = self.fetch(&segment.url).await;
+            (segment, fetched)
+        });
+        let mut fetches =
+            futures::stream::iter(fetches).buffered(self.config.download_concurrency.max(1));
+        while let Some((segment, fetched)) = fetches.next().await {
+            progress.position = Some(segment.position);
+            match fetched {
+                Ok(data) => {
+                    let item =
+                        HlsData::mp4_segment(media_segment(&segment.url, segment.duration), data);
+                    send(tx, item).await?;
+                }
+                // A live segment can leave the time shift buffer before it is fetched
+                Err(e) if live && !matches!(e, DownloadError::Cancelled) => {
+                    warn!(url = %segment.url, error = %e, "Skipping DASH segment");
+                }
+                Err(e) => return Err(Stop::Error(e)),
+            }
+        }
+        Ok(())
+    }
+
+    async fn fetch(&self, url: &Url) -> Result<Bytes, DownloadError> {
+        tokio::select! {
+            _ = self.token.cancelled() => Err(DownloadError::Cancelled),
+            fetched = self.fetcher.fetch_segment(url) => fetched,
+        }
+    }
+}
+
+async fn send(tx: &mpsc::Sender<Item>, item: HlsData) -> Result<(), Stop> {
+    tx.send(Ok(item))
+        .await
+        .map_err(|_| Stop::Terminal(DownloadTerminal::DownstreamClosed))
+}
+
+fn media_segment(url: &Url, duration: f64) -> m3u8_rs::MediaSegment {
+    m3u8_rs::MediaSegment {
+        uri: url.to_string(),
+        duration: duration as f32,
+        ..Default::default()
+    }
+}
@@ -0,0 +1,418 @@
+//! MPEG-DASH manifest (MPD) model.
+//!
+//! Only what is needed to enumerate the segments of a representation is modelled: periods,
+//! adaptation sets, representations, `BaseURL`s and the three segment addressing schemes
+//! (`SegmentTemplate` with or without `SegmentTimeline`, and `SegmentList`). Everything else in
+//! the manifest is ignored.
+
+use std::time::Duration;
+
+use serde::Deserialize;
+
+use crate::DownloadError;
+
+/// The root `MPD` element.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Mpd {
+    /// `static` (VOD) or `dynamic` (live).
+    #[serde(rename = "@type", default)]
+    pub mpd_type: Option<String>,
+    #[serde(rename = "@mediaPresentationDuration", default)]
+    pub media_presentation_duration: Option<String>,
+    #[serde(rename = "@minimumUpdatePeriod", default)]
+    pub minimum_update_period: Option<String>,
+    #[serde(rename = "@availabilityStartTime", default)]
+    pub availability_start_time: Option<String>,
+    #[serde(rename = "@timeShiftBufferDepth", default)]
+    pub time_shift_buffer_depth: Option<String>,
+    #[serde(rename = "BaseURL", default)]
+    pub base_urls: Vec<String>,
+    #[serde(rename = "Period", default)]
+    pub periods: Vec<Period>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Period {
+    #[serde(rename = "@id", default)]
+    pub id: Option<String>,
+    #[serde(rename = "@start", default)]
+    pub start: Option<String>,
+    #[serde(rename = "@duration", default)]
+    pub duration: Option<String>,
+    #[serde(rename = "BaseURL", default)]
+    pub base_urls: Vec<String>,
+    #[serde(rename = "SegmentTemplate", default)]
+    pub segment_template: Option<SegmentTemplate>,
+    #[serde(rename = "AdaptationSet", default)]
+    pub adaptation_sets: Vec<AdaptationSet>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct AdaptationSet {
+    #[serde(rename = "@id", default)]
+    pub id: Option<String>,
+    #[serde(rename = "@contentType", default)]
+    pub content_type: Option<String>,
+    #[serde(rename = "@mimeType", default)]
+    pub mime_type: Option<String>,
+    #[serde(rename = "@codecs", default)]
+    pub codecs: Option<String>,
+    #[serde(rename = "@lang", default)]
+    pub lang: Option<String>,
+    #[serde(rename = "BaseURL", default)]
+    pub base_urls: Vec<String>,
+    #[serde(rename = "SegmentTemplate", default)]
+    pub segment_template: Option<SegmentTemplate>,
+    #[serde(rename = "SegmentList", default)]
+    pub segment_list: Option<SegmentList>,
+    #[serde(rename = "Representation", default)]
+    pub representations: Vec<Representation>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Representation {
+    #[serde(rename = "@id", default)]
+    pub id: String,
+    #[serde(rename = "@bandwidth", default)]
+    pub bandwidth: u64,
+    #[serde(rename = "@width", default)]
+    pub width: Option<u32>,
+    #[serde(rename = "@height", default)]
+    pub height: Option<u32>,
+    #[serde(rename = "@mimeType", default)]
+    pub mime_type: Option<String>,
+    #[serde(rename = "@codecs", default)]
+    pub codecs: Option<String>,
+    #[serde(rename = "BaseURL", default)]
+    pub base_urls: Vec<String>,
+    #[serde(rename = "SegmentTemplate", default)]
+    pub segment_template: Option<SegmentTemplate>,
+    #[serde(rename = "SegmentList", default)]
+    pub segment_list: Option<SegmentList>,
+}
+
+/// `SegmentTemplate`. Every attribute is optional so that templates of the period, the
+/// adaptation set and the representation can be merged.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct SegmentTemplate {
+    #[serde(rename = "@media", default)]
+    pub media: Option<String>,
+    #[serde(rename = "@initialization", default)]
+    pub initialization: Option<String>,
+    #[serde(rename = "@timescale", default)]
+    pub timescale: Option<u64>,
+    #[serde(rename = "@startNumber", default)]
+    pub start_number: Option<u64>,
+    #[serde(rename = "@duration", default)]
+    pub duration: Option<u64>,
+    #[serde(rename = "@presentationTimeOffset", default)]
+    pub presentation_time_offset: Option<u64>,
+    #[serde(rename = "SegmentTimeline", default)]
+    pub segment_timeline: Option<SegmentTimeline>,
+}
+
+impl SegmentTemplate {
+    /// Fill the attributes missing here from the template of the enclosing element.
+    pub fn inherit(self, parent: Option<&SegmentTemplate>) -> SegmentTemplate {
+        let Some(parent) = parent else {
+            return self;
+        };
+        SegmentTemplate {
+            media: self.media.or_else(|| parent.media.clone()),
+            initialization: self
+                .initialization
+                .or_else(|| parent.initialization.clone()),
+            timescale: self.timescale.or(parent.timescale),
+            start_number: self.start_number.or(parent.start_number),
+            duration: self.duration.or(parent.duration),
+            presentation_time_offset: self
+                .presentation_time_offset
+                .or(parent.presentation_time_offset),
+            segment_timeline: self
+                .segment_timeline
+                .or_else(|| parent.segment_timeline.clone()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct SegmentTimeline {
+    #[serde(rename = "S", default)]
+    pub entries: Vec<TimelineEntry>,
+}
+
+/// An `S` element: `r + 1` segments of duration `d`, the first starting at `t`.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct TimelineEntry {
+    #[serde(rename = "@t", default)]
+    pub t: Option<u64>,
+    #[serde(rename = "@d")]
+    pub d: u64,
+    /// Repeat count; `-1` repeats until the next entry or the end of the period.
+    #[serde(rename = "@r", default)]
+    pub r: Option<i64>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct SegmentList {
+    #[serde(rename = "@timescale", default)]
+    pub timescale: Option<u64>,
+    #[serde(rename = "@duration", default)]
+    pub duration: Option<u64>,
+    #[serde(rename = "@startNumber", default)]
+    pub start_number: Option<u64>,
+    #[serde(rename = "Initialization", default)]
+    pub initialization: Option<SegmentListInitialization>,
+    #[serde(rename = "SegmentURL", default)]
+    pub segment_urls: Vec<SegmentUrl>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct SegmentListInitialization {
+    #[serde(rename = "@sourceURL", default)]
+    pub source_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct SegmentUrl {
+    #[serde(rename = "@media", default)]
+    pub media: Option<String>,
+}
+
+impl Mpd {
+    pub fn parse(text: &str) -> Result<Self, DownloadError> {
+        quick_xml::de::from_str(text).map_err(|e| DownloadError::Playlist {
+            reason: format!("invalid DASH manifest: {e}"),
+        })
+    }
+
+    /// Whether this is a live manifest that is refreshed for new segments.
+    pub fn is_dynamic(&self) -> bool {
+        self.mpd_type.as_deref() == Some("dynamic")
+    }
+
+    pub fn minimum_update_period(&self) -> Option<Duration> {
+        self.minimum_update_period
+            .as_deref()
+            .and_then(parse_duration)
+    }
+
+    pub fn time_shift_buffer_depth(&self) -> Option<Duration> {
+        self.time_shift_buffer_depth
+            .as_deref()
+            .and_then(parse_duration)
+    }
+
+    /// Availability start time of a live manifest, as seconds since the Unix epoch.
+    pub fn availability_start_time(&self) -> Option<f64> {
+        self.availability_start_time
+            .as_deref()
+            .and_then(parse_date_time)
+    }
+
+    /// Duration of the period at `index`, from its own duration, the start of the next period
+    /// or the duration of the presentation.
+    pub fn period_duration(&self, index: usize) -> Option<Duration> {
+        let period = self.periods.get(index)?;
+        if let Some(duration) = period.duration.as_deref().and_then(parse_duration) {
+            return Some(duration);
+        }
+        let start = period
+            .start
+            .as_deref()
+            .and_then(parse_duration)
+            .unwrap_or_default();
+        let end = match self.periods.get(index + 1) {
+            Some(next) => next.start.as_deref().and_then(parse_duration)?,
+            None => self
+                .media_presentation_duration
+                .as_deref()
+                .and_then(parse_duration)?,
+        };
+        end.checked_sub(start)
+    }
+}
+
+impl Period {
+    pub fn start(&self) -> Duration {
+        self.start
+            .as_deref()
+            .and_then(parse_duration)
+            .unwrap_or_default()
+    }
+}
+
+impl AdaptationSet {
+    /// The content type, from `contentType` or the MIME type of the set or its first
+    /// representation.
+    pub fn media_kind(&self) -> Option<&str> {
+        if let Some(content_type) = self.content_type.as_deref() {
+            return Some(content_type);
+        }
+        let mime_type = self.mime_type.as_deref().or_else(|| {
+            self.representations
+                .first()
+                .and_then(|representation| representation.mime_type.as_deref())
+        })?;
+        mime_type.split('/').next()
+    }
+}
+
+/// Parse an `xs:duration` such as `PT1H2M3.5S` or `P1DT2H`.
+///
+/// Years and months have no fixed length and are not supported.
+pub fn parse_duration(value: &str) -> Option<Duration> {
+    let rest = value.trim().strip_prefix('P')?;
+    let (date, time) = match rest.split_once('T') {
+        Some((date, time)) => (date, time),
+        None => (rest, ""),
+    };
+
+    let mut seconds = 0.0;
+    let mut number = String::new();
+    for (part, is_time) in [(date, false), (time, true)] {
+        for c in part.chars() {
+            if c.is_ascii_digit() || c == '.' {
+                number.push(c);
+                continue;
+            }
+            let value: f64 = number.parse().ok()?;
+            number.clear();
+            seconds += value
+                * match (c, is_time) {
+                    ('D', false) => 86_400.0,
+                    ('H', true) => 3_600.0,
+                    ('M', true) => 60.0,
+                    ('S', true) => 1.0,
+                    _ => return None,
+                };
+        }
+        if !number.is_empty() {
+            return None;
+        }
+    }
+    Duration::try_from_secs_f64(seconds).ok()
+}
+
+/// Parse an `xs:dateTime` such as `2024-05-01T12:00:00.5Z` into seconds since the Unix epoch.
+///
+/// A missing time zone is taken as UTC.
+pub fn parse_date_time(value: &str) -> Option<f64> {
+    let value = value.trim();
+    let (date, time) = value.split_once('T')?;
+    let mut date_parts = date.splitn(3, '-');
+    let year: i64 = date_parts.next()?.parse().ok()?;
+    let month: i64 = date_parts.next()?.parse().ok()?;
+    let day: i64 = date_parts.next()?.parse().ok()?;
+
+    let (time, offset_seconds) = if let Some(time) = time.strip_suffix('Z') {
+        (time, 0)
+    } else if let Some(sign_index) = time.rfind(['+', '-']) {
+        let (time, offset) = time.split_at(sign_index);
+        let sign = if offset.starts_with('-') { -1 } else { 1 };
+        let (hours, minutes) = offset[1..].split_once(':')?;
+        let hours: i64 = hours.parse().ok()?;
+        let minutes: i64 = minutes.parse().ok()?;
+        (time, sign * (hours * 3_600 + minutes * 60))
+    } else {
+        (time, 0)
+    };
+    let mut time_parts = time.splitn(3, ':');
+    let hour: i64 = time_parts.next()?.parse().ok()?;
+    let minute: i64 = time_parts.next()?.parse().ok()?;
+    let second: f64 = time_parts.next()?.parse().ok()?;
+
+    let days = days_from_civil(year, month, day);
+    Some((days * 86_400 + hour * 3_600 + minute * 60 - offset_seconds) as f64 + second)
+}
+
+/// Days since 1970-01-01 of a proleptic Gregorian date.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month_index = (month + 9) % 12;
+    let day_of_year = (153 * month_index + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<MPD xmlns="urn:mpeg:dash:schema:mpd:2011" type="dynamic" minimumUpdatePeriod="PT2S"
+     availabilityStartTime="2024-05-01T12:00:00Z" timeShiftBufferDepth="PT30S">
+  <BaseURL>https://cdn.example.com/live/</BaseURL>
+  <Period id="p0" start="PT0S">
+    <AdaptationSet contentType="video" mimeType="video/mp4">
+      <SegmentTemplate timescale="90000" initialization="$RepresentationID$/init.mp4"
+                       media="$RepresentationID$/$Time$.m4s"/>
+      <Representation id="1080p" bandwidth="6000000" width="1920" height="1080">
+        <SegmentTemplate>
+          <SegmentTimeline>
+            <S t="0" d="180000" r="2"/>
+            <S d="90000"/>
+          </SegmentTimeline>
+        </SegmentTemplate>
+      </Representation>
+      <Representation id="720p" bandwidth="3000000" width="1280" height="720"/>
+    </AdaptationSet>
+    <AdaptationSet mimeType="audio/mp4" lang="en">
+      <Representation id="audio" bandwidth="128000"/>
+    </AdaptationSet>
+  </Period>
+</MPD>"#;
+
+    #[test]
+    fn parses_manifest_structure() {
+        let mpd = Mpd::parse(MANIFEST).unwrap();
+        assert!(mpd.is_dynamic());
+        assert_eq!(mpd.minimum_update_period(), Some(Duration::from_secs(2)));
+        assert_eq!(mpd.base_urls, ["https://cdn.example.com/live/"]);
+
+        let period = &mpd.periods[0];
+        assert_eq!(period.adaptation_sets.len(), 2);
+        let video = &period.adaptation_sets[0];
+        assert_eq!(video.media_kind(), Some("video"));
+        assert_eq!(video.representations[0].height, Some(1080));
+        assert_eq!(period.adaptation_sets[1].media_kind(), Some("audio"));
+
+        let template = video.representations[0]
+            .segment_template
+            .clone()
+            .unwrap()
+            .inherit(video.segment_template.as_ref());
+        assert_eq!(template.timescale, Some(90000));
+        assert_eq!(
+            template.media.as_deref(),
+            Some("$RepresentationID$/$Time$.m4s")
+        );
+        let timeline = template.segment_timeline.unwrap();
+        assert_eq!(timeline.entries[0].r, Some(2));
+        assert_eq!(timeline.entries[1].t, None);
+    }
+
+    #[test]
+    fn parses_durations_and_date_times() {
+        assert_eq!(
+            parse_duration("PT1H2M3.5S"),
+            Some(Duration::from_secs_f64(3723.5))
+        );
+        assert_eq!(parse_duration("P1DT1S"), Some(Duration::from_secs(86_401)));
+        assert_eq!(parse_duration("PT"), Some(Duration::ZERO));
+        assert_eq!(parse_duration("P1Y"), None);
+        assert_eq!(parse_duration("1S"), None);
+
+        assert_eq!(parse_date_time("1970-01-01T00:00:00Z"), Some(0.0));
+        assert_eq!(
+            parse_date_time("2024-05-01T12:00:00Z"),
+            Some(1_714_564_800.0)
+        );
+        assert_eq!(
+            parse_date_time("2024-05-01T14:00:00.5+02:00"),
+            Some(1_714_564_800.5)
+        );
+    }
+}
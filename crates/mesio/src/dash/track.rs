@@ -0,0 +1,567 @@
+//! Representation selection and segment enumeration.
+//!
+//! A [`DashTrack`] is the list of segments of the selected representation in one manifest.
+//! Segments are identified by a position that grows monotonically across manifest refreshes:
+//! the segment start time for `SegmentTimeline`s, the segment number otherwise.
+
+use url::Url;
+
+use super::config::{DashContentType, DashRepresentationSelection};
+use super::mpd::{AdaptationSet, Mpd, Period, Representation, SegmentList, SegmentTemplate};
+use crate::DownloadError;
+
+/// Upper bound of the segments enumerated from one manifest, against `r="-1"` timelines or
+/// live templates with bogus timing.
+const MAX_SEGMENTS: usize = 100_000;
+
+/// A segment of the selected representation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DashSegment {
+    pub position: u64,
+    pub url: Url,
+    /// Duration in seconds.
+    pub duration: f64,
+}
+
+/// The selected representation of the current period of a manifest.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DashTrack {
+    /// Identity of the period, to detect period changes across refreshes.
+    pub period: String,
+    pub representation_id: String,
+    pub bandwidth: u64,
+    pub init_url: Option<Url>,
+    pub segments: Vec<DashSegment>,
+}
+
+/// Wall clock time the segments of a live manifest are computed for.
+#[derive(Debug, Clone, Copy)]
+pub struct Clock {
+    /// Seconds since the Unix epoch.
+    pub now: f64,
+}
+
+impl Clock {
+    pub fn system() -> Self {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_secs_f64())
+            .unwrap_or_default();
+        Self { now }
+    }
+}
+
+/// Select the representation to download from every period of `mpd`.
+///
+/// Live manifests only yield their last period, the one being produced.
+pub fn select_tracks(
+    mpd: &Mpd,
+    manifest_url: &Url,
+    content_type: DashContentType,
+    selection: DashRepresentationSelection,
+    clock: Clock,
+) -> Result<Vec<DashTrack>, DownloadError> {
+    if mpd.periods.is_empty() {
+        return Err(DownloadError::Playlist {
+            reason: "DASH manifest has no period".to_string(),
+        });
+    }
+    let mpd_base = join_base(manifest_url, &mpd.base_urls)?;
+    let first_period = if mpd.is_dynamic() {
+        mpd.periods.len() - 1
+    } else {
+        0
+    };
+
+    (first_period..mpd.periods.len())
+        .map(|index| {
+            let period = &mpd.periods[index];
+            let (set, representation) = select_representation(period, content_type, selection)
+                .ok_or_else(|| DownloadError::Playlist {
+                    reason: format!("DASH period has no {content_type:?} representation"),
+                })?;
+            let base = join_base(&mpd_base, &period.base_urls)?;
+            let base = join_base(&base, &set.base_urls)?;
+            let base = join_base(&base, &representation.base_urls)?;
+            let timing = PeriodTiming {
+                dynamic: mpd.is_dynamic(),
+                now: clock.now,
+                availability_start: mpd.availability_start_time(),
+                period_start: period.start().as_secs_f64(),
+                period_duration: mpd.period_duration(index).map(|d| d.as_secs_f64()),
+                time_shift_buffer_depth: mpd.time_shift_buffer_depth().map(|d| d.as_secs_f64()),
+            };
+            let (init_url, segments) = enumerate(period, set, representation, &base, &timing)?;
+            Ok(DashTrack {
+                period: period.id.clone().unwrap_or_else(|| index.to_string()),
+                representation_id: representation.id.clone(),
+                bandwidth: representation.bandwidth,
+                init_url,
+                segments,
+            })
+        })
+        .collect()
+}
+
+fn select_representation(
+    period: &Period,
+    content_type: DashContentType,
+    selection: DashRepresentationSelection,
+) -> Option<(&AdaptationSet, &Representation)> {
+    let wanted = |set: &AdaptationSet| match (content_type, set.media_kind()) {
+        (DashContentType::Audio, kind) => kind == Some("audio"),
+        (DashContentType::Video, Some("video") | None) => true,
+        (DashContentType::Video, _) => false,
+    };
+    let mut candidates: Vec<_> = period
+        .adaptation_sets
+        .iter()
+        .filter(|set| wanted(set))
+        .flat_map(|set| {
+            set.representations
+                .iter()
+                .map(move |representation| (set, representation))
+        })
+        .collect();
+    candidates.sort_by_key(|(_, representation)| representation.bandwidth);
+
+    let within = |representation: &Representation| match selection {
+        DashRepresentationSelection::Highest | DashRepresentationSelection::Lowest => true,
+        DashRepresentationSelection::MaxBandwidth(max) => representation.bandwidth <= max,
+        DashRepresentationSelection::MaxHeight(max) => {
+            representation.height.is_none_or(|height| height <= max)
+        }
+    };
+    match selection {
+        DashRepresentationSelection::Lowest => candidates.first().copied(),
+        _ => candidates
+            .iter()
+            .rev()
+            .find(|(_, representation)| within(representation))
+            .or(candidates.first())
+            .copied(),
+    }
+}
+
+fn join_base(base: &Url, base_urls: &[String]) -> Result<Url, DownloadError> {
+    match base_urls.first() {
+        Some(relative) => base
+            .join(relative.trim())
+            .map_err(|e| DownloadError::invalid_url(relative.clone(), e.to_string())),
+        None => Ok(base.clone()),
+    }
+}
+
+struct PeriodTiming {
+    dynamic: bool,
+    now: f64,
+    availability_start: Option<f64>,
+    period_start: f64,
+    period_duration: Option<f64>,
+    time_shift_buffer_depth: Option<f64>,
+}
+
+impl PeriodTiming {
+    /// Seconds of the period that are available: up to now for live manifests, the whole
+    /// period otherwise.
+    fn available(&self) -> Option<f64> {
+        if self.dynamic {
+            let elapsed = self.now - self.availability_start? - self.period_start;
+            return Some(match self.period_duration {
+                Some(duration) => elapsed.min(duration),
+                None => elapsed,
+            });
+        }
+        self.period_duration
+    }
+}
+
+fn enumerate(
+    period: &Period,
+    set: &AdaptationSet,
+    representation: &Representation,
+    base: &Url,
+    timing: &PeriodTiming,
+) -> Result<(Option<Url>, Vec<DashSegment>), DownloadError> {
+    if let Some(list) = representation
+        .segment_list
+        .as_ref()
+        .or(set.segment_list.as_ref())
+    {
+        return enumerate_list(list, base);
+    }
+
+    let set_template = set
+        .segment_template
+        .clone()
+        .map(|template| template.inherit(period.segment_template.as_ref()))
+        .or_else(|| period.segment_template.clone());
+    let template = match representation.segment_template.clone() {
+        Some(template) => Some(template.inherit(set_template.as_ref())),
+        None => set_template,
+    };
+    let Some(template) = template.filter(|template| template.media.is_some()) else {
+        return Err(DownloadError::Playlist {
+            reason: format!(
+                "DASH representation `{}` has no segment template or list; single-file \
+                 representations are not supported",
+                representation.id
+            ),
+        });
+    };
+
+    let init_url = template
+        .initialization
+        .as_deref()
+        .map(|pattern| resolve(base, &expand(pattern, representation, None, None)))
+        .transpose()?;
+    let segments = match &template.segment_timeline {
+        Some(_) => enumerate_timeline(&template, representation, base, timing)?,
+        None => enumerate_numbers(&template, representation, base, timing)?,
+    };
+    Ok((init_url, segments))
+}
+
+fn enumerate_timeline(
+    template: &SegmentTemplate,
+    representation: &Representation,
+    base: &Url,
+    timing: &PeriodTiming,
+) -> Result<Vec<DashSegment>, DownloadError> {
+    let timescale = template.timescale.unwrap_or(1).max(1);
+    let start_number = template.start_number.unwrap_or(1);
+    let entries = template
+        .segment_timeline
+        .as_ref()
+        .map(|timeline| timeline.entries.as_slice())
+        .unwrap_or_default();
+    let pto = template.presentation_time_offset.unwrap_or(0);
+    // Where `r="-1"` repeats stop when no later entry gives a start time
+    let period_end = timing
+        .available()
+        .map(|seconds| pto + (seconds.max(0.0) * timescale as f64) as u64);
+
+    let media = template.media.as_deref().unwrap_or_default();
+    let mut segments = Vec::new();
+    let mut time = 0;
+    for (index, entry) in entries.iter().enumerate() {
+        if let Some(t) = entry.t {
+            time = t;
+        }
+        let duration = entry.d.max(1);
+        let repeat = match entry.r {
+            Some(r) if r >= 0 => r as u64,
+            Some(_) => {
+                let end = entries
+                    .get(index + 1)
+                    .and_then(|next| next.t)
+                    .or(period_end)
+                    .unwrap_or(time + duration);
+                end.saturating_sub(time)
+                    .div_ceil(duration)
+                    .saturating_sub(1)
+            }
+            None => 0,
+        };
+        for _ in 0..=repeat {
+            if segments.len() >= MAX_SEGMENTS {
+                return Ok(segments);
+            }
+            let number = start_number + segments.len() as u64;
+            let url = expand(media, representation, Some(number), Some(time));
+            segments.push(DashSegment {
+                position: time,
+                url: resolve(base, &url)?,
+                duration: duration as f64 / timescale as f64,
+            });
+            time += duration;
+        }
+    }
+    Ok(segments)
+}
+
+fn enumerate_numbers(
+    template: &SegmentTemplate,
+    representation: &Representation,
+    base: &Url,
+    timing: &PeriodTiming,
+) -> Result<Vec<DashSegment>, DownloadError> {
+    let timescale = template.timescale.unwrap_or(1).max(1);
+    let start_number = template.start_number.unwrap_or(1);
+    let Some(duration) = template.duration.filter(|duration| *duration > 0) else {
+        return Err(DownloadError::Playlist {
+            reason: format!(
+                "DASH segment template of `{}` has neither a duration nor a timeline",
+                representation.id
+            ),
+        });
+    };
+    let segment_seconds = duration as f64 / timescale as f64;
+    let Some(available) = timing.available() else {
+        return Err(DownloadError::Playlist {
+            reason: "DASH manifest does not tell how long its period is".to_string(),
+        });
+    };
+
+    let (first, end) = if timing.dynamic {
+        // Only segments that have ended are available
+        let end = (available / segment_seconds).floor().max(0.0) as u64;
+        let window = timing
+            .time_shift_buffer_depth
+            .map(|depth| (depth / segment_seconds).floor() as u64)
+            .unwrap_or(end);
+        (end.saturating_sub(window), end)
+    } else {
+        (0, (available / segment_seconds).ceil().max(0.0) as u64)
+    };
+    let first = first.max(end.saturating_sub(MAX_SEGMENTS as u64));
+
+    let media = template.media.as_deref().unwrap_or_default();
+    let pto = template.presentation_time_offset.unwrap_or(0);
+    (first..end)
+        .map(|index| {
+            let number = start_number + index;
+            let time = pto + index * duration;
+            Ok(DashSegment {
+                position: number,
+                url: resolve(
+                    base,
+                    &expand(media, representation, Some(number), Some(time)),
+                )?,
+                duration: segment_seconds,
+            })
+        })
+        .collect()
+}
+
+fn enumerate_list(
+    list: &SegmentList,
+    base: &Url,
+) -> Result<(Option<Url>, Vec<DashSegment>), DownloadError> {
+    let timescale = list.timescale.unwrap_or(1).max(1);
+    let duration = list.duration.unwrap_or(0) as f64 / timescale as f64;
+    let start_number = list.start_number.unwrap_or(1);
+    let init_url = list
+        .initialization
+        .as_ref()
+        .and_then(|init| init.source_url.as_deref())
+        .map(|url| resolve(base, url))
+        .transpose()?;
+    let segments = list
+        .segment_urls
+        .iter()
+        .enumerate()
+        .map(|(index, segment)| {
+            Ok(DashSegment {
+                position: start_number + index as u64,
+                url: match segment.media.as_deref() {
+                    Some(media) => resolve(base, media)?,
+                    None => base.clone(),
+                },
+                duration,
+            })
+        })
+        .collect::<Result<_, DownloadError>>()?;
+    Ok((init_url, segments))
+}
+
+fn resolve(base: &Url, relative: &str) -> Result<Url, DownloadError> {
+    base.join(relative)
+        .map_err(|e| DownloadError::invalid_url(relative.to_string(), e.to_string()))
+}
+
+/// Expand the `$Identifier$` and `$Identifier%0Nd$` placeholders of a segment template.
+fn expand(
+    pattern: &str,
+    representation: &Representation,
+    number: Option<u64>,
+    time: Option<u64>,
+) -> String {
+    let mut expanded = String::with_capacity(pattern.len());
+    let mut parts = pattern.split('$');
+    if let Some(first) = parts.next() {
+        expanded.push_str(first);
+    }
+    // Placeholders are the odd parts between two `$`
+    let mut in_placeholder = true;
+    while let Some(part) = parts.next() {
+        if !in_placeholder {
+            expanded.push_str(part);
+            in_placeholder = true;
+            continue;
+        }
+        in_placeholder = false;
+        let (name, width) = match part.split_once('%') {
+            Some((name, format)) => (
+                name,
+                format
+                    .strip_suffix('d')
+                    .and_then(|width| width.trim_start_matches('0').parse().ok())
+                    .unwrap_or(0),
+            ),
+            None => (part, 0),
+        };
+        let value = match name {
+            "" => Some("$".to_string()),
+            "RepresentationID" => Some(representation.id.clone()),
+            "Bandwidth" => Some(representation.bandwidth.to_string()),
+            "Number" => number.map(|number| number.to_string()),
+            "Time" => time.map(|time| time.to_string()),
+            _ => None,
+        };
+        match value {
+            Some(value) => expanded.push_str(&format!("{value:0>width$}")),
+            // Not a known placeholder: keep it as it was
+            None => {
+                expanded.push('$');
+                expanded.push_str(part);
+                if parts.clone().next().is_some() {
+                    expanded.push('$');
+                }
+            }
+        }
+    }
+    expanded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn representation() -> Representation {
+        Representation {
+            id: "v1".to_string(),
+            bandwidth: 500_000,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn expands_template_placeholders() {
+        let representation = representation();
+        assert_eq!(
+            expand(
+                "$RepresentationID$/seg-$Number%05d$-$Time$-$Bandwidth$.m4s",
+                &representation,
+                Some(42),
+                Some(9000)
+            ),
+            "v1/seg-00042-9000-500000.m4s"
+        );
+        assert_eq!(expand("a$$b", &representation, None, None), "a$b");
+    }
+
+    #[test]
+    fn static_timeline_lists_every_segment() {
+        let mpd = Mpd::parse(
+            r#"<MPD type="static" mediaPresentationDuration="PT10S">
+                 <Period>
+                   <AdaptationSet mimeType="video/mp4">
+                     <SegmentTemplate timescale="1000" initialization="init-$RepresentationID$.mp4"
+                                      media="$RepresentationID$-$Time$.m4s" startNumber="5">
+                       <SegmentTimeline><S t="0" d="4000" r="1"/><S d="2000"/></SegmentTimeline>
+                     </SegmentTemplate>
+                     <Representation id="low" bandwidth="100"/>
+                     <Representation id="high" bandwidth="900" height="1080"/>
+                   </AdaptationSet>
+                   <AdaptationSet mimeType="audio/mp4">
+                     <Representation id="audio" bandwidth="64">
+                       <SegmentList duration="2">
+                         <Initialization sourceURL="a/init.mp4"/>
+                         <SegmentURL media="a/1.m4s"/><SegmentURL media="a/2.m4s"/>
+                       </SegmentList>
+                     </Representation>
+                   </AdaptationSet>
+                 </Period>
+               </MPD>"#,
+        )
+        .unwrap();
+        let manifest = Url::parse("https://cdn.example.com/vod/manifest.mpd").unwrap();
+        let clock = Clock { now: 0.0 };
+
+        let tracks = select_tracks(
+            &mpd,
+            &manifest,
+            DashContentType::Video,
+            DashRepresentationSelection::Highest,
+            clock,
+        )
+        .unwrap();
+        let track = &tracks[0];
+        assert_eq!(track.representation_id, "high");
+        assert_eq!(
+            track.init_url.as_ref().unwrap().as_str(),
+            "https://cdn.example.com/vod/init-high.mp4"
+        );
+        let urls: Vec<_> = track.segments.iter().map(|s| s.url.path()).collect();
+        assert_eq!(
+            urls,
+            [
+                "/vod/high-0.m4s",
+                "/vod/high-4000.m4s",
+                "/vod/high-8000.m4s"
+            ]
+        );
+        assert_eq!(track.segments[2].duration, 2.0);
+
+        let lowest = select_tracks(
+            &mpd,
+            &manifest,
+            DashContentType::Video,
+            DashRepresentationSelection::MaxHeight(720),
+            clock,
+        )
+        .unwrap();
+        assert_eq!(lowest[0].representation_id, "low");
+
+        let audio = select_tracks(
+            &mpd,
+            &manifest,
+            DashContentType::Audio,
+            DashRepresentationSelection::Highest,
+            clock,
+        )
+        .unwrap();
+        assert_eq!(audio[0].segments.len(), 2);
+        assert_eq!(
+            audio[0].init_url.as_ref().unwrap().path(),
+            "/vod/a/init.mp4"
+        );
+    }
+
+    #[test]
+    fn live_number_template_follows_the_clock() {
+        let mpd = Mpd::parse(
+            r#"<MPD type="dynamic" availabilityStartTime="1970-01-01T00:00:00Z"
+                    timeShiftBufferDepth="PT10S">
+                 <Period start="PT0S">
+                   <AdaptationSet contentType="video">
+                     <SegmentTemplate media="$Number$.m4s" duration="2" startNumber="1"/>
+                     <Representation id="v" bandwidth="1"/>
+                   </AdaptationSet>
+                 </Period>
+               </MPD>"#,
+        )
+        .unwrap();
+        let manifest = Url::parse("https://cdn.example.com/live/manifest.mpd").unwrap();
+        let segments = |now| {
+            select_tracks(
+                &mpd,
+                &manifest,
+                DashContentType::Video,
+                DashRepresentationSelection::Highest,
+                Clock { now },
+            )
+            .unwrap()
+            .remove(0)
+            .segments
+            .into_iter()
+            .map(|segment| segment.position)
+            .collect::<Vec<_>>()
+        };
+
+        // 101 seconds in: segments 1..=50 ended, the last 5 are in the time shift buffer
+        assert_eq!(segments(101.0), [46, 47, 48, 49, 50]);
+        assert_eq!(segments(103.0), [47, 48, 49, 50, 51]);
+    }
+}
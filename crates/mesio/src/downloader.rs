@@ -1,9 +1,11 @@
-use reqwest::Client;
+use reqwest::{Client, RequestBuilder};
 use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 use std::sync::OnceLock;
+use std::time::SystemTime;
 use tracing::{debug, info};
 
 use crate::DownloaderConfig;
+use crate::header_template::HeaderTemplates;
 use crate::{DownloadError, proxy::build_proxy_from_config};
 
 /// Create a reqwest Client with the provided configuration
@@ -32,6 +34,10 @@ pub fn create_client(config: &DownloaderConfig) -> Result<Client, DownloadError>
             reqwest::redirect::Policy::none()
         });
 
+    if let Some(jar) = &config.cookie_jar {
+        client_builder = client_builder.cookie_provider(jar.provider());
+    }
+
     debug!(
         pool_max_idle_per_host = config.pool_max_idle_per_host,
         pool_idle_timeout_secs = config.pool_idle_timeout.as_secs(),
@@ -57,27 +63,7 @@ pub fn create_client(config: &DownloaderConfig) -> Result<Client, DownloadError>
         }
     }
 
-    // --- HTTP/2 Configuration Notes ---
-    // With rustls-tls backend, HTTP/2 is automatically negotiated via ALPN.
-    // The flow control window sizes use hyper's defaults which are reasonable
-    // for most use cases. The key optimizations we can apply are:
-    // 1. Connection pooling (configured above)
-    // 2. TCP keep-alive to maintain connections
-    // 3. Proper timeout configuration
-    //
-    // Note: HTTP/2 specific methods like http2_adaptive_window() require
-    // the native-tls backend. With rustls-tls, HTTP/2 works but with
-    // default flow control settings.
-
-    // --- TCP Keep-Alive for long-lived connections ---
-    // This helps maintain HTTP/2 connections for multiplexing
-    if let Some(interval) = config.http2_keep_alive_interval {
-        client_builder = client_builder.tcp_keepalive(interval);
-        debug!(
-            ?interval,
-            "TCP keep-alive configured for HTTP/2 connection reuse"
-        );
-    }
+    client_builder = apply_connection_tuning(client_builder, config);
 
     // Force IP Version
     client_builder = match (config.force_ipv4, config.force_ipv6) {
@@ -125,9 +111,44 @@ pub fn create_client(config: &DownloaderConfig) -> Result<Client, DownloadError>
     client_builder.build().map_err(DownloadError::from)
 }
 
+/// Apply the HTTP/2 flow-control and keep-alive settings and TCP keep-alive.
+fn apply_connection_tuning(
+    mut client_builder: reqwest::ClientBuilder,
+    config: &DownloaderConfig,
+) -> reqwest::ClientBuilder {
+    if let Some(interval) = config.tcp_keepalive {
+        client_builder = client_builder.tcp_keepalive(interval);
+    }
+
+    // Dead HTTP/2 connections are detected by unanswered PINGs, so a stalled
+    // connection does not hold every multiplexed segment download hostage
+    if let Some(interval) = config.http2_keep_alive_interval {
+        client_builder = client_builder
+            .http2_keep_alive_interval(interval)
+            .http2_keep_alive_timeout(config.http2_keep_alive_timeout)
+            .http2_keep_alive_while_idle(config.http2_keep_alive_while_idle);
+    }
+
+    if config.http2_adaptive_window {
+        client_builder = client_builder.http2_adaptive_window(true);
+    } else {
+        client_builder = client_builder
+            .http2_initial_stream_window_size(config.http2_initial_stream_window_size)
+            .http2_initial_connection_window_size(config.http2_initial_connection_window_size);
+    }
+
+    debug!(
+        tcp_keepalive = ?config.tcp_keepalive,
+        http2_keep_alive_interval = ?config.http2_keep_alive_interval,
+        http2_adaptive_window = config.http2_adaptive_window,
+        "Connection tuning configured"
+    );
+    client_builder
+}
+
 pub(crate) const ENV_NATIVE_TLS_HOSTS: &str = "RUST_SREC_NATIVE_TLS_HOSTS";
 
-fn install_rustls_provider() {
+pub(crate) fn install_rustls_provider() {
     // `reqwest` is configured with `rustls-tls-*-no-provider`; install one globally.
     static PROVIDER_INSTALLED: OnceLock<()> = OnceLock::new();
     PROVIDER_INSTALLED.get_or_init(|| {
@@ -178,6 +199,7 @@ pub struct ClientPool {
     #[cfg(feature = "tls-native-fallback")]
     native: Client,
     native_hosts: Vec<String>,
+    header_templates: HeaderTemplates,
 }
 
 impl ClientPool {
@@ -195,9 +217,19 @@ impl ClientPool {
             #[cfg(feature = "tls-native-fallback")]
             native,
             native_hosts,
+            header_templates: config.header_templates.clone(),
         })
     }
 
+    /// A GET request to `url` on the client for its host, with the header templates rendered
+    pub fn get(&self, url: &url::Url) -> RequestBuilder {
+        let request = self.client_for_url(url).get(url.clone());
+        if self.header_templates.is_empty() {
+            return request;
+        }
+        request.headers(self.header_templates.render(SystemTime::now()))
+    }
+
     pub fn default_client(&self) -> &Client {
         &self.rustls
     }
@@ -258,6 +290,10 @@ fn create_client_with_backend(
             reqwest::redirect::Policy::none()
         });
 
+    if let Some(jar) = &config.cookie_jar {
+        client_builder = client_builder.cookie_provider(jar.provider());
+    }
+
     match backend {
         TlsBackend::Rustls => {
             client_builder = client_builder.use_rustls_tls();
@@ -278,9 +314,7 @@ fn create_client_with_backend(
         HttpVersionPreference::Auto => {}
     }
 
-    if let Some(interval) = config.http2_keep_alive_interval {
-        client_builder = client_builder.tcp_keepalive(interval);
-    }
+    client_builder = apply_connection_tuning(client_builder, config);
 
     client_builder = match (config.force_ipv4, config.force_ipv6) {
         (true, false) => client_builder.local_address(IpAddr::V4(Ipv4Addr::UNSPECIFIED)),
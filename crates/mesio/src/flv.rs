@@ -1,7 +1,10 @@
 pub mod error;
 pub mod flv_config;
 pub mod flv_downloader;
+pub mod resume;
+mod websocket;
 
 pub use flv_downloader::FlvDownloader;
 
 pub use flv_config::FlvProtocolConfig;
+pub use resume::FlvResumePoint;
@@ -3,26 +3,35 @@
 //! This module implements efficient streaming download functionality for FLV resources.
 //! It uses reqwest to download data in chunks and pipes it directly to the FLV parser,
 //! minimizing memory usage and providing a seamless integration with the processing pipeline.
+//! `rtmp://` and `rtmps://` URLs are played with the RTMP client, whose media messages are
+//! rewrapped as FLV tags for the same parser, and `ws://` and `wss://` URLs are read as FLV
+//! split across WebSocket binary messages.
 
+use bytes::{Bytes, BytesMut};
 use flv::{data::FlvData, parser_async::FlvDecoderStream};
 use futures::StreamExt;
-use reqwest::{Response, StatusCode, Url};
+use reqwest::{Response, StatusCode, Url, header};
+use std::future::Future;
 use std::pin::Pin;
 use std::sync::Arc;
 use std::task::{Context, Poll};
-use std::time::Instant;
+use std::time::{Duration, Instant};
 use tokio::sync::mpsc;
 use tokio_stream::wrappers::ReceiverStream;
 use tracing::{debug, info, warn};
 
 use super::error::FlvDownloadError;
 use super::flv_config::FlvProtocolConfig;
+use super::resume::{self, FlvResumePoint};
+use super::websocket;
 use crate::bytes_stream::BytesStreamReader;
+use crate::rtmp::{RtmpPlayer, RtmpUrl};
 use crate::{BoxMediaStream, DownloadError, downloader::create_client_pool};
 use crate::{
     DownloadEvent, DownloadRequest, DownloadSession, EventSink, MediaEngine, ProtocolSelection,
     ProtocolType, ResourceId,
 };
+use tokio_util::io::StreamReader;
 use tokio_util::sync::CancellationToken;
 
 /// FLV Downloader for streaming FLV content from URLs
@@ -56,6 +65,78 @@ impl Drop for CancelOnDropStream {
     }
 }
 
+/// Throttled `Progress` events and the closing `ResourceFinished` of one FLV stream.
+struct ProgressReporter {
+    events: Option<EventSink>,
+    url: Arc<str>,
+    min_bytes: u64,
+    min_interval: Duration,
+    bytes_total: u64,
+    pending: u64,
+    last_emit: Instant,
+}
+
+impl ProgressReporter {
+    fn new(config: &FlvProtocolConfig, events: Option<EventSink>, url: &Url) -> Self {
+        Self {
+            events,
+            url: Arc::from(url.as_str()),
+            min_bytes: config.progress_emit_min_bytes,
+            min_interval: config.progress_emit_min_interval,
+            bytes_total: 0,
+            pending: 0,
+            last_emit: Instant::now(),
+        }
+    }
+
+    fn record(&mut self, bytes: u64) {
+        self.bytes_total += bytes;
+        self.pending += bytes;
+        if self.min_bytes == 0
+            || self.min_interval.is_zero()
+            || self.pending >= self.min_bytes
+            || self.last_emit.elapsed() >= self.min_interval
+        {
+            self.flush();
+        }
+    }
+
+    fn flush(&mut self) {
+        emit_event(
+            &self.events,
+            DownloadEvent::Progress {
+                resource: ResourceId::FlvStream {
+                    url: Arc::clone(&self.url),
+                },
+                bytes_delta: self.pending,
+                bytes_total: self.bytes_total,
+            },
+        );
+        self.pending = 0;
+        self.last_emit = Instant::now();
+    }
+
+    fn finish(mut self) {
+        if self.pending > 0 {
+            self.flush();
+        }
+        emit_event(
+            &self.events,
+            DownloadEvent::ResourceFinished {
+                resource: ResourceId::FlvStream { url: self.url },
+                bytes: self.bytes_total,
+                from_cache: false,
+            },
+        );
+    }
+}
+
+/// Error fed to the decoder when a stream that does not go through reqwest fails; it surfaces
+/// as a stream network error, like a dropped HTTP body.
+fn stream_aborted(error: DownloadError) -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::ConnectionAborted, error.to_string())
+}
+
 impl FlvDownloader {
     fn log_unexpected_status(url: &Url, status: StatusCode, context: &'static str) {
         let reason = status.canonical_reason().unwrap_or("unknown");
@@ -89,17 +170,22 @@ impl FlvDownloader {
         Ok(Self { clients, config })
     }
 
-    /// Core method to start a download request and return the response
-    async fn start_download_request(&self, url: &Url) -> Result<Response, DownloadError> {
-        info!(url = %url, "Starting FLV download request");
+    /// Core method to start a download request and return the response.
+    ///
+    /// With `range_start`, only the bytes from that offset on are requested.
+    async fn start_download_request(
+        &self,
+        url: &Url,
+        range_start: Option<u64>,
+    ) -> Result<Response, DownloadError> {
+        info!(url = %url, range_start, "Starting FLV download request");
         debug!(url = %url, params = ?self.config.base.params, "Sending FLV download request");
 
-        let client = self.clients.client_for_url(url);
-        let response = client
-            .get(url.clone())
-            .query(&self.config.base.params)
-            .send()
-            .await?;
+        let mut request = self.clients.get(url).query(&self.config.base.params);
+        if let Some(offset) = range_start {
+            request = request.header(header::RANGE, format!("bytes={offset}-"));
+        }
+        let response = request.send().await?;
 
         // Check response status
         if !response.status().is_success() {
@@ -111,6 +197,18 @@ impl FlvDownloader {
             ));
         }
 
+        // A full response would repeat what the partial output already holds
+        if range_start.is_some() && response.status() != StatusCode::PARTIAL_CONTENT {
+            warn!(url = %url, status = %response.status(), "Server ignored the FLV resume range");
+            return Err(DownloadError::InvalidContent {
+                protocol: "flv",
+                reason: format!(
+                    "Cannot resume: server answered a range request with {}",
+                    response.status()
+                ),
+            });
+        }
+
         // Fast path: Check Content-Type header if present
         // Reject obviously wrong content types early without reading body
         if let Some(content_type) = response.headers().get("content-type")
@@ -156,6 +254,35 @@ impl FlvDownloader {
         Ok(response)
     }
 
+    /// Open a stream, retrying failed attempts as the retry policy of the configuration allows.
+    async fn open_with_retries<T, F, Fut>(
+        &self,
+        url: &Url,
+        token: &CancellationToken,
+        events: &Option<EventSink>,
+        open: F,
+    ) -> Result<T, DownloadError>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, DownloadError>>,
+    {
+        let on_retry = |attempt, delay, error: &DownloadError| {
+            warn!(url = %url, attempt, ?delay, %error, "Retrying FLV connection");
+            emit_event(
+                events,
+                DownloadEvent::RetryScheduled {
+                    resource: Some(ResourceId::FlvStream {
+                        url: Arc::from(url.as_str()),
+                    }),
+                    attempt,
+                    delay,
+                    reason: Arc::from(error.to_string()),
+                },
+            );
+        };
+        self.config.base.retry.run(token, on_retry, open).await
+    }
+
     /// Create an FLV decoder stream from any async reader
     #[inline]
     fn create_decoder_stream<R>(&self, reader: R) -> BoxMediaStream<FlvData, FlvDownloadError>
@@ -167,8 +294,9 @@ impl FlvDownloader {
         // can easily saturate smaller buffers
         let buffer_size = self.config.buffer_size.max(64 * 1024);
 
-        let buffered_reader = tokio::io::BufReader::with_capacity(buffer_size, reader);
-        let pinned_reader = Box::pin(buffered_reader);
+        // The decoder buffers on its own and hands out tags as slices of that buffer; a
+        // `BufReader` in front of it would only add a second copy of every byte.
+        let pinned_reader = Box::pin(reader);
         let flv_stream = FlvDecoderStream::with_capacity(pinned_reader, buffer_size);
         flv_stream
             .map(|result| match result {
@@ -178,18 +306,126 @@ impl FlvDownloader {
             .boxed()
     }
 
+    /// Read the first chunk of a response, checking that it is FLV data.
+    async fn read_first_chunk<S>(url: &Url, byte_stream: &mut S) -> Result<Bytes, DownloadError>
+    where
+        S: futures::Stream<Item = reqwest::Result<Bytes>> + Unpin,
+    {
+        // Read the first chunk to validate it's FLV binary data
+        let first_chunk = match byte_stream.next().await {
+            Some(Ok(chunk)) => chunk,
+            Some(Err(e)) => return Err(DownloadError::Network { source: e }),
+            None => {
+                return Err(DownloadError::InvalidContent {
+                    protocol: "flv",
+                    reason: "Empty response received".to_string(),
+                });
+            }
+        };
+        Self::validate_first_chunk(url, &first_chunk)?;
+        Ok(first_chunk)
+    }
+
+    /// Check that the first chunk of a stream is FLV data.
+    fn validate_first_chunk(url: &Url, first_chunk: &[u8]) -> Result<(), DownloadError> {
+        // Validate FLV signature (first 3 bytes should be "FLV" = 0x46 0x4C 0x56)
+        // OR first byte is a valid FLV tag type (for mid-stream CDN joins)
+        if first_chunk.is_empty() {
+            warn!(url = %url, "Empty first chunk received");
+            return Err(DownloadError::InvalidContent {
+                protocol: "flv",
+                reason: "Empty response received".to_string(),
+            });
+        }
+
+        // Check for FLV magic bytes OR valid FLV tag types
+        const FLV_SIGNATURE: [u8; 3] = [0x46, 0x4C, 0x56]; // "FLV"
+        const TAG_TYPE_AUDIO: u8 = 8;
+        const TAG_TYPE_VIDEO: u8 = 9;
+        const TAG_TYPE_SCRIPT: u8 = 18;
+
+        let first_byte = first_chunk[0];
+        let is_header = first_chunk.len() >= 3 && first_chunk[0..3] == FLV_SIGNATURE;
+        let is_valid_flv = if is_header {
+            true
+        } else {
+            // Check if first byte is a valid FLV tag type (for mid-stream CDN joins)
+            // The lower 5 bits contain the tag type (ignore filter bit)
+            let tag_type = first_byte & 0x1F;
+            tag_type == TAG_TYPE_AUDIO || tag_type == TAG_TYPE_VIDEO || tag_type == TAG_TYPE_SCRIPT
+        };
+
+        if !is_valid_flv {
+            // Check if it looks like text/HTML content
+            let is_text = first_chunk.iter().take(64).all(|&b| {
+                b.is_ascii_alphanumeric() || b.is_ascii_whitespace() || b.is_ascii_punctuation()
+            });
+
+            let preview = if is_text {
+                // Convert to string for readable error message
+                String::from_utf8_lossy(&first_chunk[..first_chunk.len().min(128)]).to_string()
+            } else {
+                format!("{:02X?}", &first_chunk[..first_chunk.len().min(32)])
+            };
+
+            warn!(
+                url = %url,
+                preview = %preview,
+                first_byte = format!("0x{:02X}", first_byte),
+                is_text = is_text,
+                "Invalid FLV content: expected FLV signature or valid tag type"
+            );
+            return Err(DownloadError::InvalidContent {
+                protocol: "flv",
+                reason: format!(
+                    "Invalid FLV content: expected FLV signature or valid tag type: 0x{:02X}",
+                    first_byte
+                ),
+            });
+        }
+
+        // Log validation result once (header vs mid-stream)
+        debug!(
+            url = %url,
+            is_header = is_header,
+            "FLV content validated, starting stream"
+        );
+
+        Ok(())
+    }
+
     async fn download_url_with_events(
         &self,
         url: Url,
         token: CancellationToken,
         events: Option<EventSink>,
+        resume: Option<&FlvResumePoint>,
     ) -> Result<BoxMediaStream<FlvData, FlvDownloadError>, DownloadError> {
+        let scheme = url.scheme();
+        if matches!(scheme, "rtmp" | "rtmps" | "ws" | "wss") && resume.is_some() {
+            return Err(DownloadError::Configuration {
+                reason: format!("{scheme}:// downloads cannot be resumed"),
+            });
+        }
+        match scheme {
+            "rtmp" | "rtmps" => return self.download_rtmp_with_events(url, token, events).await,
+            "ws" | "wss" => {
+                return self
+                    .download_websocket_with_events(url, token, events)
+                    .await;
+            }
+            _ => {}
+        }
+
+        let range_start = resume.map(|resume| resume.offset);
         tokio::select! {
             _ = token.cancelled() => {
                 info!(url = %url, "Download cancelled");
                 Err(DownloadError::Cancelled)
             }
-            response = self.start_download_request(&url) => {
+            response = self.open_with_retries(&url, &token, &events, || {
+                self.start_download_request(&url, range_start)
+            }) => {
                 let response = response?;
                 let content_length = response.content_length();
                 emit_event(
@@ -204,107 +440,29 @@ impl FlvDownloader {
                 );
                 let mut byte_stream = response.bytes_stream();
 
-                // Read the first chunk to validate it's FLV binary data
-                let first_chunk = match byte_stream.next().await {
-                    Some(Ok(chunk)) => chunk,
-                    Some(Err(e)) => return Err(DownloadError::Network { source: e }),
-                    None => return Err(DownloadError::InvalidContent {
-                        protocol: "flv",
-                        reason: "Empty response received".to_string(),
-                    }),
-                };
-
-                // Validate FLV signature (first 3 bytes should be "FLV" = 0x46 0x4C 0x56)
-                // OR first byte is a valid FLV tag type (for mid-stream CDN joins)
-                if first_chunk.is_empty() {
-                    warn!(url = %url, "Empty first chunk received");
-                    return Err(DownloadError::InvalidContent {
-                        protocol: "flv",
-                        reason: "Empty response received".to_string(),
-                    });
-                }
-
-                // Check for FLV magic bytes OR valid FLV tag types
-                const FLV_SIGNATURE: [u8; 3] = [0x46, 0x4C, 0x56]; // "FLV"
-                const TAG_TYPE_AUDIO: u8 = 8;
-                const TAG_TYPE_VIDEO: u8 = 9;
-                const TAG_TYPE_SCRIPT: u8 = 18;
-
-                let first_byte = first_chunk[0];
-                let is_header = first_chunk.len() >= 3 && first_chunk[0..3] == FLV_SIGNATURE;
-                let is_valid_flv = if is_header {
-                    true
-                } else {
-                    // Check if first byte is a valid FLV tag type (for mid-stream CDN joins)
-                    // The lower 5 bits contain the tag type (ignore filter bit)
-                    let tag_type = first_byte & 0x1F;
-                    tag_type == TAG_TYPE_AUDIO || tag_type == TAG_TYPE_VIDEO || tag_type == TAG_TYPE_SCRIPT
+                // A resumed response starts anywhere in a tag
+                let (first_chunk, resumed_timestamp) = match resume {
+                    Some(resume) => {
+                        let (chunk, timestamp) =
+                            resume::resync_to_tag_boundary(&mut byte_stream).await?;
+                        info!(
+                            url = %url,
+                            offset = resume.offset,
+                            timestamp_ms = timestamp,
+                            "Resuming FLV download"
+                        );
+                        (chunk, Some(timestamp))
+                    }
+                    None => (Self::read_first_chunk(&url, &mut byte_stream).await?, None),
                 };
 
-                if !is_valid_flv {
-                    // Check if it looks like text/HTML content
-                    let is_text = first_chunk.iter().take(64).all(|&b| {
-                        b.is_ascii_alphanumeric() || b.is_ascii_whitespace() || b.is_ascii_punctuation()
-                    });
-
-                    let preview = if is_text {
-                        // Convert to string for readable error message
-                        String::from_utf8_lossy(&first_chunk[..first_chunk.len().min(128)]).to_string()
-                    } else {
-                        format!("{:02X?}", &first_chunk[..first_chunk.len().min(32)])
-                    };
-
-                    warn!(
-                        url = %url,
-                        preview = %preview,
-                        first_byte = format!("0x{:02X}", first_byte),
-                        is_text = is_text,
-                        "Invalid FLV content: expected FLV signature or valid tag type"
-                    );
-                    return Err(DownloadError::InvalidContent {
-                        protocol: "flv",
-                        reason: format!(
-                            "Invalid FLV content: expected FLV signature or valid tag type: 0x{:02X}",
-                            first_byte
-                        ),
-                    });
-                }
-
-                // Log validation result once (header vs mid-stream)
-                debug!(
-                    url = %url,
-                    is_header = is_header,
-                    "FLV content validated, starting stream"
-                );
-
+                let mut progress = ProgressReporter::new(&self.config, events.clone(), &url);
                 let (tx, rx) = mpsc::channel(2);
-
-                // Send the first chunk we already read
-                let first_chunk_for_send = first_chunk.clone();
                 let stream_token = token.clone();
-                let forward_events = events.clone();
-                let resource_url: Arc<str> = Arc::from(url.as_str());
-                let progress_emit_min_bytes = self.config.progress_emit_min_bytes;
-                let progress_emit_min_interval = self.config.progress_emit_min_interval;
                 tokio::spawn(async move {
                     // First, send the chunk we already validated
-                    let mut bytes_total = first_chunk_for_send.len() as u64;
-                    let mut progress_since_last = first_chunk_for_send.len() as u64;
-                    let mut last_progress_emit = Instant::now();
-                    if progress_emit_min_bytes == 0 || progress_emit_min_interval.is_zero() {
-                        emit_event(
-                            &forward_events,
-                            DownloadEvent::Progress {
-                                resource: ResourceId::FlvStream {
-                                    url: Arc::clone(&resource_url),
-                                },
-                                bytes_delta: progress_since_last,
-                                bytes_total,
-                            },
-                        );
-                        progress_since_last = 0;
-                    }
-                    if tx.send(Ok(first_chunk_for_send)).await.is_err() {
+                    progress.record(first_chunk.len() as u64);
+                    if tx.send(Ok(first_chunk)).await.is_err() {
                         return;
                     }
 
@@ -319,55 +477,14 @@ impl FlvDownloader {
                                 match data {
                                     Some(item) => {
                                         if let Ok(bytes) = &item {
-                                            bytes_total += bytes.len() as u64;
-                                            progress_since_last += bytes.len() as u64;
-                                            let elapsed = last_progress_emit.elapsed();
-                                            if progress_emit_min_bytes == 0
-                                                || progress_emit_min_interval.is_zero()
-                                                || progress_since_last >= progress_emit_min_bytes
-                                                || elapsed >= progress_emit_min_interval
-                                            {
-                                                emit_event(
-                                                    &forward_events,
-                                                    DownloadEvent::Progress {
-                                                        resource: ResourceId::FlvStream {
-                                                            url: Arc::clone(&resource_url),
-                                                        },
-                                                        bytes_delta: progress_since_last,
-                                                        bytes_total,
-                                                    },
-                                                );
-                                                progress_since_last = 0;
-                                                last_progress_emit = Instant::now();
-                                            }
+                                            progress.record(bytes.len() as u64);
                                         }
                                         if tx.send(item).await.is_err() {
                                             break;
                                         }
                                     }
                                     None => {
-                                        if progress_since_last > 0 {
-                                            emit_event(
-                                                &forward_events,
-                                                DownloadEvent::Progress {
-                                                    resource: ResourceId::FlvStream {
-                                                        url: Arc::clone(&resource_url),
-                                                    },
-                                                    bytes_delta: progress_since_last,
-                                                    bytes_total,
-                                                },
-                                            );
-                                        }
-                                        emit_event(
-                                            &forward_events,
-                                            DownloadEvent::ResourceFinished {
-                                                resource: ResourceId::FlvStream {
-                                                    url: Arc::clone(&resource_url),
-                                                },
-                                                bytes: bytes_total,
-                                                from_cache: false,
-                                            },
-                                        );
+                                        progress.finish();
                                         break;
                                     }
                                 }
@@ -378,11 +495,181 @@ impl FlvDownloader {
 
                 let stream = ReceiverStream::new(rx);
                 let reader = BytesStreamReader::new(stream.boxed());
-                Ok(self.create_decoder_stream(reader))
+                let stream = self.create_decoder_stream(reader);
+                Ok(match (resume, resumed_timestamp) {
+                    (Some(resume), Some(timestamp)) => {
+                        resume::continue_stream(stream, resume, timestamp).boxed()
+                    }
+                    _ => stream,
+                })
             }
         }
     }
 
+    /// Read an FLV stream delivered in WebSocket binary messages.
+    async fn download_websocket_with_events(
+        &self,
+        url: Url,
+        token: CancellationToken,
+        events: Option<EventSink>,
+    ) -> Result<BoxMediaStream<FlvData, FlvDownloadError>, DownloadError> {
+        info!(url = %url, "Starting WebSocket FLV download");
+        let (mut socket, first_chunk) = tokio::select! {
+            _ = token.cancelled() => {
+                info!(url = %url, "Download cancelled");
+                return Err(DownloadError::Cancelled);
+            }
+            connected = async {
+                let mut socket = self
+                    .open_with_retries(&url, &token, &events, || {
+                        websocket::connect(&url, &self.config.base)
+                    })
+                    .await?;
+                let first_chunk = websocket::next_chunk(&mut socket, &url)
+                    .await?
+                    .unwrap_or_default();
+                Self::validate_first_chunk(&url, &first_chunk)?;
+                Ok::<_, DownloadError>((socket, first_chunk))
+            } => connected?,
+        };
+        emit_event(
+            &events,
+            DownloadEvent::ResourceStarted {
+                resource: ResourceId::FlvStream {
+                    url: Arc::from(url.as_str()),
+                },
+                display_url: Arc::from(url.as_str()),
+                content_length: None,
+            },
+        );
+
+        let mut progress = ProgressReporter::new(&self.config, events, &url);
+        let read_timeout = self.config.base.read_timeout;
+        let (tx, rx) = mpsc::channel(2);
+        tokio::spawn(async move {
+            progress.record(first_chunk.len() as u64);
+            if tx.send(Ok(first_chunk)).await.is_err() {
+                return;
+            }
+            loop {
+                let next = async {
+                    match read_timeout.is_zero() {
+                        true => websocket::next_chunk(&mut socket, &url).await,
+                        false => tokio::time::timeout(
+                            read_timeout,
+                            websocket::next_chunk(&mut socket, &url),
+                        )
+                        .await
+                        .unwrap_or_else(|_| {
+                            Err(DownloadError::Timeout {
+                                reason: format!("no WebSocket data for {read_timeout:?}"),
+                            })
+                        }),
+                    }
+                };
+                let next = tokio::select! {
+                    _ = token.cancelled() => {
+                        debug!("WebSocket download stream cancelled");
+                        let _ = socket.close(None).await;
+                        return;
+                    }
+                    next = next => next,
+                };
+                match next {
+                    Ok(Some(bytes)) => {
+                        progress.record(bytes.len() as u64);
+                        if tx.send(Ok(bytes)).await.is_err() {
+                            return;
+                        }
+                    }
+                    Ok(None) => {
+                        info!(url = %url, "WebSocket stream ended");
+                        progress.finish();
+                        return;
+                    }
+                    Err(e) => {
+                        warn!(url = %url, error = %e, "WebSocket stream failed");
+                        let _ = tx.send(Err(stream_aborted(e))).await;
+                        return;
+                    }
+                }
+            }
+        });
+
+        let reader = StreamReader::new(ReceiverStream::new(rx));
+        Ok(self.create_decoder_stream(reader))
+    }
+
+    /// Play an RTMP stream, feeding the FLV header and the tags of its media messages to the
+    /// decoder.
+    async fn download_rtmp_with_events(
+        &self,
+        url: Url,
+        token: CancellationToken,
+        events: Option<EventSink>,
+    ) -> Result<BoxMediaStream<FlvData, FlvDownloadError>, DownloadError> {
+        let rtmp_url = RtmpUrl::parse(&url)?;
+        info!(url = %url, "Starting RTMP download");
+        let mut player = tokio::select! {
+            _ = token.cancelled() => {
+                info!(url = %url, "Download cancelled");
+                return Err(DownloadError::Cancelled);
+            }
+            player = self.open_with_retries(&url, &token, &events, || {
+                RtmpPlayer::connect(&rtmp_url, &self.config.base)
+            }) => player?,
+        };
+        emit_event(
+            &events,
+            DownloadEvent::ResourceStarted {
+                resource: ResourceId::FlvStream {
+                    url: Arc::from(url.as_str()),
+                },
+                display_url: Arc::from(url.as_str()),
+                content_length: None,
+            },
+        );
+
+        let mut progress = ProgressReporter::new(&self.config, events, &url);
+        let (tx, rx) = mpsc::channel(2);
+        tokio::spawn(async move {
+            // Audio and video flags set; the tags tell which ones the stream has
+            const FLV_HEADER: [u8; 13] = [b'F', b'L', b'V', 1, 0x05, 0, 0, 0, 9, 0, 0, 0, 0];
+            let mut buffer = BytesMut::from(&FLV_HEADER[..]);
+            loop {
+                let read = tokio::select! {
+                    _ = token.cancelled() => {
+                        debug!("RTMP download stream cancelled");
+                        return;
+                    }
+                    read = player.read_tags(&mut buffer) => read,
+                };
+                match read {
+                    Ok(true) => {
+                        let bytes = buffer.split().freeze();
+                        progress.record(bytes.len() as u64);
+                        if tx.send(Ok(bytes)).await.is_err() {
+                            return;
+                        }
+                    }
+                    Ok(false) => {
+                        info!(url = %url, "RTMP stream ended");
+                        progress.finish();
+                        return;
+                    }
+                    Err(e) => {
+                        warn!(url = %url, error = %e, "RTMP stream failed");
+                        let _ = tx.send(Err(stream_aborted(e))).await;
+                        return;
+                    }
+                }
+            }
+        });
+
+        let reader = StreamReader::new(ReceiverStream::new(rx));
+        Ok(self.create_decoder_stream(reader))
+    }
+
     pub async fn start_session(
         &self,
         request: DownloadRequest,
@@ -395,8 +682,17 @@ impl FlvDownloader {
             url: Arc::from(request.url.as_str()),
         });
 
+        let resume = match &request.protocol {
+            ProtocolSelection::Flv(options) => options.resume.as_ref(),
+            _ => request.options.flv.resume.as_ref(),
+        };
         let stream = self
-            .download_url_with_events(request.url, stream_token.clone(), Some(events.clone()))
+            .download_url_with_events(
+                request.url.clone(),
+                stream_token.clone(),
+                Some(events.clone()),
+                resume,
+            )
             .await?;
         let stream = stream.map(|item| item.map_err(DownloadError::from)).boxed();
         let stream: BoxMediaStream<FlvData, DownloadError> =
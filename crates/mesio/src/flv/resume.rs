@@ -0,0 +1,300 @@
+//! # Resuming FLV downloads
+//!
+//! An interrupted download is resumed by requesting the rest of the resource with an HTTP
+//! `Range` request starting where the partial output stopped. The partial output is not
+//! necessarily byte-identical to the source (the flv-fix pipeline rewrites tags), so the
+//! response is scanned for the next tag boundary before it is decoded.
+//!
+//! Decoders need the codec configuration, which is only sent at the start of a stream: the
+//! last sequence headers of the partial output are replayed before the resumed tags. Resumed
+//! timestamps are shifted so they never go back past the end of the partial output.
+
+use std::path::Path;
+
+use bytes::{Bytes, BytesMut};
+use flv::data::FlvData;
+use flv::header::FlvHeader;
+use flv::parser_async::FlvDecoderStream;
+use flv::tag::FlvTag;
+use futures::{Stream, StreamExt};
+use tokio::fs::File;
+use tokio::io::BufReader;
+
+use crate::DownloadError;
+
+const TAG_HEADER_LEN: usize = 11;
+const PREV_TAG_SIZE_LEN: usize = 4;
+
+/// Bytes of a resumed response scanned for a tag boundary before giving up.
+pub(crate) const MAX_RESYNC_BYTES: usize = 4 * 1024 * 1024;
+
+/// Where an interrupted FLV download stopped.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlvResumePoint {
+    /// Byte offset the rest of the resource is requested from.
+    pub offset: u64,
+    /// Timestamp of the last complete tag, in milliseconds.
+    pub timestamp_ms: u32,
+    /// Header of the partial output.
+    pub header: FlvHeader,
+    /// Last audio and video sequence headers, replayed before the resumed tags.
+    pub sequence_headers: Vec<FlvTag>,
+}
+
+impl FlvResumePoint {
+    /// Inspect a partial FLV file, ignoring a trailing incomplete tag.
+    ///
+    /// Returns `None` if the file does not hold a complete tag.
+    pub async fn from_partial_file(path: &Path) -> std::io::Result<Option<Self>> {
+        let file = BufReader::new(File::open(path).await?);
+        let mut decoder = FlvDecoderStream::new(file);
+
+        let mut offset = 0u64;
+        let mut header = None;
+        let mut last_timestamp = None;
+        let mut audio_sequence_header = None;
+        let mut video_sequence_header = None;
+        // A decode error can only come from the truncated end of the file
+        while let Some(Ok(item)) = decoder.next().await {
+            offset += item.size() as u64;
+            match item {
+                FlvData::Header(flv_header) => header = Some(flv_header),
+                FlvData::Tag(tag) => {
+                    last_timestamp = Some(tag.timestamp_ms);
+                    if tag.is_audio_sequence_header() {
+                        audio_sequence_header = Some(tag);
+                    } else if tag.is_video_sequence_header() {
+                        video_sequence_header = Some(tag);
+                    }
+                }
+                FlvData::Split(_) | FlvData::EndOfSequence(_) => {}
+            }
+        }
+
+        let (Some(header), Some(timestamp_ms)) = (header, last_timestamp) else {
+            return Ok(None);
+        };
+        Ok(Some(Self {
+            offset,
+            timestamp_ms,
+            header,
+            sequence_headers: video_sequence_header
+                .into_iter()
+                .chain(audio_sequence_header)
+                .collect(),
+        }))
+    }
+}
+
+/// Read a resumed response up to the first tag boundary.
+///
+/// Returns the bytes from the boundary on, prefixed with an FLV header so they can be
+/// decoded, and the timestamp of the first resumed tag.
+pub(crate) async fn resync_to_tag_boundary<S>(
+    byte_stream: &mut S,
+) -> Result<(Bytes, u32), DownloadError>
+where
+    S: Stream<Item = reqwest::Result<Bytes>> + Unpin,
+{
+    let mut buffer = BytesMut::new();
+    let mut scan_from = 0;
+    loop {
+        match byte_stream.next().await {
+            Some(Ok(chunk)) => buffer.extend_from_slice(&chunk),
+            Some(Err(source)) => return Err(DownloadError::Network { source }),
+            None => {
+                return Err(DownloadError::InvalidContent {
+                    protocol: "flv",
+                    reason: "Resumed response ended before a complete FLV tag".to_string(),
+                });
+            }
+        }
+
+        match find_tag_boundary(&buffer, scan_from) {
+            Ok(start) => {
+                let timestamp = tag_timestamp(&buffer[start..]);
+                let mut resumed = BytesMut::with_capacity(13 + buffer.len() - start);
+                resumed.extend_from_slice(b"FLV\x01\x05\x00\x00\x00\x09\x00\x00\x00\x00");
+                resumed.extend_from_slice(&buffer[start..]);
+                return Ok((resumed.freeze(), timestamp));
+            }
+            Err(next_scan) => scan_from = next_scan,
+        }
+
+        if buffer.len() > MAX_RESYNC_BYTES {
+            return Err(DownloadError::InvalidContent {
+                protocol: "flv",
+                reason: format!(
+                    "No FLV tag boundary within {MAX_RESYNC_BYTES} bytes of the resume offset"
+                ),
+            });
+        }
+    }
+}
+
+/// Find the first offset in `buf`, at or after `from`, where a complete tag followed by a
+/// matching previous-tag-size starts.
+///
+/// Otherwise returns the offset to continue scanning from once more data has arrived: the
+/// first candidate whose tag is not complete yet.
+pub(crate) fn find_tag_boundary(buf: &[u8], from: usize) -> Result<usize, usize> {
+    let mut undecided = None;
+    for start in from..buf.len() {
+        match is_tag_boundary(&buf[start..]) {
+            Some(true) => return Ok(start),
+            Some(false) => {}
+            None => {
+                undecided.get_or_insert(start);
+            }
+        }
+    }
+    Err(undecided.unwrap_or(buf.len()))
+}
+
+/// Whether a tag starts at the beginning of `buf`, or `None` if more data is needed to tell.
+fn is_tag_boundary(buf: &[u8]) -> Option<bool> {
+    let Some(header) = buf.get(..TAG_HEADER_LEN) else {
+        // Rule out what the available bytes already contradict
+        return match buf.first() {
+            Some(&first) if !is_tag_type(first) => Some(false),
+            _ => None,
+        };
+    };
+    // Stream id is always 0
+    if !is_tag_type(header[0]) || header[8..11] != [0, 0, 0] {
+        return Some(false);
+    }
+    let data_size = u32::from_be_bytes([0, header[1], header[2], header[3]]) as usize;
+    let end = TAG_HEADER_LEN + data_size;
+    let prev_tag_size = buf.get(end..end + PREV_TAG_SIZE_LEN)?;
+    Some(u32::from_be_bytes(prev_tag_size.try_into().ok()?) as usize == end)
+}
+
+/// Audio, video or script data, with the reserved bits clear.
+fn is_tag_type(byte: u8) -> bool {
+    byte & 0xC0 == 0 && matches!(byte & 0x1F, 8 | 9 | 18)
+}
+
+fn tag_timestamp(tag: &[u8]) -> u32 {
+    u32::from_be_bytes([tag[7], tag[4], tag[5], tag[6]])
+}
+
+/// Shift resumed tags so they continue the partial output, and replay its header and
+/// sequence headers first.
+///
+/// `first_timestamp` is the timestamp of the first resumed tag, before shifting.
+pub(crate) fn continue_stream<S, E>(
+    stream: S,
+    resume: &FlvResumePoint,
+    first_timestamp: u32,
+) -> impl Stream<Item = Result<FlvData, E>> + Send + 'static
+where
+    S: Stream<Item = Result<FlvData, E>> + Send + 'static,
+    E: Send + 'static,
+{
+    let shift = resume.timestamp_ms.saturating_sub(first_timestamp);
+    let replayed_timestamp = first_timestamp.saturating_add(shift);
+
+    let prefix = std::iter::once(FlvData::Header(resume.header.clone()))
+        .chain(resume.sequence_headers.iter().cloned().map(|mut tag| {
+            tag.timestamp_ms = replayed_timestamp;
+            FlvData::Tag(tag)
+        }))
+        .map(Ok)
+        .collect::<Vec<_>>();
+
+    futures::stream::iter(prefix).chain(stream.filter_map(move |item| {
+        let item = match item {
+            // Only the synthetic header put in front of the resumed bytes
+            Ok(FlvData::Header(_)) => None,
+            Ok(FlvData::Tag(mut tag)) => {
+                tag.timestamp_ms = tag.timestamp_ms.saturating_add(shift);
+                Some(Ok(FlvData::Tag(tag)))
+            }
+            other => Some(other),
+        };
+        std::future::ready(item)
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tag(tag_type: u8, timestamp: u32, data: &[u8]) -> Vec<u8> {
+        let size = data.len() as u32;
+        let mut bytes = vec![tag_type];
+        bytes.extend_from_slice(&size.to_be_bytes()[1..]);
+        bytes.extend_from_slice(&timestamp.to_be_bytes()[1..]);
+        bytes.push((timestamp >> 24) as u8);
+        bytes.extend_from_slice(&[0, 0, 0]);
+        bytes.extend_from_slice(data);
+        bytes.extend_from_slice(&(size + 11).to_be_bytes());
+        bytes
+    }
+
+    #[test]
+    fn finds_the_next_tag_after_a_partial_one() {
+        let first = tag(9, 40, &[0x27, 0x01, 0, 0, 0, 1, 2, 3]);
+        let second = tag(8, 0x0100_0042, &[0xAF, 0x01, 4, 5]);
+        // Start in the middle of the first tag
+        let mut buf = first[5..].to_vec();
+        buf.extend_from_slice(&second);
+
+        let start = find_tag_boundary(&buf, 0).unwrap();
+        assert_eq!(start, first.len() - 5);
+        assert_eq!(tag_timestamp(&buf[start..]), 0x0100_0042);
+
+        // Not enough data yet: scanning continues from the undecided candidate
+        let truncated = &buf[..start + 12];
+        assert_eq!(find_tag_boundary(truncated, 0), Err(start));
+    }
+
+    #[tokio::test]
+    async fn partial_file_resumes_after_last_complete_tag() {
+        let mut file = b"FLV\x01\x05\x00\x00\x00\x09\x00\x00\x00\x00".to_vec();
+        // AVC sequence header, a frame and an AAC sequence header
+        file.extend(tag(9, 0, &[0x17, 0x00, 0, 0, 0, 1, 0x64]));
+        file.extend(tag(9, 33, &[0x27, 0x01, 0, 0, 0, 9]));
+        file.extend(tag(8, 40, &[0xAF, 0x00, 0x12, 0x10]));
+        let complete = file.len() as u64;
+        file.extend(&tag(9, 66, &[0x27, 0x01, 0, 0, 0, 9])[..7]);
+
+        let path = std::env::temp_dir().join(format!("mesio-resume-{}.flv", std::process::id()));
+        tokio::fs::write(&path, &file).await.unwrap();
+        let resume = FlvResumePoint::from_partial_file(&path).await;
+        tokio::fs::remove_file(&path).await.unwrap();
+        let resume = resume.unwrap().unwrap();
+
+        assert_eq!(resume.offset, complete);
+        assert_eq!(resume.timestamp_ms, 40);
+        assert_eq!(resume.sequence_headers.len(), 2);
+        assert!(resume.sequence_headers[0].is_video_sequence_header());
+        assert!(resume.sequence_headers[1].is_audio_sequence_header());
+
+        // Resumed tags that went back in time are shifted past the partial output
+        let resumed = futures::stream::iter(vec![
+            Ok::<_, ()>(FlvData::Header(FlvHeader::new(true, true))),
+            Ok(FlvData::Tag(FlvTag::new(
+                10,
+                0,
+                flv::tag::FlvTagType::Video,
+                false,
+                Bytes::from_static(&[0x27, 0x01, 0, 0, 0, 9]),
+            ))),
+        ]);
+        let items: Vec<_> = continue_stream(resumed, &resume, 10)
+            .map(Result::unwrap)
+            .collect()
+            .await;
+        let timestamps: Vec<_> = items
+            .iter()
+            .filter_map(|item| match item {
+                FlvData::Tag(tag) => Some(tag.timestamp_ms),
+                _ => None,
+            })
+            .collect();
+        assert!(items[0].is_header());
+        assert_eq!(timestamps, [40, 40, 40]);
+    }
+}
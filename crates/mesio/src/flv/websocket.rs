@@ -0,0 +1,120 @@
+//! # FLV over WebSocket
+//!
+//! Some CDNs deliver FLV over `ws://` or `wss://`, splitting the byte stream across binary
+//! messages at arbitrary points. The connection carries the same headers, cookies and query
+//! parameters as HTTP requests.
+
+use std::time::SystemTime;
+
+use bytes::Bytes;
+use futures::StreamExt;
+use reqwest::{StatusCode, Url, header};
+use tokio_tungstenite::WebSocketStream;
+use tokio_tungstenite::tungstenite::{self, Message, client::IntoClientRequest};
+use tracing::debug;
+
+use crate::transport::{self, Transport};
+use crate::{DownloadError, DownloaderConfig};
+
+pub(crate) type FlvWebSocket = WebSocketStream<Box<dyn Transport>>;
+
+/// Open a WebSocket connection to `url` for an FLV stream.
+pub(crate) async fn connect(
+    url: &Url,
+    config: &DownloaderConfig,
+) -> Result<FlvWebSocket, DownloadError> {
+    let tls = match url.scheme() {
+        "ws" => false,
+        "wss" => true,
+        _ => {
+            return Err(DownloadError::invalid_url(
+                url.as_str(),
+                "not a WebSocket URL",
+            ));
+        }
+    };
+    let host = url
+        .host_str()
+        .ok_or_else(|| DownloadError::invalid_url(url.as_str(), "missing host"))?;
+    let port = url
+        .port_or_known_default()
+        .unwrap_or(if tls { 443 } else { 80 });
+
+    let mut url = url.clone();
+    if !config.params.is_empty() {
+        url.query_pairs_mut().extend_pairs(&config.params);
+    }
+    let mut request = url
+        .as_str()
+        .into_client_request()
+        .map_err(|e| websocket_error(&url, e))?;
+    let headers = request.headers_mut();
+    if !config.headers.contains_key(header::USER_AGENT)
+        && let Ok(user_agent) = header::HeaderValue::from_str(&config.user_agent)
+    {
+        headers.insert(header::USER_AGENT, user_agent);
+    }
+    for (name, value) in &config.headers {
+        headers.insert(name, value.clone());
+    }
+    for (name, value) in &config.header_templates.render(SystemTime::now()) {
+        headers.insert(name, value.clone());
+    }
+    // As with reqwest, an explicit Cookie header takes precedence over the jar
+    if !headers.contains_key(header::COOKIE)
+        && let Some(cookie) = config
+            .cookie_jar
+            .as_ref()
+            .and_then(|jar| jar.cookie_header(&url))
+    {
+        headers.insert(header::COOKIE, cookie);
+    }
+
+    let stream = transport::connect(host, port, tls, config).await?;
+    let handshake = tokio_tungstenite::client_async(request, stream);
+    let (socket, response) = match config.connect_timeout.is_zero() {
+        true => handshake.await,
+        false => tokio::time::timeout(config.connect_timeout, handshake)
+            .await
+            .map_err(|_| DownloadError::Timeout {
+                reason: format!("WebSocket handshake with {host}"),
+            })?,
+    }
+    .map_err(|e| websocket_error(&url, e))?;
+    debug!(url = %url, status = %response.status(), "WebSocket connected");
+    Ok(socket)
+}
+
+/// The next piece of the FLV byte stream, or `None` once the server closed the connection.
+pub(crate) async fn next_chunk(
+    socket: &mut FlvWebSocket,
+    url: &Url,
+) -> Result<Option<Bytes>, DownloadError> {
+    while let Some(message) = socket.next().await {
+        match message.map_err(|e| websocket_error(url, e))? {
+            Message::Binary(data) if !data.is_empty() => return Ok(Some(data)),
+            Message::Text(text) => debug!(text = %text, "Ignoring WebSocket text message"),
+            Message::Close(frame) => {
+                debug!(?frame, "WebSocket closed by the server");
+                return Ok(None);
+            }
+            // Pings are answered by tungstenite while reading
+            _ => {}
+        }
+    }
+    Ok(None)
+}
+
+fn websocket_error(url: &Url, error: tungstenite::Error) -> DownloadError {
+    match error {
+        tungstenite::Error::Io(e) => e.into(),
+        tungstenite::Error::Http(response) => DownloadError::http_status(
+            StatusCode::from_u16(response.status().as_u16()).unwrap_or(StatusCode::BAD_GATEWAY),
+            url.to_string(),
+            "websocket_handshake",
+        ),
+        e => DownloadError::Protocol {
+            reason: format!("WebSocket: {e}"),
+        },
+    }
+}
@@ -0,0 +1,126 @@
+//! # Header Templates
+//!
+//! Headers whose values are rendered for every request, for platforms that expect values such as
+//! a `Referer` naming the room or a current timestamp. Placeholders are written as `{name}`:
+//! `{timestamp}` and `{timestamp_ms}` are the current Unix time in seconds and milliseconds, and
+//! any other name is looked up in the template variables (e.g. `{room_id}`). Unknown placeholders
+//! are left as they are.
+
+use std::collections::HashMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use tracing::warn;
+
+/// Header templates and the variables they are rendered with.
+#[derive(Debug, Clone, Default)]
+pub struct HeaderTemplates {
+    templates: Vec<(HeaderName, String)>,
+    vars: HashMap<String, String>,
+}
+
+impl HeaderTemplates {
+    /// Create an empty set of templates
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether no header is templated
+    pub fn is_empty(&self) -> bool {
+        self.templates.is_empty()
+    }
+
+    /// Add a header rendered from `template`, replacing an earlier template of the same name
+    pub fn add(&mut self, name: HeaderName, template: impl Into<String>) {
+        self.templates.retain(|(existing, _)| *existing != name);
+        self.templates.push((name, template.into()));
+    }
+
+    /// Set the value of the `{name}` placeholder
+    pub fn set_var(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        self.vars.insert(name.into(), value.into());
+    }
+
+    /// Render the headers as of `now`. Headers that render to an invalid value are left out.
+    pub fn render(&self, now: SystemTime) -> HeaderMap {
+        let since_epoch = now.duration_since(UNIX_EPOCH).unwrap_or_default();
+        let timestamp = since_epoch.as_secs().to_string();
+        let timestamp_ms = since_epoch.as_millis().to_string();
+        let lookup = |name: &str| match name {
+            "timestamp" => Some(timestamp.as_str()),
+            "timestamp_ms" => Some(timestamp_ms.as_str()),
+            _ => self.vars.get(name).map(String::as_str),
+        };
+
+        let mut headers = HeaderMap::with_capacity(self.templates.len());
+        for (name, template) in &self.templates {
+            let value = render(template, lookup);
+            match HeaderValue::from_str(&value) {
+                Ok(value) => {
+                    headers.insert(name.clone(), value);
+                }
+                Err(e) => warn!(header = %name, error = %e, "Skipping invalid templated header"),
+            }
+        }
+        headers
+    }
+}
+
+fn render<'a>(template: &str, lookup: impl Fn(&str) -> Option<&'a str>) -> String {
+    let mut rendered = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        rendered.push_str(&rest[..start]);
+        let placeholder = &rest[start..];
+        match placeholder.find('}') {
+            Some(end) => {
+                match lookup(&placeholder[1..end]) {
+                    Some(value) => rendered.push_str(value),
+                    None => rendered.push_str(&placeholder[..=end]),
+                }
+                rest = &placeholder[end + 1..];
+            }
+            None => {
+                rendered.push_str(placeholder);
+                rest = "";
+            }
+        }
+    }
+    rendered.push_str(rest);
+    rendered
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use reqwest::header::{COOKIE, REFERER};
+
+    use super::*;
+
+    #[test]
+    fn placeholders_are_rendered_per_request() {
+        let mut templates = HeaderTemplates::new();
+        templates.set_var("room_id", "6154037");
+        templates.add(REFERER, "https://live.example.com/{room_id}");
+        templates.add(COOKIE, "ts={timestamp}; ms={timestamp_ms}; {unknown}");
+
+        let headers = templates.render(UNIX_EPOCH + Duration::from_millis(1_700_000_000_123));
+        assert_eq!(headers[REFERER], "https://live.example.com/6154037");
+        assert_eq!(
+            headers[COOKIE],
+            "ts=1700000000; ms=1700000000123; {unknown}"
+        );
+    }
+
+    #[test]
+    fn later_templates_replace_earlier_ones() {
+        let mut templates = HeaderTemplates::new();
+        templates.add(REFERER, "https://a.example.com/");
+        templates.add(REFERER, "https://b.example.com/{");
+
+        let headers = templates.render(SystemTime::now());
+        assert_eq!(headers.len(), 1);
+        assert_eq!(headers[REFERER], "https://b.example.com/{");
+    }
+}
@@ -12,7 +12,10 @@ mod soop_processor;
 mod twitch_processor;
 
 // Re-exports for easier access
-pub use config::{BufferLimits, GapSkipStrategy, HlsConfig, HlsEngineConfig, IdentityPolicyConfig};
+pub use config::{
+    BufferLimits, GapSkipStrategy, HlsAudioRendition, HlsConfig, HlsEngineConfig,
+    IdentityPolicyConfig,
+};
 pub use error::HlsDownloaderError;
 pub use events::{GapSkipReason, HlsStreamEvent};
 pub use hls_downloader::HlsDownloader;
@@ -154,6 +154,13 @@ pub struct HlsPlaylistConfig {
     pub adaptive_refresh_min_interval: Duration,
     /// Maximum adaptive refresh interval (won't go above this)
     pub adaptive_refresh_max_interval: Duration,
+    /// Follow LL-HLS playlists: download partial segments (`EXT-X-PART`) and
+    /// preload hints of the segment still being produced, and use blocking
+    /// playlist reload when the server supports it
+    pub low_latency_enabled: bool,
+    /// Record an audio rendition (`EXT-X-MEDIA:TYPE=AUDIO`) of the selected variant
+    /// instead of the variant itself
+    pub audio_rendition: Option<HlsAudioRendition>,
 }
 
 impl Default for HlsPlaylistConfig {
@@ -168,10 +175,21 @@ impl Default for HlsPlaylistConfig {
             adaptive_refresh_enabled: true,
             adaptive_refresh_min_interval: Duration::from_millis(500),
             adaptive_refresh_max_interval: Duration::from_secs(3),
+            low_latency_enabled: false,
+            audio_rendition: None,
         }
     }
 }
 
+/// Which audio rendition of the selected variant's audio group to record
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HlsAudioRendition {
+    /// The `DEFAULT=YES` rendition, otherwise the first one of the group
+    Default,
+    /// The rendition whose `LANGUAGE` or `NAME` matches, ignoring ASCII case
+    Matching(String),
+}
+
 #[derive(Debug, Clone, Default)]
 pub enum HlsVariantSelectionPolicy {
     #[default]
@@ -25,6 +25,7 @@ pub mod input;
 pub mod payload;
 pub mod planner;
 pub mod reactor;
+pub mod sample_aes;
 pub mod store;
 pub mod watcher;
 
@@ -48,7 +49,7 @@ use crate::session::EventSink;
 
 use assembler::SequenceAssembler;
 use budget::ByteBudget;
-use crypto::{CryptoBackend, CryptoExecutor, KeyCache};
+use crypto::{CryptoBackend, CryptoExecutor, InitProtectionCache, KeyCache};
 use fetch::FetchContext;
 use identity::{SegmentIdentityPolicy, StripQueryIdentity};
 use planner::PlannerContext;
@@ -57,6 +58,10 @@ use reactor::{ReactorConfig, run_reactor};
 use store::StoreConfig;
 use watcher::PlaylistWatcher;
 
+/// SAMPLE-AES init protections kept per source; an init only changes on
+/// `EXT-X-MAP` updates, so a handful covers any live window.
+const INIT_PROTECTION_CACHE_ENTRIES: u64 = 16;
+
 pub struct EngineHandles {
     pub watcher: JoinHandle<()>,
     pub reactor: JoinHandle<Terminal>,
@@ -164,6 +169,7 @@ pub async fn start_with_events(
             config.decryption_config.key_cache_ttl,
             engine.key_cache_max_entries,
         ),
+        init_protection: InitProtectionCache::new(INIT_PROTECTION_CACHE_ENTRIES),
         cache_manager,
         metrics: performance_metrics.clone(),
         cancel: cancel.clone(),
@@ -222,6 +228,9 @@ pub async fn start_with_events(
             retry_budget: engine.lifecycle_retry_budget,
             retry_delay_base: engine.lifecycle_retry_delay_base,
             retry_delay_max: engine.lifecycle_retry_delay_max,
+            retry_multiplier: config.base.retry.multiplier,
+            retry_jitter: config.base.retry.jitter,
+            retry_on: config.base.retry.retry_on,
             fallback_size_estimate: engine.initial_segment_size_estimate,
             max_segment_size: engine.max_segment_size_bytes,
             max_retained_inits: engine.max_retained_inits,
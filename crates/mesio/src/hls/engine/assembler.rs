@@ -5,7 +5,9 @@
 //! single producer of consumer-facing events. Reorders media by MSN, gates
 //! fMP4 media behind init segments, applies gap policies, and keeps draining
 //! input even under reorder-buffer pressure (the next item may be the one
-//! that unblocks the buffer).
+//! that unblocks the buffer). An LL-HLS segment followed as parts is emitted
+//! part by part as the parts arrive; the cursor moves past it once the planner
+//! reports its final part count (`PartsComplete`).
 //!
 //! Terminal semantics: an explicit `End` item (authoritative ENDLIST path)
 //! drains the buffer in order and emits `StreamEnded`; `Fatal` drops the
@@ -186,6 +188,38 @@ impl BufferedPayload {
     }
 }
 
+/// The parts of one LL-HLS segment, emitted in index order.
+#[derive(Debug, Default)]
+struct PartAssembly {
+    parts: BTreeMap<u32, BufferedPayload>,
+    /// Index of the next part to emit.
+    next_part: u32,
+    /// Final part count, known once the segment is published in full.
+    total: Option<u32>,
+}
+
+impl PartAssembly {
+    fn is_complete(&self) -> bool {
+        self.total.is_some_and(|total| self.next_part >= total)
+    }
+
+    fn size_bytes(&self) -> usize {
+        self.parts
+            .values()
+            .map(|buffered| buffered.size_bytes)
+            .sum()
+    }
+}
+
+/// Progress of the part assembly at the cursor.
+enum PartProgress {
+    Complete,
+    /// Waiting for the next part or for the final part count.
+    Waiting,
+    /// The next part's init terminally failed.
+    InitFailed,
+}
+
 enum EmitOutcome {
     Continue,
     /// The consumer channel closed: every send fails from here on.
@@ -208,6 +242,9 @@ pub struct SequenceAssembler {
     input_rx: mpsc::Receiver<AssemblerInput>,
     event_tx: mpsc::Sender<Result<HlsStreamEvent, HlsDownloaderError>>,
     reorder_buffer: BTreeMap<u64, BufferedPayload>,
+    /// LL-HLS segments delivered as parts, keyed by MSN. Their bytes count
+    /// toward `current_buffer_bytes` like whole segments.
+    part_assemblies: BTreeMap<u64, PartAssembly>,
     /// fMP4 init segments keyed by the MSN at which they become applicable;
     /// kept out of `reorder_buffer` because it is keyed by MSN and an init
     /// and a media segment can share one.
@@ -259,6 +296,7 @@ impl SequenceAssembler {
             input_rx,
             event_tx,
             reorder_buffer: BTreeMap::new(),
+            part_assemblies: BTreeMap::new(),
             pending_init_segments: BTreeMap::new(),
             has_seen_init_segment: false,
             is_fmp4_stream: false,
@@ -295,20 +333,26 @@ impl SequenceAssembler {
     /// count/duration thresholds are bypassed.
     fn buffer_at_limit(&self) -> bool {
         let limits = &self.config.output_config.buffer_limits;
-        (limits.max_segments > 0 && self.reorder_buffer.len() >= limits.max_segments)
+        let segments = self.reorder_buffer.len() + self.part_assemblies.len();
+        (limits.max_segments > 0 && segments >= limits.max_segments)
             || (limits.max_bytes > 0 && self.current_buffer_bytes >= limits.max_bytes)
     }
 
     /// Init-readiness of the buffered media segment at `msn`.
     fn init_state(&self, msn: u64) -> InitState {
-        let Some(buffered) = self.reorder_buffer.get(&msn) else {
-            return InitState::Ready;
-        };
-        if !matches!(buffered.payload, SegmentPayload::Mp4Media { .. }) {
+        match self.reorder_buffer.get(&msn) {
+            Some(buffered) => self.payload_init_state(&buffered.payload),
+            None => InitState::Ready,
+        }
+    }
+
+    /// Init-readiness of one media payload (a whole segment or a part).
+    fn payload_init_state(&self, payload: &SegmentPayload) -> InitState {
+        if !matches!(payload, SegmentPayload::Mp4Media { .. }) {
             // TS (and any non-fMP4 media) needs no init.
             return InitState::Ready;
         }
-        match buffered.payload.descriptor().init_key.as_ref() {
+        match payload.descriptor().init_key.as_ref() {
             Some(key) => {
                 if self.seen_init_keys.contains(key) {
                     InitState::Ready
@@ -469,6 +513,16 @@ impl SequenceAssembler {
                                 return;
                             }
                         }
+                        Some(AssemblerInput::PartsComplete { msn, parts }) => {
+                            if msn >= self.expected_next_media_sequence
+                                && self.dead.run_end(msn).is_none()
+                            {
+                                self.part_assemblies.entry(msn).or_default().total = Some(parts);
+                                if matches!(self.try_emit().await, EmitOutcome::DownstreamClosed) {
+                                    return;
+                                }
+                            }
+                        }
                         Some(AssemblerInput::Notice(notice)) => {
                             let event = match notice {
                                 PlaylistNotice::PlaylistRefreshed {
@@ -579,6 +633,10 @@ impl SequenceAssembler {
             return EmitOutcome::Continue;
         }
 
+        if let Some(index) = payload.descriptor().part {
+            return self.handle_part(msn, index, payload).await;
+        }
+
         let buffered = BufferedPayload::new(payload);
         self.current_buffer_bytes += buffered.size_bytes;
         // Replacing an existing entry at this MSN must release the old entry's
@@ -621,6 +679,80 @@ impl SequenceAssembler {
         self.try_emit().await
     }
 
+    async fn handle_part(&mut self, msn: u64, index: u32, payload: SegmentPayload) -> EmitOutcome {
+        let is_new_msn = !self.part_assemblies.contains_key(&msn);
+        let assembly = self.part_assemblies.entry(msn).or_default();
+        if index < assembly.next_part {
+            debug!(msn, index, "rejecting already-emitted part");
+            self.metrics
+                .segments_rejected_stale
+                .fetch_add(1, Ordering::Relaxed);
+            return EmitOutcome::Continue;
+        }
+        let buffered = BufferedPayload::new(payload);
+        self.current_buffer_bytes += buffered.size_bytes;
+        // A preload-hint download and its published part share a key, but a
+        // part whose URI changed is fetched again: keep one copy per index.
+        if let Some(replaced) = assembly.parts.insert(index, buffered) {
+            self.current_buffer_bytes = self
+                .current_buffer_bytes
+                .saturating_sub(replaced.size_bytes);
+        }
+
+        if is_new_msn
+            && self.is_live_stream
+            && let Some(gap_state) = self.gap_state.as_mut()
+            && msn > gap_state.missing_sequence
+        {
+            gap_state.segments_since_gap += 1;
+        }
+
+        self.try_emit().await
+    }
+
+    /// Emit the consecutive arrived parts of the segment at `msn`.
+    async fn emit_parts(&mut self, msn: u64) -> Result<PartProgress, ()> {
+        loop {
+            let Some(assembly) = self.part_assemblies.get(&msn) else {
+                return Ok(PartProgress::Complete);
+            };
+            if assembly.is_complete() {
+                self.part_assemblies.remove(&msn);
+                return Ok(PartProgress::Complete);
+            }
+            let index = assembly.next_part;
+            let Some(buffered) = assembly.parts.get(&index) else {
+                return Ok(PartProgress::Waiting);
+            };
+            match self.payload_init_state(&buffered.payload) {
+                InitState::Ready => {}
+                InitState::Gated => return Ok(PartProgress::Waiting),
+                InitState::Failed => return Ok(PartProgress::InitFailed),
+            }
+
+            let Some(assembly) = self.part_assemblies.get_mut(&msn) else {
+                return Ok(PartProgress::Complete);
+            };
+            let Some(buffered) = assembly.parts.remove(&index) else {
+                return Ok(PartProgress::Waiting);
+            };
+            assembly.next_part += 1;
+            self.current_buffer_bytes = self
+                .current_buffer_bytes
+                .saturating_sub(buffered.size_bytes);
+            trace!(msn, index, "emitting part");
+            self.emit_payload(buffered.payload).await?;
+        }
+    }
+
+    fn remove_part_assembly(&mut self, msn: u64) -> Option<PartAssembly> {
+        let assembly = self.part_assemblies.remove(&msn)?;
+        self.current_buffer_bytes = self
+            .current_buffer_bytes
+            .saturating_sub(assembly.size_bytes());
+        Some(assembly)
+    }
+
     /// Emit everything emittable from the reorder buffer, advancing the
     /// cursor through dead ranges and applying gap policy. Mirrors the
     /// while-loop shape of the original OutputManager.
@@ -673,7 +805,76 @@ impl SequenceAssembler {
                 continue;
             }
 
-            let Some(first_msn) = self.reorder_buffer.keys().next().copied() else {
+            // Part assemblies left below the cursor by a skip are stale.
+            while let Some(msn) = self.part_assemblies.keys().next().copied()
+                && msn < self.expected_next_media_sequence
+            {
+                self.remove_part_assembly(msn);
+                self.metrics
+                    .segments_rejected_stale
+                    .fetch_add(1, Ordering::Relaxed);
+            }
+
+            let cursor = self.expected_next_media_sequence;
+            if self.part_assemblies.contains_key(&cursor) {
+                match self.emit_parts(cursor).await {
+                    Err(()) => return EmitOutcome::DownstreamClosed,
+                    Ok(PartProgress::Complete) => {
+                        self.expected_next_media_sequence += 1;
+                        self.gap_state = None;
+                        self.dead.prune_below(self.expected_next_media_sequence);
+                        continue;
+                    }
+                    Ok(PartProgress::InitFailed) => {
+                        warn!(
+                            msn = cursor,
+                            "skipping parts whose init segment terminally failed"
+                        );
+                        self.remove_part_assembly(cursor);
+                        self.dead.insert(cursor, cursor);
+                        continue;
+                    }
+                    Ok(PartProgress::Waiting) => {
+                        let untouched = self.part_assemblies.get(&cursor).is_some_and(|assembly| {
+                            assembly.next_part == 0 && assembly.total.is_none()
+                        });
+                        if untouched && self.reorder_buffer.contains_key(&cursor) {
+                            // The planner fell back to the whole segment
+                            // before any part was emitted.
+                            self.remove_part_assembly(cursor);
+                            continue;
+                        }
+                        if self.is_live_stream && self.buffer_at_limit() {
+                            warn!(
+                                msn = cursor,
+                                "abandoning incomplete LL-HLS segment under buffer pressure"
+                            );
+                            self.remove_part_assembly(cursor);
+                            let Some(to) = cursor.checked_add(1) else {
+                                // The abort always ends the stream.
+                                self.abort_u64_max_cursor().await.ok();
+                                return EmitOutcome::DownstreamClosed;
+                            };
+                            if self
+                                .emit_gap_skipped(cursor, to, GapSkipReason::BufferPressure)
+                                .await
+                                .is_err()
+                            {
+                                return EmitOutcome::DownstreamClosed;
+                            }
+                            self.expected_next_media_sequence = to;
+                            self.gap_state = None;
+                            self.dead.prune_below(to);
+                            continue;
+                        }
+                        break;
+                    }
+                }
+            }
+
+            let first_whole = self.reorder_buffer.keys().next().copied();
+            let first_part = self.part_assemblies.keys().next().copied();
+            let Some(first_msn) = first_whole.into_iter().chain(first_part).min() else {
                 break;
             };
 
@@ -758,10 +959,10 @@ impl SequenceAssembler {
                 if is_new_gap {
                     self.metrics.gaps_detected.fetch_add(1, Ordering::Relaxed);
                     let mut gap = GapState::new(self.expected_next_media_sequence);
-                    gap.segments_since_gap = self
-                        .reorder_buffer
-                        .range(self.expected_next_media_sequence + 1..)
-                        .count() as u64;
+                    let later = self.expected_next_media_sequence + 1..;
+                    gap.segments_since_gap = (self.reorder_buffer.range(later.clone()).count()
+                        + self.part_assemblies.range(later).count())
+                        as u64;
                     self.gap_state = Some(gap);
                 }
 
@@ -991,7 +1192,17 @@ impl SequenceAssembler {
     /// Authoritative-end drain: emit everything left in MSN order. fMP4 media
     /// whose governing init never arrived is surfaced as a visible gap.
     async fn flush_in_order(&mut self) -> Result<(), ()> {
-        while let Some(msn) = self.reorder_buffer.keys().next().copied() {
+        loop {
+            let first_whole = self.reorder_buffer.keys().next().copied();
+            let first_part = self.part_assemblies.keys().next().copied();
+            let Some(msn) = first_whole.into_iter().chain(first_part).min() else {
+                break;
+            };
+            if first_part == Some(msn) {
+                self.flush_parts(msn).await?;
+                continue;
+            }
+
             match self.init_state(msn) {
                 InitState::Ready => {}
                 InitState::Gated => {
@@ -1022,6 +1233,36 @@ impl SequenceAssembler {
         Ok(())
     }
 
+    /// Drain the parts of the segment at `msn` in index order. A part whose
+    /// init never arrived (or failed) ends the segment as a visible gap.
+    async fn flush_parts(&mut self, msn: u64) -> Result<(), ()> {
+        let Some(assembly) = self.remove_part_assembly(msn) else {
+            return Ok(());
+        };
+        if assembly.next_part == 0
+            && assembly.total.is_none()
+            && self.reorder_buffer.contains_key(&msn)
+        {
+            // The whole segment superseded the parts before any was emitted.
+            return Ok(());
+        }
+        self.remove_buffered_payload(msn);
+        for buffered in assembly.parts.into_values() {
+            if !matches!(self.payload_init_state(&buffered.payload), InitState::Ready) {
+                warn!(
+                    msn,
+                    "skipping LL-HLS parts during flush: governing init unavailable"
+                );
+                let to = msn.saturating_add(1);
+                return self
+                    .emit_gap_skipped(msn, to, GapSkipReason::Upstream)
+                    .await;
+            }
+            self.emit_payload(buffered.payload).await?;
+        }
+        Ok(())
+    }
+
     /// Live pruning by count and buffered duration (only entries below the
     /// emit cursor are eligible — entries at/above it are pending output).
     fn prune_reorder_buffer(&mut self) {
@@ -1103,6 +1344,7 @@ mod tests {
             discontinuity: false,
             encryption: None,
             init_key: None,
+            part: None,
             media_segment: Arc::new(m3u8_rs::MediaSegment {
                 uri: uri.to_string(),
                 duration: 2.0,
@@ -1730,6 +1972,43 @@ mod tests {
         let _ = h.join.await;
     }
 
+    fn ts_part(msn: u64, index: u32) -> AssemblerInput {
+        let uri = format!("https://e.com/seg{msn}.{index}.ts");
+        let mut d = (*descriptor(&uri, msn, SegmentKind::Part)).clone();
+        d.part = Some(index);
+        AssemblerInput::Payload(SegmentPayload::Ts {
+            data: Bytes::from(format!("part{msn}.{index}")),
+            descriptor: Arc::new(d),
+        })
+    }
+
+    #[tokio::test]
+    async fn parts_emit_in_order_and_complete_their_segment() {
+        let mut h = spawn_assembler(HlsConfig::default(), true, 100);
+        h.input_tx.send(ts_part(100, 1)).await.unwrap();
+        h.input_tx.send(ts_payload(101)).await.unwrap();
+        h.input_tx.send(ts_part(100, 0)).await.unwrap();
+        // Parts stream out as they become contiguous; 101 waits for the count.
+        let uris = collect_data_uris(&mut h, 2).await;
+        assert_eq!(
+            uris,
+            ["https://e.com/seg100.0.ts", "https://e.com/seg100.1.ts"]
+        );
+
+        h.input_tx.send(ts_part(100, 2)).await.unwrap();
+        h.input_tx
+            .send(AssemblerInput::PartsComplete { msn: 100, parts: 3 })
+            .await
+            .unwrap();
+        let uris = collect_data_uris(&mut h, 2).await;
+        assert_eq!(
+            uris,
+            ["https://e.com/seg100.2.ts", "https://e.com/seg101.ts"]
+        );
+        h.cancel.cancel();
+        let _ = h.join.await;
+    }
+
     #[test]
     fn dead_ranges_merge_and_query() {
         let mut dead = DeadRanges::default();
@@ -12,9 +12,13 @@ use std::time::Duration;
 use aes::Aes128;
 use bytes::Bytes;
 use cipher::{BlockModeDecrypt, KeyIvInit, block_padding::Pkcs7};
+use tokio::sync::Notify;
 
 use crate::hls::HlsDownloaderError;
 
+use super::identity::SegmentKey;
+use super::sample_aes::CmafProtection;
+
 type Aes128CbcDec = cbc::Decryptor<Aes128>;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -52,15 +56,24 @@ impl CryptoExecutor {
         key: [u8; 16],
         iv: [u8; 16],
     ) -> Result<Bytes, HlsDownloaderError> {
+        self.run(move || decrypt_aes128_cbc_sync(data, &key, &iv))
+            .await?
+    }
+
+    /// Run arbitrary CPU-bound decryption work (e.g. SAMPLE-AES container
+    /// rewriting) on this executor's backend.
+    pub async fn run<T, F>(&self, work: F) -> Result<T, HlsDownloaderError>
+    where
+        F: FnOnce() -> T + Send + 'static,
+        T: Send + 'static,
+    {
         match self.backend {
-            CryptoBackend::Inline => decrypt_aes128_cbc_sync(data, &key, &iv),
-            CryptoBackend::TokioBlocking => {
-                tokio::task::spawn_blocking(move || decrypt_aes128_cbc_sync(data, &key, &iv))
-                    .await
-                    .map_err(|e| HlsDownloaderError::Decryption {
-                        reason: format!("decryption offload task failed: {e}"),
-                    })?
-            }
+            CryptoBackend::Inline => Ok(work()),
+            CryptoBackend::TokioBlocking => tokio::task::spawn_blocking(work).await.map_err(|e| {
+                HlsDownloaderError::Decryption {
+                    reason: format!("decryption offload task failed: {e}"),
+                }
+            }),
         }
     }
 }
@@ -126,6 +139,54 @@ impl KeyCache {
     }
 }
 
+/// Protection parameters of SAMPLE-AES (`cbcs`) init segments, keyed by the
+/// init segment identity.
+///
+/// Media segments are fetched concurrently with their init segment, but their
+/// samples can only be located and decrypted with the init's `tenc` defaults.
+/// A media fetch therefore waits here for the init fetch to publish them.
+#[derive(Debug, Clone)]
+pub struct InitProtectionCache {
+    entries: moka::sync::Cache<SegmentKey, Arc<CmafProtection>>,
+    published: Arc<Notify>,
+}
+
+impl InitProtectionCache {
+    pub fn new(max_entries: u64) -> Self {
+        Self {
+            entries: moka::sync::Cache::new(max_entries),
+            published: Arc::new(Notify::new()),
+        }
+    }
+
+    pub fn insert(&self, init: SegmentKey, protection: Arc<CmafProtection>) {
+        self.entries.insert(init, protection);
+        self.published.notify_waiters();
+    }
+
+    /// The protection of `init`, waiting up to `timeout` for it to be
+    /// published. `None` when the init never completed in time.
+    pub async fn wait_for(
+        &self,
+        init: &SegmentKey,
+        timeout: Duration,
+    ) -> Option<Arc<CmafProtection>> {
+        let wait = async {
+            loop {
+                // Register before checking so a publish in between is not missed.
+                let published = self.published.notified();
+                tokio::pin!(published);
+                published.as_mut().enable();
+                if let Some(protection) = self.entries.get(init) {
+                    return protection;
+                }
+                published.await;
+            }
+        };
+        tokio::time::timeout(timeout, wait).await.ok()
+    }
+}
+
 /// Validate fetched key material: AES-128 keys are exactly 16 bytes.
 pub fn validate_key_bytes(raw: &[u8], identity: &str) -> Result<[u8; 16], HlsDownloaderError> {
     <[u8; 16]>::try_from(raw).map_err(|_| HlsDownloaderError::Decryption {
@@ -15,6 +15,10 @@ use super::identity::SegmentKey;
 pub enum SegmentSource {
     Playlist,
     PlaylistPrefetch,
+    /// An LL-HLS `EXT-X-PRELOAD-HINT`: the server holds the request open until
+    /// the announced part exists. Keyed like the part it becomes, so the
+    /// published part dedups against it.
+    PreloadHint,
 }
 
 /// Normalized encryption metadata, created by the planner so the payload
@@ -36,7 +40,11 @@ pub struct EncryptionDescriptor {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum EncryptionMethod {
     Aes128Cbc,
-    /// Any method the processor cannot decrypt yet (SAMPLE-AES, AES-256, ...).
+    /// SAMPLE-AES: per-sample AES-128-CBC inside the container (H.264/AAC in
+    /// MPEG-TS, `cbcs` in fMP4). Decryption is container-aware; see
+    /// `engine::sample_aes`.
+    SampleAes,
+    /// Any method the processor cannot decrypt yet (AES-256, ...).
     /// Carries the raw method token for diagnostics; always maps to a terminal
     /// segment failure (`FailureClass::UnsupportedCrypto`).
     Unsupported(Arc<str>),
@@ -75,6 +83,9 @@ pub struct SegmentDescriptor {
     /// having arrived (or terminally failed), so a rotated init cannot lose
     /// the race against the first media segment it covers.
     pub init_key: Option<SegmentKey>,
+    /// For LL-HLS parts: the index of the part within segment `msn`. The
+    /// assembler emits a segment's parts in index order.
+    pub part: Option<u32>,
     /// Parser-native segment carried for output compatibility (`HlsData`
     /// construction). Identity and scheduling use the typed fields above.
     pub media_segment: Arc<m3u8_rs::MediaSegment>,
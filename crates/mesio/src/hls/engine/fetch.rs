@@ -10,6 +10,7 @@ use std::time::{Duration, Instant};
 
 use bytes::{Bytes, BytesMut};
 use futures::StreamExt;
+use hls::DeliveryMarker;
 use reqwest::StatusCode;
 use reqwest::header::{CONTENT_RANGE, HeaderMap, HeaderValue, RANGE};
 use tokio_util::sync::CancellationToken;
@@ -23,13 +24,17 @@ use crate::hls::HlsDownloaderError;
 use crate::hls::config::HlsConfig;
 use crate::hls::metrics::PerformanceMetrics;
 use crate::hls::segment_utils::is_m4s_segment;
+use crate::retry::RetryPolicy;
 use crate::session::{DownloadEvent, EventSink, ResourceId};
 
 use super::budget::{ByteBudget, ByteReservation};
-use super::crypto::{CryptoExecutor, KeyCache, validate_key_bytes};
-use super::descriptor::{EffectiveIv, EncryptionDescriptor, EncryptionMethod, KeyFormat};
+use super::crypto::{CryptoExecutor, InitProtectionCache, KeyCache, validate_key_bytes};
+use super::descriptor::{
+    EffectiveIv, EncryptionDescriptor, EncryptionMethod, KeyFormat, SegmentDescriptor,
+};
 use super::identity::{ByteRangeKey, SegmentKind};
 use super::payload::SegmentPayload;
+use super::sample_aes::{self, SampleAesError};
 use super::store::{FailureClass, ReadyJob, SegmentOutcome};
 
 /// Everything a fetch-and-process task needs, shared across all tasks.
@@ -39,6 +44,7 @@ pub struct FetchContext {
     pub budget: Arc<ByteBudget>,
     pub crypto: CryptoExecutor,
     pub key_cache: KeyCache,
+    pub init_protection: InitProtectionCache,
     pub cache_manager: Option<Arc<CacheManager>>,
     pub metrics: Option<Arc<PerformanceMetrics>>,
     pub cancel: CancellationToken,
@@ -87,9 +93,12 @@ pub async fn fetch_and_process(job: ReadyJob, ctx: Arc<FetchContext>) -> Segment
 
     // Cache lookup keys on the normalized identity URI (not the volatile
     // fetch URL), so a rotated auth token still hits. Cached bytes are the
-    // final (decrypted) payload.
+    // final (decrypted) payload. SAMPLE-AES init segments bypass the cache:
+    // fetching them is what publishes the protection their media needs.
     let cache_key = segment_cache_key(&descriptor.key);
-    if let Some(cache) = &ctx.cache_manager
+    let cacheable = !is_sample_aes_init(&descriptor);
+    if cacheable
+        && let Some(cache) = &ctx.cache_manager
         && let Ok(Some((bytes, _, _))) = cache.get(&cache_key).await
     {
         debug!(msn, "segment served from cache");
@@ -107,17 +116,19 @@ pub async fn fetch_and_process(job: ReadyJob, ctx: Arc<FetchContext>) -> Segment
             },
         );
         reservation.reconcile(bytes.len() as u64);
-        let payload = wrap_payload(bytes, &descriptor);
+        let payload = wrap_payload(bytes, &descriptor, None);
         drop(reservation);
         return SegmentOutcome::Completed { key, msn, payload };
     }
-    if ctx.cache_manager.is_some()
+    if cacheable
+        && ctx.cache_manager.is_some()
         && let Some(metrics) = &ctx.metrics
     {
         metrics.record_cache_miss();
     }
 
     // --- Download (attempt-level retry, deliberately tight) ---
+    let download_started = Instant::now();
     let raw = match download_body(
         &ctx,
         &descriptor.parsed_url,
@@ -139,6 +150,7 @@ pub async fn fetch_and_process(job: ReadyJob, ctx: Arc<FetchContext>) -> Segment
             };
         }
     };
+    let download_ms = download_started.elapsed().as_millis() as u64;
 
     // --- Decrypt (off-thread) when the descriptor carries encryption ---
     // `output_reservation` keeps the resident output bytes charged to a budget
@@ -149,8 +161,13 @@ pub async fn fetch_and_process(job: ReadyJob, ctx: Arc<FetchContext>) -> Segment
     // point is "when the payload is wrapped", not "after decrypt"). Held only
     // for its drop.
     let (final_bytes, output_reservation): (Bytes, ByteReservation) = match &descriptor.encryption {
-        Some(enc) if enc.method == EncryptionMethod::Aes128Cbc => {
-            match decrypt_segment(&ctx, enc, msn, raw, &mut reservation).await {
+        Some(enc)
+            if matches!(
+                enc.method,
+                EncryptionMethod::Aes128Cbc | EncryptionMethod::SampleAes
+            ) =>
+        {
+            match decrypt_segment(&ctx, &descriptor, enc, raw, &mut reservation).await {
                 Ok((bytes, processing)) => {
                     // The encrypted input was consumed by decrypt; its download
                     // reservation is released here and the output is now held by
@@ -173,7 +190,7 @@ pub async fn fetch_and_process(job: ReadyJob, ctx: Arc<FetchContext>) -> Segment
         _ => (raw, reservation),
     };
 
-    if let Some(cache) = &ctx.cache_manager {
+    if cacheable && let Some(cache) = &ctx.cache_manager {
         let metadata = CacheMetadata::new(final_bytes.len() as u64)
             .with_expiration(ctx.config.processor_config.processed_segment_ttl);
         if let Err(e) = cache.put(cache_key, final_bytes.clone(), metadata).await {
@@ -181,7 +198,7 @@ pub async fn fetch_and_process(job: ReadyJob, ctx: Arc<FetchContext>) -> Segment
         }
     }
 
-    let payload = wrap_payload(final_bytes, &descriptor);
+    let payload = wrap_payload(final_bytes, &descriptor, Some(download_ms));
     // Release the output reservation only now, after wrap: from here the
     // payload is accounted by the reactor's pending budget.
     drop(output_reservation);
@@ -197,11 +214,35 @@ fn segment_cache_key(key: &super::identity::SegmentKey) -> CacheKey {
     )
 }
 
+fn is_sample_aes_init(descriptor: &SegmentDescriptor) -> bool {
+    descriptor.key.kind == SegmentKind::Init
+        && descriptor
+            .encryption
+            .as_ref()
+            .is_some_and(|enc| enc.method == EncryptionMethod::SampleAes)
+}
+
+/// Wrap fetched bytes for output. A measured download time is recorded in the
+/// segment's delivery marker, on a copy of the shared descriptor.
 fn wrap_payload(
     data: Bytes,
-    descriptor: &Arc<super::descriptor::SegmentDescriptor>,
+    descriptor: &Arc<SegmentDescriptor>,
+    download_ms: Option<u64>,
 ) -> SegmentPayload {
-    let descriptor = Arc::clone(descriptor);
+    let descriptor = match download_ms.zip(DeliveryMarker::from_segment(&descriptor.media_segment))
+    {
+        Some((download_ms, marker)) => {
+            let mut media_segment = descriptor.media_segment.as_ref().clone();
+            marker
+                .with_download_ms(download_ms)
+                .attach(&mut media_segment);
+            Arc::new(SegmentDescriptor {
+                media_segment: Arc::new(media_segment),
+                ..descriptor.as_ref().clone()
+            })
+        }
+        None => Arc::clone(descriptor),
+    };
     if descriptor.key.kind == SegmentKind::Init {
         SegmentPayload::Mp4Init { data, descriptor }
     } else if descriptor.init_key.is_some() || is_m4s_segment(&descriptor.parsed_url) {
@@ -217,6 +258,10 @@ fn wrap_payload(
 /// re-discovery refreshes reach the store, not a running future. Anything
 /// longer-lived returns a `FailureClass` and lets the lifecycle retry (which
 /// frees the slot and re-engages the refreshed-URL path) handle it.
+///
+/// Attempt-level retries are capped by the smallest of the retry policy's
+/// `max_retries`, `max_segment_retries` and two, and only cover the failures
+/// its `retry_on` selects among network errors, timeouts and 5xx.
 async fn download_body(
     ctx: &FetchContext,
     url: &Url,
@@ -224,16 +269,20 @@ async fn download_body(
     reservation: &mut ByteReservation,
 ) -> Result<Bytes, Failure> {
     const MAX_ATTEMPT_RETRIES: u32 = 2;
-    let attempt_retries = ctx
-        .config
-        .fetcher_config
-        .max_segment_retries
+    // Short, jittered delays: the slot and reservation stay held while waiting.
+    let retry = RetryPolicy {
+        base_delay: ctx
+            .config
+            .fetcher_config
+            .segment_retry_delay_base
+            .min(Duration::from_secs(1)),
+        max_delay: Duration::from_secs(1),
+        ..ctx.config.base.retry.clone()
+    };
+    let attempt_retries = retry
+        .max_retries
+        .min(ctx.config.fetcher_config.max_segment_retries)
         .min(MAX_ATTEMPT_RETRIES);
-    let retry_delay = ctx
-        .config
-        .fetcher_config
-        .segment_retry_delay_base
-        .min(Duration::from_secs(1));
 
     let mut last_failure: Option<Failure> = None;
     for attempt in 0..=attempt_retries {
@@ -242,7 +291,7 @@ async fn download_body(
                 _ = ctx.cancel.cancelled() => {
                     return Err(Failure::new(FailureClass::Network, "cancelled"));
                 }
-                _ = tokio::time::sleep(retry_delay) => {}
+                _ = tokio::time::sleep(retry.delay(attempt)) => {}
             }
         }
         match download_once(ctx, url, key, reservation).await {
@@ -266,16 +315,17 @@ async fn download_body(
 }
 
 /// One download attempt. The bool in the error is attempt-level retryability
-/// (network hiccups and 5xx); everything else goes straight back to the store.
+/// (network hiccups and 5xx, as selected by the retry policy); everything else
+/// goes straight back to the store.
 async fn download_once(
     ctx: &FetchContext,
     url: &Url,
     key: &super::identity::SegmentKey,
     reservation: &mut ByteReservation,
 ) -> Result<Bytes, (Failure, bool)> {
-    let client = ctx.clients.client_for_url(url);
-    let mut request = client
-        .get(url.clone())
+    let mut request = ctx
+        .clients
+        .get(url)
         .query(&ctx.config.base.params)
         .timeout(ctx.config.fetcher_config.segment_download_timeout);
     if let Some(range) = key.byte_range {
@@ -305,7 +355,7 @@ async fn download_once(
         let class = classify_reqwest(&e);
         (
             Failure::new(class, e.to_string()),
-            matches!(class, FailureClass::Network | FailureClass::Timeout),
+            retry_attempt(ctx, class),
         )
     })?;
 
@@ -317,7 +367,8 @@ async fn download_once(
         );
         // 5xx gets one quick attempt-level retry; 4xx goes straight to the
         // store (it owns 404/429 pacing and the 401/403 freshness rule).
-        return Err((failure, status.is_server_error()));
+        let retryable = status.is_server_error() && ctx.config.base.retry.retry_on.status(status);
+        return Err((failure, retryable));
     }
     let range_mode = validate_range_response(status, response.headers(), key.byte_range)
         .map_err(|e| (e, false))?;
@@ -384,7 +435,10 @@ async fn download_once(
         let Some(chunk) = chunk else { break };
         let chunk = chunk.map_err(|e| {
             let class = classify_reqwest(&e);
-            (Failure::new(class, e.to_string()), true)
+            (
+                Failure::new(class, e.to_string()),
+                retry_attempt(ctx, class),
+            )
         })?;
 
         let new_len = buffer.len() as u64 + chunk.len() as u64;
@@ -606,12 +660,20 @@ fn range_end(range: ByteRangeKey) -> Option<u64> {
 /// holds the returned processing reservation until the payload is wrapped.
 async fn decrypt_segment(
     ctx: &FetchContext,
+    descriptor: &SegmentDescriptor,
     enc: &EncryptionDescriptor,
-    msn: u64,
     encrypted: Bytes,
     download_reservation: &mut ByteReservation,
 ) -> Result<(Bytes, ByteReservation), Failure> {
-    let key_bytes = fetch_key(ctx, enc).await?;
+    let msn = descriptor.msn;
+    // A SAMPLE-AES init segment is not encrypted itself; it only describes
+    // how its media samples are, so it needs no key.
+    let sample_aes_init = is_sample_aes_init(descriptor);
+    let key_bytes = if sample_aes_init {
+        [0u8; 16]
+    } else {
+        fetch_key(ctx, enc).await?
+    };
 
     let iv = match enc.iv {
         EffectiveIv::Explicit(iv) => iv,
@@ -641,11 +703,16 @@ async fn decrypt_segment(
     };
 
     let started = std::time::Instant::now();
-    let decrypted = ctx
-        .crypto
-        .decrypt_aes128_cbc(encrypted, key_bytes, iv)
-        .await
-        .map_err(|e| Failure::new(FailureClass::Decode, e.to_string()))?;
+    let decrypted = match enc.method {
+        EncryptionMethod::SampleAes => {
+            decrypt_sample_aes(ctx, descriptor, encrypted, key_bytes, iv).await?
+        }
+        _ => ctx
+            .crypto
+            .decrypt_aes128_cbc(encrypted, key_bytes, iv)
+            .await
+            .map_err(|e| Failure::new(FailureClass::Decode, e.to_string()))?,
+    };
     // The encrypted input has been consumed by the decrypt copy. The caller
     // drops the download reservation on success; reconcile it to 0 here too so
     // an error return (which the caller does not drain) cannot leave the input
@@ -663,6 +730,58 @@ async fn decrypt_segment(
     Ok((decrypted, processing))
 }
 
+/// SAMPLE-AES: clear an fMP4 init segment (publishing its protection for the
+/// media segments), decrypt an fMP4 media segment with its init's protection,
+/// or decrypt an MPEG-TS segment.
+async fn decrypt_sample_aes(
+    ctx: &FetchContext,
+    descriptor: &SegmentDescriptor,
+    data: Bytes,
+    key: [u8; 16],
+    iv: [u8; 16],
+) -> Result<Bytes, Failure> {
+    if descriptor.key.kind == SegmentKind::Init {
+        let (init, protection) =
+            run_sample_aes(ctx, move || sample_aes::clear_cmaf_init(&data)).await?;
+        ctx.init_protection
+            .insert(descriptor.key.clone(), Arc::new(protection));
+        return Ok(init);
+    }
+
+    if let Some(init_key) = &descriptor.init_key {
+        let timeout = ctx.config.fetcher_config.segment_download_timeout;
+        let Some(protection) = ctx.init_protection.wait_for(init_key, timeout).await else {
+            return Err(Failure::new(
+                FailureClass::Decode,
+                "SAMPLE-AES init segment protection unavailable",
+            ));
+        };
+        return run_sample_aes(ctx, move || {
+            sample_aes::decrypt_cmaf_segment(&data, &protection, &key, &iv)
+        })
+        .await;
+    }
+
+    run_sample_aes(ctx, move || sample_aes::decrypt_ts(&data, &key, &iv)).await
+}
+
+async fn run_sample_aes<T, F>(ctx: &FetchContext, work: F) -> Result<T, Failure>
+where
+    F: FnOnce() -> Result<T, SampleAesError> + Send + 'static,
+    T: Send + 'static,
+{
+    match ctx.crypto.run(work).await {
+        Ok(Ok(output)) => Ok(output),
+        Ok(Err(e @ SampleAesError::Unsupported(_))) => {
+            Err(Failure::new(FailureClass::UnsupportedCrypto, e.to_string()))
+        }
+        Ok(Err(e @ SampleAesError::Malformed(_))) => {
+            Err(Failure::new(FailureClass::Decode, e.to_string()))
+        }
+        Err(e) => Err(Failure::new(FailureClass::Decode, e.to_string())),
+    }
+}
+
 /// Fetch (or hit) the decryption key. Single-flight per identity URI via the
 /// key cache; the load future uses the *latest* fetch URL.
 async fn fetch_key(ctx: &FetchContext, enc: &EncryptionDescriptor) -> Result<[u8; 16], Failure> {
@@ -686,9 +805,8 @@ async fn fetch_key(ctx: &FetchContext, enc: &EncryptionDescriptor) -> Result<[u8
             let params = ctx.config.base.params.clone();
             let identity = Arc::clone(&identity);
             async move {
-                let client = ctx_clients.client_for_url(&fetch_url);
-                let response = client
-                    .get(fetch_url.as_ref().clone())
+                let response = ctx_clients
+                    .get(fetch_url.as_ref())
                     .query(&params)
                     .timeout(timeout)
                     .send()
@@ -743,6 +861,16 @@ async fn fetch_key(ctx: &FetchContext, enc: &EncryptionDescriptor) -> Result<[u8
     }
 }
 
+/// Whether a transport failure of `class` is retried within the attempt.
+fn retry_attempt(ctx: &FetchContext, class: FailureClass) -> bool {
+    let retry_on = &ctx.config.base.retry.retry_on;
+    match class {
+        FailureClass::Network => retry_on.network,
+        FailureClass::Timeout => retry_on.timeout,
+        _ => false,
+    }
+}
+
 fn classify_reqwest(e: &reqwest::Error) -> FailureClass {
     if e.is_timeout() {
         FailureClass::Timeout
@@ -11,10 +11,14 @@ use url::Url;
 /// of identity. Prefetch is deliberately *not* a kind: a Twitch prefetch URL is
 /// the same resource that reappears as a normal media segment on the next
 /// refresh, so prefetch-ness lives on `SegmentDescriptor::source` only.
+/// LL-HLS parts are their own kind: a part and the parent segment it belongs
+/// to are distinct resources, and a preload hint shares the key of the part it
+/// announces.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum SegmentKind {
     Init,
     Media,
+    Part,
 }
 
 /// Resolved absolute byte range. `offset` is never optional here: the manifest
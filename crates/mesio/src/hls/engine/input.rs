@@ -39,6 +39,12 @@ pub enum AssemblerInput {
         key: SegmentKey,
         msn: u64,
     },
+    /// The LL-HLS segment at `msn`, delivered as parts, was published with
+    /// `parts` parts: once they are emitted the assembler moves past it.
+    PartsComplete {
+        msn: u64,
+        parts: u32,
+    },
     Notice(PlaylistNotice),
     /// Pipeline error: drop the reorder buffer and surface this as the
     /// stream's terminal `Err`. Never followed by `End`.
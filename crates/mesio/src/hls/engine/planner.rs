@@ -10,11 +10,13 @@
 //! because two pieces of normalization are inherently cross-snapshot: the
 //! BYTERANGE inference chain (a snapshot's first BYTERANGE segment may
 //! continue a chain started in the previous snapshot) and the decision
-//! watermark used for window-slide gap detection.
+//! watermark used for window-slide gap detection. LL-HLS part mode is a third:
+//! once an MSN is followed as parts, it stays parts when published.
 
-use std::collections::HashSet;
+use std::collections::{BTreeSet, HashSet};
 use std::sync::Arc;
 
+use hls::{DeliveryMarker, LowLatencyPlaylist, PartialSegment};
 use tracing::{debug, trace, warn};
 use url::Url;
 
@@ -47,6 +49,10 @@ pub struct Planned {
     /// MSNs that will never be planned: ads, empty URIs, uninferable
     /// BYTERANGEs, malformed URLs. Decided exactly once (watermark-gated).
     pub skipped: Vec<MsnRange>,
+    /// LL-HLS segments delivered as parts that the playlist has now published,
+    /// with their final part count. Forwarded as
+    /// `AssemblerInput::PartsComplete`; decided exactly once (watermark-gated).
+    pub completed_parts: Vec<(u64, u32)>,
     /// A media-sequence reset was detected: the window regressed too far to
     /// be a stale edge response. Output continuity cannot be preserved across
     /// a reset (every re-based payload would sit below the assembler's emit
@@ -87,6 +93,11 @@ pub struct PlannerContext {
     /// When true, SOOP `preloading` placeholder segments are skipped at plan
     /// time without removing them from the playlist (MSN indices stay stable).
     soop: bool,
+    /// MSNs delivered as LL-HLS parts. An MSN enters part mode when it is
+    /// first seen in progress (parts listed after the last segment URI) and
+    /// keeps it once published, so its parts are planned instead of the whole
+    /// segment and no media is downloaded twice.
+    part_mode: BTreeSet<u64>,
 }
 
 impl PlannerContext {
@@ -100,6 +111,7 @@ impl PlannerContext {
             current_map: None,
             twitch: twitch.then(TwitchPlaylistProcessor::new),
             soop,
+            part_mode: BTreeSet::new(),
         }
     }
 }
@@ -170,6 +182,9 @@ pub fn plan(snapshot: &PlaylistSnapshot, ctx: &mut PlannerContext) -> Planned {
         }
     }
 
+    let low_latency = snapshot.low_latency.as_deref();
+    ctx.part_mode = ctx.part_mode.split_off(&window_start);
+
     let base_url = Url::parse(snapshot.base_url.as_ref()).ok();
     let parent_params: Vec<(String, String)> = snapshot
         .parent_query
@@ -227,60 +242,33 @@ pub fn plan(snapshot: &PlaylistSnapshot, ctx: &mut PlannerContext) -> Planned {
         let deciding = watermark.is_none_or(|w| msn >= w);
         let segment = scanned.segment;
 
-        // --- Encryption normalization (shared by init + media) ---
+        // --- Encryption and init map scopes ---
         // A key tag opens a scope covering every following segment until the
-        // next tag; the parser only attaches it to the first one.
+        // next tag; the parser only attaches it to the first one. The same
+        // holds for map tags (RFC 8216 §4.3.2.5), so both scopes are
+        // propagated via the context. A playlist-level X-MAP re-seeds
+        // current_map at the start of each snapshot; if a refresh omits it,
+        // the carried scope remains active.
         if segment.key.is_some() {
             ctx.current_key = segment.key.clone();
         }
-        let scoped_key = segment.key.as_ref().or(ctx.current_key.as_ref());
-        let encryption = scoped_key
-            .and_then(|key| normalize_encryption(key, msn, &base_url, &parent_params, &ctx.policy));
-        let resolved_key = scoped_key.map(|key| {
-            let mut key = key.clone();
-            if let Some(uri) = key.uri.as_deref() {
-                let absolute = resolve_uri(&base_url, uri).unwrap_or_else(|| uri.to_string());
-                key.uri = Some(merge_params(&parent_params, &absolute));
-            }
-            key
-        });
-
-        // --- Init map (EXT-X-MAP) ---
-        // A map tag opens a scope covering every following segment until the
-        // next map tag (RFC 8216 §4.3.2.5); the parser attaches it only to
-        // the segment it precedes, so the scope is propagated via current_map.
-        // A playlist-level X-MAP re-seeds current_map at the start of each
-        // snapshot; if a refresh omits it, the carried scope remains active.
         if segment.map.is_some() {
             ctx.current_map = segment.map.clone();
         }
+        let scope = resolve_scope(ctx, msn, scanned.discontinuity, &base_url, &parent_params);
         // The init key the media segments below this map depend on, so the
         // assembler can gate media emission on its arrival.
-        let mut active_init_key: Option<SegmentKey> = None;
-        if let Some(map_info) = ctx.current_map.clone()
-            && let Some(absolute_map_uri) = resolve_uri(&base_url, &map_info.uri)
+        let active_init_key = scope.init.as_ref().map(|init| init.key.clone());
+        // Emit one descriptor per distinct init key per call; the store
+        // dedups across calls, so only the first occurrence in this scan
+        // produces a descriptor.
+        if let Some(init) = scope.init
+            && emitted_init_keys.insert(init.key.clone())
         {
-            let final_map_uri = merge_params(&parent_params, &absolute_map_uri);
-            if let Some(descriptor) = build_init_descriptor(
-                &final_map_uri,
-                &map_info,
-                msn,
-                scanned.discontinuity,
-                init_map_encryption(encryption.clone()),
-                resolved_key.clone(),
-                &ctx.policy,
-            ) {
-                active_init_key = Some(descriptor.key.clone());
-                // Emit one descriptor per distinct init key per call; the
-                // store dedups across calls, so only the first occurrence in
-                // this scan produces a descriptor.
-                if emitted_init_keys.insert(descriptor.key.clone()) {
-                    planned.descriptors.push(descriptor);
-                }
-            } else {
-                warn!(msn, uri = %final_map_uri, "unparseable init map URI");
-            }
+            planned.descriptors.push(init);
         }
+        let encryption = scope.encryption;
+        let resolved_key = scope.resolved_key;
 
         // --- Effective URI (BYTERANGE entries may omit the URI) ---
         let effective_uri: Option<String> = if segment.uri.trim().is_empty() {
@@ -406,6 +394,40 @@ pub fn plan(snapshot: &PlaylistSnapshot, ctx: &mut PlannerContext) -> Planned {
             continue;
         };
 
+        // --- LL-HLS: a segment followed as parts stays parts once published ---
+        if ctx.part_mode.contains(&msn)
+            && let Some(parts) = low_latency
+                .and_then(|ll| ll.segment_parts.get(idx))
+                .filter(|parts| !parts.is_empty())
+        {
+            let part_scope = PartScope {
+                msn,
+                discontinuity: scanned.discontinuity,
+                encryption: &encryption,
+                resolved_key: &resolved_key,
+                init_key: &active_init_key,
+                base_url: &base_url,
+                parent_params: &parent_params,
+                policy: &ctx.policy,
+            };
+            let (descriptors, all_planned) = plan_parts(parts, &part_scope);
+            if deciding {
+                if !all_planned {
+                    warn!(
+                        msn,
+                        planned = descriptors.len(),
+                        listed = parts.len(),
+                        "LL-HLS segment has an unusable part; truncating it there"
+                    );
+                }
+                planned
+                    .completed_parts
+                    .push((msn, descriptors.len() as u32));
+            }
+            planned.descriptors.extend(descriptors);
+            continue;
+        }
+
         let source = if segment.title.as_deref() == Some(PREFETCH_SEGMENT_TITLE) {
             SegmentSource::PlaylistPrefetch
         } else {
@@ -434,10 +456,33 @@ pub fn plan(snapshot: &PlaylistSnapshot, ctx: &mut PlannerContext) -> Planned {
             discontinuity: scanned.discontinuity,
             encryption,
             init_key: active_init_key,
+            part: None,
             media_segment: Arc::new(media_segment),
         });
     }
 
+    if let Some(ll) = low_latency {
+        plan_pending_parts(
+            ll,
+            window_end,
+            ctx,
+            &base_url,
+            &parent_params,
+            &mut emitted_init_keys,
+            &mut planned,
+        );
+    }
+
+    // Record each segment's playlist position for downstream reports. The
+    // segments were just built, so `make_mut` does not copy.
+    let target_duration = Some(playlist.target_duration as f64);
+    for descriptor in &mut planned.descriptors {
+        if descriptor.key.kind != SegmentKind::Init {
+            DeliveryMarker::new(descriptor.msn, target_duration)
+                .attach(Arc::make_mut(&mut descriptor.media_segment));
+        }
+    }
+
     ctx.next_undecided_msn = Some(ctx.next_undecided_msn.unwrap_or(0).max(window_end));
     planned
 }
@@ -485,10 +530,226 @@ fn build_init_descriptor(
         discontinuity,
         encryption,
         init_key: None,
+        part: None,
+        media_segment: Arc::new(media_segment),
+    })
+}
+
+/// Encryption and init scope in effect for one MSN.
+struct SegmentScope {
+    encryption: Option<EncryptionDescriptor>,
+    resolved_key: Option<m3u8_rs::Key>,
+    init: Option<SegmentDescriptor>,
+}
+
+fn resolve_scope(
+    ctx: &PlannerContext,
+    msn: u64,
+    discontinuity: bool,
+    base_url: &Option<Url>,
+    parent_params: &[(String, String)],
+) -> SegmentScope {
+    let scoped_key = ctx.current_key.as_ref();
+    let encryption = scoped_key
+        .and_then(|key| normalize_encryption(key, msn, base_url, parent_params, &ctx.policy));
+    let resolved_key = scoped_key.map(|key| {
+        let mut key = key.clone();
+        if let Some(uri) = key.uri.as_deref() {
+            let absolute = resolve_uri(base_url, uri).unwrap_or_else(|| uri.to_string());
+            key.uri = Some(merge_params(parent_params, &absolute));
+        }
+        key
+    });
+
+    let mut init = None;
+    if let Some(map_info) = ctx.current_map.as_ref()
+        && let Some(absolute_map_uri) = resolve_uri(base_url, &map_info.uri)
+    {
+        let final_map_uri = merge_params(parent_params, &absolute_map_uri);
+        init = build_init_descriptor(
+            &final_map_uri,
+            map_info,
+            msn,
+            discontinuity,
+            init_map_encryption(encryption.clone()),
+            resolved_key.clone(),
+            &ctx.policy,
+        );
+        if init.is_none() {
+            warn!(msn, uri = %final_map_uri, "unparseable init map URI");
+        }
+    }
+
+    SegmentScope {
+        encryption,
+        resolved_key,
+        init,
+    }
+}
+
+/// What the parts of one segment inherit from it.
+struct PartScope<'a> {
+    msn: u64,
+    discontinuity: bool,
+    encryption: &'a Option<EncryptionDescriptor>,
+    resolved_key: &'a Option<m3u8_rs::Key>,
+    init_key: &'a Option<SegmentKey>,
+    base_url: &'a Option<Url>,
+    parent_params: &'a [(String, String)],
+    policy: &'a SegmentIdentityPolicy,
+}
+
+/// Plan the parts of one segment in order, stopping at the first part that
+/// cannot be fetched (a `GAP`, an uninferable BYTERANGE, a malformed URL).
+/// The flag reports whether every part was planned.
+fn plan_parts(parts: &[PartialSegment], scope: &PartScope<'_>) -> (Vec<SegmentDescriptor>, bool) {
+    let mut descriptors = Vec::with_capacity(parts.len());
+    for (index, part) in parts.iter().enumerate() {
+        let Some(descriptor) = u32::try_from(index)
+            .ok()
+            .and_then(|index| build_part_descriptor(part, index, SegmentSource::Playlist, scope))
+        else {
+            return (descriptors, false);
+        };
+        descriptors.push(descriptor);
+    }
+    (descriptors, true)
+}
+
+fn build_part_descriptor(
+    part: &PartialSegment,
+    index: u32,
+    source: SegmentSource,
+    scope: &PartScope<'_>,
+) -> Option<SegmentDescriptor> {
+    if part.gap {
+        return None;
+    }
+    let absolute_uri = resolve_uri(scope.base_url, &part.uri)?;
+    let final_uri = merge_params(scope.parent_params, &absolute_uri);
+    let parsed_url = Url::parse(&final_uri).ok()?;
+    let byte_range_key = match part.byte_range.as_ref() {
+        Some(range) => Some(ByteRangeKey {
+            length: range.length,
+            offset: range.offset?,
+        }),
+        None => None,
+    };
+    // A discontinuity applies to the parent segment, so only its first part
+    // raises it downstream.
+    let discontinuity = scope.discontinuity && index == 0;
+    let media_segment = m3u8_rs::MediaSegment {
+        uri: final_uri,
+        duration: part.duration as f32,
+        byte_range: byte_range_key.map(|br| m3u8_rs::ByteRange {
+            length: br.length,
+            offset: Some(br.offset),
+        }),
+        discontinuity,
+        key: scope.resolved_key.clone(),
+        unknown_tags: vec![part.marker(index)],
+        ..Default::default()
+    };
+    trace!(msn = scope.msn, index, uri = %media_segment.uri, ?source, "planned part");
+    Some(SegmentDescriptor {
+        key: SegmentKey {
+            kind: SegmentKind::Part,
+            uri: scope.policy.canonical_uri(&parsed_url),
+            byte_range: byte_range_key,
+        },
+        msn: scope.msn,
+        source,
+        parsed_url: Arc::new(parsed_url),
+        discontinuity,
+        encryption: scope.encryption.clone(),
+        init_key: scope.init_key.clone(),
+        part: Some(index),
         media_segment: Arc::new(media_segment),
     })
 }
 
+/// Plan the parts of the segment the server is still producing (`msn`, one
+/// past the window). An MSN enters part mode here, the first time it is seen
+/// in progress with parts that can all be fetched; otherwise it is left to be
+/// downloaded whole once published. Parts listed after a key or map tag are
+/// left whole too: m3u8-rs drops those tags until a segment URI follows them.
+#[allow(clippy::too_many_arguments)]
+fn plan_pending_parts(
+    ll: &LowLatencyPlaylist,
+    msn: u64,
+    ctx: &mut PlannerContext,
+    base_url: &Option<Url>,
+    parent_params: &[(String, String)],
+    emitted_init_keys: &mut HashSet<SegmentKey>,
+    planned: &mut Planned,
+) {
+    let in_part_mode = ctx.part_mode.contains(&msn);
+    let hint = ll
+        .next_part_hint()
+        .filter(|hint| hint.byte_range_start.is_none() && hint.byte_range_length.is_none());
+    if !in_part_mode && (ll.pending_scope_change || (ll.pending_parts.is_empty() && hint.is_none()))
+    {
+        return;
+    }
+
+    let scope = resolve_scope(ctx, msn, ll.pending_discontinuity, base_url, parent_params);
+    let init_key = scope.init.as_ref().map(|init| init.key.clone());
+    let part_scope = PartScope {
+        msn,
+        discontinuity: ll.pending_discontinuity,
+        encryption: &scope.encryption,
+        resolved_key: &scope.resolved_key,
+        init_key: &init_key,
+        base_url,
+        parent_params,
+        policy: &ctx.policy,
+    };
+    let (mut descriptors, all_planned) = plan_parts(&ll.pending_parts, &part_scope);
+    if !in_part_mode && !all_planned {
+        debug!(
+            msn,
+            "in-progress LL-HLS segment has an unusable part; downloading it whole"
+        );
+        return;
+    }
+
+    // The hint announces the part after the listed ones. It is fetched ahead
+    // of publication; the published part shares its key and dedups against it.
+    if all_planned
+        && let Some(hint) = hint
+        && let Ok(index) = u32::try_from(ll.pending_parts.len())
+    {
+        let announced = PartialSegment {
+            uri: hint.uri.clone(),
+            duration: ll.part_target.unwrap_or_default(),
+            independent: false,
+            byte_range: None,
+            gap: false,
+        };
+        descriptors.extend(build_part_descriptor(
+            &announced,
+            index,
+            SegmentSource::PreloadHint,
+            &part_scope,
+        ));
+    }
+
+    if !in_part_mode {
+        debug!(
+            msn,
+            parts = ll.pending_parts.len(),
+            "following in-progress LL-HLS segment as parts"
+        );
+        ctx.part_mode.insert(msn);
+    }
+    if let Some(init) = scope.init
+        && emitted_init_keys.insert(init.key.clone())
+    {
+        planned.descriptors.push(init);
+    }
+    planned.descriptors.extend(descriptors);
+}
+
 fn init_map_encryption(encryption: Option<EncryptionDescriptor>) -> Option<EncryptionDescriptor> {
     encryption.map(|mut enc| {
         if enc.method == EncryptionMethod::Aes128Cbc
@@ -527,10 +788,16 @@ fn normalize_encryption(
 
     match &key.method {
         m3u8_rs::KeyMethod::None => None,
-        m3u8_rs::KeyMethod::AES128 => {
+        m3u8_rs::KeyMethod::AES128 | m3u8_rs::KeyMethod::SampleAES => {
+            // Both methods share the key tag semantics (URI, IV, KEYFORMAT);
+            // only what the key is applied to differs.
+            let (method, name) = match key.method {
+                m3u8_rs::KeyMethod::AES128 => (EncryptionMethod::Aes128Cbc, "AES-128"),
+                _ => (EncryptionMethod::SampleAes, "SAMPLE-AES"),
+            };
             let Some(uri) = key.uri.as_deref().filter(|u| !u.trim().is_empty()) else {
                 return Some(unsupported(
-                    "AES-128 key tag without URI".to_string(),
+                    format!("{name} key tag without URI"),
                     placeholder(),
                 ));
             };
@@ -549,7 +816,7 @@ fn normalize_encryption(
                     Some(iv) => EffectiveIv::Explicit(iv),
                     None => {
                         return Some(unsupported(
-                            format!("malformed AES-128 IV {iv_hex}"),
+                            format!("malformed {name} IV {iv_hex}"),
                             Arc::new(fetch_url),
                         ));
                     }
@@ -563,18 +830,13 @@ fn normalize_encryption(
 
             let fetch_url = Arc::new(fetch_url);
             Some(EncryptionDescriptor {
-                method: EncryptionMethod::Aes128Cbc,
+                method,
                 key_identity_uri: policy.canonical_uri(&fetch_url),
                 key_fetch_url: fetch_url,
                 iv,
                 key_format,
             })
         }
-        m3u8_rs::KeyMethod::SampleAES => {
-            // SAMPLE-AES needs NAL/container-aware partial decryption, not a
-            // cipher swap; it stays Unsupported until that path exists.
-            Some(unsupported("SAMPLE-AES".to_string(), placeholder()))
-        }
         m3u8_rs::KeyMethod::Other(name) => Some(unsupported(name.clone(), placeholder())),
     }
 }
@@ -702,6 +964,14 @@ mod tests {
             base_url: Arc::from("https://example.com/path/"),
             parent_query: parent_query.map(Arc::from),
             terminal: None,
+            low_latency: None,
+        }
+    }
+
+    fn ll_snapshot(generation: u64, input: &str) -> PlaylistSnapshot {
+        PlaylistSnapshot {
+            low_latency: Some(Arc::new(LowLatencyPlaylist::parse(input.as_bytes()))),
+            ..snapshot(generation, input)
         }
     }
 
@@ -1051,12 +1321,24 @@ mod tests {
     }
 
     #[test]
-    fn sample_aes_maps_to_unsupported() {
+    fn sample_aes_resolves_like_aes128() {
         let body = "#EXTM3U\n#EXT-X-VERSION:5\n#EXT-X-TARGETDURATION:2\n#EXT-X-MEDIA-SEQUENCE:1\n#EXT-X-KEY:METHOD=SAMPLE-AES,URI=\"key.bin\"\n#EXTINF:2.0,\nseg1.ts\n";
         let mut c = ctx();
         let planned = plan(&snapshot(0, body), &mut c);
         let enc = planned.descriptors[0].encryption.as_ref().expect("marked");
-        assert!(matches!(enc.method, EncryptionMethod::Unsupported(_)));
+        assert_eq!(enc.method, EncryptionMethod::SampleAes);
+        assert_eq!(enc.iv, EffectiveIv::MediaSequenceDerived(1));
+        assert_eq!(enc.key_format, KeyFormat::Identity);
+        assert!(enc.key_fetch_url.as_str().ends_with("/key.bin"));
+    }
+
+    #[test]
+    fn fairplay_sample_aes_key_format_is_unsupported() {
+        let body = "#EXTM3U\n#EXT-X-VERSION:5\n#EXT-X-TARGETDURATION:2\n#EXT-X-MEDIA-SEQUENCE:1\n#EXT-X-KEY:METHOD=SAMPLE-AES,URI=\"skd://key\",KEYFORMAT=\"com.apple.streamingkeydelivery\"\n#EXTINF:2.0,\nseg1.ts\n";
+        let mut c = ctx();
+        let planned = plan(&snapshot(0, body), &mut c);
+        let enc = planned.descriptors[0].encryption.as_ref().expect("marked");
+        assert!(matches!(enc.key_format, KeyFormat::Unsupported(_)));
     }
 
     #[test]
@@ -1126,4 +1408,69 @@ mod tests {
             "stale regression must not manufacture a missing range over decided MSNs"
         );
     }
+
+    #[test]
+    fn in_progress_segment_is_followed_as_parts_until_published() {
+        let mut c = ctx();
+        let header = "#EXTM3U\n#EXT-X-VERSION:9\n#EXT-X-TARGETDURATION:4\n#EXT-X-SERVER-CONTROL:CAN-BLOCK-RELOAD=YES,PART-HOLD-BACK=3.0\n#EXT-X-PART-INF:PART-TARGET=1.0\n#EXT-X-MEDIA-SEQUENCE:10\n#EXTINF:4.0,\nseg10.ts\n";
+        let pending = format!(
+            "{header}#EXT-X-PART:DURATION=1.0,URI=\"seg11.0.ts\",INDEPENDENT=YES\n#EXT-X-PRELOAD-HINT:TYPE=PART,URI=\"seg11.1.ts\"\n"
+        );
+        let planned = plan(&ll_snapshot(0, &pending), &mut c);
+        let parts: Vec<_> = planned
+            .descriptors
+            .iter()
+            .filter(|d| d.msn == 11)
+            .map(|d| (d.key.kind, d.part, d.source, d.parsed_url.path()))
+            .collect();
+        assert_eq!(
+            parts,
+            vec![
+                (
+                    SegmentKind::Part,
+                    Some(0),
+                    SegmentSource::Playlist,
+                    "/path/seg11.0.ts"
+                ),
+                (
+                    SegmentKind::Part,
+                    Some(1),
+                    SegmentSource::PreloadHint,
+                    "/path/seg11.1.ts"
+                ),
+            ]
+        );
+        assert!(planned.completed_parts.is_empty());
+        let hinted_key = planned.descriptors[planned.descriptors.len() - 1]
+            .key
+            .clone();
+
+        // Once published, the segment is planned as its parts, never whole.
+        let published = format!(
+            "{header}#EXT-X-PART:DURATION=1.0,URI=\"seg11.0.ts\",INDEPENDENT=YES\n#EXT-X-PART:DURATION=1.0,URI=\"seg11.1.ts\"\n#EXTINF:2.0,\nseg11.ts\n"
+        );
+        let planned = plan(&ll_snapshot(1, &published), &mut c);
+        let msn11: Vec<_> = planned.descriptors.iter().filter(|d| d.msn == 11).collect();
+        assert_eq!(msn11.len(), 2);
+        assert!(msn11.iter().all(|d| d.key.kind == SegmentKind::Part));
+        // The published part dedups against the one fetched from the hint.
+        assert_eq!(msn11[1].key, hinted_key);
+        assert_eq!(planned.completed_parts, vec![(11, 2)]);
+    }
+
+    #[test]
+    fn segment_first_seen_published_is_planned_whole() {
+        let mut c = ctx();
+        let planned = plan(
+            &ll_snapshot(
+                0,
+                "#EXTM3U\n#EXT-X-VERSION:9\n#EXT-X-TARGETDURATION:4\n#EXT-X-PART-INF:PART-TARGET=1.0\n#EXT-X-MEDIA-SEQUENCE:10\n#EXT-X-PART:DURATION=1.0,URI=\"seg10.0.ts\"\n#EXTINF:1.0,\nseg10.ts\n",
+            ),
+            &mut c,
+        );
+        assert_eq!(planned.descriptors.len(), 1);
+        assert_eq!(planned.descriptors[0].key.kind, SegmentKind::Media);
+        assert_eq!(planned.descriptors[0].part, None);
+        assert!(planned.completed_parts.is_empty());
+    }
 }
@@ -304,6 +304,9 @@ fn process_snapshot(
             for (from_msn, to_msn) in planned.skipped {
                 pending.push(AssemblerInput::Skipped { from_msn, to_msn });
             }
+            for (msn, parts) in planned.completed_parts {
+                pending.push(AssemblerInput::PartsComplete { msn, parts });
+            }
             // Snapshot-derived notices cross the same ordered boundary as
             // payloads, keeping the client channel single-producer.
             pending.push(AssemblerInput::Notice(PlaylistNotice::PlaylistRefreshed {
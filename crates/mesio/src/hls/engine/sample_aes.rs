@@ -0,0 +1,1208 @@
+//! SAMPLE-AES decryption.
+//!
+//! SAMPLE-AES encrypts media samples rather than whole segments: the container
+//! stays parseable and only parts of each sample are ciphertext, so decryption
+//! has to understand the container. Two layouts are supported:
+//!
+//! - MPEG-TS, per Apple's "MPEG-2 Stream Encryption Format for HTTP Live
+//!   Streaming": H.264 slice NAL units and AAC ADTS frames. Decrypted PES
+//!   packets are re-packetized and the PMT stream types restored to their
+//!   clear values.
+//! - fMP4/CMAF with the `cbcs` scheme: the init segment's protection boxes are
+//!   neutralized (renamed to `free`, so no offset moves) and each media sample
+//!   is decrypted in place using the track's `tenc` defaults and the
+//!   fragment's `senc` subsample map.
+//!
+//! Everything here is synchronous CPU work; the fetch future runs it on the
+//! `CryptoExecutor`.
+
+use std::collections::HashMap;
+use std::ops::Range;
+
+use aes::Aes128;
+use bytes::Bytes;
+use cipher::{Array, BlockModeDecrypt, KeyIvInit};
+use thiserror::Error;
+
+type Aes128CbcDec = cbc::Decryptor<Aes128>;
+
+const TS_PACKET_SIZE: usize = 188;
+const TS_PAYLOAD_SIZE: usize = 184;
+const TS_SYNC_BYTE: u8 = 0x47;
+const PID_PAT: u16 = 0x0000;
+
+/// PMT stream types of SAMPLE-AES elementary streams.
+const STREAM_TYPE_H264_SAMPLE_AES: u8 = 0xDB;
+const STREAM_TYPE_AAC_SAMPLE_AES: u8 = 0xCF;
+const STREAM_TYPE_AC3_SAMPLE_AES: u8 = 0xC1;
+const STREAM_TYPE_EAC3_SAMPLE_AES: u8 = 0xC2;
+const STREAM_TYPE_H264: u8 = 0x1B;
+const STREAM_TYPE_ADTS_AAC: u8 = 0x0F;
+
+/// Encrypted H.264 NAL units keep their first 32 bytes clear; shorter slices
+/// (48 bytes or less) are not encrypted at all.
+const NAL_CLEAR_LEADER: usize = 32;
+const NAL_MIN_ENCRYPTED_LEN: usize = 48;
+/// ADTS frames keep 16 bytes after the header clear.
+const ADTS_CLEAR_LEADER: usize = 16;
+
+#[derive(Debug, Error)]
+pub enum SampleAesError {
+    #[error("unsupported SAMPLE-AES content: {0}")]
+    Unsupported(String),
+    #[error("malformed SAMPLE-AES segment: {0}")]
+    Malformed(String),
+}
+
+/// Per-track `cbcs` parameters from an init segment's `tenc` box.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrackProtection {
+    pub protected: bool,
+    /// Size of the per-sample IVs in `senc`; 0 when the track uses a constant IV.
+    pub per_sample_iv_size: u8,
+    pub crypt_byte_block: u8,
+    pub skip_byte_block: u8,
+    pub constant_iv: Option<[u8; 16]>,
+}
+
+/// Protection parameters of every protected track of an fMP4 init segment.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CmafProtection {
+    tracks: HashMap<u32, TrackProtection>,
+}
+
+impl CmafProtection {
+    pub fn track(&self, track_id: u32) -> Option<&TrackProtection> {
+        self.tracks.get(&track_id)
+    }
+}
+
+/// AES-128-CBC without padding: runs of `crypt` blocks are decrypted and the
+/// following `skip` blocks left clear, with the chain carried across the clear
+/// blocks. `crypt == 0` decrypts every whole block. A trailing partial block
+/// is always clear.
+fn decrypt_pattern(data: &mut [u8], key: &[u8; 16], iv: &[u8; 16], crypt: usize, skip: usize) {
+    let mut decryptor = Aes128CbcDec::new(&(*key).into(), &(*iv).into());
+    let (blocks, _) = Array::slice_as_chunks_mut(data);
+    if crypt == 0 {
+        decryptor.decrypt_blocks(blocks);
+        return;
+    }
+    for run in blocks.chunks_mut(crypt + skip) {
+        let encrypted = crypt.min(run.len());
+        decryptor.decrypt_blocks(&mut run[..encrypted]);
+    }
+}
+
+// --- MPEG-TS ---
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EncryptedCodec {
+    H264,
+    Aac,
+}
+
+struct PacketView {
+    pid: u16,
+    pusi: bool,
+    continuity_counter: u8,
+    /// Adaptation field bytes after its length byte.
+    adaptation: Range<usize>,
+    payload: Range<usize>,
+}
+
+impl PacketView {
+    fn parse(packet: &[u8]) -> Result<Self, SampleAesError> {
+        if packet.len() != TS_PACKET_SIZE || packet[0] != TS_SYNC_BYTE {
+            return Err(SampleAesError::Malformed("lost MPEG-TS sync".to_string()));
+        }
+        let control = (packet[3] >> 4) & 0x03;
+        let mut payload_start = 4;
+        let mut adaptation = 5..5;
+        if control & 0x02 != 0 {
+            let length = usize::from(packet[4]);
+            if 5 + length > TS_PACKET_SIZE {
+                return Err(SampleAesError::Malformed(
+                    "adaptation field overruns its packet".to_string(),
+                ));
+            }
+            adaptation = 5..5 + length;
+            payload_start = 5 + length;
+        }
+        let payload = if control & 0x01 != 0 {
+            payload_start..TS_PACKET_SIZE
+        } else {
+            TS_PACKET_SIZE..TS_PACKET_SIZE
+        };
+        Ok(Self {
+            pid: (u16::from(packet[1] & 0x1F) << 8) | u16::from(packet[2]),
+            pusi: packet[1] & 0x40 != 0,
+            continuity_counter: packet[3] & 0x0F,
+            adaptation,
+            payload,
+        })
+    }
+}
+
+/// The adaptation field content worth keeping when re-packetizing: flags and
+/// the optional fields they announce (PCR, OPCR, ...), without stuffing.
+/// `None` when the field carries nothing but stuffing.
+fn essential_adaptation(field: &[u8]) -> Option<Vec<u8>> {
+    let (&flags, _) = field.split_first()?;
+    if flags == 0 {
+        return None;
+    }
+    let mut length = 1;
+    if flags & 0x10 != 0 {
+        length += 6;
+    }
+    if flags & 0x08 != 0 {
+        length += 6;
+    }
+    if flags & 0x04 != 0 {
+        length += 1;
+    }
+    for flag in [0x02, 0x01] {
+        if flags & flag != 0
+            && let Some(&extra) = field.get(length)
+        {
+            length += 1 + usize::from(extra);
+        }
+    }
+    Some(field[..length.min(field.len())].to_vec())
+}
+
+/// A PES packet being collected from the TS packets of one PID.
+struct PendingPes {
+    codec: EncryptedCodec,
+    data: Vec<u8>,
+    /// Essential adaptation fields of the original packets, in order.
+    adaptations: Vec<Option<Vec<u8>>>,
+}
+
+/// Packetize one PES into TS packets. Packet `i` keeps the adaptation field of
+/// the original packet `i` (PCR, random access flag); adaptation fields left
+/// over once the payload runs out are emitted as adaptation-only packets so no
+/// PCR is lost.
+fn write_pes(
+    out: &mut Vec<u8>,
+    pid: u16,
+    continuity_counter: &mut u8,
+    adaptations: &[Option<Vec<u8>>],
+    pes: &[u8],
+) {
+    let mut remaining = pes;
+    let mut index = 0;
+    while !remaining.is_empty() || index < adaptations.len() {
+        let adaptation = adaptations.get(index).and_then(Option::as_deref);
+        index += 1;
+        let first = remaining.len() == pes.len();
+        let pid_high = ((pid >> 8) as u8 & 0x1F) | if first { 0x40 } else { 0 };
+
+        if remaining.is_empty() {
+            let Some(adaptation) = adaptation else {
+                continue;
+            };
+            // Adaptation-only packets repeat the previous continuity counter.
+            let counter = continuity_counter.wrapping_sub(1) & 0x0F;
+            out.extend_from_slice(&[TS_SYNC_BYTE, pid_high, pid as u8, 0x20 | counter]);
+            out.push((TS_PAYLOAD_SIZE - 1) as u8);
+            out.extend_from_slice(adaptation);
+            out.resize(out.len() + TS_PAYLOAD_SIZE - 1 - adaptation.len(), 0xFF);
+            continue;
+        }
+
+        let adaptation_len = adaptation.map_or(0, |field| 1 + field.len());
+        let capacity = TS_PAYLOAD_SIZE - adaptation_len;
+        let take = remaining.len().min(capacity);
+        let stuffing = capacity - take;
+        let control = if adaptation_len + stuffing > 0 {
+            0x30
+        } else {
+            0x10
+        };
+        out.extend_from_slice(&[
+            TS_SYNC_BYTE,
+            pid_high,
+            pid as u8,
+            control | *continuity_counter,
+        ]);
+        match adaptation {
+            Some(field) => {
+                out.push((field.len() + stuffing) as u8);
+                out.extend_from_slice(field);
+                out.resize(out.len() + stuffing, 0xFF);
+            }
+            None if stuffing > 0 => {
+                out.push((stuffing - 1) as u8);
+                if stuffing > 1 {
+                    out.push(0x00);
+                    out.resize(out.len() + stuffing - 2, 0xFF);
+                }
+            }
+            None => {}
+        }
+        out.extend_from_slice(&remaining[..take]);
+        remaining = &remaining[take..];
+        *continuity_counter = (*continuity_counter + 1) & 0x0F;
+    }
+}
+
+/// Offset of the PSI section in a packet payload that starts one.
+fn psi_section(packet: &[u8], view: &PacketView) -> Option<Range<usize>> {
+    if !view.pusi {
+        return None;
+    }
+    let pointer = usize::from(*packet.get(view.payload.start)?);
+    let start = view.payload.start + 1 + pointer;
+    let section = packet.get(start..)?;
+    let length = 3 + ((usize::from(*section.get(1)? & 0x0F) << 8) | usize::from(*section.get(2)?));
+    (length <= section.len() && length >= 12).then_some(start..start + length)
+}
+
+fn parse_pat(section: &[u8]) -> Vec<u16> {
+    section[8..section.len() - 4]
+        .chunks_exact(4)
+        .filter(|program| program[0] != 0 || program[1] != 0)
+        .map(|program| (u16::from(program[2] & 0x1F) << 8) | u16::from(program[3]))
+        .collect()
+}
+
+/// Restore the clear stream types of SAMPLE-AES streams in a PMT section and
+/// return the encrypted PIDs.
+fn rewrite_pmt(section: &mut [u8]) -> Result<Vec<(u16, EncryptedCodec)>, SampleAesError> {
+    let crc_start = section.len() - 4;
+    let program_info_length = (usize::from(section[10] & 0x0F) << 8) | usize::from(section[11]);
+    let mut pos = 12 + program_info_length;
+    let mut encrypted = Vec::new();
+    while pos + 5 <= crc_start {
+        let pid = (u16::from(section[pos + 1] & 0x1F) << 8) | u16::from(section[pos + 2]);
+        let codec = match section[pos] {
+            STREAM_TYPE_H264_SAMPLE_AES => Some((EncryptedCodec::H264, STREAM_TYPE_H264)),
+            STREAM_TYPE_AAC_SAMPLE_AES => Some((EncryptedCodec::Aac, STREAM_TYPE_ADTS_AAC)),
+            STREAM_TYPE_AC3_SAMPLE_AES | STREAM_TYPE_EAC3_SAMPLE_AES => {
+                return Err(SampleAesError::Unsupported(
+                    "AC-3 / E-AC-3 elementary streams".to_string(),
+                ));
+            }
+            _ => None,
+        };
+        if let Some((codec, clear_type)) = codec {
+            section[pos] = clear_type;
+            encrypted.push((pid, codec));
+        }
+        let es_info_length =
+            (usize::from(section[pos + 3] & 0x0F) << 8) | usize::from(section[pos + 4]);
+        pos += 5 + es_info_length;
+    }
+    if !encrypted.is_empty() {
+        let crc = ts::mpeg2_crc32(&section[..crc_start]);
+        section[crc_start..].copy_from_slice(&crc.to_be_bytes());
+    }
+    Ok(encrypted)
+}
+
+/// Decrypt the elementary stream inside one PES packet and fix up its length.
+fn decrypt_pes(mut pes: Vec<u8>, codec: EncryptedCodec, key: &[u8; 16], iv: &[u8; 16]) -> Vec<u8> {
+    if pes.len() < 9 || pes[..3] != [0, 0, 1] {
+        return pes;
+    }
+    let header_len = 9 + usize::from(pes[8]);
+    if header_len > pes.len() {
+        return pes;
+    }
+    match codec {
+        EncryptedCodec::Aac => decrypt_adts(&mut pes[header_len..], key, iv),
+        EncryptedCodec::H264 => {
+            let es = decrypt_h264(&pes[header_len..], key, iv);
+            pes.truncate(header_len);
+            pes.extend_from_slice(&es);
+            if pes[4] != 0 || pes[5] != 0 {
+                let length = u16::try_from(pes.len() - 6).unwrap_or(0);
+                pes[4..6].copy_from_slice(&length.to_be_bytes());
+            }
+        }
+    }
+    pes
+}
+
+/// Decrypt an H.264 Annex B stream. Each encrypted NAL unit restarts the CBC
+/// chain from `iv`; the emulation prevention added after encryption is
+/// removed, which restores the original (escaped) clear NAL unit.
+fn decrypt_h264(es: &[u8], key: &[u8; 16], iv: &[u8; 16]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(es.len());
+    let starts = start_codes(es);
+    let mut copied = 0;
+    for (i, &begin) in starts.iter().enumerate() {
+        let mut end = starts.get(i + 1).map_or(es.len(), |next| next - 3);
+        while end > begin && es[end - 1] == 0 {
+            end -= 1;
+        }
+        let nal = &es[begin..end];
+        let Some(&header) = nal.first() else {
+            continue;
+        };
+        if !matches!(header & 0x1F, 1 | 5) || nal.len() <= NAL_MIN_ENCRYPTED_LEN {
+            continue;
+        }
+        out.extend_from_slice(&es[copied..begin]);
+        let mut unescaped = remove_emulation_prevention(nal);
+        // A block is only encrypted when more than 16 bytes remain.
+        let last = unescaped.len() - 1;
+        decrypt_pattern(&mut unescaped[NAL_CLEAR_LEADER..last], key, iv, 1, 9);
+        out.extend_from_slice(&unescaped);
+        copied = end;
+    }
+    out.extend_from_slice(&es[copied..]);
+    out
+}
+
+/// Positions just past each `00 00 01` start code.
+fn start_codes(es: &[u8]) -> Vec<usize> {
+    es.windows(3)
+        .enumerate()
+        .filter(|(_, window)| *window == [0, 0, 1])
+        .map(|(pos, _)| pos + 3)
+        .collect()
+}
+
+fn remove_emulation_prevention(nal: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(nal.len());
+    let mut zeros = 0;
+    for &byte in nal {
+        if zeros >= 2 && byte == 0x03 {
+            zeros = 0;
+            continue;
+        }
+        zeros = if byte == 0 { zeros + 1 } else { 0 };
+        out.push(byte);
+    }
+    out
+}
+
+/// Decrypt ADTS frames in place. Each frame restarts the CBC chain from `iv`.
+fn decrypt_adts(es: &mut [u8], key: &[u8; 16], iv: &[u8; 16]) {
+    let mut pos = 0;
+    while let Some(header) = es.get(pos..pos + 7) {
+        if header[0] != 0xFF || header[1] & 0xF0 != 0xF0 {
+            break;
+        }
+        let header_len = if header[1] & 0x01 == 0 { 9 } else { 7 };
+        let frame_len = (usize::from(header[3] & 0x03) << 11)
+            | (usize::from(header[4]) << 3)
+            | (usize::from(header[5]) >> 5);
+        if frame_len < header_len || pos + frame_len > es.len() {
+            break;
+        }
+        let leader = header_len + ADTS_CLEAR_LEADER;
+        if frame_len > leader {
+            decrypt_pattern(&mut es[pos + leader..pos + frame_len], key, iv, 0, 0);
+        }
+        pos += frame_len;
+    }
+}
+
+/// Decrypt a SAMPLE-AES MPEG-TS segment.
+pub fn decrypt_ts(data: &[u8], key: &[u8; 16], iv: &[u8; 16]) -> Result<Bytes, SampleAesError> {
+    let mut out = Vec::with_capacity(data.len());
+    let mut pmt_pids = Vec::new();
+    let mut encrypted = HashMap::new();
+    let mut pending: HashMap<u16, PendingPes> = HashMap::new();
+    let mut counters: HashMap<u16, u8> = HashMap::new();
+
+    let flush = |out: &mut Vec<u8>, counters: &mut HashMap<u16, u8>, pid: u16, pes: PendingPes| {
+        let data = decrypt_pes(pes.data, pes.codec, key, iv);
+        let counter = counters.entry(pid).or_default();
+        write_pes(out, pid, counter, &pes.adaptations, &data);
+    };
+
+    let mut packets = data.chunks_exact(TS_PACKET_SIZE);
+    for packet in packets.by_ref() {
+        let view = PacketView::parse(packet)?;
+        if view.pid == PID_PAT {
+            if let Some(section) = psi_section(packet, &view) {
+                pmt_pids = parse_pat(&packet[section]);
+            }
+            out.extend_from_slice(packet);
+            continue;
+        }
+        if pmt_pids.contains(&view.pid) {
+            let mut packet = packet.to_vec();
+            match psi_section(&packet, &view) {
+                Some(section) => encrypted.extend(rewrite_pmt(&mut packet[section])?),
+                None if view.pusi => {
+                    return Err(SampleAesError::Unsupported(
+                        "PMT spanning several packets".to_string(),
+                    ));
+                }
+                None => {}
+            }
+            out.extend_from_slice(&packet);
+            continue;
+        }
+        let Some(&codec) = encrypted.get(&view.pid) else {
+            out.extend_from_slice(packet);
+            continue;
+        };
+
+        let adaptation = essential_adaptation(&packet[view.adaptation.clone()]);
+        if view.pusi {
+            if let Some(pes) = pending.remove(&view.pid) {
+                flush(&mut out, &mut counters, view.pid, pes);
+            }
+            counters.entry(view.pid).or_insert(view.continuity_counter);
+            pending.insert(
+                view.pid,
+                PendingPes {
+                    codec,
+                    data: packet[view.payload.clone()].to_vec(),
+                    adaptations: vec![adaptation],
+                },
+            );
+        } else if let Some(pes) = pending.get_mut(&view.pid) {
+            pes.data.extend_from_slice(&packet[view.payload.clone()]);
+            pes.adaptations.push(adaptation);
+        } else {
+            // The tail of a PES that started in an earlier segment.
+            out.extend_from_slice(packet);
+        }
+    }
+
+    let mut remaining: Vec<_> = pending.into_iter().collect();
+    remaining.sort_by_key(|(pid, _)| *pid);
+    for (pid, pes) in remaining {
+        flush(&mut out, &mut counters, pid, pes);
+    }
+    out.extend_from_slice(packets.remainder());
+    Ok(Bytes::from(out))
+}
+
+// --- fMP4 / CMAF ---
+
+#[derive(Debug, Clone, Copy)]
+struct Mp4Box {
+    kind: [u8; 4],
+    start: usize,
+    body: usize,
+    end: usize,
+}
+
+fn malformed(what: &str) -> SampleAesError {
+    SampleAesError::Malformed(what.to_string())
+}
+
+fn read_bytes<const N: usize>(buf: &[u8], at: usize) -> Result<[u8; N], SampleAesError> {
+    buf.get(at..at + N)
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| malformed("box field out of bounds"))
+}
+
+fn read_u16(buf: &[u8], at: usize) -> Result<u16, SampleAesError> {
+    read_bytes(buf, at).map(u16::from_be_bytes)
+}
+
+fn read_u32(buf: &[u8], at: usize) -> Result<u32, SampleAesError> {
+    read_bytes(buf, at).map(u32::from_be_bytes)
+}
+
+fn read_u64(buf: &[u8], at: usize) -> Result<u64, SampleAesError> {
+    read_bytes(buf, at).map(u64::from_be_bytes)
+}
+
+fn read_boxes(buf: &[u8], start: usize, end: usize) -> Result<Vec<Mp4Box>, SampleAesError> {
+    let mut boxes = Vec::new();
+    let mut pos = start;
+    while pos + 8 <= end {
+        let kind = read_bytes(buf, pos + 4)?;
+        let (size, header) = match read_u32(buf, pos)? {
+            0 => (end - pos, 8),
+            1 => (
+                usize::try_from(read_u64(buf, pos + 8)?).map_err(|_| malformed("box size"))?,
+                16,
+            ),
+            size => (size as usize, 8),
+        };
+        if size < header || size > end - pos {
+            return Err(malformed("box overruns its parent"));
+        }
+        boxes.push(Mp4Box {
+            kind,
+            start: pos,
+            body: pos + header,
+            end: pos + size,
+        });
+        pos += size;
+    }
+    Ok(boxes)
+}
+
+fn find_box(boxes: &[Mp4Box], kind: &[u8; 4]) -> Option<Mp4Box> {
+    boxes.iter().find(|b| &b.kind == kind).copied()
+}
+
+fn find_path(
+    buf: &[u8],
+    parent: Mp4Box,
+    path: &[&[u8; 4]],
+) -> Result<Option<Mp4Box>, SampleAesError> {
+    let mut current = parent;
+    for kind in path {
+        match find_box(&read_boxes(buf, current.body, current.end)?, kind) {
+            Some(child) => current = child,
+            None => return Ok(None),
+        }
+    }
+    Ok(Some(current))
+}
+
+fn rename_box(buf: &mut [u8], mp4_box: Mp4Box, kind: &[u8; 4]) {
+    buf[mp4_box.start + 4..mp4_box.start + 8].copy_from_slice(kind);
+}
+
+fn padded_iv(bytes: &[u8]) -> [u8; 16] {
+    let mut iv = [0u8; 16];
+    let len = bytes.len().min(16);
+    iv[..len].copy_from_slice(&bytes[..len]);
+    iv
+}
+
+fn parse_tenc(buf: &[u8], tenc: Mp4Box) -> Result<TrackProtection, SampleAesError> {
+    let body = tenc.body;
+    let version = read_bytes::<1>(buf, body)?[0];
+    let [_, pattern, protected, per_sample_iv_size] = read_bytes(buf, body + 4)?;
+    let (crypt_byte_block, skip_byte_block) = if version == 0 {
+        (0, 0)
+    } else {
+        (pattern >> 4, pattern & 0x0F)
+    };
+    let constant_iv = if protected == 1 && per_sample_iv_size == 0 {
+        let size = usize::from(read_bytes::<1>(buf, body + 24)?[0]);
+        let iv = buf
+            .get(body + 25..body + 25 + size)
+            .ok_or_else(|| malformed("tenc constant IV out of bounds"))?;
+        Some(padded_iv(iv))
+    } else {
+        None
+    };
+    Ok(TrackProtection {
+        protected: protected == 1,
+        per_sample_iv_size,
+        crypt_byte_block,
+        skip_byte_block,
+        constant_iv,
+    })
+}
+
+fn clear_track(
+    buf: &mut [u8],
+    trak: Mp4Box,
+    protection: &mut CmafProtection,
+) -> Result<(), SampleAesError> {
+    let Some(tkhd) = find_path(buf, trak, &[b"tkhd"])? else {
+        return Ok(());
+    };
+    let version = read_bytes::<1>(buf, tkhd.body)?[0];
+    let track_id = read_u32(buf, tkhd.body + if version == 1 { 20 } else { 12 })?;
+    let Some(stsd) = find_path(buf, trak, &[b"mdia", b"minf", b"stbl", b"stsd"])? else {
+        return Ok(());
+    };
+
+    // stsd: full box header and entry count precede the sample entries.
+    for entry in read_boxes(buf, stsd.body + 8, stsd.end)? {
+        // Fixed fields of visual and audio sample entries before their children.
+        let fields = match &entry.kind {
+            b"encv" => 78,
+            b"enca" => 28,
+            _ => continue,
+        };
+        let children = read_boxes(buf, entry.body + fields, entry.end)?;
+        let Some(sinf) = find_box(&children, b"sinf") else {
+            continue;
+        };
+        let frma = find_path(buf, sinf, &[b"frma"])?
+            .ok_or_else(|| malformed("protected sample entry without frma"))?;
+        let original_format: [u8; 4] = read_bytes(buf, frma.body)?;
+        let schm = find_path(buf, sinf, &[b"schm"])?
+            .ok_or_else(|| malformed("protected sample entry without schm"))?;
+        let scheme: [u8; 4] = read_bytes(buf, schm.body + 4)?;
+        if &scheme != b"cbcs" && &scheme != b"cbc1" {
+            return Err(SampleAesError::Unsupported(format!(
+                "protection scheme {}",
+                String::from_utf8_lossy(&scheme)
+            )));
+        }
+        let tenc = find_path(buf, sinf, &[b"schi", b"tenc"])?
+            .ok_or_else(|| malformed("protected sample entry without tenc"))?;
+        protection.tracks.insert(track_id, parse_tenc(buf, tenc)?);
+
+        rename_box(buf, entry, &original_format);
+        rename_box(buf, sinf, b"free");
+    }
+    Ok(())
+}
+
+/// Turn a `cbcs` init segment into a clear one and extract the protection
+/// parameters its media segments need.
+pub fn clear_cmaf_init(data: &[u8]) -> Result<(Bytes, CmafProtection), SampleAesError> {
+    let mut buf = data.to_vec();
+    let mut protection = CmafProtection::default();
+    for moov in read_boxes(&buf, 0, buf.len())?
+        .into_iter()
+        .filter(|b| &b.kind == b"moov")
+    {
+        for child in read_boxes(&buf, moov.body, moov.end)? {
+            match &child.kind {
+                b"pssh" => rename_box(&mut buf, child, b"free"),
+                b"trak" => clear_track(&mut buf, child, &mut protection)?,
+                _ => {}
+            }
+        }
+    }
+    Ok((Bytes::from(buf), protection))
+}
+
+struct EncryptedSample {
+    range: Range<usize>,
+    iv: [u8; 16],
+    /// `(clear, protected)` byte counts; empty when the whole sample is protected.
+    subsamples: Vec<(usize, usize)>,
+}
+
+/// Sample byte ranges of one `traf`, from its `tfhd` and `trun` boxes.
+fn sample_ranges(
+    buf: &[u8],
+    moof_start: usize,
+    children: &[Mp4Box],
+) -> Result<(u32, Vec<Range<usize>>), SampleAesError> {
+    let tfhd = find_box(children, b"tfhd").ok_or_else(|| malformed("traf without tfhd"))?;
+    let flags = read_u32(buf, tfhd.body)? & 0x00FF_FFFF;
+    let track_id = read_u32(buf, tfhd.body + 4)?;
+    let mut pos = tfhd.body + 8;
+    // Without an explicit base offset CMAF sets default-base-is-moof.
+    let mut base = moof_start;
+    if flags & 0x01 != 0 {
+        base = usize::try_from(read_u64(buf, pos)?).map_err(|_| malformed("base offset"))?;
+        pos += 8;
+    }
+    for (flag, size) in [(0x02, 4), (0x08, 4)] {
+        if flags & flag != 0 {
+            pos += size;
+        }
+    }
+    let default_size = if flags & 0x10 != 0 {
+        Some(read_u32(buf, pos)?)
+    } else {
+        None
+    };
+
+    let mut ranges = Vec::new();
+    let mut next = base;
+    for trun in children.iter().filter(|b| &b.kind == b"trun") {
+        let flags = read_u32(buf, trun.body)? & 0x00FF_FFFF;
+        let count = read_u32(buf, trun.body + 4)?;
+        let mut pos = trun.body + 8;
+        if flags & 0x01 != 0 {
+            let offset = read_u32(buf, pos)? as i32;
+            next = base
+                .checked_add_signed(offset as isize)
+                .ok_or_else(|| malformed("trun data offset"))?;
+            pos += 4;
+        }
+        if flags & 0x04 != 0 {
+            pos += 4;
+        }
+        for _ in 0..count {
+            if flags & 0x100 != 0 {
+                pos += 4;
+            }
+            let size = if flags & 0x200 != 0 {
+                pos += 4;
+                read_u32(buf, pos - 4)?
+            } else {
+                default_size.ok_or_else(|| malformed("sample without a size"))?
+            };
+            for flag in [0x400, 0x800] {
+                if flags & flag != 0 {
+                    pos += 4;
+                }
+            }
+            let end = next + size as usize;
+            if end > buf.len() {
+                return Err(malformed("sample outside the segment"));
+            }
+            ranges.push(next..end);
+            next = end;
+        }
+    }
+    Ok((track_id, ranges))
+}
+
+fn fragment_samples(
+    buf: &mut [u8],
+    moof_start: usize,
+    traf: Mp4Box,
+    protection: &CmafProtection,
+    fallback_iv: &[u8; 16],
+    samples: &mut Vec<(EncryptedSample, (usize, usize))>,
+) -> Result<(), SampleAesError> {
+    let children = read_boxes(buf, traf.body, traf.end)?;
+    let (track_id, ranges) = sample_ranges(buf, moof_start, &children)?;
+    let Some(track) = protection.track(track_id).filter(|track| track.protected) else {
+        return Ok(());
+    };
+    let pattern = (
+        usize::from(track.crypt_byte_block),
+        usize::from(track.skip_byte_block),
+    );
+    let default_iv = track.constant_iv.unwrap_or(*fallback_iv);
+
+    let Some(senc) = find_box(&children, b"senc") else {
+        samples.extend(ranges.into_iter().map(|range| {
+            let sample = EncryptedSample {
+                range,
+                iv: default_iv,
+                subsamples: Vec::new(),
+            };
+            (sample, pattern)
+        }));
+        return Ok(());
+    };
+
+    let flags = read_u32(buf, senc.body)? & 0x00FF_FFFF;
+    let count = read_u32(buf, senc.body + 4)? as usize;
+    if count != ranges.len() {
+        return Err(malformed("senc and trun sample counts differ"));
+    }
+    let iv_size = usize::from(track.per_sample_iv_size);
+    let mut pos = senc.body + 8;
+    for range in ranges {
+        let iv = if iv_size > 0 {
+            let bytes = buf
+                .get(pos..pos + iv_size)
+                .ok_or_else(|| malformed("senc IV out of bounds"))?;
+            pos += iv_size;
+            padded_iv(bytes)
+        } else {
+            default_iv
+        };
+        let mut subsamples = Vec::new();
+        if flags & 0x02 != 0 {
+            let entries = read_u16(buf, pos)?;
+            pos += 2;
+            for _ in 0..entries {
+                let clear = usize::from(read_u16(buf, pos)?);
+                let protected = read_u32(buf, pos + 2)? as usize;
+                subsamples.push((clear, protected));
+                pos += 6;
+            }
+        }
+        samples.push((
+            EncryptedSample {
+                range,
+                iv,
+                subsamples,
+            },
+            pattern,
+        ));
+    }
+
+    // The sample auxiliary information describes ciphertext that no longer exists.
+    for aux in children
+        .iter()
+        .filter(|b| matches!(&b.kind, b"senc" | b"saiz" | b"saio"))
+    {
+        rename_box(buf, *aux, b"free");
+    }
+    Ok(())
+}
+
+/// Decrypt a `cbcs` media segment in place, using the protection parameters
+/// of its init segment. `fallback_iv` (the playlist IV) applies to tracks with
+/// neither per-sample nor constant IVs.
+pub fn decrypt_cmaf_segment(
+    data: &[u8],
+    protection: &CmafProtection,
+    key: &[u8; 16],
+    fallback_iv: &[u8; 16],
+) -> Result<Bytes, SampleAesError> {
+    let mut buf = data.to_vec();
+    let mut samples = Vec::new();
+    for moof in read_boxes(&buf, 0, buf.len())?
+        .into_iter()
+        .filter(|b| &b.kind == b"moof")
+    {
+        for traf in read_boxes(&buf, moof.body, moof.end)?
+            .into_iter()
+            .filter(|b| &b.kind == b"traf")
+        {
+            fragment_samples(
+                &mut buf,
+                moof.start,
+                traf,
+                protection,
+                fallback_iv,
+                &mut samples,
+            )?;
+        }
+    }
+
+    for (sample, (crypt, skip)) in samples {
+        let data = &mut buf[sample.range];
+        if sample.subsamples.is_empty() {
+            decrypt_pattern(data, key, &sample.iv, crypt, skip);
+            continue;
+        }
+        let mut pos = 0;
+        for (clear, protected) in sample.subsamples {
+            pos += clear;
+            let region = data
+                .get_mut(pos..pos + protected)
+                .ok_or_else(|| malformed("subsample outside its sample"))?;
+            // The chain restarts from the sample IV at every subsample.
+            decrypt_pattern(region, key, &sample.iv, crypt, skip);
+            pos += protected;
+        }
+    }
+    Ok(Bytes::from(buf))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cipher::BlockModeEncrypt;
+
+    type Aes128CbcEnc = cbc::Encryptor<Aes128>;
+
+    const KEY: [u8; 16] = [0x11; 16];
+    const IV: [u8; 16] = [0x22; 16];
+
+    fn encrypt_pattern(data: &mut [u8], iv: &[u8; 16], crypt: usize, skip: usize) {
+        let mut encryptor = Aes128CbcEnc::new(&KEY.into(), &(*iv).into());
+        let (blocks, _) = Array::slice_as_chunks_mut(data);
+        if crypt == 0 {
+            encryptor.encrypt_blocks(blocks);
+            return;
+        }
+        for run in blocks.chunks_mut(crypt + skip) {
+            let encrypted = crypt.min(run.len());
+            encryptor.encrypt_blocks(&mut run[..encrypted]);
+        }
+    }
+
+    fn add_emulation_prevention(nal: &[u8]) -> Vec<u8> {
+        let mut out = Vec::with_capacity(nal.len());
+        let mut zeros = 0;
+        for &byte in nal {
+            if zeros >= 2 && byte <= 0x03 {
+                out.push(0x03);
+                zeros = 0;
+            }
+            zeros = if byte == 0 { zeros + 1 } else { 0 };
+            out.push(byte);
+        }
+        out
+    }
+
+    fn pes(stream_id: u8, es: &[u8], bounded: bool) -> Vec<u8> {
+        let length = if bounded { 8 + es.len() } else { 0 };
+        let mut pes = vec![0, 0, 1, stream_id];
+        pes.extend_from_slice(&(length as u16).to_be_bytes());
+        pes.extend_from_slice(&[0x80, 0x80, 0x05, 0x21, 0x00, 0x01, 0x00, 0x01]);
+        pes.extend_from_slice(es);
+        pes
+    }
+
+    fn psi_packet(pid: u16, mut section: Vec<u8>) -> Vec<u8> {
+        let crc = ts::mpeg2_crc32(&section);
+        section.extend_from_slice(&crc.to_be_bytes());
+        let mut packet = vec![TS_SYNC_BYTE, 0x40 | (pid >> 8) as u8, pid as u8, 0x10, 0x00];
+        packet.extend_from_slice(&section);
+        packet.resize(TS_PACKET_SIZE, 0xFF);
+        packet
+    }
+
+    fn program_tables(video_type: u8, audio_type: u8) -> Vec<u8> {
+        let mut out = psi_packet(
+            PID_PAT,
+            vec![
+                0x00, 0xB0, 13, 0x00, 0x01, 0xC1, 0x00, 0x00, 0x00, 0x01, 0xF0, 0x00,
+            ],
+        );
+        out.extend(psi_packet(
+            0x1000,
+            vec![
+                0x02, 0xB0, 23, 0x00, 0x01, 0xC1, 0x00, 0x00, 0xE1, 0x00, 0xF0, 0x00, video_type,
+                0xE1, 0x00, 0xF0, 0x00, audio_type, 0xE1, 0x01, 0xF0, 0x00,
+            ],
+        ));
+        out
+    }
+
+    /// Clear and SAMPLE-AES versions of a segment with one H.264 and one AAC PES.
+    fn ts_segment() -> (Vec<u8>, Vec<u8>, Vec<u8>, Vec<u8>) {
+        let aud = [0x09, 0xF0];
+        // The clear slice carries its own emulation prevention; encryption is
+        // applied to it and the ciphertext escaped once more.
+        let mut rbsp: Vec<u8> = vec![0x65];
+        rbsp.extend((1..=400u32).map(|i| if i % 50 < 3 { 0 } else { i as u8 | 1 }));
+        let idr = add_emulation_prevention(&rbsp);
+        let sps: Vec<u8> = std::iter::once(0x67).chain(1..=60).collect();
+
+        let mut encrypted_idr = idr.clone();
+        let last = encrypted_idr.len() - 1;
+        encrypt_pattern(&mut encrypted_idr[NAL_CLEAR_LEADER..last], &IV, 1, 9);
+        let encrypted_idr = add_emulation_prevention(&encrypted_idr);
+
+        let h264 = |idr: &[u8]| {
+            let mut es = Vec::new();
+            for nal in [&aud[..], &sps, idr] {
+                es.extend_from_slice(&[0, 0, 0, 1]);
+                es.extend_from_slice(nal);
+            }
+            es
+        };
+        let clear_video = pes(0xE0, &h264(&idr), false);
+        let encrypted_video = pes(0xE0, &h264(&encrypted_idr), false);
+
+        let mut frame = vec![0xFF, 0xF1, 0x50, 0x80, 0x00, 0x1F, 0xFC];
+        frame.extend((0..100u8).map(|i| i.wrapping_mul(7)));
+        let frame_len = frame.len();
+        frame[3] |= ((frame_len >> 11) & 0x03) as u8;
+        frame[4] = (frame_len >> 3) as u8;
+        frame[5] = ((frame_len & 0x07) << 5) as u8 | 0x1F;
+        let mut encrypted_frame = frame.clone();
+        encrypt_pattern(&mut encrypted_frame[7 + ADTS_CLEAR_LEADER..], &IV, 0, 0);
+        let clear_audio = pes(0xC0, &[frame.clone(), frame].concat(), true);
+        let encrypted_audio = pes(
+            0xC0,
+            &[encrypted_frame.clone(), encrypted_frame].concat(),
+            true,
+        );
+
+        let pcr = Some(vec![0x50, 0x00, 0x00, 0x00, 0x01, 0x7E, 0x00]);
+        let mut segment = program_tables(STREAM_TYPE_H264_SAMPLE_AES, STREAM_TYPE_AAC_SAMPLE_AES);
+        let (mut video_cc, mut audio_cc) = (3, 9);
+        write_pes(
+            &mut segment,
+            0x100,
+            &mut video_cc,
+            &[pcr, None, None],
+            &encrypted_video,
+        );
+        write_pes(&mut segment, 0x101, &mut audio_cc, &[], &encrypted_audio);
+        (segment, clear_video, clear_audio, encrypted_video)
+    }
+
+    fn pid_packets(segment: &[u8], pid: u16) -> Vec<&[u8]> {
+        segment
+            .chunks_exact(TS_PACKET_SIZE)
+            .filter(|packet| PacketView::parse(packet).unwrap().pid == pid)
+            .collect()
+    }
+
+    fn pid_payload(segment: &[u8], pid: u16) -> Vec<u8> {
+        pid_packets(segment, pid)
+            .into_iter()
+            .flat_map(|packet| {
+                let view = PacketView::parse(packet).unwrap();
+                packet[view.payload].to_vec()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn pattern_decryption_leaves_skipped_and_partial_blocks_clear() {
+        let clear: Vec<u8> = (0..=255).collect::<Vec<_>>().repeat(2)[..16 * 12 + 5].to_vec();
+        let mut data = clear.clone();
+        encrypt_pattern(&mut data, &IV, 1, 9);
+        assert_ne!(data[..16], clear[..16]);
+        assert_eq!(data[16..160], clear[16..160]);
+        assert_ne!(data[160..176], clear[160..176]);
+        assert_eq!(data[192..], clear[192..]);
+
+        decrypt_pattern(&mut data, &KEY, &IV, 1, 9);
+        assert_eq!(data, clear);
+    }
+
+    #[test]
+    fn ts_segment_is_decrypted_and_restored_to_clear_stream_types() {
+        let (segment, clear_video, clear_audio, encrypted_video) = ts_segment();
+        let out = decrypt_ts(&segment, &KEY, &IV).unwrap();
+        assert_eq!(out.len() % TS_PACKET_SIZE, 0);
+        assert!(out.len() <= segment.len());
+
+        let pmt = pid_packets(&out, 0x1000)[0];
+        let section = psi_section(pmt, &PacketView::parse(pmt).unwrap()).unwrap();
+        let section = &pmt[section];
+        assert!(ts::validate_section_crc32(section));
+        assert_eq!(
+            (section[12], section[17]),
+            (STREAM_TYPE_H264, STREAM_TYPE_ADTS_AAC)
+        );
+
+        let video = pid_payload(&out, 0x100);
+        assert_eq!(video[..clear_video.len()], clear_video[..]);
+        assert!(video[clear_video.len()..].is_empty());
+        assert_ne!(encrypted_video, clear_video);
+        assert_eq!(pid_payload(&out, 0x101), clear_audio);
+
+        let video_packets = pid_packets(&out, 0x100);
+        let first = PacketView::parse(video_packets[0]).unwrap();
+        assert!(first.pusi);
+        assert_eq!(video_packets[0][first.adaptation.start], 0x50, "PCR kept");
+        let counters: Vec<_> = video_packets
+            .iter()
+            .map(|packet| PacketView::parse(packet).unwrap().continuity_counter)
+            .collect();
+        let expected: Vec<_> = (3..3 + counters.len() as u8).map(|cc| cc & 0x0F).collect();
+        assert_eq!(counters, expected);
+    }
+
+    #[test]
+    fn ts_clear_streams_pass_through_unchanged() {
+        let clean = program_tables(STREAM_TYPE_H264, STREAM_TYPE_ADTS_AAC);
+        let out = decrypt_ts(&clean, &KEY, &IV).unwrap();
+        assert_eq!(out.as_ref(), clean.as_slice());
+    }
+
+    #[test]
+    fn ts_ac3_sample_aes_is_unsupported() {
+        let segment = program_tables(STREAM_TYPE_H264_SAMPLE_AES, STREAM_TYPE_AC3_SAMPLE_AES);
+        assert!(matches!(
+            decrypt_ts(&segment, &KEY, &IV),
+            Err(SampleAesError::Unsupported(_))
+        ));
+    }
+
+    fn mp4_box(kind: &[u8; 4], body: &[u8]) -> Vec<u8> {
+        let mut out = ((body.len() + 8) as u32).to_be_bytes().to_vec();
+        out.extend_from_slice(kind);
+        out.extend_from_slice(body);
+        out
+    }
+
+    fn full_box(kind: &[u8; 4], version: u8, flags: u32, body: &[u8]) -> Vec<u8> {
+        let mut header = (flags | (u32::from(version) << 24)).to_be_bytes().to_vec();
+        header.extend_from_slice(body);
+        mp4_box(kind, &header)
+    }
+
+    fn cbcs_init(scheme: &[u8; 4]) -> Vec<u8> {
+        let mut tkhd = vec![0u8; 80];
+        tkhd[8..12].copy_from_slice(&1u32.to_be_bytes());
+        let mut tenc = vec![0x00, 0x19, 0x01, 0x00];
+        tenc.extend_from_slice(&[0xAB; 16]);
+        tenc.push(16);
+        tenc.extend_from_slice(&IV);
+        let sinf = mp4_box(
+            b"sinf",
+            &[
+                mp4_box(b"frma", b"avc1"),
+                full_box(b"schm", 0, 0, &[scheme.as_slice(), &[0, 1, 0, 0]].concat()),
+                mp4_box(b"schi", &full_box(b"tenc", 1, 0, &tenc)),
+            ]
+            .concat(),
+        );
+        let encv = mp4_box(
+            b"encv",
+            &[vec![0u8; 78], mp4_box(b"avcC", &[1, 2, 3]), sinf].concat(),
+        );
+        let stsd = full_box(b"stsd", 0, 0, &[1u32.to_be_bytes().to_vec(), encv].concat());
+        let stbl = mp4_box(b"stbl", &stsd);
+        let trak = mp4_box(
+            b"trak",
+            &[
+                full_box(b"tkhd", 0, 3, &tkhd),
+                mp4_box(b"mdia", &mp4_box(b"minf", &stbl)),
+            ]
+            .concat(),
+        );
+        let moov = mp4_box(b"moov", &[trak, full_box(b"pssh", 0, 0, &[0; 20])].concat());
+        [mp4_box(b"ftyp", b"isom\0\0\0\0"), moov].concat()
+    }
+
+    fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+        haystack
+            .windows(needle.len())
+            .any(|window| window == needle)
+    }
+
+    #[test]
+    fn cmaf_init_is_cleared_and_protection_extracted() {
+        let init = cbcs_init(b"cbcs");
+        let (clear, protection) = clear_cmaf_init(&init).unwrap();
+
+        assert_eq!(clear.len(), init.len());
+        assert!(contains(&clear, b"avc1\0\0\0\0"), "sample entry renamed");
+        for kind in [b"encv", b"sinf", b"pssh"] {
+            assert!(!contains(&clear, kind), "{}", String::from_utf8_lossy(kind));
+        }
+        assert_eq!(
+            protection.track(1),
+            Some(&TrackProtection {
+                protected: true,
+                per_sample_iv_size: 0,
+                crypt_byte_block: 1,
+                skip_byte_block: 9,
+                constant_iv: Some(IV),
+            })
+        );
+    }
+
+    #[test]
+    fn cmaf_cenc_scheme_is_unsupported() {
+        assert!(matches!(
+            clear_cmaf_init(&cbcs_init(b"cenc")),
+            Err(SampleAesError::Unsupported(_))
+        ));
+    }
+
+    #[test]
+    fn cmaf_media_samples_are_decrypted_in_place() {
+        let (_, protection) = clear_cmaf_init(&cbcs_init(b"cbcs")).unwrap();
+        let clear_samples: Vec<Vec<u8>> = [200usize, 40]
+            .iter()
+            .map(|&len| (0..len).map(|i| (i * 13 + len) as u8).collect())
+            .collect();
+        let mut encrypted_samples = clear_samples.clone();
+        // Sample 1: 5 clear bytes, then a 195 byte protected range.
+        encrypt_pattern(&mut encrypted_samples[0][5..], &IV, 1, 9);
+        // Sample 2: whole sample protected (subsample with no clear bytes).
+        encrypt_pattern(&mut encrypted_samples[1], &IV, 1, 9);
+
+        let build = |data_offset: u32| {
+            let mut trun = 2u32.to_be_bytes().to_vec();
+            trun.extend_from_slice(&data_offset.to_be_bytes());
+            for sample in &clear_samples {
+                trun.extend_from_slice(&(sample.len() as u32).to_be_bytes());
+            }
+            let mut senc = 2u32.to_be_bytes().to_vec();
+            for (clear, protected) in [(5u16, 195u32), (0, 40)] {
+                senc.extend_from_slice(&1u16.to_be_bytes());
+                senc.extend_from_slice(&clear.to_be_bytes());
+                senc.extend_from_slice(&protected.to_be_bytes());
+            }
+            let traf = mp4_box(
+                b"traf",
+                &[
+                    full_box(b"tfhd", 0, 0x02_0000, &1u32.to_be_bytes()),
+                    full_box(b"trun", 0, 0x201, &trun),
+                    full_box(b"senc", 0, 0x2, &senc),
+                ]
+                .concat(),
+            );
+            mp4_box(
+                b"moof",
+                &[full_box(b"mfhd", 0, 0, &1u32.to_be_bytes()), traf].concat(),
+            )
+        };
+        let moof_len = build(0).len();
+        let moof = build(moof_len as u32 + 8);
+        let segment = [moof, mp4_box(b"mdat", &encrypted_samples.concat())].concat();
+
+        let out = decrypt_cmaf_segment(&segment, &protection, &KEY, &[0; 16]).unwrap();
+        assert_eq!(out.len(), segment.len());
+        assert!(!contains(&out, b"senc"));
+        assert_eq!(out[out.len() - 240..], clear_samples.concat()[..]);
+    }
+}
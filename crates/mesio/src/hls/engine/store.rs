@@ -14,6 +14,10 @@ use std::time::{Duration, Instant};
 
 use tracing::{debug, trace, warn};
 
+use reqwest::StatusCode;
+
+use crate::retry::{RetryOn, RetryPolicy};
+
 use super::budget::{ByteBudget, ByteReservation};
 use super::descriptor::{SegmentDescriptor, SegmentSource};
 use super::identity::{SegmentKey, SegmentKind};
@@ -141,6 +145,12 @@ pub struct StoreConfig {
     pub retry_budget: u32,
     pub retry_delay_base: Duration,
     pub retry_delay_max: Duration,
+    /// Growth of the reschedule delay and its random spread, as in [`RetryPolicy`].
+    pub retry_multiplier: f64,
+    pub retry_jitter: f64,
+    /// Failures that are rescheduled. A 404 is always rescheduled: a segment
+    /// the playlist announced can 404 until every edge has it.
+    pub retry_on: RetryOn,
     /// Size estimate used before any segment has completed; afterwards an EMA
     /// of actual completed sizes takes over.
     pub fallback_size_estimate: u64,
@@ -158,6 +168,9 @@ impl Default for StoreConfig {
             retry_budget: 3,
             retry_delay_base: Duration::from_millis(500),
             retry_delay_max: Duration::from_secs(10),
+            retry_multiplier: 2.0,
+            retry_jitter: 0.0,
+            retry_on: RetryOn::default(),
             fallback_size_estimate: 2 * 1024 * 1024,
             max_segment_size: 0,
             max_retained_inits: 8,
@@ -188,7 +201,8 @@ struct SegmentRecord {
     ready_entry: Option<ReadyEntry>,
 }
 
-/// Ready-index entry. Order: init first, then playlist media, then prefetch;
+/// Ready-index entry. Order: init first, then playlist media and parts, then
+/// prefetch and preload hints;
 /// within a class lower MSN first, then stable insertion order. `order` is
 /// globally unique, which keeps the `BTreeSet` collision-free.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
@@ -201,8 +215,8 @@ struct ReadyEntry {
 fn priority_class(descriptor: &SegmentDescriptor) -> u8 {
     match (descriptor.key.kind, descriptor.source) {
         (SegmentKind::Init, _) => 0,
-        (SegmentKind::Media, SegmentSource::Playlist) => 1,
-        (SegmentKind::Media, SegmentSource::PlaylistPrefetch) => 2,
+        (SegmentKind::Media | SegmentKind::Part, SegmentSource::Playlist) => 1,
+        (_, SegmentSource::PlaylistPrefetch | SegmentSource::PreloadHint) => 2,
     }
 }
 
@@ -560,20 +574,31 @@ impl SegmentStateStore {
                     return OutcomeEffects::default();
                 };
 
+                if record.descriptor.source == SegmentSource::PreloadHint {
+                    // A hint only announces the next part. Forget it instead of
+                    // retrying or terminalizing, so the published part is
+                    // discovered as fresh work on the next refresh.
+                    debug!(msn, ?class, %reason, "preload hint failed; dropping it");
+                    self.records.remove(&key);
+                    return OutcomeEffects::default();
+                }
+
+                let retry_on = &self.config.retry_on;
                 let retryable = match class {
-                    FailureClass::Http(404 | 429) | FailureClass::Http(500..=599) => true,
-                    FailureClass::Network
-                    | FailureClass::Timeout
-                    | FailureClass::Decode
-                    | FailureClass::OverBudget => true,
+                    FailureClass::Http(404) => true,
+                    FailureClass::Network => retry_on.network,
+                    FailureClass::Timeout => retry_on.timeout,
+                    FailureClass::Decode | FailureClass::OverBudget => true,
                     // Auth failures are conditionally retryable: a signed URL
                     // can expire mid-flight while a newer playlist already
                     // refreshed it. Retry only when re-discovery advanced the
                     // generation past the one this attempt fetched with —
                     // otherwise the denial is real.
                     FailureClass::Http(401 | 403) => record.generation > record.attempt_generation,
-                    FailureClass::Http(_)
-                    | FailureClass::UnsupportedCrypto
+                    FailureClass::Http(status) => {
+                        StatusCode::from_u16(status).is_ok_and(|status| retry_on.status(status))
+                    }
+                    FailureClass::UnsupportedCrypto
                     | FailureClass::InvalidFormat
                     | FailureClass::Oversize => false,
                 };
@@ -581,13 +606,14 @@ impl SegmentStateStore {
                 if retryable && record.reschedules < self.config.retry_budget {
                     record.reschedules += 1;
                     let reschedules = record.reschedules;
-                    let exp = reschedules.saturating_sub(1).min(16);
-                    let delay = self
-                        .config
-                        .retry_delay_base
-                        .checked_mul(1u32 << exp)
-                        .unwrap_or(self.config.retry_delay_max)
-                        .min(self.config.retry_delay_max);
+                    let delay = RetryPolicy {
+                        base_delay: self.config.retry_delay_base,
+                        max_delay: self.config.retry_delay_max,
+                        multiplier: self.config.retry_multiplier,
+                        jitter: self.config.retry_jitter,
+                        ..RetryPolicy::default()
+                    }
+                    .delay(reschedules);
                     let retry_at = now + delay;
                     record.state = SegmentState::RetryAt {
                         attempt: reschedules,
@@ -744,6 +770,7 @@ mod tests {
             discontinuity: false,
             encryption: None,
             init_key: None,
+            part: None,
             media_segment: Arc::new(m3u8_rs::MediaSegment {
                 uri: uri.to_string(),
                 duration: 2.0,
@@ -915,6 +942,48 @@ mod tests {
         assert!(jobs.is_empty());
     }
 
+    #[test]
+    fn retry_on_decides_which_failures_reschedule() {
+        let mut s = SegmentStateStore::new(StoreConfig {
+            retry_on: RetryOn {
+                network: false,
+                server_error: false,
+                ..RetryOn::default()
+            },
+            ..StoreConfig::default()
+        });
+        let b = budget_unlimited();
+        let now = Instant::now();
+        s.ingest(
+            vec![
+                descriptor("https://e.com/1.ts", 1, SegmentKind::Media),
+                descriptor("https://e.com/2.ts", 2, SegmentKind::Media),
+                descriptor("https://e.com/3.ts", 3, SegmentKind::Media),
+                descriptor("https://e.com/4.ts", 4, SegmentKind::Media),
+            ],
+            now,
+        );
+
+        for (msn, class, rescheduled) in [
+            (1, FailureClass::Http(503), false),
+            (2, FailureClass::Network, false),
+            (3, FailureClass::Http(429), true),
+            (4, FailureClass::Http(404), true),
+        ] {
+            let key = take_one(&mut s, &b).unwrap().descriptor.key.clone();
+            let out = s.apply_outcome(
+                SegmentOutcome::Failed {
+                    key,
+                    msn,
+                    class,
+                    reason: Arc::from("failed"),
+                },
+                now,
+            );
+            assert_eq!(out.retry_notice.is_some(), rescheduled, "{class:?}");
+        }
+    }
+
     #[test]
     fn auth_failure_with_fresh_url_retries_without_fresh_url_terminalizes() {
         let mut s = store();
@@ -1292,6 +1361,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn failed_preload_hint_is_forgotten_and_rediscoverable() {
+        let mut s = store();
+        let b = budget_unlimited();
+        let hint = descriptor_with_source(
+            "https://e.com/11.1.ts",
+            11,
+            SegmentKind::Part,
+            SegmentSource::PreloadHint,
+        );
+        s.ingest(vec![hint.clone()], Instant::now());
+        let key = take_one(&mut s, &b).unwrap().descriptor.key.clone();
+
+        let out = s.apply_outcome(
+            SegmentOutcome::Failed {
+                key,
+                msn: 11,
+                class: FailureClass::Http(404),
+                reason: Arc::from("404"),
+            },
+            Instant::now(),
+        );
+        assert!(out.assembler_inputs.is_empty());
+        assert!(out.retry_notice.is_none());
+        assert!(!s.has_unfinished_work());
+
+        // The published part is fresh work under the same key.
+        let published = SegmentDescriptor {
+            source: SegmentSource::Playlist,
+            ..hint
+        };
+        let stats = s.ingest(vec![published], Instant::now());
+        assert_eq!(stats.discovered, 1);
+        assert!(take_one(&mut s, &b).is_some());
+    }
+
     #[test]
     fn pruned_keys_in_indices_never_schedule() {
         let mut s = store();
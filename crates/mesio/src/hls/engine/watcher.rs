@@ -10,11 +10,17 @@
 //! tracking, no per-segment retry, and no consumer-facing events. The terminal
 //! cause is carried *on the snapshot* (`TerminalCause`), never inferred from a
 //! sender drop, so a watcher crash can never masquerade as a clean ENDLIST.
+//!
+//! With low-latency support enabled, the watcher also scans each refresh for
+//! LL-HLS tags and, when the server advertises `CAN-BLOCK-RELOAD`, requests the
+//! next update with `_HLS_msn` / `_HLS_part` instead of polling: the server
+//! holds the request until that part exists.
 
 use std::borrow::Cow;
 use std::sync::Arc;
 use std::time::Duration;
 
+use hls::LowLatencyPlaylist;
 use m3u8_rs::MediaPlaylist;
 use tokio::sync::watch;
 use tokio_util::sync::CancellationToken;
@@ -49,6 +55,16 @@ pub struct PlaylistSnapshot {
     /// params inherit them (query-param inheritance, see the planner).
     pub parent_query: Option<Arc<str>>,
     pub terminal: Option<TerminalCause>,
+    /// LL-HLS view of this generation (parts, preload hints). Present only
+    /// when low-latency support is enabled and the playlist advertises parts.
+    pub low_latency: Option<Arc<LowLatencyPlaylist>>,
+}
+
+/// One parsed refresh.
+struct FetchedPlaylist {
+    playlist: MediaPlaylist,
+    low_latency: Option<LowLatencyPlaylist>,
+    raw_bytes: bytes::Bytes,
 }
 
 pub struct PlaylistWatcher {
@@ -112,6 +128,7 @@ impl PlaylistWatcher {
             base_url: Arc::clone(&self.base_url),
             parent_query: parent_query.clone(),
             terminal: initial_terminal.clone(),
+            low_latency: None,
         };
         let (tx, rx) = watch::channel(initial);
 
@@ -143,11 +160,19 @@ impl PlaylistWatcher {
             self.config.playlist_config.adaptive_refresh_min_interval,
             self.config.playlist_config.adaptive_refresh_max_interval,
         );
+        // `_HLS_msn` / `_HLS_part` of the next blocking reload. Set only after
+        // a changed refresh from a server that can block; a blocking request
+        // is issued immediately, since the server does the waiting.
+        let mut blocking_update: Option<(u64, Option<u32>)> = None;
 
         loop {
-            let base_refresh_interval = Duration::from_secs_f64(current_target_duration * 0.5)
-                .max(self.config.playlist_config.live_refresh_interval);
-            let refresh_delay = tracker.get_refresh_interval(base_refresh_interval);
+            let refresh_delay = if blocking_update.is_some() {
+                Duration::ZERO
+            } else {
+                let base_refresh_interval = Duration::from_secs_f64(current_target_duration * 0.5)
+                    .max(self.config.playlist_config.live_refresh_interval);
+                tracker.get_refresh_interval(base_refresh_interval)
+            };
 
             tokio::select! {
                 biased;
@@ -162,11 +187,26 @@ impl PlaylistWatcher {
                 _ = tokio::time::sleep(refresh_delay) => {}
             }
 
-            match self.fetch_and_parse(&last_playlist_bytes).await {
-                Ok(Some((playlist, raw_bytes))) => {
+            let fetched = self
+                .fetch_and_parse(
+                    &last_playlist_bytes,
+                    blocking_update.take(),
+                    current_target_duration,
+                )
+                .await;
+            match fetched {
+                Ok(Some(FetchedPlaylist {
+                    playlist,
+                    low_latency,
+                    raw_bytes,
+                })) => {
                     retries = 0;
                     generation += 1;
                     current_target_duration = playlist.target_duration as f64;
+                    blocking_update = low_latency
+                        .as_ref()
+                        .filter(|ll| ll.can_block_reload() && !playlist.end_list)
+                        .map(|ll| ll.next_update(playlist.media_sequence));
 
                     let window_end = playlist.media_sequence + playlist.segments.len() as u64;
                     let new_segments = last_window_end
@@ -183,6 +223,9 @@ impl PlaylistWatcher {
                         base_url: Arc::clone(&self.base_url),
                         parent_query: parent_query.clone(),
                         terminal,
+                        low_latency: low_latency
+                            .filter(LowLatencyPlaylist::is_low_latency)
+                            .map(Arc::new),
                     };
                     last_playlist_bytes = Some(raw_bytes);
                     if tx.send(snapshot).is_err() {
@@ -231,16 +274,19 @@ impl PlaylistWatcher {
     }
 
     /// Fetch and parse one refresh. `Ok(None)` means byte-identical to the
-    /// previous fetch (parse skipped).
+    /// previous fetch (parse skipped). `blocking_update` turns the request
+    /// into a blocking reload, which the server may hold for up to three
+    /// target durations.
     async fn fetch_and_parse(
         &self,
         last_playlist_bytes: &Option<bytes::Bytes>,
-    ) -> Result<Option<(MediaPlaylist, bytes::Bytes)>, HlsDownloaderError> {
+        blocking_update: Option<(u64, Option<u32>)>,
+        target_duration: f64,
+    ) -> Result<Option<FetchedPlaylist>, HlsDownloaderError> {
         if self.cancel.is_cancelled() {
             return Err(HlsDownloaderError::Cancelled);
         }
 
-        let client = self.clients.client_for_url(&self.playlist_url);
         let resource = ResourceId::HlsPlaylist {
             url: Arc::from(self.playlist_url.as_str()),
         };
@@ -252,10 +298,19 @@ impl PlaylistWatcher {
                 content_length: None,
             },
         );
-        let request = client
-            .get(self.playlist_url.clone())
-            .timeout(self.config.playlist_config.initial_playlist_fetch_timeout)
+        let mut timeout = self.config.playlist_config.initial_playlist_fetch_timeout;
+        let mut request = self
+            .clients
+            .get(&self.playlist_url)
             .query(&self.config.base.params);
+        if let Some((msn, part)) = blocking_update {
+            request = request.query(&[("_HLS_msn", msn)]);
+            if let Some(part) = part {
+                request = request.query(&[("_HLS_part", part)]);
+            }
+            timeout = timeout.max(Duration::from_secs_f64(target_duration.max(0.0) * 3.0));
+        }
+        let request = request.timeout(timeout);
 
         let response = tokio::select! {
             _ = self.cancel.cancelled() => return Err(HlsDownloaderError::Cancelled),
@@ -302,7 +357,15 @@ impl PlaylistWatcher {
             };
 
         match m3u8_rs::parse_playlist_res(&playlist_bytes_to_parse) {
-            Ok(m3u8_rs::Playlist::MediaPlaylist(new_mp)) => Ok(Some((new_mp, playlist_bytes))),
+            Ok(m3u8_rs::Playlist::MediaPlaylist(playlist)) => Ok(Some(FetchedPlaylist {
+                playlist,
+                low_latency: self
+                    .config
+                    .playlist_config
+                    .low_latency_enabled
+                    .then(|| LowLatencyPlaylist::parse(&playlist_bytes_to_parse)),
+                raw_bytes: playlist_bytes,
+            })),
             Ok(m3u8_rs::Playlist::MasterPlaylist(_)) => Err(HlsDownloaderError::Playlist {
                 reason: format!(
                     "Expected media playlist, got master for {}",
@@ -88,10 +88,13 @@ impl HlsDownloader {
         });
 
         let mut config = self.config.clone();
-        if let ProtocolSelection::Hls(options) = &request.protocol
-            && let Some(policy) = options.variant_selection_policy.clone()
-        {
-            config.playlist_config.variant_selection_policy = policy;
+        if let ProtocolSelection::Hls(options) = &request.protocol {
+            if let Some(policy) = options.variant_selection_policy.clone() {
+                config.playlist_config.variant_selection_policy = policy;
+            }
+            if let Some(rendition) = options.audio_rendition.clone() {
+                config.playlist_config.audio_rendition = Some(rendition);
+            }
         }
         let config = Arc::new(config);
         let (client_event_rx, handles) = engine::start_with_events(
@@ -4,10 +4,11 @@
 use crate::cache::{CacheKey, CacheManager, CacheMetadata, CacheResourceType};
 use crate::downloader::ClientPool;
 use crate::hls::HlsDownloaderError;
-use crate::hls::config::{HlsConfig, HlsVariantSelectionPolicy};
+use crate::hls::config::{HlsAudioRendition, HlsConfig, HlsVariantSelectionPolicy};
 use crate::hls::twitch_processor::{TwitchPlaylistProcessor, preprocess_twitch_playlist};
 use crate::session::{DownloadEvent, EventSink, ResourceId};
-use m3u8_rs::{MasterPlaylist, MediaPlaylist, parse_playlist_res};
+use hls::{MultivariantPlaylist, RenditionType, VariantSelection};
+use m3u8_rs::{MediaPlaylist, parse_playlist_res};
 use std::borrow::Cow;
 use std::sync::Arc;
 use tracing::{debug, warn};
@@ -15,7 +16,7 @@ use url::Url;
 
 #[derive(Debug, Clone)]
 pub enum InitialPlaylist {
-    Master(MasterPlaylist, String),
+    Master(MultivariantPlaylist, String),
     Media(MediaPlaylist, String),
 }
 
@@ -77,7 +78,6 @@ impl PlaylistEngine {
             return Self::parse_initial(&playlist_url, &cached_data);
         }
 
-        let client = self.clients.client_for_url(&playlist_url);
         let resource = ResourceId::HlsPlaylist {
             url: Arc::from(playlist_url.as_str()),
         };
@@ -89,8 +89,9 @@ impl PlaylistEngine {
                 content_length: None,
             },
         );
-        let response = client
-            .get(playlist_url.clone())
+        let response = self
+            .clients
+            .get(&playlist_url)
             .timeout(self.config.playlist_config.initial_playlist_fetch_timeout)
             .query(&self.config.base.params)
             .send()
@@ -150,7 +151,10 @@ impl PlaylistEngine {
             playlist_url, base_url
         );
         match parse_playlist_res(&playlist_bytes_to_parse) {
-            Ok(m3u8_rs::Playlist::MasterPlaylist(pl)) => Ok(InitialPlaylist::Master(pl, base_url)),
+            Ok(m3u8_rs::Playlist::MasterPlaylist(pl)) => Ok(InitialPlaylist::Master(
+                MultivariantPlaylist::from(&pl),
+                base_url,
+            )),
             Ok(m3u8_rs::Playlist::MediaPlaylist(pl)) => Ok(InitialPlaylist::Media(pl, base_url)),
             Err(e) => Err(HlsDownloaderError::Playlist {
                 reason: format!("Failed to parse playlist: {e}"),
@@ -178,80 +182,96 @@ impl PlaylistEngine {
                 reason: "Master playlist has no variants".to_string(),
             });
         }
-        let selected_variant = match policy {
-            HlsVariantSelectionPolicy::HighestBitrate => master_playlist_ref
-                .variants
-                .iter()
-                .max_by_key(|v| v.bandwidth)
-                .ok_or_else(|| HlsDownloaderError::Playlist {
-                    reason: "No variants for HighestBitrate".to_string(),
-                })?,
-            HlsVariantSelectionPolicy::LowestBitrate => master_playlist_ref
-                .variants
-                .iter()
-                .min_by_key(|v| v.bandwidth)
-                .ok_or_else(|| HlsDownloaderError::Playlist {
-                    reason: "No variants for LowestBitrate".to_string(),
-                })?,
-            HlsVariantSelectionPolicy::ClosestToBitrate(target_bw) => master_playlist_ref
-                .variants
-                .iter()
-                .min_by_key(|v| (*target_bw as i64 - v.bandwidth as i64).abs())
-                .ok_or_else(|| HlsDownloaderError::Playlist {
-                    reason: format!("No variants for ClosestToBitrate: {target_bw}"),
-                })?,
-            HlsVariantSelectionPolicy::AudioOnly => master_playlist_ref
-                .variants
-                .iter()
-                .find(|v| {
-                    v.audio.is_some()
-                        && v.video.is_none()
-                        && v.codecs.as_ref().is_some_and(|c| c.contains("mp4a"))
-                })
-                .ok_or_else(|| HlsDownloaderError::Playlist {
-                    reason: "No AudioOnly variant".to_string(),
-                })?,
-            HlsVariantSelectionPolicy::VideoOnly => master_playlist_ref
-                .variants
-                .iter()
-                .find(|v| v.video.is_some() && v.audio.is_none())
-                .ok_or_else(|| HlsDownloaderError::Playlist {
-                    reason: "No VideoOnly variant".to_string(),
-                })?,
-            HlsVariantSelectionPolicy::MatchingResolution { width, height } => master_playlist_ref
-                .variants
-                .iter()
-                .find(|v| {
-                    v.resolution
-                        .is_some_and(|r| r.width == (*width as u64) && r.height == (*height as u64))
+        let selection = match policy {
+            HlsVariantSelectionPolicy::HighestBitrate => Some(VariantSelection::HighestBandwidth),
+            HlsVariantSelectionPolicy::LowestBitrate => Some(VariantSelection::LowestBandwidth),
+            HlsVariantSelectionPolicy::ClosestToBitrate(target_bw) => {
+                Some(VariantSelection::ClosestToBandwidth(*target_bw))
+            }
+            HlsVariantSelectionPolicy::AudioOnly => Some(VariantSelection::AudioOnly),
+            HlsVariantSelectionPolicy::VideoOnly => Some(VariantSelection::VideoOnly),
+            HlsVariantSelectionPolicy::MatchingResolution { width, height } => {
+                Some(VariantSelection::Resolution {
+                    width: *width,
+                    height: *height,
                 })
-                .ok_or_else(|| HlsDownloaderError::Playlist {
-                    reason: format!("No variant for resolution {width}x{height}"),
-                })?,
+            }
             HlsVariantSelectionPolicy::Custom(name) => {
                 warn!("Custom policy '{name}' selected; falling back to first variant.");
-                master_playlist_ref.variants.first().ok_or_else(|| {
-                    HlsDownloaderError::Playlist {
-                        reason: "No variants for Custom policy".to_string(),
-                    }
-                })?
+                None
+            }
+        };
+        let selected_variant = match selection {
+            Some(selection) => master_playlist_ref.select(selection),
+            None => master_playlist_ref.variants.first(),
+        }
+        .ok_or_else(|| HlsDownloaderError::Playlist {
+            reason: format!("No variant matches {policy:?}"),
+        })?;
+        debug!(
+            "Selected variant {} ({} bps, resolution {:?}, codecs {:?})",
+            selected_variant.uri,
+            selected_variant.bandwidth,
+            selected_variant.resolution,
+            selected_variant.codecs
+        );
+        let media_uri = match &self.config.playlist_config.audio_rendition {
+            Some(selection) => {
+                let rendition = match selection {
+                    HlsAudioRendition::Default => master_playlist_ref
+                        .default_rendition(selected_variant, RenditionType::Audio),
+                    HlsAudioRendition::Matching(name) => master_playlist_ref.find_rendition(
+                        selected_variant,
+                        RenditionType::Audio,
+                        name,
+                    ),
+                }
+                .ok_or_else(|| HlsDownloaderError::Playlist {
+                    reason: format!(
+                        "No audio rendition matches {selection:?} for variant {}",
+                        selected_variant.uri
+                    ),
+                })?;
+                let uri = rendition
+                    .uri
+                    .as_deref()
+                    .ok_or_else(|| HlsDownloaderError::Playlist {
+                        reason: format!(
+                            "Audio rendition {} is muxed into the variant and has no playlist",
+                            rendition.name
+                        ),
+                    })?;
+                debug!(
+                    "Selected audio rendition {} (language {:?})",
+                    rendition.name, rendition.language
+                );
+                uri
+            }
+            None => {
+                if let Some(audio) =
+                    master_playlist_ref.default_rendition(selected_variant, RenditionType::Audio)
+                    && audio.uri.is_some()
+                {
+                    warn!(
+                        "Selected variant plays audio from a separate rendition ({}); only the variant's own media playlist is recorded",
+                        audio.name
+                    );
+                }
+                selected_variant.uri.as_str()
             }
         };
         let master_playlist_url =
             Url::parse(master_base_url_str).map_err(|e| HlsDownloaderError::Playlist {
                 reason: format!("Invalid master base URL {master_base_url_str}: {e}"),
             })?;
-        let media_playlist_url = master_playlist_url
-            .join(&selected_variant.uri)
-            .map_err(|e| HlsDownloaderError::Playlist {
-                reason: format!(
-                    "Could not join master URL with variant URI {}: {e}",
-                    selected_variant.uri
-                ),
-            })?;
+        let media_playlist_url =
+            master_playlist_url
+                .join(media_uri)
+                .map_err(|e| HlsDownloaderError::Playlist {
+                    reason: format!("Could not join master URL with media URI {media_uri}: {e}"),
+                })?;
 
         debug!("Selected media playlist URL: {media_playlist_url}");
-        let client = self.clients.client_for_url(&media_playlist_url);
         let resource = ResourceId::HlsPlaylist {
             url: Arc::from(media_playlist_url.as_str()),
         };
@@ -263,8 +283,9 @@ impl PlaylistEngine {
                 content_length: None,
             },
         );
-        let response = client
-            .get(media_playlist_url.clone())
+        let response = self
+            .clients
+            .get(&media_playlist_url)
             .timeout(self.config.playlist_config.initial_playlist_fetch_timeout)
             .query(&self.config.base.params)
             .send()
@@ -1,12 +1,12 @@
 //! # Mesio
 //!
 //! A library for downloading media content from various sources.
-//! Supports FLV, HLS, and other streaming formats with efficient
+//! Supports FLV (over HTTP or RTMP), HLS, MPEG-DASH and other streaming formats with efficient
 //! processing pipeline integration.
 //!
 //! ## Features
 //!
-//! - Multiple protocol support (HLS, FLV)
+//! - Multiple protocol support (HLS, FLV, DASH, RTMP)
 //! - Efficient download management with caching
 //! - Source selection with fallback capabilities
 //! - Protocol-neutral session and event API
@@ -16,14 +16,22 @@ pub mod builder;
 pub mod bytes_stream;
 pub mod cache;
 pub mod config;
+pub mod cookies;
+pub mod dash;
 pub mod downloader;
 pub mod error;
 pub mod flv;
+pub mod header_template;
 pub mod hls;
+pub mod progress;
 pub mod protocol_builder;
 pub mod proxy;
+pub mod retry;
+pub mod rtmp;
 pub mod session;
 pub mod source;
+pub mod transport;
+pub mod watchdog;
 
 /// A boxed async media stream.
 pub type BoxMediaStream<D, E> = std::pin::Pin<Box<dyn futures::Stream<Item = Result<D, E>> + Send>>;
@@ -31,9 +39,13 @@ pub type BoxMediaStream<D, E> = std::pin::Pin<Box<dyn futures::Stream<Item = Res
 pub use config::DEFAULT_USER_AGENT;
 
 pub use builder::DownloaderConfigBuilder;
-pub use cache::{CacheConfig, CacheManager};
+pub use cache::{CacheConfig, CacheManager, CacheStats};
 pub use config::{DownloaderConfig, HttpVersionPreference};
+pub use cookies::CookieJar;
 pub use error::DownloadError;
+pub use header_template::HeaderTemplates;
+pub use progress::{DownloadProgress, ProgressCallback, ProgressMeter, ProgressOptions};
+pub use retry::{RetryOn, RetryPolicy};
 
 // Re-export protocol builders
 pub use protocol_builder::{FlvProtocolBuilder, HlsProtocolBuilder, ProtocolBuilder};
@@ -44,11 +56,13 @@ pub use downloader::create_client;
 
 // Re-export session/event API
 pub use session::{
-    DownloadEvent, DownloadEventStream, DownloadHandle, DownloadOptions, DownloadRequest,
-    DownloadSession, DownloadTerminal, DownloaderSession, EventSink, FlvReconnect,
+    DashRequestOptions, DownloadEvent, DownloadEventStream, DownloadHandle, DownloadOptions,
+    DownloadRequest, DownloadSession, DownloadTerminal, DownloaderSession, EventSink, FlvReconnect,
     FlvRequestOptions, HlsRequestOptions, MediaEngine, MesioConfig, MesioDownloader,
     ProtocolSelection, ProtocolType, ResourceId,
 };
 
+pub use watchdog::{StallAction, WatchdogConfig};
+
 // Re-export proxy utilities
 pub use proxy::{ProxyAuth, ProxyConfig, ProxyType};
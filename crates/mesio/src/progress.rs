@@ -0,0 +1,241 @@
+//! # Download Progress
+//!
+//! Structured progress derived from the [`DownloadEvent`]s of a session, so callers do not have to
+//! compute speeds from byte counts themselves. A [`ProgressMeter`] observes the events and reports
+//! the throughput over a rolling window, the latency of the last segment and whether the download
+//! has stalled. [`DownloadSession::with_progress`](crate::DownloadSession::with_progress) runs a
+//! meter over a session's events and reports it to a callback at a fixed interval.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::session::{DownloadEvent, ResourceId};
+
+/// Callback receiving periodic progress reports.
+pub type ProgressCallback = Arc<dyn Fn(&DownloadProgress) + Send + Sync>;
+
+/// How progress is measured and reported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProgressOptions {
+    /// Interval between two progress reports.
+    pub interval: Duration,
+    /// Window the current speed is averaged over.
+    pub window: Duration,
+    /// Time without data after which the download counts as stalled.
+    pub stall_after: Duration,
+}
+
+impl Default for ProgressOptions {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(1),
+            window: Duration::from_secs(5),
+            stall_after: Duration::from_secs(10),
+        }
+    }
+}
+
+/// A progress report of a download.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DownloadProgress {
+    /// Bytes received so far, including segments served from the cache.
+    pub bytes_total: u64,
+    /// Bytes per second received from the network over the rolling window.
+    pub bytes_per_sec: f64,
+    /// Bytes per second received from the network since the download started.
+    pub average_bytes_per_sec: f64,
+    /// Segments finished so far. Always zero for FLV streams.
+    pub segments_completed: u64,
+    /// Time from requesting the last network-fetched segment until it was fully received.
+    pub segment_latency: Option<Duration>,
+    /// Whether no data has arrived for [`ProgressOptions::stall_after`].
+    pub stalled: bool,
+    /// Time since data last arrived, or since the download started if none has.
+    pub since_last_data: Duration,
+    /// Time since the download started.
+    pub elapsed: Duration,
+}
+
+/// Measures the progress of a download from its events.
+#[derive(Debug)]
+pub struct ProgressMeter {
+    options: ProgressOptions,
+    started: Instant,
+    last_data: Option<Instant>,
+    bytes_total: u64,
+    network_bytes: u64,
+    window: VecDeque<(Instant, u64)>,
+    segments_completed: u64,
+    segment_latency: Option<Duration>,
+    in_flight: HashMap<ResourceId, Instant>,
+}
+
+impl ProgressMeter {
+    /// Create a meter for a download starting now
+    pub fn new(options: ProgressOptions) -> Self {
+        Self::starting_at(options, Instant::now())
+    }
+
+    fn starting_at(options: ProgressOptions, started: Instant) -> Self {
+        Self {
+            options,
+            started,
+            last_data: None,
+            bytes_total: 0,
+            network_bytes: 0,
+            window: VecDeque::new(),
+            segments_completed: 0,
+            segment_latency: None,
+            in_flight: HashMap::new(),
+        }
+    }
+
+    /// Account for an event of the download
+    pub fn observe(&mut self, event: &DownloadEvent) {
+        self.observe_at(event, Instant::now());
+    }
+
+    fn observe_at(&mut self, event: &DownloadEvent, now: Instant) {
+        match event {
+            DownloadEvent::ResourceStarted { resource, .. } if is_segment(resource) => {
+                self.in_flight.insert(resource.clone(), now);
+            }
+            DownloadEvent::Progress { bytes_delta, .. } => {
+                self.bytes_total += bytes_delta;
+                self.network_bytes += bytes_delta;
+                self.last_data = Some(now);
+                self.window.push_back((now, *bytes_delta));
+                self.trim_window(now);
+            }
+            DownloadEvent::ResourceFinished {
+                resource,
+                bytes,
+                from_cache,
+            } if is_segment(resource) => {
+                self.segments_completed += 1;
+                let started = self.in_flight.remove(resource);
+                if *from_cache {
+                    self.bytes_total += bytes;
+                    self.last_data = Some(now);
+                } else if let Some(started) = started {
+                    self.segment_latency = Some(now.saturating_duration_since(started));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// The progress as of now
+    pub fn snapshot(&mut self) -> DownloadProgress {
+        self.snapshot_at(Instant::now())
+    }
+
+    fn snapshot_at(&mut self, now: Instant) -> DownloadProgress {
+        self.trim_window(now);
+        let elapsed = now.saturating_duration_since(self.started);
+        let since_last_data = now.saturating_duration_since(self.last_data.unwrap_or(self.started));
+        // Average over the window, or over the whole download while it is younger than the window
+        let window_secs = self.options.window.min(elapsed).as_secs_f64();
+        let window_bytes: u64 = self.window.iter().map(|(_, bytes)| bytes).sum();
+
+        DownloadProgress {
+            bytes_total: self.bytes_total,
+            bytes_per_sec: rate(window_bytes, window_secs),
+            average_bytes_per_sec: rate(self.network_bytes, elapsed.as_secs_f64()),
+            segments_completed: self.segments_completed,
+            segment_latency: self.segment_latency,
+            stalled: since_last_data >= self.options.stall_after,
+            since_last_data,
+            elapsed,
+        }
+    }
+
+    fn trim_window(&mut self, now: Instant) {
+        while let Some((at, _)) = self.window.front()
+            && now.saturating_duration_since(*at) > self.options.window
+        {
+            self.window.pop_front();
+        }
+    }
+}
+
+fn is_segment(resource: &ResourceId) -> bool {
+    matches!(
+        resource,
+        ResourceId::HlsSegment { .. } | ResourceId::DashSegment { .. }
+    )
+}
+
+fn rate(bytes: u64, secs: f64) -> f64 {
+    if secs > 0.0 { bytes as f64 / secs } else { 0.0 }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(url: &str) -> ResourceId {
+        ResourceId::DashSegment { url: url.into() }
+    }
+
+    fn progress(bytes: u64) -> DownloadEvent {
+        DownloadEvent::Progress {
+            resource: segment("https://cdn.example.com/1.m4s"),
+            bytes_delta: bytes,
+            bytes_total: bytes,
+        }
+    }
+
+    #[test]
+    fn speed_is_averaged_over_the_rolling_window() {
+        let options = ProgressOptions {
+            window: Duration::from_secs(2),
+            ..Default::default()
+        };
+        let start = Instant::now();
+        let mut meter = ProgressMeter::starting_at(options, start);
+
+        meter.observe_at(&progress(8_000), start + Duration::from_secs(1));
+        meter.observe_at(&progress(2_000), start + Duration::from_secs(3));
+        meter.observe_at(&progress(2_000), start + Duration::from_secs(4));
+
+        let snapshot = meter.snapshot_at(start + Duration::from_secs(4));
+        assert_eq!(snapshot.bytes_total, 12_000);
+        assert_eq!(snapshot.bytes_per_sec, 2_000.0);
+        assert_eq!(snapshot.average_bytes_per_sec, 3_000.0);
+        assert!(!snapshot.stalled);
+    }
+
+    #[test]
+    fn segment_latency_and_stalls_are_reported() {
+        let start = Instant::now();
+        let mut meter = ProgressMeter::starting_at(ProgressOptions::default(), start);
+        let first = segment("https://cdn.example.com/1.m4s");
+
+        meter.observe_at(
+            &DownloadEvent::ResourceStarted {
+                resource: first.clone(),
+                display_url: "1.m4s".into(),
+                content_length: None,
+            },
+            start,
+        );
+        meter.observe_at(&progress(1_000), start + Duration::from_millis(300));
+        meter.observe_at(
+            &DownloadEvent::ResourceFinished {
+                resource: first,
+                bytes: 1_000,
+                from_cache: false,
+            },
+            start + Duration::from_millis(400),
+        );
+
+        let snapshot = meter.snapshot_at(start + Duration::from_secs(11));
+        assert_eq!(snapshot.segments_completed, 1);
+        assert_eq!(snapshot.segment_latency, Some(Duration::from_millis(400)));
+        assert_eq!(snapshot.since_last_data, Duration::from_millis(10_700));
+        assert!(snapshot.stalled);
+        assert_eq!(snapshot.bytes_per_sec, 0.0);
+    }
+}
@@ -5,12 +5,16 @@
 
 use crate::{
     CacheConfig, DownloadError, DownloaderConfig,
+    cookies::CookieJar,
     flv::{FlvDownloader, FlvProtocolConfig},
     hls::{
         HlsDownloader,
-        config::{HlsConfig, HlsVariantSelectionPolicy as NewHlsVariantSelectionPolicy},
+        config::{
+            HlsAudioRendition, HlsConfig, HlsVariantSelectionPolicy as NewHlsVariantSelectionPolicy,
+        },
     },
     proxy::ProxyConfig,
+    retry::RetryPolicy,
 };
 use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
 use std::{str::FromStr, time::Duration};
@@ -23,6 +27,12 @@ macro_rules! impl_base_downloader_config_methods {
             self
         }
 
+        /// Set how failed requests are retried
+        pub fn retry_policy(mut self, retry: RetryPolicy) -> Self {
+            self.$($base).+.retry = retry;
+            self
+        }
+
         /// Set overall HTTP timeout
         pub fn timeout(mut self, timeout: Duration) -> Self {
             self.$($base).+.timeout = timeout;
@@ -60,6 +70,26 @@ macro_rules! impl_base_downloader_config_methods {
             }
             self
         }
+
+        /// Add a header rendered for every request from a template
+        pub fn add_header_template(mut self, name: &str, template: &str) -> Self {
+            if let Ok(name) = HeaderName::from_str(name) {
+                self.$($base).+.header_templates.add(name, template);
+            }
+            self
+        }
+
+        /// Set the value of a `{name}` placeholder of the header templates
+        pub fn template_var(mut self, name: &str, value: &str) -> Self {
+            self.$($base).+.header_templates.set_var(name, value);
+            self
+        }
+
+        /// Set the cookie jar
+        pub fn cookie_jar(mut self, jar: CookieJar) -> Self {
+            self.$($base).+.cookie_jar = Some(jar);
+            self
+        }
     };
 }
 
@@ -239,6 +269,12 @@ impl HlsProtocolBuilder {
         self
     }
 
+    /// Record an audio rendition of the selected variant instead of the variant.
+    pub fn audio_rendition(mut self, rendition: HlsAudioRendition) -> Self {
+        self.config.playlist_config.audio_rendition = Some(rendition);
+        self
+    }
+
     // --- HLS SchedulerConfig methods ---
 
     /// Set maximum concurrent segment downloads.
@@ -10,6 +10,8 @@ pub enum ProxyType {
     Https,
     /// SOCKS5 proxy
     Socks5,
+    /// SOCKS5 proxy resolving host names on the proxy server instead of locally
+    Socks5h,
 }
 
 /// Proxy authentication type
@@ -26,7 +28,7 @@ pub struct ProxyAuth {
 pub struct ProxyConfig {
     /// Proxy server URL (for example, <http://proxy.example.com:8080>)
     pub url: String,
-    /// Type of proxy (HTTP, HTTPS, SOCKS5).
+    /// Type of proxy (HTTP, HTTPS, SOCKS5, SOCKS5 with remote DNS).
     ///
     /// Note: this describes how the client connects to the proxy server, not which URL schemes
     /// (http/https) are proxied. Both HTTP and HTTPS requests should follow the configured proxy.
@@ -44,15 +46,26 @@ fn normalize_proxy_url(proxy_url: &str, proxy_type: ProxyType) -> String {
         ProxyType::Http => format!("http://{proxy_url}"),
         ProxyType::Https => format!("https://{proxy_url}"),
         ProxyType::Socks5 => format!("socks5://{proxy_url}"),
+        ProxyType::Socks5h => format!("socks5h://{proxy_url}"),
     }
 }
 
-/// Build a reqwest `Proxy` object from our proxy configuration.
-pub fn build_proxy_from_config(config: &ProxyConfig) -> Result<Proxy, String> {
-    let proxy_url = match config.proxy_type {
+/// The URL of the proxy server, with the scheme of its proxy type.
+fn proxy_url(config: &ProxyConfig) -> String {
+    match config.proxy_type {
         ProxyType::Socks5 if config.url.starts_with("socks5h://") => config.url.clone(),
+        // Remote DNS must not silently fall back to resolving locally
+        ProxyType::Socks5h => match config.url.strip_prefix("socks5://") {
+            Some(rest) => format!("socks5h://{rest}"),
+            None => normalize_proxy_url(&config.url, ProxyType::Socks5h),
+        },
         proxy_type => normalize_proxy_url(&config.url, proxy_type),
-    };
+    }
+}
+
+/// Build a reqwest `Proxy` object from our proxy configuration.
+pub fn build_proxy_from_config(config: &ProxyConfig) -> Result<Proxy, String> {
+    let proxy_url = proxy_url(config);
 
     // Use `all` so both http and https requests follow the configured proxy.
     let mut proxy = Proxy::all(&proxy_url).map_err(|e| format!("Invalid proxy URL: {e}"))?;
@@ -122,4 +135,26 @@ mod tests {
 
         build_proxy_from_config(&config).expect("socks5h proxy should build");
     }
+
+    #[test]
+    fn socks5h_resolves_host_names_remotely() {
+        assert_eq!(
+            normalize_proxy_url("proxy.example.com:1080", ProxyType::Socks5h),
+            "socks5h://proxy.example.com:1080"
+        );
+
+        for url in [
+            "proxy.example.com:1080",
+            "socks5://proxy.example.com:1080",
+            "socks5h://proxy.example.com:1080",
+        ] {
+            let config = ProxyConfig {
+                url: url.to_string(),
+                proxy_type: ProxyType::Socks5h,
+                auth: None,
+            };
+            assert_eq!(proxy_url(&config), "socks5h://proxy.example.com:1080");
+            build_proxy_from_config(&config).expect("socks5h proxy should build");
+        }
+    }
 }
@@ -0,0 +1,249 @@
+//! # Retry Policy
+//!
+//! Backoff and retry classification shared by the FLV, HLS and DASH downloaders. Delays grow
+//! exponentially from `base_delay` by `multiplier` up to `max_delay`, and are spread by a random
+//! `jitter` so that many recordings behind the same CDN do not retry in lockstep.
+
+use std::future::Future;
+use std::time::Duration;
+
+use rand::RngExt;
+use reqwest::StatusCode;
+use tokio_util::sync::CancellationToken;
+use tracing::debug;
+
+use crate::DownloadError;
+
+/// Failures a [`RetryPolicy`] retries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryOn {
+    /// Connection failures and streams broken mid-transfer.
+    pub network: bool,
+    /// Timeouts, including HTTP 408.
+    pub timeout: bool,
+    /// HTTP 5xx responses.
+    pub server_error: bool,
+    /// HTTP 429 responses.
+    pub rate_limited: bool,
+    /// HTTP 404 and 410 responses, for origins that publish late.
+    pub not_found: bool,
+}
+
+impl Default for RetryOn {
+    fn default() -> Self {
+        Self {
+            network: true,
+            timeout: true,
+            server_error: true,
+            rate_limited: true,
+            not_found: false,
+        }
+    }
+}
+
+impl RetryOn {
+    /// Whether a response with `status` is retried.
+    pub fn status(&self, status: StatusCode) -> bool {
+        match status.as_u16() {
+            408 => self.timeout,
+            429 => self.rate_limited,
+            404 | 410 => self.not_found,
+            500..=599 => self.server_error,
+            _ => false,
+        }
+    }
+}
+
+/// How often and how fast failed requests are retried.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// Retries after the first attempt.
+    pub max_retries: u32,
+    /// Delay before the first retry.
+    pub base_delay: Duration,
+    /// Cap of the delay, before jitter.
+    pub max_delay: Duration,
+    /// Growth of the delay per retry; `1.0` retries at a fixed interval.
+    pub multiplier: f64,
+    /// Fraction of the delay it is randomly shortened or lengthened by, from `0.0` to `1.0`.
+    pub jitter: f64,
+    pub retry_on: RetryOn,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(10),
+            multiplier: 2.0,
+            jitter: 0.2,
+            retry_on: RetryOn::default(),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that never retries.
+    pub fn none() -> Self {
+        Self {
+            max_retries: 0,
+            ..Self::default()
+        }
+    }
+
+    /// Delay before retry number `retry`, counted from 1.
+    pub fn delay(&self, retry: u32) -> Duration {
+        let exponent = retry.saturating_sub(1).min(32) as i32;
+        let delay = self
+            .base_delay
+            .mul_f64(self.multiplier.max(1.0).powi(exponent).min(1e9))
+            .min(self.max_delay);
+        let jitter = self.jitter.clamp(0.0, 1.0);
+        if jitter == 0.0 || delay.is_zero() {
+            return delay;
+        }
+        delay.mul_f64(rand::rng().random_range((1.0 - jitter)..=(1.0 + jitter)))
+    }
+
+    /// Whether `error` is worth another attempt under this policy.
+    pub fn should_retry(&self, error: &DownloadError) -> bool {
+        match error {
+            DownloadError::Network { source } => {
+                if source.is_timeout() {
+                    self.retry_on.timeout
+                } else if let Some(status) = source.status() {
+                    self.retry_on.status(status)
+                } else {
+                    self.retry_on.network
+                }
+            }
+            DownloadError::StreamNetwork { .. } | DownloadError::Io { .. } => self.retry_on.network,
+            DownloadError::Timeout { .. } => self.retry_on.timeout,
+            DownloadError::HttpStatus { status, .. } => self.retry_on.status(*status),
+            DownloadError::SegmentFetch { retryable, .. } => *retryable,
+            _ => false,
+        }
+    }
+
+    /// Run `attempt` until it succeeds, fails with an error the policy does not retry, or runs
+    /// out of retries. `on_retry` is told about every retry before its delay.
+    pub(crate) async fn run<T, F, Fut>(
+        &self,
+        token: &CancellationToken,
+        mut on_retry: impl FnMut(u32, Duration, &DownloadError),
+        mut attempt: F,
+    ) -> Result<T, DownloadError>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, DownloadError>>,
+    {
+        let mut retry = 0;
+        loop {
+            let error = match attempt().await {
+                Ok(value) => return Ok(value),
+                Err(error) => error,
+            };
+            if retry >= self.max_retries || !self.should_retry(&error) {
+                return Err(error);
+            }
+            retry += 1;
+            let delay = self.delay(retry);
+            debug!(retry, delay_ms = delay.as_millis() as u64, %error, "Retrying request");
+            on_retry(retry, delay, &error);
+            tokio::select! {
+                _ = token.cancelled() => return Err(DownloadError::Cancelled),
+                _ = tokio::time::sleep(delay) => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn delays_grow_exponentially_up_to_the_cap() {
+        let policy = RetryPolicy {
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(350),
+            jitter: 0.0,
+            ..RetryPolicy::default()
+        };
+        let delays: Vec<_> = (1..=4)
+            .map(|retry| policy.delay(retry).as_millis())
+            .collect();
+        assert_eq!(delays, [100, 200, 350, 350]);
+    }
+
+    #[test]
+    fn jitter_stays_within_its_fraction() {
+        let policy = RetryPolicy {
+            base_delay: Duration::from_millis(1000),
+            multiplier: 1.0,
+            jitter: 0.5,
+            ..RetryPolicy::default()
+        };
+        for _ in 0..100 {
+            let delay = policy.delay(3).as_millis();
+            assert!((500..=1500).contains(&delay), "{delay}");
+        }
+    }
+
+    #[test]
+    fn classification_follows_retry_on() {
+        let policy = RetryPolicy::default();
+        let status = |code| {
+            DownloadError::http_status(
+                StatusCode::from_u16(code).unwrap(),
+                "https://example.test/",
+                "test",
+            )
+        };
+        assert!(policy.should_retry(&status(503)));
+        assert!(policy.should_retry(&status(429)));
+        assert!(!policy.should_retry(&status(404)));
+        assert!(!policy.should_retry(&status(403)));
+        assert!(!policy.should_retry(&DownloadError::Cancelled));
+
+        let policy = RetryPolicy {
+            retry_on: RetryOn {
+                server_error: false,
+                not_found: true,
+                ..RetryOn::default()
+            },
+            ..policy
+        };
+        assert!(!policy.should_retry(&status(503)));
+        assert!(policy.should_retry(&status(404)));
+    }
+
+    #[tokio::test]
+    async fn run_stops_at_the_retry_budget() {
+        let policy = RetryPolicy {
+            max_retries: 2,
+            base_delay: Duration::from_millis(1),
+            ..RetryPolicy::default()
+        };
+        let mut attempts = 0;
+        let mut retries = Vec::new();
+        let result: Result<(), _> = policy
+            .run(
+                &CancellationToken::new(),
+                |retry, _, _| retries.push(retry),
+                || {
+                    attempts += 1;
+                    async {
+                        Err(DownloadError::Timeout {
+                            reason: "test".to_string(),
+                        })
+                    }
+                },
+            )
+            .await;
+        assert!(matches!(result, Err(DownloadError::Timeout { .. })));
+        assert_eq!(attempts, 3);
+        assert_eq!(retries, [1, 2]);
+    }
+}
@@ -0,0 +1,7 @@
+// RTMP play client: `rtmp://` and `rtmps://` streams recorded as FLV.
+
+mod chunk;
+mod client;
+mod handshake;
+
+pub use client::{RtmpPlayer, RtmpUrl};
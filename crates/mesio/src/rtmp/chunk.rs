@@ -0,0 +1,294 @@
+//! RTMP chunk stream: messages split into interleaved chunks of the negotiated chunk size.
+
+use std::collections::HashMap;
+
+use bytes::{BufMut, Bytes, BytesMut};
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+use crate::DownloadError;
+
+pub(crate) const SET_CHUNK_SIZE: u8 = 1;
+pub(crate) const ABORT: u8 = 2;
+pub(crate) const ACKNOWLEDGEMENT: u8 = 3;
+pub(crate) const USER_CONTROL: u8 = 4;
+pub(crate) const WINDOW_ACK_SIZE: u8 = 5;
+pub(crate) const SET_PEER_BANDWIDTH: u8 = 6;
+pub(crate) const AUDIO: u8 = 8;
+pub(crate) const VIDEO: u8 = 9;
+pub(crate) const DATA_AMF0: u8 = 18;
+pub(crate) const COMMAND_AMF0: u8 = 20;
+pub(crate) const AGGREGATE: u8 = 22;
+
+const DEFAULT_CHUNK_SIZE: usize = 128;
+const EXTENDED_TIMESTAMP: u32 = 0xFF_FFFF;
+/// Larger messages are rejected rather than buffered; FLV tags cannot exceed 16 MiB either.
+const MAX_MESSAGE_LENGTH: usize = 0xFF_FFFF;
+
+/// A complete RTMP message.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Message {
+    pub type_id: u8,
+    pub stream_id: u32,
+    pub timestamp: u32,
+    pub payload: Bytes,
+}
+
+/// Header state of one chunk stream, which later chunks abbreviate against.
+#[derive(Default)]
+struct ChunkStream {
+    timestamp: u32,
+    /// Timestamp field of the last header: a delta, or the absolute timestamp of a type 0 header.
+    delta: u32,
+    length: usize,
+    type_id: u8,
+    stream_id: u32,
+    extended: bool,
+    payload: BytesMut,
+}
+
+/// Reassembles messages from the chunks read off a connection.
+pub(crate) struct ChunkReader {
+    chunk_size: usize,
+    streams: HashMap<u32, ChunkStream>,
+    bytes_read: u64,
+}
+
+impl ChunkReader {
+    pub fn new() -> Self {
+        Self {
+            chunk_size: DEFAULT_CHUNK_SIZE,
+            streams: HashMap::new(),
+            bytes_read: 0,
+        }
+    }
+
+    pub fn set_chunk_size(&mut self, size: usize) {
+        self.chunk_size = size.max(1);
+    }
+
+    /// Bytes read off the connection, for acknowledgements.
+    pub fn bytes_read(&self) -> u64 {
+        self.bytes_read
+    }
+
+    /// Drop the partial message of a chunk stream.
+    pub fn abort(&mut self, chunk_stream_id: u32) {
+        if let Some(stream) = self.streams.get_mut(&chunk_stream_id) {
+            stream.payload.clear();
+        }
+    }
+
+    /// Read chunks until a message is complete.
+    ///
+    /// Returns `None` when the connection is closed between two chunks.
+    pub async fn read_message<R>(
+        &mut self,
+        reader: &mut R,
+    ) -> Result<Option<Message>, DownloadError>
+    where
+        R: AsyncRead + Unpin,
+    {
+        loop {
+            let basic = match reader.read_u8().await {
+                Ok(basic) => basic,
+                Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(None),
+                Err(e) => return Err(e.into()),
+            };
+            self.bytes_read += 1;
+            let format = basic >> 6;
+            let chunk_stream_id = match basic & 0x3F {
+                0 => 64 + self.read_uint(reader, 1).await?,
+                1 => {
+                    let id = self.read_uint(reader, 2).await?;
+                    64 + ((id & 0xFF) << 8 | id >> 8)
+                }
+                id => u32::from(id),
+            };
+
+            let starts_message = self
+                .streams
+                .get(&chunk_stream_id)
+                .is_none_or(|stream| stream.payload.is_empty());
+            if format == 3 && !self.streams.contains_key(&chunk_stream_id) {
+                return Err(protocol_error(format!(
+                    "chunk stream {chunk_stream_id} continues without a header"
+                )));
+            }
+
+            let mut header = [0u8; 11];
+            let header_len = [11, 7, 3, 0][format as usize];
+            reader.read_exact(&mut header[..header_len]).await?;
+            self.bytes_read += header_len as u64;
+            let timestamp_field = u32::from_be_bytes([0, header[0], header[1], header[2]]);
+
+            let extended = match format {
+                3 => self.streams[&chunk_stream_id].extended,
+                _ => timestamp_field == EXTENDED_TIMESTAMP,
+            };
+            let timestamp_field = if extended {
+                self.read_uint(reader, 4).await?
+            } else {
+                timestamp_field
+            };
+
+            let stream = self.streams.entry(chunk_stream_id).or_default();
+            if format < 3 {
+                stream.delta = timestamp_field;
+                stream.extended = extended;
+            }
+            if format < 2 {
+                stream.length = u32::from_be_bytes([0, header[3], header[4], header[5]]) as usize;
+                stream.type_id = header[6];
+                if stream.length > MAX_MESSAGE_LENGTH {
+                    return Err(protocol_error(format!(
+                        "message of {} bytes is too large",
+                        stream.length
+                    )));
+                }
+            }
+            if format == 0 {
+                stream.stream_id =
+                    u32::from_le_bytes([header[7], header[8], header[9], header[10]]);
+                stream.timestamp = timestamp_field;
+            } else if starts_message {
+                stream.timestamp = stream.timestamp.wrapping_add(stream.delta);
+            }
+
+            let read = (stream.length - stream.payload.len()).min(self.chunk_size);
+            let start = stream.payload.len();
+            stream.payload.resize(start + read, 0);
+            reader.read_exact(&mut stream.payload[start..]).await?;
+            self.bytes_read += read as u64;
+
+            if stream.payload.len() == stream.length {
+                return Ok(Some(Message {
+                    type_id: stream.type_id,
+                    stream_id: stream.stream_id,
+                    timestamp: stream.timestamp,
+                    payload: stream.payload.split().freeze(),
+                }));
+            }
+        }
+    }
+
+    async fn read_uint<R>(&mut self, reader: &mut R, len: usize) -> Result<u32, DownloadError>
+    where
+        R: AsyncRead + Unpin,
+    {
+        let mut bytes = [0u8; 4];
+        reader.read_exact(&mut bytes[4 - len..]).await?;
+        self.bytes_read += len as u64;
+        Ok(u32::from_be_bytes(bytes))
+    }
+}
+
+/// Splits messages into chunks with full headers.
+pub(crate) struct ChunkWriter {
+    chunk_size: usize,
+}
+
+impl ChunkWriter {
+    pub fn new() -> Self {
+        Self {
+            chunk_size: DEFAULT_CHUNK_SIZE,
+        }
+    }
+
+    pub fn set_chunk_size(&mut self, size: usize) {
+        self.chunk_size = size.max(1);
+    }
+
+    /// Append the chunks of `message` on chunk stream `chunk_stream_id` (2 to 63) to `out`.
+    pub fn write(&self, chunk_stream_id: u8, message: &Message, out: &mut BytesMut) {
+        let extended = message.timestamp >= EXTENDED_TIMESTAMP;
+        out.put_u8(chunk_stream_id & 0x3F);
+        out.put_uint(u64::from(message.timestamp.min(EXTENDED_TIMESTAMP)), 3);
+        out.put_uint(message.payload.len() as u64, 3);
+        out.put_u8(message.type_id);
+        out.put_u32_le(message.stream_id);
+        if extended {
+            out.put_u32(message.timestamp);
+        }
+
+        for (index, chunk) in message.payload.chunks(self.chunk_size).enumerate() {
+            if index > 0 {
+                out.put_u8(0xC0 | (chunk_stream_id & 0x3F));
+                if extended {
+                    out.put_u32(message.timestamp);
+                }
+            }
+            out.put_slice(chunk);
+        }
+    }
+}
+
+fn protocol_error(reason: String) -> DownloadError {
+    DownloadError::Protocol {
+        reason: format!("RTMP: {reason}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(type_id: u8, timestamp: u32, len: usize) -> Message {
+        Message {
+            type_id,
+            stream_id: 1,
+            timestamp,
+            payload: Bytes::from((0..len).map(|i| i as u8).collect::<Vec<_>>()),
+        }
+    }
+
+    #[tokio::test]
+    async fn written_messages_read_back() {
+        let mut writer = ChunkWriter::new();
+        let mut out = BytesMut::new();
+        let small = message(AUDIO, 40, 10);
+        let large = message(VIDEO, 0x0100_0000, 300);
+        writer.write(4, &small, &mut out);
+        writer.write(6, &large, &mut out);
+        writer.set_chunk_size(4096);
+        writer.write(6, &large, &mut out);
+
+        let mut reader = ChunkReader::new();
+        let mut input = &out[..];
+        assert_eq!(reader.read_message(&mut input).await.unwrap(), Some(small));
+        assert_eq!(
+            reader.read_message(&mut input).await.unwrap(),
+            Some(large.clone())
+        );
+        reader.set_chunk_size(4096);
+        assert_eq!(reader.read_message(&mut input).await.unwrap(), Some(large));
+        assert_eq!(reader.read_message(&mut input).await.unwrap(), None);
+        assert_eq!(reader.bytes_read(), out.len() as u64);
+    }
+
+    #[tokio::test]
+    async fn abbreviated_headers_reuse_the_previous_header() {
+        let mut input = BytesMut::new();
+        // Type 0: timestamp 100, 2 bytes of video on message stream 1
+        input.put_slice(&[0x06, 0, 0, 100, 0, 0, 2, VIDEO, 1, 0, 0, 0, 0xAA, 0xBB]);
+        // Type 2: delta 20, same length and type
+        input.put_slice(&[0x86, 0, 0, 20, 0xCC, 0xDD]);
+        // Type 3: another message with the same delta
+        input.put_slice(&[0xC6, 0xEE, 0xFF]);
+
+        let mut reader = ChunkReader::new();
+        let mut input = &input[..];
+        let mut timestamps = Vec::new();
+        while let Some(message) = reader.read_message(&mut input).await.unwrap() {
+            assert_eq!(message.stream_id, 1);
+            timestamps.push((message.timestamp, message.payload.to_vec()));
+        }
+        assert_eq!(
+            timestamps,
+            [
+                (100, vec![0xAA, 0xBB]),
+                (120, vec![0xCC, 0xDD]),
+                (140, vec![0xEE, 0xFF])
+            ]
+        );
+    }
+}
@@ -0,0 +1,687 @@
+//! RTMP play client: connects to an application, plays a stream and converts its media
+//! messages into FLV tags.
+
+use std::borrow::Cow;
+use std::time::Duration;
+
+use amf0::{Amf0Decoder, Amf0Encoder, Amf0Value};
+use bytes::{BufMut, Bytes, BytesMut};
+use tokio::io::{AsyncRead, AsyncWrite, AsyncWriteExt, BufStream};
+use tracing::{debug, info};
+use url::Url;
+
+use super::chunk::{self, ChunkReader, ChunkWriter, Message};
+use super::handshake;
+use crate::transport::{self, Transport};
+use crate::{DownloadError, DownloaderConfig};
+
+/// Chunk stream of protocol control messages.
+const CONTROL_CHUNK_STREAM: u8 = 2;
+/// Chunk stream of NetConnection commands.
+const COMMAND_CHUNK_STREAM: u8 = 3;
+/// Chunk stream of NetStream commands.
+const STREAM_CHUNK_STREAM: u8 = 8;
+const CLIENT_CHUNK_SIZE: usize = 4096;
+const DEFAULT_WINDOW_ACK_SIZE: u32 = 2_500_000;
+/// Buffer length requested from the server, in milliseconds.
+const BUFFER_LENGTH_MS: u32 = 3_000;
+const FLASH_VERSION: &str = "LNX 9,0,124,2";
+
+const USER_CONTROL_STREAM_EOF: u16 = 1;
+const USER_CONTROL_SET_BUFFER_LENGTH: u16 = 3;
+const USER_CONTROL_PING_REQUEST: u16 = 6;
+const USER_CONTROL_PING_RESPONSE: u16 = 7;
+
+/// Connection endpoint and stream of an `rtmp://` or `rtmps://` URL.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RtmpUrl {
+    pub host: String,
+    pub port: u16,
+    pub tls: bool,
+    /// Application, every path segment but the last one.
+    pub app: String,
+    /// URL of the application sent in `connect`.
+    pub tc_url: String,
+    /// Stream name, the last path segment with the query of the URL.
+    pub stream: String,
+}
+
+impl RtmpUrl {
+    pub fn parse(url: &Url) -> Result<Self, DownloadError> {
+        let invalid = |reason: &str| DownloadError::invalid_url(url.to_string(), reason);
+        let (tls, default_port) = match url.scheme() {
+            "rtmp" => (false, 1935),
+            "rtmps" => (true, 443),
+            _ => return Err(invalid("not an RTMP URL")),
+        };
+        let host = url.host_str().ok_or_else(|| invalid("missing host"))?;
+        let segments: Vec<&str> = url
+            .path_segments()
+            .map(|segments| segments.filter(|s| !s.is_empty()).collect())
+            .unwrap_or_default();
+        let Some((stream, app)) = segments.split_last().filter(|(_, app)| !app.is_empty()) else {
+            return Err(invalid("expected rtmp://host/app/stream"));
+        };
+        let app = app.join("/");
+
+        let authority = match url.port() {
+            Some(port) => format!("{host}:{port}"),
+            None => host.to_string(),
+        };
+        let stream = match url.query() {
+            Some(query) => format!("{stream}?{query}"),
+            None => stream.to_string(),
+        };
+        Ok(Self {
+            host: host.to_string(),
+            port: url.port().unwrap_or(default_port),
+            tls,
+            tc_url: format!("{}://{authority}/{app}", url.scheme()),
+            app,
+            stream,
+        })
+    }
+}
+
+/// An RTMP connection playing one stream.
+pub struct RtmpPlayer<S> {
+    io: BufStream<S>,
+    reader: ChunkReader,
+    writer: ChunkWriter,
+    window_ack_size: u32,
+    acknowledged: u64,
+    stream_id: u32,
+    read_timeout: Duration,
+}
+
+impl RtmpPlayer<Box<dyn Transport>> {
+    /// Connect to the server of `url` and start playing its stream.
+    pub async fn connect(url: &RtmpUrl, config: &DownloaderConfig) -> Result<Self, DownloadError> {
+        let io = transport::connect(&url.host, url.port, url.tls, config).await?;
+        Self::play(io, url, config.read_timeout).await
+    }
+}
+
+impl<S: AsyncRead + AsyncWrite + Unpin> RtmpPlayer<S> {
+    /// Run the handshake, connect to the application and play the stream over `io`.
+    pub async fn play(io: S, url: &RtmpUrl, read_timeout: Duration) -> Result<Self, DownloadError> {
+        let mut player = Self {
+            io: BufStream::new(io),
+            reader: ChunkReader::new(),
+            writer: ChunkWriter::new(),
+            window_ack_size: DEFAULT_WINDOW_ACK_SIZE,
+            acknowledged: 0,
+            stream_id: 0,
+            read_timeout,
+        };
+        handshake::client_handshake(&mut player.io).await?;
+
+        let mut out = BytesMut::new();
+        let chunk_size = Message {
+            type_id: chunk::SET_CHUNK_SIZE,
+            stream_id: 0,
+            timestamp: 0,
+            payload: Bytes::copy_from_slice(&(CLIENT_CHUNK_SIZE as u32).to_be_bytes()),
+        };
+        player
+            .writer
+            .write(CONTROL_CHUNK_STREAM, &chunk_size, &mut out);
+        player.writer.set_chunk_size(CLIENT_CHUNK_SIZE);
+        let properties = [
+            ("app", Amf0Value::String(Cow::Borrowed(url.app.as_str()))),
+            ("flashVer", Amf0Value::String(Cow::Borrowed(FLASH_VERSION))),
+            (
+                "tcUrl",
+                Amf0Value::String(Cow::Borrowed(url.tc_url.as_str())),
+            ),
+            ("fpad", Amf0Value::Boolean(false)),
+            ("capabilities", Amf0Value::Number(15.0)),
+            ("audioCodecs", Amf0Value::Number(3191.0)),
+            ("videoCodecs", Amf0Value::Number(252.0)),
+            ("videoFunction", Amf0Value::Number(1.0)),
+        ]
+        .map(|(key, value)| (Cow::Borrowed(key), value));
+        player.write_command(
+            &mut out,
+            COMMAND_CHUNK_STREAM,
+            0,
+            &[
+                Amf0Value::String(Cow::Borrowed("connect")),
+                Amf0Value::Number(1.0),
+                Amf0Value::Object(Cow::Borrowed(&properties)),
+            ],
+        )?;
+        player.send(&out).await?;
+        player.await_result(1.0).await?;
+        debug!(app = %url.app, "RTMP application connected");
+
+        out.clear();
+        player.write_command(
+            &mut out,
+            COMMAND_CHUNK_STREAM,
+            0,
+            &[
+                Amf0Value::String(Cow::Borrowed("createStream")),
+                Amf0Value::Number(2.0),
+                Amf0Value::Null,
+            ],
+        )?;
+        player.send(&out).await?;
+        let result = player.await_result(2.0).await?;
+        player.stream_id = result
+            .get(3)
+            .and_then(Amf0Value::as_number)
+            .ok_or_else(|| protocol_error("createStream returned no stream id".to_string()))?
+            as u32;
+
+        out.clear();
+        let stream_id = player.stream_id;
+        player.write_command(
+            &mut out,
+            STREAM_CHUNK_STREAM,
+            stream_id,
+            &[
+                Amf0Value::String(Cow::Borrowed("play")),
+                Amf0Value::Number(0.0),
+                Amf0Value::Null,
+                Amf0Value::String(Cow::Borrowed(url.stream.as_str())),
+                // Live or recorded, whichever the server has
+                Amf0Value::Number(-2000.0),
+            ],
+        )?;
+        let mut buffer_length = BytesMut::with_capacity(10);
+        buffer_length.put_u16(USER_CONTROL_SET_BUFFER_LENGTH);
+        buffer_length.put_u32(stream_id);
+        buffer_length.put_u32(BUFFER_LENGTH_MS);
+        player.writer.write(
+            CONTROL_CHUNK_STREAM,
+            &control_message(chunk::USER_CONTROL, buffer_length.freeze()),
+            &mut out,
+        );
+        player.send(&out).await?;
+        info!(stream = %url.stream, "Playing RTMP stream");
+        Ok(player)
+    }
+
+    /// Append the FLV tags of the next media message to `out`.
+    ///
+    /// Returns `false` once the stream has ended.
+    pub async fn read_tags(&mut self, out: &mut BytesMut) -> Result<bool, DownloadError> {
+        loop {
+            let Some(message) = self.read_message().await? else {
+                return Ok(false);
+            };
+            match message.type_id {
+                chunk::AUDIO | chunk::VIDEO => {
+                    if message.payload.is_empty() {
+                        continue;
+                    }
+                    write_tag(out, message.type_id, message.timestamp, &message.payload);
+                    return Ok(true);
+                }
+                chunk::DATA_AMF0 => {
+                    if let Some(data) = script_data(&message.payload) {
+                        write_tag(out, chunk::DATA_AMF0, message.timestamp, data);
+                        return Ok(true);
+                    }
+                }
+                chunk::AGGREGATE => {
+                    if append_aggregate(out, &message)? {
+                        return Ok(true);
+                    }
+                }
+                chunk::COMMAND_AMF0 => {
+                    if !self.handle_status(&message.payload)? {
+                        return Ok(false);
+                    }
+                }
+                chunk::USER_CONTROL => {
+                    if !self.handle_user_control(&message.payload).await? {
+                        return Ok(false);
+                    }
+                }
+                _ => self.handle_control(&message).await?,
+            }
+        }
+    }
+
+    async fn read_message(&mut self) -> Result<Option<Message>, DownloadError> {
+        let read = self.reader.read_message(&mut self.io);
+        let message = if self.read_timeout.is_zero() {
+            read.await?
+        } else {
+            tokio::time::timeout(self.read_timeout, read)
+                .await
+                .map_err(|_| DownloadError::Timeout {
+                    reason: format!("no RTMP data for {:?}", self.read_timeout),
+                })??
+        };
+
+        let unacknowledged = self.reader.bytes_read() - self.acknowledged;
+        if self.window_ack_size > 0 && unacknowledged >= u64::from(self.window_ack_size) {
+            self.acknowledged = self.reader.bytes_read();
+            let sequence = (self.acknowledged as u32).to_be_bytes();
+            let ack = control_message(chunk::ACKNOWLEDGEMENT, Bytes::copy_from_slice(&sequence));
+            let mut out = BytesMut::new();
+            self.writer.write(CONTROL_CHUNK_STREAM, &ack, &mut out);
+            self.send(&out).await?;
+        }
+        Ok(message)
+    }
+
+    /// Wait for the `_result` of the command with `transaction`, handling protocol control
+    /// messages in between.
+    async fn await_result(
+        &mut self,
+        transaction: f64,
+    ) -> Result<Vec<Amf0Value<'static>>, DownloadError> {
+        loop {
+            let message = self
+                .read_message()
+                .await?
+                .ok_or_else(|| protocol_error("connection closed during setup".to_string()))?;
+            if message.type_id != chunk::COMMAND_AMF0 {
+                if message.type_id == chunk::USER_CONTROL {
+                    self.handle_user_control(&message.payload).await?;
+                } else {
+                    self.handle_control(&message).await?;
+                }
+                continue;
+            }
+            let values = decode_command(&message.payload);
+            let name = values
+                .first()
+                .and_then(Amf0Value::as_str)
+                .unwrap_or_default();
+            let id = values.get(1).and_then(Amf0Value::as_number);
+            match name {
+                "_result" if id == Some(transaction) => return Ok(values),
+                "_error" if id == Some(transaction) => {
+                    return Err(protocol_error(format!(
+                        "server rejected the command: {}",
+                        status_description(&values)
+                    )));
+                }
+                _ => debug!(command = name, "Ignoring RTMP command during setup"),
+            }
+        }
+    }
+
+    /// Handle an `onStatus` command; returns `false` when it ends the stream.
+    fn handle_status(&self, payload: &[u8]) -> Result<bool, DownloadError> {
+        let values = decode_command(payload);
+        if values.first().and_then(Amf0Value::as_str) != Some("onStatus") {
+            return Ok(true);
+        }
+        let info = values.get(3).and_then(Amf0Value::as_object_properties);
+        let field = |name: &str| {
+            info.and_then(|info| info.iter().find(|(key, _)| key == name))
+                .and_then(|(_, value)| value.as_str())
+                .unwrap_or_default()
+        };
+        let (level, code) = (field("level"), field("code"));
+        debug!(level, code, "RTMP status");
+        if level == "error" {
+            return Err(protocol_error(format!("{code}: {}", field("description"))));
+        }
+        Ok(!matches!(
+            code,
+            "NetStream.Play.Stop" | "NetStream.Play.UnpublishNotify" | "NetStream.Play.Complete"
+        ))
+    }
+
+    /// Handle a user control event; returns `false` when it ends the stream.
+    async fn handle_user_control(&mut self, payload: &[u8]) -> Result<bool, DownloadError> {
+        let Some((event, data)) = payload
+            .split_first_chunk::<2>()
+            .map(|(event, data)| (u16::from_be_bytes(*event), data))
+        else {
+            return Ok(true);
+        };
+        match event {
+            USER_CONTROL_PING_REQUEST => {
+                let mut pong = BytesMut::with_capacity(6);
+                pong.put_u16(USER_CONTROL_PING_RESPONSE);
+                pong.put_slice(data);
+                let mut out = BytesMut::new();
+                self.writer.write(
+                    CONTROL_CHUNK_STREAM,
+                    &control_message(chunk::USER_CONTROL, pong.freeze()),
+                    &mut out,
+                );
+                self.send(&out).await?;
+                Ok(true)
+            }
+            USER_CONTROL_STREAM_EOF => {
+                let stream_id = data.first_chunk::<4>().map(|id| u32::from_be_bytes(*id));
+                Ok(self.stream_id == 0 || stream_id != Some(self.stream_id))
+            }
+            _ => Ok(true),
+        }
+    }
+
+    async fn handle_control(&mut self, message: &Message) -> Result<(), DownloadError> {
+        let value = message
+            .payload
+            .first_chunk::<4>()
+            .map(|value| u32::from_be_bytes(*value));
+        match (message.type_id, value) {
+            (chunk::SET_CHUNK_SIZE, Some(size)) => {
+                self.reader.set_chunk_size((size & 0x7FFF_FFFF) as usize);
+            }
+            (chunk::ABORT, Some(chunk_stream_id)) => self.reader.abort(chunk_stream_id),
+            (chunk::WINDOW_ACK_SIZE, Some(size)) => self.window_ack_size = size,
+            (chunk::SET_PEER_BANDWIDTH, Some(size)) => {
+                let ack_size = control_message(
+                    chunk::WINDOW_ACK_SIZE,
+                    Bytes::copy_from_slice(&size.to_be_bytes()),
+                );
+                let mut out = BytesMut::new();
+                self.writer.write(CONTROL_CHUNK_STREAM, &ack_size, &mut out);
+                self.send(&out).await?;
+            }
+            (type_id, _) => debug!(type_id, "Ignoring RTMP message"),
+        }
+        Ok(())
+    }
+
+    fn write_command(
+        &self,
+        out: &mut BytesMut,
+        chunk_stream_id: u8,
+        stream_id: u32,
+        values: &[Amf0Value<'_>],
+    ) -> Result<(), DownloadError> {
+        let mut payload = Vec::new();
+        for value in values {
+            Amf0Encoder::encode(&mut payload, value).map_err(|e| DownloadError::Internal {
+                reason: format!("failed to encode RTMP command: {e}"),
+            })?;
+        }
+        let message = Message {
+            type_id: chunk::COMMAND_AMF0,
+            stream_id,
+            timestamp: 0,
+            payload: payload.into(),
+        };
+        self.writer.write(chunk_stream_id, &message, out);
+        Ok(())
+    }
+
+    async fn send(&mut self, bytes: &[u8]) -> Result<(), DownloadError> {
+        self.io.write_all(bytes).await?;
+        self.io.flush().await?;
+        Ok(())
+    }
+}
+
+fn control_message(type_id: u8, payload: Bytes) -> Message {
+    Message {
+        type_id,
+        stream_id: 0,
+        timestamp: 0,
+        payload,
+    }
+}
+
+fn decode_command(payload: &[u8]) -> Vec<Amf0Value<'static>> {
+    let (values, _) = Amf0Decoder::new(payload).decode_all();
+    values.iter().map(Amf0Value::into_owned).collect()
+}
+
+fn status_description(values: &[Amf0Value<'_>]) -> String {
+    values
+        .iter()
+        .filter_map(Amf0Value::as_object_properties)
+        .flat_map(|properties| properties.iter())
+        .find(|(key, _)| key == "description" || key == "code")
+        .and_then(|(_, value)| value.as_str())
+        .unwrap_or("no description")
+        .to_string()
+}
+
+/// Script data to record: `@setDataFrame` wrappers removed, and `|RtmpSampleAccess`, which only
+/// concerns Flash Player, dropped.
+fn script_data(payload: &[u8]) -> Option<&[u8]> {
+    const STRING_MARKER: u8 = 0x02;
+    let mut decoder = Amf0Decoder::new(payload);
+    match decoder.decode() {
+        Ok(Amf0Value::String(name))
+            if name == "@setDataFrame" && payload.first() == Some(&STRING_MARKER) =>
+        {
+            // Marker, length and the name itself
+            Some(&payload[3 + name.len()..])
+        }
+        Ok(Amf0Value::String(name)) if name == "|RtmpSampleAccess" => None,
+        _ => Some(payload),
+    }
+}
+
+/// Append the FLV tags of an aggregate message; their timestamps are relative to the message.
+/// Returns whether any tag was appended.
+fn append_aggregate(out: &mut BytesMut, message: &Message) -> Result<bool, DownloadError> {
+    let mut rest = &message.payload[..];
+    let mut first_timestamp = None;
+    let mut appended = false;
+    while rest.len() >= 11 {
+        let type_id = rest[0];
+        let size = u32::from_be_bytes([0, rest[1], rest[2], rest[3]]) as usize;
+        let timestamp = u32::from_be_bytes([rest[7], rest[4], rest[5], rest[6]]);
+        let Some(data) = rest.get(11..11 + size) else {
+            return Err(protocol_error("truncated aggregate message".to_string()));
+        };
+        let first = *first_timestamp.get_or_insert(timestamp);
+        let timestamp = message
+            .timestamp
+            .wrapping_add(timestamp.wrapping_sub(first));
+        if matches!(type_id, chunk::AUDIO | chunk::VIDEO | chunk::DATA_AMF0) {
+            write_tag(out, type_id, timestamp, data);
+            appended = true;
+        }
+        // Tag header, data and the back pointer
+        rest = rest.get(11 + size + 4..).unwrap_or_default();
+    }
+    Ok(appended)
+}
+
+/// Append an FLV tag and its previous tag size.
+pub(crate) fn write_tag(out: &mut BytesMut, tag_type: u8, timestamp: u32, data: &[u8]) {
+    out.put_u8(tag_type);
+    out.put_uint(data.len() as u64, 3);
+    out.put_uint(u64::from(timestamp & 0xFF_FFFF), 3);
+    out.put_u8((timestamp >> 24) as u8);
+    out.put_uint(0, 3);
+    out.put_slice(data);
+    out.put_u32(11 + data.len() as u32);
+}
+
+fn protocol_error(reason: String) -> DownloadError {
+    DownloadError::Protocol {
+        reason: format!("RTMP: {reason}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{AsyncReadExt, DuplexStream};
+
+    #[test]
+    fn parses_rtmp_urls() {
+        let url = Url::parse("rtmp://live.example.com/live/room_1?secret=abc&t=1").unwrap();
+        let url = RtmpUrl::parse(&url).unwrap();
+        assert_eq!(url.port, 1935);
+        assert!(!url.tls);
+        assert_eq!(url.app, "live");
+        assert_eq!(url.tc_url, "rtmp://live.example.com/live");
+        assert_eq!(url.stream, "room_1?secret=abc&t=1");
+
+        let url = Url::parse("rtmps://cdn.example.com:8443/app/inst/stream").unwrap();
+        let url = RtmpUrl::parse(&url).unwrap();
+        assert_eq!((url.port, url.tls), (8443, true));
+        assert_eq!(url.app, "app/inst");
+        assert_eq!(url.tc_url, "rtmps://cdn.example.com:8443/app/inst");
+
+        let url = Url::parse("rtmp://live.example.com/stream").unwrap();
+        assert!(RtmpUrl::parse(&url).is_err());
+    }
+
+    /// Server side of a play session: simple handshake, then scripted replies.
+    async fn serve(mut io: DuplexStream) {
+        let mut c0c1 = vec![0u8; 1 + handshake::HANDSHAKE_SIZE];
+        io.read_exact(&mut c0c1).await.unwrap();
+        let mut s0s1s2 = vec![3u8];
+        s0s1s2.extend_from_slice(&[0u8; handshake::HANDSHAKE_SIZE]);
+        s0s1s2.extend_from_slice(&c0c1[1..]);
+        io.write_all(&s0s1s2).await.unwrap();
+        let mut c2 = vec![0u8; handshake::HANDSHAKE_SIZE];
+        io.read_exact(&mut c2).await.unwrap();
+
+        let mut reader = ChunkReader::new();
+        let writer = ChunkWriter::new();
+        let command = |values: &[Amf0Value<'_>], stream_id| {
+            let mut payload = Vec::new();
+            for value in values {
+                Amf0Encoder::encode(&mut payload, value).unwrap();
+            }
+            Message {
+                type_id: chunk::COMMAND_AMF0,
+                stream_id,
+                timestamp: 0,
+                payload: payload.into(),
+            }
+        };
+        let media = |type_id, timestamp, payload: &'static [u8]| Message {
+            type_id,
+            stream_id: 1,
+            timestamp,
+            payload: Bytes::from_static(payload),
+        };
+
+        let mut names = Vec::new();
+        while let Some(message) = reader.read_message(&mut io).await.unwrap() {
+            if message.type_id == chunk::SET_CHUNK_SIZE {
+                reader.set_chunk_size(4096);
+                continue;
+            }
+            if message.type_id != chunk::COMMAND_AMF0 {
+                continue;
+            }
+            let values = decode_command(&message.payload);
+            let name = values[0].as_str().unwrap().to_string();
+            let transaction = values[1].clone();
+            let mut out = BytesMut::new();
+            match name.as_str() {
+                "connect" => writer.write(
+                    3,
+                    &command(
+                        &[
+                            Amf0Value::String("_result".into()),
+                            transaction,
+                            Amf0Value::Null,
+                        ],
+                        0,
+                    ),
+                    &mut out,
+                ),
+                "createStream" => writer.write(
+                    3,
+                    &command(
+                        &[
+                            Amf0Value::String("_result".into()),
+                            transaction,
+                            Amf0Value::Null,
+                            Amf0Value::Number(1.0),
+                        ],
+                        0,
+                    ),
+                    &mut out,
+                ),
+                "play" => {
+                    assert_eq!(values[3].as_str(), Some("room?token=1"));
+                    let mut data = Vec::new();
+                    Amf0Encoder::encode_string(&mut data, "@setDataFrame").unwrap();
+                    Amf0Encoder::encode_string(&mut data, "onMetaData").unwrap();
+                    let data: &'static [u8] = data.leak();
+                    writer.write(5, &media(chunk::DATA_AMF0, 0, data), &mut out);
+                    writer.write(6, &media(chunk::VIDEO, 0, &[0x17, 0, 0, 0, 0]), &mut out);
+                    writer.write(4, &media(chunk::AUDIO, 23, &[0xAF, 1, 0x21]), &mut out);
+                    let status = [
+                        (Cow::Borrowed("level"), Amf0Value::String("status".into())),
+                        (
+                            Cow::Borrowed("code"),
+                            Amf0Value::String("NetStream.Play.Stop".into()),
+                        ),
+                    ];
+                    writer.write(
+                        5,
+                        &command(
+                            &[
+                                Amf0Value::String("onStatus".into()),
+                                Amf0Value::Number(0.0),
+                                Amf0Value::Null,
+                                Amf0Value::Object(Cow::Borrowed(&status)),
+                            ],
+                            1,
+                        ),
+                        &mut out,
+                    );
+                }
+                _ => {}
+            }
+            names.push(name);
+            io.write_all(&out).await.unwrap();
+        }
+        assert_eq!(names, ["connect", "createStream", "play"]);
+    }
+
+    #[tokio::test]
+    async fn plays_a_stream_into_flv_tags() {
+        let (client, server) = tokio::io::duplex(64 * 1024);
+        let server = tokio::spawn(serve(server));
+
+        let url =
+            RtmpUrl::parse(&Url::parse("rtmp://localhost/live/room?token=1").unwrap()).unwrap();
+        let mut player = RtmpPlayer::play(client, &url, Duration::from_secs(5))
+            .await
+            .unwrap();
+        let mut tags = BytesMut::new();
+        let mut count = 0;
+        while player.read_tags(&mut tags).await.unwrap() {
+            count += 1;
+        }
+        assert_eq!(count, 3);
+        drop(player);
+        server.await.unwrap();
+
+        // Script data without the @setDataFrame wrapper
+        assert_eq!(tags[0], chunk::DATA_AMF0);
+        assert_eq!(&tags[11..14], &[0x02, 0x00, 0x0A]);
+        let video_at = 11 + 13 + 4;
+        assert_eq!(tags[video_at], chunk::VIDEO);
+        let audio_at = video_at + 11 + 5 + 4;
+        assert_eq!(tags[audio_at], chunk::AUDIO);
+        assert_eq!(tags[audio_at + 6], 23);
+        assert_eq!(tags.len(), audio_at + 11 + 3 + 4);
+    }
+
+    #[test]
+    fn aggregate_messages_are_rebased_on_the_message_timestamp() {
+        let mut payload = BytesMut::new();
+        write_tag(&mut payload, chunk::VIDEO, 5000, &[1, 2]);
+        write_tag(&mut payload, chunk::AUDIO, 5020, &[3]);
+        let message = Message {
+            type_id: chunk::AGGREGATE,
+            stream_id: 1,
+            timestamp: 100,
+            payload: payload.freeze(),
+        };
+
+        let mut out = BytesMut::new();
+        assert!(append_aggregate(&mut out, &message).unwrap());
+        let mut expected = BytesMut::new();
+        write_tag(&mut expected, chunk::VIDEO, 100, &[1, 2]);
+        write_tag(&mut expected, chunk::AUDIO, 120, &[3]);
+        assert_eq!(out, expected);
+    }
+}
@@ -0,0 +1,169 @@
+//! RTMP handshake.
+//!
+//! The client sends the digest handshake of Flash Player, since some servers only deliver H.264
+//! and AAC to clients that pass it, and falls back to the simple handshake when the server
+//! answers without a digest.
+
+use sha2::{Digest, Sha256};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+use crate::DownloadError;
+
+const RTMP_VERSION: u8 = 3;
+pub(crate) const HANDSHAKE_SIZE: usize = 1536;
+const DIGEST_SIZE: usize = 32;
+/// Flash Player version advertised in C1; a non-zero version selects the digest handshake.
+const CLIENT_VERSION: [u8; 4] = [0x09, 0x00, 0x7c, 0x02];
+
+const KEY_TAIL: [u8; 32] = [
+    0xF0, 0xEE, 0xC2, 0x4A, 0x80, 0x68, 0xBE, 0xE8, 0x2E, 0x00, 0xD0, 0xD1, 0x02, 0x9E, 0x7E, 0x57,
+    0x6E, 0xEC, 0x5D, 0x2D, 0x29, 0x80, 0x6F, 0xAB, 0x93, 0xB8, 0xE6, 0x36, 0xCF, 0xEB, 0x31, 0xAE,
+];
+/// Client digests are signed with the text part of the player key only.
+const PLAYER_KEY_TEXT: &[u8] = b"Genuine Adobe Flash Player 001";
+/// Server digests are signed with the text part of the server key only.
+const SERVER_KEY_TEXT: &[u8] = b"Genuine Adobe Flash Media Server 001";
+
+/// Run the client side of the handshake on a fresh connection.
+pub(crate) async fn client_handshake<S>(stream: &mut S) -> Result<(), DownloadError>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let c1 = client_c1();
+    let mut c0c1 = Vec::with_capacity(1 + HANDSHAKE_SIZE);
+    c0c1.push(RTMP_VERSION);
+    c0c1.extend_from_slice(&c1);
+    stream.write_all(&c0c1).await?;
+    stream.flush().await?;
+
+    let mut s0 = [0u8; 1];
+    stream.read_exact(&mut s0).await?;
+    if s0[0] != RTMP_VERSION {
+        return Err(DownloadError::Protocol {
+            reason: format!("unsupported RTMP version {}", s0[0]),
+        });
+    }
+    let mut s1 = vec![0u8; HANDSHAKE_SIZE];
+    stream.read_exact(&mut s1).await?;
+
+    stream.write_all(&client_c2(&s1)).await?;
+    stream.flush().await?;
+
+    // S2 echoes C1; servers disagree on its digest, so it is not checked
+    let mut s2 = vec![0u8; HANDSHAKE_SIZE];
+    stream.read_exact(&mut s2).await?;
+    Ok(())
+}
+
+fn client_c1() -> Vec<u8> {
+    let mut c1 = vec![0u8; HANDSHAKE_SIZE];
+    rand::fill(&mut c1[8..]);
+    c1[4..8].copy_from_slice(&CLIENT_VERSION);
+    let offset = digest_offset(&c1, DigestScheme::First);
+    let digest = message_digest(&c1, offset, PLAYER_KEY_TEXT);
+    c1[offset..offset + DIGEST_SIZE].copy_from_slice(&digest);
+    c1
+}
+
+/// C2: signed with the digest of S1 when the server sent one, or S1 echoed back.
+fn client_c2(s1: &[u8]) -> Vec<u8> {
+    let Some(server_digest) = find_digest(s1, SERVER_KEY_TEXT) else {
+        return s1.to_vec();
+    };
+    let mut c2 = vec![0u8; HANDSHAKE_SIZE];
+    rand::fill(&mut c2[..]);
+    let key = hmac_sha256(&player_key(), &[server_digest]);
+    let signature_at = HANDSHAKE_SIZE - DIGEST_SIZE;
+    let signature = hmac_sha256(&key, &[&c2[..signature_at]]);
+    c2[signature_at..].copy_from_slice(&signature);
+    c2
+}
+
+fn player_key() -> Vec<u8> {
+    [PLAYER_KEY_TEXT, &KEY_TAIL].concat()
+}
+
+#[derive(Clone, Copy)]
+enum DigestScheme {
+    /// Digest in the first half of the handshake, after time and version.
+    First,
+    /// Digest in the second half of the handshake.
+    Second,
+}
+
+fn digest_offset(block: &[u8], scheme: DigestScheme) -> usize {
+    let (base, sum_at) = match scheme {
+        DigestScheme::First => (12, 8),
+        DigestScheme::Second => (776, 772),
+    };
+    let sum: usize = block[sum_at..sum_at + 4].iter().map(|&b| b as usize).sum();
+    sum % 728 + base
+}
+
+/// HMAC of a handshake block without the digest at `offset`.
+fn message_digest(block: &[u8], offset: usize, key: &[u8]) -> [u8; DIGEST_SIZE] {
+    hmac_sha256(key, &[&block[..offset], &block[offset + DIGEST_SIZE..]])
+}
+
+/// The digest of a server handshake block, if it has a valid one.
+fn find_digest<'a>(block: &'a [u8], key: &[u8]) -> Option<&'a [u8]> {
+    [DigestScheme::First, DigestScheme::Second]
+        .into_iter()
+        .map(|scheme| digest_offset(block, scheme))
+        .find(|&offset| message_digest(block, offset, key) == block[offset..offset + DIGEST_SIZE])
+        .map(|offset| &block[offset..offset + DIGEST_SIZE])
+}
+
+fn hmac_sha256(key: &[u8], parts: &[&[u8]]) -> [u8; DIGEST_SIZE] {
+    const BLOCK_SIZE: usize = 64;
+    let mut block = [0u8; BLOCK_SIZE];
+    if key.len() > BLOCK_SIZE {
+        block[..DIGEST_SIZE].copy_from_slice(&Sha256::digest(key));
+    } else {
+        block[..key.len()].copy_from_slice(key);
+    }
+
+    let mut inner = Sha256::new();
+    inner.update(block.map(|b| b ^ 0x36));
+    for part in parts {
+        inner.update(part);
+    }
+    let mut outer = Sha256::new();
+    outer.update(block.map(|b| b ^ 0x5c));
+    outer.update(inner.finalize());
+    outer.finalize().into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hmac_matches_rfc_4231() {
+        let mac = hmac_sha256(b"Jefe", &[b"what do ya ", b"want for nothing?"]);
+        assert_eq!(
+            hex::encode(mac),
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+
+    #[test]
+    fn c1_carries_a_verifiable_digest() {
+        let c1 = client_c1();
+        assert_eq!(c1.len(), HANDSHAKE_SIZE);
+        assert!(find_digest(&c1, PLAYER_KEY_TEXT).is_some());
+        assert!(find_digest(&c1, SERVER_KEY_TEXT).is_none());
+    }
+
+    #[test]
+    fn c2_echoes_s1_without_digest() {
+        let s1 = vec![7u8; HANDSHAKE_SIZE];
+        assert_eq!(client_c2(&s1), s1);
+
+        let mut signed = vec![1u8; HANDSHAKE_SIZE];
+        let offset = digest_offset(&signed, DigestScheme::Second);
+        let digest = message_digest(&signed, offset, SERVER_KEY_TEXT);
+        signed[offset..offset + DIGEST_SIZE].copy_from_slice(&digest);
+        assert_ne!(client_c2(&signed), signed);
+    }
+}
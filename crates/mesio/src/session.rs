@@ -18,12 +18,16 @@ use tracing::{debug, warn};
 use url::Url;
 
 use crate::cache::CacheManager;
-use crate::flv::{FlvDownloader, FlvProtocolConfig};
-use crate::hls::config::HlsVariantSelectionPolicy;
+use crate::dash::{DashConfig, DashContentType, DashDownloader, DashRepresentationSelection};
+use crate::flv::{FlvDownloader, FlvProtocolConfig, FlvResumePoint};
+use crate::hls::config::{HlsAudioRendition, HlsVariantSelectionPolicy};
 use crate::hls::engine::identity::SegmentKey;
 use crate::hls::{GapSkipReason, MetricsSnapshot, PerformanceMetrics};
 use crate::hls::{HlsConfig, HlsDownloader};
+use crate::progress::{ProgressCallback, ProgressMeter, ProgressOptions};
+use crate::proxy::ProxyConfig;
 use crate::source::{ContentSource, SourceManager};
+use crate::watchdog::{self, WatchdogConfig};
 use crate::{BoxMediaStream, DownloadError};
 
 pub type DownloadEventStream = Pin<Box<dyn Stream<Item = DownloadEvent> + Send + 'static>>;
@@ -35,6 +39,8 @@ pub enum ProtocolType {
     Flv,
     /// HLS protocol.
     Hls,
+    /// MPEG-DASH protocol.
+    Dash,
     /// Auto-detect from URL.
     Auto,
 }
@@ -46,6 +52,8 @@ pub struct DownloadRequest {
     pub sources: Vec<ContentSource>,
     pub cache: Option<Arc<CacheManager>>,
     pub cancel: Option<CancellationToken>,
+    /// Overrides the proxy of the protocol configurations for this download
+    pub proxy: Option<ProxyConfig>,
     pub options: DownloadOptions,
 }
 
@@ -57,6 +65,7 @@ impl DownloadRequest {
             sources: Vec::new(),
             cache: None,
             cancel: None,
+            proxy: None,
             options: DownloadOptions::default(),
         }
     }
@@ -81,6 +90,11 @@ impl DownloadRequest {
         self
     }
 
+    pub fn with_proxy(mut self, proxy: ProxyConfig) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
     pub fn add_source(mut self, source: ContentSource) -> Self {
         self.sources.push(source);
         self
@@ -91,6 +105,7 @@ impl DownloadRequest {
 pub struct DownloadOptions {
     pub hls: HlsRequestOptions,
     pub flv: FlvRequestOptions,
+    pub dash: DashRequestOptions,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -99,22 +114,36 @@ pub enum ProtocolSelection {
     Auto,
     Hls(HlsRequestOptions),
     Flv(FlvRequestOptions),
+    Dash(DashRequestOptions),
 }
 
 #[derive(Debug, Clone, Default)]
 pub struct HlsRequestOptions {
     pub variant_selection_policy: Option<HlsVariantSelectionPolicy>,
+    /// Record this audio rendition of the selected variant instead of the variant
+    pub audio_rendition: Option<HlsAudioRendition>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct DashRequestOptions {
+    /// Overrides the representation selection of the DASH configuration
+    pub representation_selection: Option<DashRepresentationSelection>,
+    /// Record the audio adaptation set instead of the video one
+    pub content_type: DashContentType,
 }
 
 #[derive(Debug, Clone)]
 pub struct FlvRequestOptions {
     pub reconnect: FlvReconnect,
+    /// Continue an interrupted download instead of starting from the beginning
+    pub resume: Option<FlvResumePoint>,
 }
 
 impl Default for FlvRequestOptions {
     fn default() -> Self {
         Self {
             reconnect: FlvReconnect::FailTerminal,
+            resume: None,
         }
     }
 }
@@ -132,6 +161,74 @@ pub struct DownloadSession<T> {
     pub handle: DownloadHandle,
 }
 
+impl<T> DownloadSession<T> {
+    /// Report the progress of the download to `callback` every `options.interval`, and once
+    /// more when the events end. Progress is measured from the session's events, so they must
+    /// still be consumed. Must be called within a Tokio runtime.
+    pub fn with_progress(self, options: ProgressOptions, callback: ProgressCallback) -> Self {
+        let meter = Arc::new(Mutex::new(ProgressMeter::new(options)));
+        let ended = CancellationToken::new();
+        let events = MeteredEvents {
+            inner: self.events,
+            meter: Arc::clone(&meter),
+            ended: ended.clone(),
+        };
+
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(options.interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            ticker.tick().await;
+            loop {
+                tokio::select! {
+                    _ = ended.cancelled() => break,
+                    _ = ticker.tick() => callback(&meter.lock().snapshot()),
+                }
+            }
+            callback(&meter.lock().snapshot());
+        });
+
+        Self {
+            items: self.items,
+            events: Box::pin(events),
+            handle: self.handle,
+        }
+    }
+}
+
+impl<T: Send + 'static> DownloadSession<T> {
+    fn watched(mut self, watchdog: Option<WatchdogConfig>) -> Self {
+        self.items = watchdog::watch(self.items, watchdog);
+        self
+    }
+}
+
+/// Events feeding a [`ProgressMeter`]. Reporting stops once they end or are dropped.
+struct MeteredEvents {
+    inner: DownloadEventStream,
+    meter: Arc<Mutex<ProgressMeter>>,
+    ended: CancellationToken,
+}
+
+impl Stream for MeteredEvents {
+    type Item = DownloadEvent;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let polled = self.inner.poll_next_unpin(cx);
+        match &polled {
+            Poll::Ready(Some(event)) => self.meter.lock().observe(event),
+            Poll::Ready(None) => self.ended.cancel(),
+            Poll::Pending => {}
+        }
+        polled
+    }
+}
+
+impl Drop for MeteredEvents {
+    fn drop(&mut self) {
+        self.ended.cancel();
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum DownloadTerminal {
     AuthoritativeEnd,
@@ -143,6 +240,8 @@ pub enum DownloadTerminal {
 pub enum DownloaderSession {
     Flv(DownloadSession<flv::data::FlvData>),
     Hls(DownloadSession<hls::HlsData>),
+    /// DASH segments are fMP4, carried as HLS data.
+    Dash(DownloadSession<hls::HlsData>),
 }
 
 impl DownloaderSession {
@@ -152,6 +251,9 @@ impl DownloaderSession {
             Self::Flv(_) => Err(DownloadError::UnsupportedProtocol {
                 protocol: "expected HLS session, got FLV".to_string(),
             }),
+            Self::Dash(_) => Err(DownloadError::UnsupportedProtocol {
+                protocol: "expected HLS session, got DASH".to_string(),
+            }),
         }
     }
 
@@ -161,6 +263,9 @@ impl DownloaderSession {
             Self::Hls(_) => Err(DownloadError::UnsupportedProtocol {
                 protocol: "expected FLV session, got HLS".to_string(),
             }),
+            Self::Dash(_) => Err(DownloadError::UnsupportedProtocol {
+                protocol: "expected FLV session, got DASH".to_string(),
+            }),
         }
     }
 }
@@ -289,6 +394,8 @@ pub enum ResourceId {
     HlsSegment { key: SegmentKey },
     HlsKey { uri: Arc<str> },
     FlvStream { url: Arc<str> },
+    DashManifest { url: Arc<str> },
+    DashSegment { url: Arc<str> },
 }
 
 #[derive(Clone)]
@@ -351,7 +458,10 @@ pub trait MediaEngine: Send + Sync + 'static {
 pub struct MesioConfig {
     pub flv: FlvProtocolConfig,
     pub hls: HlsConfig,
+    pub dash: DashConfig,
     pub token: CancellationToken,
+    /// Ends or restarts downloads that stop producing media. `None` disables it.
+    pub watchdog: Option<WatchdogConfig>,
 }
 
 impl Default for MesioConfig {
@@ -359,7 +469,9 @@ impl Default for MesioConfig {
         Self {
             flv: FlvProtocolConfig::default(),
             hls: HlsConfig::default(),
+            dash: DashConfig::default(),
             token: CancellationToken::new(),
+            watchdog: Some(WatchdogConfig::default()),
         }
     }
 }
@@ -391,6 +503,10 @@ impl MesioDownloader {
         })?;
 
         let path = url.path().to_lowercase();
+        if path.ends_with(".mpd") {
+            return Ok(ProtocolType::Dash);
+        }
+
         if path.ends_with(".m3u8") || path.ends_with(".m3u") || path.contains("playlist") {
             return Ok(ProtocolType::Hls);
         }
@@ -406,7 +522,7 @@ impl MesioDownloader {
             }
         }
 
-        if matches!(url.scheme(), "rtmp" | "rtmps" | "rtsp") {
+        if matches!(url.scheme(), "rtmp" | "rtmps" | "rtsp" | "ws" | "wss") {
             return Ok(ProtocolType::Flv);
         }
 
@@ -425,6 +541,7 @@ impl MesioDownloader {
             ProtocolSelection::Auto => Self::detect_protocol(request.url.as_str())?,
             ProtocolSelection::Hls(_) => ProtocolType::Hls,
             ProtocolSelection::Flv(_) => ProtocolType::Flv,
+            ProtocolSelection::Dash(_) => ProtocolType::Dash,
         };
 
         match protocol {
@@ -442,6 +559,7 @@ impl MesioDownloader {
                 }
                 Ok(DownloaderSession::Flv(self.start_flv(request).await?))
             }
+            ProtocolType::Dash => Ok(DownloaderSession::Dash(self.start_dash(request).await?)),
             ProtocolType::Auto => unreachable!(),
         }
     }
@@ -464,8 +582,22 @@ impl MesioDownloader {
         &self,
         request: DownloadRequest,
     ) -> Result<DownloadSession<hls::HlsData>, DownloadError> {
-        let downloader = HlsDownloader::with_config(self.config.hls.clone())?;
-        downloader.start(request).await
+        let downloader = HlsDownloader::with_config(self.config_for(&request).hls)?;
+        let session = downloader.start(request).await?;
+        Ok(session.watched(self.config.watchdog))
+    }
+
+    pub async fn start_dash(
+        &self,
+        mut request: DownloadRequest,
+    ) -> Result<DownloadSession<hls::HlsData>, DownloadError> {
+        self.apply_defaults(&mut request);
+        if matches!(request.protocol, ProtocolSelection::Auto) {
+            request.protocol = ProtocolSelection::Dash(request.options.dash.clone());
+        }
+        let downloader = DashDownloader::with_config(self.config_for(&request).dash)?;
+        let session = downloader.start(request).await?;
+        Ok(session.watched(self.config.watchdog))
     }
 
     pub async fn start_flv(
@@ -480,23 +612,49 @@ impl MesioDownloader {
             ProtocolSelection::Flv(options) => options.reconnect,
             _ => request.options.flv.reconnect,
         };
-        if reconnect != FlvReconnect::FailTerminal {
-            return Err(DownloadError::Configuration {
-                reason: format!("FLV reconnect mode {reconnect:?} is declared but not implemented"),
-            });
+        match reconnect {
+            FlvReconnect::FailTerminal => {}
+            FlvReconnect::SwitchSourceWithDiscontinuity => {
+                return self.start_flv_with_sources(request).await;
+            }
+            FlvReconnect::ReconnectSameSourceWithDiscontinuity => {
+                return Err(DownloadError::Configuration {
+                    reason: format!(
+                        "FLV reconnect mode {reconnect:?} is declared but not implemented"
+                    ),
+                });
+            }
         }
         let selected_source = Self::select_initial_source(&mut request)?;
         if let Some(source) = &selected_source {
             request.url = source.url.clone();
         }
-        let downloader = FlvDownloader::with_config(self.config.flv.clone())?;
-        let session = downloader.start(request).await?;
+        let downloader = FlvDownloader::with_config(self.config_for(&request).flv)?;
+        let session = downloader
+            .start(request)
+            .await?
+            .watched(self.config.watchdog);
         if let Some(source) = selected_source {
             return wrap_flv_source_session(session, source);
         }
         Ok(session)
     }
 
+    /// The downloader's configuration with the overrides of `request` applied
+    fn config_for(&self, request: &DownloadRequest) -> MesioConfig {
+        let mut config = self.config.clone();
+        if let Some(proxy) = &request.proxy {
+            for base in [
+                &mut config.flv.base,
+                &mut config.hls.base,
+                &mut config.dash.base,
+            ] {
+                base.proxy = Some(proxy.clone());
+            }
+        }
+        config
+    }
+
     fn apply_defaults(&self, request: &mut DownloadRequest) {
         if request.cancel.is_none() {
             request.cancel = Some(self.config.token.clone());
@@ -541,7 +699,7 @@ impl MesioDownloader {
         let (events, event_stream) = EventSink::channel(event_capacity);
         let dropped_counter = events.dropped_counter();
         let (item_tx, item_rx) = mpsc::channel(32);
-        let config = self.config.clone();
+        let config = self.config_for(&request);
         let request_cache = request.cache.clone();
         let active_metrics = Arc::new(Mutex::new(None));
         let lifecycle_token = session_token.clone();
@@ -574,6 +732,44 @@ impl MesioDownloader {
             Some(lifecycle),
         );
 
+        Ok(DownloadSession {
+            items: stream,
+            events: event_stream,
+            handle,
+        })
+    }
+    async fn start_flv_with_sources(
+        &self,
+        mut request: DownloadRequest,
+    ) -> Result<DownloadSession<flv::data::FlvData>, DownloadError> {
+        if request.sources.is_empty() {
+            request
+                .sources
+                .push(ContentSource::new(request.url.as_str(), 0));
+        }
+        let parent_token = request
+            .cancel
+            .clone()
+            .unwrap_or_else(|| self.config.token.clone());
+        let session_token = parent_token.child_token();
+        let (events, event_stream) = EventSink::channel(256);
+        let dropped_counter = events.dropped_counter();
+        let (item_tx, item_rx) = mpsc::channel(32);
+        let config = self.config_for(&request);
+        let lifecycle_token = session_token.clone();
+
+        let lifecycle = tokio::spawn(async move {
+            run_flv_source_failover(config, request, lifecycle_token, events, item_tx).await
+        });
+
+        let stream: BoxMediaStream<flv::data::FlvData, DownloadError> =
+            Box::pin(SessionCancelOnDropStream {
+                inner: Box::pin(ReceiverStream::new(item_rx)),
+                token: session_token.clone(),
+            });
+
+        let handle = DownloadHandle::new(session_token, None, dropped_counter, Some(lifecycle));
+
         Ok(DownloadSession {
             items: stream,
             events: event_stream,
@@ -691,7 +887,8 @@ async fn run_hls_source_failover(
         let attempt_events = session.events;
         let forward_events = events.clone();
         let event_task = tokio::spawn(forward_event_stream(attempt_events, forward_events));
-        let mut items = session.items;
+        // A stalled source fails like any other, so the next one takes over
+        let mut items = watchdog::watch(session.items, config.watchdog);
         let handle = session.handle;
         let mut terminal = DownloadTerminal::Cancelled;
         let mut failed = None;
@@ -753,6 +950,140 @@ async fn run_hls_source_failover(
     }
 }
 
+/// Forward FLV data from the sources in turn, switching to the next source when one fails.
+///
+/// Every source starts with its own FLV header, which marks the discontinuity for the
+/// downstream pipeline. A source that ends cleanly ends the session.
+async fn run_flv_source_failover(
+    config: MesioConfig,
+    request: DownloadRequest,
+    token: CancellationToken,
+    events: EventSink,
+    item_tx: mpsc::Sender<Result<flv::data::FlvData, DownloadError>>,
+) -> DownloadTerminal {
+    let mut manager = SourceManager::new();
+    for source in request.sources.iter().cloned() {
+        manager.add_source(source);
+    }
+
+    let mut attempted = HashSet::new();
+    let mut last_error: Option<DownloadError> = None;
+    let mut attempt = 0_u32;
+
+    loop {
+        if token.is_cancelled() {
+            return DownloadTerminal::Cancelled;
+        }
+
+        let selected = match select_source_attempt(&mut manager, &attempted) {
+            Ok(Some(source)) => source,
+            Ok(None) => {
+                let reason = last_error
+                    .map(|err| err.to_string())
+                    .unwrap_or_else(|| "all FLV sources failed".to_string());
+                let error = DownloadError::source_exhausted(reason.clone());
+                if item_tx.send(Err(error)).await.is_err() {
+                    debug!("FLV error receiver closed after source exhaustion");
+                }
+                return DownloadTerminal::PipelineError(Arc::from(reason));
+            }
+            Err(err) => {
+                let reason = err.to_string();
+                if item_tx.send(Err(err)).await.is_err() {
+                    debug!("FLV error receiver closed after source selection failure");
+                }
+                return DownloadTerminal::PipelineError(Arc::from(reason));
+            }
+        };
+        attempted.insert(selected.original_url.clone());
+        attempt = attempt.saturating_add(1);
+
+        events.emit(DownloadEvent::SourceSelected {
+            url: Arc::from(selected.original_url.as_str()),
+            priority: selected.priority,
+            attempt,
+        });
+
+        let mut attempt_request = request.clone();
+        attempt_request.url = selected.url;
+        attempt_request.sources.clear();
+        attempt_request.cancel = Some(token.clone());
+        // A resume offset only applies to the partial output of the first source
+        if attempt > 1 {
+            attempt_request.options.flv.resume = None;
+            if let ProtocolSelection::Flv(options) = &mut attempt_request.protocol {
+                options.resume = None;
+            }
+        }
+
+        let downloader = match FlvDownloader::with_config(config.flv.clone()) {
+            Ok(downloader) => downloader,
+            Err(err) => {
+                let reason = err.to_string();
+                if item_tx.send(Err(err)).await.is_err() {
+                    debug!("FLV error receiver closed after downloader initialization failure");
+                }
+                return DownloadTerminal::PipelineError(Arc::from(reason));
+            }
+        };
+
+        let started_at = Instant::now();
+        let session = match downloader.start(attempt_request).await {
+            Ok(session) => session,
+            Err(err) => {
+                manager.record_failure(&selected.original_url, &err, started_at.elapsed());
+                last_error = Some(err);
+                continue;
+            }
+        };
+
+        let event_task = tokio::spawn(forward_event_stream(session.events, events.clone()));
+        let mut items = watchdog::watch(session.items, config.watchdog);
+        let handle = session.handle;
+        let mut failed = None;
+
+        while let Some(item) = items.next().await {
+            match item {
+                Ok(item) => {
+                    if item_tx.send(Ok(item)).await.is_err() {
+                        handle.cancel();
+                        if let Err(error) = event_task.await {
+                            warn!(%error, "FLV event forwarding task failed during cancellation");
+                        }
+                        return DownloadTerminal::DownstreamClosed;
+                    }
+                }
+                Err(err) => {
+                    failed = Some(err);
+                    break;
+                }
+            }
+        }
+
+        drop(items);
+        handle.cancel();
+        if let Err(error) = event_task.await {
+            warn!(%error, "FLV event forwarding task failed");
+        }
+
+        match failed {
+            Some(err) => {
+                if !token.is_cancelled() {
+                    warn!(
+                        url = %selected.original_url,
+                        error = %err,
+                        "FLV source failed, switching to the next source"
+                    );
+                }
+                manager.record_failure(&selected.original_url, &err, started_at.elapsed());
+                last_error = Some(err);
+            }
+            None if token.is_cancelled() => return DownloadTerminal::Cancelled,
+            None => return DownloadTerminal::AuthoritativeEnd,
+        }
+    }
+}
+
 fn wrap_flv_source_session(
     session: DownloadSession<flv::data::FlvData>,
     source: SelectedSource,
@@ -873,6 +1204,27 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn detect_protocol_recognizes_dash_manifests() {
+        assert_eq!(
+            MesioDownloader::detect_protocol("https://example.test/live/manifest.mpd?token=1")
+                .unwrap(),
+            ProtocolType::Dash
+        );
+        assert_eq!(
+            MesioDownloader::detect_protocol("https://example.test/live/playlist.m3u8").unwrap(),
+            ProtocolType::Hls
+        );
+    }
+
+    #[test]
+    fn detect_protocol_treats_websocket_streams_as_flv() {
+        assert_eq!(
+            MesioDownloader::detect_protocol("wss://example.test/live/room?token=1").unwrap(),
+            ProtocolType::Flv
+        );
+    }
+
     #[test]
     fn handle_cancel_cancels_token() {
         let token = CancellationToken::new();
@@ -880,4 +1232,30 @@ mod tests {
         handle.cancel();
         assert!(token.is_cancelled());
     }
+
+    #[test]
+    fn request_proxy_overrides_every_protocol() {
+        let downloader = MesioDownloader::new(MesioConfig::default());
+        let request = DownloadRequest::from_url("https://example.test/live.flv")
+            .unwrap()
+            .with_proxy(ProxyConfig {
+                url: "proxy.example.test:1080".to_string(),
+                proxy_type: crate::ProxyType::Socks5h,
+                auth: None,
+            });
+
+        let config = downloader.config_for(&request);
+        for base in [&config.flv.base, &config.hls.base, &config.dash.base] {
+            let proxy = base.proxy.as_ref().expect("proxy override applied");
+            assert_eq!(proxy.proxy_type, crate::ProxyType::Socks5h);
+        }
+        assert!(
+            downloader
+                .config_for(&DownloadRequest::from_url("https://example.test/live.flv").unwrap())
+                .flv
+                .base
+                .proxy
+                .is_none()
+        );
+    }
 }
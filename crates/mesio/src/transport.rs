@@ -0,0 +1,84 @@
+//! # Raw connections
+//!
+//! TCP and TLS connections for the streaming protocols that do not go through reqwest (RTMP and
+//! WebSocket). They honour the connect timeout and IP family of the [`DownloaderConfig`] and
+//! verify certificates against the same webpki roots as the HTTP clients; proxies are not
+//! supported.
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio::net::TcpStream;
+use tracing::warn;
+
+use crate::{DownloadError, DownloaderConfig};
+
+/// A connection a streaming protocol runs over.
+pub trait Transport: AsyncRead + AsyncWrite + Unpin + Send {}
+
+impl<T: AsyncRead + AsyncWrite + Unpin + Send> Transport for T {}
+
+/// Open a TCP connection to `host:port`, wrapped in TLS when `tls` is set.
+pub(crate) async fn connect(
+    host: &str,
+    port: u16,
+    tls: bool,
+    config: &DownloaderConfig,
+) -> Result<Box<dyn Transport>, DownloadError> {
+    if config.proxy.is_some() {
+        warn!(host, "Connection does not go through the configured proxy");
+    }
+
+    let connect = connect_tcp(host, port, config);
+    let tcp = match config.connect_timeout.is_zero() {
+        true => connect.await?,
+        false => tokio::time::timeout(config.connect_timeout, connect)
+            .await
+            .map_err(|_| DownloadError::Timeout {
+                reason: format!("connecting to {host}:{port}"),
+            })??,
+    };
+    tcp.set_nodelay(true)?;
+    if !tls {
+        return Ok(Box::new(tcp));
+    }
+
+    crate::downloader::install_rustls_provider();
+    let roots = rustls::RootCertStore {
+        roots: webpki_roots::TLS_SERVER_ROOTS.to_vec(),
+    };
+    let tls_config = rustls::ClientConfig::builder()
+        .with_root_certificates(roots)
+        .with_no_client_auth();
+    let server_name = rustls::pki_types::ServerName::try_from(host.to_string())
+        .map_err(|e| DownloadError::invalid_url(host, e.to_string()))?;
+    let tls = tokio_rustls::TlsConnector::from(Arc::new(tls_config))
+        .connect(server_name, tcp)
+        .await?;
+    Ok(Box::new(tls))
+}
+
+async fn connect_tcp(
+    host: &str,
+    port: u16,
+    config: &DownloaderConfig,
+) -> Result<TcpStream, DownloadError> {
+    let addresses = tokio::net::lookup_host((host, port))
+        .await?
+        .filter(|address: &SocketAddr| {
+            (!config.force_ipv4 || address.is_ipv4()) && (!config.force_ipv6 || address.is_ipv6())
+        });
+
+    let mut last_error = None;
+    for address in addresses {
+        match TcpStream::connect(address).await {
+            Ok(stream) => return Ok(stream),
+            Err(e) => last_error = Some(e),
+        }
+    }
+    Err(match last_error {
+        Some(e) => e.into(),
+        None => DownloadError::invalid_url(host, "no address of the requested IP family"),
+    })
+}
@@ -0,0 +1,158 @@
+//! # Stall Watchdog
+//!
+//! Protocol timeouts only cover the request currently in flight: a server trickling bytes that
+//! never form an FLV tag, or a live playlist whose media sequence stops advancing, keep a download
+//! alive without it ever producing media. The watchdog watches the media items of a download
+//! instead, and once none has arrived for [`WatchdogConfig::stall_timeout`] it either fails the
+//! download so it reconnects or fails over to the next source, or ends it cleanly.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use futures::{Stream, StreamExt};
+use tokio::time::{Instant, Sleep};
+use tracing::warn;
+
+use crate::{BoxMediaStream, DownloadError};
+
+/// What the watchdog does with a download that stopped producing media.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StallAction {
+    /// Fail with [`DownloadError::Timeout`], so the download reconnects or switches source.
+    #[default]
+    Reconnect,
+    /// End the media stream as if the broadcast had finished.
+    EndStream,
+}
+
+/// Configuration of the stall watchdog.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WatchdogConfig {
+    /// Time without a media item after which the download counts as dead.
+    pub stall_timeout: Duration,
+    /// What to do once the download counts as dead.
+    pub action: StallAction,
+}
+
+impl Default for WatchdogConfig {
+    fn default() -> Self {
+        Self {
+            stall_timeout: Duration::from_secs(120),
+            action: StallAction::Reconnect,
+        }
+    }
+}
+
+/// Watch the media items of a download, or pass them through if there is no watchdog.
+///
+/// Only the time spent waiting for the next item counts, so a slow consumer never trips it.
+pub(crate) fn watch<T: Send + 'static>(
+    items: BoxMediaStream<T, DownloadError>,
+    config: Option<WatchdogConfig>,
+) -> BoxMediaStream<T, DownloadError> {
+    match config {
+        Some(config) if !config.stall_timeout.is_zero() => Box::pin(Watched {
+            inner: items,
+            config,
+            deadline: Box::pin(tokio::time::sleep(config.stall_timeout)),
+            waiting: false,
+            tripped: false,
+        }),
+        _ => items,
+    }
+}
+
+struct Watched<T> {
+    inner: BoxMediaStream<T, DownloadError>,
+    config: WatchdogConfig,
+    deadline: Pin<Box<Sleep>>,
+    /// Whether the consumer is waiting for an item, i.e. the deadline is running
+    waiting: bool,
+    tripped: bool,
+}
+
+impl<T> Stream for Watched<T> {
+    type Item = Result<T, DownloadError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if self.tripped {
+            return Poll::Ready(None);
+        }
+        if !self.waiting {
+            let deadline = Instant::now() + self.config.stall_timeout;
+            self.deadline.as_mut().reset(deadline);
+            self.waiting = true;
+        }
+
+        if let Poll::Ready(item) = self.inner.poll_next_unpin(cx) {
+            self.waiting = false;
+            return Poll::Ready(item);
+        }
+        if self.deadline.as_mut().poll(cx).is_pending() {
+            return Poll::Pending;
+        }
+
+        self.tripped = true;
+        let stall_timeout = self.config.stall_timeout;
+        match self.config.action {
+            StallAction::Reconnect => {
+                warn!(?stall_timeout, "No media received, reconnecting");
+                Poll::Ready(Some(Err(DownloadError::Timeout {
+                    reason: format!("no media received for {stall_timeout:?}"),
+                })))
+            }
+            StallAction::EndStream => {
+                warn!(?stall_timeout, "No media received, ending the stream");
+                Poll::Ready(None)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(action: StallAction) -> Option<WatchdogConfig> {
+        Some(WatchdogConfig {
+            stall_timeout: Duration::from_millis(50),
+            action,
+        })
+    }
+
+    fn one_item_then_silence() -> BoxMediaStream<u32, DownloadError> {
+        Box::pin(futures::stream::iter([Ok(1)]).chain(futures::stream::pending()))
+    }
+
+    #[tokio::test]
+    async fn silent_download_fails_with_a_timeout() {
+        let mut items = watch(one_item_then_silence(), config(StallAction::Reconnect));
+
+        assert!(matches!(items.next().await, Some(Ok(1))));
+        assert!(matches!(
+            items.next().await,
+            Some(Err(DownloadError::Timeout { .. }))
+        ));
+        assert!(items.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn silent_download_can_end_cleanly() {
+        let mut items = watch(one_item_then_silence(), config(StallAction::EndStream));
+
+        assert!(matches!(items.next().await, Some(Ok(1))));
+        assert!(items.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn slow_consumers_do_not_trip_the_watchdog() {
+        let source = futures::stream::iter([Ok(1), Ok(2)]).chain(futures::stream::pending());
+        let mut items = watch(Box::pin(source), config(StallAction::Reconnect));
+
+        assert!(matches!(items.next().await, Some(Ok(1))));
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert!(matches!(items.next().await, Some(Ok(2))));
+    }
+}
@@ -12,7 +12,8 @@ use std::time::Duration;
 use axum::Router;
 use axum::body::Body;
 use axum::extract::State;
-use axum::http::{StatusCode, Uri};
+use axum::http::header::COOKIE;
+use axum::http::{HeaderMap, StatusCode, Uri};
 use axum::response::Response;
 use bytes::Bytes;
 use flv::FlvData;
@@ -35,6 +36,8 @@ struct FileEntry {
     body: Vec<u8>,
     fail_status: u16,
     fail_times: u32,
+    /// Abort the connection after sending the body, instead of completing the response.
+    abort: bool,
 }
 
 #[derive(Default)]
@@ -46,6 +49,8 @@ struct OriginState {
     playlist_fail_after: Option<u32>,
     files: HashMap<String, FileEntry>,
     hits: HashMap<String, u64>,
+    /// `Cookie` header of the last request per path.
+    cookies: HashMap<String, String>,
 }
 
 #[derive(Clone, Default)]
@@ -67,6 +72,19 @@ impl Origin {
                 body: body.into(),
                 fail_status: 0,
                 fail_times: 0,
+                abort: false,
+            },
+        );
+    }
+
+    fn add_file_aborting(&self, path: &str, body: impl Into<Vec<u8>>) {
+        self.0.lock().unwrap().files.insert(
+            path.to_string(),
+            FileEntry {
+                body: body.into(),
+                fail_status: 0,
+                fail_times: 0,
+                abort: true,
             },
         );
     }
@@ -78,6 +96,7 @@ impl Origin {
                 body: body.into(),
                 fail_status: status,
                 fail_times: times,
+                abort: false,
             },
         );
     }
@@ -90,6 +109,10 @@ impl Origin {
         self.0.lock().unwrap().hits.get(path).copied().unwrap_or(0)
     }
 
+    fn cookie(&self, path: &str) -> Option<String> {
+        self.0.lock().unwrap().cookies.get(path).cloned()
+    }
+
     async fn serve(self) -> String {
         let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
             .await
@@ -103,10 +126,13 @@ impl Origin {
     }
 }
 
-async fn handler(State(origin): State<Origin>, uri: Uri) -> Response {
+async fn handler(State(origin): State<Origin>, uri: Uri, headers: HeaderMap) -> Response {
     let path = uri.path().trim_start_matches('/').to_string();
     let mut state = origin.0.lock().unwrap();
     *state.hits.entry(path.clone()).or_default() += 1;
+    if let Some(cookie) = headers.get(COOKIE).and_then(|v| v.to_str().ok()) {
+        state.cookies.insert(path.clone(), cookie.to_string());
+    }
 
     let respond = |status: StatusCode, body: Vec<u8>| {
         Response::builder()
@@ -139,6 +165,19 @@ async fn handler(State(origin): State<Origin>, uri: Uri) -> Response {
                 return respond(status, Vec::new());
             }
             let body = entry.body.clone();
+            if entry.abort {
+                use futures::StreamExt;
+                // Let the client process the body before the connection breaks
+                let chunks =
+                    futures::stream::iter([Ok(body)]).chain(futures::stream::once(async {
+                        tokio::time::sleep(Duration::from_millis(200)).await;
+                        Err::<Vec<u8>, _>(std::io::Error::other("connection aborted"))
+                    }));
+                return Response::builder()
+                    .status(StatusCode::OK)
+                    .body(Body::from_stream(chunks))
+                    .expect("response builds");
+            }
             respond(StatusCode::OK, body)
         }
         None => respond(StatusCode::NOT_FOUND, Vec::new()),
@@ -249,6 +288,16 @@ fn data_uris(events: &[Result<HlsStreamEvent, HlsDownloaderError>]) -> Vec<Strin
         .collect()
 }
 
+fn data_payloads(events: &[Result<HlsStreamEvent, HlsDownloaderError>]) -> Vec<Bytes> {
+    events
+        .iter()
+        .filter_map(|e| match e {
+            Ok(HlsStreamEvent::Data(data)) => data.data().cloned(),
+            _ => None,
+        })
+        .collect()
+}
+
 fn ends_with_stream_ended(events: &[Result<HlsStreamEvent, HlsDownloaderError>]) -> bool {
     matches!(events.last(), Some(Ok(HlsStreamEvent::StreamEnded)))
 }
@@ -439,24 +488,26 @@ async fn window_slide_surfaces_explicit_skip() {
     assert!(ends_with_stream_ended(&events));
 }
 
-#[tokio::test(flavor = "multi_thread")]
-async fn encrypted_stream_decrypts_with_single_key_fetch() {
+fn aes128_cbc_encrypt(key: &[u8; 16], iv: &[u8; 16], plaintext: &[u8]) -> Vec<u8> {
     use aes::Aes128;
     use cipher::{BlockModeEncrypt, KeyIvInit, block_padding::Pkcs7};
     type Aes128CbcEnc = cbc::Encryptor<Aes128>;
 
+    let cipher = Aes128CbcEnc::new_from_slices(key, iv).unwrap();
+    let padded_len = ((plaintext.len() / 16) + 1) * 16;
+    let mut buffer = vec![0u8; padded_len];
+    buffer[..plaintext.len()].copy_from_slice(plaintext);
+    cipher
+        .encrypt_padded::<Pkcs7>(&mut buffer, plaintext.len())
+        .unwrap()
+        .to_vec()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn encrypted_stream_decrypts_with_single_key_fetch() {
     let key = [0x42u8; 16];
     let iv = [0x13u8; 16];
-    let encrypt = |plaintext: &[u8]| -> Vec<u8> {
-        let cipher = Aes128CbcEnc::new_from_slices(&key, &iv).unwrap();
-        let padded_len = ((plaintext.len() / 16) + 1) * 16;
-        let mut buffer = vec![0u8; padded_len];
-        buffer[..plaintext.len()].copy_from_slice(plaintext);
-        cipher
-            .encrypt_padded::<Pkcs7>(&mut buffer, plaintext.len())
-            .unwrap()
-            .to_vec()
-    };
+    let encrypt = |plaintext: &[u8]| aes128_cbc_encrypt(&key, &iv, plaintext);
 
     let origin = Origin::new();
     let key_line =
@@ -480,13 +531,7 @@ async fn encrypted_stream_decrypts_with_single_key_fetch() {
     let base = origin.clone().serve().await;
     let events = run_engine(&base, fast_config()).await;
 
-    let payloads: Vec<Bytes> = events
-        .iter()
-        .filter_map(|e| match e {
-            Ok(HlsStreamEvent::Data(data)) => data.data().cloned(),
-            _ => None,
-        })
-        .collect();
+    let payloads = data_payloads(&events);
     assert_eq!(payloads.len(), 3);
     for (i, payload) in payloads.iter().enumerate() {
         assert_eq!(
@@ -503,6 +548,50 @@ async fn encrypted_stream_decrypts_with_single_key_fetch() {
     assert!(ends_with_stream_ended(&events));
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn encrypted_stream_derives_iv_from_media_sequence_and_sends_session_headers() {
+    let key = [0x42u8; 16];
+    let iv_for = |msn: u64| {
+        let mut iv = [0u8; 16];
+        iv[8..].copy_from_slice(&msn.to_be_bytes());
+        iv
+    };
+
+    let origin = Origin::new();
+    let mut body = String::from(
+        "#EXTM3U\n#EXT-X-VERSION:3\n#EXT-X-TARGETDURATION:0\n#EXT-X-MEDIA-SEQUENCE:7\n#EXT-X-KEY:METHOD=AES-128,URI=\"key.bin\"\n",
+    );
+    for msn in 7..9 {
+        body.push_str(&format!("#EXTINF:0.5,\nseg{msn}.ts\n"));
+        origin.add_file(
+            &format!("seg{msn}.ts"),
+            aes128_cbc_encrypt(&key, &iv_for(msn), format!("clear-{msn}").as_bytes()),
+        );
+    }
+    body.push_str("#EXT-X-ENDLIST\n");
+    origin.push_playlist(body);
+    origin.add_file("key.bin", key.to_vec());
+
+    let mut config = fast_config();
+    config
+        .base
+        .headers
+        .insert(COOKIE, "session=abc".parse().unwrap());
+    let base = origin.clone().serve().await;
+    let events = run_engine(&base, config).await;
+
+    let payloads = data_payloads(&events);
+    assert_eq!(
+        payloads,
+        vec![
+            Bytes::from_static(b"clear-7"),
+            Bytes::from_static(b"clear-8")
+        ]
+    );
+    assert_eq!(origin.cookie("key.bin").as_deref(), Some("session=abc"));
+    assert!(ends_with_stream_ended(&events));
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn byterange_segments_emit_requested_slices_when_origin_ignores_range() {
     let origin = Origin::new();
@@ -513,13 +602,7 @@ async fn byterange_segments_emit_requested_slices_when_origin_ignores_range() {
     let base = origin.clone().serve().await;
     let events = run_engine(&base, fast_config()).await;
 
-    let payloads: Vec<Bytes> = events
-        .iter()
-        .filter_map(|e| match e {
-            Ok(HlsStreamEvent::Data(data)) => data.data().cloned(),
-            _ => None,
-        })
-        .collect();
+    let payloads = data_payloads(&events);
     assert_eq!(
         payloads,
         vec![Bytes::from_static(b"CDEF"), Bytes::from_static(b"GHI")]
@@ -968,6 +1051,90 @@ async fn mesio_downloader_flv_source_selection_emits_event() {
     assert_eq!(selected, Some((format!("{base}/stream.flv"), 7, 1)));
 }
 
+/// An FLV header followed by one complete video tag per timestamp.
+fn flv_body(timestamps: &[u32]) -> Vec<u8> {
+    let mut body = b"FLV\x01\x01\x00\x00\x00\x09\x00\x00\x00\x00".to_vec();
+    for &timestamp in timestamps {
+        let data = [0x27, 0x01, 0, 0, 0, 0xAA];
+        body.push(9);
+        body.extend_from_slice(&(data.len() as u32).to_be_bytes()[1..]);
+        body.extend_from_slice(&timestamp.to_be_bytes()[1..]);
+        body.push((timestamp >> 24) as u8);
+        body.extend_from_slice(&[0, 0, 0]);
+        body.extend_from_slice(&data);
+        body.extend_from_slice(&(11 + data.len() as u32).to_be_bytes());
+    }
+    body
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn mesio_downloader_flv_sources_fail_over_mid_stream() {
+    use futures::StreamExt;
+
+    // The connection to the first source breaks after its first tag
+    let first_origin = Origin::new();
+    first_origin.add_file_aborting("stream.flv", flv_body(&[0]));
+    let first_base = first_origin.clone().serve().await;
+
+    let second_origin = Origin::new();
+    second_origin.add_file("stream.flv", flv_body(&[66]));
+    let second_base = second_origin.clone().serve().await;
+
+    let downloader = MesioDownloader::new(MesioConfig::default());
+    let request = DownloadRequest::from_url(&format!("{first_base}/stream.flv"))
+        .expect("valid URL")
+        .with_protocol(ProtocolSelection::Flv(mesio_engine::FlvRequestOptions {
+            reconnect: mesio_engine::FlvReconnect::SwitchSourceWithDiscontinuity,
+            ..Default::default()
+        }))
+        .add_source(ContentSource::new(format!("{first_base}/stream.flv"), 0))
+        .add_source(ContentSource::new(format!("{second_base}/stream.flv"), 1));
+    let session = downloader
+        .start_flv(request)
+        .await
+        .expect("source session starts");
+    let mut items = session.items;
+    let mut events = session.events;
+
+    let mut received = Vec::new();
+    while let Some(item) = tokio::time::timeout(Duration::from_secs(15), items.next())
+        .await
+        .expect("stream item")
+    {
+        match item.expect("no stream error") {
+            FlvData::Header(_) => received.push("header".to_string()),
+            FlvData::Tag(tag) => received.push(format!("tag {}", tag.timestamp_ms)),
+            other => panic!("unexpected item {other:?}"),
+        }
+    }
+    assert_eq!(
+        session
+            .handle
+            .join()
+            .await
+            .expect("lifecycle task")
+            .expect("joins"),
+        DownloadTerminal::AuthoritativeEnd
+    );
+
+    let mut selected = Vec::new();
+    let deadline = tokio::time::Instant::now() + Duration::from_secs(1);
+    while let Ok(Some(event)) = tokio::time::timeout_at(deadline, events.next()).await {
+        if let DownloadEvent::SourceSelected { url, attempt, .. } = event {
+            selected.push((url.to_string(), attempt));
+        }
+    }
+
+    assert_eq!(received, ["header", "tag 0", "header", "tag 66"]);
+    assert_eq!(
+        selected,
+        vec![
+            (format!("{first_base}/stream.flv"), 1),
+            (format!("{second_base}/stream.flv"), 2),
+        ]
+    );
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn mesio_downloader_rejects_unimplemented_flv_reconnect_modes() {
     let downloader = MesioDownloader::new(MesioConfig::default());
@@ -975,6 +1142,7 @@ async fn mesio_downloader_rejects_unimplemented_flv_reconnect_modes() {
         .expect("valid URL")
         .with_protocol(ProtocolSelection::Flv(mesio_engine::FlvRequestOptions {
             reconnect: mesio_engine::FlvReconnect::ReconnectSameSourceWithDiscontinuity,
+            ..Default::default()
         }));
 
     let err = match downloader.start_flv(request).await {
@@ -994,7 +1162,8 @@ async fn mesio_downloader_rejects_flv_reconnect_from_request_options() {
     let downloader = MesioDownloader::new(MesioConfig::default());
     let mut request =
         DownloadRequest::from_url("http://127.0.0.1:9/stream.flv").expect("valid URL");
-    request.options.flv.reconnect = mesio_engine::FlvReconnect::SwitchSourceWithDiscontinuity;
+    request.options.flv.reconnect =
+        mesio_engine::FlvReconnect::ReconnectSameSourceWithDiscontinuity;
 
     let err = match downloader.start_flv(request).await {
         Ok(_) => panic!("unsupported reconnect mode must be rejected before network I/O"),
@@ -1157,3 +1326,139 @@ async fn hls_download_handle_join_reports_authoritative_end() {
         DownloadTerminal::AuthoritativeEnd
     );
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn mesio_downloader_records_static_dash_manifest() {
+    use futures::StreamExt;
+    use mesio_engine::DownloaderSession;
+
+    let origin = Origin::new();
+    origin.add_file(
+        "vod/manifest.mpd",
+        r#"<MPD type="static" mediaPresentationDuration="PT4S">
+             <Period>
+               <AdaptationSet contentType="video">
+                 <SegmentTemplate initialization="$RepresentationID$/init.mp4"
+                                  media="$RepresentationID$/$Number$.m4s" duration="2"/>
+                 <Representation id="low" bandwidth="100000"/>
+                 <Representation id="high" bandwidth="900000"/>
+               </AdaptationSet>
+             </Period>
+           </MPD>"#,
+    );
+    origin.add_file("vod/high/init.mp4", b"init".to_vec());
+    origin.add_file("vod/high/1.m4s", b"one".to_vec());
+    origin.add_file_failing("vod/high/2.m4s", b"two".to_vec(), 503, 1);
+    let base = origin.clone().serve().await;
+
+    let downloader = MesioDownloader::new(MesioConfig::default());
+    let request =
+        DownloadRequest::from_url(&format!("{base}/vod/manifest.mpd")).expect("valid URL");
+    let Ok(DownloaderSession::Dash(session)) = downloader.start(request).await else {
+        panic!("expected a DASH session");
+    };
+    let handle = session.handle.clone();
+    let items: Vec<_> = tokio::time::timeout(Duration::from_secs(15), session.items.collect())
+        .await
+        .expect("download finishes");
+
+    let mut received = Vec::new();
+    for item in items {
+        match item.expect("no stream error") {
+            hls::HlsData::EndMarker(reason) => received.push(format!("end {reason:?}")),
+            data => received.push(String::from_utf8_lossy(data.data().unwrap()).to_string()),
+        }
+    }
+    assert_eq!(received, ["init", "one", "two", "end Some(EndOfStream)"]);
+    assert_eq!(origin.hits("vod/high/2.m4s"), 2);
+    assert_eq!(origin.hits("vod/low/1.m4s"), 0);
+    assert_eq!(
+        handle.join().await.expect("join handle").expect("join ok"),
+        DownloadTerminal::AuthoritativeEnd
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+// The error type of handshake callbacks is set by tungstenite
+#[allow(clippy::result_large_err)]
+async fn flv_downloader_reassembles_websocket_messages() {
+    use futures::{SinkExt, StreamExt};
+    use mesio_engine::DownloaderConfig;
+    use mesio_engine::flv::FlvDownloader;
+    use tokio_tungstenite::tungstenite::Message;
+    use tokio_tungstenite::tungstenite::handshake::server::{Request, Response};
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("bind");
+    let addr = listener.local_addr().expect("local addr");
+    let server = tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.expect("accept");
+        let mut seen = None;
+        let mut socket =
+            tokio_tungstenite::accept_hdr_async(stream, |request: &Request, response| {
+                let referer = request.headers().get("referer").cloned();
+                seen = Some((request.uri().to_string(), referer));
+                Ok::<Response, _>(response)
+            })
+            .await
+            .expect("handshake");
+
+        // Tags split across messages at arbitrary points
+        let body = flv_body(&[0, 40, 80]);
+        socket
+            .send(Message::Binary(Bytes::copy_from_slice(&body[..5])))
+            .await
+            .unwrap();
+        socket.send(Message::text("keepalive")).await.unwrap();
+        socket
+            .send(Message::Binary(Bytes::copy_from_slice(&body[5..30])))
+            .await
+            .unwrap();
+        socket
+            .send(Message::Binary(Bytes::copy_from_slice(&body[30..])))
+            .await
+            .unwrap();
+        socket.close(None).await.unwrap();
+        seen.expect("request seen")
+    });
+
+    let mut headers = reqwest::header::HeaderMap::new();
+    headers.insert("referer", "https://live.example.test/".parse().unwrap());
+    let config = FlvProtocolConfig {
+        base: DownloaderConfig {
+            headers,
+            params: vec![("key".to_string(), "abc".to_string())],
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let downloader = FlvDownloader::with_config(config).expect("downloader builds");
+    let request = DownloadRequest::from_url(&format!("ws://{addr}/live/room?token=1"))
+        .expect("valid URL")
+        .with_protocol(ProtocolSelection::Flv(Default::default()));
+    let session = downloader
+        .start_session(request)
+        .await
+        .expect("download starts");
+    let items: Vec<_> = tokio::time::timeout(Duration::from_secs(15), session.items.collect())
+        .await
+        .expect("stream ends");
+
+    let received: Vec<_> = items
+        .into_iter()
+        .map(|item| match item.expect("no stream error") {
+            FlvData::Header(_) => "header".to_string(),
+            FlvData::Tag(tag) => format!("tag {}", tag.timestamp_ms),
+            other => panic!("unexpected item {other:?}"),
+        })
+        .collect();
+    assert_eq!(received, ["header", "tag 0", "tag 40", "tag 80"]);
+
+    let (uri, referer) = server.await.expect("server task");
+    assert_eq!(uri, "/live/room?token=1&key=abc");
+    assert_eq!(
+        referer.expect("referer header"),
+        "https://live.example.test/"
+    );
+}
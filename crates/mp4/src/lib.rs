@@ -1,7 +1,9 @@
 mod box_utils;
 pub mod fragment;
 pub mod isobmff;
+pub mod samples;
 #[cfg(any(test, feature = "test-utils"))]
 pub mod test_support;
+pub mod timing;
 
 pub use media_types::Resolution;
@@ -0,0 +1,526 @@
+//! fMP4 sample extraction.
+//!
+//! This module reads the codec configuration of each track from an init
+//! segment (`avcC`, `hvcC`, or the AAC `AudioSpecificConfig` inside `esds`)
+//! and splits media segments into individual samples (`trun` entries resolved
+//! against `tfhd`/`trex` defaults and sliced out of `mdat`), so samples can be
+//! re-muxed into another container.
+
+use std::io;
+
+use bytes::Bytes;
+
+use crate::box_utils::{BoxView, box_at, find_first_box};
+use crate::timing::parse_track_timings;
+
+const TFHD_BASE_DATA_OFFSET: u32 = 0x000001;
+const TFHD_SAMPLE_DESCRIPTION_INDEX: u32 = 0x000002;
+const TFHD_DEFAULT_SAMPLE_DURATION: u32 = 0x000008;
+const TFHD_DEFAULT_SAMPLE_SIZE: u32 = 0x000010;
+const TFHD_DEFAULT_SAMPLE_FLAGS: u32 = 0x000020;
+
+const TRUN_DATA_OFFSET: u32 = 0x000001;
+const TRUN_FIRST_SAMPLE_FLAGS: u32 = 0x000004;
+const TRUN_SAMPLE_DURATION: u32 = 0x000100;
+const TRUN_SAMPLE_SIZE: u32 = 0x000200;
+const TRUN_SAMPLE_FLAGS: u32 = 0x000400;
+const TRUN_SAMPLE_CTO: u32 = 0x000800;
+
+/// `sample_is_non_sync_sample` bit of the sample flags.
+const SAMPLE_IS_NON_SYNC: u32 = 0x0001_0000;
+
+/// Bytes of a `VisualSampleEntry` body before its child boxes (ISO 14496-12).
+const VISUAL_SAMPLE_ENTRY_HEADER: usize = 78;
+/// Bytes of an `AudioSampleEntry` body before its child boxes: 6 reserved +
+/// 2 data_ref_idx + 8 reserved + 2 channels + 2 sample size + 4 reserved +
+/// 4 sample rate.
+const AUDIO_SAMPLE_ENTRY_HEADER: usize = 28;
+
+/// Codec configuration of a track, as found in its sample entry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SampleCodec {
+    /// `avcC` payload (`AVCDecoderConfigurationRecord`)
+    Avc(Bytes),
+    /// `hvcC` payload (`HEVCDecoderConfigurationRecord`)
+    Hevc(Bytes),
+    /// AAC `AudioSpecificConfig` from the `esds` decoder specific info
+    Aac(Bytes),
+}
+
+/// A track of an init segment with what is needed to extract its samples.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SampleTrack {
+    pub track_id: u32,
+    /// Media timescale (`mdhd`), in ticks per second.
+    pub timescale: u32,
+    /// `None` for sample entries other than AVC, HEVC and AAC.
+    pub codec: Option<SampleCodec>,
+    default_sample_duration: u32,
+    default_sample_size: u32,
+    default_sample_flags: u32,
+}
+
+/// One sample of a media segment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FragmentSample {
+    pub track_id: u32,
+    /// Decode time in the track's timescale.
+    pub decode_time: u64,
+    /// Composition time offset in the track's timescale.
+    pub composition_offset: i64,
+    pub duration: u32,
+    pub is_sync: bool,
+    pub data: Bytes,
+}
+
+fn invalid(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
+
+fn read_u32(body: &[u8], at: usize) -> Option<u32> {
+    let bytes = body.get(at..at + 4)?;
+    Some(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+fn read_u64(body: &[u8], at: usize) -> Option<u64> {
+    let high = read_u32(body, at)?;
+    let low = read_u32(body, at + 4)?;
+    Some((u64::from(high) << 32) | u64::from(low))
+}
+
+/// Iterate the direct children of `[start..end)`.
+fn children(data: &Bytes, start: usize, end: usize) -> impl Iterator<Item = BoxView> + '_ {
+    let mut offset = start;
+    std::iter::from_fn(move || {
+        let parsed = box_at(data, offset, end)?;
+        offset = parsed.end;
+        Some(parsed)
+    })
+}
+
+/// Find a box by path of FourCCs below `[start..end)`.
+fn find_path(data: &Bytes, start: usize, end: usize, path: &[[u8; 4]]) -> Option<BoxView> {
+    let (first, rest) = path.split_first()?;
+    let found = find_first_box(data, start, end, *first)?;
+    if rest.is_empty() {
+        Some(found)
+    } else {
+        find_path(data, found.body_start, found.end, rest)
+    }
+}
+
+/// Read a descriptor header of an `esds` box: returns `(tag, body_start, body_end)`.
+fn descriptor_at(body: &[u8], offset: usize) -> Option<(u8, usize, usize)> {
+    let tag = *body.get(offset)?;
+    let mut size = 0usize;
+    let mut idx = offset + 1;
+    for _ in 0..4 {
+        let byte = *body.get(idx)?;
+        idx += 1;
+        size = (size << 7) | usize::from(byte & 0x7F);
+        if byte & 0x80 == 0 {
+            break;
+        }
+    }
+    (idx + size <= body.len()).then_some((tag, idx, idx + size))
+}
+
+/// Extract the `AudioSpecificConfig` from an `esds` box body.
+fn audio_specific_config(esds: &Bytes) -> Option<Bytes> {
+    // Full box header, then ES_Descriptor (tag 0x03)
+    let (tag, start, end) = descriptor_at(esds, 4)?;
+    if tag != 0x03 {
+        return None;
+    }
+    let flags = *esds.get(start + 2)?;
+    let mut offset = start + 3;
+    if flags & 0x80 != 0 {
+        offset += 2;
+    }
+    if flags & 0x40 != 0 {
+        offset += 1 + usize::from(*esds.get(offset)?);
+    }
+    if flags & 0x20 != 0 {
+        offset += 2;
+    }
+    // DecoderConfigDescriptor (tag 0x04), then DecoderSpecificInfo (tag 0x05)
+    let (tag, start, dcd_end) = descriptor_at(esds, offset)?;
+    if tag != 0x04 || dcd_end > end {
+        return None;
+    }
+    let (tag, start, end) = descriptor_at(esds, start + 13)?;
+    (tag == 0x05 && end <= dcd_end).then(|| esds.slice(start..end))
+}
+
+fn sample_codec(data: &Bytes, trak: &BoxView) -> Option<SampleCodec> {
+    let stsd = find_path(
+        data,
+        trak.body_start,
+        trak.end,
+        &[*b"mdia", *b"minf", *b"stbl", *b"stsd"],
+    )?;
+    // Full box header + entry_count, then the first sample entry
+    let entry = box_at(data, stsd.body_start + 8, stsd.end)?;
+    let child = |header: usize, fourcc: [u8; 4]| {
+        let start = entry.body_start + header;
+        (start < entry.end)
+            .then(|| find_first_box(data, start, entry.end, fourcc))
+            .flatten()
+            .map(|found| data.slice(found.body_start..found.body_end))
+    };
+    match &entry.fourcc {
+        b"avc1" | b"avc3" => child(VISUAL_SAMPLE_ENTRY_HEADER, *b"avcC").map(SampleCodec::Avc),
+        b"hvc1" | b"hev1" => child(VISUAL_SAMPLE_ENTRY_HEADER, *b"hvcC").map(SampleCodec::Hevc),
+        b"mp4a" => child(AUDIO_SAMPLE_ENTRY_HEADER, *b"esds")
+            .and_then(|esds| audio_specific_config(&esds))
+            .map(SampleCodec::Aac),
+        _ => None,
+    }
+}
+
+/// Read the tracks of an init segment (`moov`) with their codec configuration.
+///
+/// Tracks without a usable `tkhd`/`mdhd` are skipped.
+pub fn parse_sample_tracks(init: &Bytes) -> Vec<SampleTrack> {
+    let timings = parse_track_timings(init);
+    let mut tracks = Vec::new();
+    let mut trex_defaults = Vec::new();
+
+    for moov in children(init, 0, init.len()).filter(|b| b.fourcc == *b"moov") {
+        for child in children(init, moov.body_start, moov.end) {
+            match &child.fourcc {
+                b"trak" => {
+                    let Some(tkhd) = find_first_box(init, child.body_start, child.end, *b"tkhd")
+                    else {
+                        continue;
+                    };
+                    let tkhd = &init[tkhd.body_start..tkhd.body_end];
+                    let track_id = match tkhd.first() {
+                        Some(1) => read_u32(tkhd, 20),
+                        _ => read_u32(tkhd, 12),
+                    };
+                    let Some(timing) = track_id.and_then(|track_id| {
+                        timings.iter().find(|timing| timing.track_id == track_id)
+                    }) else {
+                        continue;
+                    };
+                    tracks.push(SampleTrack {
+                        track_id: timing.track_id,
+                        timescale: timing.timescale,
+                        codec: sample_codec(init, &child),
+                        default_sample_duration: timing.default_sample_duration,
+                        default_sample_size: 0,
+                        default_sample_flags: 0,
+                    });
+                }
+                b"mvex" => {
+                    for trex in
+                        children(init, child.body_start, child.end).filter(|b| b.fourcc == *b"trex")
+                    {
+                        let body = &init[trex.body_start..trex.body_end];
+                        if let (Some(track_id), Some(size), Some(flags)) =
+                            (read_u32(body, 4), read_u32(body, 16), read_u32(body, 20))
+                        {
+                            trex_defaults.push((track_id, size, flags));
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    for track in &mut tracks {
+        if let Some((_, size, flags)) = trex_defaults
+            .iter()
+            .find(|(track_id, _, _)| *track_id == track.track_id)
+        {
+            track.default_sample_size = *size;
+            track.default_sample_flags = *flags;
+        }
+    }
+    tracks
+}
+
+fn parse_traf(
+    data: &Bytes,
+    moof: &BoxView,
+    traf: &BoxView,
+    tracks: &[SampleTrack],
+    samples: &mut Vec<FragmentSample>,
+) -> io::Result<()> {
+    let tfhd = find_first_box(data, traf.body_start, traf.end, *b"tfhd")
+        .ok_or_else(|| invalid("traf without tfhd"))?;
+    let tfhd = &data[tfhd.body_start..tfhd.body_end];
+    let too_short = || invalid("tfhd box too short");
+    let tfhd_flags = read_u32(tfhd, 0).ok_or_else(too_short)? & 0x00FF_FFFF;
+    let track_id = read_u32(tfhd, 4).ok_or_else(too_short)?;
+    let Some(track) = tracks.iter().find(|track| track.track_id == track_id) else {
+        return Ok(());
+    };
+
+    let mut idx = 8;
+    let mut base_offset = moof.start;
+    if tfhd_flags & TFHD_BASE_DATA_OFFSET != 0 {
+        base_offset = read_u64(tfhd, idx).ok_or_else(too_short)? as usize;
+        idx += 8;
+    }
+    if tfhd_flags & TFHD_SAMPLE_DESCRIPTION_INDEX != 0 {
+        idx += 4;
+    }
+    let mut default = |flag: u32, fallback: u32| -> io::Result<u32> {
+        if tfhd_flags & flag == 0 {
+            return Ok(fallback);
+        }
+        let value = read_u32(tfhd, idx).ok_or_else(too_short)?;
+        idx += 4;
+        Ok(value)
+    };
+    let default_duration = default(TFHD_DEFAULT_SAMPLE_DURATION, track.default_sample_duration)?;
+    let default_size = default(TFHD_DEFAULT_SAMPLE_SIZE, track.default_sample_size)?;
+    let default_flags = default(TFHD_DEFAULT_SAMPLE_FLAGS, track.default_sample_flags)?;
+
+    let mut decode_time = None;
+    let mut data_offset = base_offset;
+    for child in children(data, traf.body_start, traf.end) {
+        let body = &data[child.body_start..child.body_end];
+        match &child.fourcc {
+            b"tfdt" => {
+                decode_time = if body.first() == Some(&1) {
+                    read_u64(body, 4)
+                } else {
+                    read_u32(body, 4).map(u64::from)
+                };
+            }
+            b"trun" => {
+                let too_short = || invalid("trun box too short");
+                let flags = read_u32(body, 0).ok_or_else(too_short)? & 0x00FF_FFFF;
+                let sample_count = read_u32(body, 4).ok_or_else(too_short)?;
+                let mut idx = 8;
+                if flags & TRUN_DATA_OFFSET != 0 {
+                    let offset = read_u32(body, idx).ok_or_else(too_short)? as i32;
+                    data_offset = base_offset
+                        .checked_add_signed(offset as isize)
+                        .ok_or_else(|| invalid("trun data offset out of range"))?;
+                    idx += 4;
+                }
+                let mut first_flags = None;
+                if flags & TRUN_FIRST_SAMPLE_FLAGS != 0 {
+                    first_flags = Some(read_u32(body, idx).ok_or_else(too_short)?);
+                    idx += 4;
+                }
+
+                let mut time = decode_time.unwrap_or(0);
+                for sample in 0..sample_count {
+                    let mut field = |flag: u32, fallback: u32| -> io::Result<u32> {
+                        if flags & flag == 0 {
+                            return Ok(fallback);
+                        }
+                        let value = read_u32(body, idx).ok_or_else(too_short)?;
+                        idx += 4;
+                        Ok(value)
+                    };
+                    let duration = field(TRUN_SAMPLE_DURATION, default_duration)?;
+                    let size = field(TRUN_SAMPLE_SIZE, default_size)? as usize;
+                    let sample_flags = field(TRUN_SAMPLE_FLAGS, default_flags)?;
+                    let cto = field(TRUN_SAMPLE_CTO, 0)?;
+                    let sample_flags = match first_flags {
+                        Some(first) if sample == 0 => first,
+                        _ => sample_flags,
+                    };
+
+                    let end = data_offset + size;
+                    if end > data.len() {
+                        return Err(invalid("sample data overflows the segment"));
+                    }
+                    samples.push(FragmentSample {
+                        track_id,
+                        decode_time: time,
+                        // Version 1 runs carry signed offsets; version 0 offsets stay below 2^31.
+                        composition_offset: i64::from(cto as i32),
+                        duration,
+                        is_sync: sample_flags & SAMPLE_IS_NON_SYNC == 0,
+                        data: data.slice(data_offset..end),
+                    });
+                    data_offset = end;
+                    time += u64::from(duration);
+                }
+                decode_time = Some(time);
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Split a media segment into samples, in `trun` order.
+///
+/// Samples of tracks missing from `tracks` are skipped; pass the result of
+/// [`parse_sample_tracks`] for the segment's init segment.
+pub fn parse_fragment_samples(
+    media: &Bytes,
+    tracks: &[SampleTrack],
+) -> io::Result<Vec<FragmentSample>> {
+    let mut samples = Vec::new();
+    for moof in children(media, 0, media.len()).filter(|b| b.fourcc == *b"moof") {
+        for traf in children(media, moof.body_start, moof.end).filter(|b| b.fourcc == *b"traf") {
+            parse_traf(media, &moof, &traf, tracks, &mut samples)?;
+        }
+    }
+    Ok(samples)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{make_box, make_full_box};
+
+    fn trak(track_id: u32, timescale: u32, sample_entry: Vec<u8>) -> Vec<u8> {
+        let mut tkhd = vec![0u8; 8];
+        tkhd.extend_from_slice(&track_id.to_be_bytes());
+        tkhd.extend_from_slice(&[0u8; 4]);
+        let mut mdhd = vec![0u8; 8];
+        mdhd.extend_from_slice(&timescale.to_be_bytes());
+        mdhd.extend_from_slice(&[0u8; 8]);
+        let mut stsd = 1u32.to_be_bytes().to_vec();
+        stsd.extend_from_slice(&sample_entry);
+        let stbl = make_box(b"stbl", &make_full_box(b"stsd", 0, 0, &stsd));
+        make_box(
+            b"trak",
+            &[
+                make_full_box(b"tkhd", 0, 0, &tkhd),
+                make_box(
+                    b"mdia",
+                    &[
+                        make_full_box(b"mdhd", 0, 0, &mdhd),
+                        make_box(b"minf", &stbl),
+                    ]
+                    .concat(),
+                ),
+            ]
+            .concat(),
+        )
+    }
+
+    fn init() -> Bytes {
+        let avc1 = make_box(
+            b"avc1",
+            &[
+                vec![0u8; VISUAL_SAMPLE_ENTRY_HEADER],
+                make_box(b"avcC", &[1, 0x64, 0, 0x28]),
+            ]
+            .concat(),
+        );
+        // ES_Descriptor > DecoderConfigDescriptor > DecoderSpecificInfo
+        let mut esds = vec![0x03, 22, 0x00, 0x01, 0x00, 0x04, 17, 0x40, 0x15];
+        esds.extend_from_slice(&[0u8; 11]);
+        esds.extend_from_slice(&[0x05, 2, 0x12, 0x10]);
+        let mp4a = make_box(
+            b"mp4a",
+            &[
+                vec![0u8; AUDIO_SAMPLE_ENTRY_HEADER],
+                make_full_box(b"esds", 0, 0, &esds),
+            ]
+            .concat(),
+        );
+        let mut trex = 2u32.to_be_bytes().to_vec();
+        trex.extend_from_slice(&[0, 0, 0, 1, 0, 0, 4, 0, 0, 0, 4, 0, 0, 0, 0, 0]);
+        Bytes::from(make_box(
+            b"moov",
+            &[
+                trak(1, 90_000, avc1),
+                trak(2, 48_000, mp4a),
+                make_box(b"mvex", &make_full_box(b"trex", 0, 0, &trex)),
+            ]
+            .concat(),
+        ))
+    }
+
+    #[test]
+    fn reads_codec_configuration_per_track() {
+        let tracks = parse_sample_tracks(&init());
+        assert_eq!(tracks.len(), 2);
+        assert_eq!(
+            tracks[0].codec,
+            Some(SampleCodec::Avc(Bytes::from_static(&[1, 0x64, 0, 0x28])))
+        );
+        assert_eq!(
+            tracks[1].codec,
+            Some(SampleCodec::Aac(Bytes::from_static(&[0x12, 0x10])))
+        );
+        assert_eq!(tracks[1].timescale, 48_000);
+        assert_eq!(tracks[1].default_sample_duration, 1024);
+    }
+
+    #[test]
+    fn splits_media_segment_into_samples() {
+        let tracks = parse_sample_tracks(&init());
+
+        // Video: two samples with explicit duration/size/flags/cto.
+        let video_tfhd = make_full_box(b"tfhd", 0, 0x020000, &1u32.to_be_bytes());
+        let video_tfdt = make_full_box(b"tfdt", 1, 0, &9_000u64.to_be_bytes());
+        let mut video_trun = 2u32.to_be_bytes().to_vec();
+        video_trun.extend_from_slice(&[0u8; 4]); // data offset, patched below
+        for (size, flags, cto) in [(3u32, 0x0200_0000u32, 3_000u32), (2, 0x0101_0000, 0)] {
+            video_trun.extend_from_slice(&3_000u32.to_be_bytes());
+            video_trun.extend_from_slice(&size.to_be_bytes());
+            video_trun.extend_from_slice(&flags.to_be_bytes());
+            video_trun.extend_from_slice(&cto.to_be_bytes());
+        }
+        // Audio: one sample using trex defaults for duration and size.
+        let audio_tfhd = make_full_box(b"tfhd", 0, 0x020000, &2u32.to_be_bytes());
+        let audio_tfdt = make_full_box(b"tfdt", 0, 0, &4_800u32.to_be_bytes());
+        let mut audio_trun = 1u32.to_be_bytes().to_vec();
+        audio_trun.extend_from_slice(&[0u8; 4]);
+
+        let build = |video_offset: u32, audio_offset: u32| {
+            let mut video_trun = video_trun.clone();
+            video_trun[4..8].copy_from_slice(&video_offset.to_be_bytes());
+            let mut audio_trun = audio_trun.clone();
+            audio_trun[4..8].copy_from_slice(&audio_offset.to_be_bytes());
+            make_box(
+                b"moof",
+                &[
+                    make_box(
+                        b"traf",
+                        &[
+                            video_tfhd.clone(),
+                            video_tfdt.clone(),
+                            make_full_box(b"trun", 0, 0x000F01, &video_trun),
+                        ]
+                        .concat(),
+                    ),
+                    make_box(
+                        b"traf",
+                        &[
+                            audio_tfhd.clone(),
+                            audio_tfdt.clone(),
+                            make_full_box(b"trun", 0, 0x000001, &audio_trun),
+                        ]
+                        .concat(),
+                    ),
+                ]
+                .concat(),
+            )
+        };
+        let moof_size = build(0, 0).len() as u32;
+        let mut audio_payload = vec![0xAAu8; 4];
+        audio_payload.resize(1024, 0xAA);
+        let mdat = make_box(b"mdat", &[&[1u8, 2, 3, 4, 5][..], &audio_payload].concat());
+        let media = Bytes::from([build(moof_size + 8, moof_size + 13), mdat].concat());
+
+        let samples = parse_fragment_samples(&media, &tracks).unwrap();
+        assert_eq!(samples.len(), 3);
+        assert_eq!(samples[0].decode_time, 9_000);
+        assert_eq!(samples[0].composition_offset, 3_000);
+        assert!(samples[0].is_sync);
+        assert_eq!(samples[0].data, Bytes::from_static(&[1, 2, 3]));
+        assert_eq!(samples[1].decode_time, 12_000);
+        assert!(!samples[1].is_sync);
+        assert_eq!(samples[1].data, Bytes::from_static(&[4, 5]));
+        assert_eq!(samples[2].track_id, 2);
+        assert_eq!(samples[2].decode_time, 4_800);
+        assert_eq!(samples[2].duration, 1024);
+        assert_eq!(samples[2].data.len(), 1024);
+    }
+}
@@ -0,0 +1,452 @@
+//! fMP4 decode-time helpers.
+//!
+//! This module reads per-track timescales from an init segment (`mdhd`, plus
+//! `trex` sample duration defaults), measures the decode time span of each
+//! track in a media segment (`tfdt` + `trun`), and rewrites `tfdt` base media
+//! decode times in place so fragments from different sessions can be stitched
+//! into one continuous timeline.
+
+use std::io;
+
+use bytes::Bytes;
+
+use crate::box_utils::{BoxView, box_at, find_first_box};
+
+const TFHD_BASE_DATA_OFFSET: u32 = 0x000001;
+const TFHD_SAMPLE_DESCRIPTION_INDEX: u32 = 0x000002;
+const TFHD_DEFAULT_SAMPLE_DURATION: u32 = 0x000008;
+
+const TRUN_DATA_OFFSET: u32 = 0x000001;
+const TRUN_FIRST_SAMPLE_FLAGS: u32 = 0x000004;
+const TRUN_SAMPLE_DURATION: u32 = 0x000100;
+const TRUN_SAMPLE_SIZE: u32 = 0x000200;
+const TRUN_SAMPLE_FLAGS: u32 = 0x000400;
+const TRUN_SAMPLE_CTO: u32 = 0x000800;
+
+/// Timing parameters of one track, from an init segment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TrackTiming {
+    pub track_id: u32,
+    /// Media timescale (`mdhd`), in ticks per second.
+    pub timescale: u32,
+    /// Fallback sample duration from `trex`, 0 when the init has none.
+    pub default_sample_duration: u32,
+}
+
+/// Decode time span of one track within a media segment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FragmentTiming {
+    pub track_id: u32,
+    /// `tfdt` of the track's first fragment in the segment.
+    pub base_media_decode_time: u64,
+    /// Sum of the track's sample durations in the segment, in timescale ticks.
+    pub duration: u64,
+}
+
+fn invalid(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
+
+fn read_u32(body: &[u8], at: usize) -> Option<u32> {
+    let bytes = body.get(at..at + 4)?;
+    Some(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+fn read_u64(body: &[u8], at: usize) -> Option<u64> {
+    let high = read_u32(body, at)?;
+    let low = read_u32(body, at + 4)?;
+    Some((u64::from(high) << 32) | u64::from(low))
+}
+
+fn full_box_flags(body: &[u8]) -> Option<u32> {
+    read_u32(body, 0).map(|word| word & 0x00FF_FFFF)
+}
+
+/// Iterate the direct children of `[start..end)`.
+fn children(data: &Bytes, start: usize, end: usize) -> impl Iterator<Item = BoxView> + '_ {
+    let mut offset = start;
+    std::iter::from_fn(move || {
+        let parsed = box_at(data, offset, end)?;
+        offset = parsed.end;
+        Some(parsed)
+    })
+}
+
+fn track_timescale(data: &Bytes, trak_start: usize, trak_end: usize) -> Option<(u32, u32)> {
+    let tkhd = find_first_box(data, trak_start, trak_end, *b"tkhd")?;
+    let tkhd = &data[tkhd.body_start..tkhd.body_end];
+    let track_id = match tkhd.first()? {
+        1 => read_u32(tkhd, 20)?,
+        _ => read_u32(tkhd, 12)?,
+    };
+
+    let mdia = find_first_box(data, trak_start, trak_end, *b"mdia")?;
+    let mdhd = find_first_box(data, mdia.body_start, mdia.body_end, *b"mdhd")?;
+    let mdhd = &data[mdhd.body_start..mdhd.body_end];
+    let timescale = match mdhd.first()? {
+        1 => read_u32(mdhd, 20)?,
+        _ => read_u32(mdhd, 12)?,
+    };
+    (timescale > 0).then_some((track_id, timescale))
+}
+
+/// Read the timescale of every track in an init segment (`moov`).
+///
+/// Tracks without a usable `tkhd`/`mdhd` are skipped.
+pub fn parse_track_timings(init: &Bytes) -> Vec<TrackTiming> {
+    let mut tracks = Vec::new();
+    let mut trex_durations = Vec::new();
+
+    for top in children(init, 0, init.len()) {
+        if top.fourcc != *b"moov" {
+            continue;
+        }
+        for child in children(init, top.body_start, top.end) {
+            match &child.fourcc {
+                b"trak" => {
+                    if let Some((track_id, timescale)) =
+                        track_timescale(init, child.body_start, child.end)
+                    {
+                        tracks.push(TrackTiming {
+                            track_id,
+                            timescale,
+                            default_sample_duration: 0,
+                        });
+                    }
+                }
+                b"mvex" => {
+                    for trex in
+                        children(init, child.body_start, child.end).filter(|b| b.fourcc == *b"trex")
+                    {
+                        let body = &init[trex.body_start..trex.body_end];
+                        if let (Some(track_id), Some(duration)) =
+                            (read_u32(body, 4), read_u32(body, 12))
+                        {
+                            trex_durations.push((track_id, duration));
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    for track in &mut tracks {
+        if let Some((_, duration)) = trex_durations
+            .iter()
+            .find(|(track_id, _)| *track_id == track.track_id)
+        {
+            track.default_sample_duration = *duration;
+        }
+    }
+    tracks
+}
+
+/// Position of a `tfdt` value inside a media segment.
+struct TfdtField {
+    track_id: u32,
+    /// Offset of the decode time field (after the full box header).
+    offset: usize,
+    wide: bool,
+    value: u64,
+}
+
+struct TrafTiming {
+    tfdt: Option<TfdtField>,
+    track_id: u32,
+    duration: u64,
+}
+
+fn parse_traf(
+    data: &Bytes,
+    start: usize,
+    end: usize,
+    tracks: &[TrackTiming],
+) -> io::Result<TrafTiming> {
+    let tfhd =
+        find_first_box(data, start, end, *b"tfhd").ok_or_else(|| invalid("traf without tfhd"))?;
+    let tfhd_body = &data[tfhd.body_start..tfhd.body_end];
+    let tfhd_flags = full_box_flags(tfhd_body).ok_or_else(|| invalid("tfhd box too short"))?;
+    let track_id = read_u32(tfhd_body, 4).ok_or_else(|| invalid("tfhd box too short"))?;
+
+    let mut idx = 8;
+    if tfhd_flags & TFHD_BASE_DATA_OFFSET != 0 {
+        idx += 8;
+    }
+    if tfhd_flags & TFHD_SAMPLE_DESCRIPTION_INDEX != 0 {
+        idx += 4;
+    }
+    let default_duration = if tfhd_flags & TFHD_DEFAULT_SAMPLE_DURATION != 0 {
+        read_u32(tfhd_body, idx).ok_or_else(|| invalid("tfhd missing default_sample_duration"))?
+    } else {
+        tracks
+            .iter()
+            .find(|track| track.track_id == track_id)
+            .map_or(0, |track| track.default_sample_duration)
+    };
+
+    let mut tfdt = None;
+    let mut duration = 0u64;
+    for child in children(data, start, end) {
+        let body = &data[child.body_start..child.body_end];
+        match &child.fourcc {
+            b"tfdt" => {
+                let wide = body.first() == Some(&1);
+                let value = if wide {
+                    read_u64(body, 4)
+                } else {
+                    read_u32(body, 4).map(u64::from)
+                }
+                .ok_or_else(|| invalid("tfdt box too short"))?;
+                tfdt = Some(TfdtField {
+                    track_id,
+                    offset: child.body_start + 4,
+                    wide,
+                    value,
+                });
+            }
+            b"trun" => duration += trun_duration(body, default_duration)?,
+            _ => {}
+        }
+    }
+
+    Ok(TrafTiming {
+        tfdt,
+        track_id,
+        duration,
+    })
+}
+
+fn trun_duration(body: &[u8], default_duration: u32) -> io::Result<u64> {
+    let flags = full_box_flags(body).ok_or_else(|| invalid("trun box too short"))?;
+    let sample_count = read_u32(body, 4).ok_or_else(|| invalid("trun box too short"))?;
+    if flags & TRUN_SAMPLE_DURATION == 0 {
+        return Ok(u64::from(sample_count) * u64::from(default_duration));
+    }
+
+    let mut idx = 8;
+    if flags & TRUN_DATA_OFFSET != 0 {
+        idx += 4;
+    }
+    if flags & TRUN_FIRST_SAMPLE_FLAGS != 0 {
+        idx += 4;
+    }
+    let stride = [
+        TRUN_SAMPLE_DURATION,
+        TRUN_SAMPLE_SIZE,
+        TRUN_SAMPLE_FLAGS,
+        TRUN_SAMPLE_CTO,
+    ]
+    .iter()
+    .filter(|flag| flags & **flag != 0)
+    .count()
+        * 4;
+
+    let mut duration = 0u64;
+    for _ in 0..sample_count {
+        let sample =
+            read_u32(body, idx).ok_or_else(|| invalid("trun sample duration overflows box"))?;
+        duration += u64::from(sample);
+        idx += stride;
+    }
+    Ok(duration)
+}
+
+fn parse_trafs(data: &Bytes, tracks: &[TrackTiming]) -> io::Result<Vec<TrafTiming>> {
+    let mut trafs = Vec::new();
+    for top in children(data, 0, data.len()) {
+        if top.fourcc != *b"moof" {
+            continue;
+        }
+        for traf in children(data, top.body_start, top.end).filter(|b| b.fourcc == *b"traf") {
+            trafs.push(parse_traf(data, traf.body_start, traf.end, tracks)?);
+        }
+    }
+    Ok(trafs)
+}
+
+/// Measure the decode time span of each track in a media segment.
+///
+/// Tracks are reported in order of first appearance. Track fragments without
+/// a `tfdt` carry no absolute time and are not reported. `tracks` supplies the
+/// `trex` duration defaults; pass the result of [`parse_track_timings`].
+pub fn parse_fragment_timings(
+    media: &Bytes,
+    tracks: &[TrackTiming],
+) -> io::Result<Vec<FragmentTiming>> {
+    let mut timings: Vec<FragmentTiming> = Vec::new();
+    for traf in parse_trafs(media, tracks)? {
+        match timings.iter_mut().find(|t| t.track_id == traf.track_id) {
+            Some(timing) => timing.duration += traf.duration,
+            None => {
+                if let Some(tfdt) = traf.tfdt {
+                    timings.push(FragmentTiming {
+                        track_id: traf.track_id,
+                        base_media_decode_time: tfdt.value,
+                        duration: traf.duration,
+                    });
+                }
+            }
+        }
+    }
+    Ok(timings)
+}
+
+/// Rewrite every `tfdt` in a media segment with `map(track_id, decode_time)`.
+///
+/// Box sizes never change: a version 0 `tfdt` whose new value does not fit
+/// in 32 bits is an error, and the input is returned untouched when no value
+/// changes.
+pub fn rewrite_base_media_decode_times(
+    media: &Bytes,
+    mut map: impl FnMut(u32, u64) -> u64,
+) -> io::Result<Bytes> {
+    let fields: Vec<TfdtField> = parse_trafs(media, &[])?
+        .into_iter()
+        .filter_map(|traf| traf.tfdt)
+        .collect();
+
+    let mut out: Option<Vec<u8>> = None;
+    for field in fields {
+        let value = map(field.track_id, field.value);
+        if value == field.value {
+            continue;
+        }
+        let buffer = out.get_or_insert_with(|| media.to_vec());
+        if field.wide {
+            buffer[field.offset..field.offset + 8].copy_from_slice(&value.to_be_bytes());
+        } else {
+            let narrow = u32::try_from(value)
+                .map_err(|_| invalid("decode time does not fit a version 0 tfdt"))?;
+            buffer[field.offset..field.offset + 4].copy_from_slice(&narrow.to_be_bytes());
+        }
+    }
+    Ok(out.map_or_else(|| media.clone(), Bytes::from))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{make_box, make_full_box};
+
+    fn init(tracks: &[(u32, u32)], trex_duration: u32) -> Bytes {
+        let mut moov = Vec::new();
+        let mut mvex = Vec::new();
+        for &(track_id, timescale) in tracks {
+            let mut tkhd = vec![0u8; 8];
+            tkhd.extend_from_slice(&track_id.to_be_bytes());
+            tkhd.extend_from_slice(&[0u8; 4]);
+            let mut mdhd = vec![0u8; 8];
+            mdhd.extend_from_slice(&timescale.to_be_bytes());
+            mdhd.extend_from_slice(&[0u8; 8]);
+            let mdia = make_box(b"mdia", &make_full_box(b"mdhd", 0, 0, &mdhd));
+            let trak = make_box(
+                b"trak",
+                &[make_full_box(b"tkhd", 0, 0, &tkhd), mdia].concat(),
+            );
+            moov.extend(trak);
+
+            let mut trex = track_id.to_be_bytes().to_vec();
+            trex.extend_from_slice(&1u32.to_be_bytes());
+            trex.extend_from_slice(&trex_duration.to_be_bytes());
+            trex.extend_from_slice(&[0u8; 8]);
+            mvex.extend(make_full_box(b"trex", 0, 0, &trex));
+        }
+        moov.extend(make_box(b"mvex", &mvex));
+        Bytes::from(make_box(b"moov", &moov))
+    }
+
+    fn traf(track_id: u32, tfdt_version: u8, decode_time: u64, durations: &[u32]) -> Vec<u8> {
+        let tfhd = make_full_box(b"tfhd", 0, 0x020000, &track_id.to_be_bytes());
+        let tfdt = if tfdt_version == 1 {
+            make_full_box(b"tfdt", 1, 0, &decode_time.to_be_bytes())
+        } else {
+            make_full_box(b"tfdt", 0, 0, &(decode_time as u32).to_be_bytes())
+        };
+        let trun = if durations.is_empty() {
+            make_full_box(b"trun", 0, 0, &3u32.to_be_bytes())
+        } else {
+            let mut body = (durations.len() as u32).to_be_bytes().to_vec();
+            for duration in durations {
+                body.extend_from_slice(&duration.to_be_bytes());
+                body.extend_from_slice(&100u32.to_be_bytes());
+            }
+            make_full_box(b"trun", 0, TRUN_SAMPLE_DURATION | TRUN_SAMPLE_SIZE, &body)
+        };
+        make_box(b"traf", &[tfhd, tfdt, trun].concat())
+    }
+
+    fn media(trafs: &[Vec<u8>]) -> Bytes {
+        let moof = make_box(b"moof", &trafs.concat());
+        Bytes::from([moof, make_box(b"mdat", &[0u8; 16])].concat())
+    }
+
+    #[test]
+    fn reads_timescales_and_trex_defaults() {
+        let tracks = parse_track_timings(&init(&[(1, 90_000), (2, 48_000)], 1024));
+        assert_eq!(
+            tracks,
+            vec![
+                TrackTiming {
+                    track_id: 1,
+                    timescale: 90_000,
+                    default_sample_duration: 1024,
+                },
+                TrackTiming {
+                    track_id: 2,
+                    timescale: 48_000,
+                    default_sample_duration: 1024,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn measures_fragment_spans_per_track() {
+        let tracks = parse_track_timings(&init(&[(1, 90_000), (2, 48_000)], 1024));
+        let segment = media(&[
+            traf(1, 1, 900_000, &[3000, 3000, 3000]),
+            traf(2, 0, 480_000, &[]),
+        ]);
+
+        let timings = parse_fragment_timings(&segment, &tracks).unwrap();
+        assert_eq!(
+            timings,
+            vec![
+                FragmentTiming {
+                    track_id: 1,
+                    base_media_decode_time: 900_000,
+                    duration: 9000,
+                },
+                FragmentTiming {
+                    track_id: 2,
+                    base_media_decode_time: 480_000,
+                    duration: 3 * 1024,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn rewrites_decode_times_in_place() {
+        let segment = media(&[traf(1, 1, 900_000, &[3000]), traf(2, 0, 480_000, &[])]);
+        let rewritten = rewrite_base_media_decode_times(&segment, |track_id, time| {
+            time - u64::from(track_id) * 1000
+        })
+        .unwrap();
+        assert_eq!(rewritten.len(), segment.len());
+
+        let timings = parse_fragment_timings(&rewritten, &[]).unwrap();
+        assert_eq!(timings[0].base_media_decode_time, 899_000);
+        assert_eq!(timings[1].base_media_decode_time, 478_000);
+
+        let unchanged = rewrite_base_media_decode_times(&segment, |_, time| time).unwrap();
+        assert_eq!(unchanged, segment);
+    }
+
+    #[test]
+    fn narrow_tfdt_overflow_is_rejected() {
+        let segment = media(&[traf(1, 0, 10, &[3000])]);
+        assert!(rewrite_base_media_decode_times(&segment, |_, _| u64::from(u32::MAX) + 1).is_err());
+    }
+}
@@ -256,110 +256,176 @@ pub struct SpawnedPipeline<T> {
     pub tasks: Vec<JoinHandle<Result<(), PipelineError>>>,
 }
 
-pub fn spawn_pipeline<T>(mut pipeline: Pipeline<T>, spec: ChannelSpec<T>) -> SpawnedPipeline<T>
+/// Spawn `pipeline` on blocking tasks, one per stage (see `Pipeline::add_stage_boundary`).
+///
+/// Stages are connected by channels with the same capacity and byte budget as the pipeline's
+/// input and output.
+pub fn spawn_pipeline<T>(pipeline: Pipeline<T>, spec: ChannelSpec<T>) -> SpawnedPipeline<T>
 where
     T: Send + 'static,
 {
     let batch_items = spec.batch_items();
     let output_capacity = spec.capacity.div_ceil(batch_items).max(1);
     let input_limiter = spec.byte_limiter();
-    let output_limiter = spec.byte_limiter();
     let runtime = Handle::current();
-    let (input_tx, mut input_rx) = mpsc::channel::<BudgetedMessage<T>>(spec.capacity);
+    let (input_tx, input_rx) = mpsc::channel::<BudgetedMessage<T>>(spec.capacity);
+
+    let (leading_stages, last_stage) = pipeline.into_stages();
+    let mut tasks = Vec::with_capacity(leading_stages.len() + 1);
+    let mut stage_input = StageInput::Messages(input_rx);
+    let stage_output = |tx, is_last| StageOutput {
+        tx,
+        limiter: spec.byte_limiter(),
+        runtime: runtime.clone(),
+        batch_items,
+        is_last,
+    };
+
+    for stage in leading_stages {
+        let (output_tx, output_rx) = mpsc::channel::<OutputBatch<T>>(output_capacity);
+        let input = std::mem::replace(&mut stage_input, StageInput::Batches(output_rx));
+        let output = stage_output(output_tx, false);
+        tasks.push(tokio::task::spawn_blocking(move || {
+            run_stage(stage, input, output)
+        }));
+    }
+
     let (output_tx, output_rx) = mpsc::channel::<OutputBatch<T>>(output_capacity);
+    let output = stage_output(output_tx, true);
+    tasks.push(tokio::task::spawn_blocking(move || {
+        run_stage(last_stage, stage_input, output)
+    }));
+
+    // Downstream stages first: when a stage fails, the stages feeding it only see a closed
+    // channel, and callers usually report the first error they find.
+    tasks.reverse();
+
+    SpawnedPipeline {
+        input_tx: PipelineSender::new(input_tx, input_limiter),
+        output_rx: PipelineReceiver::batched(output_rx),
+        tasks,
+    }
+}
 
-    let task = tokio::task::spawn_blocking(move || {
-        let mut inputs = Vec::with_capacity(batch_items);
-        let mut input_permits = Vec::with_capacity(batch_items);
-
-        while let Some(first) = input_rx.blocking_recv() {
-            let (item, permit) = first.into_parts();
-            inputs.push(item);
-            input_permits.extend(permit);
-            while inputs.len() < batch_items {
-                match input_rx.try_recv() {
-                    Ok(message) => {
-                        let (item, permit) = message.into_parts();
-                        inputs.push(item);
-                        input_permits.extend(permit);
-                    }
-                    Err(mpsc::error::TryRecvError::Empty) => break,
-                    Err(mpsc::error::TryRecvError::Disconnected) => break,
+enum StageInput<T> {
+    Messages(mpsc::Receiver<BudgetedMessage<T>>),
+    Batches(mpsc::Receiver<OutputBatch<T>>),
+}
+
+impl<T> StageInput<T> {
+    /// Wait for input, then take what is already queued, up to `max_items` for single
+    /// messages. Returns `false` once the input is closed and drained.
+    fn blocking_recv_batch(
+        &mut self,
+        max_items: usize,
+        inputs: &mut Vec<Result<T, PipelineError>>,
+        permits: &mut Vec<OwnedSemaphorePermit>,
+    ) -> bool {
+        match self {
+            Self::Messages(rx) => {
+                let Some(first) = rx.blocking_recv() else {
+                    return false;
+                };
+                let (item, permit) = first.into_parts();
+                inputs.push(item);
+                permits.extend(permit);
+                while inputs.len() < max_items {
+                    let Ok(message) = rx.try_recv() else {
+                        break;
+                    };
+                    let (item, permit) = message.into_parts();
+                    inputs.push(item);
+                    permits.extend(permit);
                 }
+                true
+            }
+            Self::Batches(rx) => {
+                let Some(batch) = rx.blocking_recv() else {
+                    return false;
+                };
+                inputs.extend(batch.items);
+                permits.extend(batch.permit);
+                true
             }
+        }
+    }
+}
 
-            let mut outputs = Vec::with_capacity(inputs.len());
-            let process_result = pipeline.process_items(inputs.drain(..), &mut |item| {
-                outputs.push(item);
-            });
-            input_permits.clear();
+struct StageOutput<T> {
+    tx: mpsc::Sender<OutputBatch<T>>,
+    limiter: Option<ByteLimiter<T>>,
+    runtime: Handle,
+    batch_items: usize,
+    is_last: bool,
+}
 
-            if let Err(source) = process_result {
-                if matches!(source, PipelineError::Cancelled) {
-                    return Err(PipelineError::Cancelled);
-                }
+impl<T> StageOutput<T> {
+    fn send(
+        &self,
+        outputs: Vec<Result<T, PipelineError>>,
+        finishing: bool,
+    ) -> Result<(), PipelineError> {
+        let channel_name = match (self.is_last, finishing) {
+            (true, false) => "pipeline output",
+            (true, true) => "pipeline output during finish",
+            (false, false) => "pipeline stage output",
+            (false, true) => "pipeline stage output during finish",
+        };
+        send_output_items(
+            &self.tx,
+            self.limiter.as_ref(),
+            &self.runtime,
+            self.batch_items,
+            outputs,
+            channel_name,
+        )
+    }
+}
 
-                let message = source.to_string();
-                outputs.push(Err(source));
-                send_output_items(
-                    &output_tx,
-                    output_limiter.as_ref(),
-                    &runtime,
-                    batch_items,
-                    outputs,
-                    "pipeline output",
-                )?;
-                error!(error = %message, "Pipeline processing failed");
-                return Err(stage_process_error(message));
-            }
+fn run_stage<T>(
+    mut pipeline: Pipeline<T>,
+    mut input: StageInput<T>,
+    output: StageOutput<T>,
+) -> Result<(), PipelineError> {
+    let mut inputs = Vec::with_capacity(output.batch_items);
+    let mut input_permits = Vec::with_capacity(output.batch_items);
 
-            send_output_items(
-                &output_tx,
-                output_limiter.as_ref(),
-                &runtime,
-                batch_items,
-                outputs,
-                "pipeline output",
-            )?;
-        }
+    while input.blocking_recv_batch(output.batch_items, &mut inputs, &mut input_permits) {
+        let mut outputs = Vec::with_capacity(inputs.len());
+        let process_result = pipeline.process_items(inputs.drain(..), &mut |item| {
+            outputs.push(item);
+        });
+        input_permits.clear();
 
-        let mut outputs = Vec::new();
-        if let Err(source) = pipeline.finalize_processors(&mut |item| outputs.push(item)) {
+        if let Err(source) = process_result {
             if matches!(source, PipelineError::Cancelled) {
                 return Err(PipelineError::Cancelled);
             }
 
             let message = source.to_string();
             outputs.push(Err(source));
-            send_output_items(
-                &output_tx,
-                output_limiter.as_ref(),
-                &runtime,
-                batch_items,
-                outputs,
-                "pipeline output during finish",
-            )?;
-            error!(error = %message, "Pipeline finalization failed");
-            return Err(stage_finish_error(message));
+            output.send(outputs, false)?;
+            error!(error = %message, "Pipeline processing failed");
+            return Err(stage_process_error(message));
         }
 
-        send_output_items(
-            &output_tx,
-            output_limiter.as_ref(),
-            &runtime,
-            batch_items,
-            outputs,
-            "pipeline output during finish",
-        )?;
+        output.send(outputs, false)?;
+    }
 
-        Ok(())
-    });
+    let mut outputs = Vec::new();
+    if let Err(source) = pipeline.finalize_processors(&mut |item| outputs.push(item)) {
+        if matches!(source, PipelineError::Cancelled) {
+            return Err(PipelineError::Cancelled);
+        }
 
-    SpawnedPipeline {
-        input_tx: PipelineSender::new(input_tx, input_limiter),
-        output_rx: PipelineReceiver::batched(output_rx),
-        tasks: vec![task],
+        let message = source.to_string();
+        outputs.push(Err(source));
+        output.send(outputs, true)?;
+        error!(error = %message, "Pipeline finalization failed");
+        return Err(stage_finish_error(message));
     }
+
+    output.send(outputs, true)
 }
 
 fn send_output_items<T>(
@@ -609,6 +675,73 @@ mod tests {
         assert_eq!(counter.load(Ordering::SeqCst), 3);
     }
 
+    #[tokio::test]
+    async fn spawned_pipeline_runs_each_stage_on_its_own_task() {
+        let context = StreamerContext::arc_new(CancellationToken::new());
+        let counter = Arc::new(AtomicUsize::new(0));
+        let pipeline = Pipeline::new(context)
+            .add_processor(TestProcessor::new(counter.clone()))
+            .add_stage_boundary()
+            .add_processor(TestProcessor::new(counter.clone()));
+        let SpawnedPipeline {
+            input_tx,
+            mut output_rx,
+            tasks,
+        } = spawn_pipeline(pipeline, ChannelSpec::items(8).with_max_batch_items(2));
+
+        assert_eq!(tasks.len(), 2);
+        for item in ["one", "two", "three"] {
+            input_tx.send(Ok(item.to_string())).await.unwrap();
+        }
+        drop(input_tx);
+
+        let mut output = Vec::new();
+        while let Some(item) = output_rx.recv().await {
+            output.push(item.unwrap());
+        }
+        for task in tasks {
+            task.await.unwrap().unwrap();
+        }
+
+        assert_eq!(
+            output,
+            [
+                "one-processed-processed",
+                "two-processed-processed",
+                "three-processed-processed"
+            ]
+        );
+        assert_eq!(counter.load(Ordering::SeqCst), 6);
+    }
+
+    #[tokio::test]
+    async fn failing_downstream_stage_is_reported_first() {
+        let context = StreamerContext::arc_new(CancellationToken::new());
+        let counter = Arc::new(AtomicUsize::new(0));
+        let pipeline = Pipeline::new(context)
+            .add_processor(TestProcessor::new(counter))
+            .add_stage_boundary()
+            .add_processor(FailingProcessor);
+        let SpawnedPipeline {
+            input_tx,
+            mut output_rx,
+            tasks,
+        } = spawn_pipeline(pipeline, ChannelSpec::items(8));
+
+        input_tx.send(Ok("one".to_string())).await.unwrap();
+        drop(input_tx);
+        while output_rx.recv().await.is_some() {}
+
+        let mut results = Vec::new();
+        for task in tasks {
+            results.push(task.await.unwrap());
+        }
+        assert!(matches!(
+            results[0],
+            Err(PipelineError::StageProcess { .. })
+        ));
+    }
+
     #[tokio::test]
     async fn byte_budget_allows_an_item_larger_than_the_budget() {
         let context = StreamerContext::arc_new(CancellationToken::new());
@@ -8,6 +8,9 @@ pub struct PipelineConfig {
     /// Maximum duration limit
     pub max_duration: Option<Duration>,
 
+    /// Split at every multiple of this interval since local midnight
+    pub wall_clock_split: Option<Duration>,
+
     /// Size of internal processing channels
     pub channel_size: usize,
 }
@@ -17,6 +20,7 @@ impl Default for PipelineConfig {
         Self {
             max_file_size: 0,
             max_duration: None,
+            wall_clock_split: None,
             channel_size: 64,
         }
     }
@@ -35,10 +39,15 @@ impl Display for PipelineConfig {
             None => "unlimited".to_string(),
         };
 
+        let wall_clock_split_display = match self.wall_clock_split {
+            Some(interval) => format!("{}s", interval.as_secs()),
+            None => "off".to_string(),
+        };
+
         write!(
             f,
-            "PipelineConfig {{ max_file_size: {}, max_duration: {}, channel_size: {} }}",
-            max_size_display, max_duration_display, self.channel_size
+            "PipelineConfig {{ max_file_size: {}, max_duration: {}, wall_clock_split: {}, channel_size: {} }}",
+            max_size_display, max_duration_display, wall_clock_split_display, self.channel_size
         )
     }
 }
@@ -72,6 +81,11 @@ impl PipelineConfigBuilder {
         self
     }
 
+    pub fn wall_clock_split(mut self, interval: Duration) -> Self {
+        self.config.wall_clock_split = Some(interval);
+        self
+    }
+
     pub fn channel_size(mut self, channel_size: usize) -> Self {
         self.config.channel_size = channel_size;
         self
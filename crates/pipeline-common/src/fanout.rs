@@ -0,0 +1,254 @@
+//! # Fan-out and fan-in
+//!
+//! [`Pipeline::tee`](crate::Pipeline::tee) copies every item reaching a point of the pipeline
+//! to a branch receiver, e.g. repaired FLV tags to both the writer and a live-preview remuxer.
+//! [`merge_receivers`] does the opposite and interleaves several receivers into one.
+//!
+//! ## Backpressure
+//!
+//! A branch is a bounded channel. What happens when it is full is chosen per branch with
+//! [`Backpressure`]: either the pipeline waits for the branch, so the slowest consumer sets the
+//! pace of the whole pipeline, or the branch misses the item and the pipeline carries on.
+//! A branch whose receiver is dropped is detached in both cases; the pipeline never fails
+//! because of a branch.
+//!
+//! A merged receiver forwards with bounded channels too: while it is full, no input is read
+//! any further, so each input's producer is throttled by its own channel.
+//!
+
+use std::sync::Arc;
+
+use tokio::sync::mpsc;
+use tracing::debug;
+
+use crate::{PipelineError, PipelineReceiver, Processor, StreamerContext};
+
+/// What a tee does when its branch is full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Backpressure {
+    /// Wait until the branch has room. The pipeline runs no faster than the branch consumer.
+    ///
+    /// The wait blocks the current thread, so the pipeline must run on a blocking task, as
+    /// `spawn_pipeline` does.
+    #[default]
+    Block,
+    /// Skip the item for the branch. Suits consumers that can tolerate gaps, such as previews.
+    DropNewest,
+}
+
+/// Passes items through unchanged and sends a copy of each to a branch.
+///
+/// The branch is closed when the processor finishes or is dropped.
+pub struct TeeProcessor<T> {
+    branch: Option<mpsc::Sender<Result<T, PipelineError>>>,
+    backpressure: Backpressure,
+    dropped_items: u64,
+}
+
+impl<T> TeeProcessor<T> {
+    /// Create a tee and the receiver of its branch, which holds up to `capacity` items.
+    pub fn new(capacity: usize, backpressure: Backpressure) -> (Self, PipelineReceiver<T>) {
+        let (tx, rx) = mpsc::channel(capacity.max(1));
+        let tee = Self {
+            branch: Some(tx),
+            backpressure,
+            dropped_items: 0,
+        };
+        (tee, PipelineReceiver::from_items(rx))
+    }
+
+    /// Items the branch missed because it was full.
+    pub fn dropped_items(&self) -> u64 {
+        self.dropped_items
+    }
+
+    fn send_to_branch(&mut self, item: &T)
+    where
+        T: Clone,
+    {
+        let Some(branch) = &self.branch else {
+            return;
+        };
+        let closed = match self.backpressure {
+            Backpressure::Block => branch.blocking_send(Ok(item.clone())).is_err(),
+            Backpressure::DropNewest => match branch.try_send(Ok(item.clone())) {
+                Ok(()) => false,
+                Err(mpsc::error::TrySendError::Full(_)) => {
+                    self.dropped_items += 1;
+                    false
+                }
+                Err(mpsc::error::TrySendError::Closed(_)) => true,
+            },
+        };
+        if closed {
+            debug!("Tee branch receiver dropped, detaching the branch");
+            self.branch = None;
+        }
+    }
+}
+
+impl<T: Clone> Processor<T> for TeeProcessor<T> {
+    fn process(
+        &mut self,
+        _context: &Arc<StreamerContext>,
+        input: T,
+        output: &mut dyn FnMut(T) -> Result<(), PipelineError>,
+    ) -> Result<(), PipelineError> {
+        self.send_to_branch(&input);
+        output(input)
+    }
+
+    fn finish(
+        &mut self,
+        _context: &Arc<StreamerContext>,
+        _output: &mut dyn FnMut(T) -> Result<(), PipelineError>,
+    ) -> Result<(), PipelineError> {
+        if self.dropped_items > 0 {
+            debug!(
+                dropped_items = self.dropped_items,
+                "Tee branch missed items while full"
+            );
+        }
+        self.branch = None;
+        Ok(())
+    }
+
+    fn name(&self) -> &'static str {
+        "TeeProcessor"
+    }
+}
+
+/// Interleave `receivers` into one receiver holding up to `capacity` items.
+///
+/// Items of one input keep their order; items of different inputs are forwarded as they
+/// arrive. The merged receiver ends once every input has ended. Dropping it stops the
+/// forwarding and drops the inputs.
+///
+/// Must be called within a Tokio runtime.
+pub fn merge_receivers<T>(
+    receivers: Vec<PipelineReceiver<T>>,
+    capacity: usize,
+) -> PipelineReceiver<T>
+where
+    T: Send + 'static,
+{
+    let (tx, rx) = mpsc::channel(capacity.max(1));
+    for mut receiver in receivers {
+        let tx = tx.clone();
+        tokio::spawn(async move {
+            while let Some(item) = receiver.recv().await {
+                if tx.send(item).await.is_err() {
+                    break;
+                }
+            }
+        });
+    }
+    PipelineReceiver::from_items(rx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CancellationToken, ChannelSpec, Pipeline, spawn_pipeline};
+
+    async fn drain<T>(mut receiver: PipelineReceiver<T>) -> Vec<T> {
+        let mut items = Vec::new();
+        while let Some(item) = receiver.recv().await {
+            items.push(item.unwrap());
+        }
+        items
+    }
+
+    async fn run_tee(
+        backpressure: Backpressure,
+        capacity: usize,
+        read_branch: bool,
+    ) -> (Vec<u32>, Vec<u32>) {
+        let context = Arc::new(StreamerContext::new(CancellationToken::new()));
+        let (pipeline, branch) = Pipeline::new(context).tee(capacity, backpressure);
+        let branch_task = if read_branch {
+            Some(tokio::spawn(drain(branch)))
+        } else {
+            drop(branch);
+            None
+        };
+
+        let spawned = spawn_pipeline(pipeline, ChannelSpec::items(4));
+        let output_task = tokio::spawn(drain(spawned.output_rx));
+        for item in 0..20 {
+            spawned.input_tx.send(Ok(item)).await.unwrap();
+        }
+        drop(spawned.input_tx);
+        for task in spawned.tasks {
+            task.await.unwrap().unwrap();
+        }
+
+        let branch = match branch_task {
+            Some(task) => task.await.unwrap(),
+            None => Vec::new(),
+        };
+        (output_task.await.unwrap(), branch)
+    }
+
+    #[tokio::test]
+    async fn tee_blocking_branch_gets_every_item() {
+        let (output, branch) = run_tee(Backpressure::Block, 1, true).await;
+        let expected: Vec<u32> = (0..20).collect();
+        assert_eq!(output, expected);
+        assert_eq!(branch, expected);
+
+        // A branch nobody reads is detached instead of stalling the pipeline
+        let (output, _) = run_tee(Backpressure::Block, 1, false).await;
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn tee_drop_newest_skips_items_while_full() {
+        let context = Arc::new(StreamerContext::new(CancellationToken::new()));
+        let (mut tee, mut branch) = TeeProcessor::new(2, Backpressure::DropNewest);
+        let mut output = Vec::new();
+        for item in 0..5u32 {
+            tee.process(&context, item, &mut |item| {
+                output.push(item);
+                Ok(())
+            })
+            .unwrap();
+        }
+        tee.finish(&context, &mut |_| Ok(())).unwrap();
+
+        assert_eq!(output, vec![0, 1, 2, 3, 4]);
+        assert_eq!(tee.dropped_items(), 3);
+        assert_eq!(branch.blocking_recv().unwrap().unwrap(), 0);
+        assert_eq!(branch.blocking_recv().unwrap().unwrap(), 1);
+        // Closed by finish
+        assert!(branch.blocking_recv().is_none());
+    }
+
+    #[tokio::test]
+    async fn merge_keeps_order_per_input() {
+        let (first_tx, first_rx) = mpsc::channel(1);
+        let (second_tx, second_rx) = mpsc::channel(1);
+        let merged = merge_receivers(
+            vec![
+                PipelineReceiver::from_items(first_rx),
+                PipelineReceiver::from_items(second_rx),
+            ],
+            1,
+        );
+        let merged_task = tokio::spawn(drain(merged));
+
+        for item in 0..10u32 {
+            first_tx.send(Ok(item)).await.unwrap();
+            second_tx.send(Ok(100 + item)).await.unwrap();
+        }
+        drop(first_tx);
+        drop(second_tx);
+
+        let merged = merged_task.await.unwrap();
+        assert_eq!(merged.len(), 20);
+        let first: Vec<u32> = merged.iter().copied().filter(|item| *item < 100).collect();
+        let second: Vec<u32> = merged.iter().copied().filter(|item| *item >= 100).collect();
+        assert_eq!(first, (0..10).collect::<Vec<_>>());
+        assert_eq!(second, (100..110).collect::<Vec<_>>());
+    }
+}
@@ -8,6 +8,7 @@
 //!
 //! - Generic `Processor<T>` trait for processing any type of data
 //! - Generic `Pipeline<T>` implementation for chaining processors
+//! - Tee and merge combinators for fanning a pipeline out and in
 //! - Common error types and context sharing utilities
 //!
 //! ## License
@@ -27,12 +28,16 @@ pub mod cancellation;
 pub mod channel_pipeline;
 pub mod config;
 mod context;
+pub mod fanout;
+pub mod metrics;
+mod output_sink;
 pub mod pipeline;
 pub mod processor;
 pub mod progress;
 mod run_completion;
 pub mod split_reason;
 mod utils;
+pub mod wall_clock;
 mod writer_task;
 
 /// Re-export key traits and types
@@ -40,6 +45,9 @@ pub use channel_pipeline::{
     ChannelSpec, PipelineReceiver, PipelineSender, SpawnedPipeline, spawn_pipeline,
 };
 pub use context::StreamerContext;
+pub use fanout::{Backpressure, TeeProcessor, merge_receivers};
+pub use metrics::{ProcessorMetrics, ProcessorMetricsSink};
+pub use output_sink::{AsyncSink, OutputSink, SinkFactory};
 pub use pipeline::{Pipeline, ProgressSink, ProgressThrottle};
 pub use processor::Processor;
 pub use progress::{Progress, ProgressEvent};
@@ -54,6 +62,7 @@ pub use writer_task::{
 };
 
 pub use split_reason::{AudioCodecInfo, SplitReason, VideoCodecInfo};
+pub use wall_clock::WallClockBoundary;
 
 use crate::config::PipelineConfig;
 pub use cancellation::CancellationToken;
@@ -0,0 +1,194 @@
+//! # Per-processor metrics
+//!
+//! A [`Pipeline`](crate::Pipeline) can count, for each of its processors, the items and bytes
+//! going in and out, the time spent in the processor and the items it swallowed. Counting is
+//! opt-in through [`Pipeline::with_metrics_sink`](crate::Pipeline::with_metrics_sink); the
+//! totals are handed to a [`ProcessorMetricsSink`], which can feed a metrics backend.
+//!
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::{PipelineError, Processor, StreamerContext, pipeline::ProgressThrottle};
+
+/// Cumulative counters of one processor of a pipeline.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProcessorMetrics {
+    /// Position of the processor in the pipeline, across stages
+    pub index: usize,
+    /// [`Processor::name`]
+    pub name: &'static str,
+    pub items_in: u64,
+    pub items_out: u64,
+    pub bytes_in: u64,
+    pub bytes_out: u64,
+    /// Inputs that produced no output when processed. Processors that buffer count the
+    /// items they hold back here too, and emit them later as extra outputs.
+    pub dropped_items: u64,
+    /// Time spent in `process` and `finish`
+    pub processing_time: Duration,
+}
+
+impl ProcessorMetrics {
+    fn new(index: usize, name: &'static str) -> Self {
+        Self {
+            index,
+            name,
+            items_in: 0,
+            items_out: 0,
+            bytes_in: 0,
+            bytes_out: 0,
+            dropped_items: 0,
+            processing_time: Duration::ZERO,
+        }
+    }
+}
+
+/// Receives the per-processor totals of a pipeline.
+///
+/// A pipeline spawned in several stages reports each stage separately, with the processors of
+/// that stage only, so a sink is shared and must tell processors apart by
+/// [`ProcessorMetrics::index`].
+pub trait ProcessorMetricsSink: Send + Sync {
+    /// Cumulative totals, reported at the throttle given to the pipeline.
+    fn record(&self, metrics: &[ProcessorMetrics]);
+
+    /// Final totals, reported once when the pipeline (or stage) is dropped.
+    fn finished(&self, metrics: &[ProcessorMetrics]) {
+        self.record(metrics);
+    }
+}
+
+/// Counts items of a pipeline per processor and reports them to a sink.
+pub(crate) struct MetricsObserver<T> {
+    sink: Arc<dyn ProcessorMetricsSink>,
+    throttle: ProgressThrottle,
+    size_of: fn(&T) -> usize,
+    /// One entry per processor, created when the first item is counted
+    counters: Vec<ProcessorMetrics>,
+    /// Index of the first processor of this stage within the whole pipeline
+    first_index: usize,
+    next_item: usize,
+    last_update: Instant,
+}
+
+impl<T> MetricsObserver<T> {
+    pub(crate) fn new(
+        sink: Arc<dyn ProcessorMetricsSink>,
+        throttle: ProgressThrottle,
+        size_of: fn(&T) -> usize,
+    ) -> Self {
+        Self {
+            sink,
+            throttle,
+            size_of,
+            counters: Vec::new(),
+            first_index: 0,
+            next_item: throttle.item_interval(),
+            last_update: Instant::now(),
+        }
+    }
+
+    /// Create the counters of `processors` if they don't exist yet.
+    pub(crate) fn ensure_counters(&mut self, processors: &[Box<dyn Processor<T> + Send>]) {
+        for (offset, processor) in processors.iter().enumerate().skip(self.counters.len()) {
+            self.counters.push(ProcessorMetrics::new(
+                self.first_index + offset,
+                processor.name(),
+            ));
+        }
+    }
+
+    /// Move the counters of the processors from `at` on into a new observer sharing the sink.
+    pub(crate) fn split_off(&mut self, at: usize) -> Self {
+        let counters = self.counters.split_off(at.min(self.counters.len()));
+        Self {
+            sink: Arc::clone(&self.sink),
+            throttle: self.throttle,
+            size_of: self.size_of,
+            counters,
+            first_index: self.first_index + at,
+            next_item: self.throttle.item_interval(),
+            last_update: Instant::now(),
+        }
+    }
+
+    /// Run `processor` on `input`, counting it as processor `index` of this stage.
+    pub(crate) fn process(
+        &mut self,
+        index: usize,
+        processor: &mut (dyn Processor<T> + Send),
+        context: &Arc<StreamerContext>,
+        input: T,
+        outputs: &mut Vec<T>,
+    ) -> Result<(), PipelineError> {
+        let size_of = self.size_of;
+        let counters = &mut self.counters[index];
+        counters.items_in += 1;
+        counters.bytes_in += size_of(&input) as u64;
+
+        let produced_from = outputs.len();
+        let start = Instant::now();
+        let result = processor.process(context, input, &mut |item| {
+            outputs.push(item);
+            Ok(())
+        });
+        counters.processing_time += start.elapsed();
+
+        let produced = &outputs[produced_from..];
+        if produced.is_empty() {
+            counters.dropped_items += 1;
+        }
+        Self::count_outputs(counters, size_of, produced);
+        result
+    }
+
+    /// Run `finish` of `processor`, counting it as processor `index` of this stage.
+    pub(crate) fn finish(
+        &mut self,
+        index: usize,
+        processor: &mut (dyn Processor<T> + Send),
+        context: &Arc<StreamerContext>,
+        outputs: &mut Vec<T>,
+    ) -> Result<(), PipelineError> {
+        let size_of = self.size_of;
+        let counters = &mut self.counters[index];
+
+        let produced_from = outputs.len();
+        let start = Instant::now();
+        let result = processor.finish(context, &mut |item| {
+            outputs.push(item);
+            Ok(())
+        });
+        counters.processing_time += start.elapsed();
+
+        Self::count_outputs(counters, size_of, &outputs[produced_from..]);
+        result
+    }
+
+    fn count_outputs(counters: &mut ProcessorMetrics, size_of: fn(&T) -> usize, outputs: &[T]) {
+        counters.items_out += outputs.len() as u64;
+        counters.bytes_out += outputs.iter().map(|item| size_of(item) as u64).sum::<u64>();
+    }
+
+    /// Report the totals if the throttle allows it.
+    pub(crate) fn observe(&mut self, processed_items: usize) {
+        if processed_items < self.next_item
+            || self.last_update.elapsed() < self.throttle.min_interval()
+        {
+            return;
+        }
+
+        self.sink.record(&self.counters);
+        self.next_item = processed_items.saturating_add(self.throttle.item_interval());
+        self.last_update = Instant::now();
+    }
+}
+
+impl<T> Drop for MetricsObserver<T> {
+    fn drop(&mut self) {
+        if !self.counters.is_empty() {
+            self.sink.finished(&self.counters);
+        }
+    }
+}
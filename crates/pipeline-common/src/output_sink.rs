@@ -0,0 +1,179 @@
+//! # Output sinks
+//!
+//! Where a writer strategy sends the bytes of each output file: a file at the output path, or
+//! any `AsyncWrite` (a child process' stdin, an upload stream, ...) opened by a [`SinkFactory`].
+//!
+//! Writer tasks run on blocking threads, so async sinks are driven through
+//! [`SyncIoBridge`]. Rotation and limits are unchanged: every rotation opens a new sink for the
+//! next output path, which then only names the segment.
+//!
+//! Async sinks cannot seek. Strategies that patch headers after the fact (e.g. FLV metadata)
+//! should check [`OutputSink::is_seekable`] and skip the patch.
+
+use std::fmt;
+use std::fs::{File, OpenOptions};
+use std::io::{self, IoSlice, Seek, SeekFrom, Write};
+use std::path::Path;
+use std::sync::Arc;
+
+use tokio::io::AsyncWrite;
+use tokio::runtime::Handle;
+use tokio_util::io::SyncIoBridge;
+
+/// A boxed asynchronous output.
+pub type AsyncSink = Box<dyn AsyncWrite + Send + Unpin>;
+
+type OpenSinkFn = dyn Fn(&Path) -> io::Result<AsyncSink> + Send + Sync;
+
+/// Opens an [`AsyncSink`] for each output path.
+#[derive(Clone)]
+pub struct SinkFactory(Arc<OpenSinkFn>);
+
+impl SinkFactory {
+    pub fn new(factory: impl Fn(&Path) -> io::Result<AsyncSink> + Send + Sync + 'static) -> Self {
+        Self(Arc::new(factory))
+    }
+
+    pub fn open(&self, path: &Path) -> io::Result<AsyncSink> {
+        (self.0)(path)
+    }
+}
+
+impl fmt::Debug for SinkFactory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SinkFactory")
+    }
+}
+
+/// The output of one file written by a writer task.
+pub enum OutputSink {
+    File(File),
+    Async(SyncIoBridge<AsyncSink>),
+}
+
+impl OutputSink {
+    /// Create the file at `path`, or open a sink from `factory` when one is given.
+    ///
+    /// Async sinks must be opened from a thread with access to a Tokio runtime, such as a
+    /// `spawn_blocking` task.
+    pub fn create(path: &Path, factory: Option<&SinkFactory>) -> io::Result<Self> {
+        match factory {
+            None => OpenOptions::new()
+                .write(true)
+                .create(true)
+                .truncate(true)
+                .open(path)
+                .map(Self::File),
+            Some(factory) => {
+                let handle = Handle::try_current().map_err(io::Error::other)?;
+                let sink = factory.open(path)?;
+                Ok(Self::Async(SyncIoBridge::new_with_handle(sink, handle)))
+            }
+        }
+    }
+
+    pub fn is_seekable(&self) -> bool {
+        matches!(self, Self::File(_))
+    }
+
+    /// Flush the output and shut down async sinks, signalling the end of the file.
+    pub fn finish(&mut self) -> io::Result<()> {
+        match self {
+            Self::File(file) => file.flush(),
+            Self::Async(sink) => {
+                sink.flush()?;
+                sink.shutdown()
+            }
+        }
+    }
+}
+
+impl fmt::Debug for OutputSink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::File(file) => f.debug_tuple("File").field(file).finish(),
+            Self::Async(_) => f.write_str("Async"),
+        }
+    }
+}
+
+impl Write for OutputSink {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            Self::File(file) => file.write(buf),
+            Self::Async(sink) => sink.write(buf),
+        }
+    }
+
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+        match self {
+            Self::File(file) => file.write_vectored(bufs),
+            Self::Async(sink) => sink.write_vectored(bufs),
+        }
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        match self {
+            Self::File(file) => file.write_all(buf),
+            Self::Async(sink) => sink.write_all(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            Self::File(file) => file.flush(),
+            Self::Async(sink) => sink.flush(),
+        }
+    }
+}
+
+impl Seek for OutputSink {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        match self {
+            Self::File(file) => file.seek(pos),
+            Self::Async(_) => Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "async output sinks cannot seek",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn async_sink_receives_all_bytes_and_eof() {
+        let (client, mut server) = tokio::io::duplex(64);
+        let client = std::sync::Mutex::new(Some(client));
+        let factory = SinkFactory::new(move |_| {
+            client
+                .lock()
+                .map_err(|_| io::Error::other("poisoned"))?
+                .take()
+                .map(|client| Box::new(client) as AsyncSink)
+                .ok_or_else(|| io::Error::other("sink already opened"))
+        });
+
+        let reader = tokio::spawn(async move {
+            let mut received = Vec::new();
+            tokio::io::AsyncReadExt::read_to_end(&mut server, &mut received)
+                .await
+                .map(|_| received)
+        });
+
+        tokio::task::spawn_blocking(move || {
+            let mut sink = OutputSink::create(Path::new("segment.flv"), Some(&factory))?;
+            assert!(!sink.is_seekable());
+            assert!(sink.seek(SeekFrom::Start(0)).is_err());
+            sink.write_all(&[7; 200])?;
+            sink.finish()
+        })
+        .await
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(reader.await.unwrap().unwrap(), vec![7; 200]);
+    }
+}
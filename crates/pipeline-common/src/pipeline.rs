@@ -8,8 +8,16 @@
 //! Create a new `Pipeline<T>` and add processors that implement the `Processor<T>`
 //! trait. Then process a stream of data through the pipeline.
 //!
+//! ## Stages
+//!
+//! [`Pipeline::add_stage_boundary`] splits the processors into stages. `spawn_pipeline` runs
+//! each stage on its own blocking task, connected by bounded channels, so CPU-heavy processors
+//! can use another core. [`Pipeline::run`] ignores boundaries and runs everything inline.
+//!
 
-use crate::{PipelineError, Processor, StreamerContext};
+use crate::fanout::{Backpressure, TeeProcessor};
+use crate::metrics::{MetricsObserver, ProcessorMetricsSink};
+use crate::{PipelineError, PipelineReceiver, Processor, StreamerContext};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
@@ -37,6 +45,14 @@ impl ProgressThrottle {
     pub fn every_items(item_interval: usize) -> Self {
         Self::new(item_interval, Duration::ZERO)
     }
+
+    pub(crate) fn item_interval(&self) -> usize {
+        self.item_interval
+    }
+
+    pub(crate) fn min_interval(&self) -> Duration {
+        self.min_interval
+    }
 }
 
 struct ProgressObserver {
@@ -77,7 +93,10 @@ pub struct Pipeline<T> {
     processors: Vec<Box<dyn Processor<T> + Send>>,
     context: Arc<StreamerContext>,
     progress: Option<ProgressObserver>,
+    metrics: Option<MetricsObserver<T>>,
     processed_items: usize,
+    /// Indices of the processors that start a new stage.
+    stage_boundaries: Vec<usize>,
 }
 
 impl<T> Pipeline<T> {
@@ -87,7 +106,9 @@ impl<T> Pipeline<T> {
             processors: Vec::new(),
             context,
             progress: None,
+            metrics: None,
             processed_items: 0,
+            stage_boundaries: Vec::new(),
         }
     }
 
@@ -99,6 +120,69 @@ impl<T> Pipeline<T> {
         self
     }
 
+    /// Copy every item reaching this point of the pipeline to a branch, and return the
+    /// branch receiver, which holds up to `capacity` items.
+    ///
+    /// `backpressure` decides whether the pipeline waits for a full branch or skips the item
+    /// for it. The branch ends when the pipeline finishes. See [`crate::fanout`].
+    pub fn tee(self, capacity: usize, backpressure: Backpressure) -> (Self, PipelineReceiver<T>)
+    where
+        T: Clone + Send + 'static,
+    {
+        let (tee, branch) = TeeProcessor::new(capacity, backpressure);
+        (self.add_processor(tee), branch)
+    }
+
+    /// Start a new stage: processors added after this call run on a separate task when the
+    /// pipeline is spawned with `spawn_pipeline`.
+    ///
+    /// Boundaries at the start of the pipeline or next to another boundary are ignored.
+    pub fn add_stage_boundary(mut self) -> Self {
+        let index = self.processors.len();
+        if index > 0 && self.stage_boundaries.last() != Some(&index) {
+            self.stage_boundaries.push(index);
+        }
+        self
+    }
+
+    /// Number of stages `spawn_pipeline` runs this pipeline on.
+    pub fn stage_count(&self) -> usize {
+        1 + self
+            .stage_boundaries
+            .iter()
+            .filter(|&&index| index < self.processors.len())
+            .count()
+    }
+
+    /// Split the pipeline at its stage boundaries into the leading stages, in order, and the
+    /// last stage. Progress is reported by the first stage.
+    pub(crate) fn into_stages(mut self) -> (Vec<Pipeline<T>>, Pipeline<T>) {
+        if let Some(metrics) = &mut self.metrics {
+            metrics.ensure_counters(&self.processors);
+        }
+        // Collected last stage first
+        let mut stages = Vec::with_capacity(self.stage_count() - 1);
+        for index in std::mem::take(&mut self.stage_boundaries).into_iter().rev() {
+            if index >= self.processors.len() {
+                continue;
+            }
+            let mut stage = Pipeline::new(Arc::clone(&self.context));
+            stage.processors = self.processors.split_off(index);
+            stage.metrics = self
+                .metrics
+                .as_mut()
+                .map(|metrics| metrics.split_off(index));
+            stages.push(stage);
+        }
+        if stages.is_empty() {
+            return (stages, self);
+        }
+        let last = stages.remove(0);
+        stages.push(self);
+        stages.reverse();
+        (stages, last)
+    }
+
     pub fn with_progress_sink(
         mut self,
         sink: impl ProgressSink + 'static,
@@ -108,6 +192,20 @@ impl<T> Pipeline<T> {
         self
     }
 
+    /// Count items, bytes, processing time and dropped items per processor, and report the
+    /// totals to `sink` at `throttle` and when the pipeline is dropped.
+    ///
+    /// `size_of` gives the size in bytes of an item.
+    pub fn with_metrics_sink(
+        mut self,
+        sink: Arc<dyn ProcessorMetricsSink>,
+        throttle: ProgressThrottle,
+        size_of: fn(&T) -> usize,
+    ) -> Self {
+        self.metrics = Some(MetricsObserver::new(sink, throttle, size_of));
+        self
+    }
+
     /// Runs the pipeline, processing all input and then finalizing the processors.
     ///
     /// Takes an iterator of input data and a function to handle output data.
@@ -152,6 +250,9 @@ impl<T> Pipeline<T> {
         // Pre-allocate vectors for stage processing - these will be reused
         let mut current_stage_items: Vec<T> = Vec::with_capacity(DEFAULT_STAGE_CAPACITY);
         let mut next_stage_items: Vec<T> = Vec::with_capacity(DEFAULT_STAGE_CAPACITY);
+        if let Some(metrics) = &mut self.metrics {
+            metrics.ensure_counters(&self.processors);
+        }
 
         for item_result in input {
             // Check for cancellation before processing each input item
@@ -172,16 +273,27 @@ impl<T> Pipeline<T> {
 
                         // Process all items in current stage
                         for item_to_process in current_stage_items.drain(..) {
-                            let mut processor_output_handler = |processed_item: T| {
-                                next_stage_items.push(processed_item);
-                                Ok(())
+                            let result = if let Some(metrics) = &mut self.metrics {
+                                metrics.process(
+                                    processor_index,
+                                    processor.as_mut(),
+                                    &self.context,
+                                    item_to_process,
+                                    &mut next_stage_items,
+                                )
+                            } else {
+                                let mut processor_output_handler = |processed_item: T| {
+                                    next_stage_items.push(processed_item);
+                                    Ok(())
+                                };
+                                processor.process(
+                                    &self.context,
+                                    item_to_process,
+                                    &mut processor_output_handler,
+                                )
                             };
 
-                            if let Err(e) = processor.process(
-                                &self.context,
-                                item_to_process,
-                                &mut processor_output_handler,
-                            ) {
+                            if let Err(e) = result {
                                 // Enhanced error context
                                 tracing::error!(
                                     processor = processor.name(),
@@ -217,6 +329,9 @@ impl<T> Pipeline<T> {
             if let Some(progress) = &mut self.progress {
                 progress.observe(self.processed_items);
             }
+            if let Some(metrics) = &mut self.metrics {
+                metrics.observe(self.processed_items);
+            }
         }
 
         Ok(())
@@ -233,6 +348,9 @@ impl<T> Pipeline<T> {
         let mut items_for_subsequent: Vec<T> = Vec::with_capacity(DEFAULT_STAGE_CAPACITY);
         let mut next_stage_items: Vec<T> = Vec::with_capacity(DEFAULT_STAGE_CAPACITY);
         let mut final_flushed_outputs: Vec<T> = Vec::with_capacity(DEFAULT_STAGE_CAPACITY);
+        if let Some(metrics) = &mut self.metrics {
+            metrics.ensure_counters(&self.processors);
+        }
 
         for i in 0..self.processors.len() {
             // Split processors into current and subsequent
@@ -242,12 +360,22 @@ impl<T> Pipeline<T> {
 
             // Flush current processor
             items_flushed_by_current.clear();
-            let mut current_finish_handler = |flushed_item: T| {
-                items_flushed_by_current.push(flushed_item);
-                Ok(())
+            let result = if let Some(metrics) = &mut self.metrics {
+                metrics.finish(
+                    i,
+                    current_processor.as_mut(),
+                    &self.context,
+                    &mut items_flushed_by_current,
+                )
+            } else {
+                let mut current_finish_handler = |flushed_item: T| {
+                    items_flushed_by_current.push(flushed_item);
+                    Ok(())
+                };
+                current_processor.finish(&self.context, &mut current_finish_handler)
             };
 
-            if let Err(e) = current_processor.finish(&self.context, &mut current_finish_handler) {
+            if let Err(e) = result {
                 // Enhanced error context for finalization
                 tracing::error!(
                     processor = current_processor.name(),
@@ -268,16 +396,27 @@ impl<T> Pipeline<T> {
                 next_stage_items.clear();
 
                 for item_to_process in items_for_subsequent.drain(..) {
-                    let mut subsequent_process_handler = |processed_item: T| {
-                        next_stage_items.push(processed_item);
-                        Ok(())
+                    let result = if let Some(metrics) = &mut self.metrics {
+                        metrics.process(
+                            i + 1 + subsequent_index,
+                            subsequent_processor.as_mut(),
+                            &self.context,
+                            item_to_process,
+                            &mut next_stage_items,
+                        )
+                    } else {
+                        let mut subsequent_process_handler = |processed_item: T| {
+                            next_stage_items.push(processed_item);
+                            Ok(())
+                        };
+                        subsequent_processor.process(
+                            &self.context,
+                            item_to_process,
+                            &mut subsequent_process_handler,
+                        )
                     };
 
-                    if let Err(e) = subsequent_processor.process(
-                        &self.context,
-                        item_to_process,
-                        &mut subsequent_process_handler,
-                    ) {
+                    if let Err(e) = result {
                         tracing::error!(
                             processor = subsequent_processor.name(),
                             processor_index = i + 1 + subsequent_index,
@@ -374,6 +513,52 @@ mod tests {
         buffer: Vec<u32>,
     }
 
+    // Processor that drops odd inputs
+    struct EvenFilterProcessor;
+
+    impl Processor<u32> for EvenFilterProcessor {
+        fn process(
+            &mut self,
+            _context: &Arc<StreamerContext>,
+            input: u32,
+            output: &mut dyn FnMut(u32) -> Result<(), PipelineError>,
+        ) -> Result<(), PipelineError> {
+            if input.is_multiple_of(2) {
+                output(input)
+            } else {
+                Ok(())
+            }
+        }
+
+        fn finish(
+            &mut self,
+            _context: &Arc<StreamerContext>,
+            _output: &mut dyn FnMut(u32) -> Result<(), PipelineError>,
+        ) -> Result<(), PipelineError> {
+            Ok(())
+        }
+
+        fn name(&self) -> &'static str {
+            "EvenFilterProcessor"
+        }
+    }
+
+    #[derive(Default)]
+    struct RecordingMetricsSink {
+        records: Mutex<usize>,
+        finished: Mutex<Vec<crate::ProcessorMetrics>>,
+    }
+
+    impl ProcessorMetricsSink for RecordingMetricsSink {
+        fn record(&self, _metrics: &[crate::ProcessorMetrics]) {
+            *self.records.lock().unwrap() += 1;
+        }
+
+        fn finished(&self, metrics: &[crate::ProcessorMetrics]) {
+            self.finished.lock().unwrap().extend_from_slice(metrics);
+        }
+    }
+
     struct RecordingProgressSink(Arc<Mutex<Vec<usize>>>);
 
     impl ProgressSink for RecordingProgressSink {
@@ -509,6 +694,30 @@ mod tests {
         assert!(matches!(result, Err(PipelineError::Cancelled)));
     }
 
+    #[test]
+    fn stage_boundaries_split_processors() {
+        let context = StreamerContext::arc_new(CancellationToken::new());
+        let pipeline = Pipeline::new(context)
+            .add_stage_boundary()
+            .add_processor(IncrementProcessor)
+            .add_stage_boundary()
+            .add_stage_boundary()
+            .add_processor(IncrementProcessor)
+            .add_processor(IncrementProcessor)
+            .add_stage_boundary();
+        assert_eq!(pipeline.stage_count(), 2);
+
+        let (leading, last) = pipeline.into_stages();
+        assert_eq!(
+            leading
+                .iter()
+                .map(|stage| stage.processors.len())
+                .collect::<Vec<_>>(),
+            vec![1]
+        );
+        assert_eq!(last.processors.len(), 2);
+    }
+
     #[test]
     fn test_empty_pipeline() {
         let context = Arc::new(StreamerContext::new(CancellationToken::new()));
@@ -526,6 +735,57 @@ mod tests {
         assert_eq!(results, vec![1, 2, 3]);
     }
 
+    #[test]
+    fn metrics_sink_counts_per_processor() {
+        let context = StreamerContext::arc_new(CancellationToken::new());
+        let sink = Arc::new(RecordingMetricsSink::default());
+        let pipeline = Pipeline::new(context)
+            .add_processor(DuplicateProcessor)
+            .add_processor(BufferingProcessor::new())
+            .add_stage_boundary()
+            .add_processor(EvenFilterProcessor)
+            .with_metrics_sink(sink.clone(), ProgressThrottle::every_items(2), |_| 4);
+
+        let (mut leading, mut last) = pipeline.into_stages();
+        let mut first = leading.remove(0);
+        let mut flushed = Vec::new();
+        first
+            .process_items((1..=3).map(Ok::<_, PipelineError>), &mut |_| {})
+            .unwrap();
+        first
+            .finalize_processors::<_, PipelineError>(&mut |item| flushed.push(item.unwrap()))
+            .unwrap();
+        last.process_items(flushed.into_iter().map(Ok::<_, PipelineError>), &mut |_| {})
+            .unwrap();
+        // Every 2 items: once in the first stage, three times in the second
+        assert_eq!(*sink.records.lock().unwrap(), 4);
+        drop(first);
+        drop(last);
+
+        let finished = sink.finished.lock().unwrap();
+        let summary: Vec<_> = finished
+            .iter()
+            .map(|m| {
+                (
+                    m.index,
+                    m.name,
+                    m.items_in,
+                    m.items_out,
+                    m.dropped_items,
+                    m.bytes_out,
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                (0, "DuplicateProcessor", 3, 6, 0, 24),
+                (1, "BufferingProcessor", 6, 6, 6, 24),
+                (2, "EvenFilterProcessor", 6, 2, 4, 8),
+            ]
+        );
+    }
+
     #[test]
     fn progress_sink_is_opt_in_and_throttled() {
         let context = Arc::new(StreamerContext::new(CancellationToken::new()));
@@ -55,6 +55,8 @@ pub enum SplitReason {
     SizeLimit,
     /// Duration limit reached.
     DurationLimit,
+    /// A configured wall-clock boundary was crossed.
+    WallClock,
     /// A new FLV header arrived from upstream (stream restart/reconnect).
     HeaderReceived,
     /// Video resolution changed.
@@ -63,6 +65,12 @@ pub enum SplitReason {
     StreamStructureChange { description: String },
     /// HLS playlist discontinuity tag encountered.
     Discontinuity,
+    /// An ad break started (HLS ad markers, split mode).
+    AdBreakStart,
+    /// An ad break ended (HLS ad markers, split mode).
+    AdBreakEnd,
+    /// A user-provided split condition requested a new segment.
+    Requested { description: String },
     /// HLS playlist `#EXT-X-ENDLIST` tag encountered — authoritative end of
     /// the live stream. Surfaced as the final `HlsData::EndMarker` so the
     /// engine wrapper can promote the terminal event to
@@ -91,6 +99,7 @@ impl fmt::Display for SplitReason {
             }
             Self::SizeLimit => write!(f, "size limit"),
             Self::DurationLimit => write!(f, "duration limit"),
+            Self::WallClock => write!(f, "wall-clock boundary"),
             Self::HeaderReceived => write!(f, "header received"),
             Self::ResolutionChange { from, to } => {
                 write!(
@@ -103,6 +112,9 @@ impl fmt::Display for SplitReason {
                 write!(f, "stream structure change: {description}")
             }
             Self::Discontinuity => write!(f, "discontinuity"),
+            Self::AdBreakStart => write!(f, "ad break start"),
+            Self::AdBreakEnd => write!(f, "ad break end"),
+            Self::Requested { description } => write!(f, "requested split: {description}"),
             Self::EndOfStream => write!(f, "end of stream"),
         }
     }
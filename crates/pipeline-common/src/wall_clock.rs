@@ -0,0 +1,261 @@
+//! Wall-clock split boundaries.
+//!
+//! Splits at fixed times of day rather than after a fixed amount of media:
+//! boundaries fall on every multiple of the interval counted from local
+//! midnight, so a one-hour interval ends files on the hour and a 24-hour
+//! interval ends them at midnight. Intervals that do not divide a day evenly
+//! still restart at midnight.
+//!
+//! The local offset is looked up for each boundary from the system timezone
+//! (`TZ` or `/etc/localtime`), so boundaries follow daylight-saving changes
+//! while a recording runs.
+
+use std::fmt;
+use std::time::Duration;
+
+use chrono::{DateTime, Local};
+use time::{OffsetDateTime, Time, UtcOffset};
+
+const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+
+type OffsetAt = Box<dyn Fn(OffsetDateTime) -> UtcOffset + Send + Sync>;
+
+/// Local UTC offset in effect at `at`, or UTC when it cannot be determined.
+///
+/// Read through chrono, which unlike `time` can do so from any thread.
+fn local_offset_at(at: OffsetDateTime) -> UtcOffset {
+    DateTime::from_timestamp(at.unix_timestamp(), 0)
+        .map(|utc| utc.with_timezone(&Local).offset().local_minus_utc())
+        .and_then(|seconds| UtcOffset::from_whole_seconds(seconds).ok())
+        .unwrap_or(UtcOffset::UTC)
+}
+
+fn local_now() -> OffsetDateTime {
+    let now = OffsetDateTime::now_utc();
+    now.to_offset(local_offset_at(now))
+}
+
+/// Tracks the next wall-clock boundary of a recording.
+pub struct WallClockBoundary {
+    interval: Duration,
+    next_boundary: OffsetDateTime,
+    clock: Box<dyn Fn() -> OffsetDateTime + Send + Sync>,
+    offset_at: OffsetAt,
+}
+
+impl WallClockBoundary {
+    pub fn new(interval: Duration) -> Self {
+        Self::with_zone(interval, local_now, local_offset_at)
+    }
+
+    /// Use `clock` instead of the local time; boundaries stay in the offset
+    /// `clock` reports.
+    pub fn with_clock(
+        interval: Duration,
+        clock: impl Fn() -> OffsetDateTime + Send + Sync + 'static,
+    ) -> Self {
+        Self::with_zone(interval, clock, |at| at.offset())
+    }
+
+    fn with_zone(
+        interval: Duration,
+        clock: impl Fn() -> OffsetDateTime + Send + Sync + 'static,
+        offset_at: impl Fn(OffsetDateTime) -> UtcOffset + Send + Sync + 'static,
+    ) -> Self {
+        let interval = interval.clamp(Duration::from_secs(1), DAY);
+        let next_boundary = boundary_after(interval, clock(), &offset_at);
+        Self {
+            interval,
+            next_boundary,
+            clock: Box::new(clock),
+            offset_at: Box::new(offset_at),
+        }
+    }
+
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Whether the current file has crossed a boundary.
+    ///
+    /// Only boundaries after creation or the last [`restart`](Self::restart)
+    /// count, so a file never ends right away because of a boundary crossed
+    /// before it started. The boundary stays due until the next restart,
+    /// letting callers wait for a safe split point.
+    pub fn is_due(&self) -> bool {
+        (self.clock)() >= self.next_boundary
+    }
+
+    /// Start tracking a new file opened now.
+    pub fn restart(&mut self) {
+        self.next_boundary = boundary_after(self.interval, (self.clock)(), &self.offset_at);
+    }
+}
+
+/// The first boundary after `now`, counted on the wall clock of `now`.
+///
+/// When the offset changes before the boundary, the boundary keeps its wall
+/// time in the new offset. A wall time skipped by the change is taken in the
+/// old offset instead, which is the moment the clock jumps past it.
+fn boundary_after(
+    interval: Duration,
+    now: OffsetDateTime,
+    offset_at: impl Fn(OffsetDateTime) -> UtcOffset,
+) -> OffsetDateTime {
+    let midnight = now.replace_time(Time::MIDNIGHT);
+    let elapsed = (now - midnight).unsigned_abs().as_millis();
+    let interval = interval.as_millis();
+    let next = ((elapsed / interval + 1) * interval).min(DAY.as_millis());
+    let boundary = midnight + Duration::from_millis(u64::try_from(next).unwrap_or(u64::MAX));
+
+    let shifted = boundary.replace_offset(offset_at(boundary));
+    if shifted > now { shifted } else { boundary }
+}
+
+impl fmt::Debug for WallClockBoundary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WallClockBoundary")
+            .field("interval", &self.interval)
+            .field("next_boundary", &self.next_boundary)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+    use time::macros::datetime;
+
+    fn boundary_at(
+        interval: Duration,
+        start: OffsetDateTime,
+    ) -> (WallClockBoundary, Arc<Mutex<OffsetDateTime>>) {
+        let now = Arc::new(Mutex::new(start));
+        let clock = Arc::clone(&now);
+        let boundary = WallClockBoundary::with_clock(interval, move || *clock.lock().unwrap());
+        (boundary, now)
+    }
+
+    #[test]
+    fn splits_on_the_hour() {
+        let (mut boundary, now) = boundary_at(
+            Duration::from_secs(3_600),
+            datetime!(2024-05-01 12:59:50 +8),
+        );
+
+        assert!(!boundary.is_due());
+        *now.lock().unwrap() = datetime!(2024-05-01 12:59:59 +8);
+        assert!(!boundary.is_due());
+        *now.lock().unwrap() = datetime!(2024-05-01 13:00:01 +8);
+        assert!(boundary.is_due());
+        assert!(boundary.is_due());
+
+        boundary.restart();
+        assert!(!boundary.is_due());
+        *now.lock().unwrap() = datetime!(2024-05-01 13:59:00 +8);
+        assert!(!boundary.is_due());
+        *now.lock().unwrap() = datetime!(2024-05-01 14:00:00 +8);
+        assert!(boundary.is_due());
+    }
+
+    #[test]
+    fn daily_interval_splits_at_local_midnight() {
+        let (boundary, now) =
+            boundary_at(Duration::from_secs(86_400), datetime!(2024-05-01 07:00 +8));
+
+        assert!(!boundary.is_due());
+        *now.lock().unwrap() = datetime!(2024-05-01 23:59:59 +8);
+        assert!(!boundary.is_due());
+        *now.lock().unwrap() = datetime!(2024-05-02 00:00:05 +8);
+        assert!(boundary.is_due());
+    }
+
+    #[test]
+    fn uneven_interval_restarts_at_midnight() {
+        let (mut boundary, now) = boundary_at(
+            Duration::from_secs(7 * 3_600),
+            datetime!(2024-05-01 22:00 +0),
+        );
+
+        assert!(!boundary.is_due());
+        *now.lock().unwrap() = datetime!(2024-05-02 00:00:01 +0);
+        assert!(boundary.is_due());
+
+        boundary.restart();
+        assert!(!boundary.is_due());
+        *now.lock().unwrap() = datetime!(2024-05-02 06:59 +0);
+        assert!(!boundary.is_due());
+        *now.lock().unwrap() = datetime!(2024-05-02 07:00 +0);
+        assert!(boundary.is_due());
+    }
+
+    /// Central European time: +1, and +2 from 2024-03-31 01:00 UTC until
+    /// 2024-10-27 01:00 UTC.
+    fn central_europe(at: OffsetDateTime) -> UtcOffset {
+        let summer = datetime!(2024-03-31 01:00 UTC)..datetime!(2024-10-27 01:00 UTC);
+        let hours = if summer.contains(&at) { 2 } else { 1 };
+        UtcOffset::from_hms(hours, 0, 0).unwrap()
+    }
+
+    fn boundary_in_zone(
+        interval: Duration,
+        start: OffsetDateTime,
+    ) -> (WallClockBoundary, Arc<Mutex<OffsetDateTime>>) {
+        let now = Arc::new(Mutex::new(start.to_offset(central_europe(start))));
+        let clock = Arc::clone(&now);
+        let boundary = WallClockBoundary::with_zone(
+            interval,
+            move || {
+                let now = *clock.lock().unwrap();
+                now.to_offset(central_europe(now))
+            },
+            central_europe,
+        );
+        (boundary, now)
+    }
+
+    #[test]
+    fn follows_daylight_saving_changes() {
+        // 6-hour splits across the spring change end at 06:00 summer time.
+        let (boundary, now) = boundary_in_zone(
+            Duration::from_secs(6 * 3_600),
+            datetime!(2024-03-31 00:30 UTC),
+        );
+        *now.lock().unwrap() = datetime!(2024-03-31 03:59 UTC);
+        assert!(!boundary.is_due());
+        *now.lock().unwrap() = datetime!(2024-03-31 04:00 UTC);
+        assert!(boundary.is_due());
+
+        // The skipped 02:00 ends the file when the clock jumps to 03:00.
+        let (boundary, now) =
+            boundary_in_zone(Duration::from_secs(3_600), datetime!(2024-03-31 00:30 UTC));
+        *now.lock().unwrap() = datetime!(2024-03-31 00:59 UTC);
+        assert!(!boundary.is_due());
+        *now.lock().unwrap() = datetime!(2024-03-31 01:00 UTC);
+        assert!(boundary.is_due());
+
+        // Across the autumn change, 06:00 is in winter time.
+        let (boundary, now) = boundary_in_zone(
+            Duration::from_secs(6 * 3_600),
+            datetime!(2024-10-27 00:30 UTC),
+        );
+        *now.lock().unwrap() = datetime!(2024-10-27 04:59 UTC);
+        assert!(!boundary.is_due());
+        *now.lock().unwrap() = datetime!(2024-10-27 05:00 UTC);
+        assert!(boundary.is_due());
+    }
+
+    #[test]
+    fn boundary_crossed_before_restart_is_ignored() {
+        let (mut boundary, now) =
+            boundary_at(Duration::from_secs(3_600), datetime!(2024-05-01 12:30 +0));
+
+        assert!(!boundary.is_due());
+        *now.lock().unwrap() = datetime!(2024-05-01 13:30 +0);
+        boundary.restart();
+        assert!(!boundary.is_due());
+        *now.lock().unwrap() = datetime!(2024-05-01 14:00 +0);
+        assert!(boundary.is_due());
+    }
+}
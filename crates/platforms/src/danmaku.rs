@@ -9,7 +9,7 @@ pub mod websocket;
 pub mod writer;
 
 pub use error::{DanmakuError, Result};
-pub use event::{DanmuControlEvent, DanmuItem};
+pub use event::{DanmuConnectionEvent, DanmuControlEvent, DanmuItem};
 pub use message::{DanmuMessage, DanmuType};
 pub use provider::{ConnectionConfig, DanmuConnection, DanmuProvider};
 pub use registry::ProviderRegistry;
@@ -17,9 +17,10 @@ pub use sampler::{
     DanmuSampler, DanmuSamplingConfig, FixedIntervalSampler, VelocitySampler, create_sampler,
 };
 pub use statistics::{
-    DanmuStatistics, RateDataPoint, StatisticsAggregator, TopTalker, WordFrequency,
+    ActivityBurst, BurstDetectionConfig, BurstDetector, DanmuStatistics, RateDataPoint,
+    StatisticsAggregator, StopWordLanguage, TopTalker, WordFrequency,
 };
-pub use websocket::{DanmuProtocol, WebSocketDanmuProvider};
+pub use websocket::{DanmuProtocol, WebSocketDanmuProvider, WebSocketProviderConfig};
 pub use writer::{XmlDanmuWriter, escape_xml, message_type_to_int};
 
 pub use crate::extractor::platforms::huya::danmu::HuyaDanmuProvider;
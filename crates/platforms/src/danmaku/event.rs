@@ -35,10 +35,28 @@ pub enum DanmuControlEvent {
     },
 }
 
+/// Transport state changes of a danmu connection.
+///
+/// Emitted by the connection task of [`super::WebSocketDanmuProvider`] so consumers can tell
+/// when chat collection is degraded; reconnects themselves are handled by the provider.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DanmuConnectionEvent {
+    /// An established connection was lost; a reconnect will follow.
+    Disconnected { reason: String },
+    /// A reconnect attempt is scheduled after `delay_ms`.
+    Reconnecting { attempt: u32, delay_ms: u64 },
+    /// The connection was re-established after `attempts` failed attempts.
+    Reconnected { attempts: u32 },
+    /// The reconnect budget is exhausted; the stream ends after this event.
+    ReconnectFailed { attempts: u32 },
+}
+
 /// A single item in the danmu stream.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "kind", rename_all = "snake_case")]
 pub enum DanmuItem {
     Message(super::message::DanmuMessage),
     Control(DanmuControlEvent),
+    Connection(DanmuConnectionEvent),
 }
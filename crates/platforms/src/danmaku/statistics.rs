@@ -31,6 +31,9 @@ pub struct DanmuStatistics {
     pub end_time: Option<DateTime<Utc>>,
     /// Duration in seconds
     pub duration_secs: u64,
+    /// Detected activity bursts, ordered by start time
+    #[serde(default)]
+    pub bursts: Vec<ActivityBurst>,
 }
 
 /// A top talker entry.
@@ -55,6 +58,73 @@ pub struct RateDataPoint {
     pub count: u64,
 }
 
+/// A period where the message rate rose well above the session baseline.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActivityBurst {
+    /// Start of the sliding window that first crossed the threshold
+    pub start: DateTime<Utc>,
+    /// End of the last second that was still above the threshold
+    pub end: DateTime<Utc>,
+    /// Messages received between `start` and `end`
+    pub message_count: u64,
+    /// Highest sliding-window rate seen during the burst (messages per second)
+    pub peak_rate: f64,
+    /// Baseline rate when the burst started (messages per second)
+    pub baseline_rate: f64,
+}
+
+/// Configuration for sliding-window burst detection.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BurstDetectionConfig {
+    /// Width of the sliding window the current rate is measured over.
+    pub window_secs: u64,
+    /// Horizon of the moving-average baseline the window rate is compared against.
+    pub baseline_secs: u64,
+    /// Window rate must reach `baseline * threshold` to count as a burst.
+    pub threshold: f64,
+    /// Minimum messages inside the window, so quiet rooms do not report noise as bursts.
+    pub min_messages: u64,
+    /// Maximum bursts kept per session; the weakest are dropped first.
+    pub max_bursts: usize,
+}
+
+impl Default for BurstDetectionConfig {
+    fn default() -> Self {
+        Self {
+            window_secs: 10,
+            baseline_secs: 300,
+            threshold: 3.0,
+            min_messages: 20,
+            max_bursts: 100,
+        }
+    }
+}
+
+/// Built-in stop word lists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StopWordLanguage {
+    English,
+    Chinese,
+    Japanese,
+    Korean,
+}
+
+impl StopWordLanguage {
+    /// All built-in languages.
+    pub const ALL: [Self; 4] = [Self::English, Self::Chinese, Self::Japanese, Self::Korean];
+
+    fn words(self) -> &'static HashSet<&'static str> {
+        match self {
+            Self::English => &ENGLISH_STOP_WORDS,
+            Self::Chinese => &CHINESE_STOP_WORDS,
+            Self::Japanese => &JAPANESE_STOP_WORDS,
+            Self::Korean => &KOREAN_STOP_WORDS,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 struct TalkerCounter {
     username: String,
@@ -163,6 +233,162 @@ impl TalkerHeavyHitters {
     }
 }
 
+/// Sliding-window burst detector over per-second message counts.
+///
+/// The baseline is a running mean for the first `baseline_secs` seconds and an exponential
+/// moving average afterwards. It is frozen while a burst is active so the burst itself does not
+/// raise the bar it is measured against. Messages are expected in timestamp order.
+#[derive(Debug, Clone)]
+pub struct BurstDetector {
+    config: BurstDetectionConfig,
+    /// Per-second message counts inside the window, oldest first.
+    window: VecDeque<(i64, u64)>,
+    window_count: u64,
+    first_second: Option<i64>,
+    /// Completed seconds folded into the baseline.
+    baseline_samples: u64,
+    baseline: f64,
+    active: Option<ActivityBurst>,
+    bursts: Vec<ActivityBurst>,
+}
+
+impl BurstDetector {
+    pub fn new(config: BurstDetectionConfig) -> Self {
+        Self {
+            config,
+            window: VecDeque::new(),
+            window_count: 0,
+            first_second: None,
+            baseline_samples: 0,
+            baseline: 0.0,
+            active: None,
+            bursts: Vec::new(),
+        }
+    }
+
+    fn window_secs(&self) -> i64 {
+        self.config.window_secs.max(1) as i64
+    }
+
+    /// Record one message at `timestamp`.
+    pub fn record(&mut self, timestamp: DateTime<Utc>) {
+        self.record_weighted(timestamp, 1);
+    }
+
+    /// Record a message at `timestamp` that counts as `weight` messages.
+    pub fn record_weighted(&mut self, timestamp: DateTime<Utc>, weight: u64) {
+        let second = timestamp.timestamp();
+        let first_second = *self.first_second.get_or_insert(second);
+
+        match self.window.back().copied() {
+            // Late messages count towards the newest second.
+            Some((last, _)) if second <= last => {
+                if let Some((_, count)) = self.window.back_mut() {
+                    *count += weight;
+                }
+            }
+            Some((last, count)) => {
+                self.advance_baseline(count, second - last - 1);
+                self.window.push_back((second, weight));
+            }
+            None => self.window.push_back((second, weight)),
+        }
+        self.window_count += weight;
+
+        let current = self.window.back().map_or(second, |(s, _)| *s);
+        let window_secs = self.window_secs();
+        while let Some(&(s, count)) = self.window.front()
+            && s <= current - window_secs
+        {
+            self.window.pop_front();
+            self.window_count -= count;
+        }
+
+        // The baseline is meaningless until one full window has elapsed.
+        if current - first_second < window_secs {
+            return;
+        }
+        self.evaluate(current, weight);
+    }
+
+    /// Fold a completed second with `count` messages, followed by `idle_secs` empty seconds,
+    /// into the baseline.
+    fn advance_baseline(&mut self, count: u64, idle_secs: i64) {
+        if self.active.is_some() {
+            return;
+        }
+        let horizon = self.config.baseline_secs.max(1);
+        let mut fold = |value: f64| {
+            self.baseline_samples += 1;
+            let alpha = 1.0 / self.baseline_samples.min(horizon) as f64;
+            self.baseline += alpha * (value - self.baseline);
+        };
+        fold(count as f64);
+        // Past a few horizons the baseline has decayed to ~0 anyway.
+        let idle_secs = idle_secs.clamp(0, (horizon as i64).saturating_mul(4));
+        for _ in 0..idle_secs {
+            fold(0.0);
+        }
+    }
+
+    fn evaluate(&mut self, current: i64, weight: u64) {
+        let window_secs = self.window_secs();
+        let rate = self.window_count as f64 / window_secs as f64;
+        let busy = self.window_count >= self.config.min_messages;
+        let window_end = DateTime::from_timestamp(current + 1, 0).unwrap_or_default();
+
+        match &mut self.active {
+            Some(burst) if busy && rate >= burst.baseline_rate * self.config.threshold => {
+                burst.end = window_end;
+                burst.message_count += weight;
+                burst.peak_rate = burst.peak_rate.max(rate);
+            }
+            Some(_) => self.close(),
+            None if busy && rate >= self.baseline * self.config.threshold => {
+                let start = self.window.front().map_or(current, |(s, _)| *s);
+                self.active = Some(ActivityBurst {
+                    start: DateTime::from_timestamp(start, 0).unwrap_or_default(),
+                    end: window_end,
+                    message_count: self.window_count,
+                    peak_rate: rate,
+                    baseline_rate: self.baseline,
+                });
+            }
+            None => {}
+        }
+    }
+
+    fn close(&mut self) {
+        let Some(burst) = self.active.take() else {
+            return;
+        };
+        self.bursts.push(burst);
+        if self.bursts.len() > self.config.max_bursts
+            && let Some(weakest) = self
+                .bursts
+                .iter()
+                .enumerate()
+                .min_by(|(_, a), (_, b)| a.peak_rate.total_cmp(&b.peak_rate))
+                .map(|(index, _)| index)
+        {
+            self.bursts.remove(weakest);
+        }
+    }
+
+    /// Closed bursts plus the active one, if any.
+    pub fn snapshot(&self) -> Vec<ActivityBurst> {
+        let mut bursts = self.bursts.clone();
+        bursts.extend(self.active.clone());
+        bursts
+    }
+
+    /// Close the active burst and return all bursts.
+    pub fn finish(mut self) -> Vec<ActivityBurst> {
+        self.close();
+        self.bursts
+    }
+}
+
 #[derive(Debug, Clone)]
 struct WordCounter {
     count: u64,
@@ -362,12 +588,12 @@ pub struct StatisticsAggregator {
     max_words: usize,
     /// Maximum number of rate points kept in memory.
     max_rate_points: usize,
-    /// Stop words to filter out
-    stop_words: &'static HashSet<&'static str>,
+    /// Languages whose stop words are filtered out of word frequency
+    stop_word_languages: Vec<StopWordLanguage>,
+    /// Activity burst detection
+    bursts: BurstDetector,
 }
 
-static STOP_WORDS: LazyLock<HashSet<&'static str>> = LazyLock::new(default_stop_words);
-
 impl StatisticsAggregator {
     /// Create a new statistics aggregator.
     pub fn new() -> Self {
@@ -403,10 +629,44 @@ impl StatisticsAggregator {
             max_top_talkers,
             max_words,
             max_rate_points,
-            stop_words: &STOP_WORDS,
+            stop_word_languages: StopWordLanguage::ALL.to_vec(),
+            bursts: BurstDetector::new(BurstDetectionConfig::default()),
         }
     }
 
+    /// Restrict stop word filtering to `languages`.
+    ///
+    /// Chat expressions shared across platforms ("lol", "666", "233", ...) are always filtered.
+    pub fn with_stop_word_languages(mut self, languages: &[StopWordLanguage]) -> Self {
+        self.stop_word_languages = languages.to_vec();
+        self
+    }
+
+    /// Use `config` for activity burst detection.
+    pub fn with_burst_detection(mut self, config: BurstDetectionConfig) -> Self {
+        self.bursts = BurstDetector::new(config);
+        self
+    }
+
+    /// A fresh aggregator with the same configuration.
+    fn fresh(&self) -> Self {
+        Self::with_config(
+            self.max_top_talkers,
+            self.max_words,
+            self.bucket_duration_secs,
+        )
+        .with_stop_word_languages(&self.stop_word_languages)
+        .with_burst_detection(self.bursts.config)
+    }
+
+    fn is_stop_word(&self, word: &str) -> bool {
+        CHAT_STOP_WORDS.contains(word)
+            || self
+                .stop_word_languages
+                .iter()
+                .any(|language| language.words().contains(word))
+    }
+
     /// Record a message.
     pub fn record_message(
         &mut self,
@@ -438,6 +698,7 @@ impl StatisticsAggregator {
 
         // Update rate data
         self.update_rate_bucket(timestamp);
+        self.bursts.record(timestamp);
     }
 
     /// Process words from a message.
@@ -449,7 +710,7 @@ impl StatisticsAggregator {
             let word_lower = word.to_lowercase();
 
             // Skip stop words and very short words
-            if word_lower.len() < 2 || self.stop_words.contains(word_lower.as_str()) {
+            if word_lower.len() < 2 || self.is_stop_word(&word_lower) {
                 continue;
             }
 
@@ -510,6 +771,8 @@ impl StatisticsAggregator {
 
         let top_talkers = self.talker_hh.into_top_n(self.max_top_talkers);
         let word_frequency = self.word_hh.into_top_n(self.max_words);
+        let mut bursts = self.bursts.finish();
+        bursts.sort_by_key(|burst| burst.start);
         DanmuStatistics {
             total_count: self.total_count,
             chat_count: self.chat_count,
@@ -520,6 +783,7 @@ impl StatisticsAggregator {
             start_time: self.start_time,
             end_time: Some(end_time),
             duration_secs,
+            bursts,
         }
     }
 
@@ -536,6 +800,9 @@ impl StatisticsAggregator {
             });
         }
 
+        let mut bursts = self.bursts.snapshot();
+        bursts.sort_by_key(|burst| burst.start);
+
         DanmuStatistics {
             total_count: self.total_count,
             chat_count: self.chat_count,
@@ -546,6 +813,7 @@ impl StatisticsAggregator {
             start_time: self.start_time,
             end_time: None,
             duration_secs: 0,
+            bursts,
         }
     }
 
@@ -554,24 +822,14 @@ impl StatisticsAggregator {
     /// This is useful for long-running sessions to avoid unbounded memory growth
     /// from per-user/per-word tracking over time.
     pub fn checkpoint(&mut self, end_time: DateTime<Utc>) -> DanmuStatistics {
-        let prev = std::mem::replace(
-            self,
-            Self::with_config(
-                self.max_top_talkers,
-                self.max_words,
-                self.bucket_duration_secs,
-            ),
-        );
+        let fresh = self.fresh();
+        let prev = std::mem::replace(self, fresh);
         prev.finalize(end_time)
     }
 
     /// Reset all counters and tracked state.
     pub fn reset(&mut self) {
-        *self = Self::with_config(
-            self.max_top_talkers,
-            self.max_words,
-            self.bucket_duration_secs,
-        );
+        *self = self.fresh();
     }
 }
 
@@ -581,10 +839,8 @@ impl Default for StatisticsAggregator {
     }
 }
 
-/// Default stop words for filtering.
-fn default_stop_words() -> HashSet<&'static str> {
-    let words = [
-        // English
+static ENGLISH_STOP_WORDS: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
+    [
         "the", "a", "an", "is", "are", "was", "were", "be", "been", "being", "have", "has", "had",
         "do", "does", "did", "will", "would", "could", "should", "may", "might", "must", "shall",
         "can", "need", "dare", "to", "of", "in", "for", "on", "with", "at", "by", "from", "as",
@@ -593,15 +849,65 @@ fn default_stop_words() -> HashSet<&'static str> {
         "each", "few", "more", "most", "other", "some", "such", "no", "nor", "not", "only", "own",
         "same", "so", "than", "too", "very", "just", "and", "but", "if", "or", "because", "until",
         "while", "this", "that", "these", "those", "it", "its", "he", "she", "they", "them", "his",
-        "her", "their", "what", "which", "who", "whom", // Chinese common words
+        "her", "their", "what", "which", "who", "whom",
+    ]
+    .into_iter()
+    .collect()
+});
+
+static CHINESE_STOP_WORDS: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
+    [
         "的", "了", "是", "在", "我", "有", "和", "就", "不", "人", "都", "一", "一个", "上", "也",
         "很", "到", "说", "要", "去", "你", "会", "着", "没有", "看", "好", "自己", "这", "那",
-        // Common chat expressions
-        "lol", "lmao", "haha", "hehe", "xd", "gg", "ez", "wp", "666", "233", "哈哈", "呵呵", "嘿嘿",
-    ];
-
-    words.into_iter().collect()
-}
+    ]
+    .into_iter()
+    .collect()
+});
+
+static JAPANESE_STOP_WORDS: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
+    [
+        "の", "に", "は", "を", "た", "が", "で", "て", "と", "し", "も", "な", "か", "だ", "よ",
+        "ね", "です", "ます", "ある", "いる", "する", "から", "こと", "これ", "それ", "あれ",
+        "この", "その", "あの", "ない", "なる", "まで", "より", "でも",
+    ]
+    .into_iter()
+    .collect()
+});
+
+static KOREAN_STOP_WORDS: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
+    [
+        "이", "그", "저", "것", "수", "등", "들", "및", "의", "가", "을", "를", "은", "는", "에",
+        "와", "과", "도", "로", "에서", "으로", "하다", "있다", "없다", "진짜", "너무",
+    ]
+    .into_iter()
+    .collect()
+});
+
+/// Chat expressions filtered regardless of the configured languages.
+static CHAT_STOP_WORDS: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
+    [
+        "lol",
+        "lmao",
+        "haha",
+        "hehe",
+        "xd",
+        "gg",
+        "ez",
+        "wp",
+        "666",
+        "233",
+        "哈哈",
+        "呵呵",
+        "嘿嘿",
+        "www",
+        "草",
+        "ㅋㅋ",
+        "ㅋㅋㅋ",
+        "ㅎㅎ",
+    ]
+    .into_iter()
+    .collect()
+});
 
 #[cfg(test)]
 mod tests {
@@ -724,6 +1030,120 @@ mod tests {
         assert_eq!(stats.gift_count, 2);
     }
 
+    #[test]
+    fn test_stop_word_languages() {
+        let now = Utc::now();
+
+        let mut agg = StatisticsAggregator::with_config(10, 10, 10);
+        agg.record_message("user1", "User", "the stream of the year", false, now);
+        let stats = agg.current_stats();
+        assert!(stats.word_frequency.iter().all(|w| w.word != "the"));
+
+        let mut agg = StatisticsAggregator::with_config(10, 10, 10)
+            .with_stop_word_languages(&[StopWordLanguage::Chinese]);
+        agg.record_message("user1", "User", "the stream lol", false, now);
+        let stats = agg.current_stats();
+        assert!(stats.word_frequency.iter().any(|w| w.word == "the"));
+        // Chat expressions are filtered regardless of language.
+        assert!(stats.word_frequency.iter().all(|w| w.word != "lol"));
+
+        // Configuration survives a checkpoint.
+        agg.checkpoint(now);
+        agg.record_message("user1", "User", "the stream", false, now);
+        let stats = agg.current_stats();
+        assert!(stats.word_frequency.iter().any(|w| w.word == "the"));
+    }
+
+    #[test]
+    fn test_burst_detection() {
+        let mut agg = StatisticsAggregator::with_config(10, 10, 10).with_burst_detection(
+            BurstDetectionConfig {
+                window_secs: 5,
+                baseline_secs: 60,
+                threshold: 3.0,
+                min_messages: 10,
+                max_bursts: 10,
+            },
+        );
+        let base = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+        let at = |secs: i64| base + chrono::Duration::seconds(secs);
+
+        // One message per second of background chatter.
+        for sec in 0..60 {
+            agg.record_message("user", "User", "msg", false, at(sec));
+        }
+        // Ten messages per second for ten seconds.
+        for sec in 60..70 {
+            for _ in 0..10 {
+                agg.record_message("user", "User", "msg", false, at(sec));
+            }
+        }
+        // Back to background chatter.
+        for sec in 70..100 {
+            agg.record_message("user", "User", "msg", false, at(sec));
+        }
+
+        let stats = agg.finalize(at(100));
+        assert_eq!(stats.bursts.len(), 1);
+        let burst = &stats.bursts[0];
+        assert!(burst.start >= at(56) && burst.start <= at(61), "{burst:?}");
+        assert!(burst.end >= at(70) && burst.end <= at(76), "{burst:?}");
+        assert!(burst.message_count >= 100, "{burst:?}");
+        assert!(
+            burst.baseline_rate > 0.5 && burst.baseline_rate < 2.0,
+            "{burst:?}"
+        );
+        assert!(burst.peak_rate >= 9.0, "{burst:?}");
+    }
+
+    #[test]
+    fn test_weighted_records_match_repeated_records() {
+        let config = BurstDetectionConfig {
+            window_secs: 5,
+            baseline_secs: 60,
+            threshold: 3.0,
+            min_messages: 10,
+            max_bursts: 10,
+        };
+        let base = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+        let at = |secs: i64| base + chrono::Duration::seconds(secs);
+        let rate = |sec: i64| if (60..70).contains(&sec) { 10 } else { 1 };
+
+        let mut repeated = BurstDetector::new(config);
+        let mut weighted = BurstDetector::new(config);
+        for sec in 0..100 {
+            for _ in 0..rate(sec) {
+                repeated.record(at(sec));
+            }
+            weighted.record_weighted(at(sec), rate(sec));
+        }
+
+        let bursts = weighted.finish();
+        assert_eq!(bursts.len(), 1);
+        assert_eq!(bursts, repeated.finish());
+    }
+
+    #[test]
+    fn test_steady_rate_has_no_bursts() {
+        let mut agg = StatisticsAggregator::new();
+        let base = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+
+        for sec in 0..600 {
+            for _ in 0..5 {
+                agg.record_message(
+                    "user",
+                    "User",
+                    "msg",
+                    false,
+                    base + chrono::Duration::seconds(sec),
+                );
+            }
+        }
+
+        let stats = agg.finalize(base + chrono::Duration::seconds(600));
+        assert!(stats.bursts.is_empty(), "{:?}", stats.bursts);
+    }
+
     #[test]
     fn test_heavy_hitter_high_cardinality_bounds() {
         let mut agg = StatisticsAggregator::with_config(10, 50, 10);
@@ -1,5 +1,6 @@
 use async_trait::async_trait;
 use futures::{SinkExt, StreamExt};
+use rand::RngExt;
 use rustls::{ClientConfig, crypto::aws_lc_rs};
 use rustls_platform_verifier::BuilderVerifierExt;
 use std::collections::HashMap;
@@ -23,7 +24,7 @@ use url::Url;
 
 use crate::danmaku::ConnectionConfig;
 use crate::danmaku::error::{DanmakuError, Result};
-use crate::danmaku::event::DanmuItem;
+use crate::danmaku::event::{DanmuConnectionEvent, DanmuItem};
 use crate::danmaku::provider::{DanmuConnection, DanmuProvider};
 use crate::extractor::utils::merge_cookie_headers;
 
@@ -389,11 +390,24 @@ pub struct WebSocketDanmuProvider<F: DanmuProtocolFactory> {
     connection_semaphore: Arc<Semaphore>,
 }
 
+/// Heartbeat and reconnect policy for a WebSocket danmaku connection.
 #[derive(Clone, Copy, Debug)]
 pub struct WebSocketProviderConfig {
+    /// Consecutive failed connection attempts before the connection task gives up.
     pub max_reconnect_attempts: u32,
+    /// Delay before the first reconnect attempt.
     pub base_reconnect_delay_ms: u64,
+    /// Upper bound for the backoff delay (before jitter).
     pub max_reconnect_delay_ms: u64,
+    /// Factor applied to the delay after every failed attempt.
+    pub backoff_multiplier: f64,
+    /// Fraction of the delay that is randomized, in `0.0..=1.0`.
+    ///
+    /// A value of `0.2` spreads a 10s delay over `8s..=12s`, so collectors that lost their
+    /// connection together do not reconnect in lockstep.
+    pub jitter_ratio: f64,
+    /// Overrides [`DanmuProtocol::heartbeat_interval`] when set to a non-zero value.
+    pub heartbeat_interval_ms: Option<u64>,
 }
 
 impl Default for WebSocketProviderConfig {
@@ -402,7 +416,48 @@ impl Default for WebSocketProviderConfig {
             max_reconnect_attempts: 10,
             base_reconnect_delay_ms: 1000,
             max_reconnect_delay_ms: 60000,
+            backoff_multiplier: 2.0,
+            jitter_ratio: 0.0,
+            heartbeat_interval_ms: None,
+        }
+    }
+}
+
+impl WebSocketProviderConfig {
+    /// Backoff delay before reconnect `attempt` (1-based), without jitter.
+    pub fn backoff_delay(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(i32::MAX as u32) as i32;
+        let multiplier = if self.backoff_multiplier.is_finite() {
+            self.backoff_multiplier.max(1.0)
+        } else {
+            1.0
+        };
+        let delay_ms = (self.base_reconnect_delay_ms as f64 * multiplier.powi(exponent))
+            .min(self.max_reconnect_delay_ms as f64);
+        Duration::from_millis(delay_ms as u64)
+    }
+
+    /// Backoff delay before reconnect `attempt` (1-based) with jitter applied.
+    pub fn reconnect_delay(&self, attempt: u32) -> Duration {
+        let delay = self.backoff_delay(attempt);
+        let ratio = if self.jitter_ratio.is_finite() {
+            self.jitter_ratio.clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        if ratio == 0.0 || delay.is_zero() {
+            return delay;
         }
+        let factor = rand::rng().random_range((1.0 - ratio)..=(1.0 + ratio));
+        delay.mul_f64(factor)
+    }
+
+    /// Heartbeat interval to use for `protocol`.
+    fn heartbeat_interval_for(&self, protocol: &impl DanmuProtocol) -> Duration {
+        self.heartbeat_interval_ms
+            .filter(|ms| *ms > 0)
+            .map(Duration::from_millis)
+            .unwrap_or_else(|| protocol.heartbeat_interval())
     }
 }
 
@@ -449,7 +504,7 @@ impl<F: DanmuProtocolFactory> WebSocketDanmuProvider<F> {
                 F::Protocol,
             )> = None;
             let mut attempt = 0;
-            let mut delay = ws_config.base_reconnect_delay_ms;
+            let mut ever_connected = false;
 
             loop {
                 // Check shutdown
@@ -590,8 +645,20 @@ impl<F: DanmuProtocolFactory> WebSocketDanmuProvider<F> {
                                     if handshake_ok {
                                         is_connected_clone.store(true, Ordering::SeqCst);
                                         reconnect_count_clone.store(0, Ordering::SeqCst);
+                                        if ever_connected {
+                                            let event = DanmuConnectionEvent::Reconnected {
+                                                attempts: attempt,
+                                            };
+                                            if message_tx
+                                                .send(DanmuItem::Connection(event))
+                                                .await
+                                                .is_err()
+                                            {
+                                                return;
+                                            }
+                                        }
+                                        ever_connected = true;
                                         attempt = 0;
-                                        delay = ws_config.base_reconnect_delay_ms;
                                         current_connection = Some((ws_stream, protocol));
                                     }
                                 }
@@ -608,17 +675,31 @@ impl<F: DanmuProtocolFactory> WebSocketDanmuProvider<F> {
                     if current_connection.is_none() {
                         if attempt >= ws_config.max_reconnect_attempts {
                             error!("Max reconnect attempts reached for {}", room_id_owned);
+                            // Best-effort: the receiver observes the closed channel right after.
+                            let event = DanmuConnectionEvent::ReconnectFailed { attempts: attempt };
+                            if message_tx.send(DanmuItem::Connection(event)).await.is_err() {
+                                trace!(
+                                    "Danmu receiver dropped before reconnect failure was reported"
+                                );
+                            }
                             break;
                         }
                         attempt += 1;
                         reconnect_count_clone.store(attempt, Ordering::SeqCst);
 
+                        let delay = ws_config.reconnect_delay(attempt);
+                        let event = DanmuConnectionEvent::Reconnecting {
+                            attempt,
+                            delay_ms: delay.as_millis() as u64,
+                        };
+                        if message_tx.send(DanmuItem::Connection(event)).await.is_err() {
+                            return;
+                        }
+
                         tokio::select! {
-                            _ = tokio::time::sleep(Duration::from_millis(delay)) => {},
+                            _ = tokio::time::sleep(delay) => {},
                             _ = shutdown_rx.recv() => break,
                         }
-
-                        delay = (delay * 2).min(ws_config.max_reconnect_delay_ms);
                         continue;
                     }
                 }
@@ -626,17 +707,17 @@ impl<F: DanmuProtocolFactory> WebSocketDanmuProvider<F> {
                 // Main loop: read/write/heartbeat
                 if let Some((mut stream, mut protocol)) = current_connection.take() {
                     let heartbeat_enabled = protocol.heartbeat_message().is_some();
-                    let heartbeat_interval = protocol.heartbeat_interval();
+                    let heartbeat_interval = ws_config.heartbeat_interval_for(&protocol);
                     let mut heartbeat_timer = tokio::time::interval(heartbeat_interval);
                     heartbeat_timer.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
-                    loop {
+                    let reason = loop {
                         tokio::select! {
                              // Heartbeat (only if enabled)
                             _ = heartbeat_timer.tick(), if heartbeat_enabled => {
                                 if let Some(msg) = protocol.heartbeat_message() {
                                     if let Err(e) = stream.send(msg).await {
                                         error!("Failed to send heartbeat: {}", e);
-                                        break; // Reconnect
+                                        break format!("heartbeat failed: {e}"); // Reconnect
                                     }
                                     trace!("Sent heartbeat for {}", room_id_owned);
                                 }
@@ -658,7 +739,7 @@ impl<F: DanmuProtocolFactory> WebSocketDanmuProvider<F> {
                                                     }
                                                 }
                                                 if response_failed {
-                                                    break;
+                                                    break "protocol response failed".to_string();
                                                 }
                                                 for item in items {
                                                     if message_tx.send(item).await.is_err() {
@@ -672,17 +753,17 @@ impl<F: DanmuProtocolFactory> WebSocketDanmuProvider<F> {
                                                     _ = tokio::time::sleep(Duration::from_millis(ws_config.base_reconnect_delay_ms)) => {}
                                                     _ = shutdown_rx.recv() => return,
                                                 }
-                                                break;
+                                                break format!("decode failed: {e}");
                                             }
                                         }
                                     }
                                     Some(Err(e)) => {
                                         error!("WebSocket error: {}", e);
-                                        break; // Reconnect
+                                        break format!("websocket error: {e}"); // Reconnect
                                     }
                                     None => {
                                         warn!("WebSocket stream closed");
-                                        break; // Reconnect
+                                        break "stream closed".to_string(); // Reconnect
                                     }
                                 }
                             }
@@ -695,10 +776,14 @@ impl<F: DanmuProtocolFactory> WebSocketDanmuProvider<F> {
                                 return;
                             }
                         }
-                    }
+                    };
 
                     // If we break internal loop, connection is lost/broken
                     is_connected_clone.store(false, Ordering::SeqCst);
+                    let event = DanmuConnectionEvent::Disconnected { reason };
+                    if message_tx.send(DanmuItem::Connection(event)).await.is_err() {
+                        return;
+                    }
                 }
             }
             debug!("WebSocket task for {} stopped", room_id_owned);
@@ -819,6 +904,43 @@ impl<F: DanmuProtocolFactory> DanmuProvider for WebSocketDanmuProvider<F> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn backoff_delay_grows_and_caps() {
+        let config = WebSocketProviderConfig {
+            base_reconnect_delay_ms: 1000,
+            max_reconnect_delay_ms: 5000,
+            backoff_multiplier: 2.0,
+            ..Default::default()
+        };
+
+        assert_eq!(config.backoff_delay(1), Duration::from_millis(1000));
+        assert_eq!(config.backoff_delay(2), Duration::from_millis(2000));
+        assert_eq!(config.backoff_delay(3), Duration::from_millis(4000));
+        assert_eq!(config.backoff_delay(4), Duration::from_millis(5000));
+        assert_eq!(config.backoff_delay(u32::MAX), Duration::from_millis(5000));
+    }
+
+    #[test]
+    fn reconnect_delay_stays_within_jitter_bounds() {
+        let config = WebSocketProviderConfig {
+            base_reconnect_delay_ms: 10_000,
+            jitter_ratio: 0.2,
+            ..Default::default()
+        };
+
+        for _ in 0..100 {
+            let delay = config.reconnect_delay(1);
+            assert!(delay >= Duration::from_millis(8000), "{delay:?}");
+            assert!(delay <= Duration::from_millis(12_000), "{delay:?}");
+        }
+    }
+
+    #[test]
+    fn reconnect_delay_without_jitter_is_deterministic() {
+        let config = WebSocketProviderConfig::default();
+        assert_eq!(config.reconnect_delay(2), config.backoff_delay(2));
+    }
+
     #[test]
     fn test_merge_cookie_headers_merges_and_overrides() {
         let base = "a=1; b=2";
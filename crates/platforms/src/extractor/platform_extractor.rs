@@ -460,4 +460,11 @@ pub trait PlatformExtractor: Send + Sync {
         // Default implementation, can be overridden by specific extractors
         Ok(())
     }
+
+    /// Extract the replay of the broadcast that is currently live, from its start.
+    ///
+    /// Returns `None` when the platform publishes no replay while the stream is running.
+    async fn extract_replay(&self) -> Result<Option<MediaInfo>, ExtractorError> {
+        Ok(None)
+    }
 }
@@ -813,6 +813,9 @@ mod tests {
                             println!("[control] {control:?}");
                             control_count += 1;
                         }
+                        crate::danmaku::DanmuItem::Connection(event) => {
+                            println!("[connection] {event:?}");
+                        }
                     },
                     Ok(None) => {
                         tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
@@ -529,6 +529,9 @@ mod tests {
                                                 DanmuItem::Control(control) => {
                                                     println!("    [control] {:?}", control);
                                                 }
+                                                DanmuItem::Connection(event) => {
+                                                    println!("    [connection] {:?}", event);
+                                                }
                                             }
                                         }
                                     }
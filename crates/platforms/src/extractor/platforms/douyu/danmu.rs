@@ -403,6 +403,9 @@ mod tests {
                         crate::danmaku::DanmuItem::Control(control) => {
                             println!("[control] {:?}", control);
                         }
+                        crate::danmaku::DanmuItem::Connection(event) => {
+                            println!("[connection] {:?}", event);
+                        }
                     },
                     Ok(None) => {
                         // Provider uses a short poll timeout; yield.
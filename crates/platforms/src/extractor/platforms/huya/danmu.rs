@@ -488,6 +488,9 @@ mod tests {
                     crate::danmaku::DanmuItem::Control(control) => {
                         println!("[control] {:?}", control);
                     }
+                    crate::danmaku::DanmuItem::Connection(event) => {
+                        println!("[connection] {:?}", event);
+                    }
                 },
                 Ok(Ok(None)) => {
                     // No message, continue
@@ -572,6 +575,9 @@ mod tests {
                                                     crate::danmaku::DanmuItem::Control(control) => {
                                                         println!("  -> Control: {:?}", control);
                                                     }
+                                                    crate::danmaku::DanmuItem::Connection(event) => {
+                                                        println!("  -> Connection: {:?}", event);
+                                                    }
                                                 }
                                             }
                                         }
@@ -221,43 +221,52 @@ impl Twitch {
             .build())
     }
 
-    pub async fn get_streams(&self, rid: &str) -> Result<Vec<StreamInfo>, ExtractorError> {
-        let live_gpl = self.build_persisted_query_request(
+    /// Fetch a playback access token, returning its value and signature.
+    ///
+    /// `field` is `streamPlaybackAccessToken` for live streams and
+    /// `videoPlaybackAccessToken` for videos.
+    async fn get_playback_access_token(
+        &self,
+        variables: serde_json::Value,
+        field: &str,
+    ) -> Result<(serde_json::Value, serde_json::Value), ExtractorError> {
+        let gpl = self.build_persisted_query_request(
             "PlaybackAccessToken",
             "ed230aa1e33e07eebb8928504583da78a5173989fadfb1ac94be06a04f3cdbe9",
-            serde_json::json!({
-                "isLive": true,
-                "login": rid,
-                "isVod": false,
-                "vodID": "",
-                "playerType": "site",
-                "isClip": false,
-                "clipID": "",
-                "platform" : "site",
-            }),
+            variables,
         );
 
-        let response = self.post_gql::<serde_json::Value>(live_gpl).await?;
-        let stream_playback_access_token = response
+        let response = self.post_gql::<serde_json::Value>(gpl).await?;
+        let playback_access_token = response
             .first()
-            .and_then(|data| {
-                data.get("data")
-                    .and_then(|data| data.get("streamPlaybackAccessToken"))
-            })
-            .ok_or_else(|| {
-                ExtractorError::ValidationError(
-                    "Could not find streamPlaybackAccessToken".to_string(),
-                )
-            })?;
+            .and_then(|data| data.get("data").and_then(|data| data.get(field)))
+            .ok_or_else(|| ExtractorError::ValidationError(format!("Could not find {field}")))?;
 
-        let playback_token = stream_playback_access_token.get("value").ok_or_else(|| {
+        let playback_token = playback_access_token.get("value").ok_or_else(|| {
             ExtractorError::ValidationError("Could not find token value".to_string())
         })?;
-        let signature = stream_playback_access_token
-            .get("signature")
-            .ok_or_else(|| {
-                ExtractorError::ValidationError("Could not find signature".to_string())
-            })?;
+        let signature = playback_access_token.get("signature").ok_or_else(|| {
+            ExtractorError::ValidationError("Could not find signature".to_string())
+        })?;
+        Ok((playback_token.clone(), signature.clone()))
+    }
+
+    pub async fn get_streams(&self, rid: &str) -> Result<Vec<StreamInfo>, ExtractorError> {
+        let (playback_token, signature) = self
+            .get_playback_access_token(
+                serde_json::json!({
+                    "isLive": true,
+                    "login": rid,
+                    "isVod": false,
+                    "vodID": "",
+                    "playerType": "site",
+                    "isClip": false,
+                    "clipID": "",
+                    "platform" : "site",
+                }),
+                "streamPlaybackAccessToken",
+            )
+            .await?;
 
         let m3u8_url = format!("https://usher.ttvnw.net/api/channel/hls/{rid}.m3u8");
 
@@ -291,6 +300,100 @@ impl Twitch {
         // debug!("response: {:?}", response);
         Ok(streams)
     }
+
+    /// Resolve the archive video Twitch records while the channel is live.
+    ///
+    /// Returns `None` when the channel is offline or does not archive its broadcasts.
+    pub async fn get_replay(&self) -> Result<Option<MediaInfo>, ExtractorError> {
+        let room_id = self.extract_room_id()?;
+        let query = serde_json::to_string(&serde_json::json!({
+            "query": "query($login: String!) { user(login: $login) { displayName \
+                      lastBroadcast { title } stream { createdAt archiveVideo { id } } } }",
+            "variables": { "login": room_id },
+        }))
+        .unwrap_or_else(|_| "{}".to_string());
+
+        let response = self.post_gql::<serde_json::Value>(query).await?;
+        let user = response
+            .first()
+            .and_then(|data| data.pointer("/data/user"))
+            .filter(|user| !user.is_null())
+            .ok_or_else(|| ExtractorError::ValidationError("Could not find user".to_string()))?;
+
+        let Some(video_id) = user
+            .pointer("/stream/archiveVideo/id")
+            .and_then(|id| id.as_str())
+        else {
+            debug!("no archive video for {}", room_id);
+            return Ok(None);
+        };
+
+        let (playback_token, signature) = self
+            .get_playback_access_token(
+                serde_json::json!({
+                    "isLive": false,
+                    "login": "",
+                    "isVod": true,
+                    "vodID": video_id,
+                    "playerType": "site",
+                    "isClip": false,
+                    "clipID": "",
+                    "platform" : "site",
+                }),
+                "videoPlaybackAccessToken",
+            )
+            .await?;
+
+        let m3u8_url = format!("https://usher.ttvnw.net/vod/{video_id}.m3u8");
+        let epoch_seconds_str = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+            .to_string();
+
+        let headers = self.extractor.get_platform_headers();
+        let streams = self
+            .extract_hls_stream_with_params(
+                &self.extractor.client,
+                Some(headers.clone()),
+                Some(&[
+                    ("player", "twitchweb"),
+                    ("p", &epoch_seconds_str),
+                    ("allow_source", "true"),
+                    ("allow_audio_only", "true"),
+                    ("allow_spectre", "true"),
+                    ("token", playback_token.as_str().unwrap_or("")),
+                    ("sig", signature.as_str().unwrap_or("")),
+                ]),
+                &m3u8_url,
+                None,
+                None,
+            )
+            .await?;
+
+        let title = user
+            .pointer("/lastBroadcast/title")
+            .and_then(|title| title.as_str())
+            .unwrap_or_default();
+        let artist = user
+            .get("displayName")
+            .and_then(|name| name.as_str())
+            .unwrap_or(room_id);
+        let live_start_time = user
+            .pointer("/stream/createdAt")
+            .and_then(|created_at| created_at.as_str())
+            .and_then(|created_at| DateTime::parse_from_rfc3339(created_at).ok())
+            .map(|dt| dt.with_timezone(&Utc));
+
+        Ok(Some(
+            MediaInfo::builder(Self::BASE_URL, title, artist)
+                .live_start_time_opt(live_start_time)
+                .is_live(false)
+                .streams(streams)
+                .headers(self.extractor.get_platform_headers_map())
+                .build(),
+        ))
+    }
 }
 
 impl HlsExtractor for Twitch {}
@@ -305,6 +408,10 @@ impl PlatformExtractor for Twitch {
         let media_info = self.get_live_stream_info().await?;
         Ok(media_info)
     }
+
+    async fn extract_replay(&self) -> Result<Option<MediaInfo>, ExtractorError> {
+        self.get_replay().await
+    }
 }
 
 #[cfg(test)]
@@ -329,4 +436,17 @@ mod tests {
         let media_info = twitch.get_live_stream_info().await.unwrap();
         println!("{media_info:?}");
     }
+
+    #[tokio::test]
+    #[ignore]
+    async fn test_get_replay() {
+        let twitch = Twitch::new(
+            "https://www.twitch.tv/abby_".to_string(),
+            default_client(),
+            None,
+            None,
+        );
+        let replay = twitch.get_replay().await.unwrap();
+        println!("{replay:?}");
+    }
 }
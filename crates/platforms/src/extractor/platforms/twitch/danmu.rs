@@ -372,6 +372,9 @@ mod tests {
                     crate::danmaku::DanmuItem::Control(control) => {
                         println!("[control] {:?}", control);
                     }
+                    crate::danmaku::DanmuItem::Connection(event) => {
+                        println!("[connection] {:?}", event);
+                    }
                 },
                 Ok(None) => {
                     tokio::time::sleep(Duration::from_millis(100)).await;
@@ -1,4 +1,5 @@
 use crate::media::{StreamFormat, formats::MediaFormat};
+use media_types::StreamDescription;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
@@ -96,6 +97,22 @@ impl StreamInfo {
         StreamInfoBuilder::new(url, stream_format, media_format)
     }
 
+    /// What the platform reports the stream to carry, from `codec` and `fps`.
+    ///
+    /// `codec` may be a single codec name (`h264`, `hevc`) or a comma-separated
+    /// RFC 6381 list (`avc1.64001F,mp4a.40.2`).
+    pub fn description(&self) -> StreamDescription {
+        let mut description = StreamDescription::from_codecs(self.codec.split(','));
+        if self.is_audio_only {
+            description.video_codec = None;
+            description.profile = None;
+            description.level = None;
+        } else if self.fps > 0.0 {
+            description.frame_rate = Some(self.fps);
+        }
+        description
+    }
+
     /// Serialize the StreamInfo to a JSON string
     pub fn to_json(&self) -> Result<String, serde_json::Error> {
         serde_json::to_string(self)
@@ -315,3 +332,36 @@ impl fmt::Display for StreamInfo {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use media_types::{AudioCodec, VideoCodec};
+
+    #[test]
+    fn test_description() {
+        let stream = StreamInfo::builder(
+            "https://example.com/live.flv",
+            StreamFormat::Flv,
+            MediaFormat::Flv,
+        )
+        .codec("bytevc1")
+        .fps(30.0)
+        .build();
+        let description = stream.description();
+        assert_eq!(description.video_codec, Some(VideoCodec::Hevc));
+        assert_eq!(description.frame_rate, Some(30.0));
+
+        let stream = StreamInfo::builder(
+            "https://example.com/live.m3u8",
+            StreamFormat::Hls,
+            MediaFormat::Ts,
+        )
+        .codec("avc1.64001F,mp4a.40.2")
+        .build();
+        let description = stream.description();
+        assert_eq!(description.level, Some(31));
+        assert_eq!(description.audio_codec, Some(AudioCodec::Aac));
+        assert_eq!(description.frame_rate, None);
+    }
+}
@@ -2,6 +2,12 @@
 
 use std::ffi::OsStr;
 
+mod scheduling;
+mod tree;
+
+pub use scheduling::{ProcessPriority, ProcessScheduling, ProcessSchedulingExt};
+pub use tree::{ProcessGroupExt, ProcessTree};
+
 #[cfg(windows)]
 const CREATE_NO_WINDOW: u32 = 0x0800_0000;
 
@@ -0,0 +1,229 @@
+//! CPU priority and affinity of child processes.
+//!
+//! On Unix the settings are applied in the child right before `exec`, so they cover every
+//! thread and every process it starts. On Windows they can only be applied to a running
+//! process: call [`ProcessScheduling::apply_to_tokio`] or [`ProcessScheduling::apply_to_std`]
+//! right after spawning. Processes the child started in between keep the default scheduling.
+//!
+//! The settings are hints: whatever the OS rejects (e.g. CPUs that do not exist) is ignored
+//! instead of failing the spawn.
+
+use std::io;
+
+/// CPU priority of a child process.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum ProcessPriority {
+    /// Inherit the priority of this process.
+    #[default]
+    Normal,
+    /// Run when normal-priority work leaves CPU time (nice 10 / `BELOW_NORMAL_PRIORITY_CLASS`).
+    BelowNormal,
+    /// Run only when the system is otherwise idle (nice 19 / `IDLE_PRIORITY_CLASS`).
+    Idle,
+}
+
+impl ProcessPriority {
+    #[cfg(unix)]
+    fn nice(self) -> Option<libc::c_int> {
+        match self {
+            Self::Normal => None,
+            Self::BelowNormal => Some(10),
+            Self::Idle => Some(19),
+        }
+    }
+
+    #[cfg(windows)]
+    fn priority_class(self) -> Option<u32> {
+        use windows_sys::Win32::System::Threading::{
+            BELOW_NORMAL_PRIORITY_CLASS, IDLE_PRIORITY_CLASS,
+        };
+        match self {
+            Self::Normal => None,
+            Self::BelowNormal => Some(BELOW_NORMAL_PRIORITY_CLASS),
+            Self::Idle => Some(IDLE_PRIORITY_CLASS),
+        }
+    }
+}
+
+/// Scheduling settings for a child process.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProcessScheduling {
+    /// CPU priority.
+    pub priority: ProcessPriority,
+    /// Zero-based indexes of the CPUs the process may run on; empty allows every CPU.
+    ///
+    /// Supported on Linux and Windows (first 64 CPUs), ignored elsewhere.
+    pub cpu_affinity: Vec<usize>,
+}
+
+impl ProcessScheduling {
+    /// Whether these settings leave the scheduling of the child untouched.
+    pub fn is_default(&self) -> bool {
+        self.priority == ProcessPriority::Normal && self.cpu_affinity.is_empty()
+    }
+
+    /// Apply the settings that must be set on the running process. No-op outside Windows.
+    pub fn apply_to_std(&self, child: &std::process::Child) -> io::Result<()> {
+        #[cfg(windows)]
+        {
+            use std::os::windows::io::AsRawHandle;
+            self.apply_to_handle(child.as_raw_handle())
+        }
+        #[cfg(not(windows))]
+        {
+            let _ = child;
+            Ok(())
+        }
+    }
+
+    /// Apply the settings that must be set on the running process. No-op outside Windows.
+    #[cfg(feature = "tokio")]
+    pub fn apply_to_tokio(&self, child: &tokio::process::Child) -> io::Result<()> {
+        #[cfg(windows)]
+        {
+            match child.raw_handle() {
+                Some(handle) => self.apply_to_handle(handle),
+                // Already reaped, nothing left to schedule
+                None => Ok(()),
+            }
+        }
+        #[cfg(not(windows))]
+        {
+            let _ = child;
+            Ok(())
+        }
+    }
+
+    #[cfg(windows)]
+    fn apply_to_handle(&self, handle: std::os::windows::io::RawHandle) -> io::Result<()> {
+        use windows_sys::Win32::Foundation::HANDLE;
+        use windows_sys::Win32::System::Threading::{SetPriorityClass, SetProcessAffinityMask};
+
+        let handle = handle as HANDLE;
+        if let Some(class) = self.priority.priority_class() {
+            // SAFETY: the handle is valid while the child is borrowed
+            if unsafe { SetPriorityClass(handle, class) } == 0 {
+                return Err(io::Error::last_os_error());
+            }
+        }
+        let mask = self
+            .cpu_affinity
+            .iter()
+            .filter(|&&cpu| cpu < usize::BITS as usize)
+            .fold(0usize, |mask, &cpu| mask | (1 << cpu));
+        if mask != 0 {
+            // SAFETY: the handle is valid while the child is borrowed
+            if unsafe { SetProcessAffinityMask(handle, mask) } == 0 {
+                return Err(io::Error::last_os_error());
+            }
+        }
+        Ok(())
+    }
+
+    /// Apply the settings to the calling process. Runs in the forked child before `exec`, so it
+    /// must not allocate or lock.
+    #[cfg(unix)]
+    fn apply_to_current_process(&self) {
+        if let Some(nice) = self.priority.nice() {
+            // SAFETY: setpriority is async-signal-safe and has no memory-safety preconditions
+            unsafe { libc::setpriority(libc::PRIO_PROCESS, 0, nice) };
+        }
+        #[cfg(any(target_os = "linux", target_os = "android"))]
+        if !self.cpu_affinity.is_empty() {
+            // SAFETY: all-zero is an empty cpu_set_t
+            let mut set: libc::cpu_set_t = unsafe { std::mem::zeroed() };
+            let capacity = 8 * std::mem::size_of::<libc::cpu_set_t>();
+            for &cpu in self.cpu_affinity.iter().filter(|&&cpu| cpu < capacity) {
+                // SAFETY: `cpu` is within the set
+                unsafe { libc::CPU_SET(cpu, &mut set) };
+            }
+            // SAFETY: `set` is a valid cpu_set_t of the given size
+            unsafe { libc::sched_setaffinity(0, std::mem::size_of_val(&set), &set) };
+        }
+    }
+}
+
+/// Apply [`ProcessScheduling`] to commands.
+pub trait ProcessSchedulingExt {
+    /// Start the child with `scheduling`. On Windows, also call
+    /// [`ProcessScheduling::apply_to_tokio`] or [`ProcessScheduling::apply_to_std`] after
+    /// spawning.
+    fn scheduling(&mut self, scheduling: &ProcessScheduling) -> &mut Self;
+}
+
+impl ProcessSchedulingExt for std::process::Command {
+    fn scheduling(&mut self, scheduling: &ProcessScheduling) -> &mut Self {
+        #[cfg(unix)]
+        if !scheduling.is_default() {
+            use std::os::unix::process::CommandExt;
+            let scheduling = scheduling.clone();
+            // SAFETY: the hook only makes async-signal-safe syscalls
+            unsafe {
+                self.pre_exec(move || {
+                    scheduling.apply_to_current_process();
+                    Ok(())
+                });
+            }
+        }
+        #[cfg(not(unix))]
+        let _ = scheduling;
+        self
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl ProcessSchedulingExt for tokio::process::Command {
+    fn scheduling(&mut self, scheduling: &ProcessScheduling) -> &mut Self {
+        self.as_std_mut().scheduling(scheduling);
+        self
+    }
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use std::process::Command;
+
+    use super::*;
+
+    /// The nice value and allowed CPU list of a process, from `/proc`.
+    fn nice_and_cpus(pid: u32) -> (i32, String) {
+        let stat = std::fs::read_to_string(format!("/proc/{pid}/stat")).unwrap();
+        let (_, fields) = stat.rsplit_once(") ").unwrap();
+        // `nice` is field 19; `fields` starts at field 3
+        let nice = fields.split(' ').nth(16).unwrap().parse().unwrap();
+        let status = std::fs::read_to_string(format!("/proc/{pid}/status")).unwrap();
+        let cpus = status
+            .lines()
+            .find_map(|line| line.strip_prefix("Cpus_allowed_list:"))
+            .unwrap()
+            .trim()
+            .to_string();
+        (nice, cpus)
+    }
+
+    #[test]
+    fn child_starts_with_lower_priority_and_pinned_cpu() {
+        let scheduling = ProcessScheduling {
+            priority: ProcessPriority::Idle,
+            cpu_affinity: vec![0],
+        };
+        let mut child = Command::new("sleep")
+            .arg("30")
+            .scheduling(&scheduling)
+            .spawn()
+            .unwrap();
+        scheduling.apply_to_std(&child).unwrap();
+
+        let (nice, cpus) = nice_and_cpus(child.id());
+        child.kill().unwrap();
+        child.wait().unwrap();
+
+        assert_eq!(nice, 19);
+        assert_eq!(cpus, "0");
+    }
+}
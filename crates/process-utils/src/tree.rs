@@ -0,0 +1,290 @@
+//! Killing a child together with everything it spawned.
+//!
+//! Killing a child only stops that process: an ffmpeg started by a shell script, or by
+//! streamlink, keeps running and writing. [`ProcessTree`] tracks the whole tree of a child:
+//!
+//! - on Unix the child leads its own process group (see [`ProcessGroupExt`]) and the group is
+//!   signalled;
+//! - on Windows the child is assigned to a job object, which is terminated.
+//!
+//! On Windows, processes the child starts before it is assigned to the job escape it, so the
+//! tree should be tracked right after spawning.
+
+use std::io;
+
+/// Spawn children as the leader of a new process group, so [`ProcessTree`] can signal every
+/// process they start.
+///
+/// Children no longer receive the terminal's Ctrl+C. This is a no-op on Windows, where
+/// trees are tracked with job objects instead.
+pub trait ProcessGroupExt {
+    fn own_process_group(&mut self) -> &mut Self;
+}
+
+impl ProcessGroupExt for std::process::Command {
+    fn own_process_group(&mut self) -> &mut Self {
+        #[cfg(unix)]
+        {
+            use std::os::unix::process::CommandExt;
+            self.process_group(0);
+        }
+        self
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl ProcessGroupExt for tokio::process::Command {
+    fn own_process_group(&mut self) -> &mut Self {
+        #[cfg(unix)]
+        self.process_group(0);
+        self
+    }
+}
+
+/// A child process and all of its descendants.
+///
+/// The tree is killed when this is dropped, unless [`ProcessTree::release`] was called, so
+/// holding it across a wait kills the tree when the waiting future is cancelled.
+pub struct ProcessTree {
+    #[cfg(unix)]
+    pgid: libc::pid_t,
+    #[cfg(windows)]
+    job: windows::Job,
+    kill_on_drop: bool,
+}
+
+impl ProcessTree {
+    /// Track the tree of a child spawned with [`ProcessGroupExt::own_process_group`].
+    pub fn from_std(child: &std::process::Child) -> io::Result<Self> {
+        #[cfg(unix)]
+        {
+            Self::from_pid(child.id())
+        }
+        #[cfg(windows)]
+        {
+            use std::os::windows::io::AsRawHandle;
+            Self::from_handle(child.as_raw_handle())
+        }
+    }
+
+    /// Track the tree of a child spawned with [`ProcessGroupExt::own_process_group`].
+    ///
+    /// Fails if the child has already been reaped.
+    #[cfg(feature = "tokio")]
+    pub fn from_tokio(child: &tokio::process::Child) -> io::Result<Self> {
+        #[cfg(unix)]
+        {
+            let pid = child
+                .id()
+                .ok_or_else(|| io::Error::other("child process already exited"))?;
+            Self::from_pid(pid)
+        }
+        #[cfg(windows)]
+        {
+            let handle = child
+                .raw_handle()
+                .ok_or_else(|| io::Error::other("child process already exited"))?;
+            Self::from_handle(handle)
+        }
+    }
+
+    #[cfg(unix)]
+    fn from_pid(pid: u32) -> io::Result<Self> {
+        let pid = libc::pid_t::try_from(pid).map_err(io::Error::other)?;
+        // Signalling a group the child merely belongs to could hit this process too
+        // SAFETY: getpgid has no memory-safety preconditions
+        let pgid = unsafe { libc::getpgid(pid) };
+        if pgid != pid {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "child process does not lead its own process group",
+            ));
+        }
+        Ok(Self {
+            pgid,
+            kill_on_drop: true,
+        })
+    }
+
+    #[cfg(windows)]
+    fn from_handle(handle: std::os::windows::io::RawHandle) -> io::Result<Self> {
+        let job = windows::Job::new()?;
+        job.assign(handle)?;
+        Ok(Self {
+            job,
+            kill_on_drop: true,
+        })
+    }
+
+    /// Forcibly kill every process of the tree that is still running.
+    pub fn kill(&self) -> io::Result<()> {
+        #[cfg(unix)]
+        {
+            // SAFETY: kill has no memory-safety preconditions
+            if unsafe { libc::kill(-self.pgid, libc::SIGKILL) } == 0 {
+                return Ok(());
+            }
+            let error = io::Error::last_os_error();
+            // The group is already gone
+            if error.raw_os_error() == Some(libc::ESRCH) {
+                return Ok(());
+            }
+            Err(error)
+        }
+        #[cfg(windows)]
+        {
+            self.job.terminate()
+        }
+    }
+
+    /// Leave the tree running when this is dropped, e.g. once the child exited normally.
+    pub fn release(&mut self) {
+        #[cfg(windows)]
+        if self.kill_on_drop {
+            // Closing a kill-on-close job would still kill what is left in it
+            let _ = self.job.set_kill_on_close(false);
+        }
+        self.kill_on_drop = false;
+    }
+}
+
+impl Drop for ProcessTree {
+    fn drop(&mut self) {
+        if self.kill_on_drop {
+            let _ = self.kill();
+        }
+    }
+}
+
+#[cfg(windows)]
+mod windows {
+    use std::io;
+    use std::os::windows::io::RawHandle;
+
+    use windows_sys::Win32::Foundation::{CloseHandle, HANDLE};
+    use windows_sys::Win32::System::JobObjects::{
+        AssignProcessToJobObject, CreateJobObjectW, JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE,
+        JOBOBJECT_EXTENDED_LIMIT_INFORMATION, JobObjectExtendedLimitInformation,
+        SetInformationJobObject, TerminateJobObject,
+    };
+
+    /// An owned job object handle.
+    pub(super) struct Job(HANDLE);
+
+    // SAFETY: job object handles can be used and closed from any thread
+    unsafe impl Send for Job {}
+    unsafe impl Sync for Job {}
+
+    impl Job {
+        pub(super) fn new() -> io::Result<Self> {
+            // SAFETY: null attributes and name are allowed
+            let handle = unsafe { CreateJobObjectW(std::ptr::null(), std::ptr::null()) };
+            if handle.is_null() {
+                return Err(io::Error::last_os_error());
+            }
+            let job = Self(handle);
+            job.set_kill_on_close(true)?;
+            Ok(job)
+        }
+
+        /// Whether the processes of the job are killed when its last handle is closed, which
+        /// also covers this process exiting.
+        pub(super) fn set_kill_on_close(&self, enabled: bool) -> io::Result<()> {
+            let mut info = JOBOBJECT_EXTENDED_LIMIT_INFORMATION::default();
+            if enabled {
+                info.BasicLimitInformation.LimitFlags = JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE;
+            }
+            // SAFETY: `info` is valid for the given size for the duration of the call
+            let result = unsafe {
+                SetInformationJobObject(
+                    self.0,
+                    JobObjectExtendedLimitInformation,
+                    (&raw const info).cast(),
+                    std::mem::size_of_val(&info) as u32,
+                )
+            };
+            if result == 0 {
+                return Err(io::Error::last_os_error());
+            }
+            Ok(())
+        }
+
+        pub(super) fn assign(&self, process: RawHandle) -> io::Result<()> {
+            // SAFETY: both handles are valid for the duration of the call
+            if unsafe { AssignProcessToJobObject(self.0, process as HANDLE) } == 0 {
+                return Err(io::Error::last_os_error());
+            }
+            Ok(())
+        }
+
+        pub(super) fn terminate(&self) -> io::Result<()> {
+            // SAFETY: the job handle is valid
+            if unsafe { TerminateJobObject(self.0, 1) } == 0 {
+                return Err(io::Error::last_os_error());
+            }
+            Ok(())
+        }
+    }
+
+    impl Drop for Job {
+        fn drop(&mut self) {
+            // SAFETY: the handle is owned and closed once
+            unsafe { CloseHandle(self.0) };
+        }
+    }
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use std::io::{BufRead, BufReader};
+    use std::process::{Command, Stdio};
+    use std::time::{Duration, Instant};
+
+    use super::*;
+
+    /// Whether `pid` has exited, counting zombies nobody reaped as exited.
+    fn has_exited(pid: &str) -> bool {
+        match std::fs::read_to_string(format!("/proc/{pid}/stat")) {
+            Ok(stat) => stat
+                .rsplit_once(") ")
+                .is_some_and(|(_, rest)| rest.starts_with('Z')),
+            Err(_) => true,
+        }
+    }
+
+    #[test]
+    fn kill_reaches_grandchildren() {
+        let mut child = Command::new("sh")
+            .args(["-c", "sleep 30 & echo $!; wait"])
+            .stdout(Stdio::piped())
+            .own_process_group()
+            .spawn()
+            .unwrap();
+        let mut grandchild = String::new();
+        BufReader::new(child.stdout.take().unwrap())
+            .read_line(&mut grandchild)
+            .unwrap();
+        let grandchild = grandchild.trim().to_string();
+
+        let tree = ProcessTree::from_std(&child).unwrap();
+        drop(tree);
+        child.wait().unwrap();
+
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while !has_exited(&grandchild) {
+            assert!(
+                Instant::now() < deadline,
+                "grandchild {grandchild} survived"
+            );
+            std::thread::sleep(Duration::from_millis(20));
+        }
+    }
+
+    #[test]
+    fn rejects_children_sharing_our_group() {
+        let mut child = Command::new("sleep").arg("30").spawn().unwrap();
+        assert!(ProcessTree::from_std(&child).is_err());
+        child.kill().unwrap();
+        child.wait().unwrap();
+    }
+}
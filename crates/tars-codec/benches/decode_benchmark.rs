@@ -0,0 +1,93 @@
+use bytes::Bytes;
+use criterion::{Criterion, criterion_group, criterion_main};
+use rustc_hash::FxHashMap;
+use std::hint::black_box;
+use tars_codec::{TarsValue, decode_tars_value, encode_tars_value_wrapped, view::view_value};
+
+/// A stream list shaped like a Huya `GetLivingInfo` response: `count` stream infos, each with
+/// a few strings, numbers and a flag map.
+fn stream_list(count: usize) -> Bytes {
+    let streams = (0..count)
+        .map(|index| {
+            let mut flags = FxHashMap::default();
+            flags.insert(TarsValue::String("hdr".into()), TarsValue::Int(0));
+            flags.insert(TarsValue::String("p2p".into()), TarsValue::Int(1));
+
+            let mut stream = FxHashMap::default();
+            stream.insert(0, TarsValue::String(format!("cdn-{index}")));
+            stream.insert(1, TarsValue::String(format!("{index}-1234567890-abcdef")));
+            stream.insert(2, TarsValue::String("https://al.flv.huya.com/src".into()));
+            stream.insert(
+                3,
+                TarsValue::String("wsSecret=0123456789abcdef&wsTime=65f0".into()),
+            );
+            stream.insert(4, TarsValue::Long(1_234_567_890 + index as i64));
+            stream.insert(5, TarsValue::Int(index as i32 % 8));
+            stream.insert(6, TarsValue::Map(flags));
+            Box::new(TarsValue::Struct(stream))
+        })
+        .collect();
+
+    let mut root = FxHashMap::default();
+    root.insert(0, TarsValue::Int(1));
+    root.insert(1, TarsValue::List(streams));
+    root.insert(2, TarsValue::String("ok".into()));
+    encode_tars_value_wrapped(&TarsValue::Struct(root))
+        .unwrap()
+        .freeze()
+}
+
+/// Sum the stream priorities and count the CDN names, the way an extractor picks a stream.
+fn walk_owned(bytes: Bytes) -> (i64, usize) {
+    let root = decode_tars_value(bytes).unwrap().try_into_struct().unwrap();
+    let streams = root.get(&1).cloned().unwrap().try_into_list().unwrap();
+    let mut sum = 0;
+    let mut names = 0;
+    for stream in streams {
+        let stream = stream.try_into_struct().unwrap();
+        sum += stream.get(&5).cloned().unwrap().try_into_i64().unwrap();
+        names += stream
+            .get(&0)
+            .and_then(|name| name.as_str())
+            .map_or(0, str::len);
+    }
+    (sum, names)
+}
+
+fn walk_view(bytes: Bytes) -> (i64, usize) {
+    let root = view_value(bytes).unwrap();
+    let streams = root.as_struct().unwrap().get(1).unwrap().unwrap();
+    let mut sum = 0;
+    let mut names = 0;
+    for stream in streams.as_list().unwrap().iter() {
+        let stream = stream.unwrap();
+        let stream = stream.as_struct().unwrap();
+        for field in stream.fields() {
+            match field.unwrap() {
+                (0, name) => names += name.as_str().map_or(0, str::len),
+                (5, priority) => sum += priority.as_i64().unwrap(),
+                _ => {}
+            }
+        }
+    }
+    (sum, names)
+}
+
+fn bench_decode(c: &mut Criterion) {
+    for count in [10, 500] {
+        let bytes = stream_list(count);
+        assert_eq!(walk_owned(bytes.clone()), walk_view(bytes.clone()));
+
+        let mut group = c.benchmark_group(format!("decode_{count}_streams_{}b", bytes.len()));
+        group.bench_function("owned_tars_value", |b| {
+            b.iter(|| walk_owned(black_box(bytes.clone())))
+        });
+        group.bench_function("borrowed_view", |b| {
+            b.iter(|| walk_view(black_box(bytes.clone())))
+        });
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench_decode);
+criterion_main!(benches);
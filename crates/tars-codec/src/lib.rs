@@ -5,6 +5,7 @@ pub mod pool;
 pub mod ser;
 pub mod simd;
 pub mod types;
+pub mod view;
 
 pub use crate::{
     codec::TarsCodec,
@@ -12,6 +13,7 @@ pub use crate::{
     pool::{PooledByteBuffer, PooledDeserializer, PooledSerializer, TarsCodecPool},
     simd::{bulk_ops, utf8_simd},
     types::{TarsMessage, TarsRequestHeader, TarsValue, ValidatedBytes, next_request_id},
+    view::{StructView, TarsValueView, view_value},
 };
 use bytes::{Bytes, BytesMut};
 use tokio_util::codec::Decoder;
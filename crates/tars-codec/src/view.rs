@@ -0,0 +1,520 @@
+//! Borrowing TARS decoder.
+//!
+//! [`TarsDeserializer`](crate::de::TarsDeserializer) builds a [`TarsValue`] tree: every struct
+//! and map becomes a hash map and every list a vector of boxed values. For large responses,
+//! such as a Huya stream list of several hundred KB, most of that tree is thrown away.
+//!
+//! A [`TarsValueView`] instead keeps nested structs, lists and maps as slices of the input
+//! [`Bytes`] and decodes them only when they are visited. Strings and simple lists are
+//! sub-slices of the input too, so walking a view allocates nothing.
+//!
+//! Each nested value is scanned once to find its end when its parent is read, and again when
+//! it is visited.
+
+use bytes::Bytes;
+use rustc_hash::FxHashMap;
+use smallvec::SmallVec;
+
+use crate::error::TarsError;
+use crate::types::{TarsType, TarsValue};
+
+/// A decoded TARS value whose nested values are decoded on access.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TarsValueView {
+    Byte(u8),
+    Short(i16),
+    Int(i32),
+    Long(i64),
+    Float(f32),
+    Double(f64),
+    /// String bytes, not validated as UTF-8 until [`TarsValueView::as_str`]
+    String(Bytes),
+    Struct(StructView),
+    Map(MapView),
+    List(ListView),
+    SimpleList(Bytes),
+}
+
+impl TarsValueView {
+    pub fn as_i32(&self) -> Option<i32> {
+        match *self {
+            TarsValueView::Byte(v) => Some(v as i32),
+            TarsValueView::Short(v) => Some(v as i32),
+            TarsValueView::Int(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub fn as_i64(&self) -> Option<i64> {
+        match *self {
+            TarsValueView::Long(v) => Some(v),
+            _ => self.as_i32().map(i64::from),
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match *self {
+            TarsValueView::Float(v) => Some(v as f64),
+            TarsValueView::Double(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// The string, if this is a string of valid UTF-8.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            TarsValueView::String(bytes) => std::str::from_utf8(bytes).ok(),
+            _ => None,
+        }
+    }
+
+    pub fn as_bytes(&self) -> Option<&Bytes> {
+        match self {
+            TarsValueView::String(bytes) | TarsValueView::SimpleList(bytes) => Some(bytes),
+            _ => None,
+        }
+    }
+
+    pub fn as_struct(&self) -> Option<&StructView> {
+        match self {
+            TarsValueView::Struct(view) => Some(view),
+            _ => None,
+        }
+    }
+
+    pub fn as_map(&self) -> Option<&MapView> {
+        match self {
+            TarsValueView::Map(view) => Some(view),
+            _ => None,
+        }
+    }
+
+    pub fn as_list(&self) -> Option<&ListView> {
+        match self {
+            TarsValueView::List(view) => Some(view),
+            _ => None,
+        }
+    }
+
+    /// Decode the whole value into an owned [`TarsValue`], as
+    /// [`decode_tars_value`](crate::decode_tars_value) would.
+    pub fn to_value(&self) -> Result<TarsValue, TarsError> {
+        Ok(match self {
+            TarsValueView::Byte(v) => TarsValue::Byte(*v),
+            TarsValueView::Short(v) => TarsValue::Short(*v),
+            TarsValueView::Int(v) => TarsValue::Int(*v),
+            TarsValueView::Long(v) => TarsValue::Long(*v),
+            TarsValueView::Float(v) => TarsValue::Float(*v),
+            TarsValueView::Double(v) => TarsValue::Double(*v),
+            TarsValueView::String(bytes) => TarsValue::String(std::str::from_utf8(bytes)?.into()),
+            TarsValueView::Struct(view) => {
+                let mut fields = FxHashMap::default();
+                for field in view.fields() {
+                    let (tag, value) = field?;
+                    fields.insert(tag, value.to_value()?);
+                }
+                TarsValue::Struct(fields)
+            }
+            TarsValueView::Map(view) => {
+                let mut map = FxHashMap::default();
+                for entry in view.iter() {
+                    let (key, value) = entry?;
+                    map.insert(key.to_value()?, value.to_value()?);
+                }
+                TarsValue::Map(map)
+            }
+            TarsValueView::List(view) => {
+                let mut list = SmallVec::with_capacity(view.len());
+                for item in view.iter() {
+                    list.push(Box::new(item?.to_value()?));
+                }
+                TarsValue::List(list)
+            }
+            TarsValueView::SimpleList(bytes) => TarsValue::SimpleList(bytes.clone()),
+        })
+    }
+}
+
+/// The fields of a struct, without its `StructEnd`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StructView {
+    body: Bytes,
+}
+
+impl StructView {
+    /// View struct fields that are not wrapped in `StructBegin`/`StructEnd`, like
+    /// [`decode_tars_struct`](crate::decode_tars_struct) expects.
+    pub fn naked(body: Bytes) -> Self {
+        Self { body }
+    }
+
+    /// Iterate over the `(tag, value)` fields in encoding order.
+    pub fn fields(&self) -> StructFields {
+        StructFields {
+            reader: ViewReader::new(self.body.clone()),
+        }
+    }
+
+    /// The field with `tag`, if present.
+    pub fn get(&self, tag: u8) -> Result<Option<TarsValueView>, TarsError> {
+        for field in self.fields() {
+            let (field_tag, value) = field?;
+            if field_tag == tag {
+                return Ok(Some(value));
+            }
+        }
+        Ok(None)
+    }
+
+    /// The encoded fields.
+    pub fn as_bytes(&self) -> &Bytes {
+        &self.body
+    }
+}
+
+/// Iterator over the fields of a [`StructView`].
+pub struct StructFields {
+    reader: ViewReader,
+}
+
+impl Iterator for StructFields {
+    type Item = Result<(u8, TarsValueView), TarsError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.reader.is_empty() {
+            return None;
+        }
+        let field = self.reader.read_field();
+        if field.is_err() {
+            // Nothing after a malformed field can be trusted
+            self.reader.clear();
+        }
+        Some(field)
+    }
+}
+
+/// The entries of a map.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MapView {
+    len: usize,
+    entries: Bytes,
+}
+
+impl MapView {
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Iterate over the `(key, value)` entries in encoding order.
+    pub fn iter(&self) -> MapEntries {
+        MapEntries {
+            reader: ViewReader::new(self.entries.clone()),
+            remaining: self.len,
+        }
+    }
+}
+
+/// Iterator over the entries of a [`MapView`].
+pub struct MapEntries {
+    reader: ViewReader,
+    remaining: usize,
+}
+
+impl Iterator for MapEntries {
+    type Item = Result<(TarsValueView, TarsValueView), TarsError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let entry = self.reader.read_field().and_then(|(_, key)| {
+            let (_, value) = self.reader.read_field()?;
+            Ok((key, value))
+        });
+        if entry.is_err() {
+            self.remaining = 0;
+        }
+        Some(entry)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.remaining))
+    }
+}
+
+/// The items of a list.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListView {
+    len: usize,
+    items: Bytes,
+}
+
+impl ListView {
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Iterate over the items in order.
+    pub fn iter(&self) -> ListItems {
+        ListItems {
+            reader: ViewReader::new(self.items.clone()),
+            remaining: self.len,
+        }
+    }
+}
+
+/// Iterator over the items of a [`ListView`].
+pub struct ListItems {
+    reader: ViewReader,
+    remaining: usize,
+}
+
+impl Iterator for ListItems {
+    type Item = Result<TarsValueView, TarsError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let item = self.reader.read_field().map(|(_, value)| value);
+        if item.is_err() {
+            self.remaining = 0;
+        }
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.remaining))
+    }
+}
+
+/// View the value encoded in `bytes`, e.g. a struct wrapped in `StructBegin`/`StructEnd`.
+pub fn view_value(bytes: Bytes) -> Result<TarsValueView, TarsError> {
+    ViewReader::new(bytes).read_field().map(|(_, value)| value)
+}
+
+/// Bounds-checked reader over a `Bytes`, slicing instead of copying.
+struct ViewReader {
+    buffer: Bytes,
+    position: usize,
+}
+
+impl ViewReader {
+    fn new(buffer: Bytes) -> Self {
+        Self {
+            buffer,
+            position: 0,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.position >= self.buffer.len()
+    }
+
+    fn clear(&mut self) {
+        self.position = self.buffer.len();
+    }
+
+    fn take(&mut self, len: usize) -> Result<&[u8], TarsError> {
+        let end = self
+            .position
+            .checked_add(len)
+            .filter(|&end| end <= self.buffer.len())
+            .ok_or_else(|| {
+                TarsError::Io(std::io::Error::new(
+                    std::io::ErrorKind::UnexpectedEof,
+                    "Unexpected EOF reading value",
+                ))
+            })?;
+        let bytes = &self.buffer[self.position..end];
+        self.position = end;
+        Ok(bytes)
+    }
+
+    fn take_bytes(&mut self, len: usize) -> Result<Bytes, TarsError> {
+        let start = self.position;
+        self.take(len)?;
+        Ok(self.buffer.slice(start..self.position))
+    }
+
+    fn take_array<const N: usize>(&mut self) -> Result<[u8; N], TarsError> {
+        let mut array = [0; N];
+        array.copy_from_slice(self.take(N)?);
+        Ok(array)
+    }
+
+    fn read_head(&mut self) -> Result<(u8, TarsType), TarsError> {
+        let [head] = self.take_array()?;
+        let type_id =
+            TarsType::try_from(head & 0x0F).map_err(|()| TarsError::InvalidTypeId(head & 0x0F))?;
+        let tag = head >> 4;
+        if tag == 15 {
+            let [extended_tag] = self.take_array()?;
+            Ok((extended_tag, type_id))
+        } else {
+            Ok((tag, type_id))
+        }
+    }
+
+    fn read_field(&mut self) -> Result<(u8, TarsValueView), TarsError> {
+        let (tag, type_id) = self.read_head()?;
+        Ok((tag, self.read_value(type_id)?))
+    }
+
+    /// Length of a map, list or simple list, encoded as an integer field.
+    fn read_len(&mut self) -> Result<usize, TarsError> {
+        let len = self
+            .read_field()?
+            .1
+            .as_i32()
+            .ok_or(TarsError::TypeMismatch {
+                expected: "Int",
+                actual: "Other",
+            })?;
+        usize::try_from(len).map_err(|_| {
+            TarsError::Io(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "Negative length",
+            ))
+        })
+    }
+
+    /// Skip `count` fields and return the bytes they span.
+    fn skip_fields(&mut self, count: usize) -> Result<Bytes, TarsError> {
+        let start = self.position;
+        for _ in 0..count {
+            self.read_field()?;
+        }
+        Ok(self.buffer.slice(start..self.position))
+    }
+
+    fn read_value(&mut self, type_id: TarsType) -> Result<TarsValueView, TarsError> {
+        Ok(match type_id {
+            TarsType::Zero => TarsValueView::Byte(0),
+            TarsType::Int1 => TarsValueView::Byte(self.take_array::<1>()?[0]),
+            TarsType::Int2 => TarsValueView::Short(i16::from_be_bytes(self.take_array()?)),
+            TarsType::Int4 => TarsValueView::Int(i32::from_be_bytes(self.take_array()?)),
+            TarsType::Int8 => TarsValueView::Long(i64::from_be_bytes(self.take_array()?)),
+            TarsType::Float => TarsValueView::Float(f32::from_be_bytes(self.take_array()?)),
+            TarsType::Double => TarsValueView::Double(f64::from_be_bytes(self.take_array()?)),
+            TarsType::String1 => {
+                let [len] = self.take_array()?;
+                TarsValueView::String(self.take_bytes(len as usize)?)
+            }
+            TarsType::String4 => {
+                let len = u32::from_be_bytes(self.take_array()?);
+                TarsValueView::String(self.take_bytes(len as usize)?)
+            }
+            TarsType::StructBegin => {
+                let start = self.position;
+                loop {
+                    let end = self.position;
+                    let (_, type_id) = self.read_head()?;
+                    if type_id == TarsType::StructEnd {
+                        break TarsValueView::Struct(StructView {
+                            body: self.buffer.slice(start..end),
+                        });
+                    }
+                    self.read_value(type_id)?;
+                }
+            }
+            TarsType::Map => {
+                let len = self.read_len()?;
+                let entries = self.skip_fields(len.saturating_mul(2))?;
+                TarsValueView::Map(MapView { len, entries })
+            }
+            TarsType::List => {
+                let len = self.read_len()?;
+                let items = self.skip_fields(len)?;
+                TarsValueView::List(ListView { len, items })
+            }
+            TarsType::SimpleList => {
+                // Element type head, always a byte
+                self.read_head()?;
+                let len = self.read_len()?;
+                TarsValueView::SimpleList(self.take_bytes(len)?)
+            }
+            TarsType::StructEnd => {
+                return Err(TarsError::TypeMismatch {
+                    expected: "Value",
+                    actual: "StructEnd",
+                });
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{decode_tars_value, encode_tars_value_wrapped};
+
+    fn sample() -> TarsValue {
+        let mut stream = FxHashMap::default();
+        stream.insert(0, TarsValue::String("al".into()));
+        stream.insert(1, TarsValue::Long(1 << 40));
+        stream.insert(2, TarsValue::SimpleList(Bytes::from_static(b"\x01\x02")));
+        let mut flags = FxHashMap::default();
+        flags.insert(TarsValue::String("hdr".into()), TarsValue::Int(1));
+        stream.insert(3, TarsValue::Map(flags));
+
+        let mut root = FxHashMap::default();
+        root.insert(0, TarsValue::Int(7));
+        root.insert(
+            1,
+            TarsValue::List(std::iter::repeat_n(Box::new(TarsValue::Struct(stream)), 3).collect()),
+        );
+        root.insert(20, TarsValue::String("x".repeat(300)));
+        TarsValue::Struct(root)
+    }
+
+    #[test]
+    fn view_matches_owned_decoding() {
+        let bytes = encode_tars_value_wrapped(&sample()).unwrap().freeze();
+        let view = view_value(bytes.clone()).unwrap();
+        assert_eq!(view.to_value().unwrap(), decode_tars_value(bytes).unwrap());
+
+        let root = view.as_struct().unwrap();
+        assert_eq!(root.get(0).unwrap().unwrap().as_i32(), Some(7));
+        assert_eq!(root.get(20).unwrap().unwrap().as_str().unwrap().len(), 300);
+        assert!(root.get(5).unwrap().is_none());
+
+        let streams = root.get(1).unwrap().unwrap();
+        let streams = streams.as_list().unwrap();
+        assert_eq!(streams.len(), 3);
+        for stream in streams.iter() {
+            let stream = stream.unwrap();
+            let stream = stream.as_struct().unwrap();
+            assert_eq!(stream.get(0).unwrap().unwrap().as_str(), Some("al"));
+            assert_eq!(stream.get(1).unwrap().unwrap().as_i64(), Some(1 << 40));
+            let flags = stream.get(3).unwrap().unwrap();
+            let (key, value) = flags.as_map().unwrap().iter().next().unwrap().unwrap();
+            assert_eq!(key.as_str(), Some("hdr"));
+            assert_eq!(value.as_i32(), Some(1));
+        }
+    }
+
+    #[test]
+    fn truncated_input_is_an_error() {
+        let bytes = encode_tars_value_wrapped(&sample()).unwrap().freeze();
+        for len in [0, 1, bytes.len() / 2, bytes.len() - 1] {
+            assert!(view_value(bytes.slice(..len)).is_err(), "length {len}");
+        }
+
+        // A malformed field ends the iteration
+        let view = StructView::naked(Bytes::from_static(&[0x02, 0x00]));
+        let fields: Vec<_> = view.fields().collect();
+        assert_eq!(fields.len(), 1);
+        assert!(fields[0].is_err());
+    }
+}
@@ -0,0 +1,24 @@
+use std::hint::black_box;
+
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use ts::mpeg2_crc32;
+
+fn crc32_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Mpeg2Crc32");
+
+    // A typical PAT, a PMT with a few streams, and a section spanning several packets
+    for size in [16usize, 184, 1_024] {
+        let data = (0..size)
+            .map(|i| (i.wrapping_mul(31) ^ (i >> 3)) as u8)
+            .collect::<Vec<_>>();
+        group.throughput(Throughput::Bytes(size as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(size), &data, |b, data| {
+            b.iter(|| mpeg2_crc32(black_box(data)))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, crc32_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,546 @@
+//! Transport stream statistics.
+//!
+//! [`TsAnalyzer`] walks a stream packet by packet and collects the figures a
+//! broadcast monitor looks at, in the spirit of ETSI TR 101 290: bitrate per
+//! PID, PCR repetition interval and jitter, continuity counter errors and
+//! gaps between consecutive PTS and DTS. It keeps a fixed amount of state per
+//! PID, so it can follow live recordings of any length; [`TsAnalyzer::report`]
+//! summarizes what was seen so far.
+//!
+//! PCR jitter is the difference between each PCR and the value predicted from
+//! its byte position at the average transport rate since the last PCR
+//! discontinuity. Variable bitrate streams (HLS segments, muxers without
+//! null packet stuffing) show jitter that players absorb easily; constant
+//! bitrate broadcasts should stay within a few hundred nanoseconds.
+
+use std::collections::BTreeMap;
+
+use crate::{
+    adaptation_field::AdaptationField,
+    packet::PID_NULL,
+    timestamp::{TIMESTAMP_MODULO, packet_pes_header, timestamp_diff},
+    writer::TS_PACKET_SIZE,
+};
+
+/// Timestamp steps larger than this are counted as gaps by default.
+pub const DEFAULT_GAP_THRESHOLD_MS: u64 = 1_000;
+
+/// PCR steps larger than this (or backwards) are discontinuities, not
+/// intervals.
+const PCR_DISCONTINUITY_MS: f64 = 1_000.0;
+
+/// Ticks of the 27 MHz PCR clock per millisecond.
+const PCR_TICKS_PER_MS: f64 = 27_000.0;
+
+/// Full PCR values wrap around at 2^33 * 300 ticks.
+const PCR_MODULO: i64 = TIMESTAMP_MODULO as i64 * 300;
+
+/// Minimum, maximum and mean of a series, in milliseconds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IntervalStats {
+    pub count: u64,
+    pub min_ms: f64,
+    pub max_ms: f64,
+    pub mean_ms: f64,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Accumulator {
+    count: u64,
+    min: f64,
+    max: f64,
+    sum: f64,
+}
+
+impl Accumulator {
+    fn add(&mut self, value: f64) {
+        if self.count == 0 {
+            self.min = value;
+            self.max = value;
+        } else {
+            self.min = self.min.min(value);
+            self.max = self.max.max(value);
+        }
+        self.count += 1;
+        self.sum += value;
+    }
+
+    fn stats(&self) -> Option<IntervalStats> {
+        (self.count > 0).then(|| IntervalStats {
+            count: self.count,
+            min_ms: self.min,
+            max_ms: self.max,
+            mean_ms: self.sum / self.count as f64,
+        })
+    }
+}
+
+/// PCR statistics of a PID.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PcrStats {
+    pub count: u64,
+    /// Time between consecutive PCRs, discontinuities excluded
+    pub interval: Option<IntervalStats>,
+    /// Largest absolute deviation from the predicted PCR
+    pub max_jitter_ms: f64,
+    pub mean_jitter_ms: f64,
+    /// PCR jumps signalled by the discontinuity indicator, going backwards or
+    /// exceeding one second
+    pub discontinuities: u64,
+}
+
+/// Statistics of the PTS or DTS of a PID, one value per PES packet.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimestampStats {
+    pub count: u64,
+    pub first: u64,
+    pub last: u64,
+    /// Signed steps between consecutive timestamps
+    pub delta: Option<IntervalStats>,
+    /// Steps larger than the gap threshold, in either direction
+    pub gaps: u64,
+    /// Steps going backwards. Expected for the PTS of video with B-frames,
+    /// an error for DTS.
+    pub backward: u64,
+}
+
+/// Statistics of one PID.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PidStats {
+    pub pid: u16,
+    pub packets: u64,
+    /// Bits per second over the stream duration
+    pub bitrate_bps: Option<f64>,
+    pub continuity_errors: u64,
+    /// Packets repeating the previous packet's continuity counter
+    pub duplicates: u64,
+    pub scrambled_packets: u64,
+    pub pcr: Option<PcrStats>,
+    pub pts: Option<TimestampStats>,
+    pub dts: Option<TimestampStats>,
+}
+
+/// Summary of a stream.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TsAnalysis {
+    pub packets: u64,
+    /// Packets not starting with the sync byte
+    pub sync_errors: u64,
+    /// Packets with the transport error indicator set
+    pub transport_errors: u64,
+    /// Stream duration from the PCR, or from the timestamps when no PCR is
+    /// present
+    pub duration_secs: Option<f64>,
+    pub bitrate_bps: Option<f64>,
+    /// Per-PID statistics, by PID
+    pub pids: Vec<PidStats>,
+}
+
+impl TsAnalysis {
+    pub fn pid(&self, pid: u16) -> Option<&PidStats> {
+        self.pids.iter().find(|stats| stats.pid == pid)
+    }
+
+    pub fn continuity_errors(&self) -> u64 {
+        self.pids.iter().map(|stats| stats.continuity_errors).sum()
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct PcrState {
+    count: u64,
+    /// Byte offset and value of the last PCR
+    last: Option<(u64, u64)>,
+    /// Byte offset of the first PCR since the last discontinuity
+    reference_offset: u64,
+    /// PCR ticks elapsed since the reference, up to the last PCR
+    elapsed: i64,
+    /// PCR ticks elapsed over the whole stream, discontinuities excluded
+    total: i64,
+    interval: Accumulator,
+    jitter: Accumulator,
+    discontinuities: u64,
+}
+
+impl PcrState {
+    fn push(&mut self, offset: u64, pcr: u64, discontinuity: bool) {
+        self.count += 1;
+        let Some((last_offset, last_pcr)) = self.last.replace((offset, pcr)) else {
+            self.reference_offset = offset;
+            return;
+        };
+        let mut delta = (pcr as i64 - last_pcr as i64).rem_euclid(PCR_MODULO);
+        if delta >= PCR_MODULO / 2 {
+            delta -= PCR_MODULO;
+        }
+        let delta_ms = delta as f64 / PCR_TICKS_PER_MS;
+        if discontinuity || !(0.0..=PCR_DISCONTINUITY_MS).contains(&delta_ms) {
+            self.discontinuities += 1;
+            self.reference_offset = offset;
+            self.elapsed = 0;
+            return;
+        }
+
+        self.interval.add(delta_ms);
+        if last_offset > self.reference_offset {
+            let ticks_per_byte = self.elapsed as f64 / (last_offset - self.reference_offset) as f64;
+            let predicted = (offset - last_offset) as f64 * ticks_per_byte;
+            self.jitter
+                .add(((delta as f64 - predicted) / PCR_TICKS_PER_MS).abs());
+        }
+        self.elapsed += delta;
+        self.total += delta;
+    }
+
+    fn stats(&self) -> Option<PcrStats> {
+        (self.count > 0).then(|| PcrStats {
+            count: self.count,
+            interval: self.interval.stats(),
+            max_jitter_ms: self.jitter.stats().map_or(0.0, |jitter| jitter.max_ms),
+            mean_jitter_ms: self.jitter.stats().map_or(0.0, |jitter| jitter.mean_ms),
+            discontinuities: self.discontinuities,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct TimestampState {
+    count: u64,
+    first: u64,
+    last: Option<u64>,
+    /// Sum of the steps within the gap threshold, in 90 kHz ticks
+    span: i64,
+    delta: Accumulator,
+    gaps: u64,
+    backward: u64,
+}
+
+impl TimestampState {
+    fn push(&mut self, timestamp: u64, gap_threshold_ms: u64) {
+        self.count += 1;
+        let Some(last) = self.last.replace(timestamp) else {
+            self.first = timestamp;
+            return;
+        };
+        let delta = timestamp_diff(timestamp, last);
+        self.delta.add(delta as f64 / 90.0);
+        if delta < 0 {
+            self.backward += 1;
+        }
+        if delta.unsigned_abs() > gap_threshold_ms * 90 {
+            self.gaps += 1;
+        } else {
+            self.span += delta;
+        }
+    }
+
+    fn stats(&self) -> Option<TimestampStats> {
+        let last = self.last?;
+        Some(TimestampStats {
+            count: self.count,
+            first: self.first,
+            last,
+            delta: self.delta.stats(),
+            gaps: self.gaps,
+            backward: self.backward,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct PidState {
+    packets: u64,
+    last_continuity: Option<u8>,
+    /// The previous packet was a duplicate
+    duplicated: bool,
+    continuity_errors: u64,
+    duplicates: u64,
+    scrambled_packets: u64,
+    pcr: PcrState,
+    pts: TimestampState,
+    dts: TimestampState,
+}
+
+impl PidState {
+    fn check_continuity(&mut self, continuity: u8, has_payload: bool, discontinuity: bool) {
+        let Some(last) = self.last_continuity else {
+            self.last_continuity = Some(continuity);
+            return;
+        };
+        if discontinuity {
+            self.duplicated = false;
+        } else if !has_payload {
+            // Packets without payload repeat the counter
+            if continuity != last {
+                self.continuity_errors += 1;
+            }
+            return;
+        } else if continuity == last {
+            // A packet may be sent twice, but not three times
+            if self.duplicated {
+                self.continuity_errors += 1;
+            }
+            self.duplicates += 1;
+            self.duplicated = true;
+            return;
+        } else if continuity != (last + 1) & 0x0F {
+            self.continuity_errors += 1;
+        }
+        self.duplicated = false;
+        self.last_continuity = Some(continuity);
+    }
+}
+
+/// Collects per-PID statistics over a transport stream.
+#[derive(Debug, Clone)]
+pub struct TsAnalyzer {
+    pids: BTreeMap<u16, PidState>,
+    packets: u64,
+    sync_errors: u64,
+    transport_errors: u64,
+    /// Trailing bytes of the last buffer that did not form a whole packet
+    pending: Vec<u8>,
+    gap_threshold_ms: u64,
+}
+
+impl Default for TsAnalyzer {
+    fn default() -> Self {
+        Self {
+            pids: BTreeMap::new(),
+            packets: 0,
+            sync_errors: 0,
+            transport_errors: 0,
+            pending: Vec::new(),
+            gap_threshold_ms: DEFAULT_GAP_THRESHOLD_MS,
+        }
+    }
+}
+
+impl TsAnalyzer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Count PTS/DTS steps larger than `threshold_ms` as gaps.
+    pub fn with_gap_threshold_ms(mut self, threshold_ms: u64) -> Self {
+        self.gap_threshold_ms = threshold_ms;
+        self
+    }
+
+    /// Forget everything seen so far.
+    pub fn reset(&mut self) {
+        *self = Self::new().with_gap_threshold_ms(self.gap_threshold_ms);
+    }
+
+    /// Feed stream data. Packets may be split across calls.
+    pub fn push(&mut self, data: &[u8]) {
+        let mut data = data;
+        if !self.pending.is_empty() {
+            let missing = (TS_PACKET_SIZE - self.pending.len()).min(data.len());
+            self.pending.extend_from_slice(&data[..missing]);
+            data = &data[missing..];
+            if self.pending.len() < TS_PACKET_SIZE {
+                return;
+            }
+            let packet = std::mem::take(&mut self.pending);
+            self.push_packet(&packet);
+        }
+        let mut packets = data.chunks_exact(TS_PACKET_SIZE);
+        for packet in &mut packets {
+            self.push_packet(packet);
+        }
+        self.pending.extend_from_slice(packets.remainder());
+    }
+
+    /// Feed one 188-byte packet.
+    pub fn push_packet(&mut self, packet: &[u8]) {
+        let offset = self.packets * TS_PACKET_SIZE as u64;
+        self.packets += 1;
+        if packet.len() < TS_PACKET_SIZE || packet[0] != 0x47 {
+            self.sync_errors += 1;
+            return;
+        }
+        if packet[1] & 0x80 != 0 {
+            self.transport_errors += 1;
+        }
+        let pid = (u16::from(packet[1] & 0x1F) << 8) | u16::from(packet[2]);
+        let state = self.pids.entry(pid).or_default();
+        state.packets += 1;
+        if pid == PID_NULL {
+            return;
+        }
+        if packet[3] & 0xC0 != 0 {
+            state.scrambled_packets += 1;
+        }
+
+        let adaptation_field = (packet[3] & 0x20 != 0 && packet[4] > 0)
+            .then(|| packet.get(5..5 + packet[4] as usize))
+            .flatten()
+            .and_then(AdaptationField::parse);
+        let discontinuity = adaptation_field
+            .as_ref()
+            .is_some_and(|field| field.discontinuity_indicator);
+        state.check_continuity(packet[3] & 0x0F, packet[3] & 0x10 != 0, discontinuity);
+        if let Some(pcr) = adaptation_field.and_then(|field| field.pcr) {
+            state.pcr.push(offset, pcr.as_27mhz(), discontinuity);
+        }
+
+        if let Some(header) = packet_pes_header(packet) {
+            if let Some(pts) = header.pts {
+                state.pts.push(pts, self.gap_threshold_ms);
+            }
+            if let Some(dts) = header.dts {
+                state.dts.push(dts, self.gap_threshold_ms);
+            }
+        }
+    }
+
+    /// Summarize the stream seen so far.
+    pub fn report(&self) -> TsAnalysis {
+        let pcr_secs = self
+            .pids
+            .values()
+            .filter(|state| state.pcr.count > 1)
+            .max_by_key(|state| state.pcr.count)
+            .map(|state| state.pcr.total as f64 / PCR_TICKS_PER_MS / 1000.0);
+        let timestamp_secs = || {
+            self.pids
+                .values()
+                .map(|state| {
+                    let timestamps = if state.dts.count > 0 {
+                        &state.dts
+                    } else {
+                        &state.pts
+                    };
+                    timestamps.span
+                })
+                .max()
+                .filter(|span| *span > 0)
+                .map(|span| span as f64 / 90_000.0)
+        };
+        let duration_secs = pcr_secs.or_else(timestamp_secs).filter(|secs| *secs > 0.0);
+        let bitrate = |packets: u64| {
+            duration_secs.map(|secs| (packets * TS_PACKET_SIZE as u64 * 8) as f64 / secs)
+        };
+
+        TsAnalysis {
+            packets: self.packets,
+            sync_errors: self.sync_errors,
+            transport_errors: self.transport_errors,
+            duration_secs,
+            bitrate_bps: bitrate(self.packets),
+            pids: self
+                .pids
+                .iter()
+                .map(|(pid, state)| PidStats {
+                    pid: *pid,
+                    packets: state.packets,
+                    bitrate_bps: bitrate(state.packets),
+                    continuity_errors: state.continuity_errors,
+                    duplicates: state.duplicates,
+                    scrambled_packets: state.scrambled_packets,
+                    pcr: state.pcr.stats(),
+                    pts: state.pts.stats(),
+                    dts: state.dts.stats(),
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        mux::{EsFrame, TsMuxer},
+        pmt::StreamType,
+    };
+
+    /// 25 fps video with a PCR on every frame, jumping 2 s after `gap_after`.
+    fn stream(frames: u64, gap_after: Option<u64>) -> Vec<u8> {
+        let mut muxer = TsMuxer::new(1, 0x1000);
+        muxer
+            .add_stream(0x100, StreamType::H264, Vec::new())
+            .unwrap();
+        let mut data = Vec::new();
+        muxer.write_tables(&mut data).unwrap();
+        let payload = [0u8; 1000];
+        for frame in 0..frames {
+            let mut dts = 90_000 + frame * 3_600;
+            if gap_after.is_some_and(|gap| frame > gap) {
+                dts += 180_000;
+            }
+            muxer
+                .write_frame(
+                    &mut data,
+                    &EsFrame {
+                        pid: 0x100,
+                        pts: dts + 7_200,
+                        dts: Some(dts),
+                        random_access: frame == 0,
+                        data: &payload,
+                    },
+                )
+                .unwrap();
+        }
+        data
+    }
+
+    #[test]
+    fn test_reports_bitrate_and_pcr_statistics() {
+        let data = stream(25, None);
+        let mut analyzer = TsAnalyzer::new();
+        // Split mid-packet to exercise reassembly
+        analyzer.push(&data[..1000]);
+        analyzer.push(&data[1000..]);
+        let report = analyzer.report();
+
+        assert_eq!(report.packets, (data.len() / TS_PACKET_SIZE) as u64);
+        assert_eq!(report.continuity_errors(), 0);
+        assert!((report.duration_secs.unwrap() - 0.96).abs() < 1e-9);
+
+        let video = report.pid(0x100).unwrap();
+        let pcr = video.pcr.as_ref().unwrap();
+        assert_eq!(pcr.count, 25);
+        let interval = pcr.interval.unwrap();
+        assert!((interval.mean_ms - 40.0).abs() < 1e-9);
+        // Equal frame sizes make the stream constant bitrate
+        assert!(pcr.max_jitter_ms < 1e-6);
+        assert!(video.bitrate_bps.unwrap() > 200_000.0);
+
+        let dts = video.dts.as_ref().unwrap();
+        assert_eq!((dts.count, dts.gaps, dts.backward), (25, 0, 0));
+    }
+
+    #[test]
+    fn test_counts_continuity_errors_and_timestamp_gaps() {
+        let mut data = stream(10, Some(4));
+        let video_packets: Vec<usize> = data
+            .chunks_exact(TS_PACKET_SIZE)
+            .enumerate()
+            .filter(|(_, packet)| packet[2] == 0x00 && packet[1] & 0x1F == 0x01)
+            .map(|(index, _)| index)
+            .collect();
+        // Drop one video packet and duplicate another
+        let dropped = video_packets[3] * TS_PACKET_SIZE;
+        data.drain(dropped..dropped + TS_PACKET_SIZE);
+        let duplicate = video_packets[10] * TS_PACKET_SIZE - TS_PACKET_SIZE;
+        let copy = data[duplicate..duplicate + TS_PACKET_SIZE].to_vec();
+        data.splice(duplicate..duplicate, copy);
+
+        let mut analyzer = TsAnalyzer::new();
+        analyzer.push(&data);
+        let report = analyzer.report();
+        let video = report.pid(0x100).unwrap();
+        assert_eq!(video.continuity_errors, 1);
+        assert_eq!(video.duplicates, 1);
+        assert_eq!(video.dts.as_ref().unwrap().gaps, 1);
+        // The 2 s PCR jump is a discontinuity, not an interval
+        let pcr = video.pcr.as_ref().unwrap();
+        assert_eq!(pcr.discontinuities, 1);
+        assert!(pcr.interval.unwrap().max_ms < 41.0);
+
+        analyzer.reset();
+        assert_eq!(analyzer.report().packets, 0);
+    }
+}
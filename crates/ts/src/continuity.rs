@@ -0,0 +1,220 @@
+//! Continuity counter repair.
+//!
+//! Transport streams stitched together from separately produced pieces (HLS
+//! segments, failover sources, re-encoded ad breaks) restart or jump their
+//! continuity counters at every join, which players report as packet loss.
+//! [`ContinuityFixer`] renumbers the counter of every PID so the output
+//! counts up without gaps, following the ISO/IEC 13818-1 rules:
+//!
+//! - packets without payload (adaptation field only) repeat the previous
+//!   counter instead of incrementing it;
+//! - a duplicate packet repeats the counter of the packet it duplicates, and
+//!   can optionally be dropped altogether.
+
+use std::collections::HashMap;
+
+use bytes::{Bytes, BytesMut};
+
+use crate::{packet::PID_NULL, writer::TS_PACKET_SIZE};
+
+/// What [`ContinuityFixer::fix_packet`] did to a packet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContinuityFix {
+    /// The counter was already correct, or the packet is not subject to
+    /// continuity (null packets, packets without a sync byte)
+    Unchanged,
+    /// The counter was renumbered
+    Rewritten { from: u8, to: u8 },
+    /// The packet repeats the previous packet of its PID
+    Duplicate,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct PidContinuity {
+    /// Counter of the last payload packet in the current source, as received
+    last_input: Option<u8>,
+    /// Counter of the last packet written
+    last_output: u8,
+}
+
+/// Rewrites continuity counters across a packet stream, per PID.
+#[derive(Debug, Clone, Default)]
+pub struct ContinuityFixer {
+    pids: HashMap<u16, PidContinuity>,
+    drop_duplicates: bool,
+}
+
+impl ContinuityFixer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Drop duplicate packets in [`fix`](Self::fix) instead of keeping them
+    /// with a repeated counter.
+    pub fn with_drop_duplicates(mut self, drop_duplicates: bool) -> Self {
+        self.drop_duplicates = drop_duplicates;
+        self
+    }
+
+    /// Mark the start of packets from another source.
+    ///
+    /// Output counters keep counting on, but the first packet of each PID is
+    /// no longer compared with the previous source's counters, so a
+    /// coincidentally equal counter is not mistaken for a duplicate.
+    pub fn start_source(&mut self) {
+        for state in self.pids.values_mut() {
+            state.last_input = None;
+        }
+    }
+
+    /// Forget every PID, starting a new output stream.
+    pub fn reset(&mut self) {
+        self.pids.clear();
+    }
+
+    /// Counter of the last packet written on `pid`.
+    pub fn last_counter(&self, pid: u16) -> Option<u8> {
+        self.pids.get(&pid).map(|state| state.last_output)
+    }
+
+    /// Renumber the continuity counter of one 188-byte packet in place.
+    ///
+    /// The first packet seen on a PID keeps its counter.
+    pub fn fix_packet(&mut self, packet: &mut [u8]) -> ContinuityFix {
+        if packet.len() < 4 || packet[0] != 0x47 {
+            return ContinuityFix::Unchanged;
+        }
+        let pid = (u16::from(packet[1] & 0x1F) << 8) | u16::from(packet[2]);
+        if pid == PID_NULL {
+            return ContinuityFix::Unchanged;
+        }
+        let has_payload = packet[3] & 0x10 != 0;
+        let input = packet[3] & 0x0F;
+
+        let (output, duplicate) = match self.pids.get_mut(&pid) {
+            None => {
+                self.pids.insert(
+                    pid,
+                    PidContinuity {
+                        last_input: has_payload.then_some(input),
+                        last_output: input,
+                    },
+                );
+                return ContinuityFix::Unchanged;
+            }
+            Some(state) if !has_payload => (state.last_output, false),
+            Some(state) if state.last_input == Some(input) => (state.last_output, true),
+            Some(state) => {
+                state.last_input = Some(input);
+                state.last_output = (state.last_output + 1) & 0x0F;
+                (state.last_output, false)
+            }
+        };
+
+        packet[3] = (packet[3] & 0xF0) | output;
+        if duplicate {
+            ContinuityFix::Duplicate
+        } else if output != input {
+            ContinuityFix::Rewritten {
+                from: input,
+                to: output,
+            }
+        } else {
+            ContinuityFix::Unchanged
+        }
+    }
+
+    /// Renumber the continuity counters of a buffer of whole packets.
+    ///
+    /// Trailing bytes that do not form a whole packet are kept as-is.
+    pub fn fix(&mut self, data: &[u8]) -> Bytes {
+        let mut output = BytesMut::with_capacity(data.len());
+        let mut packets = data.chunks_exact(TS_PACKET_SIZE);
+        for packet in &mut packets {
+            let start = output.len();
+            output.extend_from_slice(packet);
+            let fix = self.fix_packet(&mut output[start..]);
+            if fix == ContinuityFix::Duplicate && self.drop_duplicates {
+                output.truncate(start);
+            }
+        }
+        output.extend_from_slice(packets.remainder());
+        output.freeze()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn packet(pid: u16, continuity: u8, has_payload: bool, fill: u8) -> Vec<u8> {
+        let mut packet = vec![fill; TS_PACKET_SIZE];
+        packet[0] = 0x47;
+        packet[1] = (pid >> 8) as u8;
+        packet[2] = pid as u8;
+        packet[3] = if has_payload { 0x10 } else { 0x20 } | continuity;
+        if !has_payload {
+            packet[4] = 183;
+        }
+        packet
+    }
+
+    fn counters(data: &[u8], pid: u16) -> Vec<u8> {
+        data.chunks_exact(TS_PACKET_SIZE)
+            .filter(|packet| (u16::from(packet[1] & 0x1F) << 8) | u16::from(packet[2]) == pid)
+            .map(|packet| packet[3] & 0x0F)
+            .collect()
+    }
+
+    #[test]
+    fn test_renumbers_joined_sources() {
+        let mut fixer = ContinuityFixer::new();
+        let first = [packet(0x100, 14, true, 0), packet(0x100, 15, true, 1)].concat();
+        let fixed = fixer.fix(&first);
+        assert_eq!(fixed.as_ref(), first.as_slice());
+
+        fixer.start_source();
+        let second = [
+            packet(0x100, 15, true, 2),
+            packet(0x100, 0, false, 0xFF),
+            packet(0x100, 0, true, 3),
+            packet(0x101, 7, true, 4),
+        ]
+        .concat();
+        let fixed = fixer.fix(&second);
+        assert_eq!(counters(&fixed, 0x100), vec![0, 0, 1]);
+        // A PID first seen in the second source keeps its counter.
+        assert_eq!(counters(&fixed, 0x101), vec![7]);
+        assert_eq!(fixer.last_counter(0x100), Some(1));
+    }
+
+    #[test]
+    fn test_duplicates_repeat_or_drop() {
+        let data = [
+            packet(0x100, 4, true, 0),
+            packet(0x100, 4, true, 0),
+            packet(0x100, 5, true, 1),
+        ]
+        .concat();
+
+        let mut fixer = ContinuityFixer::new();
+        let mut first = data[..TS_PACKET_SIZE].to_vec();
+        let mut duplicate = data[TS_PACKET_SIZE..2 * TS_PACKET_SIZE].to_vec();
+        assert_eq!(fixer.fix_packet(&mut first), ContinuityFix::Unchanged);
+        assert_eq!(fixer.fix_packet(&mut duplicate), ContinuityFix::Duplicate);
+
+        let kept = ContinuityFixer::new().fix(&data);
+        assert_eq!(counters(&kept, 0x100), vec![4, 4, 5]);
+
+        let dropped = ContinuityFixer::new().with_drop_duplicates(true).fix(&data);
+        assert_eq!(counters(&dropped, 0x100), vec![4, 5]);
+    }
+
+    #[test]
+    fn test_null_packets_are_ignored() {
+        let mut fixer = ContinuityFixer::new();
+        let mut null = packet(PID_NULL, 9, true, 0);
+        assert_eq!(fixer.fix_packet(&mut null), ContinuityFix::Unchanged);
+        assert_eq!(fixer.last_counter(PID_NULL), None);
+    }
+}
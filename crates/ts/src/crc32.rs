@@ -3,9 +3,13 @@
 /// Polynomial: 0x04C11DB7, init: 0xFFFFFFFF, no bit reflection, no final XOR.
 /// This is NOT the same as the zlib/ISO 3309 CRC-32.
 ///
-/// Compile-time generated 256-entry lookup table for MPEG-2 CRC-32.
-const CRC32_TABLE: [u32; 256] = {
-    let mut table = [0u32; 256];
+/// Compile-time generated slice-by-8 lookup tables for MPEG-2 CRC-32.
+///
+/// `CRC32_TABLES[0]` is the classic byte-at-a-time table; `CRC32_TABLES[k]` advances a byte
+/// through `k` further zero bytes, so eight input bytes are folded with eight independent
+/// lookups instead of a chain of eight dependent ones.
+const CRC32_TABLES: [[u32; 256]; 8] = {
+    let mut tables = [[0u32; 256]; 8];
     let mut i = 0u32;
     while i < 256 {
         let mut crc = i << 24;
@@ -18,17 +22,43 @@ const CRC32_TABLE: [u32; 256] = {
             }
             j += 1;
         }
-        table[i as usize] = crc;
+        tables[0][i as usize] = crc;
         i += 1;
     }
-    table
+
+    let mut k = 1;
+    while k < 8 {
+        let mut i = 0;
+        while i < 256 {
+            let prev = tables[k - 1][i];
+            tables[k][i] = (prev << 8) ^ tables[0][(prev >> 24) as usize];
+            i += 1;
+        }
+        k += 1;
+    }
+    tables
 };
 
 /// Compute MPEG-2 CRC-32 over a byte slice.
 pub fn mpeg2_crc32(data: &[u8]) -> u32 {
+    let [t0, t1, t2, t3, t4, t5, t6, t7] = &CRC32_TABLES;
     let mut crc: u32 = 0xFFFF_FFFF;
-    for &byte in data {
-        crc = (crc << 8) ^ CRC32_TABLE[((crc >> 24) ^ byte as u32) as usize];
+
+    let (chunks, rest) = data.as_chunks::<8>();
+    for chunk in chunks {
+        let hi = crc ^ u32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        let lo = u32::from_be_bytes([chunk[4], chunk[5], chunk[6], chunk[7]]);
+        crc = t7[(hi >> 24) as usize]
+            ^ t6[((hi >> 16) & 0xFF) as usize]
+            ^ t5[((hi >> 8) & 0xFF) as usize]
+            ^ t4[(hi & 0xFF) as usize]
+            ^ t3[(lo >> 24) as usize]
+            ^ t2[((lo >> 16) & 0xFF) as usize]
+            ^ t1[((lo >> 8) & 0xFF) as usize]
+            ^ t0[(lo & 0xFF) as usize];
+    }
+    for &byte in rest {
+        crc = (crc << 8) ^ t0[((crc >> 24) ^ byte as u32) as usize];
     }
     crc
 }
@@ -49,6 +79,29 @@ mod tests {
         assert_eq!(mpeg2_crc32(b"123456789"), 0x0376_E6E7);
     }
 
+    #[test]
+    fn test_matches_bytewise_reference() {
+        fn reference(data: &[u8]) -> u32 {
+            let mut crc: u32 = 0xFFFF_FFFF;
+            for &byte in data {
+                crc = (crc << 8) ^ CRC32_TABLES[0][((crc >> 24) ^ byte as u32) as usize];
+            }
+            crc
+        }
+
+        let data = (0..1_000u32)
+            .map(|i| (i.wrapping_mul(2_654_435_761) >> 13) as u8)
+            .collect::<Vec<_>>();
+        // Every length around the 8-byte chunking, plus long inputs
+        for len in (0..=33).chain([183, 184, 1_000]) {
+            assert_eq!(
+                mpeg2_crc32(&data[..len]),
+                reference(&data[..len]),
+                "len {len}"
+            );
+        }
+    }
+
     #[test]
     fn test_empty_data() {
         // CRC-32/MPEG-2 of empty data is 0xFFFFFFFF (init value, no processing)
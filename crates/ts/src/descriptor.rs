@@ -14,6 +14,10 @@ pub const TAG_DTS: u8 = 0x7B;
 pub const TAG_AAC: u8 = 0x7C;
 /// Subtitling descriptor (tag 0x59)
 pub const TAG_SUBTITLING: u8 = 0x59;
+/// DVB service descriptor (tag 0x48)
+pub const TAG_SERVICE: u8 = 0x48;
+/// DVB short event descriptor (tag 0x4D)
+pub const TAG_SHORT_EVENT: u8 = 0x4D;
 
 /// Zero-copy descriptor reference.
 #[derive(Debug, Clone)]
@@ -164,10 +168,110 @@ pub fn parse_ac3_descriptor(data: &[u8]) -> Option<Ac3Descriptor> {
     })
 }
 
+/// A single subtitling descriptor entry, one per subtitle language and page.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubtitlingEntry {
+    /// 3-character ISO 639-2 language code
+    pub language_code: [u8; 3],
+    /// 0x10-0x15 normal subtitles, 0x20-0x25 for the hard of hearing
+    pub subtitling_type: u8,
+    /// Page carrying the subtitles of this entry
+    pub composition_page_id: u16,
+    /// Page carrying data shared between subtitle services (CLUTs, logos)
+    pub ancillary_page_id: u16,
+}
+
+impl SubtitlingEntry {
+    pub fn is_hard_of_hearing(&self) -> bool {
+        (0x20..=0x25).contains(&self.subtitling_type)
+    }
+}
+
+/// Parse DVB subtitling descriptor (tag 0x59).
+pub fn parse_subtitling_descriptor(data: &[u8]) -> Vec<SubtitlingEntry> {
+    data.chunks_exact(8)
+        .map(|entry| SubtitlingEntry {
+            language_code: [entry[0], entry[1], entry[2]],
+            subtitling_type: entry[3],
+            composition_page_id: u16::from_be_bytes([entry[4], entry[5]]),
+            ancillary_page_id: u16::from_be_bytes([entry[6], entry[7]]),
+        })
+        .collect()
+}
+
+/// Parsed DVB service descriptor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServiceDescriptor {
+    /// Service type (0x01 = digital television, 0x02 = digital radio, ...)
+    pub service_type: u8,
+    pub provider_name: String,
+    pub service_name: String,
+}
+
+/// Parse DVB service descriptor (tag 0x48).
+pub fn parse_service_descriptor(data: &[u8]) -> Option<ServiceDescriptor> {
+    let service_type = *data.first()?;
+    let provider_length = *data.get(1)? as usize;
+    let provider_end = 2 + provider_length;
+    let service_length = *data.get(provider_end)? as usize;
+    let service_end = provider_end + 1 + service_length;
+    if service_end > data.len() {
+        return None;
+    }
+    Some(ServiceDescriptor {
+        service_type,
+        provider_name: crate::si::decode_dvb_text(&data[2..provider_end]),
+        service_name: crate::si::decode_dvb_text(&data[provider_end + 1..service_end]),
+    })
+}
+
+/// Parsed DVB short event descriptor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShortEventDescriptor {
+    /// ISO 639-2 language code of the texts
+    pub language_code: [u8; 3],
+    pub event_name: String,
+    pub text: String,
+}
+
+/// Parse DVB short event descriptor (tag 0x4D).
+pub fn parse_short_event_descriptor(data: &[u8]) -> Option<ShortEventDescriptor> {
+    if data.len() < 5 {
+        return None;
+    }
+    let name_length = data[3] as usize;
+    let name_end = 4 + name_length;
+    let text_length = *data.get(name_end)? as usize;
+    let text_end = name_end + 1 + text_length;
+    if text_end > data.len() {
+        return None;
+    }
+    Some(ShortEventDescriptor {
+        language_code: [data[0], data[1], data[2]],
+        event_name: crate::si::decode_dvb_text(&data[4..name_end]),
+        text: crate::si::decode_dvb_text(&data[name_end + 1..text_end]),
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_subtitling_descriptor() {
+        let data = [
+            b'e', b'n', b'g', 0x10, 0x00, 0x01, 0x00, 0x02, b'd', b'e', b'u', 0x20, 0x00, 0x03,
+            0x00, 0x03,
+        ];
+        let entries = parse_subtitling_descriptor(&data);
+        assert_eq!(entries.len(), 2);
+        assert_eq!(&entries[0].language_code, b"eng");
+        assert_eq!(entries[0].composition_page_id, 1);
+        assert_eq!(entries[0].ancillary_page_id, 2);
+        assert!(!entries[0].is_hard_of_hearing());
+        assert!(entries[1].is_hard_of_hearing());
+    }
+
     #[test]
     fn test_descriptor_iterator_empty() {
         let iter = DescriptorIterator::new(Bytes::new());
@@ -254,4 +358,30 @@ mod tests {
     fn test_parse_ac3_descriptor_empty() {
         assert!(parse_ac3_descriptor(&[]).is_none());
     }
+
+    #[test]
+    fn test_parse_service_descriptor() {
+        let mut data = vec![0x01, 0x03];
+        data.extend_from_slice(b"ARD");
+        data.push(0x07);
+        data.extend_from_slice(b"Das Ers");
+        let desc = parse_service_descriptor(&data).unwrap();
+        assert_eq!(desc.service_type, 0x01);
+        assert_eq!(desc.provider_name, "ARD");
+        assert_eq!(desc.service_name, "Das Ers");
+
+        assert!(parse_service_descriptor(&data[..6]).is_none());
+    }
+
+    #[test]
+    fn test_parse_short_event_descriptor() {
+        let mut data = b"deu".to_vec();
+        data.push(0x0A);
+        data.extend_from_slice(b"Tagesschau");
+        data.push(0x00);
+        let desc = parse_short_event_descriptor(&data).unwrap();
+        assert_eq!(&desc.language_code, b"deu");
+        assert_eq!(desc.event_name, "Tagesschau");
+        assert!(desc.text.is_empty());
+    }
 }
@@ -0,0 +1,1067 @@
+//! DVB subtitles (ETSI EN 300 743).
+//!
+//! DVB subtitles are bitmaps carried in private data PES packets
+//! (stream type 0x06) announced by a subtitling descriptor in the PMT. Each
+//! PES packet holds a sequence of segments belonging to a composition page
+//! (the subtitles of one language) or an ancillary page shared between
+//! languages:
+//!
+//! - a page composition lists the regions shown on screen and their positions;
+//! - a region composition sizes a region and places objects inside it;
+//! - a CLUT definition sets the colors of a region's pixel codes;
+//! - object data carries the run-length coded pixels of an object;
+//! - an end of display set segment closes the update.
+//!
+//! [`parse_subtitle_segments`] splits a PES payload into segments, and
+//! [`DvbSubtitleDecoder`] follows one page across PES packets, rendering
+//! each display set into RGBA bitmaps timed by the PES PTS.
+//!
+//! Restamping a subtitle stream only touches the PES header, so its segments
+//! pass through [`restamp`](crate::timestamp::restamp) unchanged.
+
+use std::collections::HashMap;
+use std::io::Cursor;
+
+use bytes::Bytes;
+use bytes_util::BitReader;
+
+use crate::{Result, TsError, descriptor::SubtitlingEntry};
+
+/// `data_identifier` of a DVB subtitle PES payload
+pub const DATA_IDENTIFIER_DVB_SUBTITLE: u8 = 0x20;
+/// Page composition segment
+pub const SEGMENT_PAGE_COMPOSITION: u8 = 0x10;
+/// Region composition segment
+pub const SEGMENT_REGION_COMPOSITION: u8 = 0x11;
+/// CLUT definition segment
+pub const SEGMENT_CLUT_DEFINITION: u8 = 0x12;
+/// Object data segment
+pub const SEGMENT_OBJECT_DATA: u8 = 0x13;
+/// Display definition segment
+pub const SEGMENT_DISPLAY_DEFINITION: u8 = 0x14;
+/// End of display set segment
+pub const SEGMENT_END_OF_DISPLAY_SET: u8 = 0x80;
+
+const SEGMENT_SYNC_BYTE: u8 = 0x0F;
+const END_OF_PES_DATA_FIELD: u8 = 0xFF;
+
+/// Display size assumed when a stream sends no display definition.
+const DEFAULT_DISPLAY: (u16, u16) = (720, 576);
+
+fn invalid(message: &str) -> TsError {
+    TsError::InvalidDvbSubtitle(message.to_string())
+}
+
+fn insufficient(expected: usize, actual: usize) -> TsError {
+    TsError::InsufficientData { expected, actual }
+}
+
+/// One segment of a subtitle PES payload.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubtitleSegment {
+    pub segment_type: u8,
+    pub page_id: u16,
+    /// Segment data after the segment header
+    pub data: Bytes,
+}
+
+/// Split the payload of a DVB subtitle PES packet (after the PES header)
+/// into its segments.
+pub fn parse_subtitle_segments(payload: &Bytes) -> Result<Vec<SubtitleSegment>> {
+    if payload.len() < 2 {
+        return Err(insufficient(2, payload.len()));
+    }
+    if payload[0] != DATA_IDENTIFIER_DVB_SUBTITLE || payload[1] != 0x00 {
+        return Err(invalid("not a DVB subtitle stream"));
+    }
+
+    let mut segments = Vec::new();
+    let mut offset = 2;
+    while offset < payload.len() && payload[offset] == SEGMENT_SYNC_BYTE {
+        if offset + 6 > payload.len() {
+            return Err(insufficient(offset + 6, payload.len()));
+        }
+        let segment_type = payload[offset + 1];
+        let page_id = u16::from_be_bytes([payload[offset + 2], payload[offset + 3]]);
+        let length = u16::from_be_bytes([payload[offset + 4], payload[offset + 5]]) as usize;
+        let end = offset + 6 + length;
+        if end > payload.len() {
+            return Err(insufficient(end, payload.len()));
+        }
+        segments.push(SubtitleSegment {
+            segment_type,
+            page_id,
+            data: payload.slice(offset + 6..end),
+        });
+        offset = end;
+    }
+    if offset < payload.len() && payload[offset] != END_OF_PES_DATA_FIELD {
+        return Err(invalid("missing segment sync byte"));
+    }
+    Ok(segments)
+}
+
+/// How a page composition relates to the previous display sets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PageState {
+    /// Update of the current page
+    NormalCase,
+    /// Complete page, a decoder can start here
+    AcquisitionPoint,
+    /// Complete page starting a new epoch: previous regions and CLUTs are gone
+    ModeChange,
+    Reserved,
+}
+
+impl From<u8> for PageState {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => PageState::NormalCase,
+            1 => PageState::AcquisitionPoint,
+            2 => PageState::ModeChange,
+            _ => PageState::Reserved,
+        }
+    }
+}
+
+/// A region placed on a page.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PageRegion {
+    pub region_id: u8,
+    pub horizontal_address: u16,
+    pub vertical_address: u16,
+}
+
+/// Page composition segment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PageComposition {
+    /// Seconds the page stays on screen unless replaced earlier
+    pub page_time_out: u8,
+    pub version: u8,
+    pub state: PageState,
+    pub regions: Vec<PageRegion>,
+}
+
+impl PageComposition {
+    pub fn parse(data: &[u8]) -> Result<Self> {
+        if data.len() < 2 {
+            return Err(insufficient(2, data.len()));
+        }
+        let regions = data[2..]
+            .chunks_exact(6)
+            .map(|region| PageRegion {
+                region_id: region[0],
+                horizontal_address: u16::from_be_bytes([region[2], region[3]]),
+                vertical_address: u16::from_be_bytes([region[4], region[5]]),
+            })
+            .collect();
+        Ok(PageComposition {
+            page_time_out: data[0],
+            version: data[1] >> 4,
+            state: PageState::from((data[1] >> 2) & 0x03),
+            regions,
+        })
+    }
+}
+
+/// An object placed in a region.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegionObject {
+    pub object_id: u16,
+    /// 0 bitmap, 1 basic character, 2 composite string of characters
+    pub object_type: u8,
+    pub provider_flag: u8,
+    pub horizontal_position: u16,
+    pub vertical_position: u16,
+    /// Colors of character objects
+    pub foreground_pixel_code: Option<u8>,
+    pub background_pixel_code: Option<u8>,
+}
+
+/// Region composition segment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegionComposition {
+    pub region_id: u8,
+    pub version: u8,
+    /// Fill the region with its background pixel code before drawing objects
+    pub fill: bool,
+    pub width: u16,
+    pub height: u16,
+    pub level_of_compatibility: u8,
+    /// Bits per pixel: 2, 4 or 8
+    pub depth: u8,
+    pub clut_id: u8,
+    pub pixel_code_8bit: u8,
+    pub pixel_code_4bit: u8,
+    pub pixel_code_2bit: u8,
+    pub objects: Vec<RegionObject>,
+}
+
+impl RegionComposition {
+    pub fn parse(data: &[u8]) -> Result<Self> {
+        if data.len() < 10 {
+            return Err(insufficient(10, data.len()));
+        }
+        let depth = match (data[6] >> 2) & 0x07 {
+            1 => 2,
+            2 => 4,
+            3 => 8,
+            _ => return Err(invalid("reserved region depth")),
+        };
+
+        let mut objects = Vec::new();
+        let mut offset = 10;
+        while offset + 6 <= data.len() {
+            let object_type = data[offset + 2] >> 6;
+            let mut object = RegionObject {
+                object_id: u16::from_be_bytes([data[offset], data[offset + 1]]),
+                object_type,
+                provider_flag: (data[offset + 2] >> 4) & 0x03,
+                horizontal_position: u16::from_be_bytes([data[offset + 2], data[offset + 3]])
+                    & 0x0FFF,
+                vertical_position: u16::from_be_bytes([data[offset + 4], data[offset + 5]])
+                    & 0x0FFF,
+                foreground_pixel_code: None,
+                background_pixel_code: None,
+            };
+            offset += 6;
+            if matches!(object_type, 1 | 2) {
+                if offset + 2 > data.len() {
+                    return Err(insufficient(offset + 2, data.len()));
+                }
+                object.foreground_pixel_code = Some(data[offset]);
+                object.background_pixel_code = Some(data[offset + 1]);
+                offset += 2;
+            }
+            objects.push(object);
+        }
+
+        Ok(RegionComposition {
+            region_id: data[0],
+            version: data[1] >> 4,
+            fill: data[1] & 0x08 != 0,
+            width: u16::from_be_bytes([data[2], data[3]]),
+            height: u16::from_be_bytes([data[4], data[5]]),
+            level_of_compatibility: data[6] >> 5,
+            depth,
+            clut_id: data[7],
+            pixel_code_8bit: data[8],
+            pixel_code_4bit: data[9] >> 4,
+            pixel_code_2bit: (data[9] >> 2) & 0x03,
+            objects,
+        })
+    }
+
+    /// Background pixel code at the region's depth.
+    pub fn background_pixel_code(&self) -> u8 {
+        match self.depth {
+            2 => self.pixel_code_2bit,
+            4 => self.pixel_code_4bit,
+            _ => self.pixel_code_8bit,
+        }
+    }
+}
+
+/// One color of a CLUT definition, in Y'CbCr with transparency.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClutEntry {
+    pub entry_id: u8,
+    /// Which CLUTs the entry belongs to: 0x04 2-bit, 0x02 4-bit, 0x01 8-bit
+    pub depth_flags: u8,
+    pub y: u8,
+    pub cr: u8,
+    pub cb: u8,
+    /// Transparency, 0 opaque to 255 fully transparent
+    pub t: u8,
+}
+
+impl ClutEntry {
+    /// Convert to RGBA (ITU-R BT.601). A luma of zero is fully transparent.
+    pub fn to_rgba(&self) -> [u8; 4] {
+        if self.y == 0 {
+            return [0; 4];
+        }
+        let y = f32::from(self.y);
+        let cr = f32::from(self.cr) - 128.0;
+        let cb = f32::from(self.cb) - 128.0;
+        let clamp = |value: f32| value.round().clamp(0.0, 255.0) as u8;
+        [
+            clamp(y + 1.402 * cr),
+            clamp(y - 0.344_136 * cb - 0.714_136 * cr),
+            clamp(y + 1.772 * cb),
+            255 - self.t,
+        ]
+    }
+}
+
+/// CLUT definition segment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClutDefinition {
+    pub clut_id: u8,
+    pub version: u8,
+    pub entries: Vec<ClutEntry>,
+}
+
+impl ClutDefinition {
+    pub fn parse(data: &[u8]) -> Result<Self> {
+        if data.len() < 2 {
+            return Err(insufficient(2, data.len()));
+        }
+        let mut entries = Vec::new();
+        let mut offset = 2;
+        while offset + 2 <= data.len() {
+            let entry_id = data[offset];
+            let flags = data[offset + 1];
+            offset += 2;
+            let full_range = flags & 0x01 != 0;
+            let entry = if full_range {
+                let Some(values) = data.get(offset..offset + 4) else {
+                    return Err(insufficient(offset + 4, data.len()));
+                };
+                offset += 4;
+                ClutEntry {
+                    entry_id,
+                    depth_flags: flags >> 5,
+                    y: values[0],
+                    cr: values[1],
+                    cb: values[2],
+                    t: values[3],
+                }
+            } else {
+                // Reduced range: Y 6 bits, Cr 4 bits, Cb 4 bits, T 2 bits
+                let Some(values) = data.get(offset..offset + 2) else {
+                    return Err(insufficient(offset + 2, data.len()));
+                };
+                offset += 2;
+                let value = u16::from_be_bytes([values[0], values[1]]);
+                ClutEntry {
+                    entry_id,
+                    depth_flags: flags >> 5,
+                    y: ((value >> 10) as u8) << 2,
+                    cr: (((value >> 6) & 0x0F) as u8) << 4,
+                    cb: (((value >> 2) & 0x0F) as u8) << 4,
+                    t: (value & 0x03) as u8 * 0x55,
+                }
+            };
+            entries.push(entry);
+        }
+        Ok(ClutDefinition {
+            clut_id: data[0],
+            version: data[1] >> 4,
+            entries,
+        })
+    }
+}
+
+/// Content of an object data segment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ObjectCoding {
+    /// Run-length coded pixel data of the top and bottom field
+    Pixels {
+        top_field: Bytes,
+        bottom_field: Bytes,
+    },
+    /// Character codes, rendered by the decoder's own font
+    Characters(Vec<u16>),
+}
+
+/// Object data segment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ObjectData {
+    pub object_id: u16,
+    pub version: u8,
+    /// Pixel code 1 leaves the underlying pixel unchanged
+    pub non_modifying_colour: bool,
+    pub coding: ObjectCoding,
+}
+
+impl ObjectData {
+    pub fn parse(data: &Bytes) -> Result<Self> {
+        if data.len() < 3 {
+            return Err(insufficient(3, data.len()));
+        }
+        let coding = match (data[2] >> 2) & 0x03 {
+            0 => {
+                if data.len() < 7 {
+                    return Err(insufficient(7, data.len()));
+                }
+                let top_length = u16::from_be_bytes([data[3], data[4]]) as usize;
+                let bottom_length = u16::from_be_bytes([data[5], data[6]]) as usize;
+                let end = 7 + top_length + bottom_length;
+                if end > data.len() {
+                    return Err(insufficient(end, data.len()));
+                }
+                ObjectCoding::Pixels {
+                    top_field: data.slice(7..7 + top_length),
+                    bottom_field: data.slice(7 + top_length..end),
+                }
+            }
+            1 => {
+                let count = *data.get(3).ok_or_else(|| insufficient(4, data.len()))? as usize;
+                let end = 4 + count * 2;
+                if end > data.len() {
+                    return Err(insufficient(end, data.len()));
+                }
+                ObjectCoding::Characters(
+                    data[4..end]
+                        .chunks_exact(2)
+                        .map(|code| u16::from_be_bytes([code[0], code[1]]))
+                        .collect(),
+                )
+            }
+            _ => return Err(invalid("reserved object coding method")),
+        };
+        Ok(ObjectData {
+            object_id: u16::from_be_bytes([data[0], data[1]]),
+            version: data[2] >> 4,
+            non_modifying_colour: data[2] & 0x02 != 0,
+            coding,
+        })
+    }
+}
+
+/// Area of the display that subtitles are drawn into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DisplayWindow {
+    pub horizontal_min: u16,
+    pub horizontal_max: u16,
+    pub vertical_min: u16,
+    pub vertical_max: u16,
+}
+
+/// Display definition segment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DisplayDefinition {
+    pub version: u8,
+    pub width: u16,
+    pub height: u16,
+    pub window: Option<DisplayWindow>,
+}
+
+impl DisplayDefinition {
+    pub fn parse(data: &[u8]) -> Result<Self> {
+        if data.len() < 5 {
+            return Err(insufficient(5, data.len()));
+        }
+        let window = if data[0] & 0x08 != 0 {
+            if data.len() < 13 {
+                return Err(insufficient(13, data.len()));
+            }
+            let field = |offset: usize| u16::from_be_bytes([data[offset], data[offset + 1]]);
+            Some(DisplayWindow {
+                horizontal_min: field(5),
+                horizontal_max: field(7),
+                vertical_min: field(9),
+                vertical_max: field(11),
+            })
+        } else {
+            None
+        };
+        Ok(DisplayDefinition {
+            version: data[0] >> 4,
+            width: u16::from_be_bytes([data[1], data[2]]).saturating_add(1),
+            height: u16::from_be_bytes([data[3], data[4]]).saturating_add(1),
+            window,
+        })
+    }
+}
+
+/// A rendered region of a display set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubtitleBitmap {
+    /// Position on the display
+    pub x: u16,
+    pub y: u16,
+    pub width: u16,
+    pub height: u16,
+    /// `width * height` RGBA pixels, row by row
+    pub rgba: Vec<u8>,
+}
+
+/// A complete subtitle update: what is on screen from `pts` on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DisplaySet {
+    /// PTS of the PES packet that completed the set, in 90 kHz units
+    pub pts: u64,
+    /// Seconds the set stays on screen unless replaced earlier
+    pub timeout_secs: u8,
+    pub display_width: u16,
+    pub display_height: u16,
+    /// Empty when the set clears the screen
+    pub bitmaps: Vec<SubtitleBitmap>,
+}
+
+/// Colors of a CLUT at each depth.
+#[derive(Debug, Clone)]
+struct Clut {
+    two_bit: [[u8; 4]; 4],
+    four_bit: [[u8; 4]; 16],
+    eight_bit: Box<[[u8; 4]; 256]>,
+}
+
+impl Default for Clut {
+    /// The default CLUTs of EN 300 743.
+    fn default() -> Self {
+        let two_bit = [
+            [0, 0, 0, 0],
+            [255, 255, 255, 255],
+            [0, 0, 0, 255],
+            [127, 127, 127, 255],
+        ];
+
+        let mut four_bit = [[0; 4]; 16];
+        for (i, color) in four_bit.iter_mut().enumerate().skip(1) {
+            let level = if i < 8 { 255 } else { 127 };
+            let on = |bit: usize| if i & bit != 0 { level } else { 0 };
+            *color = [on(1), on(2), on(4), 255];
+        }
+
+        let mut eight_bit = Box::new([[0; 4]; 256]);
+        for (i, color) in eight_bit.iter_mut().enumerate().skip(1) {
+            let bits = |low: usize, high: usize, low_level: u8, high_level: u8| {
+                (if i & low != 0 { low_level } else { 0 })
+                    + (if i & high != 0 { high_level } else { 0 })
+            };
+            *color = if i < 8 {
+                [
+                    bits(1, 0, 255, 0),
+                    bits(2, 0, 255, 0),
+                    bits(4, 0, 255, 0),
+                    63,
+                ]
+            } else {
+                match i & 0x88 {
+                    0x00 => [
+                        bits(1, 0x10, 85, 170),
+                        bits(2, 0x20, 85, 170),
+                        bits(4, 0x40, 85, 170),
+                        255,
+                    ],
+                    0x08 => [
+                        bits(1, 0x10, 85, 170),
+                        bits(2, 0x20, 85, 170),
+                        bits(4, 0x40, 85, 170),
+                        127,
+                    ],
+                    0x80 => [
+                        127 + bits(1, 0x10, 43, 85),
+                        127 + bits(2, 0x20, 43, 85),
+                        127 + bits(4, 0x40, 43, 85),
+                        255,
+                    ],
+                    _ => [
+                        bits(1, 0x10, 43, 85),
+                        bits(2, 0x20, 43, 85),
+                        bits(4, 0x40, 43, 85),
+                        255,
+                    ],
+                }
+            };
+        }
+
+        Clut {
+            two_bit,
+            four_bit,
+            eight_bit,
+        }
+    }
+}
+
+impl Clut {
+    fn apply(&mut self, definition: &ClutDefinition) {
+        for entry in &definition.entries {
+            let rgba = entry.to_rgba();
+            let id = usize::from(entry.entry_id);
+            if entry.depth_flags & 0x04 != 0 && id < 4 {
+                self.two_bit[id] = rgba;
+            }
+            if entry.depth_flags & 0x02 != 0 && id < 16 {
+                self.four_bit[id] = rgba;
+            }
+            if entry.depth_flags & 0x01 != 0 {
+                self.eight_bit[id] = rgba;
+            }
+        }
+    }
+
+    fn color(&self, depth: u8, code: u8) -> [u8; 4] {
+        match depth {
+            2 => self.two_bit[usize::from(code & 0x03)],
+            4 => self.four_bit[usize::from(code & 0x0F)],
+            _ => self.eight_bit[usize::from(code)],
+        }
+    }
+}
+
+/// Pixel code map tables, replaced by map table entries in the pixel data.
+#[derive(Debug, Clone, Copy)]
+struct MapTables {
+    two_to_four: [u8; 4],
+    two_to_eight: [u8; 4],
+    four_to_eight: [u8; 16],
+}
+
+impl Default for MapTables {
+    fn default() -> Self {
+        let mut four_to_eight = [0; 16];
+        for (i, code) in four_to_eight.iter_mut().enumerate() {
+            *code = (i as u8) * 0x11;
+        }
+        MapTables {
+            two_to_four: [0x0, 0x7, 0x8, 0xF],
+            two_to_eight: [0x00, 0x77, 0x88, 0xFF],
+            four_to_eight,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Region {
+    composition: RegionComposition,
+    /// Pixel codes at the region's depth
+    pixels: Vec<u8>,
+}
+
+/// Draws the pixel data of one object field into a region.
+struct ObjectPainter<'a> {
+    region: &'a mut Region,
+    maps: MapTables,
+    non_modifying_colour: bool,
+    x: usize,
+    y: usize,
+    left: usize,
+}
+
+impl ObjectPainter<'_> {
+    /// Draw `run` pixels of a code read from a string of `bits` per pixel.
+    fn paint(&mut self, bits: u8, code: u8, run: usize) {
+        let depth = self.region.composition.depth;
+        let skip = self.non_modifying_colour && code == 1;
+        let code = match (bits, depth) {
+            (2, 4) => self.maps.two_to_four[usize::from(code)],
+            (2, 8) => self.maps.two_to_eight[usize::from(code)],
+            (4, 8) => self.maps.four_to_eight[usize::from(code)],
+            (4, 2) => code >> 2,
+            (8, 2) => code >> 6,
+            (8, 4) => code >> 4,
+            _ => code,
+        };
+        let width = usize::from(self.region.composition.width);
+        let height = usize::from(self.region.composition.height);
+        if !skip && self.y < height {
+            let start = self.x.min(width);
+            let end = (self.x + run).min(width);
+            self.region.pixels[self.y * width + start..self.y * width + end].fill(code);
+        }
+        self.x += run;
+    }
+
+    /// Draw one field, starting at the given line of the object.
+    fn draw_field(&mut self, data: &[u8], first_line: usize) {
+        self.y += first_line;
+        let mut cursor = Cursor::new(data);
+        while (cursor.position() as usize) < data.len() {
+            let data_type = data[cursor.position() as usize];
+            cursor.set_position(cursor.position() + 1);
+            let done = match data_type {
+                0x10 => self.pixel_string(&mut cursor, 2),
+                0x11 => self.pixel_string(&mut cursor, 4),
+                0x12 => self.pixel_string(&mut cursor, 8),
+                0x20 => self.read_map(&mut cursor, 2).map(|map| {
+                    for (i, code) in self.maps.two_to_four.iter_mut().enumerate() {
+                        *code = (map[i / 2] >> (4 * (1 - i % 2))) & 0x0F;
+                    }
+                }),
+                0x21 => self.read_map(&mut cursor, 4).map(|map| {
+                    self.maps.two_to_eight.copy_from_slice(&map);
+                }),
+                0x22 => self.read_map(&mut cursor, 16).map(|map| {
+                    self.maps.four_to_eight.copy_from_slice(&map);
+                }),
+                // End of object line: fields are interlaced
+                0xF0 => {
+                    self.x = self.left;
+                    self.y += 2;
+                    Some(())
+                }
+                _ => None,
+            };
+            if done.is_none() {
+                break;
+            }
+        }
+    }
+
+    fn read_map(&self, cursor: &mut Cursor<&[u8]>, length: usize) -> Option<Vec<u8>> {
+        let start = cursor.position() as usize;
+        let map = cursor.get_ref().get(start..start + length)?.to_vec();
+        cursor.set_position((start + length) as u64);
+        Some(map)
+    }
+
+    /// Decode one run-length coded pixel string, leaving the cursor after it.
+    fn pixel_string(&mut self, cursor: &mut Cursor<&[u8]>, bits: u8) -> Option<()> {
+        let mut reader = BitReader::new(&mut *cursor);
+        let mut read = |count: u8| reader.read_bits(count).ok().map(|value| value as u8);
+        loop {
+            let code = read(bits)?;
+            if code != 0 {
+                self.paint(bits, code, 1);
+                continue;
+            }
+            let (code, run) = match bits {
+                2 => {
+                    if read(1)? == 1 {
+                        let run = usize::from(read(3)?) + 3;
+                        (read(2)?, run)
+                    } else if read(1)? == 1 {
+                        (0, 1)
+                    } else {
+                        match read(2)? {
+                            0 => break,
+                            1 => (0, 2),
+                            2 => {
+                                let run = usize::from(read(4)?) + 12;
+                                (read(2)?, run)
+                            }
+                            _ => {
+                                let run = usize::from(read(8)?) + 29;
+                                (read(2)?, run)
+                            }
+                        }
+                    }
+                }
+                4 => {
+                    if read(1)? == 0 {
+                        match read(3)? {
+                            0 => break,
+                            run => (0, usize::from(run) + 2),
+                        }
+                    } else if read(1)? == 0 {
+                        let run = usize::from(read(2)?) + 4;
+                        (read(4)?, run)
+                    } else {
+                        match read(2)? {
+                            0 => (0, 1),
+                            1 => (0, 2),
+                            2 => {
+                                let run = usize::from(read(4)?) + 9;
+                                (read(4)?, run)
+                            }
+                            _ => {
+                                let run = usize::from(read(8)?) + 25;
+                                (read(4)?, run)
+                            }
+                        }
+                    }
+                }
+                _ => {
+                    let switch = read(1)?;
+                    let run = usize::from(read(7)?);
+                    match (switch, run) {
+                        (0, 0) => break,
+                        (0, _) => (0, run),
+                        _ => (read(8)?, run),
+                    }
+                }
+            };
+            self.paint(bits, code, run);
+        }
+        // Pixel strings end byte-aligned
+        Some(())
+    }
+}
+
+/// Follows one subtitle page and renders its display sets.
+#[derive(Debug, Clone)]
+pub struct DvbSubtitleDecoder {
+    composition_page_id: u16,
+    ancillary_page_id: u16,
+    display: DisplayDefinition,
+    page: Option<PageComposition>,
+    regions: HashMap<u8, Region>,
+    cluts: HashMap<u8, Clut>,
+}
+
+impl DvbSubtitleDecoder {
+    pub fn new(composition_page_id: u16, ancillary_page_id: u16) -> Self {
+        Self {
+            composition_page_id,
+            ancillary_page_id,
+            display: DisplayDefinition {
+                version: 0,
+                width: DEFAULT_DISPLAY.0,
+                height: DEFAULT_DISPLAY.1,
+                window: None,
+            },
+            page: None,
+            regions: HashMap::new(),
+            cluts: HashMap::new(),
+        }
+    }
+
+    /// Decoder for the page of a subtitling descriptor entry.
+    pub fn for_entry(entry: &SubtitlingEntry) -> Self {
+        Self::new(entry.composition_page_id, entry.ancillary_page_id)
+    }
+
+    /// Decode the payload of one subtitle PES packet (after the PES header).
+    ///
+    /// Returns the display set completed by this packet, if any. Segments of
+    /// other pages are ignored.
+    pub fn push(&mut self, pts: u64, payload: &Bytes) -> Result<Option<DisplaySet>> {
+        let mut completed = None;
+        for segment in parse_subtitle_segments(payload)? {
+            if segment.page_id != self.composition_page_id
+                && segment.page_id != self.ancillary_page_id
+            {
+                continue;
+            }
+            match segment.segment_type {
+                SEGMENT_PAGE_COMPOSITION => {
+                    let page = PageComposition::parse(&segment.data)?;
+                    if page.state != PageState::NormalCase {
+                        self.regions.clear();
+                        self.cluts.clear();
+                    }
+                    self.page = Some(page);
+                }
+                SEGMENT_REGION_COMPOSITION => {
+                    self.update_region(RegionComposition::parse(&segment.data)?)
+                }
+                SEGMENT_CLUT_DEFINITION => {
+                    let definition = ClutDefinition::parse(&segment.data)?;
+                    self.cluts
+                        .entry(definition.clut_id)
+                        .or_default()
+                        .apply(&definition);
+                }
+                SEGMENT_OBJECT_DATA => self.draw_object(&ObjectData::parse(&segment.data)?),
+                SEGMENT_DISPLAY_DEFINITION => {
+                    self.display = DisplayDefinition::parse(&segment.data)?
+                }
+                SEGMENT_END_OF_DISPLAY_SET => completed = self.render(pts),
+                _ => {}
+            }
+        }
+        Ok(completed)
+    }
+
+    fn update_region(&mut self, composition: RegionComposition) {
+        let size = usize::from(composition.width) * usize::from(composition.height);
+        let background = composition.background_pixel_code();
+        let region = self
+            .regions
+            .entry(composition.region_id)
+            .or_insert_with(|| Region {
+                composition: composition.clone(),
+                pixels: vec![background; size],
+            });
+        if region.pixels.len() != size {
+            region.pixels = vec![background; size];
+        } else if composition.fill {
+            region.pixels.fill(background);
+        }
+        region.composition = composition;
+    }
+
+    fn draw_object(&mut self, object: &ObjectData) {
+        let ObjectCoding::Pixels {
+            top_field,
+            bottom_field,
+        } = &object.coding
+        else {
+            // Character objects need a font; they are not rendered
+            return;
+        };
+        // A missing bottom field repeats the top field
+        let bottom_field = if bottom_field.is_empty() {
+            top_field
+        } else {
+            bottom_field
+        };
+        for region in self.regions.values_mut() {
+            let placements: Vec<_> = region
+                .composition
+                .objects
+                .iter()
+                .filter(|placed| placed.object_id == object.object_id)
+                .map(|placed| {
+                    (
+                        usize::from(placed.horizontal_position),
+                        usize::from(placed.vertical_position),
+                    )
+                })
+                .collect();
+            for (x, y) in placements {
+                for (field, first_line) in [(top_field, 0), (bottom_field, 1)] {
+                    ObjectPainter {
+                        region: &mut *region,
+                        maps: MapTables::default(),
+                        non_modifying_colour: object.non_modifying_colour,
+                        x,
+                        y,
+                        left: x,
+                    }
+                    .draw_field(field, first_line);
+                }
+            }
+        }
+    }
+
+    fn render(&self, pts: u64) -> Option<DisplaySet> {
+        let page = self.page.as_ref()?;
+        let (offset_x, offset_y) = self.display.window.map_or((0, 0), |window| {
+            (window.horizontal_min, window.vertical_min)
+        });
+        let default_clut = Clut::default();
+        let bitmaps = page
+            .regions
+            .iter()
+            .filter_map(|placed| {
+                let region = self.regions.get(&placed.region_id)?;
+                let composition = &region.composition;
+                let clut = self
+                    .cluts
+                    .get(&composition.clut_id)
+                    .unwrap_or(&default_clut);
+                let rgba = region
+                    .pixels
+                    .iter()
+                    .flat_map(|code| clut.color(composition.depth, *code))
+                    .collect();
+                Some(SubtitleBitmap {
+                    x: offset_x + placed.horizontal_address,
+                    y: offset_y + placed.vertical_address,
+                    width: composition.width,
+                    height: composition.height,
+                    rgba,
+                })
+            })
+            .filter(|bitmap| bitmap.rgba.chunks_exact(4).any(|pixel| pixel[3] != 0))
+            .collect();
+        Some(DisplaySet {
+            pts,
+            timeout_secs: page.page_time_out,
+            display_width: self.display.width,
+            display_height: self.display.height,
+            bitmaps,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        packet::TsPacket,
+        timestamp::{packet_pes_header, restamp},
+        writer::write_pes_header,
+    };
+
+    fn segment(segment_type: u8, page_id: u16, data: &[u8]) -> Vec<u8> {
+        let mut out = vec![SEGMENT_SYNC_BYTE, segment_type];
+        out.extend_from_slice(&page_id.to_be_bytes());
+        out.extend_from_slice(&(data.len() as u16).to_be_bytes());
+        out.extend_from_slice(data);
+        out
+    }
+
+    /// A display set showing one 4x2 region of 4-bit pixels on page 1.
+    fn display_set() -> Bytes {
+        let mut payload = vec![DATA_IDENTIFIER_DVB_SUBTITLE, 0x00];
+        // Page: timeout 5 s, mode change, region 0 at (100, 400)
+        payload.extend(segment(
+            SEGMENT_PAGE_COMPOSITION,
+            1,
+            &[5, 0x08, 0, 0, 0, 100, 0x01, 0x90],
+        ));
+        // Region 0: 4x2, filled, 4-bit, CLUT 0, background code 0, object 7 at (0, 0)
+        payload.extend(segment(
+            SEGMENT_REGION_COMPOSITION,
+            1,
+            &[0, 0x08, 0, 4, 0, 2, 0x48, 0, 0, 0, 0, 7, 0, 0, 0, 0],
+        ));
+        // CLUT 0: entry 1 opaque white (full range) in the 4-bit CLUT
+        payload.extend(segment(
+            SEGMENT_CLUT_DEFINITION,
+            1,
+            &[0, 0, 1, 0x41, 235, 128, 128, 0],
+        ));
+        // Object 7: top field is two white pixels, a run of two background
+        // pixels and the end of the string; the bottom field repeats it
+        let top_field = [0x11, 0x11, 0x0D, 0x00, 0xF0];
+        let mut object = vec![0, 7, 0x00, 0, top_field.len() as u8, 0, 0];
+        object.extend_from_slice(&top_field);
+        payload.extend(segment(SEGMENT_OBJECT_DATA, 1, &object));
+        // A segment of another page is ignored
+        payload.extend(segment(SEGMENT_PAGE_COMPOSITION, 9, &[1, 0x08]));
+        payload.extend(segment(SEGMENT_END_OF_DISPLAY_SET, 1, &[]));
+        payload.push(END_OF_PES_DATA_FIELD);
+        Bytes::from(payload)
+    }
+
+    #[test]
+    fn test_parses_segments() {
+        let segments = parse_subtitle_segments(&display_set()).unwrap();
+        let types: Vec<_> = segments.iter().map(|s| s.segment_type).collect();
+        assert_eq!(types, vec![0x10, 0x11, 0x12, 0x13, 0x10, 0x80]);
+
+        let page = PageComposition::parse(&segments[0].data).unwrap();
+        assert_eq!(page.state, PageState::ModeChange);
+        assert_eq!(page.regions[0].vertical_address, 400);
+
+        let region = RegionComposition::parse(&segments[1].data).unwrap();
+        assert_eq!((region.width, region.height, region.depth), (4, 2, 4));
+        assert_eq!(region.objects[0].object_id, 7);
+
+        assert!(parse_subtitle_segments(&Bytes::from_static(&[0x21, 0x00])).is_err());
+    }
+
+    #[test]
+    fn test_renders_display_set() {
+        let mut decoder = DvbSubtitleDecoder::new(1, 1);
+        let set = decoder.push(90_000, &display_set()).unwrap().unwrap();
+        assert_eq!(set.pts, 90_000);
+        assert_eq!(set.timeout_secs, 5);
+        assert_eq!((set.display_width, set.display_height), (720, 576));
+        assert_eq!(set.bitmaps.len(), 1);
+
+        let bitmap = &set.bitmaps[0];
+        assert_eq!(
+            (bitmap.x, bitmap.y, bitmap.width, bitmap.height),
+            (100, 400, 4, 2)
+        );
+        let alpha: Vec<_> = bitmap.rgba.chunks_exact(4).map(|pixel| pixel[3]).collect();
+        assert_eq!(alpha, vec![255, 255, 0, 0, 255, 255, 0, 0]);
+        assert_eq!(&bitmap.rgba[..3], &[235, 235, 235]);
+
+        // An empty page clears the screen
+        let mut payload = vec![DATA_IDENTIFIER_DVB_SUBTITLE, 0x00];
+        payload.extend(segment(SEGMENT_PAGE_COMPOSITION, 1, &[0, 0x10]));
+        payload.extend(segment(SEGMENT_END_OF_DISPLAY_SET, 1, &[]));
+        let cleared = decoder
+            .push(180_000, &Bytes::from(payload))
+            .unwrap()
+            .unwrap();
+        assert!(cleared.bitmaps.is_empty());
+    }
+
+    #[test]
+    fn test_restamping_keeps_segments_intact() {
+        let payload = display_set();
+        let mut pes = Vec::new();
+        write_pes_header(&mut pes, 0xBD, Some(90_000), None, true, payload.len());
+        let header_len = pes.len();
+        pes.extend_from_slice(&payload);
+        let mut data = TsPacket::new(0x120, true, 0, None, Some(Bytes::from(pes)))
+            .to_bytes()
+            .unwrap()
+            .to_vec();
+
+        restamp(&mut data, 45_000);
+        assert_eq!(packet_pes_header(&data).unwrap().pts, Some(135_000));
+        let offset = crate::timestamp::packet_payload_offset(&data).unwrap() + header_len;
+        assert_eq!(&data[offset..offset + payload.len()], payload.as_ref());
+    }
+}
@@ -44,6 +44,12 @@ pub enum TsError {
     #[error("Invalid PTS/DTS flags: 0x{0:02x}")]
     InvalidPtsDtsFlags(u8),
 
+    #[error("Packet overflow: {0} bytes do not fit in the 184-byte packet body")]
+    PacketOverflow(usize),
+
     #[error("Invalid SCTE-35 section: {0}")]
     InvalidScte35(String),
+
+    #[error("Invalid DVB subtitle data: {0}")]
+    InvalidDvbSubtitle(String),
 }
@@ -2,12 +2,23 @@
 //!
 //! This crate provides functionality to parse Program Association Table (PAT),
 //! Program Map Table (PMT), PES headers, adaptation fields, descriptors,
-//! and SCTE-35 splice information from MPEG-TS (Transport Stream) data.
+//! SCTE-35 splice information, DVB service information (SDT, EIT, TDT) and
+//! DVB subtitles from MPEG-TS (Transport Stream) data.
+//!
+//! The [`writer`] and [`mux`] modules go the other way, serializing packets
+//! and PSI sections and muxing elementary streams into a transport stream;
+//! [`SpliceInfoSection::to_section`] encodes SCTE-35 cues for re-injection.
+//! The [`analyzer`] module collects bitrate, PCR, continuity and timestamp
+//! statistics over a stream.
 
 pub mod adaptation_field;
+pub mod analyzer;
+pub mod continuity;
 pub mod crc32;
 pub mod descriptor;
+pub mod dvb_subtitle;
 pub mod error;
+pub mod mux;
 pub mod packet;
 pub mod parser_owned;
 pub mod parser_zero_copy;
@@ -15,11 +26,26 @@ pub mod pat;
 pub mod pes;
 pub mod pmt;
 pub mod scte35;
+pub mod si;
+pub mod timestamp;
+pub mod writer;
 
 pub use adaptation_field::{AdaptationField, AdaptationFieldRef, Pcr};
+pub use analyzer::{
+    DEFAULT_GAP_THRESHOLD_MS, IntervalStats, PcrStats, PidStats, TimestampStats, TsAnalysis,
+    TsAnalyzer,
+};
+pub use continuity::{ContinuityFix, ContinuityFixer};
 pub use crc32::{mpeg2_crc32, validate_section_crc32};
-pub use descriptor::{Ac3Descriptor, DescriptorIterator, DescriptorRef, LanguageEntry};
+pub use descriptor::{
+    Ac3Descriptor, DescriptorIterator, DescriptorRef, LanguageEntry, ServiceDescriptor,
+    ShortEventDescriptor, SubtitlingEntry,
+};
+pub use dvb_subtitle::{
+    DisplaySet, DvbSubtitleDecoder, SubtitleBitmap, SubtitleSegment, parse_subtitle_segments,
+};
 pub use error::TsError;
+pub use mux::{ContinuityCounters, EsFrame, TsMuxer};
 pub use packet::{ContinuityMode, ContinuityStatus, PID_CAT, PID_NULL, PID_PAT, TsPacket};
 pub use parser_owned::OwnedTsParser;
 pub use parser_zero_copy::{
@@ -33,6 +59,15 @@ pub use scte35::{
     BreakDuration, SpliceCommand, SpliceCommandType, SpliceInfoSection, SpliceInfoSectionRef,
     SpliceInsert, TimeSignal,
 };
+pub use si::{
+    Eit, EitEvent, NowNext, PID_EIT, PID_SDT, PID_TDT, RunningStatus, Sdt, SdtService, Tdt,
+    decode_dvb_text,
+};
+pub use timestamp::{
+    PCR_HZ, TIMESTAMP_HZ, TIMESTAMP_MODULO, packet_payload_offset, packet_pes_header, restamp,
+    restamp_packet, shift_timestamp, timestamp_diff, wrap_timestamp,
+};
+pub use writer::{TS_PACKET_BODY_SIZE, TS_PACKET_SIZE, encode_timestamp, write_pes_header};
 
 /// Result type for TS parsing operations
 pub type Result<T> = std::result::Result<T, TsError>;
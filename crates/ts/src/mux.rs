@@ -0,0 +1,381 @@
+//! Single-program transport stream muxer.
+//!
+//! [`TsMuxer`] packetizes PSI tables and elementary stream frames into
+//! 188-byte packets, keeping one continuity counter per PID. Timestamps are
+//! taken as given, in 90 kHz units; the PCR is derived from the decode time
+//! of frames on the PCR PID.
+
+use std::collections::HashMap;
+
+use bytes::Bytes;
+
+use crate::{
+    Result, TsError,
+    adaptation_field::Pcr,
+    packet::{PID_NULL, PID_PAT, TsPacket},
+    pat::{Pat, PatProgram},
+    pes::{STREAM_ID_AUDIO_MIN, STREAM_ID_PRIVATE_1, STREAM_ID_VIDEO_MIN},
+    pmt::{Pmt, PmtStream, StreamType},
+    writer::{TS_PACKET_BODY_SIZE, TS_PACKET_SIZE, write_pes_header},
+};
+
+/// Lowest PID available to PMTs and elementary streams
+const FIRST_USABLE_PID: u16 = 0x0010;
+
+/// Continuity counters of the PIDs written so far.
+#[derive(Debug, Clone, Default)]
+pub struct ContinuityCounters {
+    counters: HashMap<u16, u8>,
+}
+
+impl ContinuityCounters {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Counter for the next payload-carrying packet on `pid`, starting at 0.
+    pub fn next(&mut self, pid: u16) -> u8 {
+        let counter = self.counters.get(&pid).map_or(0, |&last| (last + 1) & 0x0F);
+        self.counters.insert(pid, counter);
+        counter
+    }
+
+    /// Counter of the last packet written on `pid`.
+    pub fn last(&self, pid: u16) -> Option<u8> {
+        self.counters.get(&pid).copied()
+    }
+
+    pub fn reset(&mut self) {
+        self.counters.clear();
+    }
+}
+
+/// One access unit of an elementary stream.
+#[derive(Debug, Clone)]
+pub struct EsFrame<'a> {
+    /// PID of the stream, as registered with [`TsMuxer::add_stream`]
+    pub pid: u16,
+    /// Presentation timestamp in 90 kHz units
+    pub pts: u64,
+    /// Decode timestamp in 90 kHz units, if it differs from the PTS
+    pub dts: Option<u64>,
+    /// Whether decoding can start at this frame (keyframes, audio frames)
+    pub random_access: bool,
+    pub data: &'a [u8],
+}
+
+#[derive(Debug, Clone)]
+struct MuxStream {
+    pid: u16,
+    stream_type: StreamType,
+    stream_id: u8,
+    es_info: Vec<u8>,
+}
+
+/// Muxer writing a single program into a transport stream.
+#[derive(Debug, Clone)]
+pub struct TsMuxer {
+    transport_stream_id: u16,
+    program_number: u16,
+    pmt_pid: u16,
+    version_number: u8,
+    pcr_pid: Option<u16>,
+    streams: Vec<MuxStream>,
+    counters: ContinuityCounters,
+}
+
+impl TsMuxer {
+    /// Create a muxer for program `program_number`, with its PMT on `pmt_pid`.
+    pub fn new(program_number: u16, pmt_pid: u16) -> Self {
+        Self {
+            transport_stream_id: 1,
+            program_number,
+            pmt_pid,
+            version_number: 0,
+            pcr_pid: None,
+            streams: Vec::new(),
+            counters: ContinuityCounters::new(),
+        }
+    }
+
+    pub fn with_transport_stream_id(mut self, transport_stream_id: u16) -> Self {
+        self.transport_stream_id = transport_stream_id;
+        self
+    }
+
+    /// Add an elementary stream to the program.
+    ///
+    /// The PCR is carried by the first video stream, or by the first stream if
+    /// the program has no video. Adding a stream bumps the PMT version.
+    pub fn add_stream(
+        &mut self,
+        pid: u16,
+        stream_type: StreamType,
+        es_info: Vec<u8>,
+    ) -> Result<()> {
+        if !(FIRST_USABLE_PID..PID_NULL).contains(&pid)
+            || pid == self.pmt_pid
+            || self.stream(pid).is_some()
+        {
+            return Err(TsError::InvalidPid(pid));
+        }
+
+        let same_kind = |stream: &&MuxStream| {
+            stream.stream_type.is_video() == stream_type.is_video()
+                && stream.stream_type.is_audio() == stream_type.is_audio()
+        };
+        let index = self.streams.iter().filter(same_kind).count() as u8;
+        let stream_id = if stream_type.is_video() {
+            STREAM_ID_VIDEO_MIN + (index & 0x0F)
+        } else if stream_type.is_audio() {
+            STREAM_ID_AUDIO_MIN + (index & 0x1F)
+        } else {
+            STREAM_ID_PRIVATE_1
+        };
+
+        let carries_pcr = match self.pcr_pid.and_then(|pcr_pid| self.stream(pcr_pid)) {
+            None => true,
+            Some(current) => stream_type.is_video() && !current.stream_type.is_video(),
+        };
+        if carries_pcr {
+            self.pcr_pid = Some(pid);
+        }
+
+        self.streams.push(MuxStream {
+            pid,
+            stream_type,
+            stream_id,
+            es_info,
+        });
+        if self.streams.len() > 1 {
+            self.version_number = (self.version_number + 1) & 0x1F;
+        }
+        Ok(())
+    }
+
+    /// The PAT announcing this program.
+    pub fn pat(&self) -> Pat {
+        Pat {
+            table_id: 0x00,
+            transport_stream_id: self.transport_stream_id,
+            version_number: 0,
+            current_next_indicator: true,
+            section_number: 0,
+            last_section_number: 0,
+            programs: vec![PatProgram {
+                program_number: self.program_number,
+                pmt_pid: self.pmt_pid,
+            }],
+        }
+    }
+
+    /// The PMT describing the streams added so far.
+    pub fn pmt(&self) -> Pmt {
+        Pmt {
+            table_id: 0x02,
+            program_number: self.program_number,
+            version_number: self.version_number,
+            current_next_indicator: true,
+            section_number: 0,
+            last_section_number: 0,
+            pcr_pid: self.pcr_pid.unwrap_or(PID_NULL),
+            program_info: Vec::new(),
+            streams: self
+                .streams
+                .iter()
+                .map(|stream| PmtStream {
+                    stream_type: stream.stream_type,
+                    elementary_pid: stream.pid,
+                    es_info: stream.es_info.clone(),
+                })
+                .collect(),
+        }
+    }
+
+    /// Write the PAT and PMT. Call this at the start of the stream and before
+    /// keyframes so that players can join mid-stream.
+    pub fn write_tables(&mut self, out: &mut Vec<u8>) -> Result<()> {
+        let pat = self.pat().to_section()?;
+        self.write_section(out, PID_PAT, &pat)?;
+        let pmt = self.pmt().to_section()?;
+        self.write_section(out, self.pmt_pid, &pmt)
+    }
+
+    /// Packetize a PSI section on `pid`, padding the last packet with 0xFF.
+    pub fn write_section(&mut self, out: &mut Vec<u8>, pid: u16, section: &[u8]) -> Result<()> {
+        // pointer_field, then the section
+        let mut data = Vec::with_capacity(section.len() + 1);
+        data.push(0x00);
+        data.extend_from_slice(section);
+
+        for (index, chunk) in data.chunks(TS_PACKET_BODY_SIZE).enumerate() {
+            let mut payload = chunk.to_vec();
+            payload.resize(TS_PACKET_BODY_SIZE, 0xFF);
+            let counter = self.counters.next(pid);
+            TsPacket::new(pid, index == 0, counter, None, Some(Bytes::from(payload)))
+                .write_to(out)?;
+        }
+        Ok(())
+    }
+
+    /// Packetize one frame as a PES packet on its stream's PID.
+    pub fn write_frame(&mut self, out: &mut Vec<u8>, frame: &EsFrame<'_>) -> Result<()> {
+        let stream_id = self
+            .stream(frame.pid)
+            .map(|stream| stream.stream_id)
+            .ok_or(TsError::InvalidPid(frame.pid))?;
+
+        let mut pes = Vec::with_capacity(frame.data.len() + 19);
+        write_pes_header(
+            &mut pes,
+            stream_id,
+            Some(frame.pts),
+            frame.dts,
+            true,
+            frame.data.len(),
+        );
+        pes.extend_from_slice(frame.data);
+        let pes = Bytes::from(pes);
+
+        let mut adaptation_field = Vec::new();
+        let pcr = (self.pcr_pid == Some(frame.pid)).then(|| Pcr {
+            base: frame.dts.unwrap_or(frame.pts),
+            extension: 0,
+        });
+        if frame.random_access || pcr.is_some() {
+            adaptation_field
+                .push((u8::from(frame.random_access) << 6) | (u8::from(pcr.is_some()) << 4));
+            if let Some(pcr) = pcr {
+                adaptation_field.extend_from_slice(&pcr.to_bytes());
+            }
+        }
+
+        out.reserve((pes.len() / TS_PACKET_BODY_SIZE + 2) * TS_PACKET_SIZE);
+        let mut offset = 0;
+        while offset < pes.len() {
+            let first = offset == 0;
+            let field = (first && !adaptation_field.is_empty())
+                .then(|| Bytes::from(adaptation_field.clone()));
+            let room = TS_PACKET_BODY_SIZE - field.as_ref().map_or(0, |field| field.len() + 1);
+            let end = pes.len().min(offset + room);
+
+            let counter = self.counters.next(frame.pid);
+            TsPacket::new(
+                frame.pid,
+                first,
+                counter,
+                field,
+                Some(pes.slice(offset..end)),
+            )
+            .write_to(out)?;
+            offset = end;
+        }
+        Ok(())
+    }
+
+    /// Continuity counters of the PIDs written so far.
+    pub fn continuity_counters(&self) -> &ContinuityCounters {
+        &self.counters
+    }
+
+    fn stream(&self, pid: u16) -> Option<&MuxStream> {
+        self.streams.iter().find(|stream| stream.pid == pid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ContinuityMode, OwnedTsParser, PesHeader};
+
+    fn muxer() -> TsMuxer {
+        let mut muxer = TsMuxer::new(1, 0x1000);
+        muxer
+            .add_stream(0x0101, StreamType::AdtsAac, vec![])
+            .unwrap();
+        muxer.add_stream(0x0100, StreamType::H264, vec![]).unwrap();
+        muxer
+    }
+
+    #[test]
+    fn test_rejects_reserved_and_duplicate_pids() {
+        let mut muxer = muxer();
+        assert!(muxer.add_stream(0x0000, StreamType::H264, vec![]).is_err());
+        assert!(muxer.add_stream(0x1000, StreamType::H264, vec![]).is_err());
+        assert!(muxer.add_stream(0x0100, StreamType::H265, vec![]).is_err());
+        assert!(
+            muxer
+                .add_stream(PID_NULL, StreamType::H265, vec![])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_pcr_moves_to_the_video_stream() {
+        let pmt = muxer().pmt();
+        assert_eq!(pmt.pcr_pid, 0x0100);
+        assert_eq!(pmt.version_number, 1);
+    }
+
+    #[test]
+    fn test_output_parses_back() {
+        let mut muxer = muxer();
+        let video = vec![0x42; 1_000];
+        let mut out = Vec::new();
+        muxer.write_tables(&mut out).unwrap();
+        muxer
+            .write_frame(
+                &mut out,
+                &EsFrame {
+                    pid: 0x0100,
+                    pts: 93_003,
+                    dts: Some(90_000),
+                    random_access: true,
+                    data: &video,
+                },
+            )
+            .unwrap();
+        muxer
+            .write_frame(
+                &mut out,
+                &EsFrame {
+                    pid: 0x0101,
+                    pts: 90_000,
+                    dts: None,
+                    random_access: true,
+                    data: &[0x21; 10],
+                },
+            )
+            .unwrap();
+        assert_eq!(out.len() % TS_PACKET_SIZE, 0);
+
+        let mut parser = OwnedTsParser::new()
+            .with_crc_validation(true)
+            .with_continuity_mode(ContinuityMode::Strict);
+        parser.parse_packets(Bytes::from(out.clone())).unwrap();
+        let pmt = parser.pmt(1).unwrap();
+        assert_eq!(pmt.streams.len(), 2);
+
+        // Reassemble the video PES from its packets.
+        let packets: Vec<TsPacket> = out
+            .chunks(TS_PACKET_SIZE)
+            .map(|chunk| TsPacket::parse(Bytes::copy_from_slice(chunk)).unwrap())
+            .filter(|packet| packet.pid == 0x0100)
+            .collect();
+        let counters: Vec<u8> = packets.iter().map(|p| p.continuity_counter).collect();
+        assert_eq!(counters, (0..packets.len() as u8).collect::<Vec<_>>());
+
+        let first = packets[0].parse_adaptation_field().unwrap();
+        assert!(first.random_access_indicator);
+        assert_eq!(first.pcr.unwrap().base, 90_000);
+
+        let pes: Vec<u8> = packets
+            .iter()
+            .flat_map(|packet| packet.payload.clone().unwrap())
+            .collect();
+        let header = PesHeader::parse(&pes).unwrap();
+        assert_eq!((header.pts, header.dts), (Some(93_003), Some(90_000)));
+        assert_eq!(header.payload(&pes).unwrap(), video.as_slice());
+        assert_eq!(muxer.continuity_counters().last(0x0101), Some(0));
+    }
+}
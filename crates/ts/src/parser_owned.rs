@@ -3,18 +3,30 @@ use crate::{
     packet::{ContinuityMode, ContinuityStatus, PID_NULL, PID_PAT, TsPacket},
     pat::Pat,
     pmt::Pmt,
+    si::{
+        Eit, NowNext, PID_EIT, PID_SDT, PID_TDT, Sdt, TABLE_ID_EIT_PF_ACTUAL, TABLE_ID_SDT_ACTUAL,
+        TABLE_ID_TDT, TABLE_ID_TOT, Tdt,
+    },
 };
 use bytes::{Buf, Bytes};
 use memchr::memchr;
 use std::collections::HashMap;
+use tracing::debug;
 
-/// Transport Stream parser for PAT and PMT tables
+/// Transport Stream parser for PAT and PMT tables, and the DVB SDT, EIT
+/// present/following and TDT/TOT when the stream carries them
 #[derive(Debug, Default)]
 pub struct OwnedTsParser {
     /// Cached PAT table
     pat: Option<Pat>,
     /// Cached PMT tables by program number
     pmts: HashMap<u16, Pmt>,
+    /// Latest SDT of the actual transport stream
+    sdt: Option<Sdt>,
+    /// Present/following events by service ID
+    now_next: HashMap<u16, NowNext>,
+    /// Latest UTC time from a TDT or TOT, in seconds since the Unix epoch
+    utc_time: Option<u64>,
     /// Buffer for incomplete PSI sections
     psi_buffers: HashMap<u16, Vec<u8>>,
     /// Current version numbers to detect updates
@@ -170,6 +182,10 @@ impl OwnedTsParser {
 
                     if packet.payload_unit_start_indicator {
                         self.process_packet(&packet)?;
+                    } else if is_si_pid(packet.pid)
+                        && let Some(payload) = &packet.payload
+                    {
+                        self.append_si_section(packet.pid, payload);
                     }
                     remaining_data.advance(188);
                 }
@@ -205,6 +221,11 @@ impl OwnedTsParser {
                 pid if self.is_pmt_pid(pid) && table_id == 0x02 => {
                     self.process_pmt(pid, &psi_payload)?;
                 }
+                pid if is_si_pid(pid) => {
+                    // SI sections often span several packets
+                    self.psi_buffers.insert(pid, Vec::new());
+                    self.append_si_section(pid, &psi_payload);
+                }
                 _ => {
                     // Not a PAT or PMT packet we are interested in
                 }
@@ -259,6 +280,59 @@ impl OwnedTsParser {
         Ok(())
     }
 
+    /// Buffer part of an SI section, handling it once complete.
+    fn append_si_section(&mut self, pid: u16, data: &[u8]) {
+        let Some(buffer) = self.psi_buffers.get_mut(&pid) else {
+            return;
+        };
+        buffer.extend_from_slice(data);
+        if buffer.len() < 3 {
+            return;
+        }
+        let section_end = 3 + (((buffer[1] as usize) & 0x0F) << 8 | buffer[2] as usize);
+        if buffer.len() < section_end {
+            return;
+        }
+        let mut section = self.psi_buffers.remove(&pid).unwrap_or_default();
+        section.truncate(section_end);
+        if let Err(error) = self.process_si_section(pid, &section) {
+            // SI is informational; a broken table never fails the stream
+            debug!(pid, %error, "Skipping malformed SI section");
+        }
+    }
+
+    /// Parse a complete SDT, EIT present/following or TDT/TOT section
+    fn process_si_section(&mut self, pid: u16, section: &[u8]) -> Result<(), TsError> {
+        match (pid, section[0]) {
+            (PID_SDT, TABLE_ID_SDT_ACTUAL) => {
+                self.sdt = Some(if self.validate_crc {
+                    Sdt::parse_with_crc(section)?
+                } else {
+                    Sdt::parse(section)?
+                });
+            }
+            (PID_EIT, TABLE_ID_EIT_PF_ACTUAL) => {
+                let eit = if self.validate_crc {
+                    Eit::parse_with_crc(section)?
+                } else {
+                    Eit::parse(section)?
+                };
+                let event = eit.events.into_iter().next();
+                let now_next = self.now_next.entry(eit.service_id).or_default();
+                match eit.section_number {
+                    0 => now_next.present = event,
+                    1 => now_next.following = event,
+                    _ => {}
+                }
+            }
+            (PID_TDT, TABLE_ID_TDT | TABLE_ID_TOT) => {
+                self.utc_time = Some(Tdt::parse(section)?.utc_time);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
     /// Get the parsed PAT
     pub fn pat(&self) -> Option<&Pat> {
         self.pat.as_ref()
@@ -274,10 +348,28 @@ impl OwnedTsParser {
         self.pmts.get(&program_number)
     }
 
+    /// Get the latest SDT of the actual transport stream
+    pub fn sdt(&self) -> Option<&Sdt> {
+        self.sdt.as_ref()
+    }
+
+    /// Get the present and following events of a service
+    pub fn now_next(&self, service_id: u16) -> Option<&NowNext> {
+        self.now_next.get(&service_id)
+    }
+
+    /// Get the latest broadcast UTC time, in seconds since the Unix epoch
+    pub fn utc_time(&self) -> Option<u64> {
+        self.utc_time
+    }
+
     /// Reset the parser state
     pub fn reset(&mut self) {
         self.pat = None;
         self.pmts.clear();
+        self.sdt = None;
+        self.now_next.clear();
+        self.utc_time = None;
         self.psi_buffers.clear();
         self.pat_version = None;
         self.pmt_versions.clear();
@@ -288,6 +380,10 @@ impl OwnedTsParser {
     }
 }
 
+fn is_si_pid(pid: u16) -> bool {
+    matches!(pid, PID_SDT | PID_EIT | PID_TDT)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -387,4 +483,33 @@ mod tests {
             } if p == pid
         ));
     }
+
+    #[test]
+    fn test_sdt_spanning_packets_is_reassembled() {
+        let name = "N".repeat(200);
+        let mut descriptor = vec![crate::descriptor::TAG_SERVICE, 0, 0x01, 0];
+        descriptor.push(name.len() as u8);
+        descriptor.extend_from_slice(name.as_bytes());
+        descriptor[1] = (descriptor.len() - 2) as u8;
+
+        let mut section = vec![0x42, 0, 0, 0x00, 0x01, 0xC1, 0x00, 0x00, 0x00, 0x01, 0xFF];
+        section.extend_from_slice(&[0x00, 0x01, 0xFC, 0x80, descriptor.len() as u8]);
+        section.extend_from_slice(&descriptor);
+        let section_length = section.len() - 3 + 4;
+        section[1] = 0xF0 | (section_length >> 8) as u8;
+        section[2] = section_length as u8;
+        let crc = crate::crc32::mpeg2_crc32(&section);
+        section.extend_from_slice(&crc.to_be_bytes());
+
+        let mut data = Vec::new();
+        crate::mux::TsMuxer::new(1, 0x1000)
+            .write_section(&mut data, PID_SDT, &section)
+            .unwrap();
+        assert_eq!(data.len(), 2 * 188);
+
+        let mut parser = OwnedTsParser::new().with_crc_validation(true);
+        parser.parse_packets(Bytes::from(data)).unwrap();
+        let service = parser.sdt().unwrap().service(1).unwrap();
+        assert_eq!(service.service_name.as_deref(), Some(name.as_str()));
+    }
 }
@@ -188,6 +188,71 @@ impl From<u8> for StreamType {
     }
 }
 
+impl From<StreamType> for u8 {
+    fn from(value: StreamType) -> Self {
+        match value {
+            StreamType::Mpeg1Video => 0x01,
+            StreamType::Mpeg2Video => 0x02,
+            StreamType::Mpeg1Audio => 0x03,
+            StreamType::Mpeg2Audio => 0x04,
+            StreamType::Mpeg2PrivateSections => 0x05,
+            StreamType::Mpeg2PrivatePes => 0x06,
+            StreamType::Mheg => 0x07,
+            StreamType::DsmCc => 0x08,
+            StreamType::H2221 => 0x09,
+            StreamType::Iso13818_6TypeA => 0x0A,
+            StreamType::Iso13818_6TypeB => 0x0B,
+            StreamType::Iso13818_6TypeC => 0x0C,
+            StreamType::Iso13818_6TypeD => 0x0D,
+            StreamType::Mpeg2Auxiliary => 0x0E,
+            StreamType::AdtsAac => 0x0F,
+            StreamType::Mpeg4Visual => 0x10,
+            StreamType::LatmAac => 0x11,
+            StreamType::Mpeg4SlPes => 0x12,
+            StreamType::Mpeg4SlSections => 0x13,
+            StreamType::Iso13818_6Sdp => 0x14,
+            StreamType::MetadataPes => 0x15,
+            StreamType::MetadataSections => 0x16,
+            StreamType::MetadataDataCarousel => 0x17,
+            StreamType::MetadataObjectCarousel => 0x18,
+            StreamType::MetadataSdp => 0x19,
+            StreamType::Ipmp => 0x1A,
+            StreamType::H264 => 0x1B,
+            StreamType::Mpeg4Audio => 0x1C,
+            StreamType::Mpeg4VisualPlain => 0x1D,
+            StreamType::Svc => 0x1E,
+            StreamType::Mvc => 0x1F,
+            StreamType::H264Additional => 0x20,
+            StreamType::Jpeg2000 => 0x21,
+            StreamType::H262Additional => 0x22,
+            StreamType::H264AdditionalView => 0x23,
+            StreamType::H265 => 0x24,
+            StreamType::Mvcd => 0x25,
+            StreamType::Timeline => 0x26,
+            StreamType::H265Temporal => 0x27,
+            StreamType::H265Enhancement => 0x28,
+            StreamType::H265TemporalEnhancement => 0x29,
+            StreamType::H265Tile => 0x2A,
+            StreamType::JpegXs => 0x32,
+            StreamType::H266 => 0x33,
+            StreamType::Evc => 0x34,
+            StreamType::Lcevc => 0x35,
+            StreamType::Avs2 => 0x40,
+            StreamType::Avs3 => 0x41,
+            StreamType::Avs3P10 => 0x42,
+            StreamType::Ac3 => 0x81,
+            StreamType::Dts => 0x82,
+            StreamType::TrueHd => 0x83,
+            StreamType::EAc3 => 0x84,
+            StreamType::DtsHd => 0x85,
+            StreamType::DtsHdMa => 0x86,
+            StreamType::DolbyE => 0x87,
+            StreamType::DiracI => 0xA1,
+            StreamType::Unknown(value) => value,
+        }
+    }
+}
+
 impl StreamType {
     /// Check if this stream type is video
     pub fn is_video(&self) -> bool {
@@ -442,6 +507,18 @@ impl PmtStream {
     pub fn descriptors(&self) -> crate::descriptor::DescriptorIterator {
         crate::descriptor::DescriptorIterator::new(Bytes::from(self.es_info.clone()))
     }
+
+    /// DVB subtitle services of a private data stream, from its subtitling
+    /// descriptor. Empty for any other stream.
+    pub fn dvb_subtitles(&self) -> Vec<crate::descriptor::SubtitlingEntry> {
+        if self.stream_type != StreamType::Mpeg2PrivatePes {
+            return Vec::new();
+        }
+        self.descriptors()
+            .filter(|descriptor| descriptor.tag == crate::descriptor::TAG_SUBTITLING)
+            .flat_map(|descriptor| crate::descriptor::parse_subtitling_descriptor(&descriptor.data))
+            .collect()
+    }
 }
 
 impl Pmt {
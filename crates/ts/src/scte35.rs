@@ -1,6 +1,6 @@
 use bytes::Bytes;
 
-use crate::{Result, TsError};
+use crate::{Result, TsError, crc32::mpeg2_crc32};
 
 /// SCTE-35 table ID
 pub const SCTE35_TABLE_ID: u8 = 0xFC;
@@ -8,6 +8,9 @@ pub const SCTE35_TABLE_ID: u8 = 0xFC;
 /// SCTE-35 registration format identifier
 pub const SCTE35_FORMAT_IDENTIFIER: [u8; 4] = *b"CUEI";
 
+/// Largest `section_length` of a splice info section
+const MAX_SECTION_LENGTH: usize = 4093;
+
 /// SCTE-35 splice command types
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SpliceCommandType {
@@ -34,6 +37,20 @@ impl From<u8> for SpliceCommandType {
     }
 }
 
+impl From<SpliceCommandType> for u8 {
+    fn from(value: SpliceCommandType) -> Self {
+        match value {
+            SpliceCommandType::SpliceNull => 0x00,
+            SpliceCommandType::SpliceSchedule => 0x04,
+            SpliceCommandType::SpliceInsert => 0x05,
+            SpliceCommandType::TimeSignal => 0x06,
+            SpliceCommandType::BandwidthReservation => 0x07,
+            SpliceCommandType::PrivateCommand => 0xFF,
+            SpliceCommandType::Unknown(v) => v,
+        }
+    }
+}
+
 /// Parsed splice command
 #[derive(Debug, Clone)]
 pub enum SpliceCommand {
@@ -93,6 +110,26 @@ fn parse_splice_time(data: &[u8]) -> (Option<u64>, usize) {
     }
 }
 
+/// Encode a 33-bit value behind the flag bits in `prefix`, with the
+/// reserved bits in between set.
+fn encode_33bit(prefix: u8, value: u64) -> [u8; 5] {
+    [
+        prefix | 0x7E | ((value >> 32) as u8 & 0x01),
+        (value >> 24) as u8,
+        (value >> 16) as u8,
+        (value >> 8) as u8,
+        value as u8,
+    ]
+}
+
+/// Write a splice_time() structure.
+fn write_splice_time(out: &mut Vec<u8>, time: Option<u64>) {
+    match time {
+        Some(pts) => out.extend_from_slice(&encode_33bit(0x80, pts)),
+        None => out.push(0x7F),
+    }
+}
+
 /// Parse a break_duration() structure
 fn parse_break_duration(data: &[u8]) -> Option<BreakDuration> {
     if data.len() < 5 {
@@ -239,6 +276,17 @@ impl SpliceInfoSection {
             let (time, consumed) = parse_splice_time(&data[offset..]);
             splice_time = time;
             offset += consumed;
+        } else if !program_splice_flag {
+            // Component splice mode: skip the per-component tags and times
+            let component_count = data.get(offset).copied().unwrap_or(0);
+            offset += 1;
+            for _ in 0..component_count {
+                offset += 1;
+                if !splice_immediate_flag {
+                    let (_, consumed) = parse_splice_time(data.get(offset..).unwrap_or(&[]));
+                    offset += consumed;
+                }
+            }
         }
 
         let duration = if duration_flag && offset + 5 <= data.len() {
@@ -273,6 +321,97 @@ impl SpliceInfoSection {
     }
 }
 
+impl SpliceInfoSection {
+    /// Serialize the section, CRC-32 included.
+    ///
+    /// The section is written unencrypted with no tier restriction and an
+    /// empty descriptor loop. Splice inserts in component mode are written
+    /// with no components, as [`SpliceInsert`] does not carry them.
+    pub fn to_section(&self) -> Result<Vec<u8>> {
+        if self.encrypted_packet {
+            return Err(TsError::InvalidScte35(
+                "encrypted sections cannot be encoded".to_string(),
+            ));
+        }
+
+        let (command_type, command) = match &self.splice_command {
+            SpliceCommand::SpliceNull => (SpliceCommandType::SpliceNull, Vec::new()),
+            SpliceCommand::SpliceInsert(insert) => {
+                (SpliceCommandType::SpliceInsert, insert.to_bytes())
+            }
+            SpliceCommand::TimeSignal(signal) => {
+                let mut command = Vec::with_capacity(5);
+                write_splice_time(&mut command, signal.splice_time);
+                (SpliceCommandType::TimeSignal, command)
+            }
+            SpliceCommand::Other(data) => (self.splice_command_type, data.clone()),
+        };
+
+        let mut section = Vec::with_capacity(command.len() + 20);
+        // section_syntax_indicator=0, private_indicator=0, sap_type=3,
+        // section_length filled in below
+        section.extend_from_slice(&[self.table_id, 0x30, 0x00, self.protocol_version]);
+        section.extend_from_slice(&encode_33bit(0x00, self.pts_adjustment));
+        // encryption_algorithm=0 leaves only the pts_adjustment high bit
+        section[4] &= 0x01;
+        // cw_index, tier=0xFFF, splice_command_length
+        section.push(0x00);
+        section.push(0xFF);
+        section.push(0xF0 | ((command.len() >> 8) as u8 & 0x0F));
+        section.push(command.len() as u8);
+        section.push(command_type.into());
+        section.extend_from_slice(&command);
+        // descriptor_loop_length
+        section.extend_from_slice(&[0x00, 0x00]);
+
+        let section_length = section.len() - 3 + 4;
+        if section_length > MAX_SECTION_LENGTH {
+            return Err(TsError::InvalidSectionLength(section_length as u16));
+        }
+        section[1] |= (section_length >> 8) as u8 & 0x0F;
+        section[2] = section_length as u8;
+        let crc = mpeg2_crc32(&section);
+        section.extend_from_slice(&crc.to_be_bytes());
+        Ok(section)
+    }
+}
+
+impl SpliceInsert {
+    /// Encode the splice_insert() command bytes.
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(20);
+        out.extend_from_slice(&self.splice_event_id.to_be_bytes());
+        if self.splice_event_cancel_indicator {
+            out.push(0xFF);
+            return out;
+        }
+        out.push(0x7F);
+        out.push(
+            (u8::from(self.out_of_network_indicator) << 7)
+                | (u8::from(self.program_splice_flag) << 6)
+                | (u8::from(self.duration.is_some()) << 5)
+                | (u8::from(self.splice_immediate_flag) << 4)
+                | 0x0F,
+        );
+        if !self.program_splice_flag {
+            // component_count
+            out.push(0x00);
+        } else if !self.splice_immediate_flag {
+            write_splice_time(&mut out, self.splice_time);
+        }
+        if let Some(duration) = self.duration {
+            out.extend_from_slice(&encode_33bit(
+                u8::from(duration.auto_return) << 7,
+                duration.duration,
+            ));
+        }
+        out.extend_from_slice(&self.unique_program_id.to_be_bytes());
+        out.push(self.avail_num);
+        out.push(self.avails_expected);
+        out
+    }
+}
+
 /// Zero-copy SCTE-35 splice info section reference.
 #[derive(Debug, Clone)]
 pub struct SpliceInfoSectionRef {
@@ -408,6 +547,110 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_scte35_section_round_trip() {
+        let insert = SpliceInsert {
+            splice_event_id: 0x4800_0001,
+            splice_event_cancel_indicator: false,
+            out_of_network_indicator: true,
+            program_splice_flag: true,
+            splice_immediate_flag: false,
+            splice_time: Some((1 << 32) + 90_000),
+            duration: Some(BreakDuration {
+                auto_return: true,
+                duration: 2_700_000,
+            }),
+            unique_program_id: 7,
+            avail_num: 1,
+            avails_expected: 2,
+        };
+        let section = SpliceInfoSection {
+            table_id: SCTE35_TABLE_ID,
+            protocol_version: 0,
+            encrypted_packet: false,
+            pts_adjustment: (1 << 32) + 1,
+            splice_command_type: SpliceCommandType::SpliceInsert,
+            splice_command: SpliceCommand::SpliceInsert(insert.clone()),
+        };
+        let data = section.to_section().unwrap();
+        assert!(crate::crc32::validate_section_crc32(&data));
+        assert_eq!(
+            data.len() - 3,
+            ((data[1] as usize & 0x0F) << 8) | data[2] as usize
+        );
+
+        let parsed = SpliceInfoSection::parse(&data).unwrap();
+        assert_eq!(parsed.pts_adjustment, (1 << 32) + 1);
+        let SpliceCommand::SpliceInsert(parsed_insert) = &parsed.splice_command else {
+            panic!("Expected SpliceInsert, got {:?}", parsed.splice_command);
+        };
+        assert_eq!(parsed_insert.splice_event_id, insert.splice_event_id);
+        assert_eq!(parsed_insert.splice_time, insert.splice_time);
+        assert_eq!(parsed_insert.duration.unwrap().duration, 2_700_000);
+        assert_eq!(
+            (
+                parsed_insert.unique_program_id,
+                parsed_insert.avail_num,
+                parsed_insert.avails_expected
+            ),
+            (7, 1, 2)
+        );
+
+        // Component mode carries no splice time, the avail fields still line up
+        let component = SpliceInsert {
+            program_splice_flag: false,
+            splice_time: None,
+            ..insert
+        };
+        let data = SpliceInfoSection {
+            splice_command: SpliceCommand::SpliceInsert(component),
+            ..section
+        }
+        .to_section()
+        .unwrap();
+        let SpliceCommand::SpliceInsert(parsed) =
+            SpliceInfoSection::parse(&data).unwrap().splice_command
+        else {
+            panic!("Expected SpliceInsert");
+        };
+        assert!(!parsed.program_splice_flag);
+        assert_eq!(parsed.duration.unwrap().duration, 2_700_000);
+        assert_eq!(parsed.avails_expected, 2);
+    }
+
+    #[test]
+    fn test_scte35_time_signal_encoding() {
+        let section = SpliceInfoSection {
+            table_id: SCTE35_TABLE_ID,
+            protocol_version: 0,
+            encrypted_packet: false,
+            pts_adjustment: 0,
+            splice_command_type: SpliceCommandType::TimeSignal,
+            splice_command: SpliceCommand::TimeSignal(TimeSignal {
+                splice_time: Some(90_000),
+            }),
+        };
+        let data = section.to_section().unwrap();
+        // Same layout as the hand-built section, with a descriptor loop and CRC
+        let mut expected = make_scte35_time_signal(90_000);
+        expected[2] = 0x16;
+        expected[11] = 0xF0;
+        expected[14] = 0xFE;
+        expected.truncate(expected.len() - 4);
+        expected.extend_from_slice(&[0x00, 0x00]);
+        assert_eq!(&data[..data.len() - 4], expected.as_slice());
+        assert!(crate::crc32::validate_section_crc32(&data));
+
+        let encrypted = SpliceInfoSection {
+            encrypted_packet: true,
+            ..section
+        };
+        assert!(matches!(
+            encrypted.to_section(),
+            Err(TsError::InvalidScte35(_))
+        ));
+    }
+
     #[test]
     fn test_scte35_invalid_table_id() {
         let data = vec![0x00; 20];
@@ -0,0 +1,566 @@
+//! DVB Service Information tables (ETSI EN 300 468).
+//!
+//! Streams originating from DVB broadcasts carry, next to the MPEG PSI, a
+//! Service Description Table naming the services, Event Information Tables
+//! describing the current and next programme of each service, and a Time and
+//! Date Table with the broadcast UTC time.
+
+use crate::{Result, TsError, descriptor};
+
+/// PID carrying the SDT (and BAT)
+pub const PID_SDT: u16 = 0x0011;
+/// PID carrying the EIT
+pub const PID_EIT: u16 = 0x0012;
+/// PID carrying the TDT and TOT
+pub const PID_TDT: u16 = 0x0014;
+
+/// SDT describing the current transport stream
+pub const TABLE_ID_SDT_ACTUAL: u8 = 0x42;
+/// SDT describing another transport stream
+pub const TABLE_ID_SDT_OTHER: u8 = 0x46;
+/// EIT present/following of the current transport stream
+pub const TABLE_ID_EIT_PF_ACTUAL: u8 = 0x4E;
+/// EIT present/following of another transport stream
+pub const TABLE_ID_EIT_PF_OTHER: u8 = 0x4F;
+/// Time and Date Table
+pub const TABLE_ID_TDT: u8 = 0x70;
+/// Time Offset Table
+pub const TABLE_ID_TOT: u8 = 0x73;
+
+/// Modified Julian Date of 1970-01-01
+const MJD_UNIX_EPOCH: u64 = 40_587;
+
+/// Running status of a service or event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RunningStatus {
+    Undefined,
+    NotRunning,
+    StartsInAFewSeconds,
+    Pausing,
+    Running,
+    ServiceOffAir,
+    Reserved(u8),
+}
+
+impl From<u8> for RunningStatus {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => RunningStatus::Undefined,
+            1 => RunningStatus::NotRunning,
+            2 => RunningStatus::StartsInAFewSeconds,
+            3 => RunningStatus::Pausing,
+            4 => RunningStatus::Running,
+            5 => RunningStatus::ServiceOffAir,
+            _ => RunningStatus::Reserved(value),
+        }
+    }
+}
+
+/// Service Description Table (SDT) - Table ID 0x42 (actual) / 0x46 (other)
+#[derive(Debug, Clone)]
+pub struct Sdt {
+    pub table_id: u8,
+    pub transport_stream_id: u16,
+    pub version_number: u8,
+    pub current_next_indicator: bool,
+    pub section_number: u8,
+    pub last_section_number: u8,
+    pub original_network_id: u16,
+    pub services: Vec<SdtService>,
+}
+
+/// Service entry in SDT
+#[derive(Debug, Clone)]
+pub struct SdtService {
+    /// Service ID, equal to the program number in the PAT
+    pub service_id: u16,
+    pub eit_schedule_flag: bool,
+    pub eit_present_following_flag: bool,
+    pub running_status: RunningStatus,
+    pub free_ca_mode: bool,
+    /// Service type from the service descriptor
+    pub service_type: Option<u8>,
+    /// Provider name from the service descriptor
+    pub provider_name: Option<String>,
+    /// Service name from the service descriptor
+    pub service_name: Option<String>,
+    /// Raw descriptor loop
+    pub descriptors: Vec<u8>,
+}
+
+impl Sdt {
+    /// Parse SDT from PSI section data
+    pub fn parse(data: &[u8]) -> Result<Self> {
+        let section_end = long_section_end(data, 12, "SDT")?;
+        let table_id = data[0];
+        if table_id != TABLE_ID_SDT_ACTUAL && table_id != TABLE_ID_SDT_OTHER {
+            return Err(TsError::InvalidTableId {
+                expected: TABLE_ID_SDT_ACTUAL,
+                actual: table_id,
+            });
+        }
+
+        let mut services = Vec::new();
+        let mut offset = 11;
+        while offset + 5 <= section_end {
+            let descriptors_length =
+                ((data[offset + 3] as usize & 0x0F) << 8) | data[offset + 4] as usize;
+            let descriptors_end = offset + 5 + descriptors_length;
+            if descriptors_end > section_end {
+                return Err(TsError::InsufficientData {
+                    expected: descriptors_end,
+                    actual: section_end,
+                });
+            }
+            let descriptors = data[offset + 5..descriptors_end].to_vec();
+            let service = find_descriptor(&descriptors, descriptor::TAG_SERVICE)
+                .and_then(descriptor::parse_service_descriptor);
+
+            services.push(SdtService {
+                service_id: ((data[offset] as u16) << 8) | data[offset + 1] as u16,
+                eit_schedule_flag: data[offset + 2] & 0x02 != 0,
+                eit_present_following_flag: data[offset + 2] & 0x01 != 0,
+                running_status: RunningStatus::from(data[offset + 3] >> 5),
+                free_ca_mode: data[offset + 3] & 0x10 != 0,
+                service_type: service.as_ref().map(|service| service.service_type),
+                provider_name: service
+                    .as_ref()
+                    .map(|service| service.provider_name.clone()),
+                service_name: service.map(|service| service.service_name),
+                descriptors,
+            });
+            offset = descriptors_end;
+        }
+
+        Ok(Sdt {
+            table_id,
+            transport_stream_id: ((data[3] as u16) << 8) | data[4] as u16,
+            version_number: (data[5] >> 1) & 0x1F,
+            current_next_indicator: (data[5] & 0x01) != 0,
+            section_number: data[6],
+            last_section_number: data[7],
+            original_network_id: ((data[8] as u16) << 8) | data[9] as u16,
+            services,
+        })
+    }
+
+    /// Parse SDT from PSI section data with CRC-32/MPEG-2 validation.
+    pub fn parse_with_crc(data: &[u8]) -> Result<Self> {
+        validate_crc(data)?;
+        Self::parse(data)
+    }
+
+    /// Get the service with the given ID (the program number of the PAT)
+    pub fn service(&self, service_id: u16) -> Option<&SdtService> {
+        self.services
+            .iter()
+            .find(|service| service.service_id == service_id)
+    }
+}
+
+/// Event Information Table (EIT) - Table IDs 0x4E..=0x6F
+#[derive(Debug, Clone)]
+pub struct Eit {
+    pub table_id: u8,
+    pub service_id: u16,
+    pub version_number: u8,
+    pub current_next_indicator: bool,
+    /// For present/following tables, 0 carries the present event and 1 the
+    /// following one
+    pub section_number: u8,
+    pub last_section_number: u8,
+    pub transport_stream_id: u16,
+    pub original_network_id: u16,
+    pub events: Vec<EitEvent>,
+}
+
+/// Event entry in EIT
+#[derive(Debug, Clone)]
+pub struct EitEvent {
+    pub event_id: u16,
+    /// Start time in seconds since the Unix epoch (UTC), `None` if undefined
+    pub start_time: Option<u64>,
+    /// Duration in seconds, `None` if undefined
+    pub duration_secs: Option<u32>,
+    pub running_status: RunningStatus,
+    pub free_ca_mode: bool,
+    /// Language, name and text from the short event descriptor
+    pub short_event: Option<descriptor::ShortEventDescriptor>,
+    /// Raw descriptor loop
+    pub descriptors: Vec<u8>,
+}
+
+impl Eit {
+    /// Parse EIT from PSI section data
+    pub fn parse(data: &[u8]) -> Result<Self> {
+        let section_end = long_section_end(data, 18, "EIT")?;
+        let table_id = data[0];
+        if !(TABLE_ID_EIT_PF_ACTUAL..=0x6F).contains(&table_id) {
+            return Err(TsError::InvalidTableId {
+                expected: TABLE_ID_EIT_PF_ACTUAL,
+                actual: table_id,
+            });
+        }
+
+        let mut events = Vec::new();
+        let mut offset = 14;
+        while offset + 12 <= section_end {
+            let descriptors_length =
+                ((data[offset + 10] as usize & 0x0F) << 8) | data[offset + 11] as usize;
+            let descriptors_end = offset + 12 + descriptors_length;
+            if descriptors_end > section_end {
+                return Err(TsError::InsufficientData {
+                    expected: descriptors_end,
+                    actual: section_end,
+                });
+            }
+            let descriptors = data[offset + 12..descriptors_end].to_vec();
+            let short_event = find_descriptor(&descriptors, descriptor::TAG_SHORT_EVENT)
+                .and_then(descriptor::parse_short_event_descriptor);
+
+            events.push(EitEvent {
+                event_id: ((data[offset] as u16) << 8) | data[offset + 1] as u16,
+                start_time: parse_utc_time(&data[offset + 2..offset + 7]),
+                duration_secs: parse_bcd_duration(&data[offset + 7..offset + 10]),
+                running_status: RunningStatus::from(data[offset + 10] >> 5),
+                free_ca_mode: data[offset + 10] & 0x10 != 0,
+                short_event,
+                descriptors,
+            });
+            offset = descriptors_end;
+        }
+
+        Ok(Eit {
+            table_id,
+            service_id: ((data[3] as u16) << 8) | data[4] as u16,
+            version_number: (data[5] >> 1) & 0x1F,
+            current_next_indicator: (data[5] & 0x01) != 0,
+            section_number: data[6],
+            last_section_number: data[7],
+            transport_stream_id: ((data[8] as u16) << 8) | data[9] as u16,
+            original_network_id: ((data[10] as u16) << 8) | data[11] as u16,
+            events,
+        })
+    }
+
+    /// Parse EIT from PSI section data with CRC-32/MPEG-2 validation.
+    pub fn parse_with_crc(data: &[u8]) -> Result<Self> {
+        validate_crc(data)?;
+        Self::parse(data)
+    }
+
+    /// Check if this is a present/following table rather than a schedule
+    pub fn is_present_following(&self) -> bool {
+        self.table_id == TABLE_ID_EIT_PF_ACTUAL || self.table_id == TABLE_ID_EIT_PF_OTHER
+    }
+}
+
+/// Present and following events of one service.
+#[derive(Debug, Clone, Default)]
+pub struct NowNext {
+    pub present: Option<EitEvent>,
+    pub following: Option<EitEvent>,
+}
+
+/// Time and Date Table (TDT) - Table ID 0x70, or the time of a Time Offset
+/// Table (TOT) - Table ID 0x73
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tdt {
+    pub table_id: u8,
+    /// Current UTC time in seconds since the Unix epoch
+    pub utc_time: u64,
+}
+
+impl Tdt {
+    /// Parse TDT or TOT from PSI section data
+    pub fn parse(data: &[u8]) -> Result<Self> {
+        if data.len() < 8 {
+            return Err(TsError::InsufficientData {
+                expected: 8,
+                actual: data.len(),
+            });
+        }
+        let table_id = data[0];
+        if table_id != TABLE_ID_TDT && table_id != TABLE_ID_TOT {
+            return Err(TsError::InvalidTableId {
+                expected: TABLE_ID_TDT,
+                actual: table_id,
+            });
+        }
+        let utc_time = parse_utc_time(&data[3..8])
+            .ok_or_else(|| TsError::ParseError("TDT carries an undefined UTC time".to_string()))?;
+        Ok(Tdt { table_id, utc_time })
+    }
+}
+
+/// End of the section payload (before the CRC-32) of a long-form section.
+fn long_section_end(data: &[u8], min_len: usize, table: &str) -> Result<usize> {
+    if data.len() < min_len {
+        return Err(TsError::InsufficientData {
+            expected: min_len,
+            actual: data.len(),
+        });
+    }
+    if data[1] & 0x80 == 0 {
+        return Err(TsError::ParseError(format!(
+            "{table} must have section syntax indicator set"
+        )));
+    }
+    let section_length = ((data[1] as u16 & 0x0F) << 8) | data[2] as u16;
+    let total = 3 + section_length as usize;
+    if total < min_len {
+        return Err(TsError::InvalidSectionLength(section_length));
+    }
+    if data.len() < total {
+        return Err(TsError::InsufficientData {
+            expected: total,
+            actual: data.len(),
+        });
+    }
+    Ok(total - 4)
+}
+
+fn validate_crc(data: &[u8]) -> Result<()> {
+    if data.len() < 7 {
+        return Ok(());
+    }
+    let section_length = ((data[1] as u16 & 0x0F) << 8) | data[2] as u16;
+    let section_end = 3 + section_length as usize;
+    if section_end <= data.len()
+        && section_end >= 4
+        && !crate::crc32::validate_section_crc32(&data[..section_end])
+    {
+        let stored = u32::from_be_bytes([
+            data[section_end - 4],
+            data[section_end - 3],
+            data[section_end - 2],
+            data[section_end - 1],
+        ]);
+        return Err(TsError::Crc32Mismatch {
+            expected: stored,
+            calculated: crate::crc32::mpeg2_crc32(&data[..section_end - 4]),
+        });
+    }
+    Ok(())
+}
+
+fn find_descriptor(descriptors: &[u8], tag: u8) -> Option<&[u8]> {
+    let mut offset = 0;
+    while offset + 2 <= descriptors.len() {
+        let end = offset + 2 + descriptors[offset + 1] as usize;
+        if end > descriptors.len() {
+            return None;
+        }
+        if descriptors[offset] == tag {
+            return Some(&descriptors[offset + 2..end]);
+        }
+        offset = end;
+    }
+    None
+}
+
+fn bcd(byte: u8) -> Option<u64> {
+    let (high, low) = (byte >> 4, byte & 0x0F);
+    (high < 10 && low < 10).then(|| u64::from(high * 10 + low))
+}
+
+/// Parse a 40-bit UTC time: 16-bit Modified Julian Date followed by
+/// HH:MM:SS in BCD. All ones means undefined.
+pub fn parse_utc_time(data: &[u8]) -> Option<u64> {
+    if data.len() < 5 || data[..5].iter().all(|&byte| byte == 0xFF) {
+        return None;
+    }
+    let mjd = ((data[0] as u64) << 8) | data[1] as u64;
+    let days = mjd.checked_sub(MJD_UNIX_EPOCH)?;
+    let seconds = bcd(data[2])? * 3600 + bcd(data[3])? * 60 + bcd(data[4])?;
+    Some(days * 86_400 + seconds)
+}
+
+/// Parse a 24-bit HH:MM:SS duration in BCD. All ones means undefined.
+fn parse_bcd_duration(data: &[u8]) -> Option<u32> {
+    if data[..3].iter().all(|&byte| byte == 0xFF) {
+        return None;
+    }
+    Some((bcd(data[0])? * 3600 + bcd(data[1])? * 60 + bcd(data[2])?) as u32)
+}
+
+/// Decode a DVB text field (EN 300 468 Annex A).
+///
+/// UTF-8 and the 16-bit BMP table are decoded exactly. Single-byte tables are
+/// decoded as ISO 8859-1, which is exact for ASCII and the Latin tables'
+/// common characters. Control codes are dropped, except the CR/LF code 0x8A.
+pub fn decode_dvb_text(data: &[u8]) -> String {
+    let Some(&first) = data.first() else {
+        return String::new();
+    };
+    let text = match first {
+        0x20..=0xFF => data,
+        0x10 => data.get(3..).unwrap_or_default(),
+        0x1F => data.get(2..).unwrap_or_default(),
+        _ => &data[1..],
+    };
+    match first {
+        0x15 => String::from_utf8_lossy(text).into_owned(),
+        0x11 => {
+            let units: Vec<u16> = text
+                .chunks_exact(2)
+                .map(|pair| u16::from_be_bytes([pair[0], pair[1]]))
+                .collect();
+            String::from_utf16_lossy(&units)
+        }
+        _ => text
+            .iter()
+            .filter_map(|&byte| match byte {
+                0x8A => Some('\n'),
+                0x00..=0x1F | 0x80..=0x9F => None,
+                _ => Some(char::from(byte)),
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn finish(mut section: Vec<u8>) -> Vec<u8> {
+        let section_length = section.len() - 3 + 4;
+        section[1] = 0xF0 | (section_length >> 8) as u8;
+        section[2] = section_length as u8;
+        let crc = crate::crc32::mpeg2_crc32(&section);
+        section.extend_from_slice(&crc.to_be_bytes());
+        section
+    }
+
+    fn sdt_section() -> Vec<u8> {
+        let mut descriptor = vec![descriptor::TAG_SERVICE, 0, 0x01, 3];
+        descriptor.extend_from_slice(b"ARD");
+        descriptor.push(8);
+        descriptor.extend_from_slice(b"Das Erst");
+        descriptor[1] = (descriptor.len() - 2) as u8;
+
+        let mut section = vec![
+            TABLE_ID_SDT_ACTUAL,
+            0,
+            0,
+            0x04,
+            0x1B, // transport_stream_id
+            0xC3, // version 1, current
+            0x00,
+            0x00,
+            0x00,
+            0x01, // original_network_id
+            0xFF,
+            0x6D,
+            0x66, // service_id 0x6D66
+            0xFD, // EIT p/f flag
+            0x80, // running
+            descriptor.len() as u8,
+        ];
+        section.extend_from_slice(&descriptor);
+        finish(section)
+    }
+
+    #[test]
+    fn test_sdt_parsing() {
+        let sdt = Sdt::parse_with_crc(&sdt_section()).unwrap();
+        assert_eq!(sdt.transport_stream_id, 0x041B);
+        assert_eq!(sdt.version_number, 1);
+        assert_eq!(sdt.services.len(), 1);
+
+        let service = sdt.service(0x6D66).unwrap();
+        assert!(service.eit_present_following_flag);
+        assert!(!service.eit_schedule_flag);
+        assert_eq!(service.running_status, RunningStatus::Running);
+        assert_eq!(service.service_type, Some(0x01));
+        assert_eq!(service.provider_name.as_deref(), Some("ARD"));
+        assert_eq!(service.service_name.as_deref(), Some("Das Erst"));
+    }
+
+    #[test]
+    fn test_sdt_crc_mismatch() {
+        let mut section = sdt_section();
+        section[10] ^= 0x01;
+        assert!(matches!(
+            Sdt::parse_with_crc(&section),
+            Err(TsError::Crc32Mismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_eit_parsing() {
+        let mut descriptor = vec![descriptor::TAG_SHORT_EVENT, 0];
+        descriptor.extend_from_slice(b"deu");
+        descriptor.push(10);
+        descriptor.extend_from_slice(b"Tagesschau");
+        descriptor.extend_from_slice(&[3, 0x15, b'N', b'r']);
+        descriptor[1] = (descriptor.len() - 2) as u8;
+
+        let mut section = vec![
+            TABLE_ID_EIT_PF_ACTUAL,
+            0,
+            0,
+            0x6D,
+            0x66, // service_id
+            0xC1,
+            0x00, // present
+            0x01,
+            0x04,
+            0x1B,
+            0x00,
+            0x01,
+            0x01,
+            TABLE_ID_EIT_PF_ACTUAL,
+            0x12,
+            0x34, // event_id
+            // 2024-01-01 20:00:00 (MJD 60310)
+            0xEB,
+            0x96,
+            0x20,
+            0x00,
+            0x00,
+            0x00,
+            0x15,
+            0x00, // 00:15:00
+            0x80,
+            descriptor.len() as u8,
+        ];
+        section.extend_from_slice(&descriptor);
+        let eit = Eit::parse_with_crc(&finish(section)).unwrap();
+
+        assert!(eit.is_present_following());
+        assert_eq!(eit.service_id, 0x6D66);
+        assert_eq!(eit.section_number, 0);
+        let event = &eit.events[0];
+        assert_eq!(event.event_id, 0x1234);
+        assert_eq!(event.start_time, Some(1_704_139_200));
+        assert_eq!(event.duration_secs, Some(900));
+        assert_eq!(event.running_status, RunningStatus::Running);
+        let short_event = event.short_event.as_ref().unwrap();
+        assert_eq!(&short_event.language_code, b"deu");
+        assert_eq!(short_event.event_name, "Tagesschau");
+        assert_eq!(short_event.text, "Nr");
+    }
+
+    #[test]
+    fn test_tdt_parsing() {
+        let tdt = Tdt::parse(&[TABLE_ID_TDT, 0x70, 0x05, 0xEB, 0x96, 0x20, 0x15, 0x30]).unwrap();
+        assert_eq!(tdt.utc_time, 1_704_139_200 + 15 * 60 + 30);
+
+        let undefined = [TABLE_ID_TDT, 0x70, 0x05, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF];
+        assert!(Tdt::parse(&undefined).is_err());
+    }
+
+    #[test]
+    fn test_decode_dvb_text() {
+        assert_eq!(decode_dvb_text(b"Sport\x8ANews"), "Sport\nNews");
+        assert_eq!(decode_dvb_text(&[0xE9, b't', 0xE9]), "été");
+        assert_eq!(decode_dvb_text(&[0x05, b'a', 0x86, b'b', 0x87]), "ab");
+        assert_eq!(
+            decode_dvb_text(&[0x15, 0xE6, 0x97, 0xA5, 0xE6, 0x9C, 0xAC]),
+            "日本"
+        );
+        assert_eq!(decode_dvb_text(&[0x11, 0x04, 0x1F, 0x04, 0x35]), "Пе");
+        assert_eq!(decode_dvb_text(&[]), "");
+    }
+}
@@ -0,0 +1,238 @@
+//! Timestamp arithmetic and in-place restamping of TS packets.
+//!
+//! PTS, DTS and the PCR base are 33-bit counters of a 90 kHz clock that wrap
+//! around roughly every 26.5 hours; the PCR adds a 9-bit extension counting
+//! the 27 MHz clock from 0 to 299. The helpers here do offset arithmetic
+//! modulo those ranges and shift every timestamp of a packet by a delta
+//! without re-parsing the stream into owned structures.
+
+use tracing::debug;
+
+use crate::{
+    adaptation_field::Pcr,
+    packet::{PID_NULL, PID_PAT},
+    pes::PesHeader,
+    writer::{TS_PACKET_SIZE, encode_timestamp},
+};
+
+/// PTS, DTS and PCR base wrap around at 2^33 ticks.
+pub const TIMESTAMP_MODULO: u64 = 1 << 33;
+
+/// Clock rate of PTS, DTS and the PCR base.
+pub const TIMESTAMP_HZ: u64 = 90_000;
+
+/// Clock rate of the full PCR (base * 300 + extension).
+pub const PCR_HZ: u64 = 27_000_000;
+
+/// Full PCR values wrap around at 2^33 * 300 ticks.
+const PCR_MODULO: u64 = TIMESTAMP_MODULO * 300;
+
+/// Reduce a timestamp into the 33-bit range.
+pub fn wrap_timestamp(timestamp: i64) -> u64 {
+    timestamp.rem_euclid(TIMESTAMP_MODULO as i64) as u64
+}
+
+/// Shift a 33-bit timestamp by `delta` ticks, wrapping around.
+pub fn shift_timestamp(timestamp: u64, delta: i64) -> u64 {
+    wrap_timestamp(timestamp as i64 + delta)
+}
+
+/// Signed distance from `from` to `to` on the 33-bit timestamp circle,
+/// taking the shorter way round.
+pub fn timestamp_diff(to: u64, from: u64) -> i64 {
+    let diff = wrap_timestamp(to as i64 - from as i64) as i64;
+    if diff >= TIMESTAMP_MODULO as i64 / 2 {
+        diff - TIMESTAMP_MODULO as i64
+    } else {
+        diff
+    }
+}
+
+impl Pcr {
+    /// Split a 27 MHz PCR value into base and extension, wrapping around.
+    pub fn from_27mhz(value: u64) -> Self {
+        let value = value % PCR_MODULO;
+        Pcr {
+            base: value / 300,
+            extension: (value % 300) as u16,
+        }
+    }
+
+    /// Shift the PCR by `delta` ticks of the 27 MHz clock, wrapping around.
+    pub fn shifted(&self, delta: i64) -> Self {
+        Self::from_27mhz((self.as_27mhz() as i64 + delta).rem_euclid(PCR_MODULO as i64) as u64)
+    }
+}
+
+/// Offset of the payload within a packet, `None` when it carries none.
+pub fn packet_payload_offset(packet: &[u8]) -> Option<usize> {
+    if packet.len() < TS_PACKET_SIZE || packet[3] & 0x10 == 0 {
+        return None;
+    }
+    let offset = if packet[3] & 0x20 != 0 {
+        5 + packet[4] as usize
+    } else {
+        4
+    };
+    (offset < TS_PACKET_SIZE).then_some(offset)
+}
+
+/// PES header starting in this packet, if any.
+pub fn packet_pes_header(packet: &[u8]) -> Option<PesHeader> {
+    if packet.len() < TS_PACKET_SIZE || packet[0] != 0x47 {
+        return None;
+    }
+    let pid = (u16::from(packet[1] & 0x1F) << 8) | u16::from(packet[2]);
+    let unit_start = packet[1] & 0x40 != 0;
+    if !unit_start || pid == PID_PAT || pid == PID_NULL {
+        return None;
+    }
+    PesHeader::parse(&packet[packet_payload_offset(packet)?..]).ok()
+}
+
+/// Shift the PCR, OPCR, PTS and DTS of one 188-byte packet by `delta`
+/// 90 kHz ticks, in place.
+///
+/// PES headers whose timestamps continue into the next packet are left
+/// unchanged.
+pub fn restamp_packet(packet: &mut [u8], delta: i64) {
+    if packet.len() < TS_PACKET_SIZE || packet[0] != 0x47 {
+        return;
+    }
+
+    // Adaptation field present with its flags byte
+    if packet[3] & 0x20 != 0 && packet[4] >= 1 {
+        let field_end = 5 + packet[4] as usize;
+        let flags = packet[5];
+        let mut offset = 6;
+        for flag in [0x10, 0x08] {
+            if flags & flag == 0 {
+                continue;
+            }
+            if offset + 6 > field_end.min(TS_PACKET_SIZE) {
+                break;
+            }
+            let field = &mut packet[offset..offset + 6];
+            if let Some(pcr) = Pcr::parse(field) {
+                field.copy_from_slice(&pcr.shifted(delta * 300).to_bytes());
+            }
+            offset += 6;
+        }
+    }
+
+    let (Some(header), Some(offset)) = (packet_pes_header(packet), packet_payload_offset(packet))
+    else {
+        return;
+    };
+    for (index, timestamp) in [header.pts, header.dts].into_iter().enumerate() {
+        let Some(timestamp) = timestamp else {
+            continue;
+        };
+        let start = offset + 9 + index * 5;
+        if start + 5 > packet.len() {
+            debug!("PES timestamp crosses the packet boundary, leaving it unchanged");
+            return;
+        }
+        let prefix = packet[start] >> 4;
+        packet[start..start + 5]
+            .copy_from_slice(&encode_timestamp(prefix, shift_timestamp(timestamp, delta)));
+    }
+}
+
+/// Shift the timestamps of every whole packet in `data` by `delta` 90 kHz
+/// ticks, in place.
+pub fn restamp(data: &mut [u8], delta: i64) {
+    if delta == 0 {
+        return;
+    }
+    for packet in data.chunks_exact_mut(TS_PACKET_SIZE) {
+        restamp_packet(packet, delta);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{packet::TsPacket, writer::write_pes_header};
+    use bytes::Bytes;
+
+    fn pes_packet(pts: u64, dts: u64, pcr: Pcr) -> Vec<u8> {
+        let mut adaptation_field = vec![0x10];
+        adaptation_field.extend_from_slice(&pcr.to_bytes());
+        let mut payload = Vec::new();
+        write_pes_header(&mut payload, 0xE0, Some(pts), Some(dts), true, 16);
+        payload.extend_from_slice(&[0u8; 16]);
+        TsPacket::new(
+            0x100,
+            true,
+            0,
+            Some(Bytes::from(adaptation_field)),
+            Some(Bytes::from(payload)),
+        )
+        .to_bytes()
+        .unwrap()
+        .to_vec()
+    }
+
+    #[test]
+    fn test_timestamp_arithmetic_wraps() {
+        let near_wrap = TIMESTAMP_MODULO - 100;
+        assert_eq!(shift_timestamp(near_wrap, 300), 200);
+        assert_eq!(shift_timestamp(100, -300), TIMESTAMP_MODULO - 200);
+        assert_eq!(timestamp_diff(200, near_wrap), 300);
+        assert_eq!(timestamp_diff(near_wrap, 200), -300);
+    }
+
+    #[test]
+    fn test_pcr_shift_carries_into_the_base() {
+        let pcr = Pcr {
+            base: 10,
+            extension: 299,
+        };
+        assert_eq!(
+            pcr.shifted(1),
+            Pcr {
+                base: 11,
+                extension: 0,
+            }
+        );
+        assert_eq!(
+            pcr.shifted(-3_000),
+            Pcr {
+                base: 0,
+                extension: 299,
+            }
+        );
+        let wrapped = Pcr {
+            base: TIMESTAMP_MODULO - 1,
+            extension: 200,
+        }
+        .shifted(200);
+        assert_eq!(
+            wrapped,
+            Pcr {
+                base: 0,
+                extension: 100,
+            }
+        );
+    }
+
+    #[test]
+    fn test_restamps_a_packet() {
+        let pcr = Pcr {
+            base: 90_000,
+            extension: 42,
+        };
+        let mut data = pes_packet(93_003, 90_000, pcr);
+        restamp(&mut data, -180_000);
+
+        let header = packet_pes_header(&data).unwrap();
+        assert_eq!(header.pts, Some(TIMESTAMP_MODULO - 86_997));
+        assert_eq!(header.dts, Some(TIMESTAMP_MODULO - 90_000));
+
+        let packet = TsPacket::parse(Bytes::from(data)).unwrap();
+        let restamped = packet.parse_adaptation_field().unwrap().pcr.unwrap();
+        assert_eq!(restamped.base, TIMESTAMP_MODULO - 90_000);
+        assert_eq!(restamped.extension, 42);
+    }
+}
@@ -0,0 +1,415 @@
+//! Serialization of transport stream structures.
+//!
+//! The inverse of the parsers: packets, PSI sections (PAT/PMT) with their
+//! CRC-32, PES headers and PCR values are written back to their ISO/IEC
+//! 13818-1 wire format. [`crate::mux::TsMuxer`] builds on these to produce
+//! complete transport streams.
+
+use crate::{
+    Result, TsError, adaptation_field::Pcr, crc32::mpeg2_crc32, packet::TsPacket, pat::Pat,
+    pmt::Pmt,
+};
+
+/// Size of a transport stream packet
+pub const TS_PACKET_SIZE: usize = 188;
+
+/// Bytes available after the 4-byte packet header
+pub const TS_PACKET_BODY_SIZE: usize = TS_PACKET_SIZE - 4;
+
+/// Largest `section_length` of a PAT or PMT section
+const MAX_PSI_SECTION_LENGTH: usize = 1021;
+
+impl TsPacket {
+    /// Create a packet with the adaptation field control derived from the
+    /// fields that are present.
+    pub fn new(
+        pid: u16,
+        payload_unit_start_indicator: bool,
+        continuity_counter: u8,
+        adaptation_field: Option<bytes::Bytes>,
+        payload: Option<bytes::Bytes>,
+    ) -> Self {
+        let adaptation_field_control =
+            (u8::from(adaptation_field.is_some()) << 1) | u8::from(payload.is_some());
+        TsPacket {
+            sync_byte: 0x47,
+            transport_error_indicator: false,
+            payload_unit_start_indicator,
+            transport_priority: false,
+            pid,
+            transport_scrambling_control: 0,
+            adaptation_field_control,
+            continuity_counter: continuity_counter & 0x0F,
+            adaptation_field,
+            payload,
+        }
+    }
+
+    /// Serialize the packet, appending exactly 188 bytes to `out`.
+    ///
+    /// Bodies shorter than 184 bytes are padded with adaptation field
+    /// stuffing, adding an adaptation field if the packet has none. Fails with
+    /// [`TsError::PacketOverflow`] if the adaptation field and payload do not
+    /// fit in one packet.
+    pub fn write_to(&self, out: &mut Vec<u8>) -> Result<()> {
+        let payload = match &self.payload {
+            Some(payload) if self.has_payload() => payload.as_ref(),
+            _ => &[],
+        };
+        let adaptation_field = match &self.adaptation_field {
+            Some(field) if self.has_adaptation_field() => field.as_ref(),
+            _ => &[],
+        };
+        let with_adaptation_field =
+            self.has_adaptation_field() || payload.len() < TS_PACKET_BODY_SIZE;
+
+        let body_size = payload.len()
+            + if with_adaptation_field {
+                1 + adaptation_field.len()
+            } else {
+                0
+            };
+        if body_size > TS_PACKET_BODY_SIZE {
+            return Err(TsError::PacketOverflow(body_size));
+        }
+
+        let adaptation_field_control =
+            (u8::from(with_adaptation_field) << 1) | u8::from(!payload.is_empty());
+        out.reserve(TS_PACKET_SIZE);
+        out.push(0x47);
+        out.push(
+            (u8::from(self.transport_error_indicator) << 7)
+                | (u8::from(self.payload_unit_start_indicator) << 6)
+                | (u8::from(self.transport_priority) << 5)
+                | ((self.pid >> 8) as u8 & 0x1F),
+        );
+        out.push(self.pid as u8);
+        out.push(
+            ((self.transport_scrambling_control & 0x03) << 6)
+                | (adaptation_field_control << 4)
+                | (self.continuity_counter & 0x0F),
+        );
+
+        if with_adaptation_field {
+            let field_length = TS_PACKET_BODY_SIZE - payload.len() - 1;
+            out.push(field_length as u8);
+            if field_length > 0 {
+                if adaptation_field.is_empty() {
+                    // Flags byte with every flag cleared
+                    out.push(0x00);
+                    out.resize(out.len() + field_length - 1, 0xFF);
+                } else {
+                    out.extend_from_slice(adaptation_field);
+                    out.resize(out.len() + field_length - adaptation_field.len(), 0xFF);
+                }
+            }
+        }
+        out.extend_from_slice(payload);
+        Ok(())
+    }
+
+    /// Serialize the packet into a new 188-byte buffer.
+    pub fn to_bytes(&self) -> Result<bytes::Bytes> {
+        let mut out = Vec::with_capacity(TS_PACKET_SIZE);
+        self.write_to(&mut out)?;
+        Ok(out.into())
+    }
+}
+
+impl Pcr {
+    /// Encode the PCR into its 6-byte adaptation field layout.
+    pub fn to_bytes(&self) -> [u8; 6] {
+        let base = self.base & 0x1_FFFF_FFFF;
+        let extension = self.extension & 0x01FF;
+        [
+            (base >> 25) as u8,
+            (base >> 17) as u8,
+            (base >> 9) as u8,
+            (base >> 1) as u8,
+            ((base as u8 & 0x01) << 7) | 0x7E | (extension >> 8) as u8,
+            extension as u8,
+        ]
+    }
+}
+
+impl Pat {
+    /// Serialize the PAT as a complete PSI section, CRC-32 included.
+    pub fn to_section(&self) -> Result<Vec<u8>> {
+        let mut section = section_header(
+            self.table_id,
+            self.transport_stream_id,
+            self.version_number,
+            self.current_next_indicator,
+            self.section_number,
+            self.last_section_number,
+        );
+        for program in &self.programs {
+            section.extend_from_slice(&program.program_number.to_be_bytes());
+            section.extend_from_slice(&(0xE000 | program.pmt_pid).to_be_bytes());
+        }
+        finish_section(section)
+    }
+}
+
+impl Pmt {
+    /// Serialize the PMT as a complete PSI section, CRC-32 included.
+    pub fn to_section(&self) -> Result<Vec<u8>> {
+        let mut section = section_header(
+            self.table_id,
+            self.program_number,
+            self.version_number,
+            self.current_next_indicator,
+            self.section_number,
+            self.last_section_number,
+        );
+        section.extend_from_slice(&(0xE000 | self.pcr_pid).to_be_bytes());
+        section.extend_from_slice(&(0xF000 | self.program_info.len() as u16).to_be_bytes());
+        section.extend_from_slice(&self.program_info);
+        for stream in &self.streams {
+            section.push(stream.stream_type.into());
+            section.extend_from_slice(&(0xE000 | stream.elementary_pid).to_be_bytes());
+            section.extend_from_slice(&(0xF000 | stream.es_info.len() as u16).to_be_bytes());
+            section.extend_from_slice(&stream.es_info);
+        }
+        finish_section(section)
+    }
+}
+
+/// Long-form section header up to `last_section_number`, with a zero
+/// `section_length` that [`finish_section`] fills in.
+fn section_header(
+    table_id: u8,
+    table_id_extension: u16,
+    version_number: u8,
+    current_next_indicator: bool,
+    section_number: u8,
+    last_section_number: u8,
+) -> Vec<u8> {
+    let mut section = Vec::with_capacity(64);
+    section.push(table_id);
+    // section_syntax_indicator, '0', reserved bits, section_length
+    section.extend_from_slice(&[0xB0, 0x00]);
+    section.extend_from_slice(&table_id_extension.to_be_bytes());
+    section.push(0xC0 | ((version_number & 0x1F) << 1) | u8::from(current_next_indicator));
+    section.push(section_number);
+    section.push(last_section_number);
+    section
+}
+
+/// Set `section_length` and append the CRC-32 over the whole section.
+fn finish_section(mut section: Vec<u8>) -> Result<Vec<u8>> {
+    let section_length = section.len() - 3 + 4;
+    if section_length > MAX_PSI_SECTION_LENGTH {
+        return Err(TsError::InvalidSectionLength(section_length as u16));
+    }
+    section[1] |= (section_length >> 8) as u8 & 0x0F;
+    section[2] = section_length as u8;
+    let crc = mpeg2_crc32(&section);
+    section.extend_from_slice(&crc.to_be_bytes());
+    Ok(section)
+}
+
+/// Write a PES header for `stream_id` carrying `payload_len` bytes of
+/// elementary stream data.
+///
+/// The DTS is only written when it differs from the PTS. Payloads too large
+/// for the 16-bit `PES_packet_length` get a length of 0, which ISO/IEC
+/// 13818-1 only allows for video streams.
+pub fn write_pes_header(
+    out: &mut Vec<u8>,
+    stream_id: u8,
+    pts: Option<u64>,
+    dts: Option<u64>,
+    data_alignment_indicator: bool,
+    payload_len: usize,
+) {
+    let dts = dts.filter(|dts| pts.is_some() && Some(*dts) != pts);
+    let header_data_length = match (pts, dts) {
+        (Some(_), Some(_)) => 10,
+        (Some(_), None) => 5,
+        _ => 0,
+    };
+    let packet_length = 3 + header_data_length + payload_len;
+
+    out.extend_from_slice(&[0x00, 0x00, 0x01, stream_id]);
+    out.extend_from_slice(&u16::try_from(packet_length).unwrap_or(0).to_be_bytes());
+    // '10' marker bits, no scrambling, priority or copyright
+    out.push(0x80 | (u8::from(data_alignment_indicator) << 2));
+    out.push(match (pts, dts) {
+        (Some(_), Some(_)) => 0xC0,
+        (Some(_), None) => 0x80,
+        _ => 0x00,
+    });
+    out.push(header_data_length as u8);
+    match (pts, dts) {
+        (Some(pts), Some(dts)) => {
+            out.extend_from_slice(&encode_timestamp(0x3, pts));
+            out.extend_from_slice(&encode_timestamp(0x1, dts));
+        }
+        (Some(pts), None) => out.extend_from_slice(&encode_timestamp(0x2, pts)),
+        _ => {}
+    }
+}
+
+/// Encode a 33-bit PTS/DTS with its 4-bit prefix and marker bits.
+///
+/// Layout: `[prefix(4) | ts32..30 | 1 | ts29..15 | 1 | ts14..0 | 1]`
+pub fn encode_timestamp(prefix: u8, timestamp: u64) -> [u8; 5] {
+    [
+        ((prefix & 0x0F) << 4) | (((timestamp >> 30) as u8 & 0x07) << 1) | 0x01,
+        (timestamp >> 22) as u8,
+        (((timestamp >> 15) as u8) << 1) | 0x01,
+        (timestamp >> 7) as u8,
+        ((timestamp as u8) << 1) | 0x01,
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        PesHeader,
+        pat::PatProgram,
+        pmt::{PmtStream, StreamType},
+    };
+    use bytes::Bytes;
+
+    #[test]
+    fn test_packet_round_trip() {
+        let packet = TsPacket::new(0x0100, true, 7, None, Some(Bytes::from(vec![0xAB; 184])));
+        let bytes = packet.to_bytes().unwrap();
+        assert_eq!(bytes.len(), TS_PACKET_SIZE);
+
+        let parsed = TsPacket::parse(bytes).unwrap();
+        assert_eq!(parsed.pid, 0x0100);
+        assert!(parsed.payload_unit_start_indicator);
+        assert_eq!(parsed.continuity_counter, 7);
+        assert_eq!(parsed.adaptation_field_control, 0x01);
+        assert_eq!(parsed.payload.unwrap().len(), 184);
+    }
+
+    #[test]
+    fn test_short_payload_is_stuffed() {
+        let packet = TsPacket::new(
+            0x0101,
+            false,
+            0,
+            Some(Bytes::from_static(&[0x40])),
+            Some(Bytes::from_static(b"tail")),
+        );
+        let bytes = packet.to_bytes().unwrap();
+
+        let parsed = TsPacket::parse(bytes).unwrap();
+        assert!(parsed.has_random_access_indicator());
+        assert_eq!(parsed.adaptation_field.unwrap().len(), 184 - 4 - 1);
+        assert_eq!(parsed.payload.unwrap().as_ref(), b"tail");
+
+        // A payload one byte short of a full packet gets an empty adaptation field.
+        let packet = TsPacket::new(0x0101, false, 0, None, Some(Bytes::from(vec![0; 183])));
+        let bytes = packet.to_bytes().unwrap();
+        assert_eq!(bytes[3] >> 4, 0x03);
+        assert_eq!(bytes[4], 0);
+    }
+
+    #[test]
+    fn test_overflow_is_rejected() {
+        let packet = TsPacket::new(
+            0x0101,
+            false,
+            0,
+            Some(Bytes::from_static(&[0x00])),
+            Some(Bytes::from(vec![0; 183])),
+        );
+        assert!(matches!(
+            packet.to_bytes(),
+            Err(TsError::PacketOverflow(185))
+        ));
+    }
+
+    #[test]
+    fn test_pat_section_round_trip() {
+        let pat = Pat {
+            table_id: 0x00,
+            transport_stream_id: 1,
+            version_number: 3,
+            current_next_indicator: true,
+            section_number: 0,
+            last_section_number: 0,
+            programs: vec![PatProgram {
+                program_number: 1,
+                pmt_pid: 0x1000,
+            }],
+        };
+        let section = pat.to_section().unwrap();
+        assert_eq!(section.len(), 16);
+
+        let parsed = Pat::parse_with_crc(&section).unwrap();
+        assert_eq!(parsed.version_number, 3);
+        assert_eq!(parsed.get_pmt_pid(1), Some(0x1000));
+    }
+
+    #[test]
+    fn test_pmt_section_round_trip() {
+        let pmt = Pmt {
+            table_id: 0x02,
+            program_number: 1,
+            version_number: 0,
+            current_next_indicator: true,
+            section_number: 0,
+            last_section_number: 0,
+            pcr_pid: 0x0100,
+            program_info: vec![],
+            streams: vec![
+                PmtStream {
+                    stream_type: StreamType::H264,
+                    elementary_pid: 0x0100,
+                    es_info: vec![],
+                },
+                PmtStream {
+                    stream_type: StreamType::AdtsAac,
+                    elementary_pid: 0x0101,
+                    es_info: vec![0x0A, 0x04, b'e', b'n', b'g', 0x00],
+                },
+            ],
+        };
+        let section = pmt.to_section().unwrap();
+
+        let parsed = Pmt::parse_with_crc(&section).unwrap();
+        assert_eq!(parsed.pcr_pid, 0x0100);
+        assert_eq!(parsed.streams.len(), 2);
+        assert_eq!(parsed.streams[0].stream_type, StreamType::H264);
+        assert_eq!(parsed.streams[1].elementary_pid, 0x0101);
+        assert_eq!(parsed.streams[1].es_info, pmt.streams[1].es_info);
+    }
+
+    #[test]
+    fn test_pes_header_round_trip() {
+        let pts = (1 << 32) + 12_345;
+        let mut out = Vec::new();
+        write_pes_header(&mut out, 0xE0, Some(pts), Some(pts - 3_003), true, 100);
+        out.extend_from_slice(&[0u8; 100]);
+
+        let header = PesHeader::parse(&out).unwrap();
+        assert_eq!(header.stream_id, 0xE0);
+        assert_eq!(header.pts, Some(pts));
+        assert_eq!(header.dts, Some(pts - 3_003));
+        assert!(header.data_alignment_indicator);
+        assert_eq!(header.pes_packet_length as usize, out.len() - 6);
+        assert_eq!(header.payload(&out).unwrap().len(), 100);
+
+        // Equal timestamps only carry the PTS.
+        let mut out = Vec::new();
+        write_pes_header(&mut out, 0xC0, Some(900), Some(900), false, 10);
+        let header = PesHeader::parse(&out).unwrap();
+        assert_eq!((header.pts, header.dts), (Some(900), None));
+    }
+
+    #[test]
+    fn test_pcr_round_trip() {
+        let pcr = Pcr {
+            base: 0x1_2345_6789,
+            extension: 0x155,
+        };
+        assert_eq!(Pcr::parse(&pcr.to_bytes()), Some(pcr));
+    }
+}
@@ -13,7 +13,7 @@ use crate::output::provider::OutputFormat;
     long_about = "A powerful tool for downloading, processing, and repairing media streams.\n\
                   Part of the stream-rec project: https://github.com/hua0512/rust-rec\n\
                   \n\
-                  This tool supports multiple protocols (FLV, HLS) and can fix common issues\n\
+                  This tool supports multiple protocols (FLV, HLS, DASH, RTMP) and can fix common issues\n\
                   such as timestamp anomalies, out-of-order frames, and metadata inconsistencies.\n\
                   It supports processing individual files, entire directories, or downloading\n\
                   directly from URLs with automatic protocol detection."
@@ -92,6 +92,13 @@ pub struct CliArgs {
     )]
     pub enable_fix: bool,
 
+    /// Keep the unprocessed stream next to the processed output
+    #[arg(
+        long,
+        help = "Also write the untouched downloaded stream as <name>_raw files next to the processed output"
+    )]
+    pub keep_raw: bool,
+
     /// Legacy compatibility switch for FLV metadata modification
     #[arg(
         long,
@@ -102,6 +109,31 @@ pub struct CliArgs {
     )]
     pub low_latency_fix: bool,
 
+    /// Write analysis reports for processed FLV and HLS output
+    #[arg(
+        long,
+        help = "Write a JSON analysis report (<file>.report.json) next to each processed FLV or HLS file",
+        requires = "enable_fix"
+    )]
+    pub report: bool,
+
+    /// Interval for in-place keyframe index checkpoints
+    #[arg(
+        long,
+        value_name = "SECONDS",
+        help = "Write the keyframe index into processed FLV files every SECONDS of media while recording, so interrupted recordings stay seekable",
+        requires = "enable_fix"
+    )]
+    pub metadata_checkpoint: Option<u64>,
+
+    /// Run FLV processing stages on separate threads
+    #[arg(
+        long,
+        help = "Run FLV processing as parallel stages on separate threads (helps high-bitrate streams)",
+        requires = "enable_fix"
+    )]
+    pub parallel_stages: bool,
+
     /// Channel size for processing channels
     #[arg(
         short = 'b',
@@ -151,6 +183,45 @@ pub struct CliArgs {
     )]
     pub write_timeout: u64,
 
+    /// Retries of failed connections and requests
+    #[arg(
+        long,
+        default_value = "3",
+        help = "Retries of failed stream connections and DASH requests, with exponential backoff"
+    )]
+    pub retries: u32,
+
+    /// Delay before the first retry in milliseconds
+    #[arg(
+        long,
+        default_value = "500",
+        help = "Delay before the first retry in milliseconds; it doubles with each retry"
+    )]
+    pub retry_delay: u64,
+
+    /// Random spread of retry delays
+    #[arg(
+        long,
+        default_value = "0.2",
+        help = "Fraction retry delays are randomly shortened or lengthened by (0 to 1)"
+    )]
+    pub retry_jitter: f64,
+
+    /// Seconds without media after which a download counts as dead
+    #[arg(
+        long,
+        default_value = "120",
+        help = "Seconds without media after which a download is failed over to the next source or stopped (0 disables)"
+    )]
+    pub stall_timeout: u64,
+
+    /// End dead downloads cleanly instead of failing them
+    #[arg(
+        long,
+        help = "End a download without media for --stall-timeout seconds as finished instead of failing it"
+    )]
+    pub end_on_stall: bool,
+
     /// Proxy URL (e.g., "http://proxy.example.com:8080")
     #[arg(
         long,
@@ -196,6 +267,22 @@ pub struct CliArgs {
     )]
     pub headers: Vec<String>,
 
+    /// Header templates rendered for every request
+    #[arg(
+        long = "header-template",
+        help = "Add an HTTP header rendered for every request (can be used multiple times). Format: 'Name: Template', where {timestamp}, {timestamp_ms} and --var names are replaced",
+        value_name = "TEMPLATE"
+    )]
+    pub header_templates: Vec<String>,
+
+    /// Variables of the header templates
+    #[arg(
+        long = "var",
+        help = "Set a placeholder of the header templates (can be used multiple times). Format: 'Name=Value', e.g. 'room_id=6154037'",
+        value_name = "VAR"
+    )]
+    pub template_vars: Vec<String>,
+
     /// Custom parameters for download requests
     #[arg(
         long = "param",
@@ -277,6 +364,73 @@ pub struct CliArgs {
     )]
     pub hls_cache_playlists: bool,
 
+    /// Archive HLS streams as a local VOD playlist
+    #[arg(
+        long,
+        help = "Keep HLS segments as individual files next to a local VOD playlist (<name>.m3u8) instead of concatenating them"
+    )]
+    pub hls_archive: bool,
+
+    /// Write concatenated HLS TS output as one continuous stream
+    #[arg(
+        long,
+        help = "Repair continuity counters and restamp PCR/PTS/DTS when concatenating HLS TS segments, so output plays seamlessly across discontinuities",
+        conflicts_with = "hls_archive"
+    )]
+    pub hls_continuous_ts: bool,
+
+    /// Transmux HLS segments into FLV output
+    #[arg(
+        long,
+        help = "Transmux HLS segments (H.264/H.265 + AAC) into FLV and run them through the FLV processing pipeline",
+        conflicts_with_all = ["hls_archive", "hls_continuous_ts"],
+        requires = "enable_fix"
+    )]
+    pub hls_to_flv: bool,
+
+    /// Also record an audio rendition of the selected HLS variant
+    #[arg(
+        long,
+        value_name = "RENDITION",
+        help = "Also record an audio rendition (EXT-X-MEDIA) of the selected HLS variant into <name>_audio files: 'default' or a LANGUAGE/NAME to match"
+    )]
+    pub hls_audio_rendition: Option<String>,
+
+    /// Extract closed captions from HLS TS segments into subtitle sidecars
+    #[arg(
+        long,
+        value_name = "FORMAT",
+        help = "Extract CEA-608/708 closed captions from HLS TS segments into subtitle sidecars next to each output file (<name>.cc1.srt, <name>.service1.srt, ...)",
+        value_parser = ["srt", "vtt"],
+        conflicts_with_all = ["hls_archive", "hls_to_flv"]
+    )]
+    pub hls_captions: Option<String>,
+
+    /// Directory of the on-disk segment cache
+    #[arg(
+        long,
+        value_name = "DIR",
+        help = "Cache HLS and DASH segments in DIR, so retries and later inputs reuse segments that were already downloaded"
+    )]
+    pub cache_dir: Option<PathBuf>,
+
+    /// Size limit of the segment cache in megabytes
+    #[arg(
+        long,
+        value_name = "MB",
+        default_value = "500",
+        help = "Size limit of the segment cache in megabytes; the least recently used segments are evicted beyond it"
+    )]
+    pub cache_size: u64,
+
+    /// Resume an interrupted FLV download
+    #[arg(
+        long,
+        value_name = "FILE",
+        help = "Resume an interrupted FLV download: request the rest of the stream after the last complete tag of FILE with an HTTP Range request and write it to a new file"
+    )]
+    pub resume: Option<PathBuf>,
+
     /// Force IPv4
     #[arg(
         short = '4',
@@ -304,11 +458,43 @@ pub struct CliArgs {
     )]
     pub http_version: String,
 
-    /// TCP keep-alive interval for HTTP/2 connections (seconds)
+    /// HTTP/2 PING interval (seconds)
     #[arg(
         long = "http2-keepalive",
-        help = "TCP keep-alive interval in seconds for maintaining HTTP/2 connections (default: 20)",
+        help = "Interval in seconds of HTTP/2 PINGs keeping connections alive, 0 to disable (default: 20)",
         default_value = "20"
     )]
     pub http2_keepalive: u64,
+
+    /// HTTP/2 adaptive flow-control window
+    #[arg(
+        long = "http2-adaptive-window",
+        help = "Size HTTP/2 flow-control windows from the measured bandwidth-delay product, for high-latency links",
+        default_value = "false"
+    )]
+    pub http2_adaptive_window: bool,
+
+    /// TCP keep-alive interval (seconds)
+    #[arg(
+        long = "tcp-keepalive",
+        help = "TCP keep-alive interval in seconds, 0 to disable (default: 20)",
+        default_value = "20"
+    )]
+    pub tcp_keepalive: u64,
+
+    /// Idle connections kept per host
+    #[arg(
+        long = "pool-max-idle",
+        help = "Maximum idle connections kept open per host (default: 10)",
+        default_value = "10"
+    )]
+    pub pool_max_idle: usize,
+
+    /// Idle connection timeout (seconds)
+    #[arg(
+        long = "pool-idle-timeout",
+        help = "Seconds an idle pooled connection stays open (default: 30)",
+        default_value = "30"
+    )]
+    pub pool_idle_timeout: u64,
 }
@@ -1,6 +1,14 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
 use flv_fix::FlvPipelineConfig;
-use hls_fix::HlsPipelineConfig;
-use mesio_engine::{flv::FlvProtocolConfig, hls::HlsConfig};
+use hls_fix::{CaptionFormat, HlsPipelineConfig};
+use mesio_engine::{
+    CacheConfig, WatchdogConfig,
+    dash::DashConfig,
+    flv::FlvProtocolConfig,
+    hls::{HlsAudioRendition, HlsConfig},
+};
 use pipeline_common::config::PipelineConfig;
 
 use crate::output::provider::OutputFormat;
@@ -23,11 +31,47 @@ pub struct ProgramConfig {
     /// HLS-specific configuration
     pub hls_config: Option<HlsConfig>,
 
+    /// DASH-specific configuration
+    pub dash_config: Option<DashConfig>,
+
     /// Whether to enable processing pipeline (vs raw download)
     pub enable_processing: bool,
 
     /// Output format (file, stdout, stderr)
     pub output_format: OutputFormat,
+
+    /// Whether to write a JSON analysis report next to each processed FLV or HLS file
+    pub write_reports: bool,
+
+    /// Whether to also write the unprocessed stream next to the processed output
+    pub keep_raw: bool,
+
+    /// Media time between in-place keyframe index checkpoints of processed FLV files
+    pub metadata_checkpoint_interval: Option<Duration>,
+
+    /// Whether to keep HLS segments as files referenced by a local VOD playlist
+    pub hls_playlist_archive: bool,
+
+    /// Whether to repair continuity counters and restamp timestamps of concatenated TS output
+    pub hls_continuous_ts: bool,
+
+    /// Whether to transmux HLS segments into FLV output
+    pub hls_to_flv: bool,
+
+    /// Audio rendition to record alongside the selected HLS variant
+    pub hls_audio_rendition: Option<HlsAudioRendition>,
+
+    /// Subtitle format of closed caption sidecars extracted from HLS TS output
+    pub hls_captions: Option<CaptionFormat>,
+
+    /// Partial FLV output to resume the download of
+    pub resume_from: Option<PathBuf>,
+
+    /// On-disk cache of HLS and DASH segments shared by all inputs
+    pub segment_cache: Option<CacheConfig>,
+
+    /// Watchdog for downloads that stop producing media
+    pub watchdog: Option<WatchdogConfig>,
 }
 
 impl ProgramConfig {
@@ -46,8 +90,20 @@ pub struct ProgramConfigBuilder {
     hls_pipeline_config: Option<HlsPipelineConfig>,
     flv_config: Option<FlvProtocolConfig>,
     hls_config: Option<HlsConfig>,
+    dash_config: Option<DashConfig>,
     enable_processing: bool,
     output_format: OutputFormat,
+    write_reports: bool,
+    keep_raw: bool,
+    metadata_checkpoint_interval: Option<Duration>,
+    hls_playlist_archive: bool,
+    hls_continuous_ts: bool,
+    hls_to_flv: bool,
+    hls_audio_rendition: Option<HlsAudioRendition>,
+    hls_captions: Option<CaptionFormat>,
+    resume_from: Option<PathBuf>,
+    segment_cache: Option<CacheConfig>,
+    watchdog: Option<WatchdogConfig>,
 }
 
 impl ProgramConfigBuilder {
@@ -60,8 +116,20 @@ impl ProgramConfigBuilder {
             hls_pipeline_config: None,
             flv_config: None,
             hls_config: None,
+            dash_config: None,
             enable_processing: true,
             output_format: OutputFormat::File,
+            write_reports: false,
+            keep_raw: false,
+            metadata_checkpoint_interval: None,
+            hls_playlist_archive: false,
+            hls_continuous_ts: false,
+            hls_to_flv: false,
+            hls_audio_rendition: None,
+            hls_captions: None,
+            resume_from: None,
+            segment_cache: None,
+            watchdog: None,
         }
     }
 
@@ -100,6 +168,13 @@ impl ProgramConfigBuilder {
         self
     }
 
+    /// Set the DASH-specific configuration
+    #[inline]
+    pub fn dash_config(mut self, config: DashConfig) -> Self {
+        self.dash_config = Some(config);
+        self
+    }
+
     /// Set whether to enable processing pipeline
     #[inline]
     pub fn enable_processing(mut self, enable: bool) -> Self {
@@ -114,6 +189,83 @@ impl ProgramConfigBuilder {
         self
     }
 
+    /// Set whether to write FLV and HLS analysis reports
+    #[inline]
+    pub fn write_reports(mut self, enable: bool) -> Self {
+        self.write_reports = enable;
+        self
+    }
+
+    /// Set whether to keep the unprocessed stream next to the processed output
+    #[inline]
+    pub fn keep_raw(mut self, enable: bool) -> Self {
+        self.keep_raw = enable;
+        self
+    }
+
+    /// Set the interval of in-place FLV keyframe index checkpoints
+    #[inline]
+    pub fn metadata_checkpoint_interval(mut self, interval: Option<Duration>) -> Self {
+        self.metadata_checkpoint_interval = interval;
+        self
+    }
+
+    /// Set whether to archive HLS streams as a local VOD playlist
+    #[inline]
+    pub fn hls_playlist_archive(mut self, enable: bool) -> Self {
+        self.hls_playlist_archive = enable;
+        self
+    }
+
+    /// Set whether to write concatenated TS output as one continuous stream
+    #[inline]
+    pub fn hls_continuous_ts(mut self, enable: bool) -> Self {
+        self.hls_continuous_ts = enable;
+        self
+    }
+
+    /// Set whether to transmux HLS segments into FLV output
+    #[inline]
+    pub fn hls_to_flv(mut self, enable: bool) -> Self {
+        self.hls_to_flv = enable;
+        self
+    }
+
+    /// Set the audio rendition to record alongside the selected HLS variant
+    #[inline]
+    pub fn hls_audio_rendition(mut self, rendition: Option<HlsAudioRendition>) -> Self {
+        self.hls_audio_rendition = rendition;
+        self
+    }
+
+    /// Set the subtitle format of closed caption sidecars
+    #[inline]
+    pub fn hls_captions(mut self, format: Option<CaptionFormat>) -> Self {
+        self.hls_captions = format;
+        self
+    }
+
+    /// Set the partial FLV output to resume the download of
+    #[inline]
+    pub fn resume_from(mut self, path: Option<PathBuf>) -> Self {
+        self.resume_from = path;
+        self
+    }
+
+    /// Set the on-disk segment cache
+    #[inline]
+    pub fn segment_cache(mut self, config: Option<CacheConfig>) -> Self {
+        self.segment_cache = config;
+        self
+    }
+
+    /// Set the watchdog for downloads that stop producing media
+    #[inline]
+    pub fn watchdog(mut self, config: Option<WatchdogConfig>) -> Self {
+        self.watchdog = config;
+        self
+    }
+
     /// Build the ProgramConfig
     pub fn build(self) -> Result<ProgramConfig, &'static str> {
         let pipeline_config = self.pipeline_config.ok_or("pipeline_config is required")?;
@@ -130,8 +282,20 @@ impl ProgramConfigBuilder {
             hls_pipeline_config,
             flv_config: self.flv_config,
             hls_config: self.hls_config,
+            dash_config: self.dash_config,
             enable_processing: self.enable_processing,
             output_format: self.output_format,
+            write_reports: self.write_reports,
+            keep_raw: self.keep_raw,
+            metadata_checkpoint_interval: self.metadata_checkpoint_interval,
+            hls_playlist_archive: self.hls_playlist_archive,
+            hls_continuous_ts: self.hls_continuous_ts,
+            hls_to_flv: self.hls_to_flv,
+            hls_audio_rendition: self.hls_audio_rendition,
+            hls_captions: self.hls_captions,
+            resume_from: self.resume_from,
+            segment_cache: self.segment_cache,
+            watchdog: self.watchdog,
         })
     }
 }
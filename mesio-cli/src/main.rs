@@ -7,8 +7,13 @@ use error::AppError;
 use flv_fix::FlvPipelineConfig;
 use flv_fix::ScriptFillerConfig;
 use hls_fix::HlsPipelineConfig;
+use mesio_engine::dash::DashConfig;
 use mesio_engine::flv::FlvProtocolConfig;
-use mesio_engine::{DownloaderConfig, HlsProtocolBuilder, ProxyAuth, ProxyConfig, ProxyType};
+use mesio_engine::hls::HlsAudioRendition;
+use mesio_engine::{
+    CacheConfig, DownloaderConfig, HlsProtocolBuilder, ProxyAuth, ProxyConfig, ProxyType,
+    RetryPolicy, StallAction, WatchdogConfig,
+};
 use output::provider::OutputFormat;
 use pipeline_common::{CancellationToken, config::PipelineConfig};
 use tracing::{Level, error, info};
@@ -26,7 +31,7 @@ mod utils;
 
 use cli::CliArgs;
 use input::input_handler;
-use utils::{parse_headers, parse_params, parse_size, parse_time};
+use utils::{parse_header_templates, parse_headers, parse_params, parse_size, parse_time};
 
 #[global_allocator]
 static GLOBAL: mimalloc::MiMalloc = mimalloc::MiMalloc;
@@ -197,6 +202,7 @@ async fn bootstrap() -> Result<(), AppError> {
             None
         })
         .enable_low_latency(args.low_latency_fix)
+        .parallel_stages(args.parallel_stages)
         .pipe_mode(is_pipe_mode)
         .build();
 
@@ -264,7 +270,20 @@ async fn bootstrap() -> Result<(), AppError> {
             .with_params(parse_params(&args.params)?)
             .with_caching_enabled(false)
             .with_force_ipv4(args.force_ipv4)
-            .with_force_ipv6(args.force_ipv6);
+            .with_force_ipv6(args.force_ipv6)
+            .with_retry_policy(RetryPolicy {
+                max_retries: args.retries,
+                base_delay: Duration::from_millis(args.retry_delay),
+                jitter: args.retry_jitter,
+                ..RetryPolicy::default()
+            });
+
+        for (name, template) in parse_header_templates(&args.header_templates) {
+            builder = builder.with_header_template(name, template);
+        }
+        for (name, value) in parse_params(&args.template_vars)? {
+            builder = builder.with_template_var(name, value);
+        }
 
         // Configure HTTP version preference
         let http_version = match args.http_version.as_str() {
@@ -274,14 +293,29 @@ async fn bootstrap() -> Result<(), AppError> {
         };
         builder = builder
             .with_http_version(http_version)
-            .with_http2_keep_alive_interval(Duration::from_secs(args.http2_keepalive));
+            .with_http2_adaptive_window(args.http2_adaptive_window)
+            .with_tcp_keepalive(
+                (args.tcp_keepalive > 0).then(|| Duration::from_secs(args.tcp_keepalive)),
+            )
+            .with_connection_pool(
+                args.pool_max_idle,
+                Duration::from_secs(args.pool_idle_timeout),
+            );
+        if args.http2_keepalive > 0 {
+            builder =
+                builder.with_http2_keep_alive_interval(Duration::from_secs(args.http2_keepalive));
+        }
 
         if let Some(proxy) = proxy_config {
             builder = builder.with_proxy(proxy);
         } else {
             builder = builder.with_system_proxy(use_system_proxy);
         }
-        builder.build()
+        let mut config = builder.build();
+        if args.http2_keepalive == 0 {
+            config.http2_keep_alive_interval = None;
+        }
+        config
     };
 
     // Create FLV-specific configuration
@@ -290,14 +324,20 @@ async fn bootstrap() -> Result<(), AppError> {
         .buffer_size(args.download_buffer)
         .build();
 
+    // DASH segments share the HLS segment download options
+    let hls_concurrency: usize = args
+        .hls_concurrency
+        .try_into()
+        .map_err(|_| AppError::InvalidInput("Invalid HLS concurrency".to_string()))?;
+    let dash_config = DashConfig {
+        download_concurrency: hls_concurrency,
+        ..DashConfig::from(download_config.clone())
+    };
+
     // Create HLS-specific configuration
     let hls_config = HlsProtocolBuilder::new()
         .with_base_config(download_config)
-        .download_concurrency(
-            args.hls_concurrency
-                .try_into()
-                .map_err(|_| AppError::InvalidInput("Invalid HLS concurrency".to_string()))?,
-        )
+        .download_concurrency(hls_concurrency)
         .initial_playlist_fetch_timeout(Duration::from_secs(args.hls_playlist_fetch_timeout))
         .live_refresh_interval(Duration::from_secs(args.hls_playlist_min_refresh_interval))
         .live_max_refresh_retries(args.hls_playlist_retries)
@@ -305,6 +345,13 @@ async fn bootstrap() -> Result<(), AppError> {
         .segment_download_timeout(Duration::from_secs(args.hls_segment_timeout))
         .get_config();
 
+    // A partial output belongs to a single download
+    if args.resume.is_some() && args.input.len() != 1 {
+        return Err(AppError::InvalidInput(
+            "--resume takes exactly one input URL".to_string(),
+        ));
+    }
+
     // Create the program configuration
     let program_config = ProgramConfig::builder()
         .pipeline_config(pipeline_config)
@@ -312,8 +359,43 @@ async fn bootstrap() -> Result<(), AppError> {
         .hls_pipeline_config(hls_pipeline_config)
         .flv_config(flv_config)
         .hls_config(hls_config)
+        .dash_config(dash_config)
         .enable_processing(args.enable_fix)
         .output_format(args.output_format)
+        .write_reports(args.report)
+        .keep_raw(args.keep_raw)
+        .hls_playlist_archive(args.hls_archive)
+        .hls_continuous_ts(args.hls_continuous_ts)
+        .hls_to_flv(args.hls_to_flv)
+        .hls_audio_rendition(args.hls_audio_rendition.as_deref().map(|rendition| {
+            if rendition.eq_ignore_ascii_case("default") {
+                HlsAudioRendition::Default
+            } else {
+                HlsAudioRendition::Matching(rendition.to_string())
+            }
+        }))
+        .hls_captions(
+            args.hls_captions
+                .as_deref()
+                .map(str::parse)
+                .transpose()
+                .map_err(AppError::InvalidInput)?,
+        )
+        .metadata_checkpoint_interval(args.metadata_checkpoint.map(Duration::from_secs))
+        .resume_from(args.resume)
+        .segment_cache(args.cache_dir.map(|path| CacheConfig {
+            disk_cache_path: Some(path),
+            max_disk_cache_size: args.cache_size * 1024 * 1024,
+            ..CacheConfig::default()
+        }))
+        .watchdog((args.stall_timeout > 0).then(|| WatchdogConfig {
+            stall_timeout: Duration::from_secs(args.stall_timeout),
+            action: if args.end_on_stall {
+                StallAction::EndStream
+            } else {
+                StallAction::Reconnect
+            },
+        }))
         .build()
         .map_err(|err| AppError::InvalidInput(err.to_string()))?;
 
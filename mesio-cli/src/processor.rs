@@ -2,13 +2,145 @@ mod flv;
 mod generic;
 mod hls;
 
+use crate::output::provider::OutputFormat;
 use crate::{config::ProgramConfig, error::AppError};
+use ::hls::HlsData;
 use mesio_engine::{
-    DownloadRequest, DownloaderSession, MesioConfig, MesioDownloader, ProtocolSelection,
+    CacheManager, DashRequestOptions, DownloadRequest, DownloadSession, DownloaderSession,
+    FlvRequestOptions, HlsRequestOptions, MesioConfig, MesioDownloader, ProtocolSelection,
+    dash::DashContentType, flv::FlvResumePoint,
 };
 use pipeline_common::CancellationToken;
 use std::path::{Path, PathBuf};
-use tracing::{Instrument, Level, error, info, span};
+use std::sync::Arc;
+use tracing::{Instrument, Level, debug, error, info, span, warn};
+
+/// Start a second HLS session on the audio rendition requested by `--hls-audio-rendition`.
+async fn start_audio_rendition_session(
+    downloader: &MesioDownloader,
+    url: &str,
+    config: &ProgramConfig,
+    token: &CancellationToken,
+) -> Result<Option<DownloadSession<HlsData>>, AppError> {
+    let Some(rendition) = config.hls_audio_rendition.clone() else {
+        return Ok(None);
+    };
+    if matches!(
+        config.output_format,
+        OutputFormat::Stdout | OutputFormat::Stderr
+    ) {
+        warn!("Audio renditions are not recorded in pipe output mode");
+        return Ok(None);
+    }
+
+    let request = DownloadRequest::from_url(url)?
+        .with_protocol(ProtocolSelection::Hls(HlsRequestOptions {
+            audio_rendition: Some(rendition),
+            ..Default::default()
+        }))
+        .with_cancel(token.clone());
+    match downloader.start(request).await {
+        Ok(DownloaderSession::Hls(session)) => Ok(Some(session)),
+        Ok(DownloaderSession::Flv(_) | DownloaderSession::Dash(_)) => Ok(None),
+        Err(error) => {
+            warn!(%error, "Not recording the audio rendition");
+            Ok(None)
+        }
+    }
+}
+
+/// Start a second DASH session on the audio adaptation set, which DASH manifests usually keep
+/// apart from the video representations.
+async fn start_dash_audio_session(
+    downloader: &MesioDownloader,
+    url: &str,
+    config: &ProgramConfig,
+    token: &CancellationToken,
+) -> Result<Option<DownloadSession<HlsData>>, AppError> {
+    if matches!(
+        config.output_format,
+        OutputFormat::Stdout | OutputFormat::Stderr
+    ) {
+        warn!("Separate DASH audio is not recorded in pipe output mode");
+        return Ok(None);
+    }
+
+    let request = DownloadRequest::from_url(url)?
+        .with_protocol(ProtocolSelection::Dash(DashRequestOptions {
+            content_type: DashContentType::Audio,
+            ..Default::default()
+        }))
+        .with_cancel(token.clone());
+    match downloader.start(request).await {
+        Ok(DownloaderSession::Dash(session)) => Ok(Some(session)),
+        Ok(_) => Ok(None),
+        Err(error) => {
+            debug!(%error, "No separate DASH audio to record");
+            Ok(None)
+        }
+    }
+}
+
+/// Record an HLS or DASH session, and the audio session linked to it into `<name>_audio` files.
+async fn process_with_audio(
+    input: &str,
+    output_dir: &Path,
+    config: &ProgramConfig,
+    name_template: &str,
+    session: DownloadSession<HlsData>,
+    audio_session: Option<DownloadSession<HlsData>>,
+    token: &CancellationToken,
+) -> Result<(), AppError> {
+    let main = hls::process_hls_stream(input, output_dir, config, name_template, session, token);
+
+    match audio_session {
+        Some(audio_session) => {
+            let audio_template = format!("{name_template}_audio");
+            let audio = hls::process_hls_stream(
+                input,
+                output_dir,
+                config,
+                &audio_template,
+                audio_session,
+                token,
+            );
+            let (main, audio) = tokio::join!(main, audio);
+            if let Err(error) = audio {
+                warn!(%error, "Audio recording failed");
+            }
+            main.map(|_| ())
+        }
+        None => main.await.map(|_| ()),
+    }
+}
+
+/// Protocol selection of URL inputs, resuming the partial FLV output given with `--resume`.
+async fn url_protocol(config: &ProgramConfig) -> Result<ProtocolSelection, AppError> {
+    let Some(path) = &config.resume_from else {
+        return Ok(ProtocolSelection::Auto);
+    };
+    match FlvResumePoint::from_partial_file(path).await? {
+        Some(resume) => {
+            info!(
+                path = %path.display(),
+                offset = resume.offset,
+                timestamp_ms = resume.timestamp_ms,
+                "Resuming FLV download"
+            );
+            Ok(ProtocolSelection::Flv(FlvRequestOptions {
+                resume: Some(resume),
+                ..Default::default()
+            }))
+        }
+        None => {
+            warn!(
+                path = %path.display(),
+                "No complete FLV tag to resume from, downloading from the start"
+            );
+            Ok(ProtocolSelection::Flv(FlvRequestOptions::default()))
+        }
+    }
+}
 
 /// Determine the type of input and process accordingly
 pub async fn process_inputs(
@@ -37,11 +169,20 @@ pub async fn process_inputs(
         );
     });
 
-    let downloader = MesioDownloader::new(MesioConfig {
+    let mut downloader = MesioDownloader::new(MesioConfig {
         flv: config.flv_config.clone().unwrap_or_default(),
         hls: config.hls_config.clone().unwrap_or_default(),
+        dash: config.dash_config.clone().unwrap_or_default(),
         token: token.clone(),
+        watchdog: config.watchdog,
     });
+    let segment_cache = match &config.segment_cache {
+        Some(cache_config) => Some(Arc::new(CacheManager::new(cache_config.clone()).await?)),
+        None => None,
+    };
+    if let Some(cache) = &segment_cache {
+        downloader = downloader.with_cache(Arc::clone(cache));
+    }
 
     // Process each input
     for (index, input) in inputs.iter().enumerate() {
@@ -54,9 +195,14 @@ pub async fn process_inputs(
         let input_span = span!(Level::INFO, "process_input", index = input_index, input = %input);
 
         // Process based on input type
-        if input.starts_with("http://") || input.starts_with("https://") {
+        if [
+            "http://", "https://", "rtmp://", "rtmps://", "ws://", "wss://",
+        ]
+        .iter()
+        .any(|scheme| input.starts_with(scheme))
+        {
             let request = DownloadRequest::from_url(input)?
-                .with_protocol(ProtocolSelection::Auto)
+                .with_protocol(url_protocol(config).await?)
                 .with_cancel(token.clone());
             let session = downloader.start(request).await?;
 
@@ -74,12 +220,31 @@ pub async fn process_inputs(
                     .await?;
                 }
                 DownloaderSession::Hls(session) => {
-                    hls::process_hls_stream(
+                    let audio_session =
+                        start_audio_rendition_session(&downloader, input, config, token).await?;
+                    process_with_audio(
                         input,
                         output_dir,
                         config,
                         name_template,
                         session,
+                        audio_session,
+                        token,
+                    )
+                    .instrument(input_span.clone())
+                    .await?;
+                }
+                DownloaderSession::Dash(session) => {
+                    // DASH segments are fMP4 and go through the HLS pipeline
+                    let audio_session =
+                        start_dash_audio_session(&downloader, input, config, token).await?;
+                    process_with_audio(
+                        input,
+                        output_dir,
+                        config,
+                        name_template,
+                        session,
+                        audio_session,
                         token,
                     )
                     .instrument(input_span.clone())
@@ -124,5 +289,16 @@ pub async fn process_inputs(
         }
     }
 
+    if let Some(cache) = &segment_cache {
+        let stats = cache.stats();
+        info!(
+            hits = stats.hits,
+            misses = stats.misses,
+            evictions = stats.evictions,
+            disk_usage = stats.disk_usage,
+            "Segment cache statistics"
+        );
+    }
+
     Ok(())
 }
@@ -1,7 +1,8 @@
 use crate::output::pipe_flv_strategy::PipeFlvStrategy;
 use crate::output::provider::OutputFormat;
 use crate::processor::generic::{
-    process_pipe_stream, process_pipe_stream_with_processing, process_stream,
+    ItemStream, finish_raw_writer, process_pipe_stream, process_pipe_stream_with_processing,
+    process_stream, tee_raw_stream,
 };
 use crate::utils::{create_dirs, expand_name_url, format_bytes, spans};
 use crate::{config::ProgramConfig, error::AppError};
@@ -11,7 +12,7 @@ use flv_fix::FlvPipeline;
 use flv_fix::FlvWriterConfig;
 use flv_fix::writer::FlvWriter;
 use futures::{Stream, StreamExt};
-use mesio_engine::DownloadSession;
+use mesio_engine::{DownloadSession, ProgressOptions};
 use pipeline_common::{
     CancellationToken, PipelineError, ProtocolWriter, WriterStats, config::PipelineConfig,
 };
@@ -22,6 +23,26 @@ use tokio::fs::File;
 use tokio::io::BufReader;
 use tracing::{Instrument, Level, Span, info, span, warn};
 
+/// Write a JSON analysis report next to each output segment when `--report` is set.
+pub(crate) fn attach_report_writer(writer: &mut FlvWriter, config: &ProgramConfig) {
+    if !config.write_reports {
+        return;
+    }
+    writer.set_repair_counters(config.flv_pipeline_config.repair_counters.clone());
+    writer.set_on_segment_report_callback(|path, _, report| {
+        let report_path = path.with_extension("report.json");
+        let result = serde_json::to_vec_pretty(report)
+            .map_err(std::io::Error::from)
+            .and_then(|json| std::fs::write(&report_path, json));
+        match result {
+            Ok(()) => info!(path = %report_path.display(), "Wrote analysis report"),
+            Err(error) => {
+                warn!(path = %report_path.display(), %error, "Failed to write analysis report")
+            }
+        }
+    });
+}
+
 async fn process_raw_stream(
     stream: Pin<Box<dyn Stream<Item = Result<FlvData, PipelineError>> + Send>>,
     output_dir: &Path,
@@ -156,11 +177,14 @@ pub async fn process_file(
             Box::pin(decoder_stream),
             "Writing FLV output",
             |_writer_span| {
-                FlvWriter::new(FlvWriterConfig {
+                let mut writer = FlvWriter::new(FlvWriterConfig {
                     output_dir: output_dir.to_path_buf(),
                     base_name: base_name.to_string(),
                     enable_low_latency: config.flv_pipeline_config.enable_low_latency,
-                })
+                });
+                attach_report_writer(&mut writer, config);
+                writer.set_metadata_checkpoint_interval(config.metadata_checkpoint_interval);
+                writer
             },
             token.clone(),
         )
@@ -227,6 +251,14 @@ pub async fn process_flv_stream(
     }
 
     let base_name = expand_name_url(name_template, url_str)?;
+    let session = if is_pipe_mode {
+        session
+    } else {
+        session.with_progress(
+            ProgressOptions::default(),
+            spans::download_progress(download_span.clone()),
+        )
+    };
     let DownloadSession {
         items: stream,
         events,
@@ -291,21 +323,43 @@ pub async fn process_flv_stream(
         spans::summarize_dropped_events(&handle, &download_span);
         return Ok(pipe_stats.items_written as u64);
     } else if config.enable_processing {
-        process_stream::<FlvPipeline, FlvWriter>(
+        let (stream, raw_writer) = if config.keep_raw {
+            let (stream, raw_writer) = tee_raw_stream(
+                Box::pin(stream),
+                config.pipeline_config.channel_size,
+                FlvWriter::new(FlvWriterConfig {
+                    output_dir: output_dir.to_path_buf(),
+                    base_name: format!("{base_name}_raw"),
+                    enable_low_latency: false,
+                }),
+            );
+            (stream, Some(raw_writer))
+        } else {
+            (Box::pin(stream) as ItemStream<_>, None)
+        };
+
+        let result = process_stream::<FlvPipeline, FlvWriter>(
             &config.pipeline_config,
             config.flv_pipeline_config.clone(),
-            Box::pin(stream),
+            stream,
             "Writing FLV output",
             |_writer_span| {
-                FlvWriter::new(FlvWriterConfig {
+                let mut writer = FlvWriter::new(FlvWriterConfig {
                     output_dir: output_dir.to_path_buf(),
                     base_name: base_name.clone(),
                     enable_low_latency: config.flv_pipeline_config.enable_low_latency,
-                })
+                });
+                attach_report_writer(&mut writer, config);
+                writer.set_metadata_checkpoint_interval(config.metadata_checkpoint_interval);
+                writer
             },
             token.clone(),
         )
-        .await?
+        .await;
+        if let Some(raw_writer) = raw_writer {
+            finish_raw_writer(raw_writer).await;
+        }
+        result?
     } else {
         process_raw_stream(
             Box::pin(stream),
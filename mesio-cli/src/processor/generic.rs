@@ -3,13 +3,13 @@ use crate::utils::spans;
 use futures::{Stream, StreamExt};
 use pipeline_common::{
     CancellationToken, ChannelSpec, FormatStrategy, PipelineError, PipelineProvider,
-    ProtocolWriter, RunCompletionError, StreamerContext, WriterConfig, WriterStats, WriterTask,
-    config::PipelineConfig, settle_run, spawn_pipeline,
+    ProtocolWriter, RunCompletionError, StreamerContext, WriterConfig, WriterError, WriterStats,
+    WriterTask, config::PipelineConfig, settle_run, spawn_pipeline,
 };
 use std::path::PathBuf;
 use std::pin::Pin;
 use std::sync::Arc;
-use tracing::{Level, Span, span, warn};
+use tracing::{Level, Span, info, span, warn};
 
 pub async fn process_stream<P, W>(
     pipeline_common_config: &PipelineConfig,
@@ -101,6 +101,59 @@ where
     }
 }
 
+/// A boxed stream of items fed into a processing pipeline.
+pub type ItemStream<T> = Pin<Box<dyn Stream<Item = Result<T, PipelineError>> + Send>>;
+
+/// Handle of the writer persisting the untouched copy of a stream for `--keep-raw`.
+pub type RawWriterTask = tokio::task::JoinHandle<Result<WriterStats, WriterError>>;
+
+/// Copy every item of `stream` into `writer` before it reaches the processing pipeline.
+///
+/// The copy applies backpressure, so the raw file stays bit-exact with what was downloaded.
+/// Errors are only passed to the processing side, and a failed raw writer never interrupts
+/// processing. The raw writer finishes once the returned stream is dropped.
+pub fn tee_raw_stream<W>(
+    stream: ItemStream<W::Item>,
+    channel_size: usize,
+    mut writer: W,
+) -> (ItemStream<W::Item>, RawWriterTask)
+where
+    W: ProtocolWriter,
+    W::Item: Clone,
+{
+    let (tx, rx) = tokio::sync::mpsc::channel(channel_size);
+    let current_span = Span::current();
+    let writer_task = tokio::task::spawn_blocking(move || {
+        let _enter = current_span.enter();
+        writer.run(rx.into())
+    });
+
+    let stream = stream.then(move |item| {
+        let tx = tx.clone();
+        async move {
+            if let Ok(data) = &item {
+                // A closed channel means the raw writer failed; processing carries on
+                let _ = tx.send(Ok(data.clone())).await;
+            }
+            item
+        }
+    });
+    (Box::pin(stream), writer_task)
+}
+
+/// Wait for the raw writer of [`tee_raw_stream`] and log its outcome.
+pub async fn finish_raw_writer(writer_task: RawWriterTask) {
+    match writer_task.await {
+        Ok(Ok(stats)) => info!(
+            items = stats.items_written,
+            files = stats.files_created,
+            "Raw copy complete"
+        ),
+        Ok(Err(error)) => warn!(%error, "Failed to write raw copy"),
+        Err(error) => warn!(%error, "Raw copy writer task failed"),
+    }
+}
+
 /// Statistics from pipe stream processing
 #[derive(Debug, Clone)]
 pub struct PipeStreamStats {
@@ -1,23 +1,64 @@
+use crate::output::pipe_flv_strategy::PipeFlvStrategy;
 use crate::output::pipe_hls_strategy::PipeHlsStrategy;
 use crate::output::provider::OutputFormat;
-use crate::processor::generic::process_pipe_stream;
+use crate::processor::flv::attach_report_writer as attach_flv_report_writer;
+use crate::processor::generic::{
+    ItemStream, finish_raw_writer, process_pipe_stream, process_pipe_stream_with_processing,
+    tee_raw_stream,
+};
 use crate::utils::spans;
 use crate::{
     config::ProgramConfig,
     error::AppError,
     utils::{create_dirs, expand_name_url},
 };
-use futures::{StreamExt, stream};
+use flv::data::FlvData;
+use flv_fix::writer::FlvWriter;
+use flv_fix::{FlvPipeline, FlvWriterConfig};
+use futures::{Stream, StreamExt, stream};
 use hls::HlsData;
-use hls_fix::{HlsPipeline, HlsWriter, HlsWriterConfig};
-use mesio_engine::{DownloadError, DownloadSession};
+use hls_fix::{FlvTransmuxer, HlsPipeline, HlsWriter, HlsWriterConfig};
+use mesio_engine::{DownloadError, DownloadSession, ProgressOptions};
 use pipeline_common::CancellationToken;
-use pipeline_common::PipelineError;
+use pipeline_common::{ChannelSpec, PipelineError};
 use std::path::Path;
 use std::time::Instant;
 use tracing::{Level, debug, info, span, warn};
 use tracing_indicatif::span_ext::IndicatifSpanExt;
 
+/// Write a JSON analysis report next to each output file when `--report` is set.
+fn attach_report_writer(writer: &mut HlsWriter, config: &ProgramConfig) {
+    if !config.write_reports {
+        return;
+    }
+    writer.set_on_segment_report_callback(|path, _, report| {
+        let report_path = path.with_extension("report.json");
+        let result = serde_json::to_vec_pretty(report)
+            .map_err(std::io::Error::from)
+            .and_then(|json| std::fs::write(&report_path, json));
+        match result {
+            Ok(()) => info!(path = %report_path.display(), "Wrote analysis report"),
+            Err(error) => {
+                warn!(path = %report_path.display(), %error, "Failed to write analysis report")
+            }
+        }
+    });
+}
+
+/// Transmux HLS items into FLV data for `--hls-to-flv`, passing errors through.
+fn transmux_to_flv(
+    stream: impl Stream<Item = Result<HlsData, PipelineError>> + Send,
+) -> impl Stream<Item = Result<FlvData, PipelineError>> + Send {
+    let mut transmuxer = FlvTransmuxer::new();
+    stream.flat_map(move |item| {
+        let items: Vec<_> = match item {
+            Ok(item) => transmuxer.push(item).into_iter().map(Ok).collect(),
+            Err(error) => vec![Err(error)],
+        };
+        stream::iter(items)
+    })
+}
+
 /// Process an HLS stream
 pub async fn process_hls_stream(
     url_str: &str,
@@ -57,6 +98,14 @@ pub async fn process_hls_stream(
         spans::init_hls_download_span(&download_span, format!("Downloading {}", url_str));
     }
 
+    let session = if is_pipe_mode {
+        session
+    } else {
+        session.with_progress(
+            ProgressOptions::default(),
+            spans::download_progress(download_span.clone()),
+        )
+    };
     let DownloadSession {
         items: mut stream,
         events,
@@ -112,11 +161,18 @@ pub async fn process_hls_stream(
         }
     };
 
-    info!(
-        "Detected HLS stream type: {}. Saving with .{} extension.",
-        extension.to_uppercase(),
-        extension
-    );
+    if config.hls_to_flv {
+        info!(
+            "Detected HLS stream type: {}. Transmuxing to FLV.",
+            extension.to_uppercase()
+        );
+    } else {
+        info!(
+            "Detected HLS stream type: {}. Saving with .{} extension.",
+            extension.to_uppercase(),
+            extension
+        );
+    }
 
     // Prepend the first segment back to the stream
     let stream_with_first_segment = stream::once(async { Ok(first_segment) }).chain(stream);
@@ -126,17 +182,47 @@ pub async fn process_hls_stream(
     debug!("Pipeline config: {:?}", hls_pipe_config);
 
     let stream = stream.map(|r| r.map_err(|e| PipelineError::Strategy(Box::new(e))));
+    let (stream, raw_writer) = if config.keep_raw && !is_pipe_mode {
+        let (stream, raw_writer) = tee_raw_stream(
+            Box::pin(stream),
+            config.pipeline_config.channel_size,
+            HlsWriter::new(HlsWriterConfig {
+                output_dir: output_dir.to_path_buf(),
+                base_name: format!("{base_name}_raw"),
+                extension: extension.to_string(),
+                max_file_size: None,
+                wall_clock_split: None,
+                playlist_archive: false,
+                continuous_ts: false,
+                captions: None,
+            }),
+        );
+        (stream, Some(raw_writer))
+    } else {
+        (Box::pin(stream) as ItemStream<_>, None)
+    };
 
     // Use pipe output strategy when stdout mode is active
     let stats = if is_pipe_mode {
         // Pipe mode: write directly to stdout using PipeHlsStrategy
-        let pipe_stats = process_pipe_stream(
-            Box::pin(stream),
-            &config.pipeline_config,
-            PipeHlsStrategy::new(),
-            extension,
-        )
-        .await?;
+        let pipe_stats = if config.hls_to_flv {
+            process_pipe_stream_with_processing::<FlvPipeline, _>(
+                Box::pin(transmux_to_flv(stream)),
+                &config.pipeline_config,
+                config.flv_pipeline_config.clone(),
+                PipeFlvStrategy::new(),
+                "flv",
+            )
+            .await?
+        } else {
+            process_pipe_stream(
+                Box::pin(stream),
+                &config.pipeline_config,
+                PipeHlsStrategy::new(),
+                extension,
+            )
+            .await?
+        };
 
         // Log completion statistics for pipe mode
         let elapsed = start_time.elapsed();
@@ -153,6 +239,26 @@ pub async fn process_hls_stream(
         handle.cancel();
         spans::summarize_dropped_events(&handle, &download_span);
         return Ok(pipe_stats.items_written as u64);
+    } else if config.hls_to_flv {
+        crate::processor::generic::process_stream_with_span::<FlvPipeline, FlvWriter>(
+            &config.pipeline_config,
+            config.flv_pipeline_config.clone(),
+            Box::pin(transmux_to_flv(stream)),
+            ChannelSpec::items(config.pipeline_config.channel_size),
+            writer_span.clone(),
+            |_writer_span| {
+                let mut writer = FlvWriter::new(FlvWriterConfig {
+                    output_dir: output_dir.to_path_buf(),
+                    base_name: base_name.to_string(),
+                    enable_low_latency: config.flv_pipeline_config.enable_low_latency,
+                });
+                attach_flv_report_writer(&mut writer, config);
+                writer.set_metadata_checkpoint_interval(config.metadata_checkpoint_interval);
+                writer
+            },
+            token.clone(),
+        )
+        .await
     } else {
         let max_file_size = if config.pipeline_config.max_file_size > 0 {
             Some(config.pipeline_config.max_file_size)
@@ -167,17 +273,27 @@ pub async fn process_hls_stream(
             HlsPipeline::channel_spec(config.pipeline_config.channel_size),
             writer_span.clone(),
             |_writer_span| {
-                HlsWriter::new(HlsWriterConfig {
+                let mut writer = HlsWriter::new(HlsWriterConfig {
                     output_dir: output_dir.to_path_buf(),
                     base_name: base_name.to_string(),
                     extension: extension.to_string(),
                     max_file_size,
-                })
+                    wall_clock_split: None,
+                    playlist_archive: config.hls_playlist_archive,
+                    continuous_ts: config.hls_continuous_ts,
+                    captions: config.hls_captions,
+                });
+                attach_report_writer(&mut writer, config);
+                writer
             },
             token.clone(),
         )
-        .await?
+        .await
     };
+    if let Some(raw_writer) = raw_writer {
+        finish_raw_writer(raw_writer).await;
+    }
+    let stats = stats?;
 
     // Only update progress bar finish message if not in pipe mode
     if !is_pipe_mode {
@@ -7,7 +7,7 @@ mod time;
 
 // Export utility functions
 pub use self::files::{create_dirs, expand_name_url};
-pub use self::headers::parse_headers;
+pub use self::headers::{parse_header_templates, parse_headers};
 pub use self::params::parse_params;
 pub use self::size::format_bytes;
 pub use self::size::parse_size;
@@ -30,6 +30,30 @@ pub fn parse_and_add_header(headers: &mut HeaderMap, header_str: &str) {
     headers.insert(header_name, header_value);
 }
 
+/// Parse header template strings in format "Name: Template" into name and template pairs.
+/// Templates are not valid header values until rendered, so only the name is checked.
+pub fn parse_header_templates(template_strings: &[String]) -> Vec<(String, String)> {
+    template_strings
+        .iter()
+        .filter_map(|template_str| {
+            let Some((name, template)) = template_str.split_once(':') else {
+                tracing::warn!(
+                    "Invalid header template format: '{}'. Expected 'Name: Template'",
+                    template_str
+                );
+                return None;
+            };
+            let name = name.trim();
+            if HeaderName::from_bytes(name.as_bytes()).is_err() {
+                tracing::warn!("Invalid header name: '{}'", name);
+                return None;
+            }
+            info!("Adding header template: {}: {}", name, template.trim());
+            Some((name.to_string(), template.trim().to_string()))
+        })
+        .collect()
+}
+
 /// Parse a collection of header strings and return a HeaderMap
 pub fn parse_headers(header_strings: &[String]) -> HeaderMap {
     let mut headers = HeaderMap::new();
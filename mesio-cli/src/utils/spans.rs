@@ -1,10 +1,9 @@
 use futures::StreamExt;
 use indicatif::ProgressStyle;
-use mesio_engine::{DownloadEvent, DownloadEventStream, DownloadHandle};
-use std::sync::{
-    Arc,
-    atomic::{AtomicU64, Ordering},
+use mesio_engine::{
+    DownloadEvent, DownloadEventStream, DownloadHandle, DownloadProgress, ProgressCallback,
 };
+use std::sync::Arc;
 use tracing::Span;
 use tracing_indicatif::span_ext::IndicatifSpanExt;
 
@@ -13,7 +12,7 @@ use crate::utils::format_bytes;
 /// Creates a progress bar style for download operations
 pub fn download_progress_style() -> ProgressStyle {
     ProgressStyle::default_bar()
-        .template("{span_child_prefix}{spinner:.green} {span_name} {msg}\n{span_child_prefix}[{elapsed_precise}] [{bar:40.green/white}] {bytes}/{total_bytes} @ {bytes_per_sec}")
+        .template("{span_child_prefix}{spinner:.green} {span_name} {msg}\n{span_child_prefix}[{elapsed_precise}] [{bar:40.green/white}] {bytes}/{total_bytes}")
         .unwrap()
         .progress_chars("=> ")
 }
@@ -21,7 +20,7 @@ pub fn download_progress_style() -> ProgressStyle {
 /// Creates a progress style for streaming HLS downloads without a known total.
 pub fn hls_download_progress_style() -> ProgressStyle {
     ProgressStyle::default_bar()
-        .template("{span_child_prefix}{spinner:.green} {span_name} {msg}\n{span_child_prefix}[{elapsed_precise}] {bytes} @ {bytes_per_sec}")
+        .template("{span_child_prefix}{spinner:.green} {span_name} {msg}\n{span_child_prefix}[{elapsed_precise}] {bytes}")
         .unwrap()
         .progress_chars("=> ")
 }
@@ -71,7 +70,6 @@ pub fn init_writing_span(span: &Span, message: impl Into<String>) {
 }
 
 pub async fn render_download_events(mut events: DownloadEventStream, download_span: Span) {
-    let total_bytes = Arc::new(AtomicU64::new(0));
     while let Some(event) = events.next().await {
         match event {
             DownloadEvent::ResourceStarted {
@@ -80,18 +78,6 @@ pub async fn render_download_events(mut events: DownloadEventStream, download_sp
             } => {
                 download_span.pb_set_length(length);
             }
-            DownloadEvent::Progress { bytes_delta, .. } => {
-                let total = total_bytes.fetch_add(bytes_delta, Ordering::Relaxed) + bytes_delta;
-                download_span.pb_set_position(total);
-                download_span.pb_set_message(&format!("Downloaded {}", format_bytes(total)));
-            }
-            DownloadEvent::ResourceFinished {
-                bytes, from_cache, ..
-            } if from_cache => {
-                let total = total_bytes.fetch_add(bytes, Ordering::Relaxed) + bytes;
-                download_span.pb_set_position(total);
-                download_span.pb_set_message(&format!("Downloaded {}", format_bytes(total)));
-            }
             DownloadEvent::Lagged { dropped } => {
                 download_span.pb_set_message(&format!("Dropped {} progress events", dropped));
             }
@@ -100,6 +86,32 @@ pub async fn render_download_events(mut events: DownloadEventStream, download_sp
     }
 }
 
+/// Progress callback updating the download span with the bytes received and the current speed
+pub fn download_progress(download_span: Span) -> ProgressCallback {
+    Arc::new(move |progress: &DownloadProgress| {
+        download_span.pb_set_position(progress.bytes_total);
+        download_span.pb_set_message(&progress_message(progress));
+    })
+}
+
+fn progress_message(progress: &DownloadProgress) -> String {
+    let mut message = format!(
+        "Downloaded {} @ {}/s",
+        format_bytes(progress.bytes_total),
+        format_bytes(progress.bytes_per_sec as u64)
+    );
+    if let Some(latency) = progress.segment_latency {
+        message.push_str(&format!(", segment in {} ms", latency.as_millis()));
+    }
+    if progress.stalled {
+        message.push_str(&format!(
+            ", stalled for {}s",
+            progress.since_last_data.as_secs()
+        ));
+    }
+    message
+}
+
 pub fn summarize_dropped_events(handle: &DownloadHandle, download_span: &Span) {
     let dropped = handle.dropped_events();
     if dropped == 0 {
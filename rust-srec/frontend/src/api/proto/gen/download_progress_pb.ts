@@ -10,7 +10,7 @@ import type { Message } from "@bufbuild/protobuf";
  * Describes the file download_progress.proto.
  */
 export const file_download_progress: GenFile = /*@__PURE__*/
-  fileDesc("Chdkb3dubG9hZF9wcm9ncmVzcy5wcm90bxIRZG93bmxvYWRfcHJvZ3Jlc3MixAYKCVdzTWVzc2FnZRIwCgpldmVudF90eXBlGAEgASgOMhwuZG93bmxvYWRfcHJvZ3Jlc3MuRXZlbnRUeXBlEjcKCHNuYXBzaG90GAIgASgLMiMuZG93bmxvYWRfcHJvZ3Jlc3MuRG93bmxvYWRTbmFwc2hvdEgAEjgKDWRvd25sb2FkX21ldGEYAyABKAsyHy5kb3dubG9hZF9wcm9ncmVzcy5Eb3dubG9hZE1ldGFIABI+ChBkb3dubG9hZF9tZXRyaWNzGAQgASgLMiIuZG93bmxvYWRfcHJvZ3Jlc3MuRG93bmxvYWRNZXRyaWNzSAASQAoRc2VnbWVudF9jb21wbGV0ZWQYBSABKAsyIy5kb3dubG9hZF9wcm9ncmVzcy5TZWdtZW50Q29tcGxldGVkSAASQgoSZG93bmxvYWRfY29tcGxldGVkGAYgASgLMiQuZG93bmxvYWRfcHJvZ3Jlc3MuRG93bmxvYWRDb21wbGV0ZWRIABI8Cg9kb3dubG9hZF9mYWlsZWQYByABKAsyIS5kb3dubG9hZF9wcm9ncmVzcy5Eb3dubG9hZEZhaWxlZEgAEkIKEmRvd25sb2FkX2NhbmNlbGxlZBgIIAEoCzIkLmRvd25sb2FkX3Byb2dyZXNzLkRvd25sb2FkQ2FuY2VsbGVkSAASMAoFZXJyb3IYCSABKAsyHy5kb3dubG9hZF9wcm9ncmVzcy5FcnJvclBheWxvYWRIABJAChFkb3dubG9hZF9yZWplY3RlZBgKIAEoCzIjLmRvd25sb2FkX3Byb2dyZXNzLkRvd25sb2FkUmVqZWN0ZWRIABJLChdzdHJlYW1lcl9jaGVja19yZWNvcmRlZBgLIAEoCzIoLmRvd25sb2FkX3Byb2dyZXNzLlN0cmVhbWVyQ2hlY2tSZWNvcmRlZEgAEjwKD2Rvd25sb2FkX3F1ZXVlZBgMIAEoCzIhLmRvd25sb2FkX3Byb2dyZXNzLkRvd25sb2FkUXVldWVkSAASQAoRZG93bmxvYWRfZGVxdWV1ZWQYDSABKAsyIy5kb3dubG9hZF9wcm9ncmVzcy5Eb3dubG9hZERlcXVldWVkSABCCQoHcGF5bG9hZCKRAQoNQ2xpZW50TWVzc2FnZRI4CglzdWJzY3JpYmUYASABKAsyIy5kb3dubG9hZF9wcm9ncmVzcy5TdWJzY3JpYmVSZXF1ZXN0SAASPAoLdW5zdWJzY3JpYmUYAiABKAsyJS5kb3dubG9hZF9wcm9ncmVzcy5VbnN1YnNjcmliZVJlcXVlc3RIAEIICgZhY3Rpb24iJwoQU3Vic2NyaWJlUmVxdWVzdBITCgtzdHJlYW1lcl9pZBgBIAEoCSIUChJVbnN1YnNjcmliZVJlcXVlc3QiegoQRG93bmxvYWRTbmFwc2hvdBIzCglkb3dubG9hZHMYASADKAsyIC5kb3dubG9hZF9wcm9ncmVzcy5Eb3dubG9hZFN0YXRlEjEKBnF1ZXVlZBgCIAMoCzIhLmRvd25sb2FkX3Byb2dyZXNzLkRvd25sb2FkUXVldWVkInMKDURvd25sb2FkU3RhdGUSLQoEbWV0YRgBIAEoCzIfLmRvd25sb2FkX3Byb2dyZXNzLkRvd25sb2FkTWV0YRIzCgdtZXRyaWNzGAIgASgLMiIuZG93bmxvYWRfcHJvZ3Jlc3MuRG93bmxvYWRNZXRyaWNzIrcBCgxEb3dubG9hZE1ldGESEwoLZG93bmxvYWRfaWQYASABKAkSEwoLc3RyZWFtZXJfaWQYAiABKAkSEgoKc2Vzc2lvbl9pZBgDIAEoCRITCgtlbmdpbmVfdHlwZRgEIAEoCRIVCg1zdGFydGVkX2F0X21zGAUgASgDEhUKDXVwZGF0ZWRfYXRfbXMYCCABKAMSEAoIY2RuX2hvc3QYBiABKAkSFAoMZG93bmxvYWRfdXJsGAcgASgJItUBCg9Eb3dubG9hZE1ldHJpY3MSEwoLZG93bmxvYWRfaWQYASABKAkSDgoGc3RhdHVzGAIgASgJEhgKEGJ5dGVzX2Rvd25sb2FkZWQYAyABKAQSFQoNZHVyYXRpb25fc2VjcxgEIAEoARIbChNzcGVlZF9ieXRlc19wZXJfc2VjGAUgASgEEhoKEnNlZ21lbnRzX2NvbXBsZXRlZBgGIAEoDRIbChNtZWRpYV9kdXJhdGlvbl9zZWNzGAcgASgBEhYKDnBsYXliYWNrX3JhdGlvGAggASgBIu4BChBTZWdtZW50Q29tcGxldGVkEhMKC2Rvd25sb2FkX2lkGAEgASgJEhMKC3N0cmVhbWVyX2lkGAIgASgJEhQKDHNlZ21lbnRfcGF0aBgDIAEoCRIVCg1zZWdtZW50X2luZGV4GAQgASgNEhUKDWR1cmF0aW9uX3NlY3MYBSABKAESEgoKc2l6ZV9ieXRlcxgGIAEoBBISCgpzZXNzaW9uX2lkGAcgASgJEhQKDHNwbGl0X3JlYXNvbhgIIAEoCRIVCg1zdGFydGVkX2F0X21zGAkgASgDEhcKD2NvbXBsZXRlZF9hdF9tcxgKIAEoAyKbAQoRRG93bmxvYWRDb21wbGV0ZWQSEwoLZG93bmxvYWRfaWQYASABKAkSEwoLc3RyZWFtZXJfaWQYAiABKAkSEgoKc2Vzc2lvbl9pZBgDIAEoCRITCgt0b3RhbF9ieXRlcxgEIAEoBBIbChN0b3RhbF9kdXJhdGlvbl9zZWNzGAUgASgBEhYKDnRvdGFsX3NlZ21lbnRzGAYgASgNInIKDkRvd25sb2FkRmFpbGVkEhMKC2Rvd25sb2FkX2lkGAEgASgJEhMKC3N0cmVhbWVyX2lkGAIgASgJEhIKCnNlc3Npb25faWQYAyABKAkSDQoFZXJyb3IYBCABKAkSEwoLcmVjb3ZlcmFibGUYBSABKAgiYAoRRG93bmxvYWRDYW5jZWxsZWQSEwoLZG93bmxvYWRfaWQYASABKAkSEwoLc3RyZWFtZXJfaWQYAiABKAkSEgoKc2Vzc2lvbl9pZBgDIAEoCRINCgVjYXVzZRgEIAEoCSJ6ChBEb3dubG9hZFJlamVjdGVkEhMKC3N0cmVhbWVyX2lkGAEgASgJEhIKCnNlc3Npb25faWQYAiABKAkSDgoGcmVhc29uGAMgASgJEhgKEHJldHJ5X2FmdGVyX3NlY3MYBCABKAQSEwoLcmVjb3ZlcmFibGUYBSABKAgiLQoMRXJyb3JQYXlsb2FkEgwKBGNvZGUYASABKAkSDwoHbWVzc2FnZRgCIAEoCSK7AgoVU3RyZWFtZXJDaGVja1JlY29yZGVkEhMKC3N0cmVhbWVyX2lkGAEgASgJEhUKDWNoZWNrZWRfYXRfbXMYAiABKAMSEwoLZHVyYXRpb25fbXMYAyABKAUSDwoHb3V0Y29tZRgEIAEoCRISCgpmYXRhbF9raW5kGAUgASgJEhUKDWZpbHRlcl9yZWFzb24YBiABKAkSFQoNZXJyb3JfbWVzc2FnZRgHIAEoCRIZChFzdHJlYW1zX2V4dHJhY3RlZBgIIAEoDRIcChRzdHJlYW1fc2VsZWN0ZWRfanNvbhgJIAEoCRINCgV0aXRsZRgKIAEoCRIQCghjYXRlZ29yeRgLIAEoCRIUCgx2aWV3ZXJfY291bnQYDCABKAQSHgoWc3RyZWFtc19leHRyYWN0ZWRfanNvbhgNIAEoCSKVAQoORG93bmxvYWRRdWV1ZWQSEwoLc3RyZWFtZXJfaWQYASABKAkSEgoKc2Vzc2lvbl9pZBgCIAEoCRIVCg1zdHJlYW1lcl9uYW1lGAMgASgJEhMKC2VuZ2luZV90eXBlGAQgASgJEhQKDHF1ZXVlZF9hdF9tcxgFIAEoAxIYChBpc19oaWdoX3ByaW9yaXR5GAYgASgIIlIKEERvd25sb2FkRGVxdWV1ZWQSEwoLc3RyZWFtZXJfaWQYASABKAkSEgoKc2Vzc2lvbl9pZBgCIAEoCRIVCg1zdHJlYW1lcl9uYW1lGAMgASgJKqkDCglFdmVudFR5cGUSGgoWRVZFTlRfVFlQRV9VTlNQRUNJRklFRBAAEhcKE0VWRU5UX1RZUEVfU05BUFNIT1QQARIcChhFVkVOVF9UWVBFX0RPV05MT0FEX01FVEEQAhIfChtFVkVOVF9UWVBFX0RPV05MT0FEX01FVFJJQ1MQAxIgChxFVkVOVF9UWVBFX1NFR01FTlRfQ09NUExFVEVEEAQSIQodRVZFTlRfVFlQRV9ET1dOTE9BRF9DT01QTEVURUQQBRIeChpFVkVOVF9UWVBFX0RPV05MT0FEX0ZBSUxFRBAGEiEKHUVWRU5UX1RZUEVfRE9XTkxPQURfQ0FOQ0VMTEVEEAcSFAoQRVZFTlRfVFlQRV9FUlJPUhAIEiAKHEVWRU5UX1RZUEVfRE9XTkxPQURfUkVKRUNURUQQCRImCiJFVkVOVF9UWVBFX1NUUkVBTUVSX0NIRUNLX1JFQ09SREVEEAoSHgoaRVZFTlRfVFlQRV9ET1dOTE9BRF9RVUVVRUQQDBIgChxFVkVOVF9UWVBFX0RPV05MT0FEX0RFUVVFVUVEEA1iBnByb3RvMw");
+  fileDesc("Chdkb3dubG9hZF9wcm9ncmVzcy5wcm90bxIRZG93bmxvYWRfcHJvZ3Jlc3MixAYKCVdzTWVzc2FnZRIwCgpldmVudF90eXBlGAEgASgOMhwuZG93bmxvYWRfcHJvZ3Jlc3MuRXZlbnRUeXBlEjcKCHNuYXBzaG90GAIgASgLMiMuZG93bmxvYWRfcHJvZ3Jlc3MuRG93bmxvYWRTbmFwc2hvdEgAEjgKDWRvd25sb2FkX21ldGEYAyABKAsyHy5kb3dubG9hZF9wcm9ncmVzcy5Eb3dubG9hZE1ldGFIABI+ChBkb3dubG9hZF9tZXRyaWNzGAQgASgLMiIuZG93bmxvYWRfcHJvZ3Jlc3MuRG93bmxvYWRNZXRyaWNzSAASQAoRc2VnbWVudF9jb21wbGV0ZWQYBSABKAsyIy5kb3dubG9hZF9wcm9ncmVzcy5TZWdtZW50Q29tcGxldGVkSAASQgoSZG93bmxvYWRfY29tcGxldGVkGAYgASgLMiQuZG93bmxvYWRfcHJvZ3Jlc3MuRG93bmxvYWRDb21wbGV0ZWRIABI8Cg9kb3dubG9hZF9mYWlsZWQYByABKAsyIS5kb3dubG9hZF9wcm9ncmVzcy5Eb3dubG9hZEZhaWxlZEgAEkIKEmRvd25sb2FkX2NhbmNlbGxlZBgIIAEoCzIkLmRvd25sb2FkX3Byb2dyZXNzLkRvd25sb2FkQ2FuY2VsbGVkSAASMAoFZXJyb3IYCSABKAsyHy5kb3dubG9hZF9wcm9ncmVzcy5FcnJvclBheWxvYWRIABJAChFkb3dubG9hZF9yZWplY3RlZBgKIAEoCzIjLmRvd25sb2FkX3Byb2dyZXNzLkRvd25sb2FkUmVqZWN0ZWRIABJLChdzdHJlYW1lcl9jaGVja19yZWNvcmRlZBgLIAEoCzIoLmRvd25sb2FkX3Byb2dyZXNzLlN0cmVhbWVyQ2hlY2tSZWNvcmRlZEgAEjwKD2Rvd25sb2FkX3F1ZXVlZBgMIAEoCzIhLmRvd25sb2FkX3Byb2dyZXNzLkRvd25sb2FkUXVldWVkSAASQAoRZG93bmxvYWRfZGVxdWV1ZWQYDSABKAsyIy5kb3dubG9hZF9wcm9ncmVzcy5Eb3dubG9hZERlcXVldWVkSABCCQoHcGF5bG9hZCKRAQoNQ2xpZW50TWVzc2FnZRI4CglzdWJzY3JpYmUYASABKAsyIy5kb3dubG9hZF9wcm9ncmVzcy5TdWJzY3JpYmVSZXF1ZXN0SAASPAoLdW5zdWJzY3JpYmUYAiABKAsyJS5kb3dubG9hZF9wcm9ncmVzcy5VbnN1YnNjcmliZVJlcXVlc3RIAEIICgZhY3Rpb24iJwoQU3Vic2NyaWJlUmVxdWVzdBITCgtzdHJlYW1lcl9pZBgBIAEoCSIUChJVbnN1YnNjcmliZVJlcXVlc3QiegoQRG93bmxvYWRTbmFwc2hvdBIzCglkb3dubG9hZHMYASADKAsyIC5kb3dubG9hZF9wcm9ncmVzcy5Eb3dubG9hZFN0YXRlEjEKBnF1ZXVlZBgCIAMoCzIhLmRvd25sb2FkX3Byb2dyZXNzLkRvd25sb2FkUXVldWVkInMKDURvd25sb2FkU3RhdGUSLQoEbWV0YRgBIAEoCzIfLmRvd25sb2FkX3Byb2dyZXNzLkRvd25sb2FkTWV0YRIzCgdtZXRyaWNzGAIgASgLMiIuZG93bmxvYWRfcHJvZ3Jlc3MuRG93bmxvYWRNZXRyaWNzIrcBCgxEb3dubG9hZE1ldGESEwoLZG93bmxvYWRfaWQYASABKAkSEwoLc3RyZWFtZXJfaWQYAiABKAkSEgoKc2Vzc2lvbl9pZBgDIAEoCRITCgtlbmdpbmVfdHlwZRgEIAEoCRIVCg1zdGFydGVkX2F0X21zGAUgASgDEhUKDXVwZGF0ZWRfYXRfbXMYCCABKAMSEAoIY2RuX2hvc3QYBiABKAkSFAoMZG93bmxvYWRfdXJsGAcgASgJIoICCg9Eb3dubG9hZE1ldHJpY3MSEwoLZG93bmxvYWRfaWQYASABKAkSDgoGc3RhdHVzGAIgASgJEhgKEGJ5dGVzX2Rvd25sb2FkZWQYAyABKAQSFQoNZHVyYXRpb25fc2VjcxgEIAEoARIbChNzcGVlZF9ieXRlc19wZXJfc2VjGAUgASgEEhoKEnNlZ21lbnRzX2NvbXBsZXRlZBgGIAEoDRIbChNtZWRpYV9kdXJhdGlvbl9zZWNzGAcgASgBEhYKDnBsYXliYWNrX3JhdGlvGAggASgBEhoKEnNlZ21lbnRfbGF0ZW5jeV9tcxgJIAEoBBIPCgdzdGFsbGVkGAogASgIIu4BChBTZWdtZW50Q29tcGxldGVkEhMKC2Rvd25sb2FkX2lkGAEgASgJEhMKC3N0cmVhbWVyX2lkGAIgASgJEhQKDHNlZ21lbnRfcGF0aBgDIAEoCRIVCg1zZWdtZW50X2luZGV4GAQgASgNEhUKDWR1cmF0aW9uX3NlY3MYBSABKAESEgoKc2l6ZV9ieXRlcxgGIAEoBBISCgpzZXNzaW9uX2lkGAcgASgJEhQKDHNwbGl0X3JlYXNvbhgIIAEoCRIVCg1zdGFydGVkX2F0X21zGAkgASgDEhcKD2NvbXBsZXRlZF9hdF9tcxgKIAEoAyKbAQoRRG93bmxvYWRDb21wbGV0ZWQSEwoLZG93bmxvYWRfaWQYASABKAkSEwoLc3RyZWFtZXJfaWQYAiABKAkSEgoKc2Vzc2lvbl9pZBgDIAEoCRITCgt0b3RhbF9ieXRlcxgEIAEoBBIbChN0b3RhbF9kdXJhdGlvbl9zZWNzGAUgASgBEhYKDnRvdGFsX3NlZ21lbnRzGAYgASgNInIKDkRvd25sb2FkRmFpbGVkEhMKC2Rvd25sb2FkX2lkGAEgASgJEhMKC3N0cmVhbWVyX2lkGAIgASgJEhIKCnNlc3Npb25faWQYAyABKAkSDQoFZXJyb3IYBCABKAkSEwoLcmVjb3ZlcmFibGUYBSABKAgiYAoRRG93bmxvYWRDYW5jZWxsZWQSEwoLZG93bmxvYWRfaWQYASABKAkSEwoLc3RyZWFtZXJfaWQYAiABKAkSEgoKc2Vzc2lvbl9pZBgDIAEoCRINCgVjYXVzZRgEIAEoCSJ6ChBEb3dubG9hZFJlamVjdGVkEhMKC3N0cmVhbWVyX2lkGAEgASgJEhIKCnNlc3Npb25faWQYAiABKAkSDgoGcmVhc29uGAMgASgJEhgKEHJldHJ5X2FmdGVyX3NlY3MYBCABKAQSEwoLcmVjb3ZlcmFibGUYBSABKAgiLQoMRXJyb3JQYXlsb2FkEgwKBGNvZGUYASABKAkSDwoHbWVzc2FnZRgCIAEoCSK7AgoVU3RyZWFtZXJDaGVja1JlY29yZGVkEhMKC3N0cmVhbWVyX2lkGAEgASgJEhUKDWNoZWNrZWRfYXRfbXMYAiABKAMSEwoLZHVyYXRpb25fbXMYAyABKAUSDwoHb3V0Y29tZRgEIAEoCRISCgpmYXRhbF9raW5kGAUgASgJEhUKDWZpbHRlcl9yZWFzb24YBiABKAkSFQoNZXJyb3JfbWVzc2FnZRgHIAEoCRIZChFzdHJlYW1zX2V4dHJhY3RlZBgIIAEoDRIcChRzdHJlYW1fc2VsZWN0ZWRfanNvbhgJIAEoCRINCgV0aXRsZRgKIAEoCRIQCghjYXRlZ29yeRgLIAEoCRIUCgx2aWV3ZXJfY291bnQYDCABKAQSHgoWc3RyZWFtc19leHRyYWN0ZWRfanNvbhgNIAEoCSKVAQoORG93bmxvYWRRdWV1ZWQSEwoLc3RyZWFtZXJfaWQYASABKAkSEgoKc2Vzc2lvbl9pZBgCIAEoCRIVCg1zdHJlYW1lcl9uYW1lGAMgASgJEhMKC2VuZ2luZV90eXBlGAQgASgJEhQKDHF1ZXVlZF9hdF9tcxgFIAEoAxIYChBpc19oaWdoX3ByaW9yaXR5GAYgASgIIlIKEERvd25sb2FkRGVxdWV1ZWQSEwoLc3RyZWFtZXJfaWQYASABKAkSEgoKc2Vzc2lvbl9pZBgCIAEoCRIVCg1zdHJlYW1lcl9uYW1lGAMgASgJKqkDCglFdmVudFR5cGUSGgoWRVZFTlRfVFlQRV9VTlNQRUNJRklFRBAAEhcKE0VWRU5UX1RZUEVfU05BUFNIT1QQARIcChhFVkVOVF9UWVBFX0RPV05MT0FEX01FVEEQAhIfChtFVkVOVF9UWVBFX0RPV05MT0FEX01FVFJJQ1MQAxIgChxFVkVOVF9UWVBFX1NFR01FTlRfQ09NUExFVEVEEAQSIQodRVZFTlRfVFlQRV9ET1dOTE9BRF9DT01QTEVURUQQBRIeChpFVkVOVF9UWVBFX0RPV05MT0FEX0ZBSUxFRBAGEiEKHUVWRU5UX1RZUEVfRE9XTkxPQURfQ0FOQ0VMTEVEEAcSFAoQRVZFTlRfVFlQRV9FUlJPUhAIEiAKHEVWRU5UX1RZUEVfRE9XTkxPQURfUkVKRUNURUQQCRImCiJFVkVOVF9UWVBFX1NUUkVBTUVSX0NIRUNLX1JFQ09SREVEEAoSHgoaRVZFTlRfVFlQRV9ET1dOTE9BRF9RVUVVRUQQDBIgChxFVkVOVF9UWVBFX0RPV05MT0FEX0RFUVVFVUVEEA1iBnByb3RvMw");
 
 /**
  * Server-to-client message envelope
@@ -327,6 +327,20 @@ export type DownloadMetrics = Message<"download_progress.DownloadMetrics"> & {
    * @generated from field: double playback_ratio = 8;
    */
   playbackRatio: number;
+
+  /**
+   * Milliseconds taken to fetch the last segment, 0 when unknown.
+   *
+   * @generated from field: uint64 segment_latency_ms = 9;
+   */
+  segmentLatencyMs: bigint;
+
+  /**
+   * No data has arrived for a while.
+   *
+   * @generated from field: bool stalled = 10;
+   */
+  stalled: boolean;
 };
 
 /**
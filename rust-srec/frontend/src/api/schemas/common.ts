@@ -8,6 +8,35 @@ export type Priority = z.infer<typeof PrioritySchema>;
 
 // --- Shared Config Objects ---
 
+const StreamScoringRuleSchema = z.discriminatedUnion('rule', [
+  z.object({
+    rule: z.literal('prefer_codec'),
+    codec: z.string(),
+    weight: z.number().int().optional(),
+  }),
+  z.object({
+    rule: z.literal('min_height'),
+    height: z.number().int().min(0),
+    weight: z.number().int().optional(),
+  }),
+  z.object({
+    rule: z.literal('max_height'),
+    height: z.number().int().min(0),
+    weight: z.number().int().optional(),
+  }),
+  z.object({
+    rule: z.literal('min_fps'),
+    fps: z.number().min(0),
+    weight: z.number().int().optional(),
+  }),
+  z.object({
+    rule: z.literal('max_bitrate'),
+    bitrate: z.number().int().min(0),
+    weight: z.number().int().optional(),
+  }),
+  z.object({ rule: z.literal('ban_cdn'), cdn: z.string() }),
+]);
+
 export const StreamSelectionConfigObjectSchema = z.object({
   preferred_formats: z.array(z.string()).optional(),
   preferred_media_formats: z.array(z.string()).optional(),
@@ -16,6 +45,9 @@ export const StreamSelectionConfigObjectSchema = z.object({
   blacklisted_cdns: z.array(z.string()).optional(),
   min_bitrate: z.number().optional(),
   max_bitrate: z.number().optional(),
+  rules: z.array(StreamScoringRuleSchema).optional(),
+  probe_cdns: z.boolean().optional(),
+  probe_timeout_ms: z.number().optional(),
 });
 
 export const DownloadRetryPolicyObjectSchema = z.object({
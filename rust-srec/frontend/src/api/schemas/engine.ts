@@ -32,6 +32,11 @@ const optionalInt = (min: number) =>
     )
     .optional();
 
+const optionalTrackId = () =>
+  optionalInt(0).refine((val) => val === undefined || val <= 255, {
+    message: 'Must be an integer <= 255',
+  });
+
 export const FfmpegConfigSchema = z.object({
   binary_path: z.string().default('ffmpeg'),
   input_args: z.array(z.string()).default([]),
@@ -43,6 +48,11 @@ export const FfmpegConfigSchema = z.object({
     .nullable()
     .optional()
     .transform((val) => val ?? undefined),
+  hwaccel: z
+    .string()
+    .nullable()
+    .optional()
+    .transform((val) => val || undefined),
 });
 export type FfmpegConfig = z.infer<typeof FfmpegConfigSchema>;
 
@@ -65,6 +75,24 @@ export const StreamlinkConfigSchema = z.object({
 });
 export type StreamlinkConfig = z.infer<typeof StreamlinkConfigSchema>;
 
+export const YtDlpConfigSchema = z.object({
+  binary_path: z.string().default('yt-dlp'),
+  format: z.string().default('best'),
+  cookies_file: z
+    .string()
+    .nullable()
+    .optional()
+    .transform((val) => (val?.trim() ? val.trim() : undefined)),
+  cookies_from_browser: z
+    .string()
+    .nullable()
+    .optional()
+    .transform((val) => (val?.trim() ? val.trim() : undefined)),
+  extra_args: z.array(z.string()).default([]),
+  graceful_stop_timeout_secs: z.coerce.number().int().min(0).default(60),
+});
+export type YtDlpConfig = z.infer<typeof YtDlpConfigSchema>;
+
 export const MesioHttpVersionPreferenceSchema = z.enum([
   'auto',
   'http2_only',
@@ -84,6 +112,12 @@ export const MesioDownloaderBaseOverrideSchema = z.object({
   force_ipv6: z.boolean().optional(),
   http_version: MesioHttpVersionPreferenceSchema.optional(),
   http2_keep_alive_interval_ms: optionalInt(0),
+  http2_keep_alive_timeout_ms: optionalInt(0),
+  http2_keep_alive_while_idle: z.boolean().optional(),
+  http2_adaptive_window: z.boolean().optional(),
+  http2_initial_stream_window_size: optionalInt(0),
+  http2_initial_connection_window_size: optionalInt(0),
+  tcp_keepalive_ms: optionalInt(0),
   pool_max_idle_per_host: optionalInt(0),
   pool_idle_timeout_ms: optionalInt(0),
 });
@@ -117,6 +151,7 @@ export const MesioHlsPlaylistConfigOverrideSchema = z.object({
   adaptive_refresh_enabled: z.boolean().optional(),
   adaptive_refresh_min_interval_ms: optionalInt(0),
   adaptive_refresh_max_interval_ms: optionalInt(0),
+  low_latency_enabled: z.boolean().optional(),
 });
 
 export const MesioHlsSchedulerConfigOverrideSchema = z.object({
@@ -216,10 +251,29 @@ export const MesioHlsConfigSchema = z.object({
   output_config: MesioHlsOutputConfigOverrideSchema.optional(),
 });
 
+export const MesioRetryPolicyOverrideSchema = z.object({
+  max_retries: optionalInt(0),
+  base_delay_ms: optionalInt(0),
+  max_delay_ms: optionalInt(0),
+  multiplier: z.coerce.number().min(1).optional(),
+  jitter: z.coerce.number().min(0).max(1).optional(),
+  retry_on_network: z.boolean().optional(),
+  retry_on_timeout: z.boolean().optional(),
+  retry_on_server_error: z.boolean().optional(),
+  retry_on_rate_limited: z.boolean().optional(),
+  retry_on_not_found: z.boolean().optional(),
+});
+
+export const MesioWatchdogOverrideSchema = z.object({
+  stall_timeout_secs: optionalInt(0),
+  on_stall: z.enum(['reconnect', 'end_stream']).optional(),
+});
+
 export const MesioConfigSchema = z.object({
   buffer_size: z.coerce.number().int().min(1).default(8388608), // 8MB
   fix_flv: z.boolean().default(true),
   fix_hls: z.boolean().default(true),
+  keep_raw: z.boolean().default(false),
   flv_fix: z
     .object({
       sequence_header_change_mode: z
@@ -227,9 +281,24 @@ export const MesioConfigSchema = z.object({
         .default('crc32'),
       drop_duplicate_sequence_headers: z.boolean().default(false),
       duplicate_tag_filtering: z.boolean().default(true),
+      selected_track: optionalTrackId(),
+      audio_only: z.boolean().default(false),
+      corrupt_tag_recovery: z.boolean().default(true),
+      gap_fill_mode: z.enum(['off', 'record', 'fill']).default('off'),
+      gop_integrity_mode: z.enum(['off', 'drop', 'quarantine']).default('off'),
+      split_on_resolution_change_only: z.boolean().default(false),
+      inject_recording_metadata: z.boolean().default(false),
+      custom_metadata: z.record(z.string(), z.string()).optional(),
+      metadata_checkpoint_secs: optionalInt(0),
+      parallel_stages: z.boolean().default(false),
       duplicate_tag_filter_config: z
         .object({
           window_capacity_tags: z.coerce.number().int().min(1).default(8192),
+          window_duration_ms: optionalInt(0),
+          hash_algorithm: z.enum(['crc32', 'fx64']).default('crc32'),
+          match_mode: z
+            .enum(['payload', 'timestamp_and_size'])
+            .default('payload'),
           replay_backjump_threshold_ms: z.coerce
             .number()
             .int()
@@ -240,12 +309,16 @@ export const MesioConfigSchema = z.object({
         .optional()
         .default({
           window_capacity_tags: 8192,
+          hash_algorithm: 'crc32',
+          match_mode: 'payload',
           replay_backjump_threshold_ms: 2000,
           enable_replay_offset_matching: true,
         }),
     })
     .optional(),
   hls: MesioHlsConfigSchema.optional(),
+  retry: MesioRetryPolicyOverrideSchema.optional(),
+  watchdog: MesioWatchdogOverrideSchema.optional(),
 });
 export type MesioConfig = z.infer<typeof MesioConfigSchema>;
 
@@ -260,6 +333,7 @@ export const FfmpegConfigOverrideSchema = z
     output_args: z.array(z.string()).optional(),
     timeout_secs: optionalInt(0),
     user_agent: optionalNonEmptyString(),
+    hwaccel: optionalNonEmptyString(),
   })
   .strict();
 export type FfmpegConfigOverride = z.infer<typeof FfmpegConfigOverrideSchema>;
@@ -277,9 +351,23 @@ export type StreamlinkConfigOverride = z.infer<
   typeof StreamlinkConfigOverrideSchema
 >;
 
+export const YtDlpConfigOverrideSchema = z
+  .object({
+    binary_path: optionalString(),
+    format: optionalString(),
+    cookies_file: optionalNonEmptyString(),
+    cookies_from_browser: optionalNonEmptyString(),
+    extra_args: z.array(z.string()).optional(),
+  })
+  .strict();
+export type YtDlpConfigOverride = z.infer<typeof YtDlpConfigOverrideSchema>;
+
 const MesioDuplicateTagFilterOverrideSchema = z
   .object({
     window_capacity_tags: optionalInt(1),
+    window_duration_ms: optionalInt(0),
+    hash_algorithm: z.enum(['crc32', 'fx64']).optional(),
+    match_mode: z.enum(['payload', 'timestamp_and_size']).optional(),
     replay_backjump_threshold_ms: optionalInt(0),
     enable_replay_offset_matching: z.boolean().optional(),
   })
@@ -292,6 +380,16 @@ const MesioFlvFixOverrideSchema = z
       .optional(),
     drop_duplicate_sequence_headers: z.boolean().optional(),
     duplicate_tag_filtering: z.boolean().optional(),
+    selected_track: optionalTrackId(),
+    audio_only: z.boolean().optional(),
+    corrupt_tag_recovery: z.boolean().optional(),
+    gap_fill_mode: z.enum(['off', 'record', 'fill']).optional(),
+    gop_integrity_mode: z.enum(['off', 'drop', 'quarantine']).optional(),
+    split_on_resolution_change_only: z.boolean().optional(),
+    inject_recording_metadata: z.boolean().optional(),
+    custom_metadata: z.record(z.string(), z.string()).optional(),
+    metadata_checkpoint_secs: optionalInt(0),
+    parallel_stages: z.boolean().optional(),
     duplicate_tag_filter_config:
       MesioDuplicateTagFilterOverrideSchema.optional(),
   })
@@ -302,8 +400,11 @@ export const MesioConfigOverrideSchema = z
     buffer_size: optionalInt(1),
     fix_flv: z.boolean().optional(),
     fix_hls: z.boolean().optional(),
+    keep_raw: z.boolean().optional(),
     flv_fix: MesioFlvFixOverrideSchema.optional(),
     hls: MesioHlsConfigSchema.optional(),
+    retry: MesioRetryPolicyOverrideSchema.optional(),
+    watchdog: MesioWatchdogOverrideSchema.optional(),
   })
   .strict();
 export type MesioConfigOverride = z.infer<typeof MesioConfigOverrideSchema>;
@@ -312,6 +413,7 @@ export const EngineConfigOverrideSchema = z.union([
   FfmpegConfigOverrideSchema,
   StreamlinkConfigOverrideSchema,
   MesioConfigOverrideSchema,
+  YtDlpConfigOverrideSchema,
 ]);
 export type EngineConfigOverride = z.infer<typeof EngineConfigOverrideSchema>;
 
@@ -337,11 +439,22 @@ export const EngineConfigSchema = z.discriminatedUnion('engine_type', [
     engine_type: z.literal('MESIO'),
     config: MesioConfigSchema,
   }),
+  z.object({
+    id: z.string(),
+    name: z.string(),
+    engine_type: z.literal('YTDLP'),
+    config: YtDlpConfigSchema,
+  }),
 ]);
 export type EngineConfig = z.infer<typeof EngineConfigSchema>;
 
 // --- Engine Type Enum ---
-export const EngineTypeSchema = z.enum(['FFMPEG', 'STREAMLINK', 'MESIO']);
+export const EngineTypeSchema = z.enum([
+  'FFMPEG',
+  'STREAMLINK',
+  'MESIO',
+  'YTDLP',
+]);
 export type EngineType = z.infer<typeof EngineTypeSchema>;
 
 // --- Create Request Schema ---
@@ -367,6 +480,9 @@ export const CreateEngineRequestSchema = z
       case 'MESIO':
         result = MesioConfigSchema.safeParse(config);
         break;
+      case 'YTDLP':
+        result = YtDlpConfigSchema.safeParse(config);
+        break;
       default:
         ctx.addIssue({
           code: z.ZodIssueCode.custom,
@@ -409,6 +525,9 @@ export const UpdateEngineRequestSchema = z
       case 'MESIO':
         result = MesioConfigSchema.safeParse(data.config);
         break;
+      case 'YTDLP':
+        result = YtDlpConfigSchema.safeParse(data.config);
+        break;
     }
 
     if (result && !result.success) {
@@ -423,8 +542,17 @@ export const UpdateEngineRequestSchema = z
 export type UpdateEngineRequest = z.infer<typeof UpdateEngineRequestSchema>;
 
 // --- Test Response Schema ---
+export const HwAccelCapabilitiesSchema = z.object({
+  hwaccels: z.array(z.string()),
+  encoders: z.array(z.string()),
+});
+export type HwAccelCapabilities = z.infer<typeof HwAccelCapabilitiesSchema>;
+
 export const EngineTestResponseSchema = z.object({
   available: z.boolean(),
-  version: z.string().optional(),
+  version: z.string().nullable().optional(),
+  protocols: z.array(z.enum(['hls', 'flv', 'unknown'])).default([]),
+  error: z.string().nullable().optional(),
+  hwaccel: HwAccelCapabilitiesSchema.optional(),
 });
 export type EngineTestResponse = z.infer<typeof EngineTestResponseSchema>;
@@ -120,6 +120,19 @@ export type JobPreset = z.infer<typeof JobPresetSchema>;
 export const VALID_PROCESSORS = [
   'remux',
   'rclone',
+  's3',
+  'youtube',
+  'bilibili',
+  'webhook',
+  'ffmpeg',
+  'concat',
+  'danmu_chapters',
+  'danmu_subtitle',
+  'checksum',
+  'encrypt',
+  'torrent',
+  'nfo',
+  'highlights',
   'thumbnail',
   'execute',
   'audio_extract',
@@ -91,6 +91,10 @@ export const PlatformConfigSchema = z.object({
   // `HysteresisConfig::from_scheduler` clamping.
   offline_check_count: z.number().int().min(1).nullable().optional(),
   offline_check_delay_ms: z.number().int().min(1000).nullable().optional(),
+
+  // Status checks per second shared by every streamer on the platform.
+  // NULL = built-in default.
+  rate_limit: z.number().positive().nullable().optional(),
 });
 
 export type PlatformConfig = z.infer<typeof PlatformConfigSchema>;
@@ -89,6 +89,8 @@ export const SessionSchema = z.object({
   total_size_bytes: z.number(),
   danmu_count: z.number().nullable().optional(),
   thumbnail_url: z.string().nullable().optional(),
+  download_engine: z.string().nullable().optional(),
+  kind: z.string().default('live'),
 });
 
 export const DanmuRatePointSchema = z.object({
@@ -107,14 +109,112 @@ export const DanmuWordFrequencySchema = z.object({
   count: z.number(),
 });
 
+export const DanmuActivityBurstSchema = z.object({
+  start_ts: z.number(),
+  end_ts: z.number(),
+  message_count: z.number(),
+  peak_rate: z.number(),
+  baseline_rate: z.number(),
+});
+
 export const SessionDanmuStatisticsSchema = z.object({
   session_id: z.string(),
   total_danmus: z.number(),
   danmu_rate_timeseries: z.array(DanmuRatePointSchema),
   top_talkers: z.array(DanmuTopTalkerSchema),
   word_frequency: z.array(DanmuWordFrequencySchema),
+  activity_bursts: z.array(DanmuActivityBurstSchema).default([]),
 });
 
+export const FlvSegmentAnalysisReportSchema = z.object({
+  duration_secs: z.number(),
+  file_size: z.number(),
+  has_video: z.boolean(),
+  has_audio: z.boolean(),
+  tags: z.object({
+    total: z.number(),
+    video: z.number(),
+    audio: z.number(),
+    script: z.number(),
+  }),
+  bitrates: z.object({
+    video_kbps: z.number(),
+    audio_kbps: z.number(),
+  }),
+  keyframe_intervals: z.array(
+    z.object({
+      interval_secs: z.number(),
+      count: z.number(),
+    }),
+  ),
+  timestamp_anomalies: z.object({
+    gap_count: z.number(),
+    gap_duration_ms: z.number(),
+    regressions: z.number(),
+  }),
+  repairs: z.object({
+    dropped_tags: z.number(),
+    duplicated_tags: z.number(),
+    // Absent in reports stored before these counters existed
+    duplicated_video_tags: z.number().default(0),
+    duplicated_audio_tags: z.number().default(0),
+    duplicated_bytes: z.number().default(0),
+    replayed_tags: z.number().default(0),
+    incomplete_gops: z.number().default(0),
+    incomplete_gop_tags: z.number().default(0),
+    corrupted_tags: z.number().default(0),
+    corrupted_bytes: z.number().default(0),
+  }),
+  chapters: z
+    .array(z.object({ time_s: z.number(), title: z.string() }))
+    .default([]),
+});
+
+export const HlsSegmentAnalysisReportSchema = z.object({
+  duration_secs: z.number(),
+  file_size: z.number(),
+  segments: z.object({
+    total: z.number(),
+    media: z.number(),
+    init: z.number(),
+  }),
+  target_duration_secs: z.number().nullable(),
+  duration_drift: z
+    .object({
+      mean_secs: z.number(),
+      min_secs: z.number(),
+      max_secs: z.number(),
+      over_target: z.number(),
+    })
+    .nullable(),
+  sequence_gaps: z.array(
+    z.object({
+      first_missing: z.number(),
+      missing: z.number(),
+    }),
+  ),
+  discontinuities: z.number(),
+  download_latency: z
+    .object({
+      samples: z.number(),
+      mean_ms: z.number(),
+      min_ms: z.number(),
+      p50_ms: z.number(),
+      p90_ms: z.number(),
+      p99_ms: z.number(),
+      max_ms: z.number(),
+    })
+    .nullable(),
+});
+
+export const SegmentAnalysisReportSchema = z.union([
+  FlvSegmentAnalysisReportSchema,
+  HlsSegmentAnalysisReportSchema,
+]);
+export type SegmentAnalysisReport = z.infer<
+  typeof SegmentAnalysisReportSchema
+>;
+
 export const SessionSegmentSchema = z.object({
   id: z.string(),
   session_id: z.string(),
@@ -124,13 +224,14 @@ export const SessionSegmentSchema = z.object({
   size_bytes: z.number(),
   split_reason_code: z.string().nullable().optional(),
   split_reason_details: z.any().optional(),
+  analysis_report: SegmentAnalysisReportSchema.nullable().optional(),
   created_at: z.string().nullable(),
   completed_at: z.string().nullable().optional(),
   persisted_at: z.string(),
 });
 export type SessionSegment = z.infer<typeof SessionSegmentSchema>;
 
-export const JobProgressKindSchema = z.enum(['ffmpeg', 'rclone']);
+export const JobProgressKindSchema = z.enum(['ffmpeg', 'rclone', 'upload']);
 export type JobProgressKind = z.infer<typeof JobProgressKindSchema>;
 
 export type SessionDanmuStatistics = z.infer<
@@ -121,6 +121,14 @@ export const StreamerSpecificConfigSchema = z.object({
     )
     .nullable()
     .optional(),
+  wall_clock_split_secs: z
+    .preprocess(
+      (v) => (v === '' ? null : typeof v === 'string' ? Number(v) : v),
+      z.number().nullable().optional(),
+    )
+    .nullable()
+    .optional(),
+  vod_backfill: z.boolean().nullable().optional(),
   record_danmu: z.boolean().nullable().optional(),
   cookies: z
     .preprocess((v) => (v === '' ? null : v), z.string().nullable().optional())
@@ -130,6 +138,7 @@ export const StreamerSpecificConfigSchema = z.object({
     .preprocess((v) => (v === '' ? null : v), z.string().nullable().optional())
     .nullable()
     .optional(),
+  download_engine_fallbacks: z.array(z.string()).nullable().optional(),
   engines_override: z
     .preprocess((v) => (v === '' ? null : v), z.string().nullable().optional())
     .nullable()
@@ -171,6 +180,7 @@ export const StreamerSpecificConfigFormSchema = z.object({
   record_danmu: z.boolean().nullable().optional(),
   cookies: z.string().nullable().optional(),
   download_engine: z.string().nullable().optional(),
+  download_engine_fallbacks: z.array(z.string()).nullable().optional(),
   engines_override: z.string().nullable().optional(),
   offline_check_count: z.number().int().min(1).nullable().optional(),
   offline_check_delay_ms: z.number().int().min(1000).nullable().optional(),
@@ -34,6 +34,27 @@ export const GlobalConfigSchema = z.object({
   queue_freshness_threshold_ms: z.number(),
   gpu_health_probe_interval_secs: z.number(),
   stream_proxy_allow_private_targets: z.boolean().default(false),
+  low_disk_space_threshold_bytes: z.number().default(0),
+  low_disk_space_action: z
+    .enum(['stop_new', 'finalize', 'switch_folder'])
+    .default('stop_new'),
+  low_disk_space_secondary_folder: z.string().nullable().optional(),
+  adaptive_polling: z.boolean().default(false),
+  offline_grace_secs: z.number().int().min(0).max(600).default(0),
+  load_admission_max_cpu_percent: z.number().int().min(0).max(100).default(0),
+  load_admission_max_memory_percent: z
+    .number()
+    .int()
+    .min(0)
+    .max(100)
+    .default(0),
+  load_admission_max_io_pressure_percent: z
+    .number()
+    .int()
+    .min(0)
+    .max(100)
+    .default(0),
+  danmu_connection_config: z.string().nullable().optional(),
   // Handle pipeline - backend sends JSON string, need to parse it
   pipeline: z
     .string()
@@ -110,6 +131,15 @@ export const GlobalConfigFormSchema = z.object({
   queue_freshness_threshold_ms: z.number().int().min(0),
   gpu_health_probe_interval_secs: z.number().int().min(1),
   stream_proxy_allow_private_targets: z.boolean().default(false),
+  low_disk_space_threshold_bytes: z.number().int().min(0),
+  low_disk_space_action: z.enum(['stop_new', 'finalize', 'switch_folder']),
+  low_disk_space_secondary_folder: z.string().nullable().optional(),
+  adaptive_polling: z.boolean().default(false),
+  offline_grace_secs: z.number().int().min(0).max(600),
+  load_admission_max_cpu_percent: z.number().int().min(0).max(100),
+  load_admission_max_memory_percent: z.number().int().min(0).max(100),
+  load_admission_max_io_pressure_percent: z.number().int().min(0).max(100),
+  danmu_connection_config: z.string().nullable().optional(),
   // Form works with object directly (already parsed from API response)
   pipeline: DagPipelineDefinitionSchema.nullable().optional(),
   session_complete_pipeline: DagPipelineDefinitionSchema.nullable().optional(),
@@ -147,6 +177,15 @@ export const GlobalConfigWriteSchema = z.object({
   queue_freshness_threshold_ms: z.number().int().min(0),
   gpu_health_probe_interval_secs: z.number().int().min(1),
   stream_proxy_allow_private_targets: z.boolean().default(false),
+  low_disk_space_threshold_bytes: z.number().int().min(0),
+  low_disk_space_action: z.enum(['stop_new', 'finalize', 'switch_folder']),
+  low_disk_space_secondary_folder: z.string().nullable().optional(),
+  adaptive_polling: z.boolean().default(false),
+  offline_grace_secs: z.number().int().min(0).max(600),
+  load_admission_max_cpu_percent: z.number().int().min(0).max(100),
+  load_admission_max_memory_percent: z.number().int().min(0).max(100),
+  load_admission_max_io_pressure_percent: z.number().int().min(0).max(100),
+  danmu_connection_config: z.string().nullable().optional(),
 
   // Accept any object - will be stringified by config.ts when sending to backend
   pipeline: z.any().nullable().optional(),
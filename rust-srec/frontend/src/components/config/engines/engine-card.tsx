@@ -49,6 +49,8 @@ export function EngineCard({ engine }: EngineCardProps) {
     'loading' | 'available' | 'unavailable' | 'error'
   >('loading');
   const [version, setVersion] = useState<string | null>(null);
+  const [probeError, setProbeError] = useState<string | null>(null);
+  const [protocols, setProtocols] = useState<string[]>([]);
 
   useEffect(() => {
     let mounted = true;
@@ -59,6 +61,8 @@ export function EngineCard({ engine }: EngineCardProps) {
         if (mounted) {
           setStatus(result.available ? 'available' : 'unavailable');
           setVersion(result.version ?? 'unknown');
+          setProbeError(result.error ?? null);
+          setProtocols(result.protocols.filter((p) => p !== 'unknown'));
         }
       } catch (error) {
         if (mounted) {
@@ -187,6 +191,13 @@ export function EngineCard({ engine }: EngineCardProps) {
           </span>
         </div>
 
+        {probeError && status !== 'loading' && (
+          <div className="flex items-start gap-2 p-2.5 rounded-md text-xs border border-rose-500/20 bg-rose-500/10 text-rose-600 dark:text-rose-400 break-words">
+            <AlertCircle className="w-3.5 h-3.5 mt-0.5 shrink-0" />
+            <span>{probeError}</span>
+          </div>
+        )}
+
         {version && (
           <div className="space-y-1.5">
             <span className="text-[10px] uppercase tracking-wider text-muted-foreground font-semibold ml-1">
@@ -197,6 +208,20 @@ export function EngineCard({ engine }: EngineCardProps) {
             </div>
           </div>
         )}
+
+        {protocols.length > 0 && status === 'available' && (
+          <div className="flex flex-wrap gap-1.5">
+            {protocols.map((protocol) => (
+              <Badge
+                key={protocol}
+                variant="secondary"
+                className="uppercase font-mono text-[10px] px-1.5 py-0 h-5"
+              >
+                {protocol}
+              </Badge>
+            ))}
+          </div>
+        )}
       </CardContent>
 
       <CardFooter className="relative pt-0 text-[10px] text-muted-foreground flex justify-end items-center z-10 border-t border-border/20 mt-auto px-6 py-3 bg-muted/5 gap-2">
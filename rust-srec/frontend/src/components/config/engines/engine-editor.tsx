@@ -22,6 +22,7 @@ import {
   Terminal,
   Radio,
   Database,
+  Download,
   Save,
   Loader2,
   ArrowLeft,
@@ -47,11 +48,13 @@ import {
   FfmpegConfigSchema,
   StreamlinkConfigSchema,
   MesioConfigSchema,
+  YtDlpConfigSchema,
 } from '@/api/schemas';
 import { createEngine, updateEngine } from '@/server/functions';
 import { FfmpegForm } from './forms/ffmpeg-form';
 import { StreamlinkForm } from './forms/streamlink-form';
 import { MesioForm } from './forms/mesio-form';
+import { YtDlpForm } from './forms/ytdlp-form';
 import { Link } from '@tanstack/react-router';
 import { cn } from '@/lib/utils';
 import { Badge } from '@/components/ui/badge';
@@ -81,6 +84,7 @@ function EngineConfigCard() {
             {engineType === 'FFMPEG' && <Terminal className="w-5 h-5" />}
             {engineType === 'STREAMLINK' && <Radio className="w-5 h-5" />}
             {engineType === 'MESIO' && <Database className="w-5 h-5" />}
+            {engineType === 'YTDLP' && <Download className="w-5 h-5" />}
           </div>
           <div className="space-y-0.5">
             <CardTitle className="text-lg flex items-center gap-2">
@@ -88,7 +92,9 @@ function EngineConfigCard() {
                 ? 'FFmpeg'
                 : engineType === 'STREAMLINK'
                   ? 'Streamlink'
-                  : 'Mesio'}
+                  : engineType === 'YTDLP'
+                    ? 'yt-dlp'
+                    : 'Mesio'}
               <Trans>Settings</Trans>
             </CardTitle>
             <CardDescription>
@@ -101,6 +107,7 @@ function EngineConfigCard() {
         {engineType === 'FFMPEG' && <FfmpegForm />}
         {engineType === 'STREAMLINK' && <StreamlinkForm />}
         {engineType === 'MESIO' && <MesioForm />}
+        {engineType === 'YTDLP' && <YtDlpForm />}
       </CardContent>
     </Card>
   );
@@ -227,6 +234,11 @@ function EngineForm({
                           'config',
                           MesioConfigSchema.parse({}) as any,
                         );
+                      if (value === 'YTDLP')
+                        form.setValue(
+                          'config',
+                          YtDlpConfigSchema.parse({}) as any,
+                        );
                     }}
                     value={field.value}
                   >
@@ -254,6 +266,12 @@ function EngineForm({
                           <span className="font-medium">Mesio</span>
                         </div>
                       </SelectItem>
+                      <SelectItem value="YTDLP">
+                        <div className="flex items-center gap-2">
+                          <Download className="w-4 h-4 text-rose-500" />
+                          <span className="font-medium">yt-dlp</span>
+                        </div>
+                      </SelectItem>
                     </SelectContent>
                   </Select>
                   <FormDescription>
@@ -18,6 +18,7 @@ import {
   ArrowRightFromLine,
   ArrowLeftFromLine,
   TimerOff,
+  Cpu,
 } from 'lucide-react';
 import { msg } from '@lingui/core/macro';
 import { Trans } from '@lingui/react/macro';
@@ -130,6 +131,27 @@ export function FfmpegForm({ basePath = 'config' }: FfmpegFormProps) {
         )}
       />
 
+      <FormField
+        name={`${basePath}.hwaccel`}
+        render={({ field }) => (
+          <FormItem>
+            <FormLabel className="flex items-center gap-2 text-xs uppercase tracking-wider text-muted-foreground font-semibold">
+              <Cpu className="w-3.5 h-3.5 text-primary" />
+              <Trans>Hardware Acceleration</Trans>
+            </FormLabel>
+            <FormControl>
+              <Input
+                {...field}
+                value={field.value ?? ''}
+                placeholder="auto, cuda, qsv, vaapi, videotoolbox"
+                className="bg-background/50"
+              />
+            </FormControl>
+            <FormMessage />
+          </FormItem>
+        )}
+      />
+
       <Separator className="bg-border/50" />
 
       <div className="grid gap-6 md:grid-cols-2">
@@ -20,12 +20,14 @@ import {
 } from '@/components/ui/select';
 import { Badge } from '@/components/ui/badge';
 import {
+  Archive,
   Database,
   Wrench,
   Film,
   Settings2,
   RefreshCw,
   Layers,
+  Timer,
 } from 'lucide-react';
 import { Trans } from '@lingui/react/macro';
 import { MesioHlsForm } from './mesio-hls-form';
@@ -206,6 +208,78 @@ const MesioFlvForm = React.memo(({ basePath }: SubFormProps) => {
                       </FormItem>
                     )}
                   />
+                  <FormField
+                    name={`${basePath}.flv_fix.duplicate_tag_filter_config.window_duration_ms`}
+                    render={({ field }) => (
+                      <FormItem>
+                        <FormLabel className="text-[10px] font-semibold text-blue-500/80 uppercase tracking-tight mb-1">
+                          <Trans>Filter Window Duration</Trans>
+                        </FormLabel>
+                        <FormControl>
+                          <Input
+                            type="number"
+                            {...field}
+                            value={field.value ?? ''}
+                            className="h-8 text-xs bg-background/50 border-blue-500/20 focus-visible:ring-blue-500/30 font-mono"
+                            placeholder="ms"
+                          />
+                        </FormControl>
+                        <FormMessage />
+                      </FormItem>
+                    )}
+                  />
+                  <FormField
+                    name={`${basePath}.flv_fix.duplicate_tag_filter_config.match_mode`}
+                    render={({ field }) => (
+                      <FormItem>
+                        <FormLabel className="text-[10px] font-semibold text-blue-500/80 uppercase tracking-tight mb-1">
+                          <Trans>Match Mode</Trans>
+                        </FormLabel>
+                        <Select
+                          onValueChange={field.onChange}
+                          defaultValue={field.value || 'payload'}
+                        >
+                          <FormControl>
+                            <SelectTrigger className="h-8 text-xs bg-background/50 border-blue-500/20">
+                              <SelectValue />
+                            </SelectTrigger>
+                          </FormControl>
+                          <SelectContent>
+                            <SelectItem value="payload">
+                              <Trans>Payload bytes</Trans>
+                            </SelectItem>
+                            <SelectItem value="timestamp_and_size">
+                              <Trans>Timestamp and size</Trans>
+                            </SelectItem>
+                          </SelectContent>
+                        </Select>
+                      </FormItem>
+                    )}
+                  />
+                  <FormField
+                    name={`${basePath}.flv_fix.duplicate_tag_filter_config.hash_algorithm`}
+                    render={({ field }) => (
+                      <FormItem>
+                        <FormLabel className="text-[10px] font-semibold text-blue-500/80 uppercase tracking-tight mb-1">
+                          <Trans>Payload Hash</Trans>
+                        </FormLabel>
+                        <Select
+                          onValueChange={field.onChange}
+                          defaultValue={field.value || 'crc32'}
+                        >
+                          <FormControl>
+                            <SelectTrigger className="h-8 text-xs bg-background/50 border-blue-500/20">
+                              <SelectValue />
+                            </SelectTrigger>
+                          </FormControl>
+                          <SelectContent>
+                            <SelectItem value="crc32">CRC-32</SelectItem>
+                            <SelectItem value="fx64">FxHash (64-bit)</SelectItem>
+                          </SelectContent>
+                        </Select>
+                      </FormItem>
+                    )}
+                  />
                   <div className="sm:col-span-2 pt-1 border-t border-blue-500/10">
                     <FormField
                       name={`${basePath}.flv_fix.duplicate_tag_filter_config.enable_replay_offset_matching`}
@@ -228,6 +302,273 @@ const MesioFlvForm = React.memo(({ basePath }: SubFormProps) => {
                 </div>
               </div>
             )}
+
+            <FormField
+              name={`${basePath}.flv_fix.selected_track`}
+              render={({ field }) => (
+                <FormItem className="flex flex-row items-center justify-between rounded-xl border border-border/40 bg-muted/5 p-4 py-3 shadow-none transition-all hover:bg-muted/10">
+                  <div className="space-y-0.5">
+                    <FormLabel className="text-xs font-medium">
+                      <Trans>Multitrack Selection</Trans>
+                    </FormLabel>
+                    <FormDescription className="text-[10px]">
+                      <Trans>
+                        Keep only this track of multitrack streams. Leave empty
+                        to keep all tracks
+                      </Trans>
+                    </FormDescription>
+                    <FormMessage />
+                  </div>
+                  <FormControl>
+                    <Input
+                      type="number"
+                      min={0}
+                      max={255}
+                      {...field}
+                      value={field.value ?? ''}
+                      className="h-8 w-20 text-xs bg-background/50 font-mono"
+                      placeholder="All"
+                    />
+                  </FormControl>
+                </FormItem>
+              )}
+            />
+
+            <FormField
+              name={`${basePath}.flv_fix.audio_only`}
+              render={({ field }) => (
+                <FormItem className="flex flex-row items-center justify-between rounded-xl border border-border/40 bg-muted/5 p-4 py-3 shadow-none transition-all hover:bg-muted/10">
+                  <div className="space-y-0.5">
+                    <FormLabel className="text-xs font-medium">
+                      <Trans>Audio Only</Trans>
+                    </FormLabel>
+                    <FormDescription className="text-[10px]">
+                      <Trans>Drop video and record audio-only FLV files</Trans>
+                    </FormDescription>
+                  </div>
+                  <FormControl>
+                    <Switch
+                      checked={field.value}
+                      onCheckedChange={field.onChange}
+                      className="scale-90"
+                    />
+                  </FormControl>
+                </FormItem>
+              )}
+            />
+
+            <FormField
+              name={`${basePath}.flv_fix.corrupt_tag_recovery`}
+              render={({ field }) => (
+                <FormItem className="flex flex-row items-center justify-between rounded-xl border border-border/40 bg-muted/5 p-4 py-3 shadow-none transition-all hover:bg-muted/10">
+                  <div className="space-y-0.5">
+                    <FormLabel className="text-xs font-medium">
+                      <Trans>Drop Corrupt Tags</Trans>
+                    </FormLabel>
+                    <FormDescription className="text-[10px]">
+                      <Trans>
+                        Drop tags with unknown codecs or impossible timestamps
+                      </Trans>
+                    </FormDescription>
+                  </div>
+                  <FormControl>
+                    <Switch
+                      checked={field.value}
+                      onCheckedChange={field.onChange}
+                      className="scale-90"
+                    />
+                  </FormControl>
+                </FormItem>
+              )}
+            />
+
+            <FormField
+              name={`${basePath}.flv_fix.gap_fill_mode`}
+              render={({ field }) => (
+                <FormItem className="flex flex-row items-center justify-between rounded-xl border border-border/40 bg-muted/5 p-4 py-3 shadow-none transition-all hover:bg-muted/10">
+                  <div className="space-y-0.5">
+                    <FormLabel className="text-xs font-medium">
+                      <Trans>Stall Gap Handling</Trans>
+                    </FormLabel>
+                    <FormDescription className="text-[10px]">
+                      <Trans>
+                        Keep timestamp gaps from CDN stalls instead of
+                        collapsing them
+                      </Trans>
+                    </FormDescription>
+                  </div>
+                  <Select
+                    onValueChange={field.onChange}
+                    defaultValue={field.value || 'off'}
+                  >
+                    <FormControl>
+                      <SelectTrigger className="h-8 w-36 text-xs bg-background/50">
+                        <SelectValue />
+                      </SelectTrigger>
+                    </FormControl>
+                    <SelectContent>
+                      <SelectItem value="off">
+                        <Trans>Disabled</Trans>
+                      </SelectItem>
+                      <SelectItem value="record">
+                        <Trans>Record in metadata</Trans>
+                      </SelectItem>
+                      <SelectItem value="fill">
+                        <Trans>Fill with silence</Trans>
+                      </SelectItem>
+                    </SelectContent>
+                  </Select>
+                </FormItem>
+              )}
+            />
+
+            <FormField
+              name={`${basePath}.flv_fix.gop_integrity_mode`}
+              render={({ field }) => (
+                <FormItem className="flex flex-row items-center justify-between rounded-xl border border-border/40 bg-muted/5 p-4 py-3 shadow-none transition-all hover:bg-muted/10">
+                  <div className="space-y-0.5">
+                    <FormLabel className="text-xs font-medium">
+                      <Trans>Incomplete GOPs</Trans>
+                    </FormLabel>
+                    <FormDescription className="text-[10px]">
+                      <Trans>
+                        Remove frames without a leading keyframe after
+                        reconnects, which show as grey blocks
+                      </Trans>
+                    </FormDescription>
+                  </div>
+                  <Select
+                    onValueChange={field.onChange}
+                    defaultValue={field.value || 'off'}
+                  >
+                    <FormControl>
+                      <SelectTrigger className="h-8 w-36 text-xs bg-background/50">
+                        <SelectValue />
+                      </SelectTrigger>
+                    </FormControl>
+                    <SelectContent>
+                      <SelectItem value="off">
+                        <Trans>Keep</Trans>
+                      </SelectItem>
+                      <SelectItem value="drop">
+                        <Trans>Drop video frames</Trans>
+                      </SelectItem>
+                      <SelectItem value="quarantine">
+                        <Trans>Quarantine until keyframe</Trans>
+                      </SelectItem>
+                    </SelectContent>
+                  </Select>
+                </FormItem>
+              )}
+            />
+
+            <FormField
+              name={`${basePath}.flv_fix.split_on_resolution_change_only`}
+              render={({ field }) => (
+                <FormItem className="flex flex-row items-center justify-between rounded-xl border border-border/40 bg-muted/5 p-4 py-3 shadow-none transition-all hover:bg-muted/10">
+                  <div className="space-y-0.5">
+                    <FormLabel className="text-xs font-medium">
+                      <Trans>Split on Resolution Change Only</Trans>
+                    </FormLabel>
+                    <FormDescription className="text-[10px]">
+                      <Trans>
+                        Ignore codec header changes that keep the same
+                        resolution
+                      </Trans>
+                    </FormDescription>
+                  </div>
+                  <FormControl>
+                    <Switch
+                      checked={field.value}
+                      onCheckedChange={field.onChange}
+                      className="scale-90"
+                    />
+                  </FormControl>
+                </FormItem>
+              )}
+            />
+
+            <FormField
+              name={`${basePath}.flv_fix.inject_recording_metadata`}
+              render={({ field }) => (
+                <FormItem className="flex flex-row items-center justify-between rounded-xl border border-border/40 bg-muted/5 p-4 py-3 shadow-none transition-all hover:bg-muted/10">
+                  <div className="space-y-0.5">
+                    <FormLabel className="text-xs font-medium">
+                      <Trans>Write Recording Metadata</Trans>
+                    </FormLabel>
+                    <FormDescription className="text-[10px]">
+                      <Trans>
+                        Store the streamer, platform, session and danmu file in
+                        the FLV metadata
+                      </Trans>
+                    </FormDescription>
+                  </div>
+                  <FormControl>
+                    <Switch
+                      checked={field.value}
+                      onCheckedChange={field.onChange}
+                      className="scale-90"
+                    />
+                  </FormControl>
+                </FormItem>
+              )}
+            />
+
+            <FormField
+              name={`${basePath}.flv_fix.metadata_checkpoint_secs`}
+              render={({ field }) => (
+                <FormItem className="flex flex-row items-center justify-between rounded-xl border border-border/40 bg-muted/5 p-4 py-3 shadow-none transition-all hover:bg-muted/10">
+                  <div className="space-y-0.5">
+                    <FormLabel className="text-xs font-medium">
+                      <Trans>Keyframe Index Checkpoint</Trans>
+                    </FormLabel>
+                    <FormDescription className="text-[10px]">
+                      <Trans>
+                        Update the keyframe index every this many seconds while
+                        recording, so interrupted files stay seekable
+                      </Trans>
+                    </FormDescription>
+                    <FormMessage />
+                  </div>
+                  <FormControl>
+                    <Input
+                      type="number"
+                      min={0}
+                      {...field}
+                      value={field.value ?? ''}
+                      className="h-8 w-24 text-xs bg-background/50 font-mono"
+                      placeholder="Off"
+                    />
+                  </FormControl>
+                </FormItem>
+              )}
+            />
+
+            <FormField
+              name={`${basePath}.flv_fix.parallel_stages`}
+              render={({ field }) => (
+                <FormItem className="flex flex-row items-center justify-between rounded-xl border border-border/40 bg-muted/5 p-4 py-3 shadow-none transition-all hover:bg-muted/10">
+                  <div className="space-y-0.5">
+                    <FormLabel className="text-xs font-medium">
+                      <Trans>Parallel Processing Stages</Trans>
+                    </FormLabel>
+                    <FormDescription className="text-[10px]">
+                      <Trans>
+                        Spread FLV processing over several CPU cores for
+                        high-bitrate streams
+                      </Trans>
+                    </FormDescription>
+                  </div>
+                  <FormControl>
+                    <Switch
+                      checked={field.value}
+                      onCheckedChange={field.onChange}
+                      className="scale-90"
+                    />
+                  </FormControl>
+                </FormItem>
+              )}
+            />
           </div>
         </CardContent>
       </Card>
@@ -236,6 +577,282 @@ const MesioFlvForm = React.memo(({ basePath }: SubFormProps) => {
 });
 MesioFlvForm.displayName = 'MesioFlvForm';
 
+const MesioRetrySettings = React.memo(({ basePath }: SubFormProps) => (
+  <Card className="border-border/40 bg-background/40 shadow-sm">
+    <CardHeader className="pb-3 pt-4 px-4">
+      <CardTitle className="text-sm font-medium flex items-center gap-2">
+        <RefreshCw className="w-4 h-4 text-primary" />
+        <Trans>Retry Policy</Trans>
+      </CardTitle>
+    </CardHeader>
+    <CardContent className="px-4 pb-4 space-y-4">
+      <div className="grid gap-4 sm:grid-cols-3">
+        <FormField
+          name={`${basePath}.retry.max_retries`}
+          render={({ field }) => (
+            <FormItem>
+              <FormLabel className="text-xs">
+                <Trans>Max Retries</Trans>
+              </FormLabel>
+              <FormControl>
+                <Input
+                  type="number"
+                  {...field}
+                  value={field.value ?? ''}
+                  className="h-8 text-xs font-mono"
+                  placeholder="Default: 3"
+                />
+              </FormControl>
+              <FormMessage />
+            </FormItem>
+          )}
+        />
+        <FormField
+          name={`${basePath}.retry.base_delay_ms`}
+          render={({ field }) => (
+            <FormItem>
+              <FormLabel className="text-xs">
+                <Trans>First Retry Delay (ms)</Trans>
+              </FormLabel>
+              <FormControl>
+                <Input
+                  type="number"
+                  {...field}
+                  value={field.value ?? ''}
+                  className="h-8 text-xs font-mono"
+                  placeholder="Default: 500"
+                />
+              </FormControl>
+              <FormMessage />
+            </FormItem>
+          )}
+        />
+        <FormField
+          name={`${basePath}.retry.max_delay_ms`}
+          render={({ field }) => (
+            <FormItem>
+              <FormLabel className="text-xs">
+                <Trans>Max Retry Delay (ms)</Trans>
+              </FormLabel>
+              <FormControl>
+                <Input
+                  type="number"
+                  {...field}
+                  value={field.value ?? ''}
+                  className="h-8 text-xs font-mono"
+                  placeholder="Default: 10000"
+                />
+              </FormControl>
+              <FormMessage />
+            </FormItem>
+          )}
+        />
+        <FormField
+          name={`${basePath}.retry.multiplier`}
+          render={({ field }) => (
+            <FormItem>
+              <FormLabel className="text-xs">
+                <Trans>Delay Multiplier</Trans>
+              </FormLabel>
+              <FormControl>
+                <Input
+                  type="number"
+                  step="0.1"
+                  {...field}
+                  value={field.value ?? ''}
+                  className="h-8 text-xs font-mono"
+                  placeholder="Default: 2"
+                />
+              </FormControl>
+              <FormMessage />
+            </FormItem>
+          )}
+        />
+        <FormField
+          name={`${basePath}.retry.jitter`}
+          render={({ field }) => (
+            <FormItem>
+              <FormLabel className="text-xs">
+                <Trans>Delay Jitter</Trans>
+              </FormLabel>
+              <FormControl>
+                <Input
+                  type="number"
+                  step="0.05"
+                  {...field}
+                  value={field.value ?? ''}
+                  className="h-8 text-xs font-mono"
+                  placeholder="Default: 0.2"
+                />
+              </FormControl>
+              <FormMessage />
+            </FormItem>
+          )}
+        />
+      </div>
+      <div className="grid gap-2 sm:grid-cols-2">
+        <FormField
+          name={`${basePath}.retry.retry_on_network`}
+          render={({ field }) => (
+            <FormItem className="flex flex-row items-center justify-between rounded-lg border border-border/40 bg-muted/5 px-3 py-2 shadow-sm">
+              <FormLabel className="text-[11px] font-normal">
+                <Trans>Retry Network Errors (Default: On)</Trans>
+              </FormLabel>
+              <FormControl>
+                <Switch
+                  checked={field.value ?? true}
+                  onCheckedChange={field.onChange}
+                  className="scale-75 origin-right"
+                />
+              </FormControl>
+            </FormItem>
+          )}
+        />
+        <FormField
+          name={`${basePath}.retry.retry_on_timeout`}
+          render={({ field }) => (
+            <FormItem className="flex flex-row items-center justify-between rounded-lg border border-border/40 bg-muted/5 px-3 py-2 shadow-sm">
+              <FormLabel className="text-[11px] font-normal">
+                <Trans>Retry Timeouts (Default: On)</Trans>
+              </FormLabel>
+              <FormControl>
+                <Switch
+                  checked={field.value ?? true}
+                  onCheckedChange={field.onChange}
+                  className="scale-75 origin-right"
+                />
+              </FormControl>
+            </FormItem>
+          )}
+        />
+        <FormField
+          name={`${basePath}.retry.retry_on_server_error`}
+          render={({ field }) => (
+            <FormItem className="flex flex-row items-center justify-between rounded-lg border border-border/40 bg-muted/5 px-3 py-2 shadow-sm">
+              <FormLabel className="text-[11px] font-normal">
+                <Trans>Retry 5xx Responses (Default: On)</Trans>
+              </FormLabel>
+              <FormControl>
+                <Switch
+                  checked={field.value ?? true}
+                  onCheckedChange={field.onChange}
+                  className="scale-75 origin-right"
+                />
+              </FormControl>
+            </FormItem>
+          )}
+        />
+        <FormField
+          name={`${basePath}.retry.retry_on_rate_limited`}
+          render={({ field }) => (
+            <FormItem className="flex flex-row items-center justify-between rounded-lg border border-border/40 bg-muted/5 px-3 py-2 shadow-sm">
+              <FormLabel className="text-[11px] font-normal">
+                <Trans>Retry 429 Responses (Default: On)</Trans>
+              </FormLabel>
+              <FormControl>
+                <Switch
+                  checked={field.value ?? true}
+                  onCheckedChange={field.onChange}
+                  className="scale-75 origin-right"
+                />
+              </FormControl>
+            </FormItem>
+          )}
+        />
+        <FormField
+          name={`${basePath}.retry.retry_on_not_found`}
+          render={({ field }) => (
+            <FormItem className="flex flex-row items-center justify-between rounded-lg border border-border/40 bg-muted/5 px-3 py-2 shadow-sm">
+              <FormLabel className="text-[11px] font-normal">
+                <Trans>Retry 404/410 Responses (Default: Off)</Trans>
+              </FormLabel>
+              <FormControl>
+                <Switch
+                  checked={field.value ?? false}
+                  onCheckedChange={field.onChange}
+                  className="scale-75 origin-right"
+                />
+              </FormControl>
+            </FormItem>
+          )}
+        />
+      </div>
+      <p className="text-[10px] text-muted-foreground">
+        <Trans>
+          HLS retries a segment at most twice in place, then reschedules it
+          within the HLS lifecycle retry budget. HLS always retries 404s.
+        </Trans>
+      </p>
+    </CardContent>
+  </Card>
+));
+MesioRetrySettings.displayName = 'MesioRetrySettings';
+
+const MesioWatchdogSettings = React.memo(({ basePath }: SubFormProps) => (
+  <Card className="border-border/40 bg-background/40 shadow-sm">
+    <CardHeader className="pb-3 pt-4 px-4">
+      <CardTitle className="text-sm font-medium flex items-center gap-2">
+        <Timer className="w-4 h-4 text-primary" />
+        <Trans>Stall Watchdog</Trans>
+      </CardTitle>
+    </CardHeader>
+    <CardContent className="px-4 pb-4">
+      <div className="grid gap-4 sm:grid-cols-2">
+        <FormField
+          name={`${basePath}.watchdog.stall_timeout_secs`}
+          render={({ field }) => (
+            <FormItem>
+              <FormLabel className="text-xs">
+                <Trans>Stall Timeout (s)</Trans>
+              </FormLabel>
+              <FormControl>
+                <Input
+                  type="number"
+                  {...field}
+                  value={field.value ?? ''}
+                  className="h-8 text-xs font-mono"
+                  placeholder="Default: 120, 0 disables"
+                />
+              </FormControl>
+              <FormMessage />
+            </FormItem>
+          )}
+        />
+        <FormField
+          name={`${basePath}.watchdog.on_stall`}
+          render={({ field }) => (
+            <FormItem>
+              <FormLabel className="text-xs">
+                <Trans>On Stall</Trans>
+              </FormLabel>
+              <Select
+                onValueChange={field.onChange}
+                value={field.value ?? 'reconnect'}
+              >
+                <FormControl>
+                  <SelectTrigger className="h-8 text-xs">
+                    <SelectValue />
+                  </SelectTrigger>
+                </FormControl>
+                <SelectContent>
+                  <SelectItem value="reconnect">
+                    <Trans>Reconnect (Default)</Trans>
+                  </SelectItem>
+                  <SelectItem value="end_stream">
+                    <Trans>End the stream</Trans>
+                  </SelectItem>
+                </SelectContent>
+              </Select>
+              <FormMessage />
+            </FormItem>
+          )}
+        />
+      </div>
+    </CardContent>
+  </Card>
+));
+MesioWatchdogSettings.displayName = 'MesioWatchdogSettings';
+
 interface MesioFormProps {
   basePath?: string;
 }
@@ -325,7 +942,7 @@ export function MesioForm({ basePath = 'config' }: MesioFormProps) {
           </CardContent>
         </Card>
 
-        <div className="grid gap-4 md:grid-cols-2">
+        <div className="grid gap-4 md:grid-cols-3">
           <FormField
             name={`${basePath}.fix_flv`}
             render={({ field }) => (
@@ -370,7 +987,34 @@ export function MesioForm({ basePath = 'config' }: MesioFormProps) {
               </FormItem>
             )}
           />
+          <FormField
+            name={`${basePath}.keep_raw`}
+            render={({ field }) => (
+              <FormItem className="flex flex-row items-center justify-between rounded-xl border border-border/40 bg-gradient-to-br from-background/50 to-primary/5 p-4 shadow-sm transition-all hover:border-primary/20">
+                <div className="space-y-0.5">
+                  <FormLabel className="text-sm font-medium flex items-center gap-2">
+                    <Archive className="w-4 h-4 text-primary" />
+                    <Trans>Keep Raw Stream</Trans>
+                  </FormLabel>
+                  <FormDescription className="text-[10px]">
+                    <Trans>
+                      Also save the unprocessed stream next to the fixed file
+                    </Trans>
+                  </FormDescription>
+                </div>
+                <FormControl>
+                  <Switch
+                    checked={field.value ?? false}
+                    onCheckedChange={field.onChange}
+                  />
+                </FormControl>
+              </FormItem>
+            )}
+          />
         </div>
+
+        <MesioRetrySettings basePath={basePath} />
+        <MesioWatchdogSettings basePath={basePath} />
       </TabsContent>
 
       <TabsContent value="flv" className="mt-0 focus-visible:outline-none">
@@ -826,7 +826,120 @@ const HlsBaseSettings = React.memo(({ hlsPath }: SubFormProps) => (
       />
     </div>
 
+    <div className="grid gap-4 sm:grid-cols-2">
+      <FormField
+        name={`${hlsPath}.base.http2_keep_alive_timeout_ms`}
+        render={({ field }) => (
+          <FormItem>
+            <FormLabel className="text-xs">
+              <Trans>H2 Keep-Alive Timeout (ms)</Trans>
+            </FormLabel>
+            <FormControl>
+              <Input
+                type="number"
+                {...field}
+                className="h-8 text-xs font-mono"
+                placeholder="Default: 20000"
+              />
+            </FormControl>
+            <FormMessage />
+          </FormItem>
+        )}
+      />
+      <FormField
+        name={`${hlsPath}.base.tcp_keepalive_ms`}
+        render={({ field }) => (
+          <FormItem>
+            <FormLabel className="text-xs">
+              <Trans>TCP Keep-Alive (ms)</Trans>
+            </FormLabel>
+            <FormControl>
+              <Input
+                type="number"
+                {...field}
+                className="h-8 text-xs font-mono"
+                placeholder="Default: 20000, 0 disables"
+              />
+            </FormControl>
+            <FormMessage />
+          </FormItem>
+        )}
+      />
+      <FormField
+        name={`${hlsPath}.base.http2_initial_stream_window_size`}
+        render={({ field }) => (
+          <FormItem>
+            <FormLabel className="text-xs">
+              <Trans>H2 Stream Window (bytes)</Trans>
+            </FormLabel>
+            <FormControl>
+              <Input
+                type="number"
+                {...field}
+                className="h-8 text-xs font-mono"
+                placeholder="Default: auto"
+              />
+            </FormControl>
+            <FormMessage />
+          </FormItem>
+        )}
+      />
+      <FormField
+        name={`${hlsPath}.base.http2_initial_connection_window_size`}
+        render={({ field }) => (
+          <FormItem>
+            <FormLabel className="text-xs">
+              <Trans>H2 Connection Window (bytes)</Trans>
+            </FormLabel>
+            <FormControl>
+              <Input
+                type="number"
+                {...field}
+                className="h-8 text-xs font-mono"
+                placeholder="Default: auto"
+              />
+            </FormControl>
+            <FormMessage />
+          </FormItem>
+        )}
+      />
+    </div>
+
     <div className="grid gap-2 sm:grid-cols-2">
+      <FormField
+        name={`${hlsPath}.base.http2_adaptive_window`}
+        render={({ field }) => (
+          <FormItem className="flex flex-row items-center justify-between rounded-lg border border-border/40 bg-muted/5 px-3 py-2 shadow-sm">
+            <FormLabel className="text-[11px] font-normal">
+              <Trans>H2 Adaptive Window (Default: Off)</Trans>
+            </FormLabel>
+            <FormControl>
+              <Switch
+                checked={field.value ?? false}
+                onCheckedChange={field.onChange}
+                className="scale-75 origin-right"
+              />
+            </FormControl>
+          </FormItem>
+        )}
+      />
+      <FormField
+        name={`${hlsPath}.base.http2_keep_alive_while_idle`}
+        render={({ field }) => (
+          <FormItem className="flex flex-row items-center justify-between rounded-lg border border-border/40 bg-muted/5 px-3 py-2 shadow-sm">
+            <FormLabel className="text-[11px] font-normal">
+              <Trans>H2 Keep-Alive While Idle (Default: Off)</Trans>
+            </FormLabel>
+            <FormControl>
+              <Switch
+                checked={field.value ?? false}
+                onCheckedChange={field.onChange}
+                className="scale-75 origin-right"
+              />
+            </FormControl>
+          </FormItem>
+        )}
+      />
       <FormField
         name={`${hlsPath}.base.follow_redirects`}
         render={({ field }) => (
@@ -1051,6 +1164,31 @@ const HlsPlaylistSettings = React.memo(({ hlsPath }: SubFormProps) => (
             )}
           />
         </div>
+
+        <FormField
+          name={`${hlsPath}.playlist_config.low_latency_enabled`}
+          render={({ field }) => (
+            <FormItem className="flex flex-row items-center justify-between pt-2 border-t border-border/40">
+              <div className="space-y-0.5">
+                <FormLabel className="text-xs font-medium">
+                  <Trans>Low-Latency HLS (Default: Off)</Trans>
+                </FormLabel>
+                <FormDescription className="text-[10px]">
+                  <Trans>
+                    Download partial segments and use blocking playlist reload
+                  </Trans>
+                </FormDescription>
+              </div>
+              <FormControl>
+                <Switch
+                  checked={field.value ?? false}
+                  onCheckedChange={field.onChange}
+                  className="scale-75 origin-right"
+                />
+              </FormControl>
+            </FormItem>
+          )}
+        />
       </CardContent>
     </Card>
   </div>
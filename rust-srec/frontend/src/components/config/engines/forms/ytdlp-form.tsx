@@ -0,0 +1,138 @@
+import {
+  FormControl,
+  FormField,
+  FormItem,
+  FormLabel,
+  FormMessage,
+} from '@/components/ui/form';
+import { Input } from '@/components/ui/input';
+import { ListInput } from '@/components/ui/list-input';
+import { Card, CardContent, CardHeader, CardTitle } from '@/components/ui/card';
+import { Separator } from '@/components/ui/separator';
+import { Terminal, Settings, Command, TimerOff, Cookie } from 'lucide-react';
+import { msg } from '@lingui/core/macro';
+import { Trans } from '@lingui/react/macro';
+import { useLingui } from '@lingui/react';
+
+import { InputWithUnit } from '@/components/ui/input-with-unit';
+
+interface YtDlpFormProps {
+  basePath?: string;
+}
+
+export function YtDlpForm({ basePath = 'config' }: YtDlpFormProps) {
+  const { i18n } = useLingui();
+  return (
+    <div className="space-y-6">
+      <div className="grid gap-6 md:grid-cols-2">
+        <FormField
+          name={`${basePath}.binary_path`}
+          render={({ field }) => (
+            <FormItem>
+              <FormLabel className="flex items-center gap-2 text-xs uppercase tracking-wider text-muted-foreground font-semibold">
+                <Terminal className="w-3.5 h-3.5 text-primary" />
+                <Trans>Binary Path</Trans>
+              </FormLabel>
+              <FormControl>
+                <Input
+                  {...field}
+                  placeholder="yt-dlp"
+                  className="bg-background/50"
+                />
+              </FormControl>
+              <FormMessage />
+            </FormItem>
+          )}
+        />
+        <FormField
+          name={`${basePath}.format`}
+          render={({ field }) => (
+            <FormItem>
+              <FormLabel className="flex items-center gap-2 text-xs uppercase tracking-wider text-muted-foreground font-semibold">
+                <Settings className="w-3.5 h-3.5 text-primary" />
+                <Trans>Format</Trans>
+              </FormLabel>
+              <FormControl>
+                <Input
+                  {...field}
+                  placeholder={i18n._(msg`best`)}
+                  className="bg-background/50"
+                />
+              </FormControl>
+              <FormMessage />
+            </FormItem>
+          )}
+        />
+        <FormField
+          name={`${basePath}.cookies_file`}
+          render={({ field }) => (
+            <FormItem>
+              <FormLabel className="flex items-center gap-2 text-xs uppercase tracking-wider text-muted-foreground font-semibold">
+                <Cookie className="w-3.5 h-3.5 text-primary" />
+                <Trans>Cookies</Trans>
+              </FormLabel>
+              <FormControl>
+                <Input
+                  {...field}
+                  value={field.value ?? ''}
+                  placeholder="/data/cookies.txt"
+                  className="bg-background/50"
+                />
+              </FormControl>
+              <FormMessage />
+            </FormItem>
+          )}
+        />
+        <FormField
+          name={`${basePath}.graceful_stop_timeout_secs`}
+          render={({ field }) => (
+            <FormItem>
+              <FormLabel className="flex items-center gap-2 text-xs uppercase tracking-wider text-muted-foreground font-semibold">
+                <TimerOff className="w-3.5 h-3.5 text-primary" />
+                <Trans>Graceful Stop Timeout</Trans>
+              </FormLabel>
+              <FormControl>
+                <InputWithUnit
+                  value={field.value}
+                  onChange={field.onChange}
+                  unitType="duration"
+                  className="bg-background/50"
+                />
+              </FormControl>
+              <FormMessage />
+            </FormItem>
+          )}
+        />
+      </div>
+
+      <Separator className="bg-border/50" />
+
+      <Card className="border-border/40 bg-background/40 shadow-sm">
+        <CardHeader className="pb-3 pt-4 px-4">
+          <CardTitle className="text-sm font-medium flex items-center gap-2">
+            <Command className="w-4 h-4 text-primary" />
+            <Trans>Extra Arguments</Trans>
+          </CardTitle>
+        </CardHeader>
+        <CardContent className="px-4 pb-4">
+          <FormField
+            name={`${basePath}.extra_args`}
+            render={({ field }) => (
+              <FormItem>
+                <FormControl>
+                  <ListInput
+                    value={field.value}
+                    onChange={field.onChange}
+                    placeholder="--live-from-start"
+                    className="bg-background/50"
+                  />
+                </FormControl>
+                <FormMessage />
+              </FormItem>
+            )}
+          />
+        </CardContent>
+      </Card>
+    </div>
+  );
+}
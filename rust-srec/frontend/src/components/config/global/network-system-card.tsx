@@ -21,6 +21,7 @@ import {
   CircleHelp,
   Database,
   History,
+  Hourglass,
   Info,
   Layers,
   Network,
@@ -38,6 +39,7 @@ export const NetworkSystemCard = memo(() => {
   const allowPrivateTargetsLabel = i18n._(
     msg`Allow private stream proxy targets`,
   );
+  const adaptivePollingLabel = i18n._(msg`Adaptive polling`);
   return (
     <SettingsCard
       title={<Trans>Network & System</Trans>}
@@ -171,8 +173,63 @@ export const NetworkSystemCard = memo(() => {
               </FormItem>
             )}
           />
+          <FormField
+            name="offline_grace_secs"
+            render={({ field }) => (
+              <FormItem>
+                <FormLabel className="flex items-center gap-1.5">
+                  <Hourglass className="h-3.5 w-3.5 text-amber-500/80" />
+                  <Trans>Offline Grace</Trans>
+                  <Tooltip>
+                    <TooltipTrigger asChild>
+                      <CircleHelp className="h-3.5 w-3.5 text-muted-foreground/40 cursor-help hover:text-muted-foreground transition-colors" />
+                    </TooltipTrigger>
+                    <TooltipContent className="p-0 border-border/50 shadow-xl bg-background/95 backdrop-blur-md overflow-hidden">
+                      <StatusInfoTooltip
+                        icon={<Hourglass className="w-4 h-4" />}
+                        title={<Trans>Offline Grace</Trans>}
+                        theme="amber"
+                      >
+                        <p className="text-xs leading-relaxed text-muted-foreground">
+                          <Trans>
+                            Minimum time a live streamer must stay offline
+                            before the recording session ends, on top of the
+                            detection count. Short API or CDN hiccups within
+                            this time continue the same session. 0 disables
+                            it; at most 10 minutes.
+                          </Trans>
+                        </p>
+                      </StatusInfoTooltip>
+                    </TooltipContent>
+                  </Tooltip>
+                </FormLabel>
+                <FormControl>
+                  <InputWithUnit
+                    unitType="duration"
+                    value={field.value ?? 0}
+                    onChange={(val) => field.onChange(val ?? 0)}
+                    placeholder="0"
+                  />
+                </FormControl>
+                <FormMessage />
+              </FormItem>
+            )}
+          />
         </div>
 
+        <FlagFormField
+          fieldName="adaptive_polling"
+          title={<Trans>Adaptive Polling</Trans>}
+          ariaLabel={adaptivePollingLabel}
+          description={
+            <Trans>
+              Learn when each streamer usually goes live from recent sessions.
+              Checks run twice as often around those times and up to four
+              times less often during hours the streamer has not gone live.
+            </Trans>
+          }
+        />
+
         <Separator className="bg-white/5" />
 
         {/* Persistence Section */}
@@ -8,7 +8,15 @@ import {
   FormLabel,
   FormMessage,
 } from '@/components/ui/form';
+import { Input } from '@/components/ui/input';
 import { InputWithUnit } from '@/components/ui/input-with-unit';
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from '@/components/ui/select';
 import { HardDrive } from 'lucide-react';
 import { Trans } from '@lingui/react/macro';
 
@@ -82,6 +90,155 @@ export const ResourceLimitsCard = memo(() => {
             </FormItem>
           )}
         />
+        <FormField
+          name="low_disk_space_threshold_bytes"
+          render={({ field }) => (
+            <FormItem>
+              <FormLabel>
+                <Trans>Min Free Space</Trans>
+              </FormLabel>
+              <FormControl>
+                <InputWithUnit
+                  unitType="size"
+                  value={field.value}
+                  onChange={field.onChange}
+                  placeholder="0"
+                />
+              </FormControl>
+              <FormDescription className="text-xs">
+                <Trans>
+                  Free space on the output volume below which the low disk
+                  space action applies. 0 = Disabled
+                </Trans>
+              </FormDescription>
+              <FormMessage />
+            </FormItem>
+          )}
+        />
+        <FormField
+          name="low_disk_space_action"
+          render={({ field }) => (
+            <FormItem>
+              <FormLabel>
+                <Trans>Low Disk Space Action</Trans>
+              </FormLabel>
+              <Select onValueChange={field.onChange} value={field.value}>
+                <FormControl>
+                  <SelectTrigger>
+                    <SelectValue />
+                  </SelectTrigger>
+                </FormControl>
+                <SelectContent>
+                  <SelectItem value="stop_new">
+                    <Trans>Stop new recordings</Trans>
+                  </SelectItem>
+                  <SelectItem value="finalize">
+                    <Trans>Finalize current files</Trans>
+                  </SelectItem>
+                  <SelectItem value="switch_folder">
+                    <Trans>Switch to secondary folder</Trans>
+                  </SelectItem>
+                </SelectContent>
+              </Select>
+              <FormMessage />
+            </FormItem>
+          )}
+        />
+        <FormField
+          name="low_disk_space_secondary_folder"
+          render={({ field }) => (
+            <FormItem>
+              <FormLabel>
+                <Trans>Secondary Output Folder</Trans>
+              </FormLabel>
+              <FormControl>
+                <Input
+                  placeholder="/path/to/spare/disk"
+                  {...field}
+                  value={field.value ?? ''}
+                />
+              </FormControl>
+              <FormDescription className="text-xs">
+                <Trans>
+                  Recordings moved here keep their layout below the output
+                  folder.
+                </Trans>
+              </FormDescription>
+              <FormMessage />
+            </FormItem>
+          )}
+        />
+        <div className="space-y-2">
+          <div className="grid grid-cols-3 gap-4">
+            <FormField
+              name="load_admission_max_cpu_percent"
+              render={({ field }) => (
+                <FormItem>
+                  <FormLabel>
+                    <Trans>Max CPU Load (%)</Trans>
+                  </FormLabel>
+                  <FormControl>
+                    <Input
+                      type="number"
+                      min={0}
+                      max={100}
+                      {...field}
+                      onChange={(e) => field.onChange(Number(e.target.value))}
+                    />
+                  </FormControl>
+                  <FormMessage />
+                </FormItem>
+              )}
+            />
+            <FormField
+              name="load_admission_max_memory_percent"
+              render={({ field }) => (
+                <FormItem>
+                  <FormLabel>
+                    <Trans>Max Memory Load (%)</Trans>
+                  </FormLabel>
+                  <FormControl>
+                    <Input
+                      type="number"
+                      min={0}
+                      max={100}
+                      {...field}
+                      onChange={(e) => field.onChange(Number(e.target.value))}
+                    />
+                  </FormControl>
+                  <FormMessage />
+                </FormItem>
+              )}
+            />
+            <FormField
+              name="load_admission_max_io_pressure_percent"
+              render={({ field }) => (
+                <FormItem>
+                  <FormLabel>
+                    <Trans>Max I/O Pressure (%)</Trans>
+                  </FormLabel>
+                  <FormControl>
+                    <Input
+                      type="number"
+                      min={0}
+                      max={100}
+                      {...field}
+                      onChange={(e) => field.onChange(Number(e.target.value))}
+                    />
+                  </FormControl>
+                  <FormMessage />
+                </FormItem>
+              )}
+            />
+          </div>
+          <p className="text-xs text-muted-foreground">
+            <Trans>
+              New recordings wait while the host is above any of these limits;
+              running recordings continue. I/O pressure is only available on
+              Linux. 0 = Disabled
+            </Trans>
+          </p>
+        </div>
       </div>
     </SettingsCard>
   );
@@ -61,6 +61,7 @@ export function PlatformEditor({
       pipeline: platform.pipeline,
       session_complete_pipeline: platform.session_complete_pipeline,
       paired_segment_pipeline: platform.paired_segment_pipeline,
+      rate_limit: platform.rate_limit,
     },
   });
   const { reset } = form;
@@ -87,6 +88,7 @@ export function PlatformEditor({
       pipeline: platform.pipeline,
       session_complete_pipeline: platform.session_complete_pipeline,
       paired_segment_pipeline: platform.paired_segment_pipeline,
+      rate_limit: platform.rate_limit,
     });
   }, [platform, reset]);
 
@@ -11,6 +11,7 @@ import { Trans } from '@lingui/react/macro';
 import { msg } from '@lingui/core/macro';
 import { useLingui } from '@lingui/react';
 import { Clock } from 'lucide-react';
+import { Input } from '@/components/ui/input';
 import { InputWithUnit } from '@/components/ui/input-with-unit';
 import { UseFormReturn } from 'react-hook-form';
 import { Card, CardContent, CardHeader, CardTitle } from '@/components/ui/card';
@@ -99,6 +100,41 @@ export function GeneralTab({ form, basePath }: GeneralTabProps) {
               </FormItem>
             )}
           />
+          {!basePath && (
+            <FormField
+              control={form.control}
+              name="rate_limit"
+              render={({ field }) => (
+                <FormItem>
+                  <FormLabel>
+                    <Trans>Request Rate Limit</Trans>
+                  </FormLabel>
+                  <FormControl>
+                    <Input
+                      type="number"
+                      min={0.01}
+                      step={0.1}
+                      value={field.value ?? ''}
+                      onChange={(e) => {
+                        const v = e.target.value;
+                        field.onChange(v === '' ? null : Number(v));
+                      }}
+                      placeholder={i18n._(msg`Default`)}
+                      className="bg-background"
+                    />
+                  </FormControl>
+                  <FormDescription>
+                    <Trans>
+                      Status checks per second across all streamers on this
+                      platform. Slows down automatically when the platform
+                      answers with HTTP 429.
+                    </Trans>
+                  </FormDescription>
+                  <FormMessage />
+                </FormItem>
+              )}
+            />
+          )}
         </CardContent>
       </Card>
     </div>
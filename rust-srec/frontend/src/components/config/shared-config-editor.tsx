@@ -49,6 +49,12 @@ export interface SharedConfigPaths {
   output: string;
   // Limit settings base path (duration, sizes)
   limits: string;
+  // Wall-clock split interval path; only streamers support it. Omit to hide
+  // the field.
+  wallClockSplit?: string;
+  // Replay backfill switch path; only streamers support it. Omit to hide the
+  // field.
+  vodBackfill?: string;
   // Danmu settings base path (record_danmu)
   danmu: string;
   // Danmu sampling config path
@@ -282,10 +288,12 @@ export function SharedConfigEditor<T extends FieldValues>({
                 form={form}
                 basePath={paths.output === '' ? undefined : paths.output}
                 engines={engines}
+                vodBackfillPath={paths.vodBackfill}
               />
               <LimitsCard
                 form={form}
                 basePath={paths.limits === '' ? undefined : paths.limits}
+                wallClockSplitPath={paths.wallClockSplit}
               />
             </motion.div>
           </TabsContent>
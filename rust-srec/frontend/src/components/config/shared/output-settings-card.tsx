@@ -8,6 +8,7 @@ import {
   FormMessage,
 } from '@/components/ui/form';
 import { Input } from '@/components/ui/input';
+import { Switch } from '@/components/ui/switch';
 import {
   Select,
   SelectContent,
@@ -27,10 +28,11 @@ interface OutputSettingsCardProps {
   form: UseFormReturn<any>;
   basePath?: string;
   engines?: EngineConfig[];
+  vodBackfillPath?: string;
 }
 
 export const OutputSettingsCard = memo(
-  ({ form, basePath, engines }: OutputSettingsCardProps) => {
+  ({ form, basePath, engines, vodBackfillPath }: OutputSettingsCardProps) => {
     const { i18n } = useLingui();
     return (
       <Card className="border-border/50 shadow-sm hover:shadow-md transition-all">
@@ -192,6 +194,33 @@ export const OutputSettingsCard = memo(
               )}
             />
           </div>
+          {vodBackfillPath && (
+            <FormField
+              control={form.control}
+              name={vodBackfillPath}
+              render={({ field }) => (
+                <FormItem className="flex flex-row items-center justify-between rounded-xl border p-4 shadow-sm bg-muted/30">
+                  <div className="space-y-0.5 pr-4">
+                    <FormLabel className="text-base font-medium">
+                      <Trans>Backfill From Replay</Trans>
+                    </FormLabel>
+                    <FormDescription>
+                      <Trans>
+                        Record the start of a broadcast detected late from the
+                        platform replay, as a separate session.
+                      </Trans>
+                    </FormDescription>
+                  </div>
+                  <FormControl>
+                    <Switch
+                      checked={field.value ?? false}
+                      onCheckedChange={field.onChange}
+                    />
+                  </FormControl>
+                </FormItem>
+              )}
+            />
+          )}
         </CardContent>
       </Card>
     );
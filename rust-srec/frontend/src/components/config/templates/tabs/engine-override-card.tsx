@@ -5,6 +5,7 @@ import { Trash2 } from 'lucide-react';
 import { FfmpegForm } from '../../engines/forms/ffmpeg-form';
 import { StreamlinkForm } from '../../engines/forms/streamlink-form';
 import { MesioForm } from '../../engines/forms/mesio-form';
+import { YtDlpForm } from '../../engines/forms/ytdlp-form';
 
 interface EngineOverrideCardProps {
   engineId: string;
@@ -29,6 +30,8 @@ export function EngineOverrideCard({
         return <StreamlinkForm basePath={basePath} />;
       case 'MESIO':
         return <MesioForm basePath={basePath} />;
+      case 'YTDLP':
+        return <YtDlpForm basePath={basePath} />;
       default:
         return (
           <div className="text-muted-foreground p-4">
@@ -39,6 +39,7 @@ import {
   FileCheck,
   Ban,
   AlertOctagon,
+  ArrowDownUp,
   Workflow,
   CheckSquare,
   XSquare,
@@ -48,6 +49,7 @@ import {
   Cpu,
   AlertTriangle,
   Settings,
+  MessageSquareWarning,
 } from 'lucide-react';
 import { Input } from '@/components/ui/input';
 import { motion } from 'motion/react';
@@ -93,6 +95,12 @@ const getEventIcon = (eventType: string) => {
       return { icon: Ban, color: 'text-orange-500', bg: 'bg-orange-500/10' };
     case 'download_rejected':
       return { icon: AlertOctagon, color: 'text-red-500', bg: 'bg-red-500/10' };
+    case 'download_preempted':
+      return {
+        icon: ArrowDownUp,
+        color: 'text-amber-500',
+        bg: 'bg-amber-500/10',
+      };
 
     // Pipeline
     case 'pipeline_started':
@@ -135,6 +143,12 @@ const getEventIcon = (eventType: string) => {
       };
     case 'config_updated':
       return { icon: Settings, color: 'text-gray-500', bg: 'bg-gray-500/10' };
+    case 'danmu_collection_degraded':
+      return {
+        icon: MessageSquareWarning,
+        color: 'text-orange-500',
+        bg: 'bg-orange-500/10',
+      };
 
     default:
       return { icon: BellRing, color: 'text-gray-400', bg: 'bg-gray-500/10' };
@@ -163,10 +177,18 @@ const getEventDescription = (eventType: string, i18n: any) => {
       return i18n._(
         msg`Triggered when a download is rejected (e.g., circuit breaker).`,
       );
+    case 'download_preempted':
+      return i18n._(
+        msg`Triggered when a recording is finalized to free a slot for a higher-priority streamer.`,
+      );
     case 'config_updated':
       return i18n._(
         msg`Triggered when streamer configuration is dynamically updated.`,
       );
+    case 'danmu_collection_degraded':
+      return i18n._(
+        msg`Triggered when danmu collection keeps reconnecting or gives up.`,
+      );
     case 'pipeline_started':
       return i18n._(msg`Triggered when a post-processing pipeline job starts.`);
     case 'pipeline_completed':
@@ -100,6 +100,231 @@ export const RcloneConfigSchema = z.object({
   multi_thread_cutoff: z.string().optional(),
 });
 
+// --- S3 Processor ---
+export const S3ConfigSchema = z.object({
+  endpoint: z.string().optional(),
+  region: z.string().default('us-east-1'),
+  bucket: z.string().min(1),
+  access_key_id: z.string().optional(),
+  secret_access_key: z.string().optional(),
+  session_token: z.string().optional(),
+  path_style: z.boolean().default(false),
+  key_prefix: z.string().optional(),
+  time_anchor: TimeAnchorSchema.optional(),
+  storage_class: z.string().optional(),
+  part_size_mb: z.number().int().min(5).default(16),
+  max_retries: z.number().int().min(0).max(10).default(3),
+  checksum: z.boolean().default(true),
+  verify_upload: z.boolean().default(true),
+  delete_source_on_success: z.boolean().default(false),
+});
+
+// --- YouTube Upload Processor ---
+export const YouTubeConfigSchema = z.object({
+  account: z.string().min(1),
+  title: z.string().default('{streamer} - {title} %Y-%m-%d'),
+  description: z.string().default(''),
+  tags: z.array(z.string()).default([]),
+  category_id: z.string().default('22'),
+  privacy_status: z.enum(['private', 'unlisted', 'public']).default('private'),
+  made_for_kids: z.boolean().default(false),
+  playlist_id: z.string().optional(),
+  time_anchor: TimeAnchorSchema.optional(),
+  chunk_size_mb: z.number().int().min(1).default(8),
+  max_retries: z.number().int().min(0).default(3),
+  delete_source_on_success: z.boolean().default(false),
+});
+
+// --- Bilibili Upload Processor ---
+export const BilibiliUploadConfigSchema = z.object({
+  credential_template: z.string().optional(),
+  title: z.string().default('【直播录像】{streamer} %Y-%m-%d {title}'),
+  description: z.string().default(''),
+  tags: z.array(z.string()).default(['直播录像']),
+  tid: z.number().int().positive().default(21),
+  original: z.boolean().default(false),
+  source: z.string().default('{platform} {streamer}'),
+  dynamic: z.string().default(''),
+  cover: z.boolean().default(true),
+  line: z.string().default('bda2'),
+  time_anchor: TimeAnchorSchema.optional(),
+  max_retries: z.number().int().min(0).default(3),
+  delete_source_on_success: z.boolean().default(false),
+});
+
+// --- Webhook Processor ---
+export const WebhookConfigSchema = z.object({
+  url: z.string().url(),
+  method: z.enum(['POST', 'PUT']).default('POST'),
+  headers: z.array(z.tuple([z.string(), z.string()])).default([]),
+  auth: z
+    .discriminatedUnion('type', [
+      z.object({ type: z.literal('Bearer'), token: z.string() }),
+      z.object({
+        type: z.literal('Basic'),
+        username: z.string(),
+        password: z.string(),
+      }),
+      z.object({ type: z.literal('Header'), name: z.string(), value: z.string() }),
+    ])
+    .optional(),
+  extra: z.record(z.string(), z.unknown()).default({}),
+  checksums: z.boolean().default(true),
+  wait_for_success: z.boolean().default(true),
+  success_pointer: z.string().optional(),
+  timeout_secs: z.number().int().positive().default(30),
+  max_retries: z.number().int().min(0).default(3),
+  time_anchor: TimeAnchorSchema.optional(),
+});
+
+// --- FFmpeg Processor ---
+export const FfmpegConfigSchema = z.object({
+  input_args: z.array(z.string()).default([]),
+  video_filter: z.string().optional(),
+  audio_filter: z.string().optional(),
+  filter_complex: z.string().optional(),
+  output_args: z.array(z.string()).default([]),
+  output_name: z.string().default('{input_stem}_ffmpeg'),
+  output_extension: z.string().optional(),
+  output_dir: z.string().optional(),
+  overwrite: z.boolean().default(true),
+  remove_input_on_success: z.boolean().default(false),
+  time_anchor: TimeAnchorSchema.optional(),
+});
+
+// --- Concat Processor ---
+export const ConcatConfigSchema = z.object({
+  method: z.enum(['ffmpeg', 'native']).default('ffmpeg'),
+  output_name: z.string().default('{input_stem}_merged'),
+  output_extension: z.string().optional(),
+  output_dir: z.string().optional(),
+  remove_inputs_on_success: z.boolean().default(false),
+  time_anchor: TimeAnchorSchema.optional(),
+});
+
+// --- Danmu Chapters Processor ---
+export const DanmuChaptersConfigSchema = z.object({
+  formats: z
+    .array(z.enum(['ffmetadata', 'youtube', 'embed']))
+    .min(1)
+    .default(['ffmetadata', 'youtube']),
+  window_secs: z.number().min(1).default(30),
+  sensitivity: z.number().min(0).default(3),
+  min_score: z.number().min(0).default(20),
+  keywords: z.array(z.string()).default([]),
+  keyword_weight: z.number().min(0).default(3),
+  include_super_chats: z.boolean().default(true),
+  include_gifts: z.boolean().default(false),
+  lead_secs: z.number().min(0).default(10),
+  min_gap_secs: z.number().min(0).default(60),
+  max_chapters: z.number().int().min(0).default(20),
+  start_title: z.string().default('Start'),
+  max_title_chars: z.number().int().positive().default(30),
+  embed_extension: z.string().optional(),
+});
+
+// --- Danmu Subtitle Processor ---
+export const DanmuSubtitleConfigSchema = z.object({
+  format: z.enum(['ass', 'srt']).default('ass'),
+  width: z.number().int().positive().default(1920),
+  height: z.number().int().positive().default(1080),
+  font_name: z.string().default('Microsoft YaHei'),
+  font_size: z.number().int().positive().default(38),
+  bold: z.boolean().default(false),
+  outline: z.number().min(0).default(1),
+  shadow: z.number().min(0).default(0),
+  opacity: z.number().min(0).max(1).default(0.8),
+  line_spacing: z.number().int().min(0).default(4),
+  scroll_duration_secs: z.number().positive().default(12),
+  fixed_duration_secs: z.number().positive().default(5),
+  scroll_area: z.number().min(0.05).max(1).default(0.8),
+  scroll_fixed: z.boolean().default(false),
+  allow_overlap: z.boolean().default(false),
+  include_super_chats: z.boolean().default(true),
+  include_gifts: z.boolean().default(false),
+  blocklist: z.array(z.string()).default([]),
+  time_offset_secs: z.number().default(0),
+  srt_max_lines: z.number().int().positive().default(3),
+  overwrite: z.boolean().default(true),
+  passthrough_inputs: z.boolean().default(true),
+  delete_source_xml_on_success: z.boolean().default(false),
+});
+
+// --- Checksum Processor ---
+export const ChecksumConfigSchema = z.object({
+  algorithms: z
+    .array(z.enum(['sha256', 'blake3']))
+    .min(1)
+    .default(['sha256']),
+  write_sidecars: z.boolean().default(true),
+  sidecars_in_outputs: z.boolean().default(true),
+  include_non_media: z.boolean().default(true),
+});
+
+// --- Encrypt Processor ---
+export const EncryptConfigSchema = z.object({
+  method: z.enum(['aes-gcm', 'age']).default('aes-gcm'),
+  key_env: z.string().optional(),
+  key_file: z.string().optional(),
+  chunk_size_kb: z.number().int().min(1).max(65536).default(1024),
+  recipients: z.array(z.string()).default([]),
+  recipients_file: z.string().optional(),
+  age_path: z.string().optional(),
+  manifest_in_outputs: z.boolean().default(true),
+  delete_source: z.boolean().default(false),
+});
+
+// --- Torrent Processor ---
+export const TorrentConfigSchema = z.object({
+  version: z.enum(['v1', 'v2', 'hybrid']).default('hybrid'),
+  trackers: z.array(z.string()).default([]),
+  web_seeds: z.array(z.string()).default([]),
+  piece_size_kb: z.number().int().min(16).optional(),
+  private: z.boolean().default(false),
+  comment: z.string().optional(),
+  source: z.string().optional(),
+  bundle: z.boolean().default(false),
+  bundle_name: z.string().optional(),
+  output_dir: z.string().optional(),
+  include_non_media: z.boolean().default(false),
+  torrents_in_outputs: z.boolean().default(true),
+});
+
+// --- NFO Processor ---
+export const NfoConfigSchema = z.object({
+  library_root: z.string().optional(),
+  copy: z.boolean().default(false),
+  series_title: z.string().default('{streamer}'),
+  episode_title: z.string().default('{title}'),
+  plot: z.string().optional(),
+  time_anchor: TimeAnchorSchema.default('session_start'),
+  write_tvshow_nfo: z.boolean().default(true),
+  series_poster: z.boolean().default(true),
+});
+
+// --- Highlights Processor ---
+export const HighlightsConfigSchema = z.object({
+  window_secs: z.number().min(1).default(10),
+  audio: z.boolean().default(true),
+  loudness_spike_db: z.number().positive().default(8),
+  audio_weight: z.number().min(0).default(1),
+  scenes: z.boolean().default(true),
+  scene_threshold: z.number().min(0).max(100).default(10),
+  scene_cuts_per_window: z.number().positive().default(3),
+  scene_weight: z.number().min(0).default(0.5),
+  danmu: z.boolean().default(true),
+  danmu_rate_multiplier: z.number().min(1).default(3),
+  min_danmu_per_window: z.number().int().min(0).default(5),
+  danmu_weight: z.number().min(0).default(1),
+  min_score: z.number().min(0).max(1).default(0.6),
+  pre_roll_secs: z.number().min(0).default(10),
+  post_roll_secs: z.number().min(0).default(5),
+  max_highlight_secs: z.number().positive().default(120),
+  max_highlights: z.number().int().positive().default(10),
+  write_clips: z.boolean().default(false),
+  clip_copy: z.boolean().default(true),
+});
+
 // --- Thumbnail Processor ---
 export const ThumbnailConfigSchema = z.object({
   timestamp_secs: z.number().min(0).default(10),
@@ -170,7 +170,7 @@ export const SessionCard = memo(
               ) : (
                 <div className="px-1.5 py-0.5 rounded-full bg-muted/10 border border-white/5">
                   <span className="text-[7.5px] font-black tracking-widest text-muted-foreground/50">
-                    ENDED
+                    {session.kind === 'backfill' ? 'BACKFILL' : 'ENDED'}
                   </span>
                 </div>
               )}
@@ -43,6 +43,7 @@ interface SessionHeaderProps {
     id: string;
     title?: string | null;
     end_time?: string | null;
+    kind?: string;
   };
 }
 import { useLingui } from '@lingui/react';
@@ -108,6 +109,14 @@ export function SessionHeader({ session }: SessionHeaderProps) {
               >
                 {i18n._(statusConfig.label)}
               </Badge>
+              {session.kind === 'backfill' && (
+                <Badge
+                  variant="outline"
+                  className="border bg-background/50 backdrop-blur font-mono text-[10px] md:text-xs uppercase tracking-wider h-5 md:h-6 border-blue-500/20 text-blue-500"
+                >
+                  <Trans>Backfill</Trans>
+                </Badge>
+              )}
             </div>
             <div className="flex flex-wrap items-center gap-2 text-sm text-muted-foreground font-medium">
               <span className="flex items-center gap-1.5 px-2 py-0.5 rounded-md bg-muted/50 border border-border/50">
@@ -0,0 +1,179 @@
+import { Button } from '@/components/ui/button';
+import { Input } from '@/components/ui/input';
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from '@/components/ui/select';
+import { Trans } from '@lingui/react/macro';
+import { msg } from '@lingui/core/macro';
+import { useLingui } from '@lingui/react';
+import { Plus, X } from 'lucide-react';
+
+export type StreamScoringRule =
+  | { rule: 'prefer_codec'; codec: string; weight?: number }
+  | { rule: 'min_height'; height: number; weight?: number }
+  | { rule: 'max_height'; height: number; weight?: number }
+  | { rule: 'min_fps'; fps: number; weight?: number }
+  | { rule: 'max_bitrate'; bitrate: number; weight?: number }
+  | { rule: 'ban_cdn'; cdn: string };
+
+type RuleKind = StreamScoringRule['rule'];
+
+const DEFAULT_RULES: Record<RuleKind, StreamScoringRule> = {
+  prefer_codec: { rule: 'prefer_codec', codec: 'avc', weight: 1 },
+  min_height: { rule: 'min_height', height: 720, weight: 1 },
+  max_height: { rule: 'max_height', height: 1080, weight: 1 },
+  min_fps: { rule: 'min_fps', fps: 60, weight: 1 },
+  max_bitrate: { rule: 'max_bitrate', bitrate: 8000000, weight: 1 },
+  ban_cdn: { rule: 'ban_cdn', cdn: '' },
+};
+
+export interface StreamScoringRulesInputProps {
+  value?: StreamScoringRule[];
+  onChange: (value: StreamScoringRule[]) => void;
+}
+
+export function StreamScoringRulesInput({
+  value = [],
+  onChange,
+}: StreamScoringRulesInputProps) {
+  const { i18n } = useLingui();
+
+  const ruleLabels: Record<RuleKind, string> = {
+    prefer_codec: i18n._(msg`Prefer codec`),
+    min_height: i18n._(msg`Min height`),
+    max_height: i18n._(msg`Max height`),
+    min_fps: i18n._(msg`Min FPS`),
+    max_bitrate: i18n._(msg`Max bitrate (bps)`),
+    ban_cdn: i18n._(msg`Ban CDN`),
+  };
+
+  const updateRule = (index: number, rule: StreamScoringRule) => {
+    onChange(value.map((r, i) => (i === index ? rule : r)));
+  };
+
+  const removeRule = (index: number) => {
+    onChange(value.filter((_, i) => i !== index));
+  };
+
+  const renderValueInput = (rule: StreamScoringRule, index: number) => {
+    switch (rule.rule) {
+      case 'prefer_codec':
+        return (
+          <Input
+            value={rule.codec}
+            onChange={(e) =>
+              updateRule(index, { ...rule, codec: e.target.value })
+            }
+            placeholder={i18n._(msg`e.g. avc, hevc, av1`)}
+            className="bg-background"
+          />
+        );
+      case 'ban_cdn':
+        return (
+          <Input
+            value={rule.cdn}
+            onChange={(e) => updateRule(index, { ...rule, cdn: e.target.value })}
+            placeholder={i18n._(msg`e.g. cdn-to-avoid`)}
+            className="bg-background"
+          />
+        );
+      case 'min_height':
+      case 'max_height':
+        return (
+          <Input
+            type="number"
+            min={0}
+            value={rule.height}
+            onChange={(e) =>
+              updateRule(index, { ...rule, height: Number(e.target.value) })
+            }
+            className="bg-background"
+          />
+        );
+      case 'min_fps':
+        return (
+          <Input
+            type="number"
+            min={0}
+            value={rule.fps}
+            onChange={(e) =>
+              updateRule(index, { ...rule, fps: Number(e.target.value) })
+            }
+            className="bg-background"
+          />
+        );
+      case 'max_bitrate':
+        return (
+          <Input
+            type="number"
+            min={0}
+            value={rule.bitrate}
+            onChange={(e) =>
+              updateRule(index, { ...rule, bitrate: Number(e.target.value) })
+            }
+            className="bg-background"
+          />
+        );
+    }
+  };
+
+  return (
+    <div className="space-y-3">
+      {value.map((rule, index) => (
+        <div key={index} className="flex items-center gap-2">
+          <Select
+            value={rule.rule}
+            onValueChange={(kind) =>
+              updateRule(index, DEFAULT_RULES[kind as RuleKind])
+            }
+          >
+            <SelectTrigger className="w-[180px] shrink-0 bg-background">
+              <SelectValue />
+            </SelectTrigger>
+            <SelectContent>
+              {(Object.keys(ruleLabels) as RuleKind[]).map((kind) => (
+                <SelectItem key={kind} value={kind}>
+                  {ruleLabels[kind]}
+                </SelectItem>
+              ))}
+            </SelectContent>
+          </Select>
+          <div className="flex-grow">{renderValueInput(rule, index)}</div>
+          {rule.rule !== 'ban_cdn' && (
+            <Input
+              type="number"
+              value={rule.weight ?? 1}
+              onChange={(e) =>
+                updateRule(index, { ...rule, weight: Number(e.target.value) })
+              }
+              title={i18n._(msg`Weight`)}
+              className="w-20 shrink-0 bg-background"
+            />
+          )}
+          <Button
+            type="button"
+            variant="ghost"
+            size="icon"
+            className="shrink-0"
+            onClick={() => removeRule(index)}
+          >
+            <X className="h-4 w-4" />
+          </Button>
+        </div>
+      ))}
+      <Button
+        type="button"
+        variant="secondary"
+        size="sm"
+        onClick={() => onChange([...value, DEFAULT_RULES.max_height])}
+      >
+        <Plus className="h-4 w-4 mr-1" />
+        <Trans>Add rule</Trans>
+      </Button>
+    </div>
+  );
+}
@@ -5,12 +5,17 @@ import {
   FormLabel,
 } from '@/components/ui/form';
 import { Input } from '@/components/ui/input';
+import { Switch } from '@/components/ui/switch';
 import { TagInput } from '@/components/ui/tag-input';
 import { Trans } from '@lingui/react/macro';
 import { msg } from '@lingui/core/macro';
 import { useLingui } from '@lingui/react';
-import { Filter, Zap } from 'lucide-react';
+import { Filter, Gauge, Scale, Zap } from 'lucide-react';
 import { Card, CardContent, CardHeader, CardTitle } from '@/components/ui/card';
+import {
+  StreamScoringRule,
+  StreamScoringRulesInput,
+} from './stream-scoring-rules-input';
 
 export interface StreamSelectionConfig {
   preferred_qualities?: string[];
@@ -19,6 +24,9 @@ export interface StreamSelectionConfig {
   blacklisted_cdns?: string[];
   min_bitrate?: number;
   max_bitrate?: number;
+  rules?: StreamScoringRule[];
+  probe_cdns?: boolean;
+  probe_timeout_ms?: number;
 }
 
 export interface StreamSelectionInputProps {
@@ -206,6 +214,95 @@ export function StreamSelectionInput({
           </FormItem>
         </CardContent>
       </Card>
+
+      <Card className="border-border/50 shadow-sm hover:shadow-md transition-all">
+        <CardHeader className="pb-3">
+          <div className="flex items-center gap-3">
+            <div className="p-2 rounded-lg bg-blue-500/10 text-blue-600 dark:text-blue-400">
+              <Scale className="w-5 h-5" />
+            </div>
+            <CardTitle className="text-lg">
+              <Trans>Scoring Rules</Trans>
+            </CardTitle>
+          </div>
+        </CardHeader>
+        <CardContent className="space-y-3">
+          <StreamScoringRulesInput
+            value={value.rules || []}
+            onChange={(rules) => updateField('rules', rules)}
+          />
+          <FormDescription>
+            <Trans>
+              Each stream scores the weights of the rules it meets, and the
+              highest score wins. Preferences above only break ties. Ban CDN
+              rules exclude streams entirely.
+            </Trans>
+          </FormDescription>
+        </CardContent>
+      </Card>
+
+      <Card className="border-border/50 shadow-sm hover:shadow-md transition-all">
+        <CardHeader className="pb-3">
+          <div className="flex items-center gap-3">
+            <div className="p-2 rounded-lg bg-green-500/10 text-green-600 dark:text-green-400">
+              <Gauge className="w-5 h-5" />
+            </div>
+            <CardTitle className="text-lg">
+              <Trans>CDN Probing</Trans>
+            </CardTitle>
+          </div>
+        </CardHeader>
+        <CardContent className="grid grid-cols-1 sm:grid-cols-2 gap-6">
+          <FormItem className="flex flex-row items-center justify-between rounded-xl border border-border/40 p-4 bg-background/50">
+            <div className="space-y-0.5 pr-4">
+              <FormLabel>
+                <Trans>Probe CDNs</Trans>
+              </FormLabel>
+              <FormDescription>
+                <Trans>
+                  Request each CDN before recording and prefer the one that
+                  answers fastest. Unreachable CDNs are tried last.
+                </Trans>
+              </FormDescription>
+            </div>
+            <FormControl>
+              <Switch
+                checked={value.probe_cdns ?? false}
+                onCheckedChange={(checked) =>
+                  updateField('probe_cdns', checked)
+                }
+              />
+            </FormControl>
+          </FormItem>
+
+          <FormItem>
+            <FormLabel>
+              <Trans>Probe Timeout (ms)</Trans>
+            </FormLabel>
+            <FormControl>
+              <Input
+                type="number"
+                min={0}
+                value={value.probe_timeout_ms ?? ''}
+                onChange={(e) =>
+                  updateField(
+                    'probe_timeout_ms',
+                    e.target.value ? Number(e.target.value) : undefined,
+                  )
+                }
+                placeholder="1500"
+                className="bg-background"
+              />
+            </FormControl>
+            <FormDescription>
+              <Trans>
+                How long a CDN gets to send its first byte before it counts as
+                unreachable.
+              </Trans>
+            </FormDescription>
+          </FormItem>
+        </CardContent>
+      </Card>
     </div>
   );
 }
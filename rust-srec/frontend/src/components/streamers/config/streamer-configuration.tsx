@@ -30,6 +30,8 @@ export function StreamerConfiguration({
         retryPolicy: `${basePath}.download_retry_policy`,
         output: basePath, // output_folder etc are in structure
         limits: basePath, // limits are in structure
+        wallClockSplit: `${basePath}.wall_clock_split_secs`,
+        vodBackfill: `${basePath}.vod_backfill`,
         danmu: basePath, // record_danmu is in structure
         danmuSampling: `${basePath}.danmu_sampling_config`,
         hooks: `${basePath}.event_hooks`,
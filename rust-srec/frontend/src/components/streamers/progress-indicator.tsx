@@ -18,7 +18,7 @@ export function ProgressIndicator({
   compact = false,
 }: ProgressIndicatorProps) {
   const { i18n } = useLingui();
-  const isHealthy = progress.playbackRatio >= 1.0;
+  const isHealthy = !progress.stalled && progress.playbackRatio >= 1.0;
   const cdnHost = progress.cdnHost || '';
   const tooltipTheme = isHealthy ? 'blue' : 'orange';
 
@@ -138,6 +138,16 @@ export function ProgressIndicator({
                 ? progress.playbackRatio.toFixed(2) + 'x'
                 : '-'}
             </div>
+            <div className="text-muted-foreground">
+              <Trans render={({ translation }) => <>{translation}</>}>
+                Latency
+              </Trans>
+            </div>
+            <div className="font-mono text-foreground/90">
+              {progress.segmentLatencyMs > 0n
+                ? `${progress.segmentLatencyMs} ms`
+                : '-'}
+            </div>
             <div className="text-muted-foreground">
               <Trans render={({ translation }) => <>{translation}</>}>
                 CDN
@@ -45,12 +45,20 @@ function formatSplitReasonFromCode(i18n: I18n, code: string): string | null {
       return i18n._(msg`Size limit`);
     case 'duration_limit':
       return i18n._(msg`Duration limit`);
+    case 'wall_clock':
+      return i18n._(msg`Wall-clock boundary`);
     case 'header_received':
       return i18n._(msg`Header received`);
     case 'discontinuity':
       return i18n._(msg`Discontinuity`);
+    case 'ad_break_start':
+      return i18n._(msg`Ad break start`);
+    case 'ad_break_end':
+      return i18n._(msg`Ad break end`);
     case 'stream_structure_change':
       return i18n._(msg`Stream structure change`);
+    case 'requested':
+      return i18n._(msg`Requested split`);
     case 'resolution_change':
       return i18n._(msg`Resolution change`);
     case 'video_codec_change':
@@ -278,7 +286,8 @@ export function SplitReasonDetails({
         h('span', { className: 'text-foreground' }, to ?? '—'),
       );
     }
-    case 'stream_structure_change': {
+    case 'stream_structure_change':
+    case 'requested': {
       if (!isObject(details)) return null;
       const desc = details.description;
       if (!isNonEmptyString(desc)) return null;
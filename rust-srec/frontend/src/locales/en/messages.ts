@@ -1 +1 @@
-/*eslint-disable*/import type{Messages}from"@lingui/core";export const messages=JSON.parse("{\"-10SpW\":[\"Interactive 24-hour timeline selection.\"],\"-5Xsme\":[\"Out of Space\"],\"-6YAwo\":[\"selected\"],\"-BEX4J\":[\"Failed to update filter\"],\"-BOXe-\":[\"VOD Segment Timeout (ms)\"],\"-GLqpc\":[\"Compression / Archive\"],\"-GZp3E\":[\"Switch language\"],\"-GwKvY\":[\"No platform overrides configured\"],\"-OQYUd\":[\"Key Timeout (ms)\"],\"-O_9ij\":[\"Streamer Details\"],\"-Qbuje\":[\"Download engine (mesio)\"],\"-TWWLH\":[\"ID is required\"],\"-Xjyeo\":[\"Select File\"],\"-_zWwh\":[\"Auto (Default)\"],\"-b7T3G\":[\"Updated\"],\"-eCGXh\":[\"Interactive password entry via this API is not supported on Windows. Please use \\\"Desktop Login\\\" (import tdata) or log in once in a regular terminal.\"],\"-fL-Rj\":[\"Login failed: \",[\"0\"]],\"-jO3E8\":[\"Douyu HD rate\"],\"-lH7FY\":[\"Execution time (default)\"],\"-lwQ5z\":[\"Video Codec\"],\"-rJcQ5\":[\"Failed to update subscriptions\"],\"-swXfw\":[\"Choose how files are transferred to the remote.\"],\"-xD8Ul\":[\"Size and duration constraints for recordings.\"],\"-z4lmr\":[\"Expert mode: Edit the raw platform-specific configuration directly.\"],\"-zy2Nq\":[\"Type\"],\"00W4lp\":[\"Initial Fetch Timeout (ms)\"],\"00jaOh\":[\"Archive Compression\"],\"01Oe9B\":[\"Telegram Desktop\"],\"024CCW\":[\"Select template\"],\"03Qqf9\":[\"Filtered\"],\"0CKZnZ\":[\"Generate a thumbnail image from the video at 10 seconds (320px width).\"],\"0CaXBE\":[\"Supported Platforms\"],\"0HlWEz\":[\"Workflow Step\"],\"0Jq6UY\":[\"Manage your monitored channels and downloads\"],\"0P2gFy\":[\"The page you are looking for does not exist.\"],\"0PZXF8\":[\"Select format\"],\"0RjCNS\":[\"Could not connect to the server. Please check your connection.\"],\"0SsE5J\":[\"Preset not found\"],\"0TPdbO\":[\"H.264\"],\"0Uz_Zj\":[\"Decryption Key TTL (ms)\"],\"0X8rUE\":[\"https://...\"],\"0YopG6\":[\"Resource Limits\"],\"0ZwlwE\":[\"TDL is not logged in\",[\"version\"]],\"0aTtEy\":[\"Split on any raw header change. Safe but may cause extra files.\"],\"0f9Hre\":[\"API Request Retries\"],\"0gVmZj\":[\"Extra Arguments\"],\"0ioul2\":[\"Include Images\"],\"0s5Sjx\":[\"Artist\"],\"0s7IPo\":[\"Notification Service\"],\"0sZpQb\":[\"Inherited\"],\"0tvouK\":[\"Jitter\"],\"0v9bkw\":[\"SESSION STARTED\"],\"0wk6D2\":[\"Per page:\"],\"0xayzq\":[\"Fast GPU-accelerated H.264 encoding using NVIDIA NVENC (CQ 23). Great speed-to-quality ratio.\"],\"0zpgxV\":[\"Options\"],\"1-6BOG\":[\"Download Backup\"],\"11MaKJ\":[\"Failed to load events\"],\"14PdY0\":[\"Config\"],\"16uyo-\":[\"Add Channel\"],\"17A-hi\":[\"If enabled, streams matching this pattern will be ignored.\"],\"18A9QX\":[\"Failed to delete sessions\"],\"19MiM7\":[\"Global Timeout (ms)\"],\"19P7sR\":[\"Check triggered\"],\"1AGwZy\":[\"Enter a unique name\"],\"1AigfY\":[\"Previous\"],\"1BqPFJ\":[\"Network & System\"],\"1BxbyL\":[\"No platform found.\"],\"1HbWYr\":[\"This is a default preset. Description cannot be modified.\"],\"1KMsgI\":[\"Processor is required\"],\"1PQRWr\":[\"Start Time\"],\"1TQa05\":[\"Engine\"],\"1U1a6C\":[\"https://api.example.com/webhook\"],\"1UlpHm\":[\"Streamers\"],\"1VXi7i\":[\"Cancel Pipeline\"],\"1WHNRE\":[\"Add Override\"],\"1WTDTW\":[\"JSON array of all inputs\"],\"1WrRvf\":[\"Replace Step\"],\"1XskRc\":[\"Pipeline is empty.\"],\"1ZTiaz\":[\"Segments\"],\"1ZZ47T\":[\"Adjust the roundness of UI elements.\"],\"1aPw3f\":[\"Live polling notifications enabled\"],\"1cbMZM\":[\"CQ (Constant Quality)\"],\"1dwnDD\":[\"Server not configured\"],\"1hKEom\":[\"Priority\"],\"1ir2pc\":[\"Basic Auth\"],\"1jJKez\":[\"Configure how you receive alerts in this browser and via system push notifications.\"],\"1lVNQI\":[\"Triggered when a pipeline job fails.\"],\"1mYvTJ\":[\"Platform Overrides\"],\"1naaKv\":[\"When a recording has been waiting in the concurrency queue longer than this, rust-srec re-checks the streamer to refresh stream URLs and headers before starting. Below this threshold the URLs captured at the original live event are reused. Default 60 seconds. Set to 0 to refresh on every queue wait.\"],\"1nhIls\":[\"Filter deleted successfully\"],\"1njn7W\":[\"Light\"],\"1pChHx\":[\"Notification Log Retention\"],\"1tul0y\":[\"Session is not running\"],\"1wTrMB\":[\"Replace All\"],\"1wy3Ze\":[\"INITIAL\"],\"1yzCsx\":[\"Download complete\"],\"21M8r5\":[\"Web Push\"],\"22PcUJ\":[\"Paired Segment Pipeline\"],\"22lIWc\":[\"Process exited with code \",[\"0\"]],\"237hSL\":[\"Ended\"],\"26Ki3h\":[\"This will permanently delete the pipeline and all its associated jobs and logs. This action cannot be undone.\"],\"27E8MB\":[\"Settings updated successfully\"],\"28iWmV\":[\"Loading pipeline editor...\"],\"29VNqC\":[\"Unknown error\"],\"29loHy\":[\"Pipeline deleted successfully\"],\"2BBAbc\":[\"List\"],\"2EEBQm\":[\"Streamer ID\"],\"2E_rkV\":[\"Credentials saved successfully\"],\"2FC3XU\":[\"FFmpeg Path\"],\"2GT3Hf\":[\"Global\"],\"2KpT4Y\":[\"e.g. 1M\"],\"2R2xgz\":[\"Preserve Paths\"],\"2TQlVI\":[\"Requires \\\"Record Danmu\\\" to be enabled.\"],\"2WbqDp\":[\"Manage storage paths and file formats.\"],\"2XRg_h\":[\"Read Timeout (ms)\"],\"2d1Xgp\":[\"Custom Header\"],\"2dhgET\":[\"YYYY-MM-DD\"],\"2jmZHr\":[\"Add metadata tags (title, artist, date) to the video file.\"],\"2k1l_G\":[\"Video Settings\"],\"2kAqWs\":[\"Failed to create channel\"],\"2m_AmE\":[\"NVENC HEVC High Quality\"],\"2nMNeT\":[\"High quality GPU-accelerated AV1 encoding using NVIDIA NVENC (CQ 24). Best compression ratio, requires RTX 4000+.\"],\"2o7xcf\":[\"Output Options\"],\"2qfwJn\":[\"Overwrite\"],\"2sDhLO\":[\"On Offline\"],\"2tuwGz\":[\"Add parameter\"],\"2vw0xh\":[\"End of History\"],\"2wl1yB\":[\"Max Interval (s)\"],\"2xsJvU\":[\"^Started.*\"],\"3-I7HJ\":[\"Select streamers\"],\"3-IxS7\":[\"A unique and descriptive name for this configuration.\"],\"3070NI\":[\"General Configuration\"],\"30XQ-_\":[\"Cookies\"],\"32TndD\":[\"Blocked\"],\"34KT3m\":[\"Timeout before cancelling `execute` processor commands.\"],\"39GoNc\":[\"Max Downloads\"],\"39vwDk\":[\"SD / Low\"],\"3Aerm-\":[\"Session not found\"],\"3BEjvS\":[\"Remux to MP4 without re-encoding. Fast and lossless - just changes the container format.\"],\"3H1WQ-\":[\"Connection/activity timeout\"],\"3Huwd4\":[\"Auto Layout\"],\"3Ihr5B\":[\"Select a format\"],\"3JjdaA\":[\"Run\"],\"3K6phQ\":[\"Default uses Mesio’s built-in live stall timeout. Disabled means wait indefinitely.\"],\"3KInmc\":[\"Web Push disabled for this browser\"],\"3N3v30\":[\"Fast H.264 compression (CRF 23). Good balance of speed, quality, and file size.\"],\"3NfDu6\":[\"Error loading platform\"],\"3QfsSn\":[\"No pipeline jobs yet\"],\"3QlVaA\":[\"Failed to create preset: \",[\"0\"]],\"3Qu3lA\":[\"On Pipeline Complete\"],\"3X3_SF\":[\"Steps List\"],\"3Yd9PG\":[\"Sidebar Layout\"],\"3bv0H0\":[\"Triggered when the application shuts down.\"],\"3gnbIv\":[\"TDL Binary Path\"],\"3ktmhA\":[\"Extraction API Mode\"],\"3qNoeP\":[\"Degraded\"],\"3rQgBh\":[\"Critical alert when the recording output filesystem becomes unwritable and recordings cannot continue.\"],\"3sO1Yr\":[\"Authentication & Setup\"],\"3tncII\":[\"Configure how you want to import data from the selected backup file.\"],\"3uIIkE\":[\"Add New Engine\"],\"3vSDtx\":[\"Where TDL stores its session and configuration.\"],\"3wRUCF\":[\"Extract MP3\"],\"3ywK2I\":[\"Streamer Check\"],\"3yyqaw\":[\"High Quality Compression\"],\"40Gx0U\":[\"Timezone\"],\"40lLFI\":[\"Go to next page\"],\"40wBfu\":[\"Batch URLs\"],\"41k5OL\":[\"No Extension\"],\"43YdBD\":[\"Absolute path or 'ffmpeg' if in PATH\"],\"45zsWJ\":[\"Min size to keep.\"],\"47e97a\":[\"Max Retries\"],\"47mhxP\":[\"Preferred CDNs\"],\"48NKsg\":[\"Check Login\"],\"49BbAW\":[\"files\"],\"4AWhME\":[\"Match Strategy\"],\"4BITzH\":[\"Error:\"],\"4GICMX\":[\"No playable streams found\"],\"4GKuCs\":[\"Login failed\"],\"4Lr0jZ\":[\"Apply template settings.\"],\"4P5UM2\":[\"Session deleted successfully\"],\"4QQLtV\":[\"Your digital library is currently empty. Start recording to populate your sessions here.\"],\"4RQxGo\":[\"Exceptional states\"],\"4XUbOH\":[\"Split only on codec configuration changes. Reduces false splits.\"],\"4aDTBq\":[\"Copy Files\"],\"4ba0NE\":[\"Scheduled\"],\"4cEClj\":[\"Sessions\"],\"4cOYET\":[\"Shows notifications while the application tab is open by polling recent events.\"],\"4e7ocq\":[\"Base delay between retries\"],\"4eMNNj\":[\"Enable Output Metrics (Default: On)\"],\"4jAOc9\":[\"Select the steps that must complete successfully before this step runs.\"],\"4nfUib\":[\"Brand Colors\"],\"4siLaw\":[\"Stream Archive\"],\"4uQ7PS\":[\"Background refresh enabled\"],\"4v6VMF\":[\"Resuming in\"],\"4w5jzL\":[\"Custom Tags\"],\"4xzdEv\":[\"Subscriptions updated\"],\"5-AFwM\":[\"No workflows yet\"],\"52y5Op\":[[\"0\",\"plural\",{\"one\":[\"#\",\" failed\"],\"other\":[\"#\",\" failed\"]}]],\"55n6pj\":[\"Rate Limiting\"],\"57MMsh\":[\"Max Segment Retries\"],\"5B_EbJ\":[\"OFFLINE\"],\"5FCyMZ\":[\"Target bitrate\"],\"5GJtdv\":[\"Theme Preset\"],\"5HLg1G\":[\"Streams & Metadata\"],\"5LUAOT\":[\"Raw JSON Editor\"],\"5PhtRK\":[\"No specialized options available for this platform.\"],\"5RyP14\":[\"Per-segment\"],\"5SNqKm\":[\"Custom Config\"],\"5UfPjf\":[\"View application logs in real-time. Logs are limited to the last \",[\"MAX_LOG_ENTRIES\"],\" entries.\"],\"5b4J4v\":[\"All Time\"],\"5bhON1\":[\"Bitrate\"],\"5dKF37\":[\"Failed to send input: \",[\"0\"]],\"5eHyY-\":[\"Test Notification\"],\"5gF1Yx\":[\"Click to add. You can reorder steps in the editor.\"],\"5hxDwt\":[\"CPU Job\"],\"5iuLzq\":[\"presets\"],\"5kX7hC\":[\"Add randomness to retry delays to prevent thundering herd.\"],\"5l9iMR\":[\"No templates yet\"],\"5r2EnB\":[\"NVENC AV1 Fast\"],\"5raigv\":[\"Max Pending Init Segments\"],\"5s-Rk5\":[\"Add Pipeline Step\"],\"5vx6Be\":[\"Failed to retry all failed pipelines\"],\"5x03LH\":[\"Base path for remote storage. Supports metadata and time placeholders.\"],\"61RNR5\":[\"No log files found\"],\"633mTs\":[\"Failed to create filter\"],\"65DNcr\":[\"Unknown File\"],\"677MgO\":[\"No engines found.\"],\"67RoFa\":[\"Pipeline\"],\"67hU6L\":[\"Main application\"],\"6A0WJl\":[\"Fix configuration errors before saving\"],\"6Aih4U\":[\"Offline\"],\"6BDqha\":[\"Limits\"],\"6Dd2PM\":[\"Maintenance\"],\"6GBt0m\":[\"Metadata\"],\"6GJw73\":[\"No Timeline Events\"],\"6H2zd1\":[\"Failed to send test notification\"],\"6LJ142\":[\"Failed to load engines: \",[\"0\"]],\"6O3gfw\":[\"Use Phone & Code\"],\"6Oaeoc\":[\"Cron\"],\"6Umgab\":[\"Auto (QR → Phone & Code → Desktop)\"],\"6V3Ea3\":[\"Copied\"],\"6VKo3k\":[\"Deletes the files produced by the previous step. Safe after an Upload step. After a transcode/remux it deletes the converted result, not the original — use \\\"Remove Input on Success\\\" on the transcode step to delete the source instead.\"],\"6WdDG7\":[\"Page\"],\"6YtxFj\":[\"Name\"],\"6_-6tw\":[[\"val\"],\" channel support is coming soon!\"],\"6_dCYd\":[\"Overview\"],\"6bAENo\":[\"Disk Space\"],\"6d9Ao4\":[\"No logs available for this execution.\"],\"6fi2dR\":[\"Max Retry Delay (ms)\"],\"6gRgw8\":[\"Retry\"],\"6geoEv\":[\"File path copied to clipboard\"],\"6h20bP\":[\"Cancel Pipeline?\"],\"6hur5J\":[\"e.g. --namespace my_account\"],\"6kItd_\":[\"upload : download (asymmetric)\"],\"6ldwcL\":[\"Fast Compression\"],\"6mF1Q8\":[\"Extract AAC\"],\"6moIoi\":[\"Paired\"],\"6mwiZ7\":[\"Specify preferred content delivery network (e.g., ws-h5, hw-h5).\"],\"6nFdlp\":[\"Delete Preset?\"],\"6p4wnA\":[\"Only supported for MP4/MOV/M4V\"],\"6qNpC1\":[\"Prioritize specific qualities (case-insensitive). Press Enter to add.\"],\"6sBUvP\":[\"Add a stream to start your viewing session.\"],\"6swMyy\":[\"Presets Available\"],\"6tBUzp\":[\"Remove Input on Success\"],\"6vFYPB\":[\"e.g. flv, hls\"],\"6wLR55\":[\"Edit Engine\"],\"7-c73-\":[\"Select a processing job or workflow to use in this node.\"],\"71pK1Q\":[\"This will permanently delete \",[\"selectedCount\"],\" streamers. This action cannot be undone.\"],\"72c5Qo\":[\"Total\"],\"73yjgk\":[\" - Session: \",[\"0\"]],\"76ob5K\":[\"First output file path\"],\"77da32\":[\"TPS Limit\"],\"78qtNg\":[\"This Delete step deletes the converted result, not the original recording. Enable \\\"Remove Input on Success\\\" on the transcode step instead.\"],\"7Bj3x9\":[\"Failed\"],\"7C-Jn5\":[\"Show password\"],\"7DqYk8\":[\"Filter updated successfully\"],\"7JVWl4\":[\"End Stream On Danmu Close\"],\"7Jw_XW\":[\"Error Details\"],\"7K4dCf\":[\"Try adjusting your search or filters\"],\"7L01XJ\":[\"Actions\"],\"7L3DyA\":[\"Regex Pattern\"],\"7Oyczn\":[\"Global Buffer Size\"],\"7SNWYf\":[\"Telegram 2FA via web is unsupported\"],\"7VxXSg\":[\"Browser Status\"],\"7_wpqP\":[\"Recommended: 8388608 (8 MiB)\"],\"7cmJQz\":[\"Workflow created successfully\"],\"7fIIFx\":[\"TTWID Management Mode\"],\"7jRxmJ\":[\"Triggered when the application starts up.\"],\"7kKmbL\":[\"0 disables the limit.\"],\"7p5kLi\":[\"Dashboard\"],\"7s6i2S\":[\"Download All\"],\"7sMeHQ\":[\"Key\"],\"7sNhEz\":[\"Username\"],\"7tgEva\":[\"2\"],\"7vhWI8\":[\"New Password\"],\"800TmA\":[\"Streams\"],\"80AR_c\":[\"Restore from a backup file\"],\"81mUvV\":[\"Delete Source Videos\"],\"83M8VR\":[\"Select an engine\"],\"84B6U_\":[\"Lower value = Higher Quality\"],\"8HE-Aw\":[\"Filter Interactive Games\"],\"8PNILp\":[\"Parse Stream\"],\"8T864k\":[\"Triggered when a download successfully completes.\"],\"8TMaZI\":[\"Timestamp\"],\"8Tg_JR\":[\"Custom\"],\"8WcAZl\":[\"Filter created successfully\"],\"8WgMDL\":[\"Archive Settings\"],\"8XX_CD\":[\"No filters yet\"],\"8YINy3\":[\"Config Path (Optional)\"],\"8ZsakT\":[\"Password\"],\"8_brI5\":[\"Name is required\"],\"8b6O3h\":[\"Search logs...\"],\"8eRn2a\":[\"SYSTEM\"],\"8gGPQK\":[\"From Address\"],\"8iqQ6v\":[\"Add exclude regex pattern\"],\"8j1zGy\":[\"Auto Thumbnail\"],\"8lz0kn\":[\"https://discord.com/api/webhooks/...\"],\"8mJUui\":[\"Not supported in this browser\"],\"8rp73h\":[\"Configuration imported successfully\"],\"8tjQCz\":[\"Explore\"],\"8tm102\":[\"WUP\"],\"8vQkDt\":[\"Latency\"],\"8wu9lr\":[\"Queued\"],\"8x_SfR\":[\"Memory Usage\"],\"8yPA1y\":[\"Interval between checks to see if a streamer is currently live. Slower intervals reduce API usage but might delay recording starts.\"],\"90Z1Nt\":[\"Job retry initiated\"],\"93Ucih\":[\"Additional arguments appended to the command.\"],\"95g7x4\":[\"Log levels & modules\"],\"98oK0h\":[\"Bandwidth Limit help\"],\"9AfU7B\":[\"Use danmu_inputs from job manifest\"],\"9CFpCU\":[\"Avg. Duration\"],\"9DW7l4\":[\"Awaiting first check\"],\"9E34se\":[\"Browse and download application log files. Filter by date range or download all.\"],\"9ELT_X\":[\"Enable Monitoring\"],\"9G881z\":[\"None (Default)\"],\"9IO3UM\":[\"Change Password Required\"],\"9NKF63\":[\"e.g. 1080p, source, 原画\"],\"9OmEZr\":[\"HTTP requests\"],\"9P6Cjx\":[\"Binary Path\"],\"9PbSQ8\":[\"Temporarily disabled\"],\"9SGdhm\":[\"X-Auth-Key\"],\"9SrfBr\":[\"time-of-day timetable\"],\"9UQ730\":[\"Clone\"],\"9UWq9R\":[\"Enable recording of danmu/chat messages along with the video.\"],\"9Ui6mb\":[\"Skip after count\"],\"9Ulhif\":[\"Notification Events\"],\"9XOpfw\":[\"Max Interval (ms)\"],\"9eF5oV\":[\"Welcome back\"],\"9hg9mc\":[\"Downloading\"],\"9kAtVa\":[\"Out of space\"],\"9kmN8A\":[\"No metadata tags added\"],\"9p14fd\":[\"Configure proxy strategies and connection details.\"],\"9pDx-X\":[\"Exclude ASS from Passthrough\"],\"9rG25a\":[\"Server URL\"],\"9uJs-K\":[\"HLS Tuning\"],\"A-3LfF\":[\"Preview Thumbnail\"],\"A-yfCV\":[\"QR Login\"],\"A2LNUE\":[\"Skipped \",[\"skippedCount\"],\" duplicate stream(s)\"],\"A8uySC\":[\"Failed to delete filter\"],\"ABucgF\":[\"Excluded Extensions\"],\"ADzdEE\":[\"Prioritize specific streaming protocols. Press Enter to add.\"],\"AEGM8s\":[\"Failed to copy\"],\"AGMlcc\":[\"Back to Sessions\"],\"AIdJOG\":[\"IANA Timezone (e.g. Asia/Shanghai, UTC).\"],\"AK4HwH\":[\"Error loading template\"],\"AR0orP\":[\"My Archiving Workflow\"],\"AYbtd_\":[\"Supported\"],\"Abl55S\":[\"Leave empty for stream copy\"],\"Ai2DeC\":[\"Add New Filter\"],\"AlfqgK\":[\"Watch\"],\"ApdJrx\":[\"Default Douyu quality\"],\"Ar-5tA\":[\"Max Refresh Retries\"],\"AsJWjt\":[\"Configure Settings\"],\"AsnjLf\":[\"Please set Telegram Desktop Directory first\"],\"AwXh-1\":[\"Download activity looks healthy\"],\"Az1ZEd\":[\"Runs when the streamer goes online.\"],\"AzCMby\":[\"Failed to load platforms: \",[\"0\"]],\"B0GspH\":[\"Start checking this streamer immediately.\"],\"B1KFZ1\":[\"* * * * * *\"],\"B2Zb_F\":[\"JSON\"],\"B3xl-Q\":[\"Last Activity\"],\"B495Gs\":[\"Archive\"],\"B4Ew1I\":[\"Add at least one step\"],\"B6-TsG\":[\"Files smaller than this stay single-threaded. Examples: <0>250M</0>, <1>1G</1>.\"],\"B7dmPy\":[\"Session Complete Pipeline\"],\"BAWVb9\":[\"No overrides configured\"],\"BCL8Ot\":[\"Header Value\"],\"BFl5lQ\":[\"Full HD Thumbnail\"],\"BGi3Wo\":[\"Appended before output filename\"],\"BIXG5q\":[\"Retry Policy\"],\"BQvz2A\":[\"These timeouts are applied when the pipeline worker pools start. Changes require a restart to take effect.\"],\"BWUEP1\":[\"HTTP/1.1 Only\"],\"BX_rCE\":[\"Specific TTWID Cookie\"],\"B_pQ8T\":[\"Stream Splitting Strategy\"],\"B_rQdm\":[\"Appended to all HLS requests. Useful for signed URLs or CDN routing.\"],\"BaQ0LA\":[\"Number of days to keep completed, failed, or cancelled jobs and workflow executions. Set to 0 to retain them indefinitely.\"],\"Ban892\":[\"Max transactions/second to the remote API (--tpslimit).\"],\"Bcgdrh\":[\"Name autofilled successfully\"],\"BdBelu\":[\"Danmu/min\"],\"Bhx3fS\":[\"Scanned! Please confirm on your phone\"],\"BjJjsB\":[\"FLV stream fixing pipeline\"],\"BjitBo\":[\"Download \",[\"0\"]],\"Bn9-El\":[\"default\"],\"BnOMVx\":[\"Ultrafast Compression\"],\"BrrIs8\":[\"Storage\"],\"Bs956d\":[\"High quality GPU-accelerated H.264 encoding using NVIDIA NVENC (CQ 20). Best NVENC quality.\"],\"Bsurp4\":[\"On Online\"],\"Btgfql\":[\"Job Configuration\"],\"BwhhDv\":[\"Select a streamer\"],\"ByeSsO\":[\"Security & Identity\"],\"C1TsH4\":[\"Waiting for connection...\"],\"C2WreN\":[\"0:v:0\"],\"C4IQ_M\":[\"Workflow deleted successfully\"],\"C95qpz\":[\"Sampling Strategy\"],\"CBVnKj\":[\"Connect Timeout (ms)\"],\"CKyk7Q\":[\"Go back\"],\"CO6Iw8\":[\"IO Job\"],\"COU0uA\":[\"Scan a directory for new files created by the command. Detected files will be passed to the next pipeline step.\"],\"CP3D8G\":[\"Progress\"],\"CS276w\":[\"Delete Workflow?\"],\"CSZ9-b\":[\"TPS Limit help\"],\"CZIQWR\":[\"Configure native OS notifications for important events while running the desktop app.\"],\"Cav1Ci\":[\"Danmu\"],\"Cbxd8c\":[\"Composes with Bandwidth Limit — useful to keep one big file from saturating the whole link while still allowing many small ones to run in parallel.\"],\"CdgoAe\":[\"Create a copy of \\\"\",[\"0\"],\"\\\" with a new name.\"],\"Cj2Gtd\":[\"Size\"],\"Cm-_Q8\":[\"notifier@example.com\"],\"CmOYNg\":[\"Args inserted before -i input_url\"],\"CndmuB\":[\"Stream parsed successfully\"],\"CpwhPE\":[\"Specific\"],\"CqO9qC\":[\"Session\"],\"CsekCi\":[\"Normal\"],\"CvL6qO\":[\"Min Interval (s)\"],\"Cvl3Ur\":[\"e.g. tmp\"],\"D-NlUC\":[\"System\"],\"D0K_c6\":[\"This step is linked to the preset <0>\",[\"presetName\"],\"</0>.\"],\"D1U6Wk\":[\"TDL Interactive Login\"],\"D4xpys\":[\"Triggered when a download is manually cancelled.\"],\"D5t8TK\":[\"Workflow updated successfully\"],\"DC2eVx\":[\"Pipeline Structure\"],\"DCKkhU\":[\"Current Password\"],\"DEpnGr\":[\"Verify Integrity\"],\"DHhJ7s\":[\"Previous\"],\"DIv6yv\":[\"No configuration available for this processor.\"],\"DJsY8j\":[\"Step Identifier (Unique)\"],\"DKrPOn\":[\"Failed to update logging configuration\"],\"DL3whW\":[\"Basic post-processing: Remux FLV to MP4 and generate a thumbnail preview.\"],\"DNA4aQ\":[\"How long to keep decrypted/processed segments in cache.\"],\"DPfwMq\":[\"Done\"],\"DRTrkZ\":[\"Danmus\"],\"DS-5Kd\":[\"Failed to load system health\"],\"DTAxdY\":[\"Outputs\"],\"DTD28p\":[\"Alerts when disk space is running low.\"],\"DTKoJC\":[\"No recent sessions found.\"],\"DYJ_1s\":[\"Pipeline Statistics\"],\"DYyL81\":[\"Copy (Stream Copy)\"],\"D_XERT\":[\"Failed to delete pipeline\"],\"DanbIO\":[\"Appearance & style\"],\"DfRZHP\":[\"Configured Events\"],\"DgVBNf\":[\"Telegram 2FA password is not supported\"],\"DjZUWm\":[\"Upload file to cloud storage and delete local copy after successful upload.\"],\"DkLWbn\":[\"smtp.gmail.com\"],\"DtXU8D\":[\"Number of days to keep the notification event log. Set to 0 to retain events indefinitely.\"],\"Dya8HF\":[\"Fix FLV Streams\"],\"DzUNTV\":[\"Write Timeout (ms)\"],\"E4sw5m\":[\"Session Complete\"],\"E555CB\":[\"Optional extra arguments appended to `tdl login`.\"],\"E5h2rM\":[\"High Quality MP3\"],\"E8bXne\":[\"Proxy\"],\"EBdNVo\":[\"Session Preview\"],\"ECGvyy\":[\"Failed to update template: \",[\"0\"]],\"EEMR5K\":[\"Hour (00-23)\"],\"ELDffV\":[\"Failed to cancel job\"],\"ELa85n\":[\"All Types\"],\"EOsjKj\":[\"Pipeline must have at least one step\"],\"EQoNwM\":[\"File Configuration\"],\"EVGGg9\":[\"Interact\"],\"EXa7Eb\":[\"Streamer ID is required\"],\"EZCWHe\":[\"Launch Pipeline\"],\"EZgAlu\":[\"Bearer Token\"],\"E_QGRL\":[\"Disabled\"],\"EbgT4I\":[\"Priority low-high\"],\"EdQY6l\":[\"None\"],\"Ef7StM\":[\"Unknown\"],\"Ejic1p\":[\"Platforms\"],\"EjzeaZ\":[\"Extraction Settings\"],\"EmVl8I\":[\"Default is 1 (no burst). Increase if the provider tolerates short bursts of activity.\"],\"EtVJIx\":[\"Absolute path or 'streamlink' in PATH\"],\"ExpKh1\":[\"Preset Step\"],\"EzR6wK\":[\"Audio Processing\"],\"EztJxW\":[\"rclone default: 8\"],\"F-T4fj\":[\"Browse generated media artifacts from pipeline jobs\"],\"F08Z2D\":[\"TDL binary not found\"],\"F13uOJ\":[\"Fast encoding for quick sharing on social media or messaging.\"],\"F3bW6y\":[\"Platform\"],\"F6Go3M\":[\"Header Key\"],\"F6pfE9\":[\"Active\"],\"F6qVY1\":[\"Day (01-31)\"],\"F7wXp2\":[\"Deletes all existing configurations before importing. This action cannot be undone.\"],\"F9I0Bm\":[\"Critical system errors or streamer failures.\"],\"F9lPvE\":[\"e.g. 'best', 'worst', '720p', 'audio_only'\"],\"FBIuPX\":[\"Clear selection\"],\"FBsQxQ\":[\"This will permanently remove the \\\"\",[\"0\"],\"\\\" configuration. This action cannot be undone.\"],\"FEr96N\":[\"Theme\"],\"FIHR-e\":[\"Stop recording when danmu control signals stream closed.\"],\"FIfpIQ\":[\"TDL is logged in\",[\"version\"]],\"FJxoQQ\":[\"Job configurations\"],\"FLm1Nd\":[\"Real-time\"],\"FMKXt7\":[\"Transfers\"],\"FRqceZ\":[\"Capture Settings\"],\"FSaByO\":[\"Video codec change\"],\"FUbs2p\":[\"Rclone\"],\"FZg3wM\":[\"Operation\"],\"F_amnS\":[\"Time Range\"],\"Fecn_0\":[\"Engine Overrides\"],\"Ff0Dor\":[\"Past\"],\"FfHole\":[\"Preset cloned successfully\"],\"FgYX1w\":[\"Triggered when a download is rejected (e.g., circuit breaker).\"],\"FnYEt6\":[\"Streamer pagination\"],\"FnyzZP\":[\"Top Talkers\"],\"FrndDV\":[\"Native Resolution Thumbnail\"],\"Fv9mIF\":[\"These settings only apply to the desktop app. The server will still deliver channel notifications (Discord/Email/Webhook) normally.\"],\"FyGlJm\":[\"Parse and play live streams\"],\"FzHX8f\":[\"Explicit TTWID cookie value to use for all requests.\"],\"G1YI6L\":[\"HTTP cookies for authentication. These are automatically updated when refreshed.\"],\"G39zBp\":[\"Cache\"],\"G4Ntrl\":[\"Override download engine.\"],\"G88OSb\":[\"Warning when the processing queue gets too long.\"],\"G9GerW\":[\"Execute Command\"],\"G9ULuK\":[\"Maximum HEVC/H.265 compression (CRF 28) for minimal file size.\"],\"G9jHpr\":[\"Pipeline is valid\"],\"GD1Pmm\":[\"Cutoff\"],\"GICGoz\":[\"Transient error\"],\"GRlUlG\":[\"Input Arguments\"],\"GRzPZD\":[\"If set, stream titles must contain at least one of these keywords.\"],\"GSDw5m\":[\"Set up a new download tool configuration\"],\"GSr0rF\":[\"Open menu\"],\"GWWwxV\":[\"Loop Protection\"],\"GYeo24\":[\"Return Previous Page\"],\"GbsW45\":[\"Create New Pipeline Job\"],\"GcrmOG\":[\"At least one input path is required\"],\"Gg8KwP\":[\"Please wait while we resolve the stream URL.\"],\"GkxWrz\":[\"Audio Codec\"],\"GnfGTT\":[\"Custom Headers\"],\"GrdEmt\":[\"Customize specific engine settings for this template.\"],\"GtJbUa\":[\"Background\"],\"GwDyhV\":[\"Delete Source XML\"],\"GyEhZV\":[\"Max HEVC Compression\"],\"H5UXax\":[\"Confirm Import\"],\"H7PCCE\":[\"<0>0</0> means unlimited (rclone default). Example: set to <1>10</1> if the provider caps you at 10 requests/second.\"],\"HCO_et\":[\"Encoding Settings\"],\"HDYzmV\":[\"Gap Eval Interval (ms)\"],\"HKxmVq\":[\"Number of attempts before failing the upload.\"],\"HM56Bx\":[\"Creating...\"],\"HQoaRh\":[\"Args used for processing/encoding\"],\"HRbpjg\":[\"New password (min 8 chars)\"],\"HTu6w6\":[\"Configuration exported successfully\"],\"HUqDD6\":[\"Add Environment Variable\"],\"HVrxmo\":[\"Sanitized streamer name\"],\"HWpPGm\":[\"GPU\"],\"H_IzXL\":[\"IO Job Timeout\"],\"H_smbI\":[\"Duration (ms)\"],\"HajiZl\":[\"Month\"],\"Hhc4OF\":[\"Search jobs...\"],\"HlhZ5D\":[\"Use TLS\"],\"HmHwcC\":[\"Fixed Interval\"],\"HqohwS\":[\"Max Uploads\"],\"HruwW2\":[\"Custom User-Agent string (Optional)\"],\"HtrFfw\":[\"URL is required\"],\"Hu-bBV\":[\"This will cancel all pending and processing jobs in this pipeline.\"],\"HuTRrW\":[\"Stream Player\"],\"HuisDV\":[\"Per-segment Pipeline\"],\"HvemsH\":[\"avg \",[\"averagePerMinute\"]],\"HzqrrK\":[\"Failed to check TDL status: \",[\"0\"]],\"I0LpOM\":[\"Manual update required\"],\"I1WA96\":[\"Rust-Srec\"],\"I2M7LB\":[\"Platform name\"],\"I3Tk-i\":[\"Identity and type of the engine.\"],\"I6bYOx\":[\"Adjust sidebar style and behavior.\"],\"IBg4fi\":[\"No errors found. Max depth: \",[\"0\"]],\"IBn2_D\":[\"Delays, proxy, and retention policies.\"],\"IDbOLP\":[\"Ultra HD\"],\"IDkbnS\":[\"Configure recording rules for this streamer.\"],\"IDqYXq\":[\"Attempt to get the highest original quality available.\"],\"IGmq_R\":[\"Download Delay\"],\"II3urw\":[\"File Operations\"],\"IKSVos\":[\"e.g. /data/tdl\"],\"ILdR4t\":[\"Remux with Faststart\"],\"IMcoE-\":[\"Inset\"],\"IOMDKm\":[\"Failed to start login: \",[\"0\"]],\"IOnYeq\":[\"Reorder Max Segments\"],\"IQTWsi\":[\"Enter keywords to exclude...\"],\"IQlf-W\":[[\"0\"],\"/\",[\"1\"],\" steps\"],\"IR6zrc\":[\"Failed to process selected streamers\"],\"ISAp-4\":[\"Module Log Levels\"],\"ISgi7W\":[\"Upload everything regardless of file type\"],\"IUwGEM\":[\"Save Changes\"],\"IagCbF\":[\"URL\"],\"IduvKA\":[\"Failed to update priority\"],\"IfLN8u\":[\"Monitor this streamer\"],\"IgrLD_\":[\"Pause\"],\"IiCZ6K\":[\"Queued for download\"],\"IjXiiq\":[\"Filter repeated tags and detect stream replay loops\"],\"IjvCsG\":[\"Native\"],\"IkqmLx\":[\"Import Configuration\"],\"IpYp3U\":[\"Min Bitrate (bps)\"],\"IqjaXP\":[\"e.g. mp4\"],\"IrGyPi\":[\"MB/s\"],\"IvxHT8\":[\"Browser Notifications\"],\"IwBsC9\":[\"Confirmed via backstop timer.\"],\"J-PlWE\":[\"Vars: {streamer}, {title}, {platform} | Time: %Y, %m, %d, %H, %M, %S\"],\"J0UZ9c\":[\"Custom imported theme is active.\"],\"J28zul\":[\"Connecting...\"],\"J2AZlk\":[\"Are you sure you want to delete this streamer?\"],\"J4VbZP\":[\"Streamer created successfully\"],\"J50_e4\":[\"Filter Type\"],\"J5nbej\":[\"Critical\"],\"J6n7sl\":[\"Ongoing\"],\"J7EJf5\":[\"TDL not found\"],\"J8slJP\":[\"Runs when the post-processing pipeline completes.\"],\"J9cyui\":[\"Streamers currently live will appear here.\"],\"JB_0cx\":[\"Preferred CDN\"],\"JDgrxe\":[\"Original / Blu-ray\"],\"JDiqy2\":[\"Target number of messages to capture per sample period.\"],\"JEGlfK\":[\"Started\"],\"JGAkEK\":[\"Back to Details\"],\"JGiexj\":[\"Optimize Stream Headers\"],\"JJZidX\":[\"Danmu Service\"],\"JK4_Ie\":[\"None (Strip Audio)\"],\"JO8-jA\":[\"Manage Subscriptions\"],\"JRQitQ\":[\"Confirm new password\"],\"JU-LKs\":[\"How many requests can briefly burst above TPS Limit before throttling kicks in.\"],\"JY5Oyv\":[\"Database\"],\"J_hVSQ\":[[\"0\"]],\"JbursE\":[\"TDL account namespace (`tdl --ns ...`). Use this to manage multiple Telegram accounts.\"],\"Jdxa-V\":[\"Wait indefinitely\"],\"Jf28Qk\":[\"Retry Pipeline\"],\"JiIKww\":[\"Advanced Settings\"],\"Jj9mVU\":[\"Delete Channel?\"],\"JlFcis\":[\"Send\"],\"Jmb3Ih\":[\"Velocity Based (Dynamic)\"],\"JrUYse\":[\"Closest to bitrate\"],\"JrcCgP\":[\"Current Filter Directive\"],\"Jsp72G\":[\"Processed Buffer Multiplier\"],\"Ju0JNh\":[\"Cancelled \",[\"0\"],\" steps in pipeline\"],\"JuSQP0\":[\"Check if file exists after conversion\"],\"JzY5pj\":[\"Describe what this workflow does...\"],\"K-3SzA\":[\"ffmpeg\"],\"K2vkEv\":[\"RESUMED\"],\"K38wNo\":[\"Template Information\"],\"K3UhxB\":[\"Select date range\"],\"K4zqVo\":[\"Failed to create workflow: \",[\"0\"]],\"K7tIrx\":[\"Category\"],\"KAmTRT\":[\"Enable or disable this webhook\"],\"KCzvDi\":[\"Add argument template\"],\"KDw4GX\":[\"Try again\"],\"KOT_Rc\":[\"Multi-Thread Streams help\"],\"KPx1UV\":[\"Not found\"],\"KSS7Tg\":[\"Exclude Keywords\"],\"KTmH5k\":[\"Detach & Edit\"],\"KUyMyQ\":[\"Graceful Stop Timeout\"],\"KWm8rr\":[\"Bot Name\"],\"KYBAbP\":[\"When enabled, each VOD segment must complete within this timeout.\"],\"KYdyb_\":[\"Use mobile endpoint for extraction.\"],\"KZOdTD\":[\"Client Type (ctype)\"],\"KZVQ3R\":[\"Space Saver\"],\"K_-HXP\":[\"Directory path copied\"],\"KftHu1\":[\"Min Interval (ms)\"],\"KhgRw2\":[\"Search events...\"],\"KlrNJU\":[\"Real-time status of all system components and resources.\"],\"KmtY_Q\":[\"Pipeline has failed jobs. Click to view details.\"],\"KnPHvA\":[\"Queued Refresh Threshold\"],\"KoQLpo\":[\"Use <0>-movflags +faststart</0>\"],\"Kp3hk-\":[\"Allow private stream proxy targets\"],\"KpU39u\":[\"Per-file cap (rclone --bwlimit-file). Same syntax as Bandwidth Limit; composes with it.\"],\"KtTU7C\":[\"Auth Type\"],\"KwS2c-\":[\"Processing Behavior\"],\"L0Zj1K\":[\"Pipeline Configuration\"],\"L2_QkP\":[\"Pipeline job created successfully\"],\"L3fUx7\":[\"Error: Could not load preset details.\"],\"L4RAy4\":[\"Blacklisted CDNs\"],\"L4c5Kt\":[\"-c copy\"],\"L5RWy_\":[\"HD Thumbnail\"],\"L5WboT\":[\"HTTP Version Preference\"],\"LBEZuS\":[\"Engines\"],\"LBjZN4\":[\"Minute (00-59)\"],\"LCcfAH\":[\"Failed to load engine: \",[\"0\"]],\"LKPR6G\":[\"Playlist\"],\"LLAa_9\":[\"Optional\"],\"LMn91f\":[\"Prefer Manifest\"],\"LNcpxP\":[\"Mozilla/5.0...\"],\"LPJyAY\":[\"How to pair video files with .ass subtitles\"],\"LS_ord\":[\"e.g. 128k, 320k\"],\"LTNjeK\":[\"Container\"],\"LXMVBR\":[\"Delete source file after successful processing\"],\"L_OaTA\":[\"Archive to Cloud\"],\"L_XFOI\":[\"Detailed processor settings\"],\"LbS6Kh\":[\"Minimum Priority\"],\"Le70b5\":[\"More pages\"],\"LhOLxv\":[\"Runs decryption on a blocking thread pool to avoid stalling async tasks.\"],\"LiJMls\":[\"Search platforms...\"],\"Lk1OGO\":[\"Offline Detection\"],\"LoIUGx\":[\"Supports placeholders: \",[\"PLACEHOLDER_TOKENS\"],\" and time tokens like %Y/%m/%d.\"],\"LovaQl\":[\"Max number of retry attempts for metadata fetching.\"],\"LqH9mJ\":[\"Recent notification events persisted by the backend\"],\"LubEtv\":[\"Edit Preset: \",[\"0\"]],\"M0uszl\":[\"Preset created successfully\"],\"M3QKhO\":[\"Platform Specific Configuration\"],\"M6bo58\":[\"C:path1.flv,C:path2.flv\"],\"M73whl\":[\"Context\"],\"MA1f6y\":[\"High+\"],\"MAKqi9\":[\"e.g. 10M\"],\"MDzSyV\":[\"Manage credentials and session cookies.\"],\"MF-nAj\":[\"Replace output files if they already exist\"],\"MHrjPM\":[\"Title\"],\"MItgvI\":[\"Default Engine\"],\"MMoZg-\":[\"Stream Mapping (-map)\"],\"MNP_Hr\":[\"Processed Segment TTL (ms)\"],\"MQpz7h\":[[\"0\"],\" stream(s) added successfully\"],\"MS1IQE\":[\"Dual Format\"],\"MUbeuH\":[\"HLS stream fixing pipeline\"],\"MUwvHM\":[\"Limits & Validation\"],\"MXuUDu\":[\"Ignored when \\\"Upload All Files\\\" is enabled.\"],\"MYKXvL\":[\"Unknown Engine ID: \",[\"engineId\"]],\"Mbxk4g\":[\"Target Bitrate\"],\"MhA4UF\":[\"Clone Preset\"],\"Mi9SD3\":[\"Let the in-app stream player proxy sources on private or local networks (LAN restreamers, cameras, tailnet addresses). Leave off unless you stream from local sources: it re-opens requests to internal addresses for any signed-in user.\"],\"Mj31d8\":[\"Override global default.\"],\"Ml0gf8\":[\"Multimedia Archive\"],\"MmFX21\":[\"paused\"],\"MpHt5u\":[\"Add Streamer\"],\"Mpp8ys\":[\"pipelines\"],\"MqP8N6\":[\"Force Origin Quality\"],\"MqVdIO\":[\"1 (best) - 31 (worst)\"],\"MqWOyf\":[\"Checkers help\"],\"Mr5Sgb\":[\"Extract audio track to AAC format (256kbps). High quality, widely compatible.\"],\"MucYW8\":[\"Test notification sent\"],\"MvqJb2\":[\"Create Engine\"],\"N1_4qm\":[\"Triggered when a pipeline job finishes successfully.\"],\"N2fC4V\":[\"Maximum number of concurrent segment downloads.\"],\"N2twk8\":[\"Date placeholder anchor\"],\"N40H-G\":[\"All\"],\"N4UV0r\":[\"Streamer no longer exists on the platform\"],\"N5gYIZ\":[\"Low-latency GPU-accelerated H.264 encoding for real-time applications. Minimal encoding delay.\"],\"N5vGcw\":[\"Enter your credentials to access your account\"],\"N683xA\":[\"Max Bitrate (bps)\"],\"N7OGFm\":[\"Please restart the system to ensure all configuration changes are fully applied.\"],\"N7k2OK\":[\"e.g. /output/processed/\"],\"N8YRwn\":[\"e.g. cdn-to-avoid\"],\"NAhOWx\":[\"Fatal:\"],\"NBO4gh\":[\"Concurrency & Performance\"],\"NBdIgR\":[\"Comment\"],\"NEnLep\":[\"Global Default\"],\"NFIOKv\":[\"Advanced Options\"],\"NF_R69\":[\"Job not found\"],\"NGO2th\":[\"All Templates\"],\"NHNfp3\":[\"Engine Name\"],\"NHyRGf\":[\"Case Insensitive\"],\"NIBH_E\":[\"Error Loading Job\"],\"NJevqq\":[\"Validate\"],\"NKYo1x\":[\"ASS Subtitle Burn-in\"],\"NMhr2I\":[\"Twitch OAuth token for subscriber-only and high-quality streams.\"],\"NPY5Jv\":[\"Configure a new download tool.\"],\"NQSiYb\":[\"Search by name or description...\"],\"NQas2m\":[\"Runs when both video and danmu segments are available. Requires \\\"Record Danmu\\\" to be enabled.\"],\"NRL17y\":[\"Streamer check history\"],\"NUrY9o\":[\"Categories\"],\"NV_uNb\":[\"Web Push is not configured on the server\"],\"NaT0vv\":[\"API protocol to use for live stream extraction. WUP is the standard protocol for the PC app. Note that WUP and MP only work with numeric room IDs.\"],\"NeoigG\":[\"File size threshold (--multi-thread-cutoff).\"],\"NggFDy\":[\"Total system load\"],\"NhNdzH\":[\"Runs once after the recording session concludes and all individual segment pipelines have finished. Ideal for session-wide actions like merging all segments, final notifications, or cleanup.\"],\"Nhf7R9\":[\"Telegram Desktop Directory\"],\"Ni3LjM\":[\"Resolution change\"],\"NkYQEG\":[\"i\"],\"NmgWBj\":[\"Failed to delete workflow: \",[\"0\"]],\"NnH3pK\":[\"Test\"],\"NoNwIX\":[\"Inactive\"],\"NomFnG\":[\"No changes detected\"],\"NrNA7z\":[\"Create your first job preset to define reusable processor configurations for your pipelines.\"],\"NstKgB\":[\"best\"],\"Nu4DdT\":[\"Sync\"],\"Nu4oKW\":[\"Description\"],\"Nw_bTM\":[\"In active\"],\"NxVfIs\":[\"Overall Health\"],\"Nz-2uW\":[\"Download Engine\"],\"Nzwli2\":[\"Base\"],\"O3-SAG\":[\"Recording will start when schedule allows\"],\"O7sQsS\":[\"Desktop notifications are shown by the operating system even when the app is minimized.\"],\"O92EVI\":[\"Verify Output\"],\"OD_lHs\":[\"Add an override to customize behavior for specific platforms.\"],\"OGA8CA\":[\"Remux / Transcode\"],\"OGryJ9\":[\"Move File\"],\"OH6m8Z\":[\"No other steps available to depend on.\"],\"OHthyf\":[\"Rejected\"],\"OIx5Vo\":[\"Basic details for this configuration template.\"],\"OJx3wK\":[\"Not available\"],\"OL5-bu\":[\"/usr/share/fonts\"],\"ONWvwQ\":[\"Upload\"],\"ONchxy\":[\"total\"],\"OO3KPE\":[\"No configuration.\"],\"OOXKcO\":[\"Select the events that should trigger notifications for <0>\",[\"0\"],\"</0>.\"],\"OR475H\":[\"Network\"],\"ORCmn3\":[\"Avatar URL (Optional)\"],\"OS845W\":[\"A unique name for this configuration template.\"],\"OSkKYq\":[\"Platform Configuration\"],\"OUlu9A\":[\"Sample Rate (Hz)\"],\"OXyghd\":[\"Failed to load jobs: \",[\"0\"]],\"Oc-UBb\":[\"Fine tune brand and surface colors.\"],\"OfhWJH\":[\"Reset\"],\"Oju_Jw\":[\"Replace output files\"],\"OkJ0bQ\":[\"TDL status unknown\",[\"version\"]],\"Okz6xA\":[\"Out of Schedule\"],\"OlFEI3\":[\"Skip after both\"],\"OlXThP\":[\"Bandwidth\"],\"Om3XSw\":[\"API Settings\"],\"Oq4ArH\":[\"Unrecognised event kind.\"],\"OqVga-\":[\"Cheap operations, so the default of 8 is usually fine. Increase if you have many small files against a slow remote.\"],\"OrxruT\":[\"Copy Directory\"],\"Ou1XUj\":[\"Validation Failed\"],\"OurehU\":[\"Platform Detected: \",[\"detectedPlatform\"]],\"OvTPz2\":[\"Select operation\"],\"Ovx4g5\":[\"https://twitch.tv/...\"],\"OxBy4W\":[\"Continue on Error\"],\"P-1VRa\":[\"Platform configurations will appear here when available.\"],\"P4KIZR\":[\"Remux to MKV\"],\"P5eNZ-\":[\"Last Error Log\"],\"P6F38F\":[\"Subscriptions\"],\"P8fBlG\":[\"Authentication\"],\"PE3gQR\":[\"CDN\"],\"PFFhCT\":[\"Height\"],\"PFgYun\":[\"Untitled Stream\"],\"PH-ukv\":[\"-reconnect 1\"],\"PIjwVk\":[\"Generate a Full HD thumbnail (1280px width) for modern displays and video players.\"],\"PJDaJr\":[\"Select the days required for this filter to apply.\"],\"PJf7YE\":[\"AFTER: \",[\"0\"]],\"PMM5g4\":[\"Custom FFmpeg\"],\"PRzc1D\":[\"Upload and Delete\"],\"PTBQLa\":[\"Create Directories\"],\"PTI2EV\":[\"(Delete source)\"],\"PUbRZA\":[\"Maximum number of retry attempts.\"],\"PVRSCC\":[\"Loading presets...\"],\"PVnlzh\":[\"Add Step\"],\"Pa-XIK\":[\"Ready to Watch\"],\"PaO3bE\":[\"Session complete pipeline is not supported for this entity.\"],\"PaQ3df\":[\"Enable\"],\"Pcymhf\":[\"Waiting since\"],\"PezyAA\":[\"Update Channel\"],\"PfW3ld\":[\"Transient monitoring errors are being preserved until sustained download progress confirms the stream is healthy.\"],\"PfWJSn\":[\"Checkers\"],\"Pfx_nq\":[\"Component Status\"],\"PhEJJc\":[\"Directory to scan for new files after command execution\"],\"Pi72nP\":[\"Streamer Offline\"],\"PiH3UR\":[\"Copied!\"],\"PkUJDM\":[\"Gotify application token\"],\"Pn2B7_\":[\"Current password\"],\"Poh-g6\":[\"Create Pipeline\"],\"Pw01g0\":[\"Hide password\"],\"Pwqkdw\":[\"Loading…\"],\"PzFzS-\":[\"Add Filter\"],\"Q-6haG\":[\"No credentials found.\"],\"Q-pr7E\":[\"Danmu Statistics\"],\"Q0LExG\":[\"Login session cancelled\"],\"Q3D3r6\":[\"libx264\"],\"Q3TXK1\":[\"Consecutive Failures\"],\"Q5duaX\":[\"Floating\"],\"Q6hhn8\":[\"Preferences\"],\"Q7Xlbh\":[\"Douyu UHD rate\"],\"QBhhb7\":[\"Copy File\"],\"QCzB9v\":[\"Input Path\"],\"QDaWgK\":[\"DanmakuFactory\"],\"QEazml\":[\"Delete selected\"],\"QFO8CU\":[\"Convert danmu XML (Bilibili-style) into .ass subtitles using DanmakuFactory. Manifest-aware and batch-safe.\"],\"QHcLEN\":[\"Connected\"],\"QHhZeE\":[\"Template created successfully\"],\"QMb3Xr\":[\"Multiplier for exponential backoff.\"],\"QRAWTO\":[\"Form\"],\"QWdKwH\":[\"Move\"],\"Q_OjVh\":[\"Interval between checks when a streamer is live to detect when the stream ends.\"],\"Q_vbsf\":[\"Danmaku to ASS\"],\"Qc-YIj\":[\"Override configuration (paths, delays, etc.) for specific platforms.\"],\"QcH0aB\":[\"Lossless Split\"],\"QglPWi\":[[\"0\"],\" at \",[\"1\"]],\"Qj-jxB\":[\"Exit Immersive\"],\"QkHwaF\":[\"Web Push enabled for this browser\"],\"QlT4B5\":[\"Recent Sessions\"],\"QmWJ8K\":[\"587\"],\"QqGYdP\":[\"Select a default engine\"],\"QrXc9O\":[\"Disable selected\"],\"Qttcbs\":[\"Failed to trigger check\"],\"QvdYlQ\":[\"Fonts Directory\"],\"QxKlNl\":[\"GPU Standard\"],\"QyYvAV\":[\"Precision Schedule\"],\"R3w8ra\":[\"No keywords set\"],\"R7mB1g\":[\"Preview Unavailable\"],\"R8WLd-\":[\"Force IPv4 (Default: Off)\"],\"RAHVXV\":[\"If your account has Telegram 2FA enabled, use Desktop login (`-T desktop`) or run `tdl login` locally in a terminal.\"],\"RAMost\":[\"No outputs found\"],\"RDVO7W\":[\"Go to page \",[\"p\"]],\"RDf8Wy\":[\"GPU-accelerated post-processing: NVENC H.264 encoding and thumbnail generation.\"],\"RJEhaJ\":[\"https://lb-eu.cdn-perfprod.com\"],\"RMguW7\":[\"Platform configuration updated successfully\"],\"RP7-1c\":[\"Windows Console Error\"],\"RQI0-M\":[\"Output Detection\"],\"RRWxve\":[\"Client platform type used for stream authentication signing. Different platforms use different signing methods.\"],\"RRrXGo\":[\"Please enter your new password details\"],\"RS0o7b\":[\"State\"],\"RS9BzM\":[\"Treat interactive games as offline platforms during extraction checks.\"],\"RTR7ET\":[\"FLV parser\"],\"Ralayn\":[\"Add Tag\"],\"Ratxqc\":[\"Start Desktop Login\"],\"RbevHh\":[\"Batch parse error: \",[\"0\"]],\"RclaHZ\":[\"Runs when both the video segment and its corresponding danmu segment are available.\"],\"RdUvEI\":[\"Filename Template\"],\"RdhLi5\":[\"Pending Error Clears\"],\"RehBv_\":[\"Override global defaults for this streamer.\"],\"RhkH8I\":[\"My Channel\"],\"RkoA4Q\":[\"Working Directory\"],\"RmEeKl\":[\"bytes\"],\"RoOfVq\":[\"Date range:\"],\"RojhAf\":[\"Username (Optional)\"],\"RqGEko\":[\"Segment\"],\"Rsjgm0\":[\"Experimental\"],\"Rti4g1\":[\"The underlying tool used for downloading.\"],\"Ruqy5-\":[\"Required if the stream is password-protected by the broadcaster.\"],\"RxzN1M\":[\"Enabled\"],\"S-Irrb\":[\"Updates existing items and creates new ones. Nothing is deleted.\"],\"S-gCuS\":[\"HTTP/2 Only\"],\"S0kLOH\":[\"ID\"],\"S1Avy-\":[\"Danmu statistics are not available for this session.\"],\"S2X4xO\":[\"No output generated\"],\"S32Mbj\":[\"Platform not found\"],\"S34mdL\":[\"Default password for password-protected rooms. Can be overridden per streamer with ?pwd= in the URL.\"],\"S4s1m4\":[\"Failed to update preset: \",[\"0\"]],\"S5wLae\":[\"Enter a URL to parse and play a stream\"],\"SCZJhh\":[\"Audio Bitrate\"],\"SE58Y_\":[\"Manage where system notifications are sent\"],\"SG6r-s\":[\"Password / 2FA blocked\"],\"SHEb8h\":[\"No presets found\"],\"SHZOTz\":[\"Failed to update streamer\"],\"SJV-d4\":[\"Use stream start time to keep a midnight-crossing stream in one dated folder and group all segments of a session together.\"],\"SJd-31\":[\"Use System Proxy\"],\"SJmm2B\":[\"Fast GPU-accelerated AV1 encoding using NVIDIA NVENC (CQ 28). Requires RTX 4000+ series.\"],\"STFGOH\":[\"HTTP middleware\"],\"SWoipt\":[\"Depends On (Ancestors)\"],\"SYKx8m\":[\"Generate new QR code\"],\"SZIVAR\":[\"Download Manager\"],\"SZw9tS\":[\"View Details\"],\"S_H6kY\":[\"Force IPv6 (Default: Off)\"],\"S_tge3\":[\"Output paths, templates, and formats.\"],\"Sel_id\":[\"Paired Segment\"],\"ShPPm7\":[\"No title changes or lifecycle events were recorded for this session.\"],\"SjnTbJ\":[\"Advanced Configuration\"],\"Sl7mrQ\":[\"Split after size.\"],\"SlfejT\":[\"Error\"],\"Svkela\":[\"Go to previous page\"],\"SwbFPL\":[\"streamlink\"],\"SwdouK\":[\"NVENC H.264 Low Latency\"],\"SxJsAt\":[\"Select your preferred color scheme.\"],\"Sxm8rQ\":[\"Users\"],\"T1SAsR\":[\"Session thumbnail\"],\"T3rjBy\":[\"Jobs will appear here when recordings are processed.\"],\"T4sjpn\":[\"Merge Changes\"],\"T5l00f\":[\"workflows\"],\"T6F_S1\":[\"Critical alert when the processing queue is full.\"],\"T7yhkH\":[\"Output Format\"],\"T8_uFg\":[\"Settings have been optimized for this platform.\"],\"T8drou\":[\"System Health\"],\"T91vKp\":[\"Play\"],\"TAsVLX\":[\"Failed to create template: \",[\"0\"]],\"TB_URP\":[\"Select date anchor\"],\"TCURBK\":[\"(Preserve source)\"],\"TH-C6m\":[\"Successfully deleted \",[\"0\"],\" sessions\"],\"TJBHlP\":[\"Standard\"],\"TKiEkr\":[\"Select current page\"],\"TLZ7Zy\":[\"Multi-thread upload only helps on large files; on small ones the splitting overhead outweighs the benefit.\"],\"TP4vdF\":[\"--hls-live-edge 3\"],\"TP9_K5\":[\"Token\"],\"TXe7lI\":[\"View Detailed Logs & Outputs\"],\"TYVgbP\":[\"Include\"],\"TYVyHm\":[\"No template assigned\"],\"T_R-Qz\":[\"Primary\"],\"TaKLqK\":[\"Exclude Patterns\"],\"TaeloW\":[\"Any priority\"],\"TduT07\":[\"Starting tdl process...\"],\"Tfm8LB\":[\"Matching resolution\"],\"TiE1q0\":[\"VOD Gap Strategy\"],\"TjrbDj\":[\"Uptime\"],\"Tkh4vv\":[\"Max Quality Thumbnail\"],\"TlWFTu\":[\"Downloading \",[\"0\"]],\"Tm4H6N\":[\"Type something...\"],\"TnMphH\":[\"Automate your stream recordings with ease and reliability.\"],\"ToM7t8\":[\"Prioritize specific CDN providers. Press Enter to add.\"],\"ToW2mM\":[\"Delete Engine?\"],\"Tof7pX\":[\"Jobs\"],\"Tu5he7\":[\"Treat interactive game streams (互动玩法) as offline.\"],\"Tu7ijM\":[\"Set \\\"TDL Binary Path\\\" to your `tdl` executable, or make sure `tdl` is in PATH.\"],\"Tu9eY0\":[\"Active Days\"],\"TxOTCX\":[\"Used for Desktop login (fallback); points to Telegram Desktop folder containing `tdata`.\"],\"TymLa2\":[\"Higher = faster on fast connections, but more aggressive on the remote and your CPU. Increase only if your network and provider can handle it.\"],\"Tz0i8g\":[\"Settings\"],\"U1Im-N\":[\"Template Name\"],\"U5tR0I\":[\"Runs when a download encounters an error.\"],\"U62Eow\":[\"Complete the Telegram login process in this terminal.\"],\"U80ejq\":[\"Consecutive Failures\"],\"U9NWQ6\":[\"Overall system status\"],\"UC17hj\":[\"Default password for password-protected rooms (can be overridden per-streamer with ?pwd= in the URL).\"],\"UCFp-L\":[\"Checking stream status...\"],\"UCRwk1\":[\"/path/to/rclone.conf\"],\"UGLwm7\":[\"Select priority\"],\"UHgtCG\":[\"No streamers found\"],\"UPqS9C\":[\"Health\"],\"UPyNTs\":[\"MB\"],\"URWWZg\":[\"e.g. High Quality Streamlink\"],\"UWuUpd\":[\"Execute shell commands on lifecycle events. Commands run in the system shell.\"],\"UYX0mr\":[\"Try adjusting your search or filters, or add a new streamer to start monitoring.\"],\"UZREi-\":[\"templates\"],\"UZc_Dd\":[\"No talker data\"],\"UaBHAx\":[\"Bilibili QR Login\"],\"UatJ7w\":[\"Exit selection mode\"],\"UbRKMZ\":[\"Pending\"],\"UcB-qb\":[\"Delete selected streamers?\"],\"UcL3cf\":[\"Accept Invalid Certs (Default: Off)\"],\"UhCJxj\":[\"Processor\"],\"UicgBF\":[\"Double click to edit items.\"],\"Uj7KrY\":[\"Use QR Login\"],\"UlKSMd\":[\"Left\"],\"Un7b8x\":[\"Top Words\"],\"Uns82o\":[\"Restart Required\"],\"UnuCpk\":[\"Execute Timeout\"],\"Ur7o1U\":[\"Local Archive\"],\"Urp3J5\":[\"Maximum compression to minimize storage usage, then delete original.\"],\"Uu2O-E\":[\"Runs when both video and danmu segments are available.\"],\"UwlIzL\":[\"Assign template\"],\"UxK12K\":[\"Recordings\"],\"UxpxnD\":[\"File Ops\"],\"UyWBj5\":[\"Unhealthy\"],\"Uypo6H\":[\"Timeout before cancelling IO-bound processors.\"],\"V3Lf0z\":[\"Upload (Rclone)\"],\"V50IgX\":[\"Failed to load workflows: \",[\"0\"]],\"V7Msqg\":[\"preset\"],\"V8SjEq\":[\"Include Keywords\"],\"VAn9oA\":[\"Critical alert when the GPU becomes unavailable to the container (most often the NVIDIA Container Toolkit cgroup-v2 issue), so NVENC remux/transcode jobs would fail.\"],\"VFX661\":[\"Skip after duration\"],\"VGt9mz\":[\"Trimming & Time\"],\"VJScHU\":[\"Reason\"],\"VJw9N6\":[\"Select a session\"],\"VLfBk9\":[\"Recovering\"],\"VPPVAx\":[\"Retries for locked files\"],\"VPqi9P\":[\"No parameters configured.\"],\"VQ4EeT\":[\"Optional description for this preset\"],\"VU-UYl\":[\"Channel updated\"],\"VUURcc\":[\"Effective Scope\"],\"VZobzV\":[\"QR code expired\"],\"Ve9s51\":[\"Paired pipeline is not supported for this entity.\"],\"VeOdql\":[\"Login to Telegram\"],\"Vflq7Z\":[\"This stream is live but the global download limit is fully in use. Recording and danmu collection will start automatically when a slot frees.\"],\"Vg88qu\":[\"Run a custom shell command with placeholders (e.g. \",[\"input\"],\", \",[\"inputs_json\"],\", \",[\"streamer\"],\", %Y%m%d).\"],\"VhBoUx\":[\"Loaded template: \",[\"0\"]],\"VhMDMg\":[\"Change Password\"],\"ViIBwn\":[\"channel1,channel2\"],\"Vk_Ar1\":[\"More filters\"],\"VlEnCC\":[\"Archive Format\"],\"VoevdT\":[\"Output Folder\"],\"VupaNT\":[\"Standard cron expression (sec min hour day mon dow).\"],\"VvhIcU\":[\"No custom tags added\"],\"Vw8l6h\":[\"An error occurred\"],\"VzJOiq\":[\"Pipeline Manager\"],\"W-0nOp\":[\"Offload Decryption (Default: On)\"],\"W0g6Ji\":[\"Metadata Variables\"],\"W0omwk\":[\"Design your automation pipeline step by step\"],\"W1jNHq\":[\"Scan with Bilibili mobile app\"],\"W5FKSG\":[\"On Download Complete\"],\"W743Px\":[\"Filtering & Behavior\"],\"W7R6SY\":[\"App Token\"],\"WCNCz3\":[\"123456:ABC-DEF...\"],\"WEYdDv\":[\"Recommended\"],\"WEmAU3\":[\"Definitive Offline\"],\"WIC5BC\":[\"Search presets...\"],\"WJAaWC\":[\"Target Danmus per Sample\"],\"WJXa70\":[\"Unix timestamp\"],\"WOfIql\":[\"Create New Preset\"],\"WRQtA_\":[\"e.g. 250M\"],\"WSlv-9\":[\"Select config\"],\"WSxWtw\":[\"Failed to enable Web Push\"],\"WWCeOb\":[\"Add module...\"],\"WXZiyd\":[\"Ultrafast H.264 encoding (CRF 26) for quick sharing.\"],\"W_-iQs\":[\"Fetcher\"],\"W_geaq\":[\"Select login type\"],\"WcOY3Y\":[\"Bot Token\"],\"WcgzjF\":[\"name@example.com\"],\"WdAjAz\":[\"Enable Proxy\"],\"Weq9zb\":[\"General\"],\"WgACNk\":[\"Move Files\"],\"WgCCHx\":[\"Runs when a download completes successfully.\"],\"WhUS54\":[\"ZIP Archive\"],\"WhgehB\":[\"Session Duration\"],\"X-oEI0\":[\"Parsing Stream...\"],\"X56hfZ\":[\"No platform configurations found. Please create one first.\"],\"X5P6yv\":[\"Recently updated\"],\"XB0Vn9\":[\"No Archives Found\"],\"XC33bC\":[\"H.264 medium compression (CRF 23) optimized for long-term storage.\"],\"XEH5pP\":[\"Max Stall Duration (ms)\"],\"XElSLJ\":[\"Login Args\"],\"XEpExY\":[\"Nth input: \",[\"input0\"],\", \",[\"input1\"],\"...\"],\"XF7Hlq\":[\"Start Over\"],\"XJumtU\":[\"Reusable processor configurations for pipeline steps\"],\"XOxZT4\":[\"Signing in...\"],\"XPG0DN\":[\"New Template Name\"],\"XQaZzY\":[\"TPS Burst\"],\"XS1iGf\":[\"Keep directory structure\"],\"XTz5Sa\":[\"rclone default: 4\"],\"XU5AOg\":[\"Input\"],\"XVCljg\":[\"Multi-Thread Copy\"],\"XWgxXq\":[\"Album\"],\"XY_iWu\":[\"Network & Content\"],\"X_MmPT\":[\"Process video and extract podcast audio in parallel, then upload both.\"],\"Xit5Tv\":[\"Audio Properties\"],\"XjbMaz\":[\"Back to Pipeline Jobs\"],\"XjoBtr\":[\"Filtered:\"],\"XmKjfh\":[\"Recording\"],\"XvPVPo\":[\"Configure and launch a manual processing pipeline.\"],\"XvjC4F\":[\"Saving...\"],\"Xy0Hxb\":[\"Rust regex syntax supported.\"],\"Y27roI\":[\"Custom rate\"],\"YDVUVl\":[\"Event Types\"],\"YMf077\":[\"OAuth Token\"],\"YPcVP2\":[\"No presets yet\"],\"YVNfpq\":[\"(Mirror source)\"],\"YXjgxA\":[\"System-wide preferences\"],\"Ya3PlJ\":[\"Permission required\"],\"YdqAGb\":[\"View Danmu\"],\"YjhKI5\":[\"Cloning...\"],\"YqG2MJ\":[\"Set constraints on downloads.\"],\"Ysuy2A\":[\"Template cloned successfully\"],\"Yu2FvR\":[\"This will permanently delete the preset \\\"\",[\"0\"],\"\\\". Pipelines using this preset may fail if not updated.\"],\"YuTKa4\":[\"Failed\"],\"Yy3BPK\":[\"Maximum quality compression with HEVC, then upload to cloud storage.\"],\"Z0iPqp\":[\"Configure download engine parameters\"],\"Z3KFHf\":[\"Backoff Multiplier\"],\"Z5vYTW\":[\"Manage your application configuration.\"],\"ZAVcwe\":[\"Changing Password...\"],\"ZBMTd_\":[\"No matching events found\"],\"ZF0SVM\":[\"Streams per file (--multi-thread-streams).\"],\"ZKI9Rd\":[\"Enable advanced HLS segment reconstruction\"],\"ZMViOQ\":[\"Total size:\"],\"ZNKcYa\":[\"All Levels\"],\"ZNg9mI\":[\"Restore password\"],\"ZOK2-a\":[\"type=bolt,path=/data/tdl\"],\"ZOtn9F\":[[\"minutes\"],\"m\"],\"ZTc3M9\":[\"Completed\"],\"ZWRt1W\":[\"Output Path\"],\"ZWZjuT\":[\"Variant\"],\"ZYn2Q_\":[\"Create Filter\"],\"ZYnwzF\":[\"Disconnected\"],\"Za9T3e\":[\"Check duration\"],\"Zag-nw\":[\"Rclone Transfer\"],\"ZfRjhV\":[\"Max CPU Jobs\"],\"Zi7dyz\":[\"Script\"],\"Zl_Fwr\":[\"Manage specialized extraction and identification options.\"],\"ZlzjXV\":[\"Passthrough Inputs\"],\"Zrh7RT\":[\"Failed to update platform: \",[\"0\"]],\"Zv4awZ\":[\"128k\"],\"Zw6LWb\":[\"Failed to delete job\"],\"ZyqOPH\":[\"Credential Source Status\"],\"Zz_XpE\":[\"Per-Streamer\"],\"Zza1dz\":[\"NVENC HEVC Fast\"],\"_-DHD0\":[\"Configure a new streamer to monitor and record.\"],\"_0ftcA\":[\"Error Loading Session\"],\"_73JEY\":[\"TPS Burst help\"],\"_Bh-7r\":[\"Performance\"],\"_BzI2m\":[[\"0\",\"plural\",{\"one\":[\"#\",\" Error\"],\"other\":[\"#\",\" Errors\"]}]],\"_Ct04k\":[\"Generate a maximum quality thumbnail (1920px width) preserving full 1080p detail.\"],\"_GsGHq\":[\"Job creation time (default)\"],\"_HgF9q\":[\"Sort by\"],\"_I6a4I\":[\"No platforms configured\"],\"_K2CvV\":[\"Template\"],\"_KseE7\":[\"Delete File\"],\"_M7YFj\":[\"Quality (qscale)\"],\"_NgV8X\":[\"Triggered when a pipeline job is cancelled.\"],\"_O8edb\":[\"Upload Arguments\"],\"_PAAt2\":[\"0 = Unlimited\"],\"_QU1-7\":[\"Login Type\"],\"_Qf5fl\":[\"Priority Filter\"],\"_SvQT0\":[\"Failed to download logs\"],\"_TEOcd\":[\"Presets\"],\"_VaoHF\":[\"Job cancelled\"],\"__tkD5\":[\"Segment TTL (ms)\"],\"_bBHGW\":[\"Delete Pipeline?\"],\"_bM9cT\":[\"GPU HQ Archive\"],\"_cnOWE\":[\"Send a website-style integrity token with studio requests. Enabled by default; disable only if minting fails in your network.\"],\"_gYAMa\":[\"Chat ID\"],\"_gn6RG\":[\"Fatal Error\"],\"_hKQcr\":[[\"seconds\"],\"s\"],\"_jQctM\":[\"To\"],\"_kwOBw\":[\"Job Timeouts\"],\"_l-j6K\":[\"Logs downloaded successfully\"],\"_l7qFE\":[\"Recording started\"],\"_oqig9\":[[\"hours\"],\"h\"],\"_p1aNb\":[\"Error loading workflow\"],\"_rTz0M\":[\"Audio\"],\"_srpvQ\":[\"Failed to delete channel\"],\"_tQOXN\":[\"Active Download\"],\"_w3Fy0\":[\"Define how this step identifies itself and connects to others.\"],\"_wFMEI\":[\"PENDING CONFIRMATION\"],\"_xVC1p\":[\"TDL Status\"],\"_xfOhY\":[\"Media Timeline\"],\"_zg39j\":[\"Dry Run\"],\"a0w0iQ\":[\"Subtitle\"],\"a4Dp3l\":[\"Channel deleted\"],\"a9L56c\":[\"Select behavior\"],\"aDPadU\":[\"Triggered when a new file segment is created.\"],\"aFWU1n\":[\"Width\"],\"aFk-Ho\":[\"This streamer is currently live, but outside your configured recording schedule.\"],\"aH0Q8c\":[\"Select strategy\"],\"aIUW65\":[\"Lowest bitrate\"],\"aS09EA\":[\"Receive a notification when a stream ends.\"],\"aS11pb\":[\"Select a processing job or workflow to add to your pipeline.\"],\"aVJmtU\":[\"Streams extracted\"],\"aZ2Kcr\":[\"Configure where and how you receive notifications.\"],\"aZUYz0\":[\"Fast GPU-accelerated HEVC/H.265 encoding using NVIDIA NVENC (CQ 24). Smaller files than H.264.\"],\"acyWOE\":[\"Timeout (s)\"],\"afHZ2T\":[\"SESSION ENDED\"],\"ailPEn\":[\"Event Type\"],\"aj9IS5\":[\"Concurrency\"],\"akGgwH\":[\"Selected stream\"],\"akShFD\":[\"Cleanup\"],\"amsJwt\":[\"Receive a notification when a streamer goes live.\"],\"aoDNXt\":[\"Operation Mode\"],\"atAJGZ\":[\"platforms\"],\"atJjwW\":[\"e.g. echo \",[\"input\"],\" > \",[\"output\"]],\"azIGX7\":[\"Overwrite existing files\"],\"azuNEn\":[\"Pipeline is already completed or cancelled\"],\"b21pJB\":[\"Playlist TTL (ms)\"],\"b24kPi\":[\"Queue\"],\"b2MuzC\":[\"Time offset to take screenshot from\"],\"b2Nri6\":[\"Pool Idle Timeout (ms)\"],\"b7Incv\":[\"Operation Type\"],\"b7wV8U\":[\"MP\"],\"b92aqq\":[\"Loading older logs...\"],\"bAaOjo\":[\"Capture separate stream data.\"],\"bFAwGy\":[\"Select a preset or type a Douyu rate. Audio only requests AAC without video.\"],\"bGboGD\":[\"Nth output: \",[\"output0\"],\", \",[\"output1\"],\"...\"],\"bLK9zG\":[\"Protection Settings\"],\"bMD8SW\":[\"Auto-Refresh\"],\"bOj5u_\":[\"No Steps Added\"],\"bRVIqV\":[\"Suppress redundant headers to reduce player micro-stutter\"],\"bT3NnO\":[\"Media outputs will appear here when pipeline jobs complete.\"],\"bYkKu9\":[\"Runs when a download begins.\"],\"bZ_nY3\":[\"Twitch Proxy Playlist\"],\"bagRV4\":[\"Add New Streamer\"],\"bb6TRf\":[\"Min Segment Size\"],\"bcfSrW\":[\"Database queries\"],\"bd8uQe\":[\"FFmpeg Video Filter\"],\"bdSNjC\":[\"Temporarily Paused\"],\"be6yVE\":[\"e.g. My Favorite Streamer\"],\"bex7vZ\":[\"Failed to import configuration\"],\"bgAdR4\":[\"List of allowed file extensions (without dot).\"],\"bgdEys\":[\"Web Push is not supported in this browser\"],\"bjp1xg\":[\"Border Radius\"],\"bkLoJJ\":[\"Second (00-59)\"],\"bkZWD4\":[\"Credentials refreshed successfully\"],\"bqYxTf\":[\"Audio Extraction\"],\"bql7qo\":[\"Backjump Threshold\"],\"bwRvnp\":[\"Action\"],\"bzOX-6\":[\"Platform URL extractors\"],\"c0LHG_\":[\"Pipeline Job Timeouts\"],\"c2Qn8C\":[\"Select preset\"],\"c3b0B0\":[\"Get Started\"],\"c5Xt89\":[\"Name Z-A\"],\"c5qkaI\":[\"Monitoring Stopped\"],\"c6pG_r\":[\"Engine created successfully\"],\"c7Ho94\":[\"JSON array of all outputs\"],\"c8-mAV\":[\"Segment Timeout (ms)\"],\"c8iRL_\":[\"Refine and sort streamers\"],\"c8nkFX\":[\"Time to wait for ffmpeg to finalize the file before force-killing it\"],\"cCbxO-\":[\"Extract audio track to MP3 format (192kbps). Good for podcasts and music.\"],\"cCfxH1\":[\"Downloading...\"],\"cDD5xY\":[\"Last Error\"],\"cDR51K\":[\"Default pipeline flow. Configure the sequence of processors for new jobs.\"],\"cEHxsl\":[\"Audio codec change\"],\"cH5kXP\":[\"Now\"],\"cLVp5Z\":[\"Log Files\"],\"cNC2t_\":[\"Copy the file to another location. Keeps the original file.\"],\"cO9-2L\":[\"Disable\"],\"cP_zDS\":[\"Rate-limits API calls to the remote. Useful when a provider returns 429 \\\"too many requests\\\" under heavy load.\"],\"cRxiHL\":[\"Import Successful\"],\"cS8JKn\":[\"Failed to delete session\"],\"cSCbv3\":[\"BETA\"],\"cSadgt\":[\"Delete Source\"],\"cSev-j\":[\"Filters\"],\"cVEhWm\":[\"Parse Mode\"],\"cX2i4T\":[\"First input file path\"],\"cXFXEn\":[\"Audio only\"],\"cXKoLz\":[\"Save Workflow\"],\"cd0jIM\":[\"Job Details\"],\"ce5XJx\":[\"No active recordings\"],\"cgDZLv\":[\"Triggered when video and danmu are available\"],\"ci-Ad9\":[\"Quick Share\"],\"ckH3fT\":[\"Ready\"],\"cklVjM\":[\"Timeline\"],\"ckoeXQ\":[\"Filename pattern (e.g., thumb.jpg)\"],\"cl-3tt\":[\"Stream Details\"],\"cnGeoo\":[\"Delete\"],\"cncExx\":[\"The configuration has been successfully processed. Here is a summary of the changes:\"],\"cp8Gv3\":[\"Regex patterns to exclude files. Matched against full path and filename.\"],\"cpbupq\":[\"High Quality Archive\"],\"cqFv88\":[\"Exclude\"],\"cs0tlO\":[\"Import Strategy\"],\"cupeHb\":[\"e.g., process-video\"],\"cxkEMX\":[\"Processed \",[\"0\"],\" streamers; \",[\"1\"],\" failed\"],\"cyBb5d\":[\"Decryption\"],\"d-F6q9\":[\"Created\"],\"d-eBFj\":[\"Configure granular options for the selected engine.\"],\"d1yanO\":[\"No logs to display\"],\"d2gWS6\":[\"The direct link to the channel or stream.\"],\"d3IqDh\":[\"Quality\"],\"d4uXwk\":[\"Next\"],\"d6CET4\":[\"Adaptive Refresh (Default: On)\"],\"d6ituE\":[\"Auto-scroll disabled\"],\"d7Kp2g\":[\"Break\"],\"dEXRlZ\":[\"Step Library\"],\"dEgA5A\":[\"Cancel\"],\"dF6vP6\":[\"Live\"],\"dISV1x\":[\"Bandwidth Limit\"],\"dK3Z9j\":[\"Component\"],\"dMtwVQ\":[\"NVENC H.264 Fast\"],\"dN7wel\":[\"Triggered when a download fails with an error.\"],\"dNmZ33\":[\"Workflow not found\"],\"dONv4O\":[\"Channel buffer size multiplier for processed segments.\"],\"dP2MYa\":[\"Preset deleted successfully\"],\"dPyJ15\":[\"Delete Template\"],\"dQfGrq\":[\"Fatal error\"],\"dTuElw\":[\"Add Custom Tag\"],\"dW40Uz\":[\"No events found\"],\"dXcu-7\":[\"Remux\"],\"da5jry\":[\"Stream monitoring encountered issues\"],\"de7Gux\":[\"Download Retry Policy\"],\"dg3VQG\":[\"Proxy Strategy\"],\"dgYq4k\":[\"available\"],\"dhvw0h\":[[\"0\",\"plural\",{\"one\":[\"#\",\" Line\"],\"other\":[\"#\",\" Lines\"]}]],\"djLR5U\":[\"Manifest (Pair by Order)\"],\"dkjxU6\":[\"Detailed health checks for internal services and resources.\"],\"dmsn13\":[\"Header received\"],\"dnUaj8\":[\"Failed to clone template: \",[\"0\"]],\"dsn408\":[\"Force overwrite if output exists\"],\"dsphkM\":[\"Full multimedia processing: Remux video, extract audio and thumbnail in parallel, then upload all.\"],\"dx8VNK\":[\"Engine updated successfully\"],\"dxj6g1\":[\"Interval between checks.\"],\"dxwgX3\":[\"Pipeline Name\"],\"e-gPhp\":[\"Follow Redirects (Default: On)\"],\"e20CFN\":[\"This filter activates only during the window highlighted on the timeline. If the start time is after the end time, the window will wrap around midnight automatically.\"],\"e2BurN\":[\"Extract audio to high-quality MP3 (320kbps) for podcast distribution.\"],\"e3P3Sz\":[\"Max Bytes\"],\"e3_ja4\":[\"Name A-Z\"],\"e4_PV8\":[\"e.g. gdrive:/videos\"],\"e65FAh\":[\"This Delete step depends on <0>\",[\"transformDepLabel\"],\"</0> and will delete the converted result, not your original recording. To delete the original after converting, enable \\\"Remove Input on Success\\\" on the transcode step. Add this Delete step anyway?\"],\"e6MUfG\":[\"Max Part Size\"],\"e9-VJo\":[\"Output Pattern (Optional)\"],\"eCiRz_\":[\"both directions at 10 MiB/s\"],\"eE0JZ4\":[\"Version\"],\"eEMRgC\":[\"File Extension Filter\"],\"eG3YoN\":[\"ETA\"],\"eNncgX\":[\"Copy/Move\"],\"eOpIyK\":[\"Video only\"],\"ePK91l\":[\"Edit\"],\"eUQA5e\":[\"No issues detected\"],\"eYYXD2\":[\"Concurrent checkers (--checkers).\"],\"eYe1Rb\":[\"Failed to export configuration\"],\"eZO3cK\":[\"Buffer Limits\"],\"eZgbDb\":[\"High-priority slot waiting (concurrency limit reached)\"],\"ebJPUJ\":[\"Complete workflow: Remux, generate thumbnail, add metadata, and upload.\"],\"ebiBIb\":[\"Notification Retention\"],\"ecUA8p\":[\"Today\"],\"edQdmU\":[\"FFmpeg Audio Filter (-af)\"],\"ehe4bj\":[\"Force Mobile API\"],\"ejQ0z7\":[\"Overwrite Existing\"],\"emYiLJ\":[\"Platform Override Settings\"],\"enj3eJ\":[\"Theme Mode\"],\"es0mu4\":[\"Critical Only\"],\"evbMWR\":[\"Output Arguments\"],\"ewB4dk\":[\"e.g., Standard Processing\"],\"ex8PR1\":[\"Retry All Failed\"],\"exyoIu\":[\"Search sessions...\"],\"f2d4Ps\":[\"Session Menu\"],\"f4u7wL\":[\"Timing & Delays\"],\"f4yUH6\":[\"Multi-Thread Cutoff help\"],\"f76EEx\":[\"Filter by title keywords\"],\"f77MVE\":[\"Encoding Preset\"],\"f8A73b\":[\"44100\"],\"f8hHmU\":[\"Failed to create pipeline job\"],\"f8zoXj\":[\"Error Loading Pipeline\"],\"fAGXwp\":[\"Triggered when streamer configuration is dynamically updated.\"],\"fB-Bdg\":[\"Last check \",[\"0\"],\" ago\"],\"fF22or\":[\"Danmu Stream Closed\"],\"fHh0Nn\":[\"e.g. up -c @channel -p \",[\"input\"]],\"fLcXDs\":[\"Recording Filters\"],\"fNsYxz\":[\"Add a module above to configure its log level\"],\"faK1M-\":[\"Allowed Extensions\"],\"fcWrnU\":[\"Sign out\"],\"fcirqq\":[\"This will permanently delete the workflow \\\"\",[\"0\"],\"\\\". Streamers using this workflow will need to be updated.\"],\"fiPesM\":[\"Method\"],\"fif2cG\":[\"Search workflows...\"],\"fknQhj\":[\"Destination Directory\"],\"fkpv9h\":[\"Search streamers...\"],\"fnASK5\":[\"Normal+\"],\"fpMs2Z\":[\"LIVE\"],\"fqJjiU\":[\"Runs for each recorded segment immediately after it's finished.\"],\"frUGOs\":[\"Automatically generate a thumbnail for the first segment of each session.\"],\"fss9hD\":[\"Open quality options\"],\"fvL_Ho\":[\"Units are bytes (default base KiB/s). Use suffixes B, K, M, G, T, P for larger sizes.\"],\"g-gBfk\":[\"Parsing...\"],\"g6BpXZ\":[\"Cancelled\"],\"g7_-Qd\":[\"Comma-separated list of channels to exclude from proxy\"],\"g90PVF\":[\"Step Configuration\"],\"gCv-nS\":[\"Max Idle per Host\"],\"gEQH50\":[\"Caps overall transfer bandwidth (rclone --bwlimit).\"],\"gM5LKb\":[\"This stream is already active\"],\"gORFgZ\":[\"Cancel Execution\"],\"gQG_W7\":[\"Powered by <0>Rust-srec</0>.\"],\"gUjkl_\":[\"Down\"],\"gVDufW\":[\"Define sequences of processing steps for your recordings\"],\"gVxTf6\":[\"Exports include all global settings, platforms, templates, streamers, engines, and notification channels. Sensitive data like passwords might be redacted or encrypted depending on platform settings.\"],\"gWdWG8\":[\"Collapsible\"],\"gb0sx5\":[\"Key Retry Delay (ms)\"],\"gc3su8\":[\"Subtitle Matching\"],\"gdA_lQ\":[\"Playlist Gone\"],\"gh06VD\":[\"Output\"],\"ghnHjn\":[\"Number of consecutive failed checks required to definitively confirm a streamer has gone offline. Higher values prevent \\\"fake\\\" offline detection.\"],\"glK8MG\":[\"Pipeline Jobs\"],\"gliRBz\":[\"Processing nodes\"],\"gnNUHR\":[\"This step is a sub-workflow: <0>\",[\"workflowName\"],\"</0>.\"],\"gpUgZ_\":[\"0 (None) - 9 (Best)\"],\"gptVEm\":[\"Create a filter to control when this streamer is recorded. You can filter by time, keywords, categories, and more.\"],\"gq-Q4J\":[\"Loading preset details...\"],\"gq6RwY\":[\"Failed to load danmu statistics.\"],\"gqZ7EH\":[\"Capture real-time comments and chat messages if available.\"],\"guy7NI\":[\"Modify the parameters for this processing step.\"],\"h1-4V4\":[\"Refresh failed: Manual login required\"],\"h5b8Gg\":[\"Failed to load outputs: \",[\"0\"]],\"hFcCJR\":[\"Limits each individual file's transfer rate rather than the overall total.\"],\"hKqbBO\":[\"Successfully processed \",[\"0\"],\" streamers\"],\"hM6-6Z\":[\"Session ID is required\"],\"hNmOZ7\":[\"Set priority\"],\"hO9jJx\":[\"Logging configuration updated\"],\"hQOBwU\":[\"Wait\"],\"hSHVF0\":[\"Check size after copy\"],\"hSj3SO\":[\"Custom Configuration\"],\"hV6FeJ\":[\"Throughput\"],\"hVDqsJ\":[\"HD\"],\"hVuIVS\":[\"Advanced scheduling\"],\"hXA9xB\":[\"Web Optimize\"],\"hXzOVo\":[\"Next\"],\"hY0vvK\":[\"A unique ID used by other steps to reference this one.\"],\"hYgDIe\":[\"Create\"],\"hZ6znB\":[\"Port\"],\"hdsaJo\":[\"Importing...\"],\"hdyeZ0\":[\"Delete Job\"],\"he3ygx\":[\"Copy\"],\"hgpMHD\":[\"Total Size\"],\"hmqzwF\":[\"Peak / min\"],\"hrDkPB\":[\"Basic settings for the streamer.\"],\"ht1ran\":[\"Try adjusting your search.\"],\"hul8PJ\":[\"Failed to autofill name\"],\"huzp0F\":[\"Telegram Desktop data required\"],\"hx-EJH\":[\"Max Segments\"],\"hyCRuI\":[\"Size limit\"],\"i-GrRR\":[\"Engine used for downloads when not specified by platform/streamer.\"],\"i09z4S\":[\"Month (01-12)\"],\"i0qMbr\":[\"Home\"],\"i2yKvD\":[\"STREAMER\"],\"i4y2wE\":[\"Description is managed by the system\"],\"iAJgTr\":[\"404\"],\"iAL9tI\":[\"Configure\"],\"iB8z_j\":[\"Stream Options\"],\"iBQU3w\":[\"Password change required\"],\"iDNBZe\":[\"Notifications\"],\"iDpc_N\":[\"Loading editor components...\"],\"iE4x2o\":[\"Select codec\"],\"iEfPm1\":[\"Twitch (ttv-lol)\"],\"iFIj9t\":[\"Save settings to a JSON file\"],\"iH8pgl\":[\"Back\"],\"iI8ncW\":[\"Enter categories to match against (e.g. Just Chatting, Gaming).\"],\"iKvAeG\":[\"Checking\"],\"iLYPXc\":[\"DAG Graph\"],\"iMAQTQ\":[\"Year (4 digits)\"],\"iToNdZ\":[\"SOOP account used to watch login-required (e.g. 19+) broadcasts. Prefer cookies for permanently restricted channels.\"],\"iTylMl\":[\"Templates\"],\"iWGuCP\":[\"No configuration form available for this processor.\"],\"iaPtht\":[\"copy\"],\"iaocTt\":[\"Ratio\"],\"iftgVc\":[\"No custom headers configured\"],\"ij74Jr\":[\"Schedule recording times\"],\"ilOZeV\":[\"Initial Delay (ms)\"],\"imXBeM\":[\"Consecutive failed checks needed to confirm offline.\"],\"ipzg8L\":[\"No modules configured\"],\"isRobC\":[\"New\"],\"iv8DdK\":[\"UPDATE\"],\"ivJkrj\":[\"Create New Workflow\"],\"iwyTQi\":[\"Are you sure you want to delete \",[\"0\"],\" sessions? This action cannot be undone.\"],\"iyCIi4\":[\"Parse Batch\"],\"j-MPXl\":[\"Backup & Restore\"],\"j03sWF\":[\"Triggered after the entire session ends\"],\"j0rT_n\":[\"Select or type a rate\"],\"j6ZZIX\":[\"CRF (0-51)\"],\"j7AbzC\":[\"Drag from edge to link steps\"],\"jAwQYJ\":[\"Prefer QR login first; Phone & Code (2FA optional) second; Desktop last.\"],\"jC5etE\":[[\"0\",\"plural\",{\"one\":[\"file\"],\"other\":[\"files\"]}]],\"jEw0Mr\":[\"Please enter a valid URL\"],\"jGmy11\":[\"Active Recordings\"],\"jIFKq_\":[\"Variant Selection Policy\"],\"jIPNJG\":[\"Basic Information\"],\"jKHfGg\":[\"Danmaku to ASS Subtitles\"],\"jKPJlR\":[\"Create missing folders\"],\"jKttAE\":[\"Hooks\"],\"jNqP-h\":[\"Failed to extract name from URL\"],\"jQh979\":[\"Sample danmu at a constant time interval.\"],\"jR5LZx\":[\"On Download Error\"],\"jRPz-T\":[\"Clone Template\"],\"jWXukA\":[\"This login requires a Telegram 2FA password. Enable \\\"Allow 2FA\\\" and restart.\"],\"jXwBNq\":[\"CRF (Constant Rate Factor)\"],\"jems_i\":[\"Per-File Bandwidth Limit help\"],\"jfsP4q\":[\"Upload All Files\"],\"jmbIS6\":[\"Desktop Notifications\"],\"joJBB2\":[\"Create your first workflow to define a sequence of processing steps for your recordings.\"],\"jpctdh\":[\"View\"],\"juTcaJ\":[\"NVENC H.264 High Quality\"],\"jvivc3\":[\"Session ID\"],\"k9Trbl\":[\"Create Preset\"],\"kEgzfB\":[\"How often rust-srec runs nvidia-smi to detect when the container loses GPU access (a known NVIDIA Container Toolkit issue on cgroup v2 hosts). Only active when nvidia-smi is available; otherwise the GPU row is not registered. Default 30 seconds. Changes apply on the next probe — no restart required. Values below 30 seconds are discouraged.\"],\"kF2Y0z\":[\"Offset Consistency Check\"],\"kG0i0h\":[\"Add anyway\"],\"kI1qVD\":[\"Format\"],\"kN8Y5L\":[\"CPU Job Timeout\"],\"kRJm8z\":[\"Time between sampling attempts.\"],\"kX97I8\":[\"e.g. 0:v:0, 0:a:0\"],\"kZV79T\":[\"Split Reason\"],\"kcR85X\":[\"Enter keywords to include...\"],\"kcoHto\":[\"Validation service unavailable\"],\"kfNspv\":[\"Engine Type\"],\"kjMqSj\":[\"Copy JSON\"],\"klOeIX\":[\"Failed to change password\"],\"kn1oU0\":[\"Logging paused\"],\"kne4l9\":[\"Compression\"],\"koRU21\":[\"Add extra argument\"],\"kqQaK3\":[\"Cookies Only\"],\"krzGzQ\":[\"Concurrent file transfers (--transfers).\"],\"kseNmN\":[\"This will permanently delete the notification channel \\\"\",[\"0\"],\"\\\".\"],\"kt6N2a\":[\"This Delete step will delete the files produced by <0>\",[\"transformDepLabel\"],\"</0> (the converted result), not your original recording. To delete the original source after converting, enable \\\"Remove Input on Success\\\" on the transcode step instead.\"],\"kwRUsJ\":[\"Job limits and engine settings.\"],\"l-RCZs\":[\"Create Workflow\"],\"l2Op2p\":[\"Query Parameters\"],\"l3s5ri\":[\"Import\"],\"l6DdQS\":[\"No detailed error message available\"],\"l6zyQF\":[\"Upload file to cloud storage using rclone. Configure remote in rclone config.\"],\"l7oQUQ\":[\"Wait time before starting.\"],\"l97zAi\":[\"Transcode H.264\"],\"l9wI00\":[\"Speed\"],\"lA6fmP\":[\"Scan Directory\"],\"lA8IlD\":[\"No platforms found\"],\"lBQSXl\":[\"e.g. Remux to H.264\"],\"lCF0wC\":[\"Refresh\"],\"lDvrUG\":[\"Failed to create streamer\"],\"lECX4V\":[\"Max IO Jobs\"],\"lJoArf\":[\"Cron Expression\"],\"lOyQ-B\":[\"Encoder Preset\"],\"lYLHvp\":[\"Concurrency limit reached\"],\"lcb8Hd\":[\"Compression Level\"],\"lfFsZ4\":[\"Channels\"],\"lj3GoP\":[\"No jobs found\"],\"lkz6PL\":[\"Duration\"],\"lqXgLk\":[\"Add an override to customize engine behavior for this template.\"],\"lqf04S\":[\"Secondary\"],\"ltYQCa\":[\"Since \",[\"0\"]],\"luB4tN\":[\"Choose how this streamer handles proxy connections.\"],\"m16xKo\":[\"Add\"],\"m2DSGV\":[\"Endpoint Configuration\"],\"m42dNa\":[\"Create a template to define reusable download configurations.\"],\"m48LOH\":[\"Hardware Acceleration\"],\"m50MxJ\":[\"Fetch Delay\"],\"m7AE5i\":[\"Failed to download file\"],\"m8LlVt\":[\"Splits a single large file into N parallel chunks for faster upload.\"],\"mCB6Je\":[\"Select All\"],\"mCc1g0\":[\"Failed to refresh credentials\"],\"mIbF__\":[\"Autofill name from URL\"],\"mKKr99\":[\"Restart with -d\"],\"mLVDOt\":[\"Command template args. Use \",[\"INPUT_TOKEN\"],\" and \",[\"OUTPUT_TOKEN\"],\" placeholders.\"],\"mO7heL\":[\"Media Outputs\"],\"mOXqr6\":[\"Password changed successfully. Please login with your new password.\"],\"mQe6uy\":[\"Streamer updated\"],\"mTw0S2\":[\"No media outputs generated yet.\"],\"mYGY3B\":[\"Date\"],\"meSGba\":[\"Progress:\"],\"mgOBre\":[\"Highest bitrate\"],\"mgtXne\":[\"Filter events below this priority\"],\"mgxDh9\":[\"Delete the converted result?\"],\"mkaDKN\":[\"Keep uploading other files if one fails\"],\"mkvLrG\":[\"Select category\"],\"mrmkFJ\":[\"Generate a thumbnail at native stream resolution (no scaling). Best quality, largest file size.\"],\"mrzV5V\":[\"Overwrite existing .ass files\"],\"mxhrdq\":[\"Danmaku\"],\"mxun7B\":[\"Runs when the streamer goes offline.\"],\"my6sDb\":[\"Search streamer...\"],\"mzDMo2\":[\"Time Based\"],\"mzI_c-\":[\"Download\"],\"mzffFb\":[\"Preset updated successfully\"],\"n-v6gq\":[\"Total Danmu\"],\"n1MDK4\":[\"Max Key Retries\"],\"n1ekoW\":[\"Sign In\"],\"n4AWhD\":[\"Explore matching automation steps to enhance your pipeline workflow.\"],\"n9yU9X\":[\"Live Preview\"],\"nBcxYG\":[\"Timeout before cancelling CPU-bound processors.\"],\"nC6Rpq\":[\"Add Stream\"],\"nDG2K-\":[\"Enable advanced FLV timestamp repairing\"],\"nEAJMt\":[\"Offline Check\"],\"nEJA8Y\":[\"Try adjusting the date range\"],\"nGC_6h\":[\"Add rclone argument\"],\"nGNP8v\":[\"Compression (Zip)\"],\"nGrU2q\":[\"Generate a high-resolution thumbnail (640px width) at 10 seconds.\"],\"nN1XNy\":[\"Overwrite Files\"],\"nOPbWJ\":[\"Right\"],\"nQM_01\":[\"Failed to delete preset: \",[\"0\"]],\"nQZ3Py\":[\"Failed to update workflow: \",[\"0\"]],\"nQibzB\":[\"Exclude streams from these CDN providers entirely. Press Enter to add.\"],\"nSHdq2\":[\"Triggered when a new download recording begins.\"],\"nSQv8Y\":[\"Auto-scroll enabled\"],\"nTWWCZ\":[\"Low\"],\"nUjQm2\":[\"Execution Settings\"],\"nVkOeu\":[\"TDL login session started\"],\"nWnBRV\":[\"Delivery Policy\"],\"n_s1Si\":[\"Replace destination files if they exist\"],\"ncwQad\":[\"(empty)\"],\"neL6ek\":[\"New Preset Name\"],\"nfwriv\":[\"Keep date and time tokens in your filename template when multiple sessions share one destination folder.\"],\"nhxAdQ\":[\"Keyword\"],\"nmD1Iv\":[\"Web Push allows receiving notifications even when you close the browser. It requires browser permission and valid server keys.\"],\"nnPJVX\":[\"Where TDL stores session data (`tdl --storage ...`). Example: `type=bolt,path=/data/tdl`.\"],\"nrLIEd\":[\"Split after duration.\"],\"nu0Tii\":[\"Attempts to get origin quality by replacing the audio stream. May result in audio-only streams if it fails.\"],\"nuh_Wq\":[\"Webhook URL\"],\"num3TS\":[\"Failed to load presets: \",[\"0\"]],\"nwIyEX\":[\"Fix HLS Discontinuities\"],\"nwObng\":[\"No media outputs yet\"],\"nxIXdN\":[\"Please fix validation errors\"],\"o21Y-P\":[\"entries\"],\"o5r6b2\":[\"Job deleted\"],\"o69yIu\":[\"Press Enter to add recipient\"],\"o6OQlp\":[\"Edit Channel\"],\"oBn_nO\":[\"Pipeline History Retention\"],\"oIg22A\":[\"Stream Password\"],\"oJJdGr\":[\"Destination Root\"],\"oKjqX-\":[\"Pipeline Execution\"],\"oLsREl\":[\"No word data\"],\"oMVAXw\":[\"Runs once after the entire session ends and all segment pipelines have completed.\"],\"oQw4Jd\":[\"SMTP Host\"],\"oV0Bem\":[\"Create Streamer\"],\"oaiKxc\":[\"Type your answer here...\"],\"oatACc\":[\"inline\"],\"ofUcbc\":[\"Random\"],\"ogaYcn\":[\"Shadcn UI Theme Presets\"],\"ojQSTc\":[\"Previous Title\"],\"okXKua\":[\"Mint Integrity Token\"],\"oke9ig\":[\"Streamer updated successfully\"],\"olkLN2\":[\"Single URL\"],\"oo16kX\":[\"No preview generated\"],\"opgoOE\":[\"Reorder Duration (ms)\"],\"orDnO-\":[\"Tracked Words\"],\"orI3hB\":[\"pagination\"],\"ovBPCi\":[\"Default\"],\"oyj1TW\":[\"No steps defined\"],\"p0dP0q\":[\"Generate multiple preview images at different timestamps for a gallery view.\"],\"p12eT9\":[\"Please select a JSON file\"],\"p27AhB\":[\"Phone & Code (2FA optional)\"],\"p2_GCq\":[\"Confirm Password\"],\"p2mB8S\":[\"Per-File Bandwidth Limit\"],\"p2sbyZ\":[\"Template not found\"],\"p5nYkr\":[\"View All\"],\"p6NueD\":[\"NEW\"],\"p8QOjt\":[\"Remux to MP4 with faststart flag for web streaming optimization.\"],\"pAtylB\":[\"Not Found\"],\"pD5RQD\":[\"Delete XML files after successful conversion\"],\"pECIKL\":[\"Search templates...\"],\"pHt_zF\":[\"Outside Schedule\"],\"pIKNYw\":[\"Ignore streams below this bitrate.\"],\"pKmdYH\":[\"Only include files with this extension (without dot)\"],\"pSNvte\":[\"Podcast Extraction\"],\"pWT04I\":[\"Checking...\"],\"pYUyoK\":[\"Default workflow: Remux to MP4 (deletes original), generate native-resolution thumbnail, upload both to cloud and delete local files.\"],\"pZBeKb\":[\"Pending Jobs\"],\"pZoiPG\":[\"Isolation level for tracking identifiers. Global is usually best.\"],\"p_78dY\":[\"Position\"],\"pb5U2q\":[\"Move the file to another location. Removes the original file.\"],\"peqX67\":[\"Metadata Tags\"],\"pj3WXl\":[\"--flag value\"],\"pjN1vk\":[\"e.g. C:\\\\Users\\\\...\\\\AppData\\\\Roaming\\\\Telegram Desktop\"],\"plUzgk\":[\"High quality H.265/HEVC compression (CRF 22). Smaller files but slower encoding.\"],\"pmXQXV\":[\"Streaming services\"],\"pqdYPk\":[[\"0\",\"plural\",{\"one\":[\"#\",\" Warning\"],\"other\":[\"#\",\" Warnings\"]}]],\"psqOpk\":[\"Add email and press Enter\"],\"ptAX-h\":[\"CPU Usage\"],\"puukLG\":[\"Marked all as read\"],\"pvnfJD\":[\"Dark\"],\"pwlRnQ\":[\"Metadata Editor\"],\"q-N7o6\":[\"Custom Flags\"],\"q3Dp_X\":[\"Include original files in outputs\"],\"q4bpUC\":[\"Output Configuration\"],\"q5nfv1\":[\"On Download Start\"],\"qC4H_F\":[\"Inspecting\"],\"qEH4Cy\":[\"Run a custom FFmpeg command. Edit the args to customize.\"],\"qEVp1d\":[\"Danmu Control\"],\"qGO8x4\":[\"Track Information\"],\"qH2T4F\":[\"No environment variables defined\"],\"qJb6G2\":[\"Try Again\"],\"qM884L\":[\"User Agent\"],\"qSJNp0\":[\"RAM utilization\"],\"qTqIG0\":[\"Record Danmu\"],\"qUvWln\":[\"Try adjusting your search or filters.\"],\"qWRWG1\":[\"Cause:\"],\"qZq0tI\":[\"e.g. aliyun, akamaized\"],\"q_yLs_\":[\"Preferred Quality (QN)\"],\"qgMxnz\":[\"Job creation time\"],\"qoGRes\":[\"Destination Root help\"],\"qqWcBV\":[\"Completed\"],\"qtoOYG\":[\"No limit\"],\"quwsBF\":[\"Force specific output format.\"],\"qxDAhZ\":[\"Edit Filter\"],\"r0jBJE\":[\"secret\"],\"r2yoxL\":[\"Input Options (-i flags)\"],\"r5_ZH9\":[\"Burn .ass subtitles into videos (produces *_burnin.mp4 by default). Manifest-aware and batch-safe.\"],\"r8keQd\":[\"History (\",[\"slots\"],\" pts)\"],\"rAx5u1\":[\"End Time\"],\"rCZeYu\":[\"Triggered when a file segment is finished.\"],\"rDC-T6\":[\"Retry Job\"],\"rG3WVm\":[\"Select\"],\"rGNFqY\":[\"Full Processing\"],\"rLgPvm\":[\"Backup\"],\"rOsxLC\":[\"Create Channel\"],\"rPLMLx\":[\"Scan the QR code with Bilibili app to login and save credentials\"],\"rPTjS_\":[\"Mark Read\"],\"rYpSzJ\":[\"Sanitized session title\"],\"r_-HvB\":[[\"0\",\"plural\",{\"one\":[\"#\",\" done\"],\"other\":[\"#\",\" done\"]}]],\"rc8zxf\":[\"Control verbosity for each module. Lower levels include higher ones.\"],\"rdAnZQ\":[\"Real-Time Logs\"],\"rj3LPO\":[\"Login successful!\"],\"rkmaZ8\":[\"Any additional command line arguments to pass to Streamlink\"],\"rmol8y\":[\"Discontinuity\"],\"rsjeU7\":[\"Streamer deleted\"],\"rv6zQQ\":[\"Retry Delay Base (ms)\"],\"rwpu5q\":[\"Choose your display language\"],\"ry0RbG\":[\"No description.\"],\"ryXr-I\":[\"Command Arguments\"],\"s-MGs7\":[\"Resources\"],\"s-yK3S\":[\"If enabled, streams in these categories will be ignored.\"],\"s0A5F0\":[\"Require Subtitles\"],\"s2Ok6S\":[\"Edit Workflow: \",[\"0\"]],\"s5FJH3\":[\"Total Steps\"],\"s7XkSj\":[\"Failed to load templates: \",[\"0\"]],\"s7XwOU\":[\"Create Template\"],\"s867uU\":[\"Override filename template.\"],\"sAEhzj\":[\"Stream structure change\"],\"sDMc1l\":[\"Time Placeholders (Local Time)\"],\"sER-bs\":[\"Files\"],\"sG9Y50\":[\"NVENC AV1 High Quality\"],\"sGeXL3\":[\"Thumbnail\"],\"sMakGt\":[\"ey...\"],\"sPFHpI\":[\"Disk\"],\"sPfqA-\":[\"Are you sure you want to delete the template \\\"\",[\"0\"],\"\\\"? This action cannot be undone.\"],\"sSmPh2\":[\"Immersive Mode\"],\"sThCBt\":[\"Quality Control\"],\"sWuf_r\":[\"Preferred Qualities\"],\"sXmNBK\":[\"Route traffic through an intermediate server\"],\"sYAqs-\":[\"Max Delay (ms)\"],\"saFjCh\":[\"Delete original file after successful remux\"],\"sbQMAc\":[\"Please enter at least one URL\"],\"sbanhc\":[\"No refresh needed or not supported\"],\"sfCeO6\":[\"Priority high-low\"],\"si7i6t\":[\"Create a ZIP archive of the file. Good for bundling with metadata.\"],\"sjJ6qs\":[\"RESUMED START\"],\"sjVfrA\":[\"Command\"],\"spa6AF\":[\"No input paths\"],\"sq8P7b\":[\"Off Canvas\"],\"sr0UJD\":[\"Go Back\"],\"svV_tK\":[\"Template updated successfully\"],\"sxidET\":[\"GPU-accelerated high quality archive: NVENC HEVC encoding, thumbnail, and cloud upload.\"],\"sxkWRg\":[\"Advanced\"],\"t-R8-P\":[\"Execution\"],\"t3NSIX\":[\"Replace Pipeline Step\"],\"t6QNu0\":[\"Audio Settings\"],\"t7TeQU\":[\"Messages\"],\"t7p091\":[\"Start QR Login\"],\"tBFXor\":[\"User Disabled\"],\"tBkWoY\":[\"Douyu SD or low rate\"],\"tCNR7u\":[\"Download Concurrency\"],\"tG0T2X\":[\"Failed to parse URL\"],\"tH1aCG\":[\"Video Bitrate\"],\"tLnoCj\":[\"Job Presets\"],\"tOcErW\":[\"Failed to update settings\"],\"tPg-n8\":[\"Rclone Executable\"],\"tVSmFT\":[\"Loading more...\"],\"tVxHoG\":[\"No workflows found\"],\"tWWCyb\":[\"Creation Mode\"],\"tXDHM9\":[\"Transfers help\"],\"tXQ9KI\":[\"Select type\"],\"tX_2YD\":[\"Failed to delete streamer\"],\"tXkhj_\":[\"Start\"],\"tYt23W\":[\"ASS Burn-in\"],\"tZASER\":[\"Are you sure you want to delete this job? This will permanently remove it from your list.\"],\"t_YqKh\":[\"Remove\"],\"t_a0R7\":[\"https://gotify.example.com\"],\"taWpib\":[\"Burst capacity for TPS Limit (--tpslimit-burst).\"],\"ti_7Zv\":[\"The configuration matches existing data\"],\"tkrJYR\":[\"Are you sure you want to delete this session? This action cannot be undone.\"],\"toutGW\":[\"QR Code\"],\"tqsVap\":[\"Min Priority\"],\"trzwMt\":[\"HLS parser\"],\"tst44n\":[\"Events\"],\"tuczwE\":[\"Primary Action\"],\"twkfAf\":[\"Failed steps retry initiated\"],\"tyyY8g\":[\"Session Details\"],\"u-R-Uj\":[\"No templates found\"],\"u4x6Jy\":[\"Back to Jobs\"],\"u5pdtQ\":[\"Failed to cancel pipeline\"],\"u7En0V\":[\"Add Metadata\"],\"u7KTQf\":[\"Separate multiple paths with commas.\"],\"u8XaRg\":[\"Streamer is on cooldown\"],\"u8rLL5\":[\"Connection Instability\"],\"uAQUqI\":[\"Status\"],\"uBxq0B\":[\"Add item...\"],\"uDMKZ-\":[\"Interval (seconds)\"],\"uFgIWz\":[\"Triggered after each segment recording\"],\"uFrzr2\":[\"This pipeline runs immediately after a recording segment is finished. Use it for tasks that only require the individual video segment, such as remuxing, thumbnail generation, or per-segment uploads.\"],\"uHaWdO\":[\"Extract high-quality audio for podcast distribution and upload.\"],\"uI3SjW\":[\"Connection Error\"],\"uKMMk4\":[\"Logged in successfully\"],\"uKqo4y\":[\"Event Hooks\"],\"uLlPmJ\":[\"Adjust rate based on target duration\"],\"uQUJrF\":[\"All Platforms\"],\"uRoULu\":[\"Global Configuration\"],\"uS4jm7\":[\"Inherited / Default\"],\"uTyAvE\":[\"Twitch Proxy Playlist Exclude\"],\"u_4llg\":[\"Switch Theme\"],\"uabF28\":[\"Sensitive\"],\"ueNCFX\":[\"Preset Details\"],\"uhURfE\":[\"Remove source on success\"],\"uhZE--\":[\"Failed to disable Web Push\"],\"um816y\":[\"Executions\"],\"up_E6Q\":[\"Customize the application theme and sidebar layout.\"],\"utnMp8\":[\"Account Not Found\"],\"uxjnhI\":[\"WEB\"],\"v-cKc6\":[\"Copy / Move\"],\"v0ItTA\":[\"Loading logs...\"],\"v10I4K\":[\"Skip Interactive Games\"],\"v39wLo\":[\"Resume\"],\"vBNtP8\":[\"Preferred Formats\"],\"vEkjkD\":[\"Proxy URL\"],\"vFBdNT\":[\"Resolution (Scale)\"],\"vGgj7q\":[\"Key Cache TTL (ms)\"],\"vGjmyl\":[\"Deleted\"],\"vHFsyI\":[\"Protocol Settings\"],\"vHbMb_\":[\"Pipeline name is required\"],\"vHgqv3\":[\"Custom proxy URL for Twitch playlists\"],\"vPfdKD\":[\"Adjust sampling rate based on danmu traffic volume.\"],\"vQUnlF\":[\"Specific settings for the platform.\"],\"vRLm73\":[\"Show download details\"],\"vRayGs\":[\"Player\"],\"vS1EOG\":[\"Flow & Dependencies\"],\"vSJd18\":[\"Video\"],\"vXIe7J\":[\"Language\"],\"vYVhmv\":[\"Generating QR code...\"],\"vYgeDk\":[\"Deselect All\"],\"vaHbOZ\":[\"Shared pipeline utilities\"],\"vejAXH\":[\"Stream URL\"],\"vf5Y2I\":[\"Copy (Passthrough)\"],\"vfZWyn\":[\"Try adjusting your filters or limit\"],\"vfeDAy\":[\"Generate a thumbnail at 30 seconds with 480px width for previews.\"],\"vgjQEq\":[\"Remux and Clean\"],\"vgwVkd\":[\"UTC\"],\"vjD16L\":[\"Width in pixels (keeps aspect ratio)\"],\"vlDq31\":[\"Select the highest quality level you want to attempt capturing.\"],\"voD8oZ\":[\"Configure Step\"],\"voRH7M\":[\"Examples:\"],\"vrNaKg\":[\"Tip: 1 Mbit/s ≈ 0.125M (divide bits by 8).\"],\"vrnnn9\":[\"Processing\"],\"vtDkV7\":[\"Pick a preset theme or import a custom one.\"],\"vv7kpg\":[\"Cancelled\"],\"vwRuK_\":[\"Error loading preset\"],\"vxaZfq\":[\"Duration limit\"],\"vzGE_4\":[\"Proxy Configuration\"],\"vzxlNg\":[\"Define rules to automatically record or skip streams based on title, time, or language.\"],\"w-bWA_\":[\"Numeric Room IDs Only\"],\"w2ybE1\":[[\"0\"],\" Configuration\"],\"w6RBiW\":[\"Review recorded streams and manage archives\"],\"wAFtA2\":[\"Only kicks in for files above the Cutoff size. Default is 4. Set to 0 to disable.\"],\"wAgqfF\":[\"Double Screen Data\"],\"wBMjJ2\":[\"Count\"],\"wCD4xg\":[\"Parse error: \",[\"0\"]],\"wDioLj\":[\"Failed to retry job\"],\"wEp8H9\":[\"Failed to update channel\"],\"wErX0W\":[\"Stem (Filename Match)\"],\"wFb0uO\":[\"Capture Mode\"],\"wMHvYH\":[\"Value\"],\"wMQZYQ\":[\"Live Refresh Interval (ms)\"],\"wMe2Qp\":[\"Namespace\"],\"wOjRUU\":[\"Last Check\"],\"wVagb1\":[\"Resumed after \",[\"0\"],\"s in pending state.\"],\"wVk8_8\":[\"Inline: \",[\"stepName\"]],\"w_bY7R\":[\"Logs\"],\"wdxz7K\":[\"Source\"],\"wg5HlH\":[\"How many files rclone uploads in parallel.\"],\"wgeqNr\":[\"Quality Settings\"],\"wiMpRi\":[\"Overwrite Output\"],\"woYYQq\":[\"Workflows\"],\"wqJ4zc\":[\"Environment Variables\"],\"wrl8J3\":[\"To Addresses\"],\"wspIF7\":[\"rclone default: 1\"],\"wv1e0F\":[\"Internal\"],\"wwNqQz\":[\"FLV Tuning\"],\"wwu18a\":[\"Icon\"],\"wzr18h\":[\"Export Configuration\"],\"x-uFU5\":[\"0 = unlimited\"],\"x09b8E\":[\"Request timeout duration\"],\"x0U6Og\":[\"Filter Window Size\"],\"x0aAkx\":[\"Search outputs...\"],\"x1VcXy\":[\"Framerate (FPS)\"],\"x2fr_j\":[\"Graph\"],\"x2igpn\":[\"Path to Telegram Desktop (contains tdata)\"],\"x7PDL5\":[\"Logging\"],\"xA2izn\":[\"No pipeline DAGs found.\"],\"xBO-wH\":[\"channels\"],\"xCJdfg\":[\"Clear\"],\"xCaAmT\":[\"Retry Delay (ms)\"],\"xCtNNg\":[\"Override how aggressively the scheduler confirms a stream has ended.\"],\"xD4Xm2\":[\"Fetch\"],\"xDAtGP\":[\"Message\"],\"xDr_ct\":[\"End\"],\"xGzQCl\":[\"workflow\"],\"xND0SQ\":[\"Placeholders\"],\"xQV856\":[\"Failed to clone preset: \",[\"0\"]],\"xUpogZ\":[\"veryfast\"],\"xY9s5E\":[\"Timeout\"],\"xZGBr0\":[\"Audio Extract\"],\"x_biSg\":[\"Interval between offline checks.\"],\"xdqOXO\":[\"Allow Private Stream Proxy Targets\"],\"xeMG4j\":[\"Regex\"],\"xi8BoZ\":[\"Parallel integrity checks (e.g. comparing source / destination hashes).\"],\"xjwCW_\":[\"Max Key Retry Delay (ms)\"],\"xqKCfi\":[\"Start Phone & Code Login\"],\"xsp8AP\":[\"Override output folder.\"],\"xtWiCP\":[\"Path to DanmakuFactory binary. If empty, uses environment variable or PATH.\"],\"xvp-v2\":[\"Thumbnail Generator\"],\"xxCtZv\":[\"Rows per page\"],\"xzqVyW\":[\"Live Polling\"],\"y2WOeQ\":[\"Loading workflows...\"],\"y7ZemI\":[\"Use stream start time to keep a midnight-crossing stream in one dated folder. Execution time preserves the existing copy/move behavior.\"],\"y7sj-0\":[\"Streamer\"],\"yDlWVJ\":[\"Offline Check Interval\"],\"yGS9cI\":[\"Healthy\"],\"yHXa3T\":[\"-1001234567890\"],\"yIMeW0\":[\"Force requesting the highest origin quality available (Direct stream).\"],\"yL1-1a\":[\"Stream start time\"],\"yMMvQD\":[\"If any of these keywords appear in the title, the stream will be ignored.\"],\"yRAYpv\":[\"Quality Rate\"],\"yRadQU\":[\"Triggered when a post-processing pipeline job starts.\"],\"yRzWhF\":[\"Monitor and manage processing jobs\"],\"ySWmtK\":[\"Keep Running\"],\"yScTZL\":[\"System Status\"],\"ySxXn1\":[\"/usr/bin/ffmpeg or ffmpeg\"],\"yTLtq4\":[\"Complex patterns\"],\"yThUSt\":[\"High quality GPU-accelerated HEVC/H.265 encoding using NVIDIA NVENC (CQ 21). Best compression ratio with GPU.\"],\"yXcp78\":[\"Process locally: Remux to MP4, generate thumbnail, move to archive folder.\"],\"yZgsv0\":[\"Request AAC audio without a video track.\"],\"yZpWgb\":[\"No playable stream found in response\"],\"y_0uwd\":[\"Yesterday\"],\"yfJY2E\":[\"GPU Health Probe Interval\"],\"ygqCBL\":[\"Compress video for storage, upload to cloud, then delete local file to save space.\"],\"yhvq_6\":[\"Blocked by browser\"],\"ylXj1N\":[\"Selected\"],\"yntXb7\":[\"Editing Mode\"],\"ypFsBx\":[\"Ignore streams above this bitrate.\"],\"ysAGq3\":[\"Notification permission not granted\"],\"ywDBfs\":[\"Something went wrong!\"],\"ywFj2D\":[\"Configuration\"],\"yx_fMc\":[\"High\"],\"yz7wBu\":[\"Close\"],\"z-rLjA\":[\"Timeout (seconds)\"],\"z1g5gH\":[\"Enable selected\"],\"z2QpqF\":[\"Path Variables\"],\"z4YzF4\":[\"H2 Keep-Alive (ms)\"],\"z5385K\":[\"Input Paths (comma separated)\"],\"z55Hd4\":[\"Delete Source ASS\"],\"z6HbLm\":[\"DAG Configuration\"],\"z7n92F\":[\"Live Gap Strategy\"],\"zCuWFS\":[\"Channel created\"],\"zDfdMJ\":[\"Until\"],\"zG9iNp\":[\"Search engines...\"],\"zGgIA2\":[\"Web Push priority updated\"],\"zHa4lw\":[[\"0\",\"plural\",{\"one\":[\"#\",\" step\"],\"other\":[\"#\",\" steps\"]}]],\"zIkg8M\":[\"Remux to MP4 without re-encoding and delete the original file on success. Saves disk space.\"],\"zInmXF\":[\"Scheduler\"],\"zJA0U6\":[\"Output Settings\"],\"zKwEox\":[\"Target Configuration\"],\"zNi48o\":[\"Remux to MKV without re-encoding. Matroska supports more codecs and features.\"],\"zQLplp\":[\"The platform reported this account as missing. It may have been deleted, banned, or the URL/ID is incorrect. Consider removing or updating this streamer.\"],\"zSwSsg\":[\"Dimensions & Filters\"],\"zWMS3O\":[\"Preview Gallery\"],\"zd00pH\":[\"Copy Path\"],\"zg8Pdk\":[\"Offline Detection Count\"],\"zga9sT\":[\"OK\"],\"zi6RXq\":[\"Error loading streamers: \",[\"0\"]],\"zoISi_\":[\"Failed to retry steps\"],\"zrmjn_\":[\"Max Duration\"],\"zx4BuL\":[\"Week\"]}")as Messages;
\ No newline at end of file
+/*eslint-disable*/import type{Messages}from"@lingui/core";export const messages=JSON.parse("{\"-10SpW\":[\"Interactive 24-hour timeline selection.\"],\"-5Xsme\":[\"Out of Space\"],\"-6YAwo\":[\"selected\"],\"-BEX4J\":[\"Failed to update filter\"],\"-BOXe-\":[\"VOD Segment Timeout (ms)\"],\"-GLqpc\":[\"Compression / Archive\"],\"-GZp3E\":[\"Switch language\"],\"-GwKvY\":[\"No platform overrides configured\"],\"-OQYUd\":[\"Key Timeout (ms)\"],\"-O_9ij\":[\"Streamer Details\"],\"-Qbuje\":[\"Download engine (mesio)\"],\"-TWWLH\":[\"ID is required\"],\"-Xjyeo\":[\"Select File\"],\"-_zWwh\":[\"Auto (Default)\"],\"-b7T3G\":[\"Updated\"],\"-eCGXh\":[\"Interactive password entry via this API is not supported on Windows. Please use \\\"Desktop Login\\\" (import tdata) or log in once in a regular terminal.\"],\"-fL-Rj\":[\"Login failed: \",[\"0\"]],\"-jO3E8\":[\"Douyu HD rate\"],\"-lH7FY\":[\"Execution time (default)\"],\"-lwQ5z\":[\"Video Codec\"],\"-rJcQ5\":[\"Failed to update subscriptions\"],\"-swXfw\":[\"Choose how files are transferred to the remote.\"],\"-xD8Ul\":[\"Size and duration constraints for recordings.\"],\"-z4lmr\":[\"Expert mode: Edit the raw platform-specific configuration directly.\"],\"-zy2Nq\":[\"Type\"],\"00W4lp\":[\"Initial Fetch Timeout (ms)\"],\"00jaOh\":[\"Archive Compression\"],\"01Oe9B\":[\"Telegram Desktop\"],\"024CCW\":[\"Select template\"],\"03Qqf9\":[\"Filtered\"],\"0CKZnZ\":[\"Generate a thumbnail image from the video at 10 seconds (320px width).\"],\"0CaXBE\":[\"Supported Platforms\"],\"0HlWEz\":[\"Workflow Step\"],\"0Jq6UY\":[\"Manage your monitored channels and downloads\"],\"0P2gFy\":[\"The page you are looking for does not exist.\"],\"0PZXF8\":[\"Select format\"],\"0RjCNS\":[\"Could not connect to the server. Please check your connection.\"],\"0SsE5J\":[\"Preset not found\"],\"0SvRJE\":[\"Learn when each streamer usually goes live from recent sessions. Checks run twice as often around those times and up to four times less often during hours the streamer has not gone live.\"],\"0TPdbO\":[\"H.264\"],\"0Uz_Zj\":[\"Decryption Key TTL (ms)\"],\"0X8rUE\":[\"https://...\"],\"0YopG6\":[\"Resource Limits\"],\"0ZwlwE\":[\"TDL is not logged in\",[\"version\"]],\"0aTtEy\":[\"Split on any raw header change. Safe but may cause extra files.\"],\"0f9Hre\":[\"API Request Retries\"],\"0gVmZj\":[\"Extra Arguments\"],\"0ioul2\":[\"Include Images\"],\"0s5Sjx\":[\"Artist\"],\"0s7IPo\":[\"Notification Service\"],\"0sZpQb\":[\"Inherited\"],\"0tvouK\":[\"Jitter\"],\"0v9bkw\":[\"SESSION STARTED\"],\"0wk6D2\":[\"Per page:\"],\"0xayzq\":[\"Fast GPU-accelerated H.264 encoding using NVIDIA NVENC (CQ 23). Great speed-to-quality ratio.\"],\"0zpgxV\":[\"Options\"],\"1-6BOG\":[\"Download Backup\"],\"11MaKJ\":[\"Failed to load events\"],\"14PdY0\":[\"Config\"],\"16uyo-\":[\"Add Channel\"],\"17A-hi\":[\"If enabled, streams matching this pattern will be ignored.\"],\"17WYYl\":[\"Offline Grace\"],\"18A9QX\":[\"Failed to delete sessions\"],\"19MiM7\":[\"Global Timeout (ms)\"],\"19P7sR\":[\"Check triggered\"],\"1AGwZy\":[\"Enter a unique name\"],\"1AigfY\":[\"Previous\"],\"1BqPFJ\":[\"Network & System\"],\"1BxbyL\":[\"No platform found.\"],\"1HbWYr\":[\"This is a default preset. Description cannot be modified.\"],\"1KMsgI\":[\"Processor is required\"],\"1PQRWr\":[\"Start Time\"],\"1TQa05\":[\"Engine\"],\"1U1a6C\":[\"https://api.example.com/webhook\"],\"1UlpHm\":[\"Streamers\"],\"1VXi7i\":[\"Cancel Pipeline\"],\"1WHNRE\":[\"Add Override\"],\"1WTDTW\":[\"JSON array of all inputs\"],\"1WrRvf\":[\"Replace Step\"],\"1XskRc\":[\"Pipeline is empty.\"],\"1ZTiaz\":[\"Segments\"],\"1ZZ47T\":[\"Adjust the roundness of UI elements.\"],\"1aPw3f\":[\"Live polling notifications enabled\"],\"1cbMZM\":[\"CQ (Constant Quality)\"],\"1dwnDD\":[\"Server not configured\"],\"1hKEom\":[\"Priority\"],\"1ir2pc\":[\"Basic Auth\"],\"1jJKez\":[\"Configure how you receive alerts in this browser and via system push notifications.\"],\"1lVNQI\":[\"Triggered when a pipeline job fails.\"],\"1mYvTJ\":[\"Platform Overrides\"],\"1naaKv\":[\"When a recording has been waiting in the concurrency queue longer than this, rust-srec re-checks the streamer to refresh stream URLs and headers before starting. Below this threshold the URLs captured at the original live event are reused. Default 60 seconds. Set to 0 to refresh on every queue wait.\"],\"1nhIls\":[\"Filter deleted successfully\"],\"1njn7W\":[\"Light\"],\"1pChHx\":[\"Notification Log Retention\"],\"1tul0y\":[\"Session is not running\"],\"1wTrMB\":[\"Replace All\"],\"1wy3Ze\":[\"INITIAL\"],\"1yzCsx\":[\"Download complete\"],\"21M8r5\":[\"Web Push\"],\"22PcUJ\":[\"Paired Segment Pipeline\"],\"22lIWc\":[\"Process exited with code \",[\"0\"]],\"237hSL\":[\"Ended\"],\"26Ki3h\":[\"This will permanently delete the pipeline and all its associated jobs and logs. This action cannot be undone.\"],\"27E8MB\":[\"Settings updated successfully\"],\"28iWmV\":[\"Loading pipeline editor...\"],\"29VNqC\":[\"Unknown error\"],\"29loHy\":[\"Pipeline deleted successfully\"],\"2BBAbc\":[\"List\"],\"2EEBQm\":[\"Streamer ID\"],\"2E_rkV\":[\"Credentials saved successfully\"],\"2FC3XU\":[\"FFmpeg Path\"],\"2GT3Hf\":[\"Global\"],\"2KpT4Y\":[\"e.g. 1M\"],\"2R2xgz\":[\"Preserve Paths\"],\"2TQlVI\":[\"Requires \\\"Record Danmu\\\" to be enabled.\"],\"2WbqDp\":[\"Manage storage paths and file formats.\"],\"2XRg_h\":[\"Read Timeout (ms)\"],\"2d1Xgp\":[\"Custom Header\"],\"2dhgET\":[\"YYYY-MM-DD\"],\"2jmZHr\":[\"Add metadata tags (title, artist, date) to the video file.\"],\"2k1l_G\":[\"Video Settings\"],\"2kAqWs\":[\"Failed to create channel\"],\"2m_AmE\":[\"NVENC HEVC High Quality\"],\"2nMNeT\":[\"High quality GPU-accelerated AV1 encoding using NVIDIA NVENC (CQ 24). Best compression ratio, requires RTX 4000+.\"],\"2o7xcf\":[\"Output Options\"],\"2qfwJn\":[\"Overwrite\"],\"2sDhLO\":[\"On Offline\"],\"2tuwGz\":[\"Add parameter\"],\"2vw0xh\":[\"End of History\"],\"2wl1yB\":[\"Max Interval (s)\"],\"2xsJvU\":[\"^Started.*\"],\"3-I7HJ\":[\"Select streamers\"],\"3-IxS7\":[\"A unique and descriptive name for this configuration.\"],\"3070NI\":[\"General Configuration\"],\"30XQ-_\":[\"Cookies\"],\"32TndD\":[\"Blocked\"],\"34KT3m\":[\"Timeout before cancelling `execute` processor commands.\"],\"39GoNc\":[\"Max Downloads\"],\"39vwDk\":[\"SD / Low\"],\"3Aerm-\":[\"Session not found\"],\"3BEjvS\":[\"Remux to MP4 without re-encoding. Fast and lossless - just changes the container format.\"],\"3H1WQ-\":[\"Connection/activity timeout\"],\"3Huwd4\":[\"Auto Layout\"],\"3Ihr5B\":[\"Select a format\"],\"3JjdaA\":[\"Run\"],\"3K6phQ\":[\"Default uses Mesio’s built-in live stall timeout. Disabled means wait indefinitely.\"],\"3KInmc\":[\"Web Push disabled for this browser\"],\"3N3v30\":[\"Fast H.264 compression (CRF 23). Good balance of speed, quality, and file size.\"],\"3NfDu6\":[\"Error loading platform\"],\"3QfsSn\":[\"No pipeline jobs yet\"],\"3QlVaA\":[\"Failed to create preset: \",[\"0\"]],\"3Qu3lA\":[\"On Pipeline Complete\"],\"3X3_SF\":[\"Steps List\"],\"3Yd9PG\":[\"Sidebar Layout\"],\"3bv0H0\":[\"Triggered when the application shuts down.\"],\"3c_BSm\":[\"Finalize current files\"],\"3gnbIv\":[\"TDL Binary Path\"],\"3ktmhA\":[\"Extraction API Mode\"],\"3qNoeP\":[\"Degraded\"],\"3rQgBh\":[\"Critical alert when the recording output filesystem becomes unwritable and recordings cannot continue.\"],\"3sO1Yr\":[\"Authentication & Setup\"],\"3tncII\":[\"Configure how you want to import data from the selected backup file.\"],\"3uIIkE\":[\"Add New Engine\"],\"3vSDtx\":[\"Where TDL stores its session and configuration.\"],\"3wRUCF\":[\"Extract MP3\"],\"3ywK2I\":[\"Streamer Check\"],\"3yyqaw\":[\"High Quality Compression\"],\"40Gx0U\":[\"Timezone\"],\"40lLFI\":[\"Go to next page\"],\"40wBfu\":[\"Batch URLs\"],\"41k5OL\":[\"No Extension\"],\"43YdBD\":[\"Absolute path or 'ffmpeg' if in PATH\"],\"44KALZ\":[\"Min FPS\"],\"45zsWJ\":[\"Min size to keep.\"],\"47e97a\":[\"Max Retries\"],\"47mhxP\":[\"Preferred CDNs\"],\"48NKsg\":[\"Check Login\"],\"49BbAW\":[\"files\"],\"4AWhME\":[\"Match Strategy\"],\"4BITzH\":[\"Error:\"],\"4GICMX\":[\"No playable streams found\"],\"4GKuCs\":[\"Login failed\"],\"4Lr0jZ\":[\"Apply template settings.\"],\"4P5UM2\":[\"Session deleted successfully\"],\"4QQLtV\":[\"Your digital library is currently empty. Start recording to populate your sessions here.\"],\"4RQxGo\":[\"Exceptional states\"],\"4XUbOH\":[\"Split only on codec configuration changes. Reduces false splits.\"],\"4aDTBq\":[\"Copy Files\"],\"4ba0NE\":[\"Scheduled\"],\"4cEClj\":[\"Sessions\"],\"4cOYET\":[\"Shows notifications while the application tab is open by polling recent events.\"],\"4e7ocq\":[\"Base delay between retries\"],\"4eMNNj\":[\"Enable Output Metrics (Default: On)\"],\"4jAOc9\":[\"Select the steps that must complete successfully before this step runs.\"],\"4nfUib\":[\"Brand Colors\"],\"4siLaw\":[\"Stream Archive\"],\"4uQ7PS\":[\"Background refresh enabled\"],\"4v6VMF\":[\"Resuming in\"],\"4w5jzL\":[\"Custom Tags\"],\"4xzdEv\":[\"Subscriptions updated\"],\"5-AFwM\":[\"No workflows yet\"],\"52y5Op\":[[\"0\",\"plural\",{\"one\":[\"#\",\" failed\"],\"other\":[\"#\",\" failed\"]}]],\"55n6pj\":[\"Rate Limiting\"],\"57MMsh\":[\"Max Segment Retries\"],\"5B_EbJ\":[\"OFFLINE\"],\"5FCyMZ\":[\"Target bitrate\"],\"5GJtdv\":[\"Theme Preset\"],\"5HLg1G\":[\"Streams & Metadata\"],\"5LUAOT\":[\"Raw JSON Editor\"],\"5PhtRK\":[\"No specialized options available for this platform.\"],\"5RyP14\":[\"Per-segment\"],\"5SNqKm\":[\"Custom Config\"],\"5UfPjf\":[\"View application logs in real-time. Logs are limited to the last \",[\"MAX_LOG_ENTRIES\"],\" entries.\"],\"5b4J4v\":[\"All Time\"],\"5bhON1\":[\"Bitrate\"],\"5dKF37\":[\"Failed to send input: \",[\"0\"]],\"5eHyY-\":[\"Test Notification\"],\"5gF1Yx\":[\"Click to add. You can reorder steps in the editor.\"],\"5hxDwt\":[\"CPU Job\"],\"5iuLzq\":[\"presets\"],\"5kX7hC\":[\"Add randomness to retry delays to prevent thundering herd.\"],\"5l9iMR\":[\"No templates yet\"],\"5r2EnB\":[\"NVENC AV1 Fast\"],\"5raigv\":[\"Max Pending Init Segments\"],\"5s-Rk5\":[\"Add Pipeline Step\"],\"5vx6Be\":[\"Failed to retry all failed pipelines\"],\"5x03LH\":[\"Base path for remote storage. Supports metadata and time placeholders.\"],\"61RNR5\":[\"No log files found\"],\"633mTs\":[\"Failed to create filter\"],\"65DNcr\":[\"Unknown File\"],\"677MgO\":[\"No engines found.\"],\"67RoFa\":[\"Pipeline\"],\"67hU6L\":[\"Main application\"],\"6A0WJl\":[\"Fix configuration errors before saving\"],\"6Aih4U\":[\"Offline\"],\"6BDqha\":[\"Limits\"],\"6Dd2PM\":[\"Maintenance\"],\"6GBt0m\":[\"Metadata\"],\"6GJw73\":[\"No Timeline Events\"],\"6H2zd1\":[\"Failed to send test notification\"],\"6Jmdzl\":[\"Prefer codec\"],\"6LJ142\":[\"Failed to load engines: \",[\"0\"]],\"6O3gfw\":[\"Use Phone & Code\"],\"6Oaeoc\":[\"Cron\"],\"6Umgab\":[\"Auto (QR → Phone & Code → Desktop)\"],\"6V3Ea3\":[\"Copied\"],\"6VKo3k\":[\"Deletes the files produced by the previous step. Safe after an Upload step. After a transcode/remux it deletes the converted result, not the original — use \\\"Remove Input on Success\\\" on the transcode step to delete the source instead.\"],\"6WdDG7\":[\"Page\"],\"6YtxFj\":[\"Name\"],\"6_-6tw\":[[\"val\"],\" channel support is coming soon!\"],\"6_dCYd\":[\"Overview\"],\"6bAENo\":[\"Disk Space\"],\"6d9Ao4\":[\"No logs available for this execution.\"],\"6fi2dR\":[\"Max Retry Delay (ms)\"],\"6gRgw8\":[\"Retry\"],\"6geoEv\":[\"File path copied to clipboard\"],\"6h20bP\":[\"Cancel Pipeline?\"],\"6hur5J\":[\"e.g. --namespace my_account\"],\"6kItd_\":[\"upload : download (asymmetric)\"],\"6ldwcL\":[\"Fast Compression\"],\"6mF1Q8\":[\"Extract AAC\"],\"6moIoi\":[\"Paired\"],\"6mwiZ7\":[\"Specify preferred content delivery network (e.g., ws-h5, hw-h5).\"],\"6nFdlp\":[\"Delete Preset?\"],\"6p4wnA\":[\"Only supported for MP4/MOV/M4V\"],\"6qNpC1\":[\"Prioritize specific qualities (case-insensitive). Press Enter to add.\"],\"6sBUvP\":[\"Add a stream to start your viewing session.\"],\"6swMyy\":[\"Presets Available\"],\"6tBUzp\":[\"Remove Input on Success\"],\"6vFYPB\":[\"e.g. flv, hls\"],\"6wLR55\":[\"Edit Engine\"],\"7-c73-\":[\"Select a processing job or workflow to use in this node.\"],\"71pK1Q\":[\"This will permanently delete \",[\"selectedCount\"],\" streamers. This action cannot be undone.\"],\"72c5Qo\":[\"Total\"],\"73yjgk\":[\" - Session: \",[\"0\"]],\"76ob5K\":[\"First output file path\"],\"77da32\":[\"TPS Limit\"],\"78qtNg\":[\"This Delete step deletes the converted result, not the original recording. Enable \\\"Remove Input on Success\\\" on the transcode step instead.\"],\"7Bj3x9\":[\"Failed\"],\"7C-Jn5\":[\"Show password\"],\"7DqYk8\":[\"Filter updated successfully\"],\"7JVWl4\":[\"End Stream On Danmu Close\"],\"7Jw_XW\":[\"Error Details\"],\"7K4dCf\":[\"Try adjusting your search or filters\"],\"7L01XJ\":[\"Actions\"],\"7L3DyA\":[\"Regex Pattern\"],\"7Oyczn\":[\"Global Buffer Size\"],\"7SNWYf\":[\"Telegram 2FA via web is unsupported\"],\"7VwJ-5\":[\"Request Rate Limit\"],\"7VxXSg\":[\"Browser Status\"],\"7_wpqP\":[\"Recommended: 8388608 (8 MiB)\"],\"7cmJQz\":[\"Workflow created successfully\"],\"7fIIFx\":[\"TTWID Management Mode\"],\"7jRxmJ\":[\"Triggered when the application starts up.\"],\"7kKmbL\":[\"0 disables the limit.\"],\"7p5kLi\":[\"Dashboard\"],\"7s6i2S\":[\"Download All\"],\"7sMeHQ\":[\"Key\"],\"7sNhEz\":[\"Username\"],\"7tgEva\":[\"2\"],\"7vhWI8\":[\"New Password\"],\"800TmA\":[\"Streams\"],\"80AR_c\":[\"Restore from a backup file\"],\"81mUvV\":[\"Delete Source Videos\"],\"83M8VR\":[\"Select an engine\"],\"84B6U_\":[\"Lower value = Higher Quality\"],\"8HE-Aw\":[\"Filter Interactive Games\"],\"8PNILp\":[\"Parse Stream\"],\"8T864k\":[\"Triggered when a download successfully completes.\"],\"8TMaZI\":[\"Timestamp\"],\"8Tg_JR\":[\"Custom\"],\"8WcAZl\":[\"Filter created successfully\"],\"8WgMDL\":[\"Archive Settings\"],\"8XX_CD\":[\"No filters yet\"],\"8YINy3\":[\"Config Path (Optional)\"],\"8ZsakT\":[\"Password\"],\"8_brI5\":[\"Name is required\"],\"8b6O3h\":[\"Search logs...\"],\"8eRn2a\":[\"SYSTEM\"],\"8gGPQK\":[\"From Address\"],\"8iqQ6v\":[\"Add exclude regex pattern\"],\"8j1zGy\":[\"Auto Thumbnail\"],\"8lz0kn\":[\"https://discord.com/api/webhooks/...\"],\"8mJUui\":[\"Not supported in this browser\"],\"8rp73h\":[\"Configuration imported successfully\"],\"8tjQCz\":[\"Explore\"],\"8tm102\":[\"WUP\"],\"8vQkDt\":[\"Latency\"],\"8wu9lr\":[\"Queued\"],\"8x_SfR\":[\"Memory Usage\"],\"8yPA1y\":[\"Interval between checks to see if a streamer is currently live. Slower intervals reduce API usage but might delay recording starts.\"],\"90Z1Nt\":[\"Job retry initiated\"],\"93Ucih\":[\"Additional arguments appended to the command.\"],\"95g7x4\":[\"Log levels & modules\"],\"98oK0h\":[\"Bandwidth Limit help\"],\"9AfU7B\":[\"Use danmu_inputs from job manifest\"],\"9CFpCU\":[\"Avg. Duration\"],\"9DW7l4\":[\"Awaiting first check\"],\"9E34se\":[\"Browse and download application log files. Filter by date range or download all.\"],\"9ELT_X\":[\"Enable Monitoring\"],\"9G881z\":[\"None (Default)\"],\"9IO3UM\":[\"Change Password Required\"],\"9NKF63\":[\"e.g. 1080p, source, 原画\"],\"9OmEZr\":[\"HTTP requests\"],\"9P6Cjx\":[\"Binary Path\"],\"9PbSQ8\":[\"Temporarily disabled\"],\"9SGdhm\":[\"X-Auth-Key\"],\"9SrfBr\":[\"time-of-day timetable\"],\"9UQ730\":[\"Clone\"],\"9UWq9R\":[\"Enable recording of danmu/chat messages along with the video.\"],\"9Ui6mb\":[\"Skip after count\"],\"9Ulhif\":[\"Notification Events\"],\"9XOpfw\":[\"Max Interval (ms)\"],\"9eF5oV\":[\"Welcome back\"],\"9hg9mc\":[\"Downloading\"],\"9kAtVa\":[\"Out of space\"],\"9kmN8A\":[\"No metadata tags added\"],\"9p14fd\":[\"Configure proxy strategies and connection details.\"],\"9pDx-X\":[\"Exclude ASS from Passthrough\"],\"9rG25a\":[\"Server URL\"],\"9uJs-K\":[\"HLS Tuning\"],\"A-3LfF\":[\"Preview Thumbnail\"],\"A-yfCV\":[\"QR Login\"],\"A2LNUE\":[\"Skipped \",[\"skippedCount\"],\" duplicate stream(s)\"],\"A3JxgX\":[\"Secondary Output Folder\"],\"A8uySC\":[\"Failed to delete filter\"],\"ABucgF\":[\"Excluded Extensions\"],\"ADzdEE\":[\"Prioritize specific streaming protocols. Press Enter to add.\"],\"AEGM8s\":[\"Failed to copy\"],\"AGMlcc\":[\"Back to Sessions\"],\"AIdJOG\":[\"IANA Timezone (e.g. Asia/Shanghai, UTC).\"],\"AK4HwH\":[\"Error loading template\"],\"AR0orP\":[\"My Archiving Workflow\"],\"AYbtd_\":[\"Supported\"],\"Abl55S\":[\"Leave empty for stream copy\"],\"Ai2DeC\":[\"Add New Filter\"],\"AlfqgK\":[\"Watch\"],\"ApdJrx\":[\"Default Douyu quality\"],\"Ar-5tA\":[\"Max Refresh Retries\"],\"AsJWjt\":[\"Configure Settings\"],\"AsnjLf\":[\"Please set Telegram Desktop Directory first\"],\"AwXh-1\":[\"Download activity looks healthy\"],\"Az1ZEd\":[\"Runs when the streamer goes online.\"],\"AzCMby\":[\"Failed to load platforms: \",[\"0\"]],\"B0GspH\":[\"Start checking this streamer immediately.\"],\"B1KFZ1\":[\"* * * * * *\"],\"B2Zb_F\":[\"JSON\"],\"B3xl-Q\":[\"Last Activity\"],\"B495Gs\":[\"Archive\"],\"B4Ew1I\":[\"Add at least one step\"],\"B6-TsG\":[\"Files smaller than this stay single-threaded. Examples: <0>250M</0>, <1>1G</1>.\"],\"B7dmPy\":[\"Session Complete Pipeline\"],\"BAWVb9\":[\"No overrides configured\"],\"BCL8Ot\":[\"Header Value\"],\"BFl5lQ\":[\"Full HD Thumbnail\"],\"BGi3Wo\":[\"Appended before output filename\"],\"BIXG5q\":[\"Retry Policy\"],\"BQvz2A\":[\"These timeouts are applied when the pipeline worker pools start. Changes require a restart to take effect.\"],\"BWUEP1\":[\"HTTP/1.1 Only\"],\"BX_rCE\":[\"Specific TTWID Cookie\"],\"B_pQ8T\":[\"Stream Splitting Strategy\"],\"B_rQdm\":[\"Appended to all HLS requests. Useful for signed URLs or CDN routing.\"],\"BaQ0LA\":[\"Number of days to keep completed, failed, or cancelled jobs and workflow executions. Set to 0 to retain them indefinitely.\"],\"Ban892\":[\"Max transactions/second to the remote API (--tpslimit).\"],\"Bcgdrh\":[\"Name autofilled successfully\"],\"BdBelu\":[\"Danmu/min\"],\"Bhx3fS\":[\"Scanned! Please confirm on your phone\"],\"BjJjsB\":[\"FLV stream fixing pipeline\"],\"BjitBo\":[\"Download \",[\"0\"]],\"Bn9-El\":[\"default\"],\"BnOMVx\":[\"Ultrafast Compression\"],\"BrrIs8\":[\"Storage\"],\"Bs956d\":[\"High quality GPU-accelerated H.264 encoding using NVIDIA NVENC (CQ 20). Best NVENC quality.\"],\"Bsurp4\":[\"On Online\"],\"Btgfql\":[\"Job Configuration\"],\"BwhhDv\":[\"Select a streamer\"],\"ByeSsO\":[\"Security & Identity\"],\"C1TsH4\":[\"Waiting for connection...\"],\"C2WreN\":[\"0:v:0\"],\"C4IQ_M\":[\"Workflow deleted successfully\"],\"C95qpz\":[\"Sampling Strategy\"],\"CBVnKj\":[\"Connect Timeout (ms)\"],\"CKyk7Q\":[\"Go back\"],\"CO6Iw8\":[\"IO Job\"],\"COU0uA\":[\"Scan a directory for new files created by the command. Detected files will be passed to the next pipeline step.\"],\"COjFGL\":[\"Request each CDN before recording and prefer the one that answers fastest. Unreachable CDNs are tried last.\"],\"CP3D8G\":[\"Progress\"],\"CS276w\":[\"Delete Workflow?\"],\"CSZ9-b\":[\"TPS Limit help\"],\"CZIQWR\":[\"Configure native OS notifications for important events while running the desktop app.\"],\"Cav1Ci\":[\"Danmu\"],\"Cbxd8c\":[\"Composes with Bandwidth Limit — useful to keep one big file from saturating the whole link while still allowing many small ones to run in parallel.\"],\"CdgoAe\":[\"Create a copy of \\\"\",[\"0\"],\"\\\" with a new name.\"],\"CeRDHt\":[\"Ban CDN\"],\"CfSf24\":[\"Adaptive Polling\"],\"Cj2Gtd\":[\"Size\"],\"Cm-_Q8\":[\"notifier@example.com\"],\"CmOYNg\":[\"Args inserted before -i input_url\"],\"CndmuB\":[\"Stream parsed successfully\"],\"CpwhPE\":[\"Specific\"],\"CqO9qC\":[\"Session\"],\"CsekCi\":[\"Normal\"],\"CvL6qO\":[\"Min Interval (s)\"],\"Cvl3Ur\":[\"e.g. tmp\"],\"D-NlUC\":[\"System\"],\"D0K_c6\":[\"This step is linked to the preset <0>\",[\"presetName\"],\"</0>.\"],\"D1U6Wk\":[\"TDL Interactive Login\"],\"D4xpys\":[\"Triggered when a download is manually cancelled.\"],\"D5t8TK\":[\"Workflow updated successfully\"],\"DC2eVx\":[\"Pipeline Structure\"],\"DCKkhU\":[\"Current Password\"],\"DEpnGr\":[\"Verify Integrity\"],\"DHhJ7s\":[\"Previous\"],\"DIv6yv\":[\"No configuration available for this processor.\"],\"DJsY8j\":[\"Step Identifier (Unique)\"],\"DKrPOn\":[\"Failed to update logging configuration\"],\"DL3whW\":[\"Basic post-processing: Remux FLV to MP4 and generate a thumbnail preview.\"],\"DNA4aQ\":[\"How long to keep decrypted/processed segments in cache.\"],\"DPfwMq\":[\"Done\"],\"DRTrkZ\":[\"Danmus\"],\"DS-5Kd\":[\"Failed to load system health\"],\"DTAxdY\":[\"Outputs\"],\"DTD28p\":[\"Alerts when disk space is running low.\"],\"DTKoJC\":[\"No recent sessions found.\"],\"DYJ_1s\":[\"Pipeline Statistics\"],\"DYyL81\":[\"Copy (Stream Copy)\"],\"D_XERT\":[\"Failed to delete pipeline\"],\"DanbIO\":[\"Appearance & style\"],\"DfRZHP\":[\"Configured Events\"],\"DgVBNf\":[\"Telegram 2FA password is not supported\"],\"DjZUWm\":[\"Upload file to cloud storage and delete local copy after successful upload.\"],\"DkLWbn\":[\"smtp.gmail.com\"],\"DtXU8D\":[\"Number of days to keep the notification event log. Set to 0 to retain events indefinitely.\"],\"Dya8HF\":[\"Fix FLV Streams\"],\"DzUNTV\":[\"Write Timeout (ms)\"],\"E4sw5m\":[\"Session Complete\"],\"E555CB\":[\"Optional extra arguments appended to `tdl login`.\"],\"E5h2rM\":[\"High Quality MP3\"],\"E8bXne\":[\"Proxy\"],\"EBdNVo\":[\"Session Preview\"],\"ECGvyy\":[\"Failed to update template: \",[\"0\"]],\"EEMR5K\":[\"Hour (00-23)\"],\"ELDffV\":[\"Failed to cancel job\"],\"ELa85n\":[\"All Types\"],\"EOsjKj\":[\"Pipeline must have at least one step\"],\"EQoNwM\":[\"File Configuration\"],\"EVGGg9\":[\"Interact\"],\"EXa7Eb\":[\"Streamer ID is required\"],\"EZCWHe\":[\"Launch Pipeline\"],\"EZgAlu\":[\"Bearer Token\"],\"E_QGRL\":[\"Disabled\"],\"EbgT4I\":[\"Priority low-high\"],\"EdQY6l\":[\"None\"],\"Ef7StM\":[\"Unknown\"],\"Ejic1p\":[\"Platforms\"],\"EjzeaZ\":[\"Extraction Settings\"],\"EmVl8I\":[\"Default is 1 (no burst). Increase if the provider tolerates short bursts of activity.\"],\"EtVJIx\":[\"Absolute path or 'streamlink' in PATH\"],\"ExpKh1\":[\"Preset Step\"],\"EzR6wK\":[\"Audio Processing\"],\"EztJxW\":[\"rclone default: 8\"],\"F-0S1r\":[\"Probe Timeout (ms)\"],\"F-T4fj\":[\"Browse generated media artifacts from pipeline jobs\"],\"F08Z2D\":[\"TDL binary not found\"],\"F13uOJ\":[\"Fast encoding for quick sharing on social media or messaging.\"],\"F3bW6y\":[\"Platform\"],\"F6Go3M\":[\"Header Key\"],\"F6pfE9\":[\"Active\"],\"F6qVY1\":[\"Day (01-31)\"],\"F7wXp2\":[\"Deletes all existing configurations before importing. This action cannot be undone.\"],\"F9I0Bm\":[\"Critical system errors or streamer failures.\"],\"F9lPvE\":[\"e.g. 'best', 'worst', '720p', 'audio_only'\"],\"FBIuPX\":[\"Clear selection\"],\"FBsQxQ\":[\"This will permanently remove the \\\"\",[\"0\"],\"\\\" configuration. This action cannot be undone.\"],\"FEr96N\":[\"Theme\"],\"FIHR-e\":[\"Stop recording when danmu control signals stream closed.\"],\"FIfpIQ\":[\"TDL is logged in\",[\"version\"]],\"FJxoQQ\":[\"Job configurations\"],\"FLm1Nd\":[\"Real-time\"],\"FMKXt7\":[\"Transfers\"],\"FRqceZ\":[\"Capture Settings\"],\"FSaByO\":[\"Video codec change\"],\"FUbs2p\":[\"Rclone\"],\"FZg3wM\":[\"Operation\"],\"F_amnS\":[\"Time Range\"],\"Fecn_0\":[\"Engine Overrides\"],\"Ff0Dor\":[\"Past\"],\"FfHole\":[\"Preset cloned successfully\"],\"FgYX1w\":[\"Triggered when a download is rejected (e.g., circuit breaker).\"],\"FnYEt6\":[\"Streamer pagination\"],\"FnyzZP\":[\"Top Talkers\"],\"FrndDV\":[\"Native Resolution Thumbnail\"],\"Fv9mIF\":[\"These settings only apply to the desktop app. The server will still deliver channel notifications (Discord/Email/Webhook) normally.\"],\"FyGlJm\":[\"Parse and play live streams\"],\"FzHX8f\":[\"Explicit TTWID cookie value to use for all requests.\"],\"G1YI6L\":[\"HTTP cookies for authentication. These are automatically updated when refreshed.\"],\"G39zBp\":[\"Cache\"],\"G4Ntrl\":[\"Override download engine.\"],\"G88OSb\":[\"Warning when the processing queue gets too long.\"],\"G9GerW\":[\"Execute Command\"],\"G9ULuK\":[\"Maximum HEVC/H.265 compression (CRF 28) for minimal file size.\"],\"G9jHpr\":[\"Pipeline is valid\"],\"GD1Pmm\":[\"Cutoff\"],\"GICGoz\":[\"Transient error\"],\"GRlUlG\":[\"Input Arguments\"],\"GRzPZD\":[\"If set, stream titles must contain at least one of these keywords.\"],\"GSDw5m\":[\"Set up a new download tool configuration\"],\"GSr0rF\":[\"Open menu\"],\"GWWwxV\":[\"Loop Protection\"],\"GYeo24\":[\"Return Previous Page\"],\"GbsW45\":[\"Create New Pipeline Job\"],\"GcrmOG\":[\"At least one input path is required\"],\"Gg8KwP\":[\"Please wait while we resolve the stream URL.\"],\"GkxWrz\":[\"Audio Codec\"],\"GnfGTT\":[\"Custom Headers\"],\"GrdEmt\":[\"Customize specific engine settings for this template.\"],\"GtJbUa\":[\"Background\"],\"GwDyhV\":[\"Delete Source XML\"],\"GyEhZV\":[\"Max HEVC Compression\"],\"H5UXax\":[\"Confirm Import\"],\"H7PCCE\":[\"<0>0</0> means unlimited (rclone default). Example: set to <1>10</1> if the provider caps you at 10 requests/second.\"],\"HCO_et\":[\"Encoding Settings\"],\"HDYzmV\":[\"Gap Eval Interval (ms)\"],\"HKxmVq\":[\"Number of attempts before failing the upload.\"],\"HM56Bx\":[\"Creating...\"],\"HQoaRh\":[\"Args used for processing/encoding\"],\"HRbpjg\":[\"New password (min 8 chars)\"],\"HTu6w6\":[\"Configuration exported successfully\"],\"HUqDD6\":[\"Add Environment Variable\"],\"HVrxmo\":[\"Sanitized streamer name\"],\"HWpPGm\":[\"GPU\"],\"H_IzXL\":[\"IO Job Timeout\"],\"H_smbI\":[\"Duration (ms)\"],\"HajiZl\":[\"Month\"],\"Hhc4OF\":[\"Search jobs...\"],\"HlhZ5D\":[\"Use TLS\"],\"HmHwcC\":[\"Fixed Interval\"],\"HqohwS\":[\"Max Uploads\"],\"HruwW2\":[\"Custom User-Agent string (Optional)\"],\"HtrFfw\":[\"URL is required\"],\"Hu-bBV\":[\"This will cancel all pending and processing jobs in this pipeline.\"],\"HuTRrW\":[\"Stream Player\"],\"HuisDV\":[\"Per-segment Pipeline\"],\"HvemsH\":[\"avg \",[\"averagePerMinute\"]],\"HzqrrK\":[\"Failed to check TDL status: \",[\"0\"]],\"I0LpOM\":[\"Manual update required\"],\"I1WA96\":[\"Rust-Srec\"],\"I2M7LB\":[\"Platform name\"],\"I3Tk-i\":[\"Identity and type of the engine.\"],\"I6bYOx\":[\"Adjust sidebar style and behavior.\"],\"IBg4fi\":[\"No errors found. Max depth: \",[\"0\"]],\"IBn2_D\":[\"Delays, proxy, and retention policies.\"],\"IDbOLP\":[\"Ultra HD\"],\"IDkbnS\":[\"Configure recording rules for this streamer.\"],\"IDqYXq\":[\"Attempt to get the highest original quality available.\"],\"IGmq_R\":[\"Download Delay\"],\"II3urw\":[\"File Operations\"],\"IKSVos\":[\"e.g. /data/tdl\"],\"ILdR4t\":[\"Remux with Faststart\"],\"IMcoE-\":[\"Inset\"],\"IOMDKm\":[\"Failed to start login: \",[\"0\"]],\"IOnYeq\":[\"Reorder Max Segments\"],\"IQTWsi\":[\"Enter keywords to exclude...\"],\"IQlf-W\":[[\"0\"],\"/\",[\"1\"],\" steps\"],\"IR6zrc\":[\"Failed to process selected streamers\"],\"ISAp-4\":[\"Module Log Levels\"],\"ISgi7W\":[\"Upload everything regardless of file type\"],\"IUwGEM\":[\"Save Changes\"],\"IagCbF\":[\"URL\"],\"IduvKA\":[\"Failed to update priority\"],\"IfLN8u\":[\"Monitor this streamer\"],\"IgrLD_\":[\"Pause\"],\"IiCZ6K\":[\"Queued for download\"],\"IjXiiq\":[\"Filter repeated tags and detect stream replay loops\"],\"IjvCsG\":[\"Native\"],\"IkqmLx\":[\"Import Configuration\"],\"IpYp3U\":[\"Min Bitrate (bps)\"],\"IqjaXP\":[\"e.g. mp4\"],\"IrGyPi\":[\"MB/s\"],\"IvxHT8\":[\"Browser Notifications\"],\"IwBsC9\":[\"Confirmed via backstop timer.\"],\"J-PlWE\":[\"Vars: {streamer}, {title}, {platform} | Time: %Y, %m, %d, %H, %M, %S\"],\"J0UZ9c\":[\"Custom imported theme is active.\"],\"J28zul\":[\"Connecting...\"],\"J2AZlk\":[\"Are you sure you want to delete this streamer?\"],\"J4VbZP\":[\"Streamer created successfully\"],\"J50_e4\":[\"Filter Type\"],\"J5nbej\":[\"Critical\"],\"J6n7sl\":[\"Ongoing\"],\"J7EJf5\":[\"TDL not found\"],\"J8slJP\":[\"Runs when the post-processing pipeline completes.\"],\"J9cyui\":[\"Streamers currently live will appear here.\"],\"JB_0cx\":[\"Preferred CDN\"],\"JDgrxe\":[\"Original / Blu-ray\"],\"JDiqy2\":[\"Target number of messages to capture per sample period.\"],\"JEGlfK\":[\"Started\"],\"JGAkEK\":[\"Back to Details\"],\"JGiexj\":[\"Optimize Stream Headers\"],\"JJZidX\":[\"Danmu Service\"],\"JK4_Ie\":[\"None (Strip Audio)\"],\"JO8-jA\":[\"Manage Subscriptions\"],\"JRQitQ\":[\"Confirm new password\"],\"JU-LKs\":[\"How many requests can briefly burst above TPS Limit before throttling kicks in.\"],\"JY5Oyv\":[\"Database\"],\"J_hVSQ\":[[\"0\"]],\"JbursE\":[\"TDL account namespace (`tdl --ns ...`). Use this to manage multiple Telegram accounts.\"],\"Jdxa-V\":[\"Wait indefinitely\"],\"Jf28Qk\":[\"Retry Pipeline\"],\"JiIKww\":[\"Advanced Settings\"],\"Jj9mVU\":[\"Delete Channel?\"],\"JlFcis\":[\"Send\"],\"Jmb3Ih\":[\"Velocity Based (Dynamic)\"],\"JrUYse\":[\"Closest to bitrate\"],\"JrcCgP\":[\"Current Filter Directive\"],\"Jsp72G\":[\"Processed Buffer Multiplier\"],\"Ju0JNh\":[\"Cancelled \",[\"0\"],\" steps in pipeline\"],\"JuSQP0\":[\"Check if file exists after conversion\"],\"JzY5pj\":[\"Describe what this workflow does...\"],\"K-3SzA\":[\"ffmpeg\"],\"K2vkEv\":[\"RESUMED\"],\"K38wNo\":[\"Template Information\"],\"K3UhxB\":[\"Select date range\"],\"K4zqVo\":[\"Failed to create workflow: \",[\"0\"]],\"K7tIrx\":[\"Category\"],\"KAmTRT\":[\"Enable or disable this webhook\"],\"KCzvDi\":[\"Add argument template\"],\"KDw4GX\":[\"Try again\"],\"KOT_Rc\":[\"Multi-Thread Streams help\"],\"KPx1UV\":[\"Not found\"],\"KSS7Tg\":[\"Exclude Keywords\"],\"KTmH5k\":[\"Detach & Edit\"],\"KUyMyQ\":[\"Graceful Stop Timeout\"],\"KWdZQl\":[\"e.g. avc, hevc, av1\"],\"KWm8rr\":[\"Bot Name\"],\"KYBAbP\":[\"When enabled, each VOD segment must complete within this timeout.\"],\"KYdyb_\":[\"Use mobile endpoint for extraction.\"],\"KZOdTD\":[\"Client Type (ctype)\"],\"KZVQ3R\":[\"Space Saver\"],\"K_-HXP\":[\"Directory path copied\"],\"KftHu1\":[\"Min Interval (ms)\"],\"KhgRw2\":[\"Search events...\"],\"KlrNJU\":[\"Real-time status of all system components and resources.\"],\"KmtY_Q\":[\"Pipeline has failed jobs. Click to view details.\"],\"KnPHvA\":[\"Queued Refresh Threshold\"],\"KoQLpo\":[\"Use <0>-movflags +faststart</0>\"],\"Kp3hk-\":[\"Allow private stream proxy targets\"],\"KpU39u\":[\"Per-file cap (rclone --bwlimit-file). Same syntax as Bandwidth Limit; composes with it.\"],\"KtTU7C\":[\"Auth Type\"],\"KwS2c-\":[\"Processing Behavior\"],\"L0Zj1K\":[\"Pipeline Configuration\"],\"L2_QkP\":[\"Pipeline job created successfully\"],\"L3fUx7\":[\"Error: Could not load preset details.\"],\"L4RAy4\":[\"Blacklisted CDNs\"],\"L4c5Kt\":[\"-c copy\"],\"L5RWy_\":[\"HD Thumbnail\"],\"L5WboT\":[\"HTTP Version Preference\"],\"LBEZuS\":[\"Engines\"],\"LBjZN4\":[\"Minute (00-59)\"],\"LCcfAH\":[\"Failed to load engine: \",[\"0\"]],\"LKPR6G\":[\"Playlist\"],\"LLAa_9\":[\"Optional\"],\"LMn91f\":[\"Prefer Manifest\"],\"LNcpxP\":[\"Mozilla/5.0...\"],\"LPJyAY\":[\"How to pair video files with .ass subtitles\"],\"LS_ord\":[\"e.g. 128k, 320k\"],\"LTNjeK\":[\"Container\"],\"LXMVBR\":[\"Delete source file after successful processing\"],\"L_OaTA\":[\"Archive to Cloud\"],\"L_XFOI\":[\"Detailed processor settings\"],\"LbS6Kh\":[\"Minimum Priority\"],\"Le70b5\":[\"More pages\"],\"LhOLxv\":[\"Runs decryption on a blocking thread pool to avoid stalling async tasks.\"],\"LiJMls\":[\"Search platforms...\"],\"Lk1OGO\":[\"Offline Detection\"],\"LoIUGx\":[\"Supports placeholders: \",[\"PLACEHOLDER_TOKENS\"],\" and time tokens like %Y/%m/%d.\"],\"LovaQl\":[\"Max number of retry attempts for metadata fetching.\"],\"LqH9mJ\":[\"Recent notification events persisted by the backend\"],\"Lqw9xW\":[\"Scoring Rules\"],\"LubEtv\":[\"Edit Preset: \",[\"0\"]],\"M0uszl\":[\"Preset created successfully\"],\"M3QKhO\":[\"Platform Specific Configuration\"],\"M6bo58\":[\"C:path1.flv,C:path2.flv\"],\"M73whl\":[\"Context\"],\"MA1f6y\":[\"High+\"],\"MAKqi9\":[\"e.g. 10M\"],\"MDzSyV\":[\"Manage credentials and session cookies.\"],\"MF-nAj\":[\"Replace output files if they already exist\"],\"MHrjPM\":[\"Title\"],\"MItgvI\":[\"Default Engine\"],\"MMoZg-\":[\"Stream Mapping (-map)\"],\"MNP_Hr\":[\"Processed Segment TTL (ms)\"],\"MQpz7h\":[[\"0\"],\" stream(s) added successfully\"],\"MS1IQE\":[\"Dual Format\"],\"MUbeuH\":[\"HLS stream fixing pipeline\"],\"MUwvHM\":[\"Limits & Validation\"],\"MXuUDu\":[\"Ignored when \\\"Upload All Files\\\" is enabled.\"],\"MYKXvL\":[\"Unknown Engine ID: \",[\"engineId\"]],\"Mbxk4g\":[\"Target Bitrate\"],\"MhA4UF\":[\"Clone Preset\"],\"Mi9SD3\":[\"Let the in-app stream player proxy sources on private or local networks (LAN restreamers, cameras, tailnet addresses). Leave off unless you stream from local sources: it re-opens requests to internal addresses for any signed-in user.\"],\"Mj31d8\":[\"Override global default.\"],\"Ml0gf8\":[\"Multimedia Archive\"],\"MmFX21\":[\"paused\"],\"MpHt5u\":[\"Add Streamer\"],\"Mpp8ys\":[\"pipelines\"],\"MqP8N6\":[\"Force Origin Quality\"],\"MqVdIO\":[\"1 (best) - 31 (worst)\"],\"MqWOyf\":[\"Checkers help\"],\"Mr5Sgb\":[\"Extract audio track to AAC format (256kbps). High quality, widely compatible.\"],\"MucYW8\":[\"Test notification sent\"],\"Mv6ltO\":[\"Max bitrate (bps)\"],\"MvqJb2\":[\"Create Engine\"],\"N1_4qm\":[\"Triggered when a pipeline job finishes successfully.\"],\"N2fC4V\":[\"Maximum number of concurrent segment downloads.\"],\"N2twk8\":[\"Date placeholder anchor\"],\"N40H-G\":[\"All\"],\"N4UV0r\":[\"Streamer no longer exists on the platform\"],\"N5gYIZ\":[\"Low-latency GPU-accelerated H.264 encoding for real-time applications. Minimal encoding delay.\"],\"N5vGcw\":[\"Enter your credentials to access your account\"],\"N683xA\":[\"Max Bitrate (bps)\"],\"N7OGFm\":[\"Please restart the system to ensure all configuration changes are fully applied.\"],\"N7k2OK\":[\"e.g. /output/processed/\"],\"N8YRwn\":[\"e.g. cdn-to-avoid\"],\"NAhOWx\":[\"Fatal:\"],\"NBO4gh\":[\"Concurrency & Performance\"],\"NBdIgR\":[\"Comment\"],\"NEnLep\":[\"Global Default\"],\"NFIOKv\":[\"Advanced Options\"],\"NF_R69\":[\"Job not found\"],\"NGO2th\":[\"All Templates\"],\"NHNfp3\":[\"Engine Name\"],\"NHyRGf\":[\"Case Insensitive\"],\"NIBH_E\":[\"Error Loading Job\"],\"NJevqq\":[\"Validate\"],\"NKYo1x\":[\"ASS Subtitle Burn-in\"],\"NMhr2I\":[\"Twitch OAuth token for subscriber-only and high-quality streams.\"],\"NPY5Jv\":[\"Configure a new download tool.\"],\"NQSiYb\":[\"Search by name or description...\"],\"NQas2m\":[\"Runs when both video and danmu segments are available. Requires \\\"Record Danmu\\\" to be enabled.\"],\"NRL17y\":[\"Streamer check history\"],\"NUrY9o\":[\"Categories\"],\"NV_uNb\":[\"Web Push is not configured on the server\"],\"NaT0vv\":[\"API protocol to use for live stream extraction. WUP is the standard protocol for the PC app. Note that WUP and MP only work with numeric room IDs.\"],\"NeoigG\":[\"File size threshold (--multi-thread-cutoff).\"],\"NggFDy\":[\"Total system load\"],\"NhNdzH\":[\"Runs once after the recording session concludes and all individual segment pipelines have finished. Ideal for session-wide actions like merging all segments, final notifications, or cleanup.\"],\"Nhf7R9\":[\"Telegram Desktop Directory\"],\"Ni3LjM\":[\"Resolution change\"],\"NkYQEG\":[\"i\"],\"NmgWBj\":[\"Failed to delete workflow: \",[\"0\"]],\"NnH3pK\":[\"Test\"],\"NoNwIX\":[\"Inactive\"],\"NomFnG\":[\"No changes detected\"],\"NotuGY\":[\"Status checks per second across all streamers on this platform. Slows down automatically when the platform answers with HTTP 429.\"],\"NrNA7z\":[\"Create your first job preset to define reusable processor configurations for your pipelines.\"],\"NstKgB\":[\"best\"],\"Nu4DdT\":[\"Sync\"],\"Nu4oKW\":[\"Description\"],\"Nw_bTM\":[\"In active\"],\"NxVfIs\":[\"Overall Health\"],\"Nz-2uW\":[\"Download Engine\"],\"Nzwli2\":[\"Base\"],\"O3-SAG\":[\"Recording will start when schedule allows\"],\"O7sQsS\":[\"Desktop notifications are shown by the operating system even when the app is minimized.\"],\"O8tK4v\":[\"Add rule\"],\"O92EVI\":[\"Verify Output\"],\"OD_lHs\":[\"Add an override to customize behavior for specific platforms.\"],\"OGA8CA\":[\"Remux / Transcode\"],\"OGryJ9\":[\"Move File\"],\"OH6m8Z\":[\"No other steps available to depend on.\"],\"OHthyf\":[\"Rejected\"],\"OIx5Vo\":[\"Basic details for this configuration template.\"],\"OJx3wK\":[\"Not available\"],\"OL5-bu\":[\"/usr/share/fonts\"],\"ONWvwQ\":[\"Upload\"],\"ONchxy\":[\"total\"],\"OO3KPE\":[\"No configuration.\"],\"OOXKcO\":[\"Select the events that should trigger notifications for <0>\",[\"0\"],\"</0>.\"],\"OR475H\":[\"Network\"],\"ORCmn3\":[\"Avatar URL (Optional)\"],\"OS845W\":[\"A unique name for this configuration template.\"],\"OSkKYq\":[\"Platform Configuration\"],\"OUlu9A\":[\"Sample Rate (Hz)\"],\"OXyghd\":[\"Failed to load jobs: \",[\"0\"]],\"Oc-UBb\":[\"Fine tune brand and surface colors.\"],\"OfhWJH\":[\"Reset\"],\"Oj01eI\":[\"Min Free Space\"],\"Oju_Jw\":[\"Replace output files\"],\"OkJ0bQ\":[\"TDL status unknown\",[\"version\"]],\"Okz6xA\":[\"Out of Schedule\"],\"OlFEI3\":[\"Skip after both\"],\"OlXThP\":[\"Bandwidth\"],\"Om3XSw\":[\"API Settings\"],\"Oq4ArH\":[\"Unrecognised event kind.\"],\"OqVga-\":[\"Cheap operations, so the default of 8 is usually fine. Increase if you have many small files against a slow remote.\"],\"OrxruT\":[\"Copy Directory\"],\"Ou1XUj\":[\"Validation Failed\"],\"OurehU\":[\"Platform Detected: \",[\"detectedPlatform\"]],\"OvTPz2\":[\"Select operation\"],\"Ovx4g5\":[\"https://twitch.tv/...\"],\"OxBy4W\":[\"Continue on Error\"],\"P-1VRa\":[\"Platform configurations will appear here when available.\"],\"P4KIZR\":[\"Remux to MKV\"],\"P5eNZ-\":[\"Last Error Log\"],\"P6F38F\":[\"Subscriptions\"],\"P8fBlG\":[\"Authentication\"],\"PE3gQR\":[\"CDN\"],\"PFFhCT\":[\"Height\"],\"PFgYun\":[\"Untitled Stream\"],\"PH-ukv\":[\"-reconnect 1\"],\"PIjwVk\":[\"Generate a Full HD thumbnail (1280px width) for modern displays and video players.\"],\"PJDaJr\":[\"Select the days required for this filter to apply.\"],\"PJf7YE\":[\"AFTER: \",[\"0\"]],\"PMM5g4\":[\"Custom FFmpeg\"],\"PRzc1D\":[\"Upload and Delete\"],\"PTBQLa\":[\"Create Directories\"],\"PTI2EV\":[\"(Delete source)\"],\"PUbRZA\":[\"Maximum number of retry attempts.\"],\"PVRSCC\":[\"Loading presets...\"],\"PVnlzh\":[\"Add Step\"],\"Pa-XIK\":[\"Ready to Watch\"],\"PaO3bE\":[\"Session complete pipeline is not supported for this entity.\"],\"PaQ3df\":[\"Enable\"],\"Pcymhf\":[\"Waiting since\"],\"PezyAA\":[\"Update Channel\"],\"PfW3ld\":[\"Transient monitoring errors are being preserved until sustained download progress confirms the stream is healthy.\"],\"PfWJSn\":[\"Checkers\"],\"Pfx_nq\":[\"Component Status\"],\"PhEJJc\":[\"Directory to scan for new files after command execution\"],\"Pi72nP\":[\"Streamer Offline\"],\"PiH3UR\":[\"Copied!\"],\"PkUJDM\":[\"Gotify application token\"],\"Pn2B7_\":[\"Current password\"],\"Poh-g6\":[\"Create Pipeline\"],\"Pw01g0\":[\"Hide password\"],\"Pwqkdw\":[\"Loading…\"],\"PzFzS-\":[\"Add Filter\"],\"Q-6haG\":[\"No credentials found.\"],\"Q-pr7E\":[\"Danmu Statistics\"],\"Q0LExG\":[\"Login session cancelled\"],\"Q3D3r6\":[\"libx264\"],\"Q3TXK1\":[\"Consecutive Failures\"],\"Q5duaX\":[\"Floating\"],\"Q6hhn8\":[\"Preferences\"],\"Q7Xlbh\":[\"Douyu UHD rate\"],\"QBhhb7\":[\"Copy File\"],\"QCzB9v\":[\"Input Path\"],\"QDaWgK\":[\"DanmakuFactory\"],\"QEazml\":[\"Delete selected\"],\"QFO8CU\":[\"Convert danmu XML (Bilibili-style) into .ass subtitles using DanmakuFactory. Manifest-aware and batch-safe.\"],\"QHcLEN\":[\"Connected\"],\"QHhZeE\":[\"Template created successfully\"],\"QMb3Xr\":[\"Multiplier for exponential backoff.\"],\"QRAWTO\":[\"Form\"],\"QWdKwH\":[\"Move\"],\"Q_OjVh\":[\"Interval between checks when a streamer is live to detect when the stream ends.\"],\"Q_vbsf\":[\"Danmaku to ASS\"],\"Qc-YIj\":[\"Override configuration (paths, delays, etc.) for specific platforms.\"],\"QcH0aB\":[\"Lossless Split\"],\"QglPWi\":[[\"0\"],\" at \",[\"1\"]],\"Qj-jxB\":[\"Exit Immersive\"],\"QkHwaF\":[\"Web Push enabled for this browser\"],\"QlT4B5\":[\"Recent Sessions\"],\"QmWJ8K\":[\"587\"],\"QqGYdP\":[\"Select a default engine\"],\"QrXc9O\":[\"Disable selected\"],\"Qttcbs\":[\"Failed to trigger check\"],\"QulvHL\":[\"Each stream scores the weights of the rules it meets, and the highest score wins. Preferences above only break ties. Ban CDN rules exclude streams entirely.\"],\"QvdYlQ\":[\"Fonts Directory\"],\"QxKlNl\":[\"GPU Standard\"],\"QyYvAV\":[\"Precision Schedule\"],\"R3w8ra\":[\"No keywords set\"],\"R7mB1g\":[\"Preview Unavailable\"],\"R8WLd-\":[\"Force IPv4 (Default: Off)\"],\"RAHVXV\":[\"If your account has Telegram 2FA enabled, use Desktop login (`-T desktop`) or run `tdl login` locally in a terminal.\"],\"RAMost\":[\"No outputs found\"],\"RDVO7W\":[\"Go to page \",[\"p\"]],\"RDf8Wy\":[\"GPU-accelerated post-processing: NVENC H.264 encoding and thumbnail generation.\"],\"RJEhaJ\":[\"https://lb-eu.cdn-perfprod.com\"],\"RMguW7\":[\"Platform configuration updated successfully\"],\"RP7-1c\":[\"Windows Console Error\"],\"RQI0-M\":[\"Output Detection\"],\"RRWxve\":[\"Client platform type used for stream authentication signing. Different platforms use different signing methods.\"],\"RRrXGo\":[\"Please enter your new password details\"],\"RS0o7b\":[\"State\"],\"RS9BzM\":[\"Treat interactive games as offline platforms during extraction checks.\"],\"RTR7ET\":[\"FLV parser\"],\"Ralayn\":[\"Add Tag\"],\"Ratxqc\":[\"Start Desktop Login\"],\"RbevHh\":[\"Batch parse error: \",[\"0\"]],\"RclaHZ\":[\"Runs when both the video segment and its corresponding danmu segment are available.\"],\"RdUvEI\":[\"Filename Template\"],\"RdhLi5\":[\"Pending Error Clears\"],\"RehBv_\":[\"Override global defaults for this streamer.\"],\"RhkH8I\":[\"My Channel\"],\"RkoA4Q\":[\"Working Directory\"],\"RmEeKl\":[\"bytes\"],\"RoOfVq\":[\"Date range:\"],\"RojhAf\":[\"Username (Optional)\"],\"RqGEko\":[\"Segment\"],\"Rsjgm0\":[\"Experimental\"],\"Rti4g1\":[\"The underlying tool used for downloading.\"],\"Ruqy5-\":[\"Required if the stream is password-protected by the broadcaster.\"],\"RxzN1M\":[\"Enabled\"],\"S-Irrb\":[\"Updates existing items and creates new ones. Nothing is deleted.\"],\"S-gCuS\":[\"HTTP/2 Only\"],\"S0kLOH\":[\"ID\"],\"S1Avy-\":[\"Danmu statistics are not available for this session.\"],\"S2X4xO\":[\"No output generated\"],\"S32Mbj\":[\"Platform not found\"],\"S34mdL\":[\"Default password for password-protected rooms. Can be overridden per streamer with ?pwd= in the URL.\"],\"S4s1m4\":[\"Failed to update preset: \",[\"0\"]],\"S5wLae\":[\"Enter a URL to parse and play a stream\"],\"SCZJhh\":[\"Audio Bitrate\"],\"SE58Y_\":[\"Manage where system notifications are sent\"],\"SG6r-s\":[\"Password / 2FA blocked\"],\"SHEb8h\":[\"No presets found\"],\"SHZOTz\":[\"Failed to update streamer\"],\"SJV-d4\":[\"Use stream start time to keep a midnight-crossing stream in one dated folder and group all segments of a session together.\"],\"SJd-31\":[\"Use System Proxy\"],\"SJmm2B\":[\"Fast GPU-accelerated AV1 encoding using NVIDIA NVENC (CQ 28). Requires RTX 4000+ series.\"],\"STFGOH\":[\"HTTP middleware\"],\"SWoipt\":[\"Depends On (Ancestors)\"],\"SYKx8m\":[\"Generate new QR code\"],\"SZIVAR\":[\"Download Manager\"],\"SZw9tS\":[\"View Details\"],\"S_H6kY\":[\"Force IPv6 (Default: Off)\"],\"S_tge3\":[\"Output paths, templates, and formats.\"],\"Sel_id\":[\"Paired Segment\"],\"ShPPm7\":[\"No title changes or lifecycle events were recorded for this session.\"],\"SjnTbJ\":[\"Advanced Configuration\"],\"Sl7mrQ\":[\"Split after size.\"],\"SlfejT\":[\"Error\"],\"Svkela\":[\"Go to previous page\"],\"SwbFPL\":[\"streamlink\"],\"SwdouK\":[\"NVENC H.264 Low Latency\"],\"SxJsAt\":[\"Select your preferred color scheme.\"],\"Sxm8rQ\":[\"Users\"],\"T1SAsR\":[\"Session thumbnail\"],\"T3rjBy\":[\"Jobs will appear here when recordings are processed.\"],\"T4sjpn\":[\"Merge Changes\"],\"T5l00f\":[\"workflows\"],\"T6F_S1\":[\"Critical alert when the processing queue is full.\"],\"T7yhkH\":[\"Output Format\"],\"T8_uFg\":[\"Settings have been optimized for this platform.\"],\"T8drou\":[\"System Health\"],\"T91vKp\":[\"Play\"],\"TAsVLX\":[\"Failed to create template: \",[\"0\"]],\"TB_URP\":[\"Select date anchor\"],\"TCURBK\":[\"(Preserve source)\"],\"TH-C6m\":[\"Successfully deleted \",[\"0\"],\" sessions\"],\"TJBHlP\":[\"Standard\"],\"TKiEkr\":[\"Select current page\"],\"TLZ7Zy\":[\"Multi-thread upload only helps on large files; on small ones the splitting overhead outweighs the benefit.\"],\"TP4vdF\":[\"--hls-live-edge 3\"],\"TP9_K5\":[\"Token\"],\"TXe7lI\":[\"View Detailed Logs & Outputs\"],\"TYVgbP\":[\"Include\"],\"TYVyHm\":[\"No template assigned\"],\"T_R-Qz\":[\"Primary\"],\"TaKLqK\":[\"Exclude Patterns\"],\"TaeloW\":[\"Any priority\"],\"TduT07\":[\"Starting tdl process...\"],\"Tfm8LB\":[\"Matching resolution\"],\"TiE1q0\":[\"VOD Gap Strategy\"],\"TjrbDj\":[\"Uptime\"],\"Tkh4vv\":[\"Max Quality Thumbnail\"],\"TlWFTu\":[\"Downloading \",[\"0\"]],\"Tm4H6N\":[\"Type something...\"],\"TnMphH\":[\"Automate your stream recordings with ease and reliability.\"],\"ToM7t8\":[\"Prioritize specific CDN providers. Press Enter to add.\"],\"ToW2mM\":[\"Delete Engine?\"],\"Tof7pX\":[\"Jobs\"],\"Tu5he7\":[\"Treat interactive game streams (互动玩法) as offline.\"],\"Tu7ijM\":[\"Set \\\"TDL Binary Path\\\" to your `tdl` executable, or make sure `tdl` is in PATH.\"],\"Tu9eY0\":[\"Active Days\"],\"TxOTCX\":[\"Used for Desktop login (fallback); points to Telegram Desktop folder containing `tdata`.\"],\"TymLa2\":[\"Higher = faster on fast connections, but more aggressive on the remote and your CPU. Increase only if your network and provider can handle it.\"],\"Tz0i8g\":[\"Settings\"],\"U1Im-N\":[\"Template Name\"],\"U5tR0I\":[\"Runs when a download encounters an error.\"],\"U62Eow\":[\"Complete the Telegram login process in this terminal.\"],\"U80ejq\":[\"Consecutive Failures\"],\"U9NWQ6\":[\"Overall system status\"],\"UC17hj\":[\"Default password for password-protected rooms (can be overridden per-streamer with ?pwd= in the URL).\"],\"UCFp-L\":[\"Checking stream status...\"],\"UCRwk1\":[\"/path/to/rclone.conf\"],\"UGLwm7\":[\"Select priority\"],\"UHgtCG\":[\"No streamers found\"],\"UPqS9C\":[\"Health\"],\"UPyNTs\":[\"MB\"],\"URWWZg\":[\"e.g. High Quality Streamlink\"],\"UWuUpd\":[\"Execute shell commands on lifecycle events. Commands run in the system shell.\"],\"UYX0mr\":[\"Try adjusting your search or filters, or add a new streamer to start monitoring.\"],\"UZREi-\":[\"templates\"],\"UZc_Dd\":[\"No talker data\"],\"UaBHAx\":[\"Bilibili QR Login\"],\"UatJ7w\":[\"Exit selection mode\"],\"UbRKMZ\":[\"Pending\"],\"UcB-qb\":[\"Delete selected streamers?\"],\"UcL3cf\":[\"Accept Invalid Certs (Default: Off)\"],\"UhCJxj\":[\"Processor\"],\"UicgBF\":[\"Double click to edit items.\"],\"Uj7KrY\":[\"Use QR Login\"],\"UlKSMd\":[\"Left\"],\"Un7b8x\":[\"Top Words\"],\"Uns82o\":[\"Restart Required\"],\"UnuCpk\":[\"Execute Timeout\"],\"Ur7o1U\":[\"Local Archive\"],\"Urp3J5\":[\"Maximum compression to minimize storage usage, then delete original.\"],\"Uu2O-E\":[\"Runs when both video and danmu segments are available.\"],\"UwlIzL\":[\"Assign template\"],\"UxK12K\":[\"Recordings\"],\"UxpxnD\":[\"File Ops\"],\"UyWBj5\":[\"Unhealthy\"],\"Uypo6H\":[\"Timeout before cancelling IO-bound processors.\"],\"V3Lf0z\":[\"Upload (Rclone)\"],\"V50IgX\":[\"Failed to load workflows: \",[\"0\"]],\"V7Msqg\":[\"preset\"],\"V8SjEq\":[\"Include Keywords\"],\"VAn9oA\":[\"Critical alert when the GPU becomes unavailable to the container (most often the NVIDIA Container Toolkit cgroup-v2 issue), so NVENC remux/transcode jobs would fail.\"],\"VFX661\":[\"Skip after duration\"],\"VGt9mz\":[\"Trimming & Time\"],\"VJScHU\":[\"Reason\"],\"VJw9N6\":[\"Select a session\"],\"VLfBk9\":[\"Recovering\"],\"VPPVAx\":[\"Retries for locked files\"],\"VPqi9P\":[\"No parameters configured.\"],\"VQ4EeT\":[\"Optional description for this preset\"],\"VU-UYl\":[\"Channel updated\"],\"VUURcc\":[\"Effective Scope\"],\"VZobzV\":[\"QR code expired\"],\"Ve9s51\":[\"Paired pipeline is not supported for this entity.\"],\"VeOdql\":[\"Login to Telegram\"],\"Vflq7Z\":[\"This stream is live but the global download limit is fully in use. Recording and danmu collection will start automatically when a slot frees.\"],\"Vg88qu\":[\"Run a custom shell command with placeholders (e.g. \",[\"input\"],\", \",[\"inputs_json\"],\", \",[\"streamer\"],\", %Y%m%d).\"],\"VhBoUx\":[\"Loaded template: \",[\"0\"]],\"VhMDMg\":[\"Change Password\"],\"ViIBwn\":[\"channel1,channel2\"],\"Vk_Ar1\":[\"More filters\"],\"VlEnCC\":[\"Archive Format\"],\"VoevdT\":[\"Output Folder\"],\"VupaNT\":[\"Standard cron expression (sec min hour day mon dow).\"],\"VvhIcU\":[\"No custom tags added\"],\"Vw8l6h\":[\"An error occurred\"],\"VzJOiq\":[\"Pipeline Manager\"],\"W-0nOp\":[\"Offload Decryption (Default: On)\"],\"W0g6Ji\":[\"Metadata Variables\"],\"W0omwk\":[\"Design your automation pipeline step by step\"],\"W1jNHq\":[\"Scan with Bilibili mobile app\"],\"W5FKSG\":[\"On Download Complete\"],\"W743Px\":[\"Filtering & Behavior\"],\"W7R6SY\":[\"App Token\"],\"WCNCz3\":[\"123456:ABC-DEF...\"],\"WEYdDv\":[\"Recommended\"],\"WEmAU3\":[\"Definitive Offline\"],\"WIC5BC\":[\"Search presets...\"],\"WJAaWC\":[\"Target Danmus per Sample\"],\"WJXa70\":[\"Unix timestamp\"],\"WKHqM-\":[\"Weight\"],\"WOfIql\":[\"Create New Preset\"],\"WRQtA_\":[\"e.g. 250M\"],\"WSlv-9\":[\"Select config\"],\"WSxWtw\":[\"Failed to enable Web Push\"],\"WWCeOb\":[\"Add module...\"],\"WXZiyd\":[\"Ultrafast H.264 encoding (CRF 26) for quick sharing.\"],\"W_-iQs\":[\"Fetcher\"],\"W_geaq\":[\"Select login type\"],\"WcOY3Y\":[\"Bot Token\"],\"WcgzjF\":[\"name@example.com\"],\"WdAjAz\":[\"Enable Proxy\"],\"Weq9zb\":[\"General\"],\"WgACNk\":[\"Move Files\"],\"WgCCHx\":[\"Runs when a download completes successfully.\"],\"WhUS54\":[\"ZIP Archive\"],\"WhgehB\":[\"Session Duration\"],\"X-oEI0\":[\"Parsing Stream...\"],\"X56hfZ\":[\"No platform configurations found. Please create one first.\"],\"X5P6yv\":[\"Recently updated\"],\"X6ddkF\":[\"Low Disk Space Action\"],\"XB0Vn9\":[\"No Archives Found\"],\"XC33bC\":[\"H.264 medium compression (CRF 23) optimized for long-term storage.\"],\"XEH5pP\":[\"Max Stall Duration (ms)\"],\"XElSLJ\":[\"Login Args\"],\"XEpExY\":[\"Nth input: \",[\"input0\"],\", \",[\"input1\"],\"...\"],\"XF7Hlq\":[\"Start Over\"],\"XJumtU\":[\"Reusable processor configurations for pipeline steps\"],\"XOxZT4\":[\"Signing in...\"],\"XPG0DN\":[\"New Template Name\"],\"XQaZzY\":[\"TPS Burst\"],\"XS1iGf\":[\"Keep directory structure\"],\"XTz5Sa\":[\"rclone default: 4\"],\"XU5AOg\":[\"Input\"],\"XVCljg\":[\"Multi-Thread Copy\"],\"XWgxXq\":[\"Album\"],\"XY_iWu\":[\"Network & Content\"],\"X_MmPT\":[\"Process video and extract podcast audio in parallel, then upload both.\"],\"Xdd3Qd\":[\"Triggered when a recording is finalized to free a slot for a higher-priority streamer.\"],\"Xit5Tv\":[\"Audio Properties\"],\"XjbMaz\":[\"Back to Pipeline Jobs\"],\"XjoBtr\":[\"Filtered:\"],\"XmKjfh\":[\"Recording\"],\"XvPVPo\":[\"Configure and launch a manual processing pipeline.\"],\"XvjC4F\":[\"Saving...\"],\"Xy0Hxb\":[\"Rust regex syntax supported.\"],\"Y27roI\":[\"Custom rate\"],\"YDVUVl\":[\"Event Types\"],\"YMf077\":[\"OAuth Token\"],\"YPcVP2\":[\"No presets yet\"],\"YVNfpq\":[\"(Mirror source)\"],\"YXjgxA\":[\"System-wide preferences\"],\"Ya3PlJ\":[\"Permission required\"],\"YdqAGb\":[\"View Danmu\"],\"YjhKI5\":[\"Cloning...\"],\"YqG2MJ\":[\"Set constraints on downloads.\"],\"Ysuy2A\":[\"Template cloned successfully\"],\"Yu2FvR\":[\"This will permanently delete the preset \\\"\",[\"0\"],\"\\\". Pipelines using this preset may fail if not updated.\"],\"YuTKa4\":[\"Failed\"],\"Yy3BPK\":[\"Maximum quality compression with HEVC, then upload to cloud storage.\"],\"Z0iPqp\":[\"Configure download engine parameters\"],\"Z3KFHf\":[\"Backoff Multiplier\"],\"Z5vYTW\":[\"Manage your application configuration.\"],\"ZAVcwe\":[\"Changing Password...\"],\"ZBMTd_\":[\"No matching events found\"],\"ZF0SVM\":[\"Streams per file (--multi-thread-streams).\"],\"ZKI9Rd\":[\"Enable advanced HLS segment reconstruction\"],\"ZMViOQ\":[\"Total size:\"],\"ZNKcYa\":[\"All Levels\"],\"ZNg9mI\":[\"Restore password\"],\"ZOK2-a\":[\"type=bolt,path=/data/tdl\"],\"ZOtn9F\":[[\"minutes\"],\"m\"],\"ZTc3M9\":[\"Completed\"],\"ZWRt1W\":[\"Output Path\"],\"ZWZjuT\":[\"Variant\"],\"ZYn2Q_\":[\"Create Filter\"],\"ZYnwzF\":[\"Disconnected\"],\"Za9T3e\":[\"Check duration\"],\"Zag-nw\":[\"Rclone Transfer\"],\"ZfRjhV\":[\"Max CPU Jobs\"],\"Zi7dyz\":[\"Script\"],\"Zl_Fwr\":[\"Manage specialized extraction and identification options.\"],\"ZlzjXV\":[\"Passthrough Inputs\"],\"Zrh7RT\":[\"Failed to update platform: \",[\"0\"]],\"Zv4awZ\":[\"128k\"],\"Zw6LWb\":[\"Failed to delete job\"],\"ZyqOPH\":[\"Credential Source Status\"],\"Zz_XpE\":[\"Per-Streamer\"],\"Zza1dz\":[\"NVENC HEVC Fast\"],\"_-DHD0\":[\"Configure a new streamer to monitor and record.\"],\"_0ftcA\":[\"Error Loading Session\"],\"_73JEY\":[\"TPS Burst help\"],\"_Bh-7r\":[\"Performance\"],\"_BzI2m\":[[\"0\",\"plural\",{\"one\":[\"#\",\" Error\"],\"other\":[\"#\",\" Errors\"]}]],\"_Ct04k\":[\"Generate a maximum quality thumbnail (1920px width) preserving full 1080p detail.\"],\"_GsGHq\":[\"Job creation time (default)\"],\"_HgF9q\":[\"Sort by\"],\"_I6a4I\":[\"No platforms configured\"],\"_K2CvV\":[\"Template\"],\"_KseE7\":[\"Delete File\"],\"_M7YFj\":[\"Quality (qscale)\"],\"_NgV8X\":[\"Triggered when a pipeline job is cancelled.\"],\"_O8edb\":[\"Upload Arguments\"],\"_PAAt2\":[\"0 = Unlimited\"],\"_QU1-7\":[\"Login Type\"],\"_Qf5fl\":[\"Priority Filter\"],\"_SvQT0\":[\"Failed to download logs\"],\"_TEOcd\":[\"Presets\"],\"_VaoHF\":[\"Job cancelled\"],\"__tkD5\":[\"Segment TTL (ms)\"],\"_bBHGW\":[\"Delete Pipeline?\"],\"_bM9cT\":[\"GPU HQ Archive\"],\"_cnOWE\":[\"Send a website-style integrity token with studio requests. Enabled by default; disable only if minting fails in your network.\"],\"_gYAMa\":[\"Chat ID\"],\"_gn6RG\":[\"Fatal Error\"],\"_hKQcr\":[[\"seconds\"],\"s\"],\"_jQctM\":[\"To\"],\"_kwOBw\":[\"Job Timeouts\"],\"_l-j6K\":[\"Logs downloaded successfully\"],\"_l7qFE\":[\"Recording started\"],\"_oqig9\":[[\"hours\"],\"h\"],\"_p1aNb\":[\"Error loading workflow\"],\"_rTz0M\":[\"Audio\"],\"_srpvQ\":[\"Failed to delete channel\"],\"_tQOXN\":[\"Active Download\"],\"_w3Fy0\":[\"Define how this step identifies itself and connects to others.\"],\"_wFMEI\":[\"PENDING CONFIRMATION\"],\"_xVC1p\":[\"TDL Status\"],\"_xfOhY\":[\"Media Timeline\"],\"_zg39j\":[\"Dry Run\"],\"a0w0iQ\":[\"Subtitle\"],\"a4Dp3l\":[\"Channel deleted\"],\"a9L56c\":[\"Select behavior\"],\"aDPadU\":[\"Triggered when a new file segment is created.\"],\"aFWU1n\":[\"Width\"],\"aFk-Ho\":[\"This streamer is currently live, but outside your configured recording schedule.\"],\"aH0Q8c\":[\"Select strategy\"],\"aIUW65\":[\"Lowest bitrate\"],\"aS09EA\":[\"Receive a notification when a stream ends.\"],\"aS11pb\":[\"Select a processing job or workflow to add to your pipeline.\"],\"aVJmtU\":[\"Streams extracted\"],\"aZ2Kcr\":[\"Configure where and how you receive notifications.\"],\"aZUYz0\":[\"Fast GPU-accelerated HEVC/H.265 encoding using NVIDIA NVENC (CQ 24). Smaller files than H.264.\"],\"acyWOE\":[\"Timeout (s)\"],\"afHZ2T\":[\"SESSION ENDED\"],\"ailPEn\":[\"Event Type\"],\"aj9IS5\":[\"Concurrency\"],\"akGgwH\":[\"Selected stream\"],\"akShFD\":[\"Cleanup\"],\"amsJwt\":[\"Receive a notification when a streamer goes live.\"],\"aoDNXt\":[\"Operation Mode\"],\"atAJGZ\":[\"platforms\"],\"atJjwW\":[\"e.g. echo \",[\"input\"],\" > \",[\"output\"]],\"azIGX7\":[\"Overwrite existing files\"],\"azuNEn\":[\"Pipeline is already completed or cancelled\"],\"b21pJB\":[\"Playlist TTL (ms)\"],\"b24kPi\":[\"Queue\"],\"b2MuzC\":[\"Time offset to take screenshot from\"],\"b2Nri6\":[\"Pool Idle Timeout (ms)\"],\"b7Incv\":[\"Operation Type\"],\"b7wV8U\":[\"MP\"],\"b92aqq\":[\"Loading older logs...\"],\"bAaOjo\":[\"Capture separate stream data.\"],\"bFAwGy\":[\"Select a preset or type a Douyu rate. Audio only requests AAC without video.\"],\"bGboGD\":[\"Nth output: \",[\"output0\"],\", \",[\"output1\"],\"...\"],\"bLK9zG\":[\"Protection Settings\"],\"bMD8SW\":[\"Auto-Refresh\"],\"bOj5u_\":[\"No Steps Added\"],\"bRVIqV\":[\"Suppress redundant headers to reduce player micro-stutter\"],\"bT3NnO\":[\"Media outputs will appear here when pipeline jobs complete.\"],\"bYkKu9\":[\"Runs when a download begins.\"],\"bZ_nY3\":[\"Twitch Proxy Playlist\"],\"bagRV4\":[\"Add New Streamer\"],\"bb6TRf\":[\"Min Segment Size\"],\"bcfSrW\":[\"Database queries\"],\"bd8uQe\":[\"FFmpeg Video Filter\"],\"bdSNjC\":[\"Temporarily Paused\"],\"be6yVE\":[\"e.g. My Favorite Streamer\"],\"bex7vZ\":[\"Failed to import configuration\"],\"bgAdR4\":[\"List of allowed file extensions (without dot).\"],\"bgdEys\":[\"Web Push is not supported in this browser\"],\"bjp1xg\":[\"Border Radius\"],\"bkLoJJ\":[\"Second (00-59)\"],\"bkZWD4\":[\"Credentials refreshed successfully\"],\"bqYxTf\":[\"Audio Extraction\"],\"bql7qo\":[\"Backjump Threshold\"],\"bwRvnp\":[\"Action\"],\"bzOX-6\":[\"Platform URL extractors\"],\"c0LHG_\":[\"Pipeline Job Timeouts\"],\"c2Qn8C\":[\"Select preset\"],\"c3b0B0\":[\"Get Started\"],\"c5Xt89\":[\"Name Z-A\"],\"c5qkaI\":[\"Monitoring Stopped\"],\"c6pG_r\":[\"Engine created successfully\"],\"c7Ho94\":[\"JSON array of all outputs\"],\"c8-mAV\":[\"Segment Timeout (ms)\"],\"c8iRL_\":[\"Refine and sort streamers\"],\"c8nkFX\":[\"Time to wait for ffmpeg to finalize the file before force-killing it\"],\"cCbxO-\":[\"Extract audio track to MP3 format (192kbps). Good for podcasts and music.\"],\"cCfxH1\":[\"Downloading...\"],\"cDD5xY\":[\"Last Error\"],\"cDR51K\":[\"Default pipeline flow. Configure the sequence of processors for new jobs.\"],\"cEHxsl\":[\"Audio codec change\"],\"cH5kXP\":[\"Now\"],\"cLVp5Z\":[\"Log Files\"],\"cNC2t_\":[\"Copy the file to another location. Keeps the original file.\"],\"cO9-2L\":[\"Disable\"],\"cP_zDS\":[\"Rate-limits API calls to the remote. Useful when a provider returns 429 \\\"too many requests\\\" under heavy load.\"],\"cRxiHL\":[\"Import Successful\"],\"cS8JKn\":[\"Failed to delete session\"],\"cSCbv3\":[\"BETA\"],\"cSadgt\":[\"Delete Source\"],\"cSev-j\":[\"Filters\"],\"cVEhWm\":[\"Parse Mode\"],\"cX2i4T\":[\"First input file path\"],\"cXFXEn\":[\"Audio only\"],\"cXKoLz\":[\"Save Workflow\"],\"cd0jIM\":[\"Job Details\"],\"ce5XJx\":[\"No active recordings\"],\"cgDZLv\":[\"Triggered when video and danmu are available\"],\"ci-Ad9\":[\"Quick Share\"],\"ckH3fT\":[\"Ready\"],\"cklVjM\":[\"Timeline\"],\"ckoeXQ\":[\"Filename pattern (e.g., thumb.jpg)\"],\"cl-3tt\":[\"Stream Details\"],\"cnGeoo\":[\"Delete\"],\"cncExx\":[\"The configuration has been successfully processed. Here is a summary of the changes:\"],\"cp8Gv3\":[\"Regex patterns to exclude files. Matched against full path and filename.\"],\"cpbupq\":[\"High Quality Archive\"],\"cqFv88\":[\"Exclude\"],\"cs0tlO\":[\"Import Strategy\"],\"cupeHb\":[\"e.g., process-video\"],\"cxkEMX\":[\"Processed \",[\"0\"],\" streamers; \",[\"1\"],\" failed\"],\"cyBb5d\":[\"Decryption\"],\"d-F6q9\":[\"Created\"],\"d-eBFj\":[\"Configure granular options for the selected engine.\"],\"d1yanO\":[\"No logs to display\"],\"d2gWS6\":[\"The direct link to the channel or stream.\"],\"d3IqDh\":[\"Quality\"],\"d4uXwk\":[\"Next\"],\"d6CET4\":[\"Adaptive Refresh (Default: On)\"],\"d6ituE\":[\"Auto-scroll disabled\"],\"d7Kp2g\":[\"Break\"],\"dEXRlZ\":[\"Step Library\"],\"dEgA5A\":[\"Cancel\"],\"dF6vP6\":[\"Live\"],\"dISV1x\":[\"Bandwidth Limit\"],\"dK3Z9j\":[\"Component\"],\"dMtwVQ\":[\"NVENC H.264 Fast\"],\"dN7wel\":[\"Triggered when a download fails with an error.\"],\"dNmZ33\":[\"Workflow not found\"],\"dONv4O\":[\"Channel buffer size multiplier for processed segments.\"],\"dP2MYa\":[\"Preset deleted successfully\"],\"dPyJ15\":[\"Delete Template\"],\"dQfGrq\":[\"Fatal error\"],\"dTuElw\":[\"Add Custom Tag\"],\"dW40Uz\":[\"No events found\"],\"dXcu-7\":[\"Remux\"],\"da5jry\":[\"Stream monitoring encountered issues\"],\"de7Gux\":[\"Download Retry Policy\"],\"dg3VQG\":[\"Proxy Strategy\"],\"dgYq4k\":[\"available\"],\"dhvw0h\":[[\"0\",\"plural\",{\"one\":[\"#\",\" Line\"],\"other\":[\"#\",\" Lines\"]}]],\"djLR5U\":[\"Manifest (Pair by Order)\"],\"dkjxU6\":[\"Detailed health checks for internal services and resources.\"],\"dmsn13\":[\"Header received\"],\"dnUaj8\":[\"Failed to clone template: \",[\"0\"]],\"dsn408\":[\"Force overwrite if output exists\"],\"dsphkM\":[\"Full multimedia processing: Remux video, extract audio and thumbnail in parallel, then upload all.\"],\"dx8VNK\":[\"Engine updated successfully\"],\"dxj6g1\":[\"Interval between checks.\"],\"dxwgX3\":[\"Pipeline Name\"],\"e-gPhp\":[\"Follow Redirects (Default: On)\"],\"e20CFN\":[\"This filter activates only during the window highlighted on the timeline. If the start time is after the end time, the window will wrap around midnight automatically.\"],\"e2BurN\":[\"Extract audio to high-quality MP3 (320kbps) for podcast distribution.\"],\"e3P3Sz\":[\"Max Bytes\"],\"e3_ja4\":[\"Name A-Z\"],\"e4_PV8\":[\"e.g. gdrive:/videos\"],\"e65FAh\":[\"This Delete step depends on <0>\",[\"transformDepLabel\"],\"</0> and will delete the converted result, not your original recording. To delete the original after converting, enable \\\"Remove Input on Success\\\" on the transcode step. Add this Delete step anyway?\"],\"e6MUfG\":[\"Max Part Size\"],\"e9-VJo\":[\"Output Pattern (Optional)\"],\"eCiRz_\":[\"both directions at 10 MiB/s\"],\"eE0JZ4\":[\"Version\"],\"eEMRgC\":[\"File Extension Filter\"],\"eG3YoN\":[\"ETA\"],\"eNncgX\":[\"Copy/Move\"],\"eOpIyK\":[\"Video only\"],\"ePK91l\":[\"Edit\"],\"eUQA5e\":[\"No issues detected\"],\"eYYXD2\":[\"Concurrent checkers (--checkers).\"],\"eYe1Rb\":[\"Failed to export configuration\"],\"eZO3cK\":[\"Buffer Limits\"],\"eZgbDb\":[\"High-priority slot waiting (concurrency limit reached)\"],\"ebJPUJ\":[\"Complete workflow: Remux, generate thumbnail, add metadata, and upload.\"],\"ebiBIb\":[\"Notification Retention\"],\"ecUA8p\":[\"Today\"],\"edQdmU\":[\"FFmpeg Audio Filter (-af)\"],\"ehe4bj\":[\"Force Mobile API\"],\"ejQ0z7\":[\"Overwrite Existing\"],\"ejnHB4\":[\"Minimum time a live streamer must stay offline before the recording session ends, on top of the detection count. Short API or CDN hiccups within this time continue the same session. 0 disables it; at most 10 minutes.\"],\"emYiLJ\":[\"Platform Override Settings\"],\"enj3eJ\":[\"Theme Mode\"],\"es0mu4\":[\"Critical Only\"],\"evbMWR\":[\"Output Arguments\"],\"ewB4dk\":[\"e.g., Standard Processing\"],\"ex8PR1\":[\"Retry All Failed\"],\"exyoIu\":[\"Search sessions...\"],\"f2d4Ps\":[\"Session Menu\"],\"f4u7wL\":[\"Timing & Delays\"],\"f4yUH6\":[\"Multi-Thread Cutoff help\"],\"f76EEx\":[\"Filter by title keywords\"],\"f77MVE\":[\"Encoding Preset\"],\"f8A73b\":[\"44100\"],\"f8hHmU\":[\"Failed to create pipeline job\"],\"f8zoXj\":[\"Error Loading Pipeline\"],\"fAGXwp\":[\"Triggered when streamer configuration is dynamically updated.\"],\"fB-Bdg\":[\"Last check \",[\"0\"],\" ago\"],\"fF22or\":[\"Danmu Stream Closed\"],\"fHh0Nn\":[\"e.g. up -c @channel -p \",[\"input\"]],\"fLcXDs\":[\"Recording Filters\"],\"fNsYxz\":[\"Add a module above to configure its log level\"],\"faK1M-\":[\"Allowed Extensions\"],\"fcWrnU\":[\"Sign out\"],\"fcirqq\":[\"This will permanently delete the workflow \\\"\",[\"0\"],\"\\\". Streamers using this workflow will need to be updated.\"],\"fiPesM\":[\"Method\"],\"fif2cG\":[\"Search workflows...\"],\"fknQhj\":[\"Destination Directory\"],\"fkpv9h\":[\"Search streamers...\"],\"fnASK5\":[\"Normal+\"],\"fpMs2Z\":[\"LIVE\"],\"fqJjiU\":[\"Runs for each recorded segment immediately after it's finished.\"],\"frUGOs\":[\"Automatically generate a thumbnail for the first segment of each session.\"],\"fss9hD\":[\"Open quality options\"],\"fvL_Ho\":[\"Units are bytes (default base KiB/s). Use suffixes B, K, M, G, T, P for larger sizes.\"],\"g-gBfk\":[\"Parsing...\"],\"g6BpXZ\":[\"Cancelled\"],\"g7_-Qd\":[\"Comma-separated list of channels to exclude from proxy\"],\"g90PVF\":[\"Step Configuration\"],\"gCv-nS\":[\"Max Idle per Host\"],\"gEQH50\":[\"Caps overall transfer bandwidth (rclone --bwlimit).\"],\"gM5LKb\":[\"This stream is already active\"],\"gORFgZ\":[\"Cancel Execution\"],\"gQG_W7\":[\"Powered by <0>Rust-srec</0>.\"],\"gUjkl_\":[\"Down\"],\"gVDufW\":[\"Define sequences of processing steps for your recordings\"],\"gVxTf6\":[\"Exports include all global settings, platforms, templates, streamers, engines, and notification channels. Sensitive data like passwords might be redacted or encrypted depending on platform settings.\"],\"gWdWG8\":[\"Collapsible\"],\"gWgRVf\":[\"Free space on the output volume below which the low disk space action applies. 0 = Disabled\"],\"gb0sx5\":[\"Key Retry Delay (ms)\"],\"gc3su8\":[\"Subtitle Matching\"],\"gdA_lQ\":[\"Playlist Gone\"],\"gh06VD\":[\"Output\"],\"ghnHjn\":[\"Number of consecutive failed checks required to definitively confirm a streamer has gone offline. Higher values prevent \\\"fake\\\" offline detection.\"],\"glK8MG\":[\"Pipeline Jobs\"],\"gliRBz\":[\"Processing nodes\"],\"gnNUHR\":[\"This step is a sub-workflow: <0>\",[\"workflowName\"],\"</0>.\"],\"gpUgZ_\":[\"0 (None) - 9 (Best)\"],\"gptVEm\":[\"Create a filter to control when this streamer is recorded. You can filter by time, keywords, categories, and more.\"],\"gq-Q4J\":[\"Loading preset details...\"],\"gq6RwY\":[\"Failed to load danmu statistics.\"],\"gqZ7EH\":[\"Capture real-time comments and chat messages if available.\"],\"guy7NI\":[\"Modify the parameters for this processing step.\"],\"h1-4V4\":[\"Refresh failed: Manual login required\"],\"h5b8Gg\":[\"Failed to load outputs: \",[\"0\"]],\"hFcCJR\":[\"Limits each individual file's transfer rate rather than the overall total.\"],\"hKqbBO\":[\"Successfully processed \",[\"0\"],\" streamers\"],\"hM6-6Z\":[\"Session ID is required\"],\"hNmOZ7\":[\"Set priority\"],\"hO9jJx\":[\"Logging configuration updated\"],\"hQOBwU\":[\"Wait\"],\"hSHVF0\":[\"Check size after copy\"],\"hSj3SO\":[\"Custom Configuration\"],\"hV6FeJ\":[\"Throughput\"],\"hVDqsJ\":[\"HD\"],\"hVuIVS\":[\"Advanced scheduling\"],\"hXA9xB\":[\"Web Optimize\"],\"hXzOVo\":[\"Next\"],\"hY0vvK\":[\"A unique ID used by other steps to reference this one.\"],\"hYgDIe\":[\"Create\"],\"hZ6znB\":[\"Port\"],\"hdsaJo\":[\"Importing...\"],\"hdyeZ0\":[\"Delete Job\"],\"he3ygx\":[\"Copy\"],\"hgpMHD\":[\"Total Size\"],\"hmqzwF\":[\"Peak / min\"],\"hrDkPB\":[\"Basic settings for the streamer.\"],\"ht1ran\":[\"Try adjusting your search.\"],\"hul8PJ\":[\"Failed to autofill name\"],\"huzp0F\":[\"Telegram Desktop data required\"],\"hx-EJH\":[\"Max Segments\"],\"hyCRuI\":[\"Size limit\"],\"i-GrRR\":[\"Engine used for downloads when not specified by platform/streamer.\"],\"i09z4S\":[\"Month (01-12)\"],\"i0qMbr\":[\"Home\"],\"i2yKvD\":[\"STREAMER\"],\"i4y2wE\":[\"Description is managed by the system\"],\"iAJgTr\":[\"404\"],\"iAL9tI\":[\"Configure\"],\"iB8z_j\":[\"Stream Options\"],\"iBQU3w\":[\"Password change required\"],\"iDNBZe\":[\"Notifications\"],\"iDpc_N\":[\"Loading editor components...\"],\"iE4x2o\":[\"Select codec\"],\"iEfPm1\":[\"Twitch (ttv-lol)\"],\"iFIj9t\":[\"Save settings to a JSON file\"],\"iH8pgl\":[\"Back\"],\"iI8ncW\":[\"Enter categories to match against (e.g. Just Chatting, Gaming).\"],\"iKvAeG\":[\"Checking\"],\"iLYPXc\":[\"DAG Graph\"],\"iMAQTQ\":[\"Year (4 digits)\"],\"iToNdZ\":[\"SOOP account used to watch login-required (e.g. 19+) broadcasts. Prefer cookies for permanently restricted channels.\"],\"iTylMl\":[\"Templates\"],\"iWGuCP\":[\"No configuration form available for this processor.\"],\"iaPtht\":[\"copy\"],\"iaocTt\":[\"Ratio\"],\"iftgVc\":[\"No custom headers configured\"],\"igC7p-\":[\"Switch to secondary folder\"],\"ij74Jr\":[\"Schedule recording times\"],\"ilOZeV\":[\"Initial Delay (ms)\"],\"imXBeM\":[\"Consecutive failed checks needed to confirm offline.\"],\"ipzg8L\":[\"No modules configured\"],\"isRobC\":[\"New\"],\"iv8DdK\":[\"UPDATE\"],\"ivJkrj\":[\"Create New Workflow\"],\"iwyTQi\":[\"Are you sure you want to delete \",[\"0\"],\" sessions? This action cannot be undone.\"],\"iyCIi4\":[\"Parse Batch\"],\"j-MPXl\":[\"Backup & Restore\"],\"j03sWF\":[\"Triggered after the entire session ends\"],\"j0rT_n\":[\"Select or type a rate\"],\"j6ZZIX\":[\"CRF (0-51)\"],\"j7AbzC\":[\"Drag from edge to link steps\"],\"jAwQYJ\":[\"Prefer QR login first; Phone & Code (2FA optional) second; Desktop last.\"],\"jC5etE\":[[\"0\",\"plural\",{\"one\":[\"file\"],\"other\":[\"files\"]}]],\"jEw0Mr\":[\"Please enter a valid URL\"],\"jGmy11\":[\"Active Recordings\"],\"jIFKq_\":[\"Variant Selection Policy\"],\"jIPNJG\":[\"Basic Information\"],\"jKHfGg\":[\"Danmaku to ASS Subtitles\"],\"jKPJlR\":[\"Create missing folders\"],\"jKttAE\":[\"Hooks\"],\"jNqP-h\":[\"Failed to extract name from URL\"],\"jQh979\":[\"Sample danmu at a constant time interval.\"],\"jR5LZx\":[\"On Download Error\"],\"jRPz-T\":[\"Clone Template\"],\"jWXukA\":[\"This login requires a Telegram 2FA password. Enable \\\"Allow 2FA\\\" and restart.\"],\"jXwBNq\":[\"CRF (Constant Rate Factor)\"],\"jems_i\":[\"Per-File Bandwidth Limit help\"],\"jfsP4q\":[\"Upload All Files\"],\"jmbIS6\":[\"Desktop Notifications\"],\"joJBB2\":[\"Create your first workflow to define a sequence of processing steps for your recordings.\"],\"jpctdh\":[\"View\"],\"juTcaJ\":[\"NVENC H.264 High Quality\"],\"jvivc3\":[\"Session ID\"],\"k9Trbl\":[\"Create Preset\"],\"kEgzfB\":[\"How often rust-srec runs nvidia-smi to detect when the container loses GPU access (a known NVIDIA Container Toolkit issue on cgroup v2 hosts). Only active when nvidia-smi is available; otherwise the GPU row is not registered. Default 30 seconds. Changes apply on the next probe — no restart required. Values below 30 seconds are discouraged.\"],\"kF2Y0z\":[\"Offset Consistency Check\"],\"kG0i0h\":[\"Add anyway\"],\"kI1qVD\":[\"Format\"],\"kN8Y5L\":[\"CPU Job Timeout\"],\"kRJm8z\":[\"Time between sampling attempts.\"],\"kX97I8\":[\"e.g. 0:v:0, 0:a:0\"],\"kZV79T\":[\"Split Reason\"],\"kcR85X\":[\"Enter keywords to include...\"],\"kcoHto\":[\"Validation service unavailable\"],\"ke0Ls7\":[\"Min height\"],\"kfNspv\":[\"Engine Type\"],\"kjMqSj\":[\"Copy JSON\"],\"klOeIX\":[\"Failed to change password\"],\"kn1oU0\":[\"Logging paused\"],\"kne4l9\":[\"Compression\"],\"koRU21\":[\"Add extra argument\"],\"kqQaK3\":[\"Cookies Only\"],\"krzGzQ\":[\"Concurrent file transfers (--transfers).\"],\"kseNmN\":[\"This will permanently delete the notification channel \\\"\",[\"0\"],\"\\\".\"],\"kt6N2a\":[\"This Delete step will delete the files produced by <0>\",[\"transformDepLabel\"],\"</0> (the converted result), not your original recording. To delete the original source after converting, enable \\\"Remove Input on Success\\\" on the transcode step instead.\"],\"kwRUsJ\":[\"Job limits and engine settings.\"],\"l-RCZs\":[\"Create Workflow\"],\"l2Op2p\":[\"Query Parameters\"],\"l3s5ri\":[\"Import\"],\"l6DdQS\":[\"No detailed error message available\"],\"l6zyQF\":[\"Upload file to cloud storage using rclone. Configure remote in rclone config.\"],\"l7oQUQ\":[\"Wait time before starting.\"],\"l97zAi\":[\"Transcode H.264\"],\"l9wI00\":[\"Speed\"],\"lA6fmP\":[\"Scan Directory\"],\"lA8IlD\":[\"No platforms found\"],\"lBQSXl\":[\"e.g. Remux to H.264\"],\"lCF0wC\":[\"Refresh\"],\"lDvrUG\":[\"Failed to create streamer\"],\"lECX4V\":[\"Max IO Jobs\"],\"lJoArf\":[\"Cron Expression\"],\"lOyQ-B\":[\"Encoder Preset\"],\"lYLHvp\":[\"Concurrency limit reached\"],\"lcb8Hd\":[\"Compression Level\"],\"lfFsZ4\":[\"Channels\"],\"lj3GoP\":[\"No jobs found\"],\"lkz6PL\":[\"Duration\"],\"lqXgLk\":[\"Add an override to customize engine behavior for this template.\"],\"lqf04S\":[\"Secondary\"],\"ltYQCa\":[\"Since \",[\"0\"]],\"luB4tN\":[\"Choose how this streamer handles proxy connections.\"],\"m16xKo\":[\"Add\"],\"m2DSGV\":[\"Endpoint Configuration\"],\"m42dNa\":[\"Create a template to define reusable download configurations.\"],\"m48LOH\":[\"Hardware Acceleration\"],\"m50MxJ\":[\"Fetch Delay\"],\"m7AE5i\":[\"Failed to download file\"],\"m8LlVt\":[\"Splits a single large file into N parallel chunks for faster upload.\"],\"mCB6Je\":[\"Select All\"],\"mCc1g0\":[\"Failed to refresh credentials\"],\"mIbF__\":[\"Autofill name from URL\"],\"mKKr99\":[\"Restart with -d\"],\"mLVDOt\":[\"Command template args. Use \",[\"INPUT_TOKEN\"],\" and \",[\"OUTPUT_TOKEN\"],\" placeholders.\"],\"mO7heL\":[\"Media Outputs\"],\"mOXqr6\":[\"Password changed successfully. Please login with your new password.\"],\"mQe6uy\":[\"Streamer updated\"],\"mTw0S2\":[\"No media outputs generated yet.\"],\"mYGY3B\":[\"Date\"],\"meSGba\":[\"Progress:\"],\"mgOBre\":[\"Highest bitrate\"],\"mgtXne\":[\"Filter events below this priority\"],\"mgxDh9\":[\"Delete the converted result?\"],\"mkaDKN\":[\"Keep uploading other files if one fails\"],\"mkvLrG\":[\"Select category\"],\"mrmkFJ\":[\"Generate a thumbnail at native stream resolution (no scaling). Best quality, largest file size.\"],\"mrzV5V\":[\"Overwrite existing .ass files\"],\"mxhrdq\":[\"Danmaku\"],\"mxun7B\":[\"Runs when the streamer goes offline.\"],\"my6sDb\":[\"Search streamer...\"],\"mzDMo2\":[\"Time Based\"],\"mzI_c-\":[\"Download\"],\"mzffFb\":[\"Preset updated successfully\"],\"n-v6gq\":[\"Total Danmu\"],\"n1MDK4\":[\"Max Key Retries\"],\"n1ekoW\":[\"Sign In\"],\"n4AWhD\":[\"Explore matching automation steps to enhance your pipeline workflow.\"],\"n9yU9X\":[\"Live Preview\"],\"nBcxYG\":[\"Timeout before cancelling CPU-bound processors.\"],\"nC6Rpq\":[\"Add Stream\"],\"nDG2K-\":[\"Enable advanced FLV timestamp repairing\"],\"nEAJMt\":[\"Offline Check\"],\"nEJA8Y\":[\"Try adjusting the date range\"],\"nGC_6h\":[\"Add rclone argument\"],\"nGNP8v\":[\"Compression (Zip)\"],\"nGrU2q\":[\"Generate a high-resolution thumbnail (640px width) at 10 seconds.\"],\"nN1XNy\":[\"Overwrite Files\"],\"nOPbWJ\":[\"Right\"],\"nQM_01\":[\"Failed to delete preset: \",[\"0\"]],\"nQZ3Py\":[\"Failed to update workflow: \",[\"0\"]],\"nQibzB\":[\"Exclude streams from these CDN providers entirely. Press Enter to add.\"],\"nSHdq2\":[\"Triggered when a new download recording begins.\"],\"nSQv8Y\":[\"Auto-scroll enabled\"],\"nTN3Hr\":[\"Probe CDNs\"],\"nTWWCZ\":[\"Low\"],\"nUjQm2\":[\"Execution Settings\"],\"nVkOeu\":[\"TDL login session started\"],\"nWnBRV\":[\"Delivery Policy\"],\"n_s1Si\":[\"Replace destination files if they exist\"],\"ncwQad\":[\"(empty)\"],\"neL6ek\":[\"New Preset Name\"],\"nfwriv\":[\"Keep date and time tokens in your filename template when multiple sessions share one destination folder.\"],\"nhxAdQ\":[\"Keyword\"],\"nmD1Iv\":[\"Web Push allows receiving notifications even when you close the browser. It requires browser permission and valid server keys.\"],\"nnPJVX\":[\"Where TDL stores session data (`tdl --storage ...`). Example: `type=bolt,path=/data/tdl`.\"],\"nrLIEd\":[\"Split after duration.\"],\"nu0Tii\":[\"Attempts to get origin quality by replacing the audio stream. May result in audio-only streams if it fails.\"],\"nuh_Wq\":[\"Webhook URL\"],\"num3TS\":[\"Failed to load presets: \",[\"0\"]],\"nwIyEX\":[\"Fix HLS Discontinuities\"],\"nwObng\":[\"No media outputs yet\"],\"nxIXdN\":[\"Please fix validation errors\"],\"o21Y-P\":[\"entries\"],\"o5r6b2\":[\"Job deleted\"],\"o69yIu\":[\"Press Enter to add recipient\"],\"o6OQlp\":[\"Edit Channel\"],\"oBn_nO\":[\"Pipeline History Retention\"],\"oIg22A\":[\"Stream Password\"],\"oJJdGr\":[\"Destination Root\"],\"oKjqX-\":[\"Pipeline Execution\"],\"oLsREl\":[\"No word data\"],\"oMVAXw\":[\"Runs once after the entire session ends and all segment pipelines have completed.\"],\"oQw4Jd\":[\"SMTP Host\"],\"oV0Bem\":[\"Create Streamer\"],\"oaiKxc\":[\"Type your answer here...\"],\"oatACc\":[\"inline\"],\"ofUcbc\":[\"Random\"],\"ogaYcn\":[\"Shadcn UI Theme Presets\"],\"ojQSTc\":[\"Previous Title\"],\"okXKua\":[\"Mint Integrity Token\"],\"oke9ig\":[\"Streamer updated successfully\"],\"olkLN2\":[\"Single URL\"],\"oo16kX\":[\"No preview generated\"],\"opgoOE\":[\"Reorder Duration (ms)\"],\"orDnO-\":[\"Tracked Words\"],\"orI3hB\":[\"pagination\"],\"ovBPCi\":[\"Default\"],\"oyj1TW\":[\"No steps defined\"],\"p0dP0q\":[\"Generate multiple preview images at different timestamps for a gallery view.\"],\"p12eT9\":[\"Please select a JSON file\"],\"p1zJ0-\":[\"CDN Probing\"],\"p27AhB\":[\"Phone & Code (2FA optional)\"],\"p2_GCq\":[\"Confirm Password\"],\"p2mB8S\":[\"Per-File Bandwidth Limit\"],\"p2sbyZ\":[\"Template not found\"],\"p5nYkr\":[\"View All\"],\"p6NueD\":[\"NEW\"],\"p8QOjt\":[\"Remux to MP4 with faststart flag for web streaming optimization.\"],\"pAtylB\":[\"Not Found\"],\"pD5RQD\":[\"Delete XML files after successful conversion\"],\"pECIKL\":[\"Search templates...\"],\"pFIl0a\":[\"Wall-clock boundary\"],\"pHt_zF\":[\"Outside Schedule\"],\"pIKNYw\":[\"Ignore streams below this bitrate.\"],\"pKmdYH\":[\"Only include files with this extension (without dot)\"],\"pSNvte\":[\"Podcast Extraction\"],\"pWT04I\":[\"Checking...\"],\"pYUyoK\":[\"Default workflow: Remux to MP4 (deletes original), generate native-resolution thumbnail, upload both to cloud and delete local files.\"],\"pZBeKb\":[\"Pending Jobs\"],\"pZoiPG\":[\"Isolation level for tracking identifiers. Global is usually best.\"],\"p_78dY\":[\"Position\"],\"pb5U2q\":[\"Move the file to another location. Removes the original file.\"],\"peqX67\":[\"Metadata Tags\"],\"pj3WXl\":[\"--flag value\"],\"pjN1vk\":[\"e.g. C:\\\\Users\\\\...\\\\AppData\\\\Roaming\\\\Telegram Desktop\"],\"plUzgk\":[\"High quality H.265/HEVC compression (CRF 22). Smaller files but slower encoding.\"],\"pmXQXV\":[\"Streaming services\"],\"pqdYPk\":[[\"0\",\"plural\",{\"one\":[\"#\",\" Warning\"],\"other\":[\"#\",\" Warnings\"]}]],\"psqOpk\":[\"Add email and press Enter\"],\"ptAX-h\":[\"CPU Usage\"],\"puukLG\":[\"Marked all as read\"],\"pv2R4B\":[\"Adaptive polling\"],\"pvnfJD\":[\"Dark\"],\"pwlRnQ\":[\"Metadata Editor\"],\"q-N7o6\":[\"Custom Flags\"],\"q3Dp_X\":[\"Include original files in outputs\"],\"q4bpUC\":[\"Output Configuration\"],\"q5nfv1\":[\"On Download Start\"],\"qC4H_F\":[\"Inspecting\"],\"qEH4Cy\":[\"Run a custom FFmpeg command. Edit the args to customize.\"],\"qEVp1d\":[\"Danmu Control\"],\"qGO8x4\":[\"Track Information\"],\"qH2T4F\":[\"No environment variables defined\"],\"qJb6G2\":[\"Try Again\"],\"qM884L\":[\"User Agent\"],\"qSJNp0\":[\"RAM utilization\"],\"qTqIG0\":[\"Record Danmu\"],\"qUvWln\":[\"Try adjusting your search or filters.\"],\"qWRWG1\":[\"Cause:\"],\"qZq0tI\":[\"e.g. aliyun, akamaized\"],\"q_yLs_\":[\"Preferred Quality (QN)\"],\"qgMxnz\":[\"Job creation time\"],\"qoGRes\":[\"Destination Root help\"],\"qqWcBV\":[\"Completed\"],\"qtoOYG\":[\"No limit\"],\"quwsBF\":[\"Force specific output format.\"],\"qxDAhZ\":[\"Edit Filter\"],\"r0jBJE\":[\"secret\"],\"r2yoxL\":[\"Input Options (-i flags)\"],\"r5_ZH9\":[\"Burn .ass subtitles into videos (produces *_burnin.mp4 by default). Manifest-aware and batch-safe.\"],\"r8keQd\":[\"History (\",[\"slots\"],\" pts)\"],\"r8mo4d\":[\"How long a CDN gets to send its first byte before it counts as unreachable.\"],\"rAx5u1\":[\"End Time\"],\"rCZeYu\":[\"Triggered when a file segment is finished.\"],\"rDC-T6\":[\"Retry Job\"],\"rG3WVm\":[\"Select\"],\"rGNFqY\":[\"Full Processing\"],\"rLgPvm\":[\"Backup\"],\"rOsxLC\":[\"Create Channel\"],\"rPLMLx\":[\"Scan the QR code with Bilibili app to login and save credentials\"],\"rPTjS_\":[\"Mark Read\"],\"rYpSzJ\":[\"Sanitized session title\"],\"r_-HvB\":[[\"0\",\"plural\",{\"one\":[\"#\",\" done\"],\"other\":[\"#\",\" done\"]}]],\"rc8zxf\":[\"Control verbosity for each module. Lower levels include higher ones.\"],\"rdAnZQ\":[\"Real-Time Logs\"],\"rj3LPO\":[\"Login successful!\"],\"rkmaZ8\":[\"Any additional command line arguments to pass to Streamlink\"],\"rmol8y\":[\"Discontinuity\"],\"rsjeU7\":[\"Streamer deleted\"],\"rv6zQQ\":[\"Retry Delay Base (ms)\"],\"rwpu5q\":[\"Choose your display language\"],\"ry0RbG\":[\"No description.\"],\"ryXr-I\":[\"Command Arguments\"],\"s-MGs7\":[\"Resources\"],\"s-yK3S\":[\"If enabled, streams in these categories will be ignored.\"],\"s0A5F0\":[\"Require Subtitles\"],\"s2Ok6S\":[\"Edit Workflow: \",[\"0\"]],\"s5FJH3\":[\"Total Steps\"],\"s7XkSj\":[\"Failed to load templates: \",[\"0\"]],\"s7XwOU\":[\"Create Template\"],\"s867uU\":[\"Override filename template.\"],\"sAEhzj\":[\"Stream structure change\"],\"sDMc1l\":[\"Time Placeholders (Local Time)\"],\"sER-bs\":[\"Files\"],\"sG9Y50\":[\"NVENC AV1 High Quality\"],\"sGeXL3\":[\"Thumbnail\"],\"sMakGt\":[\"ey...\"],\"sPFHpI\":[\"Disk\"],\"sPfqA-\":[\"Are you sure you want to delete the template \\\"\",[\"0\"],\"\\\"? This action cannot be undone.\"],\"sSmPh2\":[\"Immersive Mode\"],\"sThCBt\":[\"Quality Control\"],\"sWuf_r\":[\"Preferred Qualities\"],\"sXmNBK\":[\"Route traffic through an intermediate server\"],\"sYAqs-\":[\"Max Delay (ms)\"],\"saFjCh\":[\"Delete original file after successful remux\"],\"sbQMAc\":[\"Please enter at least one URL\"],\"sbanhc\":[\"No refresh needed or not supported\"],\"sfCeO6\":[\"Priority high-low\"],\"si7i6t\":[\"Create a ZIP archive of the file. Good for bundling with metadata.\"],\"sjJ6qs\":[\"RESUMED START\"],\"sjVfrA\":[\"Command\"],\"spa6AF\":[\"No input paths\"],\"sq8P7b\":[\"Off Canvas\"],\"sr0UJD\":[\"Go Back\"],\"svV_tK\":[\"Template updated successfully\"],\"sxidET\":[\"GPU-accelerated high quality archive: NVENC HEVC encoding, thumbnail, and cloud upload.\"],\"sxkWRg\":[\"Advanced\"],\"t-R8-P\":[\"Execution\"],\"t3NSIX\":[\"Replace Pipeline Step\"],\"t6QNu0\":[\"Audio Settings\"],\"t7TeQU\":[\"Messages\"],\"t7p091\":[\"Start QR Login\"],\"tBFXor\":[\"User Disabled\"],\"tBkWoY\":[\"Douyu SD or low rate\"],\"tCNR7u\":[\"Download Concurrency\"],\"tFII0M\":[\"Recordings moved here keep their layout below the output folder.\"],\"tG0T2X\":[\"Failed to parse URL\"],\"tH1aCG\":[\"Video Bitrate\"],\"tLnoCj\":[\"Job Presets\"],\"tOcErW\":[\"Failed to update settings\"],\"tPg-n8\":[\"Rclone Executable\"],\"tVSmFT\":[\"Loading more...\"],\"tVxHoG\":[\"No workflows found\"],\"tWWCyb\":[\"Creation Mode\"],\"tXDHM9\":[\"Transfers help\"],\"tXQ9KI\":[\"Select type\"],\"tX_2YD\":[\"Failed to delete streamer\"],\"tXkhj_\":[\"Start\"],\"tYt23W\":[\"ASS Burn-in\"],\"tZASER\":[\"Are you sure you want to delete this job? This will permanently remove it from your list.\"],\"t_YqKh\":[\"Remove\"],\"t_a0R7\":[\"https://gotify.example.com\"],\"taWpib\":[\"Burst capacity for TPS Limit (--tpslimit-burst).\"],\"ti_7Zv\":[\"The configuration matches existing data\"],\"tkrJYR\":[\"Are you sure you want to delete this session? This action cannot be undone.\"],\"toutGW\":[\"QR Code\"],\"tqsVap\":[\"Min Priority\"],\"trzwMt\":[\"HLS parser\"],\"tst44n\":[\"Events\"],\"tuczwE\":[\"Primary Action\"],\"twkfAf\":[\"Failed steps retry initiated\"],\"tyyY8g\":[\"Session Details\"],\"u-R-Uj\":[\"No templates found\"],\"u4x6Jy\":[\"Back to Jobs\"],\"u5pdtQ\":[\"Failed to cancel pipeline\"],\"u7En0V\":[\"Add Metadata\"],\"u7KTQf\":[\"Separate multiple paths with commas.\"],\"u8XaRg\":[\"Streamer is on cooldown\"],\"u8rLL5\":[\"Connection Instability\"],\"uAQUqI\":[\"Status\"],\"uBxq0B\":[\"Add item...\"],\"uDMKZ-\":[\"Interval (seconds)\"],\"uFgIWz\":[\"Triggered after each segment recording\"],\"uFrzr2\":[\"This pipeline runs immediately after a recording segment is finished. Use it for tasks that only require the individual video segment, such as remuxing, thumbnail generation, or per-segment uploads.\"],\"uHaWdO\":[\"Extract high-quality audio for podcast distribution and upload.\"],\"uI3SjW\":[\"Connection Error\"],\"uKMMk4\":[\"Logged in successfully\"],\"uKqo4y\":[\"Event Hooks\"],\"uLlPmJ\":[\"Adjust rate based on target duration\"],\"uQUJrF\":[\"All Platforms\"],\"uRoULu\":[\"Global Configuration\"],\"uS4jm7\":[\"Inherited / Default\"],\"uTyAvE\":[\"Twitch Proxy Playlist Exclude\"],\"u_4llg\":[\"Switch Theme\"],\"uabF28\":[\"Sensitive\"],\"ueNCFX\":[\"Preset Details\"],\"uhURfE\":[\"Remove source on success\"],\"uhZE--\":[\"Failed to disable Web Push\"],\"uiiiQ-\":[\"Max height\"],\"um816y\":[\"Executions\"],\"up_E6Q\":[\"Customize the application theme and sidebar layout.\"],\"utnMp8\":[\"Account Not Found\"],\"uxjnhI\":[\"WEB\"],\"v-cKc6\":[\"Copy / Move\"],\"v0ItTA\":[\"Loading logs...\"],\"v10I4K\":[\"Skip Interactive Games\"],\"v39wLo\":[\"Resume\"],\"vBNtP8\":[\"Preferred Formats\"],\"vEkjkD\":[\"Proxy URL\"],\"vFBdNT\":[\"Resolution (Scale)\"],\"vGgj7q\":[\"Key Cache TTL (ms)\"],\"vGjmyl\":[\"Deleted\"],\"vHFsyI\":[\"Protocol Settings\"],\"vHbMb_\":[\"Pipeline name is required\"],\"vHgqv3\":[\"Custom proxy URL for Twitch playlists\"],\"vPfdKD\":[\"Adjust sampling rate based on danmu traffic volume.\"],\"vQUnlF\":[\"Specific settings for the platform.\"],\"vRLm73\":[\"Show download details\"],\"vRayGs\":[\"Player\"],\"vS1EOG\":[\"Flow & Dependencies\"],\"vSJd18\":[\"Video\"],\"vXIe7J\":[\"Language\"],\"vYVhmv\":[\"Generating QR code...\"],\"vYgeDk\":[\"Deselect All\"],\"vaHbOZ\":[\"Shared pipeline utilities\"],\"vejAXH\":[\"Stream URL\"],\"vf5Y2I\":[\"Copy (Passthrough)\"],\"vfZWyn\":[\"Try adjusting your filters or limit\"],\"vfeDAy\":[\"Generate a thumbnail at 30 seconds with 480px width for previews.\"],\"vgjQEq\":[\"Remux and Clean\"],\"vgwVkd\":[\"UTC\"],\"vjD16L\":[\"Width in pixels (keeps aspect ratio)\"],\"vlDq31\":[\"Select the highest quality level you want to attempt capturing.\"],\"voD8oZ\":[\"Configure Step\"],\"voRH7M\":[\"Examples:\"],\"vrNaKg\":[\"Tip: 1 Mbit/s ≈ 0.125M (divide bits by 8).\"],\"vrnnn9\":[\"Processing\"],\"vtDkV7\":[\"Pick a preset theme or import a custom one.\"],\"vv7kpg\":[\"Cancelled\"],\"vwRuK_\":[\"Error loading preset\"],\"vxaZfq\":[\"Duration limit\"],\"vzGE_4\":[\"Proxy Configuration\"],\"vzxlNg\":[\"Define rules to automatically record or skip streams based on title, time, or language.\"],\"w-bWA_\":[\"Numeric Room IDs Only\"],\"w2ybE1\":[[\"0\"],\" Configuration\"],\"w6RBiW\":[\"Review recorded streams and manage archives\"],\"wAFtA2\":[\"Only kicks in for files above the Cutoff size. Default is 4. Set to 0 to disable.\"],\"wAgqfF\":[\"Double Screen Data\"],\"wBMjJ2\":[\"Count\"],\"wCD4xg\":[\"Parse error: \",[\"0\"]],\"wDioLj\":[\"Failed to retry job\"],\"wEp8H9\":[\"Failed to update channel\"],\"wErX0W\":[\"Stem (Filename Match)\"],\"wFb0uO\":[\"Capture Mode\"],\"wMHvYH\":[\"Value\"],\"wMQZYQ\":[\"Live Refresh Interval (ms)\"],\"wMe2Qp\":[\"Namespace\"],\"wOjRUU\":[\"Last Check\"],\"wSOVbP\":[\"Stop new recordings\"],\"wVagb1\":[\"Resumed after \",[\"0\"],\"s in pending state.\"],\"wVk8_8\":[\"Inline: \",[\"stepName\"]],\"w_bY7R\":[\"Logs\"],\"wdxz7K\":[\"Source\"],\"wg5HlH\":[\"How many files rclone uploads in parallel.\"],\"wgeqNr\":[\"Quality Settings\"],\"wiMpRi\":[\"Overwrite Output\"],\"woYYQq\":[\"Workflows\"],\"wqJ4zc\":[\"Environment Variables\"],\"wrl8J3\":[\"To Addresses\"],\"wspIF7\":[\"rclone default: 1\"],\"wv1e0F\":[\"Internal\"],\"wwNqQz\":[\"FLV Tuning\"],\"wwu18a\":[\"Icon\"],\"wzr18h\":[\"Export Configuration\"],\"x-uFU5\":[\"0 = unlimited\"],\"x09b8E\":[\"Request timeout duration\"],\"x0U6Og\":[\"Filter Window Size\"],\"x0aAkx\":[\"Search outputs...\"],\"x1VcXy\":[\"Framerate (FPS)\"],\"x2fr_j\":[\"Graph\"],\"x2igpn\":[\"Path to Telegram Desktop (contains tdata)\"],\"x7PDL5\":[\"Logging\"],\"xA2izn\":[\"No pipeline DAGs found.\"],\"xBO-wH\":[\"channels\"],\"xCJdfg\":[\"Clear\"],\"xCaAmT\":[\"Retry Delay (ms)\"],\"xCtNNg\":[\"Override how aggressively the scheduler confirms a stream has ended.\"],\"xD4Xm2\":[\"Fetch\"],\"xDAtGP\":[\"Message\"],\"xDr_ct\":[\"End\"],\"xGzQCl\":[\"workflow\"],\"xND0SQ\":[\"Placeholders\"],\"xQV856\":[\"Failed to clone preset: \",[\"0\"]],\"xUpogZ\":[\"veryfast\"],\"xY9s5E\":[\"Timeout\"],\"xZGBr0\":[\"Audio Extract\"],\"x_biSg\":[\"Interval between offline checks.\"],\"xdqOXO\":[\"Allow Private Stream Proxy Targets\"],\"xeMG4j\":[\"Regex\"],\"xi8BoZ\":[\"Parallel integrity checks (e.g. comparing source / destination hashes).\"],\"xjwCW_\":[\"Max Key Retry Delay (ms)\"],\"xqKCfi\":[\"Start Phone & Code Login\"],\"xsp8AP\":[\"Override output folder.\"],\"xtWiCP\":[\"Path to DanmakuFactory binary. If empty, uses environment variable or PATH.\"],\"xvp-v2\":[\"Thumbnail Generator\"],\"xxCtZv\":[\"Rows per page\"],\"xzqVyW\":[\"Live Polling\"],\"y2WOeQ\":[\"Loading workflows...\"],\"y7ZemI\":[\"Use stream start time to keep a midnight-crossing stream in one dated folder. Execution time preserves the existing copy/move behavior.\"],\"y7sj-0\":[\"Streamer\"],\"yDlWVJ\":[\"Offline Check Interval\"],\"yGS9cI\":[\"Healthy\"],\"yHXa3T\":[\"-1001234567890\"],\"yIMeW0\":[\"Force requesting the highest origin quality available (Direct stream).\"],\"yL1-1a\":[\"Stream start time\"],\"yMMvQD\":[\"If any of these keywords appear in the title, the stream will be ignored.\"],\"yRAYpv\":[\"Quality Rate\"],\"yRadQU\":[\"Triggered when a post-processing pipeline job starts.\"],\"yRzWhF\":[\"Monitor and manage processing jobs\"],\"ySWmtK\":[\"Keep Running\"],\"yScTZL\":[\"System Status\"],\"ySxXn1\":[\"/usr/bin/ffmpeg or ffmpeg\"],\"yTLtq4\":[\"Complex patterns\"],\"yThUSt\":[\"High quality GPU-accelerated HEVC/H.265 encoding using NVIDIA NVENC (CQ 21). Best compression ratio with GPU.\"],\"yXcp78\":[\"Process locally: Remux to MP4, generate thumbnail, move to archive folder.\"],\"yZgsv0\":[\"Request AAC audio without a video track.\"],\"yZpWgb\":[\"No playable stream found in response\"],\"y_0uwd\":[\"Yesterday\"],\"yfJY2E\":[\"GPU Health Probe Interval\"],\"ygqCBL\":[\"Compress video for storage, upload to cloud, then delete local file to save space.\"],\"yhvq_6\":[\"Blocked by browser\"],\"ylXj1N\":[\"Selected\"],\"yntXb7\":[\"Editing Mode\"],\"ypFsBx\":[\"Ignore streams above this bitrate.\"],\"ysAGq3\":[\"Notification permission not granted\"],\"ywDBfs\":[\"Something went wrong!\"],\"ywFj2D\":[\"Configuration\"],\"yx_fMc\":[\"High\"],\"yz7wBu\":[\"Close\"],\"z-rLjA\":[\"Timeout (seconds)\"],\"z1g5gH\":[\"Enable selected\"],\"z2QpqF\":[\"Path Variables\"],\"z4YzF4\":[\"H2 Keep-Alive (ms)\"],\"z5385K\":[\"Input Paths (comma separated)\"],\"z55Hd4\":[\"Delete Source ASS\"],\"z6HbLm\":[\"DAG Configuration\"],\"z7n92F\":[\"Live Gap Strategy\"],\"zCuWFS\":[\"Channel created\"],\"zDfdMJ\":[\"Until\"],\"zG9iNp\":[\"Search engines...\"],\"zGgIA2\":[\"Web Push priority updated\"],\"zHa4lw\":[[\"0\",\"plural\",{\"one\":[\"#\",\" step\"],\"other\":[\"#\",\" steps\"]}]],\"zIkg8M\":[\"Remux to MP4 without re-encoding and delete the original file on success. Saves disk space.\"],\"zInmXF\":[\"Scheduler\"],\"zJA0U6\":[\"Output Settings\"],\"zKwEox\":[\"Target Configuration\"],\"zNi48o\":[\"Remux to MKV without re-encoding. Matroska supports more codecs and features.\"],\"zQLplp\":[\"The platform reported this account as missing. It may have been deleted, banned, or the URL/ID is incorrect. Consider removing or updating this streamer.\"],\"zSwSsg\":[\"Dimensions & Filters\"],\"zWMS3O\":[\"Preview Gallery\"],\"zd00pH\":[\"Copy Path\"],\"zg8Pdk\":[\"Offline Detection Count\"],\"zga9sT\":[\"OK\"],\"zi6RXq\":[\"Error loading streamers: \",[\"0\"]],\"zoISi_\":[\"Failed to retry steps\"],\"zrmjn_\":[\"Max Duration\"],\"zx4BuL\":[\"Week\"],\"aHL20j\":[\"Backfill\"],\"Y0HVk1\":[\"Backfill From Replay\"],\"Une12m\":[\"Record the start of a broadcast detected late from the platform replay, as a separate session.\"],\"Yp8Nyj\":[\"New recordings wait while the host is above any of these limits; running recordings continue. I/O pressure is only available on Linux. 0 = Disabled\"],\"doOgTJ\":[\"Max I/O Pressure (%)\"],\"tCB1Oe\":[\"Max Memory Load (%)\"],\"zkJHvX\":[\"Max CPU Load (%)\"],\"_ly4n9\":[\"Wall-Clock Split Interval\"],\"uyLmFK\":[\"Split on the clock, counted from local midnight.\"],\"az8lvo\":[\"Off\"],\"43L24v\":[\"First Retry Delay (ms)\"],\"0TejLH\":[\"Delay Multiplier\"],\"YiyJ92\":[\"Delay Jitter\"],\"Gn-MIC\":[\"Retry Network Errors (Default: On)\"],\"ycTCRM\":[\"Retry Timeouts (Default: On)\"],\"yUUq0F\":[\"Retry 5xx Responses (Default: On)\"],\"Ab8kME\":[\"Retry 429 Responses (Default: On)\"],\"v6JGfC\":[\"Retry 404/410 Responses (Default: Off)\"],\"DV0xXJ\":[\"HLS retries a segment at most twice in place, then reschedules it within the HLS lifecycle retry budget. HLS always retries 404s.\"],\"p-Iwd_\":[\"Keep Raw Stream\"],\"WTiJlJ\":[\"Also save the unprocessed stream next to the fixed file\"],\"EoerzS\":[\"Stall Watchdog\"],\"WONCR_\":[\"Stall Timeout (s)\"],\"cauGYp\":[\"On Stall\"],\"y5UwJH\":[\"Reconnect (Default)\"],\"OrPA0A\":[\"End the stream\"],\"HCGpTY\":[\"H2 Keep-Alive Timeout (ms)\"],\"Gxea7R\":[\"TCP Keep-Alive (ms)\"],\"LmLO6R\":[\"H2 Stream Window (bytes)\"],\"C2ju8g\":[\"H2 Connection Window (bytes)\"],\"qy-Njm\":[\"H2 Adaptive Window (Default: Off)\"],\"D2iyNz\":[\"H2 Keep-Alive While Idle (Default: Off)\"]}")as Messages;
\ No newline at end of file
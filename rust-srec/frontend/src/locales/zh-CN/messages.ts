@@ -1 +1 @@
-/*eslint-disable*/import type{Messages}from"@lingui/core";export const messages=JSON.parse("{\"-10SpW\":[\"24小时交互式时间线选择。\"],\"-5Xsme\":[\"空间不足\"],\"-6YAwo\":[\"已选\"],\"-BEX4J\":[\"更新过滤器失败\"],\"-BOXe-\":[\"点播分段超时（毫秒）\"],\"-GLqpc\":[\"压缩/归档\"],\"-GZp3E\":[\"切换语言\"],\"-GwKvY\":[\"未配置平台覆盖项\"],\"-OQYUd\":[\"密钥超时（毫秒）\"],\"-O_9ij\":[\"主播详情\"],\"-Qbuje\":[\"下载引擎（mesio）\"],\"-TWWLH\":[\"必须填写 ID\"],\"-Xjyeo\":[\"选择文件\"],\"-_zWwh\":[\"自动（默认）\"],\"-b7T3G\":[\"已更新\"],\"-eCGXh\":[\"Windows 不支持通过此 API 进行交互式密码输入。请使用“桌面登录”（导入 tdata）或在常规终端中登录一次。\"],\"-fL-Rj\":[\"登录失败：\",[\"0\"]],\"-jO3E8\":[\"斗鱼高清画质值\"],\"-lH7FY\":[\"执行时间（默认）\"],\"-lwQ5z\":[\"视频编码\"],\"-rJcQ5\":[\"更新订阅失败\"],\"-swXfw\":[\"选择将文件传输到远程的方式。\"],\"-xD8Ul\":[\"录制的大小与时长限制。\"],\"-z4lmr\":[\"专家模式：直接编辑原始的平台特定配置。\"],\"-zy2Nq\":[\"类型\"],\"00W4lp\":[\"初始获取超时（ms）\"],\"00jaOh\":[\"归档压缩\"],\"01Oe9B\":[\"Telegram 桌面版\"],\"024CCW\":[\"选择模板\"],\"03Qqf9\":[\"已过滤\"],\"0CKZnZ\":[\"从视频第 10 秒生成缩略图（宽 320px）。\"],\"0CaXBE\":[\"支持的平台\"],\"0HlWEz\":[\"工作流步骤\"],\"0Jq6UY\":[\"管理你监控的主播及录制\"],\"0P2gFy\":[\"你要访问的页面不存在。\"],\"0PZXF8\":[\"选择格式\"],\"0RjCNS\":[\"无法连接到服务器，请检查网络连接。\"],\"0SsE5J\":[\"未找到预设\"],\"0TPdbO\":[\"H.264\"],\"0Uz_Zj\":[\"解密密钥 TTL（毫秒）\"],\"0X8rUE\":[\"https://...\"],\"0YopG6\":[\"资源限制\"],\"0ZwlwE\":[\"TDL 未登录\",[\"version\"]],\"0aTtEy\":[\"在任意原始头变更时分割。安全但可能产生额外文件。\"],\"0f9Hre\":[\"API 请求重试次数\"],\"0gVmZj\":[\"额外参数\"],\"0ioul2\":[\"包含图片\"],\"0s5Sjx\":[\"艺术家\"],\"0s7IPo\":[\"通知服务\"],\"0sZpQb\":[\"继承自上层\"],\"0tvouK\":[\"抖动\"],\"0v9bkw\":[\"会话已启动\"],\"0wk6D2\":[\"每页：\"],\"0xayzq\":[\"使用 NVIDIA NVENC 的快速 GPU 加速 H.264 编码（CQ 23）。速度与画质的良好平衡。\"],\"0zpgxV\":[\"选项\"],\"1-6BOG\":[\"下载备份\"],\"11MaKJ\":[\"加载事件失败\"],\"14PdY0\":[\"配置\"],\"16uyo-\":[\"添加渠道\"],\"17A-hi\":[\"启用后，将忽略匹配此模式的流。\"],\"18A9QX\":[\"删除会话失败\"],\"19MiM7\":[\"全局超时 (ms)\"],\"19P7sR\":[\"已触发检查\"],\"1AGwZy\":[\"输入唯一名称\"],\"1AigfY\":[\"上一页\"],\"1BqPFJ\":[\"网络与系统\"],\"1BxbyL\":[\"未找到平台。\"],\"1HbWYr\":[\"这是默认预设，描述无法修改。\"],\"1KMsgI\":[\"必须选择处理器\"],\"1PQRWr\":[\"开始时间\"],\"1TQa05\":[\"引擎\"],\"1U1a6C\":[\"https://api.example.com/webhook\"],\"1UlpHm\":[\"主播\"],\"1VXi7i\":[\"取消处理管道\"],\"1WHNRE\":[\"添加覆盖项\"],\"1WTDTW\":[\"所有输入的 JSON 数组\"],\"1WrRvf\":[\"替换步骤\"],\"1XskRc\":[\"处理管道为空。\"],\"1ZTiaz\":[\"分段\"],\"1ZZ47T\":[\"调整 UI 元素的圆角程度。\"],\"1aPw3f\":[\"实时轮询通知已启用\"],\"1cbMZM\":[\"CQ (恒定质量)\"],\"1dwnDD\":[\"服务器未配置\"],\"1hKEom\":[\"优先级\"],\"1ir2pc\":[\"Basic Auth\"],\"1jJKez\":[\"配置如何在此浏览器中以及通过系统推送通知接收警报。\"],\"1lVNQI\":[\"当处理任务失败时触发。\"],\"1mYvTJ\":[\"平台覆盖项\"],\"1naaKv\":[\"当一项录制在并发队列中等待时间超过该阈值时，rust-srec 会在启动前重新检查主播以获取新的流地址和请求头。低于此阈值时，会复用首次直播事件中捕获的 URL。默认 60 秒。设为 0 表示每次排队等待都刷新。\"],\"1nhIls\":[\"过滤器删除成功\"],\"1njn7W\":[\"浅色\"],\"1pChHx\":[\"通知日志保留\"],\"1tul0y\":[\"会话未运行\"],\"1wTrMB\":[\"全部替换\"],\"1wy3Ze\":[\"初始\"],\"1yzCsx\":[\"下载完成\"],\"21M8r5\":[\"Web 推送\"],\"22PcUJ\":[\"配对分段处理管道\"],\"22lIWc\":[\"进程以代码 \",[\"0\"],\" 退出\"],\"237hSL\":[\"已结束\"],\"26Ki3h\":[\"这将永久删除处理管道及其关联的所有任务和日志。此操作无法撤销。\"],\"27E8MB\":[\"设置更新成功\"],\"28iWmV\":[\"正在加载处理管道编辑器...\"],\"29VNqC\":[\"未知错误\"],\"29loHy\":[\"处理管道已删除\"],\"2BBAbc\":[\"列表\"],\"2EEBQm\":[\"主播 ID\"],\"2E_rkV\":[\"凭据保存成功\"],\"2FC3XU\":[\"FFmpeg 路径\"],\"2GT3Hf\":[\"全局\"],\"2KpT4Y\":[\"例如 1M\"],\"2R2xgz\":[\"保留路径\"],\"2TQlVI\":[\"需要启用“录制弹幕”。\"],\"2WbqDp\":[\"管理存储路径和文件格式。\"],\"2XRg_h\":[\"读取超时 (ms)\"],\"2d1Xgp\":[\"自定义 Header\"],\"2dhgET\":[\"YYYY-MM-DD\"],\"2jmZHr\":[\"为视频文件添加元数据标签（标题、艺术家、日期）。\"],\"2k1l_G\":[\"视频设置\"],\"2kAqWs\":[\"创建渠道失败\"],\"2m_AmE\":[\"NVENC HEVC 高质量\"],\"2nMNeT\":[\"使用 NVIDIA NVENC 的高质量 GPU 加速 AV1 编码（CQ 24）。最佳压缩比，需要 RTX 4000+ 系列显卡。\"],\"2o7xcf\":[\"输出选项\"],\"2qfwJn\":[\"覆盖\"],\"2sDhLO\":[\"离线时\"],\"2tuwGz\":[\"添加参数\"],\"2vw0xh\":[\"历史结束\"],\"2wl1yB\":[\"最大间隔（s）\"],\"2xsJvU\":[\"^Started.*\"],\"3-I7HJ\":[\"选择主播\"],\"3-IxS7\":[\"为此配置提供一个唯一且易于识别的名称。\"],\"3070NI\":[\"常规配置\"],\"30XQ-_\":[\"Cookie\"],\"32TndD\":[\"已阻止\"],\"34KT3m\":[\"取消 `execute` 处理器命令前的超时时间。\"],\"39GoNc\":[\"最大并发录制数\"],\"39vwDk\":[\"标清 / 低清\"],\"3Aerm-\":[\"未找到录制会话\"],\"3BEjvS\":[\"无需重新编码，重封装为 MP4。快速且无损——仅更改封装格式。\"],\"3H1WQ-\":[\"连接/活动超时\"],\"3Huwd4\":[\"自动布局\"],\"3Ihr5B\":[\"选择格式\"],\"3JjdaA\":[\"运行\"],\"3K6phQ\":[\"默认使用 Mesio 内置的直播停顿超时；禁用表示无限等待。\"],\"3KInmc\":[\"已为此浏览器禁用 Web 推送\"],\"3N3v30\":[\"快速 H.264 压缩（CRF 23）。在速度、画质与体积之间取得良好平衡。\"],\"3NfDu6\":[\"加载平台失败\"],\"3QfsSn\":[\"暂无处理任务\"],\"3QlVaA\":[\"创建预设失败：\",[\"0\"]],\"3Qu3lA\":[\"处理管道完成时\"],\"3X3_SF\":[\"步骤列表\"],\"3Yd9PG\":[\"侧边栏布局\"],\"3bv0H0\":[\"应用关闭时触发。\"],\"3gnbIv\":[\"TDL 二进制路径\"],\"3ktmhA\":[\"API 模式\"],\"3qNoeP\":[\"降级\"],\"3rQgBh\":[\"录制输出文件系统无法写入、录制无法继续时的严重告警。\"],\"3sO1Yr\":[\"身份验证与设置\"],\"3tncII\":[\"配置从所选备份文件导入数据的方式。\"],\"3uIIkE\":[\"添加新引擎\"],\"3vSDtx\":[\"TDL 存储其会话和配置的地方。\"],\"3wRUCF\":[\"提取 MP3\"],\"3ywK2I\":[\"主播检查\"],\"3yyqaw\":[\"高质量压缩\"],\"40Gx0U\":[\"时区\"],\"40lLFI\":[\"下一页\"],\"40wBfu\":[\"批量 URL\"],\"41k5OL\":[\"无扩展名\"],\"43YdBD\":[\"绝对路径，或在 PATH 中时填写“ffmpeg”\"],\"45zsWJ\":[\"保留的最小大小。\"],\"47e97a\":[\"最大重试次数\"],\"47mhxP\":[\"首选 CDN\"],\"48NKsg\":[\"检查登录\"],\"49BbAW\":[\"文件\"],\"4AWhME\":[\"匹配策略\"],\"4BITzH\":[\"错误：\"],\"4GICMX\":[\"未找到可播放的流\"],\"4GKuCs\":[\"登录失败\"],\"4Lr0jZ\":[\"应用模板设置。\"],\"4P5UM2\":[\"会话已删除\"],\"4QQLtV\":[\"你的数字库目前为空。开始录制以在此显示会话。\"],\"4RQxGo\":[\"异常状态\"],\"4XUbOH\":[\"仅在编解码器配置变更时分割。减少误分割。\"],\"4aDTBq\":[\"复制文件\"],\"4ba0NE\":[\"已计划\"],\"4cEClj\":[\"录制会话\"],\"4cOYET\":[\"在应用标签页打开时，通过轮询最近事件来显示通知。\"],\"4e7ocq\":[\"重试之间的基础延迟\"],\"4eMNNj\":[\"启用输出指标（默认：开）\"],\"4jAOc9\":[\"选择必须在此步骤运行前成功完成的步骤。\"],\"4nfUib\":[\"品牌颜色\"],\"4siLaw\":[\"直播归档\"],\"4uQ7PS\":[\"后台刷新已启用\"],\"4v6VMF\":[\"将在…后恢复\"],\"4w5jzL\":[\"自定义标签\"],\"4xzdEv\":[\"订阅已更新\"],\"5-AFwM\":[\"暂无工作流\"],\"52y5Op\":[[\"0\",\"plural\",{\"other\":[\"#\",\" 失败\"]}]],\"55n6pj\":[\"速率限制\"],\"57MMsh\":[\"最大分段重试次数\"],\"5B_EbJ\":[\"离线\"],\"5FCyMZ\":[\"目标码率\"],\"5GJtdv\":[\"主题预设\"],\"5HLg1G\":[\"流与元数据\"],\"5LUAOT\":[\"原始 JSON 编辑器\"],\"5PhtRK\":[\"此平台没有可用的专门选项。\"],\"5RyP14\":[\"逐段\"],\"5SNqKm\":[\"自定义配置\"],\"5UfPjf\":[\"实时查看应用日志。日志仅保留最近 \",[\"MAX_LOG_ENTRIES\"],\" 条。\"],\"5b4J4v\":[\"全部时间\"],\"5bhON1\":[\"比特率\"],\"5dKF37\":[\"发送输入失败：\",[\"0\"]],\"5eHyY-\":[\"测试通知\"],\"5gF1Yx\":[\"点击添加。你可以在编辑器中重新排序步骤。\"],\"5hxDwt\":[\"CPU 任务\"],\"5iuLzq\":[\"预设\"],\"5kX7hC\":[\"为重试延迟添加随机性，避免“惊群”。\"],\"5l9iMR\":[\"暂无模板\"],\"5r2EnB\":[\"NVENC AV1 快速\"],\"5raigv\":[\"待处理初始化分段上限\"],\"5s-Rk5\":[\"添加处理管道步骤\"],\"5vx6Be\":[\"重试所有失败的处理任务失败\"],\"5x03LH\":[\"远端存储的基础路径。支持元数据和时间占位符。\"],\"61RNR5\":[\"未找到日志文件\"],\"633mTs\":[\"创建过滤器失败\"],\"65DNcr\":[\"未知文件\"],\"677MgO\":[\"未找到引擎。\"],\"67RoFa\":[\"处理管道\"],\"67hU6L\":[\"主程序\"],\"6A0WJl\":[\"保存前修复配置错误\"],\"6Aih4U\":[\"离线\"],\"6BDqha\":[\"限制\"],\"6Dd2PM\":[\"维护\"],\"6GBt0m\":[\"元数据\"],\"6GJw73\":[\"暂无时间线事件\"],\"6H2zd1\":[\"发送测试通知失败\"],\"6LJ142\":[\"加载引擎列表失败：\",[\"0\"]],\"6O3gfw\":[\"使用手机号和验证码\"],\"6Oaeoc\":[\"Cron\"],\"6Umgab\":[\"自动 (二维码 → 手机号和验证码 → 桌面)\"],\"6V3Ea3\":[\"已复制\"],\"6VKo3k\":[\"删除上一步骤产出的文件。在上传步骤之后使用是安全的。在转码/remux 之后，它删除的是转码结果而非原始文件——若要删除源文件，请改为在转码步骤上启用“成功后移除输入”。\"],\"6WdDG7\":[\"页码\"],\"6YtxFj\":[\"名称\"],\"6_-6tw\":[\"即将支持 \",[\"val\"],\" 渠道！\"],\"6_dCYd\":[\"概览\"],\"6bAENo\":[\"磁盘空间\"],\"6d9Ao4\":[\"该执行记录暂无日志。\"],\"6fi2dR\":[\"最大重试延迟（毫秒）\"],\"6gRgw8\":[\"重试\"],\"6geoEv\":[\"文件路径已复制到剪贴板\"],\"6h20bP\":[\"取消处理管道？\"],\"6hur5J\":[\"例如 --namespace my_account\"],\"6kItd_\":[\"上传 : 下载（不对称）\"],\"6ldwcL\":[\"快速压缩\"],\"6mF1Q8\":[\"提取 AAC\"],\"6moIoi\":[\"已配对\"],\"6mwiZ7\":[\"指定首选的内容分发网络（例如 ws-h5, hw-h5）。\"],\"6nFdlp\":[\"删除预设？\"],\"6p4wnA\":[\"仅支持 MP4/MOV/M4V\"],\"6qNpC1\":[\"优先选择特定画质（不区分大小写）。按 Enter 添加。\"],\"6sBUvP\":[\"添加一个流以开始观看会话。\"],\"6swMyy\":[\"可用预设\"],\"6tBUzp\":[\"成功后移除输入\"],\"6vFYPB\":[\"例如 flv, hls\"],\"6wLR55\":[\"编辑引擎\"],\"7-c73-\":[\"选择要在此节点中使用的处理任务或工作流。\"],\"71pK1Q\":[\"这将永久删除 \",[\"selectedCount\"],\" 个主播。此操作无法撤销。\"],\"72c5Qo\":[\"总计\"],\"73yjgk\":[\" - 会话：\",[\"0\"]],\"76ob5K\":[\"第一个输出文件路径\"],\"77da32\":[\"TPS 限制\"],\"78qtNg\":[\"此删除步骤删除的是转码后的结果文件，而不是原始录制文件。请改为在转码步骤上启用“成功后移除输入”。\"],\"7Bj3x9\":[\"失败\"],\"7C-Jn5\":[\"显示密码\"],\"7DqYk8\":[\"过滤器更新成功\"],\"7JVWl4\":[\"弹幕关闭时结束录制\"],\"7Jw_XW\":[\"错误详情\"],\"7K4dCf\":[\"尝试调整搜索或过滤器\"],\"7L01XJ\":[\"操作\"],\"7L3DyA\":[\"正则模式\"],\"7Oyczn\":[\"全局缓冲区大小\"],\"7SNWYf\":[\"不支持通过 Web 进行 Telegram 两步验证\"],\"7VxXSg\":[\"浏览器状态\"],\"7_wpqP\":[\"推荐：8388608 (8 MiB)\"],\"7cmJQz\":[\"工作流创建成功\"],\"7fIIFx\":[\"TTWID 管理模式\"],\"7jRxmJ\":[\"应用启动时触发。\"],\"7kKmbL\":[\"0 表示不限制。\"],\"7p5kLi\":[\"仪表盘\"],\"7s6i2S\":[\"下载全部\"],\"7sMeHQ\":[\"键\"],\"7sNhEz\":[\"用户名\"],\"7tgEva\":[\"2\"],\"7vhWI8\":[\"新密码\"],\"800TmA\":[\"流数\"],\"80AR_c\":[\"从备份文件恢复\"],\"81mUvV\":[\"删除源视频文件\"],\"83M8VR\":[\"选择引擎\"],\"84B6U_\":[\"数值越低 = 质量越高\"],\"8HE-Aw\":[\"过滤互动玩法\"],\"8PNILp\":[\"解析流\"],\"8T864k\":[\"当下载成功完成时触发。\"],\"8TMaZI\":[\"时间戳\"],\"8Tg_JR\":[\"自定义\"],\"8WcAZl\":[\"过滤器创建成功\"],\"8WgMDL\":[\"归档设置\"],\"8XX_CD\":[\"暂无过滤器\"],\"8YINy3\":[\"配置路径（可选）\"],\"8ZsakT\":[\"密码\"],\"8_brI5\":[\"必须填写名称\"],\"8b6O3h\":[\"搜索日志...\"],\"8eRn2a\":[\"系统\"],\"8gGPQK\":[\"发件人地址\"],\"8iqQ6v\":[\"添加排除正则模式\"],\"8j1zGy\":[\"自动封面\"],\"8lz0kn\":[\"https://discord.com/api/webhooks/...\"],\"8mJUui\":[\"此浏览器不支持\"],\"8rp73h\":[\"配置已成功导入\"],\"8tjQCz\":[\"浏览\"],\"8tm102\":[\"WUP\"],\"8vQkDt\":[\"延迟\"],\"8wu9lr\":[\"排队中\"],\"8x_SfR\":[\"内存使用率\"],\"8yPA1y\":[\"检查主播是否在线的间隔。较慢的间隔可以减少 API 使用，但可能会延迟录制开始。\"],\"90Z1Nt\":[\"已开始重试处理任务\"],\"93Ucih\":[\"附加到命令的额外参数。\"],\"95g7x4\":[\"日志级别与模块\"],\"98oK0h\":[\"带宽限制帮助\"],\"9AfU7B\":[\"使用任务清单中的 danmu_inputs\"],\"9CFpCU\":[\"平均时长\"],\"9DW7l4\":[\"等待首次检查\"],\"9E34se\":[\"浏览和下载应用程序日志文件。按日期范围筛选或下载全部。\"],\"9ELT_X\":[\"启用监控\"],\"9G881z\":[\"无（默认）\"],\"9IO3UM\":[\"需要修改密码\"],\"9NKF63\":[\"例如 1080p, source, 原画\"],\"9OmEZr\":[\"HTTP 请求\"],\"9P6Cjx\":[\"可执行文件路径\"],\"9PbSQ8\":[\"暂时禁用\"],\"9SGdhm\":[\"X-Auth-Key\"],\"9SrfBr\":[\"按时间段调度\"],\"9UQ730\":[\"克隆\"],\"9UWq9R\":[\"启用弹幕/聊天消息与视频一起录制。\"],\"9Ui6mb\":[\"达到次数后跳过\"],\"9Ulhif\":[\"通知事件\"],\"9XOpfw\":[\"最大间隔（毫秒）\"],\"9eF5oV\":[\"欢迎回来\"],\"9hg9mc\":[\"下载中\"],\"9kAtVa\":[\"空间不足\"],\"9kmN8A\":[\"未添加元数据标签\"],\"9p14fd\":[\"配置代理策略和连接详情。\"],\"9pDx-X\":[\"从透传中排除 ASS\"],\"9rG25a\":[\"服务器地址\"],\"9uJs-K\":[\"HLS 调优\"],\"A-3LfF\":[\"预览缩略图\"],\"A-yfCV\":[\"扫码登录\"],\"A2LNUE\":[\"跳过了 \",[\"skippedCount\"],\" 个重复的流\"],\"A8uySC\":[\"删除过滤器失败\"],\"ABucgF\":[\"排除的扩展名\"],\"ADzdEE\":[\"优先选择特定流媒体协议。按 Enter 添加。\"],\"AEGM8s\":[\"复制失败\"],\"AGMlcc\":[\"返回录制会话\"],\"AIdJOG\":[\"IANA 时区（例如 Asia/Shanghai、UTC）。\"],\"AK4HwH\":[\"加载模板失败\"],\"AR0orP\":[\"我的归档工作流\"],\"AYbtd_\":[\"支持\"],\"Abl55S\":[\"留空以进行流复制\"],\"Ai2DeC\":[\"添加新过滤器\"],\"AlfqgK\":[\"观看\"],\"ApdJrx\":[\"斗鱼默认画质\"],\"Ar-5tA\":[\"最大刷新重试次数\"],\"AsJWjt\":[\"配置设置\"],\"AsnjLf\":[\"请先设置 Telegram Desktop 目录\"],\"AwXh-1\":[\"下载活动看起来正常\"],\"Az1ZEd\":[\"主播上线时运行。\"],\"AzCMby\":[\"加载平台列表失败：\",[\"0\"]],\"B0GspH\":[\"立即开始检查此主播。\"],\"B1KFZ1\":[\"* * * * * *\"],\"B2Zb_F\":[\"JSON\"],\"B3xl-Q\":[\"最后活动\"],\"B495Gs\":[\"归档\"],\"B4Ew1I\":[\"至少添加一个步骤\"],\"B6-TsG\":[\"小于此值的文件仍以单线程传输。示例：<0>250M</0>、<1>1G</1>。\"],\"B7dmPy\":[\"会话完成处理管道\"],\"BAWVb9\":[\"未配置覆盖项\"],\"BCL8Ot\":[\"Header 值\"],\"BFl5lQ\":[\"全高清缩略图\"],\"BGi3Wo\":[\"追加到输出文件名之前\"],\"BIXG5q\":[\"重试策略\"],\"BQvz2A\":[\"这些超时设置在管道工作线程池启动时应用。更改需要重启才能生效。\"],\"BWUEP1\":[\"仅 HTTP/1.1\"],\"BX_rCE\":[\"特定 TTWID Cookie\"],\"B_pQ8T\":[\"流分割策略\"],\"B_rQdm\":[\"会追加到所有 HLS 请求中。适用于签名 URL 或 CDN 路由。\"],\"BaQ0LA\":[\"在数据库中保留已完成、失败或已取消的任务及工作流执行记录的天数。设置为 0 可无限期保留。\"],\"Ban892\":[\"对远端 API 的每秒最大事务数（--tpslimit）。\"],\"Bcgdrh\":[\"名称自动填充成功\"],\"BdBelu\":[\"弹幕/分钟\"],\"Bhx3fS\":[\"已扫描！请在手机上确认\"],\"BjJjsB\":[\"FLV 流修复管道\"],\"BjitBo\":[\"下载 \",[\"0\"]],\"Bn9-El\":[\"默认\"],\"BnOMVx\":[\"极速压缩\"],\"BrrIs8\":[\"存储\"],\"Bs956d\":[\"使用 NVIDIA NVENC 的高质量 GPU 加速 H.264 编码（CQ 20）。最佳 NVENC 画质。\"],\"Bsurp4\":[\"上线时\"],\"Btgfql\":[\"处理任务配置\"],\"BwhhDv\":[\"选择主播\"],\"ByeSsO\":[\"安全与身份\"],\"C1TsH4\":[\"正在等待连接...\"],\"C2WreN\":[\"0:v:0\"],\"C4IQ_M\":[\"工作流删除成功\"],\"C95qpz\":[\"采样策略\"],\"CBVnKj\":[\"连接超时 (ms)\"],\"CKyk7Q\":[\"返回\"],\"CO6Iw8\":[\"IO 任务\"],\"COU0uA\":[\"扫描目录以查找命令创建的新文件。检测到的文件将传递到下一个管道步骤。\"],\"CP3D8G\":[\"进度\"],\"CS276w\":[\"删除工作流？\"],\"CSZ9-b\":[\"TPS 限制帮助\"],\"CZIQWR\":[\"在运行桌面应用时，配置重要事件的原生操作系统通知。\"],\"Cav1Ci\":[\"弹幕\"],\"Cbxd8c\":[\"与带宽限制叠加生效——可避免单个大文件占满整条链路，同时仍允许多个小文件并行传输。\"],\"CdgoAe\":[\"创建“\",[\"0\"],\"”的副本并使用新名称。\"],\"Cj2Gtd\":[\"大小\"],\"Cm-_Q8\":[\"notifier@example.com\"],\"CmOYNg\":[\"插入到 -i input_url 之前的参数\"],\"CndmuB\":[\"流解析成功\"],\"CpwhPE\":[\"特定\"],\"CqO9qC\":[\"会话\"],\"CsekCi\":[\"正常\"],\"CvL6qO\":[\"最小间隔（s）\"],\"Cvl3Ur\":[\"例如 tmp\"],\"D-NlUC\":[\"系统\"],\"D0K_c6\":[\"此步骤已关联到预设 <0>\",[\"presetName\"],\"</0>。\"],\"D1U6Wk\":[\"TDL 交互式登录\"],\"D4xpys\":[\"当下载被手动取消时触发。\"],\"D5t8TK\":[\"工作流更新成功\"],\"DC2eVx\":[\"处理管道结构\"],\"DCKkhU\":[\"当前密码\"],\"DEpnGr\":[\"校验完整性\"],\"DHhJ7s\":[\"上一页\"],\"DIv6yv\":[\"此处理器没有可用配置。\"],\"DJsY8j\":[\"步骤标识符（唯一）\"],\"DKrPOn\":[\"更新日志配置失败\"],\"DL3whW\":[\"基础后处理：将 FLV 重封装为 MP4，并生成缩略图预览。\"],\"DNA4aQ\":[\"解密/处理后的分段在缓存中保留的时间。\"],\"DPfwMq\":[\"已完成\"],\"DRTrkZ\":[\"弹幕数\"],\"DS-5Kd\":[\"加载系统健康状态失败\"],\"DTAxdY\":[\"输出\"],\"DTD28p\":[\"磁盘空间不足时发出警报。\"],\"DTKoJC\":[\"未找到最近的录制会话。\"],\"DYJ_1s\":[\"处理管道统计\"],\"DYyL81\":[\"复制（流复制）\"],\"D_XERT\":[\"删除处理管道失败\"],\"DanbIO\":[\"外观与样式\"],\"DfRZHP\":[\"已配置的事件\"],\"DgVBNf\":[\"不支持 Telegram 两步验证密码\"],\"DjZUWm\":[\"将文件上传到云存储，上传成功后删除本地副本。\"],\"DkLWbn\":[\"smtp.gmail.com\"],\"DtXU8D\":[\"保留通知事件日志的天数。设置为 0 可无限期保留事件。\"],\"Dya8HF\":[\"修复 FLV 流\"],\"DzUNTV\":[\"写入超时（毫秒）\"],\"E4sw5m\":[\"会话完成\"],\"E555CB\":[\"附加到 `tdl login` 的可选额外参数。\"],\"E5h2rM\":[\"高质量 MP3\"],\"E8bXne\":[\"代理\"],\"EBdNVo\":[\"录制预览\"],\"ECGvyy\":[\"更新模板失败：\",[\"0\"]],\"EEMR5K\":[\"小时 (00-23)\"],\"ELDffV\":[\"取消处理任务失败\"],\"ELa85n\":[\"所有类型\"],\"EOsjKj\":[\"处理管道必须至少包含一个步骤\"],\"EQoNwM\":[\"文件配置\"],\"EVGGg9\":[\"查看详情\"],\"EXa7Eb\":[\"主播 ID 是必需的\"],\"EZCWHe\":[\"启动处理流程\"],\"EZgAlu\":[\"Bearer Token\"],\"E_QGRL\":[\"已禁用\"],\"EbgT4I\":[\"优先级从低到高\"],\"EdQY6l\":[\"无\"],\"Ef7StM\":[\"未知\"],\"Ejic1p\":[\"平台\"],\"EjzeaZ\":[\"提取设置\"],\"EmVl8I\":[\"默认值为 1（无突发）。当服务方容忍短时突发流量时可适当调高。\"],\"EtVJIx\":[\"绝对路径，或在 PATH 中时填写“streamlink”\"],\"ExpKh1\":[\"预设步骤\"],\"EzR6wK\":[\"音频处理\"],\"EztJxW\":[\"rclone 默认值：8\"],\"F-T4fj\":[\"浏览处理任务生成的媒体文件\"],\"F08Z2D\":[\"未找到 TDL 二进制文件\"],\"F13uOJ\":[\"快速编码，便于在社交媒体或聊天中快速分享。\"],\"F3bW6y\":[\"平台\"],\"F6Go3M\":[\"Header 键\"],\"F6pfE9\":[\"启用\"],\"F6qVY1\":[\"天 (01-31)\"],\"F7wXp2\":[\"导入前删除所有现有配置。此操作无法撤销。\"],\"F9I0Bm\":[\"严重的系统错误或主播故障。\"],\"F9lPvE\":[\"例如 'best'、'worst'、'720p'、'audio_only'\"],\"FBIuPX\":[\"清除选择\"],\"FBsQxQ\":[\"这将永久移除“\",[\"0\"],\"”配置。此操作无法撤销。\"],\"FEr96N\":[\"主题\"],\"FIHR-e\":[\"当弹幕控制信号指示直播已关闭时，停止录制。\"],\"FIfpIQ\":[\"TDL 已登录\",[\"version\"]],\"FJxoQQ\":[\"任务配置\"],\"FLm1Nd\":[\"实时\"],\"FMKXt7\":[\"传输数\"],\"FRqceZ\":[\"采集设置\"],\"FSaByO\":[\"视频编码变化\"],\"FUbs2p\":[\"Rclone\"],\"FZg3wM\":[\"操作\"],\"F_amnS\":[\"时间范围\"],\"Fecn_0\":[\"引擎覆盖项\"],\"Ff0Dor\":[\"过去\"],\"FfHole\":[\"预设克隆成功\"],\"FgYX1w\":[\"当下载被拒绝时触发（例如熔断器）。\"],\"FnYEt6\":[\"主播分页\"],\"FnyzZP\":[\"活跃发言用户\"],\"FrndDV\":[\"原始分辨率缩略图\"],\"Fv9mIF\":[\"这些设置仅适用于桌面应用。服务器仍将正常发送通道通知（Discord/电子邮件/Webhook）。\"],\"FyGlJm\":[\"解析并播放直播流\"],\"FzHX8f\":[\"用于所有请求的显式 TTWID Cookie 值。\"],\"G1YI6L\":[\"用于认证的 HTTP cookie。刷新时会自动更新。\"],\"G39zBp\":[\"缓存\"],\"G4Ntrl\":[\"覆盖录制引擎。\"],\"G88OSb\":[\"处理队列过长时发出警告。\"],\"G9GerW\":[\"执行命令\"],\"G9ULuK\":[\"最大 HEVC/H.265 压缩（CRF 28），以获得最小文件体积。\"],\"G9jHpr\":[\"处理管道有效\"],\"GD1Pmm\":[\"阈值\"],\"GICGoz\":[\"瞬时错误\"],\"GRlUlG\":[\"输入参数\"],\"GRzPZD\":[\"如果设置，直播标题必须包含至少一个这些关键字。\"],\"GSDw5m\":[\"设置新的录制工具配置\"],\"GSr0rF\":[\"打开菜单\"],\"GWWwxV\":[\"循环保护\"],\"GYeo24\":[\"返回上一页\"],\"GbsW45\":[\"创建新处理任务\"],\"GcrmOG\":[\"至少需要一个输入路径\"],\"Gg8KwP\":[\"正在解析流 URL，请稍候。\"],\"GkxWrz\":[\"音频编码\"],\"GnfGTT\":[\"自定义请求头\"],\"GrdEmt\":[\"为此模板自定义特定的引擎设置。\"],\"GtJbUa\":[\"后台\"],\"GwDyhV\":[\"删除源 XML 文件\"],\"GyEhZV\":[\"最大 HEVC 压缩\"],\"H5UXax\":[\"确认导入\"],\"H7PCCE\":[\"<0>0</0> 表示不限速（rclone 默认值）。例如：当服务方将你限制为每秒 10 次请求时，可设为 <1>10</1>。\"],\"HCO_et\":[\"编码设置\"],\"HDYzmV\":[\"缺口评估间隔（毫秒）\"],\"HKxmVq\":[\"上传失败前的重试次数。\"],\"HM56Bx\":[\"创建中...\"],\"HQoaRh\":[\"用于处理/编码的参数\"],\"HRbpjg\":[\"新密码（至少 8 个字符）\"],\"HTu6w6\":[\"配置已成功导出\"],\"HUqDD6\":[\"添加环境变量\"],\"HVrxmo\":[\"清理后的主播名称\"],\"HWpPGm\":[\"GPU\"],\"H_IzXL\":[\"IO 任务超时\"],\"H_smbI\":[\"时长（毫秒）\"],\"HajiZl\":[\"本月\"],\"Hhc4OF\":[\"搜索处理任务...\"],\"HlhZ5D\":[\"启用 TLS\"],\"HmHwcC\":[\"固定间隔\"],\"HqohwS\":[\"最大上传数\"],\"HruwW2\":[\"自定义 User-Agent 字符串（可选）\"],\"HtrFfw\":[\"必须填写 URL\"],\"Hu-bBV\":[\"这将取消此处理管道中所有待处理和处理中的任务。\"],\"HuTRrW\":[\"流播放器\"],\"HuisDV\":[\"逐段处理管道\"],\"HvemsH\":[\"均值 \",[\"averagePerMinute\"]],\"HzqrrK\":[\"检查 TDL 状态失败：\",[\"0\"]],\"I0LpOM\":[\"需要手动更新\"],\"I1WA96\":[\"Rust-Srec\"],\"I2M7LB\":[\"平台名称\"],\"I3Tk-i\":[\"引擎的身份与类型。\"],\"I6bYOx\":[\"调整侧边栏样式和行为。\"],\"IBg4fi\":[\"未发现错误。最大深度：\",[\"0\"]],\"IBn2_D\":[\"延迟、代理与保留策略。\"],\"IDbOLP\":[\"超清\"],\"IDkbnS\":[\"配置此主播的录制规则。\"],\"IDqYXq\":[\"尝试获取可用的最高原画质量。\"],\"IGmq_R\":[\"录制延迟\"],\"II3urw\":[\"文件操作\"],\"IKSVos\":[\"例如 /data/tdl\"],\"ILdR4t\":[\"重封装 (Faststart)\"],\"IMcoE-\":[\"嵌入\"],\"IOMDKm\":[\"启动登录失败：\",[\"0\"]],\"IOnYeq\":[\"重排最大分段数\"],\"IQTWsi\":[\"输入要排除的关键词...\"],\"IQlf-W\":[[\"0\"],\"/\",[\"1\"],\" 步\"],\"IR6zrc\":[\"处理所选主播失败\"],\"ISAp-4\":[\"模块日志级别\"],\"ISgi7W\":[\"上传所有内容，无论文件类型如何\"],\"IUwGEM\":[\"保存更改\"],\"IagCbF\":[\"URL\"],\"IduvKA\":[\"更新优先级失败\"],\"IfLN8u\":[\"监控此主播\"],\"IgrLD_\":[\"暂停\"],\"IiCZ6K\":[\"等待下载\"],\"IjXiiq\":[\"过滤重复标签并检测流重放循环\"],\"IjvCsG\":[\"原生\"],\"IkqmLx\":[\"导入配置\"],\"IpYp3U\":[\"最小比特率（bps）\"],\"IqjaXP\":[\"例如 mp4\"],\"IrGyPi\":[\"MB/s\"],\"IvxHT8\":[\"浏览器通知\"],\"IwBsC9\":[\"等待恢复超时后确认。\"],\"J-PlWE\":[\"变量：{streamer}、{title}、{platform} | 时间：%Y、%m、%d、%H、%M、%S\"],\"J0UZ9c\":[\"自定义导入主题已激活。\"],\"J28zul\":[\"连接中...\"],\"J2AZlk\":[\"确定要删除此主播吗？\"],\"J4VbZP\":[\"主播创建成功\"],\"J50_e4\":[\"过滤器类型\"],\"J5nbej\":[\"严重\"],\"J6n7sl\":[\"进行中\"],\"J7EJf5\":[\"未找到 TDL\"],\"J8slJP\":[\"后处理管道完成时运行。\"],\"J9cyui\":[\"当前正在直播的主播会显示在这里。\"],\"JB_0cx\":[\"首选 CDN\"],\"JDgrxe\":[\"原画 / 蓝光\"],\"JDiqy2\":[\"每个采样周期捕获的目标消息数量。\"],\"JEGlfK\":[\"已开始\"],\"JGAkEK\":[\"返回详情\"],\"JGiexj\":[\"优化流头部\"],\"JJZidX\":[\"弹幕服务\"],\"JK4_Ie\":[\"无（剥离音频）\"],\"JO8-jA\":[\"管理订阅\"],\"JRQitQ\":[\"确认新密码\"],\"JU-LKs\":[\"在节流生效之前，可短时超出 TPS 限制的请求数量。\"],\"JY5Oyv\":[\"数据库\"],\"J_hVSQ\":[[\"0\"]],\"JbursE\":[\"TDL 账户命名空间 (`tdl --ns ...`)。使用它来管理多个 Telegram 账户。\"],\"Jdxa-V\":[\"无限等待\"],\"Jf28Qk\":[\"重试处理管道\"],\"JiIKww\":[\"高级设置\"],\"Jj9mVU\":[\"删除渠道？\"],\"JlFcis\":[\"发送\"],\"Jmb3Ih\":[\"基于速度（动态）\"],\"JrUYse\":[\"最接近目标码率\"],\"JrcCgP\":[\"当前过滤指令\"],\"Jsp72G\":[\"已处理缓冲倍数\"],\"Ju0JNh\":[\"已取消处理管道中的 \",[\"0\"],\" 个步骤\"],\"JuSQP0\":[\"转换后检查文件是否存在\"],\"JzY5pj\":[\"描述此工作流的用途...\"],\"K-3SzA\":[\"ffmpeg\"],\"K2vkEv\":[\"已恢复\"],\"K38wNo\":[\"模板信息\"],\"K3UhxB\":[\"选择日期范围\"],\"K4zqVo\":[\"创建工作流失败：\",[\"0\"]],\"K7tIrx\":[\"分类\"],\"KAmTRT\":[\"启用或禁用此 Webhook\"],\"KCzvDi\":[\"添加参数模板\"],\"KDw4GX\":[\"重试\"],\"KOT_Rc\":[\"多线程流数帮助\"],\"KPx1UV\":[\"未找到\"],\"KSS7Tg\":[\"排除关键字\"],\"KTmH5k\":[\"分离并编辑\"],\"KUyMyQ\":[\"优雅停止超时\"],\"KWm8rr\":[\"机器人名称\"],\"KYBAbP\":[\"启用后，每个点播分段都必须在该超时内完成。\"],\"KYdyb_\":[\"使用移动端端点进行提取。\"],\"KZOdTD\":[\"客户端类型 (ctype)\"],\"KZVQ3R\":[\"空间节省\"],\"K_-HXP\":[\"目录路径已复制\"],\"KftHu1\":[\"最小间隔（毫秒）\"],\"KhgRw2\":[\"搜索事件...\"],\"KlrNJU\":[\"所有系统组件与资源的实时状态。\"],\"KmtY_Q\":[\"处理管道中有失败的处理任务。点击查看详情。\"],\"KnPHvA\":[\"排队刷新阈值\"],\"KoQLpo\":[\"使用 <0>-movflags +faststart</0>\"],\"Kp3hk-\":[\"允许私有网络流代理目标\"],\"KpU39u\":[\"单文件上限（rclone --bwlimit-file）。语法与带宽限制相同，并与之叠加生效。\"],\"KtTU7C\":[\"认证类型\"],\"KwS2c-\":[\"处理行为\"],\"L0Zj1K\":[\"处理管道配置\"],\"L2_QkP\":[\"处理任务创建成功\"],\"L3fUx7\":[\"错误：无法加载预设详情。\"],\"L4RAy4\":[\"CDN黑名单\"],\"L4c5Kt\":[\"-c copy\"],\"L5RWy_\":[\"高清缩略图\"],\"L5WboT\":[\"HTTP 版本偏好\"],\"LBEZuS\":[\"引擎列表\"],\"LBjZN4\":[\"分 (00-59)\"],\"LCcfAH\":[\"加载引擎失败：\",[\"0\"]],\"LKPR6G\":[\"播放列表\"],\"LLAa_9\":[\"可选\"],\"LMn91f\":[\"优先使用清单\"],\"LNcpxP\":[\"Mozilla/5.0...\"],\"LPJyAY\":[\"如何将视频文件与 .ass 字幕配对\"],\"LS_ord\":[\"例如 128k、320k\"],\"LTNjeK\":[\"容器\"],\"LXMVBR\":[\"处理成功后删除源文件\"],\"L_OaTA\":[\"云端归档\"],\"L_XFOI\":[\"处理器详细设置\"],\"LbS6Kh\":[\"最低优先级\"],\"Le70b5\":[\"更多页面\"],\"LhOLxv\":[\"在阻塞线程池中执行解密，避免卡住异步任务。\"],\"LiJMls\":[\"搜索平台...\"],\"Lk1OGO\":[\"离线检测\"],\"LoIUGx\":[\"支持占位符：\",[\"PLACEHOLDER_TOKENS\"],\"，以及 %Y/%m/%d 等时间占位符。\"],\"LovaQl\":[\"元数据获取的最大重试次数。\"],\"LqH9mJ\":[\"后端持久化的最近通知事件\"],\"LubEtv\":[\"编辑预设：\",[\"0\"]],\"M0uszl\":[\"预设创建成功\"],\"M3QKhO\":[\"平台专属配置\"],\"M6bo58\":[\"C:path1.flv,C:path2.flv\"],\"M73whl\":[\"上下文\"],\"MA1f6y\":[\"高+\"],\"MAKqi9\":[\"例如 10M\"],\"MDzSyV\":[\"管理凭据和会话 Cookie。\"],\"MF-nAj\":[\"若输出文件已存在则替换\"],\"MHrjPM\":[\"标题\"],\"MItgvI\":[\"默认引擎\"],\"MMoZg-\":[\"流映射 (-map)\"],\"MNP_Hr\":[\"已处理分段 TTL（毫秒）\"],\"MQpz7h\":[\"成功添加 \",[\"0\"],\" 个流\"],\"MS1IQE\":[\"双格式输出\"],\"MUbeuH\":[\"HLS 流修复管道\"],\"MUwvHM\":[\"限制与校验\"],\"MXuUDu\":[\"当“上传所有文件”启用时忽略。\"],\"MYKXvL\":[\"未知引擎 ID：\",[\"engineId\"]],\"Mbxk4g\":[\"目标比特率\"],\"MhA4UF\":[\"克隆预设\"],\"Mi9SD3\":[\"允许应用内播放器代理位于私有或本地网络上的源（局域网转推、摄像头、tailnet 地址）。除非你从本地源拉流，否则请保持关闭：它会为任何已登录用户重新开放对内部地址的请求。\"],\"Mj31d8\":[\"覆盖全局默认值。\"],\"Ml0gf8\":[\"多媒体归档\"],\"MmFX21\":[\"条已暂停\"],\"MpHt5u\":[\"添加主播\"],\"Mpp8ys\":[\"处理管道\"],\"MqP8N6\":[\"强制原画质量\"],\"MqVdIO\":[\"1（最佳）- 31（最差）\"],\"MqWOyf\":[\"校验数帮助\"],\"Mr5Sgb\":[\"提取 AAC（256kbps）音频轨道，质量高且兼容性好。\"],\"MucYW8\":[\"测试通知已发送\"],\"MvqJb2\":[\"创建引擎\"],\"N1_4qm\":[\"当处理任务成功完成时触发。\"],\"N2fC4V\":[\"分段下载的最大并发数。\"],\"N2twk8\":[\"日期占位符锚点\"],\"N40H-G\":[\"全部\"],\"N4UV0r\":[\"主播在平台上已不存在\"],\"N5gYIZ\":[\"用于实时应用的低延迟 GPU 加速 H.264 编码。编码延迟极低。\"],\"N5vGcw\":[\"输入你的凭据以登录账户\"],\"N683xA\":[\"最大比特率（bps）\"],\"N7OGFm\":[\"请重启系统以确保所有配置更改已完全生效。\"],\"N7k2OK\":[\"例如 /output/processed/\"],\"N8YRwn\":[\"例如 黑名单CDN\"],\"NAhOWx\":[\"致命：\"],\"NBO4gh\":[\"并发与性能\"],\"NBdIgR\":[\"评论\"],\"NEnLep\":[\"全局默认\"],\"NFIOKv\":[\"高级选项\"],\"NF_R69\":[\"未找到处理任务\"],\"NGO2th\":[\"所有模板\"],\"NHNfp3\":[\"引擎名称\"],\"NHyRGf\":[\"不区分大小写\"],\"NIBH_E\":[\"加载处理任务失败\"],\"NJevqq\":[\"校验\"],\"NKYo1x\":[\"ASS 字幕内嵌\"],\"NMhr2I\":[\"用于订阅者专享和高质量流的 Twitch OAuth 令牌。\"],\"NPY5Jv\":[\"配置新的录制工具。\"],\"NQSiYb\":[\"按名称或描述搜索...\"],\"NQas2m\":[\"当视频和弹幕分段都可用时运行。需要启用“录制弹幕”。\"],\"NRL17y\":[\"主播检查历史\"],\"NUrY9o\":[\"分类\"],\"NV_uNb\":[\"服务器未配置 Web 推送\"],\"NaT0vv\":[\"用于直播流提取的 API 协议。WUP 是 PC 客户端的标准协议。请注意，WUP 和 MP 仅适用于数字房间 ID。\"],\"NeoigG\":[\"文件大小阈值（--multi-thread-cutoff）。\"],\"NggFDy\":[\"系统总负载\"],\"NhNdzH\":[\"在录制会话结束且所有单个分段管道均已完成后运行一次。适用于合并所有分段、最终通知或清理等全局操作。\"],\"Nhf7R9\":[\"Telegram 桌面版目录\"],\"Ni3LjM\":[\"分辨率变化\"],\"NkYQEG\":[\"i\"],\"NmgWBj\":[\"删除工作流失败：\",[\"0\"]],\"NnH3pK\":[\"测试\"],\"NoNwIX\":[\"未激活\"],\"NomFnG\":[\"未检测到更改\"],\"NrNA7z\":[\"创建你的第一个预设，用于为处理管道定义可复用的处理器配置。\"],\"NstKgB\":[\"最佳\"],\"Nu4DdT\":[\"同步\"],\"Nu4oKW\":[\"描述\"],\"Nw_bTM\":[\"进行中\"],\"NxVfIs\":[\"总体健康\"],\"Nz-2uW\":[\"录制引擎\"],\"Nzwli2\":[\"基础\"],\"O3-SAG\":[\"录制将在计划允许时开始\"],\"O7sQsS\":[\"即使应用已最小化，操作系统仍会显示桌面通知。\"],\"O92EVI\":[\"验证输出\"],\"OD_lHs\":[\"添加覆盖项以自定义特定平台的行为。\"],\"OGA8CA\":[\"重封装/转码\"],\"OGryJ9\":[\"移动文件\"],\"OH6m8Z\":[\"没有其他可依赖的步骤。\"],\"OHthyf\":[\"已拒绝\"],\"OIx5Vo\":[\"此配置模板的基本信息。\"],\"OJx3wK\":[\"不可用\"],\"OL5-bu\":[\"/usr/share/fonts\"],\"ONWvwQ\":[\"上传\"],\"ONchxy\":[\"总计\"],\"OO3KPE\":[\"无配置。\"],\"OOXKcO\":[\"选择会为 <0>\",[\"0\"],\"</0> 触发通知的事件。\"],\"OR475H\":[\"网络\"],\"ORCmn3\":[\"头像 URL（可选）\"],\"OS845W\":[\"为此配置模板提供一个唯一的名称。\"],\"OSkKYq\":[\"平台配置\"],\"OUlu9A\":[\"采样率（Hz）\"],\"OXyghd\":[\"加载处理任务列表失败：\",[\"0\"]],\"Oc-UBb\":[\"微调品牌和表面颜色。\"],\"OfhWJH\":[\"重置\"],\"Oju_Jw\":[\"替换输出文件\"],\"OkJ0bQ\":[\"TDL 状态未知\",[\"version\"]],\"Okz6xA\":[\"不在计划时间内\"],\"OlFEI3\":[\"满足任一条件后跳过\"],\"OlXThP\":[\"带宽\"],\"Om3XSw\":[\"API 设置\"],\"Oq4ArH\":[\"未识别的事件类型。\"],\"OqVga-\":[\"此操作开销较低，默认值 8 通常已足够。如果远端较慢且有大量小文件，可适当调高。\"],\"OrxruT\":[\"复制目录\"],\"Ou1XUj\":[\"校验失败\"],\"OurehU\":[\"检测到平台：\",[\"detectedPlatform\"]],\"OvTPz2\":[\"选择操作\"],\"Ovx4g5\":[\"https://twitch.tv/...\"],\"OxBy4W\":[\"出错时继续\"],\"P-1VRa\":[\"平台配置可用时将显示在此处。\"],\"P4KIZR\":[\"重封装为 MKV\"],\"P5eNZ-\":[\"最后错误日志\"],\"P6F38F\":[\"订阅\"],\"P8fBlG\":[\"认证\"],\"PE3gQR\":[\"CDN\"],\"PFFhCT\":[\"高度\"],\"PFgYun\":[\"无标题直播\"],\"PH-ukv\":[\"-reconnect 1\"],\"PIjwVk\":[\"生成全高清缩略图（宽 1280px），适用于现代显示器与播放器。\"],\"PJDaJr\":[\"选择此过滤器生效的日期。\"],\"PJf7YE\":[\"在: \",[\"0\"],\" 之后\"],\"PMM5g4\":[\"自定义 FFmpeg\"],\"PRzc1D\":[\"上传并删除\"],\"PTBQLa\":[\"创建目录\"],\"PTI2EV\":[\"（删除源文件）\"],\"PUbRZA\":[\"最大重试尝试次数。\"],\"PVRSCC\":[\"正在加载预设...\"],\"PVnlzh\":[\"添加步骤\"],\"Pa-XIK\":[\"准备观看\"],\"PaO3bE\":[\"该实体不支持会话完成处理管道。\"],\"PaQ3df\":[\"启用\"],\"Pcymhf\":[\"等待时长\"],\"PezyAA\":[\"更新渠道\"],\"PfW3ld\":[\"在持续的下载进度确认流状态健康之前，暂时的监控错误会继续保留。\"],\"PfWJSn\":[\"校验数\"],\"Pfx_nq\":[\"组件状态\"],\"PhEJJc\":[\"命令执行后扫描新文件的目录\"],\"Pi72nP\":[\"主播离线\"],\"PiH3UR\":[\"已复制！\"],\"PkUJDM\":[\"Gotify 应用令牌\"],\"Pn2B7_\":[\"当前密码\"],\"Poh-g6\":[\"创建处理管道\"],\"Pw01g0\":[\"隐藏密码\"],\"Pwqkdw\":[\"加载中…\"],\"PzFzS-\":[\"添加过滤器\"],\"Q-6haG\":[\"未找到凭据。\"],\"Q-pr7E\":[\"弹幕统计\"],\"Q0LExG\":[\"登录会话已取消\"],\"Q3D3r6\":[\"libx264\"],\"Q3TXK1\":[\"连续失败\"],\"Q5duaX\":[\"悬浮\"],\"Q6hhn8\":[\"偏好设置\"],\"Q7Xlbh\":[\"斗鱼超清画质值\"],\"QBhhb7\":[\"复制文件\"],\"QCzB9v\":[\"输入路径\"],\"QDaWgK\":[\"DanmakuFactory\"],\"QEazml\":[\"删除所选\"],\"QFO8CU\":[\"使用 DanmakuFactory 将弹幕 XML（Bilibili 格式）转换为 .ass 字幕。支持清单感知和批量处理。\"],\"QHcLEN\":[\"已连接\"],\"QHhZeE\":[\"模板创建成功\"],\"QMb3Xr\":[\"指数退避的倍率。\"],\"QRAWTO\":[\"表单\"],\"QWdKwH\":[\"移动\"],\"Q_OjVh\":[\"主播直播时检测流结束的检查间隔。\"],\"Q_vbsf\":[\"弹幕转 ASS\"],\"Qc-YIj\":[\"为特定平台覆盖配置（路径、延迟等）。\"],\"QcH0aB\":[\"无损分割\"],\"QglPWi\":[[\"1\"],\" 时为 \",[\"0\"]],\"Qj-jxB\":[\"退出沉浸模式\"],\"QkHwaF\":[\"已为此浏览器启用 Web 推送\"],\"QlT4B5\":[\"最近录制会话\"],\"QmWJ8K\":[\"587\"],\"QqGYdP\":[\"选择默认引擎\"],\"QrXc9O\":[\"禁用所选\"],\"Qttcbs\":[\"触发检查失败\"],\"QvdYlQ\":[\"字体目录\"],\"QxKlNl\":[\"GPU 标准\"],\"QyYvAV\":[\"精确时间表\"],\"R3w8ra\":[\"未设置关键字\"],\"R7mB1g\":[\"预览不可用\"],\"R8WLd-\":[\"强制 IPv4（默认：关闭）\"],\"RAHVXV\":[\"如果你的账户启用了 Telegram 两步验证，请使用桌面登录（`-T desktop`）或在终端中本地运行 `tdl login`。\"],\"RAMost\":[\"未找到媒体文件\"],\"RDVO7W\":[\"跳转到第 \",[\"p\"],\" 页\"],\"RDf8Wy\":[\"GPU 加速后处理：NVENC H.264 编码和缩略图生成。\"],\"RJEhaJ\":[\"https://lb-eu.cdn-perfprod.com\"],\"RMguW7\":[\"平台配置更新成功\"],\"RP7-1c\":[\"Windows 控制台错误\"],\"RQI0-M\":[\"输出检测\"],\"RRWxve\":[\"用于流认证签名的客户端平台类型。不同平台使用不同的签名方式。\"],\"RRrXGo\":[\"请输入你的新密码信息\"],\"RS0o7b\":[\"状态\"],\"RS9BzM\":[\"在提取检查期间，将互动玩法视为离线平台。\"],\"RTR7ET\":[\"FLV 解析器\"],\"Ralayn\":[\"添加自定义标签\"],\"Ratxqc\":[\"开始桌面登录\"],\"RbevHh\":[\"批量解析失败：\",[\"0\"]],\"RclaHZ\":[\"当视频分段及其对应的弹幕分段都可用时运行。\"],\"RdUvEI\":[\"文件名模板\"],\"RdhLi5\":[\"待清除错误\"],\"RehBv_\":[\"为此主播覆盖全局默认值。\"],\"RhkH8I\":[\"我的渠道\"],\"RkoA4Q\":[\"工作目录\"],\"RmEeKl\":[\"字节\"],\"RoOfVq\":[\"日期范围：\"],\"RojhAf\":[\"用户名（可选）\"],\"RqGEko\":[\"分段\"],\"Rsjgm0\":[\"实验性\"],\"Rti4g1\":[\"用于录制的底层工具。\"],\"Ruqy5-\":[\"如果直播间被主播设置了密码保护，则为必填。\"],\"RxzN1M\":[\"启用\"],\"S-Irrb\":[\"更新现有项目并创建新项目，不会删除任何内容。\"],\"S-gCuS\":[\"仅 HTTP/2\"],\"S0kLOH\":[\"ID\"],\"S1Avy-\":[\"此会话的弹幕统计暂不可用。\"],\"S2X4xO\":[\"未生成输出\"],\"S32Mbj\":[\"未找到平台\"],\"S34mdL\":[\"密码锁房的默认密码。可在主播 URL 上用 ?pwd= 覆盖。\"],\"S4s1m4\":[\"更新预设失败：\",[\"0\"]],\"S5wLae\":[\"输入 URL 以解析并播放流\"],\"SCZJhh\":[\"音频比特率\"],\"SE58Y_\":[\"管理系统通知发送到哪里\"],\"SG6r-s\":[\"密码/两步验证被阻止\"],\"SHEb8h\":[\"未找到预设\"],\"SHZOTz\":[\"更新主播失败\"],\"SJV-d4\":[\"使用直播开始时间可让跨午夜直播保存在同一个日期文件夹中，并将同一会话的所有分段归组到一起。\"],\"SJd-31\":[\"使用系统代理\"],\"SJmm2B\":[\"使用 NVIDIA NVENC 的快速 GPU 加速 AV1 编码（CQ 28）。需要 RTX 4000+ 系列显卡。\"],\"STFGOH\":[\"HTTP 中间件\"],\"SWoipt\":[\"依赖于（前辈节点）\"],\"SYKx8m\":[\"生成新二维码\"],\"SZIVAR\":[\"下载管理\"],\"SZw9tS\":[\"查看详情\"],\"S_H6kY\":[\"强制 IPv6（默认：关闭）\"],\"S_tge3\":[\"输出路径、模板与格式。\"],\"Sel_id\":[\"配对分段\"],\"ShPPm7\":[\"此会话未记录任何标题变更或生命周期事件。\"],\"SjnTbJ\":[\"高级配置\"],\"Sl7mrQ\":[\"按大小分割。\"],\"SlfejT\":[\"错误\"],\"Svkela\":[\"上一页\"],\"SwbFPL\":[\"streamlink\"],\"SwdouK\":[\"NVENC H.264 低延迟\"],\"SxJsAt\":[\"选择你偏好的配色方案。\"],\"Sxm8rQ\":[\"用户\"],\"T1SAsR\":[\"会话缩略图\"],\"T3rjBy\":[\"处理录制内容后，处理任务将显示在此处。\"],\"T4sjpn\":[\"合并更改\"],\"T5l00f\":[\"工作流\"],\"T6F_S1\":[\"处理队列已满时发出严重警报。\"],\"T7yhkH\":[\"输出格式\"],\"T8_uFg\":[\"设置已针对该平台优化。\"],\"T8drou\":[\"系统健康\"],\"T91vKp\":[\"播放\"],\"TAsVLX\":[\"创建模板失败：\",[\"0\"]],\"TB_URP\":[\"选择日期锚点\"],\"TCURBK\":[\"（保留源文件）\"],\"TH-C6m\":[\"成功删除 \",[\"0\"],\" 个会话\"],\"TJBHlP\":[\"标准\"],\"TKiEkr\":[\"选择当前页\"],\"TLZ7Zy\":[\"多线程上传仅对大文件有效；对小文件而言，分块开销会超过其收益。\"],\"TP4vdF\":[\"--hls-live-edge 3\"],\"TP9_K5\":[\"令牌\"],\"TXe7lI\":[\"查看详细日志与输出\"],\"TYVgbP\":[\"包含\"],\"TYVyHm\":[\"未分配模板\"],\"T_R-Qz\":[\"主要\"],\"TaKLqK\":[\"排除模式\"],\"TaeloW\":[\"任意优先级\"],\"TduT07\":[\"正在启动 tdl 进程...\"],\"Tfm8LB\":[\"匹配分辨率\"],\"TiE1q0\":[\"点播缺口策略\"],\"TjrbDj\":[\"运行时间\"],\"Tkh4vv\":[\"最高质量缩略图\"],\"TlWFTu\":[\"正在下载 \",[\"0\"]],\"Tm4H6N\":[\"输入内容...\"],\"TnMphH\":[\"轻松自动化您的直播录制。\"],\"ToM7t8\":[\"优先选择特定 CDN 提供商。按 Enter 添加。\"],\"ToW2mM\":[\"删除引擎？\"],\"Tof7pX\":[\"处理任务\"],\"Tu5he7\":[\"将互动玩法直播视为离线。\"],\"Tu7ijM\":[\"将“TDL 二进制路径”设置为你的 `tdl` 可执行文件，或确保 `tdl` 在 PATH 中。\"],\"Tu9eY0\":[\"生效日期\"],\"TxOTCX\":[\"用于桌面登录（备选）；指向包含 `tdata` 的 Telegram Desktop 文件夹。\"],\"TymLa2\":[\"数值越高，在高速网络下越快，但对远端和本机 CPU 的压力也更大。仅在网络与服务方能承受时才建议调高。\"],\"Tz0i8g\":[\"设置\"],\"U1Im-N\":[\"模板名称\"],\"U5tR0I\":[\"录制发生错误时运行。\"],\"U62Eow\":[\"在此终端中完成 Telegram 登录过程。\"],\"U80ejq\":[\"连续失败\"],\"U9NWQ6\":[\"整体系统状态\"],\"UC17hj\":[\"密码锁房的默认密码（可在主播 URL 上用 ?pwd= 覆盖）。\"],\"UCFp-L\":[\"正在检查流状态...\"],\"UCRwk1\":[\"/path/to/rclone.conf\"],\"UGLwm7\":[\"选择优先级\"],\"UHgtCG\":[\"未找到主播\"],\"UPqS9C\":[\"健康\"],\"UPyNTs\":[\"MB\"],\"URWWZg\":[\"例如 高质量 Streamlink\"],\"UWuUpd\":[\"在生命周期事件上执行 Shell 命令。命令在系统 Shell 中运行。\"],\"UYX0mr\":[\"尝试调整搜索或过滤器，或添加新主播以开始监控。\"],\"UZREi-\":[\"模板\"],\"UZc_Dd\":[\"暂无发言用户数据\"],\"UaBHAx\":[\"哔哩哔哩扫码登录\"],\"UatJ7w\":[\"退出选择模式\"],\"UbRKMZ\":[\"待处理\"],\"UcB-qb\":[\"删除所选主播？\"],\"UcL3cf\":[\"接受无效证书（默认：关闭）\"],\"UhCJxj\":[\"处理器\"],\"UicgBF\":[\"双击以编辑项目。\"],\"Uj7KrY\":[\"使用二维码登录\"],\"UlKSMd\":[\"左侧\"],\"Un7b8x\":[\"高频词\"],\"Uns82o\":[\"需要重启\"],\"UnuCpk\":[\"执行超时\"],\"Ur7o1U\":[\"本地归档\"],\"Urp3J5\":[\"最大程度压缩以尽量降低存储占用，然后删除原始文件。\"],\"Uu2O-E\":[\"当视频和弹幕分段都可用时运行。\"],\"UwlIzL\":[\"分配模板\"],\"UxK12K\":[\"录制内容\"],\"UxpxnD\":[\"文件操作\"],\"UyWBj5\":[\"不健康\"],\"Uypo6H\":[\"取消 IO 密集型处理器前的超时时间。\"],\"V3Lf0z\":[\"上传 (Rclone)\"],\"V50IgX\":[\"加载工作流列表失败：\",[\"0\"]],\"V7Msqg\":[\"预设\"],\"V8SjEq\":[\"包含关键字\"],\"VAn9oA\":[\"GPU 不可用时(通常由 NVIDIA Container Toolkit 在 cgroup v2 上的已知问题导致),NVENC 转封装/转码任务会失败,此时发出严重告警。\"],\"VFX661\":[\"达到时长后跳过\"],\"VGt9mz\":[\"裁剪与时间\"],\"VJScHU\":[\"原因\"],\"VJw9N6\":[\"选择会话\"],\"VLfBk9\":[\"恢复中\"],\"VPPVAx\":[\"锁定文件重试次数\"],\"VPqi9P\":[\"未配置任何参数。\"],\"VQ4EeT\":[\"此预设的可选描述\"],\"VU-UYl\":[\"渠道已更新\"],\"VUURcc\":[\"生效范围\"],\"VZobzV\":[\"二维码已过期\"],\"Ve9s51\":[\"该实体不支持配对分段处理管道。\"],\"VeOdql\":[\"登录 Telegram\"],\"Vflq7Z\":[\"该主播正在直播，但全局下载并发已用满。一旦有空闲槽位，录制和弹幕采集将自动开始。\"],\"Vg88qu\":[\"运行带有占位符的自定义 Shell 命令（例如 \",[\"input\"],\", \",[\"inputs_json\"],\", \",[\"streamer\"],\", %Y%m%d）。\"],\"VhBoUx\":[\"已加载模板：\",[\"0\"]],\"VhMDMg\":[\"修改密码\"],\"ViIBwn\":[\"频道1,频道2\"],\"Vk_Ar1\":[\"更多筛选\"],\"VlEnCC\":[\"归档格式\"],\"VoevdT\":[\"输出文件夹\"],\"VupaNT\":[\"标准 Cron 表达式（秒 分 时 日 月 星期）。\"],\"VvhIcU\":[\"未添加自定义标签\"],\"Vw8l6h\":[\"发生错误\"],\"VzJOiq\":[\"处理管道管理器\"],\"W-0nOp\":[\"解密下放到线程池（默认：开）\"],\"W0g6Ji\":[\"元数据变量\"],\"W0omwk\":[\"逐步设计你的自动化处理管道\"],\"W1jNHq\":[\"使用哔哩哔哩移动端 App 扫码\"],\"W5FKSG\":[\"录制完成时\"],\"W743Px\":[\"过滤与行为\"],\"W7R6SY\":[\"应用令牌\"],\"WCNCz3\":[\"123456:ABC-DEF...\"],\"WEYdDv\":[\"推荐\"],\"WEmAU3\":[\"已确认离线\"],\"WIC5BC\":[\"搜索预设...\"],\"WJAaWC\":[\"每次采样目标弹幕数\"],\"WJXa70\":[\"Unix 时间戳\"],\"WOfIql\":[\"创建新预设\"],\"WRQtA_\":[\"例如 250M\"],\"WSlv-9\":[\"选择配置\"],\"WSxWtw\":[\"启用 Web 推送失败\"],\"WWCeOb\":[\"添加模块...\"],\"WXZiyd\":[\"超快 H.264 编码（CRF 26），用于快速分享。\"],\"W_-iQs\":[\"获取器\"],\"W_geaq\":[\"选择登录类型\"],\"WcOY3Y\":[\"机器人令牌\"],\"WcgzjF\":[\"name@example.com\"],\"WdAjAz\":[\"启用代理\"],\"Weq9zb\":[\"常规\"],\"WgACNk\":[\"移动文件\"],\"WgCCHx\":[\"录制成功完成时运行。\"],\"WhUS54\":[\"ZIP 归档\"],\"WhgehB\":[\"录制时长\"],\"X-oEI0\":[\"正在解析流...\"],\"X56hfZ\":[\"未找到平台配置，请先创建。\"],\"X5P6yv\":[\"最近更新\"],\"XB0Vn9\":[\"未找到归档\"],\"XC33bC\":[\"面向长期存储优化的 H.264 中等压缩（CRF 23）。\"],\"XEH5pP\":[\"最大停顿时间（毫秒）\"],\"XElSLJ\":[\"登录参数\"],\"XEpExY\":[\"第 N 个输入：\",[\"input0\"],\", \",[\"input1\"],\"...\"],\"XF7Hlq\":[\"重新开始\"],\"XJumtU\":[\"处理管道步骤可复用的处理器配置\"],\"XOxZT4\":[\"正在登录...\"],\"XPG0DN\":[\"新模板名称\"],\"XQaZzY\":[\"TPS 突发\"],\"XS1iGf\":[\"保留目录结构\"],\"XTz5Sa\":[\"rclone 默认值：4\"],\"XU5AOg\":[\"输入\"],\"XVCljg\":[\"多线程复制\"],\"XWgxXq\":[\"专辑\"],\"XY_iWu\":[\"网络与内容\"],\"X_MmPT\":[\"并行处理视频并提取播客音频，然后同时上传两者。\"],\"Xit5Tv\":[\"音频属性\"],\"XjbMaz\":[\"返回处理任务列表\"],\"XjoBtr\":[\"过滤原因：\"],\"XmKjfh\":[\"录制\"],\"XvPVPo\":[\"配置并启动手动处理管道。\"],\"XvjC4F\":[\"保存中...\"],\"Xy0Hxb\":[\"支持 Rust 正则语法。\"],\"Y27roI\":[\"自定义画质值\"],\"YDVUVl\":[\"事件类型\"],\"YMf077\":[\"OAuth 令牌\"],\"YPcVP2\":[\"暂无预设\"],\"YVNfpq\":[\"（镜像源端）\"],\"YXjgxA\":[\"系统级偏好设置\"],\"Ya3PlJ\":[\"需要权限\"],\"YdqAGb\":[\"查看弹幕\"],\"YjhKI5\":[\"正在克隆...\"],\"YqG2MJ\":[\"设置录制限制。\"],\"Ysuy2A\":[\"模板克隆成功\"],\"Yu2FvR\":[\"这将永久删除预设“\",[\"0\"],\"”。若不更新，使用该预设的处理管道可能会失败。\"],\"YuTKa4\":[\"下载失败\"],\"Yy3BPK\":[\"使用 HEVC 进行最高质量压缩，然后上传到云存储。\"],\"Z0iPqp\":[\"配置录制引擎参数\"],\"Z3KFHf\":[\"退避倍率\"],\"Z5vYTW\":[\"管理应用配置。\"],\"ZAVcwe\":[\"正在修改密码...\"],\"ZBMTd_\":[\"未找到匹配的事件\"],\"ZF0SVM\":[\"每个文件的流数（--multi-thread-streams）。\"],\"ZKI9Rd\":[\"启用高级 HLS 分段重建\"],\"ZMViOQ\":[\"总大小：\"],\"ZNKcYa\":[\"所有级别\"],\"ZNg9mI\":[\"重置密码\"],\"ZOK2-a\":[\"type=bolt,path=/data/tdl\"],\"ZOtn9F\":[[\"minutes\"],\" 分钟\"],\"ZTc3M9\":[\"下载断开\"],\"ZWRt1W\":[\"输出路径\"],\"ZWZjuT\":[\"样式\"],\"ZYn2Q_\":[\"创建过滤器\"],\"ZYnwzF\":[\"已断开\"],\"Za9T3e\":[\"检查耗时\"],\"Zag-nw\":[\"Rclone 传输\"],\"ZfRjhV\":[\"最大 CPU 任务数\"],\"Zi7dyz\":[\"脚本\"],\"Zl_Fwr\":[\"管理专门的提取和识别选项。\"],\"ZlzjXV\":[\"透传输入\"],\"Zrh7RT\":[\"更新平台失败：\",[\"0\"]],\"Zv4awZ\":[\"128k\"],\"Zw6LWb\":[\"删除处理任务失败\"],\"ZyqOPH\":[\"凭据来源状态\"],\"Zz_XpE\":[\"按主播\"],\"Zza1dz\":[\"NVENC HEVC 快速\"],\"_-DHD0\":[\"配置一个新的主播以进行监控和录制。\"],\"_0ftcA\":[\"加载录制会话失败\"],\"_73JEY\":[\"TPS 突发帮助\"],\"_Bh-7r\":[\"性能\"],\"_BzI2m\":[[\"0\",\"plural\",{\"other\":[\"#\",\" 个错误\"]}]],\"_Ct04k\":[\"生成最高质量缩略图（宽 1920px），保留完整 1080p 细节。\"],\"_GsGHq\":[\"任务创建时间（默认）\"],\"_HgF9q\":[\"排序方式\"],\"_I6a4I\":[\"未配置平台\"],\"_K2CvV\":[\"模板\"],\"_KseE7\":[\"删除文件\"],\"_M7YFj\":[\"质量（qscale）\"],\"_NgV8X\":[\"当处理任务被取消时触发。\"],\"_O8edb\":[\"上传参数\"],\"_PAAt2\":[\"0 = 不限\"],\"_QU1-7\":[\"登录类型\"],\"_Qf5fl\":[\"优先级过滤器\"],\"_SvQT0\":[\"下载日志失败\"],\"_TEOcd\":[\"预设\"],\"_VaoHF\":[\"处理任务已取消\"],\"__tkD5\":[\"分段 TTL（毫秒）\"],\"_bBHGW\":[\"删除处理管道？\"],\"_bM9cT\":[\"GPU 高质量归档\"],\"_cnOWE\":[\"向 studio 请求附带与网页类似的完整性 token。默认开启；仅在你所在网络签发失败时关闭。\"],\"_gYAMa\":[\"聊天 ID\"],\"_gn6RG\":[\"严重错误\"],\"_hKQcr\":[[\"seconds\"],\" 秒\"],\"_jQctM\":[\"收件人\"],\"_kwOBw\":[\"任务超时\"],\"_l-j6K\":[\"日志下载成功\"],\"_l7qFE\":[\"录制已开始\"],\"_oqig9\":[[\"hours\"],\" 小时\"],\"_p1aNb\":[\"加载工作流失败\"],\"_rTz0M\":[\"音频\"],\"_srpvQ\":[\"删除渠道失败\"],\"_tQOXN\":[\"正在录制\"],\"_w3Fy0\":[\"定义此步骤如何标识自身以及如何连接到其他步骤。\"],\"_wFMEI\":[\"待确认\"],\"_xVC1p\":[\"TDL 状态\"],\"_xfOhY\":[\"媒体时间线\"],\"_zg39j\":[\"演练模式\"],\"a0w0iQ\":[\"字幕\"],\"a4Dp3l\":[\"渠道已删除\"],\"a9L56c\":[\"选择行为\"],\"aDPadU\":[\"当创建新的文件分段时触发。\"],\"aFWU1n\":[\"宽度\"],\"aFk-Ho\":[\"该主播当前正在直播，但不在你配置的录制时间表内。\"],\"aH0Q8c\":[\"选择策略\"],\"aIUW65\":[\"最低码率\"],\"aS09EA\":[\"当直播结束时接收通知。\"],\"aS11pb\":[\"选择要添加到处理管道的处理任务或工作流。\"],\"aVJmtU\":[\"已提取流\"],\"aZ2Kcr\":[\"配置接收通知的位置和方式。\"],\"aZUYz0\":[\"使用 NVIDIA NVENC 的快速 GPU 加速 HEVC/H.265 编码（CQ 24）。文件比 H.264 更小。\"],\"acyWOE\":[\"超时（秒）\"],\"afHZ2T\":[\"会话已结束\"],\"ailPEn\":[\"事件类型\"],\"aj9IS5\":[\"并发\"],\"akGgwH\":[\"已选择的流\"],\"akShFD\":[\"清理\"],\"amsJwt\":[\"当主播开播时接收通知。\"],\"aoDNXt\":[\"操作模式\"],\"atAJGZ\":[\"平台\"],\"atJjwW\":[\"例如 echo \",[\"input\"],\" > \",[\"output\"]],\"azIGX7\":[\"覆盖现有文件\"],\"azuNEn\":[\"该处理管道已结束或已取消\"],\"b21pJB\":[\"播放列表 TTL（毫秒）\"],\"b24kPi\":[\"队列\"],\"b2MuzC\":[\"截图时间偏移\"],\"b2Nri6\":[\"连接池空闲超时（毫秒）\"],\"b7Incv\":[\"操作类型\"],\"b7wV8U\":[\"MP\"],\"b92aqq\":[\"正在加载历史日志...\"],\"bAaOjo\":[\"采集分离的流数据。\"],\"bFAwGy\":[\"选择预设或输入斗鱼画质值。仅音频会请求不含视频的 AAC。\"],\"bGboGD\":[\"第 N 个输出：\",[\"output0\"],\", \",[\"output1\"],\"...\"],\"bLK9zG\":[\"保护设置\"],\"bMD8SW\":[\"自动刷新\"],\"bOj5u_\":[\"未添加步骤\"],\"bRVIqV\":[\"抑制冗余头部以减少播放器微卡顿\"],\"bT3NnO\":[\"处理任务完成后，媒体文件将显示在此处。\"],\"bYkKu9\":[\"录制开始时运行。\"],\"bZ_nY3\":[\"Twitch 代理播放列表\"],\"bagRV4\":[\"添加新主播\"],\"bb6TRf\":[\"最小分段大小\"],\"bcfSrW\":[\"数据库查询\"],\"bd8uQe\":[\"FFmpeg 视频滤镜\"],\"bdSNjC\":[\"暂时暂停\"],\"be6yVE\":[\"例如 我喜欢的主播\"],\"bex7vZ\":[\"导入配置失败\"],\"bgAdR4\":[\"允许的文件扩展名列表（不含点号）。\"],\"bgdEys\":[\"此浏览器不支持 Web 推送\"],\"bjp1xg\":[\"圆角半径\"],\"bkLoJJ\":[\"秒 (00-59)\"],\"bkZWD4\":[\"凭据刷新成功\"],\"bqYxTf\":[\"音频提取\"],\"bql7qo\":[\"回跳阈值\"],\"bwRvnp\":[\"操作\"],\"bzOX-6\":[\"平台 URL 提取器\"],\"c0LHG_\":[\"管道任务超时\"],\"c2Qn8C\":[\"选择预设\"],\"c3b0B0\":[\"开始使用\"],\"c5Xt89\":[\"名称 Z-A\"],\"c5qkaI\":[\"已停止监控\"],\"c6pG_r\":[\"引擎创建成功\"],\"c7Ho94\":[\"所有输出的 JSON 数组\"],\"c8-mAV\":[\"分段超时（毫秒）\"],\"c8iRL_\":[\"筛选并排序主播\"],\"c8nkFX\":[\"等待 ffmpeg 完成文件写入的时间，超时后将强制终止进程\"],\"cCbxO-\":[\"提取 MP3（192kbps）音频轨道，适合播客与音乐。\"],\"cCfxH1\":[\"下载中...\"],\"cDD5xY\":[\"最后错误\"],\"cDR51K\":[\"默认处理管道流程。为新的处理任务配置处理器顺序。\"],\"cEHxsl\":[\"音频编码变化\"],\"cH5kXP\":[\"现在\"],\"cLVp5Z\":[\"日志文件\"],\"cNC2t_\":[\"将文件复制到其他位置，保留原始文件。\"],\"cO9-2L\":[\"禁用\"],\"cP_zDS\":[\"对远端的 API 调用进行限速。当服务方在高负载下返回 429 \\\"请求过多\\\" 时较为有用。\"],\"cRxiHL\":[\"导入成功\"],\"cS8JKn\":[\"删除会话失败\"],\"cSCbv3\":[\"测试版\"],\"cSadgt\":[\"删除源文件\"],\"cSev-j\":[\"过滤器\"],\"cVEhWm\":[\"解析模式\"],\"cX2i4T\":[\"第一个输入文件路径\"],\"cXFXEn\":[\"仅音频\"],\"cXKoLz\":[\"保存工作流\"],\"cd0jIM\":[\"处理任务详情\"],\"ce5XJx\":[\"暂无正在录制\"],\"cgDZLv\":[\"当视频和弹幕可用时触发\"],\"ci-Ad9\":[\"快速分享\"],\"ckH3fT\":[\"就绪\"],\"cklVjM\":[\"时间线\"],\"ckoeXQ\":[\"文件名模式（例如 thumb.jpg）\"],\"cl-3tt\":[\"流详情\"],\"cnGeoo\":[\"删除\"],\"cncExx\":[\"配置已成功处理。以下是更改摘要：\"],\"cp8Gv3\":[\"用于排除文件的正则模式。将针对完整路径和文件名进行匹配。\"],\"cpbupq\":[\"高质量归档\"],\"cqFv88\":[\"排除\"],\"cs0tlO\":[\"导入策略\"],\"cupeHb\":[\"例如：process-video\"],\"cxkEMX\":[\"已处理 \",[\"0\"],\" 个主播；\",[\"1\"],\" 个失败\"],\"cyBb5d\":[\"解密\"],\"d-F6q9\":[\"已创建\"],\"d-eBFj\":[\"配置所选引擎的详细选项。\"],\"d1yanO\":[\"暂无日志可显示\"],\"d2gWS6\":[\"频道或直播流的直接链接。\"],\"d3IqDh\":[\"质量\"],\"d4uXwk\":[\"下一页\"],\"d6CET4\":[\"自适应刷新（默认：开）\"],\"d6ituE\":[\"已关闭自动滚动\"],\"d7Kp2g\":[\"中断\"],\"dEXRlZ\":[\"步骤库\"],\"dEgA5A\":[\"取消\"],\"dF6vP6\":[\"直播\"],\"dISV1x\":[\"带宽限制\"],\"dK3Z9j\":[\"组件\"],\"dMtwVQ\":[\"NVENC H.264 快速\"],\"dN7wel\":[\"当下载因错误失败时触发。\"],\"dNmZ33\":[\"未找到工作流\"],\"dONv4O\":[\"已处理分段的通道缓冲倍数。\"],\"dP2MYa\":[\"预设删除成功\"],\"dPyJ15\":[\"删除模板\"],\"dQfGrq\":[\"致命错误\"],\"dTuElw\":[\"添加自定义标签\"],\"dW40Uz\":[\"未找到事件\"],\"dXcu-7\":[\"重封装\"],\"da5jry\":[\"流监控遇到问题\"],\"de7Gux\":[\"录制重试策略\"],\"dg3VQG\":[\"代理策略\"],\"dgYq4k\":[\"可选\"],\"dhvw0h\":[[\"0\",\"plural\",{\"other\":[\"#\",\" 行\"]}]],\"djLR5U\":[\"清单（按顺序配对）\"],\"dkjxU6\":[\"内部服务与资源的详细健康检查。\"],\"dmsn13\":[\"收到头部\"],\"dnUaj8\":[\"克隆模板失败：\",[\"0\"]],\"dsn408\":[\"若输出已存在则强制覆盖\"],\"dsphkM\":[\"完整的多媒体处理：并行进行视频重封装、音频提取和缩略图生成，然后上传所有文件。\"],\"dx8VNK\":[\"引擎更新成功\"],\"dxj6g1\":[\"检查间隔。\"],\"dxwgX3\":[\"处理管道名称\"],\"e-gPhp\":[\"跟随重定向（默认：开）\"],\"e20CFN\":[\"此过滤器仅在时间线上突出显示的窗口期间激活。如果开始时间在结束时间之后，窗口将自动跨越午夜。\"],\"e2BurN\":[\"提取高质量 MP3（320kbps）音频，用于播客分发。\"],\"e3P3Sz\":[\"最大字节数\"],\"e3_ja4\":[\"名称 A-Z\"],\"e4_PV8\":[\"例如 gdrive:/videos\"],\"e65FAh\":[\"此删除步骤依赖 <0>\",[\"transformDepLabel\"],\"</0>，将删除转码后的结果文件，而不是原始录制文件。若要在转码后删除原始文件，请在转码步骤上启用“成功后移除输入”。仍要添加此删除步骤吗？\"],\"e6MUfG\":[\"最大分片大小\"],\"e9-VJo\":[\"输出文件名模式（可选）\"],\"eCiRz_\":[\"双向均为 10 MiB/s\"],\"eE0JZ4\":[\"版本\"],\"eEMRgC\":[\"文件扩展名过滤\"],\"eG3YoN\":[\"预计剩余时间\"],\"eNncgX\":[\"复制/移动\"],\"eOpIyK\":[\"仅视频\"],\"ePK91l\":[\"编辑\"],\"eUQA5e\":[\"未检测到问题\"],\"eYYXD2\":[\"并发校验数（--checkers）。\"],\"eYe1Rb\":[\"导出配置失败\"],\"eZO3cK\":[\"缓冲区限制\"],\"eZgbDb\":[\"高优先级槽位等待中（已达到并发限制）\"],\"ebJPUJ\":[\"完整流程：重封装、生成缩略图、添加元数据并上传。\"],\"ebiBIb\":[\"通知保留\"],\"ecUA8p\":[\"今天\"],\"edQdmU\":[\"FFmpeg 音频滤镜（-af）\"],\"ehe4bj\":[\"强制使用移动端 API\"],\"ejQ0z7\":[\"覆盖已存在文件\"],\"emYiLJ\":[\"平台覆盖设置\"],\"enj3eJ\":[\"主题模式\"],\"es0mu4\":[\"仅限严重\"],\"evbMWR\":[\"输出参数\"],\"ewB4dk\":[\"例如：标准处理\"],\"ex8PR1\":[\"重试所有失败任务\"],\"exyoIu\":[\"搜索录制会话...\"],\"f2d4Ps\":[\"会话菜单\"],\"f4u7wL\":[\"时间与延迟\"],\"f4yUH6\":[\"多线程阈值帮助\"],\"f76EEx\":[\"按标题关键字过滤\"],\"f77MVE\":[\"编码预设\"],\"f8A73b\":[\"44100\"],\"f8hHmU\":[\"创建处理任务失败\"],\"f8zoXj\":[\"加载处理管道失败\"],\"fAGXwp\":[\"当主播配置被动态更新时触发。\"],\"fB-Bdg\":[\"上次检查于 \",[\"0\"],\" 前\"],\"fF22or\":[\"弹幕流已关闭\"],\"fHh0Nn\":[\"例如 up -c @channel -p \",[\"input\"]],\"fLcXDs\":[\"录制过滤器\"],\"fNsYxz\":[\"在上方添加模块以配置其日志级别\"],\"faK1M-\":[\"允许的扩展名\"],\"fcWrnU\":[\"退出登录\"],\"fcirqq\":[\"这将永久删除工作流“\",[\"0\"],\"”。使用该工作流的主播需要更新。\"],\"fiPesM\":[\"请求方法\"],\"fif2cG\":[\"搜索工作流...\"],\"fknQhj\":[\"目标文件夹\"],\"fkpv9h\":[\"搜索主播...\"],\"fnASK5\":[\"普通+\"],\"fpMs2Z\":[\"直播中\"],\"fqJjiU\":[\"在每个录制分段完成后立即运行。\"],\"frUGOs\":[\"自动为每个会话的第一段生成封面图。\"],\"fss9hD\":[\"打开画质选项\"],\"fvL_Ho\":[\"单位为字节（默认基准为 KiB/s）。可使用 B、K、M、G、T、P 等后缀表示更大的容量。\"],\"g-gBfk\":[\"解析中...\"],\"g6BpXZ\":[\"已取消\"],\"g7_-Qd\":[\"用于排除代理的频道列表（逗号分隔）\"],\"g90PVF\":[\"步骤配置\"],\"gCv-nS\":[\"每主机最大空闲连接数\"],\"gEQH50\":[\"限制整体传输带宽（rclone --bwlimit）。\"],\"gM5LKb\":[\"该流已处于活动状态\"],\"gORFgZ\":[\"取消执行\"],\"gQG_W7\":[\"由 <0>Rust-srec</0> 提供支持。\"],\"gUjkl_\":[\"不可用\"],\"gVDufW\":[\"为录制内容定义处理步骤序列\"],\"gVxTf6\":[\"导出内容包含所有全局设置、平台、模板、主播、引擎和通知渠道。敏感数据（如密码）可能会根据平台设置被脱敏或加密。\"],\"gWdWG8\":[\"折叠方式\"],\"gb0sx5\":[\"密钥重试延迟（毫秒）\"],\"gc3su8\":[\"字幕匹配\"],\"gdA_lQ\":[\"播放列表已失效\"],\"gh06VD\":[\"输出\"],\"ghnHjn\":[\"确认主播离线所需的连续失败检查次数。较高的值可以防止\\\"假\\\"离线检测。\"],\"glK8MG\":[\"处理任务\"],\"gliRBz\":[\"处理节点\"],\"gnNUHR\":[\"此步骤是一个子工作流：<0>\",[\"workflowName\"],\"</0>。\"],\"gpUgZ_\":[\"0（无）- 9（最佳）\"],\"gptVEm\":[\"创建过滤器以控制何时录制该主播。你可以按时间、关键字、分类等进行过滤。\"],\"gq-Q4J\":[\"正在加载预设详情...\"],\"gq6RwY\":[\"加载弹幕统计失败。\"],\"gqZ7EH\":[\"如果可用，采集实时评论和聊天消息。\"],\"guy7NI\":[\"修改此处理步骤的参数。\"],\"h1-4V4\":[\"刷新失败：需要手动登录\"],\"h5b8Gg\":[\"加载媒体文件失败：\",[\"0\"]],\"hFcCJR\":[\"限制单个文件的传输速率，而非整体总量。\"],\"hKqbBO\":[\"成功处理 \",[\"0\"],\" 个主播\"],\"hM6-6Z\":[\"会话 ID 是必需的\"],\"hNmOZ7\":[\"设置优先级\"],\"hO9jJx\":[\"日志配置已更新\"],\"hQOBwU\":[\"等待\"],\"hSHVF0\":[\"复制后检查大小\"],\"hSj3SO\":[\"自定义配置\"],\"hV6FeJ\":[\"吞吐量\"],\"hVDqsJ\":[\"高清\"],\"hVuIVS\":[\"高级排程\"],\"hXA9xB\":[\"网页优化\"],\"hXzOVo\":[\"下一步\"],\"hY0vvK\":[\"一个唯一的 ID，用于供其他步骤引用此步骤。\"],\"hYgDIe\":[\"创建\"],\"hZ6znB\":[\"端口\"],\"hdsaJo\":[\"正在导入...\"],\"hdyeZ0\":[\"删除处理任务\"],\"he3ygx\":[\"复制\"],\"hgpMHD\":[\"总大小\"],\"hmqzwF\":[\"峰值/分钟\"],\"hrDkPB\":[\"主播的基本设置。\"],\"ht1ran\":[\"尝试调整搜索。\"],\"hul8PJ\":[\"自动填充名称失败\"],\"huzp0F\":[\"需要 Telegram 桌面版数据\"],\"hx-EJH\":[\"最大分段数\"],\"hyCRuI\":[\"大小限制\"],\"i-GrRR\":[\"未在平台/主播中指定时使用的录制引擎。\"],\"i09z4S\":[\"月 (01-12)\"],\"i0qMbr\":[\"主页\"],\"i2yKvD\":[\"主播\"],\"i4y2wE\":[\"描述由系统管理\"],\"iAJgTr\":[\"404\"],\"iAL9tI\":[\"配置\"],\"iB8z_j\":[\"流选项\"],\"iBQU3w\":[\"请修改密码\"],\"iDNBZe\":[\"通知\"],\"iDpc_N\":[\"正在加载编辑器组件...\"],\"iE4x2o\":[\"选择编解码器\"],\"iEfPm1\":[\"Twitch (ttv-lol)\"],\"iFIj9t\":[\"将设置保存为 JSON 文件\"],\"iH8pgl\":[\"返回\"],\"iI8ncW\":[\"输入要匹配的分类（例如 Just Chatting、Gaming）。\"],\"iKvAeG\":[\"检查中\"],\"iLYPXc\":[\"DAG 图\"],\"iMAQTQ\":[\"年 (4 位数字)\"],\"iToNdZ\":[\"用于观看需要登录（如 19+）直播的 SOOP 账号。长期受限频道建议优先使用 Cookie。\"],\"iTylMl\":[\"模板\"],\"iWGuCP\":[\"此处理器没有可用的配置表单。\"],\"iaPtht\":[\"复制\"],\"iaocTt\":[\"比例\"],\"iftgVc\":[\"未配置自定义请求头\"],\"ij74Jr\":[\"安排录制时间\"],\"ilOZeV\":[\"初始延迟（ms）\"],\"imXBeM\":[\"确认离线所需的连续失败检查次数。\"],\"ipzg8L\":[\"未配置模块\"],\"isRobC\":[\"新建\"],\"iv8DdK\":[\"更新\"],\"ivJkrj\":[\"创建新工作流\"],\"iwyTQi\":[\"你确定要删除 \",[\"0\"],\" 个会话吗？此操作无法撤销。\"],\"iyCIi4\":[\"批量解析\"],\"j-MPXl\":[\"备份与恢复\"],\"j03sWF\":[\"整个会话结束后触发\"],\"j0rT_n\":[\"选择或输入画质值\"],\"j6ZZIX\":[\"CRF (0-51)\"],\"j7AbzC\":[\"从边缘拖动以连接步骤\"],\"jAwQYJ\":[\"优先使用二维码登录；其次是手机号和验证码（可选两步验证）；最后是桌面登录。\"],\"jC5etE\":[[\"0\",\"plural\",{\"other\":[\"个文件\"]}]],\"jEw0Mr\":[\"请输入有效的 URL\"],\"jGmy11\":[\"正在录制\"],\"jIFKq_\":[\"变体选择策略\"],\"jIPNJG\":[\"基本信息\"],\"jKHfGg\":[\"弹幕转 ASS 字幕\"],\"jKPJlR\":[\"创建缺失的文件夹\"],\"jKttAE\":[\"钩子\"],\"jNqP-h\":[\"从 URL 提取名称失败\"],\"jQh979\":[\"按固定时间间隔采样弹幕。\"],\"jR5LZx\":[\"录制出错时\"],\"jRPz-T\":[\"克隆模板\"],\"jWXukA\":[\"该登录需要 Telegram 两步验证密码。请启用“允许两步验证”并重启。\"],\"jXwBNq\":[\"CRF（恒定码率因子）\"],\"jems_i\":[\"单文件带宽限制帮助\"],\"jfsP4q\":[\"上传所有文件\"],\"jmbIS6\":[\"桌面通知\"],\"joJBB2\":[\"创建你的第一个工作流，用于定义录制内容的处理步骤序列。\"],\"jpctdh\":[\"查看\"],\"juTcaJ\":[\"NVENC H.264 高质量\"],\"jvivc3\":[\"录制会话 ID\"],\"k9Trbl\":[\"创建预设\"],\"kEgzfB\":[\"rust-srec 通过运行 nvidia-smi 来检测容器是否失去 GPU 访问权限(NVIDIA Container Toolkit 在 cgroup v2 主机上的已知问题)。仅在系统中存在 nvidia-smi 时启用,否则不会注册 GPU 行。默认 30 秒。修改会在下一次探测时生效,无需重启。不建议设置低于 30 秒。\"],\"kF2Y0z\":[\"偏移一致性检查\"],\"kG0i0h\":[\"仍然添加\"],\"kI1qVD\":[\"格式\"],\"kN8Y5L\":[\"CPU 任务超时\"],\"kRJm8z\":[\"采样尝试之间的时间间隔。\"],\"kX97I8\":[\"列如: 0:v:0, 0:a:0\"],\"kZV79T\":[\"分割原因\"],\"kcR85X\":[\"输入要包含的关键词...\"],\"kcoHto\":[\"校验服务不可用\"],\"kfNspv\":[\"引擎类型\"],\"kjMqSj\":[\"复制 JSON\"],\"klOeIX\":[\"修改密码失败\"],\"kn1oU0\":[\"日志已暂停\"],\"kne4l9\":[\"压缩\"],\"koRU21\":[\"添加额外参数\"],\"kqQaK3\":[\"仅 Cookie\"],\"krzGzQ\":[\"并发文件传输数（--transfers）。\"],\"kseNmN\":[\"这将永久删除通知渠道“\",[\"0\"],\"”。\"],\"kt6N2a\":[\"此删除步骤将删除 <0>\",[\"transformDepLabel\"],\"</0> 产出的文件（即转码结果），而不是原始录制文件。若要在转码后删除原始源文件，请改为在转码步骤上启用“成功后移除输入”。\"],\"kwRUsJ\":[\"任务限制与引擎设置。\"],\"l-RCZs\":[\"创建工作流\"],\"l2Op2p\":[\"查询参数\"],\"l3s5ri\":[\"导入\"],\"l6DdQS\":[\"没有可用的详细错误信息\"],\"l6zyQF\":[\"使用 rclone 将文件上传到云存储。在 rclone 配置中设置远端。\"],\"l7oQUQ\":[\"开始前等待时间。\"],\"l97zAi\":[\"转码 H.264\"],\"l9wI00\":[\"速度\"],\"lA6fmP\":[\"扫描目录\"],\"lA8IlD\":[\"未找到平台\"],\"lBQSXl\":[\"例如：重封装为 H.264\"],\"lCF0wC\":[\"刷新\"],\"lDvrUG\":[\"创建主播失败\"],\"lECX4V\":[\"最大 IO 任务数\"],\"lJoArf\":[\"Cron 表达式\"],\"lOyQ-B\":[\"编码器预设\"],\"lYLHvp\":[\"已达到并发限制\"],\"lcb8Hd\":[\"压缩级别\"],\"lfFsZ4\":[\"声道\"],\"lj3GoP\":[\"未找到处理任务\"],\"lkz6PL\":[\"时长\"],\"lqXgLk\":[\"添加覆盖项以自定义此模板的引擎行为。\"],\"lqf04S\":[\"次要\"],\"ltYQCa\":[\"自 \",[\"0\"],\" 起\"],\"luB4tN\":[\"选择此主播如何处理代理连接。\"],\"m16xKo\":[\"添加\"],\"m2DSGV\":[\"端点配置\"],\"m42dNa\":[\"创建模板以定义可复用的录制配置。\"],\"m48LOH\":[\"硬件加速\"],\"m50MxJ\":[\"获取延迟\"],\"m7AE5i\":[\"下载文件失败\"],\"m8LlVt\":[\"将单个大文件拆分为 N 个并行分块，加快上传速度。\"],\"mCB6Je\":[\"全选\"],\"mCc1g0\":[\"刷新凭据失败\"],\"mIbF__\":[\"从 URL 自动填充名称\"],\"mKKr99\":[\"使用 -d 重启\"],\"mLVDOt\":[\"命令模板参数。可使用 \",[\"INPUT_TOKEN\"],\" 和 \",[\"OUTPUT_TOKEN\"],\" 占位符。\"],\"mO7heL\":[\"媒体文件\"],\"mOXqr6\":[\"密码修改成功。请使用新密码登录。\"],\"mQe6uy\":[\"主播已更新\"],\"mTw0S2\":[\"尚未生成媒体输出。\"],\"mYGY3B\":[\"日期\"],\"meSGba\":[\"进度：\"],\"mgOBre\":[\"最高码率\"],\"mgtXne\":[\"过滤低于此优先级的事件\"],\"mgxDh9\":[\"删除转码后的结果文件？\"],\"mkaDKN\":[\"如果一个文件上传失败，继续上传其他文件\"],\"mkvLrG\":[\"选择类别\"],\"mrmkFJ\":[\"以原始直播分辨率生成缩略图（不缩放）。质量最佳，但文件体积最大。\"],\"mrzV5V\":[\"覆盖现有的 .ass 文件\"],\"mxhrdq\":[\"弹幕\"],\"mxun7B\":[\"主播离线时运行。\"],\"my6sDb\":[\"搜索主播...\"],\"mzDMo2\":[\"基于时间\"],\"mzI_c-\":[\"下载\"],\"mzffFb\":[\"预设更新成功\"],\"n-v6gq\":[\"总弹幕数\"],\"n1MDK4\":[\"最大密钥重试次数\"],\"n1ekoW\":[\"登录\"],\"n4AWhD\":[\"探索匹配的自动化步骤以增强你的处理管道工作流。\"],\"n9yU9X\":[\"实时预览\"],\"nBcxYG\":[\"取消 CPU 密集型处理器前的超时时间。\"],\"nC6Rpq\":[\"添加流\"],\"nDG2K-\":[\"启用高级 FLV 时间戳修复\"],\"nEAJMt\":[\"离线检查\"],\"nEJA8Y\":[\"尝试调整日期范围\"],\"nGC_6h\":[\"添加 rclone 参数\"],\"nGNP8v\":[\"压缩（Zip）\"],\"nGrU2q\":[\"在第 10 秒生成高清缩略图（宽 640px）。\"],\"nN1XNy\":[\"覆盖文件\"],\"nOPbWJ\":[\"右侧\"],\"nQM_01\":[\"删除预设失败：\",[\"0\"]],\"nQZ3Py\":[\"更新工作流失败：\",[\"0\"]],\"nQibzB\":[\"完全排除这些 CDN 提供商的流。按回车键添加。\"],\"nSHdq2\":[\"当新的下载录制开始时触发。\"],\"nSQv8Y\":[\"已开启自动滚动\"],\"nTWWCZ\":[\"低\"],\"nUjQm2\":[\"执行设置\"],\"nVkOeu\":[\"TDL 登录会话已开始\"],\"nWnBRV\":[\"投递策略\"],\"n_s1Si\":[\"如果目标文件已存在则替换\"],\"ncwQad\":[\"（空）\"],\"neL6ek\":[\"新预设名称\"],\"nfwriv\":[\"多个会话共用同一目标文件夹时，请在文件名模板中保留日期和时间标记。\"],\"nhxAdQ\":[\"关键字\"],\"nmD1Iv\":[\"Web 推送允许你在关闭浏览器时也能接收通知。它需要浏览器权限和有效的服务器密钥。\"],\"nnPJVX\":[\"TDL 存储会话数据的地方 (`tdl --storage ...`)。例如：`type=bolt,path=/data/tdl`。\"],\"nrLIEd\":[\"按时长分割。\"],\"nu0Tii\":[\"尝试通过替换音频流获取原画质量。如果失败，可能会导致仅有音频。\"],\"nuh_Wq\":[\"Webhook URL\"],\"num3TS\":[\"加载预设失败：\",[\"0\"]],\"nwIyEX\":[\"修复 HLS 不连续性\"],\"nwObng\":[\"暂无媒体文件\"],\"nxIXdN\":[\"请修正校验错误\"],\"o21Y-P\":[\"条\"],\"o5r6b2\":[\"处理任务已删除\"],\"o69yIu\":[\"按 Enter 添加收件人\"],\"o6OQlp\":[\"编辑渠道\"],\"oBn_nO\":[\"流水线历史保留期\"],\"oIg22A\":[\"直播密码\"],\"oJJdGr\":[\"目标根目录\"],\"oKjqX-\":[\"处理管道执行\"],\"oLsREl\":[\"暂无词频数据\"],\"oMVAXw\":[\"在整个会话结束且所有分段管道均已完成后运行一次。\"],\"oQw4Jd\":[\"SMTP 主机\"],\"oV0Bem\":[\"创建主播\"],\"oaiKxc\":[\"在此输入你的回答...\"],\"oatACc\":[\"内联\"],\"ofUcbc\":[\"随机\"],\"ogaYcn\":[\"Shadcn UI 主题预设\"],\"ojQSTc\":[\"上一个标题\"],\"okXKua\":[\"签发完整性 token\"],\"oke9ig\":[\"主播更新成功\"],\"olkLN2\":[\"单个 URL\"],\"oo16kX\":[\"未生成预览\"],\"opgoOE\":[\"重排持续时间（毫秒）\"],\"orDnO-\":[\"统计词数\"],\"orI3hB\":[\"分页\"],\"ovBPCi\":[\"默认\"],\"oyj1TW\":[\"未定义步骤\"],\"p0dP0q\":[\"在不同时间点生成多张预览图，用于图库展示。\"],\"p12eT9\":[\"请选择一个 JSON 文件\"],\"p27AhB\":[\"手机号和验证码（可选两步验证）\"],\"p2_GCq\":[\"确认密码\"],\"p2mB8S\":[\"单文件带宽限制\"],\"p2sbyZ\":[\"未找到模板\"],\"p5nYkr\":[\"查看全部\"],\"p6NueD\":[\"新\"],\"p8QOjt\":[\"使用 faststart 标志重封装为 MP4，以优化网页播放。\"],\"pAtylB\":[\"未找到主播\"],\"pD5RQD\":[\"转换成功后删除 XML 文件\"],\"pECIKL\":[\"搜索模板...\"],\"pHt_zF\":[\"不在计划时间内\"],\"pIKNYw\":[\"忽略低于此比特率的流。\"],\"pKmdYH\":[\"仅包含具有该扩展名的文件（不含点号）\"],\"pSNvte\":[\"播客提取\"],\"pWT04I\":[\"检查中...\"],\"pYUyoK\":[\"默认工作流：重封装为 MP4（删除原始文件），生成原始分辨率缩略图，上传两者至云端并删除本地文件。\"],\"pZBeKb\":[\"待处理任务\"],\"pZoiPG\":[\"追踪标识符的隔离级别。全局通常是最佳选择。\"],\"p_78dY\":[\"位置\"],\"pb5U2q\":[\"将文件移动到其他位置，并移除原始文件。\"],\"peqX67\":[\"元数据标签\"],\"pj3WXl\":[\"--flag value\"],\"pjN1vk\":[\"例如 C:\\\\Users\\\\...\\\\AppData\\\\Roaming\\\\Telegram Desktop\"],\"plUzgk\":[\"高质量 H.265/HEVC 压缩（CRF 22）。文件更小，但编码更慢。\"],\"pmXQXV\":[\"流媒体服务\"],\"pqdYPk\":[[\"0\",\"plural\",{\"other\":[\"#\",\" 个警告\"]}]],\"psqOpk\":[\"添加邮箱地址并按 Enter\"],\"ptAX-h\":[\"CPU 使用率\"],\"puukLG\":[\"已全部标记为已读\"],\"pvnfJD\":[\"深色\"],\"pwlRnQ\":[\"元数据编辑器\"],\"q-N7o6\":[\"自定义标志\"],\"q3Dp_X\":[\"在输出中包含原始文件\"],\"q4bpUC\":[\"输出配置\"],\"q5nfv1\":[\"录制开始时\"],\"qC4H_F\":[\"检查中\"],\"qEH4Cy\":[\"运行自定义 FFmpeg 命令。编辑参数以自定义。\"],\"qEVp1d\":[\"弹幕控制\"],\"qGO8x4\":[\"轨道信息\"],\"qH2T4F\":[\"未定义环境变量\"],\"qJb6G2\":[\"重试\"],\"qM884L\":[\"User-Agent\"],\"qSJNp0\":[\"内存使用率\"],\"qTqIG0\":[\"录制弹幕\"],\"qUvWln\":[\"尝试调整搜索或过滤器。\"],\"qWRWG1\":[\"原因：\"],\"qZq0tI\":[\"例如 aliyun, akamaized\"],\"q_yLs_\":[\"首选画质 (QN)\"],\"qgMxnz\":[\"任务创建时间\"],\"qoGRes\":[\"目标根目录帮助\"],\"qqWcBV\":[\"已完成\"],\"qtoOYG\":[\"不限\"],\"quwsBF\":[\"强制指定输出格式。\"],\"qxDAhZ\":[\"编辑过滤器\"],\"r0jBJE\":[\"secret\"],\"r2yoxL\":[\"输入选项（-i 参数）\"],\"r5_ZH9\":[\"将 .ass 字幕内嵌到视频中（默认生成 *_burnin.mp4）。支持清单感知和批量处理。\"],\"r8keQd\":[\"历史（\",[\"slots\"],\" 点）\"],\"rAx5u1\":[\"结束时间\"],\"rCZeYu\":[\"当文件分段完成时触发。\"],\"rDC-T6\":[\"重试处理任务\"],\"rG3WVm\":[\"选择\"],\"rGNFqY\":[\"全流程处理\"],\"rLgPvm\":[\"备份\"],\"rOsxLC\":[\"创建渠道\"],\"rPLMLx\":[\"使用哔哩哔哩 App 扫码登录并保存凭据\"],\"rPTjS_\":[\"标记已读\"],\"rYpSzJ\":[\"清理后的会话标题\"],\"r_-HvB\":[[\"0\",\"plural\",{\"other\":[\"#\",\" 已完成\"]}]],\"rc8zxf\":[\"控制各模块的日志详细程度。较低级别包含较高级别。\"],\"rdAnZQ\":[\"实时日志\"],\"rj3LPO\":[\"登录成功！\"],\"rkmaZ8\":[\"传递给 Streamlink 的其他命令行参数\"],\"rmol8y\":[\"不连续\"],\"rsjeU7\":[\"主播已删除\"],\"rv6zQQ\":[\"重试延迟基数（毫秒）\"],\"rwpu5q\":[\"选择你的显示语言\"],\"ry0RbG\":[\"暂无描述。\"],\"ryXr-I\":[\"命令参数\"],\"s-MGs7\":[\"资源\"],\"s-yK3S\":[\"启用后，将忽略这些分类的流。\"],\"s0A5F0\":[\"需要字幕\"],\"s2Ok6S\":[\"编辑工作流：\",[\"0\"]],\"s5FJH3\":[\"总步骤数\"],\"s7XkSj\":[\"加载模板列表失败：\",[\"0\"]],\"s7XwOU\":[\"创建模板\"],\"s867uU\":[\"覆盖文件名模板。\"],\"sAEhzj\":[\"流结构变化\"],\"sDMc1l\":[\"时间占位符（本地时间）\"],\"sER-bs\":[\"文件\"],\"sG9Y50\":[\"NVENC AV1 高质量\"],\"sGeXL3\":[\"缩略图\"],\"sMakGt\":[\"ey...\"],\"sPFHpI\":[\"磁盘\"],\"sPfqA-\":[\"确定要删除模板“\",[\"0\"],\"”吗？此操作无法撤销。\"],\"sSmPh2\":[\"沉浸模式\"],\"sThCBt\":[\"质量控制\"],\"sWuf_r\":[\"首选画质\"],\"sXmNBK\":[\"通过中间服务器转发流量\"],\"sYAqs-\":[\"最大延迟（ms）\"],\"saFjCh\":[\"成功重封装后删除原始文件\"],\"sbQMAc\":[\"请至少输入一个 URL\"],\"sbanhc\":[\"无需刷新或不支持\"],\"sfCeO6\":[\"优先级从高到低\"],\"si7i6t\":[\"为文件创建 ZIP 压缩包，适合与元数据一起打包。\"],\"sjJ6qs\":[\"恢复启动\"],\"sjVfrA\":[\"命令\"],\"spa6AF\":[\"没有输入路径\"],\"sq8P7b\":[\"侧滑\"],\"sr0UJD\":[\"返回\"],\"svV_tK\":[\"模板更新成功\"],\"sxidET\":[\"GPU 加速高质量归档：NVENC HEVC 编码、缩略图生成和云端上传。\"],\"sxkWRg\":[\"高级\"],\"t-R8-P\":[\"执行\"],\"t3NSIX\":[\"替换管道步骤\"],\"t6QNu0\":[\"音频设置\"],\"t7TeQU\":[\"消息数\"],\"t7p091\":[\"开始二维码登录\"],\"tBFXor\":[\"已被用户停用\"],\"tBkWoY\":[\"斗鱼标清或低清画质值\"],\"tCNR7u\":[\"下载并发数\"],\"tG0T2X\":[\"解析 URL 失败\"],\"tH1aCG\":[\"视频比特率\"],\"tLnoCj\":[\"任务预设\"],\"tOcErW\":[\"更新设置失败\"],\"tPg-n8\":[\"Rclone 可执行文件\"],\"tVSmFT\":[\"正在加载更多...\"],\"tVxHoG\":[\"未找到工作流\"],\"tWWCyb\":[\"创建模式\"],\"tXDHM9\":[\"传输数帮助\"],\"tXQ9KI\":[\"选择类型\"],\"tX_2YD\":[\"删除主播失败\"],\"tXkhj_\":[\"开始\"],\"tYt23W\":[\"ASS 硬字幕\"],\"tZASER\":[\"确定要删除此任务吗？此操作将永久从列表中删除。\"],\"t_YqKh\":[\"移除\"],\"t_a0R7\":[\"https://gotify.example.com\"],\"taWpib\":[\"TPS 限制的突发容量（--tpslimit-burst）。\"],\"ti_7Zv\":[\"配置与现有数据一致\"],\"tkrJYR\":[\"确定要删除此会话吗？此操作无法撤销。\"],\"toutGW\":[\"二维码\"],\"tqsVap\":[\"最低优先级\"],\"trzwMt\":[\"HLS 解析器\"],\"tst44n\":[\"事件\"],\"tuczwE\":[\"主要操作\"],\"twkfAf\":[\"已开始重试失败的步骤\"],\"tyyY8g\":[\"录制会话详情\"],\"u-R-Uj\":[\"未找到模板\"],\"u4x6Jy\":[\"返回处理任务列表\"],\"u5pdtQ\":[\"取消处理管道失败\"],\"u7En0V\":[\"添加元数据\"],\"u7KTQf\":[\"用英文逗号分隔多个路径。\"],\"u8XaRg\":[\"主播处于冷却期\"],\"u8rLL5\":[\"连接不稳定\"],\"uAQUqI\":[\"状态\"],\"uBxq0B\":[\"添加项目...\"],\"uDMKZ-\":[\"间隔（秒）\"],\"uFgIWz\":[\"每个分段录制后触发\"],\"uFrzr2\":[\"此处理管道在录制分段完成后立即运行。适用于仅需要单个视频分段的任务，例如重封装、缩略图生成或逐段上传。\"],\"uHaWdO\":[\"提取高质量音频用于播客分发并上传。\"],\"uI3SjW\":[\"连接错误\"],\"uKMMk4\":[\"登录成功\"],\"uKqo4y\":[\"事件钩子\"],\"uLlPmJ\":[\"根据目标时长调整速率\"],\"uQUJrF\":[\"所有平台\"],\"uRoULu\":[\"全局配置\"],\"uS4jm7\":[\"继承/默认\"],\"uTyAvE\":[\"Twitch 代理播放列表排除\"],\"u_4llg\":[\"切换主题\"],\"uabF28\":[\"敏感\"],\"ueNCFX\":[\"预设详情\"],\"uhURfE\":[\"成功后移除源文件\"],\"uhZE--\":[\"禁用 Web 推送失败\"],\"um816y\":[\"执行记录\"],\"up_E6Q\":[\"自定义应用主题和侧边栏布局。\"],\"utnMp8\":[\"账号不存在\"],\"uxjnhI\":[\"WEB\"],\"v-cKc6\":[\"复制/移动\"],\"v0ItTA\":[\"正在加载日志...\"],\"v10I4K\":[\"跳过互动玩法\"],\"v39wLo\":[\"继续\"],\"vBNtP8\":[\"首选格式\"],\"vEkjkD\":[\"代理 URL\"],\"vFBdNT\":[\"分辨率（缩放）\"],\"vGgj7q\":[\"密钥缓存 TTL（毫秒）\"],\"vGjmyl\":[\"已删除\"],\"vHFsyI\":[\"协议设置\"],\"vHbMb_\":[\"处理管道名称是必填项\"],\"vHgqv3\":[\"Twitch 播放列表的自定义代理 URL\"],\"vPfdKD\":[\"根据弹幕流量动态调整采样率。\"],\"vQUnlF\":[\"平台的特定设置。\"],\"vRLm73\":[\"显示录制下载详情\"],\"vRayGs\":[\"播放器\"],\"vS1EOG\":[\"流程与依赖\"],\"vSJd18\":[\"视频\"],\"vXIe7J\":[\"语言\"],\"vYVhmv\":[\"正在生成二维码...\"],\"vYgeDk\":[\"取消全选\"],\"vaHbOZ\":[\"共享的处理管道工具\"],\"vejAXH\":[\"直播链接\"],\"vf5Y2I\":[\"复制（直通）\"],\"vfZWyn\":[\"尝试调整过滤器或限制\"],\"vfeDAy\":[\"在第 30 秒生成缩略图（宽 480px）用于预览。\"],\"vgjQEq\":[\"重封装并清理\"],\"vgwVkd\":[\"UTC\"],\"vjD16L\":[\"宽度（像素，保持宽高比）\"],\"vlDq31\":[\"选择你想要尝试采集的最高质量等级。\"],\"voD8oZ\":[\"配置步骤\"],\"voRH7M\":[\"示例：\"],\"vrNaKg\":[\"提示：1 Mbit/s ≈ 0.125M（将比特数除以 8）。\"],\"vrnnn9\":[\"处理中\"],\"vtDkV7\":[\"选择预设主题或导入自定义主题。\"],\"vv7kpg\":[\"已取消\"],\"vwRuK_\":[\"加载预设失败\"],\"vxaZfq\":[\"时长限制\"],\"vzGE_4\":[\"代理配置\"],\"vzxlNg\":[\"根据标题、时间或语言定义自动录制或跳过的规则。\"],\"w-bWA_\":[\"仅限数字房间 ID\"],\"w2ybE1\":[[\"0\"],\" 配置\"],\"w6RBiW\":[\"查看录制内容并管理归档\"],\"wAFtA2\":[\"仅对超过阈值大小的文件生效。默认值为 4，设为 0 可禁用。\"],\"wAgqfF\":[\"双屏数据\"],\"wBMjJ2\":[\"次数\"],\"wCD4xg\":[\"解析失败：\",[\"0\"]],\"wDioLj\":[\"重试处理任务失败\"],\"wEp8H9\":[\"更新渠道失败\"],\"wErX0W\":[\"主文件名（文件名匹配）\"],\"wFb0uO\":[\"采集模式\"],\"wMHvYH\":[\"值\"],\"wMQZYQ\":[\"直播刷新间隔 (ms)\"],\"wMe2Qp\":[\"命名空间\"],\"wOjRUU\":[\"最后检查\"],\"wVagb1\":[\"在待确认状态 \",[\"0\"],\" 秒后恢复。\"],\"wVk8_8\":[\"内联：\",[\"stepName\"]],\"w_bY7R\":[\"日志\"],\"wdxz7K\":[\"来源\"],\"wg5HlH\":[\"rclone 并行上传的文件数量。\"],\"wgeqNr\":[\"画质设置\"],\"wiMpRi\":[\"覆盖输出\"],\"woYYQq\":[\"工作流\"],\"wqJ4zc\":[\"环境变量\"],\"wrl8J3\":[\"收件人地址\"],\"wspIF7\":[\"rclone 默认值：1\"],\"wv1e0F\":[\"内部\"],\"wwNqQz\":[\"FLV 调优\"],\"wwu18a\":[\"图标\"],\"wzr18h\":[\"导出配置\"],\"x-uFU5\":[\"0 = 不限\"],\"x09b8E\":[\"请求超时时长\"],\"x0U6Og\":[\"过滤窗口大小\"],\"x0aAkx\":[\"搜索媒体文件...\"],\"x1VcXy\":[\"帧率（FPS）\"],\"x2fr_j\":[\"图表\"],\"x2igpn\":[\"Telegram Desktop 路径（包含 tdata）\"],\"x7PDL5\":[\"日志\"],\"xA2izn\":[\"未发现处理管道 DAG。\"],\"xBO-wH\":[\"个渠道\"],\"xCJdfg\":[\"清空\"],\"xCaAmT\":[\"重试延迟（ms）\"],\"xCtNNg\":[\"覆盖调度器确认直播结束的激进程度。\"],\"xD4Xm2\":[\"获取\"],\"xDAtGP\":[\"消息\"],\"xDr_ct\":[\"结束\"],\"xGzQCl\":[\"工作流\"],\"xND0SQ\":[\"占位符\"],\"xQV856\":[\"克隆预设失败：\",[\"0\"]],\"xUpogZ\":[\"veryfast\"],\"xY9s5E\":[\"超时\"],\"xZGBr0\":[\"提取音频\"],\"x_biSg\":[\"离线检查之间的间隔。\"],\"xdqOXO\":[\"允许私有网络流代理目标\"],\"xeMG4j\":[\"正则表达式\"],\"xi8BoZ\":[\"并行完整性校验（例如比较源/目标哈希值）。\"],\"xjwCW_\":[\"最大密钥重试延迟（毫秒）\"],\"xqKCfi\":[\"开始手机号和验证码登录\"],\"xsp8AP\":[\"覆盖输出文件夹。\"],\"xtWiCP\":[\"DanmakuFactory 可执行文件路径。如果为空，则使用环境变量或 PATH。\"],\"xvp-v2\":[\"缩略图生成器\"],\"xxCtZv\":[\"每页行数\"],\"xzqVyW\":[\"实时轮询\"],\"y2WOeQ\":[\"正在加载工作流...\"],\"y7ZemI\":[\"使用直播开始时间可让跨午夜直播保存在同一个日期文件夹中。执行时间会保留现有 copy/move 行为。\"],\"y7sj-0\":[\"主播\"],\"yDlWVJ\":[\"离线检查间隔\"],\"yGS9cI\":[\"正常\"],\"yHXa3T\":[\"-1001234567890\"],\"yIMeW0\":[\"强制请求可用的最高原画质量（直连流）。\"],\"yL1-1a\":[\"直播开始时间\"],\"yMMvQD\":[\"如果标题中出现任何这些关键字，该流将被忽略。\"],\"yRAYpv\":[\"画质等级\"],\"yRadQU\":[\"当后处理任务开始时触发。\"],\"yRzWhF\":[\"监控并管理处理任务\"],\"ySWmtK\":[\"保持运行\"],\"yScTZL\":[\"系统状态\"],\"ySxXn1\":[\"/usr/bin/ffmpeg 或 ffmpeg\"],\"yTLtq4\":[\"复杂模式\"],\"yThUSt\":[\"使用 NVIDIA NVENC 的高质量 GPU 加速 HEVC/H.265 编码（CQ 21）。GPU 最佳压缩比。\"],\"yXcp78\":[\"本地处理：重封装为 MP4，生成缩略图，移动到归档文件夹。\"],\"yZgsv0\":[\"请求不含视频轨的 AAC 音频。\"],\"yZpWgb\":[\"响应中未找到可播放的流\"],\"y_0uwd\":[\"昨天\"],\"yfJY2E\":[\"GPU 健康探测间隔\"],\"ygqCBL\":[\"压缩视频用于存档，上传到云端，然后删除本地文件以节省空间。\"],\"yhvq_6\":[\"被浏览器阻止\"],\"ylXj1N\":[\"已选择\"],\"yntXb7\":[\"编辑模式\"],\"ypFsBx\":[\"忽略高于此比特率的流。\"],\"ysAGq3\":[\"未授予通知权限\"],\"ywDBfs\":[\"发生错误！\"],\"ywFj2D\":[\"配置\"],\"yx_fMc\":[\"高\"],\"yz7wBu\":[\"关闭\"],\"z-rLjA\":[\"超时（秒）\"],\"z1g5gH\":[\"启用所选\"],\"z2QpqF\":[\"路径变量\"],\"z4YzF4\":[\"H2 保活（毫秒）\"],\"z5385K\":[\"输入路径（逗号分隔）\"],\"z55Hd4\":[\"删除源 ASS 文件\"],\"z6HbLm\":[\"DAG 配置\"],\"z7n92F\":[\"直播缺口策略\"],\"zCuWFS\":[\"渠道已创建\"],\"zDfdMJ\":[\"直到\"],\"zG9iNp\":[\"搜索引擎...\"],\"zGgIA2\":[\"Web 推送优先级已更新\"],\"zHa4lw\":[[\"0\",\"plural\",{\"other\":[\"#\",\" 步\"]}]],\"zIkg8M\":[\"重封装为 MP4 且不重新编码，并在成功后删除原始文件。节省磁盘空间。\"],\"zInmXF\":[\"调度器\"],\"zJA0U6\":[\"输出设置\"],\"zKwEox\":[\"目标配置\"],\"zNi48o\":[\"无需重新编码，重封装为 MKV。Matroska 支持更多编码与特性。\"],\"zQLplp\":[\"平台报告该账号已不存在。可能已被删除、封禁，或者 URL/ID 有误。建议删除或更新该主播。\"],\"zSwSsg\":[\"尺寸与滤镜\"],\"zWMS3O\":[\"预览图库\"],\"zd00pH\":[\"复制路径\"],\"zg8Pdk\":[\"离线检测次数\"],\"zga9sT\":[\"正常\"],\"zi6RXq\":[\"加载主播失败：\",[\"0\"]],\"zoISi_\":[\"无法重试步骤\"],\"zrmjn_\":[\"最大时长\"],\"zx4BuL\":[\"本周\"]}")as Messages;
\ No newline at end of file
+/*eslint-disable*/import type{Messages}from"@lingui/core";export const messages=JSON.parse("{\"-10SpW\":[\"24小时交互式时间线选择。\"],\"-5Xsme\":[\"空间不足\"],\"-6YAwo\":[\"已选\"],\"-BEX4J\":[\"更新过滤器失败\"],\"-BOXe-\":[\"点播分段超时（毫秒）\"],\"-GLqpc\":[\"压缩/归档\"],\"-GZp3E\":[\"切换语言\"],\"-GwKvY\":[\"未配置平台覆盖项\"],\"-OQYUd\":[\"密钥超时（毫秒）\"],\"-O_9ij\":[\"主播详情\"],\"-Qbuje\":[\"下载引擎（mesio）\"],\"-TWWLH\":[\"必须填写 ID\"],\"-Xjyeo\":[\"选择文件\"],\"-_zWwh\":[\"自动（默认）\"],\"-b7T3G\":[\"已更新\"],\"-eCGXh\":[\"Windows 不支持通过此 API 进行交互式密码输入。请使用“桌面登录”（导入 tdata）或在常规终端中登录一次。\"],\"-fL-Rj\":[\"登录失败：\",[\"0\"]],\"-jO3E8\":[\"斗鱼高清画质值\"],\"-lH7FY\":[\"执行时间（默认）\"],\"-lwQ5z\":[\"视频编码\"],\"-rJcQ5\":[\"更新订阅失败\"],\"-swXfw\":[\"选择将文件传输到远程的方式。\"],\"-xD8Ul\":[\"录制的大小与时长限制。\"],\"-z4lmr\":[\"专家模式：直接编辑原始的平台特定配置。\"],\"-zy2Nq\":[\"类型\"],\"00W4lp\":[\"初始获取超时（ms）\"],\"00jaOh\":[\"归档压缩\"],\"01Oe9B\":[\"Telegram 桌面版\"],\"024CCW\":[\"选择模板\"],\"03Qqf9\":[\"已过滤\"],\"0CKZnZ\":[\"从视频第 10 秒生成缩略图（宽 320px）。\"],\"0CaXBE\":[\"支持的平台\"],\"0HlWEz\":[\"工作流步骤\"],\"0Jq6UY\":[\"管理你监控的主播及录制\"],\"0P2gFy\":[\"你要访问的页面不存在。\"],\"0PZXF8\":[\"选择格式\"],\"0RjCNS\":[\"无法连接到服务器，请检查网络连接。\"],\"0SsE5J\":[\"未找到预设\"],\"0SvRJE\":[\"根据近期场次学习每位主播通常的开播时间。在这些时间前后检查频率加倍，在主播从未开播的时段检查频率最多降为四分之一。\"],\"0TPdbO\":[\"H.264\"],\"0Uz_Zj\":[\"解密密钥 TTL（毫秒）\"],\"0X8rUE\":[\"https://...\"],\"0YopG6\":[\"资源限制\"],\"0ZwlwE\":[\"TDL 未登录\",[\"version\"]],\"0aTtEy\":[\"在任意原始头变更时分割。安全但可能产生额外文件。\"],\"0f9Hre\":[\"API 请求重试次数\"],\"0gVmZj\":[\"额外参数\"],\"0ioul2\":[\"包含图片\"],\"0s5Sjx\":[\"艺术家\"],\"0s7IPo\":[\"通知服务\"],\"0sZpQb\":[\"继承自上层\"],\"0tvouK\":[\"抖动\"],\"0v9bkw\":[\"会话已启动\"],\"0wk6D2\":[\"每页：\"],\"0xayzq\":[\"使用 NVIDIA NVENC 的快速 GPU 加速 H.264 编码（CQ 23）。速度与画质的良好平衡。\"],\"0zpgxV\":[\"选项\"],\"1-6BOG\":[\"下载备份\"],\"11MaKJ\":[\"加载事件失败\"],\"14PdY0\":[\"配置\"],\"16uyo-\":[\"添加渠道\"],\"17A-hi\":[\"启用后，将忽略匹配此模式的流。\"],\"17WYYl\":[\"离线宽限期\"],\"18A9QX\":[\"删除会话失败\"],\"19MiM7\":[\"全局超时 (ms)\"],\"19P7sR\":[\"已触发检查\"],\"1AGwZy\":[\"输入唯一名称\"],\"1AigfY\":[\"上一页\"],\"1BqPFJ\":[\"网络与系统\"],\"1BxbyL\":[\"未找到平台。\"],\"1HbWYr\":[\"这是默认预设，描述无法修改。\"],\"1KMsgI\":[\"必须选择处理器\"],\"1PQRWr\":[\"开始时间\"],\"1TQa05\":[\"引擎\"],\"1U1a6C\":[\"https://api.example.com/webhook\"],\"1UlpHm\":[\"主播\"],\"1VXi7i\":[\"取消处理管道\"],\"1WHNRE\":[\"添加覆盖项\"],\"1WTDTW\":[\"所有输入的 JSON 数组\"],\"1WrRvf\":[\"替换步骤\"],\"1XskRc\":[\"处理管道为空。\"],\"1ZTiaz\":[\"分段\"],\"1ZZ47T\":[\"调整 UI 元素的圆角程度。\"],\"1aPw3f\":[\"实时轮询通知已启用\"],\"1cbMZM\":[\"CQ (恒定质量)\"],\"1dwnDD\":[\"服务器未配置\"],\"1hKEom\":[\"优先级\"],\"1ir2pc\":[\"Basic Auth\"],\"1jJKez\":[\"配置如何在此浏览器中以及通过系统推送通知接收警报。\"],\"1lVNQI\":[\"当处理任务失败时触发。\"],\"1mYvTJ\":[\"平台覆盖项\"],\"1naaKv\":[\"当一项录制在并发队列中等待时间超过该阈值时，rust-srec 会在启动前重新检查主播以获取新的流地址和请求头。低于此阈值时，会复用首次直播事件中捕获的 URL。默认 60 秒。设为 0 表示每次排队等待都刷新。\"],\"1nhIls\":[\"过滤器删除成功\"],\"1njn7W\":[\"浅色\"],\"1pChHx\":[\"通知日志保留\"],\"1tul0y\":[\"会话未运行\"],\"1wTrMB\":[\"全部替换\"],\"1wy3Ze\":[\"初始\"],\"1yzCsx\":[\"下载完成\"],\"21M8r5\":[\"Web 推送\"],\"22PcUJ\":[\"配对分段处理管道\"],\"22lIWc\":[\"进程以代码 \",[\"0\"],\" 退出\"],\"237hSL\":[\"已结束\"],\"26Ki3h\":[\"这将永久删除处理管道及其关联的所有任务和日志。此操作无法撤销。\"],\"27E8MB\":[\"设置更新成功\"],\"28iWmV\":[\"正在加载处理管道编辑器...\"],\"29VNqC\":[\"未知错误\"],\"29loHy\":[\"处理管道已删除\"],\"2BBAbc\":[\"列表\"],\"2EEBQm\":[\"主播 ID\"],\"2E_rkV\":[\"凭据保存成功\"],\"2FC3XU\":[\"FFmpeg 路径\"],\"2GT3Hf\":[\"全局\"],\"2KpT4Y\":[\"例如 1M\"],\"2R2xgz\":[\"保留路径\"],\"2TQlVI\":[\"需要启用“录制弹幕”。\"],\"2WbqDp\":[\"管理存储路径和文件格式。\"],\"2XRg_h\":[\"读取超时 (ms)\"],\"2d1Xgp\":[\"自定义 Header\"],\"2dhgET\":[\"YYYY-MM-DD\"],\"2jmZHr\":[\"为视频文件添加元数据标签（标题、艺术家、日期）。\"],\"2k1l_G\":[\"视频设置\"],\"2kAqWs\":[\"创建渠道失败\"],\"2m_AmE\":[\"NVENC HEVC 高质量\"],\"2nMNeT\":[\"使用 NVIDIA NVENC 的高质量 GPU 加速 AV1 编码（CQ 24）。最佳压缩比，需要 RTX 4000+ 系列显卡。\"],\"2o7xcf\":[\"输出选项\"],\"2qfwJn\":[\"覆盖\"],\"2sDhLO\":[\"离线时\"],\"2tuwGz\":[\"添加参数\"],\"2vw0xh\":[\"历史结束\"],\"2wl1yB\":[\"最大间隔（s）\"],\"2xsJvU\":[\"^Started.*\"],\"3-I7HJ\":[\"选择主播\"],\"3-IxS7\":[\"为此配置提供一个唯一且易于识别的名称。\"],\"3070NI\":[\"常规配置\"],\"30XQ-_\":[\"Cookie\"],\"32TndD\":[\"已阻止\"],\"34KT3m\":[\"取消 `execute` 处理器命令前的超时时间。\"],\"39GoNc\":[\"最大并发录制数\"],\"39vwDk\":[\"标清 / 低清\"],\"3Aerm-\":[\"未找到录制会话\"],\"3BEjvS\":[\"无需重新编码，重封装为 MP4。快速且无损——仅更改封装格式。\"],\"3H1WQ-\":[\"连接/活动超时\"],\"3Huwd4\":[\"自动布局\"],\"3Ihr5B\":[\"选择格式\"],\"3JjdaA\":[\"运行\"],\"3K6phQ\":[\"默认使用 Mesio 内置的直播停顿超时；禁用表示无限等待。\"],\"3KInmc\":[\"已为此浏览器禁用 Web 推送\"],\"3N3v30\":[\"快速 H.264 压缩（CRF 23）。在速度、画质与体积之间取得良好平衡。\"],\"3NfDu6\":[\"加载平台失败\"],\"3QfsSn\":[\"暂无处理任务\"],\"3QlVaA\":[\"创建预设失败：\",[\"0\"]],\"3Qu3lA\":[\"处理管道完成时\"],\"3X3_SF\":[\"步骤列表\"],\"3Yd9PG\":[\"侧边栏布局\"],\"3bv0H0\":[\"应用关闭时触发。\"],\"3c_BSm\":[\"结束当前文件\"],\"3gnbIv\":[\"TDL 二进制路径\"],\"3ktmhA\":[\"API 模式\"],\"3qNoeP\":[\"降级\"],\"3rQgBh\":[\"录制输出文件系统无法写入、录制无法继续时的严重告警。\"],\"3sO1Yr\":[\"身份验证与设置\"],\"3tncII\":[\"配置从所选备份文件导入数据的方式。\"],\"3uIIkE\":[\"添加新引擎\"],\"3vSDtx\":[\"TDL 存储其会话和配置的地方。\"],\"3wRUCF\":[\"提取 MP3\"],\"3ywK2I\":[\"主播检查\"],\"3yyqaw\":[\"高质量压缩\"],\"40Gx0U\":[\"时区\"],\"40lLFI\":[\"下一页\"],\"40wBfu\":[\"批量 URL\"],\"41k5OL\":[\"无扩展名\"],\"43YdBD\":[\"绝对路径，或在 PATH 中时填写“ffmpeg”\"],\"44KALZ\":[\"最小帧率\"],\"45zsWJ\":[\"保留的最小大小。\"],\"47e97a\":[\"最大重试次数\"],\"47mhxP\":[\"首选 CDN\"],\"48NKsg\":[\"检查登录\"],\"49BbAW\":[\"文件\"],\"4AWhME\":[\"匹配策略\"],\"4BITzH\":[\"错误：\"],\"4GICMX\":[\"未找到可播放的流\"],\"4GKuCs\":[\"登录失败\"],\"4Lr0jZ\":[\"应用模板设置。\"],\"4P5UM2\":[\"会话已删除\"],\"4QQLtV\":[\"你的数字库目前为空。开始录制以在此显示会话。\"],\"4RQxGo\":[\"异常状态\"],\"4XUbOH\":[\"仅在编解码器配置变更时分割。减少误分割。\"],\"4aDTBq\":[\"复制文件\"],\"4ba0NE\":[\"已计划\"],\"4cEClj\":[\"录制会话\"],\"4cOYET\":[\"在应用标签页打开时，通过轮询最近事件来显示通知。\"],\"4e7ocq\":[\"重试之间的基础延迟\"],\"4eMNNj\":[\"启用输出指标（默认：开）\"],\"4jAOc9\":[\"选择必须在此步骤运行前成功完成的步骤。\"],\"4nfUib\":[\"品牌颜色\"],\"4siLaw\":[\"直播归档\"],\"4uQ7PS\":[\"后台刷新已启用\"],\"4v6VMF\":[\"将在…后恢复\"],\"4w5jzL\":[\"自定义标签\"],\"4xzdEv\":[\"订阅已更新\"],\"5-AFwM\":[\"暂无工作流\"],\"52y5Op\":[[\"0\",\"plural\",{\"other\":[\"#\",\" 失败\"]}]],\"55n6pj\":[\"速率限制\"],\"57MMsh\":[\"最大分段重试次数\"],\"5B_EbJ\":[\"离线\"],\"5FCyMZ\":[\"目标码率\"],\"5GJtdv\":[\"主题预设\"],\"5HLg1G\":[\"流与元数据\"],\"5LUAOT\":[\"原始 JSON 编辑器\"],\"5PhtRK\":[\"此平台没有可用的专门选项。\"],\"5RyP14\":[\"逐段\"],\"5SNqKm\":[\"自定义配置\"],\"5UfPjf\":[\"实时查看应用日志。日志仅保留最近 \",[\"MAX_LOG_ENTRIES\"],\" 条。\"],\"5b4J4v\":[\"全部时间\"],\"5bhON1\":[\"比特率\"],\"5dKF37\":[\"发送输入失败：\",[\"0\"]],\"5eHyY-\":[\"测试通知\"],\"5gF1Yx\":[\"点击添加。你可以在编辑器中重新排序步骤。\"],\"5hxDwt\":[\"CPU 任务\"],\"5iuLzq\":[\"预设\"],\"5kX7hC\":[\"为重试延迟添加随机性，避免“惊群”。\"],\"5l9iMR\":[\"暂无模板\"],\"5r2EnB\":[\"NVENC AV1 快速\"],\"5raigv\":[\"待处理初始化分段上限\"],\"5s-Rk5\":[\"添加处理管道步骤\"],\"5vx6Be\":[\"重试所有失败的处理任务失败\"],\"5x03LH\":[\"远端存储的基础路径。支持元数据和时间占位符。\"],\"61RNR5\":[\"未找到日志文件\"],\"633mTs\":[\"创建过滤器失败\"],\"65DNcr\":[\"未知文件\"],\"677MgO\":[\"未找到引擎。\"],\"67RoFa\":[\"处理管道\"],\"67hU6L\":[\"主程序\"],\"6A0WJl\":[\"保存前修复配置错误\"],\"6Aih4U\":[\"离线\"],\"6BDqha\":[\"限制\"],\"6Dd2PM\":[\"维护\"],\"6GBt0m\":[\"元数据\"],\"6GJw73\":[\"暂无时间线事件\"],\"6H2zd1\":[\"发送测试通知失败\"],\"6Jmdzl\":[\"优先编码\"],\"6LJ142\":[\"加载引擎列表失败：\",[\"0\"]],\"6O3gfw\":[\"使用手机号和验证码\"],\"6Oaeoc\":[\"Cron\"],\"6Umgab\":[\"自动 (二维码 → 手机号和验证码 → 桌面)\"],\"6V3Ea3\":[\"已复制\"],\"6VKo3k\":[\"删除上一步骤产出的文件。在上传步骤之后使用是安全的。在转码/remux 之后，它删除的是转码结果而非原始文件——若要删除源文件，请改为在转码步骤上启用“成功后移除输入”。\"],\"6WdDG7\":[\"页码\"],\"6YtxFj\":[\"名称\"],\"6_-6tw\":[\"即将支持 \",[\"val\"],\" 渠道！\"],\"6_dCYd\":[\"概览\"],\"6bAENo\":[\"磁盘空间\"],\"6d9Ao4\":[\"该执行记录暂无日志。\"],\"6fi2dR\":[\"最大重试延迟（毫秒）\"],\"6gRgw8\":[\"重试\"],\"6geoEv\":[\"文件路径已复制到剪贴板\"],\"6h20bP\":[\"取消处理管道？\"],\"6hur5J\":[\"例如 --namespace my_account\"],\"6kItd_\":[\"上传 : 下载（不对称）\"],\"6ldwcL\":[\"快速压缩\"],\"6mF1Q8\":[\"提取 AAC\"],\"6moIoi\":[\"已配对\"],\"6mwiZ7\":[\"指定首选的内容分发网络（例如 ws-h5, hw-h5）。\"],\"6nFdlp\":[\"删除预设？\"],\"6p4wnA\":[\"仅支持 MP4/MOV/M4V\"],\"6qNpC1\":[\"优先选择特定画质（不区分大小写）。按 Enter 添加。\"],\"6sBUvP\":[\"添加一个流以开始观看会话。\"],\"6swMyy\":[\"可用预设\"],\"6tBUzp\":[\"成功后移除输入\"],\"6vFYPB\":[\"例如 flv, hls\"],\"6wLR55\":[\"编辑引擎\"],\"7-c73-\":[\"选择要在此节点中使用的处理任务或工作流。\"],\"71pK1Q\":[\"这将永久删除 \",[\"selectedCount\"],\" 个主播。此操作无法撤销。\"],\"72c5Qo\":[\"总计\"],\"73yjgk\":[\" - 会话：\",[\"0\"]],\"76ob5K\":[\"第一个输出文件路径\"],\"77da32\":[\"TPS 限制\"],\"78qtNg\":[\"此删除步骤删除的是转码后的结果文件，而不是原始录制文件。请改为在转码步骤上启用“成功后移除输入”。\"],\"7Bj3x9\":[\"失败\"],\"7C-Jn5\":[\"显示密码\"],\"7DqYk8\":[\"过滤器更新成功\"],\"7JVWl4\":[\"弹幕关闭时结束录制\"],\"7Jw_XW\":[\"错误详情\"],\"7K4dCf\":[\"尝试调整搜索或过滤器\"],\"7L01XJ\":[\"操作\"],\"7L3DyA\":[\"正则模式\"],\"7Oyczn\":[\"全局缓冲区大小\"],\"7SNWYf\":[\"不支持通过 Web 进行 Telegram 两步验证\"],\"7VwJ-5\":[\"请求速率限制\"],\"7VxXSg\":[\"浏览器状态\"],\"7_wpqP\":[\"推荐：8388608 (8 MiB)\"],\"7cmJQz\":[\"工作流创建成功\"],\"7fIIFx\":[\"TTWID 管理模式\"],\"7jRxmJ\":[\"应用启动时触发。\"],\"7kKmbL\":[\"0 表示不限制。\"],\"7p5kLi\":[\"仪表盘\"],\"7s6i2S\":[\"下载全部\"],\"7sMeHQ\":[\"键\"],\"7sNhEz\":[\"用户名\"],\"7tgEva\":[\"2\"],\"7vhWI8\":[\"新密码\"],\"800TmA\":[\"流数\"],\"80AR_c\":[\"从备份文件恢复\"],\"81mUvV\":[\"删除源视频文件\"],\"83M8VR\":[\"选择引擎\"],\"84B6U_\":[\"数值越低 = 质量越高\"],\"8HE-Aw\":[\"过滤互动玩法\"],\"8PNILp\":[\"解析流\"],\"8T864k\":[\"当下载成功完成时触发。\"],\"8TMaZI\":[\"时间戳\"],\"8Tg_JR\":[\"自定义\"],\"8WcAZl\":[\"过滤器创建成功\"],\"8WgMDL\":[\"归档设置\"],\"8XX_CD\":[\"暂无过滤器\"],\"8YINy3\":[\"配置路径（可选）\"],\"8ZsakT\":[\"密码\"],\"8_brI5\":[\"必须填写名称\"],\"8b6O3h\":[\"搜索日志...\"],\"8eRn2a\":[\"系统\"],\"8gGPQK\":[\"发件人地址\"],\"8iqQ6v\":[\"添加排除正则模式\"],\"8j1zGy\":[\"自动封面\"],\"8lz0kn\":[\"https://discord.com/api/webhooks/...\"],\"8mJUui\":[\"此浏览器不支持\"],\"8rp73h\":[\"配置已成功导入\"],\"8tjQCz\":[\"浏览\"],\"8tm102\":[\"WUP\"],\"8vQkDt\":[\"延迟\"],\"8wu9lr\":[\"排队中\"],\"8x_SfR\":[\"内存使用率\"],\"8yPA1y\":[\"检查主播是否在线的间隔。较慢的间隔可以减少 API 使用，但可能会延迟录制开始。\"],\"90Z1Nt\":[\"已开始重试处理任务\"],\"93Ucih\":[\"附加到命令的额外参数。\"],\"95g7x4\":[\"日志级别与模块\"],\"98oK0h\":[\"带宽限制帮助\"],\"9AfU7B\":[\"使用任务清单中的 danmu_inputs\"],\"9CFpCU\":[\"平均时长\"],\"9DW7l4\":[\"等待首次检查\"],\"9E34se\":[\"浏览和下载应用程序日志文件。按日期范围筛选或下载全部。\"],\"9ELT_X\":[\"启用监控\"],\"9G881z\":[\"无（默认）\"],\"9IO3UM\":[\"需要修改密码\"],\"9NKF63\":[\"例如 1080p, source, 原画\"],\"9OmEZr\":[\"HTTP 请求\"],\"9P6Cjx\":[\"可执行文件路径\"],\"9PbSQ8\":[\"暂时禁用\"],\"9SGdhm\":[\"X-Auth-Key\"],\"9SrfBr\":[\"按时间段调度\"],\"9UQ730\":[\"克隆\"],\"9UWq9R\":[\"启用弹幕/聊天消息与视频一起录制。\"],\"9Ui6mb\":[\"达到次数后跳过\"],\"9Ulhif\":[\"通知事件\"],\"9XOpfw\":[\"最大间隔（毫秒）\"],\"9eF5oV\":[\"欢迎回来\"],\"9hg9mc\":[\"下载中\"],\"9kAtVa\":[\"空间不足\"],\"9kmN8A\":[\"未添加元数据标签\"],\"9p14fd\":[\"配置代理策略和连接详情。\"],\"9pDx-X\":[\"从透传中排除 ASS\"],\"9rG25a\":[\"服务器地址\"],\"9uJs-K\":[\"HLS 调优\"],\"A-3LfF\":[\"预览缩略图\"],\"A-yfCV\":[\"扫码登录\"],\"A2LNUE\":[\"跳过了 \",[\"skippedCount\"],\" 个重复的流\"],\"A3JxgX\":[\"备用输出文件夹\"],\"A8uySC\":[\"删除过滤器失败\"],\"ABucgF\":[\"排除的扩展名\"],\"ADzdEE\":[\"优先选择特定流媒体协议。按 Enter 添加。\"],\"AEGM8s\":[\"复制失败\"],\"AGMlcc\":[\"返回录制会话\"],\"AIdJOG\":[\"IANA 时区（例如 Asia/Shanghai、UTC）。\"],\"AK4HwH\":[\"加载模板失败\"],\"AR0orP\":[\"我的归档工作流\"],\"AYbtd_\":[\"支持\"],\"Abl55S\":[\"留空以进行流复制\"],\"Ai2DeC\":[\"添加新过滤器\"],\"AlfqgK\":[\"观看\"],\"ApdJrx\":[\"斗鱼默认画质\"],\"Ar-5tA\":[\"最大刷新重试次数\"],\"AsJWjt\":[\"配置设置\"],\"AsnjLf\":[\"请先设置 Telegram Desktop 目录\"],\"AwXh-1\":[\"下载活动看起来正常\"],\"Az1ZEd\":[\"主播上线时运行。\"],\"AzCMby\":[\"加载平台列表失败：\",[\"0\"]],\"B0GspH\":[\"立即开始检查此主播。\"],\"B1KFZ1\":[\"* * * * * *\"],\"B2Zb_F\":[\"JSON\"],\"B3xl-Q\":[\"最后活动\"],\"B495Gs\":[\"归档\"],\"B4Ew1I\":[\"至少添加一个步骤\"],\"B6-TsG\":[\"小于此值的文件仍以单线程传输。示例：<0>250M</0>、<1>1G</1>。\"],\"B7dmPy\":[\"会话完成处理管道\"],\"BAWVb9\":[\"未配置覆盖项\"],\"BCL8Ot\":[\"Header 值\"],\"BFl5lQ\":[\"全高清缩略图\"],\"BGi3Wo\":[\"追加到输出文件名之前\"],\"BIXG5q\":[\"重试策略\"],\"BQvz2A\":[\"这些超时设置在管道工作线程池启动时应用。更改需要重启才能生效。\"],\"BWUEP1\":[\"仅 HTTP/1.1\"],\"BX_rCE\":[\"特定 TTWID Cookie\"],\"B_pQ8T\":[\"流分割策略\"],\"B_rQdm\":[\"会追加到所有 HLS 请求中。适用于签名 URL 或 CDN 路由。\"],\"BaQ0LA\":[\"在数据库中保留已完成、失败或已取消的任务及工作流执行记录的天数。设置为 0 可无限期保留。\"],\"Ban892\":[\"对远端 API 的每秒最大事务数（--tpslimit）。\"],\"Bcgdrh\":[\"名称自动填充成功\"],\"BdBelu\":[\"弹幕/分钟\"],\"Bhx3fS\":[\"已扫描！请在手机上确认\"],\"BjJjsB\":[\"FLV 流修复管道\"],\"BjitBo\":[\"下载 \",[\"0\"]],\"Bn9-El\":[\"默认\"],\"BnOMVx\":[\"极速压缩\"],\"BrrIs8\":[\"存储\"],\"Bs956d\":[\"使用 NVIDIA NVENC 的高质量 GPU 加速 H.264 编码（CQ 20）。最佳 NVENC 画质。\"],\"Bsurp4\":[\"上线时\"],\"Btgfql\":[\"处理任务配置\"],\"BwhhDv\":[\"选择主播\"],\"ByeSsO\":[\"安全与身份\"],\"C1TsH4\":[\"正在等待连接...\"],\"C2WreN\":[\"0:v:0\"],\"C4IQ_M\":[\"工作流删除成功\"],\"C95qpz\":[\"采样策略\"],\"CBVnKj\":[\"连接超时 (ms)\"],\"CKyk7Q\":[\"返回\"],\"CO6Iw8\":[\"IO 任务\"],\"COU0uA\":[\"扫描目录以查找命令创建的新文件。检测到的文件将传递到下一个管道步骤。\"],\"COjFGL\":[\"录制前请求每个 CDN，优先选择响应最快的。无法访问的 CDN 最后尝试。\"],\"CP3D8G\":[\"进度\"],\"CS276w\":[\"删除工作流？\"],\"CSZ9-b\":[\"TPS 限制帮助\"],\"CZIQWR\":[\"在运行桌面应用时，配置重要事件的原生操作系统通知。\"],\"Cav1Ci\":[\"弹幕\"],\"Cbxd8c\":[\"与带宽限制叠加生效——可避免单个大文件占满整条链路，同时仍允许多个小文件并行传输。\"],\"CdgoAe\":[\"创建“\",[\"0\"],\"”的副本并使用新名称。\"],\"CeRDHt\":[\"禁用 CDN\"],\"CfSf24\":[\"自适应轮询\"],\"Cj2Gtd\":[\"大小\"],\"Cm-_Q8\":[\"notifier@example.com\"],\"CmOYNg\":[\"插入到 -i input_url 之前的参数\"],\"CndmuB\":[\"流解析成功\"],\"CpwhPE\":[\"特定\"],\"CqO9qC\":[\"会话\"],\"CsekCi\":[\"正常\"],\"CvL6qO\":[\"最小间隔（s）\"],\"Cvl3Ur\":[\"例如 tmp\"],\"D-NlUC\":[\"系统\"],\"D0K_c6\":[\"此步骤已关联到预设 <0>\",[\"presetName\"],\"</0>。\"],\"D1U6Wk\":[\"TDL 交互式登录\"],\"D4xpys\":[\"当下载被手动取消时触发。\"],\"D5t8TK\":[\"工作流更新成功\"],\"DC2eVx\":[\"处理管道结构\"],\"DCKkhU\":[\"当前密码\"],\"DEpnGr\":[\"校验完整性\"],\"DHhJ7s\":[\"上一页\"],\"DIv6yv\":[\"此处理器没有可用配置。\"],\"DJsY8j\":[\"步骤标识符（唯一）\"],\"DKrPOn\":[\"更新日志配置失败\"],\"DL3whW\":[\"基础后处理：将 FLV 重封装为 MP4，并生成缩略图预览。\"],\"DNA4aQ\":[\"解密/处理后的分段在缓存中保留的时间。\"],\"DPfwMq\":[\"已完成\"],\"DRTrkZ\":[\"弹幕数\"],\"DS-5Kd\":[\"加载系统健康状态失败\"],\"DTAxdY\":[\"输出\"],\"DTD28p\":[\"磁盘空间不足时发出警报。\"],\"DTKoJC\":[\"未找到最近的录制会话。\"],\"DYJ_1s\":[\"处理管道统计\"],\"DYyL81\":[\"复制（流复制）\"],\"D_XERT\":[\"删除处理管道失败\"],\"DanbIO\":[\"外观与样式\"],\"DfRZHP\":[\"已配置的事件\"],\"DgVBNf\":[\"不支持 Telegram 两步验证密码\"],\"DjZUWm\":[\"将文件上传到云存储，上传成功后删除本地副本。\"],\"DkLWbn\":[\"smtp.gmail.com\"],\"DtXU8D\":[\"保留通知事件日志的天数。设置为 0 可无限期保留事件。\"],\"Dya8HF\":[\"修复 FLV 流\"],\"DzUNTV\":[\"写入超时（毫秒）\"],\"E4sw5m\":[\"会话完成\"],\"E555CB\":[\"附加到 `tdl login` 的可选额外参数。\"],\"E5h2rM\":[\"高质量 MP3\"],\"E8bXne\":[\"代理\"],\"EBdNVo\":[\"录制预览\"],\"ECGvyy\":[\"更新模板失败：\",[\"0\"]],\"EEMR5K\":[\"小时 (00-23)\"],\"ELDffV\":[\"取消处理任务失败\"],\"ELa85n\":[\"所有类型\"],\"EOsjKj\":[\"处理管道必须至少包含一个步骤\"],\"EQoNwM\":[\"文件配置\"],\"EVGGg9\":[\"查看详情\"],\"EXa7Eb\":[\"主播 ID 是必需的\"],\"EZCWHe\":[\"启动处理流程\"],\"EZgAlu\":[\"Bearer Token\"],\"E_QGRL\":[\"已禁用\"],\"EbgT4I\":[\"优先级从低到高\"],\"EdQY6l\":[\"无\"],\"Ef7StM\":[\"未知\"],\"Ejic1p\":[\"平台\"],\"EjzeaZ\":[\"提取设置\"],\"EmVl8I\":[\"默认值为 1（无突发）。当服务方容忍短时突发流量时可适当调高。\"],\"EtVJIx\":[\"绝对路径，或在 PATH 中时填写“streamlink”\"],\"ExpKh1\":[\"预设步骤\"],\"EzR6wK\":[\"音频处理\"],\"EztJxW\":[\"rclone 默认值：8\"],\"F-0S1r\":[\"探测超时 (毫秒)\"],\"F-T4fj\":[\"浏览处理任务生成的媒体文件\"],\"F08Z2D\":[\"未找到 TDL 二进制文件\"],\"F13uOJ\":[\"快速编码，便于在社交媒体或聊天中快速分享。\"],\"F3bW6y\":[\"平台\"],\"F6Go3M\":[\"Header 键\"],\"F6pfE9\":[\"启用\"],\"F6qVY1\":[\"天 (01-31)\"],\"F7wXp2\":[\"导入前删除所有现有配置。此操作无法撤销。\"],\"F9I0Bm\":[\"严重的系统错误或主播故障。\"],\"F9lPvE\":[\"例如 'best'、'worst'、'720p'、'audio_only'\"],\"FBIuPX\":[\"清除选择\"],\"FBsQxQ\":[\"这将永久移除“\",[\"0\"],\"”配置。此操作无法撤销。\"],\"FEr96N\":[\"主题\"],\"FIHR-e\":[\"当弹幕控制信号指示直播已关闭时，停止录制。\"],\"FIfpIQ\":[\"TDL 已登录\",[\"version\"]],\"FJxoQQ\":[\"任务配置\"],\"FLm1Nd\":[\"实时\"],\"FMKXt7\":[\"传输数\"],\"FRqceZ\":[\"采集设置\"],\"FSaByO\":[\"视频编码变化\"],\"FUbs2p\":[\"Rclone\"],\"FZg3wM\":[\"操作\"],\"F_amnS\":[\"时间范围\"],\"Fecn_0\":[\"引擎覆盖项\"],\"Ff0Dor\":[\"过去\"],\"FfHole\":[\"预设克隆成功\"],\"FgYX1w\":[\"当下载被拒绝时触发（例如熔断器）。\"],\"FnYEt6\":[\"主播分页\"],\"FnyzZP\":[\"活跃发言用户\"],\"FrndDV\":[\"原始分辨率缩略图\"],\"Fv9mIF\":[\"这些设置仅适用于桌面应用。服务器仍将正常发送通道通知（Discord/电子邮件/Webhook）。\"],\"FyGlJm\":[\"解析并播放直播流\"],\"FzHX8f\":[\"用于所有请求的显式 TTWID Cookie 值。\"],\"G1YI6L\":[\"用于认证的 HTTP cookie。刷新时会自动更新。\"],\"G39zBp\":[\"缓存\"],\"G4Ntrl\":[\"覆盖录制引擎。\"],\"G88OSb\":[\"处理队列过长时发出警告。\"],\"G9GerW\":[\"执行命令\"],\"G9ULuK\":[\"最大 HEVC/H.265 压缩（CRF 28），以获得最小文件体积。\"],\"G9jHpr\":[\"处理管道有效\"],\"GD1Pmm\":[\"阈值\"],\"GICGoz\":[\"瞬时错误\"],\"GRlUlG\":[\"输入参数\"],\"GRzPZD\":[\"如果设置，直播标题必须包含至少一个这些关键字。\"],\"GSDw5m\":[\"设置新的录制工具配置\"],\"GSr0rF\":[\"打开菜单\"],\"GWWwxV\":[\"循环保护\"],\"GYeo24\":[\"返回上一页\"],\"GbsW45\":[\"创建新处理任务\"],\"GcrmOG\":[\"至少需要一个输入路径\"],\"Gg8KwP\":[\"正在解析流 URL，请稍候。\"],\"GkxWrz\":[\"音频编码\"],\"GnfGTT\":[\"自定义请求头\"],\"GrdEmt\":[\"为此模板自定义特定的引擎设置。\"],\"GtJbUa\":[\"后台\"],\"GwDyhV\":[\"删除源 XML 文件\"],\"GyEhZV\":[\"最大 HEVC 压缩\"],\"H5UXax\":[\"确认导入\"],\"H7PCCE\":[\"<0>0</0> 表示不限速（rclone 默认值）。例如：当服务方将你限制为每秒 10 次请求时，可设为 <1>10</1>。\"],\"HCO_et\":[\"编码设置\"],\"HDYzmV\":[\"缺口评估间隔（毫秒）\"],\"HKxmVq\":[\"上传失败前的重试次数。\"],\"HM56Bx\":[\"创建中...\"],\"HQoaRh\":[\"用于处理/编码的参数\"],\"HRbpjg\":[\"新密码（至少 8 个字符）\"],\"HTu6w6\":[\"配置已成功导出\"],\"HUqDD6\":[\"添加环境变量\"],\"HVrxmo\":[\"清理后的主播名称\"],\"HWpPGm\":[\"GPU\"],\"H_IzXL\":[\"IO 任务超时\"],\"H_smbI\":[\"时长（毫秒）\"],\"HajiZl\":[\"本月\"],\"Hhc4OF\":[\"搜索处理任务...\"],\"HlhZ5D\":[\"启用 TLS\"],\"HmHwcC\":[\"固定间隔\"],\"HqohwS\":[\"最大上传数\"],\"HruwW2\":[\"自定义 User-Agent 字符串（可选）\"],\"HtrFfw\":[\"必须填写 URL\"],\"Hu-bBV\":[\"这将取消此处理管道中所有待处理和处理中的任务。\"],\"HuTRrW\":[\"流播放器\"],\"HuisDV\":[\"逐段处理管道\"],\"HvemsH\":[\"均值 \",[\"averagePerMinute\"]],\"HzqrrK\":[\"检查 TDL 状态失败：\",[\"0\"]],\"I0LpOM\":[\"需要手动更新\"],\"I1WA96\":[\"Rust-Srec\"],\"I2M7LB\":[\"平台名称\"],\"I3Tk-i\":[\"引擎的身份与类型。\"],\"I6bYOx\":[\"调整侧边栏样式和行为。\"],\"IBg4fi\":[\"未发现错误。最大深度：\",[\"0\"]],\"IBn2_D\":[\"延迟、代理与保留策略。\"],\"IDbOLP\":[\"超清\"],\"IDkbnS\":[\"配置此主播的录制规则。\"],\"IDqYXq\":[\"尝试获取可用的最高原画质量。\"],\"IGmq_R\":[\"录制延迟\"],\"II3urw\":[\"文件操作\"],\"IKSVos\":[\"例如 /data/tdl\"],\"ILdR4t\":[\"重封装 (Faststart)\"],\"IMcoE-\":[\"嵌入\"],\"IOMDKm\":[\"启动登录失败：\",[\"0\"]],\"IOnYeq\":[\"重排最大分段数\"],\"IQTWsi\":[\"输入要排除的关键词...\"],\"IQlf-W\":[[\"0\"],\"/\",[\"1\"],\" 步\"],\"IR6zrc\":[\"处理所选主播失败\"],\"ISAp-4\":[\"模块日志级别\"],\"ISgi7W\":[\"上传所有内容，无论文件类型如何\"],\"IUwGEM\":[\"保存更改\"],\"IagCbF\":[\"URL\"],\"IduvKA\":[\"更新优先级失败\"],\"IfLN8u\":[\"监控此主播\"],\"IgrLD_\":[\"暂停\"],\"IiCZ6K\":[\"等待下载\"],\"IjXiiq\":[\"过滤重复标签并检测流重放循环\"],\"IjvCsG\":[\"原生\"],\"IkqmLx\":[\"导入配置\"],\"IpYp3U\":[\"最小比特率（bps）\"],\"IqjaXP\":[\"例如 mp4\"],\"IrGyPi\":[\"MB/s\"],\"IvxHT8\":[\"浏览器通知\"],\"IwBsC9\":[\"等待恢复超时后确认。\"],\"J-PlWE\":[\"变量：{streamer}、{title}、{platform} | 时间：%Y、%m、%d、%H、%M、%S\"],\"J0UZ9c\":[\"自定义导入主题已激活。\"],\"J28zul\":[\"连接中...\"],\"J2AZlk\":[\"确定要删除此主播吗？\"],\"J4VbZP\":[\"主播创建成功\"],\"J50_e4\":[\"过滤器类型\"],\"J5nbej\":[\"严重\"],\"J6n7sl\":[\"进行中\"],\"J7EJf5\":[\"未找到 TDL\"],\"J8slJP\":[\"后处理管道完成时运行。\"],\"J9cyui\":[\"当前正在直播的主播会显示在这里。\"],\"JB_0cx\":[\"首选 CDN\"],\"JDgrxe\":[\"原画 / 蓝光\"],\"JDiqy2\":[\"每个采样周期捕获的目标消息数量。\"],\"JEGlfK\":[\"已开始\"],\"JGAkEK\":[\"返回详情\"],\"JGiexj\":[\"优化流头部\"],\"JJZidX\":[\"弹幕服务\"],\"JK4_Ie\":[\"无（剥离音频）\"],\"JO8-jA\":[\"管理订阅\"],\"JRQitQ\":[\"确认新密码\"],\"JU-LKs\":[\"在节流生效之前，可短时超出 TPS 限制的请求数量。\"],\"JY5Oyv\":[\"数据库\"],\"J_hVSQ\":[[\"0\"]],\"JbursE\":[\"TDL 账户命名空间 (`tdl --ns ...`)。使用它来管理多个 Telegram 账户。\"],\"Jdxa-V\":[\"无限等待\"],\"Jf28Qk\":[\"重试处理管道\"],\"JiIKww\":[\"高级设置\"],\"Jj9mVU\":[\"删除渠道？\"],\"JlFcis\":[\"发送\"],\"Jmb3Ih\":[\"基于速度（动态）\"],\"JrUYse\":[\"最接近目标码率\"],\"JrcCgP\":[\"当前过滤指令\"],\"Jsp72G\":[\"已处理缓冲倍数\"],\"Ju0JNh\":[\"已取消处理管道中的 \",[\"0\"],\" 个步骤\"],\"JuSQP0\":[\"转换后检查文件是否存在\"],\"JzY5pj\":[\"描述此工作流的用途...\"],\"K-3SzA\":[\"ffmpeg\"],\"K2vkEv\":[\"已恢复\"],\"K38wNo\":[\"模板信息\"],\"K3UhxB\":[\"选择日期范围\"],\"K4zqVo\":[\"创建工作流失败：\",[\"0\"]],\"K7tIrx\":[\"分类\"],\"KAmTRT\":[\"启用或禁用此 Webhook\"],\"KCzvDi\":[\"添加参数模板\"],\"KDw4GX\":[\"重试\"],\"KOT_Rc\":[\"多线程流数帮助\"],\"KPx1UV\":[\"未找到\"],\"KSS7Tg\":[\"排除关键字\"],\"KTmH5k\":[\"分离并编辑\"],\"KUyMyQ\":[\"优雅停止超时\"],\"KWdZQl\":[\"例如 avc, hevc, av1\"],\"KWm8rr\":[\"机器人名称\"],\"KYBAbP\":[\"启用后，每个点播分段都必须在该超时内完成。\"],\"KYdyb_\":[\"使用移动端端点进行提取。\"],\"KZOdTD\":[\"客户端类型 (ctype)\"],\"KZVQ3R\":[\"空间节省\"],\"K_-HXP\":[\"目录路径已复制\"],\"KftHu1\":[\"最小间隔（毫秒）\"],\"KhgRw2\":[\"搜索事件...\"],\"KlrNJU\":[\"所有系统组件与资源的实时状态。\"],\"KmtY_Q\":[\"处理管道中有失败的处理任务。点击查看详情。\"],\"KnPHvA\":[\"排队刷新阈值\"],\"KoQLpo\":[\"使用 <0>-movflags +faststart</0>\"],\"Kp3hk-\":[\"允许私有网络流代理目标\"],\"KpU39u\":[\"单文件上限（rclone --bwlimit-file）。语法与带宽限制相同，并与之叠加生效。\"],\"KtTU7C\":[\"认证类型\"],\"KwS2c-\":[\"处理行为\"],\"L0Zj1K\":[\"处理管道配置\"],\"L2_QkP\":[\"处理任务创建成功\"],\"L3fUx7\":[\"错误：无法加载预设详情。\"],\"L4RAy4\":[\"CDN黑名单\"],\"L4c5Kt\":[\"-c copy\"],\"L5RWy_\":[\"高清缩略图\"],\"L5WboT\":[\"HTTP 版本偏好\"],\"LBEZuS\":[\"引擎列表\"],\"LBjZN4\":[\"分 (00-59)\"],\"LCcfAH\":[\"加载引擎失败：\",[\"0\"]],\"LKPR6G\":[\"播放列表\"],\"LLAa_9\":[\"可选\"],\"LMn91f\":[\"优先使用清单\"],\"LNcpxP\":[\"Mozilla/5.0...\"],\"LPJyAY\":[\"如何将视频文件与 .ass 字幕配对\"],\"LS_ord\":[\"例如 128k、320k\"],\"LTNjeK\":[\"容器\"],\"LXMVBR\":[\"处理成功后删除源文件\"],\"L_OaTA\":[\"云端归档\"],\"L_XFOI\":[\"处理器详细设置\"],\"LbS6Kh\":[\"最低优先级\"],\"Le70b5\":[\"更多页面\"],\"LhOLxv\":[\"在阻塞线程池中执行解密，避免卡住异步任务。\"],\"LiJMls\":[\"搜索平台...\"],\"Lk1OGO\":[\"离线检测\"],\"LoIUGx\":[\"支持占位符：\",[\"PLACEHOLDER_TOKENS\"],\"，以及 %Y/%m/%d 等时间占位符。\"],\"LovaQl\":[\"元数据获取的最大重试次数。\"],\"LqH9mJ\":[\"后端持久化的最近通知事件\"],\"Lqw9xW\":[\"评分规则\"],\"LubEtv\":[\"编辑预设：\",[\"0\"]],\"M0uszl\":[\"预设创建成功\"],\"M3QKhO\":[\"平台专属配置\"],\"M6bo58\":[\"C:path1.flv,C:path2.flv\"],\"M73whl\":[\"上下文\"],\"MA1f6y\":[\"高+\"],\"MAKqi9\":[\"例如 10M\"],\"MDzSyV\":[\"管理凭据和会话 Cookie。\"],\"MF-nAj\":[\"若输出文件已存在则替换\"],\"MHrjPM\":[\"标题\"],\"MItgvI\":[\"默认引擎\"],\"MMoZg-\":[\"流映射 (-map)\"],\"MNP_Hr\":[\"已处理分段 TTL（毫秒）\"],\"MQpz7h\":[\"成功添加 \",[\"0\"],\" 个流\"],\"MS1IQE\":[\"双格式输出\"],\"MUbeuH\":[\"HLS 流修复管道\"],\"MUwvHM\":[\"限制与校验\"],\"MXuUDu\":[\"当“上传所有文件”启用时忽略。\"],\"MYKXvL\":[\"未知引擎 ID：\",[\"engineId\"]],\"Mbxk4g\":[\"目标比特率\"],\"MhA4UF\":[\"克隆预设\"],\"Mi9SD3\":[\"允许应用内播放器代理位于私有或本地网络上的源（局域网转推、摄像头、tailnet 地址）。除非你从本地源拉流，否则请保持关闭：它会为任何已登录用户重新开放对内部地址的请求。\"],\"Mj31d8\":[\"覆盖全局默认值。\"],\"Ml0gf8\":[\"多媒体归档\"],\"MmFX21\":[\"条已暂停\"],\"MpHt5u\":[\"添加主播\"],\"Mpp8ys\":[\"处理管道\"],\"MqP8N6\":[\"强制原画质量\"],\"MqVdIO\":[\"1（最佳）- 31（最差）\"],\"MqWOyf\":[\"校验数帮助\"],\"Mr5Sgb\":[\"提取 AAC（256kbps）音频轨道，质量高且兼容性好。\"],\"MucYW8\":[\"测试通知已发送\"],\"Mv6ltO\":[\"最大码率 (bps)\"],\"MvqJb2\":[\"创建引擎\"],\"N1_4qm\":[\"当处理任务成功完成时触发。\"],\"N2fC4V\":[\"分段下载的最大并发数。\"],\"N2twk8\":[\"日期占位符锚点\"],\"N40H-G\":[\"全部\"],\"N4UV0r\":[\"主播在平台上已不存在\"],\"N5gYIZ\":[\"用于实时应用的低延迟 GPU 加速 H.264 编码。编码延迟极低。\"],\"N5vGcw\":[\"输入你的凭据以登录账户\"],\"N683xA\":[\"最大比特率（bps）\"],\"N7OGFm\":[\"请重启系统以确保所有配置更改已完全生效。\"],\"N7k2OK\":[\"例如 /output/processed/\"],\"N8YRwn\":[\"例如 黑名单CDN\"],\"NAhOWx\":[\"致命：\"],\"NBO4gh\":[\"并发与性能\"],\"NBdIgR\":[\"评论\"],\"NEnLep\":[\"全局默认\"],\"NFIOKv\":[\"高级选项\"],\"NF_R69\":[\"未找到处理任务\"],\"NGO2th\":[\"所有模板\"],\"NHNfp3\":[\"引擎名称\"],\"NHyRGf\":[\"不区分大小写\"],\"NIBH_E\":[\"加载处理任务失败\"],\"NJevqq\":[\"校验\"],\"NKYo1x\":[\"ASS 字幕内嵌\"],\"NMhr2I\":[\"用于订阅者专享和高质量流的 Twitch OAuth 令牌。\"],\"NPY5Jv\":[\"配置新的录制工具。\"],\"NQSiYb\":[\"按名称或描述搜索...\"],\"NQas2m\":[\"当视频和弹幕分段都可用时运行。需要启用“录制弹幕”。\"],\"NRL17y\":[\"主播检查历史\"],\"NUrY9o\":[\"分类\"],\"NV_uNb\":[\"服务器未配置 Web 推送\"],\"NaT0vv\":[\"用于直播流提取的 API 协议。WUP 是 PC 客户端的标准协议。请注意，WUP 和 MP 仅适用于数字房间 ID。\"],\"NeoigG\":[\"文件大小阈值（--multi-thread-cutoff）。\"],\"NggFDy\":[\"系统总负载\"],\"NhNdzH\":[\"在录制会话结束且所有单个分段管道均已完成后运行一次。适用于合并所有分段、最终通知或清理等全局操作。\"],\"Nhf7R9\":[\"Telegram 桌面版目录\"],\"Ni3LjM\":[\"分辨率变化\"],\"NkYQEG\":[\"i\"],\"NmgWBj\":[\"删除工作流失败：\",[\"0\"]],\"NnH3pK\":[\"测试\"],\"NoNwIX\":[\"未激活\"],\"NomFnG\":[\"未检测到更改\"],\"NotuGY\":[\"该平台所有主播共享的每秒状态检查次数。平台返回 HTTP 429 时会自动降速。\"],\"NrNA7z\":[\"创建你的第一个预设，用于为处理管道定义可复用的处理器配置。\"],\"NstKgB\":[\"最佳\"],\"Nu4DdT\":[\"同步\"],\"Nu4oKW\":[\"描述\"],\"Nw_bTM\":[\"进行中\"],\"NxVfIs\":[\"总体健康\"],\"Nz-2uW\":[\"录制引擎\"],\"Nzwli2\":[\"基础\"],\"O3-SAG\":[\"录制将在计划允许时开始\"],\"O7sQsS\":[\"即使应用已最小化，操作系统仍会显示桌面通知。\"],\"O8tK4v\":[\"添加规则\"],\"O92EVI\":[\"验证输出\"],\"OD_lHs\":[\"添加覆盖项以自定义特定平台的行为。\"],\"OGA8CA\":[\"重封装/转码\"],\"OGryJ9\":[\"移动文件\"],\"OH6m8Z\":[\"没有其他可依赖的步骤。\"],\"OHthyf\":[\"已拒绝\"],\"OIx5Vo\":[\"此配置模板的基本信息。\"],\"OJx3wK\":[\"不可用\"],\"OL5-bu\":[\"/usr/share/fonts\"],\"ONWvwQ\":[\"上传\"],\"ONchxy\":[\"总计\"],\"OO3KPE\":[\"无配置。\"],\"OOXKcO\":[\"选择会为 <0>\",[\"0\"],\"</0> 触发通知的事件。\"],\"OR475H\":[\"网络\"],\"ORCmn3\":[\"头像 URL（可选）\"],\"OS845W\":[\"为此配置模板提供一个唯一的名称。\"],\"OSkKYq\":[\"平台配置\"],\"OUlu9A\":[\"采样率（Hz）\"],\"OXyghd\":[\"加载处理任务列表失败：\",[\"0\"]],\"Oc-UBb\":[\"微调品牌和表面颜色。\"],\"OfhWJH\":[\"重置\"],\"Oj01eI\":[\"最小剩余空间\"],\"Oju_Jw\":[\"替换输出文件\"],\"OkJ0bQ\":[\"TDL 状态未知\",[\"version\"]],\"Okz6xA\":[\"不在计划时间内\"],\"OlFEI3\":[\"满足任一条件后跳过\"],\"OlXThP\":[\"带宽\"],\"Om3XSw\":[\"API 设置\"],\"Oq4ArH\":[\"未识别的事件类型。\"],\"OqVga-\":[\"此操作开销较低，默认值 8 通常已足够。如果远端较慢且有大量小文件，可适当调高。\"],\"OrxruT\":[\"复制目录\"],\"Ou1XUj\":[\"校验失败\"],\"OurehU\":[\"检测到平台：\",[\"detectedPlatform\"]],\"OvTPz2\":[\"选择操作\"],\"Ovx4g5\":[\"https://twitch.tv/...\"],\"OxBy4W\":[\"出错时继续\"],\"P-1VRa\":[\"平台配置可用时将显示在此处。\"],\"P4KIZR\":[\"重封装为 MKV\"],\"P5eNZ-\":[\"最后错误日志\"],\"P6F38F\":[\"订阅\"],\"P8fBlG\":[\"认证\"],\"PE3gQR\":[\"CDN\"],\"PFFhCT\":[\"高度\"],\"PFgYun\":[\"无标题直播\"],\"PH-ukv\":[\"-reconnect 1\"],\"PIjwVk\":[\"生成全高清缩略图（宽 1280px），适用于现代显示器与播放器。\"],\"PJDaJr\":[\"选择此过滤器生效的日期。\"],\"PJf7YE\":[\"在: \",[\"0\"],\" 之后\"],\"PMM5g4\":[\"自定义 FFmpeg\"],\"PRzc1D\":[\"上传并删除\"],\"PTBQLa\":[\"创建目录\"],\"PTI2EV\":[\"（删除源文件）\"],\"PUbRZA\":[\"最大重试尝试次数。\"],\"PVRSCC\":[\"正在加载预设...\"],\"PVnlzh\":[\"添加步骤\"],\"Pa-XIK\":[\"准备观看\"],\"PaO3bE\":[\"该实体不支持会话完成处理管道。\"],\"PaQ3df\":[\"启用\"],\"Pcymhf\":[\"等待时长\"],\"PezyAA\":[\"更新渠道\"],\"PfW3ld\":[\"在持续的下载进度确认流状态健康之前，暂时的监控错误会继续保留。\"],\"PfWJSn\":[\"校验数\"],\"Pfx_nq\":[\"组件状态\"],\"PhEJJc\":[\"命令执行后扫描新文件的目录\"],\"Pi72nP\":[\"主播离线\"],\"PiH3UR\":[\"已复制！\"],\"PkUJDM\":[\"Gotify 应用令牌\"],\"Pn2B7_\":[\"当前密码\"],\"Poh-g6\":[\"创建处理管道\"],\"Pw01g0\":[\"隐藏密码\"],\"Pwqkdw\":[\"加载中…\"],\"PzFzS-\":[\"添加过滤器\"],\"Q-6haG\":[\"未找到凭据。\"],\"Q-pr7E\":[\"弹幕统计\"],\"Q0LExG\":[\"登录会话已取消\"],\"Q3D3r6\":[\"libx264\"],\"Q3TXK1\":[\"连续失败\"],\"Q5duaX\":[\"悬浮\"],\"Q6hhn8\":[\"偏好设置\"],\"Q7Xlbh\":[\"斗鱼超清画质值\"],\"QBhhb7\":[\"复制文件\"],\"QCzB9v\":[\"输入路径\"],\"QDaWgK\":[\"DanmakuFactory\"],\"QEazml\":[\"删除所选\"],\"QFO8CU\":[\"使用 DanmakuFactory 将弹幕 XML（Bilibili 格式）转换为 .ass 字幕。支持清单感知和批量处理。\"],\"QHcLEN\":[\"已连接\"],\"QHhZeE\":[\"模板创建成功\"],\"QMb3Xr\":[\"指数退避的倍率。\"],\"QRAWTO\":[\"表单\"],\"QWdKwH\":[\"移动\"],\"Q_OjVh\":[\"主播直播时检测流结束的检查间隔。\"],\"Q_vbsf\":[\"弹幕转 ASS\"],\"Qc-YIj\":[\"为特定平台覆盖配置（路径、延迟等）。\"],\"QcH0aB\":[\"无损分割\"],\"QglPWi\":[[\"1\"],\" 时为 \",[\"0\"]],\"Qj-jxB\":[\"退出沉浸模式\"],\"QkHwaF\":[\"已为此浏览器启用 Web 推送\"],\"QlT4B5\":[\"最近录制会话\"],\"QmWJ8K\":[\"587\"],\"QqGYdP\":[\"选择默认引擎\"],\"QrXc9O\":[\"禁用所选\"],\"Qttcbs\":[\"触发检查失败\"],\"QulvHL\":[\"每个流按其满足的规则累计权重得分，得分最高者胜出。上方的偏好设置仅用于打破平局。禁用 CDN 规则会完全排除对应的流。\"],\"QvdYlQ\":[\"字体目录\"],\"QxKlNl\":[\"GPU 标准\"],\"QyYvAV\":[\"精确时间表\"],\"R3w8ra\":[\"未设置关键字\"],\"R7mB1g\":[\"预览不可用\"],\"R8WLd-\":[\"强制 IPv4（默认：关闭）\"],\"RAHVXV\":[\"如果你的账户启用了 Telegram 两步验证，请使用桌面登录（`-T desktop`）或在终端中本地运行 `tdl login`。\"],\"RAMost\":[\"未找到媒体文件\"],\"RDVO7W\":[\"跳转到第 \",[\"p\"],\" 页\"],\"RDf8Wy\":[\"GPU 加速后处理：NVENC H.264 编码和缩略图生成。\"],\"RJEhaJ\":[\"https://lb-eu.cdn-perfprod.com\"],\"RMguW7\":[\"平台配置更新成功\"],\"RP7-1c\":[\"Windows 控制台错误\"],\"RQI0-M\":[\"输出检测\"],\"RRWxve\":[\"用于流认证签名的客户端平台类型。不同平台使用不同的签名方式。\"],\"RRrXGo\":[\"请输入你的新密码信息\"],\"RS0o7b\":[\"状态\"],\"RS9BzM\":[\"在提取检查期间，将互动玩法视为离线平台。\"],\"RTR7ET\":[\"FLV 解析器\"],\"Ralayn\":[\"添加自定义标签\"],\"Ratxqc\":[\"开始桌面登录\"],\"RbevHh\":[\"批量解析失败：\",[\"0\"]],\"RclaHZ\":[\"当视频分段及其对应的弹幕分段都可用时运行。\"],\"RdUvEI\":[\"文件名模板\"],\"RdhLi5\":[\"待清除错误\"],\"RehBv_\":[\"为此主播覆盖全局默认值。\"],\"RhkH8I\":[\"我的渠道\"],\"RkoA4Q\":[\"工作目录\"],\"RmEeKl\":[\"字节\"],\"RoOfVq\":[\"日期范围：\"],\"RojhAf\":[\"用户名（可选）\"],\"RqGEko\":[\"分段\"],\"Rsjgm0\":[\"实验性\"],\"Rti4g1\":[\"用于录制的底层工具。\"],\"Ruqy5-\":[\"如果直播间被主播设置了密码保护，则为必填。\"],\"RxzN1M\":[\"启用\"],\"S-Irrb\":[\"更新现有项目并创建新项目，不会删除任何内容。\"],\"S-gCuS\":[\"仅 HTTP/2\"],\"S0kLOH\":[\"ID\"],\"S1Avy-\":[\"此会话的弹幕统计暂不可用。\"],\"S2X4xO\":[\"未生成输出\"],\"S32Mbj\":[\"未找到平台\"],\"S34mdL\":[\"密码锁房的默认密码。可在主播 URL 上用 ?pwd= 覆盖。\"],\"S4s1m4\":[\"更新预设失败：\",[\"0\"]],\"S5wLae\":[\"输入 URL 以解析并播放流\"],\"SCZJhh\":[\"音频比特率\"],\"SE58Y_\":[\"管理系统通知发送到哪里\"],\"SG6r-s\":[\"密码/两步验证被阻止\"],\"SHEb8h\":[\"未找到预设\"],\"SHZOTz\":[\"更新主播失败\"],\"SJV-d4\":[\"使用直播开始时间可让跨午夜直播保存在同一个日期文件夹中，并将同一会话的所有分段归组到一起。\"],\"SJd-31\":[\"使用系统代理\"],\"SJmm2B\":[\"使用 NVIDIA NVENC 的快速 GPU 加速 AV1 编码（CQ 28）。需要 RTX 4000+ 系列显卡。\"],\"STFGOH\":[\"HTTP 中间件\"],\"SWoipt\":[\"依赖于（前辈节点）\"],\"SYKx8m\":[\"生成新二维码\"],\"SZIVAR\":[\"下载管理\"],\"SZw9tS\":[\"查看详情\"],\"S_H6kY\":[\"强制 IPv6（默认：关闭）\"],\"S_tge3\":[\"输出路径、模板与格式。\"],\"Sel_id\":[\"配对分段\"],\"ShPPm7\":[\"此会话未记录任何标题变更或生命周期事件。\"],\"SjnTbJ\":[\"高级配置\"],\"Sl7mrQ\":[\"按大小分割。\"],\"SlfejT\":[\"错误\"],\"Svkela\":[\"上一页\"],\"SwbFPL\":[\"streamlink\"],\"SwdouK\":[\"NVENC H.264 低延迟\"],\"SxJsAt\":[\"选择你偏好的配色方案。\"],\"Sxm8rQ\":[\"用户\"],\"T1SAsR\":[\"会话缩略图\"],\"T3rjBy\":[\"处理录制内容后，处理任务将显示在此处。\"],\"T4sjpn\":[\"合并更改\"],\"T5l00f\":[\"工作流\"],\"T6F_S1\":[\"处理队列已满时发出严重警报。\"],\"T7yhkH\":[\"输出格式\"],\"T8_uFg\":[\"设置已针对该平台优化。\"],\"T8drou\":[\"系统健康\"],\"T91vKp\":[\"播放\"],\"TAsVLX\":[\"创建模板失败：\",[\"0\"]],\"TB_URP\":[\"选择日期锚点\"],\"TCURBK\":[\"（保留源文件）\"],\"TH-C6m\":[\"成功删除 \",[\"0\"],\" 个会话\"],\"TJBHlP\":[\"标准\"],\"TKiEkr\":[\"选择当前页\"],\"TLZ7Zy\":[\"多线程上传仅对大文件有效；对小文件而言，分块开销会超过其收益。\"],\"TP4vdF\":[\"--hls-live-edge 3\"],\"TP9_K5\":[\"令牌\"],\"TXe7lI\":[\"查看详细日志与输出\"],\"TYVgbP\":[\"包含\"],\"TYVyHm\":[\"未分配模板\"],\"T_R-Qz\":[\"主要\"],\"TaKLqK\":[\"排除模式\"],\"TaeloW\":[\"任意优先级\"],\"TduT07\":[\"正在启动 tdl 进程...\"],\"Tfm8LB\":[\"匹配分辨率\"],\"TiE1q0\":[\"点播缺口策略\"],\"TjrbDj\":[\"运行时间\"],\"Tkh4vv\":[\"最高质量缩略图\"],\"TlWFTu\":[\"正在下载 \",[\"0\"]],\"Tm4H6N\":[\"输入内容...\"],\"TnMphH\":[\"轻松自动化您的直播录制。\"],\"ToM7t8\":[\"优先选择特定 CDN 提供商。按 Enter 添加。\"],\"ToW2mM\":[\"删除引擎？\"],\"Tof7pX\":[\"处理任务\"],\"Tu5he7\":[\"将互动玩法直播视为离线。\"],\"Tu7ijM\":[\"将“TDL 二进制路径”设置为你的 `tdl` 可执行文件，或确保 `tdl` 在 PATH 中。\"],\"Tu9eY0\":[\"生效日期\"],\"TxOTCX\":[\"用于桌面登录（备选）；指向包含 `tdata` 的 Telegram Desktop 文件夹。\"],\"TymLa2\":[\"数值越高，在高速网络下越快，但对远端和本机 CPU 的压力也更大。仅在网络与服务方能承受时才建议调高。\"],\"Tz0i8g\":[\"设置\"],\"U1Im-N\":[\"模板名称\"],\"U5tR0I\":[\"录制发生错误时运行。\"],\"U62Eow\":[\"在此终端中完成 Telegram 登录过程。\"],\"U80ejq\":[\"连续失败\"],\"U9NWQ6\":[\"整体系统状态\"],\"UC17hj\":[\"密码锁房的默认密码（可在主播 URL 上用 ?pwd= 覆盖）。\"],\"UCFp-L\":[\"正在检查流状态...\"],\"UCRwk1\":[\"/path/to/rclone.conf\"],\"UGLwm7\":[\"选择优先级\"],\"UHgtCG\":[\"未找到主播\"],\"UPqS9C\":[\"健康\"],\"UPyNTs\":[\"MB\"],\"URWWZg\":[\"例如 高质量 Streamlink\"],\"UWuUpd\":[\"在生命周期事件上执行 Shell 命令。命令在系统 Shell 中运行。\"],\"UYX0mr\":[\"尝试调整搜索或过滤器，或添加新主播以开始监控。\"],\"UZREi-\":[\"模板\"],\"UZc_Dd\":[\"暂无发言用户数据\"],\"UaBHAx\":[\"哔哩哔哩扫码登录\"],\"UatJ7w\":[\"退出选择模式\"],\"UbRKMZ\":[\"待处理\"],\"UcB-qb\":[\"删除所选主播？\"],\"UcL3cf\":[\"接受无效证书（默认：关闭）\"],\"UhCJxj\":[\"处理器\"],\"UicgBF\":[\"双击以编辑项目。\"],\"Uj7KrY\":[\"使用二维码登录\"],\"UlKSMd\":[\"左侧\"],\"Un7b8x\":[\"高频词\"],\"Uns82o\":[\"需要重启\"],\"UnuCpk\":[\"执行超时\"],\"Ur7o1U\":[\"本地归档\"],\"Urp3J5\":[\"最大程度压缩以尽量降低存储占用，然后删除原始文件。\"],\"Uu2O-E\":[\"当视频和弹幕分段都可用时运行。\"],\"UwlIzL\":[\"分配模板\"],\"UxK12K\":[\"录制内容\"],\"UxpxnD\":[\"文件操作\"],\"UyWBj5\":[\"不健康\"],\"Uypo6H\":[\"取消 IO 密集型处理器前的超时时间。\"],\"V3Lf0z\":[\"上传 (Rclone)\"],\"V50IgX\":[\"加载工作流列表失败：\",[\"0\"]],\"V7Msqg\":[\"预设\"],\"V8SjEq\":[\"包含关键字\"],\"VAn9oA\":[\"GPU 不可用时(通常由 NVIDIA Container Toolkit 在 cgroup v2 上的已知问题导致),NVENC 转封装/转码任务会失败,此时发出严重告警。\"],\"VFX661\":[\"达到时长后跳过\"],\"VGt9mz\":[\"裁剪与时间\"],\"VJScHU\":[\"原因\"],\"VJw9N6\":[\"选择会话\"],\"VLfBk9\":[\"恢复中\"],\"VPPVAx\":[\"锁定文件重试次数\"],\"VPqi9P\":[\"未配置任何参数。\"],\"VQ4EeT\":[\"此预设的可选描述\"],\"VU-UYl\":[\"渠道已更新\"],\"VUURcc\":[\"生效范围\"],\"VZobzV\":[\"二维码已过期\"],\"Ve9s51\":[\"该实体不支持配对分段处理管道。\"],\"VeOdql\":[\"登录 Telegram\"],\"Vflq7Z\":[\"该主播正在直播，但全局下载并发已用满。一旦有空闲槽位，录制和弹幕采集将自动开始。\"],\"Vg88qu\":[\"运行带有占位符的自定义 Shell 命令（例如 \",[\"input\"],\", \",[\"inputs_json\"],\", \",[\"streamer\"],\", %Y%m%d）。\"],\"VhBoUx\":[\"已加载模板：\",[\"0\"]],\"VhMDMg\":[\"修改密码\"],\"ViIBwn\":[\"频道1,频道2\"],\"Vk_Ar1\":[\"更多筛选\"],\"VlEnCC\":[\"归档格式\"],\"VoevdT\":[\"输出文件夹\"],\"VupaNT\":[\"标准 Cron 表达式（秒 分 时 日 月 星期）。\"],\"VvhIcU\":[\"未添加自定义标签\"],\"Vw8l6h\":[\"发生错误\"],\"VzJOiq\":[\"处理管道管理器\"],\"W-0nOp\":[\"解密下放到线程池（默认：开）\"],\"W0g6Ji\":[\"元数据变量\"],\"W0omwk\":[\"逐步设计你的自动化处理管道\"],\"W1jNHq\":[\"使用哔哩哔哩移动端 App 扫码\"],\"W5FKSG\":[\"录制完成时\"],\"W743Px\":[\"过滤与行为\"],\"W7R6SY\":[\"应用令牌\"],\"WCNCz3\":[\"123456:ABC-DEF...\"],\"WEYdDv\":[\"推荐\"],\"WEmAU3\":[\"已确认离线\"],\"WIC5BC\":[\"搜索预设...\"],\"WJAaWC\":[\"每次采样目标弹幕数\"],\"WJXa70\":[\"Unix 时间戳\"],\"WKHqM-\":[\"权重\"],\"WOfIql\":[\"创建新预设\"],\"WRQtA_\":[\"例如 250M\"],\"WSlv-9\":[\"选择配置\"],\"WSxWtw\":[\"启用 Web 推送失败\"],\"WWCeOb\":[\"添加模块...\"],\"WXZiyd\":[\"超快 H.264 编码（CRF 26），用于快速分享。\"],\"W_-iQs\":[\"获取器\"],\"W_geaq\":[\"选择登录类型\"],\"WcOY3Y\":[\"机器人令牌\"],\"WcgzjF\":[\"name@example.com\"],\"WdAjAz\":[\"启用代理\"],\"Weq9zb\":[\"常规\"],\"WgACNk\":[\"移动文件\"],\"WgCCHx\":[\"录制成功完成时运行。\"],\"WhUS54\":[\"ZIP 归档\"],\"WhgehB\":[\"录制时长\"],\"X-oEI0\":[\"正在解析流...\"],\"X56hfZ\":[\"未找到平台配置，请先创建。\"],\"X5P6yv\":[\"最近更新\"],\"X6ddkF\":[\"低磁盘空间操作\"],\"XB0Vn9\":[\"未找到归档\"],\"XC33bC\":[\"面向长期存储优化的 H.264 中等压缩（CRF 23）。\"],\"XEH5pP\":[\"最大停顿时间（毫秒）\"],\"XElSLJ\":[\"登录参数\"],\"XEpExY\":[\"第 N 个输入：\",[\"input0\"],\", \",[\"input1\"],\"...\"],\"XF7Hlq\":[\"重新开始\"],\"XJumtU\":[\"处理管道步骤可复用的处理器配置\"],\"XOxZT4\":[\"正在登录...\"],\"XPG0DN\":[\"新模板名称\"],\"XQaZzY\":[\"TPS 突发\"],\"XS1iGf\":[\"保留目录结构\"],\"XTz5Sa\":[\"rclone 默认值：4\"],\"XU5AOg\":[\"输入\"],\"XVCljg\":[\"多线程复制\"],\"XWgxXq\":[\"专辑\"],\"XY_iWu\":[\"网络与内容\"],\"X_MmPT\":[\"并行处理视频并提取播客音频，然后同时上传两者。\"],\"Xdd3Qd\":[\"当录制被结束以为更高优先级的主播腾出名额时触发。\"],\"Xit5Tv\":[\"音频属性\"],\"XjbMaz\":[\"返回处理任务列表\"],\"XjoBtr\":[\"过滤原因：\"],\"XmKjfh\":[\"录制\"],\"XvPVPo\":[\"配置并启动手动处理管道。\"],\"XvjC4F\":[\"保存中...\"],\"Xy0Hxb\":[\"支持 Rust 正则语法。\"],\"Y27roI\":[\"自定义画质值\"],\"YDVUVl\":[\"事件类型\"],\"YMf077\":[\"OAuth 令牌\"],\"YPcVP2\":[\"暂无预设\"],\"YVNfpq\":[\"（镜像源端）\"],\"YXjgxA\":[\"系统级偏好设置\"],\"Ya3PlJ\":[\"需要权限\"],\"YdqAGb\":[\"查看弹幕\"],\"YjhKI5\":[\"正在克隆...\"],\"YqG2MJ\":[\"设置录制限制。\"],\"Ysuy2A\":[\"模板克隆成功\"],\"Yu2FvR\":[\"这将永久删除预设“\",[\"0\"],\"”。若不更新，使用该预设的处理管道可能会失败。\"],\"YuTKa4\":[\"下载失败\"],\"Yy3BPK\":[\"使用 HEVC 进行最高质量压缩，然后上传到云存储。\"],\"Z0iPqp\":[\"配置录制引擎参数\"],\"Z3KFHf\":[\"退避倍率\"],\"Z5vYTW\":[\"管理应用配置。\"],\"ZAVcwe\":[\"正在修改密码...\"],\"ZBMTd_\":[\"未找到匹配的事件\"],\"ZF0SVM\":[\"每个文件的流数（--multi-thread-streams）。\"],\"ZKI9Rd\":[\"启用高级 HLS 分段重建\"],\"ZMViOQ\":[\"总大小：\"],\"ZNKcYa\":[\"所有级别\"],\"ZNg9mI\":[\"重置密码\"],\"ZOK2-a\":[\"type=bolt,path=/data/tdl\"],\"ZOtn9F\":[[\"minutes\"],\" 分钟\"],\"ZTc3M9\":[\"下载断开\"],\"ZWRt1W\":[\"输出路径\"],\"ZWZjuT\":[\"样式\"],\"ZYn2Q_\":[\"创建过滤器\"],\"ZYnwzF\":[\"已断开\"],\"Za9T3e\":[\"检查耗时\"],\"Zag-nw\":[\"Rclone 传输\"],\"ZfRjhV\":[\"最大 CPU 任务数\"],\"Zi7dyz\":[\"脚本\"],\"Zl_Fwr\":[\"管理专门的提取和识别选项。\"],\"ZlzjXV\":[\"透传输入\"],\"Zrh7RT\":[\"更新平台失败：\",[\"0\"]],\"Zv4awZ\":[\"128k\"],\"Zw6LWb\":[\"删除处理任务失败\"],\"ZyqOPH\":[\"凭据来源状态\"],\"Zz_XpE\":[\"按主播\"],\"Zza1dz\":[\"NVENC HEVC 快速\"],\"_-DHD0\":[\"配置一个新的主播以进行监控和录制。\"],\"_0ftcA\":[\"加载录制会话失败\"],\"_73JEY\":[\"TPS 突发帮助\"],\"_Bh-7r\":[\"性能\"],\"_BzI2m\":[[\"0\",\"plural\",{\"other\":[\"#\",\" 个错误\"]}]],\"_Ct04k\":[\"生成最高质量缩略图（宽 1920px），保留完整 1080p 细节。\"],\"_GsGHq\":[\"任务创建时间（默认）\"],\"_HgF9q\":[\"排序方式\"],\"_I6a4I\":[\"未配置平台\"],\"_K2CvV\":[\"模板\"],\"_KseE7\":[\"删除文件\"],\"_M7YFj\":[\"质量（qscale）\"],\"_NgV8X\":[\"当处理任务被取消时触发。\"],\"_O8edb\":[\"上传参数\"],\"_PAAt2\":[\"0 = 不限\"],\"_QU1-7\":[\"登录类型\"],\"_Qf5fl\":[\"优先级过滤器\"],\"_SvQT0\":[\"下载日志失败\"],\"_TEOcd\":[\"预设\"],\"_VaoHF\":[\"处理任务已取消\"],\"__tkD5\":[\"分段 TTL（毫秒）\"],\"_bBHGW\":[\"删除处理管道？\"],\"_bM9cT\":[\"GPU 高质量归档\"],\"_cnOWE\":[\"向 studio 请求附带与网页类似的完整性 token。默认开启；仅在你所在网络签发失败时关闭。\"],\"_gYAMa\":[\"聊天 ID\"],\"_gn6RG\":[\"严重错误\"],\"_hKQcr\":[[\"seconds\"],\" 秒\"],\"_jQctM\":[\"收件人\"],\"_kwOBw\":[\"任务超时\"],\"_l-j6K\":[\"日志下载成功\"],\"_l7qFE\":[\"录制已开始\"],\"_oqig9\":[[\"hours\"],\" 小时\"],\"_p1aNb\":[\"加载工作流失败\"],\"_rTz0M\":[\"音频\"],\"_srpvQ\":[\"删除渠道失败\"],\"_tQOXN\":[\"正在录制\"],\"_w3Fy0\":[\"定义此步骤如何标识自身以及如何连接到其他步骤。\"],\"_wFMEI\":[\"待确认\"],\"_xVC1p\":[\"TDL 状态\"],\"_xfOhY\":[\"媒体时间线\"],\"_zg39j\":[\"演练模式\"],\"a0w0iQ\":[\"字幕\"],\"a4Dp3l\":[\"渠道已删除\"],\"a9L56c\":[\"选择行为\"],\"aDPadU\":[\"当创建新的文件分段时触发。\"],\"aFWU1n\":[\"宽度\"],\"aFk-Ho\":[\"该主播当前正在直播，但不在你配置的录制时间表内。\"],\"aH0Q8c\":[\"选择策略\"],\"aIUW65\":[\"最低码率\"],\"aS09EA\":[\"当直播结束时接收通知。\"],\"aS11pb\":[\"选择要添加到处理管道的处理任务或工作流。\"],\"aVJmtU\":[\"已提取流\"],\"aZ2Kcr\":[\"配置接收通知的位置和方式。\"],\"aZUYz0\":[\"使用 NVIDIA NVENC 的快速 GPU 加速 HEVC/H.265 编码（CQ 24）。文件比 H.264 更小。\"],\"acyWOE\":[\"超时（秒）\"],\"afHZ2T\":[\"会话已结束\"],\"ailPEn\":[\"事件类型\"],\"aj9IS5\":[\"并发\"],\"akGgwH\":[\"已选择的流\"],\"akShFD\":[\"清理\"],\"amsJwt\":[\"当主播开播时接收通知。\"],\"aoDNXt\":[\"操作模式\"],\"atAJGZ\":[\"平台\"],\"atJjwW\":[\"例如 echo \",[\"input\"],\" > \",[\"output\"]],\"azIGX7\":[\"覆盖现有文件\"],\"azuNEn\":[\"该处理管道已结束或已取消\"],\"b21pJB\":[\"播放列表 TTL（毫秒）\"],\"b24kPi\":[\"队列\"],\"b2MuzC\":[\"截图时间偏移\"],\"b2Nri6\":[\"连接池空闲超时（毫秒）\"],\"b7Incv\":[\"操作类型\"],\"b7wV8U\":[\"MP\"],\"b92aqq\":[\"正在加载历史日志...\"],\"bAaOjo\":[\"采集分离的流数据。\"],\"bFAwGy\":[\"选择预设或输入斗鱼画质值。仅音频会请求不含视频的 AAC。\"],\"bGboGD\":[\"第 N 个输出：\",[\"output0\"],\", \",[\"output1\"],\"...\"],\"bLK9zG\":[\"保护设置\"],\"bMD8SW\":[\"自动刷新\"],\"bOj5u_\":[\"未添加步骤\"],\"bRVIqV\":[\"抑制冗余头部以减少播放器微卡顿\"],\"bT3NnO\":[\"处理任务完成后，媒体文件将显示在此处。\"],\"bYkKu9\":[\"录制开始时运行。\"],\"bZ_nY3\":[\"Twitch 代理播放列表\"],\"bagRV4\":[\"添加新主播\"],\"bb6TRf\":[\"最小分段大小\"],\"bcfSrW\":[\"数据库查询\"],\"bd8uQe\":[\"FFmpeg 视频滤镜\"],\"bdSNjC\":[\"暂时暂停\"],\"be6yVE\":[\"例如 我喜欢的主播\"],\"bex7vZ\":[\"导入配置失败\"],\"bgAdR4\":[\"允许的文件扩展名列表（不含点号）。\"],\"bgdEys\":[\"此浏览器不支持 Web 推送\"],\"bjp1xg\":[\"圆角半径\"],\"bkLoJJ\":[\"秒 (00-59)\"],\"bkZWD4\":[\"凭据刷新成功\"],\"bqYxTf\":[\"音频提取\"],\"bql7qo\":[\"回跳阈值\"],\"bwRvnp\":[\"操作\"],\"bzOX-6\":[\"平台 URL 提取器\"],\"c0LHG_\":[\"管道任务超时\"],\"c2Qn8C\":[\"选择预设\"],\"c3b0B0\":[\"开始使用\"],\"c5Xt89\":[\"名称 Z-A\"],\"c5qkaI\":[\"已停止监控\"],\"c6pG_r\":[\"引擎创建成功\"],\"c7Ho94\":[\"所有输出的 JSON 数组\"],\"c8-mAV\":[\"分段超时（毫秒）\"],\"c8iRL_\":[\"筛选并排序主播\"],\"c8nkFX\":[\"等待 ffmpeg 完成文件写入的时间，超时后将强制终止进程\"],\"cCbxO-\":[\"提取 MP3（192kbps）音频轨道，适合播客与音乐。\"],\"cCfxH1\":[\"下载中...\"],\"cDD5xY\":[\"最后错误\"],\"cDR51K\":[\"默认处理管道流程。为新的处理任务配置处理器顺序。\"],\"cEHxsl\":[\"音频编码变化\"],\"cH5kXP\":[\"现在\"],\"cLVp5Z\":[\"日志文件\"],\"cNC2t_\":[\"将文件复制到其他位置，保留原始文件。\"],\"cO9-2L\":[\"禁用\"],\"cP_zDS\":[\"对远端的 API 调用进行限速。当服务方在高负载下返回 429 \\\"请求过多\\\" 时较为有用。\"],\"cRxiHL\":[\"导入成功\"],\"cS8JKn\":[\"删除会话失败\"],\"cSCbv3\":[\"测试版\"],\"cSadgt\":[\"删除源文件\"],\"cSev-j\":[\"过滤器\"],\"cVEhWm\":[\"解析模式\"],\"cX2i4T\":[\"第一个输入文件路径\"],\"cXFXEn\":[\"仅音频\"],\"cXKoLz\":[\"保存工作流\"],\"cd0jIM\":[\"处理任务详情\"],\"ce5XJx\":[\"暂无正在录制\"],\"cgDZLv\":[\"当视频和弹幕可用时触发\"],\"ci-Ad9\":[\"快速分享\"],\"ckH3fT\":[\"就绪\"],\"cklVjM\":[\"时间线\"],\"ckoeXQ\":[\"文件名模式（例如 thumb.jpg）\"],\"cl-3tt\":[\"流详情\"],\"cnGeoo\":[\"删除\"],\"cncExx\":[\"配置已成功处理。以下是更改摘要：\"],\"cp8Gv3\":[\"用于排除文件的正则模式。将针对完整路径和文件名进行匹配。\"],\"cpbupq\":[\"高质量归档\"],\"cqFv88\":[\"排除\"],\"cs0tlO\":[\"导入策略\"],\"cupeHb\":[\"例如：process-video\"],\"cxkEMX\":[\"已处理 \",[\"0\"],\" 个主播；\",[\"1\"],\" 个失败\"],\"cyBb5d\":[\"解密\"],\"d-F6q9\":[\"已创建\"],\"d-eBFj\":[\"配置所选引擎的详细选项。\"],\"d1yanO\":[\"暂无日志可显示\"],\"d2gWS6\":[\"频道或直播流的直接链接。\"],\"d3IqDh\":[\"质量\"],\"d4uXwk\":[\"下一页\"],\"d6CET4\":[\"自适应刷新（默认：开）\"],\"d6ituE\":[\"已关闭自动滚动\"],\"d7Kp2g\":[\"中断\"],\"dEXRlZ\":[\"步骤库\"],\"dEgA5A\":[\"取消\"],\"dF6vP6\":[\"直播\"],\"dISV1x\":[\"带宽限制\"],\"dK3Z9j\":[\"组件\"],\"dMtwVQ\":[\"NVENC H.264 快速\"],\"dN7wel\":[\"当下载因错误失败时触发。\"],\"dNmZ33\":[\"未找到工作流\"],\"dONv4O\":[\"已处理分段的通道缓冲倍数。\"],\"dP2MYa\":[\"预设删除成功\"],\"dPyJ15\":[\"删除模板\"],\"dQfGrq\":[\"致命错误\"],\"dTuElw\":[\"添加自定义标签\"],\"dW40Uz\":[\"未找到事件\"],\"dXcu-7\":[\"重封装\"],\"da5jry\":[\"流监控遇到问题\"],\"de7Gux\":[\"录制重试策略\"],\"dg3VQG\":[\"代理策略\"],\"dgYq4k\":[\"可选\"],\"dhvw0h\":[[\"0\",\"plural\",{\"other\":[\"#\",\" 行\"]}]],\"djLR5U\":[\"清单（按顺序配对）\"],\"dkjxU6\":[\"内部服务与资源的详细健康检查。\"],\"dmsn13\":[\"收到头部\"],\"dnUaj8\":[\"克隆模板失败：\",[\"0\"]],\"dsn408\":[\"若输出已存在则强制覆盖\"],\"dsphkM\":[\"完整的多媒体处理：并行进行视频重封装、音频提取和缩略图生成，然后上传所有文件。\"],\"dx8VNK\":[\"引擎更新成功\"],\"dxj6g1\":[\"检查间隔。\"],\"dxwgX3\":[\"处理管道名称\"],\"e-gPhp\":[\"跟随重定向（默认：开）\"],\"e20CFN\":[\"此过滤器仅在时间线上突出显示的窗口期间激活。如果开始时间在结束时间之后，窗口将自动跨越午夜。\"],\"e2BurN\":[\"提取高质量 MP3（320kbps）音频，用于播客分发。\"],\"e3P3Sz\":[\"最大字节数\"],\"e3_ja4\":[\"名称 A-Z\"],\"e4_PV8\":[\"例如 gdrive:/videos\"],\"e65FAh\":[\"此删除步骤依赖 <0>\",[\"transformDepLabel\"],\"</0>，将删除转码后的结果文件，而不是原始录制文件。若要在转码后删除原始文件，请在转码步骤上启用“成功后移除输入”。仍要添加此删除步骤吗？\"],\"e6MUfG\":[\"最大分片大小\"],\"e9-VJo\":[\"输出文件名模式（可选）\"],\"eCiRz_\":[\"双向均为 10 MiB/s\"],\"eE0JZ4\":[\"版本\"],\"eEMRgC\":[\"文件扩展名过滤\"],\"eG3YoN\":[\"预计剩余时间\"],\"eNncgX\":[\"复制/移动\"],\"eOpIyK\":[\"仅视频\"],\"ePK91l\":[\"编辑\"],\"eUQA5e\":[\"未检测到问题\"],\"eYYXD2\":[\"并发校验数（--checkers）。\"],\"eYe1Rb\":[\"导出配置失败\"],\"eZO3cK\":[\"缓冲区限制\"],\"eZgbDb\":[\"高优先级槽位等待中（已达到并发限制）\"],\"ebJPUJ\":[\"完整流程：重封装、生成缩略图、添加元数据并上传。\"],\"ebiBIb\":[\"通知保留\"],\"ecUA8p\":[\"今天\"],\"edQdmU\":[\"FFmpeg 音频滤镜（-af）\"],\"ehe4bj\":[\"强制使用移动端 API\"],\"ejQ0z7\":[\"覆盖已存在文件\"],\"ejnHB4\":[\"在离线检测次数之外，直播中的主播需保持离线的最短时间，之后才结束录制场次。在此时间内的短暂 API 或 CDN 异常会延续同一场次。0 表示禁用；最长 10 分钟。\"],\"emYiLJ\":[\"平台覆盖设置\"],\"enj3eJ\":[\"主题模式\"],\"es0mu4\":[\"仅限严重\"],\"evbMWR\":[\"输出参数\"],\"ewB4dk\":[\"例如：标准处理\"],\"ex8PR1\":[\"重试所有失败任务\"],\"exyoIu\":[\"搜索录制会话...\"],\"f2d4Ps\":[\"会话菜单\"],\"f4u7wL\":[\"时间与延迟\"],\"f4yUH6\":[\"多线程阈值帮助\"],\"f76EEx\":[\"按标题关键字过滤\"],\"f77MVE\":[\"编码预设\"],\"f8A73b\":[\"44100\"],\"f8hHmU\":[\"创建处理任务失败\"],\"f8zoXj\":[\"加载处理管道失败\"],\"fAGXwp\":[\"当主播配置被动态更新时触发。\"],\"fB-Bdg\":[\"上次检查于 \",[\"0\"],\" 前\"],\"fF22or\":[\"弹幕流已关闭\"],\"fHh0Nn\":[\"例如 up -c @channel -p \",[\"input\"]],\"fLcXDs\":[\"录制过滤器\"],\"fNsYxz\":[\"在上方添加模块以配置其日志级别\"],\"faK1M-\":[\"允许的扩展名\"],\"fcWrnU\":[\"退出登录\"],\"fcirqq\":[\"这将永久删除工作流“\",[\"0\"],\"”。使用该工作流的主播需要更新。\"],\"fiPesM\":[\"请求方法\"],\"fif2cG\":[\"搜索工作流...\"],\"fknQhj\":[\"目标文件夹\"],\"fkpv9h\":[\"搜索主播...\"],\"fnASK5\":[\"普通+\"],\"fpMs2Z\":[\"直播中\"],\"fqJjiU\":[\"在每个录制分段完成后立即运行。\"],\"frUGOs\":[\"自动为每个会话的第一段生成封面图。\"],\"fss9hD\":[\"打开画质选项\"],\"fvL_Ho\":[\"单位为字节（默认基准为 KiB/s）。可使用 B、K、M、G、T、P 等后缀表示更大的容量。\"],\"g-gBfk\":[\"解析中...\"],\"g6BpXZ\":[\"已取消\"],\"g7_-Qd\":[\"用于排除代理的频道列表（逗号分隔）\"],\"g90PVF\":[\"步骤配置\"],\"gCv-nS\":[\"每主机最大空闲连接数\"],\"gEQH50\":[\"限制整体传输带宽（rclone --bwlimit）。\"],\"gM5LKb\":[\"该流已处于活动状态\"],\"gORFgZ\":[\"取消执行\"],\"gQG_W7\":[\"由 <0>Rust-srec</0> 提供支持。\"],\"gUjkl_\":[\"不可用\"],\"gVDufW\":[\"为录制内容定义处理步骤序列\"],\"gVxTf6\":[\"导出内容包含所有全局设置、平台、模板、主播、引擎和通知渠道。敏感数据（如密码）可能会根据平台设置被脱敏或加密。\"],\"gWdWG8\":[\"折叠方式\"],\"gWgRVf\":[\"输出卷剩余空间低于此值时执行低磁盘空间操作。0 = 禁用\"],\"gb0sx5\":[\"密钥重试延迟（毫秒）\"],\"gc3su8\":[\"字幕匹配\"],\"gdA_lQ\":[\"播放列表已失效\"],\"gh06VD\":[\"输出\"],\"ghnHjn\":[\"确认主播离线所需的连续失败检查次数。较高的值可以防止\\\"假\\\"离线检测。\"],\"glK8MG\":[\"处理任务\"],\"gliRBz\":[\"处理节点\"],\"gnNUHR\":[\"此步骤是一个子工作流：<0>\",[\"workflowName\"],\"</0>。\"],\"gpUgZ_\":[\"0（无）- 9（最佳）\"],\"gptVEm\":[\"创建过滤器以控制何时录制该主播。你可以按时间、关键字、分类等进行过滤。\"],\"gq-Q4J\":[\"正在加载预设详情...\"],\"gq6RwY\":[\"加载弹幕统计失败。\"],\"gqZ7EH\":[\"如果可用，采集实时评论和聊天消息。\"],\"guy7NI\":[\"修改此处理步骤的参数。\"],\"h1-4V4\":[\"刷新失败：需要手动登录\"],\"h5b8Gg\":[\"加载媒体文件失败：\",[\"0\"]],\"hFcCJR\":[\"限制单个文件的传输速率，而非整体总量。\"],\"hKqbBO\":[\"成功处理 \",[\"0\"],\" 个主播\"],\"hM6-6Z\":[\"会话 ID 是必需的\"],\"hNmOZ7\":[\"设置优先级\"],\"hO9jJx\":[\"日志配置已更新\"],\"hQOBwU\":[\"等待\"],\"hSHVF0\":[\"复制后检查大小\"],\"hSj3SO\":[\"自定义配置\"],\"hV6FeJ\":[\"吞吐量\"],\"hVDqsJ\":[\"高清\"],\"hVuIVS\":[\"高级排程\"],\"hXA9xB\":[\"网页优化\"],\"hXzOVo\":[\"下一步\"],\"hY0vvK\":[\"一个唯一的 ID，用于供其他步骤引用此步骤。\"],\"hYgDIe\":[\"创建\"],\"hZ6znB\":[\"端口\"],\"hdsaJo\":[\"正在导入...\"],\"hdyeZ0\":[\"删除处理任务\"],\"he3ygx\":[\"复制\"],\"hgpMHD\":[\"总大小\"],\"hmqzwF\":[\"峰值/分钟\"],\"hrDkPB\":[\"主播的基本设置。\"],\"ht1ran\":[\"尝试调整搜索。\"],\"hul8PJ\":[\"自动填充名称失败\"],\"huzp0F\":[\"需要 Telegram 桌面版数据\"],\"hx-EJH\":[\"最大分段数\"],\"hyCRuI\":[\"大小限制\"],\"i-GrRR\":[\"未在平台/主播中指定时使用的录制引擎。\"],\"i09z4S\":[\"月 (01-12)\"],\"i0qMbr\":[\"主页\"],\"i2yKvD\":[\"主播\"],\"i4y2wE\":[\"描述由系统管理\"],\"iAJgTr\":[\"404\"],\"iAL9tI\":[\"配置\"],\"iB8z_j\":[\"流选项\"],\"iBQU3w\":[\"请修改密码\"],\"iDNBZe\":[\"通知\"],\"iDpc_N\":[\"正在加载编辑器组件...\"],\"iE4x2o\":[\"选择编解码器\"],\"iEfPm1\":[\"Twitch (ttv-lol)\"],\"iFIj9t\":[\"将设置保存为 JSON 文件\"],\"iH8pgl\":[\"返回\"],\"iI8ncW\":[\"输入要匹配的分类（例如 Just Chatting、Gaming）。\"],\"iKvAeG\":[\"检查中\"],\"iLYPXc\":[\"DAG 图\"],\"iMAQTQ\":[\"年 (4 位数字)\"],\"iToNdZ\":[\"用于观看需要登录（如 19+）直播的 SOOP 账号。长期受限频道建议优先使用 Cookie。\"],\"iTylMl\":[\"模板\"],\"iWGuCP\":[\"此处理器没有可用的配置表单。\"],\"iaPtht\":[\"复制\"],\"iaocTt\":[\"比例\"],\"iftgVc\":[\"未配置自定义请求头\"],\"igC7p-\":[\"切换到备用文件夹\"],\"ij74Jr\":[\"安排录制时间\"],\"ilOZeV\":[\"初始延迟（ms）\"],\"imXBeM\":[\"确认离线所需的连续失败检查次数。\"],\"ipzg8L\":[\"未配置模块\"],\"isRobC\":[\"新建\"],\"iv8DdK\":[\"更新\"],\"ivJkrj\":[\"创建新工作流\"],\"iwyTQi\":[\"你确定要删除 \",[\"0\"],\" 个会话吗？此操作无法撤销。\"],\"iyCIi4\":[\"批量解析\"],\"j-MPXl\":[\"备份与恢复\"],\"j03sWF\":[\"整个会话结束后触发\"],\"j0rT_n\":[\"选择或输入画质值\"],\"j6ZZIX\":[\"CRF (0-51)\"],\"j7AbzC\":[\"从边缘拖动以连接步骤\"],\"jAwQYJ\":[\"优先使用二维码登录；其次是手机号和验证码（可选两步验证）；最后是桌面登录。\"],\"jC5etE\":[[\"0\",\"plural\",{\"other\":[\"个文件\"]}]],\"jEw0Mr\":[\"请输入有效的 URL\"],\"jGmy11\":[\"正在录制\"],\"jIFKq_\":[\"变体选择策略\"],\"jIPNJG\":[\"基本信息\"],\"jKHfGg\":[\"弹幕转 ASS 字幕\"],\"jKPJlR\":[\"创建缺失的文件夹\"],\"jKttAE\":[\"钩子\"],\"jNqP-h\":[\"从 URL 提取名称失败\"],\"jQh979\":[\"按固定时间间隔采样弹幕。\"],\"jR5LZx\":[\"录制出错时\"],\"jRPz-T\":[\"克隆模板\"],\"jWXukA\":[\"该登录需要 Telegram 两步验证密码。请启用“允许两步验证”并重启。\"],\"jXwBNq\":[\"CRF（恒定码率因子）\"],\"jems_i\":[\"单文件带宽限制帮助\"],\"jfsP4q\":[\"上传所有文件\"],\"jmbIS6\":[\"桌面通知\"],\"joJBB2\":[\"创建你的第一个工作流，用于定义录制内容的处理步骤序列。\"],\"jpctdh\":[\"查看\"],\"juTcaJ\":[\"NVENC H.264 高质量\"],\"jvivc3\":[\"录制会话 ID\"],\"k9Trbl\":[\"创建预设\"],\"kEgzfB\":[\"rust-srec 通过运行 nvidia-smi 来检测容器是否失去 GPU 访问权限(NVIDIA Container Toolkit 在 cgroup v2 主机上的已知问题)。仅在系统中存在 nvidia-smi 时启用,否则不会注册 GPU 行。默认 30 秒。修改会在下一次探测时生效,无需重启。不建议设置低于 30 秒。\"],\"kF2Y0z\":[\"偏移一致性检查\"],\"kG0i0h\":[\"仍然添加\"],\"kI1qVD\":[\"格式\"],\"kN8Y5L\":[\"CPU 任务超时\"],\"kRJm8z\":[\"采样尝试之间的时间间隔。\"],\"kX97I8\":[\"列如: 0:v:0, 0:a:0\"],\"kZV79T\":[\"分割原因\"],\"kcR85X\":[\"输入要包含的关键词...\"],\"kcoHto\":[\"校验服务不可用\"],\"ke0Ls7\":[\"最小高度\"],\"kfNspv\":[\"引擎类型\"],\"kjMqSj\":[\"复制 JSON\"],\"klOeIX\":[\"修改密码失败\"],\"kn1oU0\":[\"日志已暂停\"],\"kne4l9\":[\"压缩\"],\"koRU21\":[\"添加额外参数\"],\"kqQaK3\":[\"仅 Cookie\"],\"krzGzQ\":[\"并发文件传输数（--transfers）。\"],\"kseNmN\":[\"这将永久删除通知渠道“\",[\"0\"],\"”。\"],\"kt6N2a\":[\"此删除步骤将删除 <0>\",[\"transformDepLabel\"],\"</0> 产出的文件（即转码结果），而不是原始录制文件。若要在转码后删除原始源文件，请改为在转码步骤上启用“成功后移除输入”。\"],\"kwRUsJ\":[\"任务限制与引擎设置。\"],\"l-RCZs\":[\"创建工作流\"],\"l2Op2p\":[\"查询参数\"],\"l3s5ri\":[\"导入\"],\"l6DdQS\":[\"没有可用的详细错误信息\"],\"l6zyQF\":[\"使用 rclone 将文件上传到云存储。在 rclone 配置中设置远端。\"],\"l7oQUQ\":[\"开始前等待时间。\"],\"l97zAi\":[\"转码 H.264\"],\"l9wI00\":[\"速度\"],\"lA6fmP\":[\"扫描目录\"],\"lA8IlD\":[\"未找到平台\"],\"lBQSXl\":[\"例如：重封装为 H.264\"],\"lCF0wC\":[\"刷新\"],\"lDvrUG\":[\"创建主播失败\"],\"lECX4V\":[\"最大 IO 任务数\"],\"lJoArf\":[\"Cron 表达式\"],\"lOyQ-B\":[\"编码器预设\"],\"lYLHvp\":[\"已达到并发限制\"],\"lcb8Hd\":[\"压缩级别\"],\"lfFsZ4\":[\"声道\"],\"lj3GoP\":[\"未找到处理任务\"],\"lkz6PL\":[\"时长\"],\"lqXgLk\":[\"添加覆盖项以自定义此模板的引擎行为。\"],\"lqf04S\":[\"次要\"],\"ltYQCa\":[\"自 \",[\"0\"],\" 起\"],\"luB4tN\":[\"选择此主播如何处理代理连接。\"],\"m16xKo\":[\"添加\"],\"m2DSGV\":[\"端点配置\"],\"m42dNa\":[\"创建模板以定义可复用的录制配置。\"],\"m48LOH\":[\"硬件加速\"],\"m50MxJ\":[\"获取延迟\"],\"m7AE5i\":[\"下载文件失败\"],\"m8LlVt\":[\"将单个大文件拆分为 N 个并行分块，加快上传速度。\"],\"mCB6Je\":[\"全选\"],\"mCc1g0\":[\"刷新凭据失败\"],\"mIbF__\":[\"从 URL 自动填充名称\"],\"mKKr99\":[\"使用 -d 重启\"],\"mLVDOt\":[\"命令模板参数。可使用 \",[\"INPUT_TOKEN\"],\" 和 \",[\"OUTPUT_TOKEN\"],\" 占位符。\"],\"mO7heL\":[\"媒体文件\"],\"mOXqr6\":[\"密码修改成功。请使用新密码登录。\"],\"mQe6uy\":[\"主播已更新\"],\"mTw0S2\":[\"尚未生成媒体输出。\"],\"mYGY3B\":[\"日期\"],\"meSGba\":[\"进度：\"],\"mgOBre\":[\"最高码率\"],\"mgtXne\":[\"过滤低于此优先级的事件\"],\"mgxDh9\":[\"删除转码后的结果文件？\"],\"mkaDKN\":[\"如果一个文件上传失败，继续上传其他文件\"],\"mkvLrG\":[\"选择类别\"],\"mrmkFJ\":[\"以原始直播分辨率生成缩略图（不缩放）。质量最佳，但文件体积最大。\"],\"mrzV5V\":[\"覆盖现有的 .ass 文件\"],\"mxhrdq\":[\"弹幕\"],\"mxun7B\":[\"主播离线时运行。\"],\"my6sDb\":[\"搜索主播...\"],\"mzDMo2\":[\"基于时间\"],\"mzI_c-\":[\"下载\"],\"mzffFb\":[\"预设更新成功\"],\"n-v6gq\":[\"总弹幕数\"],\"n1MDK4\":[\"最大密钥重试次数\"],\"n1ekoW\":[\"登录\"],\"n4AWhD\":[\"探索匹配的自动化步骤以增强你的处理管道工作流。\"],\"n9yU9X\":[\"实时预览\"],\"nBcxYG\":[\"取消 CPU 密集型处理器前的超时时间。\"],\"nC6Rpq\":[\"添加流\"],\"nDG2K-\":[\"启用高级 FLV 时间戳修复\"],\"nEAJMt\":[\"离线检查\"],\"nEJA8Y\":[\"尝试调整日期范围\"],\"nGC_6h\":[\"添加 rclone 参数\"],\"nGNP8v\":[\"压缩（Zip）\"],\"nGrU2q\":[\"在第 10 秒生成高清缩略图（宽 640px）。\"],\"nN1XNy\":[\"覆盖文件\"],\"nOPbWJ\":[\"右侧\"],\"nQM_01\":[\"删除预设失败：\",[\"0\"]],\"nQZ3Py\":[\"更新工作流失败：\",[\"0\"]],\"nQibzB\":[\"完全排除这些 CDN 提供商的流。按回车键添加。\"],\"nSHdq2\":[\"当新的下载录制开始时触发。\"],\"nSQv8Y\":[\"已开启自动滚动\"],\"nTN3Hr\":[\"探测 CDN\"],\"nTWWCZ\":[\"低\"],\"nUjQm2\":[\"执行设置\"],\"nVkOeu\":[\"TDL 登录会话已开始\"],\"nWnBRV\":[\"投递策略\"],\"n_s1Si\":[\"如果目标文件已存在则替换\"],\"ncwQad\":[\"（空）\"],\"neL6ek\":[\"新预设名称\"],\"nfwriv\":[\"多个会话共用同一目标文件夹时，请在文件名模板中保留日期和时间标记。\"],\"nhxAdQ\":[\"关键字\"],\"nmD1Iv\":[\"Web 推送允许你在关闭浏览器时也能接收通知。它需要浏览器权限和有效的服务器密钥。\"],\"nnPJVX\":[\"TDL 存储会话数据的地方 (`tdl --storage ...`)。例如：`type=bolt,path=/data/tdl`。\"],\"nrLIEd\":[\"按时长分割。\"],\"nu0Tii\":[\"尝试通过替换音频流获取原画质量。如果失败，可能会导致仅有音频。\"],\"nuh_Wq\":[\"Webhook URL\"],\"num3TS\":[\"加载预设失败：\",[\"0\"]],\"nwIyEX\":[\"修复 HLS 不连续性\"],\"nwObng\":[\"暂无媒体文件\"],\"nxIXdN\":[\"请修正校验错误\"],\"o21Y-P\":[\"条\"],\"o5r6b2\":[\"处理任务已删除\"],\"o69yIu\":[\"按 Enter 添加收件人\"],\"o6OQlp\":[\"编辑渠道\"],\"oBn_nO\":[\"流水线历史保留期\"],\"oIg22A\":[\"直播密码\"],\"oJJdGr\":[\"目标根目录\"],\"oKjqX-\":[\"处理管道执行\"],\"oLsREl\":[\"暂无词频数据\"],\"oMVAXw\":[\"在整个会话结束且所有分段管道均已完成后运行一次。\"],\"oQw4Jd\":[\"SMTP 主机\"],\"oV0Bem\":[\"创建主播\"],\"oaiKxc\":[\"在此输入你的回答...\"],\"oatACc\":[\"内联\"],\"ofUcbc\":[\"随机\"],\"ogaYcn\":[\"Shadcn UI 主题预设\"],\"ojQSTc\":[\"上一个标题\"],\"okXKua\":[\"签发完整性 token\"],\"oke9ig\":[\"主播更新成功\"],\"olkLN2\":[\"单个 URL\"],\"oo16kX\":[\"未生成预览\"],\"opgoOE\":[\"重排持续时间（毫秒）\"],\"orDnO-\":[\"统计词数\"],\"orI3hB\":[\"分页\"],\"ovBPCi\":[\"默认\"],\"oyj1TW\":[\"未定义步骤\"],\"p0dP0q\":[\"在不同时间点生成多张预览图，用于图库展示。\"],\"p12eT9\":[\"请选择一个 JSON 文件\"],\"p1zJ0-\":[\"CDN 探测\"],\"p27AhB\":[\"手机号和验证码（可选两步验证）\"],\"p2_GCq\":[\"确认密码\"],\"p2mB8S\":[\"单文件带宽限制\"],\"p2sbyZ\":[\"未找到模板\"],\"p5nYkr\":[\"查看全部\"],\"p6NueD\":[\"新\"],\"p8QOjt\":[\"使用 faststart 标志重封装为 MP4，以优化网页播放。\"],\"pAtylB\":[\"未找到主播\"],\"pD5RQD\":[\"转换成功后删除 XML 文件\"],\"pECIKL\":[\"搜索模板...\"],\"pFIl0a\":[\"时钟边界\"],\"pHt_zF\":[\"不在计划时间内\"],\"pIKNYw\":[\"忽略低于此比特率的流。\"],\"pKmdYH\":[\"仅包含具有该扩展名的文件（不含点号）\"],\"pSNvte\":[\"播客提取\"],\"pWT04I\":[\"检查中...\"],\"pYUyoK\":[\"默认工作流：重封装为 MP4（删除原始文件），生成原始分辨率缩略图，上传两者至云端并删除本地文件。\"],\"pZBeKb\":[\"待处理任务\"],\"pZoiPG\":[\"追踪标识符的隔离级别。全局通常是最佳选择。\"],\"p_78dY\":[\"位置\"],\"pb5U2q\":[\"将文件移动到其他位置，并移除原始文件。\"],\"peqX67\":[\"元数据标签\"],\"pj3WXl\":[\"--flag value\"],\"pjN1vk\":[\"例如 C:\\\\Users\\\\...\\\\AppData\\\\Roaming\\\\Telegram Desktop\"],\"plUzgk\":[\"高质量 H.265/HEVC 压缩（CRF 22）。文件更小，但编码更慢。\"],\"pmXQXV\":[\"流媒体服务\"],\"pqdYPk\":[[\"0\",\"plural\",{\"other\":[\"#\",\" 个警告\"]}]],\"psqOpk\":[\"添加邮箱地址并按 Enter\"],\"ptAX-h\":[\"CPU 使用率\"],\"puukLG\":[\"已全部标记为已读\"],\"pv2R4B\":[\"自适应轮询\"],\"pvnfJD\":[\"深色\"],\"pwlRnQ\":[\"元数据编辑器\"],\"q-N7o6\":[\"自定义标志\"],\"q3Dp_X\":[\"在输出中包含原始文件\"],\"q4bpUC\":[\"输出配置\"],\"q5nfv1\":[\"录制开始时\"],\"qC4H_F\":[\"检查中\"],\"qEH4Cy\":[\"运行自定义 FFmpeg 命令。编辑参数以自定义。\"],\"qEVp1d\":[\"弹幕控制\"],\"qGO8x4\":[\"轨道信息\"],\"qH2T4F\":[\"未定义环境变量\"],\"qJb6G2\":[\"重试\"],\"qM884L\":[\"User-Agent\"],\"qSJNp0\":[\"内存使用率\"],\"qTqIG0\":[\"录制弹幕\"],\"qUvWln\":[\"尝试调整搜索或过滤器。\"],\"qWRWG1\":[\"原因：\"],\"qZq0tI\":[\"例如 aliyun, akamaized\"],\"q_yLs_\":[\"首选画质 (QN)\"],\"qgMxnz\":[\"任务创建时间\"],\"qoGRes\":[\"目标根目录帮助\"],\"qqWcBV\":[\"已完成\"],\"qtoOYG\":[\"不限\"],\"quwsBF\":[\"强制指定输出格式。\"],\"qxDAhZ\":[\"编辑过滤器\"],\"r0jBJE\":[\"secret\"],\"r2yoxL\":[\"输入选项（-i 参数）\"],\"r5_ZH9\":[\"将 .ass 字幕内嵌到视频中（默认生成 *_burnin.mp4）。支持清单感知和批量处理。\"],\"r8keQd\":[\"历史（\",[\"slots\"],\" 点）\"],\"r8mo4d\":[\"CDN 发送首字节的最长等待时间，超时即视为无法访问。\"],\"rAx5u1\":[\"结束时间\"],\"rCZeYu\":[\"当文件分段完成时触发。\"],\"rDC-T6\":[\"重试处理任务\"],\"rG3WVm\":[\"选择\"],\"rGNFqY\":[\"全流程处理\"],\"rLgPvm\":[\"备份\"],\"rOsxLC\":[\"创建渠道\"],\"rPLMLx\":[\"使用哔哩哔哩 App 扫码登录并保存凭据\"],\"rPTjS_\":[\"标记已读\"],\"rYpSzJ\":[\"清理后的会话标题\"],\"r_-HvB\":[[\"0\",\"plural\",{\"other\":[\"#\",\" 已完成\"]}]],\"rc8zxf\":[\"控制各模块的日志详细程度。较低级别包含较高级别。\"],\"rdAnZQ\":[\"实时日志\"],\"rj3LPO\":[\"登录成功！\"],\"rkmaZ8\":[\"传递给 Streamlink 的其他命令行参数\"],\"rmol8y\":[\"不连续\"],\"rsjeU7\":[\"主播已删除\"],\"rv6zQQ\":[\"重试延迟基数（毫秒）\"],\"rwpu5q\":[\"选择你的显示语言\"],\"ry0RbG\":[\"暂无描述。\"],\"ryXr-I\":[\"命令参数\"],\"s-MGs7\":[\"资源\"],\"s-yK3S\":[\"启用后，将忽略这些分类的流。\"],\"s0A5F0\":[\"需要字幕\"],\"s2Ok6S\":[\"编辑工作流：\",[\"0\"]],\"s5FJH3\":[\"总步骤数\"],\"s7XkSj\":[\"加载模板列表失败：\",[\"0\"]],\"s7XwOU\":[\"创建模板\"],\"s867uU\":[\"覆盖文件名模板。\"],\"sAEhzj\":[\"流结构变化\"],\"sDMc1l\":[\"时间占位符（本地时间）\"],\"sER-bs\":[\"文件\"],\"sG9Y50\":[\"NVENC AV1 高质量\"],\"sGeXL3\":[\"缩略图\"],\"sMakGt\":[\"ey...\"],\"sPFHpI\":[\"磁盘\"],\"sPfqA-\":[\"确定要删除模板“\",[\"0\"],\"”吗？此操作无法撤销。\"],\"sSmPh2\":[\"沉浸模式\"],\"sThCBt\":[\"质量控制\"],\"sWuf_r\":[\"首选画质\"],\"sXmNBK\":[\"通过中间服务器转发流量\"],\"sYAqs-\":[\"最大延迟（ms）\"],\"saFjCh\":[\"成功重封装后删除原始文件\"],\"sbQMAc\":[\"请至少输入一个 URL\"],\"sbanhc\":[\"无需刷新或不支持\"],\"sfCeO6\":[\"优先级从高到低\"],\"si7i6t\":[\"为文件创建 ZIP 压缩包，适合与元数据一起打包。\"],\"sjJ6qs\":[\"恢复启动\"],\"sjVfrA\":[\"命令\"],\"spa6AF\":[\"没有输入路径\"],\"sq8P7b\":[\"侧滑\"],\"sr0UJD\":[\"返回\"],\"svV_tK\":[\"模板更新成功\"],\"sxidET\":[\"GPU 加速高质量归档：NVENC HEVC 编码、缩略图生成和云端上传。\"],\"sxkWRg\":[\"高级\"],\"t-R8-P\":[\"执行\"],\"t3NSIX\":[\"替换管道步骤\"],\"t6QNu0\":[\"音频设置\"],\"t7TeQU\":[\"消息数\"],\"t7p091\":[\"开始二维码登录\"],\"tBFXor\":[\"已被用户停用\"],\"tBkWoY\":[\"斗鱼标清或低清画质值\"],\"tCNR7u\":[\"下载并发数\"],\"tFII0M\":[\"移至此处的录制保持其在输出文件夹下的目录结构。\"],\"tG0T2X\":[\"解析 URL 失败\"],\"tH1aCG\":[\"视频比特率\"],\"tLnoCj\":[\"任务预设\"],\"tOcErW\":[\"更新设置失败\"],\"tPg-n8\":[\"Rclone 可执行文件\"],\"tVSmFT\":[\"正在加载更多...\"],\"tVxHoG\":[\"未找到工作流\"],\"tWWCyb\":[\"创建模式\"],\"tXDHM9\":[\"传输数帮助\"],\"tXQ9KI\":[\"选择类型\"],\"tX_2YD\":[\"删除主播失败\"],\"tXkhj_\":[\"开始\"],\"tYt23W\":[\"ASS 硬字幕\"],\"tZASER\":[\"确定要删除此任务吗？此操作将永久从列表中删除。\"],\"t_YqKh\":[\"移除\"],\"t_a0R7\":[\"https://gotify.example.com\"],\"taWpib\":[\"TPS 限制的突发容量（--tpslimit-burst）。\"],\"ti_7Zv\":[\"配置与现有数据一致\"],\"tkrJYR\":[\"确定要删除此会话吗？此操作无法撤销。\"],\"toutGW\":[\"二维码\"],\"tqsVap\":[\"最低优先级\"],\"trzwMt\":[\"HLS 解析器\"],\"tst44n\":[\"事件\"],\"tuczwE\":[\"主要操作\"],\"twkfAf\":[\"已开始重试失败的步骤\"],\"tyyY8g\":[\"录制会话详情\"],\"u-R-Uj\":[\"未找到模板\"],\"u4x6Jy\":[\"返回处理任务列表\"],\"u5pdtQ\":[\"取消处理管道失败\"],\"u7En0V\":[\"添加元数据\"],\"u7KTQf\":[\"用英文逗号分隔多个路径。\"],\"u8XaRg\":[\"主播处于冷却期\"],\"u8rLL5\":[\"连接不稳定\"],\"uAQUqI\":[\"状态\"],\"uBxq0B\":[\"添加项目...\"],\"uDMKZ-\":[\"间隔（秒）\"],\"uFgIWz\":[\"每个分段录制后触发\"],\"uFrzr2\":[\"此处理管道在录制分段完成后立即运行。适用于仅需要单个视频分段的任务，例如重封装、缩略图生成或逐段上传。\"],\"uHaWdO\":[\"提取高质量音频用于播客分发并上传。\"],\"uI3SjW\":[\"连接错误\"],\"uKMMk4\":[\"登录成功\"],\"uKqo4y\":[\"事件钩子\"],\"uLlPmJ\":[\"根据目标时长调整速率\"],\"uQUJrF\":[\"所有平台\"],\"uRoULu\":[\"全局配置\"],\"uS4jm7\":[\"继承/默认\"],\"uTyAvE\":[\"Twitch 代理播放列表排除\"],\"u_4llg\":[\"切换主题\"],\"uabF28\":[\"敏感\"],\"ueNCFX\":[\"预设详情\"],\"uhURfE\":[\"成功后移除源文件\"],\"uhZE--\":[\"禁用 Web 推送失败\"],\"uiiiQ-\":[\"最大高度\"],\"um816y\":[\"执行记录\"],\"up_E6Q\":[\"自定义应用主题和侧边栏布局。\"],\"utnMp8\":[\"账号不存在\"],\"uxjnhI\":[\"WEB\"],\"v-cKc6\":[\"复制/移动\"],\"v0ItTA\":[\"正在加载日志...\"],\"v10I4K\":[\"跳过互动玩法\"],\"v39wLo\":[\"继续\"],\"vBNtP8\":[\"首选格式\"],\"vEkjkD\":[\"代理 URL\"],\"vFBdNT\":[\"分辨率（缩放）\"],\"vGgj7q\":[\"密钥缓存 TTL（毫秒）\"],\"vGjmyl\":[\"已删除\"],\"vHFsyI\":[\"协议设置\"],\"vHbMb_\":[\"处理管道名称是必填项\"],\"vHgqv3\":[\"Twitch 播放列表的自定义代理 URL\"],\"vPfdKD\":[\"根据弹幕流量动态调整采样率。\"],\"vQUnlF\":[\"平台的特定设置。\"],\"vRLm73\":[\"显示录制下载详情\"],\"vRayGs\":[\"播放器\"],\"vS1EOG\":[\"流程与依赖\"],\"vSJd18\":[\"视频\"],\"vXIe7J\":[\"语言\"],\"vYVhmv\":[\"正在生成二维码...\"],\"vYgeDk\":[\"取消全选\"],\"vaHbOZ\":[\"共享的处理管道工具\"],\"vejAXH\":[\"直播链接\"],\"vf5Y2I\":[\"复制（直通）\"],\"vfZWyn\":[\"尝试调整过滤器或限制\"],\"vfeDAy\":[\"在第 30 秒生成缩略图（宽 480px）用于预览。\"],\"vgjQEq\":[\"重封装并清理\"],\"vgwVkd\":[\"UTC\"],\"vjD16L\":[\"宽度（像素，保持宽高比）\"],\"vlDq31\":[\"选择你想要尝试采集的最高质量等级。\"],\"voD8oZ\":[\"配置步骤\"],\"voRH7M\":[\"示例：\"],\"vrNaKg\":[\"提示：1 Mbit/s ≈ 0.125M（将比特数除以 8）。\"],\"vrnnn9\":[\"处理中\"],\"vtDkV7\":[\"选择预设主题或导入自定义主题。\"],\"vv7kpg\":[\"已取消\"],\"vwRuK_\":[\"加载预设失败\"],\"vxaZfq\":[\"时长限制\"],\"vzGE_4\":[\"代理配置\"],\"vzxlNg\":[\"根据标题、时间或语言定义自动录制或跳过的规则。\"],\"w-bWA_\":[\"仅限数字房间 ID\"],\"w2ybE1\":[[\"0\"],\" 配置\"],\"w6RBiW\":[\"查看录制内容并管理归档\"],\"wAFtA2\":[\"仅对超过阈值大小的文件生效。默认值为 4，设为 0 可禁用。\"],\"wAgqfF\":[\"双屏数据\"],\"wBMjJ2\":[\"次数\"],\"wCD4xg\":[\"解析失败：\",[\"0\"]],\"wDioLj\":[\"重试处理任务失败\"],\"wEp8H9\":[\"更新渠道失败\"],\"wErX0W\":[\"主文件名（文件名匹配）\"],\"wFb0uO\":[\"采集模式\"],\"wMHvYH\":[\"值\"],\"wMQZYQ\":[\"直播刷新间隔 (ms)\"],\"wMe2Qp\":[\"命名空间\"],\"wOjRUU\":[\"最后检查\"],\"wSOVbP\":[\"停止新录制\"],\"wVagb1\":[\"在待确认状态 \",[\"0\"],\" 秒后恢复。\"],\"wVk8_8\":[\"内联：\",[\"stepName\"]],\"w_bY7R\":[\"日志\"],\"wdxz7K\":[\"来源\"],\"wg5HlH\":[\"rclone 并行上传的文件数量。\"],\"wgeqNr\":[\"画质设置\"],\"wiMpRi\":[\"覆盖输出\"],\"woYYQq\":[\"工作流\"],\"wqJ4zc\":[\"环境变量\"],\"wrl8J3\":[\"收件人地址\"],\"wspIF7\":[\"rclone 默认值：1\"],\"wv1e0F\":[\"内部\"],\"wwNqQz\":[\"FLV 调优\"],\"wwu18a\":[\"图标\"],\"wzr18h\":[\"导出配置\"],\"x-uFU5\":[\"0 = 不限\"],\"x09b8E\":[\"请求超时时长\"],\"x0U6Og\":[\"过滤窗口大小\"],\"x0aAkx\":[\"搜索媒体文件...\"],\"x1VcXy\":[\"帧率（FPS）\"],\"x2fr_j\":[\"图表\"],\"x2igpn\":[\"Telegram Desktop 路径（包含 tdata）\"],\"x7PDL5\":[\"日志\"],\"xA2izn\":[\"未发现处理管道 DAG。\"],\"xBO-wH\":[\"个渠道\"],\"xCJdfg\":[\"清空\"],\"xCaAmT\":[\"重试延迟（ms）\"],\"xCtNNg\":[\"覆盖调度器确认直播结束的激进程度。\"],\"xD4Xm2\":[\"获取\"],\"xDAtGP\":[\"消息\"],\"xDr_ct\":[\"结束\"],\"xGzQCl\":[\"工作流\"],\"xND0SQ\":[\"占位符\"],\"xQV856\":[\"克隆预设失败：\",[\"0\"]],\"xUpogZ\":[\"veryfast\"],\"xY9s5E\":[\"超时\"],\"xZGBr0\":[\"提取音频\"],\"x_biSg\":[\"离线检查之间的间隔。\"],\"xdqOXO\":[\"允许私有网络流代理目标\"],\"xeMG4j\":[\"正则表达式\"],\"xi8BoZ\":[\"并行完整性校验（例如比较源/目标哈希值）。\"],\"xjwCW_\":[\"最大密钥重试延迟（毫秒）\"],\"xqKCfi\":[\"开始手机号和验证码登录\"],\"xsp8AP\":[\"覆盖输出文件夹。\"],\"xtWiCP\":[\"DanmakuFactory 可执行文件路径。如果为空，则使用环境变量或 PATH。\"],\"xvp-v2\":[\"缩略图生成器\"],\"xxCtZv\":[\"每页行数\"],\"xzqVyW\":[\"实时轮询\"],\"y2WOeQ\":[\"正在加载工作流...\"],\"y7ZemI\":[\"使用直播开始时间可让跨午夜直播保存在同一个日期文件夹中。执行时间会保留现有 copy/move 行为。\"],\"y7sj-0\":[\"主播\"],\"yDlWVJ\":[\"离线检查间隔\"],\"yGS9cI\":[\"正常\"],\"yHXa3T\":[\"-1001234567890\"],\"yIMeW0\":[\"强制请求可用的最高原画质量（直连流）。\"],\"yL1-1a\":[\"直播开始时间\"],\"yMMvQD\":[\"如果标题中出现任何这些关键字，该流将被忽略。\"],\"yRAYpv\":[\"画质等级\"],\"yRadQU\":[\"当后处理任务开始时触发。\"],\"yRzWhF\":[\"监控并管理处理任务\"],\"ySWmtK\":[\"保持运行\"],\"yScTZL\":[\"系统状态\"],\"ySxXn1\":[\"/usr/bin/ffmpeg 或 ffmpeg\"],\"yTLtq4\":[\"复杂模式\"],\"yThUSt\":[\"使用 NVIDIA NVENC 的高质量 GPU 加速 HEVC/H.265 编码（CQ 21）。GPU 最佳压缩比。\"],\"yXcp78\":[\"本地处理：重封装为 MP4，生成缩略图，移动到归档文件夹。\"],\"yZgsv0\":[\"请求不含视频轨的 AAC 音频。\"],\"yZpWgb\":[\"响应中未找到可播放的流\"],\"y_0uwd\":[\"昨天\"],\"yfJY2E\":[\"GPU 健康探测间隔\"],\"ygqCBL\":[\"压缩视频用于存档，上传到云端，然后删除本地文件以节省空间。\"],\"yhvq_6\":[\"被浏览器阻止\"],\"ylXj1N\":[\"已选择\"],\"yntXb7\":[\"编辑模式\"],\"ypFsBx\":[\"忽略高于此比特率的流。\"],\"ysAGq3\":[\"未授予通知权限\"],\"ywDBfs\":[\"发生错误！\"],\"ywFj2D\":[\"配置\"],\"yx_fMc\":[\"高\"],\"yz7wBu\":[\"关闭\"],\"z-rLjA\":[\"超时（秒）\"],\"z1g5gH\":[\"启用所选\"],\"z2QpqF\":[\"路径变量\"],\"z4YzF4\":[\"H2 保活（毫秒）\"],\"z5385K\":[\"输入路径（逗号分隔）\"],\"z55Hd4\":[\"删除源 ASS 文件\"],\"z6HbLm\":[\"DAG 配置\"],\"z7n92F\":[\"直播缺口策略\"],\"zCuWFS\":[\"渠道已创建\"],\"zDfdMJ\":[\"直到\"],\"zG9iNp\":[\"搜索引擎...\"],\"zGgIA2\":[\"Web 推送优先级已更新\"],\"zHa4lw\":[[\"0\",\"plural\",{\"other\":[\"#\",\" 步\"]}]],\"zIkg8M\":[\"重封装为 MP4 且不重新编码，并在成功后删除原始文件。节省磁盘空间。\"],\"zInmXF\":[\"调度器\"],\"zJA0U6\":[\"输出设置\"],\"zKwEox\":[\"目标配置\"],\"zNi48o\":[\"无需重新编码，重封装为 MKV。Matroska 支持更多编码与特性。\"],\"zQLplp\":[\"平台报告该账号已不存在。可能已被删除、封禁，或者 URL/ID 有误。建议删除或更新该主播。\"],\"zSwSsg\":[\"尺寸与滤镜\"],\"zWMS3O\":[\"预览图库\"],\"zd00pH\":[\"复制路径\"],\"zg8Pdk\":[\"离线检测次数\"],\"zga9sT\":[\"正常\"],\"zi6RXq\":[\"加载主播失败：\",[\"0\"]],\"zoISi_\":[\"无法重试步骤\"],\"zrmjn_\":[\"最大时长\"],\"zx4BuL\":[\"本周\"],\"aHL20j\":[\"补录\"],\"Y0HVk1\":[\"从回放补录\"],\"Une12m\":[\"直播检测较晚时，从平台回放补录开头部分，作为单独的会话保存。\"],\"Yp8Nyj\":[\"主机超过任一限制时新的录制将等待，正在进行的录制不受影响。I/O 压力仅在 Linux 上可用。0 = 不启用\"],\"doOgTJ\":[\"最高 I/O 压力 (%)\"],\"tCB1Oe\":[\"最高内存负载 (%)\"],\"zkJHvX\":[\"最高 CPU 负载 (%)\"],\"_ly4n9\":[\"按时钟整点分段间隔\"],\"uyLmFK\":[\"按时钟分段，从本地午夜起计算。\"],\"az8lvo\":[\"关闭\"],\"43L24v\":[\"首次重试延迟（毫秒）\"],\"0TejLH\":[\"延迟倍数\"],\"YiyJ92\":[\"延迟抖动\"],\"Gn-MIC\":[\"重试网络错误（默认：开）\"],\"ycTCRM\":[\"重试超时（默认：开）\"],\"yUUq0F\":[\"重试 5xx 响应（默认：开）\"],\"Ab8kME\":[\"重试 429 响应（默认：开）\"],\"v6JGfC\":[\"重试 404/410 响应（默认：关）\"],\"DV0xXJ\":[\"HLS 会就地重试分段最多两次，之后在 HLS 生命周期重试预算内重新调度。HLS 总会重试 404。\"],\"p-Iwd_\":[\"保留原始流\"],\"WTiJlJ\":[\"同时在修复后的文件旁保存未处理的原始流\"],\"EoerzS\":[\"卡死看门狗\"],\"WONCR_\":[\"卡死超时（秒）\"],\"cauGYp\":[\"卡死时\"],\"y5UwJH\":[\"重新连接（默认）\"],\"OrPA0A\":[\"结束直播流\"],\"HCGpTY\":[\"H2 保活超时（毫秒）\"],\"Gxea7R\":[\"TCP 保活（毫秒）\"],\"LmLO6R\":[\"H2 流窗口（字节）\"],\"C2ju8g\":[\"H2 连接窗口（字节）\"],\"qy-Njm\":[\"H2 自适应窗口（默认：关）\"],\"D2iyNz\":[\"H2 空闲时保活（默认：关）\"]}")as Messages;
\ No newline at end of file
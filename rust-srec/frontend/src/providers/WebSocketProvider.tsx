@@ -104,6 +104,8 @@ export function WebSocketProvider({ children }: { children: ReactNode }) {
                         segmentsCompleted: 0,
                         mediaDurationSecs: 0,
                         playbackRatio: 0,
+                        segmentLatencyMs: 0n,
+                        stalled: false,
                       },
                     };
                   })
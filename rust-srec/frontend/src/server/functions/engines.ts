@@ -8,6 +8,7 @@ import {
   FfmpegConfigSchema,
   StreamlinkConfigSchema,
   MesioConfigSchema,
+  YtDlpConfigSchema,
 } from '../../api/schemas';
 import { z } from 'zod';
 
@@ -19,6 +20,8 @@ function normalizeConfig(engineType: string, config: Record<string, unknown>) {
       return StreamlinkConfigSchema.parse(config);
     case 'MESIO':
       return MesioConfigSchema.parse(config);
+    case 'YTDLP':
+      return YtDlpConfigSchema.parse(config);
     default:
       return config;
   }
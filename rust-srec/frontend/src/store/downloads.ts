@@ -28,6 +28,8 @@ export interface DownloadMetrics {
   segmentsCompleted: number;
   mediaDurationSecs: number;
   playbackRatio: number;
+  segmentLatencyMs: bigint;
+  stalled: boolean;
 }
 
 export interface DownloadState {
@@ -55,6 +57,8 @@ export interface DownloadView {
   segmentsCompleted: number;
   mediaDurationSecs: number;
   playbackRatio: number;
+  segmentLatencyMs: bigint;
+  stalled: boolean;
 }
 
 export type Download = DownloadView;
@@ -93,6 +97,8 @@ function emptyMetrics(downloadId: string): DownloadMetrics {
     segmentsCompleted: 0,
     mediaDurationSecs: 0,
     playbackRatio: 0,
+    segmentLatencyMs: 0n,
+    stalled: false,
   };
 }
 
@@ -115,6 +121,8 @@ function toView(meta: DownloadMeta, metrics: DownloadMetrics): DownloadView {
     segmentsCompleted: metrics.segmentsCompleted,
     mediaDurationSecs: metrics.mediaDurationSecs,
     playbackRatio: metrics.playbackRatio,
+    segmentLatencyMs: metrics.segmentLatencyMs,
+    stalled: metrics.stalled,
   };
 }
 
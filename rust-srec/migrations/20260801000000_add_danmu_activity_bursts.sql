@@ -0,0 +1,11 @@
+-- Activity bursts detected by the danmu statistics aggregator.
+--
+-- JSON array of `{start_ts, end_ts, message_count, peak_rate, baseline_rate}`
+-- objects (timestamps are Unix epoch milliseconds, rates are messages per
+-- second), ordered by `start_ts`. Written alongside the rest of the session's
+-- danmu statistics and read by highlight detection to locate chat spikes.
+--
+-- NULL for rows written before the column existed.
+
+ALTER TABLE danmu_statistics
+    ADD COLUMN activity_bursts TEXT;
@@ -0,0 +1,11 @@
+-- FLV analysis report of a completed segment.
+--
+-- JSON object with tag counts, per-track bitrates, a keyframe interval
+-- histogram, remaining timestamp anomalies and the number of tags dropped or
+-- deduplicated by the FLV pipeline (see `flv_fix::AnalysisReport`).
+--
+-- NULL for segments written by other engines, without the FLV pipeline, or
+-- before the column existed.
+
+ALTER TABLE session_segments
+    ADD COLUMN analysis_report_json TEXT;
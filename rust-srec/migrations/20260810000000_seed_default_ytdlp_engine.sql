@@ -0,0 +1,8 @@
+-- Seed a default yt-dlp engine configuration.
+--
+-- The yt-dlp engine covers sites the built-in extractors don't support yet.
+-- Like the other default engines it is selectable by id, and per-streamer
+-- format selection goes through `engines_override` on the template or streamer.
+
+INSERT OR IGNORE INTO engine_configuration (id, name, engine_type, config) VALUES
+('default-ytdlp', 'default-ytdlp', 'YTDLP', '{"binary_path":"yt-dlp","format":"best","extra_args":[]}');
@@ -0,0 +1,10 @@
+-- Engine that recorded a session.
+--
+-- Id of the engine configuration (e.g. `default-mesio`) used by the most
+-- recent download of the session. Streamers with an engine fallback chain can
+-- switch engines mid-session, in which case this names the last one.
+--
+-- NULL for sessions recorded before the column existed.
+
+ALTER TABLE live_sessions
+    ADD COLUMN download_engine TEXT;
@@ -0,0 +1,27 @@
+-- Free-space policy for recording output volumes.
+--
+-- Without it, a full volume surfaces as StorageFull write errors in the
+-- middle of a recording. Once free space on the volume of an output
+-- directory drops below `low_disk_space_threshold_bytes`, the download
+-- manager applies `low_disk_space_action`:
+--
+--   stop_new       refuse new downloads on the volume; active downloads
+--                  keep writing.
+--   finalize       refuse new downloads and finalize the active ones.
+--   switch_folder  finalize active downloads and restart them, like new
+--                  downloads, below `low_disk_space_secondary_folder`,
+--                  keeping their layout relative to the output folder.
+--
+-- Active downloads are checked every 30 seconds. Hot-reloadable: config
+-- updates call DownloadManager::set_disk_space_policy.
+--
+-- Defaults to 0 (disabled) so upgrades keep the previous behaviour.
+
+ALTER TABLE global_config
+    ADD COLUMN low_disk_space_threshold_bytes INTEGER NOT NULL DEFAULT 0;
+
+ALTER TABLE global_config
+    ADD COLUMN low_disk_space_action TEXT NOT NULL DEFAULT 'stop_new';
+
+ALTER TABLE global_config
+    ADD COLUMN low_disk_space_secondary_folder TEXT;
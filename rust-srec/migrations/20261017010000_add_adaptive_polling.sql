@@ -0,0 +1,16 @@
+-- Adaptive offline polling learned from session history.
+--
+-- When TRUE, the scheduler reads the start times of each streamer's
+-- sessions from the last 28 days (hourly) and adapts the offline check
+-- interval to them: half the interval within 30 minutes of a usual start
+-- time, and up to four times the interval (at most 15 minutes) more than
+-- two hours away from every usual start time. Streamers with fewer than
+-- three recent sessions keep the configured interval.
+--
+-- Hot-reloadable: a global config update rebuilds the profiles and pushes
+-- the new timing to every streamer actor.
+--
+-- Defaults to FALSE so upgrades keep polling at the configured interval.
+
+ALTER TABLE global_config
+    ADD COLUMN adaptive_polling BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,6 @@
+-- Per-platform request rate for status checks, in requests per second.
+--
+-- Every streamer on a platform draws from one shared token bucket sized by
+-- this value. NULL keeps the built-in default for the platform.
+
+ALTER TABLE platform_config ADD COLUMN rate_limit REAL;
@@ -0,0 +1,15 @@
+-- Offline grace duration before a live streamer is declared offline.
+--
+-- After a live streamer stops responding as live, the scheduler already
+-- waits for `offline_check_count` consecutive offline checks. When this is
+-- above zero it also waits until the streamer has been offline for at
+-- least this many seconds, so momentary API flaps and CDN hiccups resume
+-- the same session instead of splitting it. Capped at 600 seconds.
+--
+-- Hot-reloadable: a global config update pushes the new value to every
+-- streamer actor and to the session lifecycle.
+--
+-- Defaults to 0 so upgrades keep confirming offline by check count alone.
+
+ALTER TABLE global_config
+    ADD COLUMN offline_grace_secs INTEGER NOT NULL DEFAULT 0;
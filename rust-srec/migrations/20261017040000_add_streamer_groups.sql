@@ -0,0 +1,27 @@
+-- `streamer_groups` — named sets of streamers that share a template and can
+-- be enabled, disabled or re-prioritised together.
+--
+-- A streamer belongs to at most one group (`streamers.group_id`). When a
+-- group has a template, members adopt it: the template is written to each
+-- member's `template_config_id` when the streamer joins the group and
+-- whenever the group's template changes, so config resolution is unchanged.
+--
+-- Deleting a group only detaches its members; their streamers and the
+-- template they inherited are kept. Deleting the group's template clears it
+-- from the group the same way.
+
+CREATE TABLE streamer_groups (
+    id TEXT PRIMARY KEY NOT NULL,
+    name TEXT NOT NULL UNIQUE,
+    description TEXT,
+    template_config_id TEXT,
+    -- Milliseconds since Unix epoch (UTC)
+    created_at INTEGER NOT NULL DEFAULT (unixepoch('now') * 1000),
+    updated_at INTEGER NOT NULL DEFAULT (unixepoch('now') * 1000),
+    FOREIGN KEY (template_config_id) REFERENCES template_config(id) ON DELETE SET NULL
+);
+
+ALTER TABLE streamers
+    ADD COLUMN group_id TEXT REFERENCES streamer_groups(id) ON DELETE SET NULL;
+
+CREATE INDEX idx_streamers_group_id ON streamers(group_id);
@@ -0,0 +1,9 @@
+-- How a session was recorded.
+--
+-- `live` sessions are recorded from the live stream. `backfill` sessions hold
+-- the part of a broadcast that went by before it was detected, recorded from
+-- the platform replay. Backfill rows are inserted already ended, so they never
+-- compete with the live session for the one active row per streamer.
+
+ALTER TABLE live_sessions
+    ADD COLUMN kind TEXT NOT NULL DEFAULT 'live';
@@ -0,0 +1,23 @@
+-- Load-aware admission control for new downloads.
+--
+-- Starting another recording on a saturated host slows every active one
+-- down. While the sampled global CPU usage, used memory or Linux I/O
+-- pressure (PSI `some avg10`) is above its threshold, the download manager
+-- defers downloads that would start a new recording and the scheduler
+-- re-checks the streamer 30 seconds later. Restarts within a session that
+-- is already recording are never deferred.
+--
+-- Values are percentages; 0 disables a threshold. The I/O threshold is
+-- ignored on hosts without PSI. Hot-reloadable: config updates call
+-- DownloadManager::set_load_admission_policy.
+--
+-- Defaults to 0 (disabled) so upgrades keep the previous behaviour.
+
+ALTER TABLE global_config
+    ADD COLUMN load_admission_max_cpu_percent INTEGER NOT NULL DEFAULT 0;
+
+ALTER TABLE global_config
+    ADD COLUMN load_admission_max_memory_percent INTEGER NOT NULL DEFAULT 0;
+
+ALTER TABLE global_config
+    ADD COLUMN load_admission_max_io_pressure_percent INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,24 @@
+-- `oauth_tokens` — OAuth 2.0 grants for upload accounts, such as the
+-- YouTube channel the `youtube` pipeline processor uploads to.
+--
+-- Processor configs reference an account by `name`. The access token is a
+-- cache: it is refreshed from `refresh_token` shortly before
+-- `access_token_expires_at` and written back here.
+
+CREATE TABLE oauth_tokens (
+    id TEXT PRIMARY KEY NOT NULL,
+    name TEXT NOT NULL UNIQUE,
+    -- Token endpoint family, e.g. 'google'.
+    provider TEXT NOT NULL,
+    client_id TEXT NOT NULL,
+    client_secret TEXT NOT NULL,
+    refresh_token TEXT NOT NULL,
+    access_token TEXT,
+    -- Milliseconds since Unix epoch (UTC); NULL when no access token is cached.
+    access_token_expires_at INTEGER,
+    -- Space-separated scopes granted by the user.
+    scope TEXT,
+    -- Milliseconds since Unix epoch (UTC)
+    created_at INTEGER NOT NULL DEFAULT (unixepoch('now') * 1000),
+    updated_at INTEGER NOT NULL DEFAULT (unixepoch('now') * 1000)
+);
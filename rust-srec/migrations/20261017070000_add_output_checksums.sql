@@ -0,0 +1,22 @@
+-- `output_checksums` — digests of session files computed by the `checksum`
+-- pipeline processor, so files can be verified after moves and uploads.
+--
+-- Keyed by path rather than media_outputs row: processors produce files
+-- (remuxes, merges) that are not listed in media_outputs.
+
+CREATE TABLE output_checksums (
+    id TEXT PRIMARY KEY NOT NULL,
+    session_id TEXT NOT NULL,
+    file_path TEXT NOT NULL,
+    -- 'sha256' or 'blake3'
+    algorithm TEXT NOT NULL,
+    -- Lowercase hex digest.
+    digest TEXT NOT NULL,
+    size_bytes INTEGER NOT NULL,
+    -- Milliseconds since Unix epoch (UTC)
+    computed_at INTEGER NOT NULL,
+    FOREIGN KEY (session_id) REFERENCES live_sessions(id) ON DELETE CASCADE,
+    UNIQUE (file_path, algorithm)
+);
+
+CREATE INDEX idx_output_checksums_session ON output_checksums(session_id);
@@ -0,0 +1,14 @@
+-- Danmu connection policy.
+--
+-- JSON object with any of `max_reconnect_attempts`, `base_reconnect_delay_ms`,
+-- `max_reconnect_delay_ms`, `backoff_multiplier`, `jitter_ratio` and
+-- `heartbeat_interval_ms`, layered over the built-in defaults for every
+-- danmu connection. A platform can override it further with the
+-- `danmu_connection` key of `platform_config.platform_specific_config`.
+--
+-- Read when the danmu service starts; changes apply after a restart.
+--
+-- NULL keeps the built-in defaults.
+
+ALTER TABLE global_config
+    ADD COLUMN danmu_connection_config TEXT;
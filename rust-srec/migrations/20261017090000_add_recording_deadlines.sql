@@ -0,0 +1,14 @@
+-- Stop times of "record now" requests with a maximum duration.
+--
+-- The download manager keeps the deadlines in memory and enforces them
+-- every few seconds; this table reloads them on startup so a restart does
+-- not leave a one-off recording running indefinitely. A row is removed
+-- once its deadline has been enforced or the request is re-issued without
+-- a duration.
+
+CREATE TABLE recording_deadlines (
+    streamer_id TEXT PRIMARY KEY NOT NULL,
+    -- Milliseconds since Unix epoch (UTC)
+    stop_at INTEGER NOT NULL,
+    FOREIGN KEY (streamer_id) REFERENCES streamers(id) ON DELETE CASCADE
+);
@@ -13,6 +13,7 @@
 //! - **Pipeline**: Job queue and processing pipeline
 //! - **Session**: Recording sessions and outputs
 //! - **Health**: System health checks
+//! - **Scheduler**: Actor diagnostics
 //! - **Utilities**: URL metadata extraction
 
 use chrono::{DateTime, Utc};
@@ -181,6 +182,49 @@ pub struct UpdatePriorityRequest {
     pub priority: Priority,
 }
 
+/// Request to mark a chapter in a streamer's active recording.
+#[derive(Debug, Clone, Deserialize, utoipa::ToSchema)]
+pub struct AddChapterMarkerRequest {
+    /// Chapter title
+    pub title: String,
+}
+
+/// Request to check a streamer and record it right away.
+#[derive(Debug, Clone, Default, Deserialize, utoipa::ToSchema)]
+pub struct RecordNowRequest {
+    /// Pause the download this many seconds after the request
+    pub max_duration_secs: Option<u64>,
+}
+
+/// Request to record a stream URL right away.
+#[derive(Debug, Clone, Deserialize, utoipa::ToSchema)]
+pub struct RecordUrlNowRequest {
+    /// Stream URL; a streamer is created when no streamer has this URL
+    pub url: String,
+    /// Name of the created streamer (defaults to the channel ID)
+    pub name: Option<String>,
+    /// Pause the download this many seconds after the request
+    pub max_duration_secs: Option<u64>,
+}
+
+/// Result of a record-now request.
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
+pub struct RecordNowResponse {
+    /// Streamer being checked
+    pub streamer: StreamerResponse,
+    /// Whether the streamer was created for this request
+    pub created: bool,
+    /// Whether a status check was triggered; a new streamer is checked as
+    /// soon as its monitor starts
+    pub check_triggered: bool,
+    /// Whether a paused download of the streamer was resumed
+    pub resumed: bool,
+    /// When the download will be paused. The session stays open until the
+    /// stream ends, so the stream is not recorded again; the download can be
+    /// resumed like any paused download
+    pub stop_at: Option<DateTime<Utc>>,
+}
+
 /// Mutation applied to every streamer in a batch request.
 #[derive(Debug, Clone, Deserialize, utoipa::ToSchema)]
 #[serde(tag = "type", rename_all = "snake_case")]
@@ -232,6 +276,64 @@ pub struct BatchStreamerResponse {
     pub results: Vec<BatchStreamerItemResult>,
 }
 
+// ============================================================================
+// Streamer Group DTOs
+// ============================================================================
+
+/// Request to create a streamer group.
+#[derive(Debug, Clone, Deserialize, utoipa::ToSchema)]
+pub struct CreateStreamerGroupRequest {
+    /// Unique group name
+    pub name: String,
+    /// Free-form description
+    pub description: Option<String>,
+    /// Template adopted by every member
+    pub template_id: Option<String>,
+    /// Streamers to add to the group
+    #[serde(default)]
+    pub streamer_ids: Vec<String>,
+}
+
+/// Request to update a streamer group.
+#[derive(Debug, Clone, Deserialize, Default, utoipa::ToSchema)]
+pub struct UpdateStreamerGroupRequest {
+    /// Unique group name
+    pub name: Option<String>,
+    /// Free-form description, cleared with `null`
+    #[serde(default, deserialize_with = "deserialize_field_present_nullable")]
+    pub description: Option<Option<String>>,
+    /// Template adopted by every member, cleared with `null`
+    #[serde(default, deserialize_with = "deserialize_field_present_nullable")]
+    pub template_id: Option<Option<String>>,
+}
+
+/// Request to add streamers to a group.
+#[derive(Debug, Clone, Deserialize, utoipa::ToSchema)]
+pub struct StreamerGroupMembersRequest {
+    /// Streamers to add; members of another group are moved.
+    pub streamer_ids: Vec<String>,
+}
+
+/// Request to apply one mutation to every member of a group.
+#[derive(Debug, Clone, Deserialize, utoipa::ToSchema)]
+pub struct StreamerGroupBatchRequest {
+    /// Mutation applied to each member.
+    pub action: BatchStreamerAction,
+}
+
+/// Streamer group response.
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
+pub struct StreamerGroupResponse {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub template_id: Option<String>,
+    /// IDs of the member streamers
+    pub streamer_ids: Vec<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
 /// Streamer response.
 #[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
 pub struct StreamerResponse {
@@ -241,6 +343,7 @@ pub struct StreamerResponse {
     pub avatar_url: Option<String>,
     pub platform_config_id: String,
     pub template_id: Option<String>,
+    pub group_id: Option<String>,
     pub state: StreamerState,
     pub priority: Priority,
     pub enabled: bool,
@@ -262,6 +365,8 @@ pub struct StreamerFilterParams {
     pub template: Option<String>,
     /// Filter by whether no configuration template is assigned
     pub template_unassigned: Option<bool>,
+    /// Filter by streamer group
+    pub group: Option<String>,
     /// Filter by state (comma-separated for multiple)
     pub state: Option<String>,
     /// Filter by priority
@@ -328,6 +433,28 @@ pub struct GlobalConfigResponse {
     /// Read per request by the stream-proxy route, so changes apply
     /// without a restart.
     pub stream_proxy_allow_private_targets: bool,
+
+    /// Minimum free bytes on an output volume; `0` disables the policy.
+    pub low_disk_space_threshold_bytes: u64,
+    /// Action taken below the free-space threshold: `stop_new`,
+    /// `finalize` or `switch_folder`.
+    pub low_disk_space_action: String,
+    /// Folder used by the `switch_folder` action.
+    pub low_disk_space_secondary_folder: Option<String>,
+    /// Whether offline polling adapts to when streamers usually go live.
+    pub adaptive_polling: bool,
+    /// Seconds a live streamer must stay offline before it is confirmed
+    /// offline; `0` confirms by `offline_check_count` alone.
+    pub offline_grace_secs: u64,
+    /// CPU usage percent above which new downloads are deferred; `0`
+    /// disables the threshold.
+    pub load_admission_max_cpu_percent: u8,
+    /// Memory usage percent above which new downloads are deferred.
+    pub load_admission_max_memory_percent: u8,
+    /// Linux I/O pressure percent above which new downloads are deferred.
+    pub load_admission_max_io_pressure_percent: u8,
+    /// JSON danmu reconnect and heartbeat policy; applied on restart.
+    pub danmu_connection_config: Option<String>,
 }
 
 /// Request to update global configuration.
@@ -371,6 +498,24 @@ pub struct UpdateGlobalConfigRequest {
     pub gpu_health_probe_interval_secs: Option<serde_json::Value>,
     /// Whether the stream proxy may fetch targets on private networks.
     pub stream_proxy_allow_private_targets: Option<serde_json::Value>,
+    /// Minimum free bytes on an output volume; `0` disables the policy.
+    pub low_disk_space_threshold_bytes: Option<serde_json::Value>,
+    /// `stop_new`, `finalize` or `switch_folder`.
+    pub low_disk_space_action: Option<serde_json::Value>,
+    /// Folder used by the `switch_folder` action; empty clears it.
+    pub low_disk_space_secondary_folder: Option<serde_json::Value>,
+    /// Whether offline polling adapts to when streamers usually go live.
+    pub adaptive_polling: Option<serde_json::Value>,
+    /// Offline grace in seconds, clamped to `0..=600`.
+    pub offline_grace_secs: Option<serde_json::Value>,
+    /// CPU usage percent limit for new downloads, clamped to `0..=100`.
+    pub load_admission_max_cpu_percent: Option<serde_json::Value>,
+    /// Memory usage percent limit for new downloads, clamped to `0..=100`.
+    pub load_admission_max_memory_percent: Option<serde_json::Value>,
+    /// I/O pressure percent limit for new downloads, clamped to `0..=100`.
+    pub load_admission_max_io_pressure_percent: Option<serde_json::Value>,
+    /// JSON danmu reconnect and heartbeat policy; empty clears it.
+    pub danmu_connection_config: Option<serde_json::Value>,
 }
 
 /// Platform configuration response.
@@ -402,6 +547,9 @@ pub struct PlatformConfigResponse {
     pub offline_check_count: Option<u32>,
     /// Override for the global `offline_check_delay_ms`. NULL inherits.
     pub offline_check_delay_ms: Option<u64>,
+    /// Status checks per second for the platform. NULL keeps the built-in default.
+    #[serde(default)]
+    pub rate_limit: Option<f64>,
 }
 
 // ============================================================================
@@ -750,11 +898,25 @@ pub struct SessionSegmentResponse {
     pub size_bytes: u64,
     pub split_reason_code: Option<String>,
     pub split_reason_details: Option<serde_json::Value>,
+    /// Analysis report of the segment (FLV or HLS), when the pipeline produced one
+    pub analysis_report: Option<serde_json::Value>,
     pub created_at: Option<DateTime<Utc>>,
     pub completed_at: Option<DateTime<Utc>>,
     pub persisted_at: DateTime<Utc>,
 }
 
+/// Digest of a session file, as stored by the `checksum` processor.
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
+pub struct OutputChecksumResponse {
+    pub file_path: String,
+    /// `sha256` or `blake3`
+    pub algorithm: String,
+    /// Lowercase hex digest
+    pub digest: String,
+    pub size_bytes: u64,
+    pub computed_at: DateTime<Utc>,
+}
+
 // ============================================================================
 // Session DTOs
 // ============================================================================
@@ -778,7 +940,9 @@ pub struct SessionSegmentResponse {
 ///     "duration_secs": 14400,
 ///     "output_count": 3,
 ///     "total_size_bytes": 5368709120,
-///     "danmu_count": 15000
+///     "danmu_count": 15000,
+///     "download_engine": "default-mesio",
+///     "kind": "live"
 /// }
 /// ```
 ///
@@ -795,6 +959,10 @@ pub struct SessionSegmentResponse {
 /// - `output_count` - Number of output files produced
 /// - `total_size_bytes` - Total size of all output files
 /// - `danmu_count` - Number of danmu (chat) messages recorded
+/// - `download_engine` - Engine that recorded the session (the last one used
+///   when the streamer fell back to another engine)
+/// - `kind` - `live`, or `backfill` for the start of a broadcast recorded
+///   from the platform replay
 #[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
 pub struct SessionResponse {
     pub id: String,
@@ -824,6 +992,8 @@ pub struct SessionResponse {
     pub total_size_bytes: u64,
     pub danmu_count: Option<u64>,
     pub thumbnail_url: Option<String>,
+    pub download_engine: Option<String>,
+    pub kind: String,
 }
 
 /// One row from the `session_events` audit log, exposed on the session
@@ -897,6 +1067,8 @@ pub struct SessionDanmuStatisticsResponse {
     pub danmu_rate_timeseries: Vec<DanmuRatePoint>,
     pub top_talkers: Vec<DanmuTopTalker>,
     pub word_frequency: Vec<DanmuWordFrequency>,
+    /// Periods of unusually high chat activity, ordered by start time.
+    pub activity_bursts: Vec<DanmuActivityBurst>,
 }
 
 /// Danmu rate datapoint.
@@ -922,6 +1094,20 @@ pub struct DanmuWordFrequency {
     pub count: i64,
 }
 
+/// Activity burst entry.
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
+pub struct DanmuActivityBurst {
+    /// Unix epoch milliseconds (UTC).
+    pub start_ts: i64,
+    /// Unix epoch milliseconds (UTC).
+    pub end_ts: i64,
+    pub message_count: i64,
+    /// Peak messages per second.
+    pub peak_rate: f64,
+    /// Baseline messages per second when the burst started.
+    pub baseline_rate: f64,
+}
+
 /// Title change entry representing a stream title update.
 ///
 /// # Example
@@ -1000,6 +1186,89 @@ pub struct ComponentHealth {
     pub check_duration_ms: Option<u64>,
 }
 
+// ============================================================================
+// Scheduler DTOs
+// ============================================================================
+
+/// Diagnostics for a supervised scheduler actor.
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
+pub struct ActorInfoResponse {
+    /// Actor ID (streamer ID or platform ID).
+    pub id: String,
+    /// Actor type ("streamer" or "platform").
+    pub actor_type: String,
+    /// Streamer name, for streamer actors.
+    pub name: Option<String>,
+    /// Platform config of the streamer, for streamer actors.
+    pub platform_config_id: Option<String>,
+    /// Whether the actor task is running. `false` while a crashed actor
+    /// waits for its restart.
+    pub running: bool,
+    /// Whether the actor answered the state query in time. A running but
+    /// unresponsive actor is busy or stuck.
+    pub responsive: bool,
+    pub high_priority: bool,
+    /// Seconds since the current actor instance was spawned.
+    pub uptime_secs: Option<u64>,
+    /// Messages waiting in the mailbox.
+    pub mailbox_len: usize,
+    pub mailbox_capacity: usize,
+    pub metrics: Option<ActorMetricsResponse>,
+    pub streamer: Option<StreamerActorInfo>,
+    pub platform: Option<PlatformActorInfo>,
+    pub restarts: ActorRestartInfo,
+}
+
+/// Message counters of the current actor instance.
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
+pub struct ActorMetricsResponse {
+    pub messages_processed: u64,
+    pub errors: u64,
+    pub average_latency_us: f64,
+    pub max_latency_us: u64,
+}
+
+/// Scheduling state of a streamer actor.
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
+pub struct StreamerActorInfo {
+    /// The actor's view of the streamer state.
+    pub state: StreamerState,
+    /// When the next status check is due.
+    pub next_check_at: Option<DateTime<Utc>>,
+    pub last_check_at: Option<DateTime<Utc>>,
+    pub last_check_state: Option<StreamerState>,
+    pub last_check_error: Option<String>,
+    /// Offline checks seen since the streamer was last live.
+    pub offline_count: u32,
+    /// Consecutive failed checks, driving the error backoff.
+    pub consecutive_error_count: i32,
+    pub disabled_until: Option<DateTime<Utc>>,
+    pub last_error: Option<String>,
+}
+
+/// Batch state of a platform actor.
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
+pub struct PlatformActorInfo {
+    pub streamer_count: usize,
+    pub pending_count: usize,
+    pub last_batch_at: Option<DateTime<Utc>>,
+    pub total_batches: u64,
+    pub success_rate: f64,
+}
+
+/// Crash and restart history of an actor.
+#[derive(Debug, Clone, Default, Serialize, utoipa::ToSchema)]
+pub struct ActorRestartInfo {
+    pub total_restarts: u64,
+    /// Crashes within the restart tracker's failure window.
+    pub recent_failures: usize,
+    pub last_restart_at: Option<DateTime<Utc>>,
+    /// Error of the most recent crash.
+    pub last_crash_error: Option<String>,
+    /// When a crashed actor is due to be restarted.
+    pub restart_at: Option<DateTime<Utc>>,
+}
+
 // ============================================================================
 // Utilities DTOs
 // ============================================================================
@@ -6,22 +6,27 @@
 use utoipa::OpenApi;
 
 use crate::api::models::{
-    ComponentHealth, CreateFilterRequest, CreateStreamerRequest, CreateTemplateRequest,
+    ActorInfoResponse, ActorMetricsResponse, ActorRestartInfo, ComponentHealth,
+    CreateFilterRequest, CreateStreamerRequest, CreateTemplateRequest, DanmuActivityBurst,
     DanmuRatePoint, DanmuTopTalker, DanmuWordFrequency, ExtractMetadataRequest,
     ExtractMetadataResponse, FilterResponse, GlobalConfigResponse, HealthResponse, JobResponse,
-    PaginatedResponse, ParseUrlRequest, ParseUrlResponse, PipelineStatsResponse,
-    PlatformConfigResponse, ResolveUrlRequest, ResolveUrlResponse, SessionDanmuStatisticsResponse,
-    SessionResponse, StreamerResponse, TemplateResponse, UpdateFilterRequest,
-    UpdateGlobalConfigRequest, UpdatePriorityRequest, UpdateStreamerRequest, UpdateTemplateRequest,
+    OutputChecksumResponse, PaginatedResponse, ParseUrlRequest, ParseUrlResponse,
+    PipelineStatsResponse, PlatformActorInfo, PlatformConfigResponse, ResolveUrlRequest,
+    ResolveUrlResponse, SessionDanmuStatisticsResponse, SessionResponse, StreamerActorInfo,
+    StreamerResponse, TemplateResponse, UpdateFilterRequest, UpdateGlobalConfigRequest,
+    UpdatePriorityRequest, UpdateStreamerRequest, UpdateTemplateRequest,
 };
 use crate::api::routes::auth::{
     ChangePasswordRequest, LoginRequest, LoginResponse, LogoutRequest, RefreshRequest,
 };
 use crate::api::routes::credentials::{
-    CredentialRefreshResponse, CredentialSaveScope, CredentialSourceResponse,
+    CredentialRefreshResponse, CredentialSaveScope, CredentialSourceResponse, OAuthAccountRequest,
+    OAuthAccountResponse, OAuthAuthorizeRequest, OAuthAuthorizeResponse, OAuthExchangeRequest,
     QrGenerateApiResponse, QrPollApiResponse, QrPollRequest,
 };
-use crate::api::routes::engines::{CreateEngineRequest, EngineTestResponse, UpdateEngineRequest};
+use crate::api::routes::engines::{
+    CreateEngineRequest, EngineHealthResponse, EngineTestResponse, UpdateEngineRequest,
+};
 use crate::api::routes::job::{
     ClonePresetRequest, CreatePresetRequest, PresetListResponse, UpdatePresetRequest,
 };
@@ -75,6 +80,7 @@ pub struct MessageResponse {
         (name = "health", description = "Health check endpoints for monitoring and orchestration"),
         (name = "auth", description = "Authentication endpoints for login, logout, and token management"),
         (name = "streamers", description = "Streamer management endpoints"),
+        (name = "groups", description = "Streamer group management endpoints"),
         (name = "config", description = "Configuration management endpoints"),
         (name = "sessions", description = "Recording session endpoints"),
         (name = "templates", description = "Template configuration endpoints"),
@@ -84,17 +90,22 @@ pub struct MessageResponse {
         (name = "logging", description = "Logging configuration endpoints"),
         (name = "media", description = "Media content delivery endpoints"),
         (name = "engines", description = "Download engine configuration endpoints"),
+        (name = "downloads", description = "Active download control endpoints"),
         (name = "notifications", description = "Notification channel management endpoints"),
         (name = "job", description = "Job preset management endpoints"),
         (name = "export_import", description = "Configuration backup and restore endpoints")
         ,
-        (name = "credentials", description = "Credential refresh and provenance endpoints")
+        (name = "credentials", description = "Credential refresh and provenance endpoints"),
+        (name = "scheduler", description = "Scheduler actor diagnostics endpoints")
     ),
     paths(
         // Health endpoints
         crate::api::routes::health::health_check,
         crate::api::routes::health::readiness_check,
         crate::api::routes::health::liveness_check,
+        // Scheduler endpoints
+        crate::api::routes::scheduler::list_actors,
+        crate::api::routes::scheduler::get_actor,
         // Auth endpoints
         crate::api::routes::auth::login,
         crate::api::routes::auth::refresh,
@@ -113,6 +124,18 @@ pub struct MessageResponse {
         crate::api::routes::streamers::update_priority,
         crate::api::routes::streamers::extract_metadata,
         crate::api::routes::streamers::get_check_history,
+        crate::api::routes::streamers::add_chapter_marker,
+        crate::api::routes::streamers::record_streamer_now,
+        crate::api::routes::streamers::record_url_now,
+        // Streamer group endpoints
+        crate::api::routes::groups::create_group,
+        crate::api::routes::groups::list_groups,
+        crate::api::routes::groups::get_group,
+        crate::api::routes::groups::update_group,
+        crate::api::routes::groups::delete_group,
+        crate::api::routes::groups::add_members,
+        crate::api::routes::groups::remove_member,
+        crate::api::routes::groups::batch_group,
         // Config endpoints
         crate::api::routes::config::get_global_config,
         crate::api::routes::config::update_global_config,
@@ -123,6 +146,7 @@ pub struct MessageResponse {
         crate::api::routes::sessions::list_sessions,
         crate::api::routes::sessions::get_session,
         crate::api::routes::sessions::get_session_danmu_statistics,
+        crate::api::routes::sessions::list_session_checksums,
         crate::api::routes::sessions::delete_session,
         crate::api::routes::sessions::delete_sessions_batch,
         // Template endpoints
@@ -187,6 +211,11 @@ pub struct MessageResponse {
         crate::api::routes::engines::update_engine,
         crate::api::routes::engines::delete_engine,
         crate::api::routes::engines::test_engine,
+        crate::api::routes::engines::engines_health,
+        // Download endpoints
+        crate::api::routes::downloads::list_paused_downloads,
+        crate::api::routes::downloads::pause_download,
+        crate::api::routes::downloads::resume_download,
         // Notification endpoints
         crate::api::routes::notifications::list_event_types,
         crate::api::routes::notifications::list_events,
@@ -218,6 +247,11 @@ pub struct MessageResponse {
         crate::api::routes::credentials::refresh_template_credentials,
         crate::api::routes::credentials::bilibili_qr_generate,
         crate::api::routes::credentials::bilibili_qr_poll,
+        crate::api::routes::credentials::list_oauth_accounts,
+        crate::api::routes::credentials::put_oauth_account,
+        crate::api::routes::credentials::delete_oauth_account,
+        crate::api::routes::credentials::google_oauth_authorize,
+        crate::api::routes::credentials::google_oauth_exchange,
     ),
     components(
         schemas(
@@ -225,6 +259,12 @@ pub struct MessageResponse {
             HealthResponse,
             ComponentHealth,
             LivenessResponse,
+            // Scheduler schemas
+            ActorInfoResponse,
+            ActorMetricsResponse,
+            StreamerActorInfo,
+            PlatformActorInfo,
+            ActorRestartInfo,
             // Auth schemas
             LoginRequest,
             LoginResponse,
@@ -239,12 +279,22 @@ pub struct MessageResponse {
             CreateStreamerRequest,
             UpdateStreamerRequest,
             UpdatePriorityRequest,
+            crate::api::models::AddChapterMarkerRequest,
+            crate::api::models::RecordNowRequest,
+            crate::api::models::RecordUrlNowRequest,
+            crate::api::models::RecordNowResponse,
             StreamerResponse,
             PaginatedResponse<StreamerResponse>,
             ExtractMetadataRequest,
             ExtractMetadataResponse,
             crate::api::models::StreamerCheckHistoryEntry,
             crate::api::models::StreamerCheckHistoryResponse,
+            // Streamer group schemas
+            crate::api::models::CreateStreamerGroupRequest,
+            crate::api::models::UpdateStreamerGroupRequest,
+            crate::api::models::StreamerGroupMembersRequest,
+            crate::api::models::StreamerGroupBatchRequest,
+            crate::api::models::StreamerGroupResponse,
             // Config schemas
             GlobalConfigResponse,
             UpdateGlobalConfigRequest,
@@ -252,9 +302,11 @@ pub struct MessageResponse {
             // Session schemas
             SessionResponse,
             SessionDanmuStatisticsResponse,
+            OutputChecksumResponse,
             DanmuRatePoint,
             DanmuTopTalker,
             DanmuWordFrequency,
+            DanmuActivityBurst,
             PaginatedResponse<SessionResponse>,
             crate::api::routes::sessions::BatchDeleteRequest,
             crate::api::routes::sessions::BatchDeleteResponse,
@@ -289,6 +341,11 @@ pub struct MessageResponse {
             CreateEngineRequest,
             UpdateEngineRequest,
             EngineTestResponse,
+            EngineHealthResponse,
+            crate::downloader::PausedDownload,
+            crate::downloader::engine::EngineProbe,
+            crate::downloader::engine::DownloadProtocol,
+            crate::downloader::engine::HwAccelCapabilities,
             crate::database::models::EngineConfigurationDbModel,
             crate::database::models::EngineType,
             // Notification schemas
@@ -320,6 +377,11 @@ pub struct MessageResponse {
             CredentialSaveScope,
             QrPollRequest,
             QrPollApiResponse,
+            OAuthAccountResponse,
+            OAuthAccountRequest,
+            OAuthAuthorizeRequest,
+            OAuthAuthorizeResponse,
+            OAuthExchangeRequest,
             // Pipeline DAG schemas
             CreatePipelineRequest,
             CreatePipelineResponse,
@@ -46,16 +46,18 @@ impl From<&DownloadInfo> for DownloadMetrics {
             status: info.status.as_str().to_string(),
             bytes_downloaded: info.progress.bytes_downloaded,
             duration_secs: info.progress.duration_secs,
-            speed_bytes_per_sec: safe_speed(
-                info.progress.bytes_downloaded,
-                info.progress.duration_secs,
-            ),
+            speed_bytes_per_sec: match info.progress.speed_bytes_per_sec {
+                0 => safe_speed(info.progress.bytes_downloaded, info.progress.duration_secs),
+                speed => speed,
+            },
             segments_completed: info.progress.segments_completed,
             media_duration_secs: info.progress.media_duration_secs,
             playback_ratio: safe_playback_ratio(
                 info.progress.media_duration_secs,
                 info.progress.duration_secs,
             ),
+            segment_latency_ms: info.progress.segment_latency_ms.unwrap_or_default(),
+            stalled: info.progress.stalled,
         }
     }
 }
@@ -140,6 +142,8 @@ mod tests {
                 current_segment: Some("segment_005.ts".to_string()),
                 media_duration_secs: 65.0,
                 playback_ratio: 1.083,
+                segment_latency_ms: Some(850),
+                stalled: false,
             },
             started_at: Utc::now(),
         }
@@ -161,6 +165,9 @@ mod tests {
         assert_eq!(metrics.status, "downloading");
         assert_eq!(metrics.bytes_downloaded, 1024000);
         assert_eq!(metrics.segments_completed, 5);
+        assert_eq!(metrics.speed_bytes_per_sec, 17066);
+        assert_eq!(metrics.segment_latency_ms, 850);
+        assert!(!metrics.stalled);
     }
 
     #[test]
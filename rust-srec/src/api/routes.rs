@@ -9,6 +9,7 @@ pub mod downloads;
 pub mod engines;
 pub mod export_import;
 pub mod filters;
+pub mod groups;
 pub mod health;
 pub mod job;
 pub mod logging;
@@ -16,6 +17,7 @@ pub mod media;
 pub mod notifications;
 pub mod parse;
 pub mod pipeline;
+pub mod scheduler;
 pub mod sessions;
 pub mod stream_proxy;
 pub mod streamers;
@@ -44,17 +46,20 @@ pub fn create_router(state: AppState) -> Router {
     let protected_routes: Router<AppState> = Router::new()
         .nest("/api/streamers", streamers::router())
         .nest("/api/streamers/{streamer_id}/filters", filters::router())
+        .nest("/api/groups", groups::router())
         .nest("/api/config", config::router())
         .nest("/api/config/backup", export_import::router())
         .nest("/api/credentials", credentials::router())
         .nest("/api/templates", templates::router())
         .nest("/api/engines", engines::router())
+        .nest("/api/downloads", downloads::protected_router())
         .nest("/api/job", job::router())
         .nest("/api/pipeline", pipeline::router())
         .nest("/api/tools/tdl", tdl::router())
         .nest("/api/sessions", sessions::router())
         .nest("/api/notifications", notifications::router())
         .nest("/api/parse", parse::router())
+        .nest("/api/scheduler", scheduler::router())
         .nest("/api/auth", auth::protected_router());
 
     // Apply JWT auth layer to protected routes if authentication is enabled.
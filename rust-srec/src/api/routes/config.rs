@@ -11,6 +11,7 @@ use crate::api::error::{ApiError, ApiResult};
 use crate::api::models::{GlobalConfigResponse, PlatformConfigResponse, UpdateGlobalConfigRequest};
 use crate::api::server::AppState;
 use crate::database::models::{GlobalConfigDbModel, PlatformConfigDbModel, RetentionDays};
+use crate::downloader::LowDiskSpaceAction;
 
 #[derive(Clone)]
 pub struct ConfigRouteState {
@@ -161,6 +162,18 @@ fn map_global_config_to_response(config: GlobalConfigDbModel) -> ApiResult<Globa
         queue_freshness_threshold_ms: config.queue_freshness_threshold_ms.max(0) as u64,
         gpu_health_probe_interval_secs: config.gpu_health_probe_interval_secs.max(0) as u64,
         stream_proxy_allow_private_targets: config.stream_proxy_allow_private_targets,
+        low_disk_space_threshold_bytes: config.low_disk_space_threshold_bytes.max(0) as u64,
+        low_disk_space_action: config.low_disk_space_action,
+        low_disk_space_secondary_folder: config.low_disk_space_secondary_folder,
+        adaptive_polling: config.adaptive_polling,
+        offline_grace_secs: config.offline_grace_secs.max(0) as u64,
+        load_admission_max_cpu_percent: config.load_admission_max_cpu_percent.clamp(0, 100) as u8,
+        load_admission_max_memory_percent: config.load_admission_max_memory_percent.clamp(0, 100)
+            as u8,
+        load_admission_max_io_pressure_percent: config
+            .load_admission_max_io_pressure_percent
+            .clamp(0, 100) as u8,
+        danmu_connection_config: config.danmu_connection_config,
     })
 }
 
@@ -190,9 +203,37 @@ fn map_platform_config_to_response(config: PlatformConfigDbModel) -> PlatformCon
         paired_segment_pipeline: config.paired_segment_pipeline,
         offline_check_count: config.offline_check_count.map(|v| v as u32),
         offline_check_delay_ms: config.offline_check_delay_ms.map(|v| v as u64),
+        rate_limit: config.rate_limit,
     }
 }
 
+fn validate_optional_low_disk_space_action(value: Option<&serde_json::Value>) -> ApiResult<()> {
+    let Some(value) = value else {
+        return Ok(());
+    };
+    value
+        .as_str()
+        .and_then(|action| action.parse::<LowDiskSpaceAction>().ok())
+        .map(|_| ())
+        .ok_or_else(|| {
+            ApiError::bad_request(
+                "low_disk_space_action must be one of stop_new, finalize, switch_folder",
+            )
+        })
+}
+
+fn validate_optional_danmu_connection_config(value: Option<&serde_json::Value>) -> ApiResult<()> {
+    let Some(json) = value.and_then(|v| v.as_str()).map(str::trim) else {
+        return Ok(());
+    };
+    if json.is_empty() {
+        return Ok(());
+    }
+    serde_json::from_str::<crate::danmu::service::DanmuConnectionConfig>(json)
+        .map(|_| ())
+        .map_err(|e| ApiError::bad_request(format!("Invalid danmu_connection_config: {e}")))
+}
+
 /// Validate the optional offline-check overrides on a request payload.
 /// Mirrors the server-side floors enforced in
 /// [`crate::session::HysteresisConfig::from_scheduler`].
@@ -259,6 +300,8 @@ pub async fn update_global_config(
         "notification_event_log_retention_days",
         request.notification_event_log_retention_days.as_ref(),
     )?;
+    validate_optional_low_disk_space_action(request.low_disk_space_action.as_ref())?;
+    validate_optional_danmu_connection_config(request.danmu_connection_config.as_ref())?;
 
     let config_service = &state.config_service;
 
@@ -315,6 +358,30 @@ pub async fn update_global_config(
         // would waste CPU. The UI hint discourages going below 30 s.
         gpu_health_probe_interval_secs: |v: serde_json::Value| v.as_i64().map(|n| n.max(1)),
         stream_proxy_allow_private_targets: |v: serde_json::Value| v.as_bool(),
+        low_disk_space_threshold_bytes: |v: serde_json::Value| v.as_i64().map(|n| n.max(0)),
+        low_disk_space_action: |v: serde_json::Value| v.as_str().map(String::from),
+        // Blank clears the folder, which turns `switch_folder` into `finalize`.
+        low_disk_space_secondary_folder: |v: serde_json::Value| v
+            .as_str()
+            .map(str::trim)
+            .filter(|folder| !folder.is_empty())
+            .map(String::from),
+        adaptive_polling: |v: serde_json::Value| v.as_bool(),
+        offline_grace_secs: |v: serde_json::Value| v
+            .as_i64()
+            .map(|n| n.clamp(0, crate::session::MAX_OFFLINE_GRACE.as_secs() as i64)),
+        load_admission_max_cpu_percent: |v: serde_json::Value| v.as_i64().map(|n| n.clamp(0, 100)),
+        load_admission_max_memory_percent: |v: serde_json::Value| v
+            .as_i64()
+            .map(|n| n.clamp(0, 100)),
+        load_admission_max_io_pressure_percent: |v: serde_json::Value| v
+            .as_i64()
+            .map(|n| n.clamp(0, 100)),
+        danmu_connection_config: |v: serde_json::Value| v
+            .as_str()
+            .map(str::trim)
+            .filter(|json| !json.is_empty())
+            .map(String::from),
     ]);
 
     debug!(
@@ -436,6 +503,13 @@ pub async fn replace_platform_config(
         request.offline_check_count.map(|v| v as i64),
         request.offline_check_delay_ms.map(|v| v as i64),
     )?;
+    if let Some(rps) = request.rate_limit
+        && (!rps.is_finite() || rps <= 0.0)
+    {
+        return Err(ApiError::bad_request(
+            "rate_limit must be a positive number",
+        ));
+    }
 
     let config_service = &state.config_service;
 
@@ -464,6 +538,7 @@ pub async fn replace_platform_config(
         paired_segment_pipeline: request.paired_segment_pipeline,
         offline_check_count: request.offline_check_count.map(|v| v as i32),
         offline_check_delay_ms: request.offline_check_delay_ms.map(|v| v as i64),
+        rate_limit: request.rate_limit,
     };
 
     // Replace config
@@ -546,6 +621,15 @@ mod tests {
             queue_freshness_threshold_ms: 60_000,
             gpu_health_probe_interval_secs: 30,
             stream_proxy_allow_private_targets: false,
+            low_disk_space_threshold_bytes: 0,
+            low_disk_space_action: "stop_new".to_string(),
+            low_disk_space_secondary_folder: None,
+            adaptive_polling: false,
+            offline_grace_secs: 0,
+            load_admission_max_cpu_percent: 0,
+            load_admission_max_memory_percent: 0,
+            load_admission_max_io_pressure_percent: 0,
+            danmu_connection_config: None,
         };
 
         let json = serde_json::to_string(&response).unwrap();
@@ -3,7 +3,7 @@
 use axum::{
     Json, Router,
     extract::{FromRef, Path, Query, State},
-    routing::{get, post},
+    routing::{get, post, put},
 };
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
@@ -11,7 +11,8 @@ use utoipa::ToSchema;
 use crate::api::error::{ApiError, ApiResult};
 use crate::api::server::AppState;
 use crate::credentials::platforms::bilibili::{BilibiliCredentialManager, QrPollStatus};
-use crate::credentials::{CredentialScope, CredentialSource};
+use crate::credentials::{CredentialScope, CredentialSource, oauth};
+use crate::database::models::OAuthTokenDbModel;
 
 #[derive(Clone)]
 pub struct CredentialRouteState {
@@ -27,6 +28,7 @@ pub struct CredentialRouteState {
         >,
     >,
     streamer_repository: std::sync::Arc<dyn crate::database::repositories::StreamerRepository>,
+    oauth_token_repository: std::sync::Arc<dyn crate::database::repositories::OAuthTokenRepository>,
 }
 
 impl FromRef<AppState> for CredentialRouteState {
@@ -35,6 +37,7 @@ impl FromRef<AppState> for CredentialRouteState {
             config_service: state.config_service.clone(),
             credential_service: state.credential_service.clone(),
             streamer_repository: state.streamer_repository.clone(),
+            oauth_token_repository: state.oauth_token_repository.clone(),
         }
     }
 }
@@ -117,6 +120,70 @@ pub struct TemplateCredentialQuery {
     pub platform: Option<String>,
 }
 
+/// A stored OAuth account. Secrets and tokens are never returned.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct OAuthAccountResponse {
+    pub name: String,
+    pub provider: String,
+    pub client_id: String,
+    pub scope: Option<String>,
+    /// Whether a cached access token is still valid.
+    pub access_token_valid: bool,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+impl OAuthAccountResponse {
+    fn from_model(token: &OAuthTokenDbModel) -> Self {
+        let now = crate::database::time::now_ms();
+        Self {
+            name: token.name.clone(),
+            provider: token.provider.clone(),
+            client_id: token.client_id.clone(),
+            scope: token.scope.clone(),
+            access_token_valid: token.access_token.is_some()
+                && token.access_token_expires_at.is_some_and(|at| at > now),
+            created_at: token.created_at,
+            updated_at: token.updated_at,
+        }
+    }
+}
+
+/// Import an existing refresh token under an account name.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct OAuthAccountRequest {
+    pub client_id: String,
+    pub client_secret: String,
+    pub refresh_token: String,
+}
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct OAuthAuthorizeRequest {
+    pub client_id: String,
+    /// Redirect URI registered for the OAuth client.
+    pub redirect_uri: String,
+    /// Opaque value echoed back to the redirect URI.
+    #[serde(default)]
+    pub state: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct OAuthAuthorizeResponse {
+    /// Consent page to open in a browser.
+    pub url: String,
+}
+
+/// Exchange the authorization code from the consent redirect and store the
+/// grant under `name`.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct OAuthExchangeRequest {
+    pub name: String,
+    pub client_id: String,
+    pub client_secret: String,
+    pub redirect_uri: String,
+    pub code: String,
+}
+
 pub fn router() -> Router<AppState> {
     Router::new()
         .route(
@@ -145,6 +212,13 @@ pub fn router() -> Router<AppState> {
         )
         .route("/bilibili/qr/generate", post(bilibili_qr_generate))
         .route("/bilibili/qr/poll", post(bilibili_qr_poll))
+        .route("/oauth/accounts", get(list_oauth_accounts))
+        .route(
+            "/oauth/accounts/{name}",
+            put(put_oauth_account).delete(delete_oauth_account),
+        )
+        .route("/oauth/google/authorize", post(google_oauth_authorize))
+        .route("/oauth/google/exchange", post(google_oauth_exchange))
 }
 
 fn extract_platform_refresh_token(platform_specific_config: Option<&str>) -> Option<String> {
@@ -856,3 +930,171 @@ pub async fn bilibili_qr_poll(
         message: message.to_string(),
     }))
 }
+
+#[utoipa::path(
+    get,
+    path = "/api/credentials/oauth/accounts",
+    tag = "credentials",
+    responses(
+        (status = 200, description = "Stored OAuth accounts", body = Vec<OAuthAccountResponse>)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn list_oauth_accounts(
+    State(state): State<CredentialRouteState>,
+) -> ApiResult<Json<Vec<OAuthAccountResponse>>> {
+    let tokens = state.oauth_token_repository.list_tokens().await?;
+    Ok(Json(
+        tokens
+            .iter()
+            .map(OAuthAccountResponse::from_model)
+            .collect(),
+    ))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/credentials/oauth/accounts/{name}",
+    tag = "credentials",
+    params(("name" = String, Path, description = "Account name")),
+    request_body = OAuthAccountRequest,
+    responses(
+        (status = 200, description = "Account stored", body = OAuthAccountResponse),
+        (status = 400, description = "Invalid request", body = crate::api::error::ApiErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn put_oauth_account(
+    State(state): State<CredentialRouteState>,
+    Path(name): Path<String>,
+    Json(request): Json<OAuthAccountRequest>,
+) -> ApiResult<Json<OAuthAccountResponse>> {
+    if [
+        &name,
+        &request.client_id,
+        &request.client_secret,
+        &request.refresh_token,
+    ]
+    .iter()
+    .any(|v| v.trim().is_empty())
+    {
+        return Err(ApiError::validation(
+            "name, client_id, client_secret and refresh_token are required",
+        ));
+    }
+
+    let token = OAuthTokenDbModel::new(
+        name.trim(),
+        oauth::GOOGLE_PROVIDER,
+        request.client_id.trim(),
+        request.client_secret.trim(),
+        request.refresh_token.trim(),
+    );
+    state.oauth_token_repository.upsert_token(&token).await?;
+    let stored = state.oauth_token_repository.get_token(&token.name).await?;
+    Ok(Json(OAuthAccountResponse::from_model(&stored)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/credentials/oauth/accounts/{name}",
+    tag = "credentials",
+    params(("name" = String, Path, description = "Account name")),
+    responses(
+        (status = 200, description = "Account deleted"),
+        (status = 404, description = "Account not found", body = crate::api::error::ApiErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn delete_oauth_account(
+    State(state): State<CredentialRouteState>,
+    Path(name): Path<String>,
+) -> ApiResult<Json<serde_json::Value>> {
+    let token = state.oauth_token_repository.get_token(&name).await?;
+    state
+        .oauth_token_repository
+        .delete_token(&token.name)
+        .await?;
+
+    Ok(Json(serde_json::json!({
+        "success": true,
+        "message": format!("OAuth account '{}' deleted successfully", token.name)
+    })))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/credentials/oauth/google/authorize",
+    tag = "credentials",
+    request_body = OAuthAuthorizeRequest,
+    responses(
+        (status = 200, description = "Consent URL", body = OAuthAuthorizeResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn google_oauth_authorize(
+    Json(request): Json<OAuthAuthorizeRequest>,
+) -> ApiResult<Json<OAuthAuthorizeResponse>> {
+    if request.client_id.trim().is_empty() || request.redirect_uri.trim().is_empty() {
+        return Err(ApiError::validation(
+            "client_id and redirect_uri are required",
+        ));
+    }
+    let state = request
+        .state
+        .unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+    Ok(Json(OAuthAuthorizeResponse {
+        url: oauth::google_authorization_url(
+            request.client_id.trim(),
+            request.redirect_uri.trim(),
+            &state,
+        ),
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/credentials/oauth/google/exchange",
+    tag = "credentials",
+    request_body = OAuthExchangeRequest,
+    responses(
+        (status = 200, description = "Account stored", body = OAuthAccountResponse),
+        (status = 400, description = "Code exchange failed", body = crate::api::error::ApiErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn google_oauth_exchange(
+    State(state): State<CredentialRouteState>,
+    Json(request): Json<OAuthExchangeRequest>,
+) -> ApiResult<Json<OAuthAccountResponse>> {
+    if request.name.trim().is_empty() || request.code.trim().is_empty() {
+        return Err(ApiError::validation("name and code are required"));
+    }
+
+    let client = reqwest::Client::new();
+    let grant = oauth::exchange_google_code(
+        &client,
+        request.client_id.trim(),
+        request.client_secret.trim(),
+        request.redirect_uri.trim(),
+        request.code.trim(),
+    )
+    .await
+    .map_err(|e| ApiError::bad_request(format!("Failed to exchange authorization code: {e}")))?;
+
+    let now = crate::database::time::now_ms();
+    let mut token = OAuthTokenDbModel::new(
+        request.name.trim(),
+        oauth::GOOGLE_PROVIDER,
+        request.client_id.trim(),
+        request.client_secret.trim(),
+        grant.refresh_token.clone().unwrap_or_default(),
+    );
+    token.access_token_expires_at = grant.expires_at(now);
+    token.access_token = Some(grant.access_token);
+    token.scope = grant.scope;
+
+    state.oauth_token_repository.upsert_token(&token).await?;
+    tracing::info!(account = %token.name, "Stored Google OAuth account");
+    Ok(Json(OAuthAccountResponse::from_model(&token)))
+}
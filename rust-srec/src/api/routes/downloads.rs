@@ -6,13 +6,13 @@
 use std::time::Duration;
 
 use axum::{
-    Router,
+    Json, Router,
     extract::{
-        FromRef, Query, State,
+        FromRef, Path, Query, State,
         ws::{Message, WebSocket, WebSocketUpgrade},
     },
     response::IntoResponse,
-    routing::get,
+    routing::{get, post},
 };
 use bytes::Bytes;
 use futures::{SinkExt, StreamExt};
@@ -29,7 +29,7 @@ const HEARTBEAT_INTERVAL_SECS: u64 = 30;
 /// This is intentionally "best effort": if the client is already gone we just exit.
 const SNAPSHOT_ON_SUBSCRIBE: bool = true;
 
-use crate::api::error::ApiError;
+use crate::api::error::{ApiError, ApiResult};
 use crate::api::proto::{
     ClientMessage, DownloadCancelled, DownloadCompleted, DownloadFailed, DownloadRejected,
     EventType, SegmentCompleted, StreamerCheckRecorded, WsMessage, create_snapshot_message,
@@ -37,7 +37,9 @@ use crate::api::proto::{
 };
 use crate::api::server::AppState;
 use crate::domain::streamer::{CheckOutcome, CheckRecord};
-use crate::downloader::{DownloadManagerEvent, DownloadProgressEvent, DownloadTerminalEvent};
+use crate::downloader::{
+    DownloadManagerEvent, DownloadProgressEvent, DownloadTerminalEvent, PausedDownload,
+};
 
 #[derive(Clone)]
 pub struct DownloadRouteState {
@@ -68,6 +70,82 @@ pub fn router() -> Router<AppState> {
     Router::new().route("/ws", get(download_progress_ws))
 }
 
+/// Create the download control router (JWT middleware applies).
+pub fn protected_router() -> Router<AppState> {
+    Router::new()
+        .route("/paused", get(list_paused_downloads))
+        .route("/{id}/pause", post(pause_download))
+        .route("/paused/{streamer_id}/resume", post(resume_download))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/downloads/paused",
+    tag = "downloads",
+    responses(
+        (status = 200, description = "Paused downloads", body = Vec<PausedDownload>)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn list_paused_downloads(
+    State(state): State<DownloadRouteState>,
+) -> Json<Vec<PausedDownload>> {
+    Json(state.download_manager.paused_downloads())
+}
+
+/// Pause an active download.
+///
+/// Ingestion stops but the recording session stays open until the download
+/// is resumed or the streamer goes offline.
+#[utoipa::path(
+    post,
+    path = "/api/downloads/{id}/pause",
+    tag = "downloads",
+    params(("id" = String, Path, description = "Download ID")),
+    responses(
+        (status = 200, description = "Download paused", body = PausedDownload),
+        (status = 404, description = "Download not found", body = crate::api::error::ApiErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn pause_download(
+    State(state): State<DownloadRouteState>,
+    Path(id): Path<String>,
+) -> ApiResult<Json<PausedDownload>> {
+    let paused = state
+        .download_manager
+        .pause_download(&id)
+        .await
+        .map_err(ApiError::from)?;
+    Ok(Json(paused))
+}
+
+/// Resume a streamer's paused download.
+///
+/// The streamer is re-checked and, if still live, recording continues into
+/// a new file of the same session.
+#[utoipa::path(
+    post,
+    path = "/api/downloads/paused/{streamer_id}/resume",
+    tag = "downloads",
+    params(("streamer_id" = String, Path, description = "Streamer ID")),
+    responses(
+        (status = 200, description = "Download resumed", body = PausedDownload),
+        (status = 404, description = "No paused download for the streamer", body = crate::api::error::ApiErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn resume_download(
+    State(state): State<DownloadRouteState>,
+    Path(streamer_id): Path<String>,
+) -> ApiResult<Json<PausedDownload>> {
+    let resumed = state
+        .download_manager
+        .resume_download(&streamer_id)
+        .map_err(ApiError::from)?;
+    Ok(Json(resumed))
+}
+
 /// WebSocket handler for download status streaming.
 ///
 /// Authenticates via JWT token in query parameter, then upgrades to WebSocket.
@@ -390,6 +468,8 @@ fn map_event_to_protobuf(
                 segments_completed: progress.segments_completed,
                 media_duration_secs: progress.media_duration_secs,
                 playback_ratio: progress.playback_ratio,
+                segment_latency_ms: progress.segment_latency_ms.unwrap_or_default(),
+                stalled: progress.stalled,
             };
             Some(WsMessage {
                 event_type: EventType::DownloadMetrics as i32,
@@ -679,6 +759,7 @@ mod tests {
             size_bytes: 1024000,
             split_reason_code: None,
             split_reason_details_json: None,
+            analysis_report_json: None,
         });
 
         let msg = map_event_to_protobuf(&event, &None).unwrap();
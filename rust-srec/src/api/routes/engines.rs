@@ -12,9 +12,12 @@ use crate::api::error::{ApiError, ApiResult};
 use crate::api::server::AppState;
 use crate::database::models::{
     EngineConfigurationDbModel, EngineType, FfmpegEngineConfig, MesioEngineConfig,
-    StreamlinkEngineConfig,
+    StreamlinkEngineConfig, YtDlpEngineConfig,
+};
+use crate::downloader::engine::{
+    DownloadEngine, EngineProbe, FfmpegEngine, HwAccelCapabilities, MesioEngine, StreamlinkEngine,
+    YtDlpEngine,
 };
-use crate::downloader::engine::{DownloadEngine, FfmpegEngine, MesioEngine, StreamlinkEngine};
 
 #[derive(Clone)]
 pub struct EngineRouteState {
@@ -38,6 +41,7 @@ impl FromRef<AppState> for EngineRouteState {
 pub fn router() -> Router<AppState> {
     Router::new()
         .route("/", get(list_engines).post(create_engine))
+        .route("/health", get(engines_health))
         .route(
             "/{id}",
             get(get_engine).patch(update_engine).delete(delete_engine),
@@ -64,8 +68,22 @@ pub struct UpdateEngineRequest {
 /// Response model for testing an engine.
 #[derive(Debug, serde::Serialize, utoipa::ToSchema)]
 pub struct EngineTestResponse {
-    pub available: bool,
-    pub version: Option<String>,
+    #[serde(flatten)]
+    pub probe: EngineProbe,
+    /// Hardware acceleration supported by the binary (FFmpeg engines only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hwaccel: Option<HwAccelCapabilities>,
+}
+
+/// Health of a configured engine.
+#[derive(Debug, serde::Serialize, utoipa::ToSchema)]
+pub struct EngineHealthResponse {
+    /// Engine config ID.
+    pub id: String,
+    pub name: String,
+    pub engine_type: String,
+    #[serde(flatten)]
+    pub probe: EngineProbe,
 }
 
 #[utoipa::path(
@@ -255,15 +273,65 @@ pub async fn test_engine(
         }
     })?;
 
+    let (engine, hwaccel) = build_engine(&config)?;
+
+    Ok(Json(EngineTestResponse {
+        probe: engine.probe().await,
+        hwaccel,
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/engines/health",
+    tag = "engines",
+    responses(
+        (status = 200, description = "Health of every configured engine", body = Vec<EngineHealthResponse>)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn engines_health(
+    State(state): State<EngineRouteState>,
+) -> ApiResult<Json<Vec<EngineHealthResponse>>> {
+    let configs = state
+        .config_service
+        .list_engine_configs()
+        .await
+        .map_err(|e| ApiError::internal(format!("Failed to list engines: {}", e)))?;
+
+    let probes = configs.into_iter().map(|config| async move {
+        let probe = match build_engine(&config) {
+            Ok((engine, _)) => engine.probe().await,
+            Err(e) => EngineProbe::from_check(Err(e.message), Vec::new()),
+        };
+        EngineHealthResponse {
+            id: config.id,
+            name: config.name,
+            engine_type: config.engine_type,
+            probe,
+        }
+    });
+
+    Ok(Json(futures::future::join_all(probes).await))
+}
+
+/// Build the engine described by a stored configuration, along with the
+/// hardware acceleration of FFmpeg engines.
+fn build_engine(
+    config: &EngineConfigurationDbModel,
+) -> ApiResult<(Box<dyn DownloadEngine>, Option<HwAccelCapabilities>)> {
     let engine_type = EngineType::parse(&config.engine_type).ok_or_else(|| {
         ApiError::internal(format!("Invalid engine type: {}", config.engine_type))
     })?;
 
+    let mut hwaccel = None;
     let engine: Box<dyn DownloadEngine> = match engine_type {
         EngineType::Ffmpeg => {
             let engine_config: FfmpegEngineConfig = serde_json::from_str(&config.config)
                 .map_err(|e| ApiError::internal(format!("Invalid ffmpeg config: {}", e)))?;
-            Box::new(FfmpegEngine::with_config(engine_config))
+            let engine = FfmpegEngine::with_config(engine_config);
+            hwaccel = Some(engine.hwaccel_capabilities().clone());
+            Box::new(engine)
         }
         EngineType::Streamlink => {
             let engine_config: StreamlinkEngineConfig = serde_json::from_str(&config.config)
@@ -275,10 +343,12 @@ pub async fn test_engine(
                 .map_err(|e| ApiError::internal(format!("Invalid mesio config: {}", e)))?;
             Box::new(MesioEngine::with_config(engine_config))
         }
+        EngineType::YtDlp => {
+            let engine_config: YtDlpEngineConfig = serde_json::from_str(&config.config)
+                .map_err(|e| ApiError::internal(format!("Invalid yt-dlp config: {}", e)))?;
+            Box::new(YtDlpEngine::with_config(engine_config))
+        }
     };
 
-    Ok(Json(EngineTestResponse {
-        available: engine.is_available(),
-        version: engine.version(),
-    }))
+    Ok((engine, hwaccel))
 }
@@ -261,6 +261,16 @@ pub async fn export_config(State(state): State<AppState>) -> Result<impl IntoRes
             queue_freshness_threshold_ms: global_config.queue_freshness_threshold_ms,
             gpu_health_probe_interval_secs: global_config.gpu_health_probe_interval_secs,
             stream_proxy_allow_private_targets: global_config.stream_proxy_allow_private_targets,
+            low_disk_space_threshold_bytes: global_config.low_disk_space_threshold_bytes,
+            low_disk_space_action: global_config.low_disk_space_action,
+            low_disk_space_secondary_folder: global_config.low_disk_space_secondary_folder,
+            adaptive_polling: global_config.adaptive_polling,
+            offline_grace_secs: global_config.offline_grace_secs,
+            load_admission_max_cpu_percent: global_config.load_admission_max_cpu_percent,
+            load_admission_max_memory_percent: global_config.load_admission_max_memory_percent,
+            load_admission_max_io_pressure_percent: global_config
+                .load_admission_max_io_pressure_percent,
+            danmu_connection_config: global_config.danmu_connection_config.map(parse_db_config),
         },
         templates: templates
             .iter()
@@ -323,6 +333,7 @@ pub async fn export_config(State(state): State<AppState>) -> Result<impl IntoRes
                 paired_segment_pipeline: p.paired_segment_pipeline.clone().map(parse_db_config),
                 offline_check_count: p.offline_check_count,
                 offline_check_delay_ms: p.offline_check_delay_ms,
+                rate_limit: p.rate_limit,
             })
             .collect(),
         notification_channels: channel_exports,
@@ -575,6 +586,15 @@ mod tests {
             queue_freshness_threshold_ms: 60_000,
             gpu_health_probe_interval_secs: 30,
             stream_proxy_allow_private_targets: false,
+            low_disk_space_threshold_bytes: 0,
+            low_disk_space_action: "stop_new".to_string(),
+            low_disk_space_secondary_folder: None,
+            adaptive_polling: false,
+            offline_grace_secs: 0,
+            load_admission_max_cpu_percent: 0,
+            load_admission_max_memory_percent: 0,
+            load_admission_max_io_pressure_percent: 0,
+            danmu_connection_config: None,
         };
         let json = serde_json::to_string(&export).unwrap();
         assert!(json.contains("rust_srec=debug"));
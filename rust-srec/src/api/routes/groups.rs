@@ -0,0 +1,416 @@
+//! Streamer group routes.
+
+use std::sync::Arc;
+
+use axum::{
+    Json, Router,
+    extract::{FromRef, Path, State},
+    routing::{delete, get, post, put},
+};
+
+use crate::api::error::{ApiError, ApiResult};
+use crate::api::models::{
+    BatchStreamerResponse, CreateStreamerGroupRequest, StreamerGroupBatchRequest,
+    StreamerGroupMembersRequest, StreamerGroupResponse, UpdateStreamerGroupRequest,
+};
+use crate::api::routes::streamers::{StreamerRouteState, run_batch, validate_batch_ids};
+use crate::api::server::AppState;
+use crate::config::ConfigService;
+use crate::database::models::StreamerGroupDbModel;
+use crate::database::repositories::{
+    SqlxConfigRepository, SqlxStreamerRepository, StreamerGroupRepository,
+};
+use crate::database::time::{ms_to_datetime, now_ms};
+use crate::streamer::StreamerManager;
+
+#[derive(Clone)]
+pub struct GroupRouteState {
+    config_service: Arc<ConfigService<SqlxConfigRepository, SqlxStreamerRepository>>,
+    streamer_manager: Arc<StreamerManager<SqlxStreamerRepository>>,
+    group_repository: Arc<dyn StreamerGroupRepository>,
+    streamers: StreamerRouteState,
+}
+
+impl FromRef<AppState> for GroupRouteState {
+    fn from_ref(state: &AppState) -> Self {
+        Self {
+            config_service: state.config_service.clone(),
+            streamer_manager: state.streamer_manager.clone(),
+            group_repository: state.streamer_group_repository.clone(),
+            streamers: StreamerRouteState::from_ref(state),
+        }
+    }
+}
+
+/// Create the streamer groups router.
+///
+/// # Routes
+///
+/// - `POST /` - Create a group
+/// - `GET /` - List groups
+/// - `GET /{id}` - Get a group
+/// - `PUT /{id}` - Update a group
+/// - `DELETE /{id}` - Delete a group, detaching its members
+/// - `POST /{id}/members` - Add streamers to a group
+/// - `DELETE /{id}/members/{streamer_id}` - Remove a streamer from a group
+/// - `POST /{id}/batch` - Apply one mutation to every member
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/", post(create_group))
+        .route("/", get(list_groups))
+        .route("/{id}", get(get_group))
+        .route("/{id}", put(update_group))
+        .route("/{id}", delete(delete_group))
+        .route("/{id}/members", post(add_members))
+        .route("/{id}/members/{streamer_id}", delete(remove_member))
+        .route("/{id}/batch", post(batch_group))
+}
+
+fn validate_group_name(name: &str) -> ApiResult<String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(ApiError::validation("Group name cannot be empty"));
+    }
+    Ok(name.to_string())
+}
+
+fn group_to_response(
+    state: &GroupRouteState,
+    group: StreamerGroupDbModel,
+) -> StreamerGroupResponse {
+    let mut streamer_ids: Vec<String> = state
+        .streamer_manager
+        .get_by_group(&group.id)
+        .into_iter()
+        .map(|m| m.id)
+        .collect();
+    streamer_ids.sort();
+
+    StreamerGroupResponse {
+        id: group.id,
+        name: group.name,
+        description: group.description,
+        template_id: group.template_config_id,
+        streamer_ids,
+        created_at: ms_to_datetime(group.created_at),
+        updated_at: ms_to_datetime(group.updated_at),
+    }
+}
+
+async fn ensure_name_available(
+    state: &GroupRouteState,
+    name: &str,
+    current_id: Option<&str>,
+) -> ApiResult<()> {
+    if let Ok(existing) = state.group_repository.get_group_by_name(name).await
+        && Some(existing.id.as_str()) != current_id
+    {
+        return Err(ApiError::conflict(format!(
+            "A group with name '{}' already exists",
+            name
+        )));
+    }
+    Ok(())
+}
+
+async fn ensure_template_exists(state: &GroupRouteState, template_id: &str) -> ApiResult<()> {
+    state
+        .config_service
+        .get_template_config(template_id)
+        .await
+        .map(|_| ())
+        .map_err(ApiError::from)
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/groups",
+    tag = "groups",
+    request_body = CreateStreamerGroupRequest,
+    responses(
+        (status = 201, description = "Group created", body = StreamerGroupResponse),
+        (status = 404, description = "Template or streamer not found", body = crate::api::error::ApiErrorResponse),
+        (status = 409, description = "Group name already exists", body = crate::api::error::ApiErrorResponse),
+        (status = 422, description = "Validation error", body = crate::api::error::ApiErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn create_group(
+    State(state): State<GroupRouteState>,
+    Json(request): Json<CreateStreamerGroupRequest>,
+) -> ApiResult<Json<StreamerGroupResponse>> {
+    let name = validate_group_name(&request.name)?;
+    if !request.streamer_ids.is_empty() {
+        validate_batch_ids(&request.streamer_ids)?;
+    }
+    ensure_name_available(&state, &name, None).await?;
+    if let Some(template_id) = &request.template_id {
+        ensure_template_exists(&state, template_id).await?;
+    }
+
+    let mut group = StreamerGroupDbModel::new(name);
+    group.description = request.description;
+    group.template_config_id = request.template_id;
+    state.group_repository.create_group(&group).await?;
+
+    if !request.streamer_ids.is_empty() {
+        state
+            .streamer_manager
+            .add_to_group(
+                &group.id,
+                group.template_config_id.as_deref(),
+                &request.streamer_ids,
+            )
+            .await?;
+    }
+
+    Ok(Json(group_to_response(&state, group)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/groups",
+    tag = "groups",
+    responses(
+        (status = 200, description = "Streamer groups", body = Vec<StreamerGroupResponse>)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn list_groups(
+    State(state): State<GroupRouteState>,
+) -> ApiResult<Json<Vec<StreamerGroupResponse>>> {
+    let groups = state.group_repository.list_groups().await?;
+    Ok(Json(
+        groups
+            .into_iter()
+            .map(|group| group_to_response(&state, group))
+            .collect(),
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/groups/{id}",
+    tag = "groups",
+    params(("id" = String, Path, description = "Group ID")),
+    responses(
+        (status = 200, description = "Group details", body = StreamerGroupResponse),
+        (status = 404, description = "Group not found", body = crate::api::error::ApiErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn get_group(
+    State(state): State<GroupRouteState>,
+    Path(id): Path<String>,
+) -> ApiResult<Json<StreamerGroupResponse>> {
+    let group = state.group_repository.get_group(&id).await?;
+    Ok(Json(group_to_response(&state, group)))
+}
+
+/// Update a group. Changing the template propagates it to every member.
+#[utoipa::path(
+    put,
+    path = "/api/groups/{id}",
+    tag = "groups",
+    params(("id" = String, Path, description = "Group ID")),
+    request_body = UpdateStreamerGroupRequest,
+    responses(
+        (status = 200, description = "Group updated", body = StreamerGroupResponse),
+        (status = 404, description = "Group or template not found", body = crate::api::error::ApiErrorResponse),
+        (status = 409, description = "Group name already exists", body = crate::api::error::ApiErrorResponse),
+        (status = 422, description = "Validation error", body = crate::api::error::ApiErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn update_group(
+    State(state): State<GroupRouteState>,
+    Path(id): Path<String>,
+    Json(request): Json<UpdateStreamerGroupRequest>,
+) -> ApiResult<Json<StreamerGroupResponse>> {
+    let mut group = state.group_repository.get_group(&id).await?;
+    let previous_template_id = group.template_config_id.clone();
+
+    if let Some(name) = &request.name {
+        let name = validate_group_name(name)?;
+        ensure_name_available(&state, &name, Some(&group.id)).await?;
+        group.name = name;
+    }
+    if let Some(description) = request.description {
+        group.description = description;
+    }
+    if let Some(template_id) = request.template_id {
+        if let Some(template_id) = &template_id {
+            ensure_template_exists(&state, template_id).await?;
+        }
+        group.template_config_id = template_id;
+    }
+
+    group.updated_at = now_ms();
+    state.group_repository.update_group(&group).await?;
+
+    if group.template_config_id != previous_template_id {
+        state
+            .streamer_manager
+            .apply_group_template(
+                &group.id,
+                previous_template_id.as_deref(),
+                group.template_config_id.as_deref(),
+            )
+            .await?;
+    }
+
+    Ok(Json(group_to_response(&state, group)))
+}
+
+/// Delete a group. Members are detached but keep their current template.
+#[utoipa::path(
+    delete,
+    path = "/api/groups/{id}",
+    tag = "groups",
+    params(("id" = String, Path, description = "Group ID")),
+    responses(
+        (status = 200, description = "Group deleted"),
+        (status = 404, description = "Group not found", body = crate::api::error::ApiErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn delete_group(
+    State(state): State<GroupRouteState>,
+    Path(id): Path<String>,
+) -> ApiResult<Json<serde_json::Value>> {
+    let group = state.group_repository.get_group(&id).await?;
+
+    state.streamer_manager.clear_group(&group.id).await?;
+    state.group_repository.delete_group(&group.id).await?;
+
+    Ok(Json(serde_json::json!({
+        "success": true,
+        "message": format!("Group '{}' deleted successfully", group.name)
+    })))
+}
+
+/// Add streamers to a group. Streamers adopt the group's template and are
+/// moved out of any other group.
+#[utoipa::path(
+    post,
+    path = "/api/groups/{id}/members",
+    tag = "groups",
+    params(("id" = String, Path, description = "Group ID")),
+    request_body = StreamerGroupMembersRequest,
+    responses(
+        (status = 200, description = "Members added", body = StreamerGroupResponse),
+        (status = 404, description = "Group or streamer not found", body = crate::api::error::ApiErrorResponse),
+        (status = 422, description = "Invalid streamer IDs", body = crate::api::error::ApiErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn add_members(
+    State(state): State<GroupRouteState>,
+    Path(id): Path<String>,
+    Json(request): Json<StreamerGroupMembersRequest>,
+) -> ApiResult<Json<StreamerGroupResponse>> {
+    validate_batch_ids(&request.streamer_ids)?;
+    let group = state.group_repository.get_group(&id).await?;
+
+    state
+        .streamer_manager
+        .add_to_group(
+            &group.id,
+            group.template_config_id.as_deref(),
+            &request.streamer_ids,
+        )
+        .await?;
+
+    Ok(Json(group_to_response(&state, group)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/groups/{id}/members/{streamer_id}",
+    tag = "groups",
+    params(
+        ("id" = String, Path, description = "Group ID"),
+        ("streamer_id" = String, Path, description = "Streamer ID")
+    ),
+    responses(
+        (status = 200, description = "Member removed", body = StreamerGroupResponse),
+        (status = 404, description = "Group not found or streamer not a member", body = crate::api::error::ApiErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn remove_member(
+    State(state): State<GroupRouteState>,
+    Path((id, streamer_id)): Path<(String, String)>,
+) -> ApiResult<Json<StreamerGroupResponse>> {
+    let group = state.group_repository.get_group(&id).await?;
+
+    let removed = state
+        .streamer_manager
+        .remove_from_group(&group.id, std::slice::from_ref(&streamer_id))
+        .await?;
+    if removed.is_empty() {
+        return Err(ApiError::not_found(format!(
+            "Streamer '{}' is not a member of group '{}'",
+            streamer_id, group.name
+        )));
+    }
+
+    Ok(Json(group_to_response(&state, group)))
+}
+
+/// Apply one mutation to every member of a group.
+///
+/// Members are processed like `POST /api/streamers/batch`, with failures
+/// reported per streamer.
+#[utoipa::path(
+    post,
+    path = "/api/groups/{id}/batch",
+    tag = "groups",
+    params(("id" = String, Path, description = "Group ID")),
+    request_body = StreamerGroupBatchRequest,
+    responses(
+        (status = 200, description = "Batch mutation results", body = BatchStreamerResponse),
+        (status = 404, description = "Group or template not found", body = crate::api::error::ApiErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn batch_group(
+    State(state): State<GroupRouteState>,
+    Path(id): Path<String>,
+    Json(request): Json<StreamerGroupBatchRequest>,
+) -> ApiResult<Json<BatchStreamerResponse>> {
+    let group = state.group_repository.get_group(&id).await?;
+
+    let mut ids: Vec<String> = state
+        .streamer_manager
+        .get_by_group(&group.id)
+        .into_iter()
+        .map(|m| m.id)
+        .collect();
+    ids.sort();
+
+    Ok(Json(
+        run_batch(&state.streamers, ids, &request.action).await?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_group_name() {
+        assert_eq!(validate_group_name("  weekend ").unwrap(), "weekend");
+        assert!(validate_group_name("").is_err());
+        assert!(validate_group_name("   ").is_err());
+    }
+
+    #[test]
+    fn test_update_request_distinguishes_null_from_missing() {
+        let request: UpdateStreamerGroupRequest =
+            serde_json::from_value(serde_json::json!({ "template_id": null })).unwrap();
+        assert_eq!(request.template_id, Some(None));
+        assert_eq!(request.description, None);
+        assert_eq!(request.name, None);
+    }
+}
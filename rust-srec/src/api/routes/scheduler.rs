@@ -0,0 +1,209 @@
+//! Scheduler diagnostics routes.
+
+use std::sync::Arc;
+use std::time::Instant;
+
+use axum::{
+    Json, Router,
+    extract::{FromRef, Path, State},
+    routing::get,
+};
+use chrono::{DateTime, Utc};
+
+use crate::api::error::{ApiError, ApiResult};
+use crate::api::models::{
+    ActorInfoResponse, ActorMetricsResponse, ActorRestartInfo, PlatformActorInfo, StreamerActorInfo,
+};
+use crate::api::server::AppState;
+use crate::database::repositories::SqlxStreamerRepository;
+use crate::scheduler::SchedulerHandle;
+use crate::scheduler::actor::ActorSnapshot;
+use crate::streamer::StreamerManager;
+
+#[derive(Clone)]
+pub struct SchedulerRouteState {
+    scheduler: SchedulerHandle,
+    streamer_manager: Arc<StreamerManager<SqlxStreamerRepository>>,
+}
+
+impl FromRef<AppState> for SchedulerRouteState {
+    fn from_ref(state: &AppState) -> Self {
+        Self {
+            scheduler: state.scheduler.clone(),
+            streamer_manager: state.streamer_manager.clone(),
+        }
+    }
+}
+
+/// Create the scheduler router.
+///
+/// # Routes
+///
+/// - `GET /actors` - List supervised actors
+/// - `GET /actors/{id}` - Get a single actor
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/actors", get(list_actors))
+        .route("/actors/{id}", get(get_actor))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/scheduler/actors",
+    tag = "scheduler",
+    responses(
+        (status = 200, description = "Supervised actors", body = Vec<ActorInfoResponse>),
+        (status = 503, description = "Scheduler is not running", body = crate::api::error::ApiErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn list_actors(
+    State(state): State<SchedulerRouteState>,
+) -> ApiResult<Json<Vec<ActorInfoResponse>>> {
+    let mut actors: Vec<_> = snapshots(&state, None)
+        .await?
+        .into_iter()
+        .map(|snapshot| to_response(&state, snapshot))
+        .collect();
+    actors.sort_by(|a, b| (&a.actor_type, &a.id).cmp(&(&b.actor_type, &b.id)));
+
+    Ok(Json(actors))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/scheduler/actors/{id}",
+    tag = "scheduler",
+    params(("id" = String, Path, description = "Streamer ID or platform ID")),
+    responses(
+        (status = 200, description = "Actor details", body = ActorInfoResponse),
+        (status = 404, description = "Actor not found", body = crate::api::error::ApiErrorResponse),
+        (status = 503, description = "Scheduler is not running", body = crate::api::error::ApiErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn get_actor(
+    State(state): State<SchedulerRouteState>,
+    Path(id): Path<String>,
+) -> ApiResult<Json<ActorInfoResponse>> {
+    let snapshot = snapshots(&state, Some(&id))
+        .await?
+        .into_iter()
+        .next()
+        .ok_or_else(|| ApiError::not_found(format!("Actor {} not found", id)))?;
+
+    Ok(Json(to_response(&state, snapshot)))
+}
+
+async fn snapshots(state: &SchedulerRouteState, id: Option<&str>) -> ApiResult<Vec<ActorSnapshot>> {
+    state
+        .scheduler
+        .actors(id)
+        .await
+        .ok_or_else(|| ApiError::service_unavailable("Scheduler is not running"))
+}
+
+fn to_response(state: &SchedulerRouteState, snapshot: ActorSnapshot) -> ActorInfoResponse {
+    let now = Instant::now();
+    let metadata = (snapshot.actor_type == "streamer")
+        .then(|| state.streamer_manager.get_streamer(&snapshot.id))
+        .flatten();
+
+    let streamer = snapshot.streamer_state.map(|actor_state| {
+        let last_check = actor_state.last_check.as_ref();
+        StreamerActorInfo {
+            state: actor_state.streamer_state,
+            next_check_at: actor_state.next_check.map(|at| to_utc(at, now)),
+            last_check_at: last_check.map(|check| check.checked_at),
+            last_check_state: last_check.map(|check| check.state),
+            last_check_error: last_check.and_then(|check| check.error.clone()),
+            offline_count: actor_state.hysteresis.offline_count(),
+            consecutive_error_count: metadata.as_ref().map_or(0, |m| m.consecutive_error_count),
+            disabled_until: metadata.as_ref().and_then(|m| m.disabled_until),
+            last_error: metadata.as_ref().and_then(|m| m.last_error.clone()),
+        }
+    });
+
+    let platform = snapshot
+        .platform_state
+        .map(|actor_state| PlatformActorInfo {
+            streamer_count: actor_state.streamer_count,
+            pending_count: actor_state.pending_count,
+            last_batch_at: actor_state.last_batch.map(|at| to_utc(at, now)),
+            total_batches: actor_state.total_batches,
+            success_rate: actor_state.success_rate,
+        });
+
+    let mut restarts = snapshot
+        .restarts
+        .map(|info| ActorRestartInfo {
+            total_restarts: info.total_restarts,
+            recent_failures: info.recent_failures,
+            last_restart_at: info.last_restart.map(|at| to_utc(at, now)),
+            last_crash_error: info.last_error,
+            restart_at: None,
+        })
+        .unwrap_or_default();
+    restarts.restart_at = snapshot.restart_at.map(|at| to_utc(at, now));
+
+    ActorInfoResponse {
+        name: metadata.as_ref().map(|m| m.name.clone()),
+        platform_config_id: metadata.map(|m| m.platform_config_id),
+        id: snapshot.id,
+        actor_type: snapshot.actor_type,
+        running: snapshot.running,
+        responsive: snapshot.responsive,
+        high_priority: snapshot.high_priority,
+        uptime_secs: snapshot.uptime.map(|uptime| uptime.as_secs()),
+        mailbox_len: snapshot.mailbox_len,
+        mailbox_capacity: snapshot.mailbox_capacity,
+        metrics: snapshot.metrics.map(|metrics| ActorMetricsResponse {
+            messages_processed: metrics.messages_processed,
+            errors: metrics.errors,
+            average_latency_us: metrics.average_latency_us,
+            max_latency_us: metrics.max_latency_us,
+        }),
+        streamer,
+        platform,
+        restarts,
+    }
+}
+
+/// Map a monotonic instant onto the wall clock.
+fn to_utc(at: Instant, now: Instant) -> DateTime<Utc> {
+    let wall_now = Utc::now();
+    let offset = if at >= now {
+        chrono::Duration::from_std(at - now)
+    } else {
+        chrono::Duration::from_std(now - at).map(|d| -d)
+    };
+    wall_now + offset.unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_to_utc_maps_past_and_future() {
+        let now = Instant::now();
+        let wall_now = Utc::now();
+
+        let future = to_utc(now + Duration::from_secs(60), now);
+        let past = to_utc(now - Duration::from_secs(60), now);
+
+        assert!(
+            (future - wall_now - chrono::Duration::seconds(60))
+                .num_seconds()
+                .abs()
+                <= 1
+        );
+        assert!(
+            (wall_now - past - chrono::Duration::seconds(60))
+                .num_seconds()
+                .abs()
+                <= 1
+        );
+    }
+}
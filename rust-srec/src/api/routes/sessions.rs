@@ -9,6 +9,7 @@
 //! |--------|------|-------------|
 //! | GET | `/api/sessions` | List sessions with filtering and pagination |
 //! | GET | `/api/sessions/:id` | Get a single session by ID |
+//! | GET | `/api/sessions/:id/checksums` | Stored file digests of a session |
 
 use axum::{
     Json, Router,
@@ -18,13 +19,14 @@ use axum::{
 
 use crate::api::error::{ApiError, ApiResult};
 use crate::api::models::{
-    DanmuRatePoint, DanmuTopTalker, DanmuWordFrequency, PageResponse, PaginatedResponse,
-    PaginationParams, SessionDanmuStatisticsResponse, SessionEventResponse, SessionFilterParams,
-    SessionResponse, SessionSegmentResponse, TitleChange,
+    DanmuActivityBurst, DanmuRatePoint, DanmuTopTalker, DanmuWordFrequency, OutputChecksumResponse,
+    PageResponse, PaginatedResponse, PaginationParams, SessionDanmuStatisticsResponse,
+    SessionEventResponse, SessionFilterParams, SessionResponse, SessionSegmentResponse,
+    TitleChange,
 };
 use crate::api::server::AppState;
 use crate::database::models::{
-    DanmuRateEntry, Pagination, SessionFilters, TitleEntry, TopTalkerEntry,
+    DanmuActivityBurstEntry, DanmuRateEntry, Pagination, SessionFilters, TitleEntry, TopTalkerEntry,
 };
 use crate::session::SessionEvent;
 
@@ -52,6 +54,7 @@ impl FromRef<AppState> for SessionRouteState {
 ///
 /// - `GET /` - List sessions with filtering and pagination
 /// - `GET /:id` - Get a single session by ID
+/// - `GET /:id/checksums` - Stored file digests of a session
 /// - `DELETE /:id` - Delete a single session by ID
 /// - `POST /batch-delete` - Delete multiple sessions by IDs
 pub fn router() -> Router<AppState> {
@@ -60,6 +63,7 @@ pub fn router() -> Router<AppState> {
         .route("/batch-delete", post(delete_sessions_batch))
         .route("/{id}/danmu-statistics", get(get_session_danmu_statistics))
         .route("/{id}/segments", get(list_session_segments))
+        .route("/{id}/checksums", get(list_session_checksums))
         .route("/{id}", get(get_session).delete(delete_session))
 }
 
@@ -118,6 +122,10 @@ pub async fn list_session_segments(
                 .split_reason_details_json
                 .as_ref()
                 .and_then(|raw| serde_json::from_str::<serde_json::Value>(raw).ok()),
+            analysis_report: s
+                .analysis_report_json
+                .as_ref()
+                .and_then(|raw| serde_json::from_str::<serde_json::Value>(raw).ok()),
             created_at: s.created_at.map(crate::database::time::ms_to_datetime),
             completed_at: s.completed_at.map(crate::database::time::ms_to_datetime),
             persisted_at: crate::database::time::ms_to_datetime(s.persisted_at),
@@ -131,6 +139,47 @@ pub async fn list_session_segments(
     )))
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/sessions/{id}/checksums",
+    tag = "sessions",
+    params(("id" = String, Path, description = "Session ID")),
+    responses(
+        (status = 200, description = "Digests computed by checksum pipeline steps, ordered by file path", body = Vec<OutputChecksumResponse>),
+        (status = 404, description = "Session not found", body = crate::api::error::ApiErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn list_session_checksums(
+    State(state): State<SessionRouteState>,
+    Path(id): Path<String>,
+) -> ApiResult<Json<Vec<OutputChecksumResponse>>> {
+    state
+        .session_repository
+        .get_session(&id)
+        .await
+        .map_err(ApiError::from)?;
+
+    let checksums = state
+        .session_repository
+        .list_output_checksums_for_session(&id)
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Json(
+        checksums
+            .into_iter()
+            .map(|c| OutputChecksumResponse {
+                file_path: c.file_path,
+                algorithm: c.algorithm,
+                digest: c.digest,
+                size_bytes: u64::try_from(c.size_bytes).unwrap_or(0),
+                computed_at: crate::database::time::ms_to_datetime(c.computed_at),
+            })
+            .collect(),
+    ))
+}
+
 /// List recording sessions with pagination and filtering.
 ///
 /// # Endpoint
@@ -279,6 +328,8 @@ pub async fn list_sessions(
             danmu_count,
             thumbnail_url: get_thumbnail_url(&session.id, session_repository.as_ref()).await,
             streamer_avatar,
+            download_engine: session.download_engine.clone(),
+            kind: session.kind.clone(),
         });
     }
 
@@ -425,6 +476,8 @@ pub async fn get_session(
         danmu_count,
         thumbnail_url,
         streamer_avatar,
+        download_engine: session.download_engine,
+        kind: session.kind,
     };
 
     Ok(Json(response))
@@ -515,12 +568,30 @@ pub async fn get_session_danmu_statistics(
         .unwrap_or_default();
     word_frequency.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.word.cmp(&b.word)));
 
+    let activity_bursts = stats
+        .activity_bursts
+        .as_deref()
+        .map(serde_json::from_str::<Vec<DanmuActivityBurstEntry>>)
+        .transpose()
+        .map_err(|e| ApiError::internal(format!("Failed to parse activity bursts: {e}")))?
+        .unwrap_or_default()
+        .into_iter()
+        .map(|burst| DanmuActivityBurst {
+            start_ts: burst.start_ts,
+            end_ts: burst.end_ts,
+            message_count: burst.message_count,
+            peak_rate: burst.peak_rate,
+            baseline_rate: burst.baseline_rate,
+        })
+        .collect();
+
     let response = SessionDanmuStatisticsResponse {
         session_id: session.id,
         total_danmus: stats.total_danmus as u64,
         danmu_rate_timeseries,
         top_talkers,
         word_frequency,
+        activity_bursts,
     };
 
     Ok(Json(response))
@@ -730,6 +801,7 @@ mod tests {
             size_bytes: 4096,
             split_reason_code: None,
             split_reason_details: None,
+            analysis_report: None,
             created_at: Some(
                 Utc.timestamp_millis_opt(1_700_000_000_000)
                     .single()
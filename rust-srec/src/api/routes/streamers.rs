@@ -10,9 +10,10 @@ use axum::{
 
 use crate::api::error::{ApiError, ApiResult};
 use crate::api::models::{
-    BatchStreamerAction, BatchStreamerItemResult, BatchStreamerRequest, BatchStreamerResponse,
-    CreateStreamerRequest, ExtractMetadataRequest, ExtractMetadataResponse, PaginatedResponse,
-    PaginationParams, PlatformConfigResponse, StreamerCheckHistoryEntry,
+    AddChapterMarkerRequest, BatchStreamerAction, BatchStreamerItemResult, BatchStreamerRequest,
+    BatchStreamerResponse, CreateStreamerRequest, ExtractMetadataRequest, ExtractMetadataResponse,
+    PaginatedResponse, PaginationParams, PlatformConfigResponse, RecordNowRequest,
+    RecordNowResponse, RecordUrlNowRequest, StreamerCheckHistoryEntry,
     StreamerCheckHistoryResponse, StreamerFilterParams, StreamerResponse, UpdatePriorityRequest,
     UpdateStreamerRequest,
 };
@@ -36,6 +37,8 @@ pub struct StreamerRouteState {
     >,
     streamer_check_history_repository:
         std::sync::Arc<dyn crate::database::repositories::StreamerCheckHistoryRepository>,
+    download_manager: std::sync::Arc<crate::downloader::DownloadManager>,
+    scheduler: crate::scheduler::SchedulerHandle,
 }
 
 impl FromRef<AppState> for StreamerRouteState {
@@ -44,6 +47,8 @@ impl FromRef<AppState> for StreamerRouteState {
             config_service: state.config_service.clone(),
             streamer_manager: state.streamer_manager.clone(),
             streamer_check_history_repository: state.streamer_check_history_repository.clone(),
+            download_manager: state.download_manager.clone(),
+            scheduler: state.scheduler.clone(),
         }
     }
 }
@@ -54,18 +59,21 @@ pub fn router() -> Router<AppState> {
         .route("/", post(create_streamer))
         .route("/", get(list_streamers))
         .route("/batch", post(batch_streamers))
+        .route("/record-now", post(record_url_now))
         .route("/{id}", get(get_streamer))
         .route("/{id}", put(update_streamer))
         .route("/{id}", delete(delete_streamer))
         .route("/{id}/clear-error", post(clear_error))
         .route("/{id}/priority", patch(update_priority))
         .route("/{id}/check-history", get(get_check_history))
+        .route("/{id}/markers", post(add_chapter_marker))
+        .route("/{id}/record-now", post(record_streamer_now))
         .route("/extract-metadata", post(extract_metadata))
 }
 
 const MAX_BATCH_SIZE: usize = 100;
 
-fn validate_batch_ids(ids: &[String]) -> ApiResult<()> {
+pub(super) fn validate_batch_ids(ids: &[String]) -> ApiResult<()> {
     if ids.is_empty() {
         return Err(ApiError::validation("At least one streamer ID is required"));
     }
@@ -102,6 +110,40 @@ fn state_for_enabled(current: Option<StreamerState>, enabled: bool) -> Option<St
     }
 }
 
+/// Build the metadata of a new streamer with a fresh ID.
+fn new_streamer_metadata(request: &CreateStreamerRequest) -> StreamerMetadata {
+    StreamerMetadata {
+        id: uuid::Uuid::new_v4().to_string(),
+        name: request.name.clone(),
+        url: request.url.clone(),
+        platform_config_id: request.platform_config_id.clone(),
+        template_config_id: request.template_id.clone(),
+        group_id: None,
+        state: if request.enabled {
+            StreamerState::NotLive
+        } else {
+            StreamerState::Disabled
+        },
+        priority: request.priority,
+        consecutive_error_count: 0,
+        disabled_until: None,
+        last_error: None,
+        avatar_url: None,
+        last_live_time: None,
+        streamer_specific_config: request.streamer_specific_config.as_ref().and_then(|v| {
+            if v.is_null() {
+                None
+            } else {
+                Some(v.to_string())
+            }
+        }),
+        effective_offline_check_count: 3,
+        effective_offline_check_delay_ms: 20_000,
+        created_at: chrono::Utc::now(),
+        updated_at: chrono::Utc::now(),
+    }
+}
+
 /// Convert StreamerMetadata to StreamerResponse.
 fn metadata_to_response(metadata: &StreamerMetadata) -> StreamerResponse {
     StreamerResponse {
@@ -110,6 +152,7 @@ fn metadata_to_response(metadata: &StreamerMetadata) -> StreamerResponse {
         url: metadata.url.clone(),
         platform_config_id: metadata.platform_config_id.clone(),
         template_id: metadata.template_config_id.clone(),
+        group_id: metadata.group_id.clone(),
         state: metadata.state,
         priority: metadata.priority,
         enabled: metadata.state != StreamerState::Disabled,
@@ -162,39 +205,8 @@ pub async fn create_streamer(
         ));
     }
 
-    // Generate a new ID for the streamer
-    let id = uuid::Uuid::new_v4().to_string();
-
     // Create metadata from request
-    let metadata = StreamerMetadata {
-        id: id.clone(),
-        name: request.name.clone(),
-        url: request.url.clone(),
-        platform_config_id: request.platform_config_id.clone(),
-        template_config_id: request.template_id.clone(),
-        state: if request.enabled {
-            StreamerState::NotLive
-        } else {
-            StreamerState::Disabled
-        },
-        priority: request.priority,
-        consecutive_error_count: 0,
-        disabled_until: None,
-        last_error: None,
-        avatar_url: None,
-        last_live_time: None,
-        streamer_specific_config: request.streamer_specific_config.as_ref().and_then(|v| {
-            if v.is_null() {
-                None
-            } else {
-                Some(v.to_string())
-            }
-        }),
-        effective_offline_check_count: 3,
-        effective_offline_check_delay_ms: 20_000,
-        created_at: chrono::Utc::now(),
-        updated_at: chrono::Utc::now(),
-    };
+    let metadata = new_streamer_metadata(&request);
 
     // Create streamer using manager
     streamer_manager
@@ -236,6 +248,9 @@ pub async fn list_streamers(
     if filters.template_unassigned == Some(true) {
         streamers.retain(|s| s.template_config_id.is_none());
     }
+    if let Some(group) = &filters.group {
+        streamers.retain(|s| s.group_id.as_ref() == Some(group));
+    }
     if let Some(state_str) = &filters.state
         && !state_str.is_empty()
     {
@@ -393,11 +408,20 @@ pub async fn batch_streamers(
 ) -> ApiResult<Json<BatchStreamerResponse>> {
     validate_batch_ids(&request.ids)?;
 
+    Ok(Json(run_batch(&state, request.ids, &request.action).await?))
+}
+
+/// Apply `action` to each streamer in `ids`, collecting per-streamer results.
+pub(super) async fn run_batch(
+    state: &StreamerRouteState,
+    ids: Vec<String>,
+    action: &BatchStreamerAction,
+) -> ApiResult<BatchStreamerResponse> {
     let streamer_manager = &state.streamer_manager;
 
     if let BatchStreamerAction::SetTemplate {
         template_id: Some(template_id),
-    } = &request.action
+    } = action
     {
         let config_service = &state.config_service;
         config_service
@@ -406,12 +430,12 @@ pub async fn batch_streamers(
             .map_err(ApiError::from)?;
     }
 
-    let requested = request.ids.len();
+    let requested = ids.len();
     let mut results = Vec::with_capacity(requested);
 
-    for id in request.ids {
+    for id in ids {
         let result: crate::Result<()> = async {
-            match &request.action {
+            match action {
                 BatchStreamerAction::SetEnabled { enabled } => {
                     let current = streamer_manager
                         .get_streamer(&id)
@@ -494,12 +518,12 @@ pub async fn batch_streamers(
     }
 
     let succeeded = results.iter().filter(|result| result.success).count();
-    Ok(Json(BatchStreamerResponse {
+    Ok(BatchStreamerResponse {
         requested,
         succeeded,
         failed: requested - succeeded,
         results,
-    }))
+    })
 }
 
 #[utoipa::path(
@@ -725,11 +749,254 @@ pub async fn update_priority(
     Ok(Json(metadata_to_response(&metadata)))
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/streamers/{id}/markers",
+    tag = "streamers",
+    params(("id" = String, Path, description = "Streamer ID")),
+    request_body = AddChapterMarkerRequest,
+    responses(
+        (status = 200, description = "Chapter marker added"),
+        (status = 404, description = "Streamer is not recording", body = crate::api::error::ApiErrorResponse),
+        (status = 422, description = "Validation error", body = crate::api::error::ApiErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn add_chapter_marker(
+    State(state): State<StreamerRouteState>,
+    Path(id): Path<String>,
+    Json(request): Json<AddChapterMarkerRequest>,
+) -> ApiResult<Json<serde_json::Value>> {
+    let title = request.title.trim();
+    if title.is_empty() {
+        return Err(ApiError::validation("Chapter title must not be empty"));
+    }
+
+    let download = state
+        .download_manager
+        .get_download_by_streamer(&id)
+        .ok_or_else(|| ApiError::not_found(format!("Streamer '{}' is not recording", id)))?;
+    state
+        .download_manager
+        .add_chapter_marker(&download.id, title)
+        .map_err(ApiError::from)?;
+
+    Ok(Json(serde_json::json!({
+        "success": true,
+        "message": format!("Chapter marker added to download '{}'", download.id)
+    })))
+}
+
+/// Resolve the requested maximum recording duration to a deadline.
+fn record_now_deadline(
+    max_duration_secs: Option<u64>,
+    now: chrono::DateTime<chrono::Utc>,
+) -> ApiResult<Option<chrono::DateTime<chrono::Utc>>> {
+    let Some(secs) = max_duration_secs else {
+        return Ok(None);
+    };
+    if secs == 0 {
+        return Err(ApiError::validation("max_duration_secs must be positive"));
+    }
+    i64::try_from(secs)
+        .ok()
+        .and_then(chrono::Duration::try_seconds)
+        .and_then(|duration| now.checked_add_signed(duration))
+        .map(Some)
+        .ok_or_else(|| ApiError::validation("max_duration_secs is out of range"))
+}
+
+/// Check a streamer right away and arm its recording deadline.
+///
+/// A paused download is resumed instead, since a plain check would leave it
+/// paused. Without a maximum duration, an earlier deadline is dropped.
+async fn record_now(
+    state: &StreamerRouteState,
+    metadata: StreamerMetadata,
+    created: bool,
+    stop_at: Option<chrono::DateTime<chrono::Utc>>,
+) -> ApiResult<RecordNowResponse> {
+    let download_manager = &state.download_manager;
+
+    let resumed = download_manager.is_paused(&metadata.id)
+        && download_manager.resume_download(&metadata.id).is_ok();
+    let check_triggered = if resumed || created {
+        // Resuming re-checks the streamer, and a new actor checks on start.
+        resumed
+    } else {
+        state
+            .scheduler
+            .check_now(&metadata.id)
+            .await
+            .ok_or_else(|| ApiError::service_unavailable("Scheduler is not running"))?
+    };
+
+    match stop_at {
+        Some(stop_at) => {
+            download_manager
+                .set_recording_deadline(&metadata.id, stop_at)
+                .await
+        }
+        None => {
+            download_manager
+                .clear_recording_deadline(&metadata.id)
+                .await
+        }
+    }
+    .map_err(ApiError::from)?;
+
+    tracing::info!(
+        streamer_id = %metadata.id,
+        created,
+        check_triggered,
+        resumed,
+        stop_at = ?stop_at,
+        "Record-now requested"
+    );
+
+    Ok(RecordNowResponse {
+        streamer: metadata_to_response(&metadata),
+        created,
+        check_triggered,
+        resumed,
+        stop_at,
+    })
+}
+
+/// Check a streamer immediately, regardless of its polling schedule, and
+/// start recording if it is live.
+///
+/// With `max_duration_secs`, the download is paused that long after the
+/// request: the current segment is finalized, and the session stays open
+/// until the stream ends so the stream is not recorded again. The download
+/// can be resumed like any paused download. The deadline survives restarts.
+#[utoipa::path(
+    post,
+    path = "/api/streamers/{id}/record-now",
+    tag = "streamers",
+    params(("id" = String, Path, description = "Streamer ID")),
+    request_body = RecordNowRequest,
+    responses(
+        (status = 200, description = "Check triggered", body = RecordNowResponse),
+        (status = 404, description = "Streamer not found", body = crate::api::error::ApiErrorResponse),
+        (status = 409, description = "Streamer is disabled", body = crate::api::error::ApiErrorResponse),
+        (status = 422, description = "Validation error", body = crate::api::error::ApiErrorResponse),
+        (status = 503, description = "Scheduler is not running", body = crate::api::error::ApiErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn record_streamer_now(
+    State(state): State<StreamerRouteState>,
+    Path(id): Path<String>,
+    Json(request): Json<RecordNowRequest>,
+) -> ApiResult<Json<RecordNowResponse>> {
+    let stop_at = record_now_deadline(request.max_duration_secs, chrono::Utc::now())?;
+    let metadata = state
+        .streamer_manager
+        .get_streamer(&id)
+        .ok_or_else(|| ApiError::not_found(format!("Streamer with id '{}' not found", id)))?;
+    if metadata.state == StreamerState::Disabled {
+        return Err(ApiError::conflict(format!(
+            "Streamer '{}' is disabled; enable it before recording",
+            metadata.name
+        )));
+    }
+
+    Ok(Json(record_now(&state, metadata, false, stop_at).await?))
+}
+
+/// Record a stream URL immediately.
+///
+/// Uses the streamer with this URL, or creates one on the platform the URL
+/// belongs to. Behaves like `POST /api/streamers/{id}/record-now` otherwise.
+#[utoipa::path(
+    post,
+    path = "/api/streamers/record-now",
+    tag = "streamers",
+    request_body = RecordUrlNowRequest,
+    responses(
+        (status = 200, description = "Check triggered", body = RecordNowResponse),
+        (status = 409, description = "Streamer is disabled", body = crate::api::error::ApiErrorResponse),
+        (status = 422, description = "Invalid or unsupported URL", body = crate::api::error::ApiErrorResponse),
+        (status = 503, description = "Scheduler is not running", body = crate::api::error::ApiErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn record_url_now(
+    State(state): State<StreamerRouteState>,
+    Json(request): Json<RecordUrlNowRequest>,
+) -> ApiResult<Json<RecordNowResponse>> {
+    use crate::domain::value_objects::StreamerUrl;
+
+    let stop_at = record_now_deadline(request.max_duration_secs, chrono::Utc::now())?;
+    let url = request.url.trim();
+
+    if let Some(metadata) = state.streamer_manager.get_streamer_by_url(url) {
+        if metadata.state == StreamerState::Disabled {
+            return Err(ApiError::conflict(format!(
+                "Streamer '{}' is disabled; enable it before recording",
+                metadata.name
+            )));
+        }
+        return Ok(Json(record_now(&state, metadata, false, stop_at).await?));
+    }
+
+    let parsed = StreamerUrl::new(url).map_err(|e| ApiError::validation(e.to_string()))?;
+    let platform = parsed.platform().ok_or_else(|| {
+        ApiError::validation("Unsupported URL; add it as a streamer to choose a platform")
+    })?;
+    let platform_config = state
+        .config_service
+        .list_platform_configs()
+        .await
+        .map_err(ApiError::from)?
+        .into_iter()
+        .find(|config| config.platform_name.eq_ignore_ascii_case(platform))
+        .ok_or_else(|| {
+            ApiError::validation(format!("No platform configuration for '{}'", platform))
+        })?;
+
+    let name = request
+        .name
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+        .or_else(|| parsed.channel_id())
+        .unwrap_or_else(|| url.to_string());
+    let metadata = new_streamer_metadata(&CreateStreamerRequest {
+        name,
+        url: url.to_string(),
+        platform_config_id: platform_config.id,
+        template_id: None,
+        priority: Default::default(),
+        enabled: true,
+        streamer_specific_config: None,
+    });
+    state
+        .streamer_manager
+        .create_streamer(metadata.clone())
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Json(record_now(&state, metadata, true, stop_at).await?))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::domain::Priority;
 
+    #[test]
+    fn test_record_now_deadline() {
+        let now = chrono::Utc::now();
+        assert_eq!(record_now_deadline(None, now).unwrap(), None);
+        assert_eq!(
+            record_now_deadline(Some(90), now).unwrap(),
+            Some(now + chrono::Duration::seconds(90))
+        );
+        assert!(record_now_deadline(Some(0), now).is_err());
+        assert!(record_now_deadline(Some(u64::MAX), now).is_err());
+    }
+
     #[test]
     fn test_create_streamer_request_validation() {
         let request = CreateStreamerRequest {
@@ -755,6 +1022,7 @@ mod tests {
             avatar_url: None,
             platform_config_id: "twitch".to_string(),
             template_config_id: Some("template1".to_string()),
+            group_id: None,
             state: StreamerState::Live,
             priority: Priority::High,
             consecutive_error_count: 2,
@@ -790,6 +1058,7 @@ mod tests {
             avatar_url: None,
             platform_config_id: "platform".to_string(),
             template_config_id: None,
+            group_id: None,
             state: StreamerState::Disabled,
             priority: Priority::Normal,
             consecutive_error_count: 0,
@@ -946,6 +1215,7 @@ pub async fn extract_metadata(
             paired_segment_pipeline: c.paired_segment_pipeline,
             offline_check_count: c.offline_check_count.map(|v| v as u32),
             offline_check_delay_ms: c.offline_check_delay_ms.map(|v| v as u64),
+            rate_limit: c.rate_limit,
         })
         .collect();
 
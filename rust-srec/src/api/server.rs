@@ -108,6 +108,10 @@ pub struct ApiServices {
     pub session_event_repository: Arc<dyn SessionEventRepository>,
     /// Per-poll check-history repository for streamer details.
     pub streamer_check_history_repository: Arc<dyn StreamerCheckHistoryRepository>,
+    /// OAuth accounts used by upload processors.
+    pub oauth_token_repository: Arc<dyn crate::database::repositories::OAuthTokenRepository>,
+    /// Streamer group repository.
+    pub streamer_group_repository: Arc<dyn crate::database::repositories::StreamerGroupRepository>,
     /// Live broadcaster for committed check-history rows.
     pub check_history_broadcaster: crate::monitor::CheckHistoryBroadcaster,
     /// Filter repository for streamer filters
@@ -133,6 +137,8 @@ pub struct ApiServices {
     /// Validated, transactional configuration import application service.
     pub(crate) configuration_import_service:
         Arc<crate::services::config_import::ConfigurationImportService>,
+    /// Read-only scheduler handle for actor diagnostics.
+    pub(crate) scheduler: crate::scheduler::SchedulerHandle,
 }
 
 /// Shared application state.
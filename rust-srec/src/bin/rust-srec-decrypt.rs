@@ -0,0 +1,75 @@
+use std::path::PathBuf;
+
+use rust_srec::pipeline::{decrypt_artifact, load_encryption_key};
+
+fn main() -> anyhow::Result<()> {
+    let mut args = std::env::args().skip(1);
+    let mut key_file = None;
+    let mut key_env = None;
+    let mut output = None;
+    let mut inputs = Vec::new();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => {
+                print_help();
+                return Ok(());
+            }
+            "--key-file" => key_file = Some(value(&mut args, &arg)?),
+            "--key-env" => key_env = Some(value(&mut args, &arg)?),
+            "-o" | "--output" => output = Some(PathBuf::from(value(&mut args, &arg)?)),
+            _ if arg.starts_with('-') => anyhow::bail!("Unknown option {arg}; see --help"),
+            _ => inputs.push(PathBuf::from(arg)),
+        }
+    }
+    if inputs.is_empty() {
+        print_help();
+        anyhow::bail!("No input files given");
+    }
+    if output.is_some() && inputs.len() > 1 {
+        anyhow::bail!("--output can only be used with a single input");
+    }
+
+    let key = load_encryption_key(key_file.as_deref(), key_env.as_deref())?;
+    for input in inputs {
+        let dest = match &output {
+            Some(output) => output.clone(),
+            None => match input.to_str().and_then(|p| p.strip_suffix(".enc")) {
+                Some(stem) => PathBuf::from(stem),
+                None => anyhow::bail!("{} does not end in .enc; pass --output", input.display()),
+            },
+        };
+        if dest.exists() {
+            anyhow::bail!("{} already exists", dest.display());
+        }
+        let opened = decrypt_artifact(&key, &input, &dest)?;
+        println!(
+            "{} -> {} ({} bytes, sha256 {})",
+            input.display(),
+            dest.display(),
+            opened.size_bytes,
+            opened.sha256
+        );
+    }
+
+    Ok(())
+}
+
+fn value(args: &mut impl Iterator<Item = String>, option: &str) -> anyhow::Result<String> {
+    args.next()
+        .ok_or_else(|| anyhow::anyhow!("{option} needs a value"))
+}
+
+fn print_help() {
+    println!("rust-srec-decrypt - Decrypt files written by the aes-gcm encrypt processor");
+    println!();
+    println!("Usage:");
+    println!("  rust-srec-decrypt [--key-file FILE | --key-env VAR] [-o OUTPUT] FILE.enc...");
+    println!();
+    println!("The key is read from --key-file, else from the environment variable named");
+    println!("by --key-env, else from SREC_ENCRYPTION_KEY (64 hex characters or base64).");
+    println!("Each FILE.enc is written to FILE unless --output is given. When");
+    println!("FILE.enc.manifest.json is present, the key fingerprint and the plaintext");
+    println!("SHA-256 are checked against it.");
+    println!();
+    println!("Files encrypted with the age method are decrypted with `age --decrypt`.");
+}
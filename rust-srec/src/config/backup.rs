@@ -212,6 +212,33 @@ pub struct GlobalConfigExport {
     /// Defaults to false so backups from older versions import fail-closed.
     #[serde(default)]
     pub stream_proxy_allow_private_targets: bool,
+    /// Minimum free bytes on an output volume; `0` disables the policy.
+    #[serde(default)]
+    pub low_disk_space_threshold_bytes: i64,
+    /// Action taken below the free-space threshold.
+    #[serde(default = "default_low_disk_space_action")]
+    pub low_disk_space_action: String,
+    /// Folder used by the `switch_folder` action.
+    #[serde(default)]
+    pub low_disk_space_secondary_folder: Option<String>,
+    /// Whether offline polling adapts to when streamers usually go live.
+    #[serde(default)]
+    pub adaptive_polling: bool,
+    /// Seconds a live streamer must stay offline before it is confirmed.
+    #[serde(default)]
+    pub offline_grace_secs: i64,
+    /// CPU usage percent above which new downloads are deferred; `0` disables.
+    #[serde(default)]
+    pub load_admission_max_cpu_percent: i64,
+    /// Memory usage percent above which new downloads are deferred.
+    #[serde(default)]
+    pub load_admission_max_memory_percent: i64,
+    /// I/O pressure percent above which new downloads are deferred.
+    #[serde(default)]
+    pub load_admission_max_io_pressure_percent: i64,
+    /// Reconnect and heartbeat policy for every danmu connection.
+    #[serde(default)]
+    pub danmu_connection_config: Option<serde_json::Value>,
 }
 
 fn default_pipeline_job_timeout_secs() -> i64 {
@@ -226,6 +253,10 @@ fn default_gpu_health_probe_interval_secs() -> i64 {
     30
 }
 
+fn default_low_disk_space_action() -> String {
+    "stop_new".to_string()
+}
+
 /// Template for export (uses name as identifier).
 #[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
 pub struct TemplateExport {
@@ -317,6 +348,8 @@ pub struct PlatformExport {
     pub offline_check_count: Option<i32>,
     #[serde(default)]
     pub offline_check_delay_ms: Option<i64>,
+    #[serde(default)]
+    pub rate_limit: Option<f64>,
 }
 
 /// Notification channel for export (uses name as identifier).
@@ -77,6 +77,13 @@ pub enum ConfigUpdateEvent {
     /// Filters are stored separately from the main config/templates and can affect scheduling
     /// decisions (e.g. OutOfSchedule smart-wake). Emit this to force a re-check for the streamer.
     StreamerFiltersUpdated { streamer_id: String },
+
+    /// Members of a streamer group were added, removed or re-templated.
+    ///
+    /// Emitted once per group operation instead of one
+    /// `StreamerMetadataUpdated` per member. Handlers should treat every
+    /// current member of the group as updated.
+    StreamerGroupUpdated { group_id: String },
 }
 
 impl ConfigUpdateEvent {
@@ -111,6 +118,9 @@ impl ConfigUpdateEvent {
             Self::StreamerFiltersUpdated { streamer_id } => {
                 format!("Streamer filters updated: {}", streamer_id)
             }
+            Self::StreamerGroupUpdated { group_id } => {
+                format!("Streamer group updated: {}", group_id)
+            }
         }
     }
 }
@@ -262,6 +272,7 @@ impl std::hash::Hash for ConfigUpdateEvent {
                 is_active.hash(state);
             }
             ConfigUpdateEvent::StreamerFiltersUpdated { streamer_id } => streamer_id.hash(state),
+            ConfigUpdateEvent::StreamerGroupUpdated { group_id } => group_id.hash(state),
         }
     }
 }
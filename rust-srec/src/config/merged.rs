@@ -22,6 +22,14 @@ pub struct MergedConfig {
     pub min_segment_size_bytes: i64,
     pub max_download_duration_secs: i64,
     pub max_part_size_bytes: i64,
+    /// Split at every multiple of this many seconds since local midnight
+    /// (0 = off).
+    #[serde(default)]
+    pub wall_clock_split_secs: u64,
+    /// Record the part of a broadcast that went by before it was detected
+    /// from the platform replay, when the platform publishes one.
+    #[serde(default)]
+    pub vod_backfill: bool,
 
     // Danmu settings
     pub record_danmu: bool,
@@ -33,6 +41,10 @@ pub struct MergedConfig {
 
     // Engine settings
     pub download_engine: String,
+    /// Engines tried, in order, when `download_engine` fails with a
+    /// non-retryable error during a session.
+    #[serde(default)]
+    pub download_engine_fallbacks: Vec<String>,
     pub download_retry_policy: RetryPolicy,
 
     // Event hooks
@@ -81,11 +93,14 @@ pub struct MergedConfigBuilder {
     min_segment_size_bytes: Option<i64>,
     max_download_duration_secs: Option<i64>,
     max_part_size_bytes: Option<i64>,
+    wall_clock_split_secs: Option<u64>,
+    vod_backfill: Option<bool>,
     record_danmu: Option<bool>,
     danmu_sampling_config: Option<DanmuSamplingConfig>,
     proxy_config: Option<ProxyConfig>,
     cookies: Option<String>,
     download_engine: Option<String>,
+    download_engine_fallbacks: Option<Vec<String>>,
     download_retry_policy: Option<RetryPolicy>,
     event_hooks: Option<EventHooks>,
     fetch_delay_ms: Option<i64>,
@@ -101,6 +116,33 @@ pub struct MergedConfigBuilder {
     offline_check_delay_ms: Option<u64>,
 }
 
+/// Merge streamer engine overrides into the template ones.
+///
+/// Each engine's settings are merged key by key, so a streamer can change
+/// one field of an engine override without repeating the others.
+fn merge_engines_override(
+    base: Option<serde_json::Value>,
+    overrides: serde_json::Value,
+) -> serde_json::Value {
+    let mut merged = match base {
+        Some(serde_json::Value::Object(base)) => base,
+        _ => serde_json::Map::new(),
+    };
+    if let serde_json::Value::Object(overrides) = overrides {
+        for (engine_id, value) in overrides {
+            match (merged.get_mut(&engine_id), value) {
+                (Some(serde_json::Value::Object(existing)), serde_json::Value::Object(fields)) => {
+                    existing.extend(fields);
+                }
+                (_, value) => {
+                    merged.insert(engine_id, value);
+                }
+            }
+        }
+    }
+    serde_json::Value::Object(merged)
+}
+
 /// Fully parsed global configuration layer.
 pub struct GlobalConfigLayer {
     pub output_folder: String,
@@ -515,6 +557,28 @@ impl MergedConfigBuilder {
                 debug!("Streamer config override: download_engine = {}", v);
                 self.download_engine = Some(v.to_string());
             }
+            if let Some(v) = config.get("download_engine_fallbacks")
+                && let Ok(v) = serde_json::from_value::<Vec<String>>(v.clone())
+            {
+                debug!(
+                    "Streamer config override: download_engine_fallbacks = {:?}",
+                    v
+                );
+                self.download_engine_fallbacks = Some(v);
+            }
+            // Per-engine overrides, e.g. FFmpeg argument templates for this
+            // streamer. Accepted as an object or as a JSON string.
+            if let Some(v) = config.get("engines_override") {
+                let v = match v {
+                    serde_json::Value::String(raw) => serde_json::from_str(raw).ok(),
+                    other => Some(other.clone()),
+                };
+                if let Some(v) = v.filter(|v| v.is_object()) {
+                    debug!("Streamer config override: engines_override");
+                    self.engines_override =
+                        Some(merge_engines_override(self.engines_override.take(), v));
+                }
+            }
             if let Some(v) = config.get("record_danmu").and_then(|v| v.as_bool()) {
                 debug!("Streamer config override: record_danmu = {}", v);
                 self.record_danmu = Some(v);
@@ -537,6 +601,14 @@ impl MergedConfigBuilder {
                 );
                 self.max_download_duration_secs = Some(v);
             }
+            if let Some(v) = config.get("wall_clock_split_secs").and_then(|v| v.as_u64()) {
+                debug!("Streamer config override: wall_clock_split_secs = {}", v);
+                self.wall_clock_split_secs = Some(v);
+            }
+            if let Some(v) = config.get("vod_backfill").and_then(|v| v.as_bool()) {
+                debug!("Streamer config override: vod_backfill = {}", v);
+                self.vod_backfill = Some(v);
+            }
             if let Some(v) = config.get("output_file_format").and_then(|v| v.as_str()) {
                 debug!("Streamer config override: output_file_format = {}", v);
                 self.output_file_format = Some(v.to_string());
@@ -704,11 +776,14 @@ impl MergedConfigBuilder {
             min_segment_size_bytes: self.min_segment_size_bytes.unwrap_or(1048576),
             max_download_duration_secs: self.max_download_duration_secs.unwrap_or(0),
             max_part_size_bytes: self.max_part_size_bytes.unwrap_or(8589934592),
+            wall_clock_split_secs: self.wall_clock_split_secs.unwrap_or(0),
+            vod_backfill: self.vod_backfill.unwrap_or(false),
             record_danmu,
             danmu_sampling_config: self.danmu_sampling_config.unwrap_or_default(),
             proxy_config: self.proxy_config.unwrap_or_default(),
             cookies: self.cookies,
             download_engine,
+            download_engine_fallbacks: self.download_engine_fallbacks.unwrap_or_default(),
             download_retry_policy: self.download_retry_policy.unwrap_or_default(),
             event_hooks: self.event_hooks.unwrap_or_default(),
             fetch_delay_ms: self.fetch_delay_ms.unwrap_or(60000),
@@ -792,6 +867,103 @@ mod tests {
         assert!(config.record_danmu);
     }
 
+    #[test]
+    fn test_streamer_wall_clock_split() {
+        let streamer_config = serde_json::json!({ "wall_clock_split_secs": 3600 });
+
+        let config = MergedConfig::builder()
+            .with_global(global_layer("mesio"))
+            .with_streamer(Some(&streamer_config))
+            .build();
+        assert_eq!(config.wall_clock_split_secs, 3600);
+
+        let config = MergedConfig::builder()
+            .with_global(global_layer("mesio"))
+            .build();
+        assert_eq!(config.wall_clock_split_secs, 0);
+    }
+
+    #[test]
+    fn test_streamer_vod_backfill() {
+        let streamer_config = serde_json::json!({ "vod_backfill": true });
+
+        let config = MergedConfig::builder()
+            .with_global(global_layer("mesio"))
+            .with_streamer(Some(&streamer_config))
+            .build();
+        assert!(config.vod_backfill);
+
+        let config = MergedConfig::builder()
+            .with_global(global_layer("mesio"))
+            .build();
+        assert!(!config.vod_backfill);
+    }
+
+    #[test]
+    fn test_streamer_engine_fallbacks() {
+        let streamer_config = serde_json::json!({
+            "download_engine": "default-mesio",
+            "download_engine_fallbacks": ["default-ffmpeg", "default-streamlink"]
+        });
+
+        let config = MergedConfig::builder()
+            .with_global(global_layer("mesio"))
+            .with_streamer(Some(&streamer_config))
+            .build();
+
+        assert_eq!(config.download_engine, "default-mesio");
+        assert_eq!(
+            config.download_engine_fallbacks,
+            vec!["default-ffmpeg", "default-streamlink"]
+        );
+
+        let config = MergedConfig::builder()
+            .with_global(global_layer("mesio"))
+            .build();
+        assert!(config.download_engine_fallbacks.is_empty());
+    }
+
+    #[test]
+    fn test_streamer_engines_override_merges_with_template() {
+        let streamer_config = serde_json::json!({
+            "engines_override": {
+                "default-ffmpeg": { "input_args": ["-rw_timeout", "{timeout_us}"] },
+                "default-streamlink": { "quality": "720p" }
+            }
+        });
+
+        let config = MergedConfig::builder()
+            .with_global(global_layer("mesio"))
+            .with_template(TemplateConfigLayer {
+                engines_override: Some(serde_json::json!({
+                    "default-ffmpeg": { "input_args": ["-re"], "timeout_secs": 10 }
+                })),
+                ..Default::default()
+            })
+            .with_streamer(Some(&streamer_config))
+            .build();
+
+        assert_eq!(
+            config.engines_override,
+            Some(serde_json::json!({
+                "default-ffmpeg": { "input_args": ["-rw_timeout", "{timeout_us}"], "timeout_secs": 10 },
+                "default-streamlink": { "quality": "720p" }
+            }))
+        );
+
+        let streamer_config = serde_json::json!({
+            "engines_override": r#"{"default-ffmpeg":{"hwaccel":"auto"}}"#
+        });
+        let config = MergedConfig::builder()
+            .with_global(global_layer("mesio"))
+            .with_streamer(Some(&streamer_config))
+            .build();
+        assert_eq!(
+            config.engines_override,
+            Some(serde_json::json!({ "default-ffmpeg": { "hwaccel": "auto" } }))
+        );
+    }
+
     #[test]
     fn test_streamer_pipeline_override() {
         // Create a streamer-specific config with custom pipeline
@@ -13,6 +13,7 @@
 
 mod error;
 mod manager;
+pub mod oauth;
 mod resolver;
 mod service;
 mod store;
@@ -0,0 +1,197 @@
+//! OAuth 2.0 grants for upload accounts.
+//!
+//! Upload processors reference an account by name. The stored refresh token
+//! is exchanged for a short-lived access token on demand; the access token
+//! is cached in the database until shortly before it expires.
+
+use reqwest::Client;
+use serde::Deserialize;
+use tracing::debug;
+
+use crate::credentials::error::CredentialError;
+use crate::database::models::OAuthTokenDbModel;
+use crate::database::repositories::OAuthTokenRepository;
+
+/// Provider name stored for Google accounts.
+pub const GOOGLE_PROVIDER: &str = "google";
+
+const GOOGLE_AUTH_URL: &str = "https://accounts.google.com/o/oauth2/v2/auth";
+const GOOGLE_TOKEN_URL: &str = "https://oauth2.googleapis.com/token";
+
+/// Scope required to upload videos and add them to playlists.
+pub const YOUTUBE_SCOPE: &str = "https://www.googleapis.com/auth/youtube";
+
+/// Refresh access tokens this long before they expire.
+const EXPIRY_MARGIN_MS: i64 = 60_000;
+
+/// Token endpoint response.
+#[derive(Debug, Deserialize)]
+pub struct TokenGrant {
+    pub access_token: String,
+    /// Only returned by the authorization code exchange.
+    pub refresh_token: Option<String>,
+    pub expires_in: Option<i64>,
+    pub scope: Option<String>,
+}
+
+impl TokenGrant {
+    /// Expiry as Unix epoch milliseconds, relative to `now_ms`.
+    pub fn expires_at(&self, now_ms: i64) -> Option<i64> {
+        self.expires_in.map(|secs| now_ms + secs * 1000)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenErrorResponse {
+    error: String,
+    error_description: Option<String>,
+}
+
+/// Build the Google consent URL for an offline (refresh token) grant.
+pub fn google_authorization_url(client_id: &str, redirect_uri: &str, state: &str) -> String {
+    let mut url = url::Url::parse(GOOGLE_AUTH_URL).expect("static URL");
+    url.query_pairs_mut()
+        .append_pair("client_id", client_id)
+        .append_pair("redirect_uri", redirect_uri)
+        .append_pair("response_type", "code")
+        .append_pair("scope", YOUTUBE_SCOPE)
+        .append_pair("access_type", "offline")
+        // Without forced consent Google omits the refresh token on re-authorization.
+        .append_pair("prompt", "consent")
+        .append_pair("state", state);
+    url.into()
+}
+
+/// Exchange an authorization code for a grant that includes a refresh token.
+pub async fn exchange_google_code(
+    http: &Client,
+    client_id: &str,
+    client_secret: &str,
+    redirect_uri: &str,
+    code: &str,
+) -> Result<TokenGrant, CredentialError> {
+    let grant = post_token(
+        http,
+        &[
+            ("grant_type", "authorization_code"),
+            ("client_id", client_id),
+            ("client_secret", client_secret),
+            ("redirect_uri", redirect_uri),
+            ("code", code),
+        ],
+    )
+    .await?;
+    if grant.refresh_token.is_none() {
+        return Err(CredentialError::MissingRefreshToken);
+    }
+    Ok(grant)
+}
+
+/// Exchange a stored refresh token for a new access token.
+pub async fn refresh_google_token(
+    http: &Client,
+    token: &OAuthTokenDbModel,
+) -> Result<TokenGrant, CredentialError> {
+    post_token(
+        http,
+        &[
+            ("grant_type", "refresh_token"),
+            ("client_id", &token.client_id),
+            ("client_secret", &token.client_secret),
+            ("refresh_token", &token.refresh_token),
+        ],
+    )
+    .await
+}
+
+/// Return a valid access token for the named account, refreshing and
+/// caching it when the stored one is missing or about to expire.
+pub async fn access_token(
+    repo: &dyn OAuthTokenRepository,
+    http: &Client,
+    name: &str,
+) -> Result<String, CredentialError> {
+    let token = repo.get_token(name).await.map_err(|e| match e {
+        crate::Error::NotFound { .. } => CredentialError::NoCredentials,
+        other => other.into(),
+    })?;
+
+    let now = crate::database::time::now_ms();
+    if let Some(access) = cached_access_token(&token, now) {
+        return Ok(access.to_string());
+    }
+
+    if token.provider != GOOGLE_PROVIDER {
+        return Err(CredentialError::UnsupportedPlatform(token.provider));
+    }
+
+    debug!(account = %name, "Refreshing OAuth access token");
+    let grant = refresh_google_token(http, &token).await?;
+    repo.update_access_token(name, &grant.access_token, grant.expires_at(now))
+        .await?;
+    Ok(grant.access_token)
+}
+
+fn cached_access_token(token: &OAuthTokenDbModel, now_ms: i64) -> Option<&str> {
+    let access = token.access_token.as_deref()?;
+    match token.access_token_expires_at {
+        Some(expires_at) if expires_at - EXPIRY_MARGIN_MS > now_ms => Some(access),
+        _ => None,
+    }
+}
+
+async fn post_token(http: &Client, form: &[(&str, &str)]) -> Result<TokenGrant, CredentialError> {
+    let response = http.post(GOOGLE_TOKEN_URL).form(form).send().await?;
+    let status = response.status();
+    let body = response.text().await?;
+
+    if status.is_success() {
+        return serde_json::from_str(&body)
+            .map_err(|e| CredentialError::ParseError(format!("token response: {e}")));
+    }
+
+    let error = serde_json::from_str::<TokenErrorResponse>(&body).ok();
+    match error {
+        // The refresh token was revoked or expired; the user must authorize again.
+        Some(err) if err.error == "invalid_grant" => Err(CredentialError::InvalidRefreshToken),
+        Some(err) => Err(CredentialError::RefreshFailed(format!(
+            "{}: {}",
+            err.error,
+            err.error_description.unwrap_or_default()
+        ))),
+        None if status.as_u16() == 429 => Err(CredentialError::RateLimited),
+        None => Err(CredentialError::RefreshFailed(format!("HTTP {status}"))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn authorization_url_requests_offline_access() {
+        let url = google_authorization_url("client-id", "http://localhost/cb", "xyz");
+        let parsed = url::Url::parse(&url).unwrap();
+        let pairs: std::collections::HashMap<_, _> = parsed.query_pairs().into_owned().collect();
+        assert_eq!(pairs["client_id"], "client-id");
+        assert_eq!(pairs["redirect_uri"], "http://localhost/cb");
+        assert_eq!(pairs["access_type"], "offline");
+        assert_eq!(pairs["prompt"], "consent");
+        assert_eq!(pairs["scope"], YOUTUBE_SCOPE);
+        assert_eq!(pairs["state"], "xyz");
+    }
+
+    #[test]
+    fn cached_token_is_reused_until_near_expiry() {
+        let mut token = OAuthTokenDbModel::new("a", GOOGLE_PROVIDER, "c", "s", "r");
+        assert_eq!(cached_access_token(&token, 0), None);
+
+        token.access_token = Some("access".to_string());
+        token.access_token_expires_at = Some(120_000);
+        assert_eq!(cached_access_token(&token, 0), Some("access"));
+        assert_eq!(cached_access_token(&token, 70_000), None);
+
+        token.access_token_expires_at = None;
+        assert_eq!(cached_access_token(&token, 0), None);
+    }
+}
@@ -7,14 +7,16 @@
 
 // Re-export core types from platforms-parser
 pub use platforms_parser::danmaku::{
-    DanmuConnection, DanmuControlEvent, DanmuItem, DanmuMessage, DanmuProvider, DanmuSampler,
-    DanmuSamplingConfig, DanmuStatistics, DanmuType, FixedIntervalSampler, HuyaDanmuProvider,
-    ProviderRegistry, RateDataPoint, StatisticsAggregator, TopTalker, TwitchDanmuProvider,
-    VelocitySampler, WordFrequency, XmlDanmuWriter, create_sampler, escape_xml,
+    ActivityBurst, BurstDetectionConfig, DanmuConnection, DanmuConnectionEvent, DanmuControlEvent,
+    DanmuItem, DanmuMessage, DanmuProvider, DanmuSampler, DanmuSamplingConfig, DanmuStatistics,
+    DanmuType, FixedIntervalSampler, HuyaDanmuProvider, ProviderRegistry, RateDataPoint,
+    StatisticsAggregator, StopWordLanguage, TopTalker, TwitchDanmuProvider, VelocitySampler,
+    WebSocketProviderConfig, WordFrequency, XmlDanmuWriter, create_sampler, escape_xml,
     message_type_to_int,
 };
 
 // Local modules (application-specific)
+mod dedup;
 pub mod events;
 mod runner;
 pub mod service;
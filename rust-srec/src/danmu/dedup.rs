@@ -0,0 +1,178 @@
+//! Duplicate suppression for danmu messages.
+//!
+//! Providers reconnect inside the transport layer (`WebSocketDanmuProvider`), and several
+//! platforms replay recent chat history when a client joins a room. Without suppression the
+//! runner writes those replayed messages a second time into the active XML segment.
+
+use std::collections::{HashSet, VecDeque};
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+
+use crate::danmu::DanmuMessage;
+
+/// Upper bound on remembered keys, independent of the time window.
+const MAX_ENTRIES: usize = 8192;
+
+/// Sliding window of recently seen messages.
+///
+/// A message is a duplicate when either its platform message ID or its content fingerprint
+/// (user, type and content) was seen within the window. The fingerprint covers providers that
+/// assign a fresh random ID on every delivery. It leaves out the timestamp: several providers
+/// (douyu, soop) stamp messages on receipt, so a replay after a reconnect carries a later time
+/// than the original. As a consequence, a user sending the same text twice within the window
+/// is recorded once.
+#[derive(Debug)]
+pub(crate) struct DedupWindow {
+    window: chrono::Duration,
+    entries: VecDeque<(DateTime<Utc>, u64)>,
+    keys: HashSet<u64>,
+    newest: Option<DateTime<Utc>>,
+}
+
+impl DedupWindow {
+    /// Create a window retaining keys for `window` of message time.
+    ///
+    /// A zero window disables suppression entirely.
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window: chrono::Duration::from_std(window).unwrap_or(chrono::Duration::MAX),
+            entries: VecDeque::new(),
+            keys: HashSet::new(),
+            newest: None,
+        }
+    }
+
+    /// Whether suppression is enabled.
+    pub fn is_enabled(&self) -> bool {
+        !self.window.is_zero()
+    }
+
+    /// Record `message` and return `true` if it was already seen within the window.
+    pub fn check_and_insert(&mut self, message: &DanmuMessage) -> bool {
+        if !self.is_enabled() {
+            return false;
+        }
+
+        let newest = self
+            .newest
+            .map_or(message.timestamp, |n| n.max(message.timestamp));
+        self.newest = Some(newest);
+        self.evict(newest);
+
+        let id_key = (!message.id.is_empty()).then(|| id_key(message));
+        let fingerprint = fingerprint_key(message);
+        if id_key.is_some_and(|k| self.keys.contains(&k)) || self.keys.contains(&fingerprint) {
+            return true;
+        }
+
+        if let Some(key) = id_key {
+            self.insert(message.timestamp, key);
+        }
+        self.insert(message.timestamp, fingerprint);
+        false
+    }
+
+    fn insert(&mut self, timestamp: DateTime<Utc>, key: u64) {
+        if self.keys.insert(key) {
+            self.entries.push_back((timestamp, key));
+        }
+        while self.entries.len() > MAX_ENTRIES {
+            if let Some((_, key)) = self.entries.pop_front() {
+                self.keys.remove(&key);
+            }
+        }
+    }
+
+    /// Drop keys older than the window, measured against the newest message timestamp so that
+    /// replayed history stamped with its original (older) server time is still compared against
+    /// live traffic.
+    fn evict(&mut self, newest: DateTime<Utc>) {
+        while let Some((timestamp, key)) = self.entries.front().copied() {
+            if newest - timestamp <= self.window {
+                break;
+            }
+            self.entries.pop_front();
+            self.keys.remove(&key);
+        }
+    }
+}
+
+fn id_key(message: &DanmuMessage) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    0u8.hash(&mut hasher);
+    message.id.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn fingerprint_key(message: &DanmuMessage) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    1u8.hash(&mut hasher);
+    message.user_id.hash(&mut hasher);
+    std::mem::discriminant(&message.message_type).hash(&mut hasher);
+    message.content.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chat(id: &str, content: &str, timestamp: DateTime<Utc>) -> DanmuMessage {
+        DanmuMessage::chat(id, "uid", "user", content).with_timestamp(timestamp)
+    }
+
+    #[test]
+    fn suppresses_repeated_message_id() {
+        let mut window = DedupWindow::new(Duration::from_secs(30));
+        let now = Utc::now();
+
+        assert!(!window.check_and_insert(&chat("m1", "hello", now)));
+        assert!(window.check_and_insert(&chat("m1", "hello", now)));
+    }
+
+    #[test]
+    fn suppresses_same_content_with_fresh_id() {
+        let mut window = DedupWindow::new(Duration::from_secs(30));
+        let now = Utc::now();
+
+        assert!(!window.check_and_insert(&chat("a", "hello", now)));
+        assert!(window.check_and_insert(&chat("b", "hello", now)));
+        assert!(!window.check_and_insert(&chat("c", "other", now)));
+    }
+
+    #[test]
+    fn suppresses_replay_stamped_at_a_later_receipt_time() {
+        let mut window = DedupWindow::new(Duration::from_secs(30));
+        let now = Utc::now();
+
+        assert!(!window.check_and_insert(&chat("a", "hello", now)));
+        // Replayed after a reconnect by a provider that stamps on receipt.
+        assert!(window.check_and_insert(&chat("b", "hello", now + chrono::Duration::seconds(12))));
+    }
+
+    #[test]
+    fn forgets_keys_outside_window() {
+        let mut window = DedupWindow::new(Duration::from_secs(5));
+        let now = Utc::now();
+
+        assert!(!window.check_and_insert(&chat("m1", "hello", now)));
+        assert!(!window.check_and_insert(&chat(
+            "m2",
+            "later",
+            now + chrono::Duration::seconds(10)
+        )));
+        assert!(!window.check_and_insert(&chat("m1", "hello", now)));
+    }
+
+    #[test]
+    fn zero_window_disables_suppression() {
+        let mut window = DedupWindow::new(Duration::ZERO);
+        let now = Utc::now();
+
+        assert!(!window.is_enabled());
+        assert!(!window.check_and_insert(&chat("m1", "hello", now)));
+        assert!(!window.check_and_insert(&chat("m1", "hello", now)));
+    }
+}
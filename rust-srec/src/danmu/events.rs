@@ -54,9 +54,26 @@ pub enum DanmuEvent {
         control: DanmuControlEvent,
     },
     /// Connection lost and reconnecting
-    Reconnecting { session_id: String, attempt: u32 },
+    Reconnecting {
+        session_id: String,
+        streamer_id: String,
+        platform: String,
+        attempt: u32,
+    },
+    /// Connection re-established after `attempts` failed reconnect attempts
+    Reconnected {
+        session_id: String,
+        streamer_id: String,
+        platform: String,
+        attempts: u32,
+    },
     /// Reconnection failed
-    ReconnectFailed { session_id: String, error: String },
+    ReconnectFailed {
+        session_id: String,
+        streamer_id: String,
+        platform: String,
+        error: String,
+    },
     /// Error during collection
     Error { session_id: String, error: String },
 }
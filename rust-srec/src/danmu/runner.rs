@@ -9,17 +9,20 @@
 use chrono::{DateTime, Utc};
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::{broadcast, mpsc};
 use tokio_util::sync::CancellationToken;
 
 use platforms_parser::danmaku::{
-    ConnectionConfig, DanmuConnection, DanmuControlEvent, DanmuItem, DanmuProvider,
+    ConnectionConfig, DanmuConnection, DanmuConnectionEvent, DanmuControlEvent, DanmuItem,
+    DanmuProvider,
     message::{DanmuMessage, DanmuType},
 };
 
 use crate::danmu::{DanmuSampler, DanmuStatistics, StatisticsAggregator, XmlDanmuWriter};
 use crate::error::{Error, Result};
 
+use super::dedup::DedupWindow;
 use super::events::{CollectionCommand, DanmuEvent};
 
 /// Configuration constants for the collection runner.
@@ -59,6 +62,10 @@ pub(crate) struct CollectionRunner {
     // Message buffer for sorting before writing
     message_buffer: Vec<DanmuMessage>,
 
+    // Suppresses messages replayed after a transport reconnect
+    dedup: DedupWindow,
+    duplicates_dropped: u64,
+
     // Stats state
     stats: StatisticsAggregator,
     sampler: Box<dyn DanmuSampler>,
@@ -77,6 +84,7 @@ pub(crate) struct RunnerParams {
     pub stats: StatisticsAggregator,
     pub sampler: Box<dyn DanmuSampler>,
     pub sampling_enabled: bool,
+    pub dedup_window: Duration,
     pub event_tx: broadcast::Sender<DanmuEvent>,
 }
 
@@ -92,6 +100,7 @@ impl CollectionRunner {
             stats,
             sampler,
             sampling_enabled,
+            dedup_window,
             event_tx,
         } = params;
         // Connect to danmu stream
@@ -105,6 +114,8 @@ impl CollectionRunner {
             connection,
             current_writer: None,
             message_buffer: Vec::with_capacity(config::MAX_BUFFER_SIZE),
+            dedup: DedupWindow::new(dedup_window),
+            duplicates_dropped: 0,
             stats,
             sampler,
             sampling_enabled,
@@ -183,21 +194,26 @@ impl CollectionRunner {
     }
 
     /// Start a new segment, flushing and finalizing the old one if present.
+    ///
+    /// `start_time` is the split boundary: buffered messages sent before it belong to the
+    /// previous segment (or are dropped when no segment is active), while messages at or after
+    /// it are carried over into the new writer so their offsets are relative to the new file.
     async fn start_segment(
         &mut self,
         segment_id: String,
         output_path: PathBuf,
         start_time: DateTime<Utc>,
     ) -> Result<()> {
-        // Flush buffer to old segment before switching
-        self.flush_buffer().await?;
+        let before_split = split_off_before(&mut self.message_buffer, start_time);
+        if let Some((_, ref mut writer)) = self.current_writer {
+            for message in &before_split {
+                writer.write_message(message).await?;
+            }
+        }
 
         // Finalize previous segment if any
         self.finalize_current_segment().await?;
 
-        // Clear buffer for new segment
-        self.message_buffer.clear();
-
         // Create output directory if needed
         crate::utils::fs::ensure_parent_dir(&output_path).await?;
 
@@ -222,6 +238,9 @@ impl CollectionRunner {
         });
         self.current_writer = Some((segment_id, writer));
 
+        // Write messages that arrived between the previous segment ending and this one starting.
+        self.flush_buffer().await?;
+
         Ok(())
     }
 
@@ -239,6 +258,13 @@ impl CollectionRunner {
 
     /// Shutdown the runner, flushing and finalizing any active segment.
     async fn shutdown(&mut self) -> Result<()> {
+        if self.duplicates_dropped > 0 {
+            tracing::debug!(
+                session_id = %self.session_id,
+                duplicates_dropped = self.duplicates_dropped,
+                "Danmu collection suppressed duplicate messages"
+            );
+        }
         self.flush_buffer().await?;
         self.finalize_current_segment().await?;
         self.provider.disconnect(&mut self.connection).await?;
@@ -317,9 +343,56 @@ impl CollectionRunner {
         match item {
             DanmuItem::Message(message) => self.handle_message(message).await,
             DanmuItem::Control(control) => self.handle_control(control).await,
+            DanmuItem::Connection(event) => {
+                self.handle_connection_event(event);
+                Ok(CommandResult::Continue)
+            }
         }
     }
 
+    /// Translate transport state changes into service events.
+    ///
+    /// Reconnects are driven by the provider; the runner keeps writing to the active segment
+    /// and only reports the state so the application can surface degraded collection.
+    fn handle_connection_event(&self, event: DanmuConnectionEvent) {
+        let session_id = self.session_id.clone();
+        let streamer_id = self.streamer_id.clone();
+        let platform = self.provider.platform().to_string();
+        let event = match event {
+            DanmuConnectionEvent::Disconnected { reason } => {
+                tracing::debug!(session_id = %self.session_id, %reason, "Danmu connection lost");
+                return;
+            }
+            DanmuConnectionEvent::Reconnecting { attempt, delay_ms } => {
+                tracing::debug!(
+                    session_id = %self.session_id,
+                    attempt,
+                    delay_ms,
+                    "Danmu reconnect scheduled"
+                );
+                DanmuEvent::Reconnecting {
+                    session_id,
+                    streamer_id,
+                    platform,
+                    attempt,
+                }
+            }
+            DanmuConnectionEvent::Reconnected { attempts } => DanmuEvent::Reconnected {
+                session_id,
+                streamer_id,
+                platform,
+                attempts,
+            },
+            DanmuConnectionEvent::ReconnectFailed { attempts } => DanmuEvent::ReconnectFailed {
+                session_id,
+                streamer_id,
+                platform,
+                error: format!("gave up after {attempts} reconnect attempts"),
+            },
+        };
+        let _ = self.event_tx.send(event);
+    }
+
     async fn handle_control(&mut self, control: DanmuControlEvent) -> Result<CommandResult> {
         // Control events are not written to XML.
         //
@@ -347,6 +420,17 @@ impl CollectionRunner {
 
     /// Handle a received danmu message.
     async fn handle_message(&mut self, message: DanmuMessage) -> Result<CommandResult> {
+        if self.dedup.check_and_insert(&message) {
+            self.duplicates_dropped += 1;
+            tracing::trace!(
+                session_id = %self.session_id,
+                message_id = %message.id,
+                duplicates_dropped = self.duplicates_dropped,
+                "Dropped duplicate danmu message"
+            );
+            return Ok(CommandResult::Continue);
+        }
+
         // Update session-level statistics.
         let is_gift = matches!(message.message_type, DanmuType::Gift | DanmuType::SuperChat);
         self.stats.record_message(
@@ -362,16 +446,68 @@ impl CollectionRunner {
             self.sampler.record_message(message.timestamp);
         }
 
-        // Buffer the message (will be written on flush)
-        if self.current_writer.is_some() {
-            self.message_buffer.push(message);
-
-            // Flush if buffer is full
-            if self.message_buffer.len() >= config::MAX_BUFFER_SIZE {
+        // Buffer the message (will be written on flush). While no segment is active the buffer
+        // keeps only the most recent messages, which `start_segment` hands to the next writer.
+        self.message_buffer.push(message);
+        if self.message_buffer.len() >= config::MAX_BUFFER_SIZE {
+            if self.current_writer.is_some() {
                 self.flush_buffer().await?;
+            } else {
+                self.message_buffer.remove(0);
             }
         }
 
         Ok(CommandResult::Continue)
     }
 }
+
+/// Sort `messages` by timestamp and remove those sent strictly before `boundary`.
+///
+/// Returns the removed messages in timestamp order; messages at or after the boundary stay in
+/// `messages`.
+fn split_off_before(
+    messages: &mut Vec<DanmuMessage>,
+    boundary: DateTime<Utc>,
+) -> Vec<DanmuMessage> {
+    messages.sort_by_key(|m| m.timestamp);
+    let split = messages.partition_point(|m| m.timestamp < boundary);
+    let after = messages.split_off(split);
+    std::mem::replace(messages, after)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn message_at(id: &str, timestamp: DateTime<Utc>) -> DanmuMessage {
+        DanmuMessage::chat(id, "user", "user", "hello").with_timestamp(timestamp)
+    }
+
+    #[test]
+    fn split_off_before_routes_messages_by_boundary() {
+        let boundary = Utc::now();
+        let mut buffer = vec![
+            message_at("late", boundary + Duration::milliseconds(500)),
+            message_at("early", boundary - Duration::milliseconds(200)),
+            message_at("exact", boundary),
+            message_at("earliest", boundary - Duration::seconds(2)),
+        ];
+
+        let before = split_off_before(&mut buffer, boundary);
+
+        let before_ids: Vec<_> = before.iter().map(|m| m.id.as_str()).collect();
+        let after_ids: Vec<_> = buffer.iter().map(|m| m.id.as_str()).collect();
+        assert_eq!(before_ids, ["earliest", "early"]);
+        assert_eq!(after_ids, ["exact", "late"]);
+    }
+
+    #[test]
+    fn split_off_before_keeps_everything_after_boundary() {
+        let boundary = Utc::now();
+        let mut buffer = vec![message_at("a", boundary + Duration::seconds(1))];
+
+        assert!(split_off_before(&mut buffer, boundary).is_empty());
+        assert_eq!(buffer.len(), 1);
+    }
+}
@@ -11,6 +11,8 @@
 //! When session ends → stop collection entirely
 
 use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::Duration;
@@ -19,10 +21,12 @@ use tokio_util::sync::CancellationToken;
 use tracing::{info, warn};
 
 use crate::danmu::{
-    DanmuSampler, DanmuSamplingConfig as SamplerConfig, DanmuStatistics, ProviderRegistry,
-    create_sampler,
+    BurstDetectionConfig, DanmuSampler, DanmuSamplingConfig as SamplerConfig, DanmuStatistics,
+    ProviderRegistry, StopWordLanguage, WebSocketProviderConfig, create_sampler,
+};
+use crate::database::models::{
+    DanmuActivityBurstEntry, DanmuRateEntry, GlobalConfigDbModel, PlatformConfigDbModel,
 };
-use crate::database::models::DanmuRateEntry;
 use crate::database::repositories::SessionRepository;
 use crate::domain::DanmuSamplingConfig;
 use crate::error::{Error, Result};
@@ -47,6 +51,105 @@ pub struct DanmuServiceConfig {
     pub default_sampling: DanmuSamplingConfig,
     /// Buffer size for statistics (number of recent messages to keep)
     pub stats_buffer_size: usize,
+    /// Languages whose stop words are excluded from word frequency.
+    pub stop_word_languages: Vec<StopWordLanguage>,
+    /// Message-rate burst detection, persisted with the session statistics.
+    pub burst_detection: BurstDetectionConfig,
+    /// How long (in message time) a message is remembered for duplicate suppression.
+    ///
+    /// Covers history replayed by platforms after the transport reconnects. `Duration::ZERO`
+    /// disables suppression.
+    pub dedup_window: Duration,
+    /// Heartbeat and reconnect policy applied to every provider connection.
+    pub connection: WebSocketProviderConfig,
+    /// Per-platform overrides of [`Self::connection`], keyed by provider platform name.
+    pub platform_connection: HashMap<String, WebSocketProviderConfig>,
+}
+
+impl DanmuServiceConfig {
+    /// Connection policy for `platform`, falling back to the service-wide default.
+    pub fn connection_for(&self, platform: &str) -> WebSocketProviderConfig {
+        self.platform_connection
+            .get(platform)
+            .copied()
+            .unwrap_or(self.connection)
+    }
+
+    /// Layer the persisted connection policy over [`Self::connection`].
+    ///
+    /// `global_config.danmu_connection_config` applies to every platform; the
+    /// `danmu_connection` key of a platform's `platform_specific_config` is
+    /// layered on top of that for the platform. Invalid JSON is logged and
+    /// skipped so a bad value cannot keep the service from starting.
+    pub fn apply_connection_config(
+        &mut self,
+        global: &GlobalConfigDbModel,
+        platforms: &[PlatformConfigDbModel],
+    ) {
+        if let Some(json) = global.danmu_connection_config.as_deref() {
+            match serde_json::from_str::<DanmuConnectionConfig>(json) {
+                Ok(overrides) => self.connection = overrides.apply(self.connection),
+                Err(e) => warn!(error = %e, "Ignoring invalid global danmu connection config"),
+            }
+        }
+
+        for platform in platforms {
+            let Some(json) = platform.platform_specific_config.as_deref() else {
+                continue;
+            };
+            let overrides = serde_json::from_str::<serde_json::Value>(json)
+                .ok()
+                .and_then(|mut value| value.get_mut("danmu_connection").map(|v| v.take()))
+                .map(serde_json::from_value::<DanmuConnectionConfig>);
+            match overrides {
+                Some(Ok(overrides)) => {
+                    self.platform_connection.insert(
+                        platform.platform_name.clone(),
+                        overrides.apply(self.connection),
+                    );
+                }
+                Some(Err(e)) => warn!(
+                    platform = %platform.platform_name,
+                    error = %e,
+                    "Ignoring invalid danmu connection config"
+                ),
+                None => {}
+            }
+        }
+    }
+}
+
+/// Persisted form of [`WebSocketProviderConfig`]; unset fields keep the
+/// value of the layer below.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DanmuConnectionConfig {
+    pub max_reconnect_attempts: Option<u32>,
+    pub base_reconnect_delay_ms: Option<u64>,
+    pub max_reconnect_delay_ms: Option<u64>,
+    pub backoff_multiplier: Option<f64>,
+    pub jitter_ratio: Option<f64>,
+    pub heartbeat_interval_ms: Option<u64>,
+}
+
+impl DanmuConnectionConfig {
+    /// `base` with every set field replaced.
+    pub fn apply(&self, base: WebSocketProviderConfig) -> WebSocketProviderConfig {
+        WebSocketProviderConfig {
+            max_reconnect_attempts: self
+                .max_reconnect_attempts
+                .unwrap_or(base.max_reconnect_attempts),
+            base_reconnect_delay_ms: self
+                .base_reconnect_delay_ms
+                .unwrap_or(base.base_reconnect_delay_ms),
+            max_reconnect_delay_ms: self
+                .max_reconnect_delay_ms
+                .unwrap_or(base.max_reconnect_delay_ms),
+            backoff_multiplier: self.backoff_multiplier.unwrap_or(base.backoff_multiplier),
+            jitter_ratio: self.jitter_ratio.unwrap_or(base.jitter_ratio),
+            heartbeat_interval_ms: self.heartbeat_interval_ms.or(base.heartbeat_interval_ms),
+        }
+    }
 }
 
 impl Default for DanmuServiceConfig {
@@ -56,6 +159,14 @@ impl Default for DanmuServiceConfig {
             sampling_enabled: false,
             default_sampling: DanmuSamplingConfig::default(),
             stats_buffer_size: 100,
+            stop_word_languages: StopWordLanguage::ALL.to_vec(),
+            burst_detection: BurstDetectionConfig::default(),
+            dedup_window: Duration::from_secs(60),
+            connection: WebSocketProviderConfig {
+                jitter_ratio: 0.2,
+                ..WebSocketProviderConfig::default()
+            },
+            platform_connection: HashMap::new(),
         }
     }
 }
@@ -359,7 +470,8 @@ impl DanmuService {
         })?;
 
         // Build connection config
-        let mut connection_config = ConnectionConfig::with_cookies(cookies.clone());
+        let mut connection_config = ConnectionConfig::with_cookies(cookies.clone())
+            .with_websocket(self.config.connection_for(platform));
         if let Some(e) = extras {
             // Remove common fields that are used for room ID extraction but might be useful as extras too
             // We keep them in extras for now as it's cleaner
@@ -377,7 +489,9 @@ impl DanmuService {
             max_top_talkers,
             max_words,
             Self::DEFAULT_RATE_BUCKET_SECS,
-        );
+        )
+        .with_stop_word_languages(&self.config.stop_word_languages)
+        .with_burst_detection(self.config.burst_detection);
         let sampler: Box<dyn DanmuSampler> = if self.config.sampling_enabled {
             let sampling = sampling_config.unwrap_or_else(|| self.config.default_sampling.clone());
             let sampler_config = to_sampler_config(&sampling);
@@ -411,6 +525,7 @@ impl DanmuService {
         let session_repo = self.session_repo.clone();
         let provider = Arc::clone(&provider);
         let sampling_enabled = self.config.sampling_enabled;
+        let dedup_window = self.config.dedup_window;
         let conn_config = connection_config;
         let cancel_token_task = cancel_token.clone();
 
@@ -426,6 +541,7 @@ impl DanmuService {
                     stats,
                     sampler,
                     sampling_enabled,
+                    dedup_window,
                     event_tx: event_tx.clone(),
                 }),
             )
@@ -749,6 +865,24 @@ async fn persist_statistics(
         }
     };
 
+    let activity_bursts = statistics
+        .bursts
+        .iter()
+        .map(|burst| DanmuActivityBurstEntry {
+            start_ts: burst.start.timestamp_millis(),
+            end_ts: burst.end.timestamp_millis(),
+            message_count: saturating_u64_to_i64(burst.message_count),
+            peak_rate: burst.peak_rate,
+            baseline_rate: burst.baseline_rate,
+        });
+    let activity_bursts = match serde_json::to_string(&activity_bursts.collect::<Vec<_>>()) {
+        Ok(value) => Some(value),
+        Err(error) => {
+            warn!(session_id, %error, "Failed to serialize danmu activity bursts");
+            None
+        }
+    };
+
     if let Err(error) = repo
         .upsert_danmu_statistics(
             session_id,
@@ -756,6 +890,7 @@ async fn persist_statistics(
             danmu_rate_timeseries.as_deref(),
             top_talkers.as_deref(),
             word_frequency.as_deref(),
+            activity_bursts.as_deref(),
         )
         .await
     {
@@ -906,4 +1041,45 @@ mod tests {
         // line 250 short-circuits before the abort logic could touch it.
         assert!(service.is_collecting(session_id));
     }
+
+    #[test]
+    fn persisted_connection_config_reaches_connection_for() {
+        let global = GlobalConfigDbModel {
+            danmu_connection_config: Some(
+                r#"{"max_reconnect_attempts":3,"heartbeat_interval_ms":15000}"#.to_string(),
+            ),
+            ..Default::default()
+        };
+        let platform: PlatformConfigDbModel = serde_json::from_value(serde_json::json!({
+            "id": "p1",
+            "platform_name": "huya",
+            "platform_specific_config":
+                r#"{"end_stream_on_danmu_stream_closed":false,"danmu_connection":{"jitter_ratio":0.5}}"#,
+        }))
+        .unwrap();
+        let broken: PlatformConfigDbModel = serde_json::from_value(serde_json::json!({
+            "id": "p2",
+            "platform_name": "douyu",
+            "platform_specific_config": r#"{"danmu_connection":{"jitter_ratio":"high"}}"#,
+        }))
+        .unwrap();
+
+        let mut config = DanmuServiceConfig::default();
+        config.apply_connection_config(&global, &[platform, broken]);
+
+        let default = config.connection_for("bilibili");
+        assert_eq!(default.max_reconnect_attempts, 3);
+        assert_eq!(default.heartbeat_interval_ms, Some(15000));
+        assert_eq!(default.jitter_ratio, 0.2);
+
+        // Platform keys layer over the global policy.
+        let huya = config.connection_for("huya");
+        assert_eq!(huya.max_reconnect_attempts, 3);
+        assert_eq!(huya.heartbeat_interval_ms, Some(15000));
+        assert_eq!(huya.jitter_ratio, 0.5);
+
+        // Invalid platform JSON falls back to the global policy.
+        assert_eq!(config.connection_for("douyu").jitter_ratio, 0.2);
+        assert_eq!(config.connection_for("douyu").max_reconnect_attempts, 3);
+    }
 }
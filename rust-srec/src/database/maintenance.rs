@@ -1263,6 +1263,8 @@ mod tests {
                     titles: Some("[]".to_string()),
                     danmu_statistics_id: None,
                     total_size_bytes: total_size,
+                    download_engine: None,
+                    kind: "live".to_string(),
                 })
                 .await
                 .expect("session");
@@ -1290,6 +1292,7 @@ mod tests {
                 size_bytes: 0,
                 split_reason_code: None,
                 split_reason_details_json: None,
+                analysis_report_json: None,
                 created_at: Some(old_empty_end - 1000),
                 completed_at: Some(old_empty_end),
                 persisted_at: old_empty_end,
@@ -1398,6 +1401,8 @@ mod tests {
                     titles: Some("[]".to_string()),
                     danmu_statistics_id: None,
                     total_size_bytes: 0,
+                    download_engine: None,
+                    kind: "live".to_string(),
                 })
                 .await
                 .expect("session");
@@ -1431,6 +1436,7 @@ mod tests {
                 size_bytes: 1024,
                 split_reason_code: None,
                 split_reason_details_json: None,
+                analysis_report_json: None,
                 created_at: Some(ended_at - 1000),
                 completed_at: Some(ended_at),
                 persisted_at: ended_at,
@@ -10,10 +10,12 @@ pub mod filter;
 pub mod job;
 pub mod job_preset;
 pub mod notification;
+pub mod oauth_token;
 pub mod refresh_token;
 pub mod session;
 pub mod streamer;
 pub mod streamer_check_history;
+pub mod streamer_group;
 pub mod user;
 
 pub use config::*;
@@ -23,8 +25,10 @@ pub use filter::*;
 pub use job::*;
 pub use job_preset::*;
 pub use notification::*;
+pub use oauth_token::*;
 pub use refresh_token::*;
 pub use session::*;
 pub use streamer::*;
 pub use streamer_check_history::*;
+pub use streamer_group::*;
 pub use user::*;
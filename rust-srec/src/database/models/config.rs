@@ -148,6 +148,39 @@ pub struct GlobalConfigDbModel {
     /// non-public address. Runtime-mutable; `stream_proxy_get` reads it per
     /// request, so no restart is required.
     pub stream_proxy_allow_private_targets: bool,
+
+    /// Minimum free bytes on an output volume; `0` disables the free-space
+    /// policy. Runtime-mutable via `DownloadManager::set_disk_space_policy`.
+    pub low_disk_space_threshold_bytes: i64,
+    /// What happens below the threshold: `stop_new`, `finalize` or
+    /// `switch_folder` (see [`crate::downloader::LowDiskSpaceAction`]).
+    pub low_disk_space_action: String,
+    /// Folder used by the `switch_folder` action.
+    pub low_disk_space_secondary_folder: Option<String>,
+
+    /// Adapt the offline check interval to when each streamer usually goes
+    /// live, learned from session history. Runtime-mutable; the scheduler
+    /// picks it up on the next global config update.
+    pub adaptive_polling: bool,
+
+    /// Seconds a live streamer must stay offline before the scheduler
+    /// confirms it, on top of `offline_check_count`; `0` confirms by count
+    /// alone. Runtime-mutable like the other offline-check tunables.
+    pub offline_grace_secs: i64,
+
+    /// Global CPU usage percent above which new downloads are deferred;
+    /// `0` disables the threshold. Runtime-mutable via
+    /// `DownloadManager::set_load_admission_policy`, like the two below.
+    pub load_admission_max_cpu_percent: i64,
+    /// Used memory percent above which new downloads are deferred.
+    pub load_admission_max_memory_percent: i64,
+    /// Linux I/O pressure (PSI `some avg10`) above which new downloads are
+    /// deferred. Ignored on hosts without PSI.
+    pub load_admission_max_io_pressure_percent: i64,
+
+    /// JSON serialized `DanmuConnectionConfig` applied to every danmu
+    /// connection. Startup-only; the danmu service reads it once.
+    pub danmu_connection_config: Option<String>,
 }
 
 impl Default for GlobalConfigDbModel {
@@ -186,6 +219,15 @@ impl Default for GlobalConfigDbModel {
             queue_freshness_threshold_ms: 60_000, // 1 minute
             gpu_health_probe_interval_secs: 30,   // matches DEFAULT_GATE_COOLDOWN_SECS
             stream_proxy_allow_private_targets: false,
+            low_disk_space_threshold_bytes: 0, // Disabled
+            low_disk_space_action: "stop_new".to_string(),
+            low_disk_space_secondary_folder: None,
+            adaptive_polling: false,
+            offline_grace_secs: 0,
+            load_admission_max_cpu_percent: 0, // Disabled
+            load_admission_max_memory_percent: 0,
+            load_admission_max_io_pressure_percent: 0,
+            danmu_connection_config: None,
         }
     }
 }
@@ -227,6 +269,8 @@ pub struct PlatformConfigDbModel {
     pub paired_segment_pipeline: Option<String>,
     pub offline_check_count: Option<i32>,
     pub offline_check_delay_ms: Option<i64>,
+    /// Status checks per second shared by the platform's streamers.
+    pub rate_limit: Option<f64>,
 }
 
 /// Template configuration database model.
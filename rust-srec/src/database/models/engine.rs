@@ -1,5 +1,7 @@
 //! Engine configuration database model.
 
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 
@@ -9,7 +11,7 @@ use sqlx::FromRow;
 pub struct EngineConfigurationDbModel {
     pub id: String,
     pub name: String,
-    /// Engine type: FFMPEG, STREAMLINK, MESIO
+    /// Engine type: FFMPEG, STREAMLINK, MESIO, YTDLP
     pub engine_type: String,
     /// JSON blob for engine-specific configuration
     pub config: String,
@@ -37,6 +39,8 @@ pub enum EngineType {
     Ffmpeg,
     Streamlink,
     Mesio,
+    #[serde(rename = "YTDLP")]
+    YtDlp,
 }
 
 impl EngineType {
@@ -45,6 +49,7 @@ impl EngineType {
             Self::Ffmpeg => "FFMPEG",
             Self::Streamlink => "STREAMLINK",
             Self::Mesio => "MESIO",
+            Self::YtDlp => "YTDLP",
         }
     }
 
@@ -53,6 +58,7 @@ impl EngineType {
             "FFMPEG" => Some(Self::Ffmpeg),
             "STREAMLINK" => Some(Self::Streamlink),
             "MESIO" => Some(Self::Mesio),
+            "YTDLP" => Some(Self::YtDlp),
             _ => None,
         }
     }
@@ -72,6 +78,7 @@ impl std::str::FromStr for EngineType {
             "FFMPEG" => Ok(Self::Ffmpeg),
             "STREAMLINK" => Ok(Self::Streamlink),
             "MESIO" => Ok(Self::Mesio),
+            "YTDLP" | "YT-DLP" | "YT_DLP" => Ok(Self::YtDlp),
             _ => Err(format!("Unknown engine type: {s}")),
         }
     }
@@ -83,12 +90,18 @@ pub struct FfmpegEngineConfig {
     /// Path to ffmpeg binary
     #[serde(default = "default_ffmpeg_path")]
     pub binary_path: String,
-    /// Additional input arguments
+    /// Additional input arguments. May contain the placeholders `{streamer}`,
+    /// `{streamer_id}`, `{session_id}`, `{platform}`, `{timeout_us}`,
+    /// `{hwaccel}` and `{hw_encoder}`, expanded for every download.
     #[serde(default)]
     pub input_args: Vec<String>,
-    /// Additional output arguments
+    /// Additional output arguments, with the same placeholders as `input_args`.
     #[serde(default)]
     pub output_args: Vec<String>,
+    /// Hardware decoding method passed to `-hwaccel`, or `auto` for the
+    /// first one detected. Only useful when the output arguments re-encode.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hwaccel: Option<String>,
     /// Timeout for connection in seconds
     #[serde(default = "default_timeout")]
     pub timeout_secs: u32,
@@ -119,6 +132,7 @@ impl Default for FfmpegEngineConfig {
             binary_path: default_ffmpeg_path(),
             input_args: Vec::new(),
             output_args: Vec::new(),
+            hwaccel: None,
             timeout_secs: default_timeout(),
             user_agent: None,
             graceful_stop_timeout_secs: default_graceful_stop_timeout(),
@@ -171,6 +185,51 @@ impl Default for StreamlinkEngineConfig {
     }
 }
 
+/// yt-dlp engine configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct YtDlpEngineConfig {
+    /// Path to yt-dlp binary
+    #[serde(default = "default_ytdlp_path")]
+    pub binary_path: String,
+    /// Format selection passed to `-f` (e.g., "best", "bv*[height<=720]+ba/b")
+    #[serde(default = "default_ytdlp_format")]
+    pub format: String,
+    /// Netscape cookie file passed to `--cookies`, used instead of the streamer cookies
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cookies_file: Option<String>,
+    /// Browser to load cookies from (`--cookies-from-browser`, e.g., "firefox")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cookies_from_browser: Option<String>,
+    /// Additional arguments
+    #[serde(default)]
+    pub extra_args: Vec<String>,
+    /// How long to wait for ffmpeg to exit gracefully before killing it (seconds).
+    /// Increase this on slow hardware to prevent unplayable files missing the moov atom.
+    #[serde(default = "default_graceful_stop_timeout")]
+    pub graceful_stop_timeout_secs: u32,
+}
+
+fn default_ytdlp_path() -> String {
+    "yt-dlp".to_string()
+}
+
+fn default_ytdlp_format() -> String {
+    "best".to_string()
+}
+
+impl Default for YtDlpEngineConfig {
+    fn default() -> Self {
+        Self {
+            binary_path: default_ytdlp_path(),
+            format: default_ytdlp_format(),
+            cookies_file: None,
+            cookies_from_browser: None,
+            extra_args: Vec::new(),
+            graceful_stop_timeout_secs: default_graceful_stop_timeout(),
+        }
+    }
+}
+
 /// How the FLV splitter should detect audio/video sequence-header changes.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -181,6 +240,48 @@ pub enum MesioSequenceHeaderChangeMode {
     SemanticSignature,
 }
 
+/// How the FLV pipeline handles large timestamp gaps caused by CDN stalls.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MesioGapFillMode {
+    /// Collapse gaps during timing repair (legacy behavior).
+    Off,
+    /// Keep gaps in the timeline and report them in the final metadata.
+    Record,
+    /// Fill gaps with silent audio and repeated video keyframes.
+    Fill,
+}
+
+/// How the FLV pipeline handles GOPs without a leading keyframe after reconnects.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MesioGopIntegrityMode {
+    /// Keep incomplete GOPs (legacy behavior).
+    Off,
+    /// Drop video frames until the next keyframe.
+    Drop,
+    /// Hold tags back until the next keyframe, then drop the held audio and video.
+    Quarantine,
+}
+
+/// Hash used by the FLV duplicate media-tag filter to compare payloads.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MesioDuplicateHashAlgorithm {
+    Crc32,
+    Fx64,
+}
+
+/// Which properties make two FLV media tags duplicates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MesioDuplicateMatchMode {
+    /// Same timestamp, size and payload hash.
+    Payload,
+    /// Same timestamp and size, without hashing the payload.
+    TimestampAndSize,
+}
+
 /// Overrides for the FLV duplicate media-tag filter.
 ///
 /// Fields are optional so they can be used as a partial override payload.
@@ -188,6 +289,13 @@ pub enum MesioSequenceHeaderChangeMode {
 pub struct MesioDuplicateTagFilterConfig {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub window_capacity_tags: Option<usize>,
+    /// Also forget tags this many milliseconds of media time behind the newest one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub window_duration_ms: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hash_algorithm: Option<MesioDuplicateHashAlgorithm>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub match_mode: Option<MesioDuplicateMatchMode>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub replay_backjump_threshold_ms: Option<u32>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -207,6 +315,34 @@ pub struct MesioFlvFixConfig {
     pub duplicate_tag_filtering: Option<bool>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub duplicate_tag_filter_config: Option<MesioDuplicateTagFilterConfig>,
+    /// Keep only this E-RTMP multitrack track id; all tracks are kept when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub selected_track: Option<u8>,
+    /// Drop video and write audio-only FLV files.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub audio_only: Option<bool>,
+    /// Drop tags with impossible payloads or isolated timestamp jumps.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub corrupt_tag_recovery: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gap_fill_mode: Option<MesioGapFillMode>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gop_integrity_mode: Option<MesioGopIntegrityMode>,
+    /// Only split on sequence-header changes that change the video resolution.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub split_on_resolution_change_only: Option<bool>,
+    /// Write the streamer name, platform, session id, recorder and danmu file into `onMetaData`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inject_recording_metadata: Option<bool>,
+    /// Extra `onMetaData` string properties; `{segment}` expands to the segment file name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_metadata: Option<BTreeMap<String, String>>,
+    /// Write the keyframe index into the file every this many seconds of media while recording.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata_checkpoint_secs: Option<u64>,
+    /// Run the FLV pipeline as parallel stages on separate threads.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parallel_stages: Option<bool>,
 }
 
 impl MesioFlvFixConfig {
@@ -233,6 +369,23 @@ impl MesioFlvFixConfig {
             if let Some(value) = override_cfg.window_capacity_tags {
                 c.window_capacity_tags = value;
             }
+            if let Some(value) = override_cfg.window_duration_ms {
+                c.window_duration_ms = (value > 0).then_some(value);
+            }
+            if let Some(value) = override_cfg.hash_algorithm {
+                c.hash_algorithm = match value {
+                    MesioDuplicateHashAlgorithm::Crc32 => flv_fix::DuplicateHashAlgorithm::Crc32,
+                    MesioDuplicateHashAlgorithm::Fx64 => flv_fix::DuplicateHashAlgorithm::Fx64,
+                };
+            }
+            if let Some(value) = override_cfg.match_mode {
+                c.match_mode = match value {
+                    MesioDuplicateMatchMode::Payload => flv_fix::DuplicateMatchMode::Payload,
+                    MesioDuplicateMatchMode::TimestampAndSize => {
+                        flv_fix::DuplicateMatchMode::TimestampAndSize
+                    }
+                };
+            }
             if let Some(value) = override_cfg.replay_backjump_threshold_ms {
                 c.replay_backjump_threshold_ms = value;
             }
@@ -241,6 +394,59 @@ impl MesioFlvFixConfig {
             }
             cfg.duplicate_tag_filter_config = c;
         }
+
+        if let Some(track) = self.selected_track {
+            cfg.track_selection = flv_fix::TrackSelection::Single(track);
+        }
+
+        if let Some(value) = self.audio_only {
+            cfg.audio_only = value;
+        }
+
+        if let Some(value) = self.corrupt_tag_recovery {
+            cfg.corrupt_tag_config = value.then(flv_fix::CorruptTagConfig::default);
+        }
+
+        if let Some(value) = self.parallel_stages {
+            cfg.parallel_stages = value;
+        }
+
+        if let Some(mode) = self.gap_fill_mode {
+            let mode = match mode {
+                MesioGapFillMode::Off => None,
+                MesioGapFillMode::Record => Some(flv_fix::GapFillMode::Record),
+                MesioGapFillMode::Fill => Some(flv_fix::GapFillMode::Fill),
+            };
+            cfg.gap_fill_config = mode.map(|mode| flv_fix::GapFillConfig {
+                mode,
+                ..flv_fix::GapFillConfig::default()
+            });
+        }
+
+        if let Some(mode) = self.gop_integrity_mode {
+            let action = match mode {
+                MesioGopIntegrityMode::Off => None,
+                MesioGopIntegrityMode::Drop => Some(flv_fix::GopIntegrityAction::Drop),
+                MesioGopIntegrityMode::Quarantine => Some(flv_fix::GopIntegrityAction::Quarantine),
+            };
+            cfg.gop_integrity_config = action.map(|action| flv_fix::GopIntegrityConfig {
+                action,
+                ..flv_fix::GopIntegrityConfig::default()
+            });
+        }
+
+        if self.split_on_resolution_change_only == Some(true) {
+            cfg.split_predicates
+                .push(flv_fix::SplitPredicateFactory::new(|| {
+                    Box::new(flv_fix::ResolutionChangePredicate)
+                }));
+        }
+
+        if let Some(ref custom_metadata) = self.custom_metadata {
+            for (key, value) in custom_metadata {
+                cfg.custom_metadata.insert(key, value);
+            }
+        }
     }
 }
 
@@ -256,12 +462,69 @@ pub struct MesioEngineConfig {
     /// Enable HLS fixing
     #[serde(default = "default_true")]
     pub fix_hls: bool,
+    /// Also write the unprocessed stream next to the fixed output (`<name>_raw`).
+    #[serde(default)]
+    pub keep_raw: bool,
     /// Extra FLV-fix tuning knobs for Mesio.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub flv_fix: Option<MesioFlvFixConfig>,
     /// Extra HLS runtime tuning knobs for Mesio.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub hls: Option<MesioHlsConfig>,
+    /// Retry policy of the FLV and HLS downloaders. HLS retries a segment request at most
+    /// twice in place and reschedules the rest within its lifecycle retry budget.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry: Option<MesioRetryPolicyOverride>,
+    /// Watchdog for downloads that stop producing media.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub watchdog: Option<MesioWatchdogOverride>,
+}
+
+/// Overrides of the watchdog for downloads that stop producing media.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct MesioWatchdogOverride {
+    /// Seconds without media after which the download counts as dead (0 disables).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stall_timeout_secs: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub on_stall: Option<MesioStallAction>,
+}
+
+/// What the watchdog does with a download that stopped producing media.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MesioStallAction {
+    /// Fail the download so it reconnects.
+    Reconnect,
+    /// End the download as if the stream had finished.
+    EndStream,
+}
+
+/// Overrides of the retry policy shared by Mesio's protocol downloaders.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct MesioRetryPolicyOverride {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_retries: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_delay_ms: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_delay_ms: Option<u64>,
+    /// Growth of the delay per retry.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub multiplier: Option<f64>,
+    /// Fraction of the delay it is randomly shortened or lengthened by (0 to 1).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jitter: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry_on_network: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry_on_timeout: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry_on_server_error: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry_on_rate_limited: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry_on_not_found: Option<bool>,
 }
 
 /// Mesio HLS tuning configuration.
@@ -330,6 +593,19 @@ pub struct MesioDownloaderBaseOverride {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub http2_keep_alive_interval_ms: Option<u64>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub http2_keep_alive_timeout_ms: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub http2_keep_alive_while_idle: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub http2_adaptive_window: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub http2_initial_stream_window_size: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub http2_initial_connection_window_size: Option<u32>,
+    /// TCP keep-alive interval; 0 disables it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tcp_keepalive_ms: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub pool_max_idle_per_host: Option<usize>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub pool_idle_timeout_ms: Option<u64>,
@@ -366,6 +642,8 @@ pub struct MesioHlsPlaylistConfigOverride {
     pub adaptive_refresh_min_interval_ms: Option<u64>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub adaptive_refresh_max_interval_ms: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub low_latency_enabled: Option<bool>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -492,8 +770,11 @@ impl Default for MesioEngineConfig {
             buffer_size: default_buffer_size(),
             fix_flv: true,
             fix_hls: true,
+            keep_raw: false,
             flv_fix: None,
             hls: None,
+            retry: None,
+            watchdog: None,
         }
     }
 }
@@ -530,6 +811,7 @@ mod tests {
         assert_eq!(parsed.buffer_size, 123);
         assert!(parsed.fix_flv);
         assert!(!parsed.fix_hls);
+        assert!(!parsed.keep_raw);
         assert!(parsed.flv_fix.is_none());
         assert!(parsed.hls.is_none());
     }
@@ -580,9 +862,18 @@ mod tests {
             "duplicate_tag_filtering": false,
             "duplicate_tag_filter_config": {
               "window_capacity_tags": 123,
+              "window_duration_ms": 10000,
+              "hash_algorithm": "fx64",
+              "match_mode": "timestamp_and_size",
               "replay_backjump_threshold_ms": 5000,
               "enable_replay_offset_matching": false
-            }
+            },
+            "selected_track": 1,
+            "audio_only": true,
+            "gap_fill_mode": "fill",
+            "gop_integrity_mode": "quarantine",
+            "corrupt_tag_recovery": false,
+            "split_on_resolution_change_only": true
           }
         }"#;
         let parsed: MesioEngineConfig = serde_json::from_str(json).unwrap();
@@ -598,6 +889,18 @@ mod tests {
         assert!(cfg.drop_duplicate_sequence_headers);
         assert!(!cfg.duplicate_tag_filtering);
         assert_eq!(cfg.duplicate_tag_filter_config.window_capacity_tags, 123);
+        assert_eq!(
+            cfg.duplicate_tag_filter_config.window_duration_ms,
+            Some(10_000)
+        );
+        assert_eq!(
+            cfg.duplicate_tag_filter_config.hash_algorithm,
+            flv_fix::DuplicateHashAlgorithm::Fx64
+        );
+        assert_eq!(
+            cfg.duplicate_tag_filter_config.match_mode,
+            flv_fix::DuplicateMatchMode::TimestampAndSize
+        );
         assert_eq!(
             cfg.duplicate_tag_filter_config.replay_backjump_threshold_ms,
             5000
@@ -606,5 +909,18 @@ mod tests {
             !cfg.duplicate_tag_filter_config
                 .enable_replay_offset_matching
         );
+        assert_eq!(cfg.track_selection, flv_fix::TrackSelection::Single(1));
+        assert!(cfg.audio_only);
+        assert_eq!(
+            cfg.gap_fill_config.map(|gap_fill| gap_fill.mode),
+            Some(flv_fix::GapFillMode::Fill)
+        );
+        assert!(cfg.corrupt_tag_config.is_none());
+        assert_eq!(
+            cfg.gop_integrity_config
+                .map(|gop_integrity| gop_integrity.action),
+            Some(flv_fix::GopIntegrityAction::Quarantine)
+        );
+        assert_eq!(cfg.split_predicates.len(), 1);
     }
 }
@@ -10,6 +10,19 @@ use crate::utils::json::{self, JsonContext};
 pub const VALID_PROCESSORS: &[&str] = &[
     "remux",
     "rclone",
+    "s3",
+    "youtube",
+    "bilibili",
+    "webhook",
+    "ffmpeg",
+    "concat",
+    "danmu_chapters",
+    "danmu_subtitle",
+    "checksum",
+    "encrypt",
+    "torrent",
+    "nfo",
+    "highlights",
     "tdl",
     "telegram",
     "thumbnail",
@@ -0,0 +1,68 @@
+//! OAuth token database model.
+
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+/// OAuth token database model.
+/// An OAuth 2.0 grant for an upload account, referenced by name from
+/// processor configs.
+#[derive(Clone, FromRow, Serialize, Deserialize)]
+pub struct OAuthTokenDbModel {
+    pub id: String,
+    /// Unique account name, e.g. "main-channel".
+    pub name: String,
+    /// Token endpoint family, e.g. "google".
+    pub provider: String,
+    pub client_id: String,
+    pub client_secret: String,
+    pub refresh_token: String,
+    /// Cached access token.
+    pub access_token: Option<String>,
+    /// Unix epoch milliseconds (UTC) when the cached access token expires.
+    pub access_token_expires_at: Option<i64>,
+    /// Space-separated scopes granted by the user.
+    pub scope: Option<String>,
+    /// Unix epoch milliseconds (UTC) when created.
+    pub created_at: i64,
+    /// Unix epoch milliseconds (UTC) when last updated.
+    pub updated_at: i64,
+}
+
+impl OAuthTokenDbModel {
+    /// Create a new grant without a cached access token.
+    pub fn new(
+        name: impl Into<String>,
+        provider: impl Into<String>,
+        client_id: impl Into<String>,
+        client_secret: impl Into<String>,
+        refresh_token: impl Into<String>,
+    ) -> Self {
+        let now = crate::database::time::now_ms();
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            name: name.into(),
+            provider: provider.into(),
+            client_id: client_id.into(),
+            client_secret: client_secret.into(),
+            refresh_token: refresh_token.into(),
+            access_token: None,
+            access_token_expires_at: None,
+            scope: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
+
+impl std::fmt::Debug for OAuthTokenDbModel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OAuthTokenDbModel")
+            .field("id", &self.id)
+            .field("name", &self.name)
+            .field("provider", &self.provider)
+            .field("client_id", &self.client_id)
+            .field("access_token_expires_at", &self.access_token_expires_at)
+            .field("scope", &self.scope)
+            .finish_non_exhaustive()
+    }
+}
@@ -121,6 +121,22 @@ pub struct LiveSessionDbModel {
     pub danmu_statistics_id: Option<String>,
     #[serde(default)]
     pub total_size_bytes: i64,
+    /// Id of the engine used by the most recent download of the session.
+    #[serde(default)]
+    pub download_engine: Option<String>,
+    /// How the session was recorded: `live` or `backfill`.
+    #[serde(default = "default_session_kind")]
+    pub kind: String,
+}
+
+/// Kind of sessions recorded from the live stream.
+pub const SESSION_KIND_LIVE: &str = "live";
+/// Kind of sessions recorded from the platform replay of a broadcast that
+/// started before it was detected.
+pub const SESSION_KIND_BACKFILL: &str = "backfill";
+
+fn default_session_kind() -> String {
+    SESSION_KIND_LIVE.to_string()
 }
 
 impl LiveSessionDbModel {
@@ -133,6 +149,8 @@ impl LiveSessionDbModel {
             titles: Some("[]".to_string()),
             danmu_statistics_id: None,
             total_size_bytes: 0,
+            download_engine: None,
+            kind: SESSION_KIND_LIVE.to_string(),
         }
     }
 }
@@ -202,6 +220,41 @@ pub struct MediaOutputDbModel {
     pub created_at: i64,
 }
 
+/// Digest of a session file.
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct OutputChecksumDbModel {
+    pub id: String,
+    pub session_id: String,
+    pub file_path: String,
+    /// Hash algorithm: sha256, blake3
+    pub algorithm: String,
+    /// Lowercase hex digest.
+    pub digest: String,
+    pub size_bytes: i64,
+    /// Unix epoch milliseconds (UTC) when the digest was computed.
+    pub computed_at: i64,
+}
+
+impl OutputChecksumDbModel {
+    pub fn new(
+        session_id: impl Into<String>,
+        file_path: impl Into<String>,
+        algorithm: impl Into<String>,
+        digest: impl Into<String>,
+        size_bytes: i64,
+    ) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            session_id: session_id.into(),
+            file_path: file_path.into(),
+            algorithm: algorithm.into(),
+            digest: digest.into(),
+            size_bytes,
+            computed_at: crate::database::time::now_ms(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
 pub struct SessionSegmentDbModel {
     pub id: String,
@@ -212,6 +265,8 @@ pub struct SessionSegmentDbModel {
     pub size_bytes: i64,
     pub split_reason_code: Option<String>,
     pub split_reason_details_json: Option<String>,
+    /// Analysis report as JSON (see `flv_fix::AnalysisReport` and `hls_fix::AnalysisReport`).
+    pub analysis_report_json: Option<String>,
     pub created_at: Option<i64>,
     pub completed_at: Option<i64>,
     pub persisted_at: i64,
@@ -265,11 +320,17 @@ impl SessionSegmentDbModel {
             size_bytes,
             split_reason_code: split_reason.code,
             split_reason_details_json: split_reason.details_json,
+            analysis_report_json: None,
             created_at: lifecycle.created_at,
             completed_at: lifecycle.completed_at,
             persisted_at,
         }
     }
+
+    pub fn with_analysis_report(mut self, analysis_report_json: Option<String>) -> Self {
+        self.analysis_report_json = analysis_report_json;
+        self
+    }
 }
 
 impl MediaOutputDbModel {
@@ -346,6 +407,8 @@ pub struct DanmuStatisticsDbModel {
     pub top_talkers: Option<String>,
     /// JSON array of word-frequency entries
     pub word_frequency: Option<String>,
+    /// JSON array of activity bursts, see [`DanmuActivityBurstEntry`]
+    pub activity_bursts: Option<String>,
 }
 
 impl DanmuStatisticsDbModel {
@@ -357,6 +420,7 @@ impl DanmuStatisticsDbModel {
             danmu_rate_timeseries: Some("[]".to_string()),
             top_talkers: Some("[]".to_string()),
             word_frequency: Some("[]".to_string()),
+            activity_bursts: Some("[]".to_string()),
         }
     }
 }
@@ -377,6 +441,20 @@ pub struct DanmuRateEntry {
     pub count: i64,
 }
 
+/// Activity burst entry for danmu statistics.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DanmuActivityBurstEntry {
+    /// Unix epoch milliseconds (UTC).
+    pub start_ts: i64,
+    /// Unix epoch milliseconds (UTC).
+    pub end_ts: i64,
+    pub message_count: i64,
+    /// Peak messages per second over the detection window.
+    pub peak_rate: f64,
+    /// Baseline messages per second when the burst started.
+    pub baseline_rate: f64,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
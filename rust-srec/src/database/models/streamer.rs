@@ -14,6 +14,8 @@ pub struct StreamerDbModel {
     pub url: String,
     pub platform_config_id: String,
     pub template_config_id: Option<String>,
+    /// Group the streamer belongs to, if any.
+    pub group_id: Option<String>,
     /// Current operational state (NOT_LIVE, LIVE, OUT_OF_SCHEDULE, etc.)
     pub state: String,
     /// Priority level for resource allocation (HIGH, NORMAL, LOW)
@@ -50,6 +52,7 @@ impl StreamerDbModel {
             url: url.into(),
             platform_config_id: platform_config_id.into(),
             template_config_id: None,
+            group_id: None,
             state: StreamerState::NotLive.as_str().to_string(),
             priority: Priority::Normal.as_str().to_string(),
             avatar: None,
@@ -151,6 +154,14 @@ impl std::fmt::Display for StreamerState {
     }
 }
 
+/// Time at which a manually started recording of a streamer stops.
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct RecordingDeadlineDbModel {
+    pub streamer_id: String,
+    /// Unix epoch milliseconds (UTC).
+    pub stop_at: i64,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
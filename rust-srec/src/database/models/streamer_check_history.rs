@@ -134,11 +134,11 @@ impl From<&crate::domain::streamer::CheckRecord> for StreamerCheckHistoryDbModel
                 row.stream_selected = selected_stream.as_ref().map(serialize_summary);
                 if !candidates.is_empty() {
                     // Serialize the whole Vec in one pass — `SelectedStreamSummary`
-                    // derives `Serialize` and its on-the-wire shape (six fields,
-                    // no URL) matches the persisted column. Avoids the per-element
+                    // derives `Serialize` and its on-the-wire shape (no URL)
+                    // matches the persisted column. Avoids the per-element
                     // intermediate `serde_json::Value` tree the previous
                     // `json!`-macro path built. `unwrap_or_default()` is sound:
-                    // `Serialize` on a struct with `String`/numeric fields cannot
+                    // `Serialize` on a struct of strings, numbers and enums cannot
                     // fail.
                     row.streams_extracted_json =
                         Some(serde_json::to_string(candidates).unwrap_or_default());
@@ -171,7 +171,7 @@ impl From<&crate::domain::streamer::CheckRecord> for StreamerCheckHistoryDbModel
 /// `SelectedStreamSummary`'s derived `Serialize` directly — its struct
 /// shape is exactly the wire shape, so we skip building an intermediate
 /// `serde_json::Value`. The `unwrap_or_default()` is sound: serialization
-/// of a struct with `String` and numeric fields cannot fail.
+/// of a struct of strings, numbers and enums cannot fail.
 fn serialize_summary(s: &crate::domain::streamer::SelectedStreamSummary) -> String {
     serde_json::to_string(s).unwrap_or_default()
 }
@@ -0,0 +1,34 @@
+//! Streamer group database model.
+
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+/// Streamer group database model.
+/// A named set of streamers sharing a template and bulk actions.
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct StreamerGroupDbModel {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    /// Template adopted by every member of the group.
+    pub template_config_id: Option<String>,
+    /// Unix epoch milliseconds (UTC) when created.
+    pub created_at: i64,
+    /// Unix epoch milliseconds (UTC) when last updated.
+    pub updated_at: i64,
+}
+
+impl StreamerGroupDbModel {
+    /// Create a new group without a template.
+    pub fn new(name: impl Into<String>) -> Self {
+        let now = crate::database::time::now_ms();
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            name: name.into(),
+            description: None,
+            template_config_id: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
@@ -10,7 +10,9 @@ pub mod filter;
 pub mod job;
 pub mod monitor_outbox;
 pub mod notification;
+pub mod oauth_token;
 pub mod preset;
+pub mod recording_deadline;
 pub mod refresh_token;
 pub mod session;
 pub mod session_event;
@@ -18,6 +20,7 @@ pub mod session_lifecycle;
 pub mod session_tx;
 pub mod streamer;
 pub mod streamer_check_history;
+pub mod streamer_group;
 pub mod streamer_tx;
 pub mod user;
 
@@ -28,7 +31,9 @@ pub use filter::*;
 pub use job::*;
 pub use monitor_outbox::*;
 pub use notification::*;
+pub use oauth_token::*;
 pub use preset::*;
+pub use recording_deadline::*;
 pub use refresh_token::*;
 pub use session::*;
 pub use session_event::*;
@@ -36,5 +41,6 @@ pub use session_lifecycle::*;
 pub use session_tx::*;
 pub use streamer::*;
 pub use streamer_check_history::*;
+pub use streamer_group::*;
 pub use streamer_tx::*;
 pub use user::*;
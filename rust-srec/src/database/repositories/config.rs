@@ -122,7 +122,16 @@ impl ConfigRepository for SqlxConfigRepository {
                 pipeline_execute_timeout_secs = ?,
                 queue_freshness_threshold_ms = ?,
                 gpu_health_probe_interval_secs = ?,
-                stream_proxy_allow_private_targets = ?
+                stream_proxy_allow_private_targets = ?,
+                low_disk_space_threshold_bytes = ?,
+                low_disk_space_action = ?,
+                low_disk_space_secondary_folder = ?,
+                adaptive_polling = ?,
+                offline_grace_secs = ?,
+                load_admission_max_cpu_percent = ?,
+                load_admission_max_memory_percent = ?,
+                load_admission_max_io_pressure_percent = ?,
+                danmu_connection_config = ?
             WHERE id = ?
             "#,
         )
@@ -155,6 +164,15 @@ impl ConfigRepository for SqlxConfigRepository {
         .bind(config.queue_freshness_threshold_ms)
         .bind(config.gpu_health_probe_interval_secs)
         .bind(config.stream_proxy_allow_private_targets)
+        .bind(config.low_disk_space_threshold_bytes)
+        .bind(&config.low_disk_space_action)
+        .bind(&config.low_disk_space_secondary_folder)
+        .bind(config.adaptive_polling)
+        .bind(config.offline_grace_secs)
+        .bind(config.load_admission_max_cpu_percent)
+        .bind(config.load_admission_max_memory_percent)
+        .bind(config.load_admission_max_io_pressure_percent)
+        .bind(&config.danmu_connection_config)
         .bind(&config.id)
         .execute(&self.write_pool)
         .await?;
@@ -179,8 +197,17 @@ impl ConfigRepository for SqlxConfigRepository {
                 pipeline_execute_timeout_secs,
                 queue_freshness_threshold_ms,
                 gpu_health_probe_interval_secs,
-                stream_proxy_allow_private_targets
-            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                stream_proxy_allow_private_targets,
+                low_disk_space_threshold_bytes,
+                low_disk_space_action,
+                low_disk_space_secondary_folder,
+                adaptive_polling,
+                offline_grace_secs,
+                load_admission_max_cpu_percent,
+                load_admission_max_memory_percent,
+                load_admission_max_io_pressure_percent,
+                danmu_connection_config
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
             "#,
         )
         .bind(&config.id)
@@ -213,6 +240,15 @@ impl ConfigRepository for SqlxConfigRepository {
         .bind(config.queue_freshness_threshold_ms)
         .bind(config.gpu_health_probe_interval_secs)
         .bind(config.stream_proxy_allow_private_targets)
+        .bind(config.low_disk_space_threshold_bytes)
+        .bind(&config.low_disk_space_action)
+        .bind(&config.low_disk_space_secondary_folder)
+        .bind(config.adaptive_polling)
+        .bind(config.offline_grace_secs)
+        .bind(config.load_admission_max_cpu_percent)
+        .bind(config.load_admission_max_memory_percent)
+        .bind(config.load_admission_max_io_pressure_percent)
+        .bind(&config.danmu_connection_config)
         .execute(&self.write_pool)
         .await?;
         Ok(())
@@ -254,8 +290,8 @@ impl ConfigRepository for SqlxConfigRepository {
                 output_folder, output_filename_template, download_engine, stream_selection_config,
                 output_file_format, min_segment_size_bytes, max_download_duration_secs, max_part_size_bytes,
                 download_retry_policy, event_hooks, pipeline, session_complete_pipeline, paired_segment_pipeline,
-                offline_check_count, offline_check_delay_ms
-            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                offline_check_count, offline_check_delay_ms, rate_limit
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
             "#,
         )
         .bind(&config.id)
@@ -281,6 +317,7 @@ impl ConfigRepository for SqlxConfigRepository {
         .bind(&config.paired_segment_pipeline)
         .bind(config.offline_check_count)
         .bind(config.offline_check_delay_ms)
+        .bind(config.rate_limit)
         .execute(&self.write_pool)
         .await?;
         Ok(())
@@ -311,7 +348,8 @@ impl ConfigRepository for SqlxConfigRepository {
                 session_complete_pipeline = ?,
                 paired_segment_pipeline = ?,
                 offline_check_count = ?,
-                offline_check_delay_ms = ?
+                offline_check_delay_ms = ?,
+                rate_limit = ?
             WHERE id = ?
             "#,
         )
@@ -337,6 +375,7 @@ impl ConfigRepository for SqlxConfigRepository {
         .bind(&config.paired_segment_pipeline)
         .bind(config.offline_check_count)
         .bind(config.offline_check_delay_ms)
+        .bind(config.rate_limit)
         .bind(&config.id)
         .execute(&self.write_pool)
         .await?;
@@ -0,0 +1,162 @@
+//! OAuth token repository.
+
+use async_trait::async_trait;
+use sqlx::SqlitePool;
+
+use crate::database::models::OAuthTokenDbModel;
+use crate::{Error, Result};
+
+/// OAuth token repository trait.
+#[async_trait]
+pub trait OAuthTokenRepository: Send + Sync {
+    async fn get_token(&self, name: &str) -> Result<OAuthTokenDbModel>;
+    async fn list_tokens(&self) -> Result<Vec<OAuthTokenDbModel>>;
+    /// Insert a grant, or replace the grant with the same name.
+    async fn upsert_token(&self, token: &OAuthTokenDbModel) -> Result<()>;
+    /// Cache a refreshed access token.
+    async fn update_access_token(
+        &self,
+        name: &str,
+        access_token: &str,
+        expires_at: Option<i64>,
+    ) -> Result<()>;
+    async fn delete_token(&self, name: &str) -> Result<()>;
+}
+
+/// SQLx implementation of OAuthTokenRepository.
+pub struct SqlxOAuthTokenRepository {
+    pool: SqlitePool,
+    write_pool: SqlitePool,
+}
+
+impl SqlxOAuthTokenRepository {
+    pub fn new(pool: SqlitePool, write_pool: SqlitePool) -> Self {
+        Self { pool, write_pool }
+    }
+}
+
+#[async_trait]
+impl OAuthTokenRepository for SqlxOAuthTokenRepository {
+    async fn get_token(&self, name: &str) -> Result<OAuthTokenDbModel> {
+        sqlx::query_as::<_, OAuthTokenDbModel>("SELECT * FROM oauth_tokens WHERE name = ?")
+            .bind(name)
+            .fetch_optional(&self.pool)
+            .await?
+            .ok_or_else(|| Error::not_found("OAuth account", name))
+    }
+
+    async fn list_tokens(&self) -> Result<Vec<OAuthTokenDbModel>> {
+        let tokens =
+            sqlx::query_as::<_, OAuthTokenDbModel>("SELECT * FROM oauth_tokens ORDER BY name")
+                .fetch_all(&self.pool)
+                .await?;
+        Ok(tokens)
+    }
+
+    async fn upsert_token(&self, token: &OAuthTokenDbModel) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO oauth_tokens (
+                id, name, provider, client_id, client_secret, refresh_token,
+                access_token, access_token_expires_at, scope, created_at, updated_at
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            ON CONFLICT(name) DO UPDATE SET
+                provider = excluded.provider,
+                client_id = excluded.client_id,
+                client_secret = excluded.client_secret,
+                refresh_token = excluded.refresh_token,
+                access_token = excluded.access_token,
+                access_token_expires_at = excluded.access_token_expires_at,
+                scope = excluded.scope,
+                updated_at = excluded.updated_at
+            "#,
+        )
+        .bind(&token.id)
+        .bind(&token.name)
+        .bind(&token.provider)
+        .bind(&token.client_id)
+        .bind(&token.client_secret)
+        .bind(&token.refresh_token)
+        .bind(&token.access_token)
+        .bind(token.access_token_expires_at)
+        .bind(&token.scope)
+        .bind(token.created_at)
+        .bind(token.updated_at)
+        .execute(&self.write_pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn update_access_token(
+        &self,
+        name: &str,
+        access_token: &str,
+        expires_at: Option<i64>,
+    ) -> Result<()> {
+        sqlx::query(
+            r#"
+            UPDATE oauth_tokens SET
+                access_token = ?,
+                access_token_expires_at = ?,
+                updated_at = ?
+            WHERE name = ?
+            "#,
+        )
+        .bind(access_token)
+        .bind(expires_at)
+        .bind(crate::database::time::now_ms())
+        .bind(name)
+        .execute(&self.write_pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn delete_token(&self, name: &str) -> Result<()> {
+        sqlx::query("DELETE FROM oauth_tokens WHERE name = ?")
+            .bind(name)
+            .execute(&self.write_pool)
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::{init_pool_with_size, run_migrations};
+
+    async fn setup_test_pool() -> SqlitePool {
+        let pool = init_pool_with_size("sqlite::memory:", 1).await.unwrap();
+        run_migrations(&pool).await.unwrap();
+        pool
+    }
+
+    #[tokio::test]
+    async fn test_upsert_replaces_grant_by_name() {
+        let pool = setup_test_pool().await;
+        let repo = SqlxOAuthTokenRepository::new(pool.clone(), pool);
+
+        let token = OAuthTokenDbModel::new("channel", "google", "client", "secret", "refresh-1");
+        repo.upsert_token(&token).await.unwrap();
+        repo.update_access_token("channel", "access-1", Some(1_000))
+            .await
+            .unwrap();
+
+        let stored = repo.get_token("channel").await.unwrap();
+        assert_eq!(stored.access_token.as_deref(), Some("access-1"));
+        assert_eq!(stored.access_token_expires_at, Some(1_000));
+
+        let replacement =
+            OAuthTokenDbModel::new("channel", "google", "client", "secret", "refresh-2");
+        repo.upsert_token(&replacement).await.unwrap();
+
+        let tokens = repo.list_tokens().await.unwrap();
+        assert_eq!(tokens.len(), 1);
+        assert_eq!(tokens[0].id, token.id);
+        assert_eq!(tokens[0].refresh_token, "refresh-2");
+        assert_eq!(tokens[0].access_token, None);
+
+        repo.delete_token("channel").await.unwrap();
+        assert!(repo.get_token("channel").await.is_err());
+    }
+}
@@ -0,0 +1,61 @@
+//! Recording deadline repository.
+
+use async_trait::async_trait;
+use sqlx::SqlitePool;
+
+use crate::Result;
+use crate::database::models::RecordingDeadlineDbModel;
+
+/// Recording deadline repository trait.
+#[async_trait]
+pub trait RecordingDeadlineRepository: Send + Sync {
+    async fn upsert_deadline(&self, deadline: &RecordingDeadlineDbModel) -> Result<()>;
+    async fn delete_deadline(&self, streamer_id: &str) -> Result<()>;
+    async fn list_deadlines(&self) -> Result<Vec<RecordingDeadlineDbModel>>;
+}
+
+/// SQLx implementation of RecordingDeadlineRepository.
+pub struct SqlxRecordingDeadlineRepository {
+    pool: SqlitePool,
+    write_pool: SqlitePool,
+}
+
+impl SqlxRecordingDeadlineRepository {
+    pub fn new(pool: SqlitePool, write_pool: SqlitePool) -> Self {
+        Self { pool, write_pool }
+    }
+}
+
+#[async_trait]
+impl RecordingDeadlineRepository for SqlxRecordingDeadlineRepository {
+    async fn upsert_deadline(&self, deadline: &RecordingDeadlineDbModel) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO recording_deadlines (streamer_id, stop_at) VALUES (?, ?)
+            ON CONFLICT(streamer_id) DO UPDATE SET stop_at = excluded.stop_at
+            "#,
+        )
+        .bind(&deadline.streamer_id)
+        .bind(deadline.stop_at)
+        .execute(&self.write_pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn delete_deadline(&self, streamer_id: &str) -> Result<()> {
+        sqlx::query("DELETE FROM recording_deadlines WHERE streamer_id = ?")
+            .bind(streamer_id)
+            .execute(&self.write_pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn list_deadlines(&self) -> Result<Vec<RecordingDeadlineDbModel>> {
+        let deadlines = sqlx::query_as::<_, RecordingDeadlineDbModel>(
+            "SELECT * FROM recording_deadlines ORDER BY stop_at",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(deadlines)
+    }
+}
@@ -5,8 +5,8 @@ use sqlx::SqlitePool;
 use tracing::warn;
 
 use crate::database::models::{
-    DanmuStatisticsDbModel, LiveSessionDbModel, MediaOutputDbModel, OutputFilters, Pagination,
-    SessionFilters, SessionSegmentDbModel,
+    DanmuStatisticsDbModel, LiveSessionDbModel, MediaOutputDbModel, OutputChecksumDbModel,
+    OutputFilters, Pagination, SessionFilters, SessionSegmentDbModel,
 };
 use crate::database::retry::retry_on_sqlite_busy;
 use crate::{Error, Result};
@@ -25,10 +25,15 @@ pub trait SessionRepository: Send + Sync {
         streamer_id: &str,
         limit: i32,
     ) -> Result<Vec<LiveSessionDbModel>>;
+    /// `(streamer_id, start_time)` of every session started at or after
+    /// `since_ms`.
+    async fn list_session_start_times_since(&self, since_ms: i64) -> Result<Vec<(String, i64)>>;
     async fn create_session(&self, session: &LiveSessionDbModel) -> Result<()>;
     async fn end_session(&self, id: &str, end_time: i64) -> Result<()>;
     async fn resume_session(&self, id: &str) -> Result<()>;
     async fn update_session_titles(&self, id: &str, titles: &str) -> Result<()>;
+    /// Record the engine that is downloading the session.
+    async fn set_session_download_engine(&self, id: &str, engine_id: &str) -> Result<()>;
     async fn delete_session(&self, id: &str) -> Result<()>;
     async fn delete_sessions_batch(&self, ids: &[String]) -> Result<u64>;
 
@@ -75,6 +80,14 @@ pub trait SessionRepository: Send + Sync {
     ) -> Result<Vec<SessionSegmentDbModel>>;
     async fn next_session_segment_index(&self, session_id: &str) -> Result<u32>;
 
+    // Output checksums
+    /// Insert or replace the digest of a file for one algorithm.
+    async fn upsert_output_checksum(&self, checksum: &OutputChecksumDbModel) -> Result<()>;
+    async fn list_output_checksums_for_session(
+        &self,
+        session_id: &str,
+    ) -> Result<Vec<OutputChecksumDbModel>>;
+
     // Danmu Statistics
     async fn get_danmu_statistics(
         &self,
@@ -89,6 +102,7 @@ pub trait SessionRepository: Send + Sync {
         danmu_rate_timeseries: Option<&str>,
         top_talkers: Option<&str>,
         word_frequency: Option<&str>,
+        activity_bursts: Option<&str>,
     ) -> Result<()>;
 }
 
@@ -142,12 +156,22 @@ impl SessionRepository for SqlxSessionRepository {
         Ok(sessions)
     }
 
+    async fn list_session_start_times_since(&self, since_ms: i64) -> Result<Vec<(String, i64)>> {
+        let rows = sqlx::query_as::<_, (String, i64)>(
+            "SELECT streamer_id, start_time FROM live_sessions WHERE start_time >= ?",
+        )
+        .bind(since_ms)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows)
+    }
+
     async fn create_session(&self, session: &LiveSessionDbModel) -> Result<()> {
         retry_on_sqlite_busy("create_session", || async {
             sqlx::query(
                 r#"
-                INSERT INTO live_sessions (id, streamer_id, start_time, end_time, titles, danmu_statistics_id, total_size_bytes)
-                VALUES (?, ?, ?, ?, ?, ?, ?)
+                INSERT INTO live_sessions (id, streamer_id, start_time, end_time, titles, danmu_statistics_id, total_size_bytes, kind)
+                VALUES (?, ?, ?, ?, ?, ?, ?, ?)
                 "#,
             )
             .bind(&session.id)
@@ -157,6 +181,7 @@ impl SessionRepository for SqlxSessionRepository {
             .bind(&session.titles)
             .bind(&session.danmu_statistics_id)
             .bind(session.total_size_bytes)
+            .bind(&session.kind)
             .execute(&self.write_pool)
             .await?;
             Ok(())
@@ -207,6 +232,18 @@ impl SessionRepository for SqlxSessionRepository {
         .await
     }
 
+    async fn set_session_download_engine(&self, id: &str, engine_id: &str) -> Result<()> {
+        retry_on_sqlite_busy("set_session_download_engine", || async {
+            sqlx::query("UPDATE live_sessions SET download_engine = ? WHERE id = ?")
+                .bind(engine_id)
+                .bind(id)
+                .execute(&self.write_pool)
+                .await?;
+            Ok(())
+        })
+        .await
+    }
+
     async fn delete_session(&self, id: &str) -> Result<()> {
         retry_on_sqlite_busy("delete_session", || async {
             sqlx::query("DELETE FROM live_sessions WHERE id = ?")
@@ -334,10 +371,11 @@ impl SessionRepository for SqlxSessionRepository {
                         size_bytes,
                         split_reason_code,
                         split_reason_details_json,
+                        analysis_report_json,
                         created_at,
                         completed_at,
                         persisted_at
-                    ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                    ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
                     "#,
                 )
                 .bind(&segment.id)
@@ -348,6 +386,7 @@ impl SessionRepository for SqlxSessionRepository {
                 .bind(segment.size_bytes)
                 .bind(&segment.split_reason_code)
                 .bind(&segment.split_reason_details_json)
+                .bind(&segment.analysis_report_json)
                 .bind(segment.created_at)
                 .bind(segment.completed_at)
                 .bind(segment.persisted_at)
@@ -430,6 +469,46 @@ impl SessionRepository for SqlxSessionRepository {
         })
     }
 
+    async fn upsert_output_checksum(&self, checksum: &OutputChecksumDbModel) -> Result<()> {
+        retry_on_sqlite_busy("upsert_output_checksum", || async {
+            sqlx::query(
+                r#"
+                INSERT INTO output_checksums (id, session_id, file_path, algorithm, digest, size_bytes, computed_at)
+                VALUES (?, ?, ?, ?, ?, ?, ?)
+                ON CONFLICT(file_path, algorithm) DO UPDATE SET
+                    session_id = excluded.session_id,
+                    digest = excluded.digest,
+                    size_bytes = excluded.size_bytes,
+                    computed_at = excluded.computed_at
+                "#,
+            )
+            .bind(&checksum.id)
+            .bind(&checksum.session_id)
+            .bind(&checksum.file_path)
+            .bind(&checksum.algorithm)
+            .bind(&checksum.digest)
+            .bind(checksum.size_bytes)
+            .bind(checksum.computed_at)
+            .execute(&self.write_pool)
+            .await?;
+            Ok(())
+        })
+        .await
+    }
+
+    async fn list_output_checksums_for_session(
+        &self,
+        session_id: &str,
+    ) -> Result<Vec<OutputChecksumDbModel>> {
+        let checksums = sqlx::query_as::<_, OutputChecksumDbModel>(
+            "SELECT * FROM output_checksums WHERE session_id = ? ORDER BY file_path, algorithm",
+        )
+        .bind(session_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(checksums)
+    }
+
     async fn delete_media_output(&self, id: &str) -> Result<()> {
         // Get output info before deletion to update session size
         let output = self.get_media_output(id).await?;
@@ -494,8 +573,8 @@ impl SessionRepository for SqlxSessionRepository {
         retry_on_sqlite_busy("create_danmu_statistics", || async {
             sqlx::query(
                 r#"
-                INSERT INTO danmu_statistics (id, session_id, total_danmus, danmu_rate_timeseries, top_talkers, word_frequency)
-                VALUES (?, ?, ?, ?, ?, ?)
+                INSERT INTO danmu_statistics (id, session_id, total_danmus, danmu_rate_timeseries, top_talkers, word_frequency, activity_bursts)
+                VALUES (?, ?, ?, ?, ?, ?, ?)
                 "#,
             )
             .bind(&stats.id)
@@ -504,6 +583,7 @@ impl SessionRepository for SqlxSessionRepository {
             .bind(&stats.danmu_rate_timeseries)
             .bind(&stats.top_talkers)
             .bind(&stats.word_frequency)
+            .bind(&stats.activity_bursts)
             .execute(&self.write_pool)
             .await?;
             Ok(())
@@ -519,7 +599,8 @@ impl SessionRepository for SqlxSessionRepository {
                     total_danmus = ?,
                     danmu_rate_timeseries = ?,
                     top_talkers = ?,
-                    word_frequency = ?
+                    word_frequency = ?,
+                    activity_bursts = ?
                 WHERE id = ?
                 "#,
             )
@@ -527,6 +608,7 @@ impl SessionRepository for SqlxSessionRepository {
             .bind(&stats.danmu_rate_timeseries)
             .bind(&stats.top_talkers)
             .bind(&stats.word_frequency)
+            .bind(&stats.activity_bursts)
             .bind(&stats.id)
             .execute(&self.write_pool)
             .await?;
@@ -542,17 +624,19 @@ impl SessionRepository for SqlxSessionRepository {
         danmu_rate_timeseries: Option<&str>,
         top_talkers: Option<&str>,
         word_frequency: Option<&str>,
+        activity_bursts: Option<&str>,
     ) -> Result<()> {
         retry_on_sqlite_busy("upsert_danmu_statistics", || async {
             sqlx::query(
                 r#"
-                INSERT INTO danmu_statistics (id, session_id, total_danmus, danmu_rate_timeseries, top_talkers, word_frequency)
-                VALUES (?, ?, ?, ?, ?, ?)
+                INSERT INTO danmu_statistics (id, session_id, total_danmus, danmu_rate_timeseries, top_talkers, word_frequency, activity_bursts)
+                VALUES (?, ?, ?, ?, ?, ?, ?)
                 ON CONFLICT(session_id) DO UPDATE SET
                     total_danmus = excluded.total_danmus,
                     danmu_rate_timeseries = excluded.danmu_rate_timeseries,
                     top_talkers = excluded.top_talkers,
-                    word_frequency = excluded.word_frequency
+                    word_frequency = excluded.word_frequency,
+                    activity_bursts = excluded.activity_bursts
                 "#,
             )
             .bind(uuid::Uuid::new_v4().to_string())
@@ -561,6 +645,7 @@ impl SessionRepository for SqlxSessionRepository {
             .bind(danmu_rate_timeseries)
             .bind(top_talkers)
             .bind(word_frequency)
+            .bind(activity_bursts)
             .execute(&self.write_pool)
             .await?;
             Ok(())
@@ -854,6 +939,65 @@ mod tests {
         assert!(saved.persisted_at >= 1_700_000_000_000);
     }
 
+    #[tokio::test]
+    async fn list_session_start_times_since_skips_older_sessions() {
+        let repo = setup_test_repo().await;
+        let mut old = LiveSessionDbModel::new("streamer-1");
+        old.id = "session-old".to_string();
+        old.start_time = 1_000;
+        old.end_time = Some(1_500);
+        repo.create_session(&old).await.unwrap();
+
+        let starts = repo.list_session_start_times_since(2_000).await.unwrap();
+
+        assert_eq!(starts.len(), 1);
+        assert_eq!(starts[0].0, "streamer-1");
+        assert!(starts[0].1 >= 2_000);
+    }
+
+    #[tokio::test]
+    async fn ended_backfill_session_coexists_with_the_active_session() {
+        let repo = setup_test_repo().await;
+        let mut backfill = LiveSessionDbModel::new("streamer-1");
+        backfill.id = "session-backfill".to_string();
+        backfill.start_time = 1_000;
+        backfill.end_time = Some(2_000);
+        backfill.kind = crate::database::models::SESSION_KIND_BACKFILL.to_string();
+        repo.create_session(&backfill).await.unwrap();
+
+        let saved = repo.get_session("session-backfill").await.unwrap();
+        assert_eq!(saved.kind, "backfill");
+        let live = repo.get_session("session-1").await.unwrap();
+        assert_eq!(live.kind, "live");
+    }
+
+    #[tokio::test]
+    async fn upsert_output_checksum_replaces_digest_per_algorithm() {
+        let repo = setup_test_repo().await;
+
+        for (algorithm, digest) in [("sha256", "old"), ("blake3", "b3"), ("sha256", "new")] {
+            repo.upsert_output_checksum(&OutputChecksumDbModel::new(
+                "session-1",
+                "/rec/a.flv",
+                algorithm,
+                digest,
+                3,
+            ))
+            .await
+            .unwrap();
+        }
+
+        let checksums = repo
+            .list_output_checksums_for_session("session-1")
+            .await
+            .unwrap();
+        let digests: Vec<(&str, &str)> = checksums
+            .iter()
+            .map(|c| (c.algorithm.as_str(), c.digest.as_str()))
+            .collect();
+        assert_eq!(digests, vec![("blake3", "b3"), ("sha256", "new")]);
+    }
+
     #[tokio::test]
     async fn next_session_segment_index_returns_zero_without_segments() {
         let repo = setup_test_repo().await;
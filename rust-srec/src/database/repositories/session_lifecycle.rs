@@ -53,6 +53,8 @@ pub struct StartSessionInputs {
     pub streams: Vec<StreamInfo>,
     pub media_headers: Option<HashMap<String, String>>,
     pub media_extras: Option<HashMap<String, String>>,
+    /// When the broadcast started according to the platform, if it reports it.
+    pub broadcast_started_at: Option<DateTime<Utc>>,
     /// Reference time for the session `start_time` and event `timestamp`.
     pub now: DateTime<Utc>,
 }
@@ -336,6 +338,7 @@ impl SessionLifecycleRepository {
             streams: inputs.streams.clone(),
             media_headers: inputs.media_headers.clone(),
             media_extras: inputs.media_extras.clone(),
+            broadcast_started_at: inputs.broadcast_started_at,
             timestamp: inputs.now,
         };
         MonitorOutboxTxOps::enqueue_event(&mut tx, &inputs.streamer_id, &event).await?;
@@ -713,6 +716,7 @@ mod tests {
             streams: vec![],
             media_headers: None,
             media_extras: None,
+            broadcast_started_at: None,
             now,
         }
     }
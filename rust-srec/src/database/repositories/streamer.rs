@@ -148,11 +148,11 @@ impl StreamerRepository for SqlxStreamerRepository {
         let result = sqlx::query(
             r#"
             INSERT INTO streamers (
-                id, name, url, platform_config_id, template_config_id,
+                id, name, url, platform_config_id, template_config_id, group_id,
                 state, priority, avatar, last_live_time, streamer_specific_config,
                 consecutive_error_count, disabled_until, last_error,
                 created_at, updated_at
-            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
             "#,
         )
         .bind(&streamer.id)
@@ -160,6 +160,7 @@ impl StreamerRepository for SqlxStreamerRepository {
         .bind(&streamer.url)
         .bind(&streamer.platform_config_id)
         .bind(&streamer.template_config_id)
+        .bind(&streamer.group_id)
         .bind(&streamer.state)
         .bind(&streamer.priority)
         .bind(&streamer.avatar)
@@ -190,6 +191,7 @@ impl StreamerRepository for SqlxStreamerRepository {
                 url = ?,
                 platform_config_id = ?,
                 template_config_id = ?,
+                group_id = ?,
                 state = ?,
                 priority = ?,
                 avatar = ?,
@@ -206,6 +208,7 @@ impl StreamerRepository for SqlxStreamerRepository {
         .bind(&streamer.url)
         .bind(&streamer.platform_config_id)
         .bind(&streamer.template_config_id)
+        .bind(&streamer.group_id)
         .bind(&streamer.state)
         .bind(&streamer.priority)
         .bind(&streamer.avatar)
@@ -0,0 +1,149 @@
+//! Streamer group repository.
+
+use async_trait::async_trait;
+use sqlx::SqlitePool;
+
+use crate::database::models::StreamerGroupDbModel;
+use crate::{Error, Result};
+
+/// Streamer group repository trait.
+#[async_trait]
+pub trait StreamerGroupRepository: Send + Sync {
+    async fn get_group(&self, id: &str) -> Result<StreamerGroupDbModel>;
+    async fn get_group_by_name(&self, name: &str) -> Result<StreamerGroupDbModel>;
+    async fn list_groups(&self) -> Result<Vec<StreamerGroupDbModel>>;
+    async fn create_group(&self, group: &StreamerGroupDbModel) -> Result<()>;
+    async fn update_group(&self, group: &StreamerGroupDbModel) -> Result<()>;
+    async fn delete_group(&self, id: &str) -> Result<()>;
+}
+
+/// SQLx implementation of StreamerGroupRepository.
+pub struct SqlxStreamerGroupRepository {
+    pool: SqlitePool,
+    write_pool: SqlitePool,
+}
+
+impl SqlxStreamerGroupRepository {
+    pub fn new(pool: SqlitePool, write_pool: SqlitePool) -> Self {
+        Self { pool, write_pool }
+    }
+}
+
+#[async_trait]
+impl StreamerGroupRepository for SqlxStreamerGroupRepository {
+    async fn get_group(&self, id: &str) -> Result<StreamerGroupDbModel> {
+        sqlx::query_as::<_, StreamerGroupDbModel>("SELECT * FROM streamer_groups WHERE id = ?")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?
+            .ok_or_else(|| Error::not_found("Streamer group", id))
+    }
+
+    async fn get_group_by_name(&self, name: &str) -> Result<StreamerGroupDbModel> {
+        sqlx::query_as::<_, StreamerGroupDbModel>("SELECT * FROM streamer_groups WHERE name = ?")
+            .bind(name)
+            .fetch_optional(&self.pool)
+            .await?
+            .ok_or_else(|| Error::not_found("Streamer group", name))
+    }
+
+    async fn list_groups(&self) -> Result<Vec<StreamerGroupDbModel>> {
+        let groups = sqlx::query_as::<_, StreamerGroupDbModel>(
+            "SELECT * FROM streamer_groups ORDER BY name",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(groups)
+    }
+
+    async fn create_group(&self, group: &StreamerGroupDbModel) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO streamer_groups (
+                id, name, description, template_config_id, created_at, updated_at
+            ) VALUES (?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(&group.id)
+        .bind(&group.name)
+        .bind(&group.description)
+        .bind(&group.template_config_id)
+        .bind(group.created_at)
+        .bind(group.updated_at)
+        .execute(&self.write_pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn update_group(&self, group: &StreamerGroupDbModel) -> Result<()> {
+        sqlx::query(
+            r#"
+            UPDATE streamer_groups SET
+                name = ?,
+                description = ?,
+                template_config_id = ?,
+                updated_at = ?
+            WHERE id = ?
+            "#,
+        )
+        .bind(&group.name)
+        .bind(&group.description)
+        .bind(&group.template_config_id)
+        .bind(group.updated_at)
+        .bind(&group.id)
+        .execute(&self.write_pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn delete_group(&self, id: &str) -> Result<()> {
+        sqlx::query("DELETE FROM streamer_groups WHERE id = ?")
+            .bind(id)
+            .execute(&self.write_pool)
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::models::StreamerDbModel;
+    use crate::database::repositories::{SqlxStreamerRepository, StreamerRepository as _};
+    use crate::database::{init_pool_with_size, run_migrations};
+
+    async fn setup_test_pool() -> SqlitePool {
+        let pool = init_pool_with_size("sqlite::memory:", 1).await.unwrap();
+        run_migrations(&pool).await.unwrap();
+        pool
+    }
+
+    #[tokio::test]
+    async fn test_deleting_group_detaches_members() {
+        let pool = setup_test_pool().await;
+        let groups = SqlxStreamerGroupRepository::new(pool.clone(), pool.clone());
+        let streamers = SqlxStreamerRepository::new(pool.clone(), pool.clone());
+
+        let group = StreamerGroupDbModel::new("weekend");
+        groups.create_group(&group).await.unwrap();
+        assert_eq!(
+            groups.get_group_by_name("weekend").await.unwrap().id,
+            group.id
+        );
+
+        let platform_id: String = sqlx::query_scalar("SELECT id FROM platform_config LIMIT 1")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        let mut streamer =
+            StreamerDbModel::new("member", "https://example.com/live/member", platform_id);
+        streamer.group_id = Some(group.id.clone());
+        streamers.create_streamer(&streamer).await.unwrap();
+
+        groups.delete_group(&group.id).await.unwrap();
+
+        assert!(groups.get_group(&group.id).await.is_err());
+        let streamer = streamers.get_streamer(&streamer.id).await.unwrap();
+        assert_eq!(streamer.group_id, None);
+    }
+}
@@ -1,6 +1,7 @@
 //! Domain representation of one monitor poll outcome.
 
 use chrono::{DateTime, Duration, Utc};
+use media_types::StreamDescription;
 use serde::{Deserialize, Serialize};
 
 /// Maximum length in bytes of a persisted transient-error message.
@@ -39,6 +40,9 @@ pub struct SelectedStreamSummary {
     pub bitrate: u64,
     pub codec: String,
     pub fps: f64,
+    /// Codecs parsed from `codec` and `fps`; omitted when nothing was recognised
+    #[serde(default, skip_serializing_if = "StreamDescription::is_empty")]
+    pub description: StreamDescription,
 }
 
 /// Reason a filtered observation was produced.
@@ -8,7 +8,10 @@
 //! - Supporting priority-based download scheduling
 //! - Stream selection based on quality, format, and CDN preferences
 
+pub(crate) mod cdn_probe;
+pub(crate) mod disk_space;
 pub mod engine;
+pub(crate) mod load_admission;
 
 mod manager;
 pub(crate) mod output_root_gate;
@@ -16,10 +19,13 @@ pub(crate) mod queue;
 mod resilience;
 mod stream_selector;
 
+pub use cdn_probe::ProbeOutcome;
+pub use disk_space::{DiskSpacePolicy, LowDiskSpaceAction};
 pub use engine::{
     DownloadConfig, DownloadEngine, DownloadFailureKind, DownloadHandle, DownloadInfo,
     DownloadProtocol, IoErrorKindSer, SegmentEvent, SegmentInfo,
 };
+pub use load_admission::LoadAdmissionPolicy;
 
 pub use output_root_gate::{
     DEFAULT_GATE_COOLDOWN_SECS, GateBlocked, LAST_ERROR_GATE_PREFIX, OutputRootGate, RecoveryHook,
@@ -29,7 +35,8 @@ pub use output_root_gate::{
 pub use manager::{
     ConfigUpdateType, DownloadManager, DownloadManagerConfig, DownloadManagerEvent,
     DownloadProgressEvent, DownloadRejectedKind, DownloadStopCause, DownloadTerminalEvent,
-    EngineEndSignal, EngineHandle, PreflightRequest,
+    EngineEndSignal, EngineHandle, LowDiskSpaceVolume, PausedDownload, PreflightRequest,
+    RECORDING_DEADLINE_CHECK_INTERVAL,
 };
 pub use queue::{
     AcquireError, AcquireRequest, ActiveSlot, DownloadQueue, PendingEntry, Priority, SlotGuard,
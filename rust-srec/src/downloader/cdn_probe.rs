@@ -0,0 +1,207 @@
+//! Probing CDN candidates before a download starts.
+//!
+//! Platforms rank their CDNs by their own criteria, which often favors a CDN
+//! far from the recorder. The probe requests every resolved candidate and
+//! times the first byte of the response, so [`StreamSelector::rank_by_probe`]
+//! can move unreachable or slow CDNs behind faster ones.
+//!
+//! [`StreamSelector::rank_by_probe`]: super::StreamSelector::rank_by_probe
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use futures::future::join_all;
+use platforms_parser::media::StreamInfo;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use tracing::debug;
+
+/// Time a candidate gets to answer when the config sets no timeout.
+pub const DEFAULT_PROBE_TIMEOUT: Duration = Duration::from_millis(1500);
+
+/// Result of probing one candidate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProbeOutcome {
+    /// The CDN answered with a success status; the duration is the time to
+    /// the first body byte.
+    Reachable(Duration),
+    /// The request failed, timed out, or returned an error status.
+    Unreachable,
+}
+
+impl ProbeOutcome {
+    pub fn latency(&self) -> Option<Duration> {
+        match self {
+            Self::Reachable(latency) => Some(*latency),
+            Self::Unreachable => None,
+        }
+    }
+}
+
+/// Probe every stream concurrently. Outcomes are in the order of `streams`.
+pub async fn probe_streams(
+    client: &reqwest::Client,
+    streams: &[StreamInfo],
+    media_headers: Option<&HashMap<String, String>>,
+    timeout: Duration,
+) -> Vec<ProbeOutcome> {
+    join_all(
+        streams
+            .iter()
+            .map(|stream| probe_stream(client, stream, media_headers, timeout)),
+    )
+    .await
+}
+
+/// Time the first body byte of `stream`, giving up after `timeout`.
+///
+/// Live streams never end, so the response is dropped after the first chunk.
+pub async fn probe_stream(
+    client: &reqwest::Client,
+    stream: &StreamInfo,
+    media_headers: Option<&HashMap<String, String>>,
+    timeout: Duration,
+) -> ProbeOutcome {
+    let started = Instant::now();
+    let request = client
+        .get(&stream.url)
+        .headers(request_headers(stream, media_headers));
+
+    let first_byte = async {
+        let mut response = request.send().await?.error_for_status()?;
+        response.chunk().await
+    };
+
+    match tokio::time::timeout(timeout, first_byte).await {
+        Ok(Ok(Some(_))) => ProbeOutcome::Reachable(started.elapsed()),
+        Ok(Ok(None)) => {
+            debug!(url = %stream.url, "CDN probe got an empty body");
+            ProbeOutcome::Unreachable
+        }
+        Ok(Err(e)) => {
+            debug!(url = %stream.url, error = %e, "CDN probe failed");
+            ProbeOutcome::Unreachable
+        }
+        Err(_) => {
+            debug!(url = %stream.url, timeout_ms = timeout.as_millis(), "CDN probe timed out");
+            ProbeOutcome::Unreachable
+        }
+    }
+}
+
+/// Headers a download of `stream` would send: the media headers, then the
+/// stream's own `headers` and `host_header` extras.
+fn request_headers(
+    stream: &StreamInfo,
+    media_headers: Option<&HashMap<String, String>>,
+) -> HeaderMap {
+    let extras = stream.extras.as_ref();
+    let stream_headers = extras
+        .and_then(|e| e.get("headers"))
+        .and_then(|v| v.as_object())
+        .into_iter()
+        .flatten()
+        .filter_map(|(k, v)| Some((k.as_str(), v.as_str()?)));
+    let host_header = extras
+        .and_then(|e| e.get("host_header"))
+        .and_then(|v| v.as_str())
+        .map(|host| ("Host", host));
+
+    let mut headers = HeaderMap::new();
+    for (name, value) in media_headers
+        .into_iter()
+        .flatten()
+        .map(|(k, v)| (k.as_str(), v.as_str()))
+        .chain(stream_headers)
+        .chain(host_header)
+    {
+        if let (Ok(name), Ok(value)) = (
+            HeaderName::from_bytes(name.as_bytes()),
+            HeaderValue::from_str(value),
+        ) {
+            headers.insert(name, value);
+        }
+    }
+    headers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use axum::Router;
+    use axum::http::{HeaderMap as AxumHeaderMap, StatusCode};
+    use axum::routing::get;
+    use platforms_parser::media::{StreamFormat, formats::MediaFormat};
+    use tokio::net::TcpListener;
+
+    fn stream(url: String) -> StreamInfo {
+        StreamInfo {
+            url,
+            stream_format: StreamFormat::Flv,
+            media_format: MediaFormat::Flv,
+            quality: "1080p".to_string(),
+            bitrate: 0,
+            priority: 0,
+            extras: Some(
+                serde_json::json!({ "headers": { "Referer": "https://live.example.com/" } }),
+            ),
+            codec: "avc".to_string(),
+            fps: 30.0,
+            is_headers_needed: true,
+            is_audio_only: false,
+        }
+    }
+
+    async fn serve(app: Router) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+        format!("http://{addr}")
+    }
+
+    #[tokio::test]
+    async fn probe_times_healthy_cdns_and_flags_failing_ones() {
+        let base = serve(
+            Router::new()
+                .route(
+                    "/ok.flv",
+                    get(|headers: AxumHeaderMap| async move {
+                        if headers.get("referer").is_some() {
+                            (StatusCode::OK, "FLV")
+                        } else {
+                            (StatusCode::FORBIDDEN, "")
+                        }
+                    }),
+                )
+                .route(
+                    "/slow.flv",
+                    get(|| async {
+                        tokio::time::sleep(Duration::from_secs(5)).await;
+                        "FLV"
+                    }),
+                )
+                .route("/gone.flv", get(|| async { StatusCode::NOT_FOUND })),
+        )
+        .await;
+
+        let streams = vec![
+            stream(format!("{base}/ok.flv")),
+            stream(format!("{base}/slow.flv")),
+            stream(format!("{base}/gone.flv")),
+        ];
+        crate::utils::http_client::install_rustls_provider();
+        let outcomes = probe_streams(
+            &reqwest::Client::new(),
+            &streams,
+            None,
+            Duration::from_millis(300),
+        )
+        .await;
+
+        assert!(matches!(outcomes[0], ProbeOutcome::Reachable(_)));
+        assert_eq!(outcomes[1], ProbeOutcome::Unreachable);
+        assert_eq!(outcomes[2], ProbeOutcome::Unreachable);
+    }
+}
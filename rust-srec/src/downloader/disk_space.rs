@@ -0,0 +1,241 @@
+//! Free-space policy for output volumes.
+//!
+//! Without a policy, a full volume surfaces as `StorageFull` write errors in
+//! the middle of a recording. The policy acts before that: once free space on
+//! the volume of an output directory drops below the configured threshold,
+//! the [`LowDiskSpaceAction`] decides whether new downloads are refused,
+//! active downloads are finalized, or both are moved to a secondary folder.
+
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+/// How often active downloads are checked against the policy. Streamers
+/// refused by the policy retry after the same delay.
+pub const DISK_SPACE_CHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+/// What happens once an output volume falls below the free-space threshold.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LowDiskSpaceAction {
+    /// Refuse new downloads on the volume; active downloads keep writing.
+    #[default]
+    StopNew,
+    /// Refuse new downloads and finalize the active ones on the volume.
+    Finalize,
+    /// Finalize active downloads and restart them, like new downloads, in
+    /// the secondary output folder. Falls back to [`Self::Finalize`] when no
+    /// secondary folder is configured or it is low on space too.
+    SwitchFolder,
+}
+
+impl LowDiskSpaceAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::StopNew => "stop_new",
+            Self::Finalize => "finalize",
+            Self::SwitchFolder => "switch_folder",
+        }
+    }
+}
+
+impl FromStr for LowDiskSpaceAction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "stop_new" => Ok(Self::StopNew),
+            "finalize" => Ok(Self::Finalize),
+            "switch_folder" => Ok(Self::SwitchFolder),
+            other => Err(format!("unknown low disk space action: {other}")),
+        }
+    }
+}
+
+/// Free-space threshold and the action taken below it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DiskSpacePolicy {
+    /// Minimum free bytes on an output volume. `0` disables the policy.
+    pub min_free_bytes: u64,
+    pub action: LowDiskSpaceAction,
+    /// Static prefix of the primary output folder. Output directories below
+    /// it keep their relative layout when moved to the secondary folder.
+    pub primary_root: Option<PathBuf>,
+    /// Folder that takes over when the primary volume is low on space.
+    pub secondary_folder: Option<PathBuf>,
+}
+
+/// Outcome of checking an output directory before a download starts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiskSpaceDecision {
+    /// Enough free space, or it could not be determined.
+    Proceed,
+    /// Write to this directory in the secondary folder instead.
+    Redirect(PathBuf),
+    /// The volume is below the threshold and there is nowhere else to go.
+    Reject { available_bytes: u64 },
+}
+
+impl DiskSpacePolicy {
+    pub fn is_enabled(&self) -> bool {
+        self.min_free_bytes > 0
+    }
+
+    /// Whether `available_bytes` of free space is below the threshold.
+    pub fn is_low(&self, available_bytes: u64) -> bool {
+        self.is_enabled() && available_bytes < self.min_free_bytes
+    }
+
+    /// Decide where a download writing to `output_dir` may start.
+    ///
+    /// `free_space` returns the free bytes of the volume holding a path, or
+    /// `None` when unknown; unknown volumes never block a download.
+    pub fn admit(
+        &self,
+        output_dir: &Path,
+        free_space: impl Fn(&Path) -> Option<u64>,
+    ) -> DiskSpaceDecision {
+        let Some(available_bytes) = free_space(output_dir).filter(|&free| self.is_low(free)) else {
+            return DiskSpaceDecision::Proceed;
+        };
+
+        if self.action == LowDiskSpaceAction::SwitchFolder
+            && let Some(secondary_dir) = self.secondary_dir_for(output_dir)
+            && !free_space(&secondary_dir).is_some_and(|free| self.is_low(free))
+        {
+            return DiskSpaceDecision::Redirect(secondary_dir);
+        }
+
+        DiskSpaceDecision::Reject { available_bytes }
+    }
+
+    /// Whether active downloads on a volume with `available_bytes` free
+    /// should be finalized.
+    pub fn should_finalize(&self, available_bytes: u64) -> bool {
+        self.action != LowDiskSpaceAction::StopNew && self.is_low(available_bytes)
+    }
+
+    /// Map `output_dir` into the secondary folder.
+    ///
+    /// Directories below the primary root keep their relative path; others
+    /// keep only their last component.
+    pub fn secondary_dir_for(&self, output_dir: &Path) -> Option<PathBuf> {
+        let secondary = self.secondary_folder.as_ref()?;
+        if output_dir.starts_with(secondary) {
+            return None;
+        }
+
+        let relative = self
+            .primary_root
+            .as_ref()
+            .and_then(|root| output_dir.strip_prefix(root).ok())
+            .filter(|relative| !relative.as_os_str().is_empty())
+            .map(Path::to_path_buf)
+            .or_else(|| output_dir.file_name().map(PathBuf::from));
+
+        Some(match relative {
+            Some(relative) => secondary.join(relative),
+            None => secondary.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GIB: u64 = 1024 * 1024 * 1024;
+
+    fn policy(action: LowDiskSpaceAction) -> DiskSpacePolicy {
+        DiskSpacePolicy {
+            min_free_bytes: 10 * GIB,
+            action,
+            primary_root: Some(PathBuf::from("/rec")),
+            secondary_folder: Some(PathBuf::from("/backup/rec")),
+        }
+    }
+
+    fn free_space(path: &Path) -> Option<u64> {
+        if path.starts_with("/rec") {
+            Some(2 * GIB)
+        } else if path.starts_with("/backup") {
+            Some(500 * GIB)
+        } else {
+            None
+        }
+    }
+
+    #[test]
+    fn disabled_policy_always_proceeds() {
+        let policy = DiskSpacePolicy::default();
+        assert_eq!(
+            policy.admit(Path::new("/rec/huya/streamer"), free_space),
+            DiskSpaceDecision::Proceed
+        );
+        assert!(!policy.should_finalize(0));
+    }
+
+    #[test]
+    fn low_volume_rejects_new_downloads() {
+        let stop_new = policy(LowDiskSpaceAction::StopNew);
+        assert_eq!(
+            stop_new.admit(Path::new("/rec/huya/streamer"), free_space),
+            DiskSpaceDecision::Reject {
+                available_bytes: 2 * GIB
+            }
+        );
+        assert_eq!(
+            stop_new.admit(Path::new("/other/streamer"), free_space),
+            DiskSpaceDecision::Proceed
+        );
+        assert!(!stop_new.should_finalize(2 * GIB));
+        assert!(policy(LowDiskSpaceAction::Finalize).should_finalize(2 * GIB));
+    }
+
+    #[test]
+    fn switch_folder_keeps_the_relative_layout() {
+        let policy = policy(LowDiskSpaceAction::SwitchFolder);
+        assert_eq!(
+            policy.admit(Path::new("/rec/huya/streamer"), free_space),
+            DiskSpaceDecision::Redirect(PathBuf::from("/backup/rec/huya/streamer"))
+        );
+        assert_eq!(
+            policy.secondary_dir_for(Path::new("/srv/streamer")),
+            Some(PathBuf::from("/backup/rec/streamer"))
+        );
+        assert_eq!(
+            policy.secondary_dir_for(Path::new("/backup/rec/huya/streamer")),
+            None
+        );
+    }
+
+    #[test]
+    fn switch_folder_rejects_when_the_secondary_is_low_too() {
+        let mut policy = policy(LowDiskSpaceAction::SwitchFolder);
+        policy.secondary_folder = Some(PathBuf::from("/rec/spill"));
+        assert!(matches!(
+            policy.admit(Path::new("/rec/huya/streamer"), free_space),
+            DiskSpaceDecision::Reject { .. }
+        ));
+
+        policy.secondary_folder = None;
+        assert!(matches!(
+            policy.admit(Path::new("/rec/huya/streamer"), free_space),
+            DiskSpaceDecision::Reject { .. }
+        ));
+    }
+
+    #[test]
+    fn parses_actions() {
+        for action in [
+            LowDiskSpaceAction::StopNew,
+            LowDiskSpaceAction::Finalize,
+            LowDiskSpaceAction::SwitchFolder,
+        ] {
+            assert_eq!(action.as_str().parse::<LowDiskSpaceAction>(), Ok(action));
+        }
+        assert!("delete".parse::<LowDiskSpaceAction>().is_err());
+    }
+}
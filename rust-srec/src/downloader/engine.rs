@@ -1,7 +1,7 @@
 //! Download engine abstraction.
 //!
 //! This module defines the `DownloadEngine` trait and related types
-//! for abstracting different download backends (ffmpeg, streamlink, mesio).
+//! for abstracting different download backends (ffmpeg, streamlink, mesio, yt-dlp).
 //!
 //! # Mesio Engine
 //!
@@ -24,12 +24,14 @@ mod mesio;
 mod streamlink;
 mod traits;
 pub mod utils;
+mod ytdlp;
 
-pub use ffmpeg::FfmpegEngine;
+pub use ffmpeg::{FfmpegEngine, HwAccelCapabilities};
 pub use mesio::{DownloadStats, FlvDownloader, HlsDownloader, MesioEngine, config};
 pub use streamlink::StreamlinkEngine;
 pub use traits::{
     DownloadConfig, DownloadEngine, DownloadFailureKind, DownloadHandle, DownloadInfo,
-    DownloadProgress, DownloadProtocol, DownloadStatus, EngineStartError, EngineType,
+    DownloadProgress, DownloadProtocol, DownloadStatus, EngineProbe, EngineStartError, EngineType,
     IoErrorKindSer, SegmentEvent, SegmentInfo,
 };
+pub use ytdlp::YtDlpEngine;
@@ -3,6 +3,7 @@
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use pipeline_common::expand_filename_template;
+use process_utils::ProcessGroupExt;
 use std::path::PathBuf;
 use std::process::Stdio;
 use std::sync::Arc;
@@ -10,16 +11,21 @@ use tokio::time::{Duration, Instant};
 use tracing::{debug, error, info, warn};
 
 use super::traits::{
-    DownloadConfig, DownloadEngine, DownloadFailureKind, DownloadHandle, EngineStartError,
-    EngineType, SegmentEvent, SegmentInfo,
+    DownloadConfig, DownloadEngine, DownloadFailureKind, DownloadHandle, DownloadProtocol,
+    EngineProbe, EngineStartError, EngineType, SegmentEvent, SegmentInfo,
 };
 use super::utils::{
-    OutputRecordReader, is_disk_full_line, is_segment_start, observe_segment_event_send,
-    parse_opened_path, parse_progress,
+    OutputRecordReader, is_disk_full_line, is_segment_start, kill_process_tree,
+    observe_segment_event_send, parse_opened_path, parse_progress, probe_binary,
+    track_process_tree,
 };
 use crate::Result;
 use crate::database::models::engine::FfmpegEngineConfig;
 
+mod hwaccel;
+
+pub use hwaccel::HwAccelCapabilities;
+
 fn is_mp4_family(format: &str) -> bool {
     matches!(
         format
@@ -67,6 +73,8 @@ pub struct FfmpegEngine {
     config: FfmpegEngineConfig,
     /// Cached version string.
     version: Option<String>,
+    /// Hardware acceleration supported by the binary.
+    hwaccel: HwAccelCapabilities,
 }
 
 impl FfmpegEngine {
@@ -78,8 +86,57 @@ impl FfmpegEngine {
     /// Create with a custom configuration.
     pub fn with_config(config: FfmpegEngineConfig) -> Self {
         let version = Self::detect_version(&config.binary_path);
+        let hwaccel = if version.is_some() {
+            HwAccelCapabilities::detect(&config.binary_path)
+        } else {
+            HwAccelCapabilities::default()
+        };
+
+        Self {
+            config,
+            version,
+            hwaccel,
+        }
+    }
+
+    /// Hardware acceleration supported by the binary.
+    pub fn hwaccel_capabilities(&self) -> &HwAccelCapabilities {
+        &self.hwaccel
+    }
+
+    /// Decoding method for `-hwaccel`, resolving `auto` to the detected one.
+    fn resolved_hwaccel(&self) -> Option<&str> {
+        match self.config.hwaccel.as_deref().map(str::trim) {
+            None | Some("") => None,
+            Some("auto") => self.hwaccel.preferred_hwaccel(),
+            Some(method) => Some(method),
+        }
+    }
+
+    /// Expand the placeholders of user-supplied arguments for one download.
+    fn expand_args(&self, args: &[String], config: &DownloadConfig) -> Vec<String> {
+        if !args.iter().any(|arg| arg.contains('{')) {
+            return args.to_vec();
+        }
 
-        Self { config, version }
+        let timeout_us = (u64::from(self.config.timeout_secs) * 1_000_000).to_string();
+        let hwaccel = self.resolved_hwaccel().unwrap_or("none");
+        let hw_encoder = self.hwaccel.preferred_encoder("h264").unwrap_or("libx264");
+        let vars = [
+            ("{streamer}", config.streamer_name.as_str()),
+            ("{streamer_id}", config.streamer_id.as_str()),
+            ("{session_id}", config.session_id.as_str()),
+            ("{platform}", config.platform.as_str()),
+            ("{timeout_us}", timeout_us.as_str()),
+            ("{hwaccel}", hwaccel),
+            ("{hw_encoder}", hw_encoder),
+        ];
+        args.iter()
+            .map(|arg| {
+                vars.iter()
+                    .fold(arg.clone(), |arg, (key, value)| arg.replace(key, value))
+            })
+            .collect()
     }
 
     /// Detect ffmpeg version.
@@ -100,8 +157,11 @@ impl FfmpegEngine {
         // 1. Force consistent output format
         args.extend(["-y".to_string(), "-hide_banner".to_string()]);
 
-        // 2. Extra input arguments from config
-        args.extend(self.config.input_args.clone());
+        // 2. Hardware decoding and extra input arguments from config
+        if let Some(method) = self.resolved_hwaccel() {
+            args.extend(["-hwaccel".to_string(), method.to_string()]);
+        }
+        args.extend(self.expand_args(&self.config.input_args, config));
 
         // 3. User Agent (if configured in engine or handle)
         // Handle config takes precedence if both set? Or engine config?
@@ -140,7 +200,7 @@ impl FfmpegEngine {
         args.extend(["-c".to_string(), "copy".to_string()]); // Copy streams without re-encoding
 
         // 7. Extra output arguments from config
-        args.extend(self.config.output_args.clone());
+        args.extend(self.expand_args(&self.config.output_args, config));
 
         // 8. File size limit if configured
         // After that size download will be stopped
@@ -245,13 +305,15 @@ impl DownloadEngine for FfmpegEngine {
             .env("LC_ALL", "C") // Force consistent output
             .stdin(Stdio::piped()) // allow graceful stop via 'q'
             .stdout(Stdio::piped())
-            .stderr(Stdio::piped());
+            .stderr(Stdio::piped())
+            .own_process_group();
         let mut child = command.spawn().map_err(|e| {
             EngineStartError::new(
                 DownloadFailureKind::Configuration,
                 format!("Failed to spawn ffmpeg: {}", e),
             )
         })?;
+        let mut process_tree = track_process_tree(&child);
 
         let mut stdin = child.stdin.take();
         let stderr = child.stderr.take().ok_or_else(|| {
@@ -303,6 +365,7 @@ impl DownloadEngine for FfmpegEngine {
                         }
                         Err(_) => {
                             warn!("FFmpeg did not exit in time; killing process");
+                            kill_process_tree(process_tree.as_ref());
                             if let Err(e) = child.kill().await {
                                 warn!(error = %e, "Failed to kill ffmpeg process");
                             }
@@ -317,6 +380,10 @@ impl DownloadEngine for FfmpegEngine {
                     }
                 }
             };
+            // ffmpeg has been reaped, so its group id may be reused
+            if let Some(tree) = &mut process_tree {
+                tree.release();
+            }
 
             if exit_tx.send(exit_code).is_err() {
                 debug!("Download exit receiver dropped before ffmpeg completed");
@@ -400,6 +467,7 @@ impl DownloadEngine for FfmpegEngine {
                                                         completed_at: Utc::now(),
                                                         split_reason_code: None,
                                                         split_reason_details_json: None,
+                                                        analysis_report_json: None,
                                                     }))
                                                     .await,
                                                 &streamer_id,
@@ -593,6 +661,7 @@ impl DownloadEngine for FfmpegEngine {
                             completed_at: Utc::now(),
                             split_reason_code: None,
                             split_reason_details_json: None,
+                            analysis_report_json: None,
                         }))
                         .await,
                     &streamer_id,
@@ -692,6 +761,13 @@ impl DownloadEngine for FfmpegEngine {
     fn version(&self) -> Option<String> {
         self.version.clone()
     }
+
+    async fn probe(&self) -> EngineProbe {
+        EngineProbe::from_check(
+            probe_binary(&self.config.binary_path, &["-version"]).await,
+            vec![DownloadProtocol::Hls, DownloadProtocol::Flv],
+        )
+    }
 }
 
 #[cfg(test)]
@@ -706,6 +782,7 @@ mod tests {
                 ..Default::default()
             },
             version: None,
+            hwaccel: HwAccelCapabilities::default(),
         }
     }
 
@@ -769,6 +846,58 @@ mod tests {
         assert!(!args.iter().any(|arg| arg == "+faststart"));
     }
 
+    #[test]
+    fn argument_templates_are_expanded_per_download() {
+        let engine = FfmpegEngine {
+            config: FfmpegEngineConfig {
+                input_args: vec!["-rw_timeout".to_string(), "{timeout_us}".to_string()],
+                output_args: vec![
+                    "-c:v".to_string(),
+                    "{hw_encoder}".to_string(),
+                    "-metadata".to_string(),
+                    "comment={streamer} ({session_id})".to_string(),
+                ],
+                timeout_secs: 15,
+                hwaccel: Some("auto".to_string()),
+                ..Default::default()
+            },
+            version: None,
+            hwaccel: HwAccelCapabilities {
+                hwaccels: vec!["vaapi".to_string()],
+                encoders: vec!["h264_vaapi".to_string()],
+            },
+        };
+
+        let args = engine.build_args(&download_config("mkv", 0));
+
+        assert!(has_arg_pair(&args, "-hwaccel", "vaapi"));
+        assert!(has_arg_pair(&args, "-rw_timeout", "15000000"));
+        assert!(has_arg_pair(&args, "-c:v", "h264_vaapi"));
+        assert!(has_arg_pair(
+            &args,
+            "-metadata",
+            "comment=Streamer (session-id)"
+        ));
+    }
+
+    #[test]
+    fn auto_hwaccel_without_detected_methods_is_omitted() {
+        let engine = FfmpegEngine {
+            config: FfmpegEngineConfig {
+                hwaccel: Some("auto".to_string()),
+                output_args: vec!["-c:v".to_string(), "{hw_encoder}".to_string()],
+                ..Default::default()
+            },
+            version: None,
+            hwaccel: HwAccelCapabilities::default(),
+        };
+
+        let args = engine.build_args(&download_config("mkv", 0));
+
+        assert!(!contains_option(&args, "-hwaccel"));
+        assert!(has_arg_pair(&args, "-c:v", "libx264"));
+    }
+
     #[test]
     fn test_parse_time() {
         // Tests now use shared utility
@@ -0,0 +1,158 @@
+//! Hardware acceleration detection.
+//!
+//! FFmpeg lists the hardware acceleration methods and encoders it was built
+//! with; whether they work also depends on the host, so the lists are a
+//! best-effort hint for users who re-encode on capture.
+
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+
+use serde::Serialize;
+use tracing::debug;
+
+/// Hardware acceleration methods, most preferred first.
+const KNOWN_HWACCELS: &[&str] = &["cuda", "qsv", "vaapi", "videotoolbox"];
+
+/// Suffixes of hardware encoders, most preferred first.
+const KNOWN_ENCODER_SUFFIXES: &[&str] = &["_nvenc", "_qsv", "_vaapi", "_videotoolbox"];
+
+/// Probes are cached per binary path, since engines are rebuilt for every
+/// download that uses an engine override.
+static CACHE: LazyLock<Mutex<HashMap<String, HwAccelCapabilities>>> =
+    LazyLock::new(Default::default);
+
+/// Hardware acceleration supported by an FFmpeg binary.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, utoipa::ToSchema)]
+pub struct HwAccelCapabilities {
+    /// Decoding methods usable with `-hwaccel` (`cuda`, `qsv`, `vaapi`, `videotoolbox`).
+    pub hwaccels: Vec<String>,
+    /// Hardware video encoders, e.g. `h264_nvenc`.
+    pub encoders: Vec<String>,
+}
+
+impl HwAccelCapabilities {
+    /// Probe the FFmpeg binary at `path`.
+    pub fn detect(path: &str) -> Self {
+        if let Some(cached) = CACHE.lock().ok().and_then(|cache| cache.get(path).cloned()) {
+            return cached;
+        }
+
+        let capabilities = Self {
+            hwaccels: run(path, "-hwaccels")
+                .map(|out| parse_hwaccels(&out))
+                .unwrap_or_default(),
+            encoders: run(path, "-encoders")
+                .map(|out| parse_encoders(&out))
+                .unwrap_or_default(),
+        };
+        debug!(
+            binary = path,
+            hwaccels = ?capabilities.hwaccels,
+            encoders = ?capabilities.encoders,
+            "Detected FFmpeg hardware acceleration"
+        );
+
+        if let Ok(mut cache) = CACHE.lock() {
+            cache.insert(path.to_string(), capabilities.clone());
+        }
+        capabilities
+    }
+
+    /// Most preferred decoding method.
+    pub fn preferred_hwaccel(&self) -> Option<&str> {
+        KNOWN_HWACCELS
+            .iter()
+            .copied()
+            .find(|known| self.hwaccels.iter().any(|method| method == known))
+    }
+
+    /// Most preferred hardware encoder for `codec` (e.g. `h264`, `hevc`).
+    pub fn preferred_encoder(&self, codec: &str) -> Option<&str> {
+        KNOWN_ENCODER_SUFFIXES.iter().find_map(|suffix| {
+            let name = format!("{codec}{suffix}");
+            self.encoders
+                .iter()
+                .find(|encoder| **encoder == name)
+                .map(String::as_str)
+        })
+    }
+}
+
+fn run(path: &str, flag: &str) -> Option<String> {
+    let mut cmd = process_utils::std_command(path);
+    cmd.args(["-hide_banner", flag]);
+    let output = cmd.output().ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Parse `ffmpeg -hwaccels`, keeping the known methods.
+fn parse_hwaccels(output: &str) -> Vec<String> {
+    output
+        .lines()
+        .skip_while(|line| !line.starts_with("Hardware acceleration methods"))
+        .skip(1)
+        .map(str::trim)
+        .filter(|method| KNOWN_HWACCELS.contains(method))
+        .map(ToString::to_string)
+        .collect()
+}
+
+/// Parse `ffmpeg -encoders`, keeping the hardware video encoders.
+///
+/// Encoder lines look like ` V....D h264_nvenc  NVIDIA NVENC H.264 encoder`.
+fn parse_encoders(output: &str) -> Vec<String> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let flags = fields.next()?;
+            let name = fields.next()?;
+            (flags.len() == 6 && flags.starts_with('V')).then_some(name)
+        })
+        .filter(|name| {
+            KNOWN_ENCODER_SUFFIXES
+                .iter()
+                .any(|suffix| name.ends_with(suffix))
+        })
+        .map(ToString::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_hwaccels() {
+        let output = "Hardware acceleration methods:\nvdpau\ncuda\nvaapi\nqsv\ndrm\n\n";
+        assert_eq!(parse_hwaccels(output), vec!["cuda", "vaapi", "qsv"]);
+        assert!(parse_hwaccels("").is_empty());
+    }
+
+    #[test]
+    fn parses_hardware_encoders() {
+        let output = "Encoders:
+ V..... = Video
+ ------
+ V....D libx264              libx264 H.264 / AVC / MPEG-4 AVC (codec h264)
+ V....D h264_nvenc           NVIDIA NVENC H.264 encoder (codec h264)
+ V....D hevc_vaapi           H.265/HEVC (VAAPI) (codec hevc)
+ A....D aac                  AAC (Advanced Audio Coding)
+";
+        assert_eq!(parse_encoders(output), vec!["h264_nvenc", "hevc_vaapi"]);
+    }
+
+    #[test]
+    fn prefers_nvidia_then_intel() {
+        let capabilities = HwAccelCapabilities {
+            hwaccels: vec!["vaapi".to_string(), "qsv".to_string()],
+            encoders: vec!["h264_vaapi".to_string(), "h264_qsv".to_string()],
+        };
+        assert_eq!(capabilities.preferred_hwaccel(), Some("qsv"));
+        assert_eq!(capabilities.preferred_encoder("h264"), Some("h264_qsv"));
+        assert_eq!(capabilities.preferred_encoder("hevc"), None);
+    }
+}
@@ -22,7 +22,8 @@ use super::hls_downloader::HlsDownloader;
 use crate::Result;
 use crate::database::models::engine::MesioEngineConfig;
 use crate::downloader::engine::traits::{
-    DownloadEngine, DownloadFailureKind, DownloadHandle, EngineStartError, EngineType,
+    DownloadEngine, DownloadFailureKind, DownloadHandle, DownloadProtocol, EngineProbe,
+    EngineStartError, EngineType,
 };
 
 /// Native Mesio download engine.
@@ -147,7 +148,8 @@ impl DownloadEngine for MesioEngine {
                     handle.event_tx.clone(),
                     handle.cancellation_token.clone(),
                     self.flv_config.clone(),
-                );
+                )
+                .with_chapter_markers(handle.chapter_markers.clone());
                 downloader.run().await.map(|_| ())
             }
             _ => {
@@ -182,6 +184,16 @@ impl DownloadEngine for MesioEngine {
     fn version(&self) -> Option<String> {
         Some(self.version.clone())
     }
+
+    async fn probe(&self) -> EngineProbe {
+        // Built in, so there is no binary to check.
+        EngineProbe {
+            available: self.available,
+            version: self.version(),
+            protocols: vec![DownloadProtocol::Hls, DownloadProtocol::Flv],
+            error: None,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -206,6 +218,17 @@ mod tests {
         assert!(engine.version().is_some());
     }
 
+    #[tokio::test]
+    async fn test_probe() {
+        let probe = MesioEngine::new().probe().await;
+        assert!(probe.available);
+        assert!(probe.error.is_none());
+        assert_eq!(
+            probe.protocols,
+            vec![DownloadProtocol::Hls, DownloadProtocol::Flv]
+        );
+    }
+
     #[test]
     fn test_default() {
         let engine = MesioEngine::default();
@@ -232,4 +255,11 @@ mod tests {
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), ProtocolType::Flv);
     }
+
+    #[test]
+    fn test_detect_protocol_rtmp() {
+        let result = MesioEngine::detect_protocol("rtmp://example.com/live/stream?token=1");
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), ProtocolType::Flv);
+    }
 }
@@ -5,9 +5,15 @@
 //! It supports both pipeline-processed and raw download modes.
 
 use flv::data::FlvData;
-use flv_fix::{FlvPipeline, FlvPipelineConfig, FlvWriter, FlvWriterConfig};
+use flv_fix::{
+    ChapterMarkers, CustomMetadata, FlvPipeline, FlvPipelineConfig, FlvWriter, FlvWriterConfig,
+    RepairCounters, SEGMENT_PLACEHOLDER,
+};
 use mesio::flv::FlvProtocolConfig;
-use mesio::{DownloadError, DownloadRequest, MesioConfig, MesioDownloader, ProtocolSelection};
+use mesio::{
+    DownloadError, DownloadRequest, FlvReconnect, FlvRequestOptions, MesioConfig, MesioDownloader,
+    ProtocolSelection,
+};
 use parking_lot::RwLock;
 use pipeline_common::{
     ChannelSpec, PipelineError, PipelineProvider, ProtocolWriter, StreamerContext, spawn_pipeline,
@@ -17,7 +23,7 @@ use tokio::sync::mpsc;
 use tokio_util::sync::CancellationToken;
 use tracing::info;
 
-use super::config::build_flv_config;
+use super::config::{build_flv_config, build_watchdog_config};
 use super::helpers::{self, DownloadStats};
 use crate::database::models::engine::MesioEngineConfig;
 use crate::downloader::engine::traits::{DownloadConfig, EngineStartError, SegmentEvent};
@@ -38,6 +44,10 @@ pub struct FlvDownloader {
     cancellation_token: CancellationToken,
     /// Base FLV configuration from the engine.
     flv_config: Option<FlvProtocolConfig>,
+    /// The writer's latest progress, reported with the network progress.
+    writer_progress: helpers::SharedWriterProgress,
+    /// Chapter markers placed in the output.
+    chapter_markers: ChapterMarkers,
 }
 
 impl FlvDownloader {
@@ -62,9 +72,17 @@ impl FlvDownloader {
             event_tx,
             cancellation_token,
             flv_config,
+            writer_progress: Default::default(),
+            chapter_markers: ChapterMarkers::default(),
         }
     }
 
+    /// Place markers from `chapter_markers` as chapters of the written files.
+    pub fn with_chapter_markers(mut self, chapter_markers: ChapterMarkers) -> Self {
+        self.chapter_markers = chapter_markers;
+        self
+    }
+
     fn config_snapshot(&self) -> DownloadConfig {
         self.config.read().clone()
     }
@@ -72,12 +90,14 @@ impl FlvDownloader {
     /// Create a MesioDownloader with the configured settings.
     fn create_downloader(&self, token: CancellationToken) -> MesioDownloader {
         let config = self.config_snapshot();
-        let flv_config = build_flv_config(&config, self.flv_config.clone());
+        let flv_config = build_flv_config(&config, self.flv_config.clone(), &self.engine_config);
 
         MesioDownloader::new(MesioConfig {
             hls: mesio::hls::HlsConfig::default(),
             flv: flv_config,
             token,
+            watchdog: build_watchdog_config(&self.engine_config),
+            ..Default::default()
         })
     }
 
@@ -97,21 +117,32 @@ impl FlvDownloader {
 
         let downloader = self.create_downloader(token.clone());
 
-        let url = self.config_snapshot().url;
+        let config = self.config_snapshot();
 
-        let request = DownloadRequest::from_url(&url)
+        // Switch to the next CDN instead of failing when there is one
+        let reconnect = if config.fallback_urls.is_empty() {
+            FlvReconnect::FailTerminal
+        } else {
+            FlvReconnect::SwitchSourceWithDiscontinuity
+        };
+        let request = DownloadRequest::from_url(&config.url)
             .map_err(|e| {
                 let kind = super::classify_download_error(&e);
                 EngineStartError::new(kind, format!("Invalid FLV download URL: {}", e))
             })?
-            .with_protocol(ProtocolSelection::Flv(Default::default()))
+            .with_protocol(ProtocolSelection::Flv(FlvRequestOptions {
+                reconnect,
+                ..Default::default()
+            }))
             .with_cancel(token.clone());
+        let request = helpers::with_fallback_sources(request, &config);
 
         let session = downloader.start_flv(request).await.map_err(|e| {
             let kind = super::classify_download_error(&e);
             EngineStartError::new(kind, format!("Failed to start FLV download: {}", e))
         })?;
-        let flv_stream = session.items;
+        let flv_stream =
+            helpers::report_session_progress(session, &self.event_tx, &self.writer_progress);
 
         let config_snapshot = self.config_snapshot();
 
@@ -140,7 +171,7 @@ impl FlvDownloader {
 
         // Build pipeline and common configs
         let pipeline_config = config.build_pipeline_config();
-        let flv_pipeline_config = if let Some(cfg) = config.flv_pipeline_config.clone() {
+        let mut flv_pipeline_config = if let Some(cfg) = config.flv_pipeline_config.clone() {
             cfg
         } else {
             let mut cfg = FlvPipelineConfig::default();
@@ -149,6 +180,24 @@ impl FlvDownloader {
             }
             cfg
         };
+        // Fresh counters so repairs of concurrent downloads sharing a config don't mix
+        let repair_counters = RepairCounters::new();
+        flv_pipeline_config.repair_counters = repair_counters.clone();
+        if self
+            .engine_config
+            .flv_fix
+            .as_ref()
+            .and_then(|opts| opts.inject_recording_metadata)
+            == Some(true)
+        {
+            // Explicitly configured properties take precedence
+            let mut custom_metadata = recording_metadata(&config);
+            for (key, value) in flv_pipeline_config.custom_metadata.iter() {
+                custom_metadata.insert(key, value);
+            }
+            flv_pipeline_config.custom_metadata = custom_metadata;
+        }
+        let custom_metadata = flv_pipeline_config.custom_metadata.clone();
 
         // Create StreamerContext with streamer name and cancellation token
         let context = Arc::new(StreamerContext::with_name(&streamer_id, token.clone()));
@@ -157,7 +206,11 @@ impl FlvDownloader {
         let pipeline_provider =
             FlvPipeline::with_config(context, &pipeline_config, flv_pipeline_config);
 
-        let pipeline = pipeline_provider.build_pipeline();
+        let pipeline = helpers::with_operator_metrics(
+            pipeline_provider.build_pipeline(),
+            &config,
+            FlvData::size,
+        );
 
         let pipeline_common::SpawnedPipeline {
             input_tx: pipeline_input_tx,
@@ -174,12 +227,34 @@ impl FlvDownloader {
             base_name,
             enable_low_latency: true,
         });
+        writer.set_repair_counters(repair_counters);
+        writer.set_custom_metadata(custom_metadata);
+        writer.set_chapter_markers(self.chapter_markers.clone());
+        writer.set_metadata_checkpoint_interval(
+            self.engine_config
+                .flv_fix
+                .as_ref()
+                .and_then(|opts| opts.metadata_checkpoint_secs)
+                .map(std::time::Duration::from_secs),
+        );
 
-        helpers::setup_writer_callbacks(&mut writer, &self.event_tx);
+        helpers::setup_writer_callbacks(&mut writer, &self.event_tx, &self.writer_progress);
 
         // Spawn blocking writer task that reads from pipeline output
         let writer_task = tokio::task::spawn_blocking(move || writer.run(pipeline_output_rx));
 
+        let raw_copy = self.engine_config.keep_raw.then(|| {
+            helpers::RawCopy::spawn(
+                FlvWriter::new(FlvWriterConfig {
+                    output_dir: config.output_dir.clone(),
+                    base_name: format!("{}_raw", config.filename_template),
+                    enable_low_latency: true,
+                }),
+                pipeline_config.channel_size,
+            )
+        });
+        let pipeline_input_tx = helpers::TeeSender::new(pipeline_input_tx, raw_copy);
+
         // Consume the FLV stream and send to pipeline
         let stream_error = helpers::consume_stream(
             flv_stream,
@@ -196,7 +271,7 @@ impl FlvDownloader {
         .await;
 
         // Close the pipeline input channel to signal completion
-        drop(pipeline_input_tx);
+        pipeline_input_tx.close().await;
 
         // Wait for writer to complete
         let writer_result = writer_task
@@ -255,7 +330,7 @@ impl FlvDownloader {
             enable_low_latency: true,
         });
 
-        helpers::setup_writer_callbacks(&mut writer, &self.event_tx);
+        helpers::setup_writer_callbacks(&mut writer, &self.event_tx, &self.writer_progress);
 
         // Spawn blocking writer task
         let writer_task = tokio::task::spawn_blocking(move || writer.run(rx.into()));
@@ -298,6 +373,22 @@ impl FlvDownloader {
     }
 }
 
+/// `onMetaData` properties describing the recording.
+fn recording_metadata(config: &DownloadConfig) -> CustomMetadata {
+    let mut metadata = CustomMetadata::new()
+        .with("streamer", &config.streamer_name)
+        .with("platform", &config.platform)
+        .with("sessionid", &config.session_id)
+        .with(
+            flv_fix::METADATA_METADATACREATOR,
+            concat!("rust-srec v", env!("CARGO_PKG_VERSION")),
+        );
+    if config.record_danmu {
+        metadata.insert("danmufile", format!("{SEGMENT_PLACEHOLDER}.xml"));
+    }
+    metadata
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -306,6 +397,22 @@ mod tests {
     use flv::tag::{FlvTag, FlvTagType};
     use tokio::time::{Duration, timeout};
 
+    #[test]
+    fn recording_metadata_references_danmu_file_only_when_recorded() {
+        let config = DownloadConfig::new("http://example.invalid", "/tmp", "id", "name", "s1")
+            .with_platform("huya");
+        let metadata = recording_metadata(&config);
+        assert!(metadata.iter().any(|(k, v)| k == "platform" && v == "huya"));
+        assert!(!metadata.iter().any(|(k, _)| k == "danmufile"));
+
+        let metadata = recording_metadata(&config.with_record_danmu(true));
+        assert!(
+            metadata
+                .iter()
+                .any(|(k, v)| k == "danmufile" && v == "{segment}.xml")
+        );
+    }
+
     #[tokio::test]
     async fn download_raw_emits_segment_completed_before_download_failed_on_stream_error() {
         let temp = tempfile::tempdir().expect("tempdir");
@@ -392,4 +499,56 @@ mod tests {
                 .collect::<Vec<_>>()
         );
     }
+
+    #[tokio::test]
+    async fn keep_raw_writes_the_unprocessed_stream_next_to_the_output() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let config = DownloadConfig::new(
+            "http://example.invalid/stream.flv",
+            temp.path().to_path_buf(),
+            "streamer",
+            "streamer",
+            "session",
+        )
+        .with_filename_template("test-flv");
+
+        let (event_tx, _event_rx) = tokio::sync::mpsc::channel::<SegmentEvent>(32);
+        let downloader = FlvDownloader::new(
+            Arc::new(RwLock::new(config)),
+            MesioEngineConfig {
+                keep_raw: true,
+                ..MesioEngineConfig::default()
+            },
+            event_tx,
+            CancellationToken::new(),
+            None,
+        );
+
+        let flv_stream = futures::stream::iter([
+            Ok(FlvData::Header(FlvHeader::new(true, true))),
+            Ok(FlvData::Tag(FlvTag::new(
+                0,
+                0,
+                FlvTagType::ScriptData,
+                false,
+                Bytes::new(),
+            ))),
+        ]);
+        let _ = downloader
+            .download_with_pipeline(CancellationToken::new(), flv_stream)
+            .await;
+
+        let raw_file = std::fs::read_dir(temp.path())
+            .expect("read output dir")
+            .filter_map(|entry| entry.ok())
+            .find(|entry| {
+                entry
+                    .file_name()
+                    .to_string_lossy()
+                    .starts_with("test-flv_raw")
+            })
+            .expect("expected a raw copy");
+        let raw = std::fs::read(raw_file.path()).expect("read raw copy");
+        assert!(raw.starts_with(b"FLV"));
+    }
 }
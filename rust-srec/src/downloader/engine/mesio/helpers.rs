@@ -5,21 +5,30 @@ use std::future::Future;
 use std::path::Path;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicI64, Ordering};
+use std::time::Duration;
 
 use chrono::{DateTime, Utc};
 use futures::StreamExt;
+use mesio::{
+    BoxMediaStream, ContentSource, DownloadError, DownloadRequest, DownloadSession, ProgressOptions,
+};
+use parking_lot::Mutex;
 use pipeline_common::{
-    PipelineError, PipelineSender, RunCompletionError, SplitReason, WriterError, WriterProgress,
-    WriterStats, settle_run,
+    Pipeline, PipelineError, PipelineSender, ProgressThrottle, ProtocolWriter, RunCompletionError,
+    SplitReason, WriterError, WriterProgress, WriterStats, settle_run,
 };
 use tokio::sync::mpsc;
 use tokio_util::sync::CancellationToken;
 use tracing::{debug, error, info, warn};
 
 use crate::downloader::engine::traits::{
-    DownloadFailureKind, DownloadProgress, SegmentEvent, SegmentInfo,
+    DownloadConfig, DownloadFailureKind, DownloadProgress, SegmentEvent, SegmentInfo,
 };
 use crate::downloader::engine::utils::observe_segment_event_send;
+use crate::metrics::PipelineOperatorMetrics;
+
+/// Items between two reports of the per-operator pipeline metrics.
+const OPERATOR_METRICS_ITEM_INTERVAL: usize = 256;
 
 // ---------------------------------------------------------------------------
 // DownloadStats (moved from hls_downloader)
@@ -56,6 +65,14 @@ pub(super) trait WriterWithCallbacks {
     fn set_progress_callback<F>(&mut self, cb: F)
     where
         F: Fn(WriterProgress) + Send + Sync + 'static;
+
+    /// Receives each segment's analysis report as JSON, right before its
+    /// segment-complete callback. Writers without reports never call it.
+    fn set_on_segment_report_callback<F>(&mut self, _cb: F)
+    where
+        F: Fn(String) + Send + Sync + 'static,
+    {
+    }
 }
 
 impl WriterWithCallbacks for flv_fix::FlvWriter {
@@ -79,6 +96,20 @@ impl WriterWithCallbacks for flv_fix::FlvWriter {
     {
         flv_fix::FlvWriter::set_progress_callback(self, cb);
     }
+
+    fn set_on_segment_report_callback<F>(&mut self, cb: F)
+    where
+        F: Fn(String) + Send + Sync + 'static,
+    {
+        flv_fix::FlvWriter::set_on_segment_report_callback(self, move |path, _, report| {
+            match serde_json::to_string(report) {
+                Ok(json) => cb(json),
+                Err(error) => {
+                    warn!(path = %path.display(), %error, "failed to serialize segment report")
+                }
+            }
+        });
+    }
 }
 
 impl WriterWithCallbacks for hls_fix::HlsWriter {
@@ -102,6 +133,117 @@ impl WriterWithCallbacks for hls_fix::HlsWriter {
     {
         hls_fix::HlsWriter::set_progress_callback(self, cb);
     }
+
+    fn set_on_segment_report_callback<F>(&mut self, cb: F)
+    where
+        F: Fn(String) + Send + Sync + 'static,
+    {
+        hls_fix::HlsWriter::set_on_segment_report_callback(self, move |path, _, report| {
+            match serde_json::to_string(report) {
+                Ok(json) => cb(json),
+                Err(error) => {
+                    warn!(path = %path.display(), %error, "failed to serialize segment report")
+                }
+            }
+        });
+    }
+}
+
+// ---------------------------------------------------------------------------
+// with_operator_metrics
+// ---------------------------------------------------------------------------
+
+/// Report the per-operator counters of `pipeline` to the download's metrics
+/// collector, if there is one.
+pub(super) fn with_operator_metrics<T: Send + 'static>(
+    pipeline: Pipeline<T>,
+    config: &DownloadConfig,
+    size_of: fn(&T) -> usize,
+) -> Pipeline<T> {
+    match config.metrics_collector.clone() {
+        Some(collector) => pipeline.with_metrics_sink(
+            Arc::new(PipelineOperatorMetrics::new(
+                collector,
+                config.streamer_id.clone(),
+            )),
+            ProgressThrottle::new(OPERATOR_METRICS_ITEM_INTERVAL, Duration::from_secs(1)),
+            size_of,
+        ),
+        None => pipeline,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// with_fallback_sources
+// ---------------------------------------------------------------------------
+
+/// Add the download URL and its fallback URLs to `request` as sources, in order of
+/// preference, so mesio switches to the next CDN when one fails mid-download.
+///
+/// Requests without fallback URLs stay single-source.
+pub(super) fn with_fallback_sources(
+    request: DownloadRequest,
+    config: &DownloadConfig,
+) -> DownloadRequest {
+    if config.fallback_urls.is_empty() {
+        return request;
+    }
+    std::iter::once(&config.url)
+        .chain(&config.fallback_urls)
+        .enumerate()
+        .fold(request, |request, (priority, url)| {
+            let priority = u8::try_from(priority).unwrap_or(u8::MAX);
+            request.add_source(ContentSource::new(url.clone(), priority))
+        })
+}
+
+// ---------------------------------------------------------------------------
+// report_session_progress
+// ---------------------------------------------------------------------------
+
+/// The writer's latest progress, merged into the progress reported by mesio.
+pub(super) type SharedWriterProgress = Arc<Mutex<Option<WriterProgress>>>;
+
+/// Report the download's progress from mesio's progress callback: bytes,
+/// speed, segment latency and stalls as measured on the network, media
+/// totals from the writer's latest progress.
+///
+/// Returns the media stream of `session`; its events are drained in the
+/// background.
+pub(super) fn report_session_progress<T>(
+    session: DownloadSession<T>,
+    event_tx: &mpsc::Sender<SegmentEvent>,
+    writer_progress: &SharedWriterProgress,
+) -> BoxMediaStream<T, DownloadError> {
+    let event_tx = event_tx.clone();
+    let writer_progress = Arc::clone(writer_progress);
+    let session = session.with_progress(
+        ProgressOptions::default(),
+        Arc::new(move |progress: &mesio::DownloadProgress| {
+            let writer = writer_progress.lock().clone();
+            let download_progress = DownloadProgress {
+                bytes_downloaded: progress.bytes_total,
+                duration_secs: progress.elapsed.as_secs_f64(),
+                speed_bytes_per_sec: progress.bytes_per_sec as u64,
+                segments_completed: writer.as_ref().map_or(0, |w| w.current_file_sequence),
+                current_segment: None,
+                media_duration_secs: writer.as_ref().map_or(0.0, |w| w.media_duration_secs_total),
+                playback_ratio: writer.as_ref().map_or(0.0, |w| w.playback_ratio),
+                segment_latency_ms: progress
+                    .segment_latency
+                    .map(|latency| latency.as_millis() as u64),
+                stalled: progress.stalled,
+            };
+            match event_tx.try_send(SegmentEvent::Progress(download_progress)) {
+                Ok(()) | Err(mpsc::error::TrySendError::Full(_)) => {}
+                Err(error @ mpsc::error::TrySendError::Closed(_)) => {
+                    debug!(%error, "progress event receiver closed");
+                }
+            }
+        }),
+    );
+    tokio::spawn(session.events.for_each(|_| async {}));
+    session.items
 }
 
 // ---------------------------------------------------------------------------
@@ -112,14 +254,16 @@ impl WriterWithCallbacks for hls_fix::HlsWriter {
 /// writer.  Replaces 4 identical ~40-line blocks.
 ///
 /// Callbacks run on a blocking thread; `blocking_send` applies backpressure
-/// rather than unbounded buffering.
+/// rather than unbounded buffering. Writer progress is only recorded in
+/// `writer_progress`; [`report_session_progress`] reports it.
 pub(super) fn setup_writer_callbacks(
     writer: &mut impl WriterWithCallbacks,
     event_tx: &mpsc::Sender<SegmentEvent>,
+    writer_progress: &SharedWriterProgress,
 ) {
     let event_tx_start = event_tx.clone();
     let event_tx_complete = event_tx.clone();
-    let event_tx_progress = event_tx.clone();
+    let writer_progress = Arc::clone(writer_progress);
 
     // Segments are strictly sequential (start N, complete N, start N+1, …),
     // so a single atomic timestamp is enough to pass started_at from the start
@@ -128,6 +272,14 @@ pub(super) fn setup_writer_callbacks(
     let started_at_writer = Arc::clone(&started_at_ms);
     let started_at_reader = started_at_ms;
 
+    // The report of a segment is produced right before its complete callback,
+    // on the same writer thread.
+    let pending_report = Arc::new(Mutex::new(None::<String>));
+    let pending_report_writer = Arc::clone(&pending_report);
+    writer.set_on_segment_report_callback(move |json| {
+        *pending_report_writer.lock() = Some(json);
+    });
+
     writer.set_on_segment_start_callback(move |path, sequence| {
         let started_at = Utc::now();
         started_at_writer.store(started_at.timestamp_millis(), Ordering::Release);
@@ -170,6 +322,7 @@ pub(super) fn setup_writer_callbacks(
                 completed_at: Utc::now(),
                 split_reason_code,
                 split_reason_details_json,
+                analysis_report_json: pending_report.lock().take(),
             });
             if let Err(error) = event_tx_complete.blocking_send(event) {
                 debug!(%error, "segment-complete event receiver closed");
@@ -178,21 +331,7 @@ pub(super) fn setup_writer_callbacks(
     );
 
     writer.set_progress_callback(move |progress| {
-        let download_progress = DownloadProgress {
-            bytes_downloaded: progress.bytes_written_total,
-            duration_secs: progress.elapsed_secs,
-            speed_bytes_per_sec: progress.speed_bytes_per_sec,
-            segments_completed: progress.current_file_sequence,
-            current_segment: None,
-            media_duration_secs: progress.media_duration_secs_total,
-            playback_ratio: progress.playback_ratio,
-        };
-        match event_tx_progress.try_send(SegmentEvent::Progress(download_progress)) {
-            Ok(()) | Err(mpsc::error::TrySendError::Full(_)) => {}
-            Err(error @ mpsc::error::TrySendError::Closed(_)) => {
-                debug!(%error, "progress event receiver closed");
-            }
-        }
+        *writer_progress.lock() = Some(progress);
     });
 }
 
@@ -202,10 +341,14 @@ fn split_reason_code(reason: &SplitReason) -> &'static str {
         SplitReason::AudioCodecChange { .. } => "audio_codec_change",
         SplitReason::SizeLimit => "size_limit",
         SplitReason::DurationLimit => "duration_limit",
+        SplitReason::WallClock => "wall_clock",
         SplitReason::HeaderReceived => "header_received",
         SplitReason::ResolutionChange { .. } => "resolution_change",
         SplitReason::StreamStructureChange { .. } => "stream_structure_change",
         SplitReason::Discontinuity => "discontinuity",
+        SplitReason::AdBreakStart => "ad_break_start",
+        SplitReason::AdBreakEnd => "ad_break_end",
+        SplitReason::Requested { .. } => "requested",
         SplitReason::EndOfStream => "end_of_stream",
     }
 }
@@ -248,13 +391,17 @@ fn split_reason_details_json(reason: &SplitReason) -> Option<String> {
             "from": { "width": from.0, "height": from.1 },
             "to": { "width": to.0, "height": to.1 },
         }),
-        SplitReason::StreamStructureChange { description } => {
+        SplitReason::StreamStructureChange { description }
+        | SplitReason::Requested { description } => {
             serde_json::json!({ "description": description })
         }
         SplitReason::SizeLimit
         | SplitReason::DurationLimit
+        | SplitReason::WallClock
         | SplitReason::HeaderReceived
         | SplitReason::Discontinuity
+        | SplitReason::AdBreakStart
+        | SplitReason::AdBreakEnd
         | SplitReason::EndOfStream => return None,
     };
 
@@ -284,6 +431,72 @@ impl<T: Send> StreamSender<T> for PipelineSender<T> {
     }
 }
 
+/// The writer of the unprocessed stream kept next to the fixed output (`keep_raw`).
+pub(super) struct RawCopy<T> {
+    tx: mpsc::Sender<std::result::Result<T, PipelineError>>,
+    task: tokio::task::JoinHandle<std::result::Result<WriterStats, WriterError>>,
+}
+
+impl<T: Send + 'static> RawCopy<T> {
+    /// Run `writer` on a blocking task, fed by the copies of a [`TeeSender`].
+    pub(super) fn spawn<W: ProtocolWriter<Item = T>>(mut writer: W, channel_size: usize) -> Self {
+        let (tx, rx) = mpsc::channel(channel_size);
+        let task = tokio::task::spawn_blocking(move || writer.run(rx.into()));
+        Self { tx, task }
+    }
+
+    /// Close the channel and wait for the writer. Its failure is logged and does not fail
+    /// the download.
+    async fn finish(self) {
+        drop(self.tx);
+        match self.task.await {
+            Ok(Ok(stats)) => debug!(
+                files = stats.files_created,
+                bytes = stats.bytes_written,
+                "Raw copy complete"
+            ),
+            Ok(Err(error)) => warn!(%error, "Failed to write raw copy"),
+            Err(error) => warn!(%error, "Raw copy writer task panicked"),
+        }
+    }
+}
+
+/// Forwards items to the processing pipeline after copying the successful ones to the raw
+/// writer, if any. The copy applies backpressure so the raw file stays bit-exact, while a
+/// failed raw writer never stops processing.
+pub(super) struct TeeSender<S, T> {
+    primary: S,
+    raw: Option<RawCopy<T>>,
+}
+
+impl<S, T: Send + 'static> TeeSender<S, T> {
+    pub(super) fn new(primary: S, raw: Option<RawCopy<T>>) -> Self {
+        Self { primary, raw }
+    }
+
+    /// Close the pipeline input and wait for the raw writer to finish.
+    pub(super) async fn close(self) {
+        drop(self.primary);
+        if let Some(raw) = self.raw {
+            raw.finish().await;
+        }
+    }
+}
+
+impl<S, T> StreamSender<T> for TeeSender<S, T>
+where
+    S: StreamSender<T> + Sync,
+    T: Clone + Send,
+{
+    async fn send_item(&self, item: std::result::Result<T, PipelineError>) -> Result<(), ()> {
+        if let (Some(raw), Ok(data)) = (&self.raw, &item) {
+            // A closed channel means the raw writer failed; processing carries on
+            let _ = raw.tx.send(Ok(data.clone())).await;
+        }
+        self.primary.send_item(item).await
+    }
+}
+
 pub(super) struct StreamConsumeContext<'a> {
     pub parent_token: &'a CancellationToken,
     pub child_token: &'a CancellationToken,
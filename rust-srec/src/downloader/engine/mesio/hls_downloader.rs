@@ -21,8 +21,8 @@ use tracing::{debug, info};
 
 use super::super::utils::observe_segment_event_send;
 use super::classify_download_error;
-use super::config::build_hls_config;
-use super::helpers::{self, DownloadStats};
+use super::config::{build_hls_config, build_watchdog_config};
+use super::helpers::{self, DownloadStats, StreamSender};
 use crate::database::models::engine::MesioEngineConfig;
 use crate::downloader::engine::traits::{
     DownloadConfig, DownloadFailureKind, EngineStartError, SegmentEvent,
@@ -44,6 +44,8 @@ pub struct HlsDownloader {
     cancellation_token: CancellationToken,
     /// Base HLS configuration from the engine.
     hls_config: Option<mesio::hls::HlsConfig>,
+    /// The writer's latest progress, reported with the network progress.
+    writer_progress: helpers::SharedWriterProgress,
 }
 
 impl HlsDownloader {
@@ -68,6 +70,7 @@ impl HlsDownloader {
             event_tx,
             cancellation_token,
             hls_config,
+            writer_progress: Default::default(),
         }
     }
 
@@ -84,6 +87,8 @@ impl HlsDownloader {
             hls: hls_config,
             flv: FlvProtocolConfig::default(),
             token,
+            watchdog: build_watchdog_config(&self.engine_config),
+            ..Default::default()
         })
     }
 
@@ -105,22 +110,23 @@ impl HlsDownloader {
         let downloader = self.create_downloader(token.clone());
 
         let config = self.config_snapshot();
-        let streamer_id = config.streamer_id;
-        let url = config.url;
 
-        let request = DownloadRequest::from_url(&url)
+        let request = DownloadRequest::from_url(&config.url)
             .map_err(|e| {
                 let kind = classify_download_error(&e);
                 EngineStartError::new(kind, format!("Invalid HLS download URL: {}", e))
             })?
             .with_protocol(ProtocolSelection::Hls(Default::default()))
             .with_cancel(token.clone());
+        let request = helpers::with_fallback_sources(request, &config);
+        let streamer_id = config.streamer_id;
 
         let session = downloader.start_hls(request).await.map_err(|e| {
             let kind = classify_download_error(&e);
             EngineStartError::new(kind, format!("Failed to start HLS download: {}", e))
         })?;
-        let mut hls_stream = session.items;
+        let mut hls_stream =
+            helpers::report_session_progress(session, &self.event_tx, &self.writer_progress);
 
         // Peek at the first segment to determine file extension
         let first_segment = loop {
@@ -221,7 +227,11 @@ impl HlsDownloader {
         let pipeline_provider =
             HlsPipeline::with_config(context, &pipeline_config, hls_pipeline_config);
 
-        let pipeline = pipeline_provider.build_pipeline();
+        let pipeline = helpers::with_operator_metrics(
+            pipeline_provider.build_pipeline(),
+            &config,
+            HlsData::size,
+        );
 
         let pipeline_common::SpawnedPipeline {
             input_tx: pipeline_input_tx,
@@ -244,15 +254,41 @@ impl HlsDownloader {
             base_name: config.filename_template.clone(),
             extension: extension.to_string(),
             max_file_size,
+            // The pipeline's segment limiter splits on wall-clock boundaries.
+            wall_clock_split: None,
+            playlist_archive: false,
+            continuous_ts: false,
+            captions: None,
         });
 
-        helpers::setup_writer_callbacks(&mut writer, &self.event_tx);
+        helpers::setup_writer_callbacks(&mut writer, &self.event_tx, &self.writer_progress);
 
         // Spawn blocking writer task that reads from pipeline output
         let writer_task = tokio::task::spawn_blocking(move || writer.run(pipeline_output_rx));
 
+        let raw_copy = self.engine_config.keep_raw.then(|| {
+            helpers::RawCopy::spawn(
+                HlsWriter::new(HlsWriterConfig {
+                    output_dir: config.output_dir.clone(),
+                    base_name: format!("{}_raw", config.filename_template),
+                    extension: extension.to_string(),
+                    max_file_size: None,
+                    wall_clock_split: None,
+                    playlist_archive: false,
+                    continuous_ts: false,
+                    captions: None,
+                }),
+                pipeline_config.channel_size,
+            )
+        });
+        let pipeline_input_tx = helpers::TeeSender::new(pipeline_input_tx, raw_copy);
+
         // Send first segment to pipeline input
-        if pipeline_input_tx.send(Ok(first_segment)).await.is_err() {
+        if pipeline_input_tx
+            .send_item(Ok(first_segment))
+            .await
+            .is_err()
+        {
             return Err(EngineStartError::new(
                 DownloadFailureKind::Other,
                 "Pipeline input channel closed unexpectedly",
@@ -287,7 +323,7 @@ impl HlsDownloader {
         .await;
 
         // Close the pipeline input channel to signal completion
-        drop(pipeline_input_tx);
+        pipeline_input_tx.close().await;
 
         // Wait for writer to complete
         let writer_result = writer_task
@@ -353,9 +389,14 @@ impl HlsDownloader {
             base_name: config.filename_template.clone(),
             extension: extension.to_string(),
             max_file_size,
+            wall_clock_split: (config.wall_clock_split_secs > 0)
+                .then(|| std::time::Duration::from_secs(config.wall_clock_split_secs)),
+            playlist_archive: false,
+            continuous_ts: false,
+            captions: None,
         });
 
-        helpers::setup_writer_callbacks(&mut writer, &self.event_tx);
+        helpers::setup_writer_callbacks(&mut writer, &self.event_tx, &self.writer_progress);
 
         // Spawn blocking writer task
         let writer_task = tokio::task::spawn_blocking(move || writer.run(rx.into()));
@@ -1,8 +1,7 @@
 //! Streamlink download engine implementation.
 
 use async_trait::async_trait;
-use chrono::{DateTime, Utc};
-use pipeline_common::expand_filename_template;
+use process_utils::ProcessGroupExt;
 use std::path::PathBuf;
 use std::process::Stdio;
 use std::sync::Arc;
@@ -11,12 +10,11 @@ use tokio::time::{Duration, Instant};
 use tracing::{debug, error, info, warn};
 
 use super::traits::{
-    DownloadConfig, DownloadEngine, DownloadFailureKind, DownloadHandle, EngineStartError,
-    EngineType, SegmentEvent, SegmentInfo,
+    DownloadConfig, DownloadEngine, DownloadFailureKind, DownloadHandle, DownloadProtocol,
+    EngineProbe, EngineStartError, EngineType,
 };
 use super::utils::{
-    OutputRecordReader, is_disk_full_line, is_segment_start, observe_segment_event_send,
-    parse_opened_path, parse_progress,
+    RemuxMonitor, kill_process_tree, probe_piped_binary, remux_args, track_process_tree,
 };
 use crate::Result;
 use crate::database::models::engine::StreamlinkEngineConfig;
@@ -128,61 +126,6 @@ impl StreamlinkEngine {
         args
     }
 
-    /// Build ffmpeg command arguments for remuxing.
-    fn build_ffmpeg_args(&self, config: &DownloadConfig) -> Vec<String> {
-        let mut args = Vec::new();
-
-        // Input from stdin
-        args.extend([
-            "-y".to_string(),
-            "-hide_banner".to_string(),
-            "-i".to_string(),
-            "pipe:0".to_string(),
-        ]);
-
-        // Copy streams without re-encoding
-        args.extend(["-c".to_string(), "copy".to_string()]);
-
-        // Segment options if splitting is enabled
-        if config.max_segment_duration_secs > 0 {
-            args.extend([
-                "-f".to_string(),
-                "segment".to_string(),
-                "-segment_time".to_string(),
-                config.max_segment_duration_secs.to_string(),
-                "-reset_timestamps".to_string(),
-                "1".to_string(),
-                "-strftime".to_string(),
-                "1".to_string(), // Enable strftime expansion for %Y, %m, %d, etc. in filename
-            ]);
-        }
-
-        // Output path (same logic as FFmpeg engine)
-        let output_path = config.output_dir.join(format!(
-            "{}.{}",
-            config.filename_template, config.output_format
-        ));
-
-        if config.max_segment_duration_secs > 0 {
-            // Use segment pattern with strftime enabled by -strftime 1 flag
-            // Convert backslashes to forward slashes for FFmpeg compatibility on Windows
-            let pattern_str = output_path.to_string_lossy().replace('\\', "/");
-            args.push(pattern_str);
-        } else {
-            // Non-segment mode: manually expand strftime patterns
-            // FFmpeg doesn't support -strftime flag in non-segment mode
-            let expanded_template = expand_filename_template(&config.filename_template, None);
-            let final_path = config
-                .output_dir
-                .join(format!("{}.{}", expanded_template, config.output_format));
-            // Convert backslashes to forward slashes for FFmpeg compatibility on Windows
-            let path_str = final_path.to_string_lossy().replace('\\', "/");
-            args.push(path_str);
-        }
-
-        args
-    }
-
     /// Parse streamlink output for status information.
     fn parse_streamlink_output(line: &str) -> Option<StreamlinkStatus> {
         if line.contains("[cli][info] Stream ended") {
@@ -230,7 +173,7 @@ impl DownloadEngine for StreamlinkEngine {
         // `DownloadManager::prepare_output_dir` before this method is called.
         // See the matching comment in ffmpeg.rs for the rationale.
         let streamlink_args = self.build_streamlink_args(&config);
-        let ffmpeg_args = self.build_ffmpeg_args(&config);
+        let ffmpeg_args = remux_args(&config);
         let segment_mode = config.max_segment_duration_secs > 0;
         let single_output_path = if segment_mode {
             None
@@ -248,13 +191,16 @@ impl DownloadEngine for StreamlinkEngine {
         streamlink_command
             .args(&streamlink_args)
             .stdout(Stdio::piped())
-            .stderr(Stdio::piped());
+            .stderr(Stdio::piped())
+            .own_process_group();
         let mut streamlink = streamlink_command.spawn().map_err(|e| {
             EngineStartError::new(
                 DownloadFailureKind::Configuration,
                 format!("Failed to spawn streamlink: {}", e),
             )
         })?;
+        // streamlink may start its own ffmpeg to mux streams
+        let mut streamlink_tree = track_process_tree(&streamlink);
 
         let mut streamlink_stdout = streamlink.stdout.take().ok_or_else(|| {
             EngineStartError::new(
@@ -276,13 +222,15 @@ impl DownloadEngine for StreamlinkEngine {
             .env("LC_ALL", "C")
             .stdin(Stdio::piped())
             .stdout(Stdio::piped())
-            .stderr(Stdio::piped());
+            .stderr(Stdio::piped())
+            .own_process_group();
         let mut ffmpeg = ffmpeg_command.spawn().map_err(|e| {
             EngineStartError::new(
                 DownloadFailureKind::Configuration,
                 format!("Failed to spawn ffmpeg: {}", e),
             )
         })?;
+        let mut ffmpeg_tree = track_process_tree(&ffmpeg);
 
         let mut ffmpeg_stdin = ffmpeg.stdin.take().ok_or_else(|| {
             EngineStartError::new(DownloadFailureKind::Other, "Failed to capture ffmpeg stdin")
@@ -317,6 +265,7 @@ impl DownloadEngine for StreamlinkEngine {
                 }
                 _ = cancellation_token_wait.cancelled() => {
                     debug!("Stop requested, killing streamlink process");
+                    kill_process_tree(streamlink_tree.as_ref());
                     if let Err(e) = streamlink.kill().await {
                         warn!(error = %e, "Failed to kill streamlink process");
                     }
@@ -336,6 +285,7 @@ impl DownloadEngine for StreamlinkEngine {
                 }
                 Err(_) => {
                     warn!("FFmpeg did not exit in time; killing process");
+                    kill_process_tree(ffmpeg_tree.as_ref());
                     if let Err(e) = ffmpeg.kill().await {
                         warn!(error = %e, "Failed to kill ffmpeg process");
                     }
@@ -348,6 +298,13 @@ impl DownloadEngine for StreamlinkEngine {
                     }
                 }
             };
+            // Both processes have been reaped, so their group ids may be reused
+            for tree in [&mut streamlink_tree, &mut ffmpeg_tree]
+                .into_iter()
+                .flatten()
+            {
+                tree.release();
+            }
 
             if exit_tx.send(exit_code).is_err() {
                 debug!("Download exit receiver dropped before streamlink pipeline completed");
@@ -425,335 +382,17 @@ impl DownloadEngine for StreamlinkEngine {
         });
 
         // 3. Spawn task to monitor ffmpeg stderr and emit events - waits for exit status
-        let event_tx_clone = event_tx.clone();
-        let streamer_id_clone = streamer_id.clone();
-        let output_dir_clone = config.output_dir.clone();
-        tokio::spawn(async move {
-            let mut reader = OutputRecordReader::new(ffmpeg_stderr);
-            let mut active_segment: Option<(u32, PathBuf, f64, DateTime<Utc>)> = None;
-            let mut next_segment_index = 0u32;
-            let mut segments_completed = 0u32;
-            let mut total_bytes = 0u64;
-            let mut total_duration = 0.0f64;
-            // Set once when a disk-full signature is detected in stderr so a
-            // later ProcessExit path doesn't double-emit DiskFull. See the
-            // matching comment in ffmpeg.rs for the rationale.
-            let mut disk_full_reported = false;
-            let mut bytes_completed = 0u64;
-            let mut media_duration_offset_secs = 0.0f64;
-            let mut media_duration_total_secs = 0.0f64;
-            let mut cached_active_segment_bytes = 0u64;
-            let mut has_active_segment_fs_bytes = false;
-            let mut last_active_segment_stat_at = Instant::now();
-            let mut last_progress_snapshot: Option<(u64, f64, f64)> = None;
-
-            if let Some(path) = single_output_path {
-                let index = 0u32;
-                next_segment_index = 1;
-                let started_at = Utc::now();
-                active_segment = Some((index, path.clone(), 0.0, started_at));
-                observe_segment_event_send(
-                    event_tx_clone
-                        .send(SegmentEvent::SegmentStarted {
-                            path,
-                            sequence: index,
-                            started_at,
-                        })
-                        .await,
-                    &streamer_id_clone,
-                );
-            }
-
-            loop {
-                tokio::select! {
-                    record_result = reader.next_record() => {
-                        match record_result {
-                            Ok(Some(line)) => {
-                                // Check for segment completion using shared utility
-                                if segment_mode
-                                    && is_segment_start(&line)
-                                    && let Some(path) = parse_opened_path(&line)
-                                {
-                                        // Complete the previous segment when a new one starts.
-                                        if let Some((index, path, started_media_at, started_at)) = active_segment.take() {
-                                            let size_bytes = tokio::fs::metadata(&path)
-                                                .await
-                                                .map(|m| m.len())
-                                                .unwrap_or(0);
-                                            let duration_secs =
-                                                (media_duration_total_secs - started_media_at).max(0.0);
-                                            segments_completed = segments_completed.saturating_add(1);
-                                            bytes_completed = bytes_completed.saturating_add(size_bytes);
-                                            media_duration_offset_secs += duration_secs;
-                                            media_duration_total_secs = media_duration_offset_secs;
-                                            total_bytes = bytes_completed;
-                                            total_duration = media_duration_offset_secs;
-                                            cached_active_segment_bytes = 0;
-                                            observe_segment_event_send(
-                                                event_tx_clone
-                                                    .send(SegmentEvent::SegmentCompleted(SegmentInfo {
-                                                        path,
-                                                        duration_secs,
-                                                        size_bytes,
-                                                        index,
-                                                        started_at: Some(started_at),
-                                                        completed_at: Utc::now(),
-                                                        split_reason_code: None,
-                                                        split_reason_details_json: None,
-                                                    }))
-                                                    .await,
-                                                &streamer_id_clone,
-                                            );
-                                        }
-
-                                        let index = next_segment_index;
-                                        next_segment_index = next_segment_index.saturating_add(1);
-                                        let started_at = Utc::now();
-                                        active_segment = Some((
-                                            index,
-                                            path.clone(),
-                                            media_duration_total_secs,
-                                            started_at,
-                                        ));
-
-                                        observe_segment_event_send(
-                                            event_tx_clone
-                                                .send(SegmentEvent::SegmentStarted {
-                                                    path,
-                                                    sequence: index,
-                                                    started_at,
-                                                })
-                                                .await,
-                                            &streamer_id_clone,
-                                        );
-                                        debug!(
-                                            "Segment {} started for {}",
-                                            index, streamer_id_clone
-                                        );
-                                    }
-
-                                // Parse progress using shared utility
-                                if let Some(mut progress) = parse_progress(&line) {
-                                    let elapsed_secs = started_instant.elapsed().as_secs_f64();
-
-                                    let segment_media_secs = progress.media_duration_secs;
-                                    if segment_mode {
-                                        media_duration_total_secs =
-                                            media_duration_offset_secs + segment_media_secs;
-                                    } else {
-                                        media_duration_total_secs = segment_media_secs;
-                                    }
-
-                                    // Prefer filesystem-backed byte counts since FFmpeg's `size=`
-                                    // can reset or be absent when segmenting.
-                                    let mut bytes_total = progress.bytes_downloaded;
-                                    if let Some((_, path, _, _)) = active_segment.as_ref() {
-                                        let now = Instant::now();
-                                        if now.duration_since(last_active_segment_stat_at)
-                                            >= Duration::from_millis(500)
-                                        {
-                                            let path = path.clone();
-                                            if let Ok(meta) = tokio::fs::metadata(&path).await {
-                                                cached_active_segment_bytes = meta.len();
-                                                has_active_segment_fs_bytes = true;
-                                            }
-                                            last_active_segment_stat_at = now;
-                                        }
-
-                                        let fs_total = if segment_mode {
-                                            bytes_completed.saturating_add(cached_active_segment_bytes)
-                                        } else {
-                                            cached_active_segment_bytes
-                                        };
-                                        let parsed_total = if segment_mode {
-                                            bytes_completed.saturating_add(progress.bytes_downloaded)
-                                        } else {
-                                            progress.bytes_downloaded
-                                        };
-                                        bytes_total = if has_active_segment_fs_bytes {
-                                            fs_total
-                                        } else {
-                                            parsed_total
-                                        };
-                                    } else if segment_mode {
-                                        bytes_total = bytes_completed.saturating_add(bytes_total);
-                                    }
-
-                                    total_bytes = bytes_total;
-                                    total_duration = media_duration_total_secs;
-
-                                    progress.bytes_downloaded = bytes_total;
-                                    progress.duration_secs = elapsed_secs;
-                                    progress.media_duration_secs = media_duration_total_secs;
-                                    progress.segments_completed = segments_completed;
-                                    progress.current_segment = active_segment
-                                        .as_ref()
-                                        .map(|(_, p, _, _)| p.to_string_lossy().to_string());
-
-                                    progress.speed_bytes_per_sec = last_progress_snapshot
-                                        .and_then(|(prev_bytes, prev_elapsed, _)| {
-                                            let dt = elapsed_secs - prev_elapsed;
-                                            (dt > 0.0).then_some(
-                                                ((bytes_total.saturating_sub(prev_bytes)) as f64 / dt) as u64,
-                                            )
-                                        })
-                                        .unwrap_or(0);
-                                    progress.playback_ratio = last_progress_snapshot
-                                        .and_then(|(_, prev_elapsed, prev_media)| {
-                                            let dt = elapsed_secs - prev_elapsed;
-                                            (dt > 0.0)
-                                                .then_some((media_duration_total_secs - prev_media) / dt)
-                                        })
-                                        .unwrap_or(0.0);
-                                    last_progress_snapshot =
-                                        Some((bytes_total, elapsed_secs, media_duration_total_secs));
-
-                                    observe_segment_event_send(
-                                        event_tx_clone.send(SegmentEvent::Progress(progress)).await,
-                                        &streamer_id_clone,
-                                    );
-                                }
-
-                                // Detect mid-stream disk-full. Same pattern as
-                                // ffmpeg.rs — see the matching block there
-                                // for the rationale. Streamlink feeds stderr
-                                // from the spawned ffmpeg process, so the
-                                // same signatures apply.
-                                if !disk_full_reported && is_disk_full_line(&line) {
-                                    disk_full_reported = true;
-                                    warn!(
-                                        streamer_id = %streamer_id_clone,
-                                        output_dir = %output_dir_clone.display(),
-                                        "Streamlink+FFmpeg signalled disk full; emitting DiskFull event for gate"
-                                    );
-                                    observe_segment_event_send(
-                                        event_tx_clone
-                                            .send(SegmentEvent::DiskFull {
-                                                output_dir: output_dir_clone.clone(),
-                                                detail: format!("streamlink+ffmpeg: {}", line),
-                                            })
-                                            .await,
-                                        &streamer_id_clone,
-                                    );
-                                }
-                            }
-                            Ok(None) => {
-                                debug!("FFmpeg process ended for {}", streamer_id_clone);
-                                break;
-                            }
-                            Err(e) => {
-                                error!("Error reading ffmpeg stderr: {}", e);
-                                break;
-                            }
-                        }
-                    }
-                }
-            }
-
-            // Complete the last active segment (if any).
-            if let Some((index, path, started_media_at, started_at)) = active_segment.take() {
-                let size_bytes = tokio::fs::metadata(&path)
-                    .await
-                    .map(|m| m.len())
-                    .unwrap_or(0);
-                let duration_secs = (media_duration_total_secs - started_media_at).max(0.0);
-                segments_completed = segments_completed.saturating_add(1);
-                bytes_completed = bytes_completed.saturating_add(size_bytes);
-                total_bytes = bytes_completed;
-                if segment_mode {
-                    media_duration_offset_secs += duration_secs;
-                    total_duration = media_duration_offset_secs;
-                } else {
-                    total_duration = media_duration_total_secs;
-                }
-                observe_segment_event_send(
-                    event_tx_clone
-                        .send(SegmentEvent::SegmentCompleted(SegmentInfo {
-                            path,
-                            duration_secs,
-                            size_bytes,
-                            index,
-                            started_at: Some(started_at),
-                            completed_at: Utc::now(),
-                            split_reason_code: None,
-                            split_reason_details_json: None,
-                        }))
-                        .await,
-                    &streamer_id_clone,
-                );
-            }
-
-            // Wait for exit status from process wait task
-            let exit_code = exit_rx.await.ok().flatten();
-
-            match exit_code {
-                Some(0) => {
-                    // Exit code 0 — same caveat as ffmpeg: the subprocess
-                    // exited cleanly but that doesn't prove the upstream
-                    // stream is over. SessionLifecycle treats
-                    // SubprocessExitZero as ambiguous → hysteresis.
-                    observe_segment_event_send(
-                        event_tx_clone
-                            .send(SegmentEvent::DownloadCompleted {
-                                total_bytes,
-                                total_duration_secs: total_duration,
-                                total_segments: segments_completed,
-                                engine_signal:
-                                    crate::downloader::EngineEndSignal::SubprocessExitZero,
-                            })
-                            .await,
-                        &streamer_id_clone,
-                    );
-                }
-                Some(code) => {
-                    // Fallback DiskFull emission for exit code 228 if we
-                    // didn't already catch it from stderr. Mirrors ffmpeg.rs.
-                    if code == 228 && !disk_full_reported {
-                        // The stderr loop has already exited by this point,
-                        // so we don't need to update `disk_full_reported` —
-                        // the variable will not be read again.
-                        warn!(
-                            streamer_id = %streamer_id_clone,
-                            output_dir = %output_dir_clone.display(),
-                            "Streamlink+FFmpeg exited with code 228; assuming disk-full"
-                        );
-                        observe_segment_event_send(
-                            event_tx_clone
-                                .send(SegmentEvent::DiskFull {
-                                    output_dir: output_dir_clone.clone(),
-                                    detail: "streamlink+ffmpeg exit 228 (I/O error, likely ENOSPC)"
-                                        .to_string(),
-                                })
-                                .await,
-                            &streamer_id_clone,
-                        );
-                    }
-
-                    // Non-zero exit code - failure
-                    observe_segment_event_send(
-                        event_tx_clone
-                            .send(SegmentEvent::DownloadFailed {
-                                kind: DownloadFailureKind::ProcessExit { code: Some(code) },
-                                message: format!("Streamlink/FFmpeg exited with code {}", code),
-                            })
-                            .await,
-                        &streamer_id_clone,
-                    );
-                }
-                None => {
-                    observe_segment_event_send(
-                        event_tx_clone
-                            .send(SegmentEvent::DownloadFailed {
-                                kind: DownloadFailureKind::ProcessExit { code: None },
-                                message: "Streamlink/FFmpeg exited without an exit code"
-                                    .to_string(),
-                            })
-                            .await,
-                        &streamer_id_clone,
-                    );
-                }
-            }
-        });
+        let monitor = RemuxMonitor {
+            event_tx,
+            streamer_id,
+            output_dir: config.output_dir.clone(),
+            source: "Streamlink",
+            segment_mode,
+            single_output_path,
+            started_instant,
+            download_speed: None,
+        };
+        tokio::spawn(monitor.run(ffmpeg_stderr, exit_rx));
 
         Ok(())
     }
@@ -772,6 +411,13 @@ impl DownloadEngine for StreamlinkEngine {
     fn version(&self) -> Option<String> {
         self.version.clone()
     }
+
+    async fn probe(&self) -> EngineProbe {
+        EngineProbe::from_check(
+            probe_piped_binary(&self.config.binary_path, &self.ffmpeg_path).await,
+            vec![DownloadProtocol::Hls, DownloadProtocol::Flv],
+        )
+    }
 }
 
 #[cfg(test)]
@@ -14,6 +14,7 @@ use tokio::sync::mpsc;
 use tokio_util::sync::CancellationToken;
 
 use crate::Result;
+use crate::metrics::MetricsCollector;
 
 /// Type of download engine.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
@@ -26,6 +27,8 @@ pub enum EngineType {
     Streamlink,
     /// Native Mesio engine.
     Mesio,
+    /// yt-dlp-based download.
+    YtDlp,
 }
 
 impl EngineType {
@@ -35,6 +38,7 @@ impl EngineType {
             Self::Ffmpeg => "ffmpeg",
             Self::Streamlink => "streamlink",
             Self::Mesio => "mesio",
+            Self::YtDlp => "ytdlp",
         }
     }
 }
@@ -47,6 +51,7 @@ impl std::str::FromStr for EngineType {
             "ffmpeg" => Ok(Self::Ffmpeg),
             "streamlink" => Ok(Self::Streamlink),
             "mesio" => Ok(Self::Mesio),
+            "ytdlp" | "yt-dlp" | "yt_dlp" => Ok(Self::YtDlp),
             _ => Err(format!("Unknown engine type: {}", s)),
         }
     }
@@ -59,7 +64,9 @@ impl std::fmt::Display for EngineType {
 }
 
 /// Stream protocol selected for a download.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default, utoipa::ToSchema,
+)]
 #[serde(rename_all = "lowercase")]
 pub enum DownloadProtocol {
     /// HLS playlist/segment stream.
@@ -96,6 +103,9 @@ impl DownloadProtocol {
 pub struct DownloadConfig {
     /// Stream URL to download.
     pub url: String,
+    /// URLs of the same stream on other CDNs, in order of preference. Engines that support it
+    /// switch to them when `url` fails mid-download.
+    pub fallback_urls: Vec<String>,
     /// Output directory.
     pub output_dir: PathBuf,
     /// Output filename template.
@@ -106,6 +116,9 @@ pub struct DownloadConfig {
     pub max_segment_duration_secs: u64,
     /// Maximum segment size in bytes (0 = no limit).
     pub max_segment_size_bytes: u64,
+    /// Split at every multiple of this many seconds since local midnight,
+    /// e.g. 3600 for every full hour or 86400 for midnight (0 = off).
+    pub wall_clock_split_secs: u64,
     /// Proxy URL (if any).
     pub proxy_url: Option<String>,
     /// Whether to use system proxy settings (ignored if proxy_url is set).
@@ -120,6 +133,10 @@ pub struct DownloadConfig {
     pub streamer_name: String,
     /// Session ID for tracking.
     pub session_id: String,
+    /// Platform the stream is recorded from (e.g. "huya").
+    pub platform: String,
+    /// Whether danmu is recorded next to each segment.
+    pub record_danmu: bool,
     /// First session-scoped segment index for this download attempt.
     pub initial_segment_index: u32,
     /// Stream protocol selected for this download.
@@ -146,6 +163,10 @@ pub struct DownloadConfig {
     /// Override configuration for engines.
     /// Map of engine_id -> config value.
     pub engines_override: Option<serde_json::Value>,
+
+    /// Collector exposing per-operator metrics of the stream pipeline.
+    /// Set by the download manager when metrics are enabled.
+    pub metrics_collector: Option<Arc<MetricsCollector>>,
 }
 
 impl DownloadConfig {
@@ -159,11 +180,13 @@ impl DownloadConfig {
     ) -> Self {
         Self {
             url: url.into(),
+            fallback_urls: Vec::new(),
             output_dir: output_dir.into(),
             filename_template: "{streamer}-%Y%m%d-%H%M%S-{title}".to_string(),
             output_format: "flv".to_string(),
             max_segment_duration_secs: 0,
             max_segment_size_bytes: 0,
+            wall_clock_split_secs: 0,
             proxy_url: None,
             use_system_proxy: false,
             cookies: None,
@@ -171,6 +194,8 @@ impl DownloadConfig {
             streamer_id: streamer_id.into(),
             streamer_name: streamer_name.into(),
             session_id: session_id.into(),
+            platform: String::new(),
+            record_danmu: false,
             initial_segment_index: 0,
             protocol: DownloadProtocol::Unknown,
             enable_processing: true,
@@ -178,9 +203,16 @@ impl DownloadConfig {
             hls_pipeline_config: None,
             flv_pipeline_config: None,
             engines_override: None,
+            metrics_collector: None,
         }
     }
 
+    /// Set the URLs of the same stream on other CDNs, in order of preference.
+    pub fn with_fallback_urls(mut self, urls: Vec<String>) -> Self {
+        self.fallback_urls = urls;
+        self
+    }
+
     /// Set the filename template.
     pub fn with_filename_template(mut self, template: impl Into<String>) -> Self {
         self.filename_template = template.into();
@@ -193,6 +225,18 @@ impl DownloadConfig {
         self
     }
 
+    /// Set the platform the stream is recorded from.
+    pub fn with_platform(mut self, platform: impl Into<String>) -> Self {
+        self.platform = platform.into();
+        self
+    }
+
+    /// Set whether danmu is recorded next to each segment.
+    pub fn with_record_danmu(mut self, record_danmu: bool) -> Self {
+        self.record_danmu = record_danmu;
+        self
+    }
+
     /// Set the stream protocol selected for the download.
     pub fn with_protocol(mut self, protocol: DownloadProtocol) -> Self {
         self.protocol = protocol;
@@ -217,6 +261,12 @@ impl DownloadConfig {
         self
     }
 
+    /// Set the wall-clock split interval.
+    pub fn with_wall_clock_split(mut self, secs: u64) -> Self {
+        self.wall_clock_split_secs = secs;
+        self
+    }
+
     /// Set the proxy URL (disables system proxy).
     pub fn with_proxy(mut self, url: impl Into<String>) -> Self {
         self.proxy_url = Some(url.into());
@@ -289,6 +339,10 @@ impl DownloadConfig {
                 builder = builder.max_duration(Duration::from_secs(self.max_segment_duration_secs));
             }
 
+            if self.wall_clock_split_secs > 0 {
+                builder = builder.wall_clock_split(Duration::from_secs(self.wall_clock_split_secs));
+            }
+
             builder.build()
         }
     }
@@ -358,6 +412,10 @@ pub struct DownloadProgress {
     pub media_duration_secs: f64,
     /// Playback ratio: media_duration / elapsed_time (>1.0 = faster than real-time).
     pub playback_ratio: f64,
+    /// Milliseconds taken to fetch the last segment, for segmented protocols.
+    pub segment_latency_ms: Option<u64>,
+    /// Whether no data has arrived for a while.
+    pub stalled: bool,
 }
 
 impl Default for DownloadProgress {
@@ -370,6 +428,8 @@ impl Default for DownloadProgress {
             current_segment: None,
             media_duration_secs: 0.0,
             playback_ratio: 0.0,
+            segment_latency_ms: None,
+            stalled: false,
         }
     }
 }
@@ -391,6 +451,9 @@ pub struct SegmentInfo {
     pub completed_at: DateTime<Utc>,
     pub split_reason_code: Option<String>,
     pub split_reason_details_json: Option<String>,
+    /// Analysis report of the segment as JSON (see `flv_fix::AnalysisReport` and
+    /// `hls_fix::AnalysisReport`).
+    pub analysis_report_json: Option<String>,
 }
 
 /// Serializable subset of [`std::io::ErrorKind`] used by the output-root write gate.
@@ -654,6 +717,8 @@ pub struct DownloadHandle {
     pub cancellation_token: CancellationToken,
     /// Event sender for segment events.
     pub event_tx: mpsc::Sender<SegmentEvent>,
+    /// Chapter markers, placed in the output by engines that support them.
+    pub chapter_markers: flv_fix::ChapterMarkers,
     /// Start time.
     pub started_at: DateTime<Utc>,
 }
@@ -672,6 +737,7 @@ impl DownloadHandle {
             config: Arc::new(RwLock::new(config)),
             cancellation_token: CancellationToken::new(),
             event_tx,
+            chapter_markers: flv_fix::ChapterMarkers::default(),
             started_at: Utc::now(),
         }
     }
@@ -722,6 +788,43 @@ pub struct DownloadInfo {
     pub started_at: DateTime<Utc>,
 }
 
+/// Health of a download engine, as reported by [`DownloadEngine::probe`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, utoipa::ToSchema)]
+pub struct EngineProbe {
+    /// Whether the engine can start downloads.
+    pub available: bool,
+    /// Version reported by the engine.
+    pub version: Option<String>,
+    /// Stream protocols the engine can record.
+    pub protocols: Vec<DownloadProtocol>,
+    /// Why the engine is unavailable, e.g. a missing binary or a failing
+    /// version check.
+    pub error: Option<String>,
+}
+
+impl EngineProbe {
+    /// Build a probe from the result of checking the engine's binaries.
+    pub fn from_check(
+        check: std::result::Result<String, String>,
+        protocols: Vec<DownloadProtocol>,
+    ) -> Self {
+        match check {
+            Ok(version) => Self {
+                available: true,
+                version: Some(version),
+                protocols,
+                error: None,
+            },
+            Err(error) => Self {
+                available: false,
+                version: None,
+                protocols,
+                error: Some(error),
+            },
+        }
+    }
+}
+
 /// Trait for download engines.
 #[async_trait]
 pub trait DownloadEngine: Send + Sync {
@@ -745,6 +848,20 @@ pub trait DownloadEngine: Send + Sync {
 
     /// Get the engine version string.
     fn version(&self) -> Option<String>;
+
+    /// Check the engine's health now.
+    ///
+    /// Unlike [`is_available`](Self::is_available), which reflects the
+    /// check made when the engine was built, this runs the binaries again
+    /// so a missing or broken install shows up before a download fails.
+    async fn probe(&self) -> EngineProbe {
+        let check = if self.is_available() {
+            Ok(self.version().unwrap_or_default())
+        } else {
+            Err(format!("{} engine is not available", self.engine_type()))
+        };
+        EngineProbe::from_check(check, Vec::new())
+    }
 }
 
 #[cfg(test)]
@@ -766,6 +883,7 @@ mod tests {
             Some(EngineType::Streamlink)
         );
         assert_eq!("mesio".parse::<EngineType>().ok(), Some(EngineType::Mesio));
+        assert_eq!("yt-dlp".parse::<EngineType>().ok(), Some(EngineType::YtDlp));
         assert_eq!("unknown".parse::<EngineType>().ok(), None);
     }
 
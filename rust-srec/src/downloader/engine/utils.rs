@@ -5,12 +5,15 @@ use tracing::debug;
 
 use super::traits::SegmentEvent;
 
+mod binary_probe;
 mod disk_full;
 mod ffmpeg_parser;
 mod files;
 mod output_record_reader;
 mod process_runner;
+mod remux_monitor;
 
+pub use binary_probe::{probe_binary, probe_piped_binary};
 pub use disk_full::is_disk_full_line;
 pub use ffmpeg_parser::{
     is_segment_start, parse_bitrate, parse_opened_path, parse_progress, parse_size, parse_speed,
@@ -18,7 +21,10 @@ pub use ffmpeg_parser::{
 };
 pub use files::ensure_output_dir;
 pub use output_record_reader::OutputRecordReader;
-pub use process_runner::{spawn_piped_process_waiter, spawn_process_waiter};
+pub use process_runner::{
+    kill_process_tree, spawn_piped_process_waiter, spawn_process_waiter, track_process_tree,
+};
+pub use remux_monitor::{RemuxMonitor, remux_args};
 
 pub(super) fn observe_segment_event_send(
     result: Result<(), mpsc::error::SendError<SegmentEvent>>,
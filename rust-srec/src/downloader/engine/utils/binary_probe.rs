@@ -0,0 +1,76 @@
+//! Health probe for external engine binaries.
+
+use std::io::ErrorKind;
+use std::time::Duration;
+
+/// How long a binary may take to print its version.
+const PROBE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Run `path args` and return the first line it prints, or a message
+/// explaining why the binary is unusable.
+pub async fn probe_binary(path: &str, args: &[&str]) -> Result<String, String> {
+    let mut cmd = process_utils::tokio_command(path);
+    cmd.args(args).kill_on_drop(true);
+
+    let output = match tokio::time::timeout(PROBE_TIMEOUT, cmd.output()).await {
+        Ok(Ok(output)) => output,
+        Ok(Err(e)) if e.kind() == ErrorKind::NotFound => {
+            return Err(format!("`{path}` not found"));
+        }
+        Ok(Err(e)) => return Err(format!("failed to run `{path}`: {e}")),
+        Err(_) => {
+            return Err(format!(
+                "`{path}` did not respond within {}s",
+                PROBE_TIMEOUT.as_secs()
+            ));
+        }
+    };
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(match first_line(&stderr) {
+            Some(line) => format!("`{path}` exited with {}: {line}", output.status),
+            None => format!("`{path}` exited with {}", output.status),
+        });
+    }
+
+    first_line(&String::from_utf8_lossy(&output.stdout))
+        .map(ToString::to_string)
+        .ok_or_else(|| format!("`{path}` printed no version"))
+}
+
+/// Probe an engine that prints its version with `--version` and pipes its
+/// output through FFmpeg for remuxing.
+pub async fn probe_piped_binary(path: &str, ffmpeg_path: &str) -> Result<String, String> {
+    let version = probe_binary(path, &["--version"]).await?;
+    probe_binary(ffmpeg_path, &["-version"])
+        .await
+        .map_err(|e| format!("{e}; FFmpeg is needed to remux the output"))?;
+    Ok(version)
+}
+
+fn first_line(output: &str) -> Option<&str> {
+    output.lines().map(str::trim).find(|line| !line.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn reports_missing_binary() {
+        let err = probe_binary("rust-srec-missing-binary", &["--version"])
+            .await
+            .unwrap_err();
+        assert!(err.contains("not found"), "{err}");
+    }
+
+    #[test]
+    fn skips_leading_blank_lines() {
+        assert_eq!(
+            first_line("\n  \nffmpeg version 7.1\n"),
+            Some("ffmpeg version 7.1")
+        );
+        assert_eq!(first_line("  \n"), None);
+    }
+}
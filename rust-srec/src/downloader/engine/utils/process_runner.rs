@@ -3,16 +3,39 @@
 //! Provides abstractions for spawning and managing child processes
 //! with cancellation support.
 
+use process_utils::ProcessTree;
 use tokio::process::Child;
 use tokio::sync::oneshot;
 use tokio_util::sync::CancellationToken;
 use tracing::{debug, error, warn};
 
+/// Track the process tree of a child spawned with
+/// [`ProcessGroupExt::own_process_group`](process_utils::ProcessGroupExt::own_process_group),
+/// so stopping it also stops everything it started.
+///
+/// Returns `None` if the tree cannot be tracked, in which case only the child itself
+/// can be killed. Release the tree once the child has been reaped.
+pub fn track_process_tree(child: &Child) -> Option<ProcessTree> {
+    ProcessTree::from_tokio(child)
+        .inspect_err(|e| warn!(error = %e, "Failed to track child process tree"))
+        .ok()
+}
+
+/// Kill a tracked process tree; call before killing the child itself.
+pub fn kill_process_tree(tree: Option<&ProcessTree>) {
+    if let Some(tree) = tree
+        && let Err(e) = tree.kill()
+    {
+        warn!(error = %e, "Failed to kill child process tree");
+    }
+}
+
 /// Spawn a task that waits for a process to exit and sends the result
 /// through a oneshot channel.
 ///
-/// If the cancellation token is triggered, the process is killed and
-/// `None` is sent through the channel.
+/// If the cancellation token is triggered, the process and, if it leads its own
+/// process group, everything it started are killed and `None` is sent through
+/// the channel.
 ///
 /// # Arguments
 /// * `child` - The child process to wait for
@@ -27,10 +50,12 @@ pub fn spawn_process_waiter(
     cancellation_token: CancellationToken,
 ) -> oneshot::Receiver<Option<i32>> {
     let (tx, rx) = oneshot::channel();
+    let mut tree = ProcessTree::from_tokio(&child).ok();
 
     tokio::spawn(async move {
         let exit_code = tokio::select! {
             _ = cancellation_token.cancelled() => {
+                kill_process_tree(tree.as_ref());
                 if let Err(e) = child.kill().await {
                     warn!(error = %e, "Failed to kill cancelled process");
                 }
@@ -54,6 +79,10 @@ pub fn spawn_process_waiter(
                 }
             }
         };
+        // The process has been reaped, so its group id may be reused
+        if let Some(tree) = &mut tree {
+            tree.release();
+        }
         if tx.send(exit_code).is_err() {
             debug!("Process exit receiver dropped before waiter completed");
         }
@@ -83,13 +112,17 @@ pub fn spawn_piped_process_waiter(
     cancellation_token: CancellationToken,
 ) -> oneshot::Receiver<Option<i32>> {
     let (tx, rx) = oneshot::channel();
+    let mut first_tree = ProcessTree::from_tokio(&first).ok();
+    let mut second_tree = ProcessTree::from_tokio(&second).ok();
 
     tokio::spawn(async move {
         let exit_code = tokio::select! {
             _ = cancellation_token.cancelled() => {
+                kill_process_tree(first_tree.as_ref());
                 if let Err(e) = first.kill().await {
                     warn!(error = %e, "Failed to kill cancelled producer process");
                 }
+                kill_process_tree(second_tree.as_ref());
                 if let Err(e) = second.kill().await {
                     warn!(error = %e, "Failed to kill cancelled consumer process");
                 }
@@ -118,6 +151,9 @@ pub fn spawn_piped_process_waiter(
                 }
             }
         };
+        for tree in [&mut first_tree, &mut second_tree].into_iter().flatten() {
+            tree.release();
+        }
         if tx.send(exit_code).is_err() {
             debug!("Piped process exit receiver dropped before waiter completed");
         }
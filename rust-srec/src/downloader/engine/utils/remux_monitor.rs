@@ -0,0 +1,440 @@
+//! Monitoring of an FFmpeg process remuxing the stream piped in by another tool.
+//!
+//! Engines such as Streamlink and yt-dlp write the stream to stdout and let FFmpeg
+//! remux and split it. The monitor turns FFmpeg's stderr into segment, progress and
+//! disk-full events and reports the outcome once FFmpeg has exited.
+
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use chrono::{DateTime, Utc};
+use pipeline_common::expand_filename_template;
+use tokio::process::ChildStderr;
+use tokio::sync::{mpsc, oneshot};
+use tokio::time::{Duration, Instant};
+use tracing::{debug, error, warn};
+
+use super::{
+    OutputRecordReader, is_disk_full_line, is_segment_start, observe_segment_event_send,
+    parse_opened_path, parse_progress,
+};
+use crate::downloader::engine::traits::{
+    DownloadConfig, DownloadFailureKind, SegmentEvent, SegmentInfo,
+};
+
+/// Build the arguments of an FFmpeg process remuxing the stream piped to its stdin.
+pub fn remux_args(config: &DownloadConfig) -> Vec<String> {
+    let mut args = Vec::new();
+
+    // Input from stdin
+    args.extend([
+        "-y".to_string(),
+        "-hide_banner".to_string(),
+        "-i".to_string(),
+        "pipe:0".to_string(),
+    ]);
+
+    // Copy streams without re-encoding
+    args.extend(["-c".to_string(), "copy".to_string()]);
+
+    // Segment options if splitting is enabled
+    if config.max_segment_duration_secs > 0 {
+        args.extend([
+            "-f".to_string(),
+            "segment".to_string(),
+            "-segment_time".to_string(),
+            config.max_segment_duration_secs.to_string(),
+            "-reset_timestamps".to_string(),
+            "1".to_string(),
+            "-strftime".to_string(),
+            "1".to_string(), // Enable strftime expansion for %Y, %m, %d, etc. in filename
+        ]);
+    }
+
+    // Output path (same logic as FFmpeg engine)
+    let output_path = config.output_dir.join(format!(
+        "{}.{}",
+        config.filename_template, config.output_format
+    ));
+
+    if config.max_segment_duration_secs > 0 {
+        // Use segment pattern with strftime enabled by -strftime 1 flag
+        // Convert backslashes to forward slashes for FFmpeg compatibility on Windows
+        let pattern_str = output_path.to_string_lossy().replace('\\', "/");
+        args.push(pattern_str);
+    } else {
+        // Non-segment mode: manually expand strftime patterns
+        // FFmpeg doesn't support -strftime flag in non-segment mode
+        let expanded_template = expand_filename_template(&config.filename_template, None);
+        let final_path = config
+            .output_dir
+            .join(format!("{}.{}", expanded_template, config.output_format));
+        // Convert backslashes to forward slashes for FFmpeg compatibility on Windows
+        let path_str = final_path.to_string_lossy().replace('\\', "/");
+        args.push(path_str);
+    }
+
+    args
+}
+
+/// Context of a remuxing FFmpeg process.
+pub struct RemuxMonitor {
+    pub event_tx: mpsc::Sender<SegmentEvent>,
+    pub streamer_id: String,
+    pub output_dir: PathBuf,
+    /// Name of the tool feeding FFmpeg, used in messages (e.g. "Streamlink").
+    pub source: &'static str,
+    /// Whether FFmpeg splits the output with the segment muxer.
+    pub segment_mode: bool,
+    /// Output file when not splitting, reported as the only segment.
+    pub single_output_path: Option<PathBuf>,
+    pub started_instant: Instant,
+    /// Network speed in bytes per second reported by the source, preferred over
+    /// the speed derived from the output size.
+    pub download_speed: Option<Arc<AtomicU64>>,
+}
+
+impl RemuxMonitor {
+    /// Follow FFmpeg's stderr until it closes, then report the exit code received on `exit_rx`.
+    pub async fn run(self, ffmpeg_stderr: ChildStderr, exit_rx: oneshot::Receiver<Option<i32>>) {
+        let mut reader = OutputRecordReader::new(ffmpeg_stderr);
+        let mut active_segment: Option<(u32, PathBuf, f64, DateTime<Utc>)> = None;
+        let mut next_segment_index = 0u32;
+        let mut segments_completed = 0u32;
+        let mut total_bytes = 0u64;
+        let mut total_duration = 0.0f64;
+        // Set once when a disk-full signature is detected in stderr so a
+        // later ProcessExit path doesn't double-emit DiskFull. See the
+        // matching comment in ffmpeg.rs for the rationale.
+        let mut disk_full_reported = false;
+        let mut bytes_completed = 0u64;
+        let mut media_duration_offset_secs = 0.0f64;
+        let mut media_duration_total_secs = 0.0f64;
+        let mut cached_active_segment_bytes = 0u64;
+        let mut has_active_segment_fs_bytes = false;
+        let mut last_active_segment_stat_at = Instant::now();
+        let mut last_progress_snapshot: Option<(u64, f64, f64)> = None;
+
+        if let Some(path) = self.single_output_path {
+            let index = 0u32;
+            next_segment_index = 1;
+            let started_at = Utc::now();
+            active_segment = Some((index, path.clone(), 0.0, started_at));
+            observe_segment_event_send(
+                self.event_tx
+                    .send(SegmentEvent::SegmentStarted {
+                        path,
+                        sequence: index,
+                        started_at,
+                    })
+                    .await,
+                &self.streamer_id,
+            );
+        }
+
+        loop {
+            tokio::select! {
+                record_result = reader.next_record() => {
+                    match record_result {
+                        Ok(Some(line)) => {
+                            // Check for segment completion using shared utility
+                            if self.segment_mode
+                                && is_segment_start(&line)
+                                && let Some(path) = parse_opened_path(&line)
+                            {
+                                    // Complete the previous segment when a new one starts.
+                                    if let Some((index, path, started_media_at, started_at)) = active_segment.take() {
+                                        let size_bytes = tokio::fs::metadata(&path)
+                                            .await
+                                            .map(|m| m.len())
+                                            .unwrap_or(0);
+                                        let duration_secs =
+                                            (media_duration_total_secs - started_media_at).max(0.0);
+                                        segments_completed = segments_completed.saturating_add(1);
+                                        bytes_completed = bytes_completed.saturating_add(size_bytes);
+                                        media_duration_offset_secs += duration_secs;
+                                        media_duration_total_secs = media_duration_offset_secs;
+                                        total_bytes = bytes_completed;
+                                        total_duration = media_duration_offset_secs;
+                                        cached_active_segment_bytes = 0;
+                                        observe_segment_event_send(
+                                            self.event_tx
+                                                .send(SegmentEvent::SegmentCompleted(SegmentInfo {
+                                                    path,
+                                                    duration_secs,
+                                                    size_bytes,
+                                                    index,
+                                                    started_at: Some(started_at),
+                                                    completed_at: Utc::now(),
+                                                    split_reason_code: None,
+                                                    split_reason_details_json: None,
+                                                    analysis_report_json: None,
+                                                }))
+                                                .await,
+                                            &self.streamer_id,
+                                        );
+                                    }
+
+                                    let index = next_segment_index;
+                                    next_segment_index = next_segment_index.saturating_add(1);
+                                    let started_at = Utc::now();
+                                    active_segment = Some((
+                                        index,
+                                        path.clone(),
+                                        media_duration_total_secs,
+                                        started_at,
+                                    ));
+
+                                    observe_segment_event_send(
+                                        self.event_tx
+                                            .send(SegmentEvent::SegmentStarted {
+                                                path,
+                                                sequence: index,
+                                                started_at,
+                                            })
+                                            .await,
+                                        &self.streamer_id,
+                                    );
+                                    debug!(
+                                        "Segment {} started for {}",
+                                        index, self.streamer_id
+                                    );
+                                }
+
+                            // Parse progress using shared utility
+                            if let Some(mut progress) = parse_progress(&line) {
+                                let elapsed_secs = self.started_instant.elapsed().as_secs_f64();
+
+                                let segment_media_secs = progress.media_duration_secs;
+                                if self.segment_mode {
+                                    media_duration_total_secs =
+                                        media_duration_offset_secs + segment_media_secs;
+                                } else {
+                                    media_duration_total_secs = segment_media_secs;
+                                }
+
+                                // Prefer filesystem-backed byte counts since FFmpeg's `size=`
+                                // can reset or be absent when segmenting.
+                                let mut bytes_total = progress.bytes_downloaded;
+                                if let Some((_, path, _, _)) = active_segment.as_ref() {
+                                    let now = Instant::now();
+                                    if now.duration_since(last_active_segment_stat_at)
+                                        >= Duration::from_millis(500)
+                                    {
+                                        let path = path.clone();
+                                        if let Ok(meta) = tokio::fs::metadata(&path).await {
+                                            cached_active_segment_bytes = meta.len();
+                                            has_active_segment_fs_bytes = true;
+                                        }
+                                        last_active_segment_stat_at = now;
+                                    }
+
+                                    let fs_total = if self.segment_mode {
+                                        bytes_completed.saturating_add(cached_active_segment_bytes)
+                                    } else {
+                                        cached_active_segment_bytes
+                                    };
+                                    let parsed_total = if self.segment_mode {
+                                        bytes_completed.saturating_add(progress.bytes_downloaded)
+                                    } else {
+                                        progress.bytes_downloaded
+                                    };
+                                    bytes_total = if has_active_segment_fs_bytes {
+                                        fs_total
+                                    } else {
+                                        parsed_total
+                                    };
+                                } else if self.segment_mode {
+                                    bytes_total = bytes_completed.saturating_add(bytes_total);
+                                }
+
+                                total_bytes = bytes_total;
+                                total_duration = media_duration_total_secs;
+
+                                progress.bytes_downloaded = bytes_total;
+                                progress.duration_secs = elapsed_secs;
+                                progress.media_duration_secs = media_duration_total_secs;
+                                progress.segments_completed = segments_completed;
+                                progress.current_segment = active_segment
+                                    .as_ref()
+                                    .map(|(_, p, _, _)| p.to_string_lossy().to_string());
+
+                                // Prefer the network speed reported by the producer, if any
+                                progress.speed_bytes_per_sec = self
+                                    .download_speed
+                                    .as_ref()
+                                    .map(|speed| speed.load(Ordering::Relaxed))
+                                    .filter(|&speed| speed > 0)
+                                    .or_else(|| {
+                                        last_progress_snapshot.and_then(
+                                            |(prev_bytes, prev_elapsed, _)| {
+                                                let dt = elapsed_secs - prev_elapsed;
+                                                (dt > 0.0).then_some(
+                                                    ((bytes_total.saturating_sub(prev_bytes)) as f64
+                                                        / dt) as u64,
+                                                )
+                                            },
+                                        )
+                                    })
+                                    .unwrap_or(0);
+                                progress.playback_ratio = last_progress_snapshot
+                                    .and_then(|(_, prev_elapsed, prev_media)| {
+                                        let dt = elapsed_secs - prev_elapsed;
+                                        (dt > 0.0)
+                                            .then_some((media_duration_total_secs - prev_media) / dt)
+                                    })
+                                    .unwrap_or(0.0);
+                                last_progress_snapshot =
+                                    Some((bytes_total, elapsed_secs, media_duration_total_secs));
+
+                                observe_segment_event_send(
+                                    self.event_tx.send(SegmentEvent::Progress(progress)).await,
+                                    &self.streamer_id,
+                                );
+                            }
+
+                            // Detect mid-stream disk-full. Same pattern as
+                            // ffmpeg.rs — see the matching block there
+                            // for the rationale. The producer feeds stderr
+                            // from the spawned ffmpeg process, so the
+                            // same signatures apply.
+                            if !disk_full_reported && is_disk_full_line(&line) {
+                                disk_full_reported = true;
+                                warn!(
+                                    streamer_id = %self.streamer_id,
+                                    output_dir = %self.output_dir.display(),
+                                    "{}+FFmpeg signalled disk full; emitting DiskFull event for gate",
+                                    self.source
+                                );
+                                observe_segment_event_send(
+                                    self.event_tx
+                                        .send(SegmentEvent::DiskFull {
+                                            output_dir: self.output_dir.clone(),
+                                            detail: format!("{}+ffmpeg: {}", self.source.to_lowercase(), line),
+                                        })
+                                        .await,
+                                    &self.streamer_id,
+                                );
+                            }
+                        }
+                        Ok(None) => {
+                            debug!("FFmpeg process ended for {}", self.streamer_id);
+                            break;
+                        }
+                        Err(e) => {
+                            error!("Error reading ffmpeg stderr: {}", e);
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+
+        // Complete the last active segment (if any).
+        if let Some((index, path, started_media_at, started_at)) = active_segment.take() {
+            let size_bytes = tokio::fs::metadata(&path)
+                .await
+                .map(|m| m.len())
+                .unwrap_or(0);
+            let duration_secs = (media_duration_total_secs - started_media_at).max(0.0);
+            segments_completed = segments_completed.saturating_add(1);
+            bytes_completed = bytes_completed.saturating_add(size_bytes);
+            total_bytes = bytes_completed;
+            if self.segment_mode {
+                media_duration_offset_secs += duration_secs;
+                total_duration = media_duration_offset_secs;
+            } else {
+                total_duration = media_duration_total_secs;
+            }
+            observe_segment_event_send(
+                self.event_tx
+                    .send(SegmentEvent::SegmentCompleted(SegmentInfo {
+                        path,
+                        duration_secs,
+                        size_bytes,
+                        index,
+                        started_at: Some(started_at),
+                        completed_at: Utc::now(),
+                        split_reason_code: None,
+                        split_reason_details_json: None,
+                        analysis_report_json: None,
+                    }))
+                    .await,
+                &self.streamer_id,
+            );
+        }
+
+        // Wait for exit status from process wait task
+        let exit_code = exit_rx.await.ok().flatten();
+
+        match exit_code {
+            Some(0) => {
+                // Exit code 0 — same caveat as ffmpeg: the subprocess
+                // exited cleanly but that doesn't prove the upstream
+                // stream is over. SessionLifecycle treats
+                // SubprocessExitZero as ambiguous → hysteresis.
+                observe_segment_event_send(
+                    self.event_tx
+                        .send(SegmentEvent::DownloadCompleted {
+                            total_bytes,
+                            total_duration_secs: total_duration,
+                            total_segments: segments_completed,
+                            engine_signal: crate::downloader::EngineEndSignal::SubprocessExitZero,
+                        })
+                        .await,
+                    &self.streamer_id,
+                );
+            }
+            Some(code) => {
+                // Fallback DiskFull emission for exit code 228 if we
+                // didn't already catch it from stderr. Mirrors ffmpeg.rs.
+                if code == 228 && !disk_full_reported {
+                    // The stderr loop has already exited by this point,
+                    // so we don't need to update `disk_full_reported` —
+                    // the variable will not be read again.
+                    warn!(
+                        streamer_id = %self.streamer_id,
+                        output_dir = %self.output_dir.display(),
+                        "{}+FFmpeg exited with code 228; assuming disk-full",
+                        self.source
+                    );
+                    observe_segment_event_send(
+                        self.event_tx
+                            .send(SegmentEvent::DiskFull {
+                                output_dir: self.output_dir.clone(),
+                                detail: format!(
+                                    "{}+ffmpeg exit 228 (I/O error, likely ENOSPC)",
+                                    self.source.to_lowercase()
+                                ),
+                            })
+                            .await,
+                        &self.streamer_id,
+                    );
+                }
+
+                // Non-zero exit code - failure
+                observe_segment_event_send(
+                    self.event_tx
+                        .send(SegmentEvent::DownloadFailed {
+                            kind: DownloadFailureKind::ProcessExit { code: Some(code) },
+                            message: format!("{}/FFmpeg exited with code {}", self.source, code),
+                        })
+                        .await,
+                    &self.streamer_id,
+                );
+            }
+            None => {
+                observe_segment_event_send(
+                    self.event_tx
+                        .send(SegmentEvent::DownloadFailed {
+                            kind: DownloadFailureKind::ProcessExit { code: None },
+                            message: format!("{}/FFmpeg exited without an exit code", self.source),
+                        })
+                        .await,
+                    &self.streamer_id,
+                );
+            }
+        }
+    }
+}
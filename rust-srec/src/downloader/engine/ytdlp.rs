@@ -0,0 +1,514 @@
+//! yt-dlp download engine implementation.
+
+use async_trait::async_trait;
+use process_utils::ProcessGroupExt;
+use std::path::PathBuf;
+use std::process::Stdio;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use tokio::time::{Duration, Instant};
+use tracing::{debug, error, info, warn};
+
+use super::traits::{
+    DownloadConfig, DownloadEngine, DownloadFailureKind, DownloadHandle, DownloadProtocol,
+    EngineProbe, EngineStartError, EngineType,
+};
+use super::utils::{
+    OutputRecordReader, RemuxMonitor, kill_process_tree, probe_piped_binary, remux_args,
+    track_process_tree,
+};
+use crate::Result;
+use crate::database::models::engine::YtDlpEngineConfig;
+
+/// Prefix of the progress lines requested with `--progress-template`.
+const PROGRESS_PREFIX: &str = "[rust-srec-progress]";
+
+/// yt-dlp-based download engine.
+///
+/// yt-dlp covers sites the built-in extractors don't support. It writes the
+/// selected format to stdout, which is piped to ffmpeg for remuxing.
+///
+/// yt-dlp only reports progress for downloads it performs itself; when it hands
+/// a live stream to its own ffmpeg, the speed is derived from the output instead.
+pub struct YtDlpEngine {
+    /// Engine configuration.
+    config: YtDlpEngineConfig,
+    /// Path to ffmpeg binary (for remuxing).
+    ffmpeg_path: String,
+    /// Cached version string.
+    version: Option<String>,
+}
+
+impl YtDlpEngine {
+    /// Create a new yt-dlp engine with default configuration.
+    pub fn new() -> Self {
+        Self::with_config(YtDlpEngineConfig::default())
+    }
+
+    /// Create with a custom configuration.
+    pub fn with_config(config: YtDlpEngineConfig) -> Self {
+        let ffmpeg_path = std::env::var("FFMPEG_PATH").unwrap_or_else(|_| "ffmpeg".to_string());
+        let version = Self::detect_version(&config.binary_path);
+
+        Self {
+            config,
+            ffmpeg_path,
+            version,
+        }
+    }
+
+    /// Detect yt-dlp version.
+    fn detect_version(path: &str) -> Option<String> {
+        let mut cmd = process_utils::std_command(path);
+        cmd.arg("--version");
+        cmd.output()
+            .ok()
+            .filter(|output| output.status.success())
+            .and_then(|output| {
+                String::from_utf8(output.stdout)
+                    .ok()
+                    .map(|s| s.trim().to_string())
+            })
+    }
+
+    /// Build yt-dlp command arguments.
+    fn build_ytdlp_args(&self, config: &DownloadConfig) -> Vec<String> {
+        let mut args = Vec::new();
+
+        // Output to stdout for piping, with machine-readable progress on stderr
+        args.extend([
+            "--output".to_string(),
+            "-".to_string(),
+            "--no-part".to_string(),
+            "--no-playlist".to_string(),
+            "--newline".to_string(),
+            "--progress-template".to_string(),
+            format!("download:{PROGRESS_PREFIX} %(progress.downloaded_bytes)s %(progress.speed)s"),
+        ]);
+
+        // Format selection (from config)
+        args.extend(["--format".to_string(), self.config.format.clone()]);
+
+        // Add proxy if configured
+        if let Some(ref proxy) = config.proxy_url {
+            args.extend(["--proxy".to_string(), proxy.clone()]);
+        }
+
+        // Add headers
+        for (key, value) in &config.headers {
+            args.extend(["--add-header".to_string(), format!("{}:{}", key, value)]);
+        }
+
+        // Cookies: an explicit cookie source in the engine config wins over the
+        // streamer cookies, which yt-dlp scopes to the domain of the URL.
+        if let Some(ref file) = self.config.cookies_file {
+            args.extend(["--cookies".to_string(), file.clone()]);
+        } else if let Some(ref browser) = self.config.cookies_from_browser {
+            args.extend(["--cookies-from-browser".to_string(), browser.clone()]);
+        } else if let Some(ref cookies) = config.cookies {
+            let cookies = cookies.trim();
+            if !cookies.is_empty() {
+                args.extend(["--add-header".to_string(), format!("Cookie:{}", cookies)]);
+            }
+        }
+
+        // Add extra arguments from config
+        args.extend(self.config.extra_args.clone());
+
+        // Stream URL, after `--` so it is never taken for an option
+        args.extend(["--".to_string(), config.url.clone()]);
+
+        args
+    }
+
+    /// Parse yt-dlp output for status information.
+    fn parse_ytdlp_output(line: &str) -> Option<YtDlpStatus> {
+        if let Some(rest) = line.strip_prefix(PROGRESS_PREFIX) {
+            let mut fields = rest.split_whitespace();
+            let downloaded_bytes = fields.next().and_then(parse_number);
+            let speed_bytes_per_sec = fields.next().and_then(parse_number);
+            return Some(YtDlpStatus::Progress {
+                downloaded_bytes,
+                speed_bytes_per_sec,
+            });
+        }
+        if line.starts_with("[download] Destination:") {
+            return Some(YtDlpStatus::StreamOpened);
+        }
+        if line.starts_with("ERROR:") {
+            return Some(YtDlpStatus::Error(line.to_string()));
+        }
+        if line.starts_with("WARNING:") {
+            return Some(YtDlpStatus::Warning(line.to_string()));
+        }
+        None
+    }
+}
+
+/// Parse a numeric template field, which is `NA` when yt-dlp doesn't know it.
+fn parse_number(field: &str) -> Option<u64> {
+    field
+        .parse::<f64>()
+        .ok()
+        .filter(|value| value.is_finite() && *value >= 0.0)
+        .map(|value| value as u64)
+}
+
+/// Status parsed from yt-dlp output.
+#[derive(Debug, PartialEq)]
+enum YtDlpStatus {
+    Progress {
+        downloaded_bytes: Option<u64>,
+        speed_bytes_per_sec: Option<u64>,
+    },
+    StreamOpened,
+    Warning(String),
+    Error(String),
+}
+
+impl Default for YtDlpEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl DownloadEngine for YtDlpEngine {
+    fn engine_type(&self) -> EngineType {
+        EngineType::YtDlp
+    }
+
+    async fn start(
+        &self,
+        handle: Arc<DownloadHandle>,
+    ) -> std::result::Result<(), EngineStartError> {
+        let config = handle.config_snapshot();
+        // Output directory is prepared by `DownloadManager::prepare_output_dir`
+        // before this method is called.
+        let ytdlp_args = self.build_ytdlp_args(&config);
+        let ffmpeg_args = remux_args(&config);
+        let segment_mode = config.max_segment_duration_secs > 0;
+        let single_output_path = if segment_mode {
+            None
+        } else {
+            ffmpeg_args.last().map(|s| PathBuf::from(s.clone()))
+        };
+
+        info!(
+            "Starting yt-dlp download for streamer {} with format {:?}",
+            config.streamer_id, self.config.format
+        );
+        debug!("yt-dlp args: {:?}", ytdlp_args);
+
+        // Spawn yt-dlp process
+        let mut ytdlp_command = process_utils::tokio_command(&self.config.binary_path);
+        ytdlp_command
+            .args(&ytdlp_args)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .own_process_group();
+        let mut ytdlp = ytdlp_command.spawn().map_err(|e| {
+            EngineStartError::new(
+                DownloadFailureKind::Configuration,
+                format!("Failed to spawn yt-dlp: {}", e),
+            )
+        })?;
+        // yt-dlp may start its own ffmpeg to download or mux streams
+        let mut ytdlp_tree = track_process_tree(&ytdlp);
+
+        let mut ytdlp_stdout = ytdlp.stdout.take().ok_or_else(|| {
+            EngineStartError::new(
+                DownloadFailureKind::Other,
+                "Failed to capture yt-dlp stdout",
+            )
+        })?;
+        let ytdlp_stderr = ytdlp.stderr.take().ok_or_else(|| {
+            EngineStartError::new(
+                DownloadFailureKind::Other,
+                "Failed to capture yt-dlp stderr",
+            )
+        })?;
+
+        // Spawn ffmpeg process with stdin piped
+        let mut ffmpeg_command = process_utils::tokio_command(&self.ffmpeg_path);
+        ffmpeg_command
+            .args(&ffmpeg_args)
+            .env("LC_ALL", "C")
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .own_process_group();
+        let mut ffmpeg = ffmpeg_command.spawn().map_err(|e| {
+            EngineStartError::new(
+                DownloadFailureKind::Configuration,
+                format!("Failed to spawn ffmpeg: {}", e),
+            )
+        })?;
+        let mut ffmpeg_tree = track_process_tree(&ffmpeg);
+
+        let mut ffmpeg_stdin = ffmpeg.stdin.take().ok_or_else(|| {
+            EngineStartError::new(DownloadFailureKind::Other, "Failed to capture ffmpeg stdin")
+        })?;
+        let ffmpeg_stderr = ffmpeg.stderr.take().ok_or_else(|| {
+            EngineStartError::new(
+                DownloadFailureKind::Other,
+                "Failed to capture ffmpeg stderr",
+            )
+        })?;
+
+        let cancellation_token = handle.cancellation_token.clone();
+        let started_instant = Instant::now();
+        let graceful_stop_timeout_secs = self.config.graceful_stop_timeout_secs;
+
+        // Wait for both processes. On cancellation yt-dlp is killed, which closes
+        // ffmpeg's stdin so it can finalize the output before exiting.
+        let (exit_tx, exit_rx) = tokio::sync::oneshot::channel::<Option<i32>>();
+        let cancellation_token_wait = cancellation_token.clone();
+        tokio::spawn(async move {
+            const YTDLP_KILL_TIMEOUT: Duration = Duration::from_secs(2);
+            let ffmpeg_stop_timeout = Duration::from_secs(graceful_stop_timeout_secs as u64);
+
+            tokio::select! {
+                status = ytdlp.wait() => {
+                    if let Err(e) = status {
+                        error!("Error waiting for yt-dlp process: {}", e);
+                    }
+                }
+                _ = cancellation_token_wait.cancelled() => {
+                    debug!("Stop requested, killing yt-dlp process");
+                    kill_process_tree(ytdlp_tree.as_ref());
+                    if let Err(e) = ytdlp.kill().await {
+                        warn!(error = %e, "Failed to kill yt-dlp process");
+                    }
+                    match tokio::time::timeout(YTDLP_KILL_TIMEOUT, ytdlp.wait()).await {
+                        Ok(Ok(_)) => {}
+                        Ok(Err(e)) => warn!(error = %e, "Failed to reap yt-dlp process"),
+                        Err(_) => warn!("Timed out waiting for killed yt-dlp process"),
+                    }
+                }
+            }
+
+            let exit_code = match tokio::time::timeout(ffmpeg_stop_timeout, ffmpeg.wait()).await {
+                Ok(Ok(exit_status)) => exit_status.code(),
+                Ok(Err(e)) => {
+                    error!("Error waiting for ffmpeg process: {}", e);
+                    Some(-1)
+                }
+                Err(_) => {
+                    warn!("FFmpeg did not exit in time; killing process");
+                    kill_process_tree(ffmpeg_tree.as_ref());
+                    if let Err(e) = ffmpeg.kill().await {
+                        warn!(error = %e, "Failed to kill ffmpeg process");
+                    }
+                    match ffmpeg.wait().await {
+                        Ok(exit_status) => exit_status.code(),
+                        Err(e) => {
+                            error!("Error waiting for killed ffmpeg process: {}", e);
+                            Some(-1)
+                        }
+                    }
+                }
+            };
+            // Both processes have been reaped, so their group ids may be reused
+            for tree in [&mut ytdlp_tree, &mut ffmpeg_tree].into_iter().flatten() {
+                tree.release();
+            }
+
+            if exit_tx.send(exit_code).is_err() {
+                debug!("Download exit receiver dropped before yt-dlp pipeline completed");
+            }
+        });
+
+        // Pipe yt-dlp stdout to ffmpeg stdin
+        let cancellation_token_pipe = cancellation_token.clone();
+        tokio::spawn(async move {
+            use tokio::io::{AsyncReadExt, AsyncWriteExt};
+            let mut buffer = [0u8; 8192];
+
+            loop {
+                tokio::select! {
+                    _ = cancellation_token_pipe.cancelled() => {
+                        break;
+                    }
+                    result = ytdlp_stdout.read(&mut buffer) => {
+                        match result {
+                            Ok(0) => break, // EOF
+                            Ok(n) => {
+                                if ffmpeg_stdin.write_all(&buffer[..n]).await.is_err() {
+                                    break;
+                                }
+                            }
+                            Err(_) => break,
+                        }
+                    }
+                }
+            }
+        });
+
+        // Monitor yt-dlp stderr, sharing its download speed with the ffmpeg monitor
+        let download_speed = Arc::new(AtomicU64::new(0));
+        let streamer_id = config.streamer_id.clone();
+        let speed = download_speed.clone();
+        let cancellation_token_stderr = cancellation_token.clone();
+        tokio::spawn(async move {
+            let mut reader = OutputRecordReader::new(ytdlp_stderr);
+
+            loop {
+                tokio::select! {
+                    _ = cancellation_token_stderr.cancelled() => {
+                        debug!("yt-dlp stderr monitor cancelled for {}", streamer_id);
+                        break;
+                    }
+                    record_result = reader.next_record() => {
+                        match record_result {
+                            Ok(Some(line)) => match Self::parse_ytdlp_output(&line) {
+                                Some(YtDlpStatus::Progress { speed_bytes_per_sec, .. }) => {
+                                    speed.store(speed_bytes_per_sec.unwrap_or(0), Ordering::Relaxed);
+                                }
+                                Some(YtDlpStatus::StreamOpened) => {
+                                    info!("yt-dlp stream opened for {}", streamer_id);
+                                }
+                                Some(YtDlpStatus::Warning(warning)) => {
+                                    debug!("yt-dlp warning for {}: {}", streamer_id, warning);
+                                }
+                                Some(YtDlpStatus::Error(err)) => {
+                                    warn!("yt-dlp error for {}: {}", streamer_id, err);
+                                }
+                                None => {}
+                            },
+                            Ok(None) => break,
+                            Err(e) => {
+                                error!("Error reading yt-dlp stderr: {}", e);
+                                break;
+                            }
+                        }
+                    }
+                }
+            }
+        });
+
+        // Monitor ffmpeg stderr and emit events - waits for exit status
+        let monitor = RemuxMonitor {
+            event_tx: handle.event_tx.clone(),
+            streamer_id: config.streamer_id.clone(),
+            output_dir: config.output_dir.clone(),
+            source: "yt-dlp",
+            segment_mode,
+            single_output_path,
+            started_instant,
+            download_speed: Some(download_speed),
+        };
+        tokio::spawn(monitor.run(ffmpeg_stderr, exit_rx));
+
+        Ok(())
+    }
+
+    async fn stop(&self, handle: &DownloadHandle) -> Result<()> {
+        let streamer_id = handle.config_snapshot().streamer_id;
+        info!("Stopping yt-dlp download for streamer {}", streamer_id);
+        handle.cancel();
+        Ok(())
+    }
+
+    fn is_available(&self) -> bool {
+        self.version.is_some()
+    }
+
+    fn version(&self) -> Option<String> {
+        self.version.clone()
+    }
+
+    async fn probe(&self) -> EngineProbe {
+        EngineProbe::from_check(
+            probe_piped_binary(&self.config.binary_path, &self.ffmpeg_path).await,
+            vec![DownloadProtocol::Hls, DownloadProtocol::Flv],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_config() -> DownloadConfig {
+        DownloadConfig::new(
+            "https://example.com/live/channel",
+            PathBuf::from("/tmp/out"),
+            "streamer-1",
+            "Streamer",
+            "session-1",
+        )
+    }
+
+    #[test]
+    fn test_engine_type() {
+        let engine = YtDlpEngine::new();
+        assert_eq!(engine.engine_type(), EngineType::YtDlp);
+    }
+
+    #[test]
+    fn test_build_ytdlp_args_uses_format_and_passes_cookies() {
+        let engine = YtDlpEngine::with_config(YtDlpEngineConfig {
+            format: "bv*[height<=720]+ba/b".to_string(),
+            ..Default::default()
+        });
+        let mut config = test_config();
+        config.cookies = Some("a=1; b=2".to_string());
+
+        let args = engine.build_ytdlp_args(&config);
+        let format = args.iter().position(|a| a == "--format").unwrap();
+        assert_eq!(args[format + 1], "bv*[height<=720]+ba/b");
+        assert!(
+            args.windows(2)
+                .any(|w| w == ["--add-header", "Cookie:a=1; b=2"])
+        );
+        assert_eq!(
+            args[args.len() - 2..],
+            ["--", "https://example.com/live/channel"]
+        );
+    }
+
+    #[test]
+    fn test_build_ytdlp_args_prefers_configured_cookie_source() {
+        let engine = YtDlpEngine::with_config(YtDlpEngineConfig {
+            cookies_from_browser: Some("firefox".to_string()),
+            ..Default::default()
+        });
+        let mut config = test_config();
+        config.cookies = Some("a=1".to_string());
+
+        let args = engine.build_ytdlp_args(&config);
+        assert!(
+            args.windows(2)
+                .any(|w| w == ["--cookies-from-browser", "firefox"])
+        );
+        assert!(!args.iter().any(|a| a.starts_with("Cookie:")));
+    }
+
+    #[test]
+    fn test_parse_ytdlp_output() {
+        assert_eq!(
+            YtDlpEngine::parse_ytdlp_output("[rust-srec-progress] 1048576 524288.5"),
+            Some(YtDlpStatus::Progress {
+                downloaded_bytes: Some(1048576),
+                speed_bytes_per_sec: Some(524288),
+            })
+        );
+        assert_eq!(
+            YtDlpEngine::parse_ytdlp_output("[rust-srec-progress] 2048 NA"),
+            Some(YtDlpStatus::Progress {
+                downloaded_bytes: Some(2048),
+                speed_bytes_per_sec: None,
+            })
+        );
+        assert_eq!(
+            YtDlpEngine::parse_ytdlp_output("[download] Destination: -"),
+            Some(YtDlpStatus::StreamOpened)
+        );
+        assert!(matches!(
+            YtDlpEngine::parse_ytdlp_output("ERROR: [generic] Unsupported URL"),
+            Some(YtDlpStatus::Error(_))
+        ));
+        assert!(YtDlpEngine::parse_ytdlp_output("[info] Downloading 1 format(s)").is_none());
+    }
+}
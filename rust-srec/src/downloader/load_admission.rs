@@ -0,0 +1,125 @@
+//! Load-aware admission of new downloads.
+//!
+//! Each active recording needs CPU for remuxing, memory for buffers and disk
+//! bandwidth for writes. Starting another one on a saturated host slows all
+//! of them down, so the policy holds new downloads back while any sampled
+//! load figure is above its threshold. Downloads that continue a session
+//! already recording are always admitted.
+
+use std::time::Duration;
+
+use crate::scheduler::SystemLoad;
+
+/// How often host load is sampled.
+pub const LOAD_SAMPLE_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Delay before a streamer whose download was deferred is checked again.
+pub const LOAD_DEFER_RETRY: Duration = Duration::from_secs(30);
+
+/// Load thresholds above which new downloads are deferred. `0` disables a
+/// threshold.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LoadAdmissionPolicy {
+    /// Maximum global CPU usage percent.
+    pub max_cpu_percent: u8,
+    /// Maximum used memory percent.
+    pub max_memory_percent: u8,
+    /// Maximum I/O pressure percent (Linux PSI `some avg10`).
+    pub max_io_pressure_percent: u8,
+}
+
+impl LoadAdmissionPolicy {
+    pub fn is_enabled(&self) -> bool {
+        self.max_cpu_percent > 0 || self.max_memory_percent > 0 || self.max_io_pressure_percent > 0
+    }
+
+    /// Describe the first threshold `load` exceeds, or `None` when a new
+    /// download may start.
+    ///
+    /// I/O pressure is ignored on hosts that don't report it.
+    pub fn exceeded(&self, load: &SystemLoad) -> Option<String> {
+        let over = |limit: u8, value: f32| limit > 0 && value > f32::from(limit);
+
+        if over(self.max_cpu_percent, load.cpu_percent) {
+            return Some(format!(
+                "CPU usage {:.0}% is above the {}% limit",
+                load.cpu_percent, self.max_cpu_percent
+            ));
+        }
+        if over(self.max_memory_percent, load.memory_percent) {
+            return Some(format!(
+                "Memory usage {:.0}% is above the {}% limit",
+                load.memory_percent, self.max_memory_percent
+            ));
+        }
+        if let Some(io_pressure) = load.io_pressure_percent
+            && over(self.max_io_pressure_percent, io_pressure)
+        {
+            return Some(format!(
+                "I/O pressure {:.0}% is above the {}% limit",
+                io_pressure, self.max_io_pressure_percent
+            ));
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn load(cpu_percent: f32, memory_percent: f32, io: Option<f32>) -> SystemLoad {
+        SystemLoad {
+            cpu_percent,
+            memory_percent,
+            io_pressure_percent: io,
+        }
+    }
+
+    #[test]
+    fn disabled_policy_admits_everything() {
+        let policy = LoadAdmissionPolicy::default();
+        assert!(!policy.is_enabled());
+        assert_eq!(policy.exceeded(&load(100.0, 100.0, Some(100.0))), None);
+    }
+
+    #[test]
+    fn reports_the_exceeded_threshold() {
+        let policy = LoadAdmissionPolicy {
+            max_cpu_percent: 85,
+            max_memory_percent: 90,
+            max_io_pressure_percent: 40,
+        };
+        assert!(policy.is_enabled());
+        assert_eq!(policy.exceeded(&load(85.0, 50.0, Some(10.0))), None);
+        assert!(
+            policy
+                .exceeded(&load(92.0, 50.0, None))
+                .unwrap()
+                .starts_with("CPU usage 92%")
+        );
+        assert!(
+            policy
+                .exceeded(&load(20.0, 95.0, None))
+                .unwrap()
+                .starts_with("Memory usage 95%")
+        );
+        assert!(
+            policy
+                .exceeded(&load(20.0, 50.0, Some(60.0)))
+                .unwrap()
+                .starts_with("I/O pressure 60%")
+        );
+    }
+
+    #[test]
+    fn unset_thresholds_are_ignored() {
+        let policy = LoadAdmissionPolicy {
+            max_cpu_percent: 0,
+            max_memory_percent: 0,
+            max_io_pressure_percent: 30,
+        };
+        assert_eq!(policy.exceeded(&load(100.0, 100.0, None)), None);
+        assert!(policy.exceeded(&load(0.0, 0.0, Some(31.0))).is_some());
+    }
+}
@@ -1,7 +1,14 @@
 //! Download Manager implementation.
 
 mod attempt;
+mod backfill;
 mod configuration;
+mod disk_space;
+mod fallback;
+mod load_admission;
+mod pause;
+mod preemption;
+mod recording_deadline;
 
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -17,11 +24,13 @@ use tokio::sync::{broadcast, mpsc};
 use tokio_util::sync::CancellationToken;
 use tracing::{debug, info, warn};
 
+use super::disk_space::DiskSpacePolicy;
 use super::engine::{
     DownloadConfig, DownloadEngine, DownloadFailureKind, DownloadHandle, DownloadInfo,
     DownloadProgress, DownloadProtocol, DownloadStatus, EngineType, FfmpegEngine, IoErrorKindSer,
-    MesioEngine, StreamlinkEngine,
+    MesioEngine, StreamlinkEngine, YtDlpEngine,
 };
+use super::load_admission::LoadAdmissionPolicy;
 use super::output_root_gate::OutputRootGate;
 use super::queue::{
     AcquireError as QueueAcquireError, AcquireRequest, ActiveSlot, DownloadQueue,
@@ -30,6 +39,13 @@ use super::queue::{
 use super::resilience::{CircuitBreakerManager, EngineKey, RetryConfig};
 use crate::Result;
 use crate::database::repositories::config::ConfigRepository;
+use crate::database::repositories::recording_deadline::RecordingDeadlineRepository;
+use crate::metrics::MetricsCollector;
+use crate::scheduler::{ResourceMonitor, SystemLoad};
+
+pub use disk_space::LowDiskSpaceVolume;
+pub use pause::PausedDownload;
+pub use recording_deadline::RECORDING_DEADLINE_CHECK_INTERVAL;
 
 fn parse_engine_config<T: DeserializeOwned>(engine: &'static str, raw: &str) -> Result<T> {
     serde_json::from_str(raw)
@@ -171,11 +187,7 @@ struct ActiveDownload {
     current_segment_started_at: Option<DateTime<Utc>>,
     /// Queue slot held by the download. Released (and the next waiter
     /// woken) when this `ActiveDownload` entry is removed from the
-    /// active map.
-    #[expect(
-        dead_code,
-        reason = "retained for optional runtime paths and diagnostics"
-    )]
+    /// active map. Its priority tier decides preemption.
     slot: Option<ActiveSlot>,
     /// Retry configuration override applied via config update.
     retry_config_override: Option<RetryConfig>,
@@ -236,6 +248,19 @@ pub struct DownloadManager {
     /// `SessionTransition::Ended` (the only surface that's actually shared
     /// across download attempts within a session).
     session_segment_indices: Arc<DashMap<String, u32>>,
+    /// Engine ids that failed with a non-retryable error, keyed by
+    /// recording session id. Preflight skips them for streamers with an
+    /// engine fallback chain. Cleared by `clear_session_engine_failures`
+    /// on `SessionTransition::Ended`.
+    failed_engines: Arc<DashMap<String, Vec<String>>>,
+    /// Paused downloads, keyed by streamer id. Cleared by
+    /// `resume_download`, or by `clear_session_pause` on
+    /// `SessionTransition::Ended`.
+    paused_downloads: Arc<DashMap<String, PausedDownload>>,
+    /// When manually started recordings must stop, keyed by streamer id.
+    recording_deadlines: DashMap<String, DateTime<Utc>>,
+    /// Persists `recording_deadlines` across restarts.
+    recording_deadline_repo: Option<Arc<dyn RecordingDeadlineRepository>>,
     /// Engine registry.
     engines: RwLock<HashMap<EngineType, Arc<dyn DownloadEngine>>>,
     /// Circuit breaker manager.
@@ -251,6 +276,9 @@ pub struct DownloadManager {
     /// download manager, and the gate depends on the former). After the
     /// one-shot write, reads are lock-free.
     output_root_gate: OnceLock<Arc<OutputRootGate>>,
+    /// Collector handed to downloads for per-operator pipeline metrics.
+    /// Late-bound like the output-root gate.
+    metrics_collector: OnceLock<Arc<MetricsCollector>>,
     /// Publishes best-effort observer events and reliable terminal events.
     events: DownloadEventPublisher,
     /// Config repository for resolving custom engines.
@@ -259,6 +287,16 @@ pub struct DownloadManager {
     /// hot path, hence `AtomicI64` rather than the `RwLock`-guarded
     /// [`DownloadManagerConfig`].
     queue_freshness_threshold_ms: AtomicI64,
+    /// Free-space policy for output volumes. Disabled until the services
+    /// container applies the persisted global config.
+    disk_space_policy: RwLock<DiskSpacePolicy>,
+    /// Load thresholds for starting new recordings. Disabled until the
+    /// services container applies the persisted global config.
+    load_admission_policy: RwLock<LoadAdmissionPolicy>,
+    /// Latest host load sample, refreshed by `sample_system_load`.
+    system_load: RwLock<Option<SystemLoad>>,
+    /// Disk list backing the free-space policy and the load sampler.
+    resource_monitor: parking_lot::Mutex<ResourceMonitor>,
 }
 
 /// Type of configuration that was updated.
@@ -341,6 +379,17 @@ pub enum DownloadStopCause {
     StreamerDisabled,
     /// Application shutdown.
     Shutdown,
+    /// User paused the download; the session stays open until it is resumed.
+    Paused,
+    /// The output volume fell below the free-space threshold; the download
+    /// is finalized and may restart in the secondary output folder.
+    LowDiskSpace,
+    /// The download was finalized to free its slot for a queued download of
+    /// a higher-priority streamer.
+    Preempted {
+        by_streamer_id: String,
+        by_streamer_name: String,
+    },
     /// Other internal/system stop reason.
     Other(String),
 }
@@ -354,6 +403,9 @@ impl DownloadStopCause {
             Self::OutOfSchedule => "out_of_schedule",
             Self::StreamerDisabled => "streamer_disabled",
             Self::Shutdown => "shutdown",
+            Self::Paused => "paused",
+            Self::LowDiskSpace => "low_disk_space",
+            Self::Preempted { .. } => "preempted",
             Self::Other(_) => "other",
         }
     }
@@ -454,6 +506,8 @@ pub enum DownloadProgressEvent {
         size_bytes: u64,
         split_reason_code: Option<String>,
         split_reason_details_json: Option<String>,
+        /// Analysis report as JSON, when the engine produces one.
+        analysis_report_json: Option<String>,
     },
     /// Configuration was updated for a download.
     ConfigUpdated {
@@ -469,6 +523,13 @@ pub enum DownloadProgressEvent {
         streamer_name: String,
         error: String,
     },
+    /// A paused download was resumed. The scheduler re-checks the
+    /// streamer, and a live result starts a new download in the session.
+    DownloadResumeRequested {
+        streamer_id: String,
+        streamer_name: String,
+        session_id: String,
+    },
 }
 
 /// Terminal download notifications: the download has stopped, no further
@@ -587,7 +648,8 @@ impl DownloadProgressEvent {
             | Self::SegmentStarted { streamer_id, .. }
             | Self::SegmentCompleted { streamer_id, .. }
             | Self::ConfigUpdated { streamer_id, .. }
-            | Self::ConfigUpdateFailed { streamer_id, .. } => streamer_id,
+            | Self::ConfigUpdateFailed { streamer_id, .. }
+            | Self::DownloadResumeRequested { streamer_id, .. } => streamer_id,
         }
     }
 
@@ -600,7 +662,8 @@ impl DownloadProgressEvent {
             | Self::SegmentStarted { streamer_name, .. }
             | Self::SegmentCompleted { streamer_name, .. }
             | Self::ConfigUpdated { streamer_name, .. }
-            | Self::ConfigUpdateFailed { streamer_name, .. } => streamer_name,
+            | Self::ConfigUpdateFailed { streamer_name, .. }
+            | Self::DownloadResumeRequested { streamer_name, .. } => streamer_name,
         }
     }
 
@@ -616,7 +679,8 @@ impl DownloadProgressEvent {
             | Self::DownloadStarted { session_id, .. }
             | Self::Progress { session_id, .. }
             | Self::SegmentStarted { session_id, .. }
-            | Self::SegmentCompleted { session_id, .. } => session_id,
+            | Self::SegmentCompleted { session_id, .. }
+            | Self::DownloadResumeRequested { session_id, .. } => session_id,
             Self::ConfigUpdated { .. } | Self::ConfigUpdateFailed { .. } => "",
         }
     }
@@ -701,6 +765,11 @@ pub enum DownloadRejectedKind {
     /// already persisted, so the scheduler only reschedules the actor after
     /// `retry_after_secs`; no `InfraBlockReason` write is needed.
     StreamerBackoff,
+    /// Host CPU, memory or I/O load is above the load admission policy.
+    /// Emitted by `preflight` for downloads that would start a new
+    /// recording; the scheduler re-checks the streamer after
+    /// `retry_after_secs` without marking it blocked.
+    SystemOverloaded,
 }
 
 /// Input to [`DownloadManager::preflight`].
@@ -718,6 +787,9 @@ pub struct PreflightRequest {
     pub output_dir: std::path::PathBuf,
     /// Engine id override; `None` means use the global default.
     pub engine_id: Option<String>,
+    /// Engines to try, in order, once `engine_id` has failed with a
+    /// non-retryable error during this session.
+    pub fallback_engine_ids: Vec<String>,
     /// Per-engine config overrides forwarded from
     /// [`DownloadConfig::engines_override`].
     pub engines_override: Option<serde_json::Value>,
@@ -730,11 +802,17 @@ pub struct EngineHandle {
     pub(crate) engine: Arc<dyn DownloadEngine>,
     pub engine_type: EngineType,
     pub(crate) engine_key: EngineKey,
+    /// Configured engine id the handle was resolved from.
+    pub engine_id: String,
+    /// Directory in the secondary output folder replacing the requested
+    /// one, set when the free-space policy moved the download there.
+    pub redirected_output_dir: Option<std::path::PathBuf>,
 }
 
 impl std::fmt::Debug for EngineHandle {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("EngineHandle")
+            .field("engine_id", &self.engine_id)
             .field("engine_type", &self.engine_type)
             .field("engine_key", &self.engine_key)
             .finish_non_exhaustive()
@@ -770,13 +848,22 @@ impl DownloadManager {
             active_downloads: Arc::new(DashMap::new()),
             pending_updates: Arc::new(DashMap::new()),
             session_segment_indices: Arc::new(DashMap::new()),
+            failed_engines: Arc::new(DashMap::new()),
+            paused_downloads: Arc::new(DashMap::new()),
+            recording_deadlines: DashMap::new(),
+            recording_deadline_repo: None,
             engines: RwLock::new(HashMap::new()),
             circuit_breakers,
             output_root_gate: OnceLock::new(),
+            metrics_collector: OnceLock::new(),
             events: DownloadEventPublisher::new(event_tx, None),
             config_repo: None,
             // Overwritten from persisted global config at boot.
             queue_freshness_threshold_ms: AtomicI64::new(60_000),
+            disk_space_policy: RwLock::new(DiskSpacePolicy::default()),
+            load_admission_policy: RwLock::new(LoadAdmissionPolicy::default()),
+            system_load: RwLock::new(None),
+            resource_monitor: parking_lot::Mutex::new(ResourceMonitor::default()),
         };
 
         // Register default engines
@@ -794,6 +881,10 @@ impl DownloadManager {
                 EngineType::Mesio,
                 Arc::new(MesioEngine::new()) as Arc<dyn DownloadEngine>,
             );
+            engines.insert(
+                EngineType::YtDlp,
+                Arc::new(YtDlpEngine::new()) as Arc<dyn DownloadEngine>,
+            );
         }
 
         manager
@@ -831,6 +922,14 @@ impl DownloadManager {
         }
     }
 
+    /// Late-bind the metrics collector that downloads report their stream
+    /// pipeline operators to.
+    pub fn set_metrics_collector(&self, collector: Arc<MetricsCollector>) {
+        if self.metrics_collector.set(collector).is_err() {
+            warn!("Ignoring attempt to replace already-configured metrics collector");
+        }
+    }
+
     /// Clear session-scoped segment allocation state after a session reaches
     /// its final ended state. Only the monotonic counter is shared across
     /// download attempts within the session; the per-download
@@ -864,6 +963,15 @@ impl DownloadManager {
         self
     }
 
+    /// Set the repository that persists recording deadlines.
+    pub fn with_recording_deadline_repo(
+        mut self,
+        repo: Arc<dyn RecordingDeadlineRepository>,
+    ) -> Self {
+        self.recording_deadline_repo = Some(repo);
+        self
+    }
+
     /// Register a download engine.
     pub fn register_engine(&mut self, engine: Arc<dyn DownloadEngine>) {
         let engine_type = engine.engine_type();
@@ -913,6 +1021,7 @@ impl DownloadManager {
             session_id: config.session_id.clone(),
             output_dir: config.output_dir.clone(),
             engine_id: engine_id.clone(),
+            fallback_engine_ids: Vec::new(),
             engines_override: config.engines_override.clone(),
         };
         let engine = self.preflight(preflight_req).await?;
@@ -942,10 +1051,20 @@ impl DownloadManager {
     /// [`Self::start_with_slot`] consumes — this avoids re-resolving
     /// the engine after the slot is acquired.
     pub async fn preflight(&self, req: PreflightRequest) -> Result<EngineHandle> {
-        let overrides = req.engines_override.as_ref();
-        let (engine, engine_type, engine_key) = self
-            .resolve_engine(req.engine_id.as_deref(), overrides)
-            .await?;
+        let (engine, engine_type, engine_key, engine_id) =
+            match self.select_fallback_engine(&req).await {
+                Some(selected) => selected,
+                None => {
+                    let (engine, engine_type, engine_key) = self
+                        .resolve_engine(req.engine_id.as_deref(), req.engines_override.as_ref())
+                        .await?;
+                    let engine_id = req
+                        .engine_id
+                        .clone()
+                        .unwrap_or_else(|| engine_type.as_str().to_string());
+                    (engine, engine_type, engine_key, engine_id)
+                }
+            };
 
         // Scope the circuit breaker to this streamer so one streamer's
         // CDN issues don't block unrelated streamers on the same engine.
@@ -1006,10 +1125,20 @@ impl DownloadManager {
             )));
         }
 
+        // Defer new recordings while the host is overloaded.
+        self.admit_system_load(&req)?;
+
+        // Apply the free-space policy. A redirect moves the download, and
+        // the directory prepared below, to the secondary output folder.
+        let redirected_output_dir = self.admit_output_dir(&req)?;
+        let output_dir = redirected_output_dir
+            .as_deref()
+            .unwrap_or(req.output_dir.as_path());
+
         // Prepare the output directory BEFORE acquiring a queue slot —
         // a ENOENT/ENOSPC failure here would otherwise hold a slot
         // until the error path released it, starving healthy streamers.
-        if let Err(engine_err) = self.prepare_output_dir_for_path(&req.output_dir).await {
+        if let Err(engine_err) = self.prepare_output_dir_for_path(output_dir).await {
             warn!(
                 "Failed to prepare output directory for streamer {}: {}",
                 req.streamer_id, engine_err
@@ -1020,8 +1149,8 @@ impl DownloadManager {
                 let path = self
                     .output_root_gate
                     .get()
-                    .map(|g| g.resolve_path(&req.output_dir))
-                    .unwrap_or_else(|| super::output_root_gate::resolve_root(&req.output_dir, &[]));
+                    .map(|g| g.resolve_path(output_dir))
+                    .unwrap_or_else(|| super::output_root_gate::resolve_root(output_dir, &[]));
                 self.emit_rejected(
                     req.streamer_id.clone(),
                     req.streamer_name.clone(),
@@ -1038,6 +1167,8 @@ impl DownloadManager {
             engine,
             engine_type,
             engine_key,
+            engine_id,
+            redirected_output_dir,
         })
     }
 
@@ -1052,6 +1183,9 @@ impl DownloadManager {
     /// is granted, the future returns
     /// [`crate::Error::Other("download acquire cancelled")`] without
     /// holding any queue capacity.
+    ///
+    /// Once parked, the request preempts the lowest-priority active
+    /// download ranked below it, if any.
     pub async fn acquire_slot(
         &self,
         req: AcquireRequest,
@@ -1064,22 +1198,32 @@ impl DownloadManager {
         // no clearance is needed either).
         let queued_emitted = Arc::new(std::sync::atomic::AtomicBool::new(false));
         let queued_emitted_cb = queued_emitted.clone();
-        let result = self
-            .queue
-            .acquire(req.clone(), cancel, move |entry| {
-                queued_emitted_cb.store(true, std::sync::atomic::Ordering::SeqCst);
-                events_for_queue.publish(DownloadManagerEvent::Progress(
-                    DownloadProgressEvent::DownloadQueued {
-                        streamer_id: entry.streamer_id.clone(),
-                        streamer_name: entry.streamer_name.clone(),
-                        session_id: entry.session_id.clone(),
-                        engine_type: entry.engine_type,
-                        is_high_priority: entry.priority.is_high(),
-                        queued_at_ms: entry.queued_at_ms,
-                    },
-                ));
-            })
-            .await;
+        let (parked_tx, parked_rx) = tokio::sync::oneshot::channel();
+        let acquire = self.queue.acquire(req.clone(), cancel, move |entry| {
+            queued_emitted_cb.store(true, std::sync::atomic::Ordering::SeqCst);
+            events_for_queue.publish(DownloadManagerEvent::Progress(
+                DownloadProgressEvent::DownloadQueued {
+                    streamer_id: entry.streamer_id.clone(),
+                    streamer_name: entry.streamer_name.clone(),
+                    session_id: entry.session_id.clone(),
+                    engine_type: entry.engine_type,
+                    is_high_priority: entry.priority.is_high(),
+                    queued_at_ms: entry.queued_at_ms,
+                },
+            ));
+            let _ = parked_tx.send(());
+        });
+        tokio::pin!(acquire);
+        let result = tokio::select! {
+            biased;
+            result = &mut acquire => result,
+            Ok(()) = parked_rx => {
+                // Parked behind full slots: free one held by a
+                // lower-priority download.
+                self.preempt_for(&req).await;
+                acquire.await
+            }
+        };
 
         match result {
             Ok(slot) => Ok(slot),
@@ -1130,14 +1274,8 @@ impl DownloadManager {
         config: DownloadConfig,
         engine: EngineHandle,
     ) -> Result<String> {
-        self.start_download_with_engine_and_slot(
-            config,
-            engine.engine,
-            engine.engine_type,
-            engine.engine_key,
-            slot,
-        )
-        .await
+        self.start_download_with_engine_and_slot(config, engine, slot)
+            .await
     }
 
     /// Emit the cleanup event for a queued slot that was granted but
@@ -1228,6 +1366,22 @@ impl DownloadManager {
         }
     }
 
+    /// Mark a chapter titled `title` in the output of an active download.
+    ///
+    /// Only engines writing FLV through the flv-fix pipeline place the marker; others ignore it.
+    pub fn add_chapter_marker(&self, download_id: &str, title: &str) -> Result<()> {
+        let download =
+            self.active_downloads
+                .get(download_id)
+                .ok_or_else(|| crate::Error::NotFound {
+                    entity_type: "Download".to_string(),
+                    id: download_id.to_string(),
+                })?;
+        download.handle.chapter_markers.mark(title);
+        info!(download_id, title, "Added chapter marker");
+        Ok(())
+    }
+
     /// Get information about active downloads.
     pub fn get_active_downloads(&self) -> Vec<DownloadInfo> {
         self.active_downloads
@@ -1889,6 +2043,7 @@ mod tests {
             completed_at: Utc::now(),
             split_reason_code: None,
             split_reason_details_json: None,
+            analysis_report_json: None,
         })
     }
 
@@ -1920,6 +2075,8 @@ mod tests {
             engine: Arc::new(scripted),
             engine_type: EngineType::Ffmpeg,
             engine_key: EngineKey::global(EngineType::Ffmpeg),
+            engine_id: "ffmpeg".to_string(),
+            redirected_output_dir: None,
         };
         let slot = manager
             .acquire_slot(
@@ -1987,6 +2144,43 @@ mod tests {
         collected
     }
 
+    #[tokio::test]
+    async fn expired_recording_deadline_pauses_the_running_download() {
+        let manager = DownloadManager::new();
+        let temp = tempfile::tempdir().unwrap();
+        let config = test_download_config(temp.path().to_path_buf(), "session-deadline");
+        let streamer_id = config.streamer_id.clone();
+        start_scripted_download_with_engine(
+            &manager,
+            config,
+            ScriptedSegmentEngine::with_gated_tail(
+                Vec::new(),
+                Arc::new(tokio::sync::Notify::new()),
+                Vec::new(),
+            ),
+        )
+        .await
+        .expect("download should start");
+
+        let now = Utc::now();
+        manager
+            .set_recording_deadline(&streamer_id, now + chrono::Duration::minutes(1))
+            .await
+            .unwrap();
+        assert!(manager.enforce_recording_deadlines(now).await.is_empty());
+        assert!(manager.get_download_by_streamer(&streamer_id).is_some());
+
+        let paused = manager
+            .enforce_recording_deadlines(now + chrono::Duration::minutes(1))
+            .await;
+
+        assert_eq!(paused.len(), 1);
+        assert_eq!(paused[0].session_id, "session-deadline");
+        assert!(manager.is_paused(&streamer_id));
+        assert!(manager.get_download_by_streamer(&streamer_id).is_none());
+        assert_eq!(manager.recording_deadline(&streamer_id), None);
+    }
+
     #[test]
     fn test_download_manager_config_default() {
         let config = DownloadManagerConfig::default();
@@ -2238,6 +2432,7 @@ mod tests {
                 completed_at: Utc::now(),
                 split_reason_code: None,
                 split_reason_details_json: None,
+                analysis_report_json: None,
             })],
         );
 
@@ -2522,6 +2717,107 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn queued_higher_priority_preempts_lowest_priority_download() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let config = DownloadManagerConfig {
+            max_concurrent_downloads: 1,
+            high_priority_extra_slots: 0,
+            ..Default::default()
+        };
+        let manager = Arc::new(DownloadManager::with_config(config));
+        let mut events = manager.subscribe();
+
+        let low_config = test_download_config(temp.path().to_path_buf(), "low-session");
+        let slot = manager
+            .acquire_slot(
+                AcquireRequest {
+                    session_id: low_config.session_id.clone(),
+                    streamer_id: low_config.streamer_id.clone(),
+                    streamer_name: low_config.streamer_name.clone(),
+                    engine_type: EngineType::Ffmpeg,
+                    priority: Priority::Low,
+                },
+                CancellationToken::new(),
+            )
+            .await
+            .unwrap();
+        let engine = EngineHandle {
+            engine: Arc::new(ScriptedSegmentEngine::with_gated_tail(
+                Vec::new(),
+                Arc::new(tokio::sync::Notify::new()),
+                Vec::new(),
+            )),
+            engine_type: EngineType::Ffmpeg,
+            engine_key: EngineKey::global(EngineType::Ffmpeg),
+            engine_id: "ffmpeg".to_string(),
+            redirected_output_dir: None,
+        };
+        let low_id = manager
+            .start_with_slot(slot, low_config, engine)
+            .await
+            .expect("low-priority download should start");
+
+        // Equal priority never preempts.
+        let peer = tokio::time::timeout(
+            std::time::Duration::from_millis(100),
+            manager.acquire_slot(
+                AcquireRequest {
+                    session_id: "peer-session".to_string(),
+                    streamer_id: "streamer-peer".to_string(),
+                    streamer_name: "Peer".to_string(),
+                    engine_type: EngineType::Ffmpeg,
+                    priority: Priority::Low,
+                },
+                CancellationToken::new(),
+            ),
+        )
+        .await;
+        assert!(peer.is_err());
+        assert_eq!(manager.active_count(), 1);
+
+        let slot = tokio::time::timeout(
+            std::time::Duration::from_secs(1),
+            manager.acquire_slot(
+                AcquireRequest {
+                    session_id: "vip-session".to_string(),
+                    streamer_id: "streamer-vip".to_string(),
+                    streamer_name: "Vip".to_string(),
+                    engine_type: EngineType::Ffmpeg,
+                    priority: Priority::Normal,
+                },
+                CancellationToken::new(),
+            ),
+        )
+        .await
+        .expect("preemption should free a slot")
+        .unwrap();
+        assert_eq!(slot.session_id(), "vip-session");
+
+        let cause = loop {
+            let event = tokio::time::timeout(std::time::Duration::from_secs(1), events.recv())
+                .await
+                .expect("timed out waiting for cancellation")
+                .expect("download event channel closed");
+            if let DownloadManagerEvent::Terminal(DownloadTerminalEvent::Cancelled {
+                download_id,
+                cause,
+                ..
+            }) = event
+            {
+                assert_eq!(download_id, low_id);
+                break cause;
+            }
+        };
+        assert_eq!(
+            cause,
+            DownloadStopCause::Preempted {
+                by_streamer_id: "streamer-vip".to_string(),
+                by_streamer_name: "Vip".to_string(),
+            }
+        );
+    }
+
     #[test]
     fn test_engine_registration() {
         let manager = DownloadManager::new();
@@ -2529,6 +2825,7 @@ mod tests {
         // FFmpeg should be registered by default
         assert!(manager.get_engine(EngineType::Ffmpeg).is_some());
         assert!(manager.get_engine(EngineType::Streamlink).is_some());
+        assert!(manager.get_engine(EngineType::YtDlp).is_some());
         assert!(manager.get_engine(EngineType::Mesio).is_some());
     }
 
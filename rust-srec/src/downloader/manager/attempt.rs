@@ -10,34 +10,44 @@ use tracing::{debug, error, info};
 use crate::Result;
 use crate::downloader::SegmentInfo;
 use crate::downloader::engine::{
-    DownloadConfig, DownloadEngine, DownloadHandle, DownloadProgress, DownloadStatus, EngineType,
-    SegmentEvent,
+    DownloadConfig, DownloadHandle, DownloadProgress, DownloadStatus, SegmentEvent,
 };
 use crate::downloader::output_root_gate::OutputRootGate;
 use crate::downloader::queue::SlotGuard;
-use crate::downloader::resilience::EngineKey;
 
 use super::{
     ActiveDownload, DownloadManager, DownloadManagerEvent, DownloadProgressEvent,
-    DownloadTerminalEvent, resolve_segment_path,
+    DownloadTerminalEvent, EngineHandle, resolve_segment_path,
 };
 
 impl DownloadManager {
     pub(super) async fn start_download_with_engine_and_slot(
         &self,
-        config: DownloadConfig,
-        engine: Arc<dyn DownloadEngine>,
-        engine_type: EngineType,
-        engine_key: EngineKey,
+        mut config: DownloadConfig,
+        engine: EngineHandle,
         slot: SlotGuard,
     ) -> Result<String> {
+        let EngineHandle {
+            engine,
+            engine_type,
+            engine_key,
+            engine_id,
+            redirected_output_dir,
+        } = engine;
         let active_slot = slot.into_active();
+        if let Some(output_dir) = redirected_output_dir {
+            config.output_dir = output_dir;
+        }
         Self::seed_session_segment_index(
             &self.session_segment_indices,
             &config.session_id,
             config.initial_segment_index,
         );
 
+        if config.metrics_collector.is_none() {
+            config.metrics_collector = self.metrics_collector.get().cloned();
+        }
+
         // Generate download ID
         let download_id = uuid::Uuid::new_v4().to_string();
 
@@ -119,6 +129,8 @@ impl DownloadManager {
         let active_downloads = self.active_downloads.clone();
         let pending_updates = self.pending_updates.clone();
         let session_segment_indices = self.session_segment_indices.clone();
+        let failed_engines = self.failed_engines.clone();
+        let paused_downloads = self.paused_downloads.clone();
         let circuit_breakers_ref = self.circuit_breakers.get(&engine_key);
         // Handle into the segment event loop so runtime ENOSPC from the
         // engine stderr readers can reach `gate.record_failure` — the
@@ -172,6 +184,7 @@ impl DownloadManager {
                             completed_at,
                             split_reason_code,
                             split_reason_details_json,
+                            analysis_report_json,
                             ..
                         } = info;
                         let segment_path = engine_segment_paths
@@ -212,6 +225,7 @@ impl DownloadManager {
                                 size_bytes,
                                 split_reason_code,
                                 split_reason_details_json,
+                                analysis_report_json,
                             },
                         ));
 
@@ -301,6 +315,17 @@ impl DownloadManager {
                         // ActiveSlot, which releases the queue capacity
                         // and wakes the next waiter automatically.
 
+                        // A paused download ends because it was stopped,
+                        // not because the stream did; `Cancelled` has
+                        // already been published and the session stays open.
+                        if Self::is_session_paused(&paused_downloads, &streamer_id, &session_id) {
+                            debug!(
+                                download_id = %download_id_clone,
+                                "Paused download completed; keeping the session open"
+                            );
+                            break;
+                        }
+
                         events.publish(DownloadManagerEvent::Terminal(
                             DownloadTerminalEvent::Completed {
                                 download_id: download_id_clone.clone(),
@@ -345,6 +370,9 @@ impl DownloadManager {
                         }
 
                         let recoverable = kind.is_recoverable();
+                        if super::fallback::triggers_fallback(&kind) {
+                            Self::record_engine_failure(&failed_engines, &session_id, &engine_id);
+                        }
 
                         // Emit one final progress update (best-effort) before the failure event.
                         if let Some(download) = active_downloads.get(&download_id_clone) {
@@ -370,6 +398,14 @@ impl DownloadManager {
                         // ActiveSlot, which releases the queue capacity
                         // and wakes the next waiter automatically.
 
+                        if Self::is_session_paused(&paused_downloads, &streamer_id, &session_id) {
+                            debug!(
+                                download_id = %download_id_clone,
+                                "Paused download exited with an error; keeping the session open"
+                            );
+                            break;
+                        }
+
                         events.publish(DownloadManagerEvent::Terminal(
                             DownloadTerminalEvent::Failed {
                                 download_id: download_id_clone.clone(),
@@ -0,0 +1,230 @@
+//! Recording the start of a broadcast from its platform replay.
+//!
+//! When a broadcast is detected late, the part that already went by can
+//! still be fetched from the replay the platform publishes while the stream
+//! runs. The replay plays from the start of the broadcast, so recording it
+//! with `max_segment_duration_secs` set to the missed span yields the missed
+//! part as the first segment; the engine is stopped as soon as that segment
+//! is complete.
+//!
+//! These recordings run on Mesio directly, outside the download queue and
+//! the per-streamer bookkeeping: the live download of the same streamer is
+//! running at the same time and owns those.
+
+use std::sync::Arc;
+
+use tokio::sync::mpsc;
+use tracing::{debug, info, warn};
+
+use crate::Result;
+use crate::downloader::SegmentInfo;
+use crate::downloader::engine::{DownloadConfig, DownloadHandle, EngineType, SegmentEvent};
+
+use super::{DownloadManager, resolve_segment_path};
+
+impl DownloadManager {
+    /// Record the first segment of a replay and stop.
+    ///
+    /// Returns the completed segment, or `None` when the replay ended
+    /// without producing one.
+    pub async fn record_replay_segment(
+        &self,
+        config: DownloadConfig,
+    ) -> Result<Option<SegmentInfo>> {
+        let engine = self
+            .get_engine(EngineType::Mesio)
+            .filter(|engine| engine.is_available())
+            .ok_or_else(|| crate::Error::Other("Mesio engine is not available".to_string()))?;
+
+        let (segment_tx, mut segment_rx) = mpsc::channel::<SegmentEvent>(32);
+        let download_id = uuid::Uuid::new_v4().to_string();
+        info!(
+            streamer_id = %config.streamer_id,
+            download_id = %download_id,
+            "Recording broadcast start from the replay"
+        );
+        let handle = Arc::new(DownloadHandle::new(
+            download_id.clone(),
+            EngineType::Mesio,
+            config,
+            segment_tx,
+        ));
+        // Only the token is kept: the handle owns the event sender, and the
+        // channel has to close once the engine is done with it.
+        let cancellation_token = handle.cancellation_token.clone();
+        let engine_task = tokio::spawn(async move { engine.start(handle).await });
+
+        let mut first_segment = None;
+        let mut failure = None;
+        while let Some(event) = segment_rx.recv().await {
+            match event {
+                SegmentEvent::SegmentCompleted(mut info) if first_segment.is_none() => {
+                    info.path = resolve_segment_path(&info.path).into();
+                    first_segment = Some(info);
+                    cancellation_token.cancel();
+                }
+                SegmentEvent::SegmentCompleted(info) => {
+                    // Flushed by the engine while stopping; not part of the
+                    // missed span.
+                    if let Err(error) = tokio::fs::remove_file(&info.path).await {
+                        warn!(
+                            path = %info.path.display(),
+                            %error,
+                            "Failed to remove extra replay segment"
+                        );
+                    }
+                }
+                SegmentEvent::DownloadFailed { kind, message } if first_segment.is_none() => {
+                    failure = Some(format!("{kind:?}: {message}"));
+                }
+                other => debug!(download_id = %download_id, event = ?other, "Replay segment event"),
+            }
+        }
+        match engine_task.await {
+            Ok(Err(error)) if first_segment.is_none() && failure.is_none() => {
+                failure = Some(error.to_string());
+            }
+            Err(join_error) if first_segment.is_none() => {
+                failure = Some(join_error.to_string());
+            }
+            _ => {}
+        }
+
+        match (first_segment, failure) {
+            (Some(segment), _) => Ok(Some(segment)),
+            (None, Some(message)) => Err(crate::Error::Other(format!(
+                "Replay recording failed: {message}"
+            ))),
+            (None, None) => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use chrono::Utc;
+
+    use super::*;
+    use crate::downloader::engine::{DownloadEngine, DownloadFailureKind, EngineStartError};
+
+    /// Mesio stand-in that sends a fixed list of events and ends.
+    struct ScriptedReplayEngine {
+        events: Vec<SegmentEvent>,
+    }
+
+    #[async_trait]
+    impl DownloadEngine for ScriptedReplayEngine {
+        fn engine_type(&self) -> EngineType {
+            EngineType::Mesio
+        }
+
+        async fn start(
+            &self,
+            handle: Arc<DownloadHandle>,
+        ) -> std::result::Result<(), EngineStartError> {
+            for event in self.events.clone() {
+                handle.event_tx.send(event).await.map_err(|e| {
+                    EngineStartError::new(DownloadFailureKind::Other, e.to_string())
+                })?;
+            }
+            Ok(())
+        }
+
+        async fn stop(&self, handle: &DownloadHandle) -> Result<()> {
+            handle.cancel();
+            Ok(())
+        }
+
+        fn is_available(&self) -> bool {
+            true
+        }
+
+        fn version(&self) -> Option<String> {
+            None
+        }
+    }
+
+    fn completed(path: std::path::PathBuf, index: u32) -> SegmentEvent {
+        SegmentEvent::SegmentCompleted(SegmentInfo {
+            path,
+            duration_secs: 1.0,
+            size_bytes: 128,
+            index,
+            started_at: None,
+            completed_at: Utc::now(),
+            split_reason_code: None,
+            split_reason_details_json: None,
+            analysis_report_json: None,
+        })
+    }
+
+    fn manager_with(events: Vec<SegmentEvent>) -> DownloadManager {
+        let mut manager = DownloadManager::new();
+        manager.register_engine(Arc::new(ScriptedReplayEngine { events }));
+        manager
+    }
+
+    fn replay_config(output_dir: &std::path::Path) -> DownloadConfig {
+        DownloadConfig::new(
+            "https://example.com/vod.m3u8",
+            output_dir,
+            "streamer",
+            "Streamer",
+            "backfill-session",
+        )
+    }
+
+    #[tokio::test]
+    async fn keeps_the_first_segment_and_removes_the_rest() {
+        let dir = tempfile::tempdir().unwrap();
+        let first = dir.path().join("first.ts");
+        let extra = dir.path().join("extra.ts");
+        std::fs::write(&first, b"first").unwrap();
+        std::fs::write(&extra, b"extra").unwrap();
+        let manager = manager_with(vec![
+            completed(first.clone(), 0),
+            completed(extra.clone(), 1),
+        ]);
+
+        let segment = manager
+            .record_replay_segment(replay_config(dir.path()))
+            .await
+            .unwrap()
+            .expect("first segment");
+
+        assert_eq!(segment.path, first);
+        assert!(first.exists());
+        assert!(!extra.exists());
+    }
+
+    #[tokio::test]
+    async fn reports_a_failure_before_any_segment() {
+        let dir = tempfile::tempdir().unwrap();
+        let manager = manager_with(vec![SegmentEvent::DownloadFailed {
+            kind: DownloadFailureKind::Network,
+            message: "playlist unavailable".to_string(),
+        }]);
+
+        assert!(
+            manager
+                .record_replay_segment(replay_config(dir.path()))
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn returns_none_when_the_replay_is_empty() {
+        let dir = tempfile::tempdir().unwrap();
+        let manager = manager_with(Vec::new());
+
+        assert!(
+            manager
+                .record_replay_segment(replay_config(dir.path()))
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+}
@@ -6,12 +6,13 @@ use tracing::{debug, warn};
 
 use crate::Result;
 use crate::database::models::engine::{
-    FfmpegEngineConfig, MesioEngineConfig, StreamlinkEngineConfig,
+    FfmpegEngineConfig, MesioEngineConfig, StreamlinkEngineConfig, YtDlpEngineConfig,
 };
 #[cfg(test)]
 use crate::downloader::engine::DownloadConfig;
 use crate::downloader::engine::{
     DownloadEngine, EngineStartError, EngineType, FfmpegEngine, MesioEngine, StreamlinkEngine,
+    YtDlpEngine,
 };
 use crate::downloader::resilience::EngineKey;
 
@@ -93,6 +94,15 @@ impl DownloadManager {
                         override_config,
                     )))
                 }
+                EngineType::YtDlp => {
+                    let base = self
+                        .load_engine_config_or_default::<YtDlpEngineConfig>(target_id)
+                        .await;
+                    Arc::new(YtDlpEngine::with_config(Self::apply_override_best_effort(
+                        base,
+                        override_config,
+                    )))
+                }
             };
 
             return Ok((engine, engine_type, key));
@@ -127,6 +137,9 @@ impl DownloadManager {
                             EngineType::Mesio => Arc::new(MesioEngine::with_config(
                                 parse_engine_config("mesio", &config.config)?,
                             )),
+                            EngineType::YtDlp => Arc::new(YtDlpEngine::with_config(
+                                parse_engine_config("ytdlp", &config.config)?,
+                            )),
                         };
                         return Ok((engine, engine_type, key));
                     }
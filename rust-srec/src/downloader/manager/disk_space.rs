@@ -0,0 +1,252 @@
+//! Applying the free-space policy to downloads.
+//!
+//! Preflight consults the policy before a download starts, and the services
+//! container calls [`DownloadManager::enforce_disk_space_policy`] every
+//! [`DISK_SPACE_CHECK_INTERVAL`] to finalize active downloads on volumes that
+//! ran low while they were recording.
+
+use std::path::{Path, PathBuf};
+
+use tracing::{info, warn};
+
+use crate::Result;
+use crate::downloader::disk_space::{
+    DISK_SPACE_CHECK_INTERVAL, DiskSpaceDecision, DiskSpacePolicy, LowDiskSpaceAction,
+};
+use crate::downloader::engine::IoErrorKindSer;
+
+use super::{DownloadManager, DownloadRejectedKind, DownloadStopCause, PreflightRequest};
+
+/// A volume below the free-space threshold.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LowDiskSpaceVolume {
+    /// Mount point of the volume.
+    pub mount_point: PathBuf,
+    pub available_bytes: u64,
+}
+
+impl DownloadManager {
+    /// Replace the free-space policy. Applies to the next preflight and the
+    /// next enforcement pass.
+    pub fn set_disk_space_policy(&self, policy: DiskSpacePolicy) {
+        *self.disk_space_policy.write() = policy;
+    }
+
+    pub fn disk_space_policy(&self) -> DiskSpacePolicy {
+        self.disk_space_policy.read().clone()
+    }
+
+    /// Check the free-space policy for a download about to start.
+    ///
+    /// Returns the directory in the secondary folder when the policy moved
+    /// the download there. A rejection emits `DownloadRejected` so the
+    /// scheduler parks the streamer until space frees up.
+    pub(super) fn admit_output_dir(&self, req: &PreflightRequest) -> Result<Option<PathBuf>> {
+        let policy = self.disk_space_policy();
+        if !policy.is_enabled() {
+            return Ok(None);
+        }
+
+        let decision = {
+            let mut monitor = self.resource_monitor.lock();
+            monitor.refresh();
+            policy.admit(&req.output_dir, |path| {
+                monitor
+                    .disk_for_path(&absolute(path))
+                    .map(|disk| disk.available_bytes)
+            })
+        };
+
+        match decision {
+            DiskSpaceDecision::Proceed => Ok(None),
+            DiskSpaceDecision::Redirect(dir) => {
+                info!(
+                    streamer_id = %req.streamer_id,
+                    from = %req.output_dir.display(),
+                    to = %dir.display(),
+                    "Output volume is low on space; recording to the secondary folder"
+                );
+                Ok(Some(dir))
+            }
+            DiskSpaceDecision::Reject { available_bytes } => {
+                let reason = format!(
+                    "Free space on the volume of {} is {} bytes, below the {} byte threshold",
+                    req.output_dir.display(),
+                    available_bytes,
+                    policy.min_free_bytes
+                );
+                warn!(streamer_id = %req.streamer_id, "{reason}; refusing to start the download");
+
+                let path = self
+                    .output_root_gate
+                    .get()
+                    .map(|g| g.resolve_path(&req.output_dir))
+                    .unwrap_or_else(|| {
+                        crate::downloader::output_root_gate::resolve_root(&req.output_dir, &[])
+                    });
+                self.emit_rejected(
+                    req.streamer_id.clone(),
+                    req.streamer_name.clone(),
+                    req.session_id.clone(),
+                    reason.clone(),
+                    Some(DISK_SPACE_CHECK_INTERVAL.as_secs()),
+                    DownloadRejectedKind::OutputRootUnavailable {
+                        path,
+                        io_kind: IoErrorKindSer::StorageFull,
+                    },
+                );
+                Err(crate::Error::Other(reason))
+            }
+        }
+    }
+
+    /// Finalize active downloads on volumes below the free-space threshold,
+    /// when the policy asks for it.
+    ///
+    /// Returns every volume currently below the threshold among the primary
+    /// output folder and the directories of active downloads.
+    pub async fn enforce_disk_space_policy(&self) -> Vec<LowDiskSpaceVolume> {
+        let policy = self.disk_space_policy();
+        if !policy.is_enabled() {
+            return Vec::new();
+        }
+
+        let downloads: Vec<(String, String, PathBuf)> = self
+            .active_downloads
+            .iter()
+            .map(|entry| {
+                let config = entry.value().handle.config_snapshot();
+                (entry.key().clone(), config.streamer_id, config.output_dir)
+            })
+            .collect();
+
+        let mut low_volumes: Vec<LowDiskSpaceVolume> = Vec::new();
+        let mut to_finalize = Vec::new();
+        {
+            let mut monitor = self.resource_monitor.lock();
+            monitor.refresh();
+
+            let mut note_low = |path: &Path| {
+                let disk = monitor.disk_for_path(&absolute(path))?;
+                if !policy.is_low(disk.available_bytes) {
+                    return None;
+                }
+                let volume = LowDiskSpaceVolume {
+                    mount_point: PathBuf::from(disk.mount_point),
+                    available_bytes: disk.available_bytes,
+                };
+                if !low_volumes
+                    .iter()
+                    .any(|low| low.mount_point == volume.mount_point)
+                {
+                    low_volumes.push(volume);
+                }
+                Some(disk.available_bytes)
+            };
+
+            if let Some(root) = &policy.primary_root {
+                note_low(root);
+            }
+            for (download_id, streamer_id, output_dir) in downloads {
+                if let Some(available_bytes) = note_low(&output_dir)
+                    && policy.should_finalize(available_bytes)
+                {
+                    to_finalize.push((download_id, streamer_id, output_dir, available_bytes));
+                }
+            }
+        }
+
+        for (download_id, streamer_id, output_dir, available_bytes) in to_finalize {
+            let restarts = policy.action == LowDiskSpaceAction::SwitchFolder
+                && policy.secondary_dir_for(&output_dir).is_some();
+            warn!(
+                streamer_id = %streamer_id,
+                download_id = %download_id,
+                output_dir = %output_dir.display(),
+                available_bytes,
+                threshold_bytes = policy.min_free_bytes,
+                action = policy.action.as_str(),
+                "Output volume is low on space; finalizing the download{}",
+                if restarts { " to continue in the secondary folder" } else { "" }
+            );
+            if let Err(e) = self
+                .stop_download_with_reason(&download_id, DownloadStopCause::LowDiskSpace)
+                .await
+            {
+                warn!(download_id = %download_id, "Failed to finalize download: {e}");
+            }
+        }
+
+        low_volumes
+    }
+}
+
+/// Anchor relative output directories at the working directory, which is
+/// where downloads resolve them, so they match a mount point.
+fn absolute(path: &Path) -> PathBuf {
+    if path.is_absolute() {
+        return path.to_path_buf();
+    }
+    std::env::current_dir()
+        .map(|cwd| cwd.join(path))
+        .unwrap_or_else(|_| path.to_path_buf())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(output_dir: &str) -> PreflightRequest {
+        PreflightRequest {
+            streamer_id: "streamer".to_string(),
+            streamer_name: "Streamer".to_string(),
+            session_id: "session".to_string(),
+            output_dir: PathBuf::from(output_dir),
+            engine_id: None,
+            fallback_engine_ids: Vec::new(),
+            engines_override: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn disabled_policy_is_a_no_op() {
+        let manager = DownloadManager::new();
+        assert_eq!(
+            manager.admit_output_dir(&request("/tmp/rec")).unwrap(),
+            None
+        );
+        assert!(manager.enforce_disk_space_policy().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn unreachable_threshold_rejects_new_downloads() {
+        let manager = DownloadManager::new();
+        let mut events = manager.subscribe();
+        let temp = tempfile::tempdir().unwrap();
+        manager.set_disk_space_policy(DiskSpacePolicy {
+            min_free_bytes: u64::MAX,
+            primary_root: Some(temp.path().to_path_buf()),
+            ..DiskSpacePolicy::default()
+        });
+
+        let req = request(&temp.path().to_string_lossy());
+        // Volumes sysinfo cannot see never block a download.
+        if manager.admit_output_dir(&req).is_ok() {
+            return;
+        }
+
+        assert!(matches!(
+            events.try_recv(),
+            Ok(super::super::DownloadManagerEvent::Terminal(
+                super::super::DownloadTerminalEvent::Rejected {
+                    kind: DownloadRejectedKind::OutputRootUnavailable {
+                        io_kind: IoErrorKindSer::StorageFull,
+                        ..
+                    },
+                    ..
+                }
+            ))
+        ));
+        assert!(!manager.enforce_disk_space_policy().await.is_empty());
+    }
+}
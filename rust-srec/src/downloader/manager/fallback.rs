@@ -0,0 +1,206 @@
+//! Engine fallback chains.
+//!
+//! A streamer can list engines to fall back to after its preferred one.
+//! When a download fails with a non-retryable error, the engine is marked
+//! as failed for the recording session, and the next preflight of that
+//! session picks the first engine of the chain that has not failed, is
+//! available, and is not blocked by its circuit breaker.
+
+use std::sync::Arc;
+
+use tracing::{debug, info, warn};
+
+use crate::downloader::engine::{DownloadEngine, DownloadFailureKind, EngineType};
+use crate::downloader::resilience::EngineKey;
+
+use super::{DownloadManager, PreflightRequest};
+
+/// Whether a failure of `kind` should move the session to the next engine.
+///
+/// Retrying the same engine can't fix a non-retryable failure, while a
+/// cancellation says nothing about the engine.
+pub(super) fn triggers_fallback(kind: &DownloadFailureKind) -> bool {
+    !kind.is_recoverable() && !matches!(kind, DownloadFailureKind::Cancelled)
+}
+
+/// Ordered, de-duplicated list of engine ids to try.
+fn engine_chain<'a>(primary: &'a str, fallbacks: &'a [String]) -> Vec<&'a str> {
+    let mut chain = vec![primary];
+    for id in fallbacks {
+        let id = id.trim();
+        if !id.is_empty() && !chain.contains(&id) {
+            chain.push(id);
+        }
+    }
+    chain
+}
+
+impl DownloadManager {
+    /// Record that `engine_id` failed non-retryably for `session_id`.
+    pub(super) fn record_engine_failure(
+        failed_engines: &dashmap::DashMap<String, Vec<String>>,
+        session_id: &str,
+        engine_id: &str,
+    ) {
+        let mut failed = failed_engines.entry(session_id.to_string()).or_default();
+        if !failed.iter().any(|id| id == engine_id) {
+            failed.push(engine_id.to_string());
+        }
+    }
+
+    /// Forget the engines that failed during a session once it has ended.
+    pub fn clear_session_engine_failures(&self, session_id: &str) {
+        self.failed_engines.remove(session_id);
+    }
+
+    /// Engine ids that failed non-retryably during a session.
+    pub fn failed_engines_for_session(&self, session_id: &str) -> Vec<String> {
+        self.failed_engines
+            .get(session_id)
+            .map(|failed| failed.clone())
+            .unwrap_or_default()
+    }
+
+    /// Pick the engine of a fallback chain to use for this attempt.
+    ///
+    /// Returns `None` when the request has no fallbacks, or when every
+    /// engine of the chain is unusable; the caller then resolves the
+    /// preferred engine as usual so the regular rejection paths apply.
+    pub(super) async fn select_fallback_engine(
+        &self,
+        req: &PreflightRequest,
+    ) -> Option<(Arc<dyn DownloadEngine>, EngineType, EngineKey, String)> {
+        if req.fallback_engine_ids.is_empty() {
+            return None;
+        }
+
+        let default_engine = self.config.read().default_engine;
+        let primary = req.engine_id.as_deref().unwrap_or(default_engine.as_str());
+        let chain = engine_chain(primary, &req.fallback_engine_ids);
+        let failed = self.failed_engines_for_session(&req.session_id);
+        let overrides = req.engines_override.as_ref();
+
+        for (position, id) in chain.iter().enumerate() {
+            if failed.iter().any(|failed_id| failed_id == id) {
+                debug!(
+                    session_id = %req.session_id,
+                    engine_id = id,
+                    "Skipping engine that already failed in this session"
+                );
+                continue;
+            }
+
+            let (engine, engine_type, engine_key) =
+                match self.resolve_engine(Some(id), overrides).await {
+                    Ok(resolved) => resolved,
+                    Err(e) => {
+                        warn!(
+                            engine_id = id,
+                            "Skipping unresolvable fallback engine: {}", e
+                        );
+                        continue;
+                    }
+                };
+            if !engine.is_available() {
+                debug!(engine_id = id, "Skipping unavailable engine");
+                continue;
+            }
+            let scoped_key = engine_key.clone().for_streamer(&req.streamer_id);
+            if !self.circuit_breakers.is_allowed(&scoped_key) {
+                debug!(engine_id = id, "Skipping engine blocked by circuit breaker");
+                continue;
+            }
+
+            if position > 0 {
+                info!(
+                    streamer_id = %req.streamer_id,
+                    session_id = %req.session_id,
+                    preferred = primary,
+                    engine_id = id,
+                    "Falling back to the next engine of the chain"
+                );
+            }
+            return Some((engine, engine_type, engine_key, id.to_string()));
+        }
+
+        // Every engine failed: start over from the preferred one rather
+        // than refusing to record.
+        if !failed.is_empty() {
+            warn!(
+                streamer_id = %req.streamer_id,
+                session_id = %req.session_id,
+                "Every engine of the fallback chain failed; retrying the preferred engine"
+            );
+            self.clear_session_engine_failures(&req.session_id);
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chain_starts_with_the_preferred_engine_and_skips_duplicates() {
+        let fallbacks = vec![
+            "ffmpeg".to_string(),
+            " ".to_string(),
+            "mesio".to_string(),
+            "streamlink".to_string(),
+            "ffmpeg".to_string(),
+        ];
+        assert_eq!(
+            engine_chain("mesio", &fallbacks),
+            vec!["mesio", "ffmpeg", "streamlink"]
+        );
+    }
+
+    #[test]
+    fn only_non_retryable_failures_trigger_fallback() {
+        assert!(triggers_fallback(&DownloadFailureKind::Configuration));
+        assert!(triggers_fallback(&DownloadFailureKind::HttpClientError {
+            status: 403
+        }));
+        assert!(!triggers_fallback(&DownloadFailureKind::Cancelled));
+        assert!(!triggers_fallback(&DownloadFailureKind::Network));
+    }
+
+    #[tokio::test]
+    async fn failed_engines_are_skipped_until_the_chain_is_exhausted() {
+        let manager = DownloadManager::new();
+        let req = PreflightRequest {
+            streamer_id: "streamer".to_string(),
+            streamer_name: "Streamer".to_string(),
+            session_id: "session".to_string(),
+            output_dir: std::env::temp_dir(),
+            engine_id: Some("ffmpeg".to_string()),
+            fallback_engine_ids: vec!["mesio".to_string()],
+            engines_override: None,
+        };
+
+        DownloadManager::record_engine_failure(&manager.failed_engines, "session", "ffmpeg");
+        let (_, engine_type, _, id) = manager.select_fallback_engine(&req).await.unwrap();
+        assert_eq!((engine_type, id.as_str()), (EngineType::Mesio, "mesio"));
+
+        DownloadManager::record_engine_failure(&manager.failed_engines, "session", "mesio");
+        assert!(manager.select_fallback_engine(&req).await.is_none());
+        assert!(manager.failed_engines_for_session("session").is_empty());
+    }
+
+    #[tokio::test]
+    async fn requests_without_fallbacks_use_the_regular_path() {
+        let manager = DownloadManager::new();
+        let req = PreflightRequest {
+            streamer_id: "streamer".to_string(),
+            streamer_name: "Streamer".to_string(),
+            session_id: "session".to_string(),
+            output_dir: std::env::temp_dir(),
+            engine_id: Some("mesio".to_string()),
+            fallback_engine_ids: Vec::new(),
+            engines_override: None,
+        };
+
+        assert!(manager.select_fallback_engine(&req).await.is_none());
+    }
+}
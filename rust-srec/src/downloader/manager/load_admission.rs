@@ -0,0 +1,114 @@
+//! Deferring new downloads while the host is overloaded.
+//!
+//! The services container calls [`DownloadManager::sample_system_load`] every
+//! [`LOAD_SAMPLE_INTERVAL`](crate::downloader::load_admission::LOAD_SAMPLE_INTERVAL);
+//! preflight compares the latest sample against the
+//! [`LoadAdmissionPolicy`] and refuses downloads that would start a new
+//! recording while a threshold is exceeded.
+
+use tracing::warn;
+
+use crate::Result;
+use crate::downloader::load_admission::{LOAD_DEFER_RETRY, LoadAdmissionPolicy};
+use crate::scheduler::SystemLoad;
+
+use super::{DownloadManager, DownloadRejectedKind, PreflightRequest};
+
+impl DownloadManager {
+    /// Replace the load admission policy. Applies to the next preflight.
+    pub fn set_load_admission_policy(&self, policy: LoadAdmissionPolicy) {
+        *self.load_admission_policy.write() = policy;
+    }
+
+    pub fn load_admission_policy(&self) -> LoadAdmissionPolicy {
+        *self.load_admission_policy.read()
+    }
+
+    /// Sample host load and keep it for the next preflight.
+    pub fn sample_system_load(&self) -> SystemLoad {
+        let load = self.resource_monitor.lock().sample_load();
+        *self.system_load.write() = Some(load);
+        load
+    }
+
+    /// Latest load sample, if the sampler has run.
+    pub fn system_load(&self) -> Option<SystemLoad> {
+        *self.system_load.read()
+    }
+
+    /// Check the load admission policy for a download about to start.
+    ///
+    /// Downloads of a session that already recorded are restarts (engine
+    /// fallback, folder switch, resume) and always pass, so an overloaded
+    /// host never cuts a running recording short. A refusal emits
+    /// `DownloadRejected` so the scheduler re-checks the streamer after
+    /// [`LOAD_DEFER_RETRY`].
+    pub(super) fn admit_system_load(&self, req: &PreflightRequest) -> Result<()> {
+        let policy = self.load_admission_policy();
+        if !policy.is_enabled() || self.session_segment_indices.contains_key(&req.session_id) {
+            return Ok(());
+        }
+        let Some(reason) = self.system_load().and_then(|load| policy.exceeded(&load)) else {
+            return Ok(());
+        };
+
+        warn!(streamer_id = %req.streamer_id, "{reason}; deferring the download");
+        self.emit_rejected(
+            req.streamer_id.clone(),
+            req.streamer_name.clone(),
+            req.session_id.clone(),
+            reason.clone(),
+            Some(LOAD_DEFER_RETRY.as_secs()),
+            DownloadRejectedKind::SystemOverloaded,
+        );
+        Err(crate::Error::Other(reason))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn request(session_id: &str) -> PreflightRequest {
+        PreflightRequest {
+            streamer_id: "streamer-1".to_string(),
+            streamer_name: "Streamer".to_string(),
+            session_id: session_id.to_string(),
+            output_dir: PathBuf::from("/rec"),
+            engine_id: None,
+            fallback_engine_ids: Vec::new(),
+            engines_override: None,
+        }
+    }
+
+    #[test]
+    fn overload_defers_new_sessions_only() {
+        let manager = DownloadManager::new();
+        manager.set_load_admission_policy(LoadAdmissionPolicy {
+            max_cpu_percent: 80,
+            ..Default::default()
+        });
+        *manager.system_load.write() = Some(SystemLoad {
+            cpu_percent: 97.0,
+            memory_percent: 40.0,
+            io_pressure_percent: None,
+        });
+
+        assert!(manager.admit_system_load(&request("new-session")).is_err());
+
+        DownloadManager::seed_session_segment_index(
+            &manager.session_segment_indices,
+            "recording-session",
+            0,
+        );
+        assert!(
+            manager
+                .admit_system_load(&request("recording-session"))
+                .is_ok()
+        );
+
+        manager.set_load_admission_policy(LoadAdmissionPolicy::default());
+        assert!(manager.admit_system_load(&request("new-session")).is_ok());
+    }
+}
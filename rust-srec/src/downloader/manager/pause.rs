@@ -0,0 +1,203 @@
+//! Pausing and resuming active downloads.
+//!
+//! Pausing stops the engine but leaves the recording session open: the
+//! download is cancelled with [`DownloadStopCause::Paused`], the engine's
+//! own end-of-download event is swallowed so the session does not enter
+//! hysteresis, and new downloads for the streamer are refused until it is
+//! resumed. Resuming publishes
+//! [`DownloadProgressEvent::DownloadResumeRequested`]; the scheduler then
+//! re-checks the streamer, and the next live result starts a new download
+//! in the same session, continuing its segment numbering.
+
+use chrono::{DateTime, Utc};
+use dashmap::DashMap;
+use serde::Serialize;
+use tracing::info;
+
+use crate::Result;
+use crate::downloader::engine::EngineType;
+
+use super::{DownloadManager, DownloadManagerEvent, DownloadProgressEvent, DownloadStopCause};
+
+/// A download paused by the user.
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
+pub struct PausedDownload {
+    pub streamer_id: String,
+    pub streamer_name: String,
+    pub session_id: String,
+    /// Engine the download was using when paused.
+    #[schema(value_type = String)]
+    pub engine_type: EngineType,
+    pub paused_at: DateTime<Utc>,
+}
+
+impl DownloadManager {
+    /// Pause an active download.
+    pub async fn pause_download(&self, download_id: &str) -> Result<PausedDownload> {
+        let (config, engine_type) = self
+            .active_downloads
+            .get(download_id)
+            .map(|download| {
+                (
+                    download.handle.config_snapshot(),
+                    download.handle.engine_type,
+                )
+            })
+            .ok_or_else(|| crate::Error::NotFound {
+                entity_type: "Download".to_string(),
+                id: download_id.to_string(),
+            })?;
+
+        let paused = PausedDownload {
+            streamer_id: config.streamer_id,
+            streamer_name: config.streamer_name,
+            session_id: config.session_id,
+            engine_type,
+            paused_at: Utc::now(),
+        };
+        // Registered before stopping so the engine's terminal event is
+        // already recognised as part of the pause.
+        self.paused_downloads
+            .insert(paused.streamer_id.clone(), paused.clone());
+
+        if let Err(e) = self
+            .stop_download_with_reason(download_id, DownloadStopCause::Paused)
+            .await
+        {
+            self.paused_downloads.remove(&paused.streamer_id);
+            return Err(e);
+        }
+
+        info!(
+            streamer_id = %paused.streamer_id,
+            session_id = %paused.session_id,
+            download_id,
+            "Paused download"
+        );
+        Ok(paused)
+    }
+
+    /// Resume the paused download of a streamer.
+    pub fn resume_download(&self, streamer_id: &str) -> Result<PausedDownload> {
+        let (_, paused) =
+            self.paused_downloads
+                .remove(streamer_id)
+                .ok_or_else(|| crate::Error::NotFound {
+                    entity_type: "Paused download".to_string(),
+                    id: streamer_id.to_string(),
+                })?;
+
+        self.events.publish(DownloadManagerEvent::Progress(
+            DownloadProgressEvent::DownloadResumeRequested {
+                streamer_id: paused.streamer_id.clone(),
+                streamer_name: paused.streamer_name.clone(),
+                session_id: paused.session_id.clone(),
+            },
+        ));
+
+        info!(
+            streamer_id = %paused.streamer_id,
+            session_id = %paused.session_id,
+            "Resuming download"
+        );
+        Ok(paused)
+    }
+
+    /// Whether the streamer's download is paused.
+    pub fn is_paused(&self, streamer_id: &str) -> bool {
+        self.paused_downloads.contains_key(streamer_id)
+    }
+
+    /// All paused downloads.
+    pub fn paused_downloads(&self) -> Vec<PausedDownload> {
+        self.paused_downloads
+            .iter()
+            .map(|entry| entry.value().clone())
+            .collect()
+    }
+
+    /// Forget the pause of a session once it has ended, so the streamer
+    /// records its next session normally.
+    pub fn clear_session_pause(&self, session_id: &str) {
+        self.paused_downloads
+            .retain(|_, paused| paused.session_id != session_id);
+    }
+
+    /// Whether the session's download was paused; its terminal event is
+    /// then a consequence of the pause rather than the stream ending.
+    pub(super) fn is_session_paused(
+        paused_downloads: &DashMap<String, PausedDownload>,
+        streamer_id: &str,
+        session_id: &str,
+    ) -> bool {
+        paused_downloads
+            .get(streamer_id)
+            .is_some_and(|paused| paused.session_id == session_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn paused(session_id: &str) -> PausedDownload {
+        PausedDownload {
+            streamer_id: "streamer".to_string(),
+            streamer_name: "Streamer".to_string(),
+            session_id: session_id.to_string(),
+            engine_type: EngineType::Mesio,
+            paused_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn pausing_an_unknown_download_fails() {
+        let manager = DownloadManager::new();
+        assert!(manager.pause_download("missing").await.is_err());
+        assert!(!manager.is_paused("streamer"));
+    }
+
+    #[test]
+    fn resume_publishes_a_resume_request() {
+        let manager = DownloadManager::new();
+        let mut events = manager.subscribe();
+        manager
+            .paused_downloads
+            .insert("streamer".to_string(), paused("session"));
+
+        let resumed = manager.resume_download("streamer").unwrap();
+        assert_eq!(resumed.session_id, "session");
+        assert!(!manager.is_paused("streamer"));
+        assert!(matches!(
+            events.try_recv(),
+            Ok(DownloadManagerEvent::Progress(
+                DownloadProgressEvent::DownloadResumeRequested { .. }
+            ))
+        ));
+        assert!(manager.resume_download("streamer").is_err());
+    }
+
+    #[test]
+    fn pause_is_scoped_to_its_session() {
+        let manager = DownloadManager::new();
+        manager
+            .paused_downloads
+            .insert("streamer".to_string(), paused("session"));
+
+        assert!(DownloadManager::is_session_paused(
+            &manager.paused_downloads,
+            "streamer",
+            "session"
+        ));
+        assert!(!DownloadManager::is_session_paused(
+            &manager.paused_downloads,
+            "streamer",
+            "other"
+        ));
+
+        manager.clear_session_pause("other");
+        assert!(manager.is_paused("streamer"));
+        manager.clear_session_pause("session");
+        assert!(!manager.is_paused("streamer"));
+    }
+}
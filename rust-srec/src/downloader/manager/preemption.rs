@@ -0,0 +1,77 @@
+//! Preempting lower-priority downloads.
+//!
+//! When a download has to queue for a slot, the lowest-priority active
+//! download ranked below it is finalized with
+//! [`DownloadStopCause::Preempted`]. The freed slot goes to the queued
+//! download. The preempted streamer is still live, so the scheduler
+//! re-checks it and its next download waits in the queue like any other.
+
+use tracing::{info, warn};
+
+use crate::downloader::queue::AcquireRequest;
+
+use super::{DownloadManager, DownloadStopCause};
+
+impl DownloadManager {
+    /// Finalize the lowest-priority active download ranked below `req`, so
+    /// the parked `req` takes its slot. Among equal priorities the most
+    /// recently started download is chosen.
+    ///
+    /// Does nothing while another waiter of the same or a higher tier is
+    /// queued, since the freed slot would go to that waiter instead.
+    ///
+    /// Returns the id of the preempted download.
+    pub(super) async fn preempt_for(&self, req: &AcquireRequest) -> Option<String> {
+        let served_first = self.queue.snapshot_pending().into_iter().any(|entry| {
+            entry.session_id != req.session_id && !req.priority.outranks(entry.priority)
+        });
+        if served_first {
+            return None;
+        }
+
+        let (download_id, priority, _) = self
+            .active_downloads
+            .iter()
+            .filter_map(|entry| {
+                let priority = entry.value().slot.as_ref()?.priority();
+                req.priority.outranks(priority).then(|| {
+                    (
+                        entry.key().clone(),
+                        priority,
+                        entry.value().handle.started_at,
+                    )
+                })
+            })
+            .reduce(|current, candidate| {
+                let lower = current.1.outranks(candidate.1);
+                let newer = current.1 == candidate.1 && candidate.2 > current.2;
+                if lower || newer { candidate } else { current }
+            })?;
+
+        let victim = self
+            .active_downloads
+            .get(&download_id)?
+            .handle
+            .config_snapshot();
+        info!(
+            streamer_id = %victim.streamer_id,
+            download_id = %download_id,
+            priority = priority.as_str(),
+            by_streamer_id = %req.streamer_id,
+            by_priority = req.priority.as_str(),
+            "Download slots are full; finalizing the download of {} to start {}",
+            victim.streamer_name,
+            req.streamer_name
+        );
+
+        let cause = DownloadStopCause::Preempted {
+            by_streamer_id: req.streamer_id.clone(),
+            by_streamer_name: req.streamer_name.clone(),
+        };
+        if let Err(e) = self.stop_download_with_reason(&download_id, cause).await {
+            warn!(download_id = %download_id, "Failed to preempt download: {e}");
+            return None;
+        }
+        Some(download_id)
+    }
+}
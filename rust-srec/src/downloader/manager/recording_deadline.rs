@@ -0,0 +1,215 @@
+//! Stopping a manually started recording after a maximum duration.
+//!
+//! A "record now" request may carry a maximum duration, stored here as a
+//! per-streamer deadline and persisted in the `recording_deadlines` table
+//! so a restart does not drop it. The
+//! services container calls [`DownloadManager::enforce_recording_deadlines`]
+//! every [`RECORDING_DEADLINE_CHECK_INTERVAL`]; a download still running once
+//! its streamer's deadline has passed is paused. Pausing finalizes the
+//! current segment but leaves the session open, so nothing is re-recorded
+//! while the stream lasts; the session ends with the stream and the next
+//! one records normally.
+
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use tracing::{info, warn};
+
+use super::{DownloadManager, PausedDownload};
+use crate::Result;
+use crate::database::models::RecordingDeadlineDbModel;
+
+/// How often expired recording deadlines are enforced.
+pub const RECORDING_DEADLINE_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+
+/// How long an expired deadline waits for its streamer's download to start,
+/// which covers the recording restarting after the process restarts.
+const EXPIRED_DEADLINE_GRACE: chrono::Duration = chrono::Duration::minutes(5);
+
+impl DownloadManager {
+    /// Stop the streamer's recording at `stop_at`, replacing any earlier deadline.
+    pub async fn set_recording_deadline(
+        &self,
+        streamer_id: &str,
+        stop_at: DateTime<Utc>,
+    ) -> Result<()> {
+        if let Some(repo) = &self.recording_deadline_repo {
+            repo.upsert_deadline(&RecordingDeadlineDbModel {
+                streamer_id: streamer_id.to_string(),
+                stop_at: stop_at.timestamp_millis(),
+            })
+            .await?;
+        }
+        self.recording_deadlines
+            .insert(streamer_id.to_string(), stop_at);
+        Ok(())
+    }
+
+    /// Drop the streamer's recording deadline, if any.
+    pub async fn clear_recording_deadline(&self, streamer_id: &str) -> Result<()> {
+        if let Some(repo) = &self.recording_deadline_repo {
+            repo.delete_deadline(streamer_id).await?;
+        }
+        self.recording_deadlines.remove(streamer_id);
+        Ok(())
+    }
+
+    pub fn recording_deadline(&self, streamer_id: &str) -> Option<DateTime<Utc>> {
+        self.recording_deadlines
+            .get(streamer_id)
+            .map(|entry| *entry.value())
+    }
+
+    /// Load the persisted deadlines into memory.
+    ///
+    /// Deadlines that passed while the process was down count as expiring
+    /// at `now`, so they still stop a recording that restarts shortly after.
+    pub async fn load_recording_deadlines(&self, now: DateTime<Utc>) -> Result<usize> {
+        let Some(repo) = &self.recording_deadline_repo else {
+            return Ok(0);
+        };
+        let deadlines = repo.list_deadlines().await?;
+        let count = deadlines.len();
+        for deadline in deadlines {
+            let Some(stop_at) = DateTime::from_timestamp_millis(deadline.stop_at) else {
+                continue;
+            };
+            self.recording_deadlines
+                .insert(deadline.streamer_id, stop_at.max(now));
+        }
+        Ok(count)
+    }
+
+    /// Pause the downloads of streamers whose deadline has passed.
+    ///
+    /// An expired deadline without a running download is dropped after
+    /// [`EXPIRED_DEADLINE_GRACE`]. Returns the downloads that were paused.
+    pub async fn enforce_recording_deadlines(&self, now: DateTime<Utc>) -> Vec<PausedDownload> {
+        let expired: Vec<(String, DateTime<Utc>)> = self
+            .recording_deadlines
+            .iter()
+            .filter(|entry| *entry.value() <= now)
+            .map(|entry| (entry.key().clone(), *entry.value()))
+            .collect();
+
+        let mut paused = Vec::new();
+        for (streamer_id, stop_at) in expired {
+            let Some(download) = self.get_download_by_streamer(&streamer_id) else {
+                if now - stop_at >= EXPIRED_DEADLINE_GRACE
+                    && let Err(error) = self.clear_recording_deadline(&streamer_id).await
+                {
+                    warn!(
+                        streamer_id = %streamer_id,
+                        %error,
+                        "Failed to remove expired recording deadline"
+                    );
+                }
+                continue;
+            };
+            match self.pause_download(&download.id).await {
+                Ok(download) => {
+                    info!(
+                        streamer_id = %download.streamer_id,
+                        session_id = %download.session_id,
+                        "Recording reached its requested duration"
+                    );
+                    if let Err(error) = self.clear_recording_deadline(&streamer_id).await {
+                        warn!(
+                            streamer_id = %streamer_id,
+                            %error,
+                            "Failed to remove enforced recording deadline"
+                        );
+                    }
+                    paused.push(download);
+                }
+                Err(error) => warn!(
+                    streamer_id = %streamer_id,
+                    download_id = %download.id,
+                    %error,
+                    "Failed to stop recording at its deadline"
+                ),
+            }
+        }
+        paused
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::database::repositories::{
+        RecordingDeadlineRepository, SqlxRecordingDeadlineRepository,
+    };
+
+    #[tokio::test]
+    async fn deadlines_without_a_download_expire_after_the_grace() {
+        let manager = DownloadManager::new();
+        let now = Utc::now();
+        let recent = now - chrono::Duration::seconds(1);
+        let stale = now - EXPIRED_DEADLINE_GRACE;
+        manager
+            .set_recording_deadline("recent", recent)
+            .await
+            .unwrap();
+        manager
+            .set_recording_deadline("stale", stale)
+            .await
+            .unwrap();
+
+        let paused = manager.enforce_recording_deadlines(now).await;
+
+        assert!(paused.is_empty());
+        assert_eq!(manager.recording_deadline("recent"), Some(recent));
+        assert_eq!(manager.recording_deadline("stale"), None);
+
+        manager.clear_recording_deadline("recent").await.unwrap();
+        assert_eq!(manager.recording_deadline("recent"), None);
+    }
+
+    #[tokio::test]
+    async fn deadlines_survive_a_restart() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let db_url = format!(
+            "sqlite:{}?mode=rwc",
+            dir.path().join("deadlines.db").to_string_lossy()
+        );
+        let pool = crate::database::init_pool(&db_url).await.unwrap();
+        crate::database::run_migrations(&pool).await.unwrap();
+        sqlx::query("INSERT INTO platform_config (id, platform_name) VALUES ('p1', 'test')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query(
+            "INSERT INTO streamers (id, name, url, platform_config_id, state) \
+             VALUES ('streamer-1', 'Streamer', 'https://example.com/s1', 'p1', 'NOT_LIVE')",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        let repo: Arc<dyn RecordingDeadlineRepository> = Arc::new(
+            SqlxRecordingDeadlineRepository::new(pool.clone(), pool.clone()),
+        );
+
+        let stop_at =
+            DateTime::from_timestamp_millis(Utc::now().timestamp_millis() + 60_000).unwrap();
+        DownloadManager::new()
+            .with_recording_deadline_repo(repo.clone())
+            .set_recording_deadline("streamer-1", stop_at)
+            .await
+            .unwrap();
+
+        // Loading after the deadline passed keeps it, expiring at load time.
+        let restarted = DownloadManager::new().with_recording_deadline_repo(repo.clone());
+        let later = stop_at + chrono::Duration::minutes(30);
+        assert_eq!(restarted.load_recording_deadlines(later).await.unwrap(), 1);
+        assert_eq!(restarted.recording_deadline("streamer-1"), Some(later));
+
+        restarted
+            .clear_recording_deadline("streamer-1")
+            .await
+            .unwrap();
+        assert!(repo.list_deadlines().await.unwrap().is_empty());
+    }
+}
@@ -59,11 +59,13 @@ use super::engine::EngineType;
 
 /// Priority tier for a queued or active download.
 ///
-/// `High` always sorts before `Normal` in the wait order; ties are
-/// broken by `queued_at_ms` (earlier first), then by the per-queue
-/// monotonic insertion sequence number.
+/// `High` sorts before `Normal`, which sorts before `Low`, in the wait
+/// order; ties are broken by `queued_at_ms` (earlier first), then by the
+/// per-queue monotonic insertion sequence number. `Low` occupies normal
+/// slots like `Normal`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Priority {
+    Low,
     Normal,
     High,
 }
@@ -76,10 +78,26 @@ impl Priority {
     /// Stable string label for logging.
     pub fn as_str(&self) -> &'static str {
         match self {
+            Self::Low => "low",
             Self::Normal => "normal",
             Self::High => "high",
         }
     }
+
+    /// Whether downloads of this tier are served before `other`'s.
+    pub fn outranks(&self, other: Self) -> bool {
+        priority_rank(*self) > priority_rank(other)
+    }
+}
+
+impl From<crate::domain::Priority> for Priority {
+    fn from(priority: crate::domain::Priority) -> Self {
+        match priority {
+            crate::domain::Priority::High => Self::High,
+            crate::domain::Priority::Normal => Self::Normal,
+            crate::domain::Priority::Low => Self::Low,
+        }
+    }
 }
 
 /// Snapshot view of an entry currently waiting on the queue.
@@ -211,6 +229,11 @@ pub struct ActiveSlot {
 }
 
 impl ActiveSlot {
+    /// Priority tier the slot was acquired with.
+    pub fn priority(&self) -> Priority {
+        self.priority
+    }
+
     /// Explicitly release the slot. Subsequent drops are no-ops.
     pub fn release(mut self) {
         if !self.released {
@@ -270,7 +293,7 @@ impl Ord for Waiter {
     /// *earliest queued_at* to come out first, so flip the natural
     /// ordering.
     fn cmp(&self, other: &Self) -> Ordering {
-        // High > Normal so that High pops first.
+        // High > Normal > Low so that High pops first.
         let prio = priority_rank(self.priority).cmp(&priority_rank(other.priority));
         if prio != Ordering::Equal {
             return prio;
@@ -288,8 +311,9 @@ impl Ord for Waiter {
 
 fn priority_rank(p: Priority) -> u8 {
     match p {
-        Priority::High => 1,
-        Priority::Normal => 0,
+        Priority::High => 2,
+        Priority::Normal => 1,
+        Priority::Low => 0,
     }
 }
 
@@ -635,7 +659,7 @@ impl DownloadQueue {
     fn try_reserve_capacity(&self, priority: Priority) -> bool {
         match priority {
             Priority::High => self.try_reserve_total_capacity(),
-            Priority::Normal => self.try_reserve_normal_capacity(),
+            Priority::Normal | Priority::Low => self.try_reserve_normal_capacity(),
         }
     }
 
@@ -717,10 +741,11 @@ impl DownloadQueue {
                 // The top isn't a high-priority that fits, and no normal
                 // capacity. If the top is high but doesn't fit, no later
                 // entry can either (they're all <= high). If the top is
-                // normal and there's no normal capacity, no later normal
-                // can either, but a later high might still fit — except
-                // BinaryHeap orders high > normal so high is always the
-                // top. So we can safely return false here.
+                // normal or low and there's no normal capacity, no later
+                // normal or low can either, but a later high might still
+                // fit — except BinaryHeap orders high > normal > low so
+                // high is always the top. So we can safely return false
+                // here.
                 return false;
             }
 
@@ -753,7 +778,7 @@ impl DownloadQueue {
     /// Release a held slot and try to promote the next waiter.
     fn release(&self, priority: Priority) {
         self.in_flight_total.fetch_sub(1, AtomicOrdering::SeqCst);
-        if matches!(priority, Priority::Normal) {
+        if !priority.is_high() {
             self.in_flight_normal.fetch_sub(1, AtomicOrdering::SeqCst);
         }
         // Wake one waiter if any fit.
@@ -1486,6 +1511,53 @@ mod tests {
         assert!(matches!(g_first.priority(), Priority::Normal));
     }
 
+    #[tokio::test]
+    async fn low_priority_waits_behind_later_normal() {
+        let q = DownloadQueue::new(1, 0);
+        let g = q
+            .acquire(req("a", Priority::Normal), CancellationToken::new(), |_| {})
+            .await
+            .unwrap();
+        let q1 = q.clone();
+        let h_low = tokio::spawn(async move {
+            q1.acquire(req("low", Priority::Low), CancellationToken::new(), |_| {})
+                .await
+        });
+        for _ in 0..50 {
+            if q.pending_count() == 1 {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        let q2 = q.clone();
+        let h_normal = tokio::spawn(async move {
+            q2.acquire(
+                req("normal", Priority::Normal),
+                CancellationToken::new(),
+                |_| {},
+            )
+            .await
+        });
+        for _ in 0..50 {
+            if q.pending_count() == 2 {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+
+        drop(g);
+        let g_normal = h_normal.await.unwrap().unwrap();
+        assert_eq!(g_normal.session_id(), "normal");
+        assert_eq!(q.in_flight_normal(), 1);
+        drop(g_normal);
+        let g_low = h_low.await.unwrap().unwrap();
+        assert!(matches!(g_low.priority(), Priority::Low));
+        assert_eq!(q.in_flight_normal(), 1);
+        drop(g_low);
+        assert_eq!(q.in_flight(), 0);
+        assert_eq!(q.in_flight_normal(), 0);
+    }
+
     #[tokio::test]
     async fn into_active_releases_only_on_active_drop() {
         let q = DownloadQueue::new(1, 0);
@@ -6,8 +6,11 @@
 use platforms_parser::media::{StreamFormat, StreamInfo, formats::MediaFormat};
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
+use std::time::Duration;
 use tracing::debug;
 
+use super::cdn_probe::{DEFAULT_PROBE_TIMEOUT, ProbeOutcome};
+
 /// Configuration for stream selection preferences.
 ///
 /// Quality names vary by platform:
@@ -44,6 +47,140 @@ pub struct StreamSelectionConfig {
     pub min_bitrate: u64,
     /// Maximum bitrate in bits per second (0 = no maximum).
     pub max_bitrate: u64,
+    /// Weighted scoring rules. Candidates are ranked by the summed weight of
+    /// the rules they meet; the preferences above only break ties.
+    /// Empty means rank by the preferences alone.
+    pub rules: Vec<StreamScoringRule>,
+    /// Probe the resolved CDN candidates before recording and move slow or
+    /// unreachable ones behind the fastest. None inherits from the layer below.
+    pub probe_cdns: Option<bool>,
+    /// How long a probe waits for the first byte, in milliseconds
+    /// (0 = default of 1500).
+    pub probe_timeout_ms: u64,
+}
+
+/// A rule contributing to a stream's selection score.
+///
+/// A stream meeting the rule adds `weight` to its score. Negative weights
+/// penalize instead. Attributes a platform does not report (height, fps,
+/// bitrate) never meet a rule.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "rule", rename_all = "snake_case")]
+pub enum StreamScoringRule {
+    /// Codec family matches, e.g. `avc` (also `h264`), `hevc` (also
+    /// `h265`) or `av1`.
+    PreferCodec {
+        codec: String,
+        #[serde(default = "default_rule_weight")]
+        weight: i32,
+    },
+    /// Video height is at least `height`.
+    MinHeight {
+        height: u32,
+        #[serde(default = "default_rule_weight")]
+        weight: i32,
+    },
+    /// Video height is at most `height`.
+    MaxHeight {
+        height: u32,
+        #[serde(default = "default_rule_weight")]
+        weight: i32,
+    },
+    /// Frame rate is at least `fps`.
+    MinFps {
+        fps: f64,
+        #[serde(default = "default_rule_weight")]
+        weight: i32,
+    },
+    /// Bitrate is at most `bitrate` bits per second.
+    MaxBitrate {
+        bitrate: u64,
+        #[serde(default = "default_rule_weight")]
+        weight: i32,
+    },
+    /// Excludes streams from the CDN, like `blacklisted_cdns`.
+    BanCdn { cdn: String },
+}
+
+fn default_rule_weight() -> i32 {
+    1
+}
+
+impl StreamScoringRule {
+    /// Weight the rule adds to `stream`'s score.
+    fn score(&self, stream: &StreamInfo) -> i64 {
+        let (met, weight) = match self {
+            Self::PreferCodec { codec, weight } => {
+                (codec_family(&stream.codec) == codec_family(codec), *weight)
+            }
+            Self::MinHeight { height, weight } => {
+                (stream_height(stream).is_some_and(|h| h >= *height), *weight)
+            }
+            Self::MaxHeight { height, weight } => {
+                (stream_height(stream).is_some_and(|h| h <= *height), *weight)
+            }
+            Self::MinFps { fps, weight } => (stream.fps > 0.0 && stream.fps >= *fps, *weight),
+            Self::MaxBitrate { bitrate, weight } => {
+                (stream.bitrate > 0 && stream.bitrate <= *bitrate, *weight)
+            }
+            Self::BanCdn { .. } => (false, 0),
+        };
+        if met { i64::from(weight) } else { 0 }
+    }
+}
+
+/// Normalize codec names so `h264` and `avc1.64001f` compare equal.
+fn codec_family(codec: &str) -> Cow<'_, str> {
+    let lower = codec.to_lowercase();
+    if lower.contains("264") || lower.starts_with("avc") {
+        Cow::Borrowed("avc")
+    } else if lower.contains("265")
+        || lower.starts_with("hevc")
+        || lower.starts_with("hvc1")
+        || lower.starts_with("hev1")
+    {
+        Cow::Borrowed("hevc")
+    } else if lower.starts_with("av1") || lower.starts_with("av01") {
+        Cow::Borrowed("av1")
+    } else {
+        Cow::Owned(lower)
+    }
+}
+
+/// Video height of a stream, from a `height` extra or a quality label such
+/// as `1080p60` or `4K`.
+fn stream_height(stream: &StreamInfo) -> Option<u32> {
+    if let Some(height) = stream
+        .extras
+        .as_ref()
+        .and_then(|e| e.get("height"))
+        .and_then(|v| v.as_u64())
+    {
+        return u32::try_from(height).ok();
+    }
+
+    let quality = stream.quality.to_lowercase();
+    if quality.contains("8k") {
+        return Some(4320);
+    }
+    if quality.contains("4k") {
+        return Some(2160);
+    }
+    let bytes = quality.as_bytes();
+    let mut start = None;
+    for (i, b) in bytes.iter().enumerate() {
+        if b.is_ascii_digit() {
+            start.get_or_insert(i);
+        } else {
+            if *b == b'p'
+                && let Some(start) = start
+            {
+                return quality[start..i].parse().ok();
+            }
+            start = None;
+        }
+    }
+    None
 }
 
 impl StreamSelectionConfig {
@@ -96,6 +233,17 @@ impl StreamSelectionConfig {
             } else {
                 self.max_bitrate
             },
+            rules: if other.rules.is_empty() {
+                self.rules.clone()
+            } else {
+                other.rules.clone()
+            },
+            probe_cdns: other.probe_cdns.or(self.probe_cdns),
+            probe_timeout_ms: if other.probe_timeout_ms > 0 {
+                other.probe_timeout_ms
+            } else {
+                self.probe_timeout_ms
+            },
         }
     }
 }
@@ -171,6 +319,49 @@ impl StreamSelector {
         sorted
     }
 
+    /// Whether the resolved CDN candidates should be probed before recording.
+    #[must_use]
+    pub fn probes_cdns(&self) -> bool {
+        self.config.probe_cdns.unwrap_or(false)
+    }
+
+    /// How long a CDN probe waits for the first byte.
+    #[must_use]
+    pub fn probe_timeout(&self) -> Duration {
+        match self.config.probe_timeout_ms {
+            0 => DEFAULT_PROBE_TIMEOUT,
+            ms => Duration::from_millis(ms),
+        }
+    }
+
+    /// Reorder streams of the same quality on different CDNs by their probe
+    /// outcomes, in the order of `streams`.
+    ///
+    /// Unreachable streams move last. Scoring rules and CDN preferences
+    /// still come first; latency replaces only the platform's own priority.
+    /// When no stream was reachable the order is left alone, since the probe
+    /// itself may be what failed.
+    pub fn rank_by_probe(&self, streams: &mut Vec<StreamInfo>, outcomes: &[ProbeOutcome]) {
+        if streams.len() != outcomes.len()
+            || outcomes.iter().all(|o| *o == ProbeOutcome::Unreachable)
+        {
+            return;
+        }
+
+        let mut ranked: Vec<(StreamInfo, ProbeOutcome)> =
+            streams.drain(..).zip(outcomes.iter().copied()).collect();
+        ranked.sort_by(|(a, a_probe), (b, b_probe)| {
+            a_probe
+                .latency()
+                .is_none()
+                .cmp(&b_probe.latency().is_none())
+                .then_with(|| self.rule_score(b).cmp(&self.rule_score(a)))
+                .then_with(|| self.cdn_score(a).cmp(&self.cdn_score(b)))
+                .then_with(|| a_probe.latency().cmp(&b_probe.latency()))
+        });
+        streams.extend(ranked.into_iter().map(|(stream, _)| stream));
+    }
+
     /// Check if a stream matches the selection criteria.
     #[inline]
     fn matches_criteria(&self, stream: &StreamInfo) -> bool {
@@ -189,15 +380,17 @@ impl StreamSelector {
     /// Lower is better (will be sorted first).
     fn compare_streams(&self, a: &StreamInfo, b: &StreamInfo) -> std::cmp::Ordering {
         // Priority Order:
-        // 1. Quality Preference
-        // 2. CDN Preference
-        // 3. Format Preference
-        // 4. Media Format Preference
-        // 5. Priority Field (lower value = higher priority)
-        // 6. Bitrate (higher value = better)
-
-        self.quality_score(a)
-            .cmp(&self.quality_score(b))
+        // 1. Rule Score (higher value = better)
+        // 2. Quality Preference
+        // 3. CDN Preference
+        // 4. Format Preference
+        // 5. Media Format Preference
+        // 6. Priority Field (lower value = higher priority)
+        // 7. Bitrate (higher value = better)
+
+        self.rule_score(b)
+            .cmp(&self.rule_score(a))
+            .then_with(|| self.quality_score(a).cmp(&self.quality_score(b)))
             .then_with(|| self.cdn_score(a).cmp(&self.cdn_score(b)))
             .then_with(|| self.format_score(a).cmp(&self.format_score(b)))
             .then_with(|| self.media_format_score(a).cmp(&self.media_format_score(b)))
@@ -205,6 +398,15 @@ impl StreamSelector {
             .then_with(|| b.bitrate.cmp(&a.bitrate))
     }
 
+    /// Get the summed weight of the scoring rules the stream meets (higher is better).
+    fn rule_score(&self, stream: &StreamInfo) -> i64 {
+        self.config
+            .rules
+            .iter()
+            .map(|rule| rule.score(stream))
+            .sum()
+    }
+
     /// Get the format preference score (lower is better).
     fn format_score(&self, stream: &StreamInfo) -> usize {
         match &self.config.preferred_formats {
@@ -287,16 +489,24 @@ impl StreamSelector {
     /// Uses case-insensitive substring matching.
     /// Falls back to URL matching if CDN info is not available in extras.
     fn is_cdn_blacklisted(&self, stream: &StreamInfo) -> bool {
-        if self.config.blacklisted_cdns.is_empty() {
+        let banned_by_rules = self.config.rules.iter().filter_map(|rule| match rule {
+            StreamScoringRule::BanCdn { cdn } if !cdn.is_empty() => Some(cdn.as_str()),
+            _ => None,
+        });
+        let mut banned = self
+            .config
+            .blacklisted_cdns
+            .iter()
+            .map(String::as_str)
+            .chain(banned_by_rules)
+            .peekable();
+        if banned.peek().is_none() {
             return false;
         }
 
         let cdn_source = self.get_cdn_source(stream);
         let cdn_lower = cdn_source.to_lowercase();
-        self.config
-            .blacklisted_cdns
-            .iter()
-            .any(|blacklisted| cdn_lower.contains(&blacklisted.to_lowercase()))
+        banned.any(|blacklisted| cdn_lower.contains(&blacklisted.to_lowercase()))
     }
 }
 
@@ -972,4 +1182,240 @@ mod tests {
         // Should NOT be blacklisted because extras["cdn"] = "goodcdn" takes precedence
         assert!(result.is_some());
     }
+
+    // ========== Scoring rule tests ==========
+
+    #[test]
+    fn test_rules_pick_highest_avc_up_to_1080p_outside_banned_cdn() {
+        let config = StreamSelectionConfig {
+            rules: vec![
+                StreamScoringRule::MaxHeight {
+                    height: 1080,
+                    weight: 10,
+                },
+                StreamScoringRule::PreferCodec {
+                    codec: "avc".to_string(),
+                    weight: 5,
+                },
+                StreamScoringRule::BanCdn {
+                    cdn: "tx".to_string(),
+                },
+            ],
+            ..Default::default()
+        };
+        let selector = StreamSelector::with_config(config);
+
+        let mut uhd = create_test_stream(
+            "https://ali.example.com/4k.flv",
+            StreamFormat::Flv,
+            "2160p",
+            20_000_000,
+            0,
+        );
+        uhd.codec = "avc".to_string();
+        let mut hevc = create_test_stream(
+            "https://ali.example.com/hevc.flv",
+            StreamFormat::Flv,
+            "1080p",
+            6_000_000,
+            1,
+        );
+        hevc.codec = "hevc".to_string();
+        let tx = create_test_stream(
+            "https://tx.example.com/1080.flv",
+            StreamFormat::Flv,
+            "1080p60",
+            8_000_000,
+            2,
+        );
+        let fhd = create_test_stream(
+            "https://ali.example.com/1080.flv",
+            StreamFormat::Flv,
+            "1080p",
+            8_000_000,
+            3,
+        );
+        let hd = create_test_stream(
+            "https://ali.example.com/720.flv",
+            StreamFormat::Flv,
+            "720p",
+            3_000_000,
+            4,
+        );
+
+        let streams = vec![uhd, hevc, tx, fhd, hd];
+        let sorted = selector.sort_candidates(&streams);
+
+        assert_eq!(sorted.len(), 4);
+        assert_eq!(sorted[0].url, "https://ali.example.com/1080.flv");
+        assert_eq!(sorted[1].url, "https://ali.example.com/720.flv");
+        assert_eq!(sorted[2].url, "https://ali.example.com/hevc.flv");
+        assert_eq!(sorted[3].url, "https://ali.example.com/4k.flv");
+    }
+
+    #[test]
+    fn test_rules_ignore_unknown_attributes() {
+        let config = StreamSelectionConfig {
+            rules: vec![
+                StreamScoringRule::MinHeight {
+                    height: 720,
+                    weight: 1,
+                },
+                StreamScoringRule::MinFps {
+                    fps: 60.0,
+                    weight: 1,
+                },
+            ],
+            ..Default::default()
+        };
+        let selector = StreamSelector::with_config(config);
+
+        let mut unknown = create_test_stream(
+            "https://a.example.com/origin.flv",
+            StreamFormat::Flv,
+            "原画",
+            0,
+            0,
+        );
+        unknown.fps = 0.0;
+        let smooth = create_test_stream(
+            "https://a.example.com/720.flv",
+            StreamFormat::Flv,
+            "720p60",
+            0,
+            1,
+        );
+
+        let streams = vec![unknown, smooth];
+        assert_eq!(
+            selector.select_best(&streams).unwrap().url,
+            "https://a.example.com/720.flv"
+        );
+    }
+
+    #[test]
+    fn test_stream_height_and_codec_family() {
+        let mut stream = create_test_stream("u", StreamFormat::Flv, "1080p60", 0, 0);
+        assert_eq!(stream_height(&stream), Some(1080));
+        stream.quality = "4K HDR".to_string();
+        assert_eq!(stream_height(&stream), Some(2160));
+        stream.quality = "蓝光".to_string();
+        assert_eq!(stream_height(&stream), None);
+        stream.extras = Some(serde_json::json!({ "height": 720 }));
+        assert_eq!(stream_height(&stream), Some(720));
+
+        assert_eq!(codec_family("h264"), codec_family("AVC"));
+        assert_eq!(codec_family("avc1.64001f"), "avc");
+        assert_eq!(codec_family("H.265"), "hevc");
+        assert_eq!(codec_family("av01.0.08M.08"), "av1");
+    }
+
+    #[test]
+    fn test_rules_deserialize_with_default_weight() {
+        let config: StreamSelectionConfig = serde_json::from_str(
+            r#"{"rules":[{"rule":"prefer_codec","codec":"avc"},{"rule":"ban_cdn","cdn":"tx"}]}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.rules,
+            vec![
+                StreamScoringRule::PreferCodec {
+                    codec: "avc".to_string(),
+                    weight: 1,
+                },
+                StreamScoringRule::BanCdn {
+                    cdn: "tx".to_string(),
+                },
+            ]
+        );
+
+        let merged = StreamSelectionConfig::default().merge(&config);
+        assert_eq!(merged.rules.len(), 2);
+        assert_eq!(
+            config.merge(&StreamSelectionConfig::default()).rules.len(),
+            2
+        );
+    }
+
+    // ========== CDN probe ranking tests ==========
+
+    fn cdn_stream(cdn: &str) -> StreamInfo {
+        let mut stream = create_test_stream(
+            &format!("https://{cdn}.example.com/live.flv"),
+            StreamFormat::Flv,
+            "1080p",
+            0,
+            0,
+        );
+        stream.extras = Some(serde_json::json!({ "cdn": cdn }));
+        stream
+    }
+
+    #[test]
+    fn test_rank_by_probe_prefers_fast_reachable_cdns() {
+        let selector = StreamSelector::new();
+        let mut streams = vec![cdn_stream("far"), cdn_stream("down"), cdn_stream("near")];
+        let outcomes = [
+            ProbeOutcome::Reachable(Duration::from_millis(900)),
+            ProbeOutcome::Unreachable,
+            ProbeOutcome::Reachable(Duration::from_millis(40)),
+        ];
+
+        selector.rank_by_probe(&mut streams, &outcomes);
+
+        let order: Vec<&str> = streams.iter().map(|s| s.url.as_str()).collect();
+        assert_eq!(
+            order,
+            vec![
+                "https://near.example.com/live.flv",
+                "https://far.example.com/live.flv",
+                "https://down.example.com/live.flv",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rank_by_probe_keeps_cdn_preference_and_failed_probes() {
+        let selector = StreamSelector::with_config(StreamSelectionConfig {
+            preferred_cdns: vec!["far".to_string()],
+            ..Default::default()
+        });
+        let mut streams = vec![cdn_stream("near"), cdn_stream("far")];
+        selector.rank_by_probe(
+            &mut streams,
+            &[
+                ProbeOutcome::Reachable(Duration::from_millis(40)),
+                ProbeOutcome::Reachable(Duration::from_millis(900)),
+            ],
+        );
+        assert_eq!(streams[0].url, "https://far.example.com/live.flv");
+
+        selector.rank_by_probe(
+            &mut streams,
+            &[ProbeOutcome::Unreachable, ProbeOutcome::Unreachable],
+        );
+        assert_eq!(streams[0].url, "https://far.example.com/live.flv");
+    }
+
+    #[test]
+    fn test_merge_probe_settings() {
+        let base = StreamSelectionConfig {
+            probe_cdns: Some(true),
+            probe_timeout_ms: 800,
+            ..Default::default()
+        };
+        let merged = base.merge(&StreamSelectionConfig::default());
+        assert!(StreamSelector::with_config(merged.clone()).probes_cdns());
+        assert_eq!(
+            StreamSelector::with_config(merged).probe_timeout(),
+            Duration::from_millis(800)
+        );
+
+        let disabled = base.merge(&StreamSelectionConfig {
+            probe_cdns: Some(false),
+            ..Default::default()
+        });
+        assert!(!StreamSelector::with_config(disabled).probes_cdns());
+        assert_eq!(StreamSelector::new().probe_timeout(), DEFAULT_PROBE_TIMEOUT);
+    }
 }
@@ -44,6 +44,9 @@ pub enum Error {
     #[error("Monitor error: {0}")]
     Monitor(String),
 
+    #[error("Rate limited by {platform}: {message}")]
+    RateLimited { platform: String, message: String },
+
     #[error("Pipeline error: {0}")]
     PipelineError(String),
 
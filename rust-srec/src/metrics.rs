@@ -7,6 +7,7 @@
 //!
 //! - Download metrics (active downloads, bytes, duration, errors)
 //! - Pipeline metrics (queue depth, jobs, duration)
+//! - Stream pipeline operator metrics of active recordings (items, bytes, time)
 //! - Streamer metrics (total, live, errors)
 //! - System metrics (cache hits/misses, disk space, memory)
 //! - Health check endpoints (/health, /ready)
@@ -30,7 +31,9 @@ pub mod gpu_health;
 mod health;
 mod prometheus;
 
-pub use collector::{MetricsCollector, MetricsSnapshot};
+pub use collector::{
+    MetricsCollector, MetricsSnapshot, PipelineOperatorMetrics, PipelineOperatorSnapshot,
+};
 pub use gpu_health::{
     DEFAULT_PROBE_INTERVAL_SECS as DEFAULT_GPU_PROBE_INTERVAL_SECS, GpuHealthMonitor,
 };
@@ -3,10 +3,12 @@
 //! Collects and stores metrics for the streaming recorder system.
 
 use std::collections::HashMap;
+use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
 
 use dashmap::DashMap;
 use parking_lot::RwLock;
+use pipeline_common::{ProcessorMetrics, ProcessorMetricsSink};
 use serde::{Deserialize, Serialize};
 
 /// Metrics collector for the streaming recorder system.
@@ -25,6 +27,9 @@ pub struct MetricsCollector {
     pipeline_job_duration_total_ms: DashMap<String, AtomicU64>,
     pipeline_job_count: DashMap<String, AtomicU64>,
 
+    // Stream pipeline operator metrics of active recordings, by streamer and operator index
+    pipeline_operators: DashMap<(String, usize), PipelineOperatorSnapshot>,
+
     // Streamer metrics
     streamers_by_state: DashMap<String, AtomicU64>,
     streamer_errors: DashMap<String, AtomicU64>,
@@ -61,6 +66,7 @@ impl MetricsCollector {
             pipeline_jobs_total: DashMap::new(),
             pipeline_job_duration_total_ms: DashMap::new(),
             pipeline_job_count: DashMap::new(),
+            pipeline_operators: DashMap::new(),
             streamers_by_state: DashMap::new(),
             streamer_errors: DashMap::new(),
             config_cache_hits: AtomicU64::new(0),
@@ -160,6 +166,24 @@ impl MetricsCollector {
             .fetch_add(1, Ordering::Relaxed);
     }
 
+    /// Record the per-operator totals of a recording's stream pipeline.
+    pub fn record_pipeline_operators(&self, streamer_id: &str, operators: &[ProcessorMetrics]) {
+        for operator in operators {
+            self.pipeline_operators.insert(
+                (streamer_id.to_string(), operator.index),
+                PipelineOperatorSnapshot::new(streamer_id, operator),
+            );
+        }
+    }
+
+    /// Forget the operators of a recording whose stream pipeline has finished.
+    pub fn remove_pipeline_operators(&self, streamer_id: &str, operators: &[ProcessorMetrics]) {
+        for operator in operators {
+            self.pipeline_operators
+                .remove(&(streamer_id.to_string(), operator.index));
+        }
+    }
+
     // ========== Streamer Metrics ==========
 
     /// Set streamer count by state.
@@ -267,6 +291,11 @@ impl MetricsCollector {
                 .map(|e| (e.key().clone(), e.value().load(Ordering::Relaxed)))
                 .collect(),
             pipeline_job_duration_avg_ms: self.avg_pipeline_job_duration_ms(),
+            pipeline_operators: self
+                .pipeline_operators
+                .iter()
+                .map(|e| e.value().clone())
+                .collect(),
             streamers_by_state: self
                 .streamers_by_state
                 .iter()
@@ -358,6 +387,7 @@ pub struct MetricsSnapshot {
     pub pipeline_queue_depth: HashMap<String, u64>,
     pub pipeline_jobs_total: HashMap<String, u64>,
     pub pipeline_job_duration_avg_ms: HashMap<String, f64>,
+    pub pipeline_operators: Vec<PipelineOperatorSnapshot>,
 
     // Streamer metrics
     pub streamers_by_state: HashMap<String, u64>,
@@ -378,6 +408,67 @@ pub struct MetricsSnapshot {
     pub web_push_delivery_duration_avg_ms: f64,
 }
 
+/// Totals of one operator of an active recording's stream pipeline.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PipelineOperatorSnapshot {
+    pub streamer_id: String,
+    /// Position of the operator in the pipeline
+    pub index: usize,
+    pub operator: String,
+    pub items_in: u64,
+    pub items_out: u64,
+    pub bytes_in: u64,
+    pub bytes_out: u64,
+    pub dropped_items: u64,
+    pub processing_time_ms: u64,
+}
+
+impl PipelineOperatorSnapshot {
+    fn new(streamer_id: &str, operator: &ProcessorMetrics) -> Self {
+        Self {
+            streamer_id: streamer_id.to_string(),
+            index: operator.index,
+            operator: operator.name.to_string(),
+            items_in: operator.items_in,
+            items_out: operator.items_out,
+            bytes_in: operator.bytes_in,
+            bytes_out: operator.bytes_out,
+            dropped_items: operator.dropped_items,
+            processing_time_ms: operator.processing_time.as_millis() as u64,
+        }
+    }
+}
+
+/// Feeds the stream pipeline of one recording into a [`MetricsCollector`].
+///
+/// Operators are exposed while the pipeline runs and removed once it finishes,
+/// so only active recordings show up.
+pub struct PipelineOperatorMetrics {
+    collector: Arc<MetricsCollector>,
+    streamer_id: String,
+}
+
+impl PipelineOperatorMetrics {
+    pub fn new(collector: Arc<MetricsCollector>, streamer_id: impl Into<String>) -> Self {
+        Self {
+            collector,
+            streamer_id: streamer_id.into(),
+        }
+    }
+}
+
+impl ProcessorMetricsSink for PipelineOperatorMetrics {
+    fn record(&self, metrics: &[ProcessorMetrics]) {
+        self.collector
+            .record_pipeline_operators(&self.streamer_id, metrics);
+    }
+
+    fn finished(&self, metrics: &[ProcessorMetrics]) {
+        self.collector
+            .remove_pipeline_operators(&self.streamer_id, metrics);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -438,6 +529,31 @@ mod tests {
         assert_eq!(snapshot.pipeline_jobs_total.get("failed"), Some(&1));
     }
 
+    #[test]
+    fn test_pipeline_operator_metrics() {
+        let collector = MetricsCollector::new();
+        let operators = [ProcessorMetrics {
+            index: 0,
+            name: "Defragment",
+            items_in: 10,
+            items_out: 8,
+            bytes_in: 1000,
+            bytes_out: 800,
+            dropped_items: 2,
+            processing_time: std::time::Duration::from_millis(5),
+        }];
+
+        collector.record_pipeline_operators("streamer-1", &operators);
+        let snapshot = collector.snapshot();
+        assert_eq!(snapshot.pipeline_operators.len(), 1);
+        assert_eq!(snapshot.pipeline_operators[0].operator, "Defragment");
+        assert_eq!(snapshot.pipeline_operators[0].dropped_items, 2);
+        assert_eq!(snapshot.pipeline_operators[0].processing_time_ms, 5);
+
+        collector.remove_pipeline_operators("streamer-1", &operators);
+        assert!(collector.snapshot().pipeline_operators.is_empty());
+    }
+
     #[test]
     fn test_streamer_metrics() {
         let collector = MetricsCollector::new();
@@ -87,6 +87,50 @@ impl PrometheusExporter {
             );
         }
 
+        // Stream pipeline operator metrics of active recordings
+        for operator in &snapshot.pipeline_operators {
+            let index = operator.index.to_string();
+            let labels = [
+                ("streamer_id", operator.streamer_id.as_str()),
+                ("operator", operator.operator.as_str()),
+                ("index", index.as_str()),
+            ];
+            for (name, help, value) in [
+                (
+                    "pipeline_operator_items_in_total",
+                    "Items received by a stream pipeline operator",
+                    operator.items_in as f64,
+                ),
+                (
+                    "pipeline_operator_items_out_total",
+                    "Items emitted by a stream pipeline operator",
+                    operator.items_out as f64,
+                ),
+                (
+                    "pipeline_operator_bytes_in_total",
+                    "Bytes received by a stream pipeline operator",
+                    operator.bytes_in as f64,
+                ),
+                (
+                    "pipeline_operator_bytes_out_total",
+                    "Bytes emitted by a stream pipeline operator",
+                    operator.bytes_out as f64,
+                ),
+                (
+                    "pipeline_operator_dropped_items_total",
+                    "Items that produced no output in a stream pipeline operator",
+                    operator.dropped_items as f64,
+                ),
+                (
+                    "pipeline_operator_processing_seconds_total",
+                    "Time spent in a stream pipeline operator",
+                    operator.processing_time_ms as f64 / 1000.0,
+                ),
+            ] {
+                self.write_counter_with_labels(&mut output, name, help, value, &labels);
+            }
+        }
+
         // Streamer metrics
         let mut total_streamers = 0u64;
         for (state, count) in &snapshot.streamers_by_state {
@@ -312,6 +356,34 @@ mod tests {
         assert!(output.contains("rust_srec_pipeline_queue_depth{worker_type=\"cpu\"}"));
     }
 
+    #[test]
+    fn test_prometheus_export_pipeline_operators() {
+        let collector = Arc::new(MetricsCollector::new());
+        collector.record_pipeline_operators(
+            "streamer-1",
+            &[pipeline_common::ProcessorMetrics {
+                index: 1,
+                name: "Split",
+                items_in: 3,
+                items_out: 3,
+                bytes_in: 300,
+                bytes_out: 300,
+                dropped_items: 0,
+                processing_time: std::time::Duration::from_millis(1500),
+            }],
+        );
+
+        let exporter = PrometheusExporter::new(collector);
+        let output = exporter.export();
+
+        assert!(output.contains(
+            "rust_srec_pipeline_operator_items_in_total{streamer_id=\"streamer-1\",operator=\"Split\",index=\"1\"} 3"
+        ));
+        assert!(output.contains(
+            "rust_srec_pipeline_operator_processing_seconds_total{streamer_id=\"streamer-1\",operator=\"Split\",index=\"1\"} 1.5"
+        ));
+    }
+
     #[test]
     fn test_prometheus_custom_namespace() {
         let collector = Arc::new(MetricsCollector::new());
@@ -207,6 +207,7 @@ impl BatchDetector {
 
                     // Check if it's a rate limit error
                     if is_rate_limit_error(&e) {
+                        self.rate_limiter.slow_down(platform_id).await;
                         let delay = self.calculate_backoff(retries);
                         warn!(
                             "Rate limited on {}, retrying in {:?} (attempt {}/{})",
@@ -269,6 +270,9 @@ impl BatchDetector {
 
 /// Check if an error is a rate limit error.
 fn is_rate_limit_error(error: &crate::Error) -> bool {
+    if matches!(error, crate::Error::RateLimited { .. }) {
+        return true;
+    }
     // Check for HTTP 429 status or rate limit messages
     let error_str = error.to_string().to_lowercase();
     error_str.contains("429") || error_str.contains("rate limit")
@@ -286,6 +290,7 @@ mod tests {
             url: format!("https://twitch.tv/{}", id),
             platform_config_id: "twitch".to_string(),
             template_config_id: None,
+            group_id: None,
             state: StreamerState::NotLive,
             priority: Priority::Normal,
             avatar_url: None,
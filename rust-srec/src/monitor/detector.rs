@@ -8,18 +8,22 @@ use chrono::{DateTime, Utc};
 use dashmap::DashMap;
 use platforms_parser::extractor::error::ExtractorError;
 use platforms_parser::extractor::factory::ExtractorFactory;
+use platforms_parser::media::media_info::MediaInfo;
 use serde::{Deserialize, Serialize};
 use tracing::{debug, error, trace, warn};
 
 use crate::Result;
 use crate::domain::ProxyConfig;
 use crate::domain::filter::{Filter, FilterType};
-use crate::downloader::{StreamSelectionConfig, StreamSelector};
+use crate::downloader::{StreamSelectionConfig, StreamSelector, cdn_probe};
 use crate::streamer::StreamerMetadata;
 
 /// Re-export StreamInfo from platforms_parser for convenience.
 pub use platforms_parser::media::StreamInfo;
 
+/// Fallback CDN streams resolved next to the selected stream.
+const MAX_FALLBACK_STREAMS: usize = 2;
+
 /// Live status of a streamer.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum LiveStatus {
@@ -37,6 +41,9 @@ pub enum LiveStatus {
         avatar: Option<String>,
         /// Stream information from platform parser (URLs, format, quality, headers).
         /// Note: Some platforms require calling get_url() to resolve the final URL.
+        ///
+        /// The first stream is the selected one. Any others carry the same quality and format
+        /// on other CDNs, in order of preference, for the downloader to fail over to.
         streams: Vec<StreamInfo>,
         /// HTTP headers extracted from MediaInfo.headers (user-agent, referer, etc.).
         /// These should be passed to download engines for platforms that require specific headers.
@@ -322,7 +329,8 @@ impl StreamDetector {
         let merged_extras =
             Self::merge_selection_config_into_extras(platform_extras, selection_config);
 
-        let extractor_factory = ExtractorFactory::new(self.client_for_proxy_config(proxy_config));
+        let client = self.client_for_proxy_config(proxy_config);
+        let extractor_factory = ExtractorFactory::new(client.clone());
 
         // Create platform extractor for this streamer's URL
         let extractor =
@@ -384,6 +392,17 @@ impl StreamDetector {
                     streamer.name, streamer.url, msg
                 )));
             }
+            Err(e) if is_rate_limited(&e) => {
+                debug!(
+                    streamer_name = %streamer.name,
+                    platform = %streamer.platform(),
+                    "platform rejected status check as too frequent"
+                );
+                return Err(crate::Error::RateLimited {
+                    platform: streamer.platform().to_string(),
+                    message: format!("{} ({}): {}", streamer.name, streamer.url, e),
+                });
+            }
             // Transient errors - should be retried
             Err(e) => {
                 return Err(crate::Error::Monitor(format!(
@@ -502,14 +521,14 @@ impl StreamDetector {
             };
 
             let mut resolved_stream = None;
-            for candidate in resolution_slice {
+            for (index, candidate) in resolution_slice.iter().enumerate() {
                 let mut stream = (*candidate).clone();
                 trace!(quality = %stream.quality, url = %stream.url, "resolving stream url");
 
                 match extractor.get_url(&mut stream).await {
                     Ok(_) => {
                         trace!(url = %stream.url, "resolved stream url");
-                        resolved_stream = Some(stream);
+                        resolved_stream = Some((index, stream));
                         break;
                     }
                     Err(e) => {
@@ -524,8 +543,8 @@ impl StreamDetector {
                 }
             }
 
-            let selected_stream = match resolved_stream {
-                Some(stream) => stream,
+            let (selected_index, selected_stream) = match resolved_stream {
+                Some(resolved) => resolved,
                 None => {
                     warn!(
                         "All stream candidates failed resolution for {}. Treating as OFFLINE.",
@@ -535,7 +554,54 @@ impl StreamDetector {
                 }
             };
 
-            let streams = vec![selected_stream];
+            // The same stream on other CDNs, resolved now so the downloader can switch to them
+            // without another poll
+            let mut streams = vec![selected_stream];
+            let selected_candidate = resolution_slice[selected_index];
+            for candidate in resolution_slice[selected_index + 1..]
+                .iter()
+                .filter(|candidate| is_cdn_fallback(selected_candidate, candidate))
+            {
+                if streams.len() > MAX_FALLBACK_STREAMS {
+                    break;
+                }
+                let mut stream = (*candidate).clone();
+                match extractor.get_url(&mut stream).await {
+                    Ok(_) if streams.iter().all(|known| known.url != stream.url) => {
+                        streams.push(stream);
+                    }
+                    Ok(_) => {}
+                    Err(e) => {
+                        debug!(
+                            streamer_name = %streamer.name,
+                            quality = %candidate.quality,
+                            error = %e,
+                            "failed to resolve fallback stream url"
+                        );
+                    }
+                }
+            }
+
+            // The platform's CDN order often ignores where the recorder is; let measured
+            // latency decide among the resolved alternatives
+            if streams.len() > 1 && selector.probes_cdns() {
+                let outcomes = cdn_probe::probe_streams(
+                    &client,
+                    &streams,
+                    media_headers.as_ref(),
+                    selector.probe_timeout(),
+                )
+                .await;
+                debug!(
+                    streamer_name = %streamer.name,
+                    latencies_ms = ?outcomes
+                        .iter()
+                        .map(|o| o.latency().map(|d| d.as_millis()))
+                        .collect::<Vec<_>>(),
+                    "probed CDN candidates"
+                );
+                selector.rank_by_probe(&mut streams, &outcomes);
+            }
 
             // Take ownership of the full candidate list before `media_info`
             // is consumed by the constructor below. The check-history strip
@@ -561,7 +627,7 @@ impl StreamDetector {
                 title: media_info.title,
                 category,
                 avatar: media_info.artist_url.clone(),
-                started_at: None, // TODO: platforms crate doesn't provide start time
+                started_at: media_info.live_start_time,
                 viewer_count,
                 streams,
                 media_headers,
@@ -579,6 +645,58 @@ impl StreamDetector {
         }
     }
 
+    /// Extract the replay of a streamer's current broadcast, when the platform publishes one.
+    ///
+    /// The replay's streams are ordered by the selection config, best first, and the best
+    /// one has its final URL resolved.
+    pub async fn extract_replay(
+        &self,
+        streamer: &StreamerMetadata,
+        cookies: Option<String>,
+        selection_config: Option<&StreamSelectionConfig>,
+        platform_extras: Option<serde_json::Value>,
+        proxy_config: &ProxyConfig,
+    ) -> Result<Option<MediaInfo>> {
+        let merged_extras =
+            Self::merge_selection_config_into_extras(platform_extras, selection_config);
+        let client = self.client_for_proxy_config(proxy_config);
+        let extractor = ExtractorFactory::new(client)
+            .create_extractor(&streamer.url, cookies, merged_extras)
+            .map_err(|e| crate::Error::Monitor(format!("Failed to create extractor: {}", e)))?;
+
+        let Some(mut media_info) = extractor.extract_replay().await.map_err(|e| {
+            crate::Error::Monitor(format!(
+                "Failed to extract replay for {} ({}): {}",
+                streamer.name, streamer.url, e
+            ))
+        })?
+        else {
+            return Ok(None);
+        };
+
+        let selector = selection_config
+            .map(|config| StreamSelector::with_config(config.clone()))
+            .unwrap_or_default();
+        let candidates: Vec<StreamInfo> = selector
+            .sort_candidates(&media_info.streams)
+            .into_iter()
+            .cloned()
+            .collect();
+        if !candidates.is_empty() {
+            media_info.streams = candidates;
+        }
+        if let Some(best) = media_info.streams.first_mut() {
+            extractor.get_url(best).await.map_err(|e| {
+                crate::Error::Monitor(format!(
+                    "Failed to resolve replay URL for {} ({}): {}",
+                    streamer.name, streamer.url, e
+                ))
+            })?;
+        }
+
+        Ok(Some(media_info))
+    }
+
     /// Check status and apply filters.
     ///
     /// # Arguments
@@ -713,6 +831,40 @@ impl From<&ProxyConfig> for ProxyKey {
     }
 }
 
+/// Whether `candidate` is the `selected` stream served by another CDN: same quality, format
+/// and request headers.
+fn is_cdn_fallback(selected: &StreamInfo, candidate: &StreamInfo) -> bool {
+    let request_extras = |stream: &StreamInfo| {
+        let extras = stream.extras.as_ref();
+        (
+            extras.and_then(|extras| extras.get("headers")).cloned(),
+            extras.and_then(|extras| extras.get("host_header")).cloned(),
+        )
+    };
+    candidate.url != selected.url
+        && candidate.stream_format == selected.stream_format
+        && candidate.media_format == selected.media_format
+        && candidate.quality == selected.quality
+        && candidate.codec == selected.codec
+        && candidate.is_audio_only == selected.is_audio_only
+        && request_extras(candidate) == request_extras(selected)
+}
+
+/// Whether the platform rejected a request for being too frequent.
+///
+/// Extractors that check the response status surface HTTP 429 directly; the
+/// others only carry it in their error message.
+fn is_rate_limited(error: &ExtractorError) -> bool {
+    match error {
+        ExtractorError::HttpError(e) => e.status() == Some(reqwest::StatusCode::TOO_MANY_REQUESTS),
+        ExtractorError::Other(message) | ExtractorError::ValidationError(message) => {
+            let message = message.to_lowercase();
+            message.contains("too many requests") || message.contains("rate limit")
+        }
+        _ => false,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -735,6 +887,20 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_is_rate_limited() {
+        assert!(is_rate_limited(&ExtractorError::Other(
+            "HTTP 429 Too Many Requests".to_string()
+        )));
+        assert!(is_rate_limited(&ExtractorError::ValidationError(
+            "API rate limit exceeded".to_string()
+        )));
+        assert!(!is_rate_limited(&ExtractorError::Other(
+            "room 429 is closed".to_string()
+        )));
+        assert!(!is_rate_limited(&ExtractorError::NoStreamsFound));
+    }
+
     #[test]
     fn test_live_status_is_live() {
         let status = LiveStatus::Live {
@@ -754,6 +920,24 @@ mod tests {
         assert!(!status.is_filtered());
     }
 
+    #[test]
+    fn test_cdn_fallback_requires_same_stream_and_headers() {
+        let selected = create_test_stream();
+        let mut other_cdn = create_test_stream();
+        other_cdn.url = "https://cdn2.example.com/stream.flv".to_string();
+        other_cdn.extras = Some(serde_json::json!({ "cdn": "cdn2" }));
+        assert!(is_cdn_fallback(&selected, &other_cdn));
+        assert!(!is_cdn_fallback(&selected, &selected));
+
+        let mut other_quality = other_cdn.clone();
+        other_quality.quality = "720p".to_string();
+        assert!(!is_cdn_fallback(&selected, &other_quality));
+
+        let mut other_host = other_cdn.clone();
+        other_host.extras = Some(serde_json::json!({ "host_header": "cdn2.example.com" }));
+        assert!(!is_cdn_fallback(&selected, &other_host));
+    }
+
     #[test]
     fn test_live_status_is_offline() {
         let status = LiveStatus::Offline;
@@ -35,6 +35,10 @@ pub enum MonitorEvent {
         media_headers: Option<HashMap<String, String>>,
         /// Additional platform-specific metadata extracted from MediaInfo.extras.
         media_extras: Option<HashMap<String, String>>,
+        /// When the broadcast started according to the platform, if it reports it.
+        /// Earlier than `timestamp` when the stream was noticed late.
+        #[serde(default)]
+        broadcast_started_at: Option<DateTime<Utc>>,
         timestamp: DateTime<Utc>,
     },
     /// Streamer went offline.
@@ -243,6 +247,7 @@ mod tests {
             streams: vec![create_test_stream()],
             media_headers: None,
             media_extras: None,
+            broadcast_started_at: None,
             timestamp: Utc::now(),
         };
         assert!(event.description().contains("TestStreamer"));
@@ -261,6 +266,7 @@ mod tests {
             streams: vec![],
             media_headers: None,
             media_extras: None,
+            broadcast_started_at: None,
             timestamp: Utc::now(),
         };
         assert!(live_event.should_notify());
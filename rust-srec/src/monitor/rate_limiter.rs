@@ -2,14 +2,30 @@
 //!
 //! Implements a token bucket algorithm for rate limiting API requests
 //! to prevent abuse and respect platform rate limits.
+//!
+//! When a platform answers with HTTP 429, its bucket slows down: the refill
+//! rate halves on every rate-limit response and doubles back towards the
+//! configured rate once per [`RECOVERY_INTERVAL`] without one.
 
 use std::collections::HashMap;
 use std::collections::hash_map::Entry;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
+use parking_lot::RwLock;
 use tokio::sync::Mutex;
-use tracing::{debug, trace};
+use tracing::{debug, trace, warn};
+
+/// Repeated slow-downs never take the refill rate below this fraction of the
+/// configured rate.
+const MAX_SLOW_DOWN_FACTOR: f64 = 16.0;
+
+/// Rate-limit responses this soon after a slow-down answer requests sent
+/// before it, so they do not slow the bucket down again.
+const SLOW_DOWN_GRACE: Duration = Duration::from_secs(5);
+
+/// How long a slowed-down bucket waits before doubling its refill rate.
+pub const RECOVERY_INTERVAL: Duration = Duration::from_secs(60);
 
 /// Configuration for a rate limiter.
 #[derive(Debug, Clone)]
@@ -61,8 +77,14 @@ pub struct RateLimiter {
     max_tokens: u32,
     /// Tokens added per second.
     refill_rate: f64,
+    /// Configured refill rate, which a slowed-down limiter recovers to.
+    base_rate: f64,
     /// Last refill time.
     last_refill: Instant,
+    /// Start of the current recovery step while slowed down.
+    throttled_at: Option<Instant>,
+    /// Time of the last slow-down.
+    last_slow_down: Option<Instant>,
 }
 
 impl RateLimiter {
@@ -72,8 +94,60 @@ impl RateLimiter {
             tokens: config.initial_tokens as f64,
             max_tokens: config.max_tokens,
             refill_rate: config.refill_rate,
+            base_rate: config.refill_rate,
             last_refill: Instant::now(),
+            throttled_at: None,
+            last_slow_down: None,
+        }
+    }
+
+    /// Apply a new configuration, keeping the tokens already saved up.
+    ///
+    /// A different rate clears any slow-down; the current one leaves the
+    /// limiter untouched.
+    pub fn reconfigure(&mut self, config: RateLimiterConfig) {
+        if config.refill_rate == self.base_rate && config.max_tokens == self.max_tokens {
+            return;
+        }
+        self.refill();
+        self.max_tokens = config.max_tokens;
+        self.refill_rate = config.refill_rate;
+        self.base_rate = config.refill_rate;
+        self.tokens = self.tokens.min(config.max_tokens as f64);
+        self.throttled_at = None;
+        self.last_slow_down = None;
+    }
+
+    /// Halve the refill rate and drop any saved-up burst after the platform
+    /// rejected a request for being too frequent.
+    ///
+    /// Returns `false` if the limiter slowed down within the last
+    /// [`SLOW_DOWN_GRACE`] and the call was ignored.
+    pub fn slow_down(&mut self) -> bool {
+        self.refill();
+        let now = Instant::now();
+        if self
+            .last_slow_down
+            .is_some_and(|at| now.duration_since(at) < SLOW_DOWN_GRACE)
+        {
+            return false;
         }
+
+        self.refill_rate = (self.refill_rate / 2.0).max(self.base_rate / MAX_SLOW_DOWN_FACTOR);
+        self.tokens = 0.0;
+        self.throttled_at = Some(now);
+        self.last_slow_down = Some(now);
+        true
+    }
+
+    /// Current refill rate in tokens per second.
+    pub fn refill_rate(&self) -> f64 {
+        self.refill_rate
+    }
+
+    /// Whether the limiter runs below its configured rate.
+    pub fn is_slowed_down(&self) -> bool {
+        self.refill_rate < self.base_rate
     }
 
     /// Try to acquire a token.
@@ -133,7 +207,8 @@ impl RateLimiter {
         }
     }
 
-    /// Refill tokens based on elapsed time.
+    /// Refill tokens based on elapsed time, and step the refill rate back up
+    /// for every [`RECOVERY_INTERVAL`] passed since the last slow-down.
     fn refill(&mut self) {
         let now = Instant::now();
         let elapsed = now.duration_since(self.last_refill);
@@ -141,6 +216,18 @@ impl RateLimiter {
 
         self.tokens = (self.tokens + new_tokens).min(self.max_tokens as f64);
         self.last_refill = now;
+
+        if let Some(since) = self.throttled_at {
+            let steps = now.duration_since(since).as_secs() / RECOVERY_INTERVAL.as_secs();
+            if steps > 0 {
+                let steps = steps.min(MAX_SLOW_DOWN_FACTOR as u64) as u32;
+                self.refill_rate = (self.refill_rate * 2f64.powi(steps as i32)).min(self.base_rate);
+                self.throttled_at = self
+                    .is_slowed_down()
+                    .then(|| since + RECOVERY_INTERVAL * steps);
+                trace!(rate = self.refill_rate, "rate limiter recovering");
+            }
+        }
     }
 }
 
@@ -158,7 +245,7 @@ pub struct RateLimiterManager {
     /// Default configuration for new limiters.
     default_config: RateLimiterConfig,
     /// Platform-specific configurations.
-    platform_configs: HashMap<String, RateLimiterConfig>,
+    platform_configs: Arc<RwLock<HashMap<String, RateLimiterConfig>>>,
 }
 
 impl RateLimiterManager {
@@ -172,16 +259,52 @@ impl RateLimiterManager {
         Self {
             limiters: Arc::new(Mutex::new(HashMap::new())),
             default_config,
-            platform_configs: HashMap::new(),
+            platform_configs: Arc::new(RwLock::new(HashMap::new())),
         }
     }
 
     /// Set a platform-specific configuration.
-    pub fn set_platform_config(&mut self, platform_id: &str, config: RateLimiterConfig) {
+    ///
+    /// Only applies to a platform whose limiter has not been created yet; use
+    /// [`reconfigure`](Self::reconfigure) for one in use.
+    pub fn set_platform_config(&self, platform_id: &str, config: RateLimiterConfig) {
         self.platform_configs
+            .write()
             .insert(platform_id.to_string(), config);
     }
 
+    /// Replace a platform's configuration, including a limiter in use.
+    pub async fn reconfigure(&self, platform_id: &str, config: RateLimiterConfig) {
+        self.set_platform_config(platform_id, config.clone());
+        if let Some(limiter) = self.limiters.lock().await.get_mut(platform_id) {
+            limiter.reconfigure(config);
+        }
+    }
+
+    /// Slow a platform down after it answered with HTTP 429.
+    ///
+    /// The refill rate halves, down to 1/16 of the configured rate, and
+    /// doubles back once per [`RECOVERY_INTERVAL`] without another 429.
+    pub async fn slow_down(&self, platform_id: &str) {
+        let mut limiters = self.limiters.lock().await;
+        let limiter = self.get_or_create_limiter(&mut limiters, platform_id);
+        if limiter.slow_down() {
+            warn!(
+                platform_id = %platform_id,
+                rate = limiter.refill_rate(),
+                "platform is rate limiting status checks, slowing down"
+            );
+        }
+    }
+
+    /// Current refill rate for a platform, in requests per second.
+    pub async fn refill_rate(&self, platform_id: &str) -> f64 {
+        let mut limiters = self.limiters.lock().await;
+        let limiter = self.get_or_create_limiter(&mut limiters, platform_id);
+        limiter.refill();
+        limiter.refill_rate()
+    }
+
     /// Try to acquire a token for a platform.
     ///
     /// # Cancel Safety
@@ -261,6 +384,7 @@ impl RateLimiterManager {
             Entry::Vacant(entry) => {
                 let config = self
                     .platform_configs
+                    .read()
                     .get(platform_id)
                     .cloned()
                     .unwrap_or_else(|| self.default_config.clone());
@@ -331,6 +455,65 @@ mod tests {
         assert!(RateLimiterConfig::with_rps(f64::INFINITY).is_err());
     }
 
+    #[test]
+    fn test_rate_limiter_slow_down_halves_rate_down_to_floor() {
+        let mut limiter = RateLimiter::new(RateLimiterConfig::with_rps(16.0).unwrap());
+
+        assert!(limiter.slow_down());
+        assert_eq!(limiter.refill_rate(), 8.0);
+        assert!(!limiter.try_acquire(), "saved-up burst is dropped");
+
+        // A second 429 from requests already in flight is ignored.
+        assert!(!limiter.slow_down());
+        assert_eq!(limiter.refill_rate(), 8.0);
+
+        for _ in 0..10 {
+            limiter.last_slow_down = None;
+            limiter.slow_down();
+        }
+        assert_eq!(limiter.refill_rate(), 1.0);
+        assert!(limiter.is_slowed_down());
+    }
+
+    #[test]
+    fn test_rate_limiter_recovers_one_step_per_interval() {
+        let mut limiter = RateLimiter::new(RateLimiterConfig::with_rps(8.0).unwrap());
+        limiter.slow_down();
+        limiter.last_slow_down = None;
+        limiter.slow_down();
+        assert_eq!(limiter.refill_rate(), 2.0);
+
+        let Some(one_interval_ago) = Instant::now().checked_sub(RECOVERY_INTERVAL) else {
+            return;
+        };
+        limiter.throttled_at = Some(one_interval_ago);
+        limiter.refill();
+        assert_eq!(limiter.refill_rate(), 4.0);
+        assert!(limiter.throttled_at.is_some());
+
+        let Some(two_intervals_ago) = Instant::now().checked_sub(RECOVERY_INTERVAL * 2) else {
+            return;
+        };
+        limiter.throttled_at = Some(two_intervals_ago);
+        limiter.refill();
+        assert_eq!(limiter.refill_rate(), 8.0);
+        assert!(!limiter.is_slowed_down());
+        assert!(limiter.throttled_at.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_rate_limiter_manager_reconfigures_limiter_in_use() {
+        let manager = RateLimiterManager::with_config(RateLimiterConfig::with_rps(1.0).unwrap());
+        manager.slow_down("huya").await;
+        assert_eq!(manager.refill_rate("huya").await, 0.5);
+
+        manager
+            .reconfigure("huya", RateLimiterConfig::with_rps(5.0).unwrap())
+            .await;
+        assert_eq!(manager.refill_rate("huya").await, 5.0);
+        assert_eq!(manager.refill_rate("douyu").await, 1.0);
+    }
+
     #[tokio::test]
     async fn test_rate_limiter_manager() {
         let manager = RateLimiterManager::new();
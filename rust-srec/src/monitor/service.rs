@@ -17,6 +17,7 @@ use tracing::{debug, info, trace, warn};
 
 use crate::credentials::CredentialRefreshService;
 use crate::database::ImmediateTransaction;
+use crate::database::models::PlatformConfigDbModel;
 use crate::database::repositories::{
     ConfigRepository, FilterRepository, MonitorOutboxOps, MonitorOutboxTxOps, SessionRepository,
     SessionTxOps, StreamerRepository, StreamerTxOps,
@@ -111,6 +112,16 @@ pub(crate) struct StreamMonitorRuntimeConfig {
     pub task_supervisor: Arc<TaskSupervisor>,
 }
 
+impl StreamMonitorConfig {
+    /// Built-in rate limit for a platform, in requests per second.
+    fn rate_limit_for(&self, platform: &str) -> f64 {
+        self.platform_rate_limits
+            .iter()
+            .find(|(id, _)| id == platform)
+            .map_or(self.default_rate_limit, |(_, rps)| *rps)
+    }
+}
+
 impl Default for StreamMonitorConfig {
     fn default() -> Self {
         Self {
@@ -178,6 +189,7 @@ pub(crate) struct LiveStatusDetails {
     pub streams: Vec<platforms_parser::media::StreamInfo>,
     pub media_headers: Option<std::collections::HashMap<String, String>>,
     pub media_extras: Option<std::collections::HashMap<String, String>>,
+    pub broadcast_started_at: Option<chrono::DateTime<chrono::Utc>>,
 }
 
 impl<
@@ -268,7 +280,7 @@ impl<
                 );
                 RateLimiterConfig::default()
             });
-        let mut rate_limiter = RateLimiterManager::with_config(default_rate_config);
+        let rate_limiter = RateLimiterManager::with_config(default_rate_config);
 
         for (platform, rps) in &config.platform_rate_limits {
             match RateLimiterConfig::with_rps(*rps) {
@@ -366,6 +378,27 @@ impl<
         self.cancellation.cancel();
     }
 
+    /// Apply a platform's configured status-check rate, in requests per
+    /// second. `None` restores the built-in rate for the platform.
+    pub async fn set_platform_rate_limit(&self, platform: &str, rps: Option<f64>) {
+        let rps = rps.unwrap_or_else(|| self.config.rate_limit_for(platform));
+        match RateLimiterConfig::with_rps(rps) {
+            Ok(config) => self.rate_limiter.reconfigure(platform, config).await,
+            Err(e) => warn!(
+                "Invalid rate limit for platform {} ({}): {}. Keeping the current limit.",
+                platform, rps, e
+            ),
+        }
+    }
+
+    /// Apply the status-check rates configured on `platforms`.
+    pub async fn apply_platform_rate_limits(&self, platforms: &[PlatformConfigDbModel]) {
+        for platform in platforms {
+            self.set_platform_rate_limit(&platform.platform_name, platform.rate_limit)
+                .await;
+        }
+    }
+
     /// Set the credential refresh service for automatic cookie refresh.
     pub fn set_credential_service(&mut self, service: Arc<CredentialRefreshService<CR>>) {
         self.credential_service = Some(service);
@@ -630,12 +663,56 @@ impl<
             self.in_flight.remove(&streamer.id);
         }
 
+        if let Err(Error::RateLimited { .. }) = &result {
+            self.rate_limiter.slow_down(platform_id).await;
+        }
+
         // Now check result - cleanup is already scheduled
         let status = result?.clone();
 
         Ok(status)
     }
 
+    /// Extract the replay of a streamer's current broadcast, when the platform publishes one.
+    ///
+    /// Uses the streamer's merged config for cookies, stream selection, platform extras and
+    /// proxy, and shares the platform rate limiter with status checks.
+    pub async fn extract_replay(
+        &self,
+        streamer: &StreamerMetadata,
+    ) -> Result<Option<platforms_parser::media::media_info::MediaInfo>> {
+        let hard_timeout = if self.config.request_timeout > Duration::ZERO {
+            self.config.request_timeout
+        } else {
+            STREAM_CHECK_HARD_TIMEOUT
+        };
+
+        self.rate_limiter.acquire(streamer.platform()).await;
+        let context = self
+            .config_service
+            .get_context_for_streamer(&streamer.id)
+            .await?;
+        let config = &context.config;
+
+        tokio::time::timeout(
+            hard_timeout,
+            self.detector.extract_replay(
+                streamer,
+                config.cookies.clone(),
+                Some(&config.stream_selection),
+                config.platform_extras.clone(),
+                &config.proxy_config,
+            ),
+        )
+        .await
+        .map_err(|_| {
+            Error::Monitor(format!(
+                "Replay extraction timed out after {:?} (streamer_id={})",
+                hard_timeout, streamer.id
+            ))
+        })?
+    }
+
     /// Check the status of multiple streamers on the same platform.
     pub async fn batch_check(
         &self,
@@ -702,7 +779,7 @@ impl<
                 title,
                 category,
                 avatar,
-                started_at: _,
+                started_at,
                 streams,
                 media_headers,
                 media_extras,
@@ -795,6 +872,7 @@ impl<
                         streams,
                         media_headers,
                         media_extras,
+                        broadcast_started_at: started_at,
                     },
                 )
                 .await?;
@@ -873,6 +951,7 @@ impl<
             streams,
             media_headers,
             media_extras,
+            broadcast_started_at,
         } = details;
         info!(
             streamer_id = %streamer.id,
@@ -902,6 +981,7 @@ impl<
                 streams: &streams,
                 media_headers: media_headers.as_ref(),
                 media_extras: media_extras.as_ref(),
+                broadcast_started_at,
                 now,
             })
             .await?;
@@ -1972,6 +2052,7 @@ mod tests {
                 streams: &streams,
                 media_headers: None,
                 media_extras: None,
+                broadcast_started_at: None,
                 now: chrono::Utc::now(),
             })
             .await
@@ -2066,6 +2147,7 @@ mod tests {
                 streams: &streams,
                 media_headers: None,
                 media_extras: None,
+                broadcast_started_at: None,
                 now: chrono::Utc::now(),
             })
             .await
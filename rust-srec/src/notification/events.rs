@@ -84,12 +84,32 @@ const NOTIFICATION_EVENT_TYPES: &[NotificationEventTypeInfo] = &[
         priority: NotificationPriority::High,
         aliases: &["download_rejected", "download.rejected", "DownloadRejected"],
     },
+    NotificationEventTypeInfo {
+        event_type: "download_preempted",
+        label: "Download Preempted",
+        priority: NotificationPriority::Normal,
+        aliases: &[
+            "download_preempted",
+            "download.preempted",
+            "DownloadPreempted",
+        ],
+    },
     NotificationEventTypeInfo {
         event_type: "config_updated",
         label: "Config Updated",
         priority: NotificationPriority::Low,
         aliases: &["config_updated", "config.updated", "ConfigUpdated"],
     },
+    NotificationEventTypeInfo {
+        event_type: "danmu_collection_degraded",
+        label: "Danmu Collection Degraded",
+        priority: NotificationPriority::High,
+        aliases: &[
+            "danmu_collection_degraded",
+            "danmu.degraded",
+            "DanmuCollectionDegraded",
+        ],
+    },
     NotificationEventTypeInfo {
         event_type: "pipeline_started",
         label: "Pipeline Started",
@@ -463,6 +483,15 @@ pub enum NotificationEvent {
         reason: String,
         timestamp: DateTime<Utc>,
     },
+    /// Download was finalized to free its slot for a higher-priority streamer.
+    DownloadPreempted {
+        streamer_id: String,
+        streamer_name: String,
+        session_id: String,
+        /// Name of the streamer the slot was freed for.
+        preempted_by: String,
+        timestamp: DateTime<Utc>,
+    },
     /// Download configuration was updated dynamically.
     ConfigUpdated {
         streamer_id: String,
@@ -470,6 +499,16 @@ pub enum NotificationEvent {
         update_type: String,
         timestamp: DateTime<Utc>,
     },
+    /// Danmu (chat) collection for a live session lost its connection and is
+    /// reconnecting repeatedly, or gave up. Video recording is unaffected.
+    DanmuCollectionDegraded {
+        streamer_id: String,
+        streamer_name: String,
+        session_id: String,
+        platform: String,
+        reason: String,
+        timestamp: DateTime<Utc>,
+    },
 
     // ========== Pipeline Events ==========
     /// Pipeline job started.
@@ -599,7 +638,9 @@ impl NotificationEvent {
             Self::SegmentCompleted { .. } => NotificationPriority::Low,
             Self::DownloadCancelled { .. } => NotificationPriority::Normal,
             Self::DownloadRejected { .. } => NotificationPriority::High,
+            Self::DownloadPreempted { .. } => NotificationPriority::Normal,
             Self::ConfigUpdated { .. } => NotificationPriority::Low,
+            Self::DanmuCollectionDegraded { .. } => NotificationPriority::High,
 
             // Pipeline events
             Self::PipelineStarted { .. } => NotificationPriority::Low,
@@ -634,7 +675,9 @@ impl NotificationEvent {
             Self::SegmentCompleted { .. } => "segment_completed",
             Self::DownloadCancelled { .. } => "download_cancelled",
             Self::DownloadRejected { .. } => "download_rejected",
+            Self::DownloadPreempted { .. } => "download_preempted",
             Self::ConfigUpdated { .. } => "config_updated",
+            Self::DanmuCollectionDegraded { .. } => "danmu_collection_degraded",
             Self::PipelineStarted { .. } => "pipeline_started",
             Self::PipelineCompleted { .. } => "pipeline_completed",
             Self::PipelineFailed { .. } => "pipeline_failed",
@@ -709,10 +752,18 @@ impl NotificationEvent {
                 "notification.download_rejected.title",
                 streamer_name = streamer_name.as_str(),
             ),
+            Self::DownloadPreempted { streamer_name, .. } => crate::t_str!(
+                "notification.download_preempted.title",
+                streamer_name = streamer_name.as_str(),
+            ),
             Self::ConfigUpdated { streamer_name, .. } => crate::t_str!(
                 "notification.config_updated.title",
                 streamer_name = streamer_name.as_str(),
             ),
+            Self::DanmuCollectionDegraded { streamer_name, .. } => crate::t_str!(
+                "notification.danmu_collection_degraded.title",
+                streamer_name = streamer_name.as_str(),
+            ),
             Self::PipelineStarted { job_type, .. } => crate::t_str!(
                 "notification.pipeline_started.title",
                 job_type = job_type.as_str(),
@@ -840,10 +891,21 @@ impl NotificationEvent {
                 "notification.download_rejected.description",
                 reason = reason.as_str(),
             ),
+            Self::DownloadPreempted { preempted_by, .. } => crate::t_str!(
+                "notification.download_preempted.description",
+                preempted_by = preempted_by.as_str(),
+            ),
             Self::ConfigUpdated { update_type, .. } => crate::t_str!(
                 "notification.config_updated.description",
                 update_type = update_type.as_str(),
             ),
+            Self::DanmuCollectionDegraded {
+                platform, reason, ..
+            } => crate::t_str!(
+                "notification.danmu_collection_degraded.description",
+                platform = platform.as_str(),
+                reason = reason.as_str(),
+            ),
             Self::PipelineStarted { job_id, .. } => crate::t_str!(
                 "notification.pipeline_started.description",
                 job_id = job_id.as_str(),
@@ -982,7 +1044,9 @@ impl NotificationEvent {
             | Self::SegmentCompleted { timestamp, .. }
             | Self::DownloadCancelled { timestamp, .. }
             | Self::DownloadRejected { timestamp, .. }
+            | Self::DownloadPreempted { timestamp, .. }
             | Self::ConfigUpdated { timestamp, .. }
+            | Self::DanmuCollectionDegraded { timestamp, .. }
             | Self::PipelineStarted { timestamp, .. }
             | Self::PipelineCompleted { timestamp, .. }
             | Self::PipelineFailed { timestamp, .. }
@@ -1016,7 +1080,9 @@ impl NotificationEvent {
             | Self::SegmentCompleted { streamer_id, .. }
             | Self::DownloadCancelled { streamer_id, .. }
             | Self::DownloadRejected { streamer_id, .. }
+            | Self::DownloadPreempted { streamer_id, .. }
             | Self::ConfigUpdated { streamer_id, .. }
+            | Self::DanmuCollectionDegraded { streamer_id, .. }
             | Self::PipelineStarted { streamer_id, .. }
             | Self::FatalError { streamer_id, .. } => Some(streamer_id),
             _ => None,
@@ -1302,6 +1368,26 @@ mod tests {
         assert!(event.description().contains("Cookies"));
     }
 
+    #[test]
+    fn test_danmu_collection_degraded_event() {
+        let event = NotificationEvent::DanmuCollectionDegraded {
+            streamer_id: "123".to_string(),
+            streamer_name: "TestStreamer".to_string(),
+            session_id: "sess-1".to_string(),
+            platform: "huya".to_string(),
+            reason: "reconnect attempt 3".to_string(),
+            timestamp: Utc::now(),
+        };
+
+        assert_eq!(event.priority(), NotificationPriority::High);
+        assert_eq!(event.event_type(), "danmu_collection_degraded");
+        assert_eq!(event.streamer_id(), Some("123"));
+        assert_eq!(
+            NotificationEvent::event_type_info("danmu.degraded").map(|i| i.event_type),
+            Some("danmu_collection_degraded")
+        );
+    }
+
     #[test]
     fn test_download_cancellation_rejection() {
         let cancel_event = NotificationEvent::DownloadCancelled {
@@ -1323,6 +1409,17 @@ mod tests {
         assert_eq!(reject_event.priority(), NotificationPriority::High);
         assert_eq!(reject_event.event_type(), "download_rejected");
         assert!(reject_event.description().contains("Circuit breaker open"));
+
+        let preempt_event = NotificationEvent::DownloadPreempted {
+            streamer_id: "123".to_string(),
+            streamer_name: "TestStreamer".to_string(),
+            session_id: "session_1".to_string(),
+            preempted_by: "VipStreamer".to_string(),
+            timestamp: Utc::now(),
+        };
+        assert_eq!(preempt_event.priority(), NotificationPriority::Normal);
+        assert_eq!(preempt_event.event_type(), "download_preempted");
+        assert!(preempt_event.description().contains("VipStreamer"));
     }
 
     /// `rust_i18n::set_locale` mutates a process global, so locale-sensitive
@@ -1532,12 +1629,27 @@ mod tests {
                 reason: "circuit breaker open".into(),
                 timestamp: now,
             },
+            NotificationEvent::DownloadPreempted {
+                streamer_id: "s1".into(),
+                streamer_name: "TestStreamer".into(),
+                session_id: "sess-1".into(),
+                preempted_by: "VipStreamer".into(),
+                timestamp: now,
+            },
             NotificationEvent::ConfigUpdated {
                 streamer_id: "s1".into(),
                 streamer_name: "TestStreamer".into(),
                 update_type: "Cookies".into(),
                 timestamp: now,
             },
+            NotificationEvent::DanmuCollectionDegraded {
+                streamer_id: "s1".into(),
+                streamer_name: "TestStreamer".into(),
+                session_id: "sess-1".into(),
+                platform: "bilibili".into(),
+                reason: "gave up after 10 reconnect attempts".into(),
+                timestamp: now,
+            },
             NotificationEvent::PipelineStarted {
                 job_id: "job-1".into(),
                 job_type: "remux".into(),
@@ -1680,7 +1792,7 @@ mod tests {
         // self-doc; bump it alongside the match arms in title/description.
         assert_eq!(
             events.len(),
-            26,
+            28,
             "sample_events is out of sync with NotificationEvent; add a sample for the new variant so its localization is covered"
         );
 
@@ -39,7 +39,9 @@ use crate::database::models::{
     WebhookChannelSettings,
 };
 use crate::database::repositories::NotificationRepository;
-use crate::downloader::{DownloadManagerEvent, DownloadProgressEvent, DownloadTerminalEvent};
+use crate::downloader::{
+    DownloadManagerEvent, DownloadProgressEvent, DownloadStopCause, DownloadTerminalEvent,
+};
 use crate::monitor::MonitorEvent;
 use crate::pipeline::PipelineEvent;
 use crate::utils::task_supervisor::TaskSupervisor;
@@ -1324,6 +1326,19 @@ impl NotificationService {
                                         duration_secs,
                                         timestamp: completed_at,
                                     }),
+                                    DownloadManagerEvent::Terminal(DownloadTerminalEvent::Cancelled {
+                                        streamer_id,
+                                        streamer_name,
+                                        session_id,
+                                        cause: DownloadStopCause::Preempted { by_streamer_name, .. },
+                                        ..
+                                    }) => Some(NotificationEvent::DownloadPreempted {
+                                        streamer_id,
+                                        streamer_name,
+                                        session_id,
+                                        preempted_by: by_streamer_name,
+                                        timestamp: Utc::now(),
+                                    }),
                                     DownloadManagerEvent::Terminal(DownloadTerminalEvent::Cancelled {
                                         streamer_id,
                                         streamer_name,
@@ -1794,6 +1809,7 @@ mod tests {
                 size_bytes: 1024,
                 split_reason_code: None,
                 split_reason_details_json: None,
+                analysis_report_json: None,
             }) {
                 DownloadManagerEvent::Progress(DownloadProgressEvent::SegmentCompleted {
                     streamer_id,
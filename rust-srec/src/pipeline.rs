@@ -36,7 +36,7 @@ pub use processors::{
     AssBurnInConfig, AssBurnInProcessor, AssMatchStrategy, CopyMoveConfig, CopyMoveOperation,
     CopyMoveProcessor, DanmakuFactoryConfig, DanmakuFactoryProcessor, ExecuteCommandProcessor,
     Processor, ProcessorContext, ProcessorInput, ProcessorOutput, ProcessorType, RcloneProcessor,
-    RemuxProcessor, ThumbnailProcessor,
+    RemuxProcessor, SealedSource, ThumbnailProcessor, decrypt_artifact, load_encryption_key,
 };
 pub use progress::{JobProgressSnapshot, ProgressKind, ProgressReporter};
 pub use throttle::{DownloadLimitAdjuster, ThrottleConfig, ThrottleController, ThrottleEvent};
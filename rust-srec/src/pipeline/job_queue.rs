@@ -18,10 +18,11 @@ use crate::database::models::JobExecutionProgressDbModel;
 use crate::database::models::job::LogEntry as DbLogEntry;
 use crate::database::models::{
     JobDbModel, JobExecutionLogDbModel, JobFilters, JobStatus, MediaFileType, MediaOutputDbModel,
-    Pagination, TitleEntry,
+    OutputChecksumDbModel, Pagination, TitleEntry,
 };
 use crate::database::repositories::{JobRepository, SessionRepository, StreamerRepository};
 use crate::pipeline::processors::utils as processor_utils;
+use crate::pipeline::processors::{ChecksumMetadata, ConcatMetadata};
 use crate::utils::json::{self, JsonContext};
 use crate::{Error, Result};
 
@@ -33,6 +34,17 @@ fn is_thumbnail_job_type(job_type: &str) -> bool {
     jt == "thumbnail" || jt.starts_with("thumbnail_")
 }
 
+fn is_concat_job_type(job_type: &str) -> bool {
+    // Same convention as thumbnails: "concat" or "concat_<preset>".
+    let jt = job_type.to_ascii_lowercase();
+    jt == "concat" || jt.starts_with("concat_")
+}
+
+fn is_checksum_job_type(job_type: &str) -> bool {
+    let jt = job_type.to_ascii_lowercase();
+    jt == "checksum" || jt.starts_with("checksum_")
+}
+
 const EXECUTION_INFO_MAX_LOGS: usize = 200;
 const PROGRESS_FLUSH_INTERVAL_MS: u64 = 250;
 
@@ -671,6 +683,90 @@ impl JobQueue {
         }
     }
 
+    /// Record a merged concat output on its session.
+    ///
+    /// Adds the merged file to media_outputs and drops the rows of segments
+    /// the processor deleted, so the session lists the artifact that exists.
+    async fn persist_concat_output(&self, session_id: &str, metadata: &str) {
+        let Some(repo) = self.session_repo.get() else {
+            return;
+        };
+        let Ok(metadata) = serde_json::from_str::<ConcatMetadata>(metadata) else {
+            return;
+        };
+        let file_type = match processor_utils::get_extension(&metadata.merged) {
+            Some(ext) if processor_utils::is_audio(&ext) => MediaFileType::Audio,
+            Some(ext) if processor_utils::is_video(&ext) => MediaFileType::Video,
+            _ => return,
+        };
+
+        let existing = repo
+            .get_media_outputs_for_session(session_id)
+            .await
+            .unwrap_or_default();
+
+        for row in existing
+            .iter()
+            .filter(|row| metadata.removed_segments.contains(&row.file_path))
+        {
+            if let Err(e) = repo.delete_media_output(&row.id).await {
+                warn!(
+                    "Failed to drop merged segment output {} for session {}: {}",
+                    row.file_path, session_id, e
+                );
+            }
+        }
+
+        // Retries must not add the merged file twice.
+        if existing.iter().any(|row| row.file_path == metadata.merged) {
+            return;
+        }
+
+        let size_bytes = tokio::fs::metadata(&metadata.merged)
+            .await
+            .map(|m| m.len() as i64)
+            .unwrap_or(0);
+        let output = MediaOutputDbModel::new(session_id, &metadata.merged, file_type, size_bytes);
+        if let Err(e) = repo.create_media_output(&output).await {
+            warn!(
+                "Failed to persist merged output for session {}: {}",
+                session_id, e
+            );
+        } else {
+            info!(
+                "Persisted merged output for session {}: {}",
+                session_id, metadata.merged
+            );
+        }
+    }
+
+    /// Store the digests reported by a checksum job on its session.
+    async fn persist_checksums(&self, session_id: &str, metadata: &str) {
+        let Some(repo) = self.session_repo.get() else {
+            return;
+        };
+        let Ok(metadata) = serde_json::from_str::<ChecksumMetadata>(metadata) else {
+            return;
+        };
+        for file in &metadata.files {
+            for (algorithm, digest) in &file.digests {
+                let checksum = OutputChecksumDbModel::new(
+                    session_id,
+                    &file.path,
+                    algorithm,
+                    digest,
+                    i64::try_from(file.size_bytes).unwrap_or(i64::MAX),
+                );
+                if let Err(e) = repo.upsert_output_checksum(&checksum).await {
+                    warn!(
+                        "Failed to store {} checksum of {} for session {}: {}",
+                        algorithm, file.path, session_id, e
+                    );
+                }
+            }
+        }
+    }
+
     pub async fn append_log_entry(&self, job_id: &str, logs: &[JobLogEntry]) -> Result<()> {
         self.persist_logs_to_db(job_id, logs).await?;
         Ok(())
@@ -1020,6 +1116,7 @@ impl JobQueue {
 
         // Capture outputs for persistence before they are moved into cache/DB models.
         let outputs_for_persist = result.outputs.clone();
+        let metadata_for_persist = result.metadata.clone();
         let mut completed_job_type: Option<String> = None;
         let mut completed_session_id: Option<String> = None;
 
@@ -1128,6 +1225,24 @@ impl JobQueue {
             }
         }
 
+        if completed_job_type
+            .as_deref()
+            .is_some_and(is_concat_job_type)
+            && let Some(session_id) = completed_session_id.as_deref()
+            && let Some(metadata) = metadata_for_persist.as_deref()
+        {
+            self.persist_concat_output(session_id, metadata).await;
+        }
+
+        if completed_job_type
+            .as_deref()
+            .is_some_and(is_checksum_job_type)
+            && let Some(session_id) = completed_session_id.as_deref()
+            && let Some(metadata) = metadata_for_persist.as_deref()
+        {
+            self.persist_checksums(session_id, metadata).await;
+        }
+
         // Cleanup in-memory tracking for this job.
         let _ = self.cancellation_tokens.remove(job_id);
         let _ = self.persisted_log_cursor.remove(job_id);
@@ -2514,6 +2629,183 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_concat_completion_replaces_segment_outputs_with_merged_file() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let db_url = format!(
+            "sqlite:{}?mode=rwc",
+            dir.path().join("job_queue_test.db").to_string_lossy()
+        );
+        let pool = crate::database::init_pool(&db_url).await.unwrap();
+        crate::database::run_migrations(&pool).await.unwrap();
+
+        sqlx::query("INSERT INTO platform_config (id, platform_name) VALUES ('p1', 'test')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query(
+            "INSERT INTO streamers (id, name, url, platform_config_id, state) \
+             VALUES ('streamer-1', 'Streamer', 'https://example.com/s1', 'p1', 'NOT_LIVE')",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            "INSERT INTO live_sessions (id, streamer_id, start_time) \
+             VALUES ('session-1', 'streamer-1', 0)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let session_repo = Arc::new(
+            crate::database::repositories::session::SqlxSessionRepository::new(
+                pool.clone(),
+                pool.clone(),
+            ),
+        );
+        let segments = vec!["/rec/a.ts".to_string(), "/rec/b.ts".to_string()];
+        for segment in &segments {
+            session_repo
+                .create_media_output(&MediaOutputDbModel::new(
+                    "session-1",
+                    segment,
+                    MediaFileType::Video,
+                    10,
+                ))
+                .await
+                .unwrap();
+        }
+
+        let job_repo: Arc<dyn JobRepository> = Arc::new(
+            crate::database::repositories::job::SqlxJobRepository::new(pool.clone(), pool.clone()),
+        );
+        let queue = JobQueue::with_repository(JobQueueConfig::default(), job_repo);
+        queue.set_session_repo(session_repo.clone());
+
+        let job = Job::new(
+            "concat",
+            segments.clone(),
+            vec![],
+            "streamer-1",
+            "session-1",
+        );
+        queue.enqueue(job).await.unwrap();
+        let job = queue.dequeue(None).await.unwrap().unwrap();
+
+        let metadata = ConcatMetadata {
+            merged: "/rec/a_merged.ts".to_string(),
+            segments: segments.clone(),
+            removed_segments: segments,
+        };
+        queue
+            .complete(
+                &job.id,
+                JobResult {
+                    outputs: vec![metadata.merged.clone()],
+                    duration_secs: 1.0,
+                    metadata: Some(serde_json::to_string(&metadata).unwrap()),
+                    logs: vec![],
+                },
+            )
+            .await
+            .unwrap();
+
+        let outputs = session_repo
+            .get_media_outputs_for_session("session-1")
+            .await
+            .unwrap();
+        assert_eq!(outputs.len(), 1);
+        assert_eq!(outputs[0].file_path, "/rec/a_merged.ts");
+        assert_eq!(outputs[0].file_type, MediaFileType::Video.as_str());
+    }
+
+    #[tokio::test]
+    async fn test_checksum_completion_stores_session_digests() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let db_url = format!(
+            "sqlite:{}?mode=rwc",
+            dir.path().join("job_queue_test.db").to_string_lossy()
+        );
+        let pool = crate::database::init_pool(&db_url).await.unwrap();
+        crate::database::run_migrations(&pool).await.unwrap();
+
+        sqlx::query("INSERT INTO platform_config (id, platform_name) VALUES ('p1', 'test')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query(
+            "INSERT INTO streamers (id, name, url, platform_config_id, state) \
+             VALUES ('streamer-1', 'Streamer', 'https://example.com/s1', 'p1', 'NOT_LIVE')",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            "INSERT INTO live_sessions (id, streamer_id, start_time) \
+             VALUES ('session-1', 'streamer-1', 0)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let session_repo = Arc::new(
+            crate::database::repositories::session::SqlxSessionRepository::new(
+                pool.clone(),
+                pool.clone(),
+            ),
+        );
+        let job_repo: Arc<dyn JobRepository> = Arc::new(
+            crate::database::repositories::job::SqlxJobRepository::new(pool.clone(), pool.clone()),
+        );
+        let queue = JobQueue::with_repository(JobQueueConfig::default(), job_repo);
+        queue.set_session_repo(session_repo.clone());
+
+        let job = Job::new(
+            "checksum",
+            vec!["/rec/a.flv".to_string()],
+            vec![],
+            "streamer-1",
+            "session-1",
+        );
+        queue.enqueue(job).await.unwrap();
+        let job = queue.dequeue(None).await.unwrap().unwrap();
+
+        // Shape written by the checksum processor.
+        let metadata = serde_json::json!({
+            "files": [{
+                "path": "/rec/a.flv",
+                "size_bytes": 42,
+                "digests": { "blake3": "b3digest", "sha256": "shadigest" },
+            }],
+        });
+        queue
+            .complete(
+                &job.id,
+                JobResult {
+                    outputs: vec!["/rec/a.flv".to_string()],
+                    duration_secs: 1.0,
+                    metadata: Some(metadata.to_string()),
+                    logs: vec![],
+                },
+            )
+            .await
+            .unwrap();
+
+        let mut checksums = session_repo
+            .list_output_checksums_for_session("session-1")
+            .await
+            .unwrap();
+        checksums.sort_by(|a, b| a.algorithm.cmp(&b.algorithm));
+        assert_eq!(checksums.len(), 2);
+        assert_eq!(checksums[0].algorithm, "blake3");
+        assert_eq!(checksums[0].digest, "b3digest");
+        assert_eq!(checksums[1].algorithm, "sha256");
+        assert_eq!(checksums[1].digest, "shadigest");
+        assert!(checksums.iter().all(|c| c.file_path == "/rec/a.flv"));
+        assert!(checksums.iter().all(|c| c.size_bytes == 42));
+    }
+
     #[test]
     fn test_job_db_state_roundtrip_preserves_session_start() {
         let session_start = chrono::DateTime::parse_from_rfc3339("2024-01-01T23:30:00Z")
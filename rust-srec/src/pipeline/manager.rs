@@ -1,6 +1,7 @@
 //! Pipeline Manager implementation.
 
 use dashmap::DashMap;
+use process_utils::ProcessPriority;
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
@@ -18,9 +19,12 @@ use super::dag_scheduler::{
 };
 use super::job_queue::{Job, JobLogEntry, JobQueue, JobQueueConfig, QueueDepthStatus};
 use super::processors::{
-    AssBurnInProcessor, AudioExtractProcessor, CompressionProcessor, CopyMoveProcessor,
-    DanmakuFactoryProcessor, DeleteProcessor, ExecuteCommandProcessor, MetadataProcessor,
-    Processor, RcloneProcessor, RemuxProcessor, TdlUploadProcessor, ThumbnailProcessor,
+    AssBurnInProcessor, AudioExtractProcessor, BilibiliUploadProcessor, ChecksumProcessor,
+    CompressionProcessor, ConcatProcessor, CopyMoveProcessor, DanmakuFactoryProcessor,
+    DanmuChaptersProcessor, DanmuSubtitleProcessor, DeleteProcessor, EncryptProcessor,
+    ExecuteCommandProcessor, FfmpegProcessor, HighlightsProcessor, MetadataProcessor, NfoProcessor,
+    Processor, RcloneProcessor, RemuxProcessor, S3UploadProcessor, TdlUploadProcessor,
+    ThumbnailProcessor, TorrentProcessor, WebhookProcessor, YouTubeUploadProcessor,
 };
 use super::progress::JobProgressSnapshot;
 use super::throttle::{DownloadLimitAdjuster, ThrottleConfig, ThrottleController, ThrottleEvent};
@@ -39,7 +43,8 @@ use crate::database::models::{
 use crate::database::repositories::config::{ConfigRepository, SqlxConfigRepository};
 use crate::database::repositories::streamer::{SqlxStreamerRepository, StreamerRepository};
 use crate::database::repositories::{
-    DagRepository, JobPresetRepository, JobRepository, PipelinePresetRepository, SessionRepository,
+    DagRepository, JobPresetRepository, JobRepository, OAuthTokenRepository,
+    PipelinePresetRepository, SessionRepository,
 };
 use crate::downloader::{DownloadManagerEvent, DownloadProgressEvent};
 use crate::utils::filename::sanitize_filename;
@@ -144,8 +149,10 @@ impl Default for PipelineManagerConfig {
     fn default() -> Self {
         Self {
             job_queue: JobQueueConfig::default(),
+            // Remux/transcode jobs yield the CPU to live downloads
             cpu_pool: WorkerPoolConfig {
                 max_workers: 2,
+                process_priority: ProcessPriority::BelowNormal,
                 ..Default::default()
             },
             io_pool: WorkerPoolConfig {
@@ -254,6 +261,8 @@ pub(crate) struct PipelineRuntimeDependencies<
     pub(crate) pipeline_preset_repository: Arc<dyn PipelinePresetRepository>,
     pub(crate) config_service: Arc<ConfigService<CR, SR>>,
     pub(crate) dag_repository: Arc<dyn DagRepository>,
+    pub(crate) oauth_token_repository: Arc<dyn OAuthTokenRepository>,
+    pub(crate) config_repository: Arc<dyn ConfigRepository>,
 }
 
 impl<CR, SR> PipelineManager<CR, SR>
@@ -325,11 +334,22 @@ where
             Arc::new(AssBurnInProcessor::new()),
             Arc::new(RcloneProcessor::new()),
             Arc::new(TdlUploadProcessor::new()),
+            Arc::new(S3UploadProcessor::new()),
+            Arc::new(WebhookProcessor::new()),
             Arc::new(ExecuteCommandProcessor::new().with_timeout(execute_timeout_secs)),
             Arc::new(ThumbnailProcessor::new()),
             Arc::new(CopyMoveProcessor::new()),
             Arc::new(AudioExtractProcessor::new()),
             Arc::new(CompressionProcessor::new()),
+            Arc::new(FfmpegProcessor::new()),
+            Arc::new(ConcatProcessor::new()),
+            Arc::new(DanmuChaptersProcessor::new()),
+            Arc::new(DanmuSubtitleProcessor::new()),
+            Arc::new(ChecksumProcessor::new()),
+            Arc::new(EncryptProcessor::new()),
+            Arc::new(TorrentProcessor::new()),
+            Arc::new(NfoProcessor::new()),
+            Arc::new(HighlightsProcessor::new()),
             Arc::new(MetadataProcessor::new()),
             Arc::new(DeleteProcessor::new()),
         ];
@@ -388,11 +408,22 @@ where
             Arc::new(AssBurnInProcessor::new()),
             Arc::new(RcloneProcessor::new()),
             Arc::new(TdlUploadProcessor::new()),
+            Arc::new(S3UploadProcessor::new()),
+            Arc::new(WebhookProcessor::new()),
             Arc::new(ExecuteCommandProcessor::new().with_timeout(execute_timeout_secs)),
             Arc::new(ThumbnailProcessor::new()),
             Arc::new(CopyMoveProcessor::new()),
             Arc::new(AudioExtractProcessor::new()),
             Arc::new(CompressionProcessor::new()),
+            Arc::new(FfmpegProcessor::new()),
+            Arc::new(ConcatProcessor::new()),
+            Arc::new(DanmuChaptersProcessor::new()),
+            Arc::new(DanmuSubtitleProcessor::new()),
+            Arc::new(ChecksumProcessor::new()),
+            Arc::new(EncryptProcessor::new()),
+            Arc::new(TorrentProcessor::new()),
+            Arc::new(NfoProcessor::new()),
+            Arc::new(HighlightsProcessor::new()),
             Arc::new(MetadataProcessor::new()),
             Arc::new(DeleteProcessor::new()),
         ];
@@ -443,6 +474,8 @@ where
             pipeline_preset_repository,
             config_service,
             dag_repository,
+            oauth_token_repository,
+            config_repository,
         } = dependencies;
 
         Self::with_repository(config, job_repository)
@@ -452,6 +485,7 @@ where
             .with_pipeline_preset_repository(pipeline_preset_repository)
             .with_config_service(config_service)
             .with_dag_repository(dag_repository)
+            .with_upload_accounts(oauth_token_repository, config_repository)
     }
 
     /// Set the session repository for persistence.
@@ -518,6 +552,22 @@ where
         self
     }
 
+    /// Register the upload processors that authenticate with stored
+    /// accounts: OAuth accounts for YouTube, and Bilibili cookies from the
+    /// platform and template configs.
+    pub fn with_upload_accounts(
+        mut self,
+        oauth_token_repository: Arc<dyn OAuthTokenRepository>,
+        config_repository: Arc<dyn ConfigRepository>,
+    ) -> Self {
+        self.processors.push(Arc::new(YouTubeUploadProcessor::new(
+            oauth_token_repository,
+        )));
+        self.processors
+            .push(Arc::new(BilibiliUploadProcessor::new(config_repository)));
+        self
+    }
+
     /// Get a reference to the DAG scheduler, if available.
     pub fn dag_scheduler(&self) -> Option<&Arc<DagScheduler>> {
         self.dag_scheduler.as_ref()
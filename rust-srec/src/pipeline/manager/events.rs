@@ -18,6 +18,7 @@ where
                 size_bytes,
                 split_reason_code,
                 split_reason_details_json,
+                analysis_report_json,
                 ..
             }) => {
                 debug!(
@@ -41,7 +42,8 @@ where
                         split_reason_code.clone(),
                         split_reason_details_json.clone(),
                     ),
-                );
+                )
+                .with_analysis_report(analysis_report_json);
                 self.persist_session_segment(&session_segment).await;
 
                 let merged_config = if let Some(config_service) = &self.config_service {
@@ -145,7 +147,8 @@ where
                 | DownloadProgressEvent::Progress { .. }
                 | DownloadProgressEvent::SegmentStarted { .. }
                 | DownloadProgressEvent::ConfigUpdated { .. }
-                | DownloadProgressEvent::ConfigUpdateFailed { .. },
+                | DownloadProgressEvent::ConfigUpdateFailed { .. }
+                | DownloadProgressEvent::DownloadResumeRequested { .. },
             ) => {}
         }
     }
@@ -1,8 +1,8 @@
 use super::*;
 use crate::database::models::{
     DagExecutionDbModel, DagStepExecutionDbModel, DanmuStatisticsDbModel, JobDbModel,
-    JobExecutionLogDbModel, LiveSessionDbModel, OutputFilters, PipelinePreset, SessionFilters,
-    SessionSegmentDbModel,
+    JobExecutionLogDbModel, LiveSessionDbModel, OutputChecksumDbModel, OutputFilters,
+    PipelinePreset, SessionFilters, SessionSegmentDbModel,
 };
 use crate::database::repositories::{PipelinePresetFilters, PipelinePresetRepository};
 use crate::downloader::DownloadTerminalEvent;
@@ -82,6 +82,8 @@ impl SessionRepository for TestSessionRepository {
             titles: Some("[]".to_string()),
             danmu_statistics_id: None,
             total_size_bytes: 0,
+            download_engine: None,
+            kind: "live".to_string(),
         })
     }
 
@@ -100,6 +102,10 @@ impl SessionRepository for TestSessionRepository {
         unimplemented!("not needed for these tests")
     }
 
+    async fn list_session_start_times_since(&self, _since_ms: i64) -> Result<Vec<(String, i64)>> {
+        unimplemented!("not needed for these tests")
+    }
+
     async fn create_session(&self, _session: &LiveSessionDbModel) -> Result<()> {
         unimplemented!("not needed for these tests")
     }
@@ -116,6 +122,10 @@ impl SessionRepository for TestSessionRepository {
         unimplemented!("not needed for these tests")
     }
 
+    async fn set_session_download_engine(&self, _id: &str, _engine_id: &str) -> Result<()> {
+        unimplemented!("not needed for these tests")
+    }
+
     async fn delete_session(&self, _id: &str) -> Result<()> {
         unimplemented!("not needed for these tests")
     }
@@ -192,6 +202,17 @@ impl SessionRepository for TestSessionRepository {
         unimplemented!("not needed for these tests")
     }
 
+    async fn upsert_output_checksum(&self, _checksum: &OutputChecksumDbModel) -> Result<()> {
+        unimplemented!("not needed for these tests")
+    }
+
+    async fn list_output_checksums_for_session(
+        &self,
+        _session_id: &str,
+    ) -> Result<Vec<OutputChecksumDbModel>> {
+        unimplemented!("not needed for these tests")
+    }
+
     async fn get_output_count(&self, _session_id: &str) -> Result<u32> {
         unimplemented!("not needed for these tests")
     }
@@ -288,6 +309,7 @@ impl SessionRepository for TestSessionRepository {
         _danmu_rate_timeseries: Option<&str>,
         _top_talkers: Option<&str>,
         _word_frequency: Option<&str>,
+        _activity_bursts: Option<&str>,
     ) -> Result<()> {
         unimplemented!("not needed for these tests")
     }
@@ -888,6 +910,14 @@ fn test_pipeline_manager_config_default() {
     let config = PipelineManagerConfig::default();
     assert_eq!(config.cpu_pool.max_workers, 2);
     assert_eq!(config.io_pool.max_workers, 4);
+    assert_eq!(
+        config.cpu_pool.process_priority,
+        process_utils::ProcessPriority::BelowNormal
+    );
+    assert_eq!(
+        config.io_pool.process_priority,
+        process_utils::ProcessPriority::Normal
+    );
     assert_eq!(config.execute_timeout_secs, 3600);
     // Verify throttle config defaults
     assert!(!config.throttle.enabled);
@@ -1662,6 +1692,8 @@ fn test_session(id: &str, streamer_id: &str, end_time: Option<i64>) -> LiveSessi
         titles: Some("[]".to_string()),
         danmu_statistics_id: None,
         total_size_bytes: 1024,
+        download_engine: None,
+        kind: "live".to_string(),
     }
 }
 
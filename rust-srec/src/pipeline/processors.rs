@@ -2,33 +2,60 @@
 
 mod ass_burnin;
 mod audio_extract;
+mod bilibili_upload;
+mod checksum;
 mod compression;
+mod concat;
 mod copy_move;
 mod danmaku_factory;
+mod danmu_chapters;
+mod danmu_subtitle;
+mod danmu_xml;
 mod delete;
+mod encrypt;
 mod execute;
+mod ffmpeg;
+mod highlights;
 mod metadata;
+mod nfo;
 mod rclone;
 mod remux;
+mod s3;
 mod tdl;
 #[cfg(test)]
 mod test_utils;
 mod thumbnail;
+mod torrent;
 mod traits;
 pub mod utils;
+mod webhook;
+mod youtube;
 
 pub use ass_burnin::{AssBurnInConfig, AssBurnInProcessor, AssMatchStrategy};
 pub use audio_extract::AudioExtractProcessor;
+pub use bilibili_upload::BilibiliUploadProcessor;
+pub use checksum::{ChecksumMetadata, ChecksumProcessor};
 pub use compression::CompressionProcessor;
+pub use concat::{ConcatMetadata, ConcatProcessor};
 pub use copy_move::{CopyMoveConfig, CopyMoveOperation, CopyMoveProcessor};
 pub use danmaku_factory::{DanmakuFactoryConfig, DanmakuFactoryProcessor};
+pub use danmu_chapters::DanmuChaptersProcessor;
+pub use danmu_subtitle::DanmuSubtitleProcessor;
 pub use delete::DeleteProcessor;
+pub use encrypt::{EncryptProcessor, SealedSource, decrypt_artifact, load_encryption_key};
 pub use execute::ExecuteCommandProcessor;
+pub use ffmpeg::FfmpegProcessor;
+pub use highlights::HighlightsProcessor;
 pub use metadata::MetadataProcessor;
+pub use nfo::NfoProcessor;
 pub use rclone::RcloneProcessor;
 pub use remux::RemuxProcessor;
+pub use s3::S3UploadProcessor;
 pub use tdl::TdlUploadProcessor;
 pub use thumbnail::ThumbnailProcessor;
+pub use torrent::TorrentProcessor;
 pub use traits::{
     JobLogSink, Processor, ProcessorContext, ProcessorInput, ProcessorOutput, ProcessorType,
 };
+pub use webhook::WebhookProcessor;
+pub use youtube::YouTubeUploadProcessor;
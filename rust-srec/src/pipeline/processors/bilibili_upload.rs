@@ -0,0 +1,593 @@
+//! Bilibili upload processor.
+//!
+//! Submits recordings to Bilibili as one archive per job: every video input
+//! becomes a part (P1, P2, ...) in input order. The cover is taken from an
+//! image input, such as the output of the `thumbnail` processor, or from an
+//! image next to the first video with the same file name.
+//!
+//! The account cookies come from the credential store, which keeps them
+//! refreshed: by default the `bilibili` platform config, or a named template.
+//!
+//! `title`, `description`, `source`, `dynamic` and `tags` support placeholder
+//! expansion:
+//! - `{streamer}` - Streamer name
+//! - `{title}` - Session title
+//! - `{streamer_id}` - Streamer ID
+//! - `{session_id}` - Session ID
+//! - `{platform}` - Platform name
+//! - Time placeholders: `%Y`, `%m`, `%d`, `%H`, `%M`, `%S`, etc.
+
+mod client;
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use platforms_parser::extractor::platforms::bilibili::{extract_cookie_value, strip_refresh_token};
+use serde::{Deserialize, Serialize};
+use tokio::io::AsyncReadExt;
+use tracing::{info, warn};
+
+use self::client::{Account, BilibiliClient, ChunkPosition, Submission, SubmissionVideo};
+use super::traits::{
+    Processor, ProcessorContext, ProcessorInput, ProcessorOutput, ProcessorType, TimeAnchor,
+};
+use super::utils::{
+    UploadProgress, create_log_entry, expand_text_template, get_extension, is_image, is_video,
+};
+use crate::Result;
+use crate::database::repositories::config::ConfigRepository;
+use crate::pipeline::job_queue::LogLevel;
+
+/// Longest archive title Bilibili accepts, in characters.
+const MAX_TITLE_CHARS: usize = 80;
+
+/// Longest archive description Bilibili accepts, in characters.
+const MAX_DESCRIPTION_CHARS: usize = 2000;
+
+/// Longest part title Bilibili accepts, in characters.
+const MAX_PART_TITLE_CHARS: usize = 80;
+
+/// Most tags an archive may have.
+const MAX_TAGS: usize = 12;
+
+/// Platform config whose cookies are used when no template is set.
+const PLATFORM_NAME: &str = "bilibili";
+
+/// Configuration for the Bilibili upload processor.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BilibiliUploadConfig {
+    /// Template (name or ID) whose cookies to upload with. Unset uses the
+    /// `bilibili` platform config.
+    pub credential_template: Option<String>,
+
+    /// Archive title template. Truncated to 80 characters.
+    pub title: String,
+
+    /// Archive description template.
+    pub description: String,
+
+    /// Tag templates. At least one tag is required.
+    pub tags: Vec<String>,
+
+    /// Category (partition) ID. `21` is "Daily", `171` is "Esports".
+    pub tid: u32,
+
+    /// Declare the recording as original content instead of a repost.
+    pub original: bool,
+
+    /// Source shown for reposts.
+    pub source: String,
+
+    /// Text posted to the account's feed with the archive.
+    pub dynamic: String,
+
+    /// Upload an image input (or an image next to the first video) as cover.
+    pub cover: bool,
+
+    /// Upload CDN line, e.g. `bda2`, `qn`, `ws` or `bldsa`.
+    pub line: String,
+
+    /// Timestamp source for time placeholder expansion.
+    pub time_anchor: TimeAnchor,
+
+    /// Retries per chunk for failed requests.
+    pub max_retries: u32,
+
+    /// Delete the local videos once the archive is submitted.
+    pub delete_source_on_success: bool,
+}
+
+impl Default for BilibiliUploadConfig {
+    fn default() -> Self {
+        Self {
+            credential_template: None,
+            title: "【直播录像】{streamer} %Y-%m-%d {title}".to_string(),
+            description: String::new(),
+            tags: vec!["直播录像".to_string()],
+            tid: 21,
+            original: false,
+            source: "{platform} {streamer}".to_string(),
+            dynamic: String::new(),
+            cover: true,
+            line: "bda2".to_string(),
+            time_anchor: TimeAnchor::default(),
+            max_retries: 3,
+            delete_source_on_success: false,
+        }
+    }
+}
+
+impl BilibiliUploadConfig {
+    /// Archive metadata for `videos`, expanded against `input`.
+    fn submission(
+        &self,
+        input: &ProcessorInput,
+        videos: Vec<SubmissionVideo>,
+        cover: String,
+    ) -> Submission {
+        let expand = |template: &str| expand_text_template(template, input, self.time_anchor);
+
+        let mut title = truncate_chars(&expand(&self.title), MAX_TITLE_CHARS);
+        if title.is_empty() {
+            title = input
+                .streamer_name
+                .clone()
+                .unwrap_or_else(|| input.streamer_id.clone());
+        }
+
+        let mut tags: Vec<String> = Vec::new();
+        for tag in self.tags.iter().map(|t| expand(t).replace(',', " ")) {
+            let tag = tag.trim().to_string();
+            if !tag.is_empty() && !tags.contains(&tag) && tags.len() < MAX_TAGS {
+                tags.push(tag);
+            }
+        }
+        if tags.is_empty() {
+            tags.push("直播录像".to_string());
+        }
+
+        Submission {
+            copyright: if self.original { 1 } else { 2 },
+            source: if self.original {
+                String::new()
+            } else {
+                truncate_chars(&expand(&self.source), 200)
+            },
+            tid: self.tid,
+            cover,
+            title,
+            desc: truncate_chars(&expand(&self.description), MAX_DESCRIPTION_CHARS),
+            tag: tags.join(","),
+            dynamic: expand(&self.dynamic),
+            videos,
+        }
+    }
+}
+
+fn truncate_chars(text: &str, max_chars: usize) -> String {
+    text.trim()
+        .chars()
+        .take(max_chars)
+        .collect::<String>()
+        .trim()
+        .to_string()
+}
+
+/// Part title for a video: its file name without extension.
+fn part_title(path: &str) -> String {
+    let stem = Path::new(path)
+        .file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    truncate_chars(&stem, MAX_PART_TITLE_CHARS)
+}
+
+/// Cover image for a job: the first image input, or an image sharing the
+/// first video's file name.
+fn find_cover(images: &[&String], first_video: &str) -> Option<PathBuf> {
+    if let Some(image) = images.iter().find(|p| Path::new(p.as_str()).is_file()) {
+        return Some(PathBuf::from(image.as_str()));
+    }
+    let video = Path::new(first_video);
+    ["jpg", "jpeg", "png", "webp"]
+        .iter()
+        .map(|ext| video.with_extension(ext))
+        .find(|candidate| candidate.is_file())
+}
+
+fn image_mime(path: &Path) -> &'static str {
+    match get_extension(&path.to_string_lossy()).as_deref() {
+        Some("png") => "image/png",
+        Some("webp") => "image/webp",
+        _ => "image/jpeg",
+    }
+}
+
+/// Processor that submits recordings to Bilibili.
+pub struct BilibiliUploadProcessor {
+    config_repository: Arc<dyn ConfigRepository>,
+}
+
+impl BilibiliUploadProcessor {
+    pub fn new(config_repository: Arc<dyn ConfigRepository>) -> Self {
+        Self { config_repository }
+    }
+
+    /// Cookies of the configured credential layer.
+    async fn account(&self, config: &BilibiliUploadConfig) -> Result<Account> {
+        let (cookies, layer) = match config
+            .credential_template
+            .as_deref()
+            .filter(|t| !t.trim().is_empty())
+        {
+            Some(template) => {
+                let template = match self.config_repository.get_template_config(template).await {
+                    Ok(found) => found,
+                    Err(_) => {
+                        self.config_repository
+                            .get_template_config_by_name(template)
+                            .await?
+                    }
+                };
+                (template.cookies, format!("template '{}'", template.name))
+            }
+            None => {
+                let platform = self
+                    .config_repository
+                    .get_platform_config_by_name(PLATFORM_NAME)
+                    .await?;
+                (platform.cookies, "the bilibili platform config".to_string())
+            }
+        };
+
+        let cookies = strip_refresh_token(cookies.as_deref().unwrap_or_default().trim());
+        if extract_cookie_value(&cookies, "SESSDATA").is_none() {
+            return Err(crate::Error::Validation(format!(
+                "No Bilibili login cookies (SESSDATA) in {layer}; log in through the credentials page first"
+            )));
+        }
+        let csrf = extract_cookie_value(&cookies, "bili_jct").ok_or_else(|| {
+            crate::Error::Validation(format!("Bilibili cookies in {layer} lack bili_jct"))
+        })?;
+        Ok(Account { cookies, csrf })
+    }
+
+    /// Upload one video and return the file name the submission refers to.
+    async fn upload_video(
+        client: &BilibiliClient,
+        path: &str,
+        progress: &mut UploadProgress<'_>,
+    ) -> Result<String> {
+        let size = tokio::fs::metadata(path)
+            .await
+            .map_err(|e| {
+                crate::Error::io_path("reading upload source metadata", Path::new(path), e)
+            })?
+            .len();
+        let name = Path::new(path)
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        let target = client.preupload(&name, size).await?;
+        let upload_id = client.init_upload(&target).await?;
+
+        let chunk_size = target.chunk_size.max(1);
+        let count = size.div_ceil(chunk_size).max(1) as u32;
+        let mut file = tokio::fs::File::open(path)
+            .await
+            .map_err(|e| crate::Error::io_path("opening upload source", Path::new(path), e))?;
+        let mut chunks = Vec::with_capacity(count as usize);
+        let mut start = 0u64;
+
+        for index in 0..count {
+            if progress.is_cancelled() {
+                return Err(crate::Error::PipelineError(
+                    "Bilibili upload cancelled".to_string(),
+                ));
+            }
+
+            let mut data = Vec::with_capacity(chunk_size.min(size - start) as usize);
+            (&mut file)
+                .take(chunk_size)
+                .read_to_end(&mut data)
+                .await
+                .map_err(|e| crate::Error::io_path("reading upload source", Path::new(path), e))?;
+            let len = data.len() as u64;
+
+            let position = ChunkPosition {
+                index,
+                count,
+                start,
+                total: size,
+            };
+            chunks.push(
+                client
+                    .upload_chunk(&target, &upload_id, position, Bytes::from(data))
+                    .await?,
+            );
+            progress.advance(len);
+            start += len;
+        }
+
+        client
+            .complete_upload(&target, &upload_id, &name, &chunks)
+            .await?;
+        Ok(target.file_name().to_string())
+    }
+}
+
+#[async_trait]
+impl Processor for BilibiliUploadProcessor {
+    fn processor_type(&self) -> ProcessorType {
+        ProcessorType::Io
+    }
+
+    fn job_types(&self) -> Vec<&'static str> {
+        vec!["bilibili"]
+    }
+
+    fn name(&self) -> &'static str {
+        "BilibiliUploadProcessor"
+    }
+
+    fn supports_batch_input(&self) -> bool {
+        true
+    }
+
+    /// All videos form one archive, so any failed part fails the job and
+    /// nothing is submitted.
+    async fn process(
+        &self,
+        input: &ProcessorInput,
+        ctx: &ProcessorContext,
+    ) -> Result<ProcessorOutput> {
+        let start = std::time::Instant::now();
+
+        if input.inputs.is_empty() {
+            return Err(crate::Error::Validation(
+                "No input files provided for BilibiliUploadProcessor".to_string(),
+            ));
+        }
+
+        let config: BilibiliUploadConfig = match input.config.as_deref() {
+            Some(s) => serde_json::from_str(s).map_err(|e| {
+                crate::Error::Validation(format!("Invalid bilibili config JSON: {e}"))
+            })?,
+            None => BilibiliUploadConfig::default(),
+        };
+
+        let videos: Vec<&String> = input
+            .inputs
+            .iter()
+            .filter(|path| get_extension(path).is_some_and(|ext| is_video(&ext)))
+            .collect();
+        let images: Vec<&String> = input
+            .inputs
+            .iter()
+            .filter(|path| get_extension(path).is_some_and(|ext| is_image(&ext)))
+            .collect();
+        let skipped_inputs: Vec<(String, String)> = input
+            .inputs
+            .iter()
+            .filter(|path| !videos.contains(path))
+            .map(|path| (path.clone(), "not a video file".to_string()))
+            .collect();
+
+        if videos.is_empty() {
+            return Ok(ProcessorOutput {
+                outputs: input.inputs.clone(),
+                duration_secs: start.elapsed().as_secs_f64(),
+                skipped_inputs,
+                logs: vec![create_log_entry(
+                    LogLevel::Info,
+                    "No video inputs to upload to Bilibili",
+                )],
+                ..Default::default()
+            });
+        }
+        if let Some(missing) = videos.iter().find(|p| !Path::new(p.as_str()).exists()) {
+            return Err(crate::Error::PipelineError(format!(
+                "Input file does not exist: {missing}"
+            )));
+        }
+
+        let account = self.account(&config).await?;
+        let client = BilibiliClient::new(
+            reqwest::Client::new(),
+            account,
+            config.line.clone(),
+            config.max_retries,
+        );
+        let mut logs = Vec::new();
+
+        let mut bytes_total = 0u64;
+        for path in &videos {
+            if let Ok(metadata) = tokio::fs::metadata(path).await {
+                bytes_total = bytes_total.saturating_add(metadata.len());
+            }
+        }
+        let mut progress = UploadProgress::new(ctx, bytes_total);
+
+        let mut parts = Vec::with_capacity(videos.len());
+        for (index, path) in videos.iter().enumerate() {
+            info!(input = %path, part = index + 1, "Uploading to Bilibili");
+            let filename = Self::upload_video(&client, path, &mut progress)
+                .await
+                .map_err(|e| {
+                    crate::Error::PipelineError(format!("Failed to upload {path} to Bilibili: {e}"))
+                })?;
+            logs.push(create_log_entry(
+                LogLevel::Info,
+                format!("Uploaded {path} as part {}", index + 1),
+            ));
+            parts.push(SubmissionVideo {
+                filename,
+                title: part_title(path),
+                desc: String::new(),
+            });
+        }
+
+        // A missing or rejected cover is not worth losing the upload over;
+        // Bilibili then picks a frame itself.
+        let mut cover_url = String::new();
+        if config.cover
+            && let Some(cover) = find_cover(&images, videos[0])
+        {
+            let uploaded = match tokio::fs::read(&cover).await {
+                Ok(bytes) => client.upload_cover(&bytes, image_mime(&cover)).await,
+                Err(e) => Err(crate::Error::io_path("reading cover image", &cover, e)),
+            };
+            match uploaded {
+                Ok(url) => cover_url = url,
+                Err(e) => {
+                    let message = format!("Failed to upload cover {}: {e}", cover.display());
+                    warn!("{message}");
+                    logs.push(create_log_entry(LogLevel::Warn, message));
+                }
+            }
+        }
+
+        let submission = config.submission(input, parts, cover_url);
+        let archive = client.submit(&submission).await?;
+        let message = format!(
+            "Submitted {} part(s) to Bilibili as {} (av{})",
+            submission.videos.len(),
+            archive.bvid,
+            archive.aid
+        );
+        info!("{message}");
+        logs.push(create_log_entry(LogLevel::Info, message));
+
+        let mut outputs: Vec<String> = Vec::new();
+        for path in &input.inputs {
+            let is_uploaded_video = videos.contains(&path);
+            if is_uploaded_video && config.delete_source_on_success {
+                match tokio::fs::remove_file(path).await {
+                    Ok(()) => continue,
+                    Err(e) => warn!(input = %path, error = %e, "Failed to delete uploaded source"),
+                }
+            }
+            outputs.push(path.clone());
+        }
+
+        Ok(ProcessorOutput {
+            outputs,
+            duration_secs: start.elapsed().as_secs_f64(),
+            metadata: Some(
+                serde_json::json!({
+                    "aid": archive.aid,
+                    "bvid": archive.bvid,
+                    "url": format!("https://www.bilibili.com/video/{}", archive.bvid),
+                    "title": submission.title,
+                    "parts": submission.videos.len(),
+                    "cover": !submission.cover.is_empty(),
+                })
+                .to_string(),
+            ),
+            items_produced: vec![],
+            input_size_bytes: Some(bytes_total),
+            output_size_bytes: Some(bytes_total),
+            failed_inputs: vec![],
+            succeeded_inputs: videos.iter().map(|p| (*p).clone()).collect(),
+            skipped_inputs,
+            logs,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_utils::utc_datetime;
+    use super::*;
+
+    fn input() -> ProcessorInput {
+        ProcessorInput::new(
+            vec!["/rec/part1.flv".to_string()],
+            vec![],
+            "streamer-1",
+            "session-1",
+        )
+        .with_streamer_name("主播")
+        .with_session_title("深夜杂谈")
+        .with_platform("Douyu")
+        .with_created_at(utc_datetime(2024, 3, 5, 12, 0, 0))
+    }
+
+    #[test]
+    fn config_deserializes_from_partial_json() {
+        let config: BilibiliUploadConfig =
+            serde_json::from_str(r#"{"tid": 171, "credential_template": "uploader"}"#).unwrap();
+        assert_eq!(config.tid, 171);
+        assert_eq!(config.credential_template.as_deref(), Some("uploader"));
+        assert_eq!(config.line, "bda2");
+        assert!(config.cover);
+        assert!(!config.original);
+    }
+
+    #[test]
+    fn submission_expands_templates() {
+        let config = BilibiliUploadConfig {
+            tags: vec![
+                "{streamer}".to_string(),
+                "直播录像".to_string(),
+                "{streamer}".to_string(),
+                "a,b".to_string(),
+            ],
+            ..Default::default()
+        };
+        let videos = vec![SubmissionVideo {
+            filename: "n1".to_string(),
+            title: part_title("/rec/part1.flv"),
+            desc: String::new(),
+        }];
+
+        let submission = config.submission(&input(), videos, String::new());
+        assert_eq!(submission.title, "【直播录像】主播 2024-03-05 深夜杂谈");
+        assert_eq!(submission.tag, "主播,直播录像,a b");
+        assert_eq!(submission.source, "Douyu 主播");
+        assert_eq!(submission.copyright, 2);
+        assert_eq!(submission.videos[0].title, "part1");
+    }
+
+    #[test]
+    fn original_submissions_have_no_source() {
+        let config = BilibiliUploadConfig {
+            original: true,
+            title: "x".repeat(100),
+            tags: vec![],
+            ..Default::default()
+        };
+        let submission = config.submission(&input(), vec![], String::new());
+        assert_eq!(submission.copyright, 1);
+        assert!(submission.source.is_empty());
+        assert_eq!(submission.title.chars().count(), MAX_TITLE_CHARS);
+        assert_eq!(submission.tag, "直播录像");
+    }
+
+    #[test]
+    fn cover_prefers_image_inputs_then_sibling_images() {
+        let dir = tempfile::tempdir().unwrap();
+        let video = dir.path().join("live.flv");
+        let sibling = dir.path().join("live.jpg");
+        let thumbnail = dir.path().join("thumb.png");
+        std::fs::write(&video, b"v").unwrap();
+
+        let video = video.to_string_lossy().into_owned();
+        assert_eq!(find_cover(&[], &video), None);
+
+        std::fs::write(&sibling, b"i").unwrap();
+        assert_eq!(find_cover(&[], &video), Some(sibling));
+
+        std::fs::write(&thumbnail, b"i").unwrap();
+        let thumbnail_str = thumbnail.to_string_lossy().into_owned();
+        assert_eq!(
+            find_cover(&[&thumbnail_str], &video),
+            Some(thumbnail.clone())
+        );
+        assert_eq!(image_mime(&thumbnail), "image/png");
+    }
+}
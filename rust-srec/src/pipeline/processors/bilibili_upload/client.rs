@@ -0,0 +1,430 @@
+//! Minimal Bilibili creator-center client: UPOS chunked video upload, cover
+//! upload and submission.
+//!
+//! Each video is uploaded through the UPOS object store: `preupload` picks an
+//! upload node and returns an upload URI with its auth token, the file is
+//! sent in numbered chunks, and completing the upload yields the file name
+//! that the submission references. All calls authenticate with the account
+//! cookies; write calls also carry the `bili_jct` CSRF token.
+
+use std::time::Duration;
+
+use base64::Engine;
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::Result;
+
+const PREUPLOAD_URL: &str = "https://member.bilibili.com/preupload";
+const COVER_URL: &str = "https://member.bilibili.com/x/vu/web/cover/up";
+const SUBMIT_URL: &str = "https://member.bilibili.com/x/vu/web/add/v3";
+const REFERER: &str = "https://member.bilibili.com/platform/upload/video/frame";
+
+/// UPOS upload profile used by the web uploader.
+const UPLOAD_PROFILE: &str = "ugcfx/bup";
+
+/// Account cookies and the CSRF token taken from them.
+#[derive(Clone)]
+pub(super) struct Account {
+    pub cookies: String,
+    pub csrf: String,
+}
+
+impl std::fmt::Debug for Account {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Account").finish_non_exhaustive()
+    }
+}
+
+/// Upload node assigned by `preupload`.
+#[derive(Debug, Clone, Deserialize)]
+pub(super) struct UploadTarget {
+    auth: String,
+    biz_id: u64,
+    /// Chunk size the node expects.
+    pub chunk_size: u64,
+    /// Protocol-relative node host, e.g. `//upos-cs-upcdnbda2.bilivideo.com`.
+    endpoint: String,
+    /// Object URI, e.g. `upos://ugcfx2lf/n2301...mp4`.
+    upos_uri: String,
+}
+
+impl UploadTarget {
+    /// HTTPS URL of the uploaded object.
+    fn url(&self) -> String {
+        let path = self
+            .upos_uri
+            .strip_prefix("upos://")
+            .unwrap_or(&self.upos_uri);
+        format!("https:{}/{}", self.endpoint, path)
+    }
+
+    /// Name the submission refers to the video by: the object file name
+    /// without extension.
+    pub fn file_name(&self) -> &str {
+        let name = self.upos_uri.rsplit('/').next().unwrap_or_default();
+        name.rsplit_once('.').map_or(name, |(stem, _)| stem)
+    }
+}
+
+/// A chunk acknowledged by the upload node.
+#[derive(Debug, Clone, Serialize)]
+pub(super) struct UploadedChunk {
+    #[serde(rename = "partNumber")]
+    pub part_number: u32,
+    #[serde(rename = "eTag")]
+    pub etag: String,
+}
+
+/// One video of a submission.
+#[derive(Debug, Clone, Serialize)]
+pub(super) struct SubmissionVideo {
+    pub filename: String,
+    pub title: String,
+    pub desc: String,
+}
+
+/// Archive metadata sent with the submission.
+#[derive(Debug, Clone, Serialize)]
+pub(super) struct Submission {
+    /// 1 for original content, 2 for reposts.
+    pub copyright: u8,
+    /// Original source, required for reposts.
+    pub source: String,
+    /// Category (partition) ID.
+    pub tid: u32,
+    pub cover: String,
+    pub title: String,
+    pub desc: String,
+    /// Comma-separated tags.
+    pub tag: String,
+    pub dynamic: String,
+    pub videos: Vec<SubmissionVideo>,
+}
+
+/// A submitted archive.
+#[derive(Debug, Clone, Deserialize)]
+pub(super) struct SubmittedArchive {
+    pub aid: u64,
+    pub bvid: String,
+}
+
+/// Response envelope of the UPOS node (`OK: 1` on success).
+#[derive(Debug, Deserialize)]
+struct UposResponse {
+    #[serde(rename = "OK")]
+    ok: i32,
+    #[serde(default)]
+    upload_id: Option<String>,
+    #[serde(default)]
+    message: Option<String>,
+}
+
+/// Response envelope of the creator-center API (`code: 0` on success).
+#[derive(Debug, Deserialize)]
+struct ApiResponse<T> {
+    code: i64,
+    #[serde(default)]
+    message: String,
+    data: Option<T>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CoverData {
+    url: String,
+}
+
+pub(super) struct BilibiliClient {
+    http: reqwest::Client,
+    account: Account,
+    /// Upload CDN line, e.g. `bda2`, `qn` or `ws`.
+    line: String,
+    max_retries: u32,
+}
+
+impl BilibiliClient {
+    pub fn new(http: reqwest::Client, account: Account, line: String, max_retries: u32) -> Self {
+        Self {
+            http,
+            account,
+            line,
+            max_retries,
+        }
+    }
+
+    fn request(&self, method: reqwest::Method, url: &str) -> reqwest::RequestBuilder {
+        self.http
+            .request(method, url)
+            .header(reqwest::header::COOKIE, &self.account.cookies)
+            .header(
+                reqwest::header::USER_AGENT,
+                platforms_parser::extractor::DEFAULT_UA,
+            )
+            .header(reqwest::header::REFERER, REFERER)
+    }
+
+    /// Ask for an upload node for a `size`-byte file.
+    pub async fn preupload(&self, name: &str, size: u64) -> Result<UploadTarget> {
+        let size = size.to_string();
+        let response = self
+            .request(reqwest::Method::GET, PREUPLOAD_URL)
+            .query(&[
+                ("name", name),
+                ("size", size.as_str()),
+                ("r", "upos"),
+                ("profile", UPLOAD_PROFILE),
+                ("ssl", "0"),
+                ("version", "2.14.0"),
+                ("build", "2140000"),
+                ("upcdn", self.line.as_str()),
+                ("probe_version", "20221109"),
+            ])
+            .send()
+            .await
+            .map_err(network_error)?;
+        let body = response.text().await.map_err(network_error)?;
+        parse_preupload(&body)
+    }
+
+    /// Start a chunked upload and return its upload ID.
+    pub async fn init_upload(&self, target: &UploadTarget) -> Result<String> {
+        let response = self
+            .request(
+                reqwest::Method::POST,
+                &format!("{}?uploads&output=json", target.url()),
+            )
+            .header("X-Upos-Auth", &target.auth)
+            .send()
+            .await
+            .map_err(network_error)?;
+        let body = response.text().await.map_err(network_error)?;
+        let parsed = parse_upos(&body)?;
+        parsed.upload_id.ok_or_else(|| {
+            crate::Error::Other("Bilibili upload node returned no upload ID".to_string())
+        })
+    }
+
+    /// Upload chunk `index` (0-based) of `chunks`, covering bytes
+    /// `start..start + data.len()` of a `total`-byte file. Retries failed
+    /// requests with exponential backoff.
+    pub async fn upload_chunk(
+        &self,
+        target: &UploadTarget,
+        upload_id: &str,
+        chunk: ChunkPosition,
+        data: Bytes,
+    ) -> Result<UploadedChunk> {
+        let end = chunk.start + data.len() as u64;
+        let url = format!(
+            "{}?partNumber={}&uploadId={}&chunk={}&chunks={}&size={}&start={}&end={}&total={}",
+            target.url(),
+            chunk.index + 1,
+            upload_id,
+            chunk.index,
+            chunk.count,
+            data.len(),
+            chunk.start,
+            end,
+            chunk.total,
+        );
+
+        let mut attempt = 0;
+        loop {
+            let result = self
+                .request(reqwest::Method::PUT, &url)
+                .header("X-Upos-Auth", &target.auth)
+                .body(data.clone())
+                .send()
+                .await;
+            let error = match result {
+                Ok(response) if response.status().is_success() => {
+                    return Ok(UploadedChunk {
+                        part_number: chunk.index + 1,
+                        etag: "etag".to_string(),
+                    });
+                }
+                Ok(response) => format!("HTTP {}", response.status()),
+                Err(e) => e.to_string(),
+            };
+
+            if attempt >= self.max_retries {
+                return Err(crate::Error::Other(format!(
+                    "Bilibili chunk {} of {} failed: {error}",
+                    chunk.index + 1,
+                    chunk.count
+                )));
+            }
+            attempt += 1;
+            warn!(chunk = chunk.index + 1, attempt, error = %error, "Bilibili chunk upload failed; retrying");
+            tokio::time::sleep(Duration::from_secs(2u64.pow(attempt.min(6)))).await;
+        }
+    }
+
+    /// Finish a chunked upload.
+    pub async fn complete_upload(
+        &self,
+        target: &UploadTarget,
+        upload_id: &str,
+        name: &str,
+        chunks: &[UploadedChunk],
+    ) -> Result<()> {
+        let response = self
+            .request(reqwest::Method::POST, &target.url())
+            .header("X-Upos-Auth", &target.auth)
+            .query(&[
+                ("output", "json"),
+                ("name", name),
+                ("profile", UPLOAD_PROFILE),
+                ("uploadId", upload_id),
+                ("biz_id", &target.biz_id.to_string()),
+            ])
+            .json(&serde_json::json!({ "parts": chunks }))
+            .send()
+            .await
+            .map_err(network_error)?;
+        let body = response.text().await.map_err(network_error)?;
+        parse_upos(&body).map(|_| ())
+    }
+
+    /// Upload a cover image and return its URL.
+    pub async fn upload_cover(&self, image: &[u8], mime: &str) -> Result<String> {
+        let data_url = format!(
+            "data:{mime};base64,{}",
+            base64::engine::general_purpose::STANDARD.encode(image)
+        );
+        let response = self
+            .request(reqwest::Method::POST, COVER_URL)
+            .query(&[("t", crate::database::time::now_ms().to_string())])
+            .form(&[("cover", data_url.as_str()), ("csrf", &self.account.csrf)])
+            .send()
+            .await
+            .map_err(network_error)?;
+        let body = response.text().await.map_err(network_error)?;
+        parse_api::<CoverData>(&body, "cover upload").map(|data| data.url)
+    }
+
+    /// Submit the uploaded videos as one archive.
+    pub async fn submit(&self, submission: &Submission) -> Result<SubmittedArchive> {
+        let mut body = serde_json::to_value(submission)
+            .map_err(|e| crate::Error::Other(format!("Failed to encode submission: {e}")))?;
+        body["csrf"] = serde_json::Value::String(self.account.csrf.clone());
+
+        let response = self
+            .request(reqwest::Method::POST, SUBMIT_URL)
+            .query(&[
+                ("t", crate::database::time::now_ms().to_string()),
+                ("csrf", self.account.csrf.clone()),
+            ])
+            .json(&body)
+            .send()
+            .await
+            .map_err(network_error)?;
+        let text = response.text().await.map_err(network_error)?;
+        parse_api::<SubmittedArchive>(&text, "submission")
+    }
+}
+
+/// Where a chunk sits in its file.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct ChunkPosition {
+    /// 0-based chunk index.
+    pub index: u32,
+    pub count: u32,
+    /// Offset of the chunk's first byte.
+    pub start: u64,
+    /// File size.
+    pub total: u64,
+}
+
+fn parse_preupload(body: &str) -> Result<UploadTarget> {
+    let ok = serde_json::from_str::<UposResponse>(body).map(|r| r.ok == 1);
+    if !matches!(ok, Ok(true)) {
+        return Err(crate::Error::Other(format!(
+            "Bilibili preupload failed (check the account cookies): {}",
+            body.chars().take(200).collect::<String>()
+        )));
+    }
+    serde_json::from_str(body)
+        .map_err(|e| crate::Error::Other(format!("Invalid Bilibili preupload response: {e}")))
+}
+
+fn parse_upos(body: &str) -> Result<UposResponse> {
+    let parsed: UposResponse = serde_json::from_str(body)
+        .map_err(|e| crate::Error::Other(format!("Invalid Bilibili upload node response: {e}")))?;
+    if parsed.ok != 1 {
+        return Err(crate::Error::Other(format!(
+            "Bilibili upload node rejected the request: {}",
+            parsed.message.as_deref().unwrap_or(body)
+        )));
+    }
+    Ok(parsed)
+}
+
+fn parse_api<T: serde::de::DeserializeOwned>(body: &str, what: &str) -> Result<T> {
+    let parsed: ApiResponse<T> = serde_json::from_str(body)
+        .map_err(|e| crate::Error::Other(format!("Invalid Bilibili {what} response: {e}")))?;
+    if parsed.code != 0 {
+        return Err(crate::Error::Other(format!(
+            "Bilibili {what} failed ({}): {}",
+            parsed.code, parsed.message
+        )));
+    }
+    parsed
+        .data
+        .ok_or_else(|| crate::Error::Other(format!("Bilibili {what} response has no data")))
+}
+
+fn network_error(error: reqwest::Error) -> crate::Error {
+    crate::Error::Other(format!("Bilibili request failed: {error}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PREUPLOAD: &str = r#"{"OK":1,"auth":"ak=1&cdn=%2F%2Fupos","biz_id":1234,"chunk_size":10485760,"endpoint":"//upos-cs-upcdnbda2.bilivideo.com","upos_uri":"upos://ugcfx2lf/n230101abcd.mp4","threads":3}"#;
+
+    #[test]
+    fn preupload_response_gives_upload_url_and_file_name() {
+        let target = parse_preupload(PREUPLOAD).unwrap();
+        assert_eq!(
+            target.url(),
+            "https://upos-cs-upcdnbda2.bilivideo.com/ugcfx2lf/n230101abcd.mp4"
+        );
+        assert_eq!(target.file_name(), "n230101abcd");
+        assert_eq!(target.chunk_size, 10_485_760);
+        assert_eq!(target.biz_id, 1234);
+
+        assert!(parse_preupload(r#"{"OK":0,"message":"not logged in"}"#).is_err());
+    }
+
+    #[test]
+    fn api_errors_carry_code_and_message() {
+        let ok: SubmittedArchive = parse_api(
+            r#"{"code":0,"message":"0","data":{"aid":1,"bvid":"BV1xx"}}"#,
+            "submission",
+        )
+        .unwrap();
+        assert_eq!(ok.bvid, "BV1xx");
+
+        let err = parse_api::<SubmittedArchive>(
+            r#"{"code":21070,"message":"请求过于频繁"}"#,
+            "submission",
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("21070"));
+    }
+
+    #[test]
+    fn chunks_serialize_in_upos_format() {
+        let chunk = UploadedChunk {
+            part_number: 1,
+            etag: "etag".into(),
+        };
+        assert_eq!(
+            serde_json::to_string(&chunk).unwrap(),
+            r#"{"partNumber":1,"eTag":"etag"}"#
+        );
+    }
+}
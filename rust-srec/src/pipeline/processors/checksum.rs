@@ -0,0 +1,349 @@
+//! Checksum processor.
+//!
+//! Computes SHA-256 and/or BLAKE3 digests of step inputs in a single read,
+//! writes `sha256sum`/`b3sum`-compatible sidecars (`<file>.sha256`,
+//! `<file>.b3`), and reports the digests in the job metadata so the job
+//! queue stores them on the session (see [`ChecksumMetadata`]).
+//!
+//! Inputs pass through unchanged; sidecars are appended to the outputs so
+//! upload steps carry them along with their files.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tokio::io::AsyncReadExt;
+use tracing::warn;
+
+use super::traits::{Processor, ProcessorContext, ProcessorInput, ProcessorOutput, ProcessorType};
+use crate::Result;
+
+/// Hash algorithm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChecksumAlgorithm {
+    Sha256,
+    Blake3,
+}
+
+impl ChecksumAlgorithm {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Sha256 => "sha256",
+            Self::Blake3 => "blake3",
+        }
+    }
+
+    /// Sidecar extension, as used by `sha256sum` and `b3sum` users.
+    fn sidecar_extension(self) -> &'static str {
+        match self {
+            Self::Sha256 => "sha256",
+            Self::Blake3 => "b3",
+        }
+    }
+}
+
+/// Configuration for the checksum processor.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChecksumConfig {
+    /// Algorithms to compute.
+    pub algorithms: Vec<ChecksumAlgorithm>,
+
+    /// Write a sidecar per file and algorithm.
+    pub write_sidecars: bool,
+
+    /// Append the sidecars to the outputs for later steps.
+    pub sidecars_in_outputs: bool,
+
+    /// Also hash inputs that are not media files (danmu, subtitles, ...).
+    pub include_non_media: bool,
+}
+
+impl Default for ChecksumConfig {
+    fn default() -> Self {
+        Self {
+            algorithms: vec![ChecksumAlgorithm::Sha256],
+            write_sidecars: true,
+            sidecars_in_outputs: true,
+            include_non_media: true,
+        }
+    }
+}
+
+/// Digests of one file.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FileChecksums {
+    pub path: String,
+    pub size_bytes: u64,
+    /// Algorithm name to lowercase hex digest.
+    pub digests: BTreeMap<String, String>,
+}
+
+/// Job metadata written by the checksum processor; the job queue stores
+/// each digest in `output_checksums` when the job completes.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ChecksumMetadata {
+    pub files: Vec<FileChecksums>,
+}
+
+/// Hash `path` with every algorithm in one pass over the file.
+pub async fn hash_file(
+    path: &Path,
+    algorithms: &[ChecksumAlgorithm],
+) -> std::io::Result<FileChecksums> {
+    let mut file = tokio::fs::File::open(path).await?;
+    let mut sha256 = algorithms
+        .contains(&ChecksumAlgorithm::Sha256)
+        .then(Sha256::new);
+    let mut blake3 = algorithms
+        .contains(&ChecksumAlgorithm::Blake3)
+        .then(blake3::Hasher::new);
+
+    let mut buffer = vec![0u8; 1024 * 1024];
+    let mut size_bytes = 0u64;
+    loop {
+        let read = file.read(&mut buffer).await?;
+        if read == 0 {
+            break;
+        }
+        size_bytes += read as u64;
+        if let Some(hasher) = sha256.as_mut() {
+            hasher.update(&buffer[..read]);
+        }
+        if let Some(hasher) = blake3.as_mut() {
+            hasher.update(&buffer[..read]);
+        }
+    }
+
+    let mut digests = BTreeMap::new();
+    if let Some(hasher) = sha256 {
+        digests.insert(
+            ChecksumAlgorithm::Sha256.as_str().to_string(),
+            hex::encode(hasher.finalize()),
+        );
+    }
+    if let Some(hasher) = blake3 {
+        digests.insert(
+            ChecksumAlgorithm::Blake3.as_str().to_string(),
+            hasher.finalize().to_hex().to_string(),
+        );
+    }
+    Ok(FileChecksums {
+        path: path.to_string_lossy().into_owned(),
+        size_bytes,
+        digests,
+    })
+}
+
+/// Sidecar line in the `<digest>  <file name>` format `sha256sum -c` and
+/// `b3sum -c` read, relative to the sidecar's directory.
+fn sidecar_line(digest: &str, path: &Path) -> String {
+    let name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    format!("{digest}  {name}\n")
+}
+
+fn is_sidecar(path: &str) -> bool {
+    let lower = path.to_ascii_lowercase();
+    lower.ends_with(".sha256") || lower.ends_with(".b3")
+}
+
+/// Processor that computes file checksums.
+pub struct ChecksumProcessor;
+
+impl ChecksumProcessor {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for ChecksumProcessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Processor for ChecksumProcessor {
+    fn processor_type(&self) -> ProcessorType {
+        ProcessorType::Io
+    }
+
+    fn job_types(&self) -> Vec<&'static str> {
+        vec!["checksum"]
+    }
+
+    fn name(&self) -> &'static str {
+        "ChecksumProcessor"
+    }
+
+    fn supports_batch_input(&self) -> bool {
+        true
+    }
+
+    async fn process(
+        &self,
+        input: &ProcessorInput,
+        ctx: &ProcessorContext,
+    ) -> Result<ProcessorOutput> {
+        let started = std::time::Instant::now();
+        let config: ChecksumConfig = match input.config.as_deref() {
+            Some(s) => serde_json::from_str(s).map_err(|e| {
+                crate::Error::Validation(format!("Invalid checksum config JSON: {e}"))
+            })?,
+            None => ChecksumConfig::default(),
+        };
+        let mut algorithms = config.algorithms.clone();
+        algorithms.sort();
+        algorithms.dedup();
+        if algorithms.is_empty() {
+            return Err(crate::Error::Validation(
+                "checksum needs at least one algorithm".to_string(),
+            ));
+        }
+
+        let mut output = ProcessorOutput {
+            outputs: input.inputs.clone(),
+            ..Default::default()
+        };
+        let mut metadata = ChecksumMetadata::default();
+        for path in &input.inputs {
+            let is_media =
+                super::utils::get_extension(path).is_some_and(|ext| super::utils::is_media(&ext));
+            if is_sidecar(path) || (!is_media && !config.include_non_media) {
+                output
+                    .skipped_inputs
+                    .push((path.clone(), "not hashed".to_string()));
+                continue;
+            }
+            if ctx.cancellation_token.is_cancelled() {
+                return Err(crate::Error::PipelineError(
+                    "Checksum computation cancelled".to_string(),
+                ));
+            }
+
+            let file_path = Path::new(path);
+            let checksums = match hash_file(file_path, &algorithms).await {
+                Ok(checksums) => checksums,
+                Err(e) => {
+                    warn!(path = %path, error = %e, "Failed to hash file");
+                    output.failed_inputs.push((path.clone(), e.to_string()));
+                    continue;
+                }
+            };
+
+            if config.write_sidecars {
+                for algorithm in &algorithms {
+                    let digest = &checksums.digests[algorithm.as_str()];
+                    let sidecar = format!("{path}.{}", algorithm.sidecar_extension());
+                    tokio::fs::write(&sidecar, sidecar_line(digest, file_path))
+                        .await
+                        .map_err(|e| {
+                            crate::Error::io_path(
+                                "writing checksum sidecar",
+                                Path::new(&sidecar),
+                                e,
+                            )
+                        })?;
+                    if config.sidecars_in_outputs {
+                        output.outputs.push(sidecar.clone());
+                    }
+                    output.items_produced.push(sidecar);
+                }
+            }
+
+            ctx.info(format!(
+                "{}: {}",
+                path,
+                checksums
+                    .digests
+                    .iter()
+                    .map(|(algorithm, digest)| format!("{algorithm} {digest}"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+            output.succeeded_inputs.push(path.clone());
+            metadata.files.push(checksums);
+        }
+
+        if output.succeeded_inputs.is_empty() && !output.failed_inputs.is_empty() {
+            return Err(crate::Error::PipelineError(format!(
+                "Checksum failed for all {} input(s): {}",
+                output.failed_inputs.len(),
+                output.failed_inputs[0].1
+            )));
+        }
+
+        output.input_size_bytes = Some(metadata.files.iter().map(|f| f.size_bytes).sum());
+        output.metadata = Some(serde_json::to_string(&metadata)?);
+        output.duration_secs = started.elapsed().as_secs_f64();
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn hashes_inputs_and_writes_sidecars() {
+        let dir = tempfile::tempdir().unwrap();
+        let video = dir.path().join("live.flv");
+        std::fs::write(&video, b"abc").unwrap();
+        let video = video.to_string_lossy().into_owned();
+
+        let input = ProcessorInput::new(vec![video.clone()], vec![], "s", "x")
+            .with_config(r#"{"algorithms":["blake3","sha256"]}"#);
+        let output = ChecksumProcessor::new()
+            .process(&input, &ProcessorContext::noop("job"))
+            .await
+            .unwrap();
+
+        let sha256 = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+        let blake3 = "6437b3ac38465133ffb63b75273a8db548c558465d79db03fd359c6cd5bd9d85";
+        assert_eq!(
+            output.outputs,
+            vec![
+                video.clone(),
+                format!("{video}.sha256"),
+                format!("{video}.b3")
+            ]
+        );
+        assert_eq!(
+            std::fs::read_to_string(format!("{video}.sha256")).unwrap(),
+            format!("{sha256}  live.flv\n")
+        );
+        assert_eq!(
+            std::fs::read_to_string(format!("{video}.b3")).unwrap(),
+            format!("{blake3}  live.flv\n")
+        );
+
+        let metadata: ChecksumMetadata =
+            serde_json::from_str(output.metadata.as_deref().unwrap()).unwrap();
+        assert_eq!(metadata.files.len(), 1);
+        assert_eq!(metadata.files[0].size_bytes, 3);
+        assert_eq!(metadata.files[0].digests["sha256"], sha256);
+        assert_eq!(metadata.files[0].digests["blake3"], blake3);
+    }
+
+    #[tokio::test]
+    async fn skips_existing_sidecars() {
+        let dir = tempfile::tempdir().unwrap();
+        let sidecar = dir.path().join("live.flv.sha256");
+        std::fs::write(&sidecar, b"x").unwrap();
+        let sidecar = sidecar.to_string_lossy().into_owned();
+
+        let input = ProcessorInput::new(vec![sidecar.clone()], vec![], "s", "x");
+        let output = ChecksumProcessor::new()
+            .process(&input, &ProcessorContext::noop("job"))
+            .await
+            .unwrap();
+        assert_eq!(output.outputs, vec![sidecar]);
+        assert_eq!(output.skipped_inputs.len(), 1);
+    }
+}
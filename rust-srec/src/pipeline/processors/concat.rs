@@ -0,0 +1,521 @@
+//! Segment concatenation processor.
+//!
+//! Merges the split files of one session (produced by size/duration limits)
+//! into a single file, in input order. Inputs are normally the segment
+//! outputs handed to a session-complete pipeline, which are already ordered
+//! by segment index.
+//!
+//! Two methods are available:
+//! - `ffmpeg` - ffmpeg's concat demuxer with stream copy. Works for every
+//!   container ffmpeg can stream-copy (FLV, TS, MP4, MKV) and rebases
+//!   timestamps across files.
+//! - `native` - byte-level concatenation, only for MPEG-TS inputs. TS is
+//!   self-synchronizing, so appending files yields a valid stream without
+//!   spawning ffmpeg; players treat the joins as timestamp discontinuities.
+//!
+//! The merged file is recorded as a session output when the job completes
+//! (see [`ConcatMetadata`]).
+
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+use tracing::{debug, info, warn};
+
+use super::traits::{
+    Processor, ProcessorContext, ProcessorInput, ProcessorOutput, ProcessorType, TimeAnchor,
+};
+use super::utils::{create_log_entry, get_extension, is_media, run_ffmpeg_with_progress};
+use crate::Result;
+use crate::pipeline::job_queue::LogLevel;
+use crate::utils::filename::expand_placeholders_at;
+
+/// How segments are joined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum ConcatMethod {
+    /// ffmpeg concat demuxer with stream copy.
+    #[default]
+    Ffmpeg,
+    /// Byte-level concatenation; MPEG-TS inputs only.
+    Native,
+}
+
+/// Configuration for the concat processor.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ConcatConfig {
+    /// How segments are joined.
+    pub method: ConcatMethod,
+
+    /// Output file name without extension. Supports the session and time
+    /// placeholders and `{input_stem}` (stem of the first segment).
+    pub output_name: String,
+
+    /// Output extension. Unset keeps the extension of the first segment.
+    pub output_extension: Option<String>,
+
+    /// Directory for the merged file. Unset writes next to the first segment.
+    pub output_dir: Option<String>,
+
+    /// Delete the segments once the merged file is written.
+    pub remove_inputs_on_success: bool,
+
+    /// Timestamp source for time placeholders in `output_name`.
+    pub time_anchor: TimeAnchor,
+}
+
+impl Default for ConcatConfig {
+    fn default() -> Self {
+        Self {
+            method: ConcatMethod::Ffmpeg,
+            output_name: "{input_stem}_merged".to_string(),
+            output_extension: None,
+            output_dir: None,
+            remove_inputs_on_success: false,
+            time_anchor: TimeAnchor::default(),
+        }
+    }
+}
+
+/// Job metadata written by the concat processor.
+///
+/// The job queue reads it on completion to add the merged file to the
+/// session's outputs and drop the rows of segments that were deleted.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ConcatMetadata {
+    /// Path of the merged file.
+    pub merged: String,
+    /// Segments that went into the merged file, in order.
+    pub segments: Vec<String>,
+    /// Segments deleted after merging.
+    #[serde(default)]
+    pub removed_segments: Vec<String>,
+}
+
+/// Processor that merges session segments into one file.
+pub struct ConcatProcessor {
+    /// Path to ffmpeg binary.
+    ffmpeg_path: String,
+}
+
+impl ConcatProcessor {
+    pub fn new() -> Self {
+        Self {
+            ffmpeg_path: std::env::var("FFMPEG_PATH").unwrap_or_else(|_| "ffmpeg".to_string()),
+        }
+    }
+
+    /// Path of the merged file for `segments`.
+    fn output_path(input: &ProcessorInput, config: &ConcatConfig, first: &Path) -> PathBuf {
+        let stem = first
+            .file_stem()
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "merged".to_string());
+        let name = expand_placeholders_at(
+            &config.output_name.replace("{input_stem}", &stem),
+            &input.streamer_id,
+            &input.session_id,
+            input.streamer_name.as_deref(),
+            input.session_title.as_deref(),
+            input.platform.as_deref(),
+            Some(config.time_anchor.reference_time(input).timestamp_millis()),
+        );
+        let extension = config
+            .output_extension
+            .as_deref()
+            .map(|e| e.trim_start_matches('.').to_string())
+            .filter(|e| !e.is_empty())
+            .or_else(|| get_extension(&first.to_string_lossy()))
+            .unwrap_or_else(|| "mp4".to_string());
+        let dir = config
+            .output_dir
+            .as_deref()
+            .filter(|d| !d.is_empty())
+            .map(PathBuf::from)
+            .or_else(|| first.parent().map(Path::to_path_buf))
+            .unwrap_or_default();
+        dir.join(format!("{name}.{extension}"))
+    }
+
+    /// Contents of the concat demuxer list file.
+    fn concat_list(segments: &[String]) -> String {
+        segments
+            .iter()
+            .map(|s| {
+                // The demuxer resolves relative entries against the list file,
+                // so pass absolute paths where possible.
+                let path = std::path::absolute(s)
+                    .map(|p| p.to_string_lossy().into_owned())
+                    .unwrap_or_else(|_| s.clone());
+                format!("file '{}'\n", path.replace('\'', "'\\''"))
+            })
+            .collect()
+    }
+
+    fn build_ffmpeg_args(list_path: &Path, output_path: &Path) -> Vec<String> {
+        let mut args: Vec<String> = [
+            "-y",
+            "-hide_banner",
+            "-nostats",
+            "-loglevel",
+            "warning",
+            "-progress",
+            "pipe:1",
+            "-f",
+            "concat",
+            "-safe",
+            "0",
+            "-i",
+        ]
+        .into_iter()
+        .map(String::from)
+        .collect();
+        args.push(list_path.to_string_lossy().into_owned());
+        args.extend(["-map", "0", "-c", "copy"].map(String::from));
+        if get_extension(&output_path.to_string_lossy())
+            .is_some_and(|ext| matches!(ext.as_str(), "mp4" | "m4v" | "mov"))
+        {
+            args.extend(["-movflags", "+faststart"].map(String::from));
+        }
+        args.push(output_path.to_string_lossy().into_owned());
+        args
+    }
+
+    async fn concat_ffmpeg(
+        &self,
+        segments: &[String],
+        output_path: &Path,
+        ctx: &ProcessorContext,
+    ) -> Result<(f64, Vec<crate::pipeline::job_queue::JobLogEntry>)> {
+        let list_path = output_path.with_extension("concat.txt");
+        tokio::fs::write(&list_path, Self::concat_list(segments))
+            .await
+            .map_err(|e| crate::Error::io_path("writing concat list", &list_path, e))?;
+
+        let args = Self::build_ffmpeg_args(&list_path, output_path);
+        debug!("Concat args: {:?}", args);
+
+        let mut cmd = Command::new(&self.ffmpeg_path);
+        cmd.args(&args).env("LC_ALL", "C");
+        let result =
+            run_ffmpeg_with_progress(&mut cmd, &ctx.progress, Some(ctx.log_sink.clone())).await;
+        let _ = tokio::fs::remove_file(&list_path).await;
+        let command_output = result?;
+
+        if !command_output.status.success() {
+            let _ = tokio::fs::remove_file(output_path).await;
+            let error_msg = command_output
+                .logs
+                .iter()
+                .rfind(|l| l.level == LogLevel::Error)
+                .map(|l| l.message.clone())
+                .unwrap_or_else(|| "Unknown ffmpeg error".to_string());
+            return Err(crate::Error::PipelineError(format!(
+                "ffmpeg concat failed with exit code {}: {}",
+                command_output.status.code().unwrap_or(-1),
+                error_msg
+            )));
+        }
+        Ok((command_output.duration, command_output.logs))
+    }
+
+    async fn concat_native(
+        segments: &[String],
+        output_path: &Path,
+        ctx: &ProcessorContext,
+    ) -> Result<f64> {
+        let started = std::time::Instant::now();
+        let result = async {
+            let mut out = tokio::fs::File::create(output_path)
+                .await
+                .map_err(|e| crate::Error::io_path("creating merged file", output_path, e))?;
+            for segment in segments {
+                if ctx.cancellation_token.is_cancelled() {
+                    return Err(crate::Error::PipelineError(
+                        "Concatenation cancelled".to_string(),
+                    ));
+                }
+                let mut file = tokio::fs::File::open(segment)
+                    .await
+                    .map_err(|e| crate::Error::io_path("opening segment", Path::new(segment), e))?;
+                tokio::io::copy(&mut file, &mut out).await.map_err(|e| {
+                    crate::Error::io_path("appending segment", Path::new(segment), e)
+                })?;
+            }
+            out.flush()
+                .await
+                .map_err(|e| crate::Error::io_path("writing merged file", output_path, e))
+        }
+        .await;
+
+        if let Err(e) = result {
+            let _ = tokio::fs::remove_file(output_path).await;
+            return Err(e);
+        }
+        Ok(started.elapsed().as_secs_f64())
+    }
+}
+
+impl Default for ConcatProcessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Processor for ConcatProcessor {
+    fn processor_type(&self) -> ProcessorType {
+        ProcessorType::Io
+    }
+
+    fn job_types(&self) -> Vec<&'static str> {
+        vec!["concat"]
+    }
+
+    fn name(&self) -> &'static str {
+        "ConcatProcessor"
+    }
+
+    fn supports_batch_input(&self) -> bool {
+        true
+    }
+
+    async fn process(
+        &self,
+        input: &ProcessorInput,
+        ctx: &ProcessorContext,
+    ) -> Result<ProcessorOutput> {
+        let config: ConcatConfig = match input.config.as_deref() {
+            Some(s) => serde_json::from_str(s).map_err(|e| {
+                crate::Error::Validation(format!("Invalid concat config JSON: {e}"))
+            })?,
+            None => ConcatConfig::default(),
+        };
+
+        let (segments, passthrough): (Vec<String>, Vec<String>) = input
+            .inputs
+            .iter()
+            .cloned()
+            .partition(|p| get_extension(p).is_some_and(|ext| is_media(&ext)));
+        let skipped_inputs: Vec<(String, String)> = passthrough
+            .iter()
+            .map(|p| (p.clone(), "not a media file".to_string()))
+            .collect();
+
+        if segments.len() < 2 {
+            info!(
+                "Concat needs at least two segments, got {}; passing inputs through",
+                segments.len()
+            );
+            let mut logs = Vec::new();
+            if segments.len() == 1 {
+                logs.push(create_log_entry(
+                    LogLevel::Info,
+                    "Single segment, nothing to concatenate",
+                ));
+            }
+            return Ok(ProcessorOutput {
+                outputs: input.inputs.clone(),
+                skipped_inputs,
+                logs,
+                ..Default::default()
+            });
+        }
+
+        for segment in &segments {
+            if !Path::new(segment).exists() {
+                return Err(crate::Error::PipelineError(format!(
+                    "Segment does not exist: {segment}"
+                )));
+            }
+        }
+        if config.method == ConcatMethod::Native
+            && let Some(other) = segments
+                .iter()
+                .find(|s| get_extension(s).as_deref() != Some("ts"))
+        {
+            return Err(crate::Error::Validation(format!(
+                "Native concatenation only supports MPEG-TS segments, got {other}; use the ffmpeg method"
+            )));
+        }
+
+        let output_path = Self::output_path(input, &config, Path::new(&segments[0]));
+        if segments.iter().any(|s| Path::new(s) == output_path) {
+            return Err(crate::Error::Validation(format!(
+                "Merged file would overwrite a segment: {}; change output_name or output_dir",
+                output_path.display()
+            )));
+        }
+        if let Some(parent) = output_path.parent()
+            && !parent.as_os_str().is_empty()
+        {
+            tokio::fs::create_dir_all(parent).await.map_err(|e| {
+                crate::Error::io_path("creating concat output directory", parent, e)
+            })?;
+        }
+
+        ctx.info(format!(
+            "Concatenating {} segments into {}",
+            segments.len(),
+            output_path.display()
+        ));
+        let (duration_secs, mut logs) = match config.method {
+            ConcatMethod::Ffmpeg => self.concat_ffmpeg(&segments, &output_path, ctx).await?,
+            ConcatMethod::Native => (
+                Self::concat_native(&segments, &output_path, ctx).await?,
+                Vec::new(),
+            ),
+        };
+
+        let mut input_size_bytes = 0u64;
+        for segment in &segments {
+            if let Ok(meta) = tokio::fs::metadata(segment).await {
+                input_size_bytes += meta.len();
+            }
+        }
+        let output_size_bytes = tokio::fs::metadata(&output_path)
+            .await
+            .ok()
+            .map(|m| m.len());
+
+        let mut removed_segments = Vec::new();
+        if config.remove_inputs_on_success {
+            for segment in &segments {
+                match tokio::fs::remove_file(segment).await {
+                    Ok(()) => removed_segments.push(segment.clone()),
+                    Err(e) => {
+                        warn!(segment = %segment, error = %e, "Failed to remove merged segment");
+                        logs.push(create_log_entry(
+                            LogLevel::Warn,
+                            format!("Failed to remove segment {segment}: {e}"),
+                        ));
+                    }
+                }
+            }
+        }
+
+        let merged = output_path.to_string_lossy().into_owned();
+        info!(
+            "Merged {} segments into {} ({:.2}s)",
+            segments.len(),
+            merged,
+            duration_secs
+        );
+        let metadata = ConcatMetadata {
+            merged: merged.clone(),
+            segments: segments.clone(),
+            removed_segments,
+        };
+
+        let mut outputs = vec![merged.clone()];
+        outputs.extend(passthrough);
+        Ok(ProcessorOutput {
+            outputs,
+            duration_secs,
+            metadata: Some(serde_json::to_string(&metadata)?),
+            items_produced: vec![merged],
+            input_size_bytes: Some(input_size_bytes),
+            output_size_bytes,
+            succeeded_inputs: segments,
+            skipped_inputs,
+            logs,
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn concat_list_quotes_paths() {
+        let list =
+            ConcatProcessor::concat_list(&["/rec/it's.flv".to_string(), "/rec/b.flv".to_string()]);
+        assert_eq!(list, "file '/rec/it'\\''s.flv'\nfile '/rec/b.flv'\n");
+    }
+
+    #[test]
+    fn ffmpeg_args_stream_copy_with_faststart_for_mp4() {
+        let args = ConcatProcessor::build_ffmpeg_args(
+            Path::new("/rec/a.concat.txt"),
+            Path::new("/rec/a.mp4"),
+        );
+        let tail: Vec<&str> = args[7..].iter().map(String::as_str).collect();
+        assert_eq!(
+            tail,
+            [
+                "-f",
+                "concat",
+                "-safe",
+                "0",
+                "-i",
+                "/rec/a.concat.txt",
+                "-map",
+                "0",
+                "-c",
+                "copy",
+                "-movflags",
+                "+faststart",
+                "/rec/a.mp4",
+            ]
+        );
+
+        let args = ConcatProcessor::build_ffmpeg_args(
+            Path::new("/rec/a.concat.txt"),
+            Path::new("/rec/a.flv"),
+        );
+        assert!(!args.contains(&"-movflags".to_string()));
+    }
+
+    #[tokio::test]
+    async fn native_concat_appends_ts_segments_in_order() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut inputs = Vec::new();
+        for (i, bytes) in [b"first", b"secnd"].iter().enumerate() {
+            let path = dir.path().join(format!("live_{i}.ts"));
+            std::fs::write(&path, bytes).unwrap();
+            inputs.push(path.to_string_lossy().into_owned());
+        }
+        let config = serde_json::json!({"method": "native", "remove_inputs_on_success": true});
+        let input =
+            ProcessorInput::new(inputs.clone(), vec![], "s", "x").with_config(config.to_string());
+
+        let output = ConcatProcessor::new()
+            .process(&input, &ProcessorContext::noop("job"))
+            .await
+            .unwrap();
+
+        let merged = dir.path().join("live_0_merged.ts");
+        assert_eq!(output.outputs, vec![merged.to_string_lossy().into_owned()]);
+        assert_eq!(std::fs::read(&merged).unwrap(), b"firstsecnd");
+        assert!(inputs.iter().all(|p| !Path::new(p).exists()));
+
+        let metadata: ConcatMetadata =
+            serde_json::from_str(output.metadata.as_deref().unwrap()).unwrap();
+        assert_eq!(metadata.segments, inputs);
+        assert_eq!(metadata.removed_segments, inputs);
+    }
+
+    #[tokio::test]
+    async fn native_concat_rejects_flv() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut inputs = Vec::new();
+        for i in 0..2 {
+            let path = dir.path().join(format!("live_{i}.flv"));
+            std::fs::write(&path, b"x").unwrap();
+            inputs.push(path.to_string_lossy().into_owned());
+        }
+        let input = ProcessorInput::new(inputs, vec![], "s", "x")
+            .with_config(r#"{"method":"native"}"#.to_string());
+
+        let err = ConcatProcessor::new()
+            .process(&input, &ProcessorContext::noop("job"))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("MPEG-TS"));
+    }
+}
@@ -0,0 +1,696 @@
+//! Chapter generation from danmu activity.
+//!
+//! Reads the danmu XML recorded alongside a video, finds bursts of chat
+//! activity (and configured keywords), and writes them as chapters:
+//! - `ffmetadata` - `<stem>.ffmetadata`, loadable with `ffmpeg -i video -i file -map_chapters 1`
+//! - `youtube` - `<stem>.chapters.txt`, timestamps in the form YouTube descriptions expect
+//! - `embed` - a copy of the video with the chapters muxed in (MKV/MP4 chapters)
+//!
+//! Bursts are found with the sliding-window detector of the live danmu
+//! statistics: a burst starts when the weighted message rate over
+//! `window_secs` reaches `sensitivity` times its moving baseline (with at
+//! least `min_score` in the window). The strongest bursts become chapters,
+//! placed `lead_secs` before the burst so the moment that triggered it is
+//! included. Each chapter is titled with the most frequent keyword or
+//! message of its burst.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+use platforms_parser::danmaku::BurstDetectionConfig;
+use serde::{Deserialize, Serialize};
+use tokio::process::Command;
+use tracing::{debug, info, warn};
+
+use super::danmu_xml::{
+    DanmuEntry, DanmuKind, detect_bursts, pair_videos_with_danmu, read_danmu_xml,
+};
+use super::traits::{Processor, ProcessorContext, ProcessorInput, ProcessorOutput, ProcessorType};
+use super::utils::{create_log_entry, get_extension, run_ffmpeg_with_progress};
+use crate::Result;
+use crate::pipeline::job_queue::LogLevel;
+
+/// Chapter output format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChapterFormat {
+    /// FFmpeg metadata file.
+    Ffmetadata,
+    /// YouTube description chapter list.
+    Youtube,
+    /// Chapters muxed into a copy of the video.
+    Embed,
+}
+
+/// Configuration for danmu chapter generation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DanmuChaptersConfig {
+    /// Outputs to write.
+    pub formats: Vec<ChapterFormat>,
+
+    /// Width of the sliding window the activity rate is measured over, in
+    /// seconds.
+    pub window_secs: f64,
+
+    /// Multiple of the baseline rate a window must reach to start a burst.
+    pub sensitivity: f64,
+
+    /// Minimum score (weighted messages) inside a window for a burst.
+    pub min_score: f64,
+
+    /// Keywords that mark highlights (case-insensitive substring match).
+    pub keywords: Vec<String>,
+
+    /// Extra score for a message containing a keyword.
+    pub keyword_weight: f64,
+
+    /// Count super chats towards activity.
+    pub include_super_chats: bool,
+
+    /// Count gifts towards activity.
+    pub include_gifts: bool,
+
+    /// Seconds to start a chapter before its burst.
+    pub lead_secs: f64,
+
+    /// Minimum distance between chapters, in seconds.
+    pub min_gap_secs: f64,
+
+    /// Maximum number of highlight chapters (the opening chapter is extra).
+    pub max_chapters: usize,
+
+    /// Title of the opening chapter at 0:00.
+    pub start_title: String,
+
+    /// Longest chapter title, in characters.
+    pub max_title_chars: usize,
+
+    /// Extension of the embedded copy. Unset keeps MKV/MP4/MOV inputs and
+    /// uses `mkv` otherwise, since FLV and TS cannot carry chapters.
+    pub embed_extension: Option<String>,
+}
+
+impl Default for DanmuChaptersConfig {
+    fn default() -> Self {
+        Self {
+            formats: vec![ChapterFormat::Ffmetadata, ChapterFormat::Youtube],
+            window_secs: 30.0,
+            sensitivity: 3.0,
+            min_score: 20.0,
+            keywords: Vec::new(),
+            keyword_weight: 3.0,
+            include_super_chats: true,
+            include_gifts: false,
+            lead_secs: 10.0,
+            min_gap_secs: 60.0,
+            max_chapters: 20,
+            start_title: "Start".to_string(),
+            max_title_chars: 30,
+            embed_extension: None,
+        }
+    }
+}
+
+/// A generated chapter.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Chapter {
+    pub start_secs: f64,
+    pub end_secs: f64,
+    pub title: String,
+}
+
+/// A detected burst, before spacing and the chapter limit are applied.
+struct Candidate {
+    start_secs: f64,
+    peak_rate: f64,
+    title: String,
+}
+
+fn entry_weight(entry: &DanmuEntry, config: &DanmuChaptersConfig, keywords: &[String]) -> f64 {
+    let base = match entry.kind {
+        DanmuKind::Comment => 1.0,
+        DanmuKind::SuperChat if config.include_super_chats => 1.0,
+        DanmuKind::Gift if config.include_gifts => 1.0,
+        _ => return 0.0,
+    };
+    let text = entry.text.to_lowercase();
+    if keywords.iter().any(|k| text.contains(k.as_str())) {
+        base + config.keyword_weight
+    } else {
+        base
+    }
+}
+
+/// Most frequent keyword in `entries`, else the most frequent message.
+fn burst_title(entries: &[&DanmuEntry], keywords: &[String], max_chars: usize) -> String {
+    let mut keyword_hits: HashMap<&str, usize> = HashMap::new();
+    let mut messages: HashMap<&str, usize> = HashMap::new();
+    for entry in entries.iter().filter(|e| e.kind == DanmuKind::Comment) {
+        let text = entry.text.to_lowercase();
+        for keyword in keywords.iter().filter(|k| text.contains(k.as_str())) {
+            *keyword_hits.entry(keyword).or_default() += 1;
+        }
+        let message = entry.text.trim();
+        if !message.is_empty() {
+            *messages.entry(message).or_default() += 1;
+        }
+    }
+
+    // Ties go to the lexically smallest text so titles are deterministic.
+    let pick = |counts: HashMap<&str, usize>| {
+        counts
+            .into_iter()
+            .max_by(|a, b| a.1.cmp(&b.1).then_with(|| b.0.cmp(a.0)))
+            .map(|(text, _)| text.to_string())
+    };
+    let title = pick(keyword_hits)
+        .or_else(|| pick(messages))
+        .unwrap_or_default();
+    title.chars().take(max_chars).collect()
+}
+
+/// Detect highlight chapters in `entries` for a recording of `duration_secs`.
+///
+/// Always returns the opening chapter first; chapter ends are the next
+/// chapter's start, and the last chapter ends at `duration_secs`.
+pub fn detect_chapters(
+    entries: &[DanmuEntry],
+    duration_secs: f64,
+    config: &DanmuChaptersConfig,
+) -> Vec<Chapter> {
+    let keywords: Vec<String> = config
+        .keywords
+        .iter()
+        .map(|k| k.trim().to_lowercase())
+        .filter(|k| !k.is_empty())
+        .collect();
+
+    let samples = entries.iter().filter_map(|entry| {
+        let weight = entry_weight(entry, config, &keywords);
+        (weight > 0.0).then(|| (entry.time_s, weight.round().max(1.0) as u64))
+    });
+    let detection = BurstDetectionConfig {
+        window_secs: config.window_secs.max(1.0).round() as u64,
+        threshold: config.sensitivity,
+        min_messages: config.min_score.max(0.0).ceil() as u64,
+        max_bursts: usize::MAX,
+        ..Default::default()
+    };
+    let bursts: Vec<Candidate> = detect_bursts(samples, detection)
+        .into_iter()
+        .map(|burst| {
+            let members: Vec<&DanmuEntry> = entries
+                .iter()
+                .filter(|e| e.time_s >= burst.start_secs && e.time_s < burst.end_secs)
+                .filter(|e| entry_weight(e, config, &keywords) > 0.0)
+                .collect();
+            Candidate {
+                start_secs: (burst.start_secs - config.lead_secs).max(0.0),
+                peak_rate: burst.peak_rate,
+                title: burst_title(&members, &keywords, config.max_title_chars),
+            }
+        })
+        .collect();
+
+    // Strongest bursts first; skip those too close to an accepted one.
+    let mut bursts = bursts;
+    bursts.sort_by(|a, b| b.peak_rate.total_cmp(&a.peak_rate));
+    let mut accepted: Vec<Candidate> = Vec::new();
+    for burst in bursts {
+        if accepted.len() >= config.max_chapters {
+            break;
+        }
+        let clear_of_start = burst.start_secs >= config.min_gap_secs;
+        let clear_of_others = accepted
+            .iter()
+            .all(|a| (a.start_secs - burst.start_secs).abs() >= config.min_gap_secs);
+        if clear_of_start && clear_of_others && burst.start_secs < duration_secs {
+            accepted.push(burst);
+        }
+    }
+    accepted.sort_by(|a, b| a.start_secs.total_cmp(&b.start_secs));
+
+    let mut starts = vec![(0.0, config.start_title.clone())];
+    for (n, burst) in accepted.into_iter().enumerate() {
+        let title = if burst.title.is_empty() {
+            format!("Highlight {}", n + 1)
+        } else {
+            burst.title
+        };
+        starts.push((burst.start_secs, title));
+    }
+
+    let ends: Vec<f64> = starts
+        .iter()
+        .skip(1)
+        .map(|(s, _)| *s)
+        .chain(std::iter::once(duration_secs.max(0.0)))
+        .collect();
+    starts
+        .into_iter()
+        .zip(ends)
+        .map(|((start_secs, title), end_secs)| Chapter {
+            start_secs,
+            end_secs,
+            title,
+        })
+        .collect()
+}
+
+/// Escape a value for an ffmetadata file.
+fn escape_ffmetadata(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for ch in value.chars() {
+        if matches!(ch, '=' | ';' | '#' | '\\' | '\n') {
+            out.push('\\');
+        }
+        out.push(ch);
+    }
+    out
+}
+
+/// Chapters as an ffmetadata file.
+pub fn to_ffmetadata(chapters: &[Chapter]) -> String {
+    let mut out = String::from(";FFMETADATA1\n");
+    for chapter in chapters {
+        out.push_str(&format!(
+            "\n[CHAPTER]\nTIMEBASE=1/1000\nSTART={}\nEND={}\ntitle={}\n",
+            (chapter.start_secs * 1000.0).round() as i64,
+            (chapter.end_secs * 1000.0).round() as i64,
+            escape_ffmetadata(&chapter.title)
+        ));
+    }
+    out
+}
+
+/// Chapters as YouTube description lines (`0:00 Start`, `1:02:03 ...`).
+pub fn to_youtube_chapters(chapters: &[Chapter]) -> String {
+    let long = chapters.last().is_some_and(|c| c.start_secs >= 3600.0);
+    chapters
+        .iter()
+        .map(|c| {
+            let total = c.start_secs as u64;
+            let (h, m, s) = (total / 3600, total / 60 % 60, total % 60);
+            let stamp = if long {
+                format!("{h}:{m:02}:{s:02}")
+            } else {
+                format!("{m}:{s:02}")
+            };
+            format!("{stamp} {}\n", c.title.replace('\n', " "))
+        })
+        .collect()
+}
+
+/// Processor that writes chapters derived from danmu activity.
+pub struct DanmuChaptersProcessor {
+    ffmpeg_path: String,
+    ffprobe_path: String,
+}
+
+impl DanmuChaptersProcessor {
+    pub fn new() -> Self {
+        Self {
+            ffmpeg_path: std::env::var("FFMPEG_PATH").unwrap_or_else(|_| "ffmpeg".to_string()),
+            ffprobe_path: std::env::var("FFPROBE_PATH").unwrap_or_else(|_| "ffprobe".to_string()),
+        }
+    }
+
+    /// Container duration of `video`, in seconds.
+    async fn probe_duration(&self, video: &str) -> Option<f64> {
+        let output = process_utils::tokio_command(&self.ffprobe_path)
+            .args([
+                "-v",
+                "error",
+                "-show_entries",
+                "format=duration",
+                "-of",
+                "default=noprint_wrappers=1:nokey=1",
+                video,
+            ])
+            .output()
+            .await
+            .ok()?;
+        String::from_utf8_lossy(&output.stdout)
+            .trim()
+            .parse::<f64>()
+            .ok()
+            .filter(|d| d.is_finite() && *d > 0.0)
+    }
+
+    fn embed_path(video: &Path, config: &DanmuChaptersConfig) -> PathBuf {
+        let ext = config
+            .embed_extension
+            .as_deref()
+            .map(|e| e.trim_start_matches('.').to_string())
+            .filter(|e| !e.is_empty())
+            .or_else(|| {
+                get_extension(&video.to_string_lossy())
+                    .filter(|e| matches!(e.as_str(), "mkv" | "mp4" | "m4v" | "mov"))
+            })
+            .unwrap_or_else(|| "mkv".to_string());
+        let stem = video
+            .file_stem()
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        video.with_file_name(format!("{stem}_chapters.{ext}"))
+    }
+
+    async fn embed(
+        &self,
+        video: &str,
+        ffmetadata: &Path,
+        output: &Path,
+        ctx: &ProcessorContext,
+    ) -> Result<Vec<crate::pipeline::job_queue::JobLogEntry>> {
+        let mut cmd = Command::new(&self.ffmpeg_path);
+        cmd.args(["-y", "-hide_banner", "-nostats", "-loglevel", "warning"])
+            .args(["-progress", "pipe:1", "-i", video, "-i"])
+            .arg(ffmetadata)
+            .args([
+                "-map",
+                "0",
+                "-map_metadata",
+                "0",
+                "-map_chapters",
+                "1",
+                "-c",
+                "copy",
+            ])
+            .arg(output)
+            .env("LC_ALL", "C");
+        let command_output =
+            run_ffmpeg_with_progress(&mut cmd, &ctx.progress, Some(ctx.log_sink.clone())).await?;
+        if !command_output.status.success() {
+            let _ = tokio::fs::remove_file(output).await;
+            let error_msg = command_output
+                .logs
+                .iter()
+                .rfind(|l| l.level == LogLevel::Error)
+                .map(|l| l.message.clone())
+                .unwrap_or_else(|| "Unknown ffmpeg error".to_string());
+            return Err(crate::Error::PipelineError(format!(
+                "Embedding chapters failed with exit code {}: {}",
+                command_output.status.code().unwrap_or(-1),
+                error_msg
+            )));
+        }
+        Ok(command_output.logs)
+    }
+
+    async fn process_pair(
+        &self,
+        video: &str,
+        danmu: &str,
+        config: &DanmuChaptersConfig,
+        ctx: &ProcessorContext,
+        output: &mut ProcessorOutput,
+    ) -> Result<Option<String>> {
+        let entries = read_danmu_xml(Path::new(danmu)).await?;
+        let duration = match self.probe_duration(video).await {
+            Some(d) => d,
+            None => {
+                warn!(video = %video, "Could not probe duration; using the last danmu time");
+                entries
+                    .last()
+                    .map(|e| e.time_s + config.window_secs)
+                    .unwrap_or(0.0)
+            }
+        };
+
+        let chapters = detect_chapters(&entries, duration, config);
+        ctx.info(format!(
+            "{} chapters from {} danmu for {}",
+            chapters.len(),
+            entries.len(),
+            video
+        ));
+        debug!(?chapters, "Detected danmu chapters");
+
+        let video_path = Path::new(video);
+        let stem = video_path
+            .file_stem()
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let ffmetadata_path = video_path.with_file_name(format!("{stem}.ffmetadata"));
+        let write_ffmetadata = config.formats.contains(&ChapterFormat::Ffmetadata)
+            || config.formats.contains(&ChapterFormat::Embed);
+        if write_ffmetadata {
+            tokio::fs::write(&ffmetadata_path, to_ffmetadata(&chapters))
+                .await
+                .map_err(|e| crate::Error::io_path("writing chapters", &ffmetadata_path, e))?;
+        }
+
+        let mut files = Vec::new();
+        if config.formats.contains(&ChapterFormat::Ffmetadata) {
+            files.push(ffmetadata_path.to_string_lossy().into_owned());
+        }
+        if config.formats.contains(&ChapterFormat::Youtube) {
+            let path = video_path.with_file_name(format!("{stem}.chapters.txt"));
+            tokio::fs::write(&path, to_youtube_chapters(&chapters))
+                .await
+                .map_err(|e| crate::Error::io_path("writing chapters", &path, e))?;
+            files.push(path.to_string_lossy().into_owned());
+        }
+
+        let mut embedded = None;
+        if config.formats.contains(&ChapterFormat::Embed) {
+            let path = Self::embed_path(video_path, config);
+            let result = self.embed(video, &ffmetadata_path, &path, ctx).await;
+            if !config.formats.contains(&ChapterFormat::Ffmetadata) {
+                let _ = tokio::fs::remove_file(&ffmetadata_path).await;
+            }
+            output.logs.extend(result?);
+            let path = path.to_string_lossy().into_owned();
+            output.items_produced.push(path.clone());
+            embedded = Some(path);
+        }
+
+        output.items_produced.extend(files.iter().cloned());
+        output.outputs.extend(files);
+        Ok(embedded)
+    }
+}
+
+impl Default for DanmuChaptersProcessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Processor for DanmuChaptersProcessor {
+    fn processor_type(&self) -> ProcessorType {
+        ProcessorType::Cpu
+    }
+
+    fn job_types(&self) -> Vec<&'static str> {
+        vec!["danmu_chapters"]
+    }
+
+    fn name(&self) -> &'static str {
+        "DanmuChaptersProcessor"
+    }
+
+    fn supports_batch_input(&self) -> bool {
+        true
+    }
+
+    async fn process(
+        &self,
+        input: &ProcessorInput,
+        ctx: &ProcessorContext,
+    ) -> Result<ProcessorOutput> {
+        let started = std::time::Instant::now();
+        let config: DanmuChaptersConfig = match input.config.as_deref() {
+            Some(s) => serde_json::from_str(s).map_err(|e| {
+                crate::Error::Validation(format!("Invalid danmu_chapters config JSON: {e}"))
+            })?,
+            None => DanmuChaptersConfig::default(),
+        };
+        if config.formats.is_empty() {
+            return Err(crate::Error::Validation(
+                "danmu_chapters needs at least one output format".to_string(),
+            ));
+        }
+
+        let pairs = pair_videos_with_danmu(&input.inputs).await;
+        let mut output = ProcessorOutput::default();
+        if pairs.is_empty() {
+            info!("No video with danmu XML found; passing inputs through");
+            output.outputs = input.inputs.clone();
+            output.skipped_inputs = input
+                .inputs
+                .iter()
+                .map(|p| (p.clone(), "no video/danmu pair".to_string()))
+                .collect();
+            output.logs.push(create_log_entry(
+                LogLevel::Info,
+                "No video with danmu XML found, nothing to do",
+            ));
+            return Ok(output);
+        }
+
+        let mut replaced: HashMap<String, String> = HashMap::new();
+        for (video, danmu) in &pairs {
+            match self
+                .process_pair(video, danmu, &config, ctx, &mut output)
+                .await
+            {
+                Ok(embedded) => {
+                    output.succeeded_inputs.push(video.clone());
+                    if let Some(embedded) = embedded {
+                        replaced.insert(video.clone(), embedded);
+                    }
+                }
+                Err(e) => {
+                    warn!(video = %video, error = %e, "Chapter generation failed");
+                    output.failed_inputs.push((video.clone(), e.to_string()));
+                }
+            }
+        }
+
+        if output.succeeded_inputs.is_empty() {
+            return Err(crate::Error::PipelineError(format!(
+                "Chapter generation failed for all {} video(s): {}",
+                output.failed_inputs.len(),
+                output.failed_inputs[0].1
+            )));
+        }
+
+        // Inputs pass through ahead of the chapter files; videos with an
+        // embedded copy are replaced by it. Manifests and danmu stay
+        // available to later steps.
+        let mut outputs: Vec<String> = input
+            .inputs
+            .iter()
+            .map(|p| replaced.get(p).cloned().unwrap_or_else(|| p.clone()))
+            .collect();
+        outputs.append(&mut output.outputs);
+        output.outputs = outputs;
+        output.duration_secs = started.elapsed().as_secs_f64();
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn comment(time_s: f64, text: &str) -> DanmuEntry {
+        DanmuEntry {
+            time_s,
+            kind: DanmuKind::Comment,
+            text: text.to_string(),
+            user: String::new(),
+            mode: 1,
+            color: 0xFFFFFF,
+        }
+    }
+
+    /// One message every 10s for an hour, plus bursts at 600s and 2400s.
+    fn session() -> Vec<DanmuEntry> {
+        let mut entries: Vec<DanmuEntry> =
+            (0..360).map(|i| comment(i as f64 * 10.0, "hi")).collect();
+        entries.extend((0..60).map(|i| comment(600.0 + i as f64 * 0.4, "lol")));
+        entries.extend((0..40).map(|i| comment(2400.0 + i as f64 * 0.5, "GG wp")));
+        entries.extend((0..20).map(|i| comment(2405.0 + i as f64 * 0.5, "what a play")));
+        entries
+    }
+
+    #[test]
+    fn bursts_become_titled_chapters() {
+        let chapters = detect_chapters(&session(), 3600.0, &DanmuChaptersConfig::default());
+        assert_eq!(
+            chapters,
+            vec![
+                Chapter {
+                    start_secs: 0.0,
+                    end_secs: 570.0,
+                    title: "Start".to_string()
+                },
+                Chapter {
+                    start_secs: 570.0,
+                    end_secs: 2370.0,
+                    title: "lol".to_string()
+                },
+                Chapter {
+                    start_secs: 2370.0,
+                    end_secs: 3600.0,
+                    title: "GG wp".to_string()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn keywords_title_and_boost_bursts() {
+        let config = DanmuChaptersConfig {
+            keywords: vec!["PLAY".to_string()],
+            max_chapters: 1,
+            ..Default::default()
+        };
+        let chapters = detect_chapters(&session(), 3600.0, &config);
+        assert_eq!(chapters.len(), 2);
+        assert_eq!(chapters[1].start_secs, 2370.0);
+        assert_eq!(chapters[1].title, "play");
+    }
+
+    #[test]
+    fn quiet_sessions_only_get_the_opening_chapter() {
+        let entries: Vec<DanmuEntry> = (0..100).map(|i| comment(i as f64 * 30.0, "hi")).collect();
+        let chapters = detect_chapters(&entries, 3000.0, &DanmuChaptersConfig::default());
+        assert_eq!(chapters.len(), 1);
+        assert_eq!(chapters[0].end_secs, 3000.0);
+    }
+
+    #[test]
+    fn formats_ffmetadata_and_youtube() {
+        let chapters = vec![
+            Chapter {
+                start_secs: 0.0,
+                end_secs: 65.5,
+                title: "Start".to_string(),
+            },
+            Chapter {
+                start_secs: 65.5,
+                end_secs: 4000.0,
+                title: "a=b; #1".to_string(),
+            },
+        ];
+        assert_eq!(
+            to_ffmetadata(&chapters),
+            ";FFMETADATA1\n\n[CHAPTER]\nTIMEBASE=1/1000\nSTART=0\nEND=65500\ntitle=Start\n\
+             \n[CHAPTER]\nTIMEBASE=1/1000\nSTART=65500\nEND=4000000\ntitle=a\\=b\\; \\#1\n"
+        );
+        assert_eq!(to_youtube_chapters(&chapters), "0:00 Start\n1:05 a=b; #1\n");
+
+        let long = vec![
+            Chapter {
+                start_secs: 0.0,
+                end_secs: 3700.0,
+                title: "Start".to_string(),
+            },
+            Chapter {
+                start_secs: 3723.0,
+                end_secs: 4000.0,
+                title: "late".to_string(),
+            },
+        ];
+        assert_eq!(to_youtube_chapters(&long), "0:00:00 Start\n1:02:03 late\n");
+    }
+
+    #[test]
+    fn embed_keeps_chapter_capable_containers() {
+        let config = DanmuChaptersConfig::default();
+        assert_eq!(
+            DanmuChaptersProcessor::embed_path(Path::new("/rec/a.flv"), &config),
+            PathBuf::from("/rec/a_chapters.mkv")
+        );
+        assert_eq!(
+            DanmuChaptersProcessor::embed_path(Path::new("/rec/a.mp4"), &config),
+            PathBuf::from("/rec/a_chapters.mp4")
+        );
+    }
+}
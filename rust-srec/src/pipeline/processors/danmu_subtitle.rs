@@ -0,0 +1,324 @@
+//! Danmu-to-subtitle processor using the built-in renderer.
+//!
+//! Converts session danmu XML files to ASS (scrolling, top and bottom
+//! comments laid out in lanes) or SRT without the external DanmakuFactory
+//! binary. Outputs are written next to the XML as `<stem>.ass`/`<stem>.srt`,
+//! so `ass_burnin` finds them the same way it finds DanmakuFactory output.
+
+mod render;
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+
+use super::danmu_xml::{is_danmu_xml, read_danmu_xml};
+use super::traits::{Processor, ProcessorContext, ProcessorInput, ProcessorOutput, ProcessorType};
+use super::utils::create_log_entry;
+use crate::Result;
+use crate::pipeline::job_queue::LogLevel;
+
+/// Subtitle format to write.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum SubtitleFormat {
+    #[default]
+    Ass,
+    Srt,
+}
+
+impl SubtitleFormat {
+    fn extension(self) -> &'static str {
+        match self {
+            Self::Ass => "ass",
+            Self::Srt => "srt",
+        }
+    }
+}
+
+/// Configuration for danmu subtitle rendering.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DanmuSubtitleConfig {
+    pub format: SubtitleFormat,
+
+    /// Script resolution; match the video for correct sizing.
+    pub width: u32,
+    pub height: u32,
+
+    pub font_name: String,
+    pub font_size: u32,
+    pub bold: bool,
+    /// Outline width in pixels.
+    pub outline: f64,
+    /// Shadow depth in pixels.
+    pub shadow: f64,
+    /// Text opacity from 0.0 (invisible) to 1.0.
+    pub opacity: f64,
+    /// Extra vertical space between lanes, in pixels.
+    pub line_spacing: u32,
+
+    /// Seconds a scrolling comment takes to cross the screen.
+    pub scroll_duration_secs: f64,
+    /// Seconds top/bottom comments stay on screen (and SRT cue length).
+    pub fixed_duration_secs: f64,
+    /// Fraction of the screen height used for scrolling lanes.
+    pub scroll_area: f64,
+    /// Render top/bottom comments as scrolling ones.
+    pub scroll_fixed: bool,
+    /// Overlap comments instead of dropping them when every lane is busy.
+    pub allow_overlap: bool,
+
+    pub include_super_chats: bool,
+    pub include_gifts: bool,
+    /// Comments containing any of these (case-insensitive) are skipped.
+    pub blocklist: Vec<String>,
+    /// Shift applied to every comment, in seconds.
+    pub time_offset_secs: f64,
+
+    /// Most comments shown at once in SRT output.
+    pub srt_max_lines: usize,
+
+    /// Overwrite existing subtitle files.
+    pub overwrite: bool,
+    /// Include the original inputs in the outputs for later steps.
+    pub passthrough_inputs: bool,
+    /// Delete the XML files once all conversions succeed.
+    pub delete_source_xml_on_success: bool,
+}
+
+impl Default for DanmuSubtitleConfig {
+    fn default() -> Self {
+        Self {
+            format: SubtitleFormat::Ass,
+            width: 1920,
+            height: 1080,
+            font_name: "Microsoft YaHei".to_string(),
+            font_size: 38,
+            bold: false,
+            outline: 1.0,
+            shadow: 0.0,
+            opacity: 0.8,
+            line_spacing: 4,
+            scroll_duration_secs: 12.0,
+            fixed_duration_secs: 5.0,
+            scroll_area: 0.8,
+            scroll_fixed: false,
+            allow_overlap: false,
+            include_super_chats: true,
+            include_gifts: false,
+            blocklist: Vec::new(),
+            time_offset_secs: 0.0,
+            srt_max_lines: 3,
+            overwrite: true,
+            passthrough_inputs: true,
+            delete_source_xml_on_success: false,
+        }
+    }
+}
+
+/// Processor that renders danmu XML to ASS/SRT natively.
+pub struct DanmuSubtitleProcessor;
+
+impl DanmuSubtitleProcessor {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Danmu XML inputs, preferring the manifest's `danmu_inputs`.
+    async fn select_xml_inputs(inputs: &[String]) -> Vec<String> {
+        if let Some(manifest) = inputs.iter().find(|p| p.to_lowercase().ends_with(".json"))
+            && let Ok(text) = tokio::fs::read_to_string(manifest).await
+            && let Ok(value) = serde_json::from_str::<serde_json::Value>(&text)
+            && let Some(list) = value.get("danmu_inputs").and_then(|v| v.as_array())
+        {
+            let selected: Vec<String> = list
+                .iter()
+                .filter_map(|v| v.as_str().map(str::to_string))
+                .collect();
+            if !selected.is_empty() {
+                return selected;
+            }
+        }
+        inputs.iter().filter(|p| is_danmu_xml(p)).cloned().collect()
+    }
+
+    fn output_path(xml: &Path, format: SubtitleFormat) -> PathBuf {
+        xml.with_extension(format.extension())
+    }
+
+    /// Render one XML file; returns the subtitle path and the dropped count.
+    async fn convert(xml: &str, config: &DanmuSubtitleConfig) -> Result<(String, usize, usize)> {
+        let xml_path = Path::new(xml);
+        let output = Self::output_path(xml_path, config.format);
+        if output.exists() && !config.overwrite {
+            return Err(crate::Error::PipelineError(format!(
+                "Subtitle already exists and overwrite is disabled: {}",
+                output.display()
+            )));
+        }
+
+        let entries = read_danmu_xml(xml_path).await?;
+        let (contents, rendered, dropped) = match config.format {
+            SubtitleFormat::Ass => {
+                let layout = render::layout(&entries, config);
+                (
+                    render::to_ass(&layout, config),
+                    layout.placed.len(),
+                    layout.dropped,
+                )
+            }
+            SubtitleFormat::Srt => {
+                let srt = render::to_srt(&entries, config);
+                let cues = srt.matches(" --> ").count();
+                (srt, cues, entries.len().saturating_sub(cues))
+            }
+        };
+        tokio::fs::write(&output, contents)
+            .await
+            .map_err(|e| crate::Error::io_path("writing subtitles", &output, e))?;
+        Ok((output.to_string_lossy().into_owned(), rendered, dropped))
+    }
+}
+
+impl Default for DanmuSubtitleProcessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Processor for DanmuSubtitleProcessor {
+    fn processor_type(&self) -> ProcessorType {
+        ProcessorType::Cpu
+    }
+
+    fn job_types(&self) -> Vec<&'static str> {
+        vec!["danmu_subtitle"]
+    }
+
+    fn name(&self) -> &'static str {
+        "DanmuSubtitleProcessor"
+    }
+
+    fn supports_batch_input(&self) -> bool {
+        true
+    }
+
+    async fn process(
+        &self,
+        input: &ProcessorInput,
+        ctx: &ProcessorContext,
+    ) -> Result<ProcessorOutput> {
+        let started = std::time::Instant::now();
+        let config: DanmuSubtitleConfig = match input.config.as_deref() {
+            Some(s) => serde_json::from_str(s).map_err(|e| {
+                crate::Error::Validation(format!("Invalid danmu_subtitle config JSON: {e}"))
+            })?,
+            None => DanmuSubtitleConfig::default(),
+        };
+
+        let xml_inputs = Self::select_xml_inputs(&input.inputs).await;
+        let mut output = ProcessorOutput::default();
+        if xml_inputs.is_empty() {
+            info!("No danmu XML inputs found; passing through");
+            output.logs.push(create_log_entry(
+                LogLevel::Info,
+                "No danmu XML inputs found; passing through",
+            ));
+            if config.passthrough_inputs {
+                output.outputs = input.inputs.clone();
+            }
+            return Ok(output);
+        }
+
+        let mut subtitles = Vec::new();
+        for xml in &xml_inputs {
+            match Self::convert(xml, &config).await {
+                Ok((path, rendered, dropped)) => {
+                    ctx.info(format!(
+                        "Rendered {rendered} danmu to {path} ({dropped} dropped for lack of space)"
+                    ));
+                    output.succeeded_inputs.push(xml.clone());
+                    subtitles.push(path);
+                }
+                Err(e) => {
+                    warn!(xml = %xml, error = %e, "Danmu subtitle rendering failed");
+                    output.failed_inputs.push((xml.clone(), e.to_string()));
+                }
+            }
+        }
+
+        if subtitles.is_empty() {
+            return Err(crate::Error::PipelineError(format!(
+                "Danmu subtitle rendering failed for all {} file(s): {}",
+                output.failed_inputs.len(),
+                output.failed_inputs[0].1
+            )));
+        }
+
+        let mut deleted = HashSet::new();
+        if config.delete_source_xml_on_success && output.failed_inputs.is_empty() {
+            for xml in &xml_inputs {
+                match tokio::fs::remove_file(xml).await {
+                    Ok(()) => {
+                        deleted.insert(xml.clone());
+                    }
+                    Err(e) => output.logs.push(create_log_entry(
+                        LogLevel::Warn,
+                        format!("Failed to delete danmu XML {xml}: {e}"),
+                    )),
+                }
+            }
+        }
+
+        if config.passthrough_inputs {
+            output.outputs = input
+                .inputs
+                .iter()
+                .filter(|p| !deleted.contains(*p))
+                .cloned()
+                .collect();
+        }
+        for subtitle in &subtitles {
+            if !output.outputs.contains(subtitle) {
+                output.outputs.push(subtitle.clone());
+            }
+        }
+        output.items_produced = subtitles;
+        output.duration_secs = started.elapsed().as_secs_f64();
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn converts_xml_next_to_source_and_passes_inputs_through() {
+        let dir = tempfile::tempdir().unwrap();
+        let xml = dir.path().join("seg.xml");
+        std::fs::write(
+            &xml,
+            r#"<?xml version="1.0" encoding="UTF-8"?><i><d p="1.000,1,25,16777215,0,0,x,1" user="a">hello</d></i>"#,
+        )
+        .unwrap();
+        let video = dir.path().join("seg.flv").to_string_lossy().into_owned();
+        let xml = xml.to_string_lossy().into_owned();
+
+        let input = ProcessorInput::new(vec![video.clone(), xml.clone()], vec![], "s", "x");
+        let output = DanmuSubtitleProcessor::new()
+            .process(&input, &ProcessorContext::noop("job"))
+            .await
+            .unwrap();
+
+        let ass = dir.path().join("seg.ass").to_string_lossy().into_owned();
+        assert_eq!(output.outputs, vec![video, xml, ass.clone()]);
+        let contents = std::fs::read_to_string(&ass).unwrap();
+        assert!(contents.contains("Dialogue: 0,0:00:01.00,0:00:13.00,Danmu,,0,0,0,,"));
+        assert!(contents.ends_with("}hello\n"));
+    }
+}
@@ -0,0 +1,397 @@
+//! Native danmu layout and ASS/SRT writers.
+//!
+//! Scrolling comments move right-to-left across the screen in lanes; a
+//! comment takes the first lane where it neither overlaps the previous
+//! comment's tail nor catches up with it before that one leaves the screen.
+//! Top and bottom comments stay centred for a fixed time in their own lanes.
+//! Comments that find no free lane are dropped unless overlap is allowed.
+
+use std::fmt::Write as _;
+
+use super::super::danmu_xml::{DanmuEntry, DanmuKind};
+use super::DanmuSubtitleConfig;
+
+/// Placement of one comment on screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Placement {
+    Scroll,
+    Top,
+    Bottom,
+}
+
+/// A comment with its lane and display interval.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlacedDanmu {
+    pub start: f64,
+    pub end: f64,
+    pub lane: usize,
+    pub placement: Placement,
+    /// Estimated rendered width, in pixels.
+    pub width: f64,
+    pub text: String,
+    pub color: u32,
+}
+
+/// Result of laying out a danmu file.
+#[derive(Debug, Default)]
+pub struct Layout {
+    pub placed: Vec<PlacedDanmu>,
+    /// Comments dropped for lack of a free lane.
+    pub dropped: usize,
+}
+
+/// Estimated width of `text` at `font_size`: full width for CJK and other
+/// wide characters, a bit over half for the rest.
+pub fn text_width(text: &str, font_size: f64) -> f64 {
+    text.chars()
+        .map(|c| {
+            if c.is_ascii() {
+                font_size * 0.55
+            } else {
+                font_size
+            }
+        })
+        .sum()
+}
+
+/// Last comment in a scrolling lane.
+#[derive(Clone, Copy)]
+struct ScrollTail {
+    start: f64,
+    width: f64,
+    speed: f64,
+}
+
+fn lane_count(area_height: f64, lane_height: f64) -> usize {
+    ((area_height / lane_height).floor() as usize).max(1)
+}
+
+fn display_text(entry: &DanmuEntry) -> String {
+    let text = match entry.kind {
+        DanmuKind::Comment => entry.text.clone(),
+        DanmuKind::SuperChat | DanmuKind::Gift if entry.user.is_empty() => entry.text.clone(),
+        DanmuKind::SuperChat | DanmuKind::Gift => format!("{}: {}", entry.user, entry.text),
+    };
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Lay out `entries` on screen.
+pub fn layout(entries: &[DanmuEntry], config: &DanmuSubtitleConfig) -> Layout {
+    let width = f64::from(config.width);
+    let height = f64::from(config.height);
+    let font_size = f64::from(config.font_size);
+    let lane_height = font_size + f64::from(config.line_spacing);
+    let scroll_duration = config.scroll_duration_secs.max(0.5);
+    let fixed_duration = config.fixed_duration_secs.max(0.5);
+
+    let scroll_lanes = lane_count(height * config.scroll_area.clamp(0.05, 1.0), lane_height);
+    let fixed_lanes = lane_count(height * 0.5, lane_height);
+    let mut scroll: Vec<Option<ScrollTail>> = vec![None; scroll_lanes];
+    let mut top: Vec<f64> = vec![f64::NEG_INFINITY; fixed_lanes];
+    let mut bottom: Vec<f64> = vec![f64::NEG_INFINITY; fixed_lanes];
+
+    let blocked: Vec<String> = config.blocklist.iter().map(|b| b.to_lowercase()).collect();
+    let mut out = Layout::default();
+    for entry in entries {
+        let included = match entry.kind {
+            DanmuKind::Comment => true,
+            DanmuKind::SuperChat => config.include_super_chats,
+            DanmuKind::Gift => config.include_gifts,
+        };
+        let text = display_text(entry);
+        let lowered = text.to_lowercase();
+        if !included || text.is_empty() || blocked.iter().any(|b| lowered.contains(b.as_str())) {
+            continue;
+        }
+
+        let start = entry.time_s + config.time_offset_secs;
+        if start < 0.0 {
+            continue;
+        }
+        let text_w = text_width(&text, font_size);
+        let placement = match entry.mode {
+            4 if !config.scroll_fixed => Placement::Bottom,
+            5 if !config.scroll_fixed => Placement::Top,
+            _ => Placement::Scroll,
+        };
+
+        let (lane, end) = match placement {
+            Placement::Scroll => {
+                let speed = (width + text_w) / scroll_duration;
+                let free = scroll.iter().position(|tail| match tail {
+                    None => true,
+                    Some(tail) => {
+                        // The previous tail has entered the screen with a
+                        // gap of one character...
+                        let entered = start >= tail.start + (tail.width + font_size) / tail.speed;
+                        // ...and this comment reaches the left edge only
+                        // after the previous one has left it.
+                        let no_catch_up = start + width / speed >= tail.start + scroll_duration;
+                        entered && no_catch_up
+                    }
+                });
+                let lane = match (free, config.allow_overlap) {
+                    (Some(lane), _) => lane,
+                    (None, true) => out.placed.len() % scroll_lanes,
+                    (None, false) => {
+                        out.dropped += 1;
+                        continue;
+                    }
+                };
+                scroll[lane] = Some(ScrollTail {
+                    start,
+                    width: text_w,
+                    speed,
+                });
+                (lane, start + scroll_duration)
+            }
+            Placement::Top | Placement::Bottom => {
+                let lanes = if placement == Placement::Top {
+                    &mut top
+                } else {
+                    &mut bottom
+                };
+                let lane = match (
+                    lanes.iter().position(|end| start >= *end),
+                    config.allow_overlap,
+                ) {
+                    (Some(lane), _) => lane,
+                    (None, true) => out.placed.len() % fixed_lanes,
+                    (None, false) => {
+                        out.dropped += 1;
+                        continue;
+                    }
+                };
+                lanes[lane] = start + fixed_duration;
+                (lane, start + fixed_duration)
+            }
+        };
+
+        out.placed.push(PlacedDanmu {
+            start,
+            end,
+            lane,
+            placement,
+            width: text_w,
+            text,
+            color: entry.color,
+        });
+    }
+    out
+}
+
+/// `H:MM:SS.cc` as used by ASS.
+fn ass_time(secs: f64) -> String {
+    let cs = (secs.max(0.0) * 100.0).round() as u64;
+    format!(
+        "{}:{:02}:{:02}.{:02}",
+        cs / 360_000,
+        cs / 6000 % 60,
+        cs / 100 % 60,
+        cs % 100
+    )
+}
+
+/// `HH:MM:SS,mmm` as used by SRT.
+fn srt_time(secs: f64) -> String {
+    let ms = (secs.max(0.0) * 1000.0).round() as u64;
+    format!(
+        "{:02}:{:02}:{:02},{:03}",
+        ms / 3_600_000,
+        ms / 60_000 % 60,
+        ms / 1000 % 60,
+        ms % 1000
+    )
+}
+
+/// Make comment text safe inside an ASS dialogue line: braces would open
+/// override blocks and backslashes start escapes.
+fn escape_ass(text: &str) -> String {
+    text.replace('\\', "＼")
+        .replace('{', "｛")
+        .replace('}', "｝")
+}
+
+/// Render a layout as an ASS script.
+pub fn to_ass(layout: &Layout, config: &DanmuSubtitleConfig) -> String {
+    let alpha = ((1.0 - config.opacity.clamp(0.0, 1.0)) * 255.0).round() as u8;
+    let lane_height = f64::from(config.font_size) + f64::from(config.line_spacing);
+    let width = f64::from(config.width);
+    let height = f64::from(config.height);
+
+    let mut out = String::new();
+    let _ = write!(
+        out,
+        "[Script Info]\nScriptType: v4.00+\nPlayResX: {}\nPlayResY: {}\nScaledBorderAndShadow: yes\nWrapStyle: 2\n\n",
+        config.width, config.height
+    );
+    out.push_str("[V4+ Styles]\nFormat: Name, Fontname, Fontsize, PrimaryColour, SecondaryColour, OutlineColour, BackColour, Bold, Italic, Underline, StrikeOut, ScaleX, ScaleY, Spacing, Angle, BorderStyle, Outline, Shadow, Alignment, MarginL, MarginR, MarginV, Encoding\n");
+    let _ = writeln!(
+        out,
+        "Style: Danmu,{},{},&H{alpha:02X}FFFFFF,&H{alpha:02X}FFFFFF,&H{alpha:02X}000000,&H{alpha:02X}000000,{},0,0,0,100,100,0,0,1,{},{},7,0,0,0,1",
+        config.font_name,
+        config.font_size,
+        if config.bold { -1 } else { 0 },
+        config.outline,
+        config.shadow,
+    );
+    out.push_str("\n[Events]\nFormat: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text\n");
+
+    for danmu in &layout.placed {
+        let y = danmu.lane as f64 * lane_height;
+        let position = match danmu.placement {
+            Placement::Scroll => {
+                format!("\\move({:.0},{:.0},{:.0},{:.0})", width, y, -danmu.width, y)
+            }
+            Placement::Top => format!("\\an8\\pos({:.0},{:.0})", width / 2.0, y),
+            Placement::Bottom => format!("\\an2\\pos({:.0},{:.0})", width / 2.0, height - y),
+        };
+        let color = danmu.color & 0xFF_FF_FF;
+        let color = if color == 0xFF_FF_FF {
+            String::new()
+        } else {
+            // ASS colours are BGR.
+            format!(
+                "\\c&H{:02X}{:02X}{:02X}&",
+                color & 0xFF,
+                (color >> 8) & 0xFF,
+                color >> 16
+            )
+        };
+        let _ = writeln!(
+            out,
+            "Dialogue: 0,{},{},Danmu,,0,0,0,,{{{position}{color}}}{}",
+            ass_time(danmu.start),
+            ass_time(danmu.end),
+            escape_ass(&danmu.text)
+        );
+    }
+    out
+}
+
+/// Render comments as SRT. SRT has no positioning, so at most
+/// `srt_max_lines` comments are on screen at once, each for the fixed
+/// duration; the rest are dropped.
+pub fn to_srt(entries: &[DanmuEntry], config: &DanmuSubtitleConfig) -> String {
+    let srt_config = DanmuSubtitleConfig {
+        scroll_fixed: true,
+        allow_overlap: true,
+        scroll_duration_secs: config.fixed_duration_secs,
+        ..config.clone()
+    };
+    let mut lanes: Vec<f64> = vec![f64::NEG_INFINITY; config.srt_max_lines.max(1)];
+    let mut out = String::new();
+    let mut index = 0;
+    for danmu in layout(entries, &srt_config).placed {
+        let Some(lane) = lanes.iter().position(|end| danmu.start >= *end) else {
+            continue;
+        };
+        let end = danmu.start + config.fixed_duration_secs;
+        lanes[lane] = end;
+        index += 1;
+        let _ = write!(
+            out,
+            "{index}\n{} --> {}\n{}\n\n",
+            srt_time(danmu.start),
+            srt_time(end),
+            danmu.text
+        );
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(time_s: f64, mode: u8, text: &str) -> DanmuEntry {
+        DanmuEntry {
+            time_s,
+            kind: DanmuKind::Comment,
+            text: text.to_string(),
+            user: String::new(),
+            mode,
+            color: 0xFFFFFF,
+        }
+    }
+
+    fn config() -> DanmuSubtitleConfig {
+        DanmuSubtitleConfig {
+            width: 1000,
+            height: 200,
+            font_size: 40,
+            line_spacing: 0,
+            scroll_area: 1.0,
+            scroll_duration_secs: 10.0,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn scrolling_comments_take_free_lanes_or_are_dropped() {
+        // 200px / 40px = 5 lanes.
+        let entries: Vec<DanmuEntry> = (0..7).map(|_| entry(1.0, 1, "hello")).collect();
+        let layout = layout(&entries, &config());
+        let lanes: Vec<usize> = layout.placed.iter().map(|d| d.lane).collect();
+        assert_eq!(lanes, vec![0, 1, 2, 3, 4]);
+        assert_eq!(layout.dropped, 2);
+
+        // Once the first comment has scrolled far enough, its lane is reused.
+        let entries = vec![entry(0.0, 1, "hi"), entry(9.5, 1, "again")];
+        let layout = super::layout(&entries, &config());
+        assert_eq!(layout.placed[1].lane, 0);
+    }
+
+    #[test]
+    fn fixed_comments_use_top_and_bottom_lanes() {
+        let entries = vec![
+            entry(0.0, 5, "top"),
+            entry(0.0, 5, "top2"),
+            entry(0.0, 4, "bottom"),
+        ];
+        let layout = layout(&entries, &config());
+        let placed: Vec<(Placement, usize)> = layout
+            .placed
+            .iter()
+            .map(|d| (d.placement, d.lane))
+            .collect();
+        assert_eq!(
+            placed,
+            vec![
+                (Placement::Top, 0),
+                (Placement::Top, 1),
+                (Placement::Bottom, 0)
+            ]
+        );
+    }
+
+    #[test]
+    fn writes_ass_dialogue_with_motion_and_colour() {
+        let mut red = entry(61.25, 1, "a{b}");
+        red.color = 0xFF0000;
+        let layout = layout(&[red], &config());
+        let ass = to_ass(&layout, &config());
+        assert!(ass.contains("PlayResX: 1000\nPlayResY: 200"));
+        assert!(ass.contains(
+            "Dialogue: 0,0:01:01.25,0:01:11.25,Danmu,,0,0,0,,{\\move(1000,0,-88,0)\\c&H0000FF&}a｛b｝\n"
+        ));
+    }
+
+    #[test]
+    fn writes_srt_with_limited_lines() {
+        let config = DanmuSubtitleConfig {
+            srt_max_lines: 1,
+            fixed_duration_secs: 2.0,
+            ..config()
+        };
+        let entries = vec![
+            entry(0.0, 1, "one"),
+            entry(1.0, 1, "dropped"),
+            entry(3.0, 1, "two"),
+        ];
+        assert_eq!(
+            to_srt(&entries, &config),
+            "1\n00:00:00,000 --> 00:00:02,000\none\n\n2\n00:00:03,000 --> 00:00:05,000\ntwo\n\n"
+        );
+    }
+}
@@ -0,0 +1,332 @@
+//! Reader for the danmu XML files written during recording.
+//!
+//! Parses the Bilibili-style `<d p="...">` comments plus the `<sc>` (super
+//! chat) and `<gift>` nodes emitted by the danmu writer, pairs danmu files
+//! with the videos they belong to, and finds bursts of activity in them.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use platforms_parser::danmaku::{BurstDetectionConfig, BurstDetector};
+use quick_xml::Reader;
+use quick_xml::events::{BytesStart, Event};
+
+use crate::Result;
+
+/// Kind of a danmu entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DanmuKind {
+    /// Regular comment (`<d>`).
+    Comment,
+    /// Super chat (`<sc>`).
+    SuperChat,
+    /// Gift (`<gift>`).
+    Gift,
+}
+
+/// One entry of a danmu XML file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DanmuEntry {
+    /// Offset from the start of the recording, in seconds.
+    pub time_s: f64,
+    pub kind: DanmuKind,
+    /// Comment or super chat text; `"<gift> x<count>"` for gifts.
+    pub text: String,
+    pub user: String,
+    /// Display mode of comments (1 scroll, 4 bottom, 5 top).
+    pub mode: u8,
+    /// Decimal RGB color.
+    pub color: u32,
+}
+
+/// Read all entries of a danmu XML file, sorted by time.
+pub async fn read_danmu_xml(path: &Path) -> Result<Vec<DanmuEntry>> {
+    let text = tokio::fs::read_to_string(path)
+        .await
+        .map_err(|e| crate::Error::io_path("reading danmu XML", path, e))?;
+    parse_danmu_xml(&text).map_err(|e| {
+        crate::Error::PipelineError(format!("Invalid danmu XML {}: {e}", path.display()))
+    })
+}
+
+/// Parse the contents of a danmu XML file.
+///
+/// A file cut short by a crash still yields the entries before the cut.
+pub fn parse_danmu_xml(xml: &str) -> std::result::Result<Vec<DanmuEntry>, quick_xml::Error> {
+    let mut reader = Reader::from_str(xml);
+    let mut entries = Vec::new();
+    let mut open: Option<DanmuEntry> = None;
+    let mut text = String::new();
+
+    loop {
+        let event = match reader.read_event() {
+            Ok(event) => event,
+            Err(_) if !entries.is_empty() => break,
+            Err(e) => return Err(e),
+        };
+        match event {
+            Event::Start(e) => {
+                open = entry_from_element(&e);
+                text.clear();
+            }
+            Event::Empty(e) => {
+                if let Some(entry) = entry_from_element(&e) {
+                    entries.push(entry);
+                }
+            }
+            Event::Text(t) if open.is_some() => text.push_str(&t.decode()?),
+            Event::CData(t) if open.is_some() => text.push_str(&t.decode()?),
+            Event::GeneralRef(r) if open.is_some() => {
+                if let Some(ch) = r.resolve_char_ref()? {
+                    text.push(ch);
+                } else if let Some(resolved) =
+                    quick_xml::escape::resolve_predefined_entity(&r.decode()?)
+                {
+                    text.push_str(resolved);
+                }
+            }
+            Event::End(_) => {
+                if let Some(mut entry) = open.take() {
+                    if entry.kind != DanmuKind::Gift {
+                        entry.text = text.trim().to_string();
+                    }
+                    entries.push(entry);
+                }
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    entries.sort_by(|a, b| a.time_s.total_cmp(&b.time_s));
+    Ok(entries)
+}
+
+fn attribute(e: &BytesStart<'_>, name: &str) -> Option<String> {
+    let attr = e.try_get_attribute(name).ok()??;
+    attr.normalized_value(quick_xml::XmlVersion::Implicit1_0)
+        .ok()
+        .map(|v| v.into_owned())
+}
+
+fn entry_from_element(e: &BytesStart<'_>) -> Option<DanmuEntry> {
+    let user = attribute(e, "user").unwrap_or_default();
+    match e.name().as_ref() {
+        b"d" => {
+            // p="{time},{mode},{size},{color},..."
+            let p = attribute(e, "p")?;
+            let mut fields = p.split(',');
+            let time_s = fields.next()?.trim().parse().ok()?;
+            let mode = fields
+                .next()
+                .and_then(|f| f.trim().parse().ok())
+                .unwrap_or(1);
+            let color = fields
+                .nth(1)
+                .and_then(|f| f.trim().parse().ok())
+                .unwrap_or(0xFF_FF_FF);
+            Some(DanmuEntry {
+                time_s,
+                kind: DanmuKind::Comment,
+                text: String::new(),
+                user,
+                mode,
+                color,
+            })
+        }
+        b"sc" => Some(DanmuEntry {
+            time_s: attribute(e, "ts")?.trim().parse().ok()?,
+            kind: DanmuKind::SuperChat,
+            text: String::new(),
+            user,
+            mode: 1,
+            color: 0xFF_FF_FF,
+        }),
+        b"gift" => {
+            let name = attribute(e, "giftname").unwrap_or_default();
+            let count = attribute(e, "giftcount").unwrap_or_else(|| "1".to_string());
+            Some(DanmuEntry {
+                time_s: attribute(e, "ts")?.trim().parse().ok()?,
+                kind: DanmuKind::Gift,
+                text: format!("{name} x{count}"),
+                user,
+                mode: 1,
+                color: 0xFF_FF_FF,
+            })
+        }
+        _ => None,
+    }
+}
+
+/// A burst of danmu activity, in seconds from the start of the recording.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DanmuBurst {
+    pub start_secs: f64,
+    pub end_secs: f64,
+    /// Highest window rate during the burst, in weighted messages per second.
+    pub peak_rate: f64,
+}
+
+/// Find bursts in `(offset, weight)` samples with the detector the live danmu
+/// statistics use, ordered by start.
+pub fn detect_bursts(
+    samples: impl IntoIterator<Item = (f64, u64)>,
+    config: BurstDetectionConfig,
+) -> Vec<DanmuBurst> {
+    let mut samples: Vec<(f64, u64)> = samples
+        .into_iter()
+        .filter(|(offset, weight)| *offset >= 0.0 && *weight > 0)
+        .collect();
+    samples.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    // The detector works on timestamps; offsets are placed after the epoch.
+    let mut detector = BurstDetector::new(config);
+    for (offset, weight) in samples {
+        let at =
+            DateTime::<Utc>::UNIX_EPOCH + chrono::Duration::milliseconds((offset * 1000.0) as i64);
+        detector.record_weighted(at, weight);
+    }
+    let offset = |at: DateTime<Utc>| at.timestamp_millis() as f64 / 1000.0;
+    let mut bursts: Vec<DanmuBurst> = detector
+        .finish()
+        .into_iter()
+        .map(|burst| DanmuBurst {
+            start_secs: offset(burst.start),
+            end_secs: offset(burst.end),
+            peak_rate: burst.peak_rate,
+        })
+        .collect();
+    bursts.sort_by(|a, b| a.start_secs.total_cmp(&b.start_secs));
+    bursts
+}
+
+/// Whether `path` looks like a danmu XML file.
+pub fn is_danmu_xml(path: &str) -> bool {
+    path.to_ascii_lowercase().ends_with(".xml")
+}
+
+/// Pair each video in `inputs` with its danmu XML file.
+///
+/// Uses the `video_inputs`/`danmu_inputs` lists of a `*_inputs.json`
+/// manifest when one is present, then falls back to matching file stems
+/// (`seg.flv` with `seg.xml`), and finally pairs a lone video with a lone
+/// XML file.
+pub async fn pair_videos_with_danmu(inputs: &[String]) -> Vec<(String, String)> {
+    let videos: Vec<&String> = inputs
+        .iter()
+        .filter(|p| super::utils::get_extension(p).is_some_and(|ext| super::utils::is_video(&ext)))
+        .collect();
+    let xmls: Vec<&String> = inputs.iter().filter(|p| is_danmu_xml(p)).collect();
+
+    if let Some(pairs) = manifest_pairs(inputs).await {
+        return pairs;
+    }
+
+    let stem = |p: &str| {
+        Path::new(p)
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_lowercase())
+            .unwrap_or_default()
+    };
+    let xml_by_stem: HashMap<String, &String> = xmls.iter().map(|x| (stem(x), *x)).collect();
+    let mut pairs: Vec<(String, String)> = videos
+        .iter()
+        .filter_map(|v| {
+            xml_by_stem
+                .get(&stem(v))
+                .map(|x| ((*v).clone(), (*x).clone()))
+        })
+        .collect();
+
+    if pairs.is_empty() && videos.len() == 1 && xmls.len() == 1 {
+        pairs.push((videos[0].clone(), xmls[0].clone()));
+    }
+    pairs
+}
+
+async fn manifest_pairs(inputs: &[String]) -> Option<Vec<(String, String)>> {
+    let manifest = inputs
+        .iter()
+        .find(|p| p.to_ascii_lowercase().ends_with(".json"))?;
+    let text = tokio::fs::read_to_string(manifest).await.ok()?;
+    let value: serde_json::Value = serde_json::from_str(&text).ok()?;
+    let list = |key: &str| -> Option<Vec<String>> {
+        Some(
+            value
+                .get(key)?
+                .as_array()?
+                .iter()
+                .filter_map(|v| v.as_str().map(str::to_string))
+                .collect(),
+        )
+    };
+    let videos = list("video_inputs")?;
+    let danmus = list("danmu_inputs")?;
+    let pairs: Vec<(String, String)> = videos.into_iter().zip(danmus).collect();
+    (!pairs.is_empty()).then_some(pairs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_comments_super_chats_and_gifts() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<i>
+  <d p="12.500,5,25,16711680,1700000000000,0,abc,1" user="alice">hi &amp; &#x4F60;好</d>
+  <sc ts="3.000" user="bob" uid="1" price="30" time="60" timestamp="1">thanks</sc>
+  <gift ts="7.250" giftname="rocket" giftcount="2" price="100" user="carol" uid="2" timestamp="1"></gift>
+  <d p="20.000,1,25,16777215,1,0,x,2" user="dave">cut"#;
+
+        let entries = parse_danmu_xml(xml).unwrap();
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[0].kind, DanmuKind::SuperChat);
+        assert_eq!(entries[0].text, "thanks");
+        assert_eq!(entries[1].kind, DanmuKind::Gift);
+        assert_eq!(entries[1].text, "rocket x2");
+        assert_eq!(entries[2].time_s, 12.5);
+        assert_eq!(entries[2].text, "hi & 你好");
+        assert_eq!(entries[2].mode, 5);
+        assert_eq!(entries[2].color, 0xFF0000);
+        assert_eq!(entries[2].user, "alice");
+    }
+
+    #[test]
+    fn detects_bursts_at_recording_offsets() {
+        let samples = (0..600)
+            .map(|i| (f64::from(i), 1))
+            .chain((0..100).map(|i| (300.0 + f64::from(i) * 0.1, 1)));
+        let config = BurstDetectionConfig {
+            window_secs: 10,
+            ..Default::default()
+        };
+
+        let bursts = detect_bursts(samples, config);
+        assert_eq!(bursts.len(), 1);
+        // A burst opens at the oldest second of the window that crossed
+        // the threshold.
+        assert!((290.0..=300.0).contains(&bursts[0].start_secs));
+        assert!((310.0..=320.0).contains(&bursts[0].end_secs));
+    }
+
+    #[tokio::test]
+    async fn pairs_by_stem_then_single_files() {
+        let inputs = vec![
+            "/rec/a.flv".to_string(),
+            "/rec/b.flv".to_string(),
+            "/rec/b.xml".to_string(),
+        ];
+        assert_eq!(
+            pair_videos_with_danmu(&inputs).await,
+            vec![("/rec/b.flv".to_string(), "/rec/b.xml".to_string())]
+        );
+
+        let inputs = vec!["/rec/video.mp4".to_string(), "/rec/danmu.xml".to_string()];
+        assert_eq!(
+            pair_videos_with_danmu(&inputs).await,
+            vec![("/rec/video.mp4".to_string(), "/rec/danmu.xml".to_string())]
+        );
+    }
+}
@@ -0,0 +1,915 @@
+//! Encryption processor.
+//!
+//! Encrypts step inputs before they leave the machine, so recordings can be
+//! uploaded to or retained on storage that is not trusted with plaintext.
+//! Each input produces an encrypted artifact and a JSON manifest next to it
+//! (`<file>.enc` + `<file>.enc.manifest.json`, or `.age` for age); later
+//! steps receive only those, never the plaintext.
+//!
+//! Two methods are supported:
+//! - `aes-gcm`: built-in AES-256-GCM with a 32-byte key read from an
+//!   environment variable or key file. The file is sealed in fixed-size
+//!   chunks (see [`SealedHeader`]) so large recordings are encrypted in
+//!   bounded memory and truncation is detected on decryption.
+//!   [`decrypt_artifact`] reverses it; the `rust-srec-decrypt` binary wraps
+//!   it for the command line.
+//! - `age`: runs the `age` CLI against one or more public recipients, for
+//!   users who already manage age or SSH keys. `age --decrypt` reverses it.
+
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+
+use aes_gcm::aead::{Aead, Payload};
+use aes_gcm::{Aes256Gcm, KeyInit};
+use async_trait::async_trait;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tokio::process::Command;
+use tokio_util::sync::CancellationToken;
+use tracing::warn;
+
+use super::traits::{Processor, ProcessorContext, ProcessorInput, ProcessorOutput, ProcessorType};
+use super::utils::{run_command_with_logs, tmp_output_path};
+use crate::Result;
+
+/// Environment variable holding the AES key when `key_env` is not set.
+const DEFAULT_KEY_ENV: &str = "SREC_ENCRYPTION_KEY";
+
+/// Magic bytes opening every `aes-gcm` artifact.
+const SEALED_MAGIC: &[u8; 8] = b"SRECAEAD";
+const SEALED_VERSION: u8 = 1;
+
+/// Length of the AES-GCM tag closing every sealed chunk.
+const TAG_LEN: usize = 16;
+
+/// Encryption method.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum EncryptionMethod {
+    #[default]
+    AesGcm,
+    Age,
+}
+
+impl EncryptionMethod {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::AesGcm => "aes-gcm",
+            Self::Age => "age",
+        }
+    }
+
+    fn extension(self) -> &'static str {
+        match self {
+            Self::AesGcm => "enc",
+            Self::Age => "age",
+        }
+    }
+}
+
+/// Configuration for the encryption processor.
+///
+/// Key material is never stored in the config: `aes-gcm` reads it from
+/// `key_file` or the `key_env` environment variable, and `age` only needs
+/// public recipients.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EncryptConfig {
+    pub method: EncryptionMethod,
+
+    /// Environment variable holding the base64 or hex encoded 32-byte key
+    /// (`aes-gcm`). Defaults to `SREC_ENCRYPTION_KEY`.
+    pub key_env: Option<String>,
+
+    /// File holding the encoded key, used instead of `key_env` (`aes-gcm`).
+    pub key_file: Option<String>,
+
+    /// Plaintext size of each sealed chunk in KiB (`aes-gcm`).
+    pub chunk_size_kb: u32,
+
+    /// age recipients (`age1...` or SSH public keys).
+    pub recipients: Vec<String>,
+
+    /// File with one age recipient per line, passed as `-R`.
+    pub recipients_file: Option<String>,
+
+    /// Path to the `age` binary. Falls back to `AGE_PATH`, then `age`.
+    pub age_path: Option<String>,
+
+    /// Pass the manifests on to later steps along with the artifacts.
+    pub manifest_in_outputs: bool,
+
+    /// Delete the plaintext input once its artifact and manifest are written.
+    pub delete_source: bool,
+}
+
+impl Default for EncryptConfig {
+    fn default() -> Self {
+        Self {
+            method: EncryptionMethod::AesGcm,
+            key_env: None,
+            key_file: None,
+            chunk_size_kb: 1024,
+            recipients: Vec::new(),
+            recipients_file: None,
+            age_path: None,
+            manifest_in_outputs: true,
+            delete_source: false,
+        }
+    }
+}
+
+/// Manifest written next to each encrypted artifact.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EncryptionManifest {
+    pub version: u32,
+    pub method: String,
+    /// File name of the plaintext input.
+    pub source_name: String,
+    pub source_size_bytes: u64,
+    /// SHA-256 of the plaintext, to verify a decrypted copy.
+    pub source_sha256: String,
+    /// File name of the encrypted artifact.
+    pub encrypted_name: String,
+    pub encrypted_size_bytes: u64,
+    /// Chunk size of an `aes-gcm` artifact.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chunk_size: Option<u32>,
+    /// First 8 bytes of the key's SHA-256, identifying the `aes-gcm` key
+    /// without revealing it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key_fingerprint: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub recipients: Vec<String>,
+    pub created_at: String,
+}
+
+/// Header of an `aes-gcm` artifact:
+/// `SRECAEAD | version (u8) | chunk size (u32 BE) | nonce prefix (7 bytes)`.
+///
+/// The plaintext follows as chunks of `chunk_size` bytes, each sealed with
+/// nonce `prefix | chunk index (u32 BE) | last flag (u8)` and the header as
+/// associated data. The last chunk (possibly empty) carries flag 1, so a
+/// truncated or extended artifact fails to decrypt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct SealedHeader {
+    chunk_size: u32,
+    nonce_prefix: [u8; 7],
+}
+
+impl SealedHeader {
+    const LEN: usize = 8 + 1 + 4 + 7;
+
+    fn to_bytes(self) -> [u8; Self::LEN] {
+        let mut bytes = [0u8; Self::LEN];
+        bytes[..8].copy_from_slice(SEALED_MAGIC);
+        bytes[8] = SEALED_VERSION;
+        bytes[9..13].copy_from_slice(&self.chunk_size.to_be_bytes());
+        bytes[13..].copy_from_slice(&self.nonce_prefix);
+        bytes
+    }
+
+    fn parse(bytes: &[u8; Self::LEN]) -> Option<Self> {
+        if &bytes[..8] != SEALED_MAGIC || bytes[8] != SEALED_VERSION {
+            return None;
+        }
+        let chunk_size = u32::from_be_bytes(bytes[9..13].try_into().ok()?);
+        let nonce_prefix = bytes[13..].try_into().ok()?;
+        (chunk_size > 0).then_some(Self {
+            chunk_size,
+            nonce_prefix,
+        })
+    }
+
+    fn nonce(&self, index: u32, last: bool) -> [u8; 12] {
+        let mut nonce = [0u8; 12];
+        nonce[..7].copy_from_slice(&self.nonce_prefix);
+        nonce[7..11].copy_from_slice(&index.to_be_bytes());
+        nonce[11] = u8::from(last);
+        nonce
+    }
+}
+
+/// Decode a 32-byte key given as 64 hex characters or base64.
+fn parse_key(encoded: &str) -> Result<[u8; 32]> {
+    let encoded = encoded.trim();
+    let bytes = if encoded.len() == 64 && encoded.chars().all(|c| c.is_ascii_hexdigit()) {
+        hex::decode(encoded).ok()
+    } else {
+        base64::engine::general_purpose::STANDARD
+            .decode(encoded)
+            .ok()
+    };
+    bytes
+        .and_then(|b| <[u8; 32]>::try_from(b).ok())
+        .ok_or_else(|| {
+            crate::Error::Validation(
+                "Encryption key must be 32 bytes, encoded as 64 hex characters or base64"
+                    .to_string(),
+            )
+        })
+}
+
+fn load_key(config: &EncryptConfig) -> Result<[u8; 32]> {
+    load_encryption_key(config.key_file.as_deref(), config.key_env.as_deref())
+}
+
+/// Read the `aes-gcm` key from `key_file` if set, otherwise from the
+/// `key_env` environment variable (default `SREC_ENCRYPTION_KEY`).
+pub fn load_encryption_key(key_file: Option<&str>, key_env: Option<&str>) -> Result<[u8; 32]> {
+    if let Some(path) = key_file.filter(|p| !p.trim().is_empty()) {
+        let encoded = std::fs::read_to_string(path).map_err(|e| {
+            crate::Error::io_path("reading encryption key file", Path::new(path), e)
+        })?;
+        return parse_key(&encoded);
+    }
+    let var = key_env
+        .filter(|v| !v.trim().is_empty())
+        .unwrap_or(DEFAULT_KEY_ENV);
+    match std::env::var(var) {
+        Ok(encoded) if !encoded.trim().is_empty() => parse_key(&encoded),
+        _ => Err(crate::Error::Validation(format!(
+            "Encryption key is missing: set key_file or the {var} environment variable"
+        ))),
+    }
+}
+
+fn key_fingerprint(key: &[u8; 32]) -> String {
+    hex::encode(&Sha256::digest(key)[..8])
+}
+
+/// Plaintext size and SHA-256 of an encrypted file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SealedSource {
+    pub size_bytes: u64,
+    pub sha256: String,
+}
+
+/// Seal `source` into `dest` as described on [`SealedHeader`]. Blocking;
+/// checks `cancel` between chunks.
+fn seal_file(
+    key: &[u8; 32],
+    chunk_size: u32,
+    source: &Path,
+    dest: &Path,
+    cancel: &CancellationToken,
+) -> std::io::Result<SealedSource> {
+    let cipher = Aes256Gcm::new_from_slice(key).map_err(std::io::Error::other)?;
+    let mut nonce_prefix = [0u8; 7];
+    rand::fill(&mut nonce_prefix);
+    let header = SealedHeader {
+        chunk_size,
+        nonce_prefix,
+    };
+    let aad = header.to_bytes();
+
+    let mut reader = std::fs::File::open(source)?;
+    let size_bytes = reader.metadata()?.len();
+    let chunk_len = chunk_size as u64;
+    let chunk_count = size_bytes.div_ceil(chunk_len).max(1);
+    if chunk_count > u64::from(u32::MAX) {
+        return Err(std::io::Error::other("file has too many chunks"));
+    }
+
+    let mut writer = std::io::BufWriter::new(std::fs::File::create(dest)?);
+    writer.write_all(&aad)?;
+    let mut hasher = Sha256::new();
+    let mut buffer = vec![0u8; chunk_size as usize];
+    for index in 0..chunk_count {
+        if cancel.is_cancelled() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Interrupted,
+                "encryption cancelled",
+            ));
+        }
+        let last = index + 1 == chunk_count;
+        let len = if last {
+            (size_bytes - index * chunk_len) as usize
+        } else {
+            chunk_size as usize
+        };
+        let plaintext = &mut buffer[..len];
+        reader.read_exact(plaintext)?;
+        hasher.update(&*plaintext);
+
+        let nonce = header.nonce(index as u32, last);
+        let sealed = cipher
+            .encrypt(
+                (&nonce).into(),
+                Payload {
+                    msg: plaintext,
+                    aad: &aad,
+                },
+            )
+            .map_err(|_| std::io::Error::other("AES-GCM encryption failed"))?;
+        writer.write_all(&sealed)?;
+    }
+    writer
+        .into_inner()
+        .map_err(|e| e.into_error())?
+        .sync_all()?;
+
+    Ok(SealedSource {
+        size_bytes,
+        sha256: hex::encode(hasher.finalize()),
+    })
+}
+
+/// Fill `buffer` from `reader`, stopping early only at the end of the input.
+fn read_full(reader: &mut impl Read, buffer: &mut [u8]) -> std::io::Result<usize> {
+    let mut filled = 0;
+    while filled < buffer.len() {
+        match reader.read(&mut buffer[filled..]) {
+            Ok(0) => break,
+            Ok(n) => filled += n,
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(filled)
+}
+
+fn invalid_artifact(message: &str) -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::InvalidData, message.to_string())
+}
+
+/// Open a file sealed by [`seal_file`] into `dest`. Blocking. Fails when the
+/// key is wrong or the artifact was modified, truncated or extended.
+fn open_sealed_file(key: &[u8; 32], source: &Path, dest: &Path) -> std::io::Result<SealedSource> {
+    let mut reader = std::io::BufReader::new(std::fs::File::open(source)?);
+    let mut aad = [0u8; SealedHeader::LEN];
+    if read_full(&mut reader, &mut aad)? < SealedHeader::LEN {
+        return Err(invalid_artifact("not an aes-gcm artifact"));
+    }
+    let header =
+        SealedHeader::parse(&aad).ok_or_else(|| invalid_artifact("not an aes-gcm artifact"))?;
+    let cipher = Aes256Gcm::new_from_slice(key).map_err(std::io::Error::other)?;
+
+    let sealed_len = header.chunk_size as usize + TAG_LEN;
+    let mut current = vec![0u8; sealed_len];
+    let mut next = vec![0u8; sealed_len];
+    let mut current_len = read_full(&mut reader, &mut current)?;
+    let mut writer = std::io::BufWriter::new(std::fs::File::create(dest)?);
+    let mut hasher = Sha256::new();
+    let mut size_bytes = 0u64;
+    for index in 0..=u32::MAX {
+        // A short chunk is the last one; a full one is last when nothing
+        // follows it.
+        let next_len = if current_len == sealed_len {
+            read_full(&mut reader, &mut next)?
+        } else {
+            0
+        };
+        let last = next_len == 0;
+        let nonce = header.nonce(index, last);
+        let plaintext = cipher
+            .decrypt(
+                (&nonce).into(),
+                Payload {
+                    msg: &current[..current_len],
+                    aad: &aad,
+                },
+            )
+            .map_err(|_| {
+                invalid_artifact("decryption failed: wrong key or modified or truncated artifact")
+            })?;
+        hasher.update(&plaintext);
+        size_bytes += plaintext.len() as u64;
+        writer.write_all(&plaintext)?;
+        if last {
+            writer
+                .into_inner()
+                .map_err(|e| e.into_error())?
+                .sync_all()?;
+            return Ok(SealedSource {
+                size_bytes,
+                sha256: hex::encode(hasher.finalize()),
+            });
+        }
+        std::mem::swap(&mut current, &mut next);
+        current_len = next_len;
+    }
+    Err(invalid_artifact("artifact has too many chunks"))
+}
+
+/// Decrypt an `aes-gcm` artifact written by the processor into `dest`.
+///
+/// When the manifest (`<artifact>.manifest.json`) is next to the artifact,
+/// the key fingerprint is checked before decrypting and the plaintext size
+/// and SHA-256 after. `dest` is removed again when any check fails.
+/// Blocking.
+pub fn decrypt_artifact(key: &[u8; 32], artifact: &Path, dest: &Path) -> Result<SealedSource> {
+    let manifest_path = PathBuf::from(format!("{}.manifest.json", artifact.display()));
+    let manifest: Option<EncryptionManifest> = match std::fs::read(&manifest_path) {
+        Ok(bytes) => Some(serde_json::from_slice(&bytes)?),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+        Err(e) => return Err(crate::Error::io_path("reading manifest", &manifest_path, e)),
+    };
+    if let Some(manifest) = &manifest {
+        if manifest.method != EncryptionMethod::AesGcm.as_str() {
+            return Err(crate::Error::Validation(format!(
+                "{} was encrypted with {}, not aes-gcm",
+                artifact.display(),
+                manifest.method
+            )));
+        }
+        if manifest
+            .key_fingerprint
+            .as_deref()
+            .is_some_and(|fingerprint| fingerprint != key_fingerprint(key))
+        {
+            return Err(crate::Error::Validation(format!(
+                "{} was encrypted with a different key",
+                artifact.display()
+            )));
+        }
+    }
+
+    let opened = open_sealed_file(key, artifact, dest)
+        .map_err(|e| crate::Error::io_path("decrypting", artifact, e))
+        .and_then(|opened| match &manifest {
+            Some(manifest)
+                if manifest.source_size_bytes != opened.size_bytes
+                    || manifest.source_sha256 != opened.sha256 =>
+            {
+                Err(crate::Error::Validation(format!(
+                    "Decrypted {} does not match the SHA-256 in its manifest",
+                    artifact.display()
+                )))
+            }
+            _ => Ok(opened),
+        });
+    if opened.is_err() {
+        let _ = std::fs::remove_file(dest);
+    }
+    opened
+}
+
+/// Plaintext size and SHA-256 of a file age has encrypted.
+async fn age_source(source: &Path) -> Result<SealedSource> {
+    let size_bytes = tokio::fs::metadata(source)
+        .await
+        .map_err(|e| crate::Error::io_path("reading metadata of", source, e))?
+        .len();
+    let sha256 = super::utils::sha256_file(source)
+        .await
+        .map_err(|e| crate::Error::io_path("hashing", source, e))?;
+    Ok(SealedSource { size_bytes, sha256 })
+}
+
+fn age_path(config: &EncryptConfig) -> String {
+    config
+        .age_path
+        .clone()
+        .filter(|p| !p.trim().is_empty())
+        .or_else(|| std::env::var("AGE_PATH").ok())
+        .unwrap_or_else(|| "age".to_string())
+}
+
+fn age_args(config: &EncryptConfig, source: &Path, dest: &Path) -> Vec<String> {
+    let mut args = vec!["--encrypt".to_string()];
+    for recipient in config.recipients.iter().filter(|r| !r.trim().is_empty()) {
+        args.push("-r".to_string());
+        args.push(recipient.trim().to_string());
+    }
+    if let Some(file) = config
+        .recipients_file
+        .as_deref()
+        .filter(|f| !f.trim().is_empty())
+    {
+        args.push("-R".to_string());
+        args.push(file.to_string());
+    }
+    args.push("-o".to_string());
+    args.push(dest.to_string_lossy().into_owned());
+    args.push(source.to_string_lossy().into_owned());
+    args
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+fn is_encrypted_artifact(path: &str) -> bool {
+    let lower = path.to_ascii_lowercase();
+    lower.ends_with(".enc") || lower.ends_with(".age") || lower.ends_with(".manifest.json")
+}
+
+/// Key material resolved once per job.
+enum Sealer {
+    AesGcm { key: [u8; 32], chunk_size: u32 },
+    Age { binary: String },
+}
+
+/// Processor that encrypts files at rest.
+pub struct EncryptProcessor;
+
+impl EncryptProcessor {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Encrypt one input into `artifact`, returning its manifest. The
+    /// artifact is written under a temporary name and renamed into place.
+    async fn encrypt_one(
+        &self,
+        sealer: &Sealer,
+        config: &EncryptConfig,
+        source: &Path,
+        artifact: &Path,
+        ctx: &ProcessorContext,
+    ) -> Result<EncryptionManifest> {
+        let tmp = tmp_output_path(artifact);
+        let sealed = match sealer {
+            Sealer::AesGcm { key, chunk_size } => {
+                let (key, chunk_size) = (*key, *chunk_size);
+                let (source_path, tmp_path) = (source.to_path_buf(), tmp.clone());
+                let cancel = ctx.cancellation_token.clone();
+                tokio::task::spawn_blocking(move || {
+                    seal_file(&key, chunk_size, &source_path, &tmp_path, &cancel)
+                })
+                .await
+                .map_err(|e| crate::Error::Other(format!("Encryption task failed: {e}")))?
+                .map_err(|e| crate::Error::io_path("encrypting", source, e))
+            }
+            Sealer::Age { binary } => {
+                let mut command = Command::new(binary);
+                command.args(age_args(config, source, &tmp));
+                let result =
+                    run_command_with_logs(&mut command, Some(ctx.log_sink.clone())).await?;
+                if result.status.success() {
+                    age_source(source).await
+                } else {
+                    let detail = result
+                        .logs
+                        .iter()
+                        .rfind(|entry| !entry.message.trim().is_empty())
+                        .map(|entry| entry.message.clone())
+                        .unwrap_or_default();
+                    Err(crate::Error::PipelineError(format!(
+                        "age exited with {}: {detail}",
+                        result.status
+                    )))
+                }
+            }
+        };
+        let sealed = match sealed {
+            Ok(sealed) => sealed,
+            Err(e) => {
+                let _ = tokio::fs::remove_file(&tmp).await;
+                return Err(e);
+            }
+        };
+
+        tokio::fs::rename(&tmp, artifact)
+            .await
+            .map_err(|e| crate::Error::io_path("renaming encrypted file", artifact, e))?;
+        let encrypted_size_bytes = tokio::fs::metadata(artifact)
+            .await
+            .map_err(|e| crate::Error::io_path("reading metadata of", artifact, e))?
+            .len();
+
+        Ok(EncryptionManifest {
+            version: 1,
+            method: config.method.as_str().to_string(),
+            source_name: file_name(source),
+            source_size_bytes: sealed.size_bytes,
+            source_sha256: sealed.sha256,
+            encrypted_name: file_name(artifact),
+            encrypted_size_bytes,
+            chunk_size: match sealer {
+                Sealer::AesGcm { chunk_size, .. } => Some(*chunk_size),
+                Sealer::Age { .. } => None,
+            },
+            key_fingerprint: match sealer {
+                Sealer::AesGcm { key, .. } => Some(key_fingerprint(key)),
+                Sealer::Age { .. } => None,
+            },
+            recipients: match sealer {
+                Sealer::AesGcm { .. } => Vec::new(),
+                Sealer::Age { .. } => config.recipients.clone(),
+            },
+            created_at: chrono::Utc::now().to_rfc3339(),
+        })
+    }
+}
+
+impl Default for EncryptProcessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Processor for EncryptProcessor {
+    fn processor_type(&self) -> ProcessorType {
+        ProcessorType::Cpu
+    }
+
+    fn job_types(&self) -> Vec<&'static str> {
+        vec!["encrypt"]
+    }
+
+    fn name(&self) -> &'static str {
+        "EncryptProcessor"
+    }
+
+    fn supports_batch_input(&self) -> bool {
+        true
+    }
+
+    async fn process(
+        &self,
+        input: &ProcessorInput,
+        ctx: &ProcessorContext,
+    ) -> Result<ProcessorOutput> {
+        let started = std::time::Instant::now();
+        let config: EncryptConfig = match input.config.as_deref() {
+            Some(s) => serde_json::from_str(s).map_err(|e| {
+                crate::Error::Validation(format!("Invalid encrypt config JSON: {e}"))
+            })?,
+            None => EncryptConfig::default(),
+        };
+        let sealer = match config.method {
+            EncryptionMethod::AesGcm => {
+                if config.chunk_size_kb == 0 || config.chunk_size_kb > 64 * 1024 {
+                    return Err(crate::Error::Validation(
+                        "chunk_size_kb must be between 1 and 65536".to_string(),
+                    ));
+                }
+                Sealer::AesGcm {
+                    key: load_key(&config)?,
+                    chunk_size: config.chunk_size_kb * 1024,
+                }
+            }
+            EncryptionMethod::Age => {
+                let has_recipients = config.recipients.iter().any(|r| !r.trim().is_empty())
+                    || config
+                        .recipients_file
+                        .as_deref()
+                        .is_some_and(|f| !f.trim().is_empty());
+                if !has_recipients {
+                    return Err(crate::Error::Validation(
+                        "age encryption needs recipients or recipients_file".to_string(),
+                    ));
+                }
+                Sealer::Age {
+                    binary: age_path(&config),
+                }
+            }
+        };
+
+        let mut output = ProcessorOutput::default();
+        let mut input_size_bytes = 0u64;
+        let mut output_size_bytes = 0u64;
+        for path in &input.inputs {
+            if is_encrypted_artifact(path) {
+                output
+                    .skipped_inputs
+                    .push((path.clone(), "already encrypted".to_string()));
+                output.outputs.push(path.clone());
+                continue;
+            }
+            if ctx.cancellation_token.is_cancelled() {
+                return Err(crate::Error::PipelineError(
+                    "Encryption cancelled".to_string(),
+                ));
+            }
+
+            let source = Path::new(path);
+            let artifact = PathBuf::from(format!("{path}.{}", config.method.extension()));
+            let manifest = match self
+                .encrypt_one(&sealer, &config, source, &artifact, ctx)
+                .await
+            {
+                Ok(manifest) => manifest,
+                Err(e) => {
+                    warn!(path = %path, error = %e, "Failed to encrypt file");
+                    output.failed_inputs.push((path.clone(), e.to_string()));
+                    continue;
+                }
+            };
+
+            let manifest_path = format!("{}.manifest.json", artifact.display());
+            tokio::fs::write(&manifest_path, serde_json::to_vec_pretty(&manifest)?)
+                .await
+                .map_err(|e| {
+                    crate::Error::io_path(
+                        "writing encryption manifest",
+                        Path::new(&manifest_path),
+                        e,
+                    )
+                })?;
+
+            if config.delete_source
+                && let Err(e) = tokio::fs::remove_file(source).await
+            {
+                warn!(path = %path, error = %e, "Failed to delete plaintext after encryption");
+            }
+
+            ctx.info(format!(
+                "Encrypted {} -> {} ({})",
+                path,
+                artifact.display(),
+                manifest.method
+            ));
+            input_size_bytes += manifest.source_size_bytes;
+            output_size_bytes += manifest.encrypted_size_bytes;
+            let artifact = artifact.to_string_lossy().into_owned();
+            output.outputs.push(artifact.clone());
+            output.items_produced.push(artifact);
+            if config.manifest_in_outputs {
+                output.outputs.push(manifest_path.clone());
+            }
+            output.items_produced.push(manifest_path);
+            output.succeeded_inputs.push(path.clone());
+        }
+
+        if output.succeeded_inputs.is_empty() && !output.failed_inputs.is_empty() {
+            return Err(crate::Error::PipelineError(format!(
+                "Encryption failed for all {} input(s): {}",
+                output.failed_inputs.len(),
+                output.failed_inputs[0].1
+            )));
+        }
+
+        output.input_size_bytes = Some(input_size_bytes);
+        output.output_size_bytes = Some(output_size_bytes);
+        output.duration_secs = started.elapsed().as_secs_f64();
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Open `sealed` through [`open_sealed_file`].
+    fn open_sealed(key: &[u8; 32], sealed: &[u8]) -> Option<Vec<u8>> {
+        let dir = tempfile::tempdir().unwrap();
+        let (source, dest) = (dir.path().join("a.enc"), dir.path().join("a"));
+        std::fs::write(&source, sealed).unwrap();
+        open_sealed_file(key, &source, &dest).ok()?;
+        Some(std::fs::read(&dest).unwrap())
+    }
+
+    #[test]
+    fn parses_hex_and_base64_keys() {
+        let key = [7u8; 32];
+        assert_eq!(parse_key(&hex::encode(key)).unwrap(), key);
+        let b64 = base64::engine::general_purpose::STANDARD.encode(key);
+        assert_eq!(parse_key(&format!("{b64}\n")).unwrap(), key);
+        assert!(parse_key("c2hvcnQ=").is_err());
+    }
+
+    #[test]
+    fn sealed_file_round_trips_and_detects_truncation() {
+        let dir = tempfile::tempdir().unwrap();
+        let key = [3u8; 32];
+        let source = dir.path().join("live.flv");
+        let plaintext: Vec<u8> = (0..10_000u32).map(|i| i as u8).collect();
+        std::fs::write(&source, &plaintext).unwrap();
+        let dest = dir.path().join("live.flv.enc");
+
+        let sealed = seal_file(&key, 4096, &source, &dest, &CancellationToken::new()).unwrap();
+        assert_eq!(sealed.size_bytes, 10_000);
+        assert_eq!(sealed.sha256, hex::encode(Sha256::digest(&plaintext)));
+
+        let bytes = std::fs::read(&dest).unwrap();
+        assert_eq!(bytes.len(), SealedHeader::LEN + 10_000 + 3 * TAG_LEN);
+        assert_eq!(open_sealed(&key, &bytes).unwrap(), plaintext);
+        assert!(open_sealed(&[4u8; 32], &bytes).is_none());
+        // Dropping the final chunk leaves a non-final chunk at the end.
+        assert!(open_sealed(&key, &bytes[..SealedHeader::LEN + 2 * (4096 + TAG_LEN)]).is_none());
+    }
+
+    #[test]
+    fn empty_file_seals_to_one_final_chunk() {
+        let dir = tempfile::tempdir().unwrap();
+        let key = [1u8; 32];
+        let source = dir.path().join("empty.xml");
+        std::fs::write(&source, b"").unwrap();
+        let dest = dir.path().join("empty.xml.enc");
+
+        seal_file(&key, 4096, &source, &dest, &CancellationToken::new()).unwrap();
+        let bytes = std::fs::read(&dest).unwrap();
+        assert_eq!(bytes.len(), SealedHeader::LEN + TAG_LEN);
+        assert_eq!(open_sealed(&key, &bytes).unwrap(), Vec::<u8>::new());
+    }
+
+    #[tokio::test]
+    async fn encrypts_inputs_with_key_file_and_writes_manifests() {
+        let dir = tempfile::tempdir().unwrap();
+        let key = [9u8; 32];
+        let key_file = dir.path().join("key");
+        std::fs::write(&key_file, hex::encode(key)).unwrap();
+        let video = dir.path().join("live.flv");
+        std::fs::write(&video, b"recording").unwrap();
+        let video = video.to_string_lossy().into_owned();
+
+        let config = serde_json::json!({
+            "key_file": key_file.to_string_lossy(),
+            "delete_source": true,
+        });
+        let input = ProcessorInput::new(vec![video.clone()], vec![], "s", "x")
+            .with_config(config.to_string());
+        let output = EncryptProcessor::new()
+            .process(&input, &ProcessorContext::noop("job"))
+            .await
+            .unwrap();
+
+        let artifact = format!("{video}.enc");
+        let manifest_path = format!("{artifact}.manifest.json");
+        assert_eq!(
+            output.outputs,
+            vec![artifact.clone(), manifest_path.clone()]
+        );
+        assert!(!Path::new(&video).exists());
+        assert_eq!(
+            open_sealed(&key, &std::fs::read(&artifact).unwrap()).unwrap(),
+            b"recording"
+        );
+
+        let manifest: EncryptionManifest =
+            serde_json::from_slice(&std::fs::read(&manifest_path).unwrap()).unwrap();
+        assert_eq!(manifest.method, "aes-gcm");
+        assert_eq!(manifest.source_name, "live.flv");
+        assert_eq!(manifest.source_size_bytes, 9);
+        assert_eq!(manifest.encrypted_name, "live.flv.enc");
+        assert_eq!(manifest.key_fingerprint, Some(key_fingerprint(&key)));
+
+        let restored = dir.path().join("restored.flv");
+        let opened = decrypt_artifact(&key, Path::new(&artifact), &restored).unwrap();
+        assert_eq!(std::fs::read(&restored).unwrap(), b"recording");
+        assert_eq!(opened.sha256, manifest.source_sha256);
+    }
+
+    #[tokio::test]
+    async fn decrypt_artifact_checks_the_manifest() {
+        let dir = tempfile::tempdir().unwrap();
+        let key = [5u8; 32];
+        let video = dir.path().join("live.flv");
+        std::fs::write(&video, b"recording").unwrap();
+        let artifact = dir.path().join("live.flv.enc");
+        let manifest_path = dir.path().join("live.flv.enc.manifest.json");
+        let restored = dir.path().join("restored.flv");
+
+        let key_file = dir.path().join("key");
+        std::fs::write(&key_file, hex::encode(key)).unwrap();
+        let key_file = key_file.to_string_lossy().into_owned();
+        let input =
+            ProcessorInput::new(vec![video.to_string_lossy().into_owned()], vec![], "s", "x")
+                .with_config(serde_json::json!({ "key_file": key_file }).to_string());
+        EncryptProcessor::new()
+            .process(&input, &ProcessorContext::noop("job"))
+            .await
+            .unwrap();
+        assert_eq!(load_encryption_key(Some(&key_file), None).unwrap(), key);
+
+        assert!(matches!(
+            decrypt_artifact(&[6u8; 32], &artifact, &restored),
+            Err(crate::Error::Validation(_))
+        ));
+
+        let mut manifest: EncryptionManifest =
+            serde_json::from_slice(&std::fs::read(&manifest_path).unwrap()).unwrap();
+        manifest.source_sha256 = hex::encode([0u8; 32]);
+        std::fs::write(&manifest_path, serde_json::to_vec(&manifest).unwrap()).unwrap();
+        assert!(decrypt_artifact(&key, &artifact, &restored).is_err());
+        assert!(!restored.exists());
+
+        std::fs::remove_file(&manifest_path).unwrap();
+        decrypt_artifact(&key, &artifact, &restored).unwrap();
+        assert_eq!(std::fs::read(&restored).unwrap(), b"recording");
+    }
+
+    #[test]
+    fn builds_age_arguments() {
+        let config = EncryptConfig {
+            method: EncryptionMethod::Age,
+            recipients: vec!["age1abc".to_string(), " ".to_string()],
+            recipients_file: Some("/keys/recipients.txt".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            age_args(
+                &config,
+                Path::new("/rec/a.flv"),
+                Path::new("/rec/a.flv.age.tmp")
+            ),
+            vec![
+                "--encrypt",
+                "-r",
+                "age1abc",
+                "-R",
+                "/keys/recipients.txt",
+                "-o",
+                "/rec/a.flv.age.tmp",
+                "/rec/a.flv"
+            ]
+        );
+    }
+}
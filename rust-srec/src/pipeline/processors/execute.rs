@@ -294,8 +294,8 @@ impl Processor for ExecuteCommandProcessor {
             Ok(Err(e)) => return Err(e),
             Err(_) => {
                 ctx.error(format!("Command timed out after {}s", self.timeout_secs));
-                // run_command_with_logs enables kill_on_drop, so dropping its
-                // future here also terminates the child.
+                // Dropping the run_command_with_logs future here also kills
+                // the child and every process it started.
                 return Err(crate::Error::Other("Command timed out".to_string()));
             }
         };
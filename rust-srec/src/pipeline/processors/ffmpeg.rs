@@ -0,0 +1,486 @@
+//! Generic FFmpeg processor.
+//!
+//! Runs ffmpeg with user-supplied input options, filters and output options,
+//! for crops, scaling, re-encodes and other one-off transforms that would
+//! otherwise need the `execute` processor. Arguments are passed to ffmpeg
+//! directly (no shell), one list entry per argument.
+//!
+//! Arguments and filters support these placeholders:
+//! - `{input}` - Input file path
+//! - `{output}` - Output file path
+//! - `{input_dir}`, `{input_stem}`, `{input_ext}` - Parts of the input path
+//! - `{streamer}`, `{title}`, `{streamer_id}`, `{session_id}`, `{platform}` - Session metadata
+//!
+//! `%` sequences are left alone so filters such as `drawtext` keep their own
+//! expansions. `output_name` additionally supports the time placeholders
+//! (`%Y`, `%m`, `%d`, ...).
+
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use tokio::process::Command;
+use tracing::{debug, info, warn};
+
+use super::traits::{
+    Processor, ProcessorContext, ProcessorInput, ProcessorOutput, ProcessorType, TimeAnchor,
+};
+use super::utils::{get_extension, is_media, run_ffmpeg_with_progress};
+use crate::Result;
+use crate::pipeline::job_queue::LogLevel;
+use crate::utils::filename::expand_placeholders_at;
+
+/// Configuration for the generic FFmpeg processor.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FfmpegConfig {
+    /// Options placed before `-i`, e.g. `["-ss", "30"]`.
+    pub input_args: Vec<String>,
+
+    /// Video filtergraph passed as `-vf`, e.g. `crop=iw/2:ih:0:0,scale=1280:-2`.
+    pub video_filter: Option<String>,
+
+    /// Audio filtergraph passed as `-af`, e.g. `loudnorm`.
+    pub audio_filter: Option<String>,
+
+    /// Complex filtergraph passed as `-filter_complex`. Use `-map` in
+    /// `output_args` to select its outputs.
+    pub filter_complex: Option<String>,
+
+    /// Options placed before the output path, e.g.
+    /// `["-c:v", "libx264", "-crf", "23", "-c:a", "copy"]`.
+    pub output_args: Vec<String>,
+
+    /// Output file name without extension. Supports the session and time
+    /// placeholders and `{input_stem}`.
+    pub output_name: String,
+
+    /// Output extension. Unset keeps the input extension.
+    pub output_extension: Option<String>,
+
+    /// Directory for outputs. Unset writes next to the input.
+    pub output_dir: Option<String>,
+
+    /// Overwrite an existing output file.
+    pub overwrite: bool,
+
+    /// Delete the input once the output is written.
+    pub remove_input_on_success: bool,
+
+    /// Timestamp source for time placeholders in `output_name`.
+    pub time_anchor: TimeAnchor,
+}
+
+impl Default for FfmpegConfig {
+    fn default() -> Self {
+        Self {
+            input_args: Vec::new(),
+            video_filter: None,
+            audio_filter: None,
+            filter_complex: None,
+            output_args: Vec::new(),
+            output_name: "{input_stem}_ffmpeg".to_string(),
+            output_extension: None,
+            output_dir: None,
+            overwrite: true,
+            remove_input_on_success: false,
+            time_anchor: TimeAnchor::default(),
+        }
+    }
+}
+
+/// Values substituted into the argument templates of one run.
+struct ArgContext<'a> {
+    input: &'a ProcessorInput,
+    input_path: &'a Path,
+    output_path: &'a Path,
+}
+
+impl ArgContext<'_> {
+    fn substitute(&self, template: &str) -> String {
+        let input = self.input;
+        let path_part = |part: Option<&std::ffi::OsStr>| {
+            part.map(|p| p.to_string_lossy().into_owned())
+                .unwrap_or_default()
+        };
+        template
+            .replace("{input}", &self.input_path.to_string_lossy())
+            .replace("{output}", &self.output_path.to_string_lossy())
+            .replace(
+                "{input_dir}",
+                &path_part(self.input_path.parent().map(|p| p.as_os_str())),
+            )
+            .replace("{input_stem}", &path_part(self.input_path.file_stem()))
+            .replace("{input_ext}", &path_part(self.input_path.extension()))
+            .replace(
+                "{streamer}",
+                input.streamer_name.as_deref().unwrap_or(&input.streamer_id),
+            )
+            .replace(
+                "{title}",
+                input.session_title.as_deref().unwrap_or_default(),
+            )
+            .replace("{streamer_id}", &input.streamer_id)
+            .replace("{session_id}", &input.session_id)
+            .replace("{platform}", input.platform.as_deref().unwrap_or_default())
+    }
+}
+
+/// Processor that runs a user-defined ffmpeg transform.
+pub struct FfmpegProcessor {
+    /// Path to ffmpeg binary.
+    ffmpeg_path: String,
+}
+
+impl FfmpegProcessor {
+    pub fn new() -> Self {
+        Self {
+            ffmpeg_path: std::env::var("FFMPEG_PATH").unwrap_or_else(|_| "ffmpeg".to_string()),
+        }
+    }
+
+    /// Output path for `input_path`.
+    fn output_path(input: &ProcessorInput, config: &FfmpegConfig, input_path: &Path) -> PathBuf {
+        let stem = input_path
+            .file_stem()
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "output".to_string());
+        let name = expand_placeholders_at(
+            &config.output_name.replace("{input_stem}", &stem),
+            &input.streamer_id,
+            &input.session_id,
+            input.streamer_name.as_deref(),
+            input.session_title.as_deref(),
+            input.platform.as_deref(),
+            Some(config.time_anchor.reference_time(input).timestamp_millis()),
+        );
+        let extension = config
+            .output_extension
+            .as_deref()
+            .map(|e| e.trim_start_matches('.').to_string())
+            .filter(|e| !e.is_empty())
+            .or_else(|| get_extension(&input_path.to_string_lossy()))
+            .unwrap_or_else(|| "mp4".to_string());
+        let dir = config
+            .output_dir
+            .as_deref()
+            .filter(|d| !d.is_empty())
+            .map(PathBuf::from)
+            .or_else(|| input_path.parent().map(Path::to_path_buf))
+            .unwrap_or_default();
+        dir.join(format!("{name}.{extension}"))
+    }
+
+    /// Full ffmpeg argument list for one input.
+    fn build_args(config: &FfmpegConfig, args: &ArgContext<'_>) -> Vec<String> {
+        let mut out = vec![
+            if config.overwrite { "-y" } else { "-n" }.to_string(),
+            "-hide_banner".to_string(),
+            "-nostats".to_string(),
+            "-loglevel".to_string(),
+            "warning".to_string(),
+            "-progress".to_string(),
+            "pipe:1".to_string(),
+        ];
+        out.extend(config.input_args.iter().map(|a| args.substitute(a)));
+        out.extend([
+            "-i".to_string(),
+            args.input_path.to_string_lossy().into_owned(),
+        ]);
+
+        let filters = [
+            ("-vf", &config.video_filter),
+            ("-af", &config.audio_filter),
+            ("-filter_complex", &config.filter_complex),
+        ];
+        for (flag, filter) in filters {
+            if let Some(filter) = filter.as_deref().filter(|f| !f.trim().is_empty()) {
+                out.extend([flag.to_string(), args.substitute(filter)]);
+            }
+        }
+
+        out.extend(config.output_args.iter().map(|a| args.substitute(a)));
+        out.push(args.output_path.to_string_lossy().into_owned());
+        out
+    }
+
+    async fn process_one(
+        &self,
+        input: &ProcessorInput,
+        config: &FfmpegConfig,
+        input_path: &str,
+        ctx: &ProcessorContext,
+    ) -> Result<ProcessorOutput> {
+        let path = Path::new(input_path);
+        if !path.exists() {
+            return Err(crate::Error::PipelineError(format!(
+                "Input file does not exist: {input_path}"
+            )));
+        }
+        if !get_extension(input_path).is_some_and(|ext| is_media(&ext)) {
+            info!("Input is not a media file, passing through: {input_path}");
+            return Ok(ProcessorOutput {
+                outputs: vec![input_path.to_string()],
+                skipped_inputs: vec![(input_path.to_string(), "not a media file".to_string())],
+                ..Default::default()
+            });
+        }
+
+        let output_path = Self::output_path(input, config, path);
+        if output_path == path {
+            return Err(crate::Error::Validation(format!(
+                "ffmpeg output would overwrite its input: {input_path}; change output_name or output_dir"
+            )));
+        }
+        if let Some(parent) = output_path.parent()
+            && !parent.as_os_str().is_empty()
+        {
+            tokio::fs::create_dir_all(parent).await.map_err(|e| {
+                crate::Error::io_path("creating ffmpeg output directory", parent, e)
+            })?;
+        }
+
+        let args = Self::build_args(
+            config,
+            &ArgContext {
+                input,
+                input_path: path,
+                output_path: &output_path,
+            },
+        );
+        debug!("FFmpeg args: {:?}", args);
+        ctx.info(format!(
+            "Running ffmpeg: {} -> {}",
+            input_path,
+            output_path.display()
+        ));
+
+        let mut cmd = Command::new(&self.ffmpeg_path);
+        cmd.args(&args).env("LC_ALL", "C");
+        let command_output =
+            run_ffmpeg_with_progress(&mut cmd, &ctx.progress, Some(ctx.log_sink.clone())).await?;
+
+        if !command_output.status.success() {
+            // Do not leave a truncated output behind.
+            let _ = tokio::fs::remove_file(&output_path).await;
+            let error_msg = command_output
+                .logs
+                .iter()
+                .rfind(|l| l.level == LogLevel::Error)
+                .map(|l| l.message.clone())
+                .unwrap_or_else(|| "Unknown ffmpeg error".to_string());
+            return Err(crate::Error::PipelineError(format!(
+                "ffmpeg failed with exit code {}: {}",
+                command_output.status.code().unwrap_or(-1),
+                error_msg
+            )));
+        }
+
+        let output = output_path.to_string_lossy().into_owned();
+        let input_size_bytes = tokio::fs::metadata(path).await.ok().map(|m| m.len());
+        let output_size_bytes = tokio::fs::metadata(&output_path)
+            .await
+            .ok()
+            .map(|m| m.len());
+
+        if config.remove_input_on_success
+            && let Err(e) = tokio::fs::remove_file(path).await
+        {
+            warn!(input = %input_path, error = %e, "Failed to remove ffmpeg input");
+        }
+
+        Ok(ProcessorOutput {
+            outputs: vec![output.clone()],
+            duration_secs: command_output.duration,
+            items_produced: vec![output],
+            input_size_bytes,
+            output_size_bytes,
+            succeeded_inputs: vec![input_path.to_string()],
+            logs: command_output.logs,
+            ..Default::default()
+        })
+    }
+}
+
+impl Default for FfmpegProcessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Processor for FfmpegProcessor {
+    fn processor_type(&self) -> ProcessorType {
+        ProcessorType::Cpu
+    }
+
+    fn job_types(&self) -> Vec<&'static str> {
+        vec!["ffmpeg"]
+    }
+
+    fn name(&self) -> &'static str {
+        "FfmpegProcessor"
+    }
+
+    fn supports_batch_input(&self) -> bool {
+        true
+    }
+
+    async fn process(
+        &self,
+        input: &ProcessorInput,
+        ctx: &ProcessorContext,
+    ) -> Result<ProcessorOutput> {
+        if input.inputs.is_empty() {
+            return Err(crate::Error::Validation(
+                "No input files provided for FfmpegProcessor".to_string(),
+            ));
+        }
+        let config: FfmpegConfig = match input.config.as_deref() {
+            Some(s) => serde_json::from_str(s).map_err(|e| {
+                crate::Error::Validation(format!("Invalid ffmpeg config JSON: {e}"))
+            })?,
+            None => FfmpegConfig::default(),
+        };
+
+        let mut combined = ProcessorOutput::default();
+        for input_path in &input.inputs {
+            match self.process_one(input, &config, input_path, ctx).await {
+                Ok(one) => {
+                    combined.duration_secs += one.duration_secs;
+                    combined.outputs.extend(one.outputs);
+                    combined.items_produced.extend(one.items_produced);
+                    combined.succeeded_inputs.extend(one.succeeded_inputs);
+                    combined.skipped_inputs.extend(one.skipped_inputs);
+                    combined.logs.extend(one.logs);
+                    if let Some(size) = one.input_size_bytes {
+                        *combined.input_size_bytes.get_or_insert(0) += size;
+                    }
+                    if let Some(size) = one.output_size_bytes {
+                        *combined.output_size_bytes.get_or_insert(0) += size;
+                    }
+                }
+                Err(e) => {
+                    warn!(input = %input_path, error = %e, "ffmpeg step failed");
+                    combined
+                        .failed_inputs
+                        .push((input_path.clone(), e.to_string()));
+                }
+            }
+        }
+
+        if combined.succeeded_inputs.is_empty() && !combined.failed_inputs.is_empty() {
+            return Err(crate::Error::PipelineError(format!(
+                "ffmpeg failed for all {} input(s): {}",
+                combined.failed_inputs.len(),
+                combined.failed_inputs[0].1
+            )));
+        }
+
+        combined.metadata = Some(
+            serde_json::json!({
+                "produced": combined.items_produced,
+                "failed": combined.failed_inputs.len(),
+            })
+            .to_string(),
+        );
+        Ok(combined)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_utils::utc_datetime;
+    use super::*;
+
+    fn input() -> ProcessorInput {
+        ProcessorInput::new(
+            vec!["/rec/live.flv".to_string()],
+            vec![],
+            "streamer-1",
+            "session-1",
+        )
+        .with_streamer_name("Alice")
+        .with_created_at(utc_datetime(2024, 3, 5, 12, 0, 0))
+    }
+
+    #[test]
+    fn output_path_follows_name_extension_and_dir() {
+        let config = FfmpegConfig::default();
+        assert_eq!(
+            FfmpegProcessor::output_path(&input(), &config, Path::new("/rec/live.flv")),
+            PathBuf::from("/rec/live_ffmpeg.flv")
+        );
+
+        let config = FfmpegConfig {
+            output_name: "{streamer}-%Y%m%d-{input_stem}".to_string(),
+            output_extension: Some(".mp4".to_string()),
+            output_dir: Some("/out".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            FfmpegProcessor::output_path(&input(), &config, Path::new("/rec/live.flv")),
+            PathBuf::from("/out/Alice-20240305-live.mp4")
+        );
+    }
+
+    #[test]
+    fn args_place_templates_around_input_and_output() {
+        let config = FfmpegConfig {
+            input_args: vec!["-ss".to_string(), "30".to_string()],
+            video_filter: Some("crop=iw/2:ih:0:0,drawtext=text='{streamer} %{pts}'".to_string()),
+            output_args: vec![
+                "-c:v".to_string(),
+                "libx264".to_string(),
+                "-metadata".to_string(),
+                "comment={input_stem}.{input_ext}".to_string(),
+            ],
+            overwrite: false,
+            ..Default::default()
+        };
+        let input = input();
+        let args = FfmpegProcessor::build_args(
+            &config,
+            &ArgContext {
+                input: &input,
+                input_path: Path::new("/rec/live.flv"),
+                output_path: Path::new("/rec/out.mp4"),
+            },
+        );
+
+        assert_eq!(args[0], "-n");
+        let tail: Vec<&str> = args[7..].iter().map(String::as_str).collect();
+        assert_eq!(
+            tail,
+            [
+                "-ss",
+                "30",
+                "-i",
+                "/rec/live.flv",
+                "-vf",
+                "crop=iw/2:ih:0:0,drawtext=text='Alice %{pts}'",
+                "-c:v",
+                "libx264",
+                "-metadata",
+                "comment=live.flv",
+                "/rec/out.mp4",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn non_media_inputs_pass_through() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("notes.txt");
+        std::fs::write(&file, b"x").unwrap();
+        let path = file.to_string_lossy().into_owned();
+
+        let input = ProcessorInput::new(vec![path.clone()], vec![], "s", "x");
+        let output = FfmpegProcessor::new()
+            .process(&input, &ProcessorContext::noop("job"))
+            .await
+            .unwrap();
+        assert_eq!(output.outputs, vec![path]);
+        assert_eq!(output.skipped_inputs.len(), 1);
+    }
+}
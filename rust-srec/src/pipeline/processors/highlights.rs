@@ -0,0 +1,781 @@
+//! Highlight detection processor.
+//!
+//! Scores a recording in fixed windows from up to three signals and writes
+//! the best-scoring stretches to `<stem>.highlights.json`, optionally
+//! cutting each one into `<stem>_highlight_NN.<ext>`:
+//! - audio: momentary loudness (ffmpeg `ebur128`) above the recording's
+//!   median by `loudness_spike_db`
+//! - scene cuts: cuts per window found by ffmpeg `scdet`
+//! - danmu: how much of the window falls in a danmu burst, found by the
+//!   sliding-window detector of the live danmu statistics on the danmu XML
+//!   recorded alongside the video
+//!
+//! Audio and scene cuts come from one ffmpeg pass over the video. Each
+//! signal is normalized to 0..1 and the window score is their weighted
+//! mean, so thresholds carry over between streamers with different
+//! baselines; per-streamer tuning goes in the streamer's pipeline config.
+
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+use platforms_parser::danmaku::BurstDetectionConfig;
+use serde::{Deserialize, Serialize};
+use tokio::process::Command;
+use tracing::warn;
+
+use super::danmu_xml::{DanmuKind, detect_bursts, pair_videos_with_danmu, read_danmu_xml};
+use super::traits::{Processor, ProcessorContext, ProcessorInput, ProcessorOutput, ProcessorType};
+use super::utils::{get_extension, is_video, run_ffmpeg_with_progress};
+use crate::Result;
+use crate::pipeline::job_queue::LogLevel;
+
+/// Configuration for highlight detection.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HighlightsConfig {
+    /// Width of the scoring windows, in seconds.
+    pub window_secs: f64,
+
+    /// Use audio loudness.
+    pub audio: bool,
+    /// Loudness above the median (LU) that scores a full audio point.
+    pub loudness_spike_db: f64,
+    pub audio_weight: f64,
+
+    /// Use scene cuts.
+    pub scenes: bool,
+    /// `scdet` threshold (0-100); lower finds more cuts.
+    pub scene_threshold: f64,
+    /// Cuts in one window that score a full scene point.
+    pub scene_cuts_per_window: f64,
+    pub scene_weight: f64,
+
+    /// Use danmu rate when a danmu XML is available.
+    pub danmu: bool,
+    /// Multiple of the baseline danmu rate that starts a burst.
+    pub danmu_rate_multiplier: f64,
+    /// Messages a window needs before it can start a burst.
+    pub min_danmu_per_window: u32,
+    pub danmu_weight: f64,
+
+    /// Minimum window score (0-1) for a highlight.
+    pub min_score: f64,
+    /// Seconds added before and after each highlight.
+    pub pre_roll_secs: f64,
+    pub post_roll_secs: f64,
+    /// Longest highlight, in seconds.
+    pub max_highlight_secs: f64,
+    /// Maximum number of highlights per recording.
+    pub max_highlights: usize,
+
+    /// Cut each highlight into its own file.
+    pub write_clips: bool,
+    /// Cut clips with stream copy (fast, cuts on keyframes) instead of
+    /// re-encoding.
+    pub clip_copy: bool,
+}
+
+impl Default for HighlightsConfig {
+    fn default() -> Self {
+        Self {
+            window_secs: 10.0,
+            audio: true,
+            loudness_spike_db: 8.0,
+            audio_weight: 1.0,
+            scenes: true,
+            scene_threshold: 10.0,
+            scene_cuts_per_window: 3.0,
+            scene_weight: 0.5,
+            danmu: true,
+            danmu_rate_multiplier: 3.0,
+            min_danmu_per_window: 5,
+            danmu_weight: 1.0,
+            min_score: 0.6,
+            pre_roll_secs: 10.0,
+            post_roll_secs: 5.0,
+            max_highlight_secs: 120.0,
+            max_highlights: 10,
+            write_clips: false,
+            clip_copy: true,
+        }
+    }
+}
+
+/// Raw signals of one recording.
+#[derive(Debug, Clone, Default)]
+pub struct Signals {
+    /// `(time, momentary loudness in LUFS)` samples.
+    pub loudness: Vec<(f64, f64)>,
+    /// Scene cut times; `None` when scenes were not analyzed.
+    pub scene_cuts: Option<Vec<f64>>,
+    /// Danmu message times; `None` when the recording has no danmu.
+    pub danmu: Option<Vec<f64>>,
+}
+
+/// Signal values of a highlight's peak window.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct HighlightSignals {
+    pub loudness_lufs: Option<f64>,
+    pub scene_cuts: usize,
+    pub danmu_count: Option<usize>,
+}
+
+/// A detected highlight.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Highlight {
+    pub start_secs: f64,
+    pub end_secs: f64,
+    pub score: f64,
+    pub peak_secs: f64,
+    pub signals: HighlightSignals,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clip: Option<String>,
+}
+
+/// Contents of `<stem>.highlights.json`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HighlightsReport {
+    pub source: String,
+    pub duration_secs: f64,
+    pub window_secs: f64,
+    pub highlights: Vec<Highlight>,
+}
+
+fn median(values: &[f64]) -> f64 {
+    if values.is_empty() {
+        return 0.0;
+    }
+    let mut sorted = values.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    sorted[sorted.len() / 2]
+}
+
+/// Score `signals` in windows and pick highlights, strongest first up to
+/// `max_highlights`, returned in time order.
+pub fn detect_highlights(
+    signals: &Signals,
+    duration_secs: f64,
+    config: &HighlightsConfig,
+) -> Vec<Highlight> {
+    let window = config.window_secs.max(1.0);
+    let len = (duration_secs / window).ceil().max(1.0) as usize;
+    let bucket = |t: f64| ((t.max(0.0) / window) as usize).min(len - 1);
+
+    // Mean loudness per window; ebur128 reports -70 LUFS and below as silence.
+    let mut loud_sum = vec![0.0f64; len];
+    let mut loud_n = vec![0usize; len];
+    for &(t, lufs) in &signals.loudness {
+        let idx = bucket(t);
+        loud_sum[idx] += lufs.max(-70.0);
+        loud_n[idx] += 1;
+    }
+    let loudness: Vec<Option<f64>> = (0..len)
+        .map(|i| (loud_n[i] > 0).then(|| loud_sum[i] / loud_n[i] as f64))
+        .collect();
+    let loud_median = median(&loudness.iter().flatten().copied().collect::<Vec<_>>());
+
+    let mut cuts = vec![0usize; len];
+    for &t in signals.scene_cuts.iter().flatten() {
+        cuts[bucket(t)] += 1;
+    }
+
+    // Messages per window, reported with each highlight.
+    let danmu_counts = signals.danmu.as_ref().map(|times| {
+        let mut counts = vec![0usize; len];
+        for &t in times.iter().filter(|t| **t >= 0.0) {
+            counts[bucket(t)] += 1;
+        }
+        counts
+    });
+
+    // Share of each window covered by a danmu burst.
+    let danmu_coverage = signals.danmu.as_ref().map(|times| {
+        let detection = BurstDetectionConfig {
+            window_secs: window.round() as u64,
+            threshold: config.danmu_rate_multiplier,
+            min_messages: u64::from(config.min_danmu_per_window),
+            max_bursts: usize::MAX,
+            ..Default::default()
+        };
+        let mut coverage = vec![0.0f64; len];
+        for burst in detect_bursts(times.iter().map(|t| (*t, 1)), detection) {
+            for (i, covered) in coverage.iter_mut().enumerate() {
+                let from = burst.start_secs.max(i as f64 * window);
+                let to = burst.end_secs.min((i + 1) as f64 * window);
+                *covered += (to - from).max(0.0) / window;
+            }
+        }
+        coverage
+    });
+
+    let use_audio = config.audio && !signals.loudness.is_empty() && config.audio_weight > 0.0;
+    let use_scenes = config.scenes && signals.scene_cuts.is_some() && config.scene_weight > 0.0;
+    let use_danmu = config.danmu && danmu_counts.is_some() && config.danmu_weight > 0.0;
+    let total_weight = f64::from(u8::from(use_audio)) * config.audio_weight
+        + f64::from(u8::from(use_scenes)) * config.scene_weight
+        + f64::from(u8::from(use_danmu)) * config.danmu_weight;
+    if total_weight <= 0.0 {
+        return Vec::new();
+    }
+
+    let scores: Vec<f64> = (0..len)
+        .map(|i| {
+            let mut score = 0.0;
+            if use_audio && let Some(lufs) = loudness[i] {
+                let excess = (lufs - loud_median) / config.loudness_spike_db.max(0.1);
+                score += config.audio_weight * excess.clamp(0.0, 1.0);
+            }
+            if use_scenes {
+                let rate = cuts[i] as f64 / config.scene_cuts_per_window.max(1.0);
+                score += config.scene_weight * rate.clamp(0.0, 1.0);
+            }
+            if use_danmu && let Some(coverage) = &danmu_coverage {
+                score += config.danmu_weight * coverage[i].clamp(0.0, 1.0);
+            }
+            score / total_weight
+        })
+        .collect();
+
+    // Runs of qualifying windows, padded and capped.
+    let mut candidates: Vec<Highlight> = Vec::new();
+    let mut idx = 0;
+    while idx < len {
+        if scores[idx] < config.min_score {
+            idx += 1;
+            continue;
+        }
+        let first = idx;
+        while idx < len && scores[idx] >= config.min_score {
+            idx += 1;
+        }
+        let peak = (first..idx)
+            .max_by(|a, b| scores[*a].total_cmp(&scores[*b]))
+            .unwrap_or(first);
+        let start = (first as f64 * window - config.pre_roll_secs).max(0.0);
+        let end = (idx as f64 * window + config.post_roll_secs).min(duration_secs.max(0.0));
+        let end = end.min(start + config.max_highlight_secs.max(window));
+        candidates.push(Highlight {
+            start_secs: start,
+            end_secs: end,
+            score: (scores[peak] * 1000.0).round() / 1000.0,
+            peak_secs: peak as f64 * window,
+            signals: HighlightSignals {
+                loudness_lufs: loudness[peak].map(|l| (l * 10.0).round() / 10.0),
+                scene_cuts: cuts[peak],
+                danmu_count: danmu_counts.as_ref().map(|c| c[peak]),
+            },
+            clip: None,
+        });
+    }
+
+    // Strongest first; drop candidates overlapping an accepted one.
+    candidates.sort_by(|a, b| b.score.total_cmp(&a.score));
+    let mut accepted: Vec<Highlight> = Vec::new();
+    for candidate in candidates {
+        if accepted.len() >= config.max_highlights {
+            break;
+        }
+        let overlaps = accepted
+            .iter()
+            .any(|a| candidate.start_secs < a.end_secs && a.start_secs < candidate.end_secs);
+        if !overlaps && candidate.end_secs > candidate.start_secs {
+            accepted.push(candidate);
+        }
+    }
+    accepted.sort_by(|a, b| a.start_secs.total_cmp(&b.start_secs));
+    accepted
+}
+
+/// Parse the output of ffmpeg's `metadata`/`ametadata` print mode into
+/// `(pts_time, value)` pairs for `key`.
+fn parse_metadata_print(text: &str, key: &str) -> Vec<(f64, f64)> {
+    let prefix = format!("{key}=");
+    let mut time = None;
+    let mut values = Vec::new();
+    for line in text.lines() {
+        let line = line.trim();
+        if line.starts_with("frame:") {
+            time = line
+                .split_whitespace()
+                .find_map(|field| field.strip_prefix("pts_time:"))
+                .and_then(|t| t.parse::<f64>().ok());
+        } else if let (Some(t), Some(value)) = (time, line.strip_prefix(&prefix))
+            && let Ok(value) = value.parse::<f64>()
+        {
+            values.push((t, value));
+        }
+    }
+    values
+}
+
+/// What ffprobe reports about a recording.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct MediaProbe {
+    duration_secs: Option<f64>,
+    has_audio: bool,
+    has_video: bool,
+}
+
+/// Parse `ffprobe -show_entries format=duration:stream=codec_type -of
+/// default=noprint_wrappers=1` output.
+fn parse_probe(text: &str) -> MediaProbe {
+    let mut probe = MediaProbe::default();
+    for line in text.lines() {
+        match line.trim().split_once('=') {
+            Some(("codec_type", "audio")) => probe.has_audio = true,
+            Some(("codec_type", "video")) => probe.has_video = true,
+            Some(("duration", value)) => {
+                probe.duration_secs = value
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|d| d.is_finite() && *d > 0.0);
+            }
+            _ => {}
+        }
+    }
+    probe
+}
+
+/// Whether to run the loudness and scene chains. A chain whose input
+/// stream is missing makes ffmpeg reject the whole graph, so each runs only
+/// when the probe found its stream; without a probe both are tried.
+fn analysis_streams(config: &HighlightsConfig, probe: Option<&MediaProbe>) -> (bool, bool) {
+    (
+        config.audio && probe.is_none_or(|p| p.has_audio),
+        config.scenes && probe.is_none_or(|p| p.has_video),
+    )
+}
+
+/// Quote a path for a filter option value.
+fn escape_filter_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace(':', "\\:")
+        .replace('\'', "\\'")
+}
+
+/// Processor that detects highlights.
+pub struct HighlightsProcessor {
+    ffmpeg_path: String,
+    ffprobe_path: String,
+}
+
+impl HighlightsProcessor {
+    pub fn new() -> Self {
+        Self {
+            ffmpeg_path: std::env::var("FFMPEG_PATH").unwrap_or_else(|_| "ffmpeg".to_string()),
+            ffprobe_path: std::env::var("FFPROBE_PATH").unwrap_or_else(|_| "ffprobe".to_string()),
+        }
+    }
+
+    /// Container duration and stream types of `video`; `None` when ffprobe
+    /// cannot read it.
+    async fn probe(&self, video: &str) -> Option<MediaProbe> {
+        let output = process_utils::tokio_command(&self.ffprobe_path)
+            .args([
+                "-v",
+                "error",
+                "-show_entries",
+                "format=duration:stream=codec_type",
+                "-of",
+                "default=noprint_wrappers=1",
+                video,
+            ])
+            .output()
+            .await
+            .ok()?;
+        output
+            .status
+            .success()
+            .then(|| parse_probe(&String::from_utf8_lossy(&output.stdout)))
+    }
+
+    /// Run one ffmpeg pass collecting loudness (`audio`) and/or scene cuts
+    /// (`scenes`).
+    async fn analyze(
+        &self,
+        video: &str,
+        (audio, scenes): (bool, bool),
+        config: &HighlightsConfig,
+        ctx: &ProcessorContext,
+    ) -> Result<(Vec<(f64, f64)>, Vec<f64>)> {
+        let dir = tempfile::tempdir()
+            .map_err(|e| crate::Error::Other(format!("Failed to create temp dir: {e}")))?;
+        let audio_file = dir.path().join("loudness.txt");
+        let scene_file = dir.path().join("scenes.txt");
+
+        let mut chains = Vec::new();
+        let mut maps = Vec::new();
+        if audio {
+            chains.push(format!(
+                "[0:a:0]ebur128=metadata=1,ametadata=mode=print:key=lavfi.r128.M:file='{}'[aout]",
+                escape_filter_value(&audio_file.to_string_lossy())
+            ));
+            maps.push("[aout]");
+        }
+        if scenes {
+            // Scene detection does not need full resolution or frame rate.
+            chains.push(format!(
+                "[0:v:0]fps=5,scale=320:-2,scdet=threshold={}:sc_pass=1,\
+                 metadata=mode=print:key=lavfi.scd.score:file='{}'[vout]",
+                config.scene_threshold.clamp(0.0, 100.0),
+                escape_filter_value(&scene_file.to_string_lossy())
+            ));
+            maps.push("[vout]");
+        }
+
+        let mut cmd = Command::new(&self.ffmpeg_path);
+        cmd.args(["-y", "-hide_banner", "-nostats", "-loglevel", "error"])
+            .args(["-progress", "pipe:1", "-i", video])
+            .args(["-filter_complex", &chains.join(";")]);
+        for map in maps {
+            cmd.args(["-map", map, "-f", "null", "-"]);
+        }
+        cmd.env("LC_ALL", "C");
+        let command_output =
+            run_ffmpeg_with_progress(&mut cmd, &ctx.progress, Some(ctx.log_sink.clone())).await?;
+        if !command_output.status.success() {
+            let error_msg = command_output
+                .logs
+                .iter()
+                .rfind(|l| l.level == LogLevel::Error)
+                .map(|l| l.message.clone())
+                .unwrap_or_else(|| "Unknown ffmpeg error".to_string());
+            return Err(crate::Error::PipelineError(format!(
+                "Highlight analysis failed with exit code {}: {}",
+                command_output.status.code().unwrap_or(-1),
+                error_msg
+            )));
+        }
+
+        let read = |path: PathBuf| async move {
+            tokio::fs::read_to_string(&path).await.unwrap_or_default()
+        };
+        let loudness = parse_metadata_print(&read(audio_file).await, "lavfi.r128.M");
+        let scene_cuts = parse_metadata_print(&read(scene_file).await, "lavfi.scd.score")
+            .into_iter()
+            .map(|(t, _)| t)
+            .collect();
+        Ok((loudness, scene_cuts))
+    }
+
+    async fn cut_clip(
+        &self,
+        video: &str,
+        highlight: &Highlight,
+        output: &Path,
+        config: &HighlightsConfig,
+        ctx: &ProcessorContext,
+    ) -> Result<()> {
+        let mut cmd = Command::new(&self.ffmpeg_path);
+        cmd.args(["-y", "-hide_banner", "-nostats", "-loglevel", "error"])
+            .args(["-progress", "pipe:1"])
+            .args(["-ss", &format!("{:.3}", highlight.start_secs), "-i", video])
+            .args([
+                "-t",
+                &format!("{:.3}", highlight.end_secs - highlight.start_secs),
+            ]);
+        if config.clip_copy {
+            cmd.args(["-c", "copy", "-avoid_negative_ts", "make_zero"]);
+        } else {
+            cmd.args(["-c:v", "libx264", "-preset", "veryfast", "-c:a", "aac"]);
+        }
+        cmd.arg(output).env("LC_ALL", "C");
+        let command_output =
+            run_ffmpeg_with_progress(&mut cmd, &ctx.progress, Some(ctx.log_sink.clone())).await?;
+        if !command_output.status.success() {
+            let _ = tokio::fs::remove_file(output).await;
+            let error_msg = command_output
+                .logs
+                .iter()
+                .rfind(|l| l.level == LogLevel::Error)
+                .map(|l| l.message.clone())
+                .unwrap_or_else(|| "Unknown ffmpeg error".to_string());
+            return Err(crate::Error::PipelineError(format!(
+                "Cutting highlight failed with exit code {}: {}",
+                command_output.status.code().unwrap_or(-1),
+                error_msg
+            )));
+        }
+        Ok(())
+    }
+
+    /// Detect highlights for one video and write its report (and clips).
+    async fn process_video(
+        &self,
+        video: &str,
+        danmu: Option<&str>,
+        config: &HighlightsConfig,
+        ctx: &ProcessorContext,
+    ) -> Result<Vec<String>> {
+        let mut signals = Signals::default();
+        if config.danmu
+            && let Some(danmu) = danmu
+        {
+            match read_danmu_xml(Path::new(danmu)).await {
+                Ok(entries) => {
+                    signals.danmu = Some(
+                        entries
+                            .iter()
+                            .filter(|e| matches!(e.kind, DanmuKind::Comment | DanmuKind::SuperChat))
+                            .map(|e| e.time_s)
+                            .collect(),
+                    );
+                }
+                Err(e) => warn!(danmu = %danmu, error = %e, "Failed to read danmu for highlights"),
+            }
+        }
+
+        let probe = self.probe(video).await;
+        let streams = analysis_streams(config, probe.as_ref());
+        if config.audio && !streams.0 {
+            ctx.info(format!("{video} has no audio stream; skipping loudness"));
+        }
+        if config.scenes && !streams.1 {
+            ctx.info(format!("{video} has no video stream; skipping scene cuts"));
+        }
+        if streams.0 || streams.1 {
+            match self.analyze(video, streams, config, ctx).await {
+                Ok((loudness, scene_cuts)) => {
+                    signals.loudness = loudness;
+                    signals.scene_cuts = streams.1.then_some(scene_cuts);
+                }
+                // Danmu alone still finds highlights when the analysis pass
+                // fails.
+                Err(e) if signals.danmu.is_some() => {
+                    ctx.warn(format!("{e}; using danmu only"));
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        let duration = match probe.and_then(|p| p.duration_secs) {
+            Some(d) => d,
+            None => signals
+                .loudness
+                .iter()
+                .map(|(t, _)| *t)
+                .chain(signals.scene_cuts.iter().flatten().copied())
+                .chain(signals.danmu.iter().flatten().copied())
+                .fold(0.0, f64::max),
+        };
+        let mut highlights = detect_highlights(&signals, duration, config);
+
+        let video_path = Path::new(video);
+        let stem = video_path
+            .file_stem()
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let ext = get_extension(video).unwrap_or_else(|| "mp4".to_string());
+        let mut produced = Vec::new();
+        if config.write_clips {
+            for (n, highlight) in highlights.iter_mut().enumerate() {
+                let clip =
+                    video_path.with_file_name(format!("{stem}_highlight_{:02}.{ext}", n + 1));
+                self.cut_clip(video, highlight, &clip, config, ctx).await?;
+                let clip = clip.to_string_lossy().into_owned();
+                highlight.clip = Some(clip.clone());
+                produced.push(clip);
+            }
+        }
+
+        let report = HighlightsReport {
+            source: video.to_string(),
+            duration_secs: duration,
+            window_secs: config.window_secs.max(1.0),
+            highlights,
+        };
+        let report_path = video_path.with_file_name(format!("{stem}.highlights.json"));
+        tokio::fs::write(&report_path, serde_json::to_vec_pretty(&report)?)
+            .await
+            .map_err(|e| crate::Error::io_path("writing highlights", &report_path, e))?;
+        ctx.info(format!(
+            "Found {} highlight(s) in {}",
+            report.highlights.len(),
+            video
+        ));
+        produced.insert(0, report_path.to_string_lossy().into_owned());
+        Ok(produced)
+    }
+}
+
+impl Default for HighlightsProcessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Processor for HighlightsProcessor {
+    fn processor_type(&self) -> ProcessorType {
+        ProcessorType::Cpu
+    }
+
+    fn job_types(&self) -> Vec<&'static str> {
+        vec!["highlights"]
+    }
+
+    fn name(&self) -> &'static str {
+        "HighlightsProcessor"
+    }
+
+    fn supports_batch_input(&self) -> bool {
+        true
+    }
+
+    async fn process(
+        &self,
+        input: &ProcessorInput,
+        ctx: &ProcessorContext,
+    ) -> Result<ProcessorOutput> {
+        let started = std::time::Instant::now();
+        let config: HighlightsConfig = match input.config.as_deref() {
+            Some(s) => serde_json::from_str(s).map_err(|e| {
+                crate::Error::Validation(format!("Invalid highlights config JSON: {e}"))
+            })?,
+            None => HighlightsConfig::default(),
+        };
+
+        let pairs = pair_videos_with_danmu(&input.inputs).await;
+        let mut output = ProcessorOutput {
+            outputs: input.inputs.clone(),
+            ..Default::default()
+        };
+        for video in input
+            .inputs
+            .iter()
+            .filter(|p| get_extension(p).is_some_and(|ext| is_video(&ext)))
+        {
+            if ctx.cancellation_token.is_cancelled() {
+                return Err(crate::Error::PipelineError(
+                    "Highlight detection cancelled".to_string(),
+                ));
+            }
+            let danmu = pairs
+                .iter()
+                .find(|(v, _)| v == video)
+                .map(|(_, d)| d.as_str());
+            match self.process_video(video, danmu, &config, ctx).await {
+                Ok(produced) => {
+                    output.succeeded_inputs.push(video.clone());
+                    output.items_produced.extend(produced.iter().cloned());
+                    output.outputs.extend(produced);
+                }
+                Err(e) => {
+                    warn!(video = %video, error = %e, "Highlight detection failed");
+                    output.failed_inputs.push((video.clone(), e.to_string()));
+                }
+            }
+        }
+
+        if output.succeeded_inputs.is_empty() && !output.failed_inputs.is_empty() {
+            return Err(crate::Error::PipelineError(format!(
+                "Highlight detection failed for all {} video(s): {}",
+                output.failed_inputs.len(),
+                output.failed_inputs[0].1
+            )));
+        }
+
+        output.duration_secs = started.elapsed().as_secs_f64();
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_metadata_print_output() {
+        let text = "frame:0    pts:0       pts_time:0\n\
+                    lavfi.r128.M=-70.000\n\
+                    frame:1    pts:4800    pts_time:0.1\n\
+                    lavfi.r128.M=-23.5\n\
+                    lavfi.r128.S=-24.0\n";
+        assert_eq!(
+            parse_metadata_print(text, "lavfi.r128.M"),
+            vec![(0.0, -70.0), (0.1, -23.5)]
+        );
+    }
+
+    #[test]
+    fn skips_loudness_for_video_without_audio() {
+        let probe = parse_probe("codec_type=video\nduration=125.400000\n");
+        assert_eq!(
+            probe,
+            MediaProbe {
+                duration_secs: Some(125.4),
+                has_audio: false,
+                has_video: true,
+            }
+        );
+
+        let config = HighlightsConfig::default();
+        assert_eq!(analysis_streams(&config, Some(&probe)), (false, true));
+        assert_eq!(analysis_streams(&config, None), (true, true));
+        let audio_only = parse_probe("codec_type=audio\nduration=N/A\n");
+        assert_eq!(audio_only.duration_secs, None);
+        assert_eq!(analysis_streams(&config, Some(&audio_only)), (true, false));
+    }
+
+    #[test]
+    fn combines_loud_and_busy_windows() {
+        // 100 s at -30 LUFS with a loud, chatty stretch at 50-60 s.
+        let loudness = (0..1000)
+            .map(|i| {
+                let t = i as f64 / 10.0;
+                (
+                    t,
+                    if (50.0..60.0).contains(&t) {
+                        -15.0
+                    } else {
+                        -30.0
+                    },
+                )
+            })
+            .collect();
+        let mut danmu: Vec<f64> = (0..100).map(f64::from).collect();
+        danmu.extend((0..40).map(|i| 50.0 + f64::from(i) * 0.25));
+        let signals = Signals {
+            loudness,
+            scene_cuts: Some(vec![52.0]),
+            danmu: Some(danmu),
+        };
+
+        let highlights = detect_highlights(&signals, 100.0, &HighlightsConfig::default());
+        assert_eq!(highlights.len(), 1);
+        let highlight = &highlights[0];
+        assert_eq!(highlight.peak_secs, 50.0);
+        assert_eq!(highlight.start_secs, 40.0);
+        assert_eq!(highlight.end_secs, 65.0);
+        assert_eq!(highlight.signals.loudness_lufs, Some(-15.0));
+        assert_eq!(highlight.signals.danmu_count, Some(50));
+    }
+
+    #[test]
+    fn keeps_strongest_non_overlapping_highlights() {
+        let scene_cuts = [10.0, 11.0, 12.0, 30.0, 31.0, 32.0, 33.0, 34.0, 35.0]
+            .into_iter()
+            .chain((0..3).map(|i| 80.0 + f64::from(i)))
+            .collect();
+        let signals = Signals {
+            scene_cuts: Some(scene_cuts),
+            ..Default::default()
+        };
+        let config = HighlightsConfig {
+            audio: false,
+            danmu: false,
+            pre_roll_secs: 0.0,
+            post_roll_secs: 0.0,
+            max_highlights: 2,
+            ..Default::default()
+        };
+
+        let highlights = detect_highlights(&signals, 100.0, &config);
+        let starts: Vec<f64> = highlights.iter().map(|h| h.start_secs).collect();
+        // All three windows score 1.0; the earliest two win ties in order.
+        assert_eq!(starts.len(), 2);
+        assert!(highlights.iter().all(|h| h.score == 1.0));
+        assert!(starts.windows(2).all(|w| w[0] < w[1]));
+    }
+}
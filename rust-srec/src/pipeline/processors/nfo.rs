@@ -0,0 +1,587 @@
+//! Jellyfin/Emby NFO processor.
+//!
+//! Writes Kodi-style `.nfo` files so media servers show recordings with
+//! proper titles and dates. Each streamer is treated as a TV series with one
+//! season per year; a recording becomes an episode numbered `MMDDHHMM` of
+//! its start time, aired on the recording date.
+//!
+//! Without `library_root` the NFO is written next to each video. With it,
+//! videos are moved (or copied) into the layout media servers scan:
+//!
+//! ```text
+//! <root>/<series>/tvshow.nfo
+//! <root>/<series>/poster.jpg
+//! <root>/<series>/Season 2026/<series> - S2026E10171930 - <title>.flv
+//! <root>/<series>/Season 2026/<series> - S2026E10171930 - <title>.nfo
+//! <root>/<series>/Season 2026/<series> - S2026E10171930 - <title>-thumb.jpg
+//! ```
+//!
+//! Inputs sharing a video's file stem (subtitles, danmu XML, thumbnails)
+//! move with it so the server picks them up as external subtitles and art.
+
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Datelike, Local, Timelike, Utc};
+use quick_xml::escape::escape;
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use super::traits::{
+    Processor, ProcessorContext, ProcessorInput, ProcessorOutput, ProcessorType, TimeAnchor,
+};
+use super::utils::{expand_text_template, get_extension, is_image, is_video, tmp_output_path};
+use crate::Result;
+use crate::utils::filename::sanitize_filename;
+
+/// Configuration for the NFO processor.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NfoConfig {
+    /// Media library root. When set, videos and their companion files are
+    /// moved into `<root>/<series>/Season <year>/`.
+    pub library_root: Option<String>,
+
+    /// Copy into the library instead of moving.
+    pub copy: bool,
+
+    /// Series title template; supports `{streamer}`, `{platform}`,
+    /// `{streamer_id}` and time tokens.
+    pub series_title: String,
+
+    /// Episode title template; supports `{title}`, `{streamer}`,
+    /// `{platform}` and time tokens.
+    pub episode_title: String,
+
+    /// Episode plot template.
+    pub plot: Option<String>,
+
+    /// Timestamp used for the season, episode number and air date.
+    pub time_anchor: TimeAnchor,
+
+    /// Write `tvshow.nfo` in the series folder if it does not exist yet.
+    pub write_tvshow_nfo: bool,
+
+    /// Copy the first thumbnail to the series folder's `poster.jpg` if it
+    /// does not exist yet.
+    pub series_poster: bool,
+}
+
+impl Default for NfoConfig {
+    fn default() -> Self {
+        Self {
+            library_root: None,
+            copy: false,
+            series_title: "{streamer}".to_string(),
+            episode_title: "{title}".to_string(),
+            plot: None,
+            time_anchor: TimeAnchor::SessionStart,
+            write_tvshow_nfo: true,
+            series_poster: true,
+        }
+    }
+}
+
+/// Fields of one `<episodedetails>` document.
+#[derive(Debug, Clone, PartialEq)]
+struct EpisodeNfo {
+    title: String,
+    show_title: String,
+    season: i32,
+    episode: u32,
+    aired: String,
+    date_added: String,
+    plot: Option<String>,
+    studio: Option<String>,
+    thumb: Option<String>,
+    unique_id: String,
+}
+
+impl EpisodeNfo {
+    fn to_xml(&self) -> String {
+        let mut xml = String::from(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n<episodedetails>\n",
+        );
+        let mut field = |tag: &str, value: &str| {
+            xml.push_str(&format!("  <{tag}>{}</{tag}>\n", escape(value)));
+        };
+        field("title", &self.title);
+        field("showtitle", &self.show_title);
+        field("season", &self.season.to_string());
+        field("episode", &self.episode.to_string());
+        field("aired", &self.aired);
+        field("premiered", &self.aired);
+        field("dateadded", &self.date_added);
+        if let Some(plot) = &self.plot {
+            field("plot", plot);
+        }
+        if let Some(studio) = &self.studio {
+            field("studio", studio);
+        }
+        if let Some(thumb) = &self.thumb {
+            field("thumb", thumb);
+        }
+        xml.push_str(&format!(
+            "  <uniqueid type=\"rust-srec\" default=\"true\">{}</uniqueid>\n",
+            escape(&self.unique_id)
+        ));
+        xml.push_str("</episodedetails>\n");
+        xml
+    }
+}
+
+fn tvshow_xml(title: &str, studio: Option<&str>, streamer_id: &str) -> String {
+    let mut xml =
+        String::from("<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n<tvshow>\n");
+    xml.push_str(&format!("  <title>{}</title>\n", escape(title)));
+    if let Some(studio) = studio {
+        xml.push_str(&format!("  <studio>{}</studio>\n", escape(studio)));
+    }
+    xml.push_str(&format!(
+        "  <uniqueid type=\"rust-srec\" default=\"true\">{}</uniqueid>\n",
+        escape(streamer_id)
+    ));
+    xml.push_str("</tvshow>\n");
+    xml
+}
+
+/// Season (year) and episode number (`MMDDHHMM`) of a local start time.
+fn season_episode(start: &DateTime<Local>) -> (i32, u32) {
+    let episode =
+        start.month() * 1_000_000 + start.day() * 10_000 + start.hour() * 100 + start.minute();
+    (start.year(), episode)
+}
+
+/// File stem shared by every file of one episode.
+fn episode_stem(
+    series: &str,
+    season: i32,
+    episode: u32,
+    title: &str,
+    part: Option<usize>,
+) -> String {
+    let mut stem = format!("{series} - S{season}E{episode:08}");
+    if !title.trim().is_empty() {
+        stem.push_str(" - ");
+        stem.push_str(title.trim());
+    }
+    if let Some(part) = part {
+        stem.push_str(&format!(" - part{part}"));
+    }
+    sanitize_filename(&stem)
+}
+
+fn file_stem(path: &Path) -> String {
+    path.file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+/// Move or copy `source` to `dest`. Moves fall back to copy-and-delete
+/// across filesystems; a move whose source is gone but whose destination
+/// exists was finished by an earlier attempt of the job.
+async fn relocate(source: &Path, dest: &Path, copy: bool) -> Result<()> {
+    if !copy {
+        if tokio::fs::rename(source, dest).await.is_ok() {
+            return Ok(());
+        }
+        if !tokio::fs::try_exists(source).await.unwrap_or(true)
+            && tokio::fs::try_exists(dest).await.unwrap_or(false)
+        {
+            return Ok(());
+        }
+    }
+    let tmp = tmp_output_path(dest);
+    if let Err(e) = tokio::fs::copy(source, &tmp).await {
+        let _ = tokio::fs::remove_file(&tmp).await;
+        return Err(crate::Error::io_path("copying into library", source, e));
+    }
+    tokio::fs::rename(&tmp, dest)
+        .await
+        .map_err(|e| crate::Error::io_path("renaming into library", dest, e))?;
+    if !copy {
+        tokio::fs::remove_file(source)
+            .await
+            .map_err(|e| crate::Error::io_path("removing moved file", source, e))?;
+    }
+    Ok(())
+}
+
+/// Processor that writes Jellyfin/Emby NFO metadata.
+pub struct NfoProcessor;
+
+impl NfoProcessor {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for NfoProcessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Processor for NfoProcessor {
+    fn processor_type(&self) -> ProcessorType {
+        ProcessorType::Io
+    }
+
+    fn job_types(&self) -> Vec<&'static str> {
+        vec!["nfo"]
+    }
+
+    fn name(&self) -> &'static str {
+        "NfoProcessor"
+    }
+
+    fn supports_batch_input(&self) -> bool {
+        true
+    }
+
+    async fn process(
+        &self,
+        input: &ProcessorInput,
+        ctx: &ProcessorContext,
+    ) -> Result<ProcessorOutput> {
+        let started = std::time::Instant::now();
+        let config: NfoConfig = match input.config.as_deref() {
+            Some(s) => serde_json::from_str(s)
+                .map_err(|e| crate::Error::Validation(format!("Invalid nfo config JSON: {e}")))?,
+            None => NfoConfig::default(),
+        };
+
+        let videos: Vec<&String> = input
+            .inputs
+            .iter()
+            .filter(|p| get_extension(p).is_some_and(|ext| is_video(&ext)))
+            .collect();
+        let mut output = ProcessorOutput::default();
+        if videos.is_empty() {
+            output.outputs = input.inputs.clone();
+            output.skipped_inputs.extend(
+                input
+                    .inputs
+                    .iter()
+                    .map(|p| (p.clone(), "not a video".to_string())),
+            );
+            output.duration_secs = started.elapsed().as_secs_f64();
+            return Ok(output);
+        }
+
+        let series = expand_text_template(&config.series_title, input, config.time_anchor);
+        let series = if series.trim().is_empty() {
+            input.streamer_id.clone()
+        } else {
+            series.trim().to_string()
+        };
+        let title = expand_text_template(&config.episode_title, input, config.time_anchor);
+        let plot = config
+            .plot
+            .as_deref()
+            .map(|p| expand_text_template(p, input, config.time_anchor))
+            .filter(|p| !p.trim().is_empty());
+        let studio = input.platform.clone().filter(|p| !p.is_empty());
+        let start = config
+            .time_anchor
+            .reference_time(input)
+            .with_timezone(&Local);
+        let (season, episode) = season_episode(&start);
+        let now = Utc::now().with_timezone(&Local);
+
+        let library = config
+            .library_root
+            .as_deref()
+            .filter(|r| !r.trim().is_empty())
+            .map(|root| PathBuf::from(root).join(sanitize_filename(&series)));
+        if let Some(series_dir) = &library {
+            let season_dir = series_dir.join(format!("Season {season}"));
+            tokio::fs::create_dir_all(&season_dir)
+                .await
+                .map_err(|e| crate::Error::io_path("creating library folder", &season_dir, e))?;
+            let tvshow = series_dir.join("tvshow.nfo");
+            if config.write_tvshow_nfo && !tokio::fs::try_exists(&tvshow).await.unwrap_or(true) {
+                tokio::fs::write(
+                    &tvshow,
+                    tvshow_xml(&series, studio.as_deref(), &input.streamer_id),
+                )
+                .await
+                .map_err(|e| crate::Error::io_path("writing tvshow.nfo", &tvshow, e))?;
+                output
+                    .items_produced
+                    .push(tvshow.to_string_lossy().into_owned());
+            }
+        }
+
+        let mut claimed = vec![false; input.inputs.len()];
+        let mut poster_source: Option<PathBuf> = None;
+        for (index, video) in videos.iter().enumerate() {
+            let video_path = Path::new(video.as_str());
+            let stem = file_stem(video_path);
+            let part = (videos.len() > 1).then_some(index + 1);
+            let companions: Vec<usize> = input
+                .inputs
+                .iter()
+                .enumerate()
+                .filter(|(_, p)| {
+                    let path = Path::new(p.as_str());
+                    p.as_str() != video.as_str()
+                        && path.parent() == video_path.parent()
+                        && file_stem(path) == stem
+                        && !get_extension(p).is_some_and(|ext| is_video(&ext))
+                })
+                .map(|(i, _)| i)
+                .collect();
+
+            let result: Result<(Vec<String>, Option<PathBuf>)> = async {
+                let mut produced = Vec::new();
+                let mut thumb = None;
+                let (dir, base) = match &library {
+                    Some(series_dir) => (
+                        series_dir.join(format!("Season {season}")),
+                        episode_stem(&series, season, episode, &title, part),
+                    ),
+                    None => (
+                        video_path
+                            .parent()
+                            .map(Path::to_path_buf)
+                            .unwrap_or_default(),
+                        stem.clone(),
+                    ),
+                };
+
+                let video_dest = match &library {
+                    Some(_) => {
+                        let ext = get_extension(video).unwrap_or_default();
+                        let dest = dir.join(format!("{base}.{ext}"));
+                        relocate(video_path, &dest, config.copy).await?;
+                        dest
+                    }
+                    None => video_path.to_path_buf(),
+                };
+                produced.push(video_dest.to_string_lossy().into_owned());
+
+                for &i in &companions {
+                    let source = Path::new(&input.inputs[i]);
+                    let ext = get_extension(&input.inputs[i]).unwrap_or_default();
+                    let image = is_image(&ext);
+                    let dest = match &library {
+                        Some(_) if image => dir.join(format!("{base}-thumb.{ext}")),
+                        Some(_) => dir.join(format!("{base}.{ext}")),
+                        None => source.to_path_buf(),
+                    };
+                    if library.is_some() {
+                        relocate(source, &dest, config.copy).await?;
+                    }
+                    if image && thumb.is_none() {
+                        thumb = Some(dest.clone());
+                    }
+                    produced.push(dest.to_string_lossy().into_owned());
+                }
+
+                let nfo = EpisodeNfo {
+                    title: if title.trim().is_empty() {
+                        base.clone()
+                    } else {
+                        match part {
+                            Some(part) => format!("{} ({part})", title.trim()),
+                            None => title.trim().to_string(),
+                        }
+                    },
+                    show_title: series.clone(),
+                    season,
+                    episode,
+                    aired: start.format("%Y-%m-%d").to_string(),
+                    date_added: now.format("%Y-%m-%d %H:%M:%S").to_string(),
+                    plot: plot.clone(),
+                    studio: studio.clone(),
+                    thumb: thumb
+                        .as_deref()
+                        .and_then(Path::file_name)
+                        .map(|n| n.to_string_lossy().into_owned()),
+                    unique_id: format!("{}:{}", input.session_id, index + 1),
+                };
+                let nfo_path = dir.join(format!("{base}.nfo"));
+                tokio::fs::write(&nfo_path, nfo.to_xml())
+                    .await
+                    .map_err(|e| crate::Error::io_path("writing nfo", &nfo_path, e))?;
+                produced.push(nfo_path.to_string_lossy().into_owned());
+                Ok((produced, thumb))
+            }
+            .await;
+
+            let index_of_video = input.inputs.iter().position(|p| p == *video);
+            match result {
+                Ok((produced, thumb)) => {
+                    ctx.info(format!("Wrote NFO for {video}"));
+                    for i in companions.iter().copied().chain(index_of_video) {
+                        claimed[i] = true;
+                        output.succeeded_inputs.push(input.inputs[i].clone());
+                    }
+                    if poster_source.is_none() {
+                        poster_source = thumb;
+                    }
+                    output
+                        .items_produced
+                        .push(produced.last().cloned().unwrap_or_default());
+                    output.outputs.extend(produced);
+                }
+                Err(e) => {
+                    warn!(path = %video, error = %e, "Failed to write NFO");
+                    output.failed_inputs.push(((*video).clone(), e.to_string()));
+                }
+            }
+        }
+
+        if let (Some(series_dir), Some(thumb), true) =
+            (&library, &poster_source, config.series_poster)
+        {
+            let ext = thumb
+                .extension()
+                .map(|e| e.to_string_lossy().into_owned())
+                .unwrap_or_else(|| "jpg".to_string());
+            let poster = series_dir.join(format!("poster.{ext}"));
+            if !tokio::fs::try_exists(&poster).await.unwrap_or(true) {
+                match tokio::fs::copy(thumb, &poster).await {
+                    Ok(_) => output
+                        .items_produced
+                        .push(poster.to_string_lossy().into_owned()),
+                    Err(e) => {
+                        warn!(poster = %poster.display(), error = %e, "Failed to write series poster")
+                    }
+                }
+            }
+        }
+
+        // Inputs that belong to no video pass through untouched.
+        for (i, path) in input.inputs.iter().enumerate() {
+            if !claimed[i] && !output.failed_inputs.iter().any(|(p, _)| p == path) {
+                output.outputs.push(path.clone());
+                output
+                    .skipped_inputs
+                    .push((path.clone(), "no matching video".to_string()));
+            }
+        }
+
+        if output.succeeded_inputs.is_empty() && !output.failed_inputs.is_empty() {
+            return Err(crate::Error::PipelineError(format!(
+                "NFO creation failed for all {} video(s): {}",
+                output.failed_inputs.len(),
+                output.failed_inputs[0].1
+            )));
+        }
+
+        output.duration_secs = started.elapsed().as_secs_f64();
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn input_at(inputs: Vec<String>, config: serde_json::Value) -> ProcessorInput {
+        let mut input = ProcessorInput::new(inputs, vec![], "streamer-1", "session-1")
+            .with_config(config.to_string());
+        input.streamer_name = Some("Alice".to_string());
+        input.session_title = Some("Late <night> stream".to_string());
+        input.platform = Some("Twitch".to_string());
+        input.session_start = Some(
+            Local
+                .with_ymd_and_hms(2026, 10, 17, 19, 30, 0)
+                .unwrap()
+                .into(),
+        );
+        input
+    }
+
+    #[test]
+    fn numbers_episodes_by_start_time() {
+        let start = Local.with_ymd_and_hms(2026, 1, 5, 8, 7, 0).unwrap();
+        assert_eq!(season_episode(&start), (2026, 1_050_807));
+        assert_eq!(
+            episode_stem("Alice", 2026, 1_050_807, "Title", Some(2)),
+            "Alice - S2026E01050807 - Title - part2"
+        );
+    }
+
+    #[tokio::test]
+    async fn writes_nfo_next_to_video() {
+        let dir = tempfile::tempdir().unwrap();
+        let video = dir.path().join("live.flv");
+        std::fs::write(&video, b"v").unwrap();
+        let video = video.to_string_lossy().into_owned();
+
+        let input = input_at(vec![video.clone()], serde_json::json!({}));
+        let output = NfoProcessor::new()
+            .process(&input, &ProcessorContext::noop("job"))
+            .await
+            .unwrap();
+
+        let nfo = dir.path().join("live.nfo");
+        assert_eq!(
+            output.outputs,
+            vec![video, nfo.to_string_lossy().into_owned()]
+        );
+        let xml = std::fs::read_to_string(nfo).unwrap();
+        assert!(xml.contains("<title>Late &lt;night&gt; stream</title>"));
+        assert!(xml.contains("<showtitle>Alice</showtitle>"));
+        assert!(xml.contains("<season>2026</season>"));
+        assert!(xml.contains("<episode>10171930</episode>"));
+        assert!(xml.contains("<aired>2026-10-17</aired>"));
+        assert!(xml.contains("<studio>Twitch</studio>"));
+    }
+
+    #[tokio::test]
+    async fn organizes_library_with_companions_and_poster() {
+        let dir = tempfile::tempdir().unwrap();
+        let recordings = dir.path().join("rec");
+        std::fs::create_dir(&recordings).unwrap();
+        let files: Vec<String> = ["live.flv", "live.ass", "live.jpg", "other.xml"]
+            .iter()
+            .map(|name| {
+                let path = recordings.join(name);
+                std::fs::write(&path, name.as_bytes()).unwrap();
+                path.to_string_lossy().into_owned()
+            })
+            .collect();
+        let library = dir.path().join("library");
+
+        let input = input_at(
+            files.clone(),
+            serde_json::json!({ "library_root": library.to_string_lossy() }),
+        );
+        let output = NfoProcessor::new()
+            .process(&input, &ProcessorContext::noop("job"))
+            .await
+            .unwrap();
+
+        let series = library.join("Alice");
+        let season = series.join("Season 2026");
+        let base = "Alice - S2026E10171930 - Late _night_ stream";
+        for name in [
+            format!("{base}.flv"),
+            format!("{base}.ass"),
+            format!("{base}-thumb.jpg"),
+            format!("{base}.nfo"),
+        ] {
+            assert!(season.join(&name).exists(), "{name} missing");
+        }
+        assert!(!Path::new(&files[0]).exists());
+        assert!(series.join("tvshow.nfo").exists());
+        assert_eq!(
+            std::fs::read(series.join("poster.jpg")).unwrap(),
+            b"live.jpg"
+        );
+        assert!(
+            std::fs::read_to_string(season.join(format!("{base}.nfo")))
+                .unwrap()
+                .contains(&format!("<thumb>{base}-thumb.jpg</thumb>"))
+        );
+        // Unrelated inputs pass through in place.
+        assert_eq!(output.outputs.last(), Some(&files[3]));
+        assert_eq!(output.succeeded_inputs.len(), 3);
+    }
+}
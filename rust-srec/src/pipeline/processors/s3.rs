@@ -0,0 +1,650 @@
+//! S3 upload processor for AWS S3 and S3-compatible object storage.
+//!
+//! Uploads each input as an object under a templated key prefix. Files larger
+//! than one part go through a multipart upload, so a network error only
+//! retries the affected part. Each part carries a SHA-256 checksum that the
+//! server verifies before storing it.
+//!
+//! Supports placeholder expansion in `key_prefix`:
+//! - `{streamer}` - Streamer name
+//! - `{title}` - Session title
+//! - `{streamer_id}` - Streamer ID
+//! - `{session_id}` - Session ID
+//! - `{platform}` - Platform name
+//! - Time placeholders: `%Y`, `%m`, `%d`, `%H`, `%M`, `%S`, etc.
+
+mod client;
+
+use std::path::Path;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+use tokio::io::AsyncReadExt;
+use tracing::{info, warn};
+
+use self::client::{BucketTarget, CompletedPart, Credentials, ObjectOptions, S3Client};
+use super::traits::{
+    Processor, ProcessorContext, ProcessorInput, ProcessorOutput, ProcessorType, TimeAnchor,
+};
+use super::utils::{UploadProgress, create_log_entry};
+use crate::Result;
+use crate::domain::RetryPolicy;
+use crate::pipeline::job_queue::LogLevel;
+use crate::utils::filename::expand_placeholders_at;
+
+const MIB: u64 = 1024 * 1024;
+
+/// Smallest part size S3 accepts for every part but the last.
+const MIN_PART_SIZE: u64 = 5 * MIB;
+
+/// Most parts a single multipart upload may have.
+const MAX_PARTS: u64 = 10_000;
+
+/// Most retries a request may be configured with.
+const MAX_RETRIES: u32 = 10;
+
+/// Configuration for the S3 upload processor.
+///
+/// Credentials left unset fall back to the `AWS_ACCESS_KEY_ID`,
+/// `AWS_SECRET_ACCESS_KEY` and `AWS_SESSION_TOKEN` environment variables.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct S3UploadConfig {
+    /// Service endpoint, e.g. `http://minio:9000`. Defaults to AWS S3 in
+    /// `region`.
+    pub endpoint: Option<String>,
+
+    /// Signing region. S3-compatible services usually accept `us-east-1`.
+    pub region: String,
+
+    /// Target bucket. Required.
+    pub bucket: String,
+
+    pub access_key_id: Option<String>,
+
+    pub secret_access_key: Option<String>,
+
+    /// Session token for temporary credentials.
+    pub session_token: Option<String>,
+
+    /// Address the bucket as `endpoint/bucket` instead of `bucket.endpoint`.
+    /// MinIO and most self-hosted services need this.
+    pub path_style: bool,
+
+    /// Key prefix the input file names are placed under (supports
+    /// placeholder expansion), e.g. `{streamer}/%Y-%m-%d`.
+    pub key_prefix: Option<String>,
+
+    /// Timestamp source for time placeholder expansion.
+    pub time_anchor: TimeAnchor,
+
+    /// Storage class, e.g. `STANDARD_IA` or `GLACIER_IR`. Unset uses the
+    /// bucket default.
+    pub storage_class: Option<String>,
+
+    /// Multipart part size in MiB. Files no larger than one part are
+    /// uploaded with a single request.
+    pub part_size_mb: u64,
+
+    /// Retries per request for network errors, throttling and server errors,
+    /// with jittered exponential backoff capped at 30 seconds. At most 10.
+    pub max_retries: u32,
+
+    /// Send a SHA-256 checksum with each part for the server to verify and
+    /// store.
+    pub checksum: bool,
+
+    /// Compare the stored object size with the local file after uploading.
+    pub verify_upload: bool,
+
+    /// Delete each local file once it is uploaded and verified.
+    pub delete_source_on_success: bool,
+}
+
+impl Default for S3UploadConfig {
+    fn default() -> Self {
+        Self {
+            endpoint: None,
+            region: "us-east-1".to_string(),
+            bucket: String::new(),
+            access_key_id: None,
+            secret_access_key: None,
+            session_token: None,
+            path_style: false,
+            key_prefix: None,
+            time_anchor: TimeAnchor::default(),
+            storage_class: None,
+            part_size_mb: 16,
+            max_retries: 3,
+            checksum: true,
+            verify_upload: true,
+            delete_source_on_success: false,
+        }
+    }
+}
+
+impl S3UploadConfig {
+    fn credentials(&self) -> Result<Credentials> {
+        let from_env = |value: &Option<String>, var: &str| {
+            value
+                .clone()
+                .filter(|v| !v.is_empty())
+                .or_else(|| std::env::var(var).ok().filter(|v| !v.is_empty()))
+        };
+        match (
+            from_env(&self.access_key_id, "AWS_ACCESS_KEY_ID"),
+            from_env(&self.secret_access_key, "AWS_SECRET_ACCESS_KEY"),
+        ) {
+            (Some(access_key_id), Some(secret_access_key)) => Ok(Credentials {
+                access_key_id,
+                secret_access_key,
+                session_token: from_env(&self.session_token, "AWS_SESSION_TOKEN"),
+            }),
+            _ => Err(crate::Error::Validation(
+                "S3 credentials are missing: set access_key_id and secret_access_key \
+                 or the AWS_ACCESS_KEY_ID and AWS_SECRET_ACCESS_KEY environment variables"
+                    .to_string(),
+            )),
+        }
+    }
+
+    fn target(&self) -> Result<BucketTarget> {
+        if self.bucket.trim().is_empty() {
+            return Err(crate::Error::Validation(
+                "S3 bucket is required".to_string(),
+            ));
+        }
+        let endpoint = match self.endpoint.as_deref().filter(|e| !e.is_empty()) {
+            Some(endpoint) => endpoint.to_string(),
+            None => format!("https://s3.{}.amazonaws.com", self.region),
+        };
+        let endpoint = url::Url::parse(&endpoint).map_err(|e| {
+            crate::Error::Validation(format!("Invalid S3 endpoint '{endpoint}': {e}"))
+        })?;
+        Ok(BucketTarget {
+            endpoint,
+            bucket: self.bucket.trim().to_string(),
+            region: self.region.clone(),
+            path_style: self.path_style,
+        })
+    }
+
+    fn retry_policy(&self) -> Result<RetryPolicy> {
+        if self.max_retries > MAX_RETRIES {
+            return Err(crate::Error::Validation(format!(
+                "S3 max_retries must be at most {MAX_RETRIES}, got {}",
+                self.max_retries
+            )));
+        }
+        Ok(RetryPolicy::with_max_retries(self.max_retries))
+    }
+
+    /// Part size for a file of `file_size` bytes: the configured size,
+    /// raised to the S3 minimum and grown so the file fits in the part limit.
+    fn part_size_for(&self, file_size: u64) -> u64 {
+        let configured = self.part_size_mb.saturating_mul(MIB).max(MIN_PART_SIZE);
+        configured.max(file_size.div_ceil(MAX_PARTS))
+    }
+}
+
+/// Processor that uploads files to S3-compatible object storage.
+pub struct S3UploadProcessor;
+
+/// An object stored by the processor.
+struct UploadedObject {
+    key: String,
+    size: u64,
+    etag: String,
+    parts: usize,
+}
+
+impl S3UploadProcessor {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Object key for `input_path`: the expanded prefix joined with the
+    /// file name.
+    fn object_key(input: &ProcessorInput, config: &S3UploadConfig, input_path: &str) -> String {
+        let file_name = Path::new(input_path)
+            .file_name()
+            .unwrap_or_default()
+            .to_string_lossy();
+        let prefix = config
+            .key_prefix
+            .as_deref()
+            .map(|template| {
+                expand_placeholders_at(
+                    template,
+                    &input.streamer_id,
+                    &input.session_id,
+                    input.streamer_name.as_deref(),
+                    input.session_title.as_deref(),
+                    input.platform.as_deref(),
+                    Some(config.time_anchor.reference_time(input).timestamp_millis()),
+                )
+            })
+            .unwrap_or_default();
+        let prefix = prefix.trim_matches('/');
+        if prefix.is_empty() {
+            file_name.into_owned()
+        } else {
+            format!("{prefix}/{file_name}")
+        }
+    }
+
+    async fn upload_file(
+        client: &S3Client,
+        config: &S3UploadConfig,
+        path: &str,
+        key: &str,
+        progress: &mut UploadProgress<'_>,
+    ) -> Result<UploadedObject> {
+        let size = tokio::fs::metadata(path)
+            .await
+            .map_err(|e| {
+                crate::Error::io_path("reading upload source metadata", Path::new(path), e)
+            })?
+            .len();
+        let options = ObjectOptions {
+            content_type: content_type(path).to_string(),
+            storage_class: config.storage_class.clone(),
+            checksum: config.checksum,
+        };
+        let part_size = config.part_size_for(size);
+
+        let (etag, parts) = if size <= part_size {
+            let body = tokio::fs::read(path)
+                .await
+                .map_err(|e| crate::Error::io_path("reading upload source", Path::new(path), e))?;
+            let etag = client.put_object(key, Bytes::from(body), &options).await?;
+            progress.advance(size);
+            (etag, 1)
+        } else {
+            let upload_id = client.create_multipart_upload(key, &options).await?;
+            match Self::upload_parts(client, config, path, key, &upload_id, part_size, progress)
+                .await
+            {
+                Ok(parts) => {
+                    let etag = client
+                        .complete_multipart_upload(key, &upload_id, &parts)
+                        .await?;
+                    (etag, parts.len())
+                }
+                Err(e) => {
+                    if let Err(abort_error) = client.abort_multipart_upload(key, &upload_id).await {
+                        warn!(key, error = %abort_error, "Failed to abort S3 multipart upload");
+                    }
+                    return Err(e);
+                }
+            }
+        };
+
+        if config.verify_upload {
+            match client.head_object(key).await? {
+                Some(stored) if stored == size => {}
+                stored => {
+                    return Err(crate::Error::Other(format!(
+                        "S3 object {key} size mismatch after upload: local {size} bytes, stored {}",
+                        stored.map_or_else(|| "none".to_string(), |s| format!("{s} bytes"))
+                    )));
+                }
+            }
+        }
+
+        Ok(UploadedObject {
+            key: key.to_string(),
+            size,
+            etag,
+            parts,
+        })
+    }
+
+    /// Upload `path` in `part_size` chunks. Stops between parts when the
+    /// job is cancelled; the caller aborts the upload on any error.
+    async fn upload_parts(
+        client: &S3Client,
+        config: &S3UploadConfig,
+        path: &str,
+        key: &str,
+        upload_id: &str,
+        part_size: u64,
+        progress: &mut UploadProgress<'_>,
+    ) -> Result<Vec<CompletedPart>> {
+        let mut file = tokio::fs::File::open(path)
+            .await
+            .map_err(|e| crate::Error::io_path("opening upload source", Path::new(path), e))?;
+        let mut parts = Vec::new();
+        let mut part_number = 1u32;
+
+        loop {
+            if progress.is_cancelled() {
+                return Err(crate::Error::PipelineError(
+                    "S3 upload cancelled".to_string(),
+                ));
+            }
+
+            let mut chunk = Vec::with_capacity(part_size as usize);
+            (&mut file)
+                .take(part_size)
+                .read_to_end(&mut chunk)
+                .await
+                .map_err(|e| crate::Error::io_path("reading upload source", Path::new(path), e))?;
+            if chunk.is_empty() {
+                break;
+            }
+
+            let len = chunk.len() as u64;
+            let part = client
+                .upload_part(
+                    key,
+                    upload_id,
+                    part_number,
+                    Bytes::from(chunk),
+                    config.checksum,
+                )
+                .await?;
+            parts.push(part);
+            progress.advance(len);
+            part_number += 1;
+        }
+
+        Ok(parts)
+    }
+}
+
+impl Default for S3UploadProcessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// MIME type sent with an upload, guessed from the file extension.
+fn content_type(path: &str) -> &'static str {
+    let extension = Path::new(path)
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(str::to_ascii_lowercase)
+        .unwrap_or_default();
+    match extension.as_str() {
+        "mp4" | "m4v" => "video/mp4",
+        "flv" => "video/x-flv",
+        "ts" => "video/mp2t",
+        "mkv" => "video/x-matroska",
+        "m4a" => "audio/mp4",
+        "mp3" => "audio/mpeg",
+        "aac" => "audio/aac",
+        "jpg" | "jpeg" => "image/jpeg",
+        "png" => "image/png",
+        "webp" => "image/webp",
+        "json" => "application/json",
+        "xml" => "application/xml",
+        "ass" | "srt" | "txt" | "nfo" => "text/plain; charset=utf-8",
+        _ => "application/octet-stream",
+    }
+}
+
+#[async_trait]
+impl Processor for S3UploadProcessor {
+    fn processor_type(&self) -> ProcessorType {
+        ProcessorType::Io
+    }
+
+    fn job_types(&self) -> Vec<&'static str> {
+        vec!["s3"]
+    }
+
+    fn name(&self) -> &'static str {
+        "S3UploadProcessor"
+    }
+
+    fn supports_batch_input(&self) -> bool {
+        true
+    }
+
+    /// A job dropped mid-upload leaves its uploaded parts on the server
+    /// until a bucket lifecycle rule removes incomplete multipart uploads.
+    async fn process(
+        &self,
+        input: &ProcessorInput,
+        ctx: &ProcessorContext,
+    ) -> Result<ProcessorOutput> {
+        let start = std::time::Instant::now();
+
+        if input.inputs.is_empty() {
+            return Err(crate::Error::Validation(
+                "No input files provided for S3UploadProcessor".to_string(),
+            ));
+        }
+
+        let config: S3UploadConfig = match input.config.as_deref() {
+            Some(s) => serde_json::from_str(s)
+                .map_err(|e| crate::Error::Validation(format!("Invalid s3 config JSON: {e}")))?,
+            None => S3UploadConfig::default(),
+        };
+        let client = S3Client::new(
+            config.target()?,
+            config.credentials()?,
+            config.retry_policy()?,
+        );
+
+        let mut bytes_total = 0u64;
+        for path in &input.inputs {
+            if let Ok(metadata) = tokio::fs::metadata(path).await {
+                bytes_total = bytes_total.saturating_add(metadata.len());
+            }
+        }
+        let mut progress = UploadProgress::new(ctx, bytes_total);
+
+        let mut outputs = Vec::new();
+        let mut succeeded_inputs = Vec::new();
+        let mut failed_inputs: Vec<(String, String)> = Vec::new();
+        let mut uploaded = Vec::new();
+        let mut logs = Vec::new();
+
+        for path in &input.inputs {
+            if !Path::new(path).exists() {
+                failed_inputs.push((path.clone(), format!("Input file does not exist: {path}")));
+                continue;
+            }
+
+            let key = Self::object_key(input, &config, path);
+            info!(input = %path, bucket = %config.bucket, key = %key, "Uploading to S3");
+
+            match Self::upload_file(&client, &config, path, &key, &mut progress).await {
+                Ok(object) => {
+                    let message = format!(
+                        "Uploaded {} to s3://{}/{} ({} bytes, {} part(s))",
+                        path, config.bucket, object.key, object.size, object.parts
+                    );
+                    info!("{message}");
+                    logs.push(create_log_entry(LogLevel::Info, message));
+
+                    if config.delete_source_on_success {
+                        if let Err(e) = tokio::fs::remove_file(path).await {
+                            warn!(input = %path, error = %e, "Failed to delete uploaded source");
+                            outputs.push(path.clone());
+                        }
+                    } else {
+                        outputs.push(path.clone());
+                    }
+                    succeeded_inputs.push(path.clone());
+                    uploaded.push(object);
+                }
+                Err(e) => {
+                    let message = format!("Failed to upload {path} to S3: {e}");
+                    warn!("{message}");
+                    logs.push(create_log_entry(LogLevel::Error, message));
+                    failed_inputs.push((path.clone(), e.to_string()));
+                }
+            }
+        }
+
+        if succeeded_inputs.is_empty() {
+            return Err(crate::Error::PipelineError(format!(
+                "All {} input files failed to upload to S3: {}",
+                failed_inputs.len(),
+                failed_inputs
+                    .first()
+                    .map(|(_, error)| error.as_str())
+                    .unwrap_or_default()
+            )));
+        }
+
+        let uploaded_bytes: u64 = uploaded.iter().map(|o| o.size).sum();
+        Ok(ProcessorOutput {
+            outputs,
+            duration_secs: start.elapsed().as_secs_f64(),
+            metadata: Some(
+                serde_json::json!({
+                    "bucket": config.bucket,
+                    "objects": uploaded
+                        .iter()
+                        .map(|o| serde_json::json!({
+                            "key": o.key,
+                            "size": o.size,
+                            "etag": o.etag,
+                            "parts": o.parts,
+                        }))
+                        .collect::<Vec<_>>(),
+                    "failed": failed_inputs.len(),
+                })
+                .to_string(),
+            ),
+            items_produced: vec![],
+            input_size_bytes: Some(bytes_total),
+            output_size_bytes: Some(uploaded_bytes),
+            failed_inputs,
+            succeeded_inputs,
+            skipped_inputs: vec![],
+            logs,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_utils::utc_datetime;
+    use super::*;
+
+    #[test]
+    fn config_deserializes_from_partial_json() {
+        let config: S3UploadConfig =
+            serde_json::from_str(r#"{"bucket": "recordings", "path_style": true}"#).unwrap();
+        assert_eq!(config.bucket, "recordings");
+        assert!(config.path_style);
+        assert_eq!(config.region, "us-east-1");
+        assert_eq!(config.part_size_mb, 16);
+        assert!(config.checksum);
+        assert!(config.verify_upload);
+        assert!(!config.delete_source_on_success);
+    }
+
+    #[test]
+    fn retry_policy_rejects_too_many_retries() {
+        let config = S3UploadConfig {
+            max_retries: 11,
+            ..Default::default()
+        };
+        assert!(matches!(
+            config.retry_policy(),
+            Err(crate::Error::Validation(_))
+        ));
+
+        let policy = S3UploadConfig::default().retry_policy().unwrap();
+        assert_eq!(policy.max_retries, 3);
+        assert!(policy.use_jitter);
+        assert_eq!(policy.max_delay_ms, 30_000);
+    }
+
+    #[test]
+    fn target_requires_a_bucket_and_defaults_to_aws() {
+        assert!(S3UploadConfig::default().target().is_err());
+
+        let config = S3UploadConfig {
+            bucket: "recordings".to_string(),
+            region: "eu-west-1".to_string(),
+            ..Default::default()
+        };
+        let target = config.target().unwrap();
+        assert_eq!(
+            target.endpoint.as_str(),
+            "https://s3.eu-west-1.amazonaws.com/"
+        );
+
+        let config = S3UploadConfig {
+            endpoint: Some("not a url".to_string()),
+            ..config
+        };
+        assert!(config.target().is_err());
+    }
+
+    #[test]
+    fn part_size_respects_s3_limits() {
+        let config = S3UploadConfig {
+            part_size_mb: 1,
+            ..Default::default()
+        };
+        assert_eq!(config.part_size_for(100 * MIB), MIN_PART_SIZE);
+
+        let config = S3UploadConfig::default();
+        assert_eq!(config.part_size_for(100 * MIB), 16 * MIB);
+
+        let huge = 16 * MIB * MAX_PARTS * 2;
+        let part_size = config.part_size_for(huge);
+        assert!(huge.div_ceil(part_size) <= MAX_PARTS);
+    }
+
+    #[test]
+    fn object_key_expands_prefix() {
+        let input = ProcessorInput::new(
+            vec!["/rec/live.flv".to_string()],
+            vec![],
+            "streamer-1",
+            "session-1",
+        )
+        .with_streamer_name("Alice")
+        .with_created_at(utc_datetime(2024, 3, 5, 12, 0, 0));
+
+        let config = S3UploadConfig {
+            key_prefix: Some("/{streamer}/%Y-%m-%d/".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            S3UploadProcessor::object_key(&input, &config, "/rec/live.flv"),
+            "Alice/2024-03-05/live.flv"
+        );
+
+        let config = S3UploadConfig::default();
+        assert_eq!(
+            S3UploadProcessor::object_key(&input, &config, "/rec/live.flv"),
+            "live.flv"
+        );
+    }
+
+    #[test]
+    fn content_type_follows_extension() {
+        assert_eq!(content_type("/rec/a.MP4"), "video/mp4");
+        assert_eq!(content_type("/rec/a.flv"), "video/x-flv");
+        assert_eq!(content_type("/rec/a"), "application/octet-stream");
+    }
+
+    #[tokio::test]
+    async fn missing_credentials_fail_before_uploading() {
+        let config = S3UploadConfig {
+            bucket: "recordings".to_string(),
+            access_key_id: Some("key".to_string()),
+            ..Default::default()
+        };
+        if std::env::var("AWS_SECRET_ACCESS_KEY").is_ok() {
+            return;
+        }
+        let input = ProcessorInput::new(vec!["/rec/a.flv".to_string()], vec![], "s", "x")
+            .with_config(serde_json::to_string(&config).unwrap());
+        let result = S3UploadProcessor::new()
+            .process(&input, &ProcessorContext::noop("job"))
+            .await;
+        assert!(matches!(result, Err(crate::Error::Validation(_))));
+    }
+}
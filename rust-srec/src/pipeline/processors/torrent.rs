@@ -0,0 +1,534 @@
+//! Torrent creation processor.
+//!
+//! Writes `.torrent` files for finished recordings so archives can be
+//! shared over BitTorrent. Supports v1, v2 and hybrid (v1 + v2) torrents,
+//! either one per input (`<file>.torrent` next to it) or one bundle for
+//! all inputs of the job. Inputs pass through unchanged; the torrents are
+//! appended to the outputs so a later upload step can publish them.
+
+mod metainfo;
+
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use tokio_util::sync::CancellationToken;
+use tracing::warn;
+
+use self::metainfo::{BLOCK_SIZE, HashedFile, MetainfoOptions, V1Pieces, V2Hasher};
+use super::traits::{Processor, ProcessorContext, ProcessorInput, ProcessorOutput, ProcessorType};
+use crate::Result;
+
+/// Largest piece length picked automatically.
+const MAX_AUTO_PIECE_LENGTH: usize = 16 * 1024 * 1024;
+
+/// Roughly how many pieces the automatic piece length aims for.
+const TARGET_PIECE_COUNT: u64 = 1500;
+
+/// Torrent format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TorrentVersion {
+    V1,
+    V2,
+    /// v1 and v2 metadata in one file, readable by clients of either.
+    #[default]
+    Hybrid,
+}
+
+impl TorrentVersion {
+    fn has_v1(self) -> bool {
+        matches!(self, Self::V1 | Self::Hybrid)
+    }
+
+    fn has_v2(self) -> bool {
+        matches!(self, Self::V2 | Self::Hybrid)
+    }
+}
+
+/// Configuration for the torrent processor.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TorrentConfig {
+    pub version: TorrentVersion,
+
+    /// Announce URLs; each becomes its own tier.
+    pub trackers: Vec<String>,
+
+    /// Web seed URLs (BEP 19 `url-list`).
+    pub web_seeds: Vec<String>,
+
+    /// Piece length in KiB, a power of two of at least 16. Picked from the
+    /// payload size when unset.
+    pub piece_size_kb: Option<u32>,
+
+    /// Set the private flag, limiting peers to the trackers.
+    pub private: bool,
+
+    pub comment: Option<String>,
+
+    /// `source` field, used by private trackers to make the info hash unique.
+    pub source: Option<String>,
+
+    /// Create one torrent for all inputs instead of one per input.
+    pub bundle: bool,
+
+    /// Name of the bundle torrent; defaults to the first input's file stem.
+    pub bundle_name: Option<String>,
+
+    /// Directory for the `.torrent` files; defaults to the input's directory.
+    pub output_dir: Option<String>,
+
+    /// Also create torrents for inputs that are not media files.
+    pub include_non_media: bool,
+
+    /// Append the `.torrent` files to the outputs for later steps.
+    pub torrents_in_outputs: bool,
+}
+
+impl Default for TorrentConfig {
+    fn default() -> Self {
+        Self {
+            version: TorrentVersion::Hybrid,
+            trackers: Vec::new(),
+            web_seeds: Vec::new(),
+            piece_size_kb: None,
+            private: false,
+            comment: None,
+            source: None,
+            bundle: false,
+            bundle_name: None,
+            output_dir: None,
+            include_non_media: false,
+            torrents_in_outputs: true,
+        }
+    }
+}
+
+/// One created torrent.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TorrentInfo {
+    pub path: String,
+    pub name: String,
+    pub info_hash_v1: Option<String>,
+    pub info_hash_v2: Option<String>,
+    pub magnet: String,
+}
+
+/// Job metadata written by the torrent processor.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TorrentMetadata {
+    pub torrents: Vec<TorrentInfo>,
+}
+
+/// Piece length for `total_bytes`: a power of two between 16 KiB and
+/// 16 MiB giving about [`TARGET_PIECE_COUNT`] pieces.
+fn auto_piece_length(total_bytes: u64) -> usize {
+    let target = (total_bytes / TARGET_PIECE_COUNT).max(1);
+    (target.next_power_of_two() as usize).clamp(BLOCK_SIZE, MAX_AUTO_PIECE_LENGTH)
+}
+
+fn piece_length(config: &TorrentConfig, total_bytes: u64) -> Result<usize> {
+    match config.piece_size_kb {
+        Some(kb) if kb >= 16 && kb.is_power_of_two() => Ok(kb as usize * 1024),
+        Some(kb) => Err(crate::Error::Validation(format!(
+            "piece_size_kb must be a power of two of at least 16, got {kb}"
+        ))),
+        None => Ok(auto_piece_length(total_bytes)),
+    }
+}
+
+fn magnet_link(name: &str, trackers: &[String], v1: Option<&str>, v2: Option<&str>) -> String {
+    let encode = |s: &str| url::form_urlencoded::byte_serialize(s.as_bytes()).collect::<String>();
+    let mut params = Vec::new();
+    if let Some(hash) = v1 {
+        params.push(format!("xt=urn:btih:{hash}"));
+    }
+    if let Some(hash) = v2 {
+        // multihash prefix: sha2-256 (0x12), 32 bytes (0x20)
+        params.push(format!("xt=urn:btmh:1220{hash}"));
+    }
+    params.push(format!("dn={}", encode(name)));
+    params.extend(trackers.iter().map(|t| format!("tr={}", encode(t))));
+    format!("magnet:?{}", params.join("&"))
+}
+
+/// Hash `paths` in order as one payload. Blocking; checks `cancel` between
+/// reads.
+fn hash_payload(
+    paths: &[PathBuf],
+    version: TorrentVersion,
+    piece_length: usize,
+    cancel: &CancellationToken,
+) -> std::io::Result<(Vec<HashedFile>, Option<Vec<u8>>)> {
+    let mut v1 = version.has_v1().then(|| V1Pieces::new(piece_length));
+    let mut files = Vec::with_capacity(paths.len());
+    let mut buffer = vec![0u8; 64 * BLOCK_SIZE];
+    for (index, path) in paths.iter().enumerate() {
+        let mut file = std::fs::File::open(path)?;
+        let mut v2 = version.has_v2().then(V2Hasher::new);
+        let mut length = 0u64;
+        loop {
+            if cancel.is_cancelled() {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::Interrupted,
+                    "torrent creation cancelled",
+                ));
+            }
+            let read = file.read(&mut buffer)?;
+            if read == 0 {
+                break;
+            }
+            length += read as u64;
+            if let Some(v1) = v1.as_mut() {
+                v1.update(&buffer[..read]);
+            }
+            if let Some(v2) = v2.as_mut() {
+                v2.update(&buffer[..read]);
+            }
+        }
+
+        // Hybrid torrents align every file to a piece boundary so the v1
+        // pieces line up with the per-file v2 trees.
+        let pad = match v1.as_mut() {
+            Some(v1) if version == TorrentVersion::Hybrid && index + 1 < paths.len() => {
+                v1.pad_to_boundary()
+            }
+            _ => 0,
+        };
+        files.push(HashedFile {
+            name: path
+                .file_name()
+                .map(|n| n.to_string_lossy().into_owned())
+                .unwrap_or_default(),
+            length,
+            v2: v2.map(|v2| v2.finish(piece_length)),
+            pad,
+        });
+    }
+    Ok((files, v1.map(V1Pieces::finish)))
+}
+
+/// Processor that creates `.torrent` files.
+pub struct TorrentProcessor;
+
+impl TorrentProcessor {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Hash `paths` and write `<output>`, returning its info.
+    async fn create(
+        &self,
+        config: &TorrentConfig,
+        name: &str,
+        paths: Vec<PathBuf>,
+        output: &Path,
+        ctx: &ProcessorContext,
+    ) -> Result<TorrentInfo> {
+        let mut total_bytes = 0u64;
+        for path in &paths {
+            total_bytes += tokio::fs::metadata(path)
+                .await
+                .map_err(|e| crate::Error::io_path("reading metadata of", path, e))?
+                .len();
+        }
+        let piece_length = piece_length(config, total_bytes)?;
+
+        let version = config.version;
+        let cancel = ctx.cancellation_token.clone();
+        let (files, v1_pieces) = tokio::task::spawn_blocking(move || {
+            hash_payload(&paths, version, piece_length, &cancel)
+        })
+        .await
+        .map_err(|e| crate::Error::Other(format!("Torrent hashing task failed: {e}")))?
+        .map_err(|e| crate::Error::io_path("hashing torrent payload", output, e))?;
+
+        let trackers: Vec<String> = config
+            .trackers
+            .iter()
+            .map(|t| t.trim().to_string())
+            .filter(|t| !t.is_empty())
+            .collect();
+        let web_seeds: Vec<String> = config
+            .web_seeds
+            .iter()
+            .map(|u| u.trim().to_string())
+            .filter(|u| !u.is_empty())
+            .collect();
+        let metainfo = metainfo::build(
+            &MetainfoOptions {
+                name,
+                piece_length,
+                trackers: &trackers,
+                web_seeds: &web_seeds,
+                private: config.private,
+                comment: config.comment.as_deref().filter(|c| !c.is_empty()),
+                source: config.source.as_deref().filter(|s| !s.is_empty()),
+                creation_date: chrono::Utc::now().timestamp(),
+            },
+            &files,
+            v1_pieces,
+        );
+        tokio::fs::write(output, &metainfo.bytes)
+            .await
+            .map_err(|e| crate::Error::io_path("writing torrent", output, e))?;
+
+        Ok(TorrentInfo {
+            path: output.to_string_lossy().into_owned(),
+            name: name.to_string(),
+            magnet: magnet_link(
+                name,
+                &trackers,
+                metainfo.info_hash_v1.as_deref(),
+                metainfo.info_hash_v2.as_deref(),
+            ),
+            info_hash_v1: metainfo.info_hash_v1,
+            info_hash_v2: metainfo.info_hash_v2,
+        })
+    }
+
+    fn torrent_path(config: &TorrentConfig, source: &Path, name: &str) -> PathBuf {
+        let dir = config
+            .output_dir
+            .as_deref()
+            .filter(|d| !d.trim().is_empty())
+            .map(PathBuf::from)
+            .unwrap_or_else(|| source.parent().map(Path::to_path_buf).unwrap_or_default());
+        dir.join(format!("{name}.torrent"))
+    }
+}
+
+impl Default for TorrentProcessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Processor for TorrentProcessor {
+    fn processor_type(&self) -> ProcessorType {
+        ProcessorType::Cpu
+    }
+
+    fn job_types(&self) -> Vec<&'static str> {
+        vec!["torrent"]
+    }
+
+    fn name(&self) -> &'static str {
+        "TorrentProcessor"
+    }
+
+    fn supports_batch_input(&self) -> bool {
+        true
+    }
+
+    async fn process(
+        &self,
+        input: &ProcessorInput,
+        ctx: &ProcessorContext,
+    ) -> Result<ProcessorOutput> {
+        let started = std::time::Instant::now();
+        let config: TorrentConfig = match input.config.as_deref() {
+            Some(s) => serde_json::from_str(s).map_err(|e| {
+                crate::Error::Validation(format!("Invalid torrent config JSON: {e}"))
+            })?,
+            None => TorrentConfig::default(),
+        };
+
+        let mut output = ProcessorOutput {
+            outputs: input.inputs.clone(),
+            ..Default::default()
+        };
+        let mut payload = Vec::new();
+        for path in &input.inputs {
+            let is_media =
+                super::utils::get_extension(path).is_some_and(|ext| super::utils::is_media(&ext));
+            if path.to_ascii_lowercase().ends_with(".torrent")
+                || (!is_media && !config.include_non_media)
+            {
+                output
+                    .skipped_inputs
+                    .push((path.clone(), "not included in a torrent".to_string()));
+                continue;
+            }
+            payload.push(path.clone());
+        }
+        if payload.is_empty() {
+            output.duration_secs = started.elapsed().as_secs_f64();
+            return Ok(output);
+        }
+
+        // (torrent name, files, inputs covered)
+        let mut jobs: Vec<(String, Vec<PathBuf>, Vec<String>)> = Vec::new();
+        if config.bundle {
+            // v2 file trees are sorted by name and a hybrid torrent's v1 file
+            // list has to follow the same order.
+            let mut files: Vec<PathBuf> = payload.iter().map(PathBuf::from).collect();
+            files.sort_by(|a, b| a.file_name().cmp(&b.file_name()));
+            if files
+                .windows(2)
+                .any(|w| w[0].file_name() == w[1].file_name())
+            {
+                return Err(crate::Error::Validation(
+                    "Bundled torrent inputs must have distinct file names".to_string(),
+                ));
+            }
+            let name = config
+                .bundle_name
+                .clone()
+                .filter(|n| !n.trim().is_empty())
+                .unwrap_or_else(|| {
+                    Path::new(&payload[0])
+                        .file_stem()
+                        .map(|s| s.to_string_lossy().into_owned())
+                        .unwrap_or_else(|| input.session_id.clone())
+                });
+            jobs.push((name, files, payload));
+        } else {
+            for path in payload {
+                let name = Path::new(&path)
+                    .file_name()
+                    .map(|n| n.to_string_lossy().into_owned())
+                    .unwrap_or_default();
+                jobs.push((name, vec![PathBuf::from(&path)], vec![path]));
+            }
+        }
+
+        let mut metadata = TorrentMetadata::default();
+        for (name, files, covered) in jobs {
+            if ctx.cancellation_token.is_cancelled() {
+                return Err(crate::Error::PipelineError(
+                    "Torrent creation cancelled".to_string(),
+                ));
+            }
+            let torrent_path = Self::torrent_path(&config, &files[0], &name);
+            match self.create(&config, &name, files, &torrent_path, ctx).await {
+                Ok(info) => {
+                    ctx.info(format!("Created {} ({})", info.path, info.magnet));
+                    if config.torrents_in_outputs {
+                        output.outputs.push(info.path.clone());
+                    }
+                    output.items_produced.push(info.path.clone());
+                    output.succeeded_inputs.extend(covered);
+                    metadata.torrents.push(info);
+                }
+                Err(e) => {
+                    warn!(torrent = %torrent_path.display(), error = %e, "Failed to create torrent");
+                    output
+                        .failed_inputs
+                        .extend(covered.into_iter().map(|p| (p, e.to_string())));
+                }
+            }
+        }
+
+        if output.succeeded_inputs.is_empty() && !output.failed_inputs.is_empty() {
+            return Err(crate::Error::PipelineError(format!(
+                "Torrent creation failed for all {} input(s): {}",
+                output.failed_inputs.len(),
+                output.failed_inputs[0].1
+            )));
+        }
+
+        output.metadata = Some(serde_json::to_string(&metadata)?);
+        output.duration_secs = started.elapsed().as_secs_f64();
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn picks_power_of_two_piece_lengths() {
+        assert_eq!(auto_piece_length(0), BLOCK_SIZE);
+        assert_eq!(auto_piece_length(1500 * 1024 * 1024), 1024 * 1024);
+        assert_eq!(auto_piece_length(u64::MAX / 2), MAX_AUTO_PIECE_LENGTH);
+
+        let config = TorrentConfig {
+            piece_size_kb: Some(48),
+            ..Default::default()
+        };
+        assert!(piece_length(&config, 0).is_err());
+    }
+
+    #[test]
+    fn builds_magnet_with_both_hashes() {
+        let magnet = magnet_link(
+            "live 1.flv",
+            &["udp://tracker.example:80/announce".to_string()],
+            Some("aa"),
+            Some("bb"),
+        );
+        assert_eq!(
+            magnet,
+            "magnet:?xt=urn:btih:aa&xt=urn:btmh:1220bb&dn=live+1.flv\
+             &tr=udp%3A%2F%2Ftracker.example%3A80%2Fannounce"
+        );
+    }
+
+    #[tokio::test]
+    async fn creates_hybrid_bundle_with_pad_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let video = dir.path().join("b.flv");
+        let audio = dir.path().join("a.m4a");
+        std::fs::write(&video, vec![1u8; BLOCK_SIZE + 5]).unwrap();
+        std::fs::write(&audio, vec![2u8; 10]).unwrap();
+        let inputs = vec![
+            video.to_string_lossy().into_owned(),
+            audio.to_string_lossy().into_owned(),
+        ];
+
+        let config = serde_json::json!({
+            "bundle": true,
+            "bundle_name": "session",
+            "piece_size_kb": 16,
+            "trackers": ["http://t.example/announce"],
+        });
+        let input =
+            ProcessorInput::new(inputs.clone(), vec![], "s", "x").with_config(config.to_string());
+        let output = TorrentProcessor::new()
+            .process(&input, &ProcessorContext::noop("job"))
+            .await
+            .unwrap();
+
+        let torrent = dir.path().join("session.torrent");
+        assert_eq!(
+            output.outputs,
+            [inputs.clone(), vec![torrent.to_string_lossy().into_owned()]].concat()
+        );
+        let bytes = std::fs::read(&torrent).unwrap();
+        let text = String::from_utf8_lossy(&bytes);
+        // a.m4a sorts first and is padded to the 16 KiB piece boundary.
+        assert!(text.contains("4:attr1:p6:lengthi16374e4:pathl4:.pad5:16374ee"));
+        assert!(text.contains("12:meta versioni2e"));
+        assert!(text.contains("8:announce25:http://t.example/announce"));
+        // a.m4a + pad is one piece, b.flv two more.
+        assert!(text.contains("6:pieces60:"));
+
+        let metadata: TorrentMetadata =
+            serde_json::from_str(output.metadata.as_deref().unwrap()).unwrap();
+        let info = &metadata.torrents[0];
+        assert_eq!(info.info_hash_v1.as_ref().unwrap().len(), 40);
+        assert_eq!(info.info_hash_v2.as_ref().unwrap().len(), 64);
+    }
+
+    #[tokio::test]
+    async fn skips_non_media_by_default() {
+        let dir = tempfile::tempdir().unwrap();
+        let xml = dir.path().join("live.xml");
+        std::fs::write(&xml, b"<i/>").unwrap();
+        let xml = xml.to_string_lossy().into_owned();
+
+        let input = ProcessorInput::new(vec![xml.clone()], vec![], "s", "x");
+        let output = TorrentProcessor::new()
+            .process(&input, &ProcessorContext::noop("job"))
+            .await
+            .unwrap();
+        assert_eq!(output.outputs, vec![xml]);
+        assert_eq!(output.skipped_inputs.len(), 1);
+        assert!(output.items_produced.is_empty());
+    }
+}
@@ -0,0 +1,419 @@
+//! BitTorrent metainfo construction: bencoding, v1 piece hashes (BEP 3)
+//! and v2 per-file merkle trees (BEP 52).
+
+use std::collections::BTreeMap;
+
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+
+/// v2 leaf block size; also the smallest allowed piece length.
+pub(super) const BLOCK_SIZE: usize = 16 * 1024;
+
+type Hash256 = [u8; 32];
+
+/// Bencoded value. Dictionaries are kept sorted by key as bencoding requires.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum Value {
+    Int(i64),
+    Bytes(Vec<u8>),
+    List(Vec<Value>),
+    Dict(BTreeMap<Vec<u8>, Value>),
+}
+
+impl Value {
+    pub(super) fn str(s: &str) -> Self {
+        Self::Bytes(s.as_bytes().to_vec())
+    }
+
+    pub(super) fn dict<'a>(entries: impl IntoIterator<Item = (&'a str, Value)>) -> Self {
+        Self::Dict(
+            entries
+                .into_iter()
+                .map(|(k, v)| (k.as_bytes().to_vec(), v))
+                .collect(),
+        )
+    }
+
+    pub(super) fn encode(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        self.encode_into(&mut out);
+        out
+    }
+
+    fn encode_into(&self, out: &mut Vec<u8>) {
+        match self {
+            Self::Int(i) => out.extend_from_slice(format!("i{i}e").as_bytes()),
+            Self::Bytes(b) => {
+                out.extend_from_slice(format!("{}:", b.len()).as_bytes());
+                out.extend_from_slice(b);
+            }
+            Self::List(items) => {
+                out.push(b'l');
+                for item in items {
+                    item.encode_into(out);
+                }
+                out.push(b'e');
+            }
+            Self::Dict(entries) => {
+                out.push(b'd');
+                for (key, value) in entries {
+                    Self::Bytes(key.clone()).encode_into(out);
+                    value.encode_into(out);
+                }
+                out.push(b'e');
+            }
+        }
+    }
+}
+
+/// Streaming SHA-1 piece hasher over the concatenated payload.
+pub(super) struct V1Pieces {
+    piece_length: usize,
+    hasher: Sha1,
+    filled: usize,
+    pieces: Vec<u8>,
+}
+
+impl V1Pieces {
+    pub(super) fn new(piece_length: usize) -> Self {
+        Self {
+            piece_length,
+            hasher: Sha1::new(),
+            filled: 0,
+            pieces: Vec::new(),
+        }
+    }
+
+    pub(super) fn update(&mut self, mut data: &[u8]) {
+        while !data.is_empty() {
+            let take = (self.piece_length - self.filled).min(data.len());
+            self.hasher.update(&data[..take]);
+            self.filled += take;
+            data = &data[take..];
+            if self.filled == self.piece_length {
+                self.flush();
+            }
+        }
+    }
+
+    /// Feed zeros up to the next piece boundary, as a hybrid torrent's pad
+    /// file does, and return how many bytes were fed.
+    pub(super) fn pad_to_boundary(&mut self) -> u64 {
+        if self.filled == 0 {
+            return 0;
+        }
+        let pad = self.piece_length - self.filled;
+        self.update(&vec![0u8; pad]);
+        pad as u64
+    }
+
+    pub(super) fn finish(mut self) -> Vec<u8> {
+        if self.filled > 0 {
+            self.flush();
+        }
+        self.pieces
+    }
+
+    fn flush(&mut self) {
+        let hasher = std::mem::replace(&mut self.hasher, Sha1::new());
+        self.pieces.extend_from_slice(&hasher.finalize());
+        self.filled = 0;
+    }
+}
+
+/// v2 hashes of one file.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct V2File {
+    /// Merkle root; `None` for an empty file.
+    pub(super) pieces_root: Option<Hash256>,
+    /// Concatenated piece-layer hashes; empty unless the file is larger
+    /// than one piece.
+    pub(super) piece_layer: Vec<u8>,
+}
+
+/// Streaming SHA-256 leaf hasher for one file.
+pub(super) struct V2Hasher {
+    block: Vec<u8>,
+    leaves: Vec<Hash256>,
+}
+
+impl V2Hasher {
+    pub(super) fn new() -> Self {
+        Self {
+            block: Vec::with_capacity(BLOCK_SIZE),
+            leaves: Vec::new(),
+        }
+    }
+
+    pub(super) fn update(&mut self, mut data: &[u8]) {
+        while !data.is_empty() {
+            let take = (BLOCK_SIZE - self.block.len()).min(data.len());
+            self.block.extend_from_slice(&data[..take]);
+            data = &data[take..];
+            if self.block.len() == BLOCK_SIZE {
+                self.leaves.push(Sha256::digest(&self.block).into());
+                self.block.clear();
+            }
+        }
+    }
+
+    pub(super) fn finish(mut self, piece_length: usize) -> V2File {
+        // The last block is hashed as-is, without padding.
+        if !self.block.is_empty() {
+            self.leaves.push(Sha256::digest(&self.block).into());
+        }
+        if self.leaves.is_empty() {
+            return V2File {
+                pieces_root: None,
+                piece_layer: Vec::new(),
+            };
+        }
+
+        let blocks_per_piece = piece_length / BLOCK_SIZE;
+        if self.leaves.len() <= blocks_per_piece {
+            let width = self.leaves.len().next_power_of_two();
+            return V2File {
+                pieces_root: Some(merkle_root(self.leaves, width, [0; 32])),
+                piece_layer: Vec::new(),
+            };
+        }
+
+        let layer: Vec<Hash256> = self
+            .leaves
+            .chunks(blocks_per_piece)
+            .map(|piece| merkle_root(piece.to_vec(), blocks_per_piece, [0; 32]))
+            .collect();
+        let piece_layer = layer.concat();
+        let zero_piece = merkle_root(Vec::new(), blocks_per_piece, [0; 32]);
+        let width = layer.len().next_power_of_two();
+        V2File {
+            pieces_root: Some(merkle_root(layer, width, zero_piece)),
+            piece_layer,
+        }
+    }
+}
+
+/// Root of a binary SHA-256 tree over `nodes` padded with `pad` to
+/// `width` (a power of two).
+fn merkle_root(mut nodes: Vec<Hash256>, width: usize, pad: Hash256) -> Hash256 {
+    nodes.resize(width.max(1), pad);
+    while nodes.len() > 1 {
+        nodes = nodes
+            .chunks(2)
+            .map(|pair| {
+                let mut hasher = Sha256::new();
+                hasher.update(pair[0]);
+                hasher.update(pair[1]);
+                hasher.finalize().into()
+            })
+            .collect();
+    }
+    nodes[0]
+}
+
+/// One payload file after hashing.
+pub(super) struct HashedFile {
+    pub(super) name: String,
+    pub(super) length: u64,
+    pub(super) v2: Option<V2File>,
+    /// Zero bytes padding this file to a piece boundary in the v1 payload
+    /// of a hybrid torrent.
+    pub(super) pad: u64,
+}
+
+/// Top-level fields besides `info`.
+pub(super) struct MetainfoOptions<'a> {
+    pub(super) name: &'a str,
+    pub(super) piece_length: usize,
+    pub(super) trackers: &'a [String],
+    pub(super) web_seeds: &'a [String],
+    pub(super) private: bool,
+    pub(super) comment: Option<&'a str>,
+    pub(super) source: Option<&'a str>,
+    pub(super) creation_date: i64,
+}
+
+pub(super) struct Metainfo {
+    pub(super) bytes: Vec<u8>,
+    pub(super) info_hash_v1: Option<String>,
+    pub(super) info_hash_v2: Option<String>,
+}
+
+/// Build the `.torrent` bytes. `v1_pieces` is set for v1 and hybrid
+/// torrents; files carry v2 hashes for v2 and hybrid torrents.
+pub(super) fn build(
+    options: &MetainfoOptions<'_>,
+    files: &[HashedFile],
+    v1_pieces: Option<Vec<u8>>,
+) -> Metainfo {
+    let has_v2 = files.iter().all(|f| f.v2.is_some());
+    let mut info = BTreeMap::new();
+    let mut put = |key: &str, value: Value| {
+        info.insert(key.as_bytes().to_vec(), value);
+    };
+    put("name", Value::str(options.name));
+    put("piece length", Value::Int(options.piece_length as i64));
+    if options.private {
+        put("private", Value::Int(1));
+    }
+    if let Some(source) = options.source {
+        put("source", Value::str(source));
+    }
+
+    let has_v1 = v1_pieces.is_some();
+    if let Some(pieces) = v1_pieces {
+        put("pieces", Value::Bytes(pieces));
+        if let [file] = files {
+            put("length", Value::Int(file.length as i64));
+        } else {
+            let mut list = Vec::new();
+            for (index, file) in files.iter().enumerate() {
+                list.push(Value::dict([
+                    ("length", Value::Int(file.length as i64)),
+                    ("path", Value::List(vec![Value::str(&file.name)])),
+                ]));
+                if file.pad > 0 && index + 1 < files.len() {
+                    list.push(Value::dict([
+                        ("attr", Value::str("p")),
+                        ("length", Value::Int(file.pad as i64)),
+                        (
+                            "path",
+                            Value::List(vec![
+                                Value::str(".pad"),
+                                Value::str(&file.pad.to_string()),
+                            ]),
+                        ),
+                    ]));
+                }
+            }
+            put("files", Value::List(list));
+        }
+    }
+
+    let mut piece_layers = BTreeMap::new();
+    if has_v2 {
+        put("meta version", Value::Int(2));
+        let mut tree = BTreeMap::new();
+        for file in files {
+            let v2 = file.v2.as_ref().expect("checked above");
+            let mut entry = vec![("length", Value::Int(file.length as i64))];
+            if let Some(root) = v2.pieces_root {
+                entry.push(("pieces root", Value::Bytes(root.to_vec())));
+                if !v2.piece_layer.is_empty() {
+                    piece_layers.insert(root.to_vec(), Value::Bytes(v2.piece_layer.clone()));
+                }
+            }
+            tree.insert(
+                file.name.as_bytes().to_vec(),
+                Value::dict([("", Value::dict(entry))]),
+            );
+        }
+        put("file tree", Value::Dict(tree));
+    }
+
+    let info = Value::Dict(info);
+    let info_bytes = info.encode();
+    let mut root = vec![
+        (
+            "created by",
+            Value::str(concat!("rust-srec/", env!("CARGO_PKG_VERSION"))),
+        ),
+        ("creation date", Value::Int(options.creation_date)),
+        ("info", info),
+    ];
+    if let Some(first) = options.trackers.first() {
+        root.push(("announce", Value::str(first)));
+        if options.trackers.len() > 1 {
+            root.push((
+                "announce-list",
+                Value::List(
+                    options
+                        .trackers
+                        .iter()
+                        .map(|t| Value::List(vec![Value::str(t)]))
+                        .collect(),
+                ),
+            ));
+        }
+    }
+    if let Some(comment) = options.comment {
+        root.push(("comment", Value::str(comment)));
+    }
+    if !options.web_seeds.is_empty() {
+        root.push((
+            "url-list",
+            Value::List(options.web_seeds.iter().map(|u| Value::str(u)).collect()),
+        ));
+    }
+    if has_v2 {
+        root.push(("piece layers", Value::Dict(piece_layers)));
+    }
+
+    Metainfo {
+        bytes: Value::dict(root).encode(),
+        info_hash_v1: has_v1.then(|| hex::encode(Sha1::digest(&info_bytes))),
+        info_hash_v2: has_v2.then(|| hex::encode(Sha256::digest(&info_bytes))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bencodes_sorted_dicts() {
+        let value = Value::dict([
+            ("zeta", Value::Int(-3)),
+            (
+                "alpha",
+                Value::List(vec![Value::str("spam"), Value::Int(0)]),
+            ),
+        ]);
+        assert_eq!(value.encode(), b"d5:alphal4:spami0ee4:zetai-3ee");
+    }
+
+    #[test]
+    fn v1_pieces_hash_each_piece() {
+        let piece = BLOCK_SIZE;
+        let data = vec![7u8; piece + 10];
+        let mut pieces = V1Pieces::new(piece);
+        pieces.update(&data[..100]);
+        pieces.update(&data[100..]);
+        let expected = [
+            Sha1::digest(&data[..piece]).to_vec(),
+            Sha1::digest(&data[piece..]).to_vec(),
+        ]
+        .concat();
+        assert_eq!(pieces.finish(), expected);
+    }
+
+    #[test]
+    fn v2_root_matches_full_tree_over_padded_leaves() {
+        let piece = 2 * BLOCK_SIZE;
+        let data: Vec<u8> = (0..5 * BLOCK_SIZE + 100).map(|i| (i % 251) as u8).collect();
+        let mut hasher = V2Hasher::new();
+        hasher.update(&data);
+        let file = hasher.finish(piece);
+
+        let leaves: Vec<Hash256> = data
+            .chunks(BLOCK_SIZE)
+            .map(|b| Sha256::digest(b).into())
+            .collect();
+        assert_eq!(leaves.len(), 6);
+        assert_eq!(file.pieces_root, Some(merkle_root(leaves, 8, [0; 32])));
+        // Three pieces of two blocks each.
+        assert_eq!(file.piece_layer.len(), 3 * 32);
+    }
+
+    #[test]
+    fn small_and_empty_files_have_no_piece_layer() {
+        let mut hasher = V2Hasher::new();
+        hasher.update(b"abc");
+        let file = hasher.finish(BLOCK_SIZE * 4);
+        assert_eq!(file.pieces_root, Some(Sha256::digest(b"abc").into()));
+        assert!(file.piece_layer.is_empty());
+
+        let empty = V2Hasher::new().finish(BLOCK_SIZE);
+        assert_eq!(empty.pieces_root, None);
+    }
+}
@@ -9,11 +9,13 @@ use std::process::Stdio;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use tokio::io::{AsyncBufReadExt, BufReader};
-use tokio::process::Command;
+use tokio::process::{Child, Command};
 use tracing::{debug, warn};
 
-use super::traits::ProcessorContext;
-use process_utils::NoWindowExt;
+use super::traits::{ProcessorContext, ProcessorInput, TimeAnchor};
+use process_utils::{
+    NoWindowExt, ProcessGroupExt, ProcessScheduling, ProcessSchedulingExt, ProcessTree,
+};
 
 const LOG_CHANNEL_CAPACITY: usize = 1024;
 const MAX_LOG_ENTRIES: usize = 2000;
@@ -145,18 +147,153 @@ fn push_log_with_cap(
     logs.push_back(entry);
 }
 
+/// Expand a free-text template (upload titles, descriptions) with session
+/// metadata.
+///
+/// Unlike [`crate::utils::filename::expand_placeholders_at`], values are
+/// inserted verbatim instead of being sanitized for the filesystem. Time
+/// tokens are expanded before the metadata so a `%` inside a stream title is
+/// kept as is.
+pub fn expand_text_template(
+    template: &str,
+    input: &ProcessorInput,
+    time_anchor: TimeAnchor,
+) -> String {
+    let expanded = pipeline_common::expand_path_template_at(
+        template,
+        Some(time_anchor.reference_time(input).timestamp_millis()),
+    );
+    expanded
+        .replace(
+            "{streamer}",
+            input.streamer_name.as_deref().unwrap_or(&input.streamer_id),
+        )
+        .replace(
+            "{title}",
+            input.session_title.as_deref().unwrap_or_default(),
+        )
+        .replace("{streamer_id}", &input.streamer_id)
+        .replace("{session_id}", &input.session_id)
+        .replace("{platform}", input.platform.as_deref().unwrap_or_default())
+}
+
+/// Running byte totals for upload progress reporting across a batch.
+pub struct UploadProgress<'a> {
+    ctx: &'a ProcessorContext,
+    bytes_total: u64,
+    bytes_done: u64,
+    started: std::time::Instant,
+}
+
+impl<'a> UploadProgress<'a> {
+    pub fn new(ctx: &'a ProcessorContext, bytes_total: u64) -> Self {
+        Self {
+            ctx,
+            bytes_total,
+            bytes_done: 0,
+            started: std::time::Instant::now(),
+        }
+    }
+
+    /// Whether the job was cancelled; uploads check this between requests.
+    pub fn is_cancelled(&self) -> bool {
+        self.ctx.cancellation_token.is_cancelled()
+    }
+
+    pub fn advance(&mut self, bytes: u64) {
+        self.bytes_done = self.bytes_done.saturating_add(bytes);
+        let elapsed = self.started.elapsed().as_secs_f64();
+
+        let mut snapshot = JobProgressSnapshot::new(ProgressKind::Upload);
+        snapshot.bytes_done = Some(self.bytes_done);
+        snapshot.bytes_total = Some(self.bytes_total);
+        if self.bytes_total > 0 {
+            snapshot.percent =
+                Some((self.bytes_done as f64 / self.bytes_total as f64 * 100.0) as f32);
+        }
+        if elapsed > 0.0 {
+            let speed = self.bytes_done as f64 / elapsed;
+            snapshot.speed_bytes_per_sec = Some(speed);
+            if speed > 0.0 {
+                snapshot.eta_secs =
+                    Some(self.bytes_total.saturating_sub(self.bytes_done) as f64 / speed);
+            }
+        }
+        self.ctx.progress.report(snapshot);
+    }
+}
+
+/// Hex SHA-256 digest of a file, read in 1 MiB blocks.
+pub async fn sha256_file(path: &Path) -> std::io::Result<String> {
+    use sha2::{Digest, Sha256};
+    use tokio::io::AsyncReadExt;
+
+    let mut file = tokio::fs::File::open(path).await?;
+    let mut hasher = Sha256::new();
+    let mut buffer = vec![0u8; 1024 * 1024];
+    loop {
+        let read = file.read(&mut buffer).await?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+    Ok(hex::encode(hasher.finalize()))
+}
+
 /// Helper function to create a log entry.
 pub fn create_log_entry(level: LogLevel, message: impl Into<String>) -> JobLogEntry {
     JobLogEntry::new(level, message)
 }
 
+tokio::task_local! {
+    /// Scheduling of the processes spawned by the job running on this task.
+    static PROCESS_SCHEDULING: Arc<ProcessScheduling>;
+}
+
+/// Run a job future so the processes it spawns through this module use
+/// `scheduling`, letting a worker pool deprioritize its jobs' ffmpeg/rclone
+/// processes without every processor passing the settings along.
+pub async fn with_process_scheduling<F: std::future::Future>(
+    scheduling: Arc<ProcessScheduling>,
+    future: F,
+) -> F::Output {
+    PROCESS_SCHEDULING.scope(scheduling, future).await
+}
+
 /// Child-process settings shared by every spawn helper in this module:
 /// hide the console window on Windows and kill the child when the owning
 /// future is dropped, because worker cancellation and job timeouts drop
 /// the processor future mid-run and the child must not outlive its job.
 fn configure_child_process(command: &mut Command) {
     command.no_window();
+    command.own_process_group();
     command.kill_on_drop(true);
+    let _ = PROCESS_SCHEDULING.try_with(|scheduling| {
+        command.scheduling(scheduling);
+    });
+}
+
+/// Spawn a command set up by [`configure_child_process`], together with a
+/// guard that kills everything the child started when the owning future is
+/// dropped. `kill_on_drop` alone only reaches the direct child, so e.g. an
+/// ffmpeg started by a user script would keep writing after cancellation.
+///
+/// The guard is `None` if the tree cannot be tracked, leaving only
+/// `kill_on_drop`. Release it once the child exits on its own.
+fn spawn_child_process(command: &mut Command) -> crate::Result<(Child, Option<ProcessTree>)> {
+    let child = command
+        .spawn()
+        .map_err(|e| crate::Error::Other(format!("Failed to spawn command: {}", e)))?;
+    let _ = PROCESS_SCHEDULING.try_with(|scheduling| {
+        if let Err(e) = scheduling.apply_to_tokio(&child) {
+            warn!(error = %e, "Failed to apply child process scheduling");
+        }
+    });
+    let tree = ProcessTree::from_tokio(&child)
+        .inspect_err(|e| warn!(error = %e, "Failed to track child process tree"))
+        .ok();
+    Ok((child, tree))
 }
 
 /// Build a sibling temp path for `final_path` (`<name>.tmp-<uuid>`).
@@ -185,9 +322,7 @@ pub async fn run_command_with_logs(
     command.stdout(Stdio::piped());
     command.stderr(Stdio::piped());
 
-    let mut child = command
-        .spawn()
-        .map_err(|e| crate::Error::Other(format!("Failed to spawn command: {}", e)))?;
+    let (mut child, mut process_tree) = spawn_child_process(command)?;
 
     let (tx, mut rx) = tokio::sync::mpsc::channel::<JobLogEntry>(LOG_CHANNEL_CAPACITY);
     let dropped_count = Arc::new(AtomicUsize::new(0));
@@ -285,6 +420,11 @@ pub async fn run_command_with_logs(
         }
     }
 
+    // The child exited on its own; leave anything it detached running.
+    if let Some(tree) = &mut process_tree {
+        tree.release();
+    }
+
     // Wait for reader tasks to complete to ensure streams are fully consumed.
     let stdout_result = wait_for_reader_task("command stdout", stdout_handle).await;
     let stderr_result = wait_for_reader_task("command stderr", stderr_handle).await;
@@ -498,9 +638,7 @@ pub async fn run_ffmpeg_with_progress(
     command.stdout(Stdio::piped());
     command.stderr(Stdio::piped());
 
-    let mut child = command
-        .spawn()
-        .map_err(|e| crate::Error::Other(format!("Failed to spawn command: {}", e)))?;
+    let (mut child, mut process_tree) = spawn_child_process(command)?;
 
     let (tx, mut rx) = tokio::sync::mpsc::channel::<JobLogEntry>(LOG_CHANNEL_CAPACITY);
     let dropped_count = Arc::new(AtomicUsize::new(0));
@@ -572,6 +710,11 @@ pub async fn run_ffmpeg_with_progress(
         }
     }
 
+    // The child exited on its own; leave anything it detached running.
+    if let Some(tree) = &mut process_tree {
+        tree.release();
+    }
+
     let stdout_result = wait_for_reader_task("ffmpeg progress", stdout_handle).await;
     let stderr_result = wait_for_reader_task("ffmpeg stderr", stderr_handle).await;
     stdout_result?;
@@ -615,9 +758,7 @@ pub async fn run_rclone_with_progress(
     command.stdout(Stdio::null());
     command.stderr(Stdio::piped());
 
-    let mut child = command
-        .spawn()
-        .map_err(|e| crate::Error::Other(format!("Failed to spawn command: {}", e)))?;
+    let (mut child, mut process_tree) = spawn_child_process(command)?;
 
     let (tx, mut rx) = tokio::sync::mpsc::channel::<JobLogEntry>(LOG_CHANNEL_CAPACITY);
     let dropped_count = Arc::new(AtomicUsize::new(0));
@@ -677,6 +818,11 @@ pub async fn run_rclone_with_progress(
         }
     }
 
+    // The child exited on its own; leave anything it detached running.
+    if let Some(tree) = &mut process_tree {
+        tree.release();
+    }
+
     wait_for_reader_task("rclone stderr", stderr_handle).await?;
 
     let duration = start.elapsed().as_secs_f64();
@@ -705,8 +851,35 @@ pub async fn run_rclone_with_progress(
 
 #[cfg(test)]
 mod tests {
+    use super::super::test_utils::utc_datetime;
     use super::*;
 
+    #[test]
+    fn text_template_keeps_metadata_verbatim() {
+        let input = ProcessorInput::new(vec![], vec![], "streamer-1", "session-1")
+            .with_streamer_name("Alice/Bob")
+            .with_session_title("100% speedrun: any%")
+            .with_platform("Twitch")
+            .with_created_at(utc_datetime(2024, 3, 5, 12, 0, 0));
+
+        assert_eq!(
+            expand_text_template(
+                "[{platform}] {streamer} - {title}",
+                &input,
+                TimeAnchor::JobCreated
+            ),
+            "[Twitch] Alice/Bob - 100% speedrun: any%"
+        );
+        assert_eq!(
+            expand_text_template(
+                "{streamer_id}/{session_id} %Y",
+                &input,
+                TimeAnchor::JobCreated
+            ),
+            "streamer-1/session-1 2024"
+        );
+    }
+
     #[test]
     fn test_determine_ffmpeg_log_level() {
         assert_eq!(
@@ -740,4 +913,22 @@ mod tests {
             LogLevel::Info
         );
     }
+    #[cfg(target_os = "linux")]
+    #[tokio::test]
+    async fn test_process_scheduling_applies_to_spawned_commands() {
+        let scheduling = Arc::new(ProcessScheduling {
+            priority: process_utils::ProcessPriority::Idle,
+            cpu_affinity: Vec::new(),
+        });
+        let mut command = Command::new("cat");
+        command.arg("/proc/self/stat");
+
+        let output = with_process_scheduling(scheduling, run_command_with_logs(&mut command, None))
+            .await
+            .unwrap();
+
+        // `nice` is the 19th field of /proc/<pid>/stat
+        let (_, fields) = output.logs[0].message.rsplit_once(") ").unwrap();
+        assert_eq!(fields.split(' ').nth(16), Some("19"));
+    }
 }
@@ -0,0 +1,535 @@
+//! HTTP webhook processor.
+//!
+//! Sends a JSON description of the step's inputs (paths, sizes, SHA-256
+//! checksums) and the session metadata to a user URL, so external automation
+//! (n8n, custom services) can act on a recording as a node of a DAG. By
+//! default the step waits for a 2xx response and fails otherwise; inputs are
+//! passed through unchanged.
+//!
+//! Payload:
+//! ```json
+//! {
+//!   "event": "pipeline_step",
+//!   "job_id": "...",
+//!   "streamer": { "id": "...", "name": "..." },
+//!   "session": { "id": "...", "title": "...", "platform": "...", "start_time": "..." },
+//!   "files": [{ "path": "...", "name": "...", "size": 123, "sha256": "..." }]
+//! }
+//! ```
+//! Entries of `extra` are added at the top level; their string values
+//! support `{streamer}`, `{title}`, `{streamer_id}`, `{session_id}`,
+//! `{platform}` and time placeholders.
+
+use std::path::Path;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+
+use super::traits::{
+    Processor, ProcessorContext, ProcessorInput, ProcessorOutput, ProcessorType, TimeAnchor,
+};
+use super::utils::{create_log_entry, expand_text_template, sha256_file};
+use crate::Result;
+use crate::notification::channels::WebhookAuth;
+use crate::pipeline::job_queue::LogLevel;
+
+/// Longest response body kept in the job metadata and logs.
+const MAX_RESPONSE_CHARS: usize = 2000;
+
+/// Configuration for the webhook processor.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WebhookProcessorConfig {
+    /// Target URL. Required.
+    pub url: String,
+
+    /// `POST` or `PUT`.
+    pub method: String,
+
+    /// Extra request headers.
+    pub headers: Vec<(String, String)>,
+
+    pub auth: Option<WebhookAuth>,
+
+    /// Fields added to the payload. String values support placeholder
+    /// expansion.
+    pub extra: serde_json::Map<String, serde_json::Value>,
+
+    /// Include the SHA-256 of each input file.
+    pub checksums: bool,
+
+    /// Fail the step unless the endpoint answers with a 2xx status. When
+    /// disabled, the request is sent once and any failure only logs a
+    /// warning.
+    pub wait_for_success: bool,
+
+    /// JSON pointer (e.g. `/ok`) into the response body that must be `true`
+    /// for the call to count as successful.
+    pub success_pointer: Option<String>,
+
+    /// Request timeout in seconds.
+    pub timeout_secs: u64,
+
+    /// Retries for network errors and non-2xx responses.
+    pub max_retries: u32,
+
+    /// Timestamp source for time placeholder expansion.
+    pub time_anchor: TimeAnchor,
+}
+
+impl Default for WebhookProcessorConfig {
+    fn default() -> Self {
+        Self {
+            url: String::new(),
+            method: "POST".to_string(),
+            headers: Vec::new(),
+            auth: None,
+            extra: serde_json::Map::new(),
+            checksums: true,
+            wait_for_success: true,
+            success_pointer: None,
+            timeout_secs: 30,
+            max_retries: 3,
+            time_anchor: TimeAnchor::default(),
+        }
+    }
+}
+
+impl WebhookProcessorConfig {
+    fn validate(&self) -> Result<reqwest::Method> {
+        let url = url::Url::parse(&self.url).map_err(|e| {
+            crate::Error::Validation(format!("Invalid webhook URL '{}': {e}", self.url))
+        })?;
+        if !matches!(url.scheme(), "http" | "https") {
+            return Err(crate::Error::Validation(format!(
+                "Webhook URL must use http or https: {}",
+                self.url
+            )));
+        }
+        match self.method.to_uppercase().as_str() {
+            "POST" => Ok(reqwest::Method::POST),
+            "PUT" => Ok(reqwest::Method::PUT),
+            other => Err(crate::Error::Validation(format!(
+                "Unsupported webhook method: {other}"
+            ))),
+        }
+    }
+
+    fn header_map(&self) -> Result<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        let mut insert = |name: &str, value: &str| -> Result<()> {
+            let name = name.parse::<HeaderName>().map_err(|e| {
+                crate::Error::Validation(format!("Invalid webhook header name '{name}': {e}"))
+            })?;
+            let value = value.parse::<HeaderValue>().map_err(|e| {
+                crate::Error::Validation(format!("Invalid value for webhook header {name}: {e}"))
+            })?;
+            headers.insert(name, value);
+            Ok(())
+        };
+        for (name, value) in &self.headers {
+            insert(name, value)?;
+        }
+        match &self.auth {
+            Some(WebhookAuth::Bearer { token }) => {
+                insert("authorization", &format!("Bearer {token}"))?
+            }
+            Some(WebhookAuth::Header { name, value }) => insert(name, value)?,
+            Some(WebhookAuth::Basic { .. }) | None => {}
+        }
+        Ok(headers)
+    }
+
+    /// Whether a response body passes `success_pointer`.
+    fn response_ok(&self, body: &str) -> bool {
+        let Some(pointer) = self.success_pointer.as_deref().filter(|p| !p.is_empty()) else {
+            return true;
+        };
+        serde_json::from_str::<serde_json::Value>(body)
+            .ok()
+            .and_then(|json| json.pointer(pointer).cloned())
+            .is_some_and(|value| value == serde_json::Value::Bool(true))
+    }
+}
+
+/// Processor that calls an HTTP webhook.
+pub struct WebhookProcessor;
+
+impl WebhookProcessor {
+    pub fn new() -> Self {
+        Self
+    }
+
+    async fn build_payload(
+        input: &ProcessorInput,
+        ctx: &ProcessorContext,
+        config: &WebhookProcessorConfig,
+    ) -> Result<serde_json::Value> {
+        let mut files = Vec::with_capacity(input.inputs.len());
+        for path in &input.inputs {
+            let file_path = Path::new(path);
+            let size = tokio::fs::metadata(file_path).await.ok().map(|m| m.len());
+            let sha256 =
+                if config.checksums && size.is_some() {
+                    Some(sha256_file(file_path).await.map_err(|e| {
+                        crate::Error::io_path("hashing webhook input", file_path, e)
+                    })?)
+                } else {
+                    None
+                };
+            files.push(serde_json::json!({
+                "path": path,
+                "name": file_path.file_name().map(|n| n.to_string_lossy()),
+                "size": size,
+                "sha256": sha256,
+            }));
+        }
+
+        let mut payload = serde_json::json!({
+            "event": "pipeline_step",
+            "job_id": ctx.job_id,
+            "streamer": {
+                "id": input.streamer_id,
+                "name": input.streamer_name,
+            },
+            "session": {
+                "id": input.session_id,
+                "title": input.session_title,
+                "platform": input.platform,
+                "start_time": input.session_start.map(|t| t.to_rfc3339()),
+            },
+            "files": files,
+            "timestamp": chrono::Utc::now().to_rfc3339(),
+        });
+        if let Some(object) = payload.as_object_mut() {
+            for (key, value) in &config.extra {
+                object.insert(key.clone(), expand_value(value, input, config.time_anchor));
+            }
+        }
+        Ok(payload)
+    }
+
+    /// Send the payload once. Returns the status and (truncated) body.
+    async fn send(
+        client: &reqwest::Client,
+        method: &reqwest::Method,
+        config: &WebhookProcessorConfig,
+        headers: &HeaderMap,
+        payload: &serde_json::Value,
+    ) -> std::result::Result<(u16, String), String> {
+        let mut request = client
+            .request(method.clone(), &config.url)
+            .headers(headers.clone())
+            .json(payload);
+        if let Some(WebhookAuth::Basic { username, password }) = &config.auth {
+            request = request.basic_auth(username, Some(password));
+        }
+
+        let response = request.send().await.map_err(|e| e.to_string())?;
+        let status = response.status();
+        let body: String = response
+            .text()
+            .await
+            .unwrap_or_default()
+            .chars()
+            .take(MAX_RESPONSE_CHARS)
+            .collect();
+
+        if !status.is_success() {
+            return Err(format!("HTTP {status}: {body}"));
+        }
+        if !config.response_ok(&body) {
+            return Err(format!(
+                "response did not set {} to true: {body}",
+                config.success_pointer.as_deref().unwrap_or_default()
+            ));
+        }
+        Ok((status.as_u16(), body))
+    }
+}
+
+impl Default for WebhookProcessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Expand placeholders in every string inside `value`.
+fn expand_value(
+    value: &serde_json::Value,
+    input: &ProcessorInput,
+    time_anchor: TimeAnchor,
+) -> serde_json::Value {
+    match value {
+        serde_json::Value::String(s) => {
+            serde_json::Value::String(expand_text_template(s, input, time_anchor))
+        }
+        serde_json::Value::Array(items) => serde_json::Value::Array(
+            items
+                .iter()
+                .map(|item| expand_value(item, input, time_anchor))
+                .collect(),
+        ),
+        serde_json::Value::Object(map) => serde_json::Value::Object(
+            map.iter()
+                .map(|(k, v)| (k.clone(), expand_value(v, input, time_anchor)))
+                .collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
+#[async_trait]
+impl Processor for WebhookProcessor {
+    fn processor_type(&self) -> ProcessorType {
+        ProcessorType::Io
+    }
+
+    fn job_types(&self) -> Vec<&'static str> {
+        vec!["webhook"]
+    }
+
+    fn name(&self) -> &'static str {
+        "WebhookProcessor"
+    }
+
+    fn supports_batch_input(&self) -> bool {
+        true
+    }
+
+    async fn process(
+        &self,
+        input: &ProcessorInput,
+        ctx: &ProcessorContext,
+    ) -> Result<ProcessorOutput> {
+        let start = std::time::Instant::now();
+
+        let config: WebhookProcessorConfig = match input.config.as_deref() {
+            Some(s) => serde_json::from_str(s).map_err(|e| {
+                crate::Error::Validation(format!("Invalid webhook config JSON: {e}"))
+            })?,
+            None => WebhookProcessorConfig::default(),
+        };
+        let method = config.validate()?;
+        let headers = config.header_map()?;
+
+        let payload = Self::build_payload(input, ctx, &config).await?;
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(config.timeout_secs.max(1)))
+            .build()
+            .map_err(|e| crate::Error::Other(format!("Failed to build webhook client: {e}")))?;
+
+        let attempts = if config.wait_for_success {
+            config.max_retries + 1
+        } else {
+            1
+        };
+        let mut logs = Vec::new();
+        let mut result = Err(String::new());
+        for attempt in 1..=attempts {
+            if ctx.cancellation_token.is_cancelled() {
+                return Err(crate::Error::PipelineError("Webhook cancelled".to_string()));
+            }
+            result = Self::send(&client, &method, &config, &headers, &payload).await;
+            match &result {
+                Ok(_) => break,
+                Err(e) if attempt < attempts => {
+                    let message = format!("Webhook attempt {attempt}/{attempts} failed: {e}");
+                    warn!("{message}");
+                    logs.push(create_log_entry(LogLevel::Warn, message));
+                    tokio::time::sleep(Duration::from_secs(2u64.pow(attempt.min(6)))).await;
+                }
+                Err(_) => {}
+            }
+        }
+
+        let (status, body) = match result {
+            Ok(response) => response,
+            Err(e) if config.wait_for_success => {
+                return Err(crate::Error::PipelineError(format!(
+                    "Webhook {} failed after {attempts} attempt(s): {e}",
+                    config.url
+                )));
+            }
+            Err(e) => {
+                let message = format!("Webhook {} failed (not waited on): {e}", config.url);
+                warn!("{message}");
+                logs.push(create_log_entry(LogLevel::Warn, message));
+                (0, String::new())
+            }
+        };
+        if status != 0 {
+            let message = format!("Webhook {} answered HTTP {status}", config.url);
+            info!("{message}");
+            logs.push(create_log_entry(LogLevel::Info, message));
+        }
+
+        // Prefer structured JSON so later steps and the UI can read fields.
+        let response = serde_json::from_str::<serde_json::Value>(&body)
+            .unwrap_or(serde_json::Value::String(body));
+        Ok(ProcessorOutput {
+            outputs: input.inputs.clone(),
+            duration_secs: start.elapsed().as_secs_f64(),
+            metadata: Some(
+                serde_json::json!({
+                    "url": config.url,
+                    "status": (status != 0).then_some(status),
+                    "response": response,
+                })
+                .to_string(),
+            ),
+            succeeded_inputs: input.inputs.clone(),
+            logs,
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use axum::{Json, Router, extract::State, http::HeaderMap as AxumHeaderMap, routing::post};
+    use tokio::net::TcpListener;
+
+    use super::super::test_utils::utc_datetime;
+    use super::*;
+
+    type Received = Arc<Mutex<Vec<(Option<String>, serde_json::Value)>>>;
+
+    async fn serve(app: Router) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+        format!("http://{addr}")
+    }
+
+    fn recording_app(received: Received) -> Router {
+        Router::new()
+            .route(
+                "/hook",
+                post(
+                    |State(received): State<Received>,
+                     headers: AxumHeaderMap,
+                     Json(body): Json<serde_json::Value>| async move {
+                        let auth = headers
+                            .get("authorization")
+                            .and_then(|v| v.to_str().ok())
+                            .map(str::to_string);
+                        received.lock().unwrap().push((auth, body));
+                        Json(serde_json::json!({ "ok": true, "id": 7 }))
+                    },
+                ),
+            )
+            .route(
+                "/rejecting",
+                post(|| async { Json(serde_json::json!({ "ok": false })) }),
+            )
+            .with_state(received)
+    }
+
+    fn input_with(config: serde_json::Value, path: &str) -> ProcessorInput {
+        ProcessorInput::new(vec![path.to_string()], vec![], "streamer-1", "session-1")
+            .with_streamer_name("Alice")
+            .with_session_title("Speedrun")
+            .with_created_at(utc_datetime(2024, 3, 5, 12, 0, 0))
+            .with_config(config.to_string())
+    }
+
+    #[tokio::test]
+    async fn posts_payload_with_checksums_and_extra_fields() {
+        crate::utils::http_client::install_rustls_provider();
+        let received: Received = Arc::default();
+        let base = serve(recording_app(received.clone())).await;
+
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("live.mp4");
+        std::fs::write(&file, b"abc").unwrap();
+        let path = file.to_string_lossy().into_owned();
+
+        let input = input_with(
+            serde_json::json!({
+                "url": format!("{base}/hook"),
+                "auth": { "type": "Bearer", "token": "secret" },
+                "extra": { "label": "{streamer} %Y", "n": 1 },
+                "success_pointer": "/ok",
+            }),
+            &path,
+        );
+        let output = WebhookProcessor::new()
+            .process(&input, &ProcessorContext::noop("job-1"))
+            .await
+            .unwrap();
+
+        assert_eq!(output.outputs, vec![path.clone()]);
+        let metadata: serde_json::Value =
+            serde_json::from_str(output.metadata.as_deref().unwrap()).unwrap();
+        assert_eq!(metadata["status"], 200);
+        assert_eq!(metadata["response"]["id"], 7);
+
+        let received = received.lock().unwrap();
+        let (auth, body) = &received[0];
+        assert_eq!(auth.as_deref(), Some("Bearer secret"));
+        assert_eq!(body["job_id"], "job-1");
+        assert_eq!(body["streamer"]["name"], "Alice");
+        assert_eq!(body["files"][0]["path"], path);
+        assert_eq!(body["files"][0]["size"], 3);
+        assert_eq!(
+            body["files"][0]["sha256"],
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_eq!(body["label"], "Alice 2024");
+        assert_eq!(body["n"], 1);
+    }
+
+    #[tokio::test]
+    async fn failed_success_pointer_fails_only_when_waiting() {
+        crate::utils::http_client::install_rustls_provider();
+        let base = serve(recording_app(Arc::default())).await;
+
+        let config = serde_json::json!({
+            "url": format!("{base}/rejecting"),
+            "success_pointer": "/ok",
+            "max_retries": 0,
+        });
+        let result = WebhookProcessor::new()
+            .process(
+                &input_with(config.clone(), "/missing.mp4"),
+                &ProcessorContext::noop("j"),
+            )
+            .await;
+        assert!(matches!(result, Err(crate::Error::PipelineError(_))));
+
+        let mut config = config;
+        config["wait_for_success"] = serde_json::Value::Bool(false);
+        let output = WebhookProcessor::new()
+            .process(
+                &input_with(config, "/missing.mp4"),
+                &ProcessorContext::noop("j"),
+            )
+            .await
+            .unwrap();
+        assert_eq!(output.outputs, vec!["/missing.mp4".to_string()]);
+    }
+
+    #[test]
+    fn invalid_url_or_method_is_rejected() {
+        let config = WebhookProcessorConfig {
+            url: "ftp://example.com".to_string(),
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+
+        let config = WebhookProcessorConfig {
+            url: "https://example.com/hook".to_string(),
+            method: "delete".to_string(),
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+    }
+}
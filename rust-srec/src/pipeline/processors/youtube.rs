@@ -0,0 +1,613 @@
+//! YouTube upload processor using the YouTube Data API.
+//!
+//! Uploads each video input with the resumable upload protocol, so a network
+//! error resumes from the last byte the server acknowledged. The channel is
+//! selected by an OAuth account name stored through the credentials API;
+//! access tokens are refreshed from the stored refresh token as needed.
+//!
+//! `title` and `description` support placeholder expansion:
+//! - `{streamer}` - Streamer name
+//! - `{title}` - Session title
+//! - `{streamer_id}` - Streamer ID
+//! - `{session_id}` - Session ID
+//! - `{platform}` - Platform name
+//! - Time placeholders: `%Y`, `%m`, `%d`, `%H`, `%M`, `%S`, etc.
+
+mod client;
+
+use std::path::Path;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+use tokio::io::{AsyncReadExt, AsyncSeekExt};
+use tracing::{info, warn};
+
+use self::client::{CHUNK_GRANULARITY, RequestError, UploadState, YouTubeClient, retry_delay};
+use super::traits::{
+    Processor, ProcessorContext, ProcessorInput, ProcessorOutput, ProcessorType, TimeAnchor,
+};
+use super::utils::{
+    UploadProgress, create_log_entry, expand_text_template, get_extension, is_video,
+};
+use crate::Result;
+use crate::credentials::oauth;
+use crate::database::repositories::OAuthTokenRepository;
+use crate::pipeline::job_queue::LogLevel;
+
+const MIB: u64 = 1024 * 1024;
+
+/// Longest video title YouTube accepts, in characters.
+const MAX_TITLE_CHARS: usize = 100;
+
+/// Longest video description YouTube accepts, in bytes.
+const MAX_DESCRIPTION_BYTES: usize = 5000;
+
+/// Who can watch the uploaded video.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum PrivacyStatus {
+    #[default]
+    Private,
+    Unlisted,
+    Public,
+}
+
+/// Configuration for the YouTube upload processor.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct YouTubeUploadConfig {
+    /// Name of the stored OAuth account to upload with. Required.
+    pub account: String,
+
+    /// Video title template. Truncated to 100 characters.
+    pub title: String,
+
+    /// Video description template.
+    pub description: String,
+
+    pub tags: Vec<String>,
+
+    /// YouTube video category ID. `22` is "People & Blogs", `20` is "Gaming".
+    pub category_id: String,
+
+    pub privacy_status: PrivacyStatus,
+
+    /// Declare the video as made for kids.
+    pub made_for_kids: bool,
+
+    /// Playlist to add uploaded videos to.
+    pub playlist_id: Option<String>,
+
+    /// Timestamp source for time placeholder expansion.
+    pub time_anchor: TimeAnchor,
+
+    /// Upload chunk size in MiB. Smaller chunks lose less progress when a
+    /// request fails.
+    pub chunk_size_mb: u64,
+
+    /// Consecutive failed requests tolerated per file before giving up.
+    pub max_retries: u32,
+
+    /// Delete each local file once it is uploaded.
+    pub delete_source_on_success: bool,
+}
+
+impl Default for YouTubeUploadConfig {
+    fn default() -> Self {
+        Self {
+            account: String::new(),
+            title: "{streamer} - {title} %Y-%m-%d".to_string(),
+            description: String::new(),
+            tags: Vec::new(),
+            category_id: "22".to_string(),
+            privacy_status: PrivacyStatus::default(),
+            made_for_kids: false,
+            playlist_id: None,
+            time_anchor: TimeAnchor::default(),
+            chunk_size_mb: 8,
+            max_retries: 3,
+            delete_source_on_success: false,
+        }
+    }
+}
+
+impl YouTubeUploadConfig {
+    /// Chunk size in bytes, rounded up to the 256 KiB multiple the API requires.
+    fn chunk_size(&self) -> u64 {
+        self.chunk_size_mb
+            .max(1)
+            .saturating_mul(MIB)
+            .div_ceil(CHUNK_GRANULARITY)
+            * CHUNK_GRANULARITY
+    }
+
+    /// Video resource sent when an upload starts. `part` numbers the input
+    /// as `(index, count)` when a job uploads more than one video.
+    fn video_metadata(
+        &self,
+        input: &ProcessorInput,
+        part: Option<(usize, usize)>,
+    ) -> serde_json::Value {
+        let mut title = expand_text_template(&self.title, input, self.time_anchor);
+        if let Some((index, count)) = part {
+            title = format!("{title} ({index}/{count})");
+        }
+        let mut title = clean_text(&title, MAX_TITLE_CHARS, usize::MAX);
+        if title.trim().is_empty() {
+            title = input
+                .streamer_name
+                .clone()
+                .unwrap_or_else(|| input.streamer_id.clone());
+        }
+        let description = clean_text(
+            &expand_text_template(&self.description, input, self.time_anchor),
+            usize::MAX,
+            MAX_DESCRIPTION_BYTES,
+        );
+
+        serde_json::json!({
+            "snippet": {
+                "title": title,
+                "description": description,
+                "tags": self.tags,
+                "categoryId": self.category_id,
+            },
+            "status": {
+                "privacyStatus": self.privacy_status,
+                "selfDeclaredMadeForKids": self.made_for_kids,
+            },
+        })
+    }
+}
+
+/// Strip the angle brackets YouTube rejects in titles and descriptions and
+/// cut the text to at most `max_chars` characters and `max_bytes` bytes.
+fn clean_text(text: &str, max_chars: usize, max_bytes: usize) -> String {
+    let mut cleaned = String::new();
+    for c in text
+        .chars()
+        .filter(|c| *c != '<' && *c != '>')
+        .take(max_chars)
+    {
+        if cleaned.len() + c.len_utf8() > max_bytes {
+            break;
+        }
+        cleaned.push(c);
+    }
+    cleaned.trim().to_string()
+}
+
+/// Processor that uploads recordings to YouTube.
+pub struct YouTubeUploadProcessor {
+    tokens: Arc<dyn OAuthTokenRepository>,
+}
+
+/// A video created by the processor.
+struct UploadedVideo {
+    path: String,
+    video_id: String,
+    size: u64,
+}
+
+impl YouTubeUploadProcessor {
+    pub fn new(tokens: Arc<dyn OAuthTokenRepository>) -> Self {
+        Self { tokens }
+    }
+
+    async fn access_token(&self, http: &reqwest::Client, account: &str) -> Result<String> {
+        oauth::access_token(self.tokens.as_ref(), http, account)
+            .await
+            .map_err(|e| crate::Error::Other(format!("YouTube account '{account}': {e}")))
+    }
+
+    /// Upload `path` and return the new video ID.
+    async fn upload_file(
+        &self,
+        http: &reqwest::Client,
+        client: &YouTubeClient,
+        config: &YouTubeUploadConfig,
+        path: &str,
+        metadata: &serde_json::Value,
+        progress: &mut UploadProgress<'_>,
+    ) -> Result<String> {
+        let size = tokio::fs::metadata(path)
+            .await
+            .map_err(|e| {
+                crate::Error::io_path("reading upload source metadata", Path::new(path), e)
+            })?
+            .len();
+        let mut file = tokio::fs::File::open(path)
+            .await
+            .map_err(|e| crate::Error::io_path("opening upload source", Path::new(path), e))?;
+
+        let token = self.access_token(http, &config.account).await?;
+        let session_url = client.start_upload(&token, metadata, size).await?;
+        let chunk_size = config.chunk_size();
+        let mut offset = 0u64;
+        let mut failures = 0u32;
+
+        loop {
+            if progress.is_cancelled() {
+                return Err(crate::Error::PipelineError(
+                    "YouTube upload cancelled".to_string(),
+                ));
+            }
+
+            let token = self.access_token(http, &config.account).await?;
+            let result = if offset < size {
+                let chunk = read_chunk(&mut file, path, offset, chunk_size).await?;
+                client
+                    .upload_chunk(&token, &session_url, offset, chunk, size)
+                    .await
+            } else {
+                // Every byte was sent but the final response was lost.
+                client.query_upload(&token, &session_url, size).await
+            };
+
+            let state = match result {
+                Ok(state) => {
+                    failures = 0;
+                    state
+                }
+                Err(e) if e.is_transient() && failures < config.max_retries => {
+                    failures += 1;
+                    warn!(input = %path, attempt = failures, error = %e, "YouTube chunk upload failed; resuming");
+                    tokio::time::sleep(retry_delay(failures)).await;
+                    Self::resume_state(client, &token, &session_url, offset, size).await?
+                }
+                Err(e) => return Err(e.into()),
+            };
+
+            match state {
+                UploadState::Complete { video_id } => {
+                    progress.advance(size.saturating_sub(offset));
+                    return Ok(video_id);
+                }
+                UploadState::Incomplete { next_offset } => {
+                    progress.advance(next_offset.saturating_sub(offset));
+                    offset = next_offset;
+                }
+            }
+        }
+    }
+
+    /// Ask the server where to resume after a failed chunk.
+    async fn resume_state(
+        client: &YouTubeClient,
+        token: &str,
+        session_url: &str,
+        offset: u64,
+        size: u64,
+    ) -> std::result::Result<UploadState, RequestError> {
+        match client.query_upload(token, session_url, size).await {
+            Ok(state) => Ok(state),
+            // The status request itself failed; resend from the same offset.
+            Err(e) if e.is_transient() => Ok(UploadState::Incomplete {
+                next_offset: offset,
+            }),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+/// Read up to `len` bytes of `file` starting at `offset`.
+async fn read_chunk(
+    file: &mut tokio::fs::File,
+    path: &str,
+    offset: u64,
+    len: u64,
+) -> Result<Bytes> {
+    file.seek(std::io::SeekFrom::Start(offset))
+        .await
+        .map_err(|e| crate::Error::io_path("seeking upload source", Path::new(path), e))?;
+    let mut chunk = Vec::with_capacity(len as usize);
+    (&mut *file)
+        .take(len)
+        .read_to_end(&mut chunk)
+        .await
+        .map_err(|e| crate::Error::io_path("reading upload source", Path::new(path), e))?;
+    Ok(Bytes::from(chunk))
+}
+
+#[async_trait]
+impl Processor for YouTubeUploadProcessor {
+    fn processor_type(&self) -> ProcessorType {
+        ProcessorType::Io
+    }
+
+    fn job_types(&self) -> Vec<&'static str> {
+        vec!["youtube"]
+    }
+
+    fn name(&self) -> &'static str {
+        "YouTubeUploadProcessor"
+    }
+
+    fn supports_batch_input(&self) -> bool {
+        true
+    }
+
+    async fn process(
+        &self,
+        input: &ProcessorInput,
+        ctx: &ProcessorContext,
+    ) -> Result<ProcessorOutput> {
+        let start = std::time::Instant::now();
+
+        if input.inputs.is_empty() {
+            return Err(crate::Error::Validation(
+                "No input files provided for YouTubeUploadProcessor".to_string(),
+            ));
+        }
+
+        let config: YouTubeUploadConfig = match input.config.as_deref() {
+            Some(s) => serde_json::from_str(s).map_err(|e| {
+                crate::Error::Validation(format!("Invalid youtube config JSON: {e}"))
+            })?,
+            None => YouTubeUploadConfig::default(),
+        };
+        if config.account.trim().is_empty() {
+            return Err(crate::Error::Validation(
+                "YouTube upload requires an OAuth account name".to_string(),
+            ));
+        }
+
+        // Only videos are uploaded; thumbnails, subtitles and danmu files
+        // pass through to the next step.
+        let (videos, skipped): (Vec<&String>, Vec<&String>) = input
+            .inputs
+            .iter()
+            .partition(|path| get_extension(path).is_some_and(|ext| is_video(&ext)));
+        if videos.is_empty() {
+            return Ok(ProcessorOutput {
+                outputs: input.inputs.clone(),
+                duration_secs: start.elapsed().as_secs_f64(),
+                skipped_inputs: input
+                    .inputs
+                    .iter()
+                    .map(|p| (p.clone(), "not a video file".to_string()))
+                    .collect(),
+                logs: vec![create_log_entry(
+                    LogLevel::Info,
+                    "No video inputs to upload to YouTube",
+                )],
+                ..Default::default()
+            });
+        }
+
+        let http = reqwest::Client::new();
+        let client = YouTubeClient::new(http.clone());
+
+        let mut bytes_total = 0u64;
+        for path in &videos {
+            if let Ok(metadata) = tokio::fs::metadata(path).await {
+                bytes_total = bytes_total.saturating_add(metadata.len());
+            }
+        }
+        let mut progress = UploadProgress::new(ctx, bytes_total);
+
+        let mut outputs: Vec<String> = skipped.iter().map(|p| (*p).clone()).collect();
+        let mut succeeded_inputs = Vec::new();
+        let mut failed_inputs: Vec<(String, String)> = Vec::new();
+        let mut uploaded = Vec::new();
+        let mut logs = Vec::new();
+
+        for (index, path) in videos.iter().enumerate() {
+            if !Path::new(path).exists() {
+                failed_inputs.push((
+                    (*path).clone(),
+                    format!("Input file does not exist: {path}"),
+                ));
+                continue;
+            }
+
+            let part = (videos.len() > 1).then_some((index + 1, videos.len()));
+            let metadata = config.video_metadata(input, part);
+            info!(input = %path, title = %metadata["snippet"]["title"], "Uploading to YouTube");
+
+            let size = tokio::fs::metadata(path)
+                .await
+                .map(|m| m.len())
+                .unwrap_or(0);
+            match self
+                .upload_file(&http, &client, &config, path, &metadata, &mut progress)
+                .await
+            {
+                Ok(video_id) => {
+                    let message = format!("Uploaded {path} to YouTube as video {video_id}");
+                    info!("{message}");
+                    logs.push(create_log_entry(LogLevel::Info, message));
+
+                    if let Some(playlist_id) =
+                        config.playlist_id.as_deref().filter(|p| !p.is_empty())
+                    {
+                        let added = match self.access_token(&http, &config.account).await {
+                            Ok(token) => client
+                                .insert_playlist_item(&token, playlist_id, &video_id)
+                                .await
+                                .map_err(crate::Error::from),
+                            Err(e) => Err(e),
+                        };
+                        // The video exists either way; a playlist failure
+                        // only warns so a retry does not upload it twice.
+                        if let Err(e) = added {
+                            let message = format!(
+                                "Failed to add video {video_id} to playlist {playlist_id}: {e}"
+                            );
+                            warn!("{message}");
+                            logs.push(create_log_entry(LogLevel::Warn, message));
+                        }
+                    }
+
+                    if config.delete_source_on_success {
+                        if let Err(e) = tokio::fs::remove_file(path).await {
+                            warn!(input = %path, error = %e, "Failed to delete uploaded source");
+                            outputs.push((*path).clone());
+                        }
+                    } else {
+                        outputs.push((*path).clone());
+                    }
+                    succeeded_inputs.push((*path).clone());
+                    uploaded.push(UploadedVideo {
+                        path: (*path).clone(),
+                        video_id,
+                        size,
+                    });
+                }
+                Err(e) => {
+                    let message = format!("Failed to upload {path} to YouTube: {e}");
+                    warn!("{message}");
+                    logs.push(create_log_entry(LogLevel::Error, message));
+                    failed_inputs.push(((*path).clone(), e.to_string()));
+                }
+            }
+        }
+
+        if succeeded_inputs.is_empty() {
+            return Err(crate::Error::PipelineError(format!(
+                "All {} video files failed to upload to YouTube: {}",
+                failed_inputs.len(),
+                failed_inputs
+                    .first()
+                    .map(|(_, error)| error.as_str())
+                    .unwrap_or_default()
+            )));
+        }
+
+        let uploaded_bytes: u64 = uploaded.iter().map(|v| v.size).sum();
+        Ok(ProcessorOutput {
+            outputs,
+            duration_secs: start.elapsed().as_secs_f64(),
+            metadata: Some(
+                serde_json::json!({
+                    "account": config.account,
+                    "videos": uploaded
+                        .iter()
+                        .map(|v| serde_json::json!({
+                            "input": v.path,
+                            "video_id": v.video_id,
+                            "url": format!("https://youtu.be/{}", v.video_id),
+                            "size": v.size,
+                        }))
+                        .collect::<Vec<_>>(),
+                    "failed": failed_inputs.len(),
+                })
+                .to_string(),
+            ),
+            items_produced: vec![],
+            input_size_bytes: Some(bytes_total),
+            output_size_bytes: Some(uploaded_bytes),
+            failed_inputs,
+            succeeded_inputs,
+            skipped_inputs: skipped
+                .iter()
+                .map(|p| ((*p).clone(), "not a video file".to_string()))
+                .collect(),
+            logs,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_utils::utc_datetime;
+    use super::*;
+    use crate::database::models::OAuthTokenDbModel;
+
+    struct NoTokens;
+
+    #[async_trait]
+    impl OAuthTokenRepository for NoTokens {
+        async fn get_token(&self, name: &str) -> Result<OAuthTokenDbModel> {
+            Err(crate::Error::not_found("OAuth account", name))
+        }
+        async fn list_tokens(&self) -> Result<Vec<OAuthTokenDbModel>> {
+            Ok(vec![])
+        }
+        async fn upsert_token(&self, _token: &OAuthTokenDbModel) -> Result<()> {
+            Ok(())
+        }
+        async fn update_access_token(&self, _: &str, _: &str, _: Option<i64>) -> Result<()> {
+            Ok(())
+        }
+        async fn delete_token(&self, _name: &str) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    fn input() -> ProcessorInput {
+        ProcessorInput::new(
+            vec!["/rec/live.mp4".to_string()],
+            vec![],
+            "streamer-1",
+            "session-1",
+        )
+        .with_streamer_name("Alice")
+        .with_session_title("Late night <chill> stream")
+        .with_created_at(utc_datetime(2024, 3, 5, 12, 0, 0))
+    }
+
+    #[test]
+    fn config_deserializes_from_partial_json() {
+        let config: YouTubeUploadConfig =
+            serde_json::from_str(r#"{"account": "main", "privacy_status": "unlisted"}"#).unwrap();
+        assert_eq!(config.account, "main");
+        assert_eq!(config.privacy_status, PrivacyStatus::Unlisted);
+        assert_eq!(config.category_id, "22");
+        assert_eq!(config.chunk_size_mb, 8);
+        assert!(!config.delete_source_on_success);
+    }
+
+    #[test]
+    fn chunk_size_is_a_multiple_of_256_kib() {
+        let config = YouTubeUploadConfig {
+            chunk_size_mb: 0,
+            ..Default::default()
+        };
+        assert_eq!(config.chunk_size(), MIB);
+        assert_eq!(
+            YouTubeUploadConfig::default().chunk_size() % CHUNK_GRANULARITY,
+            0
+        );
+    }
+
+    #[test]
+    fn metadata_expands_templates_and_numbers_parts() {
+        let config = YouTubeUploadConfig {
+            description: "Recorded from {platform} on %Y-%m-%d".to_string(),
+            tags: vec!["vod".to_string()],
+            privacy_status: PrivacyStatus::Public,
+            ..Default::default()
+        };
+        let input = input().with_platform("Twitch");
+
+        let metadata = config.video_metadata(&input, Some((2, 3)));
+        assert_eq!(
+            metadata["snippet"]["title"],
+            "Alice - Late night chill stream 2024-03-05 (2/3)"
+        );
+        assert_eq!(
+            metadata["snippet"]["description"],
+            "Recorded from Twitch on 2024-03-05"
+        );
+        assert_eq!(metadata["snippet"]["tags"][0], "vod");
+        assert_eq!(metadata["status"]["privacyStatus"], "public");
+    }
+
+    #[test]
+    fn clean_text_respects_limits() {
+        let long = "a".repeat(150);
+        assert_eq!(clean_text(&long, MAX_TITLE_CHARS, usize::MAX).len(), 100);
+        assert_eq!(clean_text("<b>直播</b>", 100, 7), "b直播");
+    }
+
+    #[tokio::test]
+    async fn missing_account_fails_before_uploading() {
+        let processor = YouTubeUploadProcessor::new(Arc::new(NoTokens));
+        let ctx = ProcessorContext::noop("test");
+        let result = processor.process(&input(), &ctx).await;
+        assert!(matches!(result, Err(crate::Error::Validation(_))));
+    }
+}
@@ -0,0 +1,283 @@
+//! Minimal YouTube Data API client: resumable video uploads and playlist
+//! insertion.
+//!
+//! A resumable upload starts with a metadata request that returns a session
+//! URL. Chunks are then `PUT` to that URL with a `Content-Range`; the server
+//! answers `308 Resume Incomplete` with the range it has stored, so after a
+//! failure the upload resumes from the last acknowledged byte instead of
+//! starting over.
+
+use std::time::Duration;
+
+use bytes::Bytes;
+use reqwest::StatusCode;
+use serde::Deserialize;
+use tracing::warn;
+
+const UPLOAD_URL: &str =
+    "https://www.googleapis.com/upload/youtube/v3/videos?uploadType=resumable&part=snippet,status";
+const PLAYLIST_ITEMS_URL: &str = "https://www.googleapis.com/youtube/v3/playlistItems?part=snippet";
+
+/// Chunk sizes must be a multiple of 256 KiB, except for the final chunk.
+pub(super) const CHUNK_GRANULARITY: u64 = 256 * 1024;
+
+/// State of a resumable upload after a chunk or status request.
+#[derive(Debug, PartialEq, Eq)]
+pub(super) enum UploadState {
+    /// The server stored bytes up to (excluding) this offset.
+    Incomplete { next_offset: u64 },
+    /// The upload finished and the video resource was created.
+    Complete { video_id: String },
+}
+
+/// A failed API request.
+#[derive(Debug, thiserror::Error)]
+pub(super) enum RequestError {
+    /// Network failure, server error or throttling; resuming may succeed.
+    #[error("{0}")]
+    Transient(String),
+    /// Rejected request (bad metadata, expired token, quota exhausted).
+    #[error("{0}")]
+    Fatal(String),
+}
+
+impl RequestError {
+    pub fn is_transient(&self) -> bool {
+        matches!(self, Self::Transient(_))
+    }
+}
+
+impl From<RequestError> for crate::Error {
+    fn from(error: RequestError) -> Self {
+        crate::Error::Other(error.to_string())
+    }
+}
+
+type Result<T> = std::result::Result<T, RequestError>;
+
+#[derive(Debug, Deserialize)]
+struct VideoResource {
+    id: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ErrorResponse {
+    error: ErrorBody,
+}
+
+#[derive(Debug, Deserialize)]
+struct ErrorBody {
+    message: String,
+    #[serde(default)]
+    errors: Vec<ErrorDetail>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ErrorDetail {
+    reason: String,
+}
+
+pub(super) struct YouTubeClient {
+    http: reqwest::Client,
+}
+
+impl YouTubeClient {
+    pub fn new(http: reqwest::Client) -> Self {
+        Self { http }
+    }
+
+    /// Start a resumable upload and return its session URL.
+    pub async fn start_upload(
+        &self,
+        access_token: &str,
+        metadata: &serde_json::Value,
+        size: u64,
+    ) -> Result<String> {
+        let response = self
+            .http
+            .post(UPLOAD_URL)
+            .bearer_auth(access_token)
+            .header("X-Upload-Content-Type", "video/*")
+            .header("X-Upload-Content-Length", size)
+            .json(metadata)
+            .send()
+            .await
+            .map_err(network_error)?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(api_error(
+                status,
+                &response.text().await.unwrap_or_default(),
+            ));
+        }
+        response
+            .headers()
+            .get(reqwest::header::LOCATION)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string)
+            .ok_or_else(|| {
+                RequestError::Fatal("YouTube upload response has no session URL".to_string())
+            })
+    }
+
+    /// Send `chunk` starting at byte `offset` of a `total`-byte upload.
+    pub async fn upload_chunk(
+        &self,
+        access_token: &str,
+        session_url: &str,
+        offset: u64,
+        chunk: Bytes,
+        total: u64,
+    ) -> Result<UploadState> {
+        let end = offset + chunk.len() as u64 - 1;
+        let response = self
+            .http
+            .put(session_url)
+            .bearer_auth(access_token)
+            .header(
+                reqwest::header::CONTENT_RANGE,
+                format!("bytes {offset}-{end}/{total}"),
+            )
+            .body(chunk)
+            .send()
+            .await
+            .map_err(network_error)?;
+        upload_state(response).await
+    }
+
+    /// Ask the server how much of the upload it has stored.
+    pub async fn query_upload(
+        &self,
+        access_token: &str,
+        session_url: &str,
+        total: u64,
+    ) -> Result<UploadState> {
+        let response = self
+            .http
+            .put(session_url)
+            .bearer_auth(access_token)
+            .header(reqwest::header::CONTENT_RANGE, format!("bytes */{total}"))
+            .header(reqwest::header::CONTENT_LENGTH, 0)
+            .send()
+            .await
+            .map_err(network_error)?;
+        upload_state(response).await
+    }
+
+    /// Append a video to a playlist.
+    pub async fn insert_playlist_item(
+        &self,
+        access_token: &str,
+        playlist_id: &str,
+        video_id: &str,
+    ) -> Result<()> {
+        let body = serde_json::json!({
+            "snippet": {
+                "playlistId": playlist_id,
+                "resourceId": { "kind": "youtube#video", "videoId": video_id },
+            }
+        });
+        let response = self
+            .http
+            .post(PLAYLIST_ITEMS_URL)
+            .bearer_auth(access_token)
+            .json(&body)
+            .send()
+            .await
+            .map_err(network_error)?;
+        let status = response.status();
+        if status.is_success() {
+            Ok(())
+        } else {
+            Err(api_error(
+                status,
+                &response.text().await.unwrap_or_default(),
+            ))
+        }
+    }
+}
+
+/// Backoff before resuming after the `attempt`-th consecutive failure.
+pub(super) fn retry_delay(attempt: u32) -> Duration {
+    Duration::from_secs(2u64.saturating_pow(attempt.min(6)))
+}
+
+async fn upload_state(response: reqwest::Response) -> Result<UploadState> {
+    let status = response.status();
+    if status.as_u16() == 308 {
+        let next_offset = response
+            .headers()
+            .get(reqwest::header::RANGE)
+            .and_then(|v| v.to_str().ok())
+            .and_then(next_offset_from_range)
+            .unwrap_or(0);
+        return Ok(UploadState::Incomplete { next_offset });
+    }
+
+    let body = response.text().await.map_err(network_error)?;
+    if status.is_success() {
+        let video: VideoResource = serde_json::from_str(&body)
+            .map_err(|e| RequestError::Fatal(format!("Invalid YouTube upload response: {e}")))?;
+        return Ok(UploadState::Complete { video_id: video.id });
+    }
+    Err(api_error(status, &body))
+}
+
+/// Offset after the stored range in a `Range: bytes=0-N` header.
+fn next_offset_from_range(range: &str) -> Option<u64> {
+    let (_, end) = range.strip_prefix("bytes=")?.split_once('-')?;
+    end.trim().parse::<u64>().ok().map(|end| end + 1)
+}
+
+fn network_error(error: reqwest::Error) -> RequestError {
+    RequestError::Transient(format!("YouTube request failed: {error}"))
+}
+
+fn api_error(status: StatusCode, body: &str) -> RequestError {
+    let parsed = serde_json::from_str::<ErrorResponse>(body).ok();
+    let message = parsed.as_ref().map_or_else(
+        || body.chars().take(200).collect::<String>(),
+        |e| e.error.message.clone(),
+    );
+    let reason = parsed
+        .as_ref()
+        .and_then(|e| e.error.errors.first())
+        .map(|d| d.reason.as_str())
+        .unwrap_or_default();
+
+    if status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS {
+        warn!(%status, reason, "YouTube API request failed");
+        return RequestError::Transient(format!("YouTube API error (HTTP {status}): {message}"));
+    }
+    if reason.is_empty() {
+        RequestError::Fatal(format!("YouTube API error (HTTP {status}): {message}"))
+    } else {
+        RequestError::Fatal(format!(
+            "YouTube API error (HTTP {status}, {reason}): {message}"
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn range_header_gives_next_offset() {
+        assert_eq!(next_offset_from_range("bytes=0-524287"), Some(524_288));
+        assert_eq!(next_offset_from_range("bytes=0-0"), Some(1));
+        assert_eq!(next_offset_from_range("0-10"), None);
+    }
+
+    #[test]
+    fn server_errors_and_throttling_are_retryable() {
+        let body =
+            r#"{"error":{"code":403,"message":"quota","errors":[{"reason":"quotaExceeded"}]}}"#;
+        let quota = api_error(StatusCode::FORBIDDEN, body);
+        assert!(!quota.is_transient());
+        assert!(quota.to_string().contains("quotaExceeded"));
+
+        assert!(api_error(StatusCode::SERVICE_UNAVAILABLE, "").is_transient());
+        assert!(api_error(StatusCode::TOO_MANY_REQUESTS, "").is_transient());
+    }
+}
@@ -8,6 +8,7 @@ pub enum ProgressKind {
     Ffmpeg,
     Rclone,
     Compression,
+    Upload,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
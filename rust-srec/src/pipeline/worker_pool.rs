@@ -1,5 +1,6 @@
 //! Worker pool implementation for pipeline processing.
 
+use process_utils::{ProcessPriority, ProcessScheduling};
 use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 use std::sync::Arc;
@@ -13,6 +14,7 @@ use super::dag_scheduler::{
     DagCompletionInfo, DagJobCompletedUpdate, DagJobFailedUpdate, DagScheduler,
 };
 use super::job_queue::{JobExecutionInfo, JobQueue, JobResult};
+use super::processors::utils::with_process_scheduling;
 use super::processors::{JobLogSink, Processor, ProcessorContext, ProcessorInput};
 
 /// Type of worker.
@@ -87,6 +89,22 @@ pub struct WorkerPoolConfig {
     /// Adaptive worker scaling configuration.
     #[serde(default)]
     pub adaptive: AdaptiveWorkerPoolConfig,
+    /// CPU priority of the external processes (ffmpeg, rclone, ...) started by jobs.
+    #[serde(default)]
+    pub process_priority: ProcessPriority,
+    /// CPUs the external processes started by jobs may run on; empty allows every CPU.
+    #[serde(default)]
+    pub cpu_affinity: Vec<usize>,
+}
+
+impl WorkerPoolConfig {
+    /// Scheduling applied to the external processes started by jobs.
+    pub fn process_scheduling(&self) -> ProcessScheduling {
+        ProcessScheduling {
+            priority: self.process_priority,
+            cpu_affinity: self.cpu_affinity.clone(),
+        }
+    }
 }
 
 impl Default for WorkerPoolConfig {
@@ -96,6 +114,8 @@ impl Default for WorkerPoolConfig {
             job_timeout_secs: 3600, // 1 hour
             poll_interval_ms: 100,
             adaptive: AdaptiveWorkerPoolConfig::default(),
+            process_priority: ProcessPriority::Normal,
+            cpu_affinity: Vec::new(),
         }
     }
 }
@@ -233,6 +253,7 @@ impl WorkerPool {
                 .max(1000),
         );
         let job_timeout = std::time::Duration::from_secs(self.config.job_timeout_secs);
+        let process_scheduling = Arc::new(self.config.process_scheduling());
         let active_workers = self.active_workers.clone();
         let avg_runtime_ms = self.avg_runtime_ms.clone();
 
@@ -278,6 +299,7 @@ impl WorkerPool {
                 let avg_runtime_ms = avg_runtime_ms.clone();
                 let dag_scheduler = dag_scheduler.clone();
                 let dag_notify_tx = dag_notify_tx.clone();
+                let process_scheduling = process_scheduling.clone();
 
                 join_set.spawn(async move {
                     debug!("{} worker {} started", worker_type, i);
@@ -632,7 +654,10 @@ impl WorkerPool {
                             let result = {
                                 let timed = tokio::time::timeout(
                                     job_timeout,
-                                    processor.process(&input, &ctx),
+                                    with_process_scheduling(
+                                        process_scheduling.clone(),
+                                        processor.process(&input, &ctx),
+                                    ),
                                 );
                                 tokio::pin!(timed);
 
@@ -1197,6 +1222,7 @@ mod tests {
                 job_timeout_secs: 3600,
                 poll_interval_ms: 10,
                 adaptive: AdaptiveWorkerPoolConfig::default(),
+                ..Default::default()
             },
         );
 
@@ -1257,6 +1283,7 @@ mod tests {
                 job_timeout_secs: 1,
                 poll_interval_ms: 10,
                 adaptive: AdaptiveWorkerPoolConfig::default(),
+                ..Default::default()
             },
         );
 
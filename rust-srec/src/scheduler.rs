@@ -16,6 +16,7 @@
 //! - The `Scheduler` acts as a supervisor, spawning and monitoring actors
 //! - Actors manage their own scheduling internally, eliminating periodic re-scheduling
 
+mod activity;
 pub mod actor;
 mod batch;
 mod resource;
@@ -32,11 +33,14 @@ pub use actor::{
     TaskCompletionAction,
 };
 
+// Polling adapted to session history
+pub use activity::LiveActivityProfile;
+
 // Batch coordination
 pub use batch::BatchGroup;
 
 // Resource monitoring
-pub use resource::{DiskInfo, DiskSpaceStatus, ResourceMonitor};
+pub use resource::{DiskInfo, DiskSpaceStatus, ResourceMonitor, SystemLoad};
 
 // Scheduler service
 pub(crate) use service::SchedulerHandle;
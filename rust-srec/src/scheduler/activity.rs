@@ -0,0 +1,177 @@
+//! Live activity profiles learned from session history.
+//!
+//! Most streamers go live around the same times of day. A profile keeps the
+//! time of day at which a streamer's recent sessions started. Offline polling
+//! speeds up near those times and slows down during the hours in which the
+//! streamer has never gone live, which cuts platform requests for large
+//! rosters without delaying the usual start of a stream.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, Timelike, Utc};
+
+/// How far back session history is read.
+pub const ACTIVITY_HISTORY_DAYS: i64 = 28;
+
+/// How often profiles are rebuilt from session history.
+pub const ACTIVITY_REFRESH_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Sessions a streamer needs before its polling adapts.
+const MIN_SESSIONS: usize = 3;
+
+/// Polling runs at half the interval within this many minutes of a learned
+/// start time.
+const HOT_WINDOW_MINS: u32 = 30;
+
+/// Farther than this many minutes from every learned start time, the
+/// streamer counts as idle.
+const WARM_WINDOW_MINS: u32 = 120;
+
+/// Multiplier applied to the check interval during idle hours.
+const IDLE_FACTOR: u32 = 4;
+
+/// Idle hours never stretch the interval beyond this.
+const MAX_IDLE_INTERVAL: Duration = Duration::from_secs(15 * 60);
+
+const MINUTES_PER_DAY: u32 = 24 * 60;
+
+/// When a streamer usually goes live.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LiveActivityProfile {
+    /// Minutes after UTC midnight at which sessions started, sorted.
+    start_minutes: Vec<u32>,
+}
+
+impl LiveActivityProfile {
+    /// Build a profile from session start times in Unix epoch milliseconds.
+    ///
+    /// Returns `None` when there are too few sessions to learn from.
+    pub fn from_start_times(start_times_ms: impl IntoIterator<Item = i64>) -> Option<Self> {
+        let mut start_minutes: Vec<u32> = start_times_ms
+            .into_iter()
+            .filter_map(DateTime::<Utc>::from_timestamp_millis)
+            .map(|start| minute_of_day(&start))
+            .collect();
+        if start_minutes.len() < MIN_SESSIONS {
+            return None;
+        }
+        start_minutes.sort_unstable();
+        start_minutes.dedup();
+        Some(Self { start_minutes })
+    }
+
+    /// Interval until the next offline check, given the configured one.
+    ///
+    /// Near a learned start time the interval halves. During idle hours it
+    /// grows up to [`IDLE_FACTOR`] times, capped at [`MAX_IDLE_INTERVAL`].
+    /// The idle band starts well beyond the longest idle interval, so a
+    /// stretched sleep never runs into the next window.
+    pub fn next_interval(&self, base: Duration, now: DateTime<Utc>) -> Duration {
+        let distance = self.distance_to_start(minute_of_day(&now));
+        if distance <= HOT_WINDOW_MINS {
+            base / 2
+        } else if distance <= WARM_WINDOW_MINS {
+            base
+        } else {
+            (base * IDLE_FACTOR).min(MAX_IDLE_INTERVAL).max(base)
+        }
+    }
+
+    /// Minutes between `minute` and the closest learned start time, either
+    /// side, wrapping around midnight.
+    fn distance_to_start(&self, minute: u32) -> u32 {
+        self.start_minutes
+            .iter()
+            .map(|&start| {
+                let diff = start.abs_diff(minute);
+                diff.min(MINUTES_PER_DAY - diff)
+            })
+            .min()
+            .unwrap_or(MINUTES_PER_DAY)
+    }
+}
+
+/// Build profiles from `(streamer_id, start_time_ms)` pairs, skipping
+/// streamers with too little history.
+pub fn build_profiles(
+    start_times: impl IntoIterator<Item = (String, i64)>,
+) -> HashMap<String, Arc<LiveActivityProfile>> {
+    let mut by_streamer: HashMap<String, Vec<i64>> = HashMap::new();
+    for (streamer_id, start_time) in start_times {
+        by_streamer.entry(streamer_id).or_default().push(start_time);
+    }
+    by_streamer
+        .into_iter()
+        .filter_map(|(streamer_id, starts)| {
+            LiveActivityProfile::from_start_times(starts)
+                .map(|profile| (streamer_id, Arc::new(profile)))
+        })
+        .collect()
+}
+
+fn minute_of_day(time: &DateTime<Utc>) -> u32 {
+    time.hour() * 60 + time.minute()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use chrono::TimeZone;
+
+    fn at(day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 3, day, hour, minute, 0).unwrap()
+    }
+
+    fn evening_streamer() -> LiveActivityProfile {
+        LiveActivityProfile::from_start_times(
+            [at(1, 20, 0), at(2, 20, 10), at(3, 19, 50)].map(|t| t.timestamp_millis()),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn too_little_history_does_not_adapt() {
+        assert!(LiveActivityProfile::from_start_times([at(1, 20, 0).timestamp_millis()]).is_none());
+        assert!(build_profiles([("a".to_string(), 0), ("a".to_string(), 1)]).is_empty());
+    }
+
+    #[test]
+    fn polls_faster_near_usual_start_times() {
+        let profile = evening_streamer();
+        let base = Duration::from_secs(60);
+
+        assert_eq!(profile.next_interval(base, at(5, 20, 5)), base / 2);
+        assert_eq!(profile.next_interval(base, at(5, 21, 30)), base);
+    }
+
+    #[test]
+    fn polls_slower_during_idle_hours() {
+        let profile = evening_streamer();
+        let base = Duration::from_secs(60);
+
+        assert_eq!(profile.next_interval(base, at(5, 4, 0)), base * IDLE_FACTOR);
+        assert_eq!(
+            profile.next_interval(Duration::from_secs(600), at(5, 4, 0)),
+            MAX_IDLE_INTERVAL
+        );
+        assert_eq!(
+            profile.next_interval(Duration::from_secs(3600), at(5, 4, 0)),
+            Duration::from_secs(3600)
+        );
+    }
+
+    #[test]
+    fn windows_wrap_around_midnight() {
+        let profile = LiveActivityProfile::from_start_times(
+            [at(1, 23, 50), at(2, 0, 5), at(3, 23, 55)].map(|t| t.timestamp_millis()),
+        )
+        .unwrap();
+        let base = Duration::from_secs(60);
+
+        assert_eq!(profile.next_interval(base, at(5, 0, 20)), base / 2);
+        assert_eq!(profile.next_interval(base, at(5, 23, 30)), base / 2);
+    }
+}
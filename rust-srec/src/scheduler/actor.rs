@@ -15,6 +15,7 @@
 
 mod config_router;
 mod handle;
+mod introspection;
 mod messages;
 mod metrics;
 mod monitor_adapter;
@@ -26,6 +27,7 @@ mod supervisor;
 
 pub use config_router::{ConfigRouter, ConfigScope, PlatformMapping, RoutingPlan, RoutingResult};
 pub use handle::{ActorHandle, ActorMetadata, SendError};
+pub use introspection::{ActorEntry, ActorEntryHandle, ActorSnapshot};
 pub use messages::{
     BatchDetectionResult, CheckResult, DownloadEndPolicy, PlatformActorState, PlatformConfig,
     PlatformMessage, StreamerActorState, StreamerConfig, StreamerMessage,
@@ -41,7 +43,7 @@ pub use monitor_adapter::{
 };
 pub use platform_actor::PlatformActor;
 pub use registry::{ActorRegistry, ActorTaskResult, RegistryError};
-pub use restart_tracker::{RestartTracker, RestartTrackerConfig, RestartTrackerStats};
+pub use restart_tracker::{RestartInfo, RestartTracker, RestartTrackerConfig, RestartTrackerStats};
 pub use streamer_actor::{
     ActorError, ActorOutcome, ActorResult, PersistedActorState, PersistedConfig, StreamerActor,
 };
@@ -3,6 +3,7 @@
 //! The `ConfigRouter` routes configuration update events to the appropriate actors:
 //! - Streamer-specific updates go to a single StreamerActor
 //! - Platform updates go to all StreamerActors on that platform
+//! - Group updates go to all StreamerActors in that group
 //! - Global updates go to all actors
 //!
 //! This implements the scheduler-actor-refactoring spec.
@@ -81,6 +82,8 @@ pub enum ConfigScope {
     Streamer(String),
     /// Update applies to all streamers on a platform.
     Platform(String),
+    /// Update applies to all streamers in a group.
+    Group(String),
     /// Update applies to all actors globally.
     Global,
 }
@@ -104,6 +107,9 @@ impl ConfigScope {
             ConfigUpdateEvent::PlatformUpdated { platform_id } => {
                 ConfigScope::Platform(platform_id.clone())
             }
+            ConfigUpdateEvent::StreamerGroupUpdated { group_id } => {
+                ConfigScope::Group(group_id.clone())
+            }
             ConfigUpdateEvent::GlobalUpdated => ConfigScope::Global,
             // Template and engine updates are treated as global for now
             ConfigUpdateEvent::TemplateUpdated { .. } => ConfigScope::Global,
@@ -112,13 +118,15 @@ impl ConfigScope {
     }
 }
 
-/// Mapping of streamer IDs to their platform IDs.
+/// Mapping of streamer IDs to their platform and group IDs.
 ///
 /// This is used to determine which streamers belong to which platform
-/// for platform-scoped config updates.
+/// or group for platform- and group-scoped config updates.
 pub struct PlatformMapping {
     /// Map from streamer ID to platform ID.
     streamer_to_platform: HashMap<String, String>,
+    /// Map from streamer ID to group ID.
+    streamer_to_group: HashMap<String, String>,
 }
 
 impl PlatformMapping {
@@ -126,6 +134,7 @@ impl PlatformMapping {
     pub fn new() -> Self {
         Self {
             streamer_to_platform: HashMap::new(),
+            streamer_to_group: HashMap::new(),
         }
     }
 
@@ -135,9 +144,42 @@ impl PlatformMapping {
             .insert(streamer_id.into(), platform_id.into());
     }
 
-    /// Remove a streamer's platform association.
+    /// Remove a streamer's platform and group associations.
     pub fn unregister(&mut self, streamer_id: &str) {
         self.streamer_to_platform.remove(streamer_id);
+        self.streamer_to_group.remove(streamer_id);
+    }
+
+    /// Register a streamer's group association, or clear it with `None`.
+    pub fn register_group(&mut self, streamer_id: impl Into<String>, group_id: Option<&str>) {
+        let streamer_id = streamer_id.into();
+        match group_id {
+            Some(group_id) => {
+                self.streamer_to_group
+                    .insert(streamer_id, group_id.to_string());
+            }
+            None => {
+                self.streamer_to_group.remove(&streamer_id);
+            }
+        }
+    }
+
+    /// Replace the members of a group.
+    pub fn set_group_members(&mut self, group_id: &str, streamer_ids: &[String]) {
+        self.streamer_to_group.retain(|_, g| g != group_id);
+        for streamer_id in streamer_ids {
+            self.streamer_to_group
+                .insert(streamer_id.clone(), group_id.to_string());
+        }
+    }
+
+    /// Get all streamer IDs in a specific group.
+    pub fn streamers_in_group(&self, group_id: &str) -> Vec<&String> {
+        self.streamer_to_group
+            .iter()
+            .filter(|(_, g)| *g == group_id)
+            .map(|(s, _)| s)
+            .collect()
     }
 
     /// Get the platform ID for a streamer.
@@ -251,6 +293,14 @@ impl<'a> ConfigRouter<'a> {
                     }
                 }
             }
+            ConfigScope::Group(group_id) => {
+                for streamer_id in self.platform_mapping.streamers_in_group(group_id) {
+                    if self.streamer_handles.contains_key(streamer_id) {
+                        plan.streamers
+                            .push((streamer_id.clone(), streamer_config_fn(streamer_id)));
+                    }
+                }
+            }
             ConfigScope::Global => {
                 for platform_id in self.platform_handles.keys() {
                     plan.platforms
@@ -344,6 +394,12 @@ impl<'a> ConfigRouter<'a> {
 
                 targets
             }
+            ConfigScope::Group(group_id) => self
+                .platform_mapping
+                .streamers_in_group(group_id)
+                .into_iter()
+                .cloned()
+                .collect(),
             ConfigScope::Global => {
                 let mut targets = Vec::new();
 
@@ -635,6 +691,53 @@ mod tests {
         assert!(rx3.try_recv().is_err());
     }
 
+    #[tokio::test]
+    async fn test_route_to_group() {
+        let (handle1, mut rx1) = create_test_streamer_handle("streamer-1");
+        let (handle2, mut rx2) = create_test_streamer_handle("streamer-2");
+        let (platform_handle, mut platform_rx) = create_test_platform_handle("twitch");
+
+        let mut streamer_handles = HashMap::new();
+        streamer_handles.insert("streamer-1".to_string(), handle1);
+        streamer_handles.insert("streamer-2".to_string(), handle2);
+
+        let mut platform_handles = HashMap::new();
+        platform_handles.insert("twitch".to_string(), platform_handle);
+
+        let mut platform_mapping = PlatformMapping::new();
+        platform_mapping.register("streamer-1", "twitch");
+        platform_mapping.register("streamer-2", "twitch");
+        platform_mapping.set_group_members("evening", &["streamer-1".to_string()]);
+        // Moving streamer-1 into the group drops it from its previous group.
+        platform_mapping.set_group_members(
+            "weekend",
+            &["streamer-1".to_string(), "streamer-2".to_string()],
+        );
+        platform_mapping.set_group_members("weekend", &["streamer-1".to_string()]);
+
+        assert!(platform_mapping.streamers_in_group("evening").is_empty());
+
+        let router = ConfigRouter::new(&streamer_handles, &platform_handles, &platform_mapping);
+        let result = router
+            .route_event(
+                &ConfigUpdateEvent::StreamerGroupUpdated {
+                    group_id: "weekend".to_string(),
+                },
+                default_streamer_config,
+                default_platform_config,
+            )
+            .await;
+
+        // Only the current member; the platform actor is untouched.
+        assert_eq!(result.delivered, 1);
+        assert!(matches!(
+            rx1.try_recv().unwrap(),
+            StreamerMessage::ConfigUpdate(_)
+        ));
+        assert!(rx2.try_recv().is_err());
+        assert!(platform_rx.try_recv().is_err());
+    }
+
     #[tokio::test]
     async fn test_route_globally() {
         let (handle1, mut rx1) = create_test_streamer_handle("streamer-1");
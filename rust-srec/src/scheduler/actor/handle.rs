@@ -11,6 +11,8 @@ use std::time::{Duration, Instant};
 use tokio::sync::mpsc;
 use tokio_util::sync::CancellationToken;
 
+use super::metrics::{MetricsSnapshot, SharedActorMetrics};
+
 /// Default mailbox capacity for actors.
 pub const DEFAULT_MAILBOX_CAPACITY: usize = 256;
 
@@ -91,6 +93,8 @@ pub struct ActorHandle<M> {
     pub metadata: ActorMetadata,
     /// Maximum mailbox capacity.
     max_capacity: usize,
+    /// Metrics shared with the running actor, if it reports any.
+    metrics: Option<SharedActorMetrics>,
 }
 
 impl<M> ActorHandle<M> {
@@ -107,6 +111,7 @@ impl<M> ActorHandle<M> {
             cancellation_token,
             metadata,
             max_capacity,
+            metrics: None,
         }
     }
 
@@ -124,9 +129,21 @@ impl<M> ActorHandle<M> {
             cancellation_token,
             metadata,
             max_capacity,
+            metrics: None,
         }
     }
 
+    /// Attach the metrics the actor records into.
+    pub fn with_metrics(mut self, metrics: SharedActorMetrics) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Snapshot of the actor's metrics, if it reports any.
+    pub fn metrics(&self) -> Option<MetricsSnapshot> {
+        self.metrics.as_ref().map(|m| m.snapshot())
+    }
+
     /// Send a message with backpressure awareness.
     ///
     /// This method first attempts a non-blocking send. If the mailbox is full,
@@ -258,6 +275,7 @@ impl<M> Clone for ActorHandle<M> {
             cancellation_token: self.cancellation_token.clone(),
             metadata: self.metadata.clone(),
             max_capacity: self.max_capacity,
+            metrics: self.metrics.clone(),
         }
     }
 }
@@ -0,0 +1,188 @@
+//! Read-only introspection of scheduler actors.
+//!
+//! The supervisor lives on the scheduler task, so diagnostics are collected
+//! in two steps: the scheduler hands out an [`ActorEntry`] per tracked actor
+//! (cheap handle clones plus restart history), and the caller then asks each
+//! running actor for its state through its mailbox, off the scheduler task.
+//! An actor that doesn't answer within the timeout is reported without state
+//! rather than holding up the whole listing.
+
+use std::time::{Duration, Instant};
+
+use tokio::sync::oneshot;
+
+use super::handle::ActorHandle;
+use super::messages::{PlatformActorState, PlatformMessage, StreamerActorState, StreamerMessage};
+use super::metrics::MetricsSnapshot;
+use super::restart_tracker::RestartInfo;
+
+/// Mailbox of a tracked actor.
+#[derive(Debug, Clone)]
+pub enum ActorEntryHandle {
+    Streamer(ActorHandle<StreamerMessage>),
+    Platform(ActorHandle<PlatformMessage>),
+}
+
+/// An actor tracked by the supervisor.
+#[derive(Debug, Clone)]
+pub struct ActorEntry {
+    /// Actor ID (streamer ID or platform ID).
+    pub id: String,
+    /// Actor type ("streamer" or "platform").
+    pub actor_type: String,
+    /// Mailbox of the running actor; `None` while it waits to be restarted.
+    pub handle: Option<ActorEntryHandle>,
+    /// Restart history, if the actor ever crashed.
+    pub restarts: Option<RestartInfo>,
+    /// When a crashed actor is due to be restarted.
+    pub restart_at: Option<tokio::time::Instant>,
+}
+
+/// Point-in-time view of an actor.
+#[derive(Debug, Clone)]
+pub struct ActorSnapshot {
+    /// Actor ID (streamer ID or platform ID).
+    pub id: String,
+    /// Actor type ("streamer" or "platform").
+    pub actor_type: String,
+    /// Whether the actor task is running.
+    pub running: bool,
+    /// Whether the actor answered the state query in time.
+    pub responsive: bool,
+    /// Whether this is a high-priority actor.
+    pub high_priority: bool,
+    /// Time since the current actor instance was spawned.
+    pub uptime: Option<Duration>,
+    /// Messages waiting in the mailbox.
+    pub mailbox_len: usize,
+    /// Mailbox capacity.
+    pub mailbox_capacity: usize,
+    /// Message and error counters of the current actor instance.
+    pub metrics: Option<MetricsSnapshot>,
+    /// Scheduling state of a streamer actor.
+    pub streamer_state: Option<StreamerActorState>,
+    /// Batch state of a platform actor.
+    pub platform_state: Option<PlatformActorState>,
+    /// Restart history, if the actor ever crashed.
+    pub restarts: Option<RestartInfo>,
+    /// When a crashed actor is due to be restarted.
+    pub restart_at: Option<Instant>,
+}
+
+impl ActorEntry {
+    /// Query the actor for its state, waiting at most `timeout` for a reply.
+    pub async fn inspect(self, timeout: Duration) -> ActorSnapshot {
+        let mut snapshot = ActorSnapshot {
+            id: self.id,
+            actor_type: self.actor_type,
+            running: false,
+            responsive: false,
+            high_priority: false,
+            uptime: None,
+            mailbox_len: 0,
+            mailbox_capacity: 0,
+            metrics: None,
+            streamer_state: None,
+            platform_state: None,
+            restarts: self.restarts,
+            restart_at: self.restart_at.map(|at| at.into_std()),
+        };
+
+        match self.handle {
+            Some(ActorEntryHandle::Streamer(handle)) => {
+                describe_handle(&mut snapshot, &handle);
+                snapshot.streamer_state = query(&handle, StreamerMessage::GetState, timeout).await;
+                snapshot.responsive = snapshot.streamer_state.is_some();
+            }
+            Some(ActorEntryHandle::Platform(handle)) => {
+                describe_handle(&mut snapshot, &handle);
+                snapshot.platform_state = query(&handle, PlatformMessage::GetState, timeout).await;
+                snapshot.responsive = snapshot.platform_state.is_some();
+            }
+            None => {}
+        }
+
+        snapshot
+    }
+}
+
+fn describe_handle<M>(snapshot: &mut ActorSnapshot, handle: &ActorHandle<M>) {
+    let (available, capacity) = handle.mailbox_capacity();
+    snapshot.running = !handle.is_cancelled();
+    snapshot.high_priority = handle.is_high_priority();
+    snapshot.uptime = Some(handle.metadata.uptime());
+    snapshot.mailbox_len = capacity.saturating_sub(available);
+    snapshot.mailbox_capacity = capacity;
+    snapshot.metrics = handle.metrics();
+}
+
+/// Send a state query without waiting for mailbox space; a full mailbox is
+/// itself the answer.
+async fn query<M, T>(
+    handle: &ActorHandle<M>,
+    message: impl FnOnce(oneshot::Sender<T>) -> M,
+    timeout: Duration,
+) -> Option<T> {
+    let (tx, rx) = oneshot::channel();
+    handle.try_send(message(tx)).ok()?;
+    tokio::time::timeout(timeout, rx).await.ok()?.ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::sync::mpsc;
+    use tokio_util::sync::CancellationToken;
+
+    use crate::scheduler::actor::ActorMetadata;
+
+    fn streamer_entry(handle: ActorHandle<StreamerMessage>) -> ActorEntry {
+        ActorEntry {
+            id: "streamer-1".to_string(),
+            actor_type: "streamer".to_string(),
+            handle: Some(ActorEntryHandle::Streamer(handle)),
+            restarts: None,
+            restart_at: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_inspect_returns_actor_state() {
+        let (tx, mut rx) = mpsc::channel(4);
+        let handle = ActorHandle::new(
+            tx,
+            CancellationToken::new(),
+            ActorMetadata::streamer("streamer-1", false),
+        );
+        tokio::spawn(async move {
+            if let Some(StreamerMessage::GetState(reply)) = rx.recv().await {
+                let _ = reply.send(StreamerActorState::default());
+            }
+        });
+
+        let snapshot = streamer_entry(handle).inspect(Duration::from_secs(1)).await;
+
+        assert!(snapshot.running);
+        assert!(snapshot.responsive);
+        assert!(snapshot.streamer_state.is_some());
+        assert_eq!(snapshot.mailbox_capacity, 4);
+    }
+
+    #[tokio::test]
+    async fn test_inspect_times_out_on_busy_actor() {
+        let (tx, _rx) = mpsc::channel(4);
+        let handle = ActorHandle::new(
+            tx,
+            CancellationToken::new(),
+            ActorMetadata::streamer("streamer-1", false),
+        );
+
+        let snapshot = streamer_entry(handle)
+            .inspect(Duration::from_millis(10))
+            .await;
+
+        assert!(snapshot.running);
+        assert!(!snapshot.responsive);
+        assert!(snapshot.streamer_state.is_none());
+    }
+}
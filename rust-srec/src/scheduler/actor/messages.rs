@@ -4,7 +4,8 @@
 //! - `StreamerMessage`: Messages sent to StreamerActors
 //! - `PlatformMessage`: Messages sent to PlatformActors
 
-use std::time::Instant;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use chrono::{DateTime, Utc};
 use tokio::sync::oneshot;
@@ -12,6 +13,7 @@ use tokio::sync::oneshot;
 use crate::domain::{Priority, StreamerState};
 use crate::downloader::DownloadStopCause;
 use crate::downloader::engine::DownloadProgress;
+use crate::scheduler::LiveActivityProfile;
 use crate::streamer::StreamerMetadata;
 
 /// Messages that can be sent to a StreamerActor.
@@ -51,6 +53,9 @@ pub enum StreamerMessage {
     /// Notify that the download has ended (streamer went offline or error).
     /// This triggers the actor to resume status checking.
     DownloadEnded(DownloadEndPolicy),
+    /// A paused download was resumed; re-check now so a live result starts
+    /// a new download.
+    DownloadResumed,
     /// Request graceful shutdown.
     Stop,
     /// Query current state (response sent via oneshot channel).
@@ -202,6 +207,21 @@ pub enum DownloadEndPolicy {
         session_id: String,
     },
 
+    /// Download start was deferred because host load is above the load
+    /// admission policy.
+    ///
+    /// Nothing is wrong with the streamer, so no state is persisted. The
+    /// actor leaves Live and re-checks after `retry_after_secs`; a stream
+    /// still live then is admitted once load has dropped.
+    SystemOverloaded {
+        /// Which threshold was exceeded, and by how much.
+        reason: String,
+        /// Seconds to wait before re-checking.
+        retry_after_secs: u64,
+        /// Session ID of the download that was deferred.
+        session_id: String,
+    },
+
     /// Other error (unexpected/unknown reason).
     ///
     /// The actor preserves hysteresis and uses normal scheduling, allowing the
@@ -236,10 +256,16 @@ pub struct StreamerConfig {
     pub offline_check_interval_ms: u64,
     /// Number of offline checks before using offline interval.
     pub offline_check_count: u32,
+    /// Minimum time a live streamer must stay offline before the offline
+    /// is confirmed, in addition to `offline_check_count`. Zero disables it.
+    pub offline_grace_ms: u64,
     /// Priority level for this streamer.
     pub priority: Priority,
     /// Whether this streamer is on a batch-capable platform.
     pub batch_capable: bool,
+    /// When the streamer usually goes live, if adaptive polling is enabled
+    /// and the streamer has enough session history.
+    pub activity: Option<Arc<LiveActivityProfile>>,
 }
 
 impl Default for StreamerConfig {
@@ -248,8 +274,10 @@ impl Default for StreamerConfig {
             check_interval_ms: 60_000,
             offline_check_interval_ms: 20_000,
             offline_check_count: 3,
+            offline_grace_ms: 0,
             priority: Priority::Normal,
             batch_capable: false,
+            activity: None,
         }
     }
 }
@@ -342,7 +370,9 @@ pub struct BatchDetectionResult {
 ///
 /// Hysteresis prevents false offline events from transient network issues.
 /// After a streamer goes from Live → NotLive, we wait for N consecutive
-/// offline checks before emitting the offline event.
+/// offline checks before emitting the offline event. With an offline grace
+/// configured, the first offline observation must also be at least that old,
+/// so a short API flap or CDN hiccup resumes the same session.
 ///
 /// # State Machine
 ///
@@ -363,8 +393,9 @@ pub struct BatchDetectionResult {
 /// │                 Grace Period                                │
 /// │  was_live=true, offline_count increments each check         │
 /// │  Events SUPPRESSED until offline_count >= threshold         │
+/// │  and the grace has elapsed since the first offline check    │
 /// └────────────────────────┬────────────────────────────────────┘
-///                          │ offline_count >= threshold
+///                          │ offline_count >= threshold, grace elapsed
 ///                          ▼
 /// ┌─────────────────────────────────────────────────────────────┐
 /// │               Confirmed Offline                             │
@@ -380,6 +411,10 @@ pub struct HysteresisState {
     /// Consecutive offline check count during grace period.
     /// Only increments when `was_live` is true and we see NotLive.
     offline_count: u32,
+    /// When the first offline check of the current grace period was seen.
+    /// Monotonic, so not persisted; a restored actor restarts the grace.
+    #[serde(skip)]
+    offline_since: Option<Instant>,
 }
 
 impl HysteresisState {
@@ -393,6 +428,7 @@ impl HysteresisState {
         Self {
             was_live: state == StreamerState::Live,
             offline_count: 0,
+            offline_since: None,
         }
     }
 
@@ -406,11 +442,13 @@ impl HysteresisState {
     /// * `prev_state` - The previous streamer state
     /// * `new_state` - The newly detected streamer state
     /// * `threshold` - Number of offline checks before confirming offline (from config)
+    /// * `grace` - Minimum time since the first offline check before confirming offline
     pub fn should_emit(
         &mut self,
         prev_state: StreamerState,
         new_state: StreamerState,
         threshold: u32,
+        grace: Duration,
     ) -> bool {
         tracing::trace!(
             prev = ?prev_state,
@@ -418,6 +456,7 @@ impl HysteresisState {
             was_live = self.was_live,
             offline_count = self.offline_count,
             threshold,
+            ?grace,
             "hysteresis evaluate"
         );
 
@@ -440,18 +479,24 @@ impl HysteresisState {
                 tracing::debug!(prev = ?prev_state, "hysteresis emit (live)");
                 self.was_live = true;
                 self.offline_count = 0;
+                self.offline_since = None;
                 true
             }
 
             // Was live, now NotLive: Apply grace period
             (_, StreamerState::NotLive) if self.was_live => {
                 self.offline_count = self.offline_count.saturating_add(1);
+                let offline_for = self
+                    .offline_since
+                    .get_or_insert_with(Instant::now)
+                    .elapsed();
 
-                if self.offline_count >= threshold {
+                if self.offline_count >= threshold && offline_for >= grace {
                     // Grace period over - emit and reset
                     tracing::debug!(
                         offline_count = self.offline_count,
                         threshold,
+                        ?offline_for,
                         "hysteresis emit (offline confirmed)"
                     );
                     self.reset();
@@ -461,6 +506,7 @@ impl HysteresisState {
                     tracing::trace!(
                         offline_count = self.offline_count,
                         threshold,
+                        ?offline_for,
                         "hysteresis suppress (grace period)"
                     );
                     false
@@ -494,6 +540,7 @@ impl HysteresisState {
         );
         self.was_live = false;
         self.offline_count = 0;
+        self.offline_since = None;
     }
 
     /// Record an offline observation while the streamer is considered "recently live".
@@ -512,6 +559,7 @@ impl HysteresisState {
 
         let prev = self.offline_count;
         self.offline_count = self.offline_count.saturating_add(1);
+        self.offline_since.get_or_insert_with(Instant::now);
         tracing::trace!(
             was_live = true,
             offline_count_prev = prev,
@@ -533,6 +581,7 @@ impl HysteresisState {
         );
         self.was_live = true;
         self.offline_count = 0;
+        self.offline_since = None;
     }
 
     /// Check if currently in grace period (suppressing offline events).
@@ -617,9 +666,12 @@ impl StreamerActorState {
         self.streamer_state = result.state;
 
         // Delegate hysteresis logic to HysteresisState
-        let mut should_emit =
-            self.hysteresis
-                .should_emit(prev_state, result.state, config.offline_check_count);
+        let mut should_emit = self.hysteresis.should_emit(
+            prev_state,
+            result.state,
+            config.offline_check_count,
+            Duration::from_millis(config.offline_grace_ms),
+        );
 
         // If we're stuck in NotLive → NotLive and the streamer has accumulated errors,
         // allow a one-time Offline processing to clear the error state.
@@ -655,6 +707,8 @@ impl StreamerActorState {
     /// - Normal case (streamer was never live): Use `check_interval_ms` (longer)
     /// - Streamer in grace period (was_live): Use `offline_check_interval_ms` (shorter)
     ///   for quick re-detection
+    /// - Offline with an activity profile: `check_interval_ms` shortened near the times the
+    ///   streamer usually goes live and stretched during idle hours
     ///
     /// # Arguments
     ///
@@ -709,7 +763,15 @@ impl StreamerActorState {
             }
         }
 
-        self.next_check = Some(Instant::now() + std::time::Duration::from_millis(interval_ms));
+        let mut interval = std::time::Duration::from_millis(interval_ms);
+        if !use_short_interval
+            && self.streamer_state == StreamerState::NotLive
+            && let Some(activity) = &config.activity
+        {
+            interval = activity.next_interval(interval, Utc::now());
+        }
+
+        self.next_check = Some(Instant::now() + interval);
     }
 
     /// Force schedule a check (used when download fails or streamer goes offline).
@@ -848,6 +910,24 @@ mod tests {
         assert!(!state.hysteresis.was_live());
     }
 
+    #[test]
+    fn test_hysteresis_waits_for_offline_grace() {
+        let mut hysteresis = HysteresisState::from_state(StreamerState::Live);
+        let grace = Duration::from_millis(50);
+
+        // Count threshold reached, but the grace has not elapsed yet
+        assert!(!hysteresis.should_emit(StreamerState::Live, StreamerState::NotLive, 1, grace));
+        assert!(hysteresis.in_grace_period());
+
+        // A flap back to live clears the grace timer
+        assert!(hysteresis.should_emit(StreamerState::NotLive, StreamerState::Live, 1, grace));
+        assert!(!hysteresis.should_emit(StreamerState::Live, StreamerState::NotLive, 1, grace));
+
+        std::thread::sleep(grace);
+        assert!(hysteresis.should_emit(StreamerState::NotLive, StreamerState::NotLive, 1, grace));
+        assert!(!hysteresis.was_live());
+    }
+
     #[test]
     fn test_hysteresis_suppresses_redundant_offline_when_never_live() {
         let mut state = StreamerActorState::default();
@@ -890,6 +970,32 @@ mod tests {
         assert!(state.next_check.is_some());
     }
 
+    #[test]
+    fn test_activity_profile_stretches_offline_polling() {
+        let now = Utc::now();
+        let starts_at = |offset_hours: i64| {
+            (0..3).map(move |day| {
+                (now + chrono::Duration::hours(offset_hours) - chrono::Duration::days(day + 1))
+                    .timestamp_millis()
+            })
+        };
+        let config = |offset_hours: i64| StreamerConfig {
+            activity: LiveActivityProfile::from_start_times(starts_at(offset_hours)).map(Arc::new),
+            ..StreamerConfig::default()
+        };
+        let scheduled_in = |config: &StreamerConfig| {
+            let mut state = StreamerActorState::default();
+            state.schedule_next_check(config, 0);
+            state.time_until_next_check().unwrap()
+        };
+        let base = std::time::Duration::from_millis(StreamerConfig::default().check_interval_ms);
+
+        // Idle hours: far from every usual start time
+        assert!(scheduled_in(&config(12)) > base * 2);
+        // Usual start time
+        assert!(scheduled_in(&config(0)) <= base / 2);
+    }
+
     #[test]
     fn test_streamer_actor_state_no_check_when_live() {
         let mut state = StreamerActorState {
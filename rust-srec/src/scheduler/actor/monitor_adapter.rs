@@ -308,6 +308,7 @@ fn selected_stream_summary(stream: &platforms_parser::media::StreamInfo) -> Sele
         bitrate: stream.bitrate,
         codec: stream.codec.clone(),
         fps: stream.fps,
+        description: stream.description(),
     }
 }
 
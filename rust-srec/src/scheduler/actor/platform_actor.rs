@@ -23,7 +23,7 @@ use super::messages::{
     BatchDetectionResult, CheckResult, PlatformActorState, PlatformConfig, PlatformMessage,
     StreamerMessage,
 };
-use super::metrics::ActorMetrics;
+use super::metrics::{SharedActorMetrics, create_metrics};
 use super::monitor_adapter::{BatchChecker, NoOpBatchChecker};
 use super::streamer_actor::{ActorError, ActorOutcome, ActorResult};
 use crate::domain::StreamerState;
@@ -70,7 +70,7 @@ pub struct PlatformActor {
     /// Cancellation token.
     cancellation_token: CancellationToken,
     /// Metrics handle.
-    metrics: ActorMetrics,
+    metrics: SharedActorMetrics,
     /// Batch checker for performing actual batch checks.
     batch_checker: std::sync::Arc<dyn BatchChecker>,
     /// Flag to indicate batch timer needs to be reset after config change.
@@ -134,13 +134,13 @@ impl PlatformActor {
         let (tx, rx) = mpsc::channel(DEFAULT_MAILBOX_CAPACITY);
 
         let actor_metadata = ActorMetadata::platform(&platform_id);
-        let handle = ActorHandle::new(tx, cancellation_token.clone(), actor_metadata);
+        let metrics = create_metrics(&platform_id, DEFAULT_MAILBOX_CAPACITY);
+        let handle = ActorHandle::new(tx, cancellation_token.clone(), actor_metadata)
+            .with_metrics(metrics.clone());
 
         let batch_window = Duration::from_millis(config.batch_window_ms);
         let max_batch_size = config.max_batch_size;
 
-        let metrics = ActorMetrics::new(&platform_id, DEFAULT_MAILBOX_CAPACITY);
-
         let state = PlatformActorState {
             streamer_count: 0,
             pending_count: 0,
@@ -186,14 +186,14 @@ impl PlatformActor {
         let (priority_tx, priority_rx) = mpsc::channel(DEFAULT_PRIORITY_MAILBOX_CAPACITY);
 
         let actor_metadata = ActorMetadata::platform(&platform_id);
+        let metrics = create_metrics(&platform_id, DEFAULT_MAILBOX_CAPACITY);
         let handle =
-            ActorHandle::with_priority(tx, priority_tx, cancellation_token.clone(), actor_metadata);
+            ActorHandle::with_priority(tx, priority_tx, cancellation_token.clone(), actor_metadata)
+                .with_metrics(metrics.clone());
 
         let batch_window = Duration::from_millis(config.batch_window_ms);
         let max_batch_size = config.max_batch_size;
 
-        let metrics = ActorMetrics::new(&platform_id, DEFAULT_MAILBOX_CAPACITY);
-
         let state = PlatformActorState {
             streamer_count: 0,
             pending_count: 0,
@@ -359,6 +359,7 @@ mod tests {
             platform_config_id: "twitch".to_string(),
 
             template_config_id: None,
+            group_id: None,
             state: StreamerState::NotLive,
             priority: Priority::Normal,
             avatar_url: None,
@@ -386,8 +387,10 @@ mod tests {
             check_interval_ms: 1000,
             offline_check_interval_ms: 500,
             offline_check_count: 3,
+            offline_grace_ms: 0,
             priority: Priority::Normal,
             batch_capable: false,
+            activity: None,
         }
     }
 
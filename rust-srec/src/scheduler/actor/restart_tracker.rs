@@ -63,6 +63,8 @@ struct RestartHistory {
     total_restarts: u64,
     /// Last restart time.
     last_restart: Option<Instant>,
+    /// Error message of the last crash.
+    last_error: Option<String>,
 }
 
 impl RestartHistory {
@@ -71,6 +73,7 @@ impl RestartHistory {
             failures: Vec::new(),
             total_restarts: 0,
             last_restart: None,
+            last_error: None,
         }
     }
 
@@ -199,6 +202,24 @@ impl RestartTracker {
             .unwrap_or(0)
     }
 
+    /// Remember why an actor last crashed, for diagnostics.
+    pub fn set_last_error(&mut self, actor_id: &str, error: &str) {
+        if let Some(history) = self.history.get_mut(actor_id) {
+            history.last_error = Some(error.to_string());
+        }
+    }
+
+    /// Restart history of an actor, or `None` if it never crashed.
+    pub fn restart_info(&self, actor_id: &str) -> Option<RestartInfo> {
+        let history = self.history.get(actor_id)?;
+        Some(RestartInfo {
+            total_restarts: history.total_restarts,
+            recent_failures: history.recent_failures(Instant::now(), self.config.failure_window),
+            last_restart: history.last_restart,
+            last_error: history.last_error.clone(),
+        })
+    }
+
     /// Clear failure history for an actor.
     ///
     /// Call this when an actor has been running successfully for a while.
@@ -248,6 +269,19 @@ impl Default for RestartTracker {
     }
 }
 
+/// Restart history of a single actor.
+#[derive(Debug, Clone, Default)]
+pub struct RestartInfo {
+    /// Total restart count.
+    pub total_restarts: u64,
+    /// Failures within the failure window.
+    pub recent_failures: usize,
+    /// When the actor last crashed.
+    pub last_restart: Option<Instant>,
+    /// Error message of the last crash.
+    pub last_error: Option<String>,
+}
+
 /// Statistics from the restart tracker.
 #[derive(Debug, Clone)]
 pub struct RestartTrackerStats {
@@ -423,6 +457,22 @@ mod tests {
         assert_eq!(stats.total_restarts, 3);
     }
 
+    #[test]
+    fn test_restart_info() {
+        let mut tracker = RestartTracker::new();
+        assert!(tracker.restart_info("actor-1").is_none());
+
+        tracker.record_failure("actor-1");
+        tracker.set_last_error("actor-1", "boom");
+        tracker.record_failure("actor-1");
+
+        let info = tracker.restart_info("actor-1").unwrap();
+        assert_eq!(info.total_restarts, 2);
+        assert_eq!(info.recent_failures, 2);
+        assert!(info.last_restart.is_some());
+        assert_eq!(info.last_error.as_deref(), Some("boom"));
+    }
+
     #[test]
     fn test_calculate_backoff_formula() {
         let config = RestartTrackerConfig {
@@ -31,7 +31,7 @@ use super::messages::{
     BatchDetectionResult, CheckResult, HysteresisState, PlatformMessage, StreamerActorState,
     StreamerConfig, StreamerMessage,
 };
-use super::metrics::ActorMetrics;
+use super::metrics::{SharedActorMetrics, create_metrics};
 use super::monitor_adapter::StatusChecker;
 use crate::domain::{Priority, StreamerState};
 use crate::downloader::DownloadStopCause;
@@ -112,7 +112,7 @@ pub struct StreamerActor {
     /// Cancellation token.
     cancellation_token: CancellationToken,
     /// Metrics handle.
-    metrics: ActorMetrics,
+    metrics: SharedActorMetrics,
     /// State persistence path (optional).
     state_path: Option<PathBuf>,
     /// Status checker for performing actual status checks.
@@ -143,14 +143,15 @@ impl StreamerActor {
         let is_high_priority = config.priority == Priority::High;
 
         let actor_metadata = ActorMetadata::streamer(&streamer_id, is_high_priority);
-        let handle = ActorHandle::new(tx.clone(), cancellation_token.clone(), actor_metadata);
+        let metrics = create_metrics(&streamer_id, DEFAULT_MAILBOX_CAPACITY);
+        let handle = ActorHandle::new(tx.clone(), cancellation_token.clone(), actor_metadata)
+            .with_metrics(metrics.clone());
 
         // Get initial state from metadata store
         let state = metadata_store
             .get(&streamer_id)
             .map(|m| StreamerActorState::from_metadata(&m))
             .unwrap_or_default();
-        let metrics = ActorMetrics::new(&streamer_id, DEFAULT_MAILBOX_CAPACITY);
 
         let actor = Self {
             id: streamer_id,
@@ -187,19 +188,20 @@ impl StreamerActor {
         let is_high_priority = config.priority == Priority::High;
 
         let actor_metadata = ActorMetadata::streamer(&streamer_id, is_high_priority);
+        let metrics = create_metrics(&streamer_id, DEFAULT_MAILBOX_CAPACITY);
         let handle = ActorHandle::with_priority(
             tx.clone(),
             priority_tx,
             cancellation_token.clone(),
             actor_metadata,
-        );
+        )
+        .with_metrics(metrics.clone());
 
         // Get initial state from metadata store
         let state = metadata_store
             .get(&streamer_id)
             .map(|m| StreamerActorState::from_metadata(&m))
             .unwrap_or_default();
-        let metrics = ActorMetrics::new(&streamer_id, DEFAULT_MAILBOX_CAPACITY);
 
         let actor = Self {
             id: streamer_id,
@@ -798,6 +800,10 @@ impl StreamerActor {
                 self.handle_download_ended(reason).await?;
                 Ok(false)
             }
+            StreamerMessage::DownloadResumed => {
+                self.handle_download_resumed();
+                Ok(false)
+            }
             StreamerMessage::Stop => {
                 self.handle_stop().await?;
                 Ok(true)
@@ -809,6 +815,19 @@ impl StreamerActor {
         }
     }
 
+    /// Handle DownloadResumed message - re-drive the download of a paused
+    /// session.
+    ///
+    /// While paused the actor stays Live, so a Live check would be
+    /// suppressed by the `(Live, Live)` arm of `HysteresisState::should_emit`.
+    /// Dropping to NotLive makes the immediate check register as a
+    /// NotLive→Live transition, and `process_status` starts the download.
+    fn handle_download_resumed(&mut self) {
+        debug!("StreamerActor {} received DownloadResumed", self.id);
+        self.state.streamer_state = StreamerState::NotLive;
+        self.state.schedule_immediate_check();
+    }
+
     /// Handle CheckStatus message - trigger an immediate check.
     async fn handle_check_status(&mut self) -> Result<(), ActorError> {
         debug!("StreamerActor {} received CheckStatus", self.id);
@@ -1100,6 +1119,36 @@ impl StreamerActor {
                 self.state.hysteresis.reset();
                 self.state.schedule_next_check(&self.config, error_count);
             }
+            DownloadEndPolicy::Stopped(DownloadStopCause::Paused) => {
+                // The user paused the download: the stream is still live and
+                // the session stays open. Do NOT publish Offline. Stay Live
+                // with checks parked, so only the stall watchdog re-checks
+                // the platform (ending the session if the streamer went
+                // offline meanwhile); its forced re-emit is ignored by the
+                // download pipeline while the pause lasts.
+                self.state.streamer_state = StreamerState::Live;
+                if !self.state.hysteresis.was_live() {
+                    self.state.hysteresis.mark_live();
+                }
+                self.state.schedule_next_check(&self.config, error_count);
+            }
+            DownloadEndPolicy::Stopped(DownloadStopCause::LowDiskSpace) => {
+                // The output volume ran low on space while the stream was
+                // still live. Do NOT publish Offline. Dropping to NotLive
+                // makes the immediate check start a new download, which
+                // preflight either moves to the secondary output folder or
+                // refuses until space frees up.
+                self.state.streamer_state = StreamerState::NotLive;
+                self.state.schedule_immediate_check();
+            }
+            DownloadEndPolicy::Stopped(DownloadStopCause::Preempted { .. }) => {
+                // A higher-priority streamer took the download slot while
+                // the stream was still live. Do NOT publish Offline.
+                // Dropping to NotLive makes the immediate check queue a new
+                // download, which resumes the recording once a slot frees.
+                self.state.streamer_state = StreamerState::NotLive;
+                self.state.schedule_immediate_check();
+            }
             DownloadEndPolicy::OutOfSchedule => {
                 // Policy stop: the streamer may still be live, but the recording window ended.
                 // Do NOT publish Offline; the monitor already recorded OutOfSchedule.
@@ -1333,6 +1382,25 @@ impl StreamerActor {
 
                 self.schedule_blocked_retry(StreamerState::TemporalDisabled, retry_after_secs);
             }
+            DownloadEndPolicy::SystemOverloaded {
+                reason,
+                retry_after_secs,
+                ..
+            } => {
+                // Preflight deferred a new recording because the host is
+                // overloaded. Leaving Live is what lets the re-check start
+                // the download: NotLive → Live is an emitting transition in
+                // `HysteresisState::should_emit`. Nothing is persisted since
+                // the streamer itself is healthy.
+                info!(
+                    streamer_id = %self.id,
+                    reason = %reason,
+                    retry_after_secs,
+                    "download deferred by load admission; rescheduling check"
+                );
+
+                self.schedule_blocked_retry(StreamerState::NotLive, retry_after_secs);
+            }
         }
 
         debug!(
@@ -1545,6 +1613,9 @@ pub struct PersistedConfig {
     pub offline_check_interval_ms: u64,
     /// Offline check count threshold.
     pub offline_check_count: u32,
+    /// Offline grace in milliseconds.
+    #[serde(default)]
+    pub offline_grace_ms: u64,
     /// Priority level.
     pub priority: String,
     /// Whether batch capable.
@@ -1568,6 +1639,7 @@ impl PersistedActorState {
                 check_interval_ms: config.check_interval_ms,
                 offline_check_interval_ms: config.offline_check_interval_ms,
                 offline_check_count: config.offline_check_count,
+                offline_grace_ms: config.offline_grace_ms,
                 priority: format!("{:?}", config.priority),
                 batch_capable: config.batch_capable,
             },
@@ -1614,8 +1686,11 @@ impl PersistedActorState {
             check_interval_ms: self.config.check_interval_ms,
             offline_check_interval_ms: self.config.offline_check_interval_ms,
             offline_check_count: self.config.offline_check_count,
+            offline_grace_ms: self.config.offline_grace_ms,
             priority,
             batch_capable: self.config.batch_capable,
+            // Rebuilt from session history by the scheduler, never persisted
+            activity: None,
         };
 
         (state, config)
@@ -1640,6 +1715,7 @@ mod tests {
             url: "https://twitch.tv/test".to_string(),
             platform_config_id: "twitch".to_string(),
             template_config_id: None,
+            group_id: None,
             state: StreamerState::NotLive,
             priority: Priority::Normal,
             avatar_url: None,
@@ -1667,8 +1743,10 @@ mod tests {
             check_interval_ms: 1000, // 1 second for tests
             offline_check_interval_ms: 500,
             offline_check_count: 3,
+            offline_grace_ms: 0,
             priority: Priority::Normal,
             batch_capable: false,
+            activity: None,
         }
     }
 
@@ -1829,8 +1907,10 @@ mod tests {
             check_interval_ms: 5000,
             offline_check_interval_ms: 2000,
             offline_check_count: 5,
+            offline_grace_ms: 0,
             priority: Priority::High,
             batch_capable: false,
+            activity: None,
         };
         handle
             .send(StreamerMessage::ConfigUpdate(new_config))
@@ -1944,7 +2024,12 @@ mod tests {
         hysteresis.mark_live();
         // Simulate some offline checks
         for _ in 0..5 {
-            hysteresis.should_emit(StreamerState::Live, StreamerState::NotLive, 10);
+            hysteresis.should_emit(
+                StreamerState::Live,
+                StreamerState::NotLive,
+                10,
+                Duration::ZERO,
+            );
         }
 
         let state = StreamerActorState {
@@ -1959,8 +2044,10 @@ mod tests {
             check_interval_ms: 30000,
             offline_check_interval_ms: 10000,
             offline_check_count: 5,
+            offline_grace_ms: 0,
             priority: Priority::High,
             batch_capable: true,
+            activity: None,
         };
 
         let persisted = PersistedActorState::from_state("test", &state, &config);
@@ -2546,6 +2633,41 @@ mod tests {
         // download through the normal monitor path.
     }
 
+    #[tokio::test]
+    async fn test_download_ended_system_overloaded_rechecks_later() {
+        let metadata_store = create_test_metadata_store();
+        let config = create_test_config();
+        let token = CancellationToken::new();
+
+        let (mut actor, _handle) = StreamerActor::new(
+            "test-streamer".to_string(),
+            metadata_store,
+            config,
+            token,
+            create_noop_checker(),
+        );
+
+        actor.state.streamer_state = StreamerState::Live;
+        actor.state.hysteresis.mark_live();
+        actor.state.next_check = None;
+
+        actor
+            .handle_download_ended(
+                super::super::messages::DownloadEndPolicy::SystemOverloaded {
+                    reason: "CPU usage 97% is above the 85% limit".to_string(),
+                    retry_after_secs: 30,
+                    session_id: "session-1".to_string(),
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(actor.state.streamer_state, StreamerState::NotLive);
+        let until = actor.state.time_until_next_check().unwrap();
+        assert!(until <= Duration::from_secs(30));
+        assert!(until > Duration::from_secs(20));
+    }
+
     #[tokio::test]
     async fn test_suppressed_live_restores_notlive_grace_hysteresis_context() {
         let metadata_store = create_test_metadata_store();
@@ -2790,4 +2912,93 @@ mod tests {
         );
         assert!(actor.state.next_check.is_some());
     }
+
+    #[tokio::test]
+    async fn paused_download_keeps_the_streamer_live_until_resumed() {
+        let metadata_store = create_test_metadata_store();
+        let config = StreamerConfig::default();
+        let token = CancellationToken::new();
+        let (mut actor, _handle) = StreamerActor::new(
+            "test-streamer".to_string(),
+            metadata_store,
+            config,
+            token,
+            Arc::new(AssertNotCalledStatusChecker),
+        );
+        actor.state.streamer_state = StreamerState::Live;
+        actor.state.hysteresis.mark_live();
+        actor.state.next_check = None;
+
+        // No panic ⇒ pausing did NOT publish Offline through `process_status`.
+        actor
+            .handle_download_ended(super::super::messages::DownloadEndPolicy::Stopped(
+                DownloadStopCause::Paused,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(actor.state.streamer_state, StreamerState::Live);
+        assert!(actor.state.next_check.is_none());
+
+        actor.handle_download_resumed();
+        assert_eq!(actor.state.streamer_state, StreamerState::NotLive);
+        assert_eq!(
+            actor.state.time_until_next_check(),
+            Some(Duration::ZERO),
+            "resume re-checks immediately"
+        );
+    }
+
+    #[tokio::test]
+    async fn low_disk_space_stop_rechecks_without_going_offline() {
+        let metadata_store = create_test_metadata_store();
+        let config = StreamerConfig::default();
+        let token = CancellationToken::new();
+        let (mut actor, _handle) = StreamerActor::new(
+            "test-streamer".to_string(),
+            metadata_store,
+            config,
+            token,
+            Arc::new(AssertNotCalledStatusChecker),
+        );
+        actor.state.streamer_state = StreamerState::Live;
+        actor.state.hysteresis.mark_live();
+
+        // No panic ⇒ the stop did NOT publish Offline through `process_status`.
+        actor
+            .handle_download_ended(super::super::messages::DownloadEndPolicy::Stopped(
+                DownloadStopCause::LowDiskSpace,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(actor.state.streamer_state, StreamerState::NotLive);
+        assert_eq!(actor.state.time_until_next_check(), Some(Duration::ZERO));
+    }
+
+    #[tokio::test]
+    async fn preempted_stop_rechecks_without_going_offline() {
+        let metadata_store = create_test_metadata_store();
+        let config = StreamerConfig::default();
+        let token = CancellationToken::new();
+        let (mut actor, _handle) = StreamerActor::new(
+            "test-streamer".to_string(),
+            metadata_store,
+            config,
+            token,
+            Arc::new(AssertNotCalledStatusChecker),
+        );
+        actor.state.streamer_state = StreamerState::Live;
+        actor.state.hysteresis.mark_live();
+
+        actor
+            .handle_download_ended(super::super::messages::DownloadEndPolicy::Stopped(
+                DownloadStopCause::Preempted {
+                    by_streamer_id: "vip".to_string(),
+                    by_streamer_name: "Vip".to_string(),
+                },
+            ))
+            .await
+            .unwrap();
+        assert_eq!(actor.state.streamer_state, StreamerState::NotLive);
+        assert_eq!(actor.state.time_until_next_check(), Some(Duration::ZERO));
+    }
 }
@@ -17,6 +17,7 @@ use tokio_util::sync::CancellationToken;
 use tracing::{debug, error, info, warn};
 
 use super::handle::ActorHandle;
+use super::introspection::{ActorEntry, ActorEntryHandle};
 use super::messages::{PlatformConfig, PlatformMessage, StreamerConfig, StreamerMessage};
 use super::monitor_adapter::{BatchChecker, NoOpBatchChecker, NoOpStatusChecker, StatusChecker};
 use super::platform_actor::PlatformActor;
@@ -304,6 +305,7 @@ impl Supervisor {
 
             // Record failure and get backoff
             let backoff = self.restart_tracker.record_failure(&actor_id);
+            self.restart_tracker.set_last_error(&actor_id, error_msg);
 
             // Check if we should restart
             if self.restart_tracker.should_restart(&actor_id) {
@@ -453,6 +455,42 @@ impl Supervisor {
         self.pending_restarts.len()
     }
 
+    /// List every actor the supervisor tracks, including crashed actors
+    /// waiting for their restart backoff.
+    pub fn actor_entries(&self) -> Vec<ActorEntry> {
+        let streamers = self
+            .registry
+            .streamer_handles_map()
+            .iter()
+            .map(|(id, handle)| ActorEntry {
+                id: id.clone(),
+                actor_type: "streamer".to_string(),
+                handle: Some(ActorEntryHandle::Streamer(handle.clone())),
+                restarts: self.restart_tracker.restart_info(id),
+                restart_at: None,
+            });
+        let platforms = self
+            .registry
+            .platform_handles_map()
+            .iter()
+            .map(|(id, handle)| ActorEntry {
+                id: id.clone(),
+                actor_type: "platform".to_string(),
+                handle: Some(ActorEntryHandle::Platform(handle.clone())),
+                restarts: self.restart_tracker.restart_info(id),
+                restart_at: None,
+            });
+        let pending = self.pending_restarts.iter().map(|pending| ActorEntry {
+            id: pending.actor_id.clone(),
+            actor_type: pending.actor_type.clone(),
+            handle: None,
+            restarts: self.restart_tracker.restart_info(&pending.actor_id),
+            restart_at: Some(pending.restart_at),
+        });
+
+        streamers.chain(platforms).chain(pending).collect()
+    }
+
     /// Initiate graceful shutdown.
     ///
     /// This method implements the graceful shutdown sequence:
@@ -711,6 +749,7 @@ mod tests {
             url: format!("https://twitch.tv/{}", id),
             platform_config_id: "twitch".to_string(),
             template_config_id: None,
+            group_id: None,
             state: StreamerState::NotLive,
             priority: Priority::Normal,
             avatar_url: None,
@@ -735,8 +774,10 @@ mod tests {
             check_interval_ms: 1000,
             offline_check_interval_ms: 500,
             offline_check_count: 3,
+            offline_grace_ms: 0,
             priority: Priority::Normal,
             batch_capable: false,
+            activity: None,
         }
     }
 
@@ -117,6 +117,7 @@ mod tests {
             platform_config_id: platform.to_string(),
 
             template_config_id: None,
+            group_id: None,
             state: StreamerState::NotLive,
             priority: Priority::Normal,
             avatar_url: None,
@@ -1,11 +1,11 @@
 //! Resource monitoring for the scheduler.
 //!
-//! This module handles checking system resources like disk space
-//! before allowing downloads to proceed.
+//! This module handles checking system resources like disk space and
+//! CPU, memory and I/O load before allowing downloads to proceed.
 
 use std::path::Path;
 
-use sysinfo::Disks;
+use sysinfo::{CpuRefreshKind, Disks, MemoryRefreshKind, RefreshKind, System};
 use tracing::{debug, warn};
 
 /// Result of a disk space check.
@@ -39,11 +39,25 @@ impl DiskSpaceStatus {
     }
 }
 
+/// Host load sampled by [`ResourceMonitor::sample_load`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SystemLoad {
+    /// Global CPU usage percent (0-100) since the previous sample.
+    pub cpu_percent: f32,
+    /// Used memory percent (0-100).
+    pub memory_percent: f32,
+    /// Share of the last 10 seconds in which at least one task stalled on
+    /// I/O, from Linux PSI (`/proc/pressure/io`). `None` where unavailable.
+    pub io_pressure_percent: Option<f32>,
+}
+
 /// Resource monitor for checking system resources.
 #[derive(Debug, Default)]
 pub struct ResourceMonitor {
     /// Cached disk information.
     disks: Disks,
+    /// CPU and memory inventory backing [`Self::sample_load`].
+    system: System,
 }
 
 impl ResourceMonitor {
@@ -51,6 +65,36 @@ impl ResourceMonitor {
     pub fn new() -> Self {
         Self {
             disks: Disks::new_with_refreshed_list(),
+            system: System::new_with_specifics(
+                RefreshKind::nothing()
+                    .with_cpu(CpuRefreshKind::nothing().with_cpu_usage())
+                    .with_memory(MemoryRefreshKind::nothing().with_ram()),
+            ),
+        }
+    }
+
+    /// Sample CPU, memory and I/O load.
+    ///
+    /// CPU usage covers the time since the previous call, so the first
+    /// sample of a monitor reads as idle. Callers sample periodically and
+    /// act on the latest result.
+    pub fn sample_load(&mut self) -> SystemLoad {
+        self.system
+            .refresh_cpu_specifics(CpuRefreshKind::nothing().with_cpu_usage());
+        self.system
+            .refresh_memory_specifics(MemoryRefreshKind::nothing().with_ram());
+
+        let total_memory = self.system.total_memory();
+        let memory_percent = if total_memory == 0 {
+            0.0
+        } else {
+            (self.system.used_memory() as f64 / total_memory as f64 * 100.0) as f32
+        };
+
+        SystemLoad {
+            cpu_percent: self.system.global_cpu_usage(),
+            memory_percent,
+            io_pressure_percent: read_io_pressure(),
         }
     }
 
@@ -123,6 +167,18 @@ impl ResourceMonitor {
 
     /// Get available space for a path.
     fn get_available_space_for_path(&self, path: &Path) -> Option<u64> {
+        self.find_disk(path).map(|disk| disk.available_space())
+    }
+
+    /// Get the disk that holds `path`, as of the last refresh.
+    ///
+    /// Does not refresh, so callers checking many paths can refresh once
+    /// with [`Self::refresh`] first.
+    pub fn disk_for_path(&self, path: &Path) -> Option<DiskInfo> {
+        self.find_disk(path).map(DiskInfo::from)
+    }
+
+    fn find_disk(&self, path: &Path) -> Option<&sysinfo::Disk> {
         // Try to find the disk that contains this path
         // We look for the disk with the longest matching mount point
 
@@ -144,7 +200,7 @@ impl ResourceMonitor {
             }
         }
 
-        best_match.map(|(disk, _)| disk.available_space())
+        best_match.map(|(disk, _)| disk)
     }
 
     /// Get total available space across all disks.
@@ -156,19 +212,34 @@ impl ResourceMonitor {
     /// Get disk information for logging/debugging.
     pub fn disk_info(&mut self) -> Vec<DiskInfo> {
         self.refresh();
-        self.disks
-            .list()
-            .iter()
-            .map(|d| DiskInfo {
-                name: d.name().to_string_lossy().to_string(),
-                mount_point: d.mount_point().to_string_lossy().to_string(),
-                total_bytes: d.total_space(),
-                available_bytes: d.available_space(),
-            })
-            .collect()
+        self.disks.list().iter().map(DiskInfo::from).collect()
     }
 }
 
+/// Read the `some avg10` value of Linux pressure stall information for I/O.
+#[cfg(target_os = "linux")]
+fn read_io_pressure() -> Option<f32> {
+    std::fs::read_to_string("/proc/pressure/io")
+        .ok()
+        .and_then(|psi| parse_io_pressure(&psi))
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_io_pressure() -> Option<f32> {
+    None
+}
+
+/// Parse the `some avg10=` field of a PSI file.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_io_pressure(psi: &str) -> Option<f32> {
+    psi.lines()
+        .find_map(|line| line.strip_prefix("some "))?
+        .split_whitespace()
+        .find_map(|field| field.strip_prefix("avg10="))?
+        .parse()
+        .ok()
+}
+
 /// Information about a disk.
 #[derive(Debug, Clone)]
 pub struct DiskInfo {
@@ -182,6 +253,17 @@ pub struct DiskInfo {
     pub available_bytes: u64,
 }
 
+impl From<&sysinfo::Disk> for DiskInfo {
+    fn from(disk: &sysinfo::Disk) -> Self {
+        Self {
+            name: disk.name().to_string_lossy().to_string(),
+            mount_point: disk.mount_point().to_string_lossy().to_string(),
+            total_bytes: disk.total_space(),
+            available_bytes: disk.available_space(),
+        }
+    }
+}
+
 impl DiskInfo {
     /// Get the percentage of space used.
     pub fn used_percent(&self) -> f64 {
@@ -240,6 +322,23 @@ mod tests {
         assert!(status.is_ok());
     }
 
+    #[test]
+    fn test_sample_load_is_bounded() {
+        let mut monitor = ResourceMonitor::new();
+        let load = monitor.sample_load();
+        assert!((0.0..=100.0).contains(&load.cpu_percent));
+        assert!((0.0..=100.0).contains(&load.memory_percent));
+    }
+
+    #[test]
+    fn test_parse_io_pressure() {
+        let psi = "some avg10=12.50 avg60=3.10 avg300=0.80 total=123456\n\
+                   full avg10=4.00 avg60=1.00 avg300=0.20 total=65432\n";
+        assert_eq!(parse_io_pressure(psi), Some(12.5));
+        assert_eq!(parse_io_pressure("full avg10=4.00 total=1\n"), None);
+        assert_eq!(parse_io_pressure(""), None);
+    }
+
     #[test]
     fn test_disk_info_used_percent() {
         let info = DiskInfo {
@@ -17,7 +17,7 @@ use std::sync::atomic::{AtomicI64, Ordering};
 use std::time::{Duration, Instant};
 
 use dashmap::DashMap;
-use tokio::sync::{broadcast, watch};
+use tokio::sync::{broadcast, mpsc, oneshot, watch};
 use tokio_util::sync::CancellationToken;
 use tracing::{debug, error, info, trace, warn};
 
@@ -33,23 +33,77 @@ use crate::downloader::{
 use crate::monitor::StreamMonitor;
 use crate::streamer::{StreamerManager, StreamerMetadata};
 
+use super::activity::{
+    ACTIVITY_HISTORY_DAYS, ACTIVITY_REFRESH_INTERVAL, LiveActivityProfile,
+    build_profiles as build_activity_profiles,
+};
 use super::actor::{
-    ActorHandle, ConfigRouter, ConfigScope, DownloadEndPolicy, MonitorBatchChecker,
-    MonitorStatusChecker, PlatformConfig, PlatformMapping, PlatformMessage, RoutingPlan,
-    ShutdownReport, StreamerConfig, StreamerMessage, Supervisor, SupervisorConfig,
-    TaskCompletionAction,
+    ActorEntry, ActorEntryHandle, ActorHandle, ActorSnapshot, ConfigRouter, ConfigScope,
+    DownloadEndPolicy, MonitorBatchChecker, MonitorStatusChecker, PlatformConfig, PlatformMapping,
+    PlatformMessage, RoutingPlan, ShutdownReport, StreamerConfig, StreamerMessage, Supervisor,
+    SupervisorConfig, TaskCompletionAction,
 };
 
-/// Read-only scheduler state for health and diagnostics consumers.
+/// How long the scheduler loop may take to hand out its actor list.
+const INSPECT_REPLY_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// How long a single actor may take to report its state.
+const INSPECT_ACTOR_TIMEOUT: Duration = Duration::from_millis(500);
+
+type InspectRequest = oneshot::Sender<Vec<ActorEntry>>;
+
+/// Scheduler state for health and diagnostics consumers, plus manual checks.
 #[derive(Clone)]
 pub(crate) struct SchedulerHandle {
     stats_rx: watch::Receiver<super::actor::SupervisorStats>,
+    inspect_tx: mpsc::Sender<InspectRequest>,
 }
 
 impl SchedulerHandle {
     pub(crate) fn stats(&self) -> super::actor::SupervisorStats {
         self.stats_rx.borrow().clone()
     }
+
+    /// Snapshot the supervised actors, optionally only the one with `id`.
+    ///
+    /// Returns `None` if the scheduler loop is not running.
+    pub(crate) async fn actors(&self, id: Option<&str>) -> Option<Vec<ActorSnapshot>> {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        self.inspect_tx.try_send(reply_tx).ok()?;
+        let entries = tokio::time::timeout(INSPECT_REPLY_TIMEOUT, reply_rx)
+            .await
+            .ok()?
+            .ok()?;
+
+        let snapshots = entries
+            .into_iter()
+            .filter(|entry| id.is_none_or(|id| entry.id == id))
+            .map(|entry| entry.inspect(INSPECT_ACTOR_TIMEOUT));
+        Some(futures::future::join_all(snapshots).await)
+    }
+
+    /// Ask a streamer's actor to check its status right away, ignoring its
+    /// polling schedule.
+    ///
+    /// Returns `None` if the scheduler loop is not running and `Some(false)`
+    /// if the streamer has no running actor.
+    pub(crate) async fn check_now(&self, streamer_id: &str) -> Option<bool> {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        self.inspect_tx.try_send(reply_tx).ok()?;
+        let entries = tokio::time::timeout(INSPECT_REPLY_TIMEOUT, reply_rx)
+            .await
+            .ok()?
+            .ok()?;
+
+        let handle = entries.into_iter().find_map(|entry| match entry.handle {
+            Some(ActorEntryHandle::Streamer(handle)) if entry.id == streamer_id => Some(handle),
+            _ => None,
+        });
+        Some(match handle {
+            Some(handle) => handle.try_send(StreamerMessage::CheckStatus).is_ok(),
+            None => false,
+        })
+    }
 }
 
 /// Default check interval (60 seconds).
@@ -86,6 +140,11 @@ pub struct SchedulerConfig {
     pub offline_check_interval_ms: u64,
     /// Number of offline checks before using offline interval.
     pub offline_check_count: u32,
+    /// Adapt offline polling to when each streamer usually goes live.
+    pub adaptive_polling: bool,
+    /// Minimum time a live streamer must stay offline before the offline
+    /// is confirmed, in milliseconds. Zero confirms by check count alone.
+    pub offline_grace_ms: u64,
     /// Supervisor configuration.
     pub supervisor_config: SupervisorConfig,
 }
@@ -96,6 +155,8 @@ impl Default for SchedulerConfig {
             check_interval_ms: DEFAULT_CHECK_INTERVAL_MS,
             offline_check_interval_ms: DEFAULT_OFFLINE_CHECK_INTERVAL_MS,
             offline_check_count: DEFAULT_OFFLINE_CHECK_COUNT,
+            adaptive_polling: false,
+            offline_grace_ms: 0,
             supervisor_config: SupervisorConfig::default(),
         }
     }
@@ -128,12 +189,19 @@ pub struct Scheduler<R: StreamerRepository + Send + Sync + 'static> {
     config: SchedulerConfig,
     /// Config repository for pulling fresh global timing config on hot reload.
     config_repo: Option<Arc<dyn ConfigRepository>>,
+    /// Session repository for learning when streamers usually go live.
+    session_repo: Option<Arc<dyn SessionRepository>>,
+    /// Activity profiles by streamer id; empty unless adaptive polling is enabled.
+    activity_profiles: HashMap<String, Arc<LiveActivityProfile>>,
     /// Cancellation token for graceful shutdown.
     cancellation_token: CancellationToken,
     /// Supervisor for managing actor lifecycle.
     supervisor: Supervisor,
     /// Latest supervisor snapshot for read-only runtime consumers.
     stats_tx: watch::Sender<super::actor::SupervisorStats>,
+    /// Actor listing requests from [`SchedulerHandle`].
+    inspect_tx: mpsc::Sender<InspectRequest>,
+    inspect_rx: Option<mpsc::Receiver<InspectRequest>>,
     /// Platform mapping for config routing.
     platform_mapping: PlatformMapping,
     /// Platform actor handles for batch coordination.
@@ -230,15 +298,20 @@ impl<R: StreamerRepository + Send + Sync + 'static> Scheduler<R> {
             metadata_store,
         );
         let (stats_tx, _) = watch::channel(supervisor.stats());
+        let (inspect_tx, inspect_rx) = mpsc::channel(8);
 
         Self {
             streamer_manager,
             event_broadcaster,
             config,
             config_repo: None,
+            session_repo: None,
+            activity_profiles: HashMap::new(),
             cancellation_token,
             supervisor,
             stats_tx,
+            inspect_tx,
+            inspect_rx: Some(inspect_rx),
             platform_mapping: PlatformMapping::new(),
             platform_handles: HashMap::new(),
             download_event_rx: None,
@@ -364,15 +437,20 @@ impl<R: StreamerRepository + Send + Sync + 'static> Scheduler<R> {
             batch_checker,
         );
         let (stats_tx, _) = watch::channel(supervisor.stats());
+        let (inspect_tx, inspect_rx) = mpsc::channel(8);
 
         Self {
             streamer_manager,
             event_broadcaster,
             config,
             config_repo: None,
+            session_repo: None,
+            activity_profiles: HashMap::new(),
             cancellation_token,
             supervisor,
             stats_tx,
+            inspect_tx,
+            inspect_rx: Some(inspect_rx),
             platform_mapping: PlatformMapping::new(),
             platform_handles: HashMap::new(),
             download_event_rx: None,
@@ -388,6 +466,12 @@ impl<R: StreamerRepository + Send + Sync + 'static> Scheduler<R> {
         self
     }
 
+    /// Attach a session repository to learn polling windows from session history.
+    pub fn with_session_repo(mut self, session_repo: Arc<dyn SessionRepository>) -> Self {
+        self.session_repo = Some(session_repo);
+        self
+    }
+
     /// Get the cancellation token for this scheduler.
     pub fn cancellation_token(&self) -> CancellationToken {
         self.cancellation_token.clone()
@@ -397,6 +481,7 @@ impl<R: StreamerRepository + Send + Sync + 'static> Scheduler<R> {
     pub(crate) fn handle(&self) -> SchedulerHandle {
         SchedulerHandle {
             stats_rx: self.stats_tx.subscribe(),
+            inspect_tx: self.inspect_tx.clone(),
         }
     }
 
@@ -437,8 +522,10 @@ impl<R: StreamerRepository + Send + Sync + 'static> Scheduler<R> {
             check_interval_ms: self.config.check_interval_ms,
             offline_check_interval_ms: metadata.effective_offline_check_delay_ms,
             offline_check_count: metadata.effective_offline_check_count,
+            offline_grace_ms: self.config.offline_grace_ms,
             priority: metadata.priority,
             batch_capable: self.is_batch_capable_platform(&metadata.platform_config_id),
+            activity: self.activity_profiles.get(&metadata.id).cloned(),
         }
     }
 
@@ -462,33 +549,87 @@ impl<R: StreamerRepository + Send + Sync + 'static> Scheduler<R> {
             check_interval_ms: global.streamer_check_delay_ms as u64,
             offline_check_interval_ms: global.offline_check_delay_ms as u64,
             offline_check_count: global.offline_check_count as u32,
+            adaptive_polling: global.adaptive_polling,
+            offline_grace_ms: global.offline_grace_secs.max(0) as u64 * 1000,
             supervisor_config: self.config.supervisor_config.clone(),
         };
 
-        if next.check_interval_ms == self.config.check_interval_ms
-            && next.offline_check_interval_ms == self.config.offline_check_interval_ms
-            && next.offline_check_count == self.config.offline_check_count
-        {
+        let timing_changed = next.check_interval_ms != self.config.check_interval_ms
+            || next.offline_check_interval_ms != self.config.offline_check_interval_ms
+            || next.offline_check_count != self.config.offline_check_count
+            || next.offline_grace_ms != self.config.offline_grace_ms;
+        let adaptive_changed = next.adaptive_polling != self.config.adaptive_polling;
+        if !timing_changed && !adaptive_changed {
             return Ok(false);
         }
 
-        info!(
-            "Scheduler timing config updated: check_interval_ms {}->{}; offline_check_interval_ms {}->{}; offline_check_count {}->{}",
-            self.config.check_interval_ms,
-            next.check_interval_ms,
-            self.config.offline_check_interval_ms,
-            next.offline_check_interval_ms,
-            self.config.offline_check_count,
-            next.offline_check_count,
-        );
+        if timing_changed {
+            info!(
+                "Scheduler timing config updated: check_interval_ms {}->{}; offline_check_interval_ms {}->{}; offline_check_count {}->{}; offline_grace_ms {}->{}",
+                self.config.check_interval_ms,
+                next.check_interval_ms,
+                self.config.offline_check_interval_ms,
+                next.offline_check_interval_ms,
+                self.config.offline_check_count,
+                next.offline_check_count,
+                self.config.offline_grace_ms,
+                next.offline_grace_ms,
+            );
+
+            self.config.check_interval_ms = next.check_interval_ms;
+            self.config.offline_check_interval_ms = next.offline_check_interval_ms;
+            self.config.offline_check_count = next.offline_check_count;
+            self.config.offline_grace_ms = next.offline_grace_ms;
+        }
 
-        self.config.check_interval_ms = next.check_interval_ms;
-        self.config.offline_check_interval_ms = next.offline_check_interval_ms;
-        self.config.offline_check_count = next.offline_check_count;
+        if adaptive_changed {
+            info!(
+                "Adaptive polling {}",
+                if next.adaptive_polling {
+                    "enabled"
+                } else {
+                    "disabled"
+                }
+            );
+            self.config.adaptive_polling = next.adaptive_polling;
+            self.refresh_activity_profiles().await;
+        }
 
         Ok(true)
     }
 
+    /// Rebuild activity profiles from recent session history, or drop them
+    /// when adaptive polling is off.
+    ///
+    /// Returns whether any profile changed. A failed read keeps the current
+    /// profiles.
+    async fn refresh_activity_profiles(&mut self) -> bool {
+        let profiles = match (&self.session_repo, self.config.adaptive_polling) {
+            (Some(repo), true) => {
+                let since =
+                    crate::database::time::now_ms() - ACTIVITY_HISTORY_DAYS * 24 * 60 * 60 * 1000;
+                match repo.list_session_start_times_since(since).await {
+                    Ok(start_times) => build_activity_profiles(start_times),
+                    Err(e) => {
+                        warn!("Failed to load session history for adaptive polling: {}", e);
+                        return false;
+                    }
+                }
+            }
+            _ => HashMap::new(),
+        };
+
+        if profiles == self.activity_profiles {
+            return false;
+        }
+        debug!(
+            "Adaptive polling profiles rebuilt for {} streamers",
+            profiles.len()
+        );
+        self.activity_profiles = profiles;
+        true
+    }
+
     /// Check if a platform supports batch detection.
     fn is_batch_capable_platform(&self, platform_id: &str) -> bool {
         let platform = platform_id.strip_prefix("platform-").unwrap_or(platform_id);
@@ -509,6 +650,17 @@ impl<R: StreamerRepository + Send + Sync + 'static> Scheduler<R> {
         // Take the download event receiver
         let mut download_event_rx = self.download_event_rx.take();
 
+        // Take the actor listing receiver
+        let mut inspect_rx = self.inspect_rx.take();
+
+        // Learn polling windows before the first actors schedule their checks
+        self.refresh_activity_profiles().await;
+        let mut activity_refresh = tokio::time::interval_at(
+            tokio::time::Instant::now() + ACTIVITY_REFRESH_INTERVAL,
+            ACTIVITY_REFRESH_INTERVAL,
+        );
+        activity_refresh.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
         // Initial actor spawning for all active streamers
         self.spawn_initial_actors().await?;
         self.publish_stats();
@@ -567,6 +719,21 @@ impl<R: StreamerRepository + Send + Sync + 'static> Scheduler<R> {
                     }
                 }
 
+                // Hand out the actor list for diagnostics
+                request = async {
+                    match &mut inspect_rx {
+                        Some(rx) => rx.recv().await,
+                        None => std::future::pending().await,
+                    }
+                } => {
+                    match request {
+                        Some(reply) => {
+                            let _ = reply.send(self.supervisor.actor_entries());
+                        }
+                        None => inspect_rx = None,
+                    }
+                }
+
                 // Handle actor task completions (crash detection)
                 // Only poll join_next if there are pending tasks to avoid busy-looping
                 result = Self::join_next_if_pending(&mut self.supervisor) => {
@@ -584,6 +751,13 @@ impl<R: StreamerRepository + Send + Sync + 'static> Scheduler<R> {
                     // None means no pending tasks - we just continue the loop
                 }
 
+                // Follow new sessions into the activity profiles
+                _ = activity_refresh.tick() => {
+                    if self.refresh_activity_profiles().await {
+                        self.deliver_config(&ConfigScope::Global).await;
+                    }
+                }
+
                 // Process pending restarts
                 _ = Self::wait_for_restart(next_restart) => {
                     let restarted = self.supervisor.process_pending_restarts();
@@ -708,6 +882,8 @@ impl<R: StreamerRepository + Send + Sync + 'static> Scheduler<R> {
 
         // Register platform mapping
         self.platform_mapping.register(&streamer_id, &platform_id);
+        self.platform_mapping
+            .register_group(&streamer_id, metadata.group_id.as_deref());
 
         // Spawn with streamer_id - actor fetches metadata from shared store
         match self
@@ -768,6 +944,16 @@ impl<R: StreamerRepository + Send + Sync + 'static> Scheduler<R> {
                 self.handle_state_sync(streamer_id, *is_active).await;
                 return;
             }
+            ConfigUpdateEvent::StreamerGroupUpdated { group_id } => {
+                // Membership may have changed; route to the current members.
+                let members: Vec<String> = self
+                    .streamer_manager
+                    .get_by_group(group_id)
+                    .into_iter()
+                    .map(|metadata| metadata.id)
+                    .collect();
+                self.platform_mapping.set_group_members(group_id, &members);
+            }
             _ => {}
         }
 
@@ -780,6 +966,11 @@ impl<R: StreamerRepository + Send + Sync + 'static> Scheduler<R> {
             return;
         }
 
+        self.deliver_config(&scope).await;
+    }
+
+    /// Build configs for every actor in `scope` and deliver them.
+    async fn deliver_config(&mut self, scope: &ConfigScope) {
         // Snapshot semantics: compute once, update restart caches, then deliver.
         //
         // Note: we intentionally keep the router borrows scoped to avoid holding an immutable
@@ -792,7 +983,7 @@ impl<R: StreamerRepository + Send + Sync + 'static> Scheduler<R> {
                 &self.platform_mapping,
             );
             router.plan_with_scope(
-                &scope,
+                scope,
                 |id| self.build_streamer_config(id),
                 |id| self.create_platform_config(id),
             )
@@ -846,8 +1037,10 @@ impl<R: StreamerRepository + Send + Sync + 'static> Scheduler<R> {
             check_interval_ms: self.config.check_interval_ms,
             offline_check_interval_ms,
             offline_check_count,
+            offline_grace_ms: self.config.offline_grace_ms,
             priority,
             batch_capable,
+            activity: self.activity_profiles.get(streamer_id).cloned(),
         }
     }
 
@@ -865,6 +1058,8 @@ impl<R: StreamerRepository + Send + Sync + 'static> Scheduler<R> {
         // Keep platform mapping consistent with the latest metadata.
         self.platform_mapping
             .register(streamer_id, &metadata.platform_config_id);
+        self.platform_mapping
+            .register_group(streamer_id, metadata.group_id.as_deref());
 
         // Ensure platform actor exists if the streamer is on a batch-capable platform.
         if self.is_batch_capable_platform(&metadata.platform_config_id)
@@ -1121,6 +1316,13 @@ impl<R: StreamerRepository + Send + Sync + 'static> Scheduler<R> {
                             session_id,
                         }
                     }
+                    crate::downloader::DownloadRejectedKind::SystemOverloaded => {
+                        DownloadEndPolicy::SystemOverloaded {
+                            reason,
+                            retry_after_secs: retry_secs,
+                            session_id,
+                        }
+                    }
                 };
                 send_to_actor(streamer_id, StreamerMessage::DownloadEnded(policy)).await;
             }
@@ -1179,6 +1381,12 @@ impl<R: StreamerRepository + Send + Sync + 'static> Scheduler<R> {
                     .await;
                 }
             }
+            DownloadManagerEvent::Progress(DownloadProgressEvent::DownloadResumeRequested {
+                streamer_id,
+                ..
+            }) => {
+                send_to_actor(streamer_id, StreamerMessage::DownloadResumed).await;
+            }
             _ => {}
         }
     }
@@ -1286,7 +1494,11 @@ mod tests {
         }
 
         let (stats_tx, stats_rx) = watch::channel(stats(1));
-        let handle = SchedulerHandle { stats_rx };
+        let (inspect_tx, _inspect_rx) = mpsc::channel(1);
+        let handle = SchedulerHandle {
+            stats_rx,
+            inspect_tx,
+        };
         assert_eq!(handle.stats().streamer_count, 1);
 
         stats_tx.send_replace(stats(2));
@@ -345,6 +345,21 @@ fn validate_import(
     {
         return validation("streamer and offline check delays must be positive");
     }
+    if !(0..=crate::session::MAX_OFFLINE_GRACE.as_secs() as i64)
+        .contains(&config.global_config.offline_grace_secs)
+    {
+        return validation("offline_grace_secs must be between 0 and 600");
+    }
+    if [
+        config.global_config.load_admission_max_cpu_percent,
+        config.global_config.load_admission_max_memory_percent,
+        config.global_config.load_admission_max_io_pressure_percent,
+    ]
+    .iter()
+    .any(|percent| !(0..=100).contains(percent))
+    {
+        return validation("load admission thresholds must be between 0 and 100");
+    }
 
     validate_pipeline_values(
         "global config",
@@ -405,6 +420,14 @@ fn validate_import(
                 platform.paired_segment_pipeline.as_ref(),
             ],
         )?;
+        if let Some(rps) = platform.rate_limit
+            && (!rps.is_finite() || rps <= 0.0)
+        {
+            return validation(format!(
+                "rate_limit for platform '{}' must be a positive number",
+                platform.platform_name
+            ));
+        }
     }
 
     validate_unique(
@@ -1011,6 +1034,15 @@ fn global_model(existing: &GlobalConfigDbModel, config: &ConfigExport) -> Global
     model.queue_freshness_threshold_ms = source.queue_freshness_threshold_ms;
     model.gpu_health_probe_interval_secs = source.gpu_health_probe_interval_secs;
     model.stream_proxy_allow_private_targets = source.stream_proxy_allow_private_targets;
+    model.low_disk_space_threshold_bytes = source.low_disk_space_threshold_bytes;
+    model.low_disk_space_action = source.low_disk_space_action.clone();
+    model.low_disk_space_secondary_folder = source.low_disk_space_secondary_folder.clone();
+    model.adaptive_polling = source.adaptive_polling;
+    model.offline_grace_secs = source.offline_grace_secs;
+    model.load_admission_max_cpu_percent = source.load_admission_max_cpu_percent;
+    model.load_admission_max_memory_percent = source.load_admission_max_memory_percent;
+    model.load_admission_max_io_pressure_percent = source.load_admission_max_io_pressure_percent;
+    model.danmu_connection_config = source.danmu_connection_config.clone().map(db_json);
     model
 }
 
@@ -1073,6 +1105,7 @@ fn platform_model(
     model.paired_segment_pipeline = source.paired_segment_pipeline.clone().map(db_json);
     model.offline_check_count = source.offline_check_count;
     model.offline_check_delay_ms = source.offline_check_delay_ms;
+    model.rate_limit = source.rate_limit;
     model
 }
 
@@ -1377,7 +1410,12 @@ async fn persist_global(
             paired_segment_pipeline = ?, log_filter_directive = ?, auto_thumbnail = ?,
             pipeline_cpu_job_timeout_secs = ?, pipeline_io_job_timeout_secs = ?,
             pipeline_execute_timeout_secs = ?, queue_freshness_threshold_ms = ?,
-            gpu_health_probe_interval_secs = ?, stream_proxy_allow_private_targets = ?
+            gpu_health_probe_interval_secs = ?, stream_proxy_allow_private_targets = ?,
+            low_disk_space_threshold_bytes = ?, low_disk_space_action = ?,
+            low_disk_space_secondary_folder = ?, adaptive_polling = ?,
+            offline_grace_secs = ?, load_admission_max_cpu_percent = ?,
+            load_admission_max_memory_percent = ?, load_admission_max_io_pressure_percent = ?,
+            danmu_connection_config = ?
         WHERE id = ?
         "#,
     )
@@ -1410,6 +1448,15 @@ async fn persist_global(
     .bind(config.queue_freshness_threshold_ms)
     .bind(config.gpu_health_probe_interval_secs)
     .bind(config.stream_proxy_allow_private_targets)
+    .bind(config.low_disk_space_threshold_bytes)
+    .bind(&config.low_disk_space_action)
+    .bind(&config.low_disk_space_secondary_folder)
+    .bind(config.adaptive_polling)
+    .bind(config.offline_grace_secs)
+    .bind(config.load_admission_max_cpu_percent)
+    .bind(config.load_admission_max_memory_percent)
+    .bind(config.load_admission_max_io_pressure_percent)
+    .bind(&config.danmu_connection_config)
     .bind(&config.id)
     .execute(&mut **tx)
     .await?;
@@ -1523,7 +1570,7 @@ async fn persist_platform(
             output_file_format = ?, min_segment_size_bytes = ?, max_download_duration_secs = ?,
             max_part_size_bytes = ?, download_retry_policy = ?, event_hooks = ?, pipeline = ?,
             session_complete_pipeline = ?, paired_segment_pipeline = ?, offline_check_count = ?,
-            offline_check_delay_ms = ?
+            offline_check_delay_ms = ?, rate_limit = ?
         WHERE id = ?
         "#,
     )
@@ -1549,6 +1596,7 @@ async fn persist_platform(
     .bind(&model.paired_segment_pipeline)
     .bind(model.offline_check_count)
     .bind(model.offline_check_delay_ms)
+    .bind(model.rate_limit)
     .bind(&model.id)
     .execute(&mut **tx)
     .await?;
@@ -1782,6 +1830,16 @@ mod tests {
                 queue_freshness_threshold_ms: global.queue_freshness_threshold_ms,
                 gpu_health_probe_interval_secs: global.gpu_health_probe_interval_secs,
                 stream_proxy_allow_private_targets: global.stream_proxy_allow_private_targets,
+                low_disk_space_threshold_bytes: global.low_disk_space_threshold_bytes,
+                low_disk_space_action: global.low_disk_space_action.clone(),
+                low_disk_space_secondary_folder: global.low_disk_space_secondary_folder.clone(),
+                adaptive_polling: global.adaptive_polling,
+                offline_grace_secs: global.offline_grace_secs,
+                load_admission_max_cpu_percent: global.load_admission_max_cpu_percent,
+                load_admission_max_memory_percent: global.load_admission_max_memory_percent,
+                load_admission_max_io_pressure_percent: global
+                    .load_admission_max_io_pressure_percent,
+                danmu_connection_config: None,
             },
             templates: Vec::new(),
             streamers: Vec::new(),
@@ -1842,6 +1900,7 @@ mod tests {
                 url TEXT NOT NULL UNIQUE,
                 platform_config_id TEXT NOT NULL,
                 template_config_id TEXT,
+                group_id TEXT,
                 state TEXT NOT NULL,
                 priority TEXT NOT NULL DEFAULT 'NORMAL',
                 last_live_time INTEGER,
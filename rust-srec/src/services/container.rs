@@ -3,6 +3,7 @@
 //! The ServiceContainer holds references to all application services
 //! and manages their lifecycle.
 
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
@@ -16,13 +17,15 @@ use crate::api::server::ApiServerConfig;
 use crate::config::{ConfigEventBroadcaster, ConfigService};
 use crate::danmu::{DanmuService, service::DanmuServiceConfig};
 use crate::database::maintenance::MaintenanceScheduler;
+use crate::database::models::GlobalConfigDbModel;
 use crate::database::repositories::NotificationRepository;
 use crate::database::repositories::{
     config::SqlxConfigRepository, filter::SqlxFilterRepository, session::SqlxSessionRepository,
     streamer::SqlxStreamerRepository,
 };
 use crate::downloader::{
-    DownloadManager, DownloadManagerConfig, LAST_ERROR_GATE_PREFIX, OutputRootGate, RecoveryHook,
+    DiskSpacePolicy, DownloadManager, DownloadManagerConfig, LAST_ERROR_GATE_PREFIX,
+    LoadAdmissionPolicy, LowDiskSpaceAction, OutputRootGate, RecoveryHook,
     engine::DownloadProgress,
 };
 use crate::logging::LoggingConfig;
@@ -154,6 +157,51 @@ fn static_root_prefix(template: &str) -> Option<String> {
     }
 }
 
+/// Build the download manager's free-space policy from the global config.
+///
+/// The primary root is the part of the `output_folder` template before its
+/// first placeholder, so directories moved to the secondary folder keep the
+/// layout the template gives them.
+fn disk_space_policy(global: &GlobalConfigDbModel) -> DiskSpacePolicy {
+    let action = global
+        .low_disk_space_action
+        .parse::<LowDiskSpaceAction>()
+        .unwrap_or_else(|e| {
+            warn!("{e}; falling back to stop_new");
+            LowDiskSpaceAction::default()
+        });
+
+    let template = global.output_folder.as_str();
+    let primary_root = match template.find(['{', '%']) {
+        Some(cut) => template[..cut].rfind('/').map(|slash| &template[..=slash]),
+        None => Some(template),
+    }
+    .filter(|root| !root.is_empty())
+    .map(PathBuf::from);
+
+    DiskSpacePolicy {
+        min_free_bytes: global.low_disk_space_threshold_bytes.max(0) as u64,
+        action,
+        primary_root,
+        secondary_folder: global
+            .low_disk_space_secondary_folder
+            .as_deref()
+            .filter(|folder| !folder.is_empty())
+            .map(PathBuf::from),
+    }
+}
+
+/// Build the download manager's load admission policy from the global
+/// config. Out-of-range percentages are clamped.
+fn load_admission_policy(global: &GlobalConfigDbModel) -> LoadAdmissionPolicy {
+    let percent = |value: i64| value.clamp(0, 100) as u8;
+    LoadAdmissionPolicy {
+        max_cpu_percent: percent(global.load_admission_max_cpu_percent),
+        max_memory_percent: percent(global.load_admission_max_memory_percent),
+        max_io_pressure_percent: percent(global.load_admission_max_io_pressure_percent),
+    }
+}
+
 /// Read `RUST_SREC_OUTPUT_ROOTS` from the environment and parse it into a
 /// list of absolute paths. The value is comma-separated; empty entries are
 /// skipped. Relative paths are rejected with a warning (they would anchor
@@ -521,6 +569,17 @@ impl ServiceContainer {
         // wedge startup.
         self.run_output_root_startup_probe().await;
 
+        // Periodic free-space enforcement for active downloads. Idles while
+        // the policy is disabled, so it runs unconditionally.
+        self.start_disk_space_monitor();
+
+        // Load sampling for admission control of new downloads. Idles while
+        // the policy is disabled, so it runs unconditionally.
+        self.start_system_load_sampler();
+
+        // Stops "record now" downloads that were given a maximum duration.
+        self.start_recording_deadline_monitor();
+
         // Start the single database maintenance task. It performs an immediate
         // retention sweep before waiting for its periodic cadence.
         let maintenance_start = Instant::now();
@@ -98,6 +98,18 @@ impl ServiceContainer {
                     self.write_pool.clone(),
                 ),
             ),
+            oauth_token_repository: Arc::new(
+                crate::database::repositories::SqlxOAuthTokenRepository::new(
+                    self.pool.clone(),
+                    self.write_pool.clone(),
+                ),
+            ),
+            streamer_group_repository: Arc::new(
+                crate::database::repositories::SqlxStreamerGroupRepository::new(
+                    self.pool.clone(),
+                    self.write_pool.clone(),
+                ),
+            ),
             check_history_broadcaster: self.check_history_broadcaster.clone(),
             filter_repository: Arc::new(SqlxFilterRepository::new(
                 self.pool.clone(),
@@ -130,6 +142,7 @@ impl ServiceContainer {
                     self.credential_service.clone(),
                 ),
             ),
+            scheduler: self.scheduler_handle.clone(),
         };
 
         let mut state = AppState::new(services);
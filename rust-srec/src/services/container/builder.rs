@@ -15,7 +15,8 @@ use crate::credentials::{
 use crate::danmu::DanmuService;
 use crate::database::maintenance::{MaintenanceConfig, MaintenanceScheduler};
 use crate::database::repositories::{
-    ConfigRepository, SqlxCredentialStore, SqlxNotificationRepository,
+    ConfigRepository, SqlxCredentialStore, SqlxNotificationRepository, SqlxOAuthTokenRepository,
+    SqlxRecordingDeadlineRepository,
     config::SqlxConfigRepository,
     dag::SqlxDagRepository,
     filter::SqlxFilterRepository,
@@ -206,6 +207,9 @@ impl ServiceContainer {
             .with_hysteresis_resolver(hysteresis_resolver)
             .with_event_repo(session_event_repo.clone()),
         );
+        session_lifecycle.set_offline_grace(std::time::Duration::from_secs(
+            global_config.offline_grace_secs.max(0) as u64,
+        ));
 
         // Create stream monitor for real status detection
         let stream_monitor_start = Instant::now();
@@ -242,6 +246,10 @@ impl ServiceContainer {
         }
         let credential_service = Arc::new(credential_service);
         stream_monitor.set_credential_service(Arc::clone(&credential_service));
+        match config_service.list_platform_configs().await {
+            Ok(platforms) => stream_monitor.apply_platform_rate_limits(&platforms).await,
+            Err(e) => warn!(error = %e, "Failed to load platform rate limits; using defaults"),
+        }
         let stream_monitor = Arc::new(stream_monitor);
         let credential_service_ms = credential_service_start.elapsed().as_millis();
 
@@ -255,10 +263,22 @@ impl ServiceContainer {
         let download_manager = Arc::new(
             DownloadManager::with_config(effective_download_config)
                 .with_required_terminal_sender(required_terminal_sender)
-                .with_config_repo(config_repo.clone()),
+                .with_config_repo(config_repo.clone())
+                .with_recording_deadline_repo(Arc::new(SqlxRecordingDeadlineRepository::new(
+                    pool.clone(),
+                    write_pool.clone(),
+                ))),
         );
+        if let Err(e) = download_manager
+            .load_recording_deadlines(chrono::Utc::now())
+            .await
+        {
+            warn!(error = %e, "Failed to load recording deadlines");
+        }
         download_manager
             .set_queue_freshness_threshold_ms(global_config.queue_freshness_threshold_ms);
+        download_manager.set_disk_space_policy(super::disk_space_policy(&global_config));
+        download_manager.set_load_admission_policy(super::load_admission_policy(&global_config));
         let download_manager_ms = download_manager_start.elapsed().as_millis();
 
         // Create job repository for pipeline persistence
@@ -304,6 +324,11 @@ impl ServiceContainer {
                 pipeline_preset_repository: pipeline_preset_repo,
                 config_service: config_service.clone(),
                 dag_repository: Arc::new(SqlxDagRepository::new(pool.clone(), write_pool.clone())),
+                oauth_token_repository: Arc::new(SqlxOAuthTokenRepository::new(
+                    pool.clone(),
+                    write_pool.clone(),
+                )),
+                config_repository: config_repo.clone(),
             },
         ));
         let pipeline_manager_ms = pipeline_manager_start.elapsed().as_millis();
@@ -313,10 +338,22 @@ impl ServiceContainer {
         let monitor_event_broadcaster = stream_monitor.event_broadcaster().clone();
         let monitor_event_broadcaster_ms = monitor_event_broadcaster_start.elapsed().as_millis();
 
-        // Create danmu service with custom config
+        // Create danmu service with custom config, overridden by the persisted
+        // connection policy (startup-only).
         let danmu_service_start = Instant::now();
-        let danmu_service =
-            Arc::new(DanmuService::new(danmu_config).with_session_repository(session_repo.clone()));
+        let mut effective_danmu_config = danmu_config;
+        match config_service.list_platform_configs().await {
+            Ok(platforms) => {
+                effective_danmu_config.apply_connection_config(&global_config, &platforms)
+            }
+            Err(e) => {
+                warn!(error = %e, "Failed to load platform danmu connection config; using global");
+                effective_danmu_config.apply_connection_config(&global_config, &[]);
+            }
+        }
+        let danmu_service = Arc::new(
+            DanmuService::new(effective_danmu_config).with_session_repository(session_repo.clone()),
+        );
         let danmu_service_ms = danmu_service_start.elapsed().as_millis();
 
         // Create notification service with default config
@@ -359,10 +396,11 @@ impl ServiceContainer {
         );
         download_manager.set_output_root_gate(output_root_gate.clone());
 
-        // Create metrics collector. Its only consumer is
-        // `WebPushService::set_metrics_collector` delivery accounting.
+        // Create metrics collector. It feeds `WebPushService` delivery
+        // accounting and the per-operator metrics of recording pipelines.
         let metrics_collector_start = Instant::now();
         let metrics_collector = Arc::new(MetricsCollector::new());
+        download_manager.set_metrics_collector(metrics_collector.clone());
         if let Some(web_push) = web_push_service.as_ref() {
             web_push.set_metrics_collector(metrics_collector);
         }
@@ -387,6 +425,8 @@ impl ServiceContainer {
             check_interval_ms: global_config.streamer_check_delay_ms as u64,
             offline_check_interval_ms: global_config.offline_check_delay_ms as u64,
             offline_check_count: global_config.offline_check_count as u32,
+            adaptive_polling: global_config.adaptive_polling,
+            offline_grace_ms: global_config.offline_grace_secs.max(0) as u64 * 1000,
             supervisor_config: crate::scheduler::actor::SupervisorConfig::default(),
         };
 
@@ -405,7 +445,8 @@ impl ServiceContainer {
             scheduler_config,
             cancellation_token.child_token(),
         )
-        .with_config_repo(config_repo.clone());
+        .with_config_repo(config_repo.clone())
+        .with_session_repo(session_repo.clone());
         let scheduler_handle = scheduler.handle();
         let scheduler = parking_lot::Mutex::new(Some(scheduler));
         let scheduler_ms = scheduler_start.elapsed().as_millis();
@@ -21,6 +21,7 @@ use crate::downloader::{
     DownloadManager, DownloadManagerEvent, DownloadProgressEvent, DownloadTerminalEvent,
 };
 use crate::monitor::StreamMonitor;
+use crate::notification::{NotificationEvent, NotificationService};
 use crate::pipeline::PipelineManager;
 use crate::services::runtime_coordinator::RuntimeCoordinator;
 use crate::session::SessionLifecycle;
@@ -43,6 +44,8 @@ impl ServiceContainer {
             download_manager: self.download_manager.clone(),
             pipeline_manager: self.pipeline_manager.clone(),
             runtime_coordinator: self.runtime_coordinator.clone(),
+            stream_monitor: self.stream_monitor.clone(),
+            session_lifecycle: self.session_lifecycle.clone(),
             gpu_health_monitor: self.gpu_health_monitor.get().cloned(),
         };
         let receiver = self.event_broadcaster.subscribe();
@@ -234,6 +237,7 @@ impl ServiceContainer {
             config_service: self.config_service.clone(),
             stream_monitor: self.stream_monitor.clone(),
             discarded_segment_keys: self.discarded_segment_keys.clone(),
+            notification_service: self.notification_service.clone(),
         };
         let cancellation_token = self.cancellation_token.clone();
 
@@ -270,6 +274,8 @@ struct ConfigEventHandler {
     download_manager: Arc<DownloadManager>,
     pipeline_manager: Arc<PipelineManager>,
     runtime_coordinator: Arc<RuntimeCoordinator>,
+    stream_monitor: Arc<RuntimeStreamMonitor>,
+    session_lifecycle: Arc<SessionLifecycle>,
     gpu_health_monitor: Option<Arc<crate::metrics::GpuHealthMonitor>>,
 }
 
@@ -355,6 +361,17 @@ impl ConfigEventHandler {
                     }
                 }
             }
+            ConfigUpdateEvent::StreamerGroupUpdated { group_id } => {
+                debug!("Received streamer group update event: {}", group_id);
+                // Group operations change membership and templates, never
+                // the streamer state, so no cleanup is needed here.
+                for metadata in self.streamer_manager.get_by_group(&group_id) {
+                    self.config_service.invalidate_streamer(&metadata.id);
+                    self.runtime_coordinator
+                        .refresh_metadata_offline_check(&metadata.id)
+                        .await;
+                }
+            }
             ConfigUpdateEvent::PlatformUpdated { platform_id } => {
                 debug!("Received platform config update event: {}", platform_id);
                 // Refresh effective offline_check_* on every streamer
@@ -372,6 +389,18 @@ impl ConfigEventHandler {
                         .refresh_metadata_offline_check(&id)
                         .await;
                 }
+
+                match self.config_service.get_platform_config(&platform_id).await {
+                    Ok(platform) => {
+                        self.stream_monitor
+                            .apply_platform_rate_limits(std::slice::from_ref(&platform))
+                            .await
+                    }
+                    Err(e) => warn!(
+                        "Failed to reload platform {} for its rate limit: {}",
+                        platform_id, e
+                    ),
+                }
             }
             ConfigUpdateEvent::TemplateUpdated { template_id } => {
                 debug!("Received template config update event: {}", template_id);
@@ -406,6 +435,17 @@ impl ConfigEventHandler {
                         .await;
                 }
 
+                // Also stands in for platform updates missed by a lagged
+                // receiver; unchanged rates leave the limiters alone.
+                match self.config_service.list_platform_configs().await {
+                    Ok(platforms) => {
+                        self.stream_monitor
+                            .apply_platform_rate_limits(&platforms)
+                            .await
+                    }
+                    Err(e) => warn!("Failed to reload platform rate limits: {}", e),
+                }
+
                 match self.config_service.get_global_config().await {
                     Ok(global) => {
                         let new_limit = (global.max_concurrent_downloads as i64).max(1) as usize;
@@ -433,6 +473,37 @@ impl ConfigEventHandler {
                             );
                         }
 
+                        // The scheduler reloads its own copy for the actors;
+                        // the lifecycle's backstop window has to follow it.
+                        self.session_lifecycle
+                            .set_offline_grace(std::time::Duration::from_secs(
+                                global.offline_grace_secs.max(0) as u64,
+                            ));
+
+                        let disk_space_policy = super::disk_space_policy(&global);
+                        if disk_space_policy != self.download_manager.disk_space_policy() {
+                            info!(
+                                min_free_bytes = disk_space_policy.min_free_bytes,
+                                action = disk_space_policy.action.as_str(),
+                                "Updated low disk space policy"
+                            );
+                            self.download_manager
+                                .set_disk_space_policy(disk_space_policy);
+                        }
+
+                        let load_admission_policy = super::load_admission_policy(&global);
+                        if load_admission_policy != self.download_manager.load_admission_policy() {
+                            info!(
+                                max_cpu_percent = load_admission_policy.max_cpu_percent,
+                                max_memory_percent = load_admission_policy.max_memory_percent,
+                                max_io_pressure_percent =
+                                    load_admission_policy.max_io_pressure_percent,
+                                "Updated load admission policy"
+                            );
+                            self.download_manager
+                                .set_load_admission_policy(load_admission_policy);
+                        }
+
                         // Hot-reload the GPU health probe cadence.
                         // No-op when the monitor wasn't registered (no
                         // GPU). `.max(0)` guards against a negative i64
@@ -796,8 +867,15 @@ struct DanmuEventHandler {
     config_service: Arc<RuntimeConfigService>,
     stream_monitor: Arc<RuntimeStreamMonitor>,
     discarded_segment_keys: Arc<DashMap<(String, String), Instant>>,
+    notification_service: Arc<NotificationService>,
 }
 
+/// Reconnect attempt at which danmu collection is reported as degraded.
+///
+/// Single dropped connections recover on the first or second attempt and are
+/// not worth a notification; only a sustained outage is.
+const DANMU_DEGRADED_RECONNECT_ATTEMPT: u32 = 3;
+
 impl DanmuEventHandler {
     async fn run(
         self,
@@ -926,18 +1004,44 @@ impl DanmuEventHandler {
             }
             DanmuEvent::Reconnecting {
                 session_id,
+                streamer_id,
+                platform,
                 attempt,
             } => {
                 warn!(
                     "Danmu reconnecting for session {}: attempt {}",
                     session_id, attempt
                 );
+                if *attempt == DANMU_DEGRADED_RECONNECT_ATTEMPT {
+                    self.notify_degraded(
+                        session_id,
+                        streamer_id,
+                        platform,
+                        format!("reconnect attempt {attempt}"),
+                    );
+                }
             }
-            DanmuEvent::ReconnectFailed { session_id, error } => {
+            DanmuEvent::Reconnected {
+                session_id,
+                attempts,
+                ..
+            } => {
+                info!(
+                    "Danmu reconnected for session {} after {} attempt(s)",
+                    session_id, attempts
+                );
+            }
+            DanmuEvent::ReconnectFailed {
+                session_id,
+                streamer_id,
+                platform,
+                error,
+            } => {
                 warn!(
                     "Danmu reconnect failed for session {}: {}",
                     session_id, error
                 );
+                self.notify_degraded(session_id, streamer_id, platform, error.clone());
             }
             DanmuEvent::Error { session_id, error } => {
                 warn!("Danmu error for session {}: {}", session_id, error);
@@ -945,6 +1049,24 @@ impl DanmuEventHandler {
         }
     }
 
+    fn notify_degraded(&self, session_id: &str, streamer_id: &str, platform: &str, reason: String) {
+        let streamer_name = self
+            .streamer_manager
+            .get_streamer(streamer_id)
+            .map(|metadata| metadata.name.clone())
+            .unwrap_or_else(|| streamer_id.to_string());
+        self.notification_service.dispatch_notification(
+            NotificationEvent::DanmuCollectionDegraded {
+                streamer_id: streamer_id.to_string(),
+                streamer_name,
+                session_id: session_id.to_string(),
+                platform: platform.to_string(),
+                reason,
+                timestamp: chrono::Utc::now(),
+            },
+        );
+    }
+
     /// Treat a danmu stream-closed control event as authoritative
     /// end-of-stream (unless the platform config opts out via
     /// `should_end_stream_on_danmu_stream_closed`):
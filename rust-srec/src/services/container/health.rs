@@ -1,4 +1,6 @@
 use std::borrow::Cow;
+use std::collections::HashSet;
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -7,8 +9,11 @@ use sqlx::SqlitePool;
 use tokio_util::sync::CancellationToken;
 use tracing::{debug, info, warn};
 
-use crate::downloader::{DownloadManager, OutputRootGate};
+use crate::downloader::disk_space::DISK_SPACE_CHECK_INTERVAL;
+use crate::downloader::load_admission::LOAD_SAMPLE_INTERVAL;
+use crate::downloader::{DownloadManager, OutputRootGate, RECORDING_DEADLINE_CHECK_INTERVAL};
 use crate::metrics::{ComponentHealth, HealthChecker, HealthProbe, SystemMetricsSnapshot};
+use crate::notification::NotificationEvent;
 use crate::pipeline::PipelineManager;
 
 use super::{
@@ -528,6 +533,112 @@ impl ServiceContainer {
         info!("Output-root startup probe complete");
     }
 
+    /// Spawn the loop that applies the free-space policy to active
+    /// downloads every [`DISK_SPACE_CHECK_INTERVAL`].
+    ///
+    /// Sends one `OutOfSpace` notification each time a volume falls below
+    /// the threshold; it is sent again only after the volume recovered.
+    pub(super) fn start_disk_space_monitor(&self) {
+        let download_manager = self.download_manager.clone();
+        let notification_service = self.notification_service.clone();
+        let cancellation_token = self.cancellation_token.child_token();
+
+        self.task_supervisor
+            .spawn("disk space monitor", async move {
+                let mut low_volumes: HashSet<PathBuf> = HashSet::new();
+                let mut interval = tokio::time::interval(DISK_SPACE_CHECK_INTERVAL);
+                interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+                loop {
+                    tokio::select! {
+                        _ = cancellation_token.cancelled() => break,
+                        _ = interval.tick() => {}
+                    }
+
+                    let threshold_bytes = download_manager.disk_space_policy().min_free_bytes;
+                    let volumes = download_manager.enforce_disk_space_policy().await;
+                    for volume in &volumes {
+                        if low_volumes.contains(&volume.mount_point) {
+                            continue;
+                        }
+                        warn!(
+                            mount_point = %volume.mount_point.display(),
+                            available_bytes = volume.available_bytes,
+                            threshold_bytes,
+                            "Output volume fell below the free-space threshold"
+                        );
+                        notification_service.dispatch_notification(NotificationEvent::OutOfSpace {
+                            path: volume.mount_point.display().to_string(),
+                            available_bytes: volume.available_bytes,
+                            threshold_bytes,
+                            timestamp: chrono::Utc::now(),
+                        });
+                    }
+                    low_volumes = volumes
+                        .into_iter()
+                        .map(|volume| volume.mount_point)
+                        .collect();
+                }
+            });
+    }
+
+    /// Spawn the loop that samples host load for the load admission policy
+    /// every [`LOAD_SAMPLE_INTERVAL`].
+    ///
+    /// Sampling is skipped while the policy is disabled. Preflight reads the
+    /// latest sample, so the first one after enabling the policy only
+    /// establishes the CPU baseline.
+    pub(super) fn start_system_load_sampler(&self) {
+        let download_manager = self.download_manager.clone();
+        let cancellation_token = self.cancellation_token.child_token();
+
+        self.task_supervisor
+            .spawn("system load sampler", async move {
+                let mut interval = tokio::time::interval(LOAD_SAMPLE_INTERVAL);
+                interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+                loop {
+                    tokio::select! {
+                        _ = cancellation_token.cancelled() => break,
+                        _ = interval.tick() => {}
+                    }
+                    if !download_manager.load_admission_policy().is_enabled() {
+                        continue;
+                    }
+                    let load = download_manager.sample_system_load();
+                    debug!(
+                        cpu_percent = load.cpu_percent,
+                        memory_percent = load.memory_percent,
+                        io_pressure_percent = ?load.io_pressure_percent,
+                        "Sampled system load"
+                    );
+                }
+            });
+    }
+
+    /// Periodically stop manually started recordings that reached their
+    /// requested duration.
+    pub(super) fn start_recording_deadline_monitor(&self) {
+        let download_manager = self.download_manager.clone();
+        let cancellation_token = self.cancellation_token.child_token();
+
+        self.task_supervisor
+            .spawn("recording deadline monitor", async move {
+                let mut interval = tokio::time::interval(RECORDING_DEADLINE_CHECK_INTERVAL);
+                interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+                loop {
+                    tokio::select! {
+                        _ = cancellation_token.cancelled() => break,
+                        _ = interval.tick() => {}
+                    }
+                    download_manager
+                        .enforce_recording_deadlines(chrono::Utc::now())
+                        .await;
+                }
+            });
+    }
+
     /// Detect the host GPU and install the [`crate::metrics::GpuHealthMonitor`] on the
     /// container if `nvidia-smi` is available. Called from
     /// [`Self::initialize`] **before** subscription wiring so the
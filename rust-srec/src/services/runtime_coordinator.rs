@@ -22,8 +22,10 @@ use crate::utils::task_supervisor::TaskSupervisor;
 use super::session_cancels::SessionCancelTokens;
 
 mod download_pipeline;
+mod vod_backfill;
 
 use download_pipeline::{StreamerLivePayload, run_live_download_pipeline};
+use vod_backfill::{BackfillRequest, run_vod_backfill};
 
 type RuntimeConfigService = ConfigService<SqlxConfigRepository, SqlxStreamerRepository>;
 type RuntimeStreamMonitor = StreamMonitor<
@@ -196,6 +198,7 @@ impl RuntimeCoordinator {
                 streamer_url,
                 media_headers,
                 media_extras,
+                broadcast_started_at,
                 ..
             } => {
                 info!(
@@ -208,6 +211,21 @@ impl RuntimeCoordinator {
                     "Streamer went live"
                 );
 
+                if !from_hysteresis_resume && let Some(broadcast_started_at) = broadcast_started_at
+                {
+                    let coordinator = self.clone();
+                    let request = BackfillRequest {
+                        streamer_id: streamer_id.clone(),
+                        streamer_name: streamer_name.clone(),
+                        session_id: session_id.clone(),
+                        title: title.clone(),
+                        broadcast_started_at,
+                    };
+                    self.task_supervisor.spawn("vod backfill", async move {
+                        run_vod_backfill(coordinator, request).await;
+                    });
+                }
+
                 let coordinator = self.clone();
                 self.task_supervisor
                     .spawn("live download pipeline", async move {
@@ -323,6 +341,9 @@ impl RuntimeCoordinator {
         if let SessionTransition::Ended { session_id, .. } = &transition {
             self.download_manager
                 .clear_session_segment_index(session_id);
+            self.download_manager
+                .clear_session_engine_failures(session_id);
+            self.download_manager.clear_session_pause(session_id);
         }
 
         if let SessionTransition::Ended {
@@ -370,6 +391,7 @@ impl RuntimeCoordinator {
                         streams: payload.streams.clone(),
                         media_headers: payload.media_headers.clone(),
                         media_extras: payload.media_extras.clone(),
+                        broadcast_started_at: None,
                         timestamp: started_at.to_owned(),
                     },
                     true,
@@ -7,7 +7,7 @@ use pipeline_common::expand_path_template;
 use tracing::{debug, info, warn};
 
 use crate::database::repositories::SessionRepository;
-use crate::domain::{Priority, StreamerState};
+use crate::domain::StreamerState;
 use crate::downloader::{DownloadConfig, DownloadProtocol};
 use crate::utils::filename::sanitize_filename;
 
@@ -28,6 +28,73 @@ pub(super) struct StreamerLivePayload {
     pub(super) media_extras: Option<std::collections::HashMap<String, String>>,
 }
 
+/// Placeholder values of a session for the `output_folder` and
+/// `output_filename_template` settings.
+pub(super) struct SessionOutputPaths<'a> {
+    streamer: String,
+    title: String,
+    session_id: &'a str,
+    platform: &'a str,
+}
+
+impl<'a> SessionOutputPaths<'a> {
+    pub(super) fn new(
+        streamer_name: &str,
+        title: &str,
+        session_id: &'a str,
+        platform: &'a str,
+    ) -> Self {
+        Self {
+            streamer: sanitize_filename(streamer_name),
+            title: sanitize_filename(title),
+            session_id,
+            platform,
+        }
+    }
+
+    /// Fill in `{streamer}`, `{title}`, `{session_id}` and `{platform}` and
+    /// expand the date/time placeholders of an output folder template.
+    pub(super) fn output_dir(&self, template: &str) -> String {
+        expand_path_template(
+            &template
+                .replace("{streamer}", &self.streamer)
+                .replace("{title}", &self.title)
+                .replace("{session_id}", self.session_id)
+                .replace("{platform}", self.platform),
+        )
+    }
+
+    /// Fill in `{streamer}`, `{title}` and `{platform}` of a filename
+    /// template. The remaining placeholders are expanded per segment.
+    pub(super) fn filename_template(&self, template: &str) -> String {
+        template
+            .replace("{streamer}", &self.streamer)
+            .replace("{title}", &self.title)
+            .replace("{platform}", self.platform)
+    }
+}
+
+/// Add the headers a stream carries in its extras (`headers` and
+/// `host_header`) on top of the media-level ones.
+pub(super) fn merge_stream_headers(
+    headers: &mut std::collections::HashMap<String, String>,
+    stream: &crate::monitor::StreamInfo,
+) {
+    let Some(extras) = stream.extras.as_ref() else {
+        return;
+    };
+    if let Some(extra_headers) = extras.get("headers").and_then(|v| v.as_object()) {
+        for (k, v) in extra_headers {
+            if let Some(v) = v.as_str() {
+                headers.insert(k.clone(), v.to_string());
+            }
+        }
+    }
+    if let Some(host_header) = extras.get("host_header").and_then(|v| v.as_str()) {
+        headers.insert("Host".to_string(), host_header.to_string());
+    }
+}
+
 /// Removes the per-streamer reservation from
 /// `RuntimeCoordinator::pending_pipelines` when the pipeline exits,
 /// on every path (early return, panic, completion).
@@ -48,7 +115,7 @@ impl Drop for PipelineReservationGuard<'_> {
 /// `RuntimeCoordinator::handle_monitor_event` per `StreamerLive` event.
 /// Walks the split startup flow:
 ///
-/// 1. **Dedup / pre-checks** — bail if the streamer is already
+/// 1. **Dedup / pre-checks** — bail if the streamer is paused, already
 ///    downloading, no longer active, disabled, or has no streams.
 /// 2. **Preflight** — engine resolution, circuit breaker, output-root
 ///    write gate, `prepare_output_dir`. Failures emit
@@ -135,6 +202,13 @@ pub(super) async fn run_live_download_pipeline(
     let cancel = cancel_handle.token();
 
     // Dedup and pre-checks.
+    if download_manager.is_paused(&streamer_id) {
+        debug!(
+            "Download paused for {}; not starting a new one",
+            streamer_id
+        );
+        return;
+    }
     if download_manager.has_active_download(&streamer_id) {
         debug!("Download already active for {}", streamer_id);
         let active = download_manager.get_active_downloads();
@@ -201,9 +275,9 @@ pub(super) async fn run_live_download_pipeline(
         return;
     }
 
-    let is_high_priority = streamer_metadata
+    let priority = streamer_metadata
         .as_ref()
-        .is_some_and(|s| s.priority == Priority::High);
+        .map_or(QueuePriority::Normal, |s| QueuePriority::from(s.priority));
     // Load merged config for this streamer.
     let merged_config = match config_service.get_config_for_streamer(&streamer_id).await {
         Ok(config) => config,
@@ -216,20 +290,11 @@ pub(super) async fn run_live_download_pipeline(
         }
     };
 
-    // Sanitize names for filename usage.
-    let sanitized_streamer = sanitize_filename(&streamer_name);
-    let sanitized_title = sanitize_filename(&title);
     let platform = streamer_metadata
         .as_ref()
         .map_or("unknown", |s| s.platform());
-
-    let dir = merged_config
-        .output_folder
-        .replace("{streamer}", &sanitized_streamer)
-        .replace("{title}", &sanitized_title)
-        .replace("{session_id}", &session_id)
-        .replace("{platform}", platform);
-    let output_dir = expand_path_template(&dir);
+    let output_paths = SessionOutputPaths::new(&streamer_name, &title, &session_id, platform);
+    let output_dir = output_paths.output_dir(&merged_config.output_folder);
 
     // Preflight.
     let preflight_req = PreflightRequest {
@@ -238,6 +303,7 @@ pub(super) async fn run_live_download_pipeline(
         session_id: session_id.clone(),
         output_dir: output_dir.clone().into(),
         engine_id: Some(merged_config.download_engine.clone()),
+        fallback_engine_ids: merged_config.download_engine_fallbacks.clone(),
         engines_override: merged_config.engines_override.clone(),
     };
     let engine = match download_manager.preflight(preflight_req).await {
@@ -248,6 +314,7 @@ pub(super) async fn run_live_download_pipeline(
         }
     };
     let engine_type = engine.engine_type;
+    let engine_id = engine.engine_id.clone();
 
     // Honour cancellation that fired between preflight and slot acquire.
     if cancel.is_cancelled() {
@@ -261,11 +328,7 @@ pub(super) async fn run_live_download_pipeline(
         streamer_id: streamer_id.clone(),
         streamer_name: streamer_name.clone(),
         engine_type,
-        priority: if is_high_priority {
-            QueuePriority::High
-        } else {
-            QueuePriority::Normal
-        },
+        priority,
     };
     let slot = match download_manager
         .acquire_slot(acquire_req, cancel.clone())
@@ -414,18 +477,7 @@ pub(super) async fn run_live_download_pipeline(
 
     // Last read of `media_headers`; move the map out rather than clone it.
     let mut headers = media_headers.unwrap_or_default();
-    if let Some(extras) = best_stream.extras.as_ref() {
-        if let Some(extra_headers) = extras.get("headers").and_then(|v| v.as_object()) {
-            for (k, v) in extra_headers {
-                if let Some(v) = v.as_str() {
-                    headers.insert(k.clone(), v.to_string());
-                }
-            }
-        }
-        if let Some(host_header) = extras.get("host_header").and_then(|v| v.as_str()) {
-            headers.insert("Host".to_string(), host_header.to_string());
-        }
-    }
+    merge_stream_headers(&mut headers, best_stream);
     if !headers.is_empty() {
         debug!(
             "Using {} merged headers for download: {:?}",
@@ -442,18 +494,21 @@ pub(super) async fn run_live_download_pipeline(
         session_id.clone(),
     )
     .with_initial_segment_index(initial_segment_index)
-    .with_filename_template(
-        merged_config
-            .output_filename_template
-            .replace("{streamer}", &sanitized_streamer)
-            .replace("{title}", &sanitized_title)
-            .replace("{platform}", platform),
-    )
+    .with_platform(platform)
+    .with_record_danmu(merged_config.record_danmu)
+    .with_filename_template(output_paths.filename_template(&merged_config.output_filename_template))
     .with_output_format(&merged_config.output_file_format)
     .with_protocol(DownloadProtocol::from_format_label(stream_format))
     .with_max_segment_duration(merged_config.max_download_duration_secs as u64)
     .with_max_segment_size(merged_config.max_part_size_bytes as u64)
-    .with_engines_override(merged_config.engines_override.clone());
+    .with_wall_clock_split(merged_config.wall_clock_split_secs)
+    .with_engines_override(merged_config.engines_override.clone())
+    .with_fallback_urls(
+        streams[1..]
+            .iter()
+            .map(|stream| stream.url.clone())
+            .collect(),
+    );
 
     if let Some(ref cookies) = merged_config.cookies {
         debug!(
@@ -502,8 +557,19 @@ pub(super) async fn run_live_download_pipeline(
                 "Started download {} for streamer {} (priority: {})",
                 download_id,
                 streamer_id,
-                if is_high_priority { "high" } else { "normal" }
+                priority.as_str()
             );
+            if let Err(e) = session_repository
+                .set_session_download_engine(&session_id, &engine_id)
+                .await
+            {
+                warn!(
+                    session_id = %session_id,
+                    engine_id = %engine_id,
+                    error = %e,
+                    "Failed to record the download engine of the session"
+                );
+            }
             true
         }
         Err(e) => {
@@ -550,3 +616,22 @@ pub(super) async fn run_live_download_pipeline(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn session_output_paths_fill_session_placeholders() {
+        let paths = SessionOutputPaths::new("a/b", "Live: now", "session-1", "twitch");
+
+        assert_eq!(
+            paths.output_dir("/rec/{platform}/{streamer}/{session_id}"),
+            "/rec/twitch/a_b/session-1"
+        );
+        assert_eq!(
+            paths.filename_template("{streamer}-{title}-{platform}-%i"),
+            "a_b-Live_ now-twitch-%i"
+        );
+    }
+}
@@ -0,0 +1,260 @@
+//! Backfill of the part of a broadcast that went by before it was detected.
+//!
+//! When a streamer with `vod_backfill` enabled is found live well after the
+//! platform says the broadcast started, the missed span is recorded from the
+//! replay the platform publishes while the stream runs. It is stored as a
+//! separate, already ended session of kind `backfill` covering the broadcast
+//! start up to the start of the live session, and goes through the segment
+//! and session-complete pipelines like any other session.
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use tracing::{debug, info, warn};
+
+use crate::database::models::{LiveSessionDbModel, SESSION_KIND_BACKFILL, TitleEntry};
+use crate::database::repositories::SessionRepository;
+use crate::downloader::{
+    DownloadConfig, DownloadManagerEvent, DownloadProgressEvent, DownloadProtocol, SegmentInfo,
+};
+use crate::session::{SessionTransition, TerminalCause};
+
+use super::RuntimeCoordinator;
+use super::download_pipeline::{SessionOutputPaths, merge_stream_headers};
+
+/// Missed spans shorter than this are not worth a backfill; they are
+/// covered by the usual detection delay.
+const MIN_BACKFILL_GAP: chrono::Duration = chrono::Duration::minutes(2);
+
+/// Sessions looked at when checking whether the broadcast was already
+/// (partly) recorded.
+const RECENT_SESSIONS_LIMIT: i32 = 5;
+
+/// The live session a backfill is requested for.
+pub(super) struct BackfillRequest {
+    pub(super) streamer_id: String,
+    pub(super) streamer_name: String,
+    pub(super) session_id: String,
+    pub(super) title: String,
+    pub(super) broadcast_started_at: DateTime<Utc>,
+}
+
+/// Record the start of the broadcast of `request` from its replay, when the
+/// streamer asks for it and the broadcast was detected late.
+pub(super) async fn run_vod_backfill(
+    coordinator: Arc<RuntimeCoordinator>,
+    request: BackfillRequest,
+) {
+    let RuntimeCoordinator {
+        download_manager,
+        streamer_manager,
+        config_service,
+        stream_monitor,
+        session_repository,
+        pipeline_manager,
+        ..
+    } = &*coordinator;
+
+    let BackfillRequest {
+        streamer_id,
+        streamer_name,
+        session_id,
+        title,
+        broadcast_started_at,
+    } = request;
+
+    let merged_config = match config_service.get_config_for_streamer(&streamer_id).await {
+        Ok(config) => config,
+        Err(e) => {
+            warn!(streamer_id = %streamer_id, error = %e, "Failed to load config for backfill");
+            return;
+        }
+    };
+    if !merged_config.vod_backfill {
+        return;
+    }
+
+    // The span to backfill ends where the live session starts. A session
+    // resumed from an earlier detection starts before the broadcast
+    // reported now, and any earlier session overlapping the broadcast
+    // already covers its start.
+    let sessions = match session_repository
+        .list_sessions_for_streamer(&streamer_id, RECENT_SESSIONS_LIMIT)
+        .await
+    {
+        Ok(sessions) => sessions,
+        Err(e) => {
+            warn!(streamer_id = %streamer_id, error = %e, "Failed to load sessions for backfill");
+            return;
+        }
+    };
+    let Some(live_session) = sessions.iter().find(|s| s.id == session_id) else {
+        debug!(session_id = %session_id, "Live session not found; skipping backfill");
+        return;
+    };
+    let broadcast_start_ms = broadcast_started_at.timestamp_millis();
+    let already_covered = sessions.iter().any(|s| {
+        s.id != session_id
+            && s.start_time < live_session.start_time
+            && s.end_time.is_none_or(|end| end > broadcast_start_ms)
+    });
+    if already_covered {
+        debug!(
+            streamer_id = %streamer_id,
+            "Broadcast start already recorded by an earlier session; skipping backfill"
+        );
+        return;
+    }
+    let gap_ms = live_session.start_time - broadcast_start_ms;
+    if gap_ms < MIN_BACKFILL_GAP.num_milliseconds() {
+        return;
+    }
+    let detected_at_ms = live_session.start_time;
+
+    let Some(metadata) = streamer_manager.get_streamer(&streamer_id) else {
+        return;
+    };
+    let media_info = match stream_monitor.extract_replay(&metadata).await {
+        Ok(Some(media_info)) => media_info,
+        Ok(None) => {
+            info!(
+                streamer_id = %streamer_id,
+                "No replay published for the broadcast; skipping backfill"
+            );
+            return;
+        }
+        Err(e) => {
+            warn!(streamer_id = %streamer_id, error = %e, "Failed to extract replay for backfill");
+            return;
+        }
+    };
+    let Some(stream) = media_info.streams.first() else {
+        warn!(streamer_id = %streamer_id, "Replay has no streams; skipping backfill");
+        return;
+    };
+
+    let titles = vec![TitleEntry {
+        ts: broadcast_start_ms,
+        title: title.clone(),
+    }];
+    let mut session = LiveSessionDbModel::new(&streamer_id);
+    session.start_time = broadcast_start_ms;
+    session.end_time = Some(detected_at_ms);
+    session.titles = serde_json::to_string(&titles).ok();
+    session.kind = SESSION_KIND_BACKFILL.to_string();
+    if let Err(e) = session_repository.create_session(&session).await {
+        warn!(streamer_id = %streamer_id, error = %e, "Failed to create backfill session");
+        return;
+    }
+    let backfill_session_id = session.id;
+
+    let platform = metadata.platform();
+    let output_paths =
+        SessionOutputPaths::new(&streamer_name, &title, &backfill_session_id, platform);
+
+    let mut headers: std::collections::HashMap<String, String> = media_info
+        .headers
+        .clone()
+        .unwrap_or_default()
+        .into_iter()
+        .collect();
+    merge_stream_headers(&mut headers, stream);
+
+    let mut config = DownloadConfig::new(
+        stream.url.clone(),
+        output_paths.output_dir(&merged_config.output_folder),
+        streamer_id.clone(),
+        streamer_name.clone(),
+        backfill_session_id.clone(),
+    )
+    .with_platform(platform)
+    .with_filename_template(output_paths.filename_template(&merged_config.output_filename_template))
+    .with_output_format(&merged_config.output_file_format)
+    .with_protocol(DownloadProtocol::from_format_label(
+        stream.stream_format.as_str(),
+    ))
+    .with_max_segment_duration((gap_ms / 1000) as u64)
+    .with_engines_override(merged_config.engines_override.clone());
+    if let Some(ref cookies) = merged_config.cookies {
+        config = config.with_cookies(cookies);
+    }
+    let proxy_config = &merged_config.proxy_config;
+    if proxy_config.enabled {
+        if let Some(effective_proxy_url) = proxy_config.effective_url() {
+            config = config.with_proxy(effective_proxy_url);
+        } else if proxy_config.use_system_proxy {
+            config = config.with_system_proxy(true);
+        }
+    }
+    for (key, value) in headers {
+        config = config.with_header(key, value);
+    }
+
+    info!(
+        streamer_id = %streamer_id,
+        session_id = %backfill_session_id,
+        missed_secs = gap_ms / 1000,
+        "Backfilling broadcast start from the replay"
+    );
+
+    let segment = match download_manager.record_replay_segment(config).await {
+        Ok(Some(segment)) => segment,
+        Ok(None) => {
+            warn!(session_id = %backfill_session_id, "Replay produced no segment; dropping backfill");
+            drop_backfill_session(session_repository.as_ref(), &backfill_session_id).await;
+            return;
+        }
+        Err(e) => {
+            warn!(session_id = %backfill_session_id, error = %e, "Backfill recording failed");
+            drop_backfill_session(session_repository.as_ref(), &backfill_session_id).await;
+            return;
+        }
+    };
+
+    let SegmentInfo {
+        path,
+        duration_secs,
+        size_bytes,
+        started_at,
+        completed_at,
+        split_reason_code,
+        split_reason_details_json,
+        analysis_report_json,
+        ..
+    } = segment;
+    pipeline_manager
+        .handle_download_event(DownloadManagerEvent::Progress(
+            DownloadProgressEvent::SegmentCompleted {
+                download_id: backfill_session_id.clone(),
+                streamer_id: streamer_id.clone(),
+                streamer_name: streamer_name.clone(),
+                session_id: backfill_session_id.clone(),
+                segment_path: path.to_string_lossy().into_owned(),
+                segment_index: 0,
+                started_at,
+                completed_at,
+                duration_secs,
+                size_bytes,
+                split_reason_code,
+                split_reason_details_json,
+                analysis_report_json,
+            },
+        ))
+        .await;
+    pipeline_manager
+        .handle_session_transition(SessionTransition::Ended {
+            session_id: backfill_session_id,
+            streamer_id,
+            streamer_name,
+            ended_at: DateTime::from_timestamp_millis(detected_at_ms).unwrap_or_else(Utc::now),
+            cause: TerminalCause::Completed,
+            via_hysteresis: false,
+        })
+        .await;
+}
+
+async fn drop_backfill_session(session_repository: &dyn SessionRepository, session_id: &str) {
+    if let Err(e) = session_repository.delete_session(session_id).await {
+        warn!(session_id = %session_id, error = %e, "Failed to delete empty backfill session");
+    }
+}
@@ -49,7 +49,7 @@ pub use classifier::{EngineKind, OfflineClassifier};
 pub use download_start::DownloadStartPayload;
 pub use events::{SessionEvent, SessionEventKind, SessionEventPayload, TerminalCauseDto};
 pub use hysteresis::{
-    HysteresisConfig, HysteresisHandle, HysteresisOutcome, MAX_HYSTERESIS_WINDOW,
+    HysteresisConfig, HysteresisHandle, HysteresisOutcome, MAX_HYSTERESIS_WINDOW, MAX_OFFLINE_GRACE,
 };
 pub use lifecycle::{
     DEFAULT_TRANSITION_CHANNEL_CAPACITY, HysteresisWindowFn, LiveDetectedArgs, OfflineDetectedArgs,
@@ -239,6 +239,7 @@ pub enum EngineKind {
     MesioUnknown,
     Ffmpeg,
     Streamlink,
+    YtDlp,
 }
 
 impl EngineKind {
@@ -250,6 +251,7 @@ impl EngineKind {
         match engine_type {
             EngineType::Ffmpeg => Self::Ffmpeg,
             EngineType::Streamlink => Self::Streamlink,
+            EngineType::YtDlp => Self::YtDlp,
             EngineType::Mesio => match protocol {
                 DownloadProtocol::Hls => Self::MesioHls,
                 DownloadProtocol::Flv => Self::MesioFlv,
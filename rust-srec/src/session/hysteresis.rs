@@ -34,8 +34,10 @@
 //! `(count + 1) × interval` keeps it strictly larger than the actor's
 //! confirmation latency, so under normal flow the actor's call always wins.
 //!
-//! No new tunable. The window tracks whatever the operator already set on
-//! `global_config.offline_check_*`.
+//! The window tracks whatever the operator already set on
+//! `global_config.offline_check_*`. When `global_config.offline_grace_secs`
+//! makes the actor wait longer than that before confirming offline, the
+//! window stretches to `grace + interval` so the backstop still fires last.
 //!
 //! This module defines the data types only. The driver lives in
 //! [`crate::session::lifecycle::SessionLifecycle`] (it owns the
@@ -54,6 +56,10 @@ use crate::session::state::TerminalCause;
 /// would otherwise park sessions for unreasonable durations.
 pub const MAX_HYSTERESIS_WINDOW: Duration = Duration::from_secs(5 * 60);
 
+/// Upper bound on `global_config.offline_grace_secs`. The API clamps to it
+/// and config import rejects anything larger.
+pub const MAX_OFFLINE_GRACE: Duration = Duration::from_secs(10 * 60);
+
 /// Default scheduler-derived window: `(3 + 1) × 20 s = 80 s`. Chosen to
 /// match the hard-coded defaults in
 /// [`crate::scheduler::SchedulerConfig::default`] so a freshly-installed
@@ -72,6 +78,9 @@ const DEFAULT_OFFLINE_CHECK_INTERVAL_MS: u64 = 20_000;
 #[derive(Debug, Clone, Copy)]
 pub struct HysteresisConfig {
     window: Duration,
+    /// Offline check interval the window was derived from, kept so an
+    /// offline grace can add one tick of slack on top of itself.
+    interval: Duration,
 }
 
 impl HysteresisConfig {
@@ -84,6 +93,23 @@ impl HysteresisConfig {
         let raw = Duration::from_millis((count + 1) * interval);
         Self {
             window: raw.min(MAX_HYSTERESIS_WINDOW),
+            interval: Duration::from_millis(interval).min(MAX_HYSTERESIS_WINDOW),
+        }
+    }
+
+    /// Stretch the window to cover an offline grace duration: the actor
+    /// won't confirm offline before `grace` has passed, so the backstop
+    /// waits at least `grace + interval`. A zero grace leaves the window
+    /// unchanged. The grace is capped at [`MAX_OFFLINE_GRACE`].
+    pub fn with_offline_grace(self, grace: Duration) -> Self {
+        if grace.is_zero() {
+            return self;
+        }
+        Self {
+            window: self
+                .window
+                .max(grace.min(MAX_OFFLINE_GRACE) + self.interval),
+            ..self
         }
     }
 
@@ -91,13 +117,16 @@ impl HysteresisConfig {
     /// a sub-second window to drive timer expiry without sleeping.
     #[cfg(test)]
     pub fn from_window(window: Duration) -> Self {
+        let window = window.min(MAX_HYSTERESIS_WINDOW);
         Self {
-            window: window.min(MAX_HYSTERESIS_WINDOW),
+            window,
+            interval: window,
         }
     }
 
     /// The backstop window applied by the lifecycle when entering
-    /// `Hysteresis`. Always `≤ MAX_HYSTERESIS_WINDOW`.
+    /// `Hysteresis`. At most [`MAX_HYSTERESIS_WINDOW`], plus the offline
+    /// grace when one was applied.
     pub fn window(&self) -> Duration {
         self.window
     }
@@ -222,6 +251,24 @@ mod tests {
         assert_eq!(c.window(), Duration::from_secs(80));
     }
 
+    #[test]
+    fn offline_grace_stretches_window_past_grace() {
+        // 2 × 20 s base window is shorter than a 3 min grace + one tick
+        let c = HysteresisConfig::from_scheduler(1, 20_000)
+            .with_offline_grace(Duration::from_secs(180));
+        assert_eq!(c.window(), Duration::from_secs(200));
+
+        // A grace inside the count-derived window changes nothing
+        let c =
+            HysteresisConfig::from_scheduler(3, 20_000).with_offline_grace(Duration::from_secs(30));
+        assert_eq!(c.window(), Duration::from_secs(80));
+
+        // Capped at MAX_OFFLINE_GRACE + interval
+        let c = HysteresisConfig::from_scheduler(3, 20_000)
+            .with_offline_grace(Duration::from_secs(3600));
+        assert_eq!(c.window(), MAX_OFFLINE_GRACE + Duration::from_secs(20));
+    }
+
     #[test]
     fn handle_starts_uncancelled() {
         let h = HysteresisHandle::new(Duration::from_secs(90));
@@ -26,6 +26,7 @@
 //! [`crate::downloader::DownloadManager`] broadcast channels.
 
 use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::Duration;
 
 use chrono::{DateTime, Utc};
@@ -113,6 +114,10 @@ pub struct SessionLifecycle {
     /// each streamer's effective `offline_check_*` (set on its
     /// `StreamerMetadata` by the config resolver).
     hysteresis_resolver: Option<HysteresisWindowFn>,
+    /// Global offline grace in milliseconds (`global_config.offline_grace_secs`).
+    /// Stretches every resolved window so the backstop outlasts the actor's
+    /// grace; updated at runtime via [`Self::set_offline_grace`].
+    offline_grace_ms: AtomicU64,
     /// How long to keep `Ended` entries in `sessions` before evicting them.
     /// Bounds memory while letting the idempotency guard dedupe any
     /// near-simultaneous duplicate authoritative-end events.
@@ -163,6 +168,7 @@ impl SessionLifecycle {
             hysteresis: Arc::new(DashMap::new()),
             hysteresis_config,
             hysteresis_resolver: None,
+            offline_grace_ms: AtomicU64::new(0),
             ended_retention: ENDED_RETENTION_DEFAULT,
             event_repo: None,
             transition_tx,
@@ -186,6 +192,14 @@ impl SessionLifecycle {
         self
     }
 
+    /// Set the global offline grace applied on top of every resolved
+    /// hysteresis window. Takes effect for the next session entering
+    /// `Hysteresis`; windows already armed keep their deadline.
+    pub fn set_offline_grace(&self, grace: Duration) {
+        self.offline_grace_ms
+            .store(grace.as_millis() as u64, Ordering::Relaxed);
+    }
+
     /// Attach the standalone session-event repository used for best-effort
     /// audit writes from in-memory transitions (`hysteresis_entered`,
     /// `session_resumed`). The atomic-tx writes for `session_started` /
@@ -439,6 +453,7 @@ impl SessionLifecycle {
             streams: args.streams.clone(),
             media_headers: args.media_headers.cloned(),
             media_extras: args.media_extras.cloned(),
+            broadcast_started_at: args.broadcast_started_at,
             now: args.now,
         };
 
@@ -778,7 +793,10 @@ impl SessionLifecycle {
             .hysteresis_resolver
             .as_ref()
             .and_then(|r| r(streamer_id))
-            .unwrap_or(self.hysteresis_config);
+            .unwrap_or(self.hysteresis_config)
+            .with_offline_grace(Duration::from_millis(
+                self.offline_grace_ms.load(Ordering::Relaxed),
+            ));
         let window = resolved_config.window();
         let handle = HysteresisHandle::new(window);
         let deadline_inst = handle.deadline;
@@ -1539,6 +1557,8 @@ pub struct LiveDetectedArgs<'a> {
     pub streams: &'a Vec<crate::monitor::StreamInfo>,
     pub media_headers: Option<&'a std::collections::HashMap<String, String>>,
     pub media_extras: Option<&'a std::collections::HashMap<String, String>>,
+    /// When the broadcast started according to the platform, if it reports it.
+    pub broadcast_started_at: Option<DateTime<Utc>>,
     pub now: DateTime<Utc>,
 }
 
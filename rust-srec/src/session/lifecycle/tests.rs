@@ -110,6 +110,7 @@ fn live_args<'a>(now: DateTime<Utc>) -> LiveDetectedArgs<'a> {
         streams,
         media_headers: None,
         media_extras: None,
+        broadcast_started_at: None,
         now,
     }
 }
@@ -630,6 +631,7 @@ async fn multi_session_isolation_across_streamers() {
         streams: &streams_b,
         media_headers: None,
         media_extras: None,
+        broadcast_started_at: None,
         now,
     };
     let sb = lifecycle.on_live_detected(args_b).await.unwrap();
@@ -488,6 +488,137 @@ where
         Ok(())
     }
 
+    // ========== Group Operations ==========
+
+    /// Add streamers to a group, moving them out of any other group.
+    ///
+    /// Members adopt the group's template when it has one. Fails without
+    /// changing anything if one of the streamers does not exist.
+    ///
+    /// # Events
+    /// Emits a single `ConfigUpdateEvent::StreamerGroupUpdated` for the group.
+    pub async fn add_to_group(
+        &self,
+        group_id: &str,
+        group_template_id: Option<&str>,
+        ids: &[String],
+    ) -> Result<Vec<StreamerMetadata>> {
+        if let Some(missing) = ids.iter().find(|id| !self.metadata.contains_key(*id)) {
+            return Err(crate::Error::not_found("Streamer", missing.clone()));
+        }
+
+        self.update_group_members(group_id, ids, |metadata| {
+            metadata.group_id = Some(group_id.to_string());
+            if let Some(template_id) = group_template_id {
+                metadata.template_config_id = Some(template_id.to_string());
+            }
+        })
+        .await
+    }
+
+    /// Remove streamers from a group. Streamers outside the group are
+    /// ignored; removed members keep the template they adopted.
+    ///
+    /// # Events
+    /// Emits a single `ConfigUpdateEvent::StreamerGroupUpdated` for the group.
+    pub async fn remove_from_group(
+        &self,
+        group_id: &str,
+        ids: &[String],
+    ) -> Result<Vec<StreamerMetadata>> {
+        let members: Vec<String> = ids
+            .iter()
+            .filter(|id| {
+                self.metadata
+                    .get(*id)
+                    .is_some_and(|entry| entry.group_id.as_deref() == Some(group_id))
+            })
+            .cloned()
+            .collect();
+
+        self.update_group_members(group_id, &members, |metadata| {
+            metadata.group_id = None;
+        })
+        .await
+    }
+
+    /// Detach every member of a group that is about to be deleted.
+    pub async fn clear_group(&self, group_id: &str) -> Result<Vec<StreamerMetadata>> {
+        let members: Vec<String> = self
+            .get_by_group(group_id)
+            .into_iter()
+            .map(|m| m.id)
+            .collect();
+        self.remove_from_group(group_id, &members).await
+    }
+
+    /// Propagate a change of the group's template to its members.
+    ///
+    /// Every member adopts the new template. When the template is cleared,
+    /// members still on the previous group template lose it, while members
+    /// that picked their own template keep it.
+    ///
+    /// # Events
+    /// Emits a single `ConfigUpdateEvent::StreamerGroupUpdated` for the group.
+    pub async fn apply_group_template(
+        &self,
+        group_id: &str,
+        previous_template_id: Option<&str>,
+        template_id: Option<&str>,
+    ) -> Result<Vec<StreamerMetadata>> {
+        let members: Vec<String> = self
+            .get_by_group(group_id)
+            .into_iter()
+            .map(|m| m.id)
+            .collect();
+
+        self.update_group_members(group_id, &members, |metadata| match template_id {
+            Some(template_id) => {
+                metadata.template_config_id = Some(template_id.to_string());
+            }
+            None if metadata.template_config_id.as_deref() == previous_template_id => {
+                metadata.template_config_id = None;
+            }
+            None => {}
+        })
+        .await
+    }
+
+    /// Persist `apply` for each streamer, then announce one group update.
+    async fn update_group_members(
+        &self,
+        group_id: &str,
+        ids: &[String],
+        apply: impl Fn(&mut StreamerMetadata),
+    ) -> Result<Vec<StreamerMetadata>> {
+        let mut updated = Vec::with_capacity(ids.len());
+        let result = async {
+            for id in ids {
+                let Some(mut metadata) = self.get_streamer(id) else {
+                    continue;
+                };
+                apply(&mut metadata);
+
+                let db_model = self.metadata_to_db_model(&metadata);
+                self.repo.update_streamer(&db_model).await?;
+                self.metadata.insert(id.clone(), metadata.clone());
+                updated.push(metadata);
+            }
+            Ok(())
+        }
+        .await;
+
+        // Members written before a failure must still be picked up.
+        if !updated.is_empty() || result.is_ok() {
+            self.broadcaster
+                .publish(ConfigUpdateEvent::StreamerGroupUpdated {
+                    group_id: group_id.to_string(),
+                });
+        }
+
+        result.map(|()| updated)
+    }
+
     /// Refresh the cached `effective_offline_check_*` values on a streamer's
     /// metadata from a freshly resolved [`crate::config::MergedConfig`].
     /// No-op if the streamer is not currently registered.
@@ -563,6 +694,15 @@ where
             .collect()
     }
 
+    /// Get the members of a group.
+    pub fn get_by_group(&self, group_id: &str) -> Vec<StreamerMetadata> {
+        self.metadata
+            .iter()
+            .filter(|entry| entry.group_id.as_deref() == Some(group_id))
+            .map(|entry| entry.value().clone())
+            .collect()
+    }
+
     /// Get streamers ready for live checking.
     ///
     /// Returns active streamers that are not currently disabled.
@@ -762,6 +902,7 @@ where
             url: metadata.url.clone(),
             platform_config_id: metadata.platform_config_id.clone(),
             template_config_id: metadata.template_config_id.clone(),
+            group_id: metadata.group_id.clone(),
             state: metadata.state.to_string(),
             priority: metadata.priority.to_string(),
             avatar: metadata.avatar_url.clone(),
@@ -993,6 +1134,7 @@ mod tests {
             url: url.to_string(),
             platform_config_id: "test".to_string(),
             template_config_id: None,
+            group_id: None,
             state: StreamerState::NotLive,
             priority: Priority::Normal,
             avatar_url: None,
@@ -1060,6 +1202,7 @@ mod tests {
             url: format!("https://example.com/{}", id),
             platform_config_id: platform.to_string(),
             template_config_id: None,
+            group_id: None,
             state: "NOT_LIVE".to_string(),
             priority: "NORMAL".to_string(),
             avatar: None,
@@ -1099,6 +1242,7 @@ mod tests {
             url: "https://twitch.tv/new".to_string(),
             platform_config_id: "twitch".to_string(),
             template_config_id: None,
+            group_id: None,
             state: StreamerState::NotLive,
             priority: Priority::Normal,
             avatar_url: None,
@@ -1155,6 +1299,55 @@ mod tests {
         assert_eq!(youtube_streamers.len(), 1);
     }
 
+    #[tokio::test]
+    async fn test_group_template_propagates_to_members() {
+        let repo = MockStreamerRepository::with_streamers(vec![
+            create_test_db_model("s1", "twitch"),
+            create_test_db_model("s2", "twitch"),
+        ]);
+        let broadcaster = ConfigEventBroadcaster::new();
+        let mut events = broadcaster.subscribe();
+        let manager = StreamerManager::new(Arc::new(repo), broadcaster);
+        manager.hydrate().await.unwrap();
+        while events.try_recv().is_ok() {}
+
+        let ids = vec!["s1".to_string(), "s2".to_string()];
+        manager.add_to_group("g1", Some("t1"), &ids).await.unwrap();
+        assert!(matches!(
+            events.try_recv(),
+            Ok(ConfigUpdateEvent::StreamerGroupUpdated { group_id }) if group_id == "g1"
+        ));
+        assert!(events.try_recv().is_err());
+        assert_eq!(manager.get_by_group("g1").len(), 2);
+
+        // s2 picks its own template; clearing the group template leaves it alone.
+        let mut s2 = manager.get_streamer("s2").unwrap();
+        s2.template_config_id = Some("own".to_string());
+        manager.update_streamer(s2).await.unwrap();
+        manager
+            .apply_group_template("g1", Some("t1"), None)
+            .await
+            .unwrap();
+        assert_eq!(manager.get_streamer("s1").unwrap().template_config_id, None);
+        assert_eq!(
+            manager
+                .get_streamer("s2")
+                .unwrap()
+                .template_config_id
+                .as_deref(),
+            Some("own")
+        );
+
+        manager.clear_group("g1").await.unwrap();
+        assert!(manager.get_by_group("g1").is_empty());
+        assert!(
+            manager
+                .add_to_group("g1", None, &["missing".to_string()])
+                .await
+                .is_err()
+        );
+    }
+
     #[tokio::test]
     async fn test_record_error_with_backoff() {
         let repo =
@@ -1299,6 +1492,7 @@ mod tests {
             url: "https://example.com".to_string(),
             platform_config_id: "twitch".to_string(),
             template_config_id: None,
+            group_id: None,
             state: StreamerState::NotLive,
             priority: Priority::Normal,
             avatar_url: None,
@@ -24,6 +24,9 @@ pub struct StreamerMetadata {
     pub platform_config_id: String,
     /// Template configuration ID (optional).
     pub template_config_id: Option<String>,
+    /// Group the streamer belongs to (optional).
+    #[serde(default)]
+    pub group_id: Option<String>,
     /// Current state.
     pub state: StreamerState,
     /// Priority level.
@@ -77,6 +80,7 @@ impl StreamerMetadata {
             url: model.url.clone(),
             platform_config_id: model.platform_config_id.clone(),
             template_config_id: model.template_config_id.clone(),
+            group_id: model.group_id.clone(),
             state: StreamerState::parse(&model.state).unwrap_or_default(),
             priority: Priority::parse(&model.priority).unwrap_or_default(),
             avatar_url: model.avatar.clone(),
@@ -170,6 +174,7 @@ mod tests {
             avatar_url: None,
             platform_config_id: "platform-twitch".to_string(),
             template_config_id: None,
+            group_id: None,
             state: StreamerState::NotLive,
             priority: Priority::Normal,
             consecutive_error_count: 0,
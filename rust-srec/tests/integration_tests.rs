@@ -47,6 +47,7 @@ async fn create_test_platform(pool: &DbPool, prefix: &str) -> String {
         paired_segment_pipeline: None,
         offline_check_count: None,
         offline_check_delay_ms: None,
+        rate_limit: None,
     };
     let id = config.id.clone();
 
@@ -1335,6 +1336,7 @@ mod end_to_end_tests {
             url: url.to_string(),
             platform_config_id: platform_id.to_string(),
             template_config_id: None,
+            group_id: None,
             state,
             priority: rust_srec::domain::Priority::Normal,
             consecutive_error_count: 0,
@@ -1731,6 +1733,7 @@ mod end_to_end_tests {
             streams: &streams,
             media_headers: None,
             media_extras: None,
+            broadcast_started_at: None,
             now,
         };
 
@@ -1790,6 +1793,7 @@ mod end_to_end_tests {
             streams: &streams2,
             media_headers: None,
             media_extras: None,
+            broadcast_started_at: None,
             now: later,
         };
         let second = lifecycle